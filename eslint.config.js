@@ -15,6 +15,7 @@ export default tseslint.config(
       'test/starlight-minimal/**',
       'temp-dummy-astro-project/**',
       'src-tauri/src/test_fixtures/**',
+      'src-tauri/sample-project/**',
       '*.config.{js,ts}',
       'vite.config.ts',
       'tailwind.config.js',
@@ -0,0 +1,30 @@
+import { defineCollection, reference } from 'astro:content';
+import { glob } from 'astro/loaders';
+import { z } from 'astro/zod';
+
+const authors = defineCollection({
+  loader: glob({ pattern: '**/[^_]*.md', base: './src/content/authors' }),
+  schema: z.object({
+    name: z.string(),
+    role: z.string().optional(),
+    website: z.string().url().optional(),
+  }),
+});
+
+const blog = defineCollection({
+  loader: glob({ pattern: '**/[^_]*.{md,mdx}', base: './src/content/blog' }),
+  schema: ({ image }) =>
+    z.object({
+      title: z.string().describe('Shown in the file list and as the page heading'),
+      description: z.string().max(160).optional(),
+      pubDate: z.coerce.date(),
+      updatedDate: z.coerce.date().optional(),
+      cover: image().optional(),
+      coverAlt: z.string().optional(),
+      author: reference('authors'),
+      tags: z.array(z.string()).optional(),
+      draft: z.boolean().default(false),
+    }),
+});
+
+export const collections = { blog, authors };
@@ -0,0 +1,17 @@
+import { defineCollection, reference, z } from 'astro:content'
+import { glob } from 'astro/loaders'
+
+const posts = defineCollection({
+  loader: glob({ pattern: '**/*.md', base: './src/content/posts' }),
+  schema: ({ image }) =>
+    z.object({
+      title: z.string(),
+      published: z.boolean(),
+      heroImage: image(),
+      author: reference('authors'),
+      tags: z.array(z.string()).default([]),
+      status: z.enum(['draft', 'published']).optional(),
+    }),
+})
+
+export const collections = { posts }
@@ -0,0 +1,23 @@
+import { defineCollection, reference, z } from 'astro:content'
+import { glob } from 'astro/loaders'
+
+const authors = defineCollection({
+  loader: glob({ pattern: '**/*.md', base: './src/content/authors' }),
+  schema: z.object({
+    name: z.string(),
+  }),
+})
+
+// No JSON schema has been generated, so only image() and reference() are known
+const blog = defineCollection({
+  loader: glob({ pattern: '**/*.md', base: './src/content/blog' }),
+  schema: ({ image }) =>
+    z.object({
+      title: z.string(),
+      cover: image().optional(),
+      author: reference('authors'),
+      related: z.array(reference('blog')).default([]),
+    }),
+})
+
+export const collections = { authors, blog }
@@ -0,0 +1,340 @@
+//! `project-asset://` protocol for serving project media to the webview
+//!
+//! Images and videos referenced from content are streamed straight from the project
+//! rather than through Tauri's built-in asset protocol, so previews work regardless of
+//! the fs plugin scope. Every request is checked against the currently open projects,
+//! only known media types are served, and `Range` requests are honoured so video can
+//! seek without loading the whole file.
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use tauri::http::{header, Request, Response, StatusCode};
+
+use crate::security::{check_path, Scope};
+
+/// URI scheme registered with the webview (`convertFileSrc(path, SCHEME)` on the frontend)
+pub const SCHEME: &str = "project-asset";
+
+/// Largest chunk returned for an open-ended range (`bytes=0-`), so videos stream
+const MAX_RANGE_CHUNK: u64 = 4 * 1024 * 1024;
+
+/// MIME type for a servable media file, or `None` for anything else
+fn mime_type_for(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    let mime = match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "svg" => "image/svg+xml",
+        "ico" => "image/x-icon",
+        "bmp" => "image/bmp",
+        "tif" | "tiff" => "image/tiff",
+        "mp4" | "m4v" => "video/mp4",
+        "webm" => "video/webm",
+        "mov" => "video/quicktime",
+        "ogv" => "video/ogg",
+        "mp3" => "audio/mpeg",
+        "wav" => "audio/wav",
+        "ogg" => "audio/ogg",
+        "m4a" => "audio/mp4",
+        _ => return None,
+    };
+    Some(mime)
+}
+
+/// Decodes the percent-encoded file path from a request URI path
+///
+/// `convertFileSrc` encodes the whole absolute path as one segment, e.g.
+/// `/%2FUsers%2Fme%2Fblog%2Fimage.png`.
+fn decode_request_path(uri_path: &str) -> Result<PathBuf, String> {
+    let encoded = uri_path.strip_prefix('/').unwrap_or(uri_path);
+    let bytes = encoded.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = bytes
+                .get(i + 1..i + 3)
+                .and_then(|h| std::str::from_utf8(h).ok())
+                .and_then(|h| u8::from_str_radix(h, 16).ok())
+                .ok_or("Invalid percent-encoding in path")?;
+            decoded.push(hex);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    let path = String::from_utf8(decoded).map_err(|e| format!("Invalid UTF-8 in path: {e}"))?;
+    if path.is_empty() {
+        return Err("Empty path".to_string());
+    }
+    Ok(PathBuf::from(path))
+}
+
+/// Canonicalizes `path` and checks it lives inside one of the open projects
+/// (or a root one of them trusts)
+fn validate_in_projects(path: &Path, project_roots: &[PathBuf]) -> Option<PathBuf> {
+    project_roots
+        .iter()
+        .find_map(|root| check_path(path, root, Scope::Project).ok())
+}
+
+/// Parses a single `Range` header into an inclusive byte range
+///
+/// Returns `Err(())` when the range can't be satisfied. Multi-range requests are
+/// served as their first range.
+fn parse_range(header_value: &str, file_len: u64) -> Result<(u64, u64), ()> {
+    let spec = header_value
+        .trim()
+        .strip_prefix("bytes=")
+        .and_then(|ranges| ranges.split(',').next())
+        .ok_or(())?
+        .trim();
+    let (start, end) = spec.split_once('-').ok_or(())?;
+
+    if file_len == 0 {
+        return Err(());
+    }
+    let last = file_len - 1;
+
+    let (start, end) = match (start.trim(), end.trim()) {
+        // Suffix range: the last N bytes
+        ("", suffix) => {
+            let suffix: u64 = suffix.parse().map_err(|_| ())?;
+            if suffix == 0 {
+                return Err(());
+            }
+            (file_len.saturating_sub(suffix), last)
+        }
+        (start, "") => {
+            let start: u64 = start.parse().map_err(|_| ())?;
+            (start, last.min(start.saturating_add(MAX_RANGE_CHUNK - 1)))
+        }
+        (start, end) => {
+            let start: u64 = start.parse().map_err(|_| ())?;
+            let end: u64 = end.parse().map_err(|_| ())?;
+            (start, end.min(last))
+        }
+    };
+
+    if start > end || start > last {
+        return Err(());
+    }
+    Ok((start, end))
+}
+
+fn error_response(status: StatusCode, message: &str) -> Response<Vec<u8>> {
+    Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, "text/plain")
+        .body(message.as_bytes().to_vec())
+        .unwrap()
+}
+
+fn read_range(path: &Path, start: u64, end: u64) -> std::io::Result<Vec<u8>> {
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(start))?;
+    let mut buffer = vec![0; (end - start + 1) as usize];
+    file.read_exact(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// Serves a `project-asset://` request from one of the open projects
+pub fn handle_request(request: &Request<Vec<u8>>, project_roots: &[PathBuf]) -> Response<Vec<u8>> {
+    let requested = match decode_request_path(request.uri().path()) {
+        Ok(path) => path,
+        Err(e) => return error_response(StatusCode::BAD_REQUEST, &e),
+    };
+
+    let Some(mime_type) = mime_type_for(&requested) else {
+        return error_response(StatusCode::FORBIDDEN, "Unsupported file type");
+    };
+
+    let Some(path) = validate_in_projects(&requested, project_roots) else {
+        log::warn!(
+            "Astro Editor [ASSET_PROTOCOL] Refused request outside open projects: {}",
+            requested.display()
+        );
+        return error_response(StatusCode::FORBIDDEN, "File outside project directory");
+    };
+
+    let file_len = match std::fs::metadata(&path) {
+        Ok(metadata) if metadata.is_file() => metadata.len(),
+        _ => return error_response(StatusCode::NOT_FOUND, "File not found"),
+    };
+
+    let range_header = request
+        .headers()
+        .get(header::RANGE)
+        .and_then(|value| value.to_str().ok());
+
+    let response = Response::builder()
+        .header(header::CONTENT_TYPE, mime_type)
+        .header(header::ACCEPT_RANGES, "bytes")
+        .header(header::CACHE_CONTROL, "no-cache");
+
+    match range_header {
+        Some(range) => {
+            let Ok((start, end)) = parse_range(range, file_len) else {
+                return Response::builder()
+                    .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                    .header(header::CONTENT_RANGE, format!("bytes */{file_len}"))
+                    .body(Vec::new())
+                    .unwrap();
+            };
+
+            match read_range(&path, start, end) {
+                Ok(body) => response
+                    .status(StatusCode::PARTIAL_CONTENT)
+                    .header(
+                        header::CONTENT_RANGE,
+                        format!("bytes {start}-{end}/{file_len}"),
+                    )
+                    .header(header::CONTENT_LENGTH, body.len())
+                    .body(body)
+                    .unwrap(),
+                Err(e) => error_response(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    &format!("Failed to read file: {e}"),
+                ),
+            }
+        }
+        None => match std::fs::read(&path) {
+            Ok(body) => response
+                .status(StatusCode::OK)
+                .header(header::CONTENT_LENGTH, body.len())
+                .body(body)
+                .unwrap(),
+            Err(e) => error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                &format!("Failed to read file: {e}"),
+            ),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn encoded_uri(path: &Path) -> String {
+        let encoded: String = path
+            .to_string_lossy()
+            .bytes()
+            .map(|b| match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' => {
+                    (b as char).to_string()
+                }
+                other => format!("%{other:02X}"),
+            })
+            .collect();
+        format!("{SCHEME}://localhost/{encoded}")
+    }
+
+    fn request(path: &Path, range: Option<&str>) -> Request<Vec<u8>> {
+        let mut builder = Request::builder().uri(encoded_uri(path));
+        if let Some(range) = range {
+            builder = builder.header(header::RANGE, range);
+        }
+        builder.body(Vec::new()).unwrap()
+    }
+
+    #[test]
+    fn test_decode_request_path() {
+        assert_eq!(
+            decode_request_path("/%2FUsers%2Fme%2Fmy%20blog%2Fimage.png").unwrap(),
+            PathBuf::from("/Users/me/my blog/image.png")
+        );
+        assert!(decode_request_path("/%2").is_err());
+        assert!(decode_request_path("/").is_err());
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("bytes=0-99", 1000), Ok((0, 99)));
+        assert_eq!(parse_range("bytes=900-", 1000), Ok((900, 999)));
+        assert_eq!(parse_range("bytes=-100", 1000), Ok((900, 999)));
+        assert_eq!(parse_range("bytes=500-5000", 1000), Ok((500, 999)));
+        assert_eq!(parse_range("bytes=0-9, 20-29", 1000), Ok((0, 9)));
+        assert_eq!(
+            parse_range("bytes=0-", 100 * 1024 * 1024),
+            Ok((0, MAX_RANGE_CHUNK - 1))
+        );
+        assert!(parse_range("bytes=1000-", 1000).is_err());
+        assert!(parse_range("bytes=50-10", 1000).is_err());
+        assert!(parse_range("items=0-1", 1000).is_err());
+    }
+
+    #[test]
+    fn test_serves_project_file_with_mime_type() {
+        let temp = TempDir::new().unwrap();
+        let image = temp.path().join("src/assets/photo.png");
+        fs::create_dir_all(image.parent().unwrap()).unwrap();
+        fs::write(&image, b"png-bytes").unwrap();
+
+        let response = handle_request(&request(&image, None), &[temp.path().to_path_buf()]);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "image/png");
+        assert_eq!(response.body(), b"png-bytes");
+    }
+
+    #[test]
+    fn test_serves_partial_content() {
+        let temp = TempDir::new().unwrap();
+        let video = temp.path().join("clip.mp4");
+        fs::write(&video, b"0123456789").unwrap();
+
+        let response = handle_request(
+            &request(&video, Some("bytes=2-5")),
+            &[temp.path().to_path_buf()],
+        );
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(response.headers()[header::CONTENT_RANGE], "bytes 2-5/10");
+        assert_eq!(response.body(), b"2345");
+
+        let response = handle_request(
+            &request(&video, Some("bytes=20-")),
+            &[temp.path().to_path_buf()],
+        );
+        assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+    }
+
+    #[test]
+    fn test_refuses_files_outside_projects_and_non_media() {
+        let project = TempDir::new().unwrap();
+        let outside = TempDir::new().unwrap();
+        let secret = outside.path().join("secret.png");
+        fs::write(&secret, b"nope").unwrap();
+        let notes = project.path().join("notes.txt");
+        fs::write(&notes, b"text").unwrap();
+        let roots = [project.path().to_path_buf()];
+
+        let response = handle_request(&request(&secret, None), &roots);
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let traversal = project.path().join("../").join(
+            outside
+                .path()
+                .file_name()
+                .map(|name| Path::new(name).join("secret.png"))
+                .unwrap(),
+        );
+        let response = handle_request(&request(&traversal, None), &roots);
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = handle_request(&request(&notes, None), &roots);
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let missing = project.path().join("missing.png");
+        let response = handle_request(&request(&missing, None), &roots);
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
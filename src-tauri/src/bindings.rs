@@ -16,20 +16,40 @@ pub fn export_bindings() {
 pub fn generate_bindings() -> Builder<tauri::Wry> {
     Builder::<tauri::Wry>::new().commands(collect_commands![
         // menu.rs commands
-        crate::commands::menu::update_format_menu_state,
+        crate::commands::menu::update_menu_context,
+        crate::commands::menu::update_frontmatter_flag_menu,
+        // window.rs commands
+        crate::commands::window::set_window_document,
+        // accessibility.rs commands
+        crate::commands::accessibility::get_system_accessibility_prefs,
+        // zoom.rs commands
+        crate::commands::zoom::get_zoom_level,
+        crate::commands::zoom::set_zoom_level,
+        // print.rs commands
+        crate::commands::print::print_current_document,
+        // preview.rs commands
+        crate::commands::preview::render_markdown_preview,
+        // asset_urls.rs commands
+        crate::commands::asset_urls::rewrite_asset_urls,
+        // toolbar.rs commands
+        crate::commands::toolbar::set_native_toolbar,
         // files.rs commands
         crate::commands::files::read_file,
         crate::commands::files::write_file,
         crate::commands::files::create_file,
         crate::commands::files::delete_file,
+        crate::commands::files::duplicate_file,
         crate::commands::files::rename_file,
+        crate::commands::files::rename_file_with_link_rewrite,
         crate::commands::files::copy_file_to_assets,
         crate::commands::files::copy_file_to_assets_with_override,
+        crate::commands::files::download_image_to_assets,
         crate::commands::files::parse_markdown_content,
+        crate::commands::files::parse_frontmatter_only,
         crate::commands::files::update_frontmatter,
+        crate::commands::files::apply_raw_frontmatter,
         crate::commands::files::save_markdown_content,
-        crate::commands::files::save_recovery_data,
-        crate::commands::files::save_crash_report,
+        crate::commands::files::toggle_frontmatter_flag,
         crate::commands::files::get_app_data_dir,
         crate::commands::files::write_app_data_file,
         crate::commands::files::read_app_data_file,
@@ -39,21 +59,151 @@ pub fn generate_bindings() -> Builder<tauri::Wry> {
         crate::commands::files::is_path_in_project,
         crate::commands::files::get_relative_path,
         crate::commands::files::resolve_image_path,
+        // recovery.rs commands
+        crate::commands::recovery::save_recovery_data,
+        crate::commands::recovery::read_recovery_data,
+        crate::commands::recovery::purge_recovery_data,
+        // crash_reports.rs commands
+        crate::commands::crash_reports::save_crash_report,
+        crate::commands::crash_reports::list_crash_reports,
+        // audit_log.rs commands
+        crate::commands::audit_log::read_audit_log,
+        // bulk_undo.rs commands
+        crate::commands::bulk_undo::get_last_bulk_operation,
+        crate::commands::bulk_undo::undo_last_bulk_operation,
+        // backup.rs commands
+        crate::commands::backup::set_backup_settings,
+        crate::commands::backup::list_backups,
+        crate::commands::backup::create_backup,
+        crate::commands::backup::restore_backup,
+        // dry_run.rs commands
+        crate::commands::dry_run::preview_changes,
+        // coercions.rs commands
+        crate::commands::coercions::check_frontmatter_types,
+        crate::commands::coercions::apply_coercions,
         // project.rs commands
         crate::commands::project::select_project_folder,
         crate::commands::project::scan_project,
         crate::commands::project::scan_project_with_content_dir,
         crate::commands::project::resolve_file_entry,
+        crate::commands::project::get_field_docs,
         crate::commands::project::scan_collection_files,
         crate::commands::project::load_file_based_collection,
         crate::commands::project::read_json_schema,
         crate::commands::project::scan_directory,
         crate::commands::project::count_collection_files_recursive,
         crate::commands::project::scan_collection_files_recursive,
+        crate::commands::project::scan_collection_tree,
+        // projects.rs commands
+        crate::commands::projects::register_project,
+        crate::commands::projects::list_recent_projects,
+        crate::commands::projects::get_project_settings,
+        crate::commands::projects::set_project_settings,
+        // file_collections.rs commands
+        crate::commands::file_collections::save_file_based_collection_entry,
+        crate::commands::file_collections::create_file_based_entry,
+        crate::commands::file_collections::delete_file_based_entry,
+        // listing_stream.rs commands
+        crate::commands::listing_stream::stream_collection_files,
+        crate::commands::listing_stream::ack_listing_batch,
+        crate::commands::listing_stream::cancel_listing_stream,
+        // security.rs commands
+        crate::security::set_trusted_roots,
+        // collections.rs commands
+        crate::commands::collections::create_collection,
+        crate::commands::collections::add_schema_field,
+        crate::commands::collections::rename_collection,
+        crate::commands::collections::preview_config_edit,
+        // remote_collections.rs commands
+        crate::commands::remote_collections::load_remote_collection,
+        crate::commands::remote_collections::refresh_remote_collection,
+        // starter.rs commands
+        crate::commands::starter::create_new_astro_project,
+        crate::commands::starter::create_demo_project,
         // watcher.rs commands
         crate::commands::watcher::start_watching_project,
         crate::commands::watcher::start_watching_project_with_content_dir,
         crate::commands::watcher::stop_watching_project,
+        crate::commands::watcher::get_watcher_stats,
+        crate::commands::watcher::ignore_next_write,
+        // content_graph.rs commands
+        crate::commands::content_graph::export_content_graph,
+        // content_calendar.rs commands
+        crate::commands::content_calendar::get_content_calendar,
+        // link_graph.rs commands
+        crate::commands::link_graph::get_link_graph,
+        crate::commands::link_graph::get_backlinks,
+        // link_check.rs commands
+        crate::commands::link_check::check_links,
+        // slugs.rs commands
+        crate::commands::slugs::generate_slug,
+        crate::commands::slugs::check_slug_conflicts,
+        // transcripts.rs commands
+        crate::commands::transcripts::ingest_transcript,
+        // natural_dates.rs commands
+        crate::commands::natural_dates::parse_natural_date,
+        // date_format.rs commands
+        crate::commands::date_format::format_date,
+        // stale_drafts.rs commands
+        crate::commands::stale_drafts::get_stale_drafts,
+        crate::commands::stale_drafts::set_stale_draft_digest,
+        // asset_browser.rs commands
+        crate::commands::asset_browser::list_project_assets,
+        // asset_repair.rs commands
+        crate::commands::asset_repair::repair_asset_references,
+        // orphaned_assets.rs commands
+        crate::commands::orphaned_assets::find_orphaned_assets,
+        crate::commands::orphaned_assets::delete_orphaned_assets,
+        // markdown_flavor.rs commands
+        crate::commands::markdown_flavor::get_markdown_flavor,
+        crate::commands::markdown_flavor::check_markdown_flavor,
+        // mdx_escaping.rs commands
+        crate::commands::mdx_escaping::check_mdx_escaping,
+        // frontmatter_merge.rs commands
+        crate::commands::frontmatter_merge::resolve_frontmatter_conflict,
+        // merge.rs commands
+        crate::commands::merge::merge_file_versions,
+        // import_mapping.rs commands
+        crate::commands::import_mapping::suggest_import_mapping,
+        // processes.rs commands
+        crate::commands::processes::list_managed_processes,
+        crate::commands::processes::kill_managed_process,
+        // dev_server.rs commands
+        crate::commands::dev_server::start_dev_server,
+        crate::commands::dev_server::stop_dev_server,
+        crate::commands::dev_server::get_dev_server_status,
+        crate::commands::dev_server::get_preview_url,
+        // image_gallery.rs commands
+        crate::commands::image_gallery::import_image_folder,
+        // embargo.rs commands
+        crate::commands::embargo::list_embargoed_entries,
+        crate::commands::embargo::set_embargo_watch,
+        // publish_gates.rs commands
+        crate::commands::publish_gates::check_publish_readiness,
+        // publishing.rs commands
+        crate::commands::publishing::publish_file,
+        // search.rs commands
+        crate::commands::search::fuzzy_find,
+        // saved_filters.rs commands
+        crate::commands::saved_filters::query_collection,
+        // content_tracking.rs commands
+        crate::commands::content_tracking::report_content_changed,
+        // reading_time.rs commands
+        crate::commands::reading_time::get_reading_time,
+        // daily_notes.rs commands
+        crate::commands::daily_notes::open_daily_note,
+        crate::commands::daily_notes::get_daily_note_calendar,
+        // intents.rs commands
+        crate::commands::intents::take_pending_intents,
+        crate::commands::intents::complete_intent,
+        crate::commands::intents::append_to_daily_note,
+        crate::commands::intents::start_capture_server,
+        crate::commands::intents::stop_capture_server,
+        // usage.rs commands
+        crate::commands::usage::record_file_opened,
+        crate::commands::usage::record_file_edited,
+        crate::commands::usage::get_recent_files,
+        crate::commands::usage::get_frequent_files,
         // preferences.rs commands
         crate::commands::preferences::open_preferences_folder,
         crate::commands::preferences::reset_all_preferences,
@@ -61,14 +211,70 @@ pub fn generate_bindings() -> Builder<tauri::Wry> {
         crate::commands::diagnostics::get_app_version,
         crate::commands::diagnostics::get_platform_info,
         crate::commands::diagnostics::get_app_info,
+        // schema_fixtures.rs commands
+        crate::commands::schema_fixtures::run_schema_fixture_tests,
+        // scratchpads.rs commands
+        crate::commands::scratchpads::create_scratchpad,
+        crate::commands::scratchpads::save_scratchpad,
+        crate::commands::scratchpads::list_scratchpads,
+        crate::commands::scratchpads::delete_scratchpad,
+        crate::commands::scratchpads::promote_scratchpad_to_entry,
+        // schema_merger.rs commands
+        crate::schema_merger::validate_frontmatter,
+        // reference_labels.rs commands
+        crate::commands::reference_labels::resolve_reference_labels,
+        // trash.rs commands
+        crate::commands::trash::list_trashed_files,
+        crate::commands::trash::restore_trashed_file,
+        // versions.rs commands
+        crate::commands::versions::list_file_versions,
+        crate::commands::versions::diff_file_version,
+        crate::commands::versions::restore_file_version,
+        // unsaved_drafts.rs commands
+        crate::commands::unsaved_drafts::save_draft,
+        crate::commands::unsaved_drafts::get_draft,
+        crate::commands::unsaved_drafts::discard_draft,
+        crate::commands::unsaved_drafts::list_unsaved_drafts,
         // ide.rs commands
         crate::commands::ide::open_path_in_ide,
+        crate::commands::ide::open_in_ide,
+        crate::commands::ide::detect_ides,
         // mdx_components.rs commands
         crate::commands::mdx_components::scan_mdx_components,
+        // component_usage.rs commands
+        crate::commands::component_usage::find_component_usages,
+        crate::commands::component_usage::component_usage_report,
+        // embeds.rs commands
+        crate::commands::embeds::suggest_embed,
+        // code_blocks.rs commands
+        crate::commands::code_blocks::audit_code_blocks,
+        crate::commands::code_blocks::normalize_code_block_languages,
+        // math.rs commands
+        crate::commands::math::validate_math_blocks,
+        // spellcheck.rs commands
+        crate::commands::spellcheck::check_text,
+        crate::commands::spellcheck::add_to_project_dictionary,
+        // transclusion.rs commands
+        crate::commands::transclusion::resolve_transclusions,
+        crate::commands::transclusion::lint_transclusions,
+        // activity_report.rs commands
+        crate::commands::activity_report::export_activity_report,
+        // git_branch.rs commands
+        crate::commands::git_branch::get_git_branch_state,
+        crate::commands::git_branch::switch_content_branch,
+        // todos.rs commands
+        crate::commands::todos::collect_todos,
+        // tables.rs commands
+        crate::commands::tables::convert_csv_to_table,
         // clipboard.rs commands
         crate::commands::clipboard::copy_text_to_clipboard,
         // updater.rs commands
         crate::commands::updater::fetch_release_notes,
+        crate::commands::updater::get_update_channel,
+        crate::commands::updater::set_update_channel,
+        crate::commands::updater::get_update_info,
+        crate::commands::updater::install_update,
+        crate::commands::updater::defer_update,
         // fonts.rs commands
         crate::commands::fonts::get_linux_ui_font,
     ])
@@ -0,0 +1,263 @@
+//! System accessibility preferences
+//!
+//! Reads reduced motion, increased contrast and the color scheme natively (NSWorkspace
+//! on macOS, `SystemParametersInfoW` on Windows) so the frontend and native surfaces
+//! can respect them. Whenever they change an `accessibility-prefs-changed` event is
+//! emitted with the new values. macOS reports changes through a workspace notification;
+//! elsewhere the settings are re-read when the theme changes or the window regains
+//! focus, which is when users come back from the system settings.
+//!
+//! Linux reports the color scheme only.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+
+const PREFS_CHANGED_EVENT: &str = "accessibility-prefs-changed";
+
+/// The last preferences sent to the frontend, so unchanged values aren't re-emitted
+static LAST_PREFS: Mutex<Option<SystemAccessibilityPrefs>> = Mutex::new(None);
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorScheme {
+    Light,
+    Dark,
+}
+
+/// Accessibility settings from the operating system
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemAccessibilityPrefs {
+    /// "Reduce motion" (macOS) or animations turned off (Windows)
+    pub reduced_motion: bool,
+    /// "Increase contrast" (macOS) or a high contrast theme (Windows)
+    pub increased_contrast: bool,
+    pub color_scheme: ColorScheme,
+}
+
+#[cfg(target_os = "macos")]
+mod native {
+    use objc2::rc::Retained;
+    use objc2::runtime::{AnyObject, NSObject};
+    use objc2::{define_class, msg_send, sel, DefinedClass, MainThreadMarker, MainThreadOnly};
+    use objc2_app_kit::{NSWorkspace, NSWorkspaceAccessibilityDisplayOptionsDidChangeNotification};
+    use std::cell::RefCell;
+    use tauri::AppHandle;
+
+    pub struct ObserverIvars {
+        app: AppHandle,
+    }
+
+    define_class!(
+        #[unsafe(super(NSObject))]
+        #[thread_kind = MainThreadOnly]
+        #[name = "AstroEditorAccessibilityObserver"]
+        #[ivars = ObserverIvars]
+        pub struct AccessibilityObserver;
+
+        impl AccessibilityObserver {
+            #[unsafe(method(displayOptionsChanged:))]
+            fn display_options_changed(&self, _notification: &AnyObject) {
+                super::emit_if_changed(&self.ivars().app);
+            }
+        }
+    );
+
+    thread_local! {
+        // The notification center doesn't retain its observers
+        static OBSERVER: RefCell<Option<Retained<AccessibilityObserver>>> = const { RefCell::new(None) };
+    }
+
+    /// Returns `(reduced_motion, increased_contrast)`
+    pub fn read() -> (bool, bool) {
+        let workspace = NSWorkspace::sharedWorkspace();
+        (
+            workspace.accessibilityDisplayShouldReduceMotion(),
+            workspace.accessibilityDisplayShouldIncreaseContrast(),
+        )
+    }
+
+    /// Observes accessibility display changes. Must be called on the main thread.
+    pub fn watch(app: AppHandle) {
+        let Some(mtm) = MainThreadMarker::new() else {
+            log::warn!("Astro Editor [ACCESSIBILITY] Not on the main thread");
+            return;
+        };
+
+        let observer = AccessibilityObserver::alloc(mtm).set_ivars(ObserverIvars { app });
+        let observer: Retained<AccessibilityObserver> = unsafe { msg_send![super(observer), init] };
+        unsafe {
+            NSWorkspace::sharedWorkspace()
+                .notificationCenter()
+                .addObserver_selector_name_object(
+                    &observer,
+                    sel!(displayOptionsChanged:),
+                    Some(NSWorkspaceAccessibilityDisplayOptionsDidChangeNotification),
+                    None,
+                );
+        }
+        OBSERVER.with(|slot| *slot.borrow_mut() = Some(observer));
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod native {
+    use std::ffi::c_void;
+
+    const SPI_GETHIGHCONTRAST: u32 = 0x0042;
+    const SPI_GETCLIENTAREAANIMATION: u32 = 0x1042;
+    const HCF_HIGHCONTRASTON: u32 = 0x0001;
+
+    #[repr(C)]
+    struct HighContrastW {
+        cb_size: u32,
+        dw_flags: u32,
+        lpsz_default_scheme: *mut u16,
+    }
+
+    #[link(name = "user32")]
+    extern "system" {
+        fn SystemParametersInfoW(
+            ui_action: u32,
+            ui_param: u32,
+            pv_param: *mut c_void,
+            f_win_ini: u32,
+        ) -> i32;
+    }
+
+    /// Returns `(reduced_motion, increased_contrast)`
+    pub fn read() -> (bool, bool) {
+        // Animations are on unless the system says otherwise
+        let mut animations: i32 = 1;
+        let mut high_contrast = HighContrastW {
+            cb_size: std::mem::size_of::<HighContrastW>() as u32,
+            dw_flags: 0,
+            lpsz_default_scheme: std::ptr::null_mut(),
+        };
+
+        unsafe {
+            SystemParametersInfoW(
+                SPI_GETCLIENTAREAANIMATION,
+                0,
+                &mut animations as *mut i32 as *mut c_void,
+                0,
+            );
+            SystemParametersInfoW(
+                SPI_GETHIGHCONTRAST,
+                high_contrast.cb_size,
+                &mut high_contrast as *mut HighContrastW as *mut c_void,
+                0,
+            );
+        }
+
+        (
+            animations == 0,
+            high_contrast.dw_flags & HCF_HIGHCONTRASTON != 0,
+        )
+    }
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+mod native {
+    pub fn read() -> (bool, bool) {
+        (false, false)
+    }
+}
+
+/// Reads the current preferences, taking the color scheme from the main window
+fn read_prefs(app: &AppHandle) -> SystemAccessibilityPrefs {
+    let (reduced_motion, increased_contrast) = native::read();
+    let theme = app
+        .get_webview_window("main")
+        .and_then(|window| window.theme().ok());
+
+    SystemAccessibilityPrefs {
+        reduced_motion,
+        increased_contrast,
+        color_scheme: match theme {
+            Some(tauri::Theme::Dark) => ColorScheme::Dark,
+            _ => ColorScheme::Light,
+        },
+    }
+}
+
+/// Stores `prefs` as the last known preferences, returning whether they changed
+fn record_prefs(prefs: SystemAccessibilityPrefs) -> bool {
+    let Ok(mut last) = LAST_PREFS.lock() else {
+        return true;
+    };
+    last.replace(prefs) != Some(prefs)
+}
+
+/// Re-reads the preferences and emits `accessibility-prefs-changed` if they changed
+pub(crate) fn emit_if_changed(app: &AppHandle) {
+    let prefs = read_prefs(app);
+    if record_prefs(prefs) {
+        let _ = app.emit(PREFS_CHANGED_EVENT, prefs);
+    }
+}
+
+/// Starts listening for system accessibility changes (called once during setup)
+pub(crate) fn watch_accessibility_prefs(app: &AppHandle) {
+    record_prefs(read_prefs(app));
+
+    #[cfg(target_os = "macos")]
+    native::watch(app.clone());
+}
+
+/// Gets the system's reduced motion, increased contrast and color scheme settings
+///
+/// Changes are emitted as `accessibility-prefs-changed` with the same payload.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_system_accessibility_prefs(
+    app: AppHandle,
+) -> Result<SystemAccessibilityPrefs, String> {
+    let prefs = read_prefs(&app);
+    record_prefs(prefs);
+    Ok(prefs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefs_serialize_for_frontend() {
+        let prefs = SystemAccessibilityPrefs {
+            reduced_motion: true,
+            increased_contrast: false,
+            color_scheme: ColorScheme::Dark,
+        };
+
+        assert_eq!(
+            serde_json::to_value(prefs).unwrap(),
+            serde_json::json!({
+                "reducedMotion": true,
+                "increasedContrast": false,
+                "colorScheme": "dark",
+            })
+        );
+    }
+
+    #[test]
+    fn test_record_prefs_reports_changes_only() {
+        let light = SystemAccessibilityPrefs {
+            reduced_motion: false,
+            increased_contrast: false,
+            color_scheme: ColorScheme::Light,
+        };
+        let reduced = SystemAccessibilityPrefs {
+            reduced_motion: true,
+            ..light
+        };
+
+        record_prefs(light);
+        assert!(!record_prefs(light));
+        assert!(record_prefs(reduced));
+        assert!(!record_prefs(reduced));
+        assert!(record_prefs(light));
+    }
+}
@@ -0,0 +1,547 @@
+//! Activity reports for "what I shipped" posts
+//!
+//! Summarises a date range: entries whose publish date falls in it, entries created
+//! and updated, words written and assets added. Creation, updates, words and assets
+//! come from git history when the project is a repository; without git, updates fall
+//! back to the usage stats and file times, and words written are unknown.
+
+use crate::commands::files::parse_frontmatter_internal;
+use crate::commands::usage;
+use crate::utils::git;
+use crate::utils::path::normalize_path_for_serialization;
+use chrono::{DateTime, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use walkdir::WalkDir;
+
+/// Tree object of an empty repository, used as the diff base for ranges before the
+/// first commit
+const EMPTY_TREE: &str = "4b825dc642cb6eb9a060e54bf8d69288fbee4904";
+
+/// Frontmatter fields the report reads, from the project's field mappings
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportFieldMappings {
+    pub title: String,
+    /// Publish date fields, first present wins
+    pub published_date: Vec<String>,
+    pub draft: String,
+}
+
+impl Default for ReportFieldMappings {
+    fn default() -> Self {
+        Self {
+            title: "title".to_string(),
+            published_date: vec![
+                "pubDate".to_string(),
+                "date".to_string(),
+                "publishedDate".to_string(),
+            ],
+            draft: "draft".to_string(),
+        }
+    }
+}
+
+/// A content entry mentioned in a report
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportEntry {
+    /// Path relative to the project root
+    pub path: String,
+    pub collection: String,
+    pub title: String,
+    /// Publish date as `YYYY-MM-DD`, if the entry has one
+    pub published_date: Option<String>,
+}
+
+/// Activity in a project over a date range
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityReport {
+    /// First day of the range, `YYYY-MM-DD`
+    pub from: String,
+    /// Last day of the range (inclusive), `YYYY-MM-DD`
+    pub to: String,
+    /// True if created/updated/words/assets came from git history
+    pub git_available: bool,
+    /// Non-draft entries with a publish date in the range
+    pub published: Vec<ReportEntry>,
+    pub created: Vec<ReportEntry>,
+    pub updated: Vec<ReportEntry>,
+    /// Words added to content files; `None` without git history
+    pub words_written: Option<u32>,
+    /// Project-relative paths of assets added in the range
+    pub new_assets: Vec<String>,
+    /// The report rendered as Markdown
+    pub markdown: String,
+}
+
+/// A content file with the frontmatter the report needs
+struct ContentFile {
+    entry: ReportEntry,
+    is_draft: bool,
+    modified: Option<SystemTime>,
+}
+
+fn parse_range(from: &str, to: &str) -> Result<(NaiveDate, NaiveDate), String> {
+    let parse = |date: &str| {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|e| format!("Invalid date '{date}': {e}"))
+    };
+    let (from, to) = (parse(from)?, parse(to)?);
+    if from > to {
+        return Err(format!("Report range starts after it ends: {from} > {to}"));
+    }
+    Ok((from, to))
+}
+
+fn is_in_range(date: NaiveDate, from: NaiveDate, to: NaiveDate) -> bool {
+    date >= from && date <= to
+}
+
+fn time_in_range(time: SystemTime, from: NaiveDate, to: NaiveDate) -> bool {
+    is_in_range(DateTime::<Local>::from(time).date_naive(), from, to)
+}
+
+/// Reads `YYYY-MM-DD` from a date or datetime frontmatter value
+fn frontmatter_date(value: &Value) -> Option<NaiveDate> {
+    let text = value.as_str()?;
+    NaiveDate::parse_from_str(text.get(..10)?, "%Y-%m-%d").ok()
+}
+
+fn read_content_files(
+    project_root: &Path,
+    content_root: &Path,
+    mappings: &ReportFieldMappings,
+) -> Vec<ContentFile> {
+    WalkDir::new(content_root)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry.file_type().is_file()
+                && matches!(
+                    entry.path().extension().and_then(|e| e.to_str()),
+                    Some("md") | Some("mdx")
+                )
+        })
+        .filter_map(|entry| {
+            let path = entry.path();
+            let content = std::fs::read_to_string(path).ok()?;
+            let frontmatter = parse_frontmatter_internal(&content)
+                .map(|parsed| parsed.frontmatter)
+                .unwrap_or_default();
+
+            let relative = path.strip_prefix(project_root).ok()?;
+            // Files directly in the content directory belong to no collection
+            let in_content = path.strip_prefix(content_root).ok()?;
+            let collection = if in_content.components().count() > 1 {
+                in_content
+                    .components()
+                    .next()
+                    .map(|c| c.as_os_str().to_string_lossy().to_string())
+                    .unwrap_or_default()
+            } else {
+                String::new()
+            };
+            let title = frontmatter
+                .get(&mappings.title)
+                .and_then(Value::as_str)
+                .map(String::from)
+                .unwrap_or_else(|| {
+                    path.file_stem()
+                        .unwrap_or_default()
+                        .to_string_lossy()
+                        .to_string()
+                });
+            let published_date = mappings
+                .published_date
+                .iter()
+                .find_map(|field| frontmatter.get(field).and_then(frontmatter_date));
+
+            Some(ContentFile {
+                entry: ReportEntry {
+                    path: normalize_path_for_serialization(relative),
+                    collection,
+                    title,
+                    published_date: published_date.map(|d| d.format("%Y-%m-%d").to_string()),
+                },
+                is_draft: frontmatter.get(&mappings.draft) == Some(&Value::Bool(true)),
+                modified: entry.metadata().ok().and_then(|m| m.modified().ok()),
+            })
+        })
+        .collect()
+}
+
+/// Project-relative paths under `dir` that git recorded with `diff_filter` (e.g. `A`
+/// for added) in the range
+fn git_changed_paths(
+    project_root: &Path,
+    dir: &str,
+    diff_filter: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> BTreeSet<String> {
+    let since = format!("--since={from}T00:00:00");
+    let until = format!("--until={to}T23:59:59");
+    let filter = format!("--diff-filter={diff_filter}");
+    git::query(
+        project_root,
+        &[
+            "log",
+            &since,
+            &until,
+            &filter,
+            "--relative",
+            "--name-only",
+            "--pretty=format:",
+            "--",
+            dir,
+        ],
+    )
+    .map(|out| {
+        out.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Words on added lines of a `--word-diff=porcelain` diff
+fn count_added_words(porcelain_diff: &str) -> u32 {
+    porcelain_diff
+        .lines()
+        .filter(|line| line.starts_with('+') && !line.starts_with("+++"))
+        .map(|line| line[1..].split_whitespace().count() as u32)
+        .sum()
+}
+
+/// Words added under `dir` between the last commit before the range and the last
+/// commit in it
+fn git_words_written(project_root: &Path, dir: &str, from: NaiveDate, to: NaiveDate) -> u32 {
+    let last_commit_before = |time: String| {
+        git::query(
+            project_root,
+            &["rev-list", "-1", &format!("--before={time}"), "HEAD"],
+        )
+        .map(|out| out.trim().to_string())
+        .filter(|rev| !rev.is_empty())
+    };
+
+    let Some(end) = last_commit_before(format!("{to}T23:59:59")) else {
+        return 0;
+    };
+    let start =
+        last_commit_before(format!("{from}T00:00:00")).unwrap_or_else(|| EMPTY_TREE.to_string());
+
+    git::query(
+        project_root,
+        &[
+            "diff",
+            "--word-diff=porcelain",
+            "--relative",
+            &start,
+            &end,
+            "--",
+            dir,
+        ],
+    )
+    .map(|diff| count_added_words(&diff))
+    .unwrap_or(0)
+}
+
+/// Assets whose creation (or, where unsupported, modification) time is in the range
+fn assets_added_on_disk(
+    project_root: &Path,
+    assets_root: &Path,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> BTreeSet<String> {
+    WalkDir::new(assets_root)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter(|entry| {
+            entry
+                .metadata()
+                .ok()
+                .and_then(|m| m.created().or_else(|_| m.modified()).ok())
+                .is_some_and(|time| time_in_range(time, from, to))
+        })
+        .filter_map(|entry| {
+            entry
+                .path()
+                .strip_prefix(project_root)
+                .ok()
+                .map(normalize_path_for_serialization)
+        })
+        .collect()
+}
+
+fn entry_line(entry: &ReportEntry) -> String {
+    match &entry.published_date {
+        Some(date) => format!("- {} ({date}) — `{}`", entry.title, entry.path),
+        None => format!("- {} — `{}`", entry.title, entry.path),
+    }
+}
+
+/// Renders a report as Markdown
+fn render_markdown(report: &ActivityReport) -> String {
+    let mut out = format!("# Activity: {} to {}\n\n", report.from, report.to);
+
+    out.push_str(&format!(
+        "- Entries published: {}\n",
+        report.published.len()
+    ));
+    out.push_str(&format!("- Entries created: {}\n", report.created.len()));
+    out.push_str(&format!("- Entries updated: {}\n", report.updated.len()));
+    match report.words_written {
+        Some(words) => out.push_str(&format!("- Words written: {words}\n")),
+        None => out.push_str("- Words written: unknown (no git history)\n"),
+    }
+    out.push_str(&format!("- New assets: {}\n", report.new_assets.len()));
+
+    let sections = [
+        ("Published", &report.published),
+        ("Created", &report.created),
+        ("Updated", &report.updated),
+    ];
+    for (heading, entries) in sections {
+        if entries.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("\n## {heading}\n\n"));
+        for entry in entries {
+            out.push_str(&entry_line(entry));
+            out.push('\n');
+        }
+    }
+
+    if !report.new_assets.is_empty() {
+        out.push_str("\n## New assets\n\n");
+        for asset in &report.new_assets {
+            out.push_str(&format!("- `{asset}`\n"));
+        }
+    }
+
+    out
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_report(
+    project_root: &Path,
+    content_directory: &str,
+    assets_directory: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+    mappings: &ReportFieldMappings,
+    usage_edited: &BTreeSet<String>,
+    use_git: bool,
+) -> ActivityReport {
+    let files = read_content_files(
+        project_root,
+        &project_root.join(content_directory),
+        mappings,
+    );
+    let select = |paths: &BTreeSet<String>| -> Vec<ReportEntry> {
+        files
+            .iter()
+            .filter(|file| paths.contains(&file.entry.path))
+            .map(|file| file.entry.clone())
+            .collect()
+    };
+
+    let mut published: Vec<ReportEntry> = files
+        .iter()
+        .filter(|file| !file.is_draft)
+        .filter(|file| {
+            file.entry
+                .published_date
+                .as_deref()
+                .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+                .is_some_and(|date| is_in_range(date, from, to))
+        })
+        .map(|file| file.entry.clone())
+        .collect();
+    published.sort_by(|a, b| {
+        a.published_date
+            .cmp(&b.published_date)
+            .then_with(|| a.title.cmp(&b.title))
+    });
+
+    let (created_paths, mut updated_paths, words_written, new_assets) = if use_git {
+        (
+            git_changed_paths(project_root, content_directory, "A", from, to),
+            git_changed_paths(project_root, content_directory, "M", from, to),
+            Some(git_words_written(project_root, content_directory, from, to)),
+            git_changed_paths(project_root, assets_directory, "A", from, to),
+        )
+    } else {
+        let modified: BTreeSet<String> = files
+            .iter()
+            .filter(|file| file.modified.is_some_and(|t| time_in_range(t, from, to)))
+            .map(|file| file.entry.path.clone())
+            .collect();
+        (
+            BTreeSet::new(),
+            modified,
+            None,
+            assets_added_on_disk(project_root, &project_root.join(assets_directory), from, to),
+        )
+    };
+    updated_paths.extend(usage_edited.iter().cloned());
+    // An entry created in the range is reported as created, not also as updated
+    let updated_paths: BTreeSet<String> =
+        updated_paths.difference(&created_paths).cloned().collect();
+
+    let mut report = ActivityReport {
+        from: from.format("%Y-%m-%d").to_string(),
+        to: to.format("%Y-%m-%d").to_string(),
+        git_available: use_git,
+        published,
+        created: select(&created_paths),
+        updated: select(&updated_paths),
+        words_written,
+        new_assets: new_assets.into_iter().collect(),
+        markdown: String::new(),
+    };
+    report.markdown = render_markdown(&report);
+    report
+}
+
+/// Summarises project activity over a date range
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override (defaults to `src/content`)
+/// * `assets_directory` - Optional assets directory override (defaults to `src/assets`)
+/// * `from` - First day of the range, `YYYY-MM-DD`
+/// * `to` - Last day of the range (inclusive), `YYYY-MM-DD`
+/// * `mappings` - Title, publish date and draft field names (defaults to `title`,
+///   `pubDate`/`date`/`publishedDate` and `draft`)
+///
+/// # Returns
+/// The report as structured data plus a Markdown rendering
+#[tauri::command]
+#[specta::specta]
+pub async fn export_activity_report(
+    app: tauri::AppHandle,
+    project_path: String,
+    content_directory: Option<String>,
+    assets_directory: Option<String>,
+    from: String,
+    to: String,
+    mappings: Option<ReportFieldMappings>,
+) -> Result<ActivityReport, String> {
+    let (from, to) = parse_range(&from, &to)?;
+    let usage_edited = usage::edited_between(&app, &project_path, from, to)?;
+
+    tokio::task::spawn_blocking(move || {
+        let project_root = PathBuf::from(&project_path);
+        let use_git = git::is_repository(&project_root);
+        build_report(
+            &project_root,
+            content_directory.as_deref().unwrap_or("src/content"),
+            assets_directory.as_deref().unwrap_or("src/assets"),
+            from,
+            to,
+            &mappings.unwrap_or_default(),
+            &usage_edited,
+            use_git,
+        )
+    })
+    .await
+    .map_err(|e| format!("Failed to build activity report: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn date(text: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_count_added_words() {
+        let diff = "diff --git a/post.md b/post.md\n\
+                    --- a/post.md\n\
+                    +++ b/post.md\n\
+                    @@ -1 +1 @@\n\
+                    \x20Intro\n\
+                    -old words\n\
+                    +three new words\n\
+                    ~\n\
+                    +another\n";
+        assert_eq!(count_added_words(diff), 4);
+    }
+
+    #[test]
+    fn test_build_report_without_git() {
+        let temp = TempDir::new().unwrap();
+        let posts = temp.path().join("src/content/posts");
+        fs::create_dir_all(&posts).unwrap();
+        fs::write(
+            posts.join("in-range.md"),
+            "---\ntitle: Shipped\npubDate: 2026-10-05\n---\n\nBody\n",
+        )
+        .unwrap();
+        fs::write(
+            posts.join("draft.md"),
+            "---\ntitle: Not yet\npubDate: 2026-10-06\ndraft: true\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            posts.join("old.md"),
+            "---\ntitle: Old\ndate: 2025-01-01T09:00:00Z\n---\n",
+        )
+        .unwrap();
+
+        let edited: BTreeSet<String> = ["src/content/posts/old.md".to_string()].into();
+        let report = build_report(
+            temp.path(),
+            "src/content",
+            "src/assets",
+            date("2026-10-01"),
+            date("2026-10-31"),
+            &ReportFieldMappings::default(),
+            &edited,
+            false,
+        );
+
+        assert_eq!(report.published.len(), 1);
+        assert_eq!(report.published[0].title, "Shipped");
+        assert_eq!(report.published[0].collection, "posts");
+        assert_eq!(
+            report.published[0].published_date.as_deref(),
+            Some("2026-10-05")
+        );
+        assert!(report
+            .updated
+            .iter()
+            .any(|e| e.path == "src/content/posts/old.md"));
+        assert_eq!(report.words_written, None);
+        assert!(report
+            .markdown
+            .starts_with("# Activity: 2026-10-01 to 2026-10-31"));
+        assert!(report
+            .markdown
+            .contains("- Shipped (2026-10-05) — `src/content/posts/in-range.md`"));
+    }
+
+    #[test]
+    fn test_parse_range_rejects_reversed_dates() {
+        assert!(parse_range("2026-10-31", "2026-10-01").is_err());
+        assert!(parse_range("2026-10-01", "not a date").is_err());
+        assert!(parse_range("2026-10-01", "2026-10-01").is_ok());
+    }
+}
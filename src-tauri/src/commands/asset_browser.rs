@@ -0,0 +1,374 @@
+//! Browsing the project's image assets
+//!
+//! `list_project_assets` lists the images in the assets directory (`src/assets`, or the
+//! project's override) for the asset picker, grouped by the collection subdirectory
+//! they were imported into. Results are paged, and dimensions are only read for the
+//! images on the requested page.
+
+use crate::commands::image_gallery::IMAGE_EXTENSIONS;
+use crate::security::{check_path, Scope};
+use crate::utils::collation::natural_cmp;
+use crate::utils::path::normalize_path_for_serialization;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Images returned per page when no limit is given
+const DEFAULT_PAGE_SIZE: u32 = 100;
+
+/// Which images to list
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetListOptions {
+    /// Only images in this collection's subdirectory
+    #[serde(default)]
+    #[specta(optional)]
+    pub collection: Option<String>,
+    /// Only these extensions (case-insensitive, without the dot)
+    #[serde(default)]
+    #[specta(optional)]
+    pub extensions: Option<Vec<String>>,
+    /// Images to skip, for paging
+    #[serde(default)]
+    #[specta(optional)]
+    pub offset: Option<u32>,
+    /// Images to return; 100 when omitted
+    #[serde(default)]
+    #[specta(optional)]
+    pub limit: Option<u32>,
+}
+
+/// An image in the assets directory
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectAsset {
+    /// Path relative to the project root, e.g. `src/assets/blog/hero.png`
+    pub path: String,
+    pub name: String,
+    pub extension: String,
+    /// Pixel dimensions; `None` when the image can't be read (or an SVG has no size)
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    #[specta(type = f64)]
+    pub size_bytes: u64,
+    #[specta(type = Option<f64>)]
+    pub last_modified: Option<u64>,
+}
+
+/// The images on a page that belong to one collection
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetGroup {
+    /// The collection subdirectory; `None` for images directly in the assets directory
+    pub collection: Option<String>,
+    pub assets: Vec<ProjectAsset>,
+}
+
+/// A page of images
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetPage {
+    pub groups: Vec<AssetGroup>,
+    /// Images matching the filters, across all pages
+    pub total: u32,
+    pub has_more: bool,
+}
+
+/// An image found while walking, before its dimensions are read
+struct FoundAsset {
+    path: PathBuf,
+    collection: Option<String>,
+    name: String,
+    extension: String,
+    size_bytes: u64,
+    last_modified: Option<u64>,
+}
+
+/// Images under `assets_dir` matching `options`, ordered by collection then name
+fn find_assets(assets_dir: &Path, options: &AssetListOptions) -> Vec<FoundAsset> {
+    let extensions: Option<Vec<String>> = options.extensions.as_ref().map(|exts| {
+        exts.iter()
+            .map(|e| e.trim_start_matches('.').to_lowercase())
+            .collect()
+    });
+
+    let mut assets: Vec<FoundAsset> = WalkDir::new(assets_dir)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() == 0 || !entry.file_name().to_string_lossy().starts_with('.')
+        })
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| {
+            let extension = entry.path().extension()?.to_str()?.to_lowercase();
+            if !IMAGE_EXTENSIONS.contains(&extension.as_str()) {
+                return None;
+            }
+            if extensions
+                .as_ref()
+                .is_some_and(|exts| !exts.contains(&extension))
+            {
+                return None;
+            }
+
+            let relative = entry.path().strip_prefix(assets_dir).ok()?;
+            let collection = (relative.components().count() > 1)
+                .then(|| relative.components().next())
+                .flatten()
+                .map(|c| c.as_os_str().to_string_lossy().to_string());
+            if options.collection.is_some() && options.collection != collection {
+                return None;
+            }
+
+            let metadata = entry.metadata().ok()?;
+            Some(FoundAsset {
+                path: entry.path().to_path_buf(),
+                collection,
+                name: entry.file_name().to_string_lossy().to_string(),
+                extension,
+                size_bytes: metadata.len(),
+                last_modified: metadata
+                    .modified()
+                    .ok()
+                    .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+                    .map(|duration| duration.as_secs()),
+            })
+        })
+        .collect();
+
+    assets.sort_by(|a, b| {
+        a.collection
+            .cmp(&b.collection)
+            .then_with(|| natural_cmp(&a.name, &b.name))
+    });
+    assets
+}
+
+/// Width and height of an image, from its header
+fn image_dimensions(path: &Path, extension: &str) -> Option<(u32, u32)> {
+    if extension == "svg" {
+        return svg_dimensions(&std::fs::read_to_string(path).ok()?);
+    }
+    image::image_dimensions(path).ok()
+}
+
+/// The size of an SVG from its root `width`/`height` attributes, else its `viewBox`
+fn svg_dimensions(svg: &str) -> Option<(u32, u32)> {
+    let root = &svg[svg.find("<svg")?..];
+    let root = &root[..root.find('>')?];
+    let attribute = |name: &str| {
+        Regex::new(&format!(r#"\s{name}\s*=\s*["']([^"']*)["']"#))
+            .unwrap()
+            .captures(root)
+            .map(|c| c[1].to_string())
+    };
+    let length = |value: String| {
+        value
+            .trim()
+            .trim_end_matches("px")
+            .parse::<f64>()
+            .ok()
+            .filter(|n| *n > 0.0)
+            .map(|n| n.round() as u32)
+    };
+
+    if let (Some(width), Some(height)) = (
+        attribute("width").and_then(length),
+        attribute("height").and_then(length),
+    ) {
+        return Some((width, height));
+    }
+
+    let view_box = attribute("viewBox")?;
+    let parts: Vec<f64> = view_box
+        .split([' ', ','])
+        .filter(|part| !part.is_empty())
+        .filter_map(|part| part.parse().ok())
+        .collect();
+    match parts[..] {
+        [_, _, width, height] if width > 0.0 && height > 0.0 => {
+            Some((width.round() as u32, height.round() as u32))
+        }
+        _ => None,
+    }
+}
+
+/// Builds a page of assets, reading dimensions only for the images on it
+fn asset_page(project_root: &Path, found: Vec<FoundAsset>, offset: u32, limit: u32) -> AssetPage {
+    let total = found.len() as u32;
+    let mut groups: Vec<AssetGroup> = Vec::new();
+
+    for asset in found.into_iter().skip(offset as usize).take(limit as usize) {
+        let (width, height) = image_dimensions(&asset.path, &asset.extension).unzip();
+        let relative = asset.path.strip_prefix(project_root).unwrap_or(&asset.path);
+        let project_asset = ProjectAsset {
+            path: normalize_path_for_serialization(relative),
+            name: asset.name,
+            extension: asset.extension,
+            width,
+            height,
+            size_bytes: asset.size_bytes,
+            last_modified: asset.last_modified,
+        };
+        match groups.last_mut() {
+            Some(group) if group.collection == asset.collection => group.assets.push(project_asset),
+            _ => groups.push(AssetGroup {
+                collection: asset.collection,
+                assets: vec![project_asset],
+            }),
+        }
+    }
+
+    AssetPage {
+        groups,
+        total,
+        has_more: offset.saturating_add(limit) < total,
+    }
+}
+
+/// Lists the images in the project's assets directory, grouped by collection
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `assets_directory` - Assets directory override, relative to the project root
+/// * `options` - Collection and extension filters, and the page to return
+#[tauri::command]
+#[specta::specta]
+pub async fn list_project_assets(
+    project_path: String,
+    assets_directory: Option<String>,
+    options: AssetListOptions,
+) -> Result<AssetPage, String> {
+    let project_root = Path::new(&project_path)
+        .canonicalize()
+        .map_err(|_| "Invalid project root".to_string())?;
+    let assets_dir = project_root.join(assets_directory.as_deref().unwrap_or("src/assets"));
+
+    if !assets_dir.exists() {
+        return Ok(AssetPage {
+            groups: Vec::new(),
+            total: 0,
+            has_more: false,
+        });
+    }
+    let assets_dir = check_path(&assets_dir, &project_root, Scope::Project)?;
+
+    tokio::task::spawn_blocking(move || {
+        let found = find_assets(&assets_dir, &options);
+        asset_page(
+            &project_root,
+            found,
+            options.offset.unwrap_or(0),
+            options.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+        )
+    })
+    .await
+    .map_err(|e| format!("Failed to list assets: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::RgbImage;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn project() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        let assets = temp.path().join("src/assets");
+        fs::create_dir_all(assets.join("blog")).unwrap();
+        fs::create_dir_all(assets.join("notes/2024")).unwrap();
+        fs::create_dir_all(assets.join(".cache")).unwrap();
+
+        let png = RgbImage::new(4, 3);
+        png.save(assets.join("blog/hero-10.png")).unwrap();
+        png.save(assets.join("blog/hero-2.png")).unwrap();
+        png.save(assets.join(".cache/hidden.png")).unwrap();
+        fs::write(
+            assets.join("notes/2024/diagram.svg"),
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 120 80"></svg>"#,
+        )
+        .unwrap();
+        fs::write(assets.join("logo.svg"), r#"<svg width="32px" height="16">"#).unwrap();
+        fs::write(assets.join("blog/notes.txt"), "not an image").unwrap();
+        temp
+    }
+
+    #[tokio::test]
+    async fn test_list_project_assets() {
+        let temp = project();
+        let project_path = temp.path().to_string_lossy().to_string();
+
+        let page = list_project_assets(project_path.clone(), None, AssetListOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(page.total, 4);
+        assert!(!page.has_more);
+        let collections: Vec<_> = page
+            .groups
+            .iter()
+            .map(|g| g.collection.as_deref())
+            .collect();
+        assert_eq!(collections, vec![None, Some("blog"), Some("notes")]);
+
+        let logo = &page.groups[0].assets[0];
+        assert_eq!(logo.path, "src/assets/logo.svg");
+        assert_eq!((logo.width, logo.height), (Some(32), Some(16)));
+
+        let blog: Vec<_> = page.groups[1]
+            .assets
+            .iter()
+            .map(|a| a.name.as_str())
+            .collect();
+        assert_eq!(blog, vec!["hero-2.png", "hero-10.png"]);
+        let hero = &page.groups[1].assets[0];
+        assert_eq!((hero.width, hero.height), (Some(4), Some(3)));
+        assert!(hero.size_bytes > 0);
+        assert!(hero.last_modified.is_some());
+
+        let diagram = &page.groups[2].assets[0];
+        assert_eq!(diagram.path, "src/assets/notes/2024/diagram.svg");
+        assert_eq!((diagram.width, diagram.height), (Some(120), Some(80)));
+    }
+
+    #[tokio::test]
+    async fn test_list_project_assets_filters_and_pages() {
+        let temp = project();
+        let project_path = temp.path().to_string_lossy().to_string();
+
+        let options = AssetListOptions {
+            extensions: Some(vec![".PNG".to_string()]),
+            offset: Some(1),
+            limit: Some(1),
+            ..Default::default()
+        };
+        let page = list_project_assets(project_path.clone(), None, options)
+            .await
+            .unwrap();
+        assert_eq!(page.total, 2);
+        assert!(!page.has_more);
+        assert_eq!(page.groups[0].assets[0].name, "hero-10.png");
+
+        let options = AssetListOptions {
+            collection: Some("notes".to_string()),
+            ..Default::default()
+        };
+        let page = list_project_assets(project_path.clone(), None, options)
+            .await
+            .unwrap();
+        assert_eq!(page.total, 1);
+
+        let page = list_project_assets(
+            project_path,
+            Some("missing".to_string()),
+            AssetListOptions::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(page.total, 0);
+    }
+}
@@ -0,0 +1,495 @@
+//! Repairing image references broken by moving assets outside the editor
+//!
+//! `repair_asset_references` finds images referenced from content (Markdown images,
+//! `src` attributes, MDX imports and frontmatter values) that no longer exist, and looks
+//! for a file with the same name in the assets and `public` directories. A single match,
+//! or several with identical contents, gives a rewrite in the style of the original
+//! reference; anything else is reported with its candidates for fixing by hand.
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::bulk_undo::UndoBundle;
+use crate::commands::dry_run;
+use crate::commands::files::{calculate_relative_path, parse_frontmatter_internal};
+use crate::commands::image_gallery::IMAGE_EXTENSIONS;
+use crate::commands::publish_gates::link_targets;
+use crate::utils::tsconfig::{load_path_aliases, resolve_aliased_path, to_aliased_path, PathAlias};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// A broken image reference and how to fix it
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetReferenceRepair {
+    pub file_path: String,
+    /// The reference as written in the file
+    pub reference: String,
+    /// The rewritten reference, when the image could be found unambiguously
+    pub replacement: Option<String>,
+    /// Project-relative paths of files with the same name
+    pub candidates: Vec<String>,
+}
+
+/// Broken image references found, and what was rewritten when applied
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetRepairReport {
+    pub repairs: Vec<AssetReferenceRepair>,
+    pub files_changed: u32,
+    pub references_changed: u32,
+}
+
+fn is_image_path(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| IMAGE_EXTENSIONS.contains(&e.to_lowercase().as_str()))
+}
+
+/// A reference without its query string or fragment
+pub(crate) fn reference_path(reference: &str) -> &str {
+    reference.split(['?', '#']).next().unwrap_or_default()
+}
+
+fn collect_frontmatter_strings(value: &Value, strings: &mut Vec<String>) {
+    match value {
+        Value::String(s) => strings.push(s.trim().to_string()),
+        Value::Array(items) => items
+            .iter()
+            .for_each(|item| collect_frontmatter_strings(item, strings)),
+        Value::Object(map) => map
+            .values()
+            .for_each(|item| collect_frontmatter_strings(item, strings)),
+        _ => {}
+    }
+}
+
+/// Local image references in a file
+fn image_references(content: &str) -> Vec<String> {
+    local_references(content)
+        .into_iter()
+        .filter(|reference| is_image_path(reference_path(reference)))
+        .collect()
+}
+
+/// References to local files in a file: frontmatter values, Markdown links and
+/// images, `src` attributes, then MDX imports
+pub(crate) fn local_references(content: &str) -> Vec<String> {
+    let src_re = Regex::new(r#"\bsrc\s*=\s*\{?\s*["']([^"']+)["']"#).unwrap();
+    let import_re =
+        Regex::new(r#"(?m)^\s*import\s+[\w{}\s,*]+\s+from\s+["']([^"']+)["']"#).unwrap();
+
+    let mut found = Vec::new();
+    match parse_frontmatter_internal(content) {
+        Ok(parsed) => {
+            for value in parsed.frontmatter.values() {
+                collect_frontmatter_strings(value, &mut found);
+            }
+            found.extend(link_targets(&parsed.content));
+        }
+        Err(_) => found.extend(link_targets(content)),
+    }
+    found.extend(src_re.captures_iter(content).map(|c| c[1].to_string()));
+    found.extend(import_re.captures_iter(content).map(|c| c[1].to_string()));
+
+    let mut references: Vec<String> = Vec::new();
+    for reference in found {
+        let path = reference_path(&reference);
+        if path.is_empty() || reference.contains(':') {
+            // External URLs and data: URIs aren't files in the project
+            continue;
+        }
+        if !references.contains(&reference) {
+            references.push(reference);
+        }
+    }
+    references
+}
+
+/// Where a reference could point: site-absolute paths may be in the project root or
+/// `public`, bare paths relative to the file or the project root
+pub(crate) fn possible_locations(
+    reference: &str,
+    file_path: &Path,
+    project_root: &Path,
+    aliases: &[PathAlias],
+) -> Vec<PathBuf> {
+    let path = reference_path(reference).replace("%20", " ");
+    let base = file_path.parent().unwrap_or(project_root);
+
+    if let Some(rest) = path.strip_prefix('/') {
+        vec![
+            project_root.join(rest),
+            project_root.join("public").join(rest),
+        ]
+    } else if path.starts_with("./") || path.starts_with("../") {
+        vec![base.join(&path)]
+    } else if let Some(resolved) = resolve_aliased_path(&path, aliases) {
+        vec![resolved]
+    } else {
+        vec![base.join(&path), project_root.join(&path)]
+    }
+}
+
+/// Image files under the given directories, keyed by lowercased file name
+fn index_images(directories: &[PathBuf]) -> HashMap<String, Vec<PathBuf>> {
+    let mut index: HashMap<String, Vec<PathBuf>> = HashMap::new();
+    for directory in directories {
+        let mut files: Vec<PathBuf> = WalkDir::new(directory)
+            .follow_links(false)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                entry.file_type().is_file() && is_image_path(&entry.path().to_string_lossy())
+            })
+            .map(|entry| entry.into_path())
+            .collect();
+        files.sort();
+        for file in files {
+            let name = file
+                .file_name()
+                .map(|n| n.to_string_lossy().to_lowercase())
+                .unwrap_or_default();
+            index.entry(name).or_default().push(file);
+        }
+    }
+    index
+}
+
+/// The file a broken reference should point at: the only candidate, or the first of
+/// several with identical contents
+fn pick_candidate(candidates: &[PathBuf]) -> Option<&PathBuf> {
+    let (first, rest) = candidates.split_first()?;
+    if rest.is_empty() {
+        return Some(first);
+    }
+    let bytes = std::fs::read(first).ok()?;
+    rest.iter()
+        .all(|other| std::fs::read(other).is_ok_and(|b| b == bytes))
+        .then_some(first)
+}
+
+/// `target` referenced from `file_path` in the same style as `original`
+fn format_replacement(
+    original: &str,
+    target: &Path,
+    file_path: &Path,
+    project_root: &Path,
+    aliases: &[PathAlias],
+) -> Option<String> {
+    let suffix = &original[reference_path(original).len()..];
+    if let Ok(public_path) = target.strip_prefix(project_root.join("public")) {
+        // Files in public are only reachable by their URL
+        let url = public_path.to_string_lossy().replace('\\', "/");
+        return Some(format!("/{url}{suffix}"));
+    }
+
+    let relative = target
+        .strip_prefix(project_root)
+        .ok()?
+        .to_string_lossy()
+        .replace('\\', "/");
+    let path = if original.starts_with('/') {
+        format!("/{relative}")
+    } else {
+        let aliased = (!original.starts_with('.'))
+            .then(|| to_aliased_path(project_root, &relative, aliases))
+            .flatten();
+        match aliased {
+            Some(aliased) => aliased,
+            None => calculate_relative_path(
+                &file_path.to_string_lossy(),
+                &project_root.to_string_lossy(),
+                &relative,
+            )
+            .ok()?,
+        }
+    };
+    Some(format!("{path}{suffix}"))
+}
+
+/// Replaces whole occurrences of `from` with `to`, skipping matches that are only part
+/// of a longer path. Returns the new content and the number of replacements.
+fn replace_reference(content: &str, from: &str, to: &str) -> (String, u32) {
+    let is_path_char = |c: char| c.is_alphanumeric() || "/._-@~%".contains(c);
+    let mut result = String::with_capacity(content.len());
+    let mut last = 0;
+    let mut count = 0;
+
+    for (start, _) in content.match_indices(from) {
+        let end = start + from.len();
+        let before = content[..start].chars().next_back();
+        let after = content[end..].chars().next();
+        if before.is_some_and(is_path_char) || after.is_some_and(is_path_char) {
+            continue;
+        }
+        result.push_str(&content[last..start]);
+        result.push_str(to);
+        last = end;
+        count += 1;
+    }
+    result.push_str(&content[last..]);
+    (result, count)
+}
+
+/// Broken image references in `files`, with a fix for each where one can be found
+fn find_repairs(
+    files: &[PathBuf],
+    project_root: &Path,
+    search_directories: &[PathBuf],
+) -> Vec<AssetReferenceRepair> {
+    let aliases = load_path_aliases(project_root);
+    let index = index_images(search_directories);
+    let mut repairs = Vec::new();
+
+    for file in files {
+        let Ok(content) = std::fs::read_to_string(file) else {
+            continue;
+        };
+        for reference in image_references(&content) {
+            if possible_locations(&reference, file, project_root, &aliases)
+                .iter()
+                .any(|location| location.is_file())
+            {
+                continue;
+            }
+
+            let name = Path::new(reference_path(&reference))
+                .file_name()
+                .map(|n| n.to_string_lossy().replace("%20", " ").to_lowercase())
+                .unwrap_or_default();
+            let candidates = index.get(&name).cloned().unwrap_or_default();
+            let replacement = pick_candidate(&candidates).and_then(|target| {
+                format_replacement(&reference, target, file, project_root, &aliases)
+            });
+
+            repairs.push(AssetReferenceRepair {
+                file_path: file.to_string_lossy().to_string(),
+                reference,
+                replacement,
+                candidates: candidates
+                    .iter()
+                    .map(|c| {
+                        c.strip_prefix(project_root)
+                            .unwrap_or(c)
+                            .to_string_lossy()
+                            .replace('\\', "/")
+                    })
+                    .collect(),
+            });
+        }
+    }
+    repairs
+}
+
+/// Finds images referenced in content that are missing on disk and, when `apply` is
+/// set, rewrites the references to files with the same name elsewhere in the project
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+/// * `assets_directory` - Optional assets directory override; `public` is always searched
+/// * `apply` - Whether to rewrite files; otherwise only reports the proposed repairs
+#[tauri::command]
+#[specta::specta]
+pub async fn repair_asset_references(
+    project_path: String,
+    content_directory: Option<String>,
+    assets_directory: Option<String>,
+    apply: bool,
+) -> Result<AssetRepairReport, String> {
+    let project_root = PathBuf::from(&project_path);
+    let content_root = project_root.join(content_directory.as_deref().unwrap_or("src/content"));
+    if !content_root.is_dir() {
+        return Err(format!(
+            "Content directory not found: {}",
+            content_root.display()
+        ));
+    }
+    let search_directories = vec![
+        project_root.join(assets_directory.as_deref().unwrap_or("src/assets")),
+        project_root.join("public"),
+    ];
+
+    let files: Vec<PathBuf> = WalkDir::new(&content_root)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry.file_type().is_file()
+                && matches!(
+                    entry.path().extension().and_then(|e| e.to_str()),
+                    Some("md") | Some("mdx")
+                )
+        })
+        .map(|entry| entry.into_path())
+        .collect();
+
+    let repairs = tokio::task::spawn_blocking(move || {
+        find_repairs(&files, &project_root, &search_directories)
+    })
+    .await
+    .map_err(|e| format!("Failed to check asset references: {e}"))?;
+
+    let mut report = AssetRepairReport {
+        repairs,
+        files_changed: 0,
+        references_changed: 0,
+    };
+    if !apply {
+        return Ok(report);
+    }
+
+    let mut by_file: Vec<(&str, Vec<(&str, &str)>)> = Vec::new();
+    for repair in &report.repairs {
+        let Some(replacement) = &repair.replacement else {
+            continue;
+        };
+        let rewrite = (repair.reference.as_str(), replacement.as_str());
+        match by_file
+            .iter_mut()
+            .find(|(file, _)| *file == repair.file_path)
+        {
+            Some((_, rewrites)) => rewrites.push(rewrite),
+            None => by_file.push((&repair.file_path, vec![rewrite])),
+        }
+    }
+
+    let mut bundle = UndoBundle::new("asset-repair");
+    let mut files_changed = 0;
+    let mut references_changed = 0;
+    let outcome = (|| {
+        for (file, rewrites) in &by_file {
+            let path = Path::new(file);
+            let original =
+                std::fs::read_to_string(path).map_err(|e| format!("Failed to read {file}: {e}"))?;
+            let mut updated = original.clone();
+            for (from, to) in rewrites {
+                let (rewritten, count) = replace_reference(&updated, from, to);
+                updated = rewritten;
+                references_changed += count;
+            }
+            if updated == original {
+                continue;
+            }
+
+            bundle.record(path, &original, &updated);
+            dry_run::write(path, &updated).map_err(|e| format!("Failed to write {file}: {e}"))?;
+            audit_log::record(AuditAction::Write, path, None, "asset-repair");
+            files_changed += 1;
+        }
+        Ok::<(), String>(())
+    })();
+    // Saved even on failure so files already rewritten can be restored
+    bundle.save();
+    outcome?;
+
+    log::info!(
+        "Astro Editor [ASSET_REPAIR] Rewrote {references_changed} image references in {files_changed} files"
+    );
+    report.files_changed = files_changed;
+    report.references_changed = references_changed;
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_image_references() {
+        let content = "---\ntitle: Post\ncover: ./cover.jpg\ngallery:\n  - src: ../img/a.png\n---\nimport hero from '../../assets/hero.png'\n\n![One](./one.png \"title\") ![Web](https://example.com/x.png)\n<img src=\"/images/two.webp\" /> [doc](./doc.pdf)\n\n```md\n![Code](./code.png)\n```\n";
+        assert_eq!(
+            image_references(content),
+            vec![
+                "./cover.jpg",
+                "../img/a.png",
+                "./one.png",
+                "/images/two.webp",
+                "../../assets/hero.png",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_replace_reference_matches_whole_paths() {
+        let (updated, count) = replace_reference(
+            "![a](./a.png) ![b](./a.png) ![c](../x/./a.png) cover: \"./a.png\"",
+            "./a.png",
+            "../assets/a.png",
+        );
+        assert_eq!(count, 3);
+        assert_eq!(
+            updated,
+            "![a](../assets/a.png) ![b](../assets/a.png) ![c](../x/./a.png) cover: \"../assets/a.png\""
+        );
+    }
+
+    #[tokio::test]
+    async fn test_repair_asset_references() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        let blog = root.join("src/content/blog");
+        fs::create_dir_all(&blog).unwrap();
+        fs::create_dir_all(root.join("src/assets/2026/photos")).unwrap();
+        fs::create_dir_all(root.join("src/assets/old")).unwrap();
+        fs::create_dir_all(root.join("public/images/moved")).unwrap();
+        fs::write(root.join("src/assets/2026/photos/cat.png"), b"cat").unwrap();
+        fs::write(root.join("src/assets/old/dup.png"), b"dup").unwrap();
+        fs::write(root.join("src/assets/2026/dup.png"), b"dup").unwrap();
+        fs::write(root.join("src/assets/old/clash.png"), b"one").unwrap();
+        fs::write(root.join("src/assets/2026/clash.png"), b"two").unwrap();
+        fs::write(root.join("public/images/moved/logo.svg"), b"<svg/>").unwrap();
+        fs::write(root.join("src/assets/here.png"), b"here").unwrap();
+
+        let post = blog.join("post.md");
+        let content = "---\ncover: ../../assets/cat.png\n---\n\n![Dup](../../assets/dup.png)\n![Clash](../../assets/clash.png)\n<img src=\"/images/logo.svg\" />\n![Gone](./gone.png)\n![Here](../../assets/here.png)\n";
+        fs::write(&post, content).unwrap();
+
+        let report = repair_asset_references(root.to_string_lossy().to_string(), None, None, false)
+            .await
+            .unwrap();
+        let summary: Vec<(&str, Option<&str>, usize)> = report
+            .repairs
+            .iter()
+            .map(|r| {
+                (
+                    r.reference.as_str(),
+                    r.replacement.as_deref(),
+                    r.candidates.len(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "../../assets/cat.png",
+                    Some("../../assets/2026/photos/cat.png"),
+                    1
+                ),
+                ("../../assets/dup.png", Some("../../assets/2026/dup.png"), 2),
+                ("../../assets/clash.png", None, 2),
+                ("./gone.png", None, 0),
+                ("/images/logo.svg", Some("/images/moved/logo.svg"), 1),
+            ]
+        );
+        assert_eq!(fs::read_to_string(&post).unwrap(), content);
+
+        let report = repair_asset_references(root.to_string_lossy().to_string(), None, None, true)
+            .await
+            .unwrap();
+        assert_eq!(report.files_changed, 1);
+        assert_eq!(report.references_changed, 3);
+        let updated = fs::read_to_string(&post).unwrap();
+        assert!(updated.contains("cover: ../../assets/2026/photos/cat.png"));
+        assert!(updated.contains("<img src=\"/images/moved/logo.svg\" />"));
+        assert!(updated.contains("![Clash](../../assets/clash.png)"));
+    }
+}
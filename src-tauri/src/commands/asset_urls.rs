@@ -0,0 +1,336 @@
+//! Deployed URLs for local assets in exported HTML
+//!
+//! Drafts shared as HTML reference images by the paths used in the Markdown
+//! (`./hero.jpg`, `/src/assets/hero.jpg`, `@assets/hero.jpg`), which recipients can't
+//! load. The project's `assetUrlMappings` setting maps project directories to the base
+//! URLs they're deployed at (e.g. `public` → `https://example.com`,
+//! `src/assets` → `https://cdn.example.com/assets`); `rewrite_asset_urls` points
+//! `src`, `href`, `poster` and `srcset` references at those URLs.
+
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::Path;
+
+use crate::utils::tsconfig::{load_path_aliases, resolve_aliased_path, PathAlias};
+
+/// A project directory and the URL its files are deployed at
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetUrlMapping {
+    /// Directory relative to the project root, e.g. `src/assets`
+    pub local_path: String,
+    /// URL the directory's files are served from, e.g. `https://cdn.example.com/assets`
+    pub base_url: String,
+}
+
+/// HTML with local asset references replaced by deployed URLs
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetUrlRewrite {
+    pub html: String,
+    /// References rewritten to a mapped URL
+    pub rewritten: u32,
+    /// Local references no mapping covers, as written in the HTML
+    pub unmapped: Vec<String>,
+}
+
+/// True for references that already work outside the project
+fn is_external(reference: &str) -> bool {
+    let lower = reference.to_ascii_lowercase();
+    reference.is_empty()
+        || reference.starts_with('#')
+        || reference.starts_with("//")
+        || ["http:", "https:", "data:", "mailto:", "tel:", "blob:"]
+            .iter()
+            .any(|scheme| lower.starts_with(scheme))
+}
+
+/// True for links to pages (`../other-post/`, `./notes.md`) rather than files
+fn is_page_link(reference: &str) -> bool {
+    let path = reference.split(['?', '#']).next().unwrap_or_default();
+    let name = path.rsplit('/').next().unwrap_or_default();
+    match name.rsplit_once('.') {
+        Some((_, extension)) => matches!(
+            extension.to_ascii_lowercase().as_str(),
+            "md" | "mdx" | "html" | "htm"
+        ),
+        None => true,
+    }
+}
+
+/// Joins `/`-separated segments, resolving `.` and `..`; `None` if it leaves the root
+fn normalize_segments<'a>(segments: impl Iterator<Item = &'a str>) -> Option<String> {
+    let mut resolved: Vec<&str> = Vec::new();
+    for segment in segments {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                resolved.pop()?;
+            }
+            other => resolved.push(other),
+        }
+    }
+    Some(resolved.join("/"))
+}
+
+/// Project-relative paths a local reference could point to
+///
+/// Site-absolute paths could be files in `public/` (how Astro serves them) or
+/// project-root paths (how the editor writes absolute image paths).
+fn candidate_paths(
+    reference: &str,
+    project_root: &Path,
+    file_dir: Option<&str>,
+    aliases: &[PathAlias],
+) -> Vec<String> {
+    if let Some(site_path) = reference.strip_prefix('/') {
+        return [format!("public/{site_path}"), site_path.to_string()]
+            .into_iter()
+            .filter_map(|path| normalize_segments(path.split('/')))
+            .collect();
+    }
+
+    if let Some(aliased) = resolve_aliased_path(reference, aliases) {
+        return aliased
+            .strip_prefix(project_root)
+            .ok()
+            .and_then(|relative| {
+                let relative = relative.to_string_lossy().replace('\\', "/");
+                normalize_segments(relative.split('/'))
+            })
+            .into_iter()
+            .collect();
+    }
+
+    // Relative to the document; bare names without one resolve from the project root
+    let base = file_dir.unwrap_or("");
+    normalize_segments(base.split('/').chain(reference.split('/')))
+        .into_iter()
+        .collect()
+}
+
+/// The deployed URL for a project-relative path under the longest matching mapping,
+/// with the length of the mapping's directory
+fn mapped_url(path: &str, mappings: &[AssetUrlMapping]) -> Option<(usize, String)> {
+    mappings
+        .iter()
+        .filter_map(|mapping| {
+            let local = mapping.local_path.trim_matches('/');
+            let rest = if local.is_empty() {
+                Some(path)
+            } else {
+                path.strip_prefix(local)
+                    .and_then(|rest| rest.strip_prefix('/').or(rest.is_empty().then_some("")))
+            }?;
+            Some((local.len(), mapping, rest))
+        })
+        .max_by_key(|(length, _, _)| *length)
+        .map(|(length, mapping, rest)| {
+            let base = mapping.base_url.trim_end_matches('/');
+            let url = if rest.is_empty() {
+                base.to_string()
+            } else {
+                format!("{base}/{rest}")
+            };
+            (length, url)
+        })
+}
+
+/// Rewrites every local reference in `html` that a mapping covers
+fn rewrite_html(
+    html: &str,
+    project_root: &Path,
+    file_dir: Option<&str>,
+    mappings: &[AssetUrlMapping],
+) -> AssetUrlRewrite {
+    let attribute_re =
+        Regex::new(r#"(?i)\b(src|href|poster|srcset)(\s*=\s*)(?:"([^"]*)"|'([^']*)')"#).unwrap();
+    let aliases = load_path_aliases(project_root);
+    let mut rewritten = 0u32;
+    let mut unmapped: Vec<String> = Vec::new();
+
+    let mut rewrite_reference = |reference: &str| -> String {
+        if is_external(reference) {
+            return reference.to_string();
+        }
+        // Keep query strings and fragments on the rewritten URL
+        let split = reference.find(['?', '#']).unwrap_or(reference.len());
+        let (path, suffix) = reference.split_at(split);
+
+        // The most specific mapping wins, so `/src/assets/a.png` isn't taken for a
+        // file in `public/src/assets`
+        let url = candidate_paths(path, project_root, file_dir, &aliases)
+            .iter()
+            .filter_map(|candidate| mapped_url(candidate, mappings))
+            .rev()
+            .max_by_key(|(length, _)| *length)
+            .map(|(_, url)| url);
+        match url {
+            Some(url) => {
+                rewritten += 1;
+                format!("{url}{suffix}")
+            }
+            None => {
+                if !unmapped.iter().any(|seen| seen == reference) {
+                    unmapped.push(reference.to_string());
+                }
+                reference.to_string()
+            }
+        }
+    };
+
+    let html = attribute_re
+        .replace_all(html, |caps: &Captures| {
+            let (quote, value) = match (caps.get(3), caps.get(4)) {
+                (Some(value), _) => ('"', value.as_str()),
+                (None, Some(value)) => ('\'', value.as_str()),
+                (None, None) => return caps[0].to_string(),
+            };
+            let new_value = if caps[1].eq_ignore_ascii_case("srcset") {
+                // `url 2x, url 640w`: rewrite the URL of each candidate
+                value
+                    .split(',')
+                    .map(|candidate| {
+                        let candidate = candidate.trim();
+                        match candidate.split_once(char::is_whitespace) {
+                            Some((url, descriptor)) => {
+                                format!("{} {}", rewrite_reference(url), descriptor.trim())
+                            }
+                            None => rewrite_reference(candidate),
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            } else if caps[1].eq_ignore_ascii_case("href") && is_page_link(value) {
+                value.to_string()
+            } else {
+                rewrite_reference(value)
+            };
+            format!("{}{}{quote}{new_value}{quote}", &caps[1], &caps[2])
+        })
+        .into_owned();
+
+    AssetUrlRewrite {
+        html,
+        rewritten,
+        unmapped,
+    }
+}
+
+/// Points local asset references in rendered HTML at their deployed URLs
+///
+/// # Arguments
+/// * `html` - The rendered document
+/// * `project_path` - The project root
+/// * `file_path` - The document's path, for resolving relative references
+/// * `mappings` - The project's `assetUrlMappings` setting
+#[tauri::command]
+#[specta::specta]
+pub async fn rewrite_asset_urls(
+    html: String,
+    project_path: String,
+    file_path: Option<String>,
+    mappings: Vec<AssetUrlMapping>,
+) -> Result<AssetUrlRewrite, String> {
+    let project_root = Path::new(&project_path);
+    let file_dir = file_path
+        .as_deref()
+        .and_then(|path| Path::new(path).parent()?.strip_prefix(project_root).ok())
+        .map(|dir| dir.to_string_lossy().replace('\\', "/"));
+
+    Ok(rewrite_html(
+        &html,
+        project_root,
+        file_dir.as_deref(),
+        &mappings,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn mappings() -> Vec<AssetUrlMapping> {
+        vec![
+            AssetUrlMapping {
+                local_path: "public".to_string(),
+                base_url: "https://example.com/".to_string(),
+            },
+            AssetUrlMapping {
+                local_path: "src/assets".to_string(),
+                base_url: "https://cdn.example.com/assets".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_mapped_url_prefers_longest_prefix() {
+        let mut mappings = mappings();
+        mappings.push(AssetUrlMapping {
+            local_path: "src/assets/blog".to_string(),
+            base_url: "https://img.example.com".to_string(),
+        });
+
+        assert_eq!(
+            mapped_url("src/assets/blog/a.png", &mappings),
+            Some((15, "https://img.example.com/a.png".to_string()))
+        );
+        assert_eq!(
+            mapped_url("src/assets/b.png", &mappings),
+            Some((10, "https://cdn.example.com/assets/b.png".to_string()))
+        );
+        assert_eq!(mapped_url("src/assetsx/b.png", &mappings), None);
+    }
+
+    #[test]
+    fn test_rewrite_html_resolves_relative_absolute_and_srcset() {
+        let temp = TempDir::new().unwrap();
+        let html = concat!(
+            r#"<p><img src="../../assets/hero.jpg?v=2" alt="Hero"></p>"#,
+            r#"<img src='/images/logo.svg'>"#,
+            r#"<img srcset="/src/assets/a.png 1x, /src/assets/a@2x.png 2x">"#,
+            r#"<a href="https://elsewhere.com/x.png">link</a>"#,
+            r##"<a href="#notes">notes</a>"##,
+            r#"<a href="../other-post/">other</a><a href="/src/assets/guide.pdf">PDF</a>"#,
+            r#"<img src="./local-only.png">"#,
+        );
+
+        let result = rewrite_html(html, temp.path(), Some("src/content/blog"), &mappings());
+
+        assert!(result
+            .html
+            .contains(r#"src="https://cdn.example.com/assets/hero.jpg?v=2""#));
+        assert!(result
+            .html
+            .contains(r#"src='https://example.com/images/logo.svg'"#));
+        assert!(result.html.contains(
+            r#"srcset="https://cdn.example.com/assets/a.png 1x, https://cdn.example.com/assets/a@2x.png 2x""#
+        ));
+        assert!(result
+            .html
+            .contains(r#"href="https://elsewhere.com/x.png""#));
+        assert!(result.html.contains(r##"href="#notes""##));
+        assert!(result.html.contains(r#"href="../other-post/""#));
+        assert!(result
+            .html
+            .contains(r#"href="https://cdn.example.com/assets/guide.pdf""#));
+        assert_eq!(result.rewritten, 5);
+        assert_eq!(result.unmapped, vec!["./local-only.png".to_string()]);
+    }
+
+    #[test]
+    fn test_references_outside_project_are_unmapped() {
+        let temp = TempDir::new().unwrap();
+        let result = rewrite_html(
+            r#"<img src="../../../../outside.png">"#,
+            temp.path(),
+            Some("src/content"),
+            &mappings(),
+        );
+
+        assert_eq!(result.rewritten, 0);
+        assert_eq!(result.unmapped.len(), 1);
+    }
+}
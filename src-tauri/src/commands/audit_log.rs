@@ -0,0 +1,275 @@
+//! Audit log of file writes
+//!
+//! Write-class commands record what they did, to which path, and which feature asked
+//! for it. Entries are JSON lines in `audit/audit.log` in app data; the log rotates at
+//! `MAX_LOG_BYTES`, keeping `MAX_ROTATED_FILES` older files, so reports of unexpected
+//! file changes can be reconstructed without the log growing unbounded.
+//!
+//! Logging is best-effort: a failure to record never fails the write itself.
+
+use chrono::{SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Manager};
+
+/// Size at which the current log is rotated
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+
+/// Rotated logs kept (`audit.log.1` is the newest)
+const MAX_ROTATED_FILES: u32 = 3;
+
+/// Entries returned when the caller doesn't specify a limit
+const DEFAULT_RESULT_LIMIT: u32 = 200;
+
+static AUDIT_LOG: OnceLock<AuditLog> = OnceLock::new();
+
+/// What a write-class command did
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditAction {
+    Write,
+    Create,
+    Delete,
+    Rename,
+    Copy,
+}
+
+/// A recorded file operation
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    /// RFC 3339 UTC timestamp
+    pub timestamp: String,
+    pub action: AuditAction,
+    pub path: String,
+    /// Destination for renames and copies
+    pub new_path: Option<String>,
+    /// The feature that asked for the write, e.g. "autosave" or "daily-notes"
+    pub feature: String,
+}
+
+struct AuditLog {
+    path: PathBuf,
+    max_bytes: u64,
+    lock: Mutex<()>,
+}
+
+impl AuditLog {
+    fn new(path: PathBuf, max_bytes: u64) -> Self {
+        Self {
+            path,
+            max_bytes,
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn rotated_path(&self, index: u32) -> PathBuf {
+        let mut name = self.path.as_os_str().to_owned();
+        name.push(format!(".{index}"));
+        PathBuf::from(name)
+    }
+
+    /// Shifts `audit.log` to `audit.log.1`, `.1` to `.2` and so on, dropping the oldest
+    fn rotate(&self) -> Result<(), String> {
+        let oldest = self.rotated_path(MAX_ROTATED_FILES);
+        if oldest.exists() {
+            std::fs::remove_file(&oldest)
+                .map_err(|e| format!("Failed to remove old audit log: {e}"))?;
+        }
+        for index in (1..MAX_ROTATED_FILES).rev() {
+            let from = self.rotated_path(index);
+            if from.exists() {
+                std::fs::rename(&from, self.rotated_path(index + 1))
+                    .map_err(|e| format!("Failed to rotate audit log: {e}"))?;
+            }
+        }
+        std::fs::rename(&self.path, self.rotated_path(1))
+            .map_err(|e| format!("Failed to rotate audit log: {e}"))
+    }
+
+    fn append(&self, entry: &AuditEntry) -> Result<(), String> {
+        let mut line = serde_json::to_string(entry)
+            .map_err(|e| format!("Failed to serialize audit entry: {e}"))?;
+        line.push('\n');
+
+        let _guard = self.lock.lock().unwrap();
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create audit log directory: {e}"))?;
+        }
+
+        let size = std::fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
+        if size > 0 && size + line.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|e| format!("Failed to write audit log: {e}"))
+    }
+
+    /// Entries newest first, optionally only those touching `path_filter`
+    fn read(&self, path_filter: Option<&str>, limit: usize) -> Vec<AuditEntry> {
+        let _guard = self.lock.lock().unwrap();
+        let files = std::iter::once(self.path.clone())
+            .chain((1..=MAX_ROTATED_FILES).map(|i| self.rotated_path(i)));
+
+        let mut entries = Vec::new();
+        for file in files {
+            let Ok(content) = std::fs::read_to_string(&file) else {
+                continue;
+            };
+            let matching = content
+                .lines()
+                .rev()
+                .filter_map(|line| serde_json::from_str::<AuditEntry>(line).ok())
+                .filter(|entry| {
+                    path_filter.map_or(true, |filter| {
+                        entry.path == filter || entry.new_path.as_deref() == Some(filter)
+                    })
+                });
+
+            for entry in matching {
+                entries.push(entry);
+                if entries.len() >= limit {
+                    return entries;
+                }
+            }
+        }
+        entries
+    }
+}
+
+/// Records a file operation, if the audit log has been initialised
+///
+/// # Arguments
+/// * `action` - What was done
+/// * `path` - The file written, created, deleted, or the source of a rename/copy
+/// * `new_path` - Destination of a rename or copy
+/// * `feature` - The feature that asked for the write
+pub(crate) fn record(action: AuditAction, path: &Path, new_path: Option<&Path>, feature: &str) {
+    // Nothing is written during a preview
+    if crate::commands::dry_run::is_active() {
+        return;
+    }
+    let Some(log) = AUDIT_LOG.get() else {
+        return;
+    };
+
+    let entry = AuditEntry {
+        timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+        action,
+        path: path.to_string_lossy().to_string(),
+        new_path: new_path.map(|p| p.to_string_lossy().to_string()),
+        feature: feature.to_string(),
+    };
+
+    if let Err(e) = log.append(&entry) {
+        log::warn!("Astro Editor [AUDIT] {e}");
+    }
+}
+
+/// Returns recorded file operations, newest first
+///
+/// # Arguments
+/// * `path` - Optional absolute path; only entries touching this file are returned
+/// * `limit` - Maximum number of entries (defaults to 200)
+#[tauri::command]
+#[specta::specta]
+pub async fn read_audit_log(
+    path: Option<String>,
+    limit: Option<u32>,
+) -> Result<Vec<AuditEntry>, String> {
+    let log = AUDIT_LOG.get().ok_or("Audit log is not available")?;
+    let limit = limit.unwrap_or(DEFAULT_RESULT_LIMIT) as usize;
+    Ok(log.read(path.as_deref(), limit))
+}
+
+// Initialize the audit log when the app starts
+pub fn init_audit_log(app: &AppHandle) {
+    match app
+        .path()
+        .resolve("audit/audit.log", BaseDirectory::AppLocalData)
+    {
+        Ok(path) => {
+            let _ = AUDIT_LOG.set(AuditLog::new(path, MAX_LOG_BYTES));
+        }
+        Err(e) => log::warn!("Astro Editor [AUDIT] Failed to resolve audit log path: {e}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn entry(path: &str, feature: &str) -> AuditEntry {
+        AuditEntry {
+            timestamp: "2026-10-16T09:00:00.000Z".to_string(),
+            action: AuditAction::Write,
+            path: path.to_string(),
+            new_path: None,
+            feature: feature.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_read_returns_newest_first_with_filter() {
+        let temp = TempDir::new().unwrap();
+        let log = AuditLog::new(temp.path().join("audit/audit.log"), MAX_LOG_BYTES);
+
+        log.append(&entry("/p/a.md", "autosave")).unwrap();
+        log.append(&entry("/p/b.md", "autosave")).unwrap();
+        log.append(&AuditEntry {
+            action: AuditAction::Rename,
+            new_path: Some("/p/c.md".to_string()),
+            ..entry("/p/a.md", "rename")
+        })
+        .unwrap();
+
+        let features: Vec<String> = log
+            .read(None, 10)
+            .into_iter()
+            .map(|e| format!("{}:{}", e.path, e.feature))
+            .collect();
+        assert_eq!(
+            features,
+            vec!["/p/a.md:rename", "/p/b.md:autosave", "/p/a.md:autosave"]
+        );
+
+        assert_eq!(log.read(Some("/p/c.md"), 10).len(), 1);
+        assert_eq!(log.read(Some("/p/a.md"), 1)[0].feature, "rename");
+    }
+
+    #[test]
+    fn test_append_rotates_and_caps_files() {
+        let temp = TempDir::new().unwrap();
+        let line_len = serde_json::to_string(&entry("/p/0.md", "x")).unwrap().len() as u64 + 1;
+        // Two entries per file
+        let log = AuditLog::new(temp.path().join("audit.log"), line_len * 2);
+
+        for i in 0..10 {
+            log.append(&entry(&format!("/p/{i}.md"), "x")).unwrap();
+        }
+
+        assert!(log.rotated_path(MAX_ROTATED_FILES).exists());
+        assert!(!log.rotated_path(MAX_ROTATED_FILES + 1).exists());
+
+        let paths: Vec<String> = log.read(None, 100).into_iter().map(|e| e.path).collect();
+        // Current file plus three rotated files of two entries each
+        assert_eq!(
+            paths,
+            vec![
+                "/p/9.md", "/p/8.md", "/p/7.md", "/p/6.md", "/p/5.md", "/p/4.md", "/p/3.md",
+                "/p/2.md"
+            ]
+        );
+    }
+}
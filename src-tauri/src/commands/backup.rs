@@ -0,0 +1,497 @@
+//! Local backups of a project's content directory
+//!
+//! Each backup is a zip of the content directory in `backups/` in app data, saved as
+//! `{id}.zip` with its details in `{id}.json`. Once the frontend configures backups for
+//! the open project, a background task takes one every `interval_minutes` (skipped when
+//! nothing has changed), and bulk operations take one before their first write (see
+//! `UndoBundle::record`). Only the newest `MAX_BACKUPS` per project are kept.
+//!
+//! Restoring writes every file in the backup back into the content directory, after
+//! first backing up the current content. Files created since the backup are left alone.
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::dry_run;
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::SystemTime;
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Manager};
+use walkdir::WalkDir;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+/// Backups kept per project; older ones are deleted when a new one is taken
+const MAX_BACKUPS: usize = 20;
+
+/// How often the background task checks whether a scheduled backup is due
+const CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Bulk operations this soon after the last backup don't take another
+const BULK_BACKUP_GAP_MINUTES: i64 = 5;
+
+static BACKUP_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Backup settings for the open project, set by the frontend
+static SETTINGS: Mutex<Option<BackupSettings>> = Mutex::new(None);
+
+/// Backup settings for a project
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupSettings {
+    pub project_path: String,
+    pub content_directory: Option<String>,
+    /// Minutes between scheduled backups; `None` only backs up before bulk operations
+    pub interval_minutes: Option<u32>,
+}
+
+/// A saved backup of a project's content directory
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupInfo {
+    pub id: String,
+    pub project_root: String,
+    /// The directory backed up, relative to the project root
+    pub content_directory: String,
+    /// What took the backup: "scheduled", "manual", "before-restore" or the bulk
+    /// operation, e.g. "asset-repair"
+    pub reason: String,
+    /// RFC 3339 UTC timestamp
+    pub created_at: String,
+    pub file_count: u32,
+    /// Size of the archive
+    #[specta(type = f64)]
+    pub size_bytes: u64,
+}
+
+/// Outcome of restoring a backup
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupRestoreResult {
+    /// Files written from the backup
+    pub restored: Vec<String>,
+    /// Backup of the content as it was just before restoring
+    pub safety_backup: BackupInfo,
+}
+
+fn canonical_root(project_root: &str) -> Result<String, String> {
+    Path::new(project_root)
+        .canonicalize()
+        .map(|root| root.to_string_lossy().to_string())
+        .map_err(|e| format!("Invalid project root: {e}"))
+}
+
+fn metadata_paths(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|e| e == "json"))
+        .collect()
+}
+
+fn read_info(path: &Path) -> Option<BackupInfo> {
+    let json = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+/// Files in the content directory, with their archive names
+fn content_files(content: &Path) -> Vec<(PathBuf, String)> {
+    WalkDir::new(content)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| {
+            let relative = entry.path().strip_prefix(content).ok()?;
+            let name = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            Some((entry.path().to_path_buf(), name))
+        })
+        .collect()
+}
+
+/// Zips the content directory into `dir`
+fn backup_in(
+    dir: &Path,
+    project_root: &str,
+    content_directory: Option<&str>,
+    reason: &str,
+) -> Result<BackupInfo, String> {
+    let project_root = canonical_root(project_root)?;
+    let content_directory = content_directory.unwrap_or("src/content");
+    let content = Path::new(&project_root).join(content_directory);
+    if !content.is_dir() {
+        return Err(format!(
+            "Content directory not found: {}",
+            content.display()
+        ));
+    }
+    std::fs::create_dir_all(dir).map_err(|e| format!("Failed to create backup folder: {e}"))?;
+
+    let now = Utc::now();
+    // Zero-padded so ids sort chronologically
+    let base = format!("{:016}", now.timestamp_millis());
+    let id = (0..)
+        .map(|n| match n {
+            0 => base.clone(),
+            n => format!("{base}-{n}"),
+        })
+        .find(|id| !dir.join(format!("{id}.json")).exists())
+        .unwrap_or(base);
+
+    let archive_path = dir.join(format!("{id}.zip"));
+    let file = std::fs::File::create(&archive_path)
+        .map_err(|e| format!("Failed to create backup: {e}"))?;
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let files = content_files(&content);
+    for (path, name) in &files {
+        let bytes =
+            std::fs::read(path).map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+        zip.start_file(name.as_str(), options)
+            .map_err(|e| format!("Failed to add {name} to backup: {e}"))?;
+        zip.write_all(&bytes)
+            .map_err(|e| format!("Failed to add {name} to backup: {e}"))?;
+    }
+    zip.finish()
+        .map_err(|e| format!("Failed to write backup: {e}"))?;
+
+    let info = BackupInfo {
+        id: id.clone(),
+        project_root,
+        content_directory: content_directory.to_string(),
+        reason: reason.to_string(),
+        created_at: now.to_rfc3339_opts(SecondsFormat::Millis, true),
+        file_count: files.len() as u32,
+        size_bytes: std::fs::metadata(&archive_path)
+            .map(|m| m.len())
+            .unwrap_or(0),
+    };
+    let json =
+        serde_json::to_string(&info).map_err(|e| format!("Failed to serialize backup: {e}"))?;
+    std::fs::write(dir.join(format!("{id}.json")), json)
+        .map_err(|e| format!("Failed to write backup details: {e}"))?;
+    Ok(info)
+}
+
+/// A project's backups, newest first
+fn list_in(dir: &Path, project_root: &str) -> Result<Vec<BackupInfo>, String> {
+    let project_root = canonical_root(project_root)?;
+    let mut backups: Vec<BackupInfo> = metadata_paths(dir)
+        .iter()
+        .filter_map(|path| read_info(path))
+        .filter(|info| info.project_root == project_root)
+        .filter(|info| dir.join(format!("{}.zip", info.id)).exists())
+        .collect();
+    backups.sort_by(|a, b| b.id.cmp(&a.id));
+    Ok(backups)
+}
+
+/// Deletes all but the newest `MAX_BACKUPS` of a project's backups
+fn prune_in(dir: &Path, project_root: &str) -> Result<(), String> {
+    for old in list_in(dir, project_root)?.iter().skip(MAX_BACKUPS) {
+        let _ = std::fs::remove_file(dir.join(format!("{}.zip", old.id)));
+        let _ = std::fs::remove_file(dir.join(format!("{}.json", old.id)));
+    }
+    Ok(())
+}
+
+/// Backs up the content directory and prunes old backups
+fn take_backup(
+    dir: &Path,
+    project_root: &str,
+    content_directory: Option<&str>,
+    reason: &str,
+) -> Result<BackupInfo, String> {
+    let info = backup_in(dir, project_root, content_directory, reason)?;
+    prune_in(dir, project_root)?;
+    log::info!(
+        "Astro Editor [BACKUP] Backed up {} files ({reason})",
+        info.file_count
+    );
+    Ok(info)
+}
+
+fn created_at(info: &BackupInfo) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(&info.created_at)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// Whether any file in the content directory changed after `since`
+fn changed_since(content: &Path, since: DateTime<Utc>) -> bool {
+    let since: SystemTime = since.into();
+    WalkDir::new(content)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter_map(|entry| entry.metadata().ok()?.modified().ok())
+        .any(|modified| modified > since)
+}
+
+fn restore_in(dir: &Path, id: &str, project_root: &str) -> Result<BackupRestoreResult, String> {
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_digit() || c == '-') {
+        return Err(format!("Invalid backup id: {id}"));
+    }
+    let info = read_info(&dir.join(format!("{id}.json"))).ok_or("That backup no longer exists")?;
+    if info.project_root != canonical_root(project_root)? {
+        return Err("That backup is from a different project".to_string());
+    }
+
+    let file = std::fs::File::open(dir.join(format!("{id}.zip")))
+        .map_err(|e| format!("Failed to open backup: {e}"))?;
+    let mut archive = ZipArchive::new(file).map_err(|e| format!("Failed to read backup: {e}"))?;
+
+    // Not pruned until the restore is done, so the backup being restored survives
+    let safety_backup = backup_in(
+        dir,
+        project_root,
+        Some(&info.content_directory),
+        "before-restore",
+    )?;
+
+    let content = Path::new(&info.project_root).join(&info.content_directory);
+    let mut restored = Vec::new();
+    for i in 0..archive.len() {
+        let mut entry = archive
+            .by_index(i)
+            .map_err(|e| format!("Failed to read backup: {e}"))?;
+        if entry.is_dir() {
+            continue;
+        }
+        // `enclosed_name` rejects names that would escape the content directory
+        let Some(name) = entry.enclosed_name() else {
+            continue;
+        };
+        let mut bytes = Vec::new();
+        entry
+            .read_to_end(&mut bytes)
+            .map_err(|e| format!("Failed to read {} from backup: {e}", name.display()))?;
+        let target = content.join(name);
+        if let Some(parent) = target.parent() {
+            dry_run::create_dir_all(parent).map_err(|e| format!("Failed to create folder: {e}"))?;
+        }
+        dry_run::write(&target, &bytes)
+            .map_err(|e| format!("Failed to restore {}: {e}", target.display()))?;
+        restored.push(target.to_string_lossy().to_string());
+    }
+
+    prune_in(dir, project_root)?;
+    Ok(BackupRestoreResult {
+        restored,
+        safety_backup,
+    })
+}
+
+/// Backs up the configured project before a bulk operation rewrites its files
+///
+/// Skipped during a dry run, without configured backups, or when the last backup is
+/// recent. Failing to back up is logged, not returned.
+pub(crate) fn backup_before(operation: &str) {
+    if dry_run::is_active() {
+        return;
+    }
+    let (Some(dir), Some(settings)) = (BACKUP_DIR.get(), SETTINGS.lock().unwrap().clone()) else {
+        return;
+    };
+
+    let recent = list_in(dir, &settings.project_path)
+        .ok()
+        .and_then(|backups| backups.first().and_then(created_at))
+        .is_some_and(|last| Utc::now() - last < Duration::minutes(BULK_BACKUP_GAP_MINUTES));
+    if recent {
+        return;
+    }
+    if let Err(e) = take_backup(
+        dir,
+        &settings.project_path,
+        settings.content_directory.as_deref(),
+        operation,
+    ) {
+        log::warn!("Astro Editor [BACKUP] {e}");
+    }
+}
+
+/// Takes a scheduled backup if one is configured, due and something has changed
+fn run_scheduled_backup(dir: &Path) -> Result<(), String> {
+    let Some(settings) = SETTINGS.lock().unwrap().clone() else {
+        return Ok(());
+    };
+    let Some(interval) = settings.interval_minutes.filter(|&m| m > 0) else {
+        return Ok(());
+    };
+
+    if let Some(last) = list_in(dir, &settings.project_path)?
+        .first()
+        .and_then(created_at)
+    {
+        let content = Path::new(&settings.project_path).join(
+            settings
+                .content_directory
+                .as_deref()
+                .unwrap_or("src/content"),
+        );
+        if Utc::now() - last < Duration::minutes(interval as i64) || !changed_since(&content, last)
+        {
+            return Ok(());
+        }
+    }
+    take_backup(
+        dir,
+        &settings.project_path,
+        settings.content_directory.as_deref(),
+        "scheduled",
+    )
+    .map(|_| ())
+}
+
+/// Turns backups on for the open project, or off with `None`
+///
+/// # Arguments
+/// * `settings` - The project to back up and how often
+#[tauri::command]
+#[specta::specta]
+pub async fn set_backup_settings(settings: Option<BackupSettings>) -> Result<(), String> {
+    *SETTINGS
+        .lock()
+        .map_err(|e| format!("Failed to update backup settings: {e}"))? = settings;
+    Ok(())
+}
+
+/// Lists a project's backups, newest first
+#[tauri::command]
+#[specta::specta]
+pub async fn list_backups(project_path: String) -> Result<Vec<BackupInfo>, String> {
+    let dir = BACKUP_DIR.get().ok_or("Backups are not available")?;
+    list_in(dir, &project_path)
+}
+
+/// Backs up a project's content directory now
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+#[tauri::command]
+#[specta::specta]
+pub async fn create_backup(
+    project_path: String,
+    content_directory: Option<String>,
+) -> Result<BackupInfo, String> {
+    let dir = BACKUP_DIR.get().ok_or("Backups are not available")?;
+    tokio::task::spawn_blocking(move || {
+        take_backup(dir, &project_path, content_directory.as_deref(), "manual")
+    })
+    .await
+    .map_err(|e| format!("Failed to create backup: {e}"))?
+}
+
+/// Restores the files in a backup to the project's content directory
+///
+/// The current content is backed up first. Files created since the backup are kept.
+///
+/// # Arguments
+/// * `id` - The backup to restore
+/// * `project_path` - The absolute path to the project root the backup belongs to
+#[tauri::command]
+#[specta::specta]
+pub async fn restore_backup(
+    id: String,
+    project_path: String,
+) -> Result<BackupRestoreResult, String> {
+    let dir = BACKUP_DIR.get().ok_or("Backups are not available")?;
+    let result = tokio::task::spawn_blocking(move || restore_in(dir, &id, &project_path))
+        .await
+        .map_err(|e| format!("Failed to restore backup: {e}"))??;
+
+    for path in &result.restored {
+        audit_log::record(AuditAction::Write, Path::new(path), None, "restore_backup");
+    }
+    log::info!(
+        "Astro Editor [BACKUP] Restored {} files",
+        result.restored.len()
+    );
+    Ok(result)
+}
+
+/// Resolves the backup folder and starts the scheduled backup task
+pub fn init_backups(app: &AppHandle) {
+    let dir = match app.path().resolve("backups", BaseDirectory::AppLocalData) {
+        Ok(dir) => dir,
+        Err(e) => {
+            log::warn!("Astro Editor [BACKUP] Failed to resolve backup directory: {e}");
+            return;
+        }
+    };
+    let dir: &'static Path = BACKUP_DIR.get_or_init(|| dir).as_path();
+
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            let outcome = tokio::task::spawn_blocking(move || run_scheduled_backup(dir)).await;
+            if let Ok(Err(e)) = outcome {
+                log::warn!("Astro Editor [BACKUP] {e}");
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_backup_and_restore() {
+        let temp = TempDir::new().unwrap();
+        let backups = temp.path().join("backups");
+        let project = temp.path().join("project");
+        let post = project.join("src/content/blog/post.md");
+        fs::create_dir_all(post.parent().unwrap()).unwrap();
+        fs::write(&post, "# Post").unwrap();
+        let root = project.to_string_lossy().to_string();
+
+        let backup = backup_in(&backups, &root, None, "manual").unwrap();
+        assert_eq!(backup.file_count, 1);
+        assert_eq!(list_in(&backups, &root).unwrap(), vec![backup.clone()]);
+
+        fs::write(&post, "# Edited").unwrap();
+        let added = project.join("src/content/blog/new.md");
+        fs::write(&added, "# New").unwrap();
+
+        let result = restore_in(&backups, &backup.id, &root).unwrap();
+        assert_eq!(result.restored.len(), 1);
+        assert_eq!(result.safety_backup.reason, "before-restore");
+        assert_eq!(result.safety_backup.file_count, 2);
+        assert_eq!(fs::read_to_string(&post).unwrap(), "# Post");
+        assert_eq!(fs::read_to_string(&added).unwrap(), "# New");
+        assert!(restore_in(&backups, "../backup", &root).is_err());
+    }
+
+    #[test]
+    fn test_prune_keeps_newest_backups() {
+        let temp = TempDir::new().unwrap();
+        let backups = temp.path().join("backups");
+        fs::create_dir_all(temp.path().join("src/content")).unwrap();
+        let root = temp.path().to_string_lossy().to_string();
+
+        let first = backup_in(&backups, &root, None, "manual").unwrap();
+        for _ in 0..MAX_BACKUPS {
+            backup_in(&backups, &root, None, "manual").unwrap();
+        }
+        prune_in(&backups, &root).unwrap();
+
+        let remaining = list_in(&backups, &root).unwrap();
+        assert_eq!(remaining.len(), MAX_BACKUPS);
+        assert!(!remaining.contains(&first));
+        assert!(!backups.join(format!("{}.zip", first.id)).exists());
+    }
+}
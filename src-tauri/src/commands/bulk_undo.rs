@@ -0,0 +1,301 @@
+//! Undo bundles for bulk file operations
+//!
+//! Commands that rewrite many files at once record each file's original content in an
+//! `UndoBundle` and save it to `undo/` in app data when they finish, so a bad bulk edit
+//! can be reverted without git. Only the newest `MAX_BUNDLES` are kept.
+//!
+//! Undo never overwrites later work: files whose content changed after the bulk
+//! operation are skipped and reported. The whole content directory is also backed up
+//! before the first file is rewritten (see `backup::backup_before`).
+
+use crate::commands::audit_log::{self, AuditAction};
+use chrono::{SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Manager};
+
+/// Bundles kept on disk; older ones are deleted when a new one is saved
+const MAX_BUNDLES: usize = 10;
+
+static UNDO_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct BundledFile {
+    path: String,
+    original: String,
+    /// Hash of the content the bulk operation wrote
+    written_hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct SavedBundle {
+    operation: String,
+    created_at: String,
+    files: Vec<BundledFile>,
+}
+
+/// The most recent bulk operation that can be undone
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkOperationSummary {
+    /// The operation, e.g. "code-block-normalize"
+    pub operation: String,
+    /// RFC 3339 UTC timestamp
+    pub created_at: String,
+    pub file_count: u32,
+}
+
+/// Outcome of undoing a bulk operation
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkUndoResult {
+    pub operation: String,
+    /// Files restored to their original content
+    pub restored: Vec<String>,
+    /// Files left alone because they changed after the bulk operation
+    pub skipped: Vec<String>,
+}
+
+/// Stable FNV-1a hash of file content
+pub(crate) fn content_hash(content: &str) -> String {
+    let hash = content.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// Original contents of the files a bulk operation rewrites
+pub(crate) struct UndoBundle {
+    operation: String,
+    files: Vec<BundledFile>,
+}
+
+impl UndoBundle {
+    pub(crate) fn new(operation: &str) -> Self {
+        Self {
+            operation: operation.to_string(),
+            files: Vec::new(),
+        }
+    }
+
+    /// Records a file about to be rewritten from `original` to `updated`
+    pub(crate) fn record(&mut self, path: &Path, original: &str, updated: &str) {
+        if self.files.is_empty() {
+            crate::commands::backup::backup_before(&self.operation);
+        }
+        self.files.push(BundledFile {
+            path: path.to_string_lossy().to_string(),
+            original: original.to_string(),
+            written_hash: content_hash(updated),
+        });
+    }
+
+    /// Saves the bundle, if any files were recorded and undo is available
+    ///
+    /// Call this even when the operation fails part-way, so the files already
+    /// rewritten can be restored. Failing to save is logged, not returned.
+    pub(crate) fn save(self) {
+        // A dry run rewrote nothing, so there's nothing to undo
+        if crate::commands::dry_run::is_active() {
+            return;
+        }
+        let Some(dir) = UNDO_DIR.get() else {
+            return;
+        };
+        if let Err(e) = self.save_to(dir) {
+            log::warn!("Astro Editor [BULK_UNDO] {e}");
+        }
+    }
+
+    fn save_to(self, dir: &Path) -> Result<(), String> {
+        if self.files.is_empty() {
+            return Ok(());
+        }
+
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create undo directory: {e}"))?;
+
+        let now = Utc::now();
+        let bundle = SavedBundle {
+            operation: self.operation,
+            created_at: now.to_rfc3339_opts(SecondsFormat::Millis, true),
+            files: self.files,
+        };
+        let json = serde_json::to_string(&bundle)
+            .map_err(|e| format!("Failed to serialize undo bundle: {e}"))?;
+        // Zero-padded so file names sort chronologically
+        let path = dir.join(format!("{:016}.json", now.timestamp_millis()));
+        std::fs::write(&path, json).map_err(|e| format!("Failed to write undo bundle: {e}"))?;
+
+        let bundles = bundle_paths(dir);
+        for old in bundles
+            .iter()
+            .take(bundles.len().saturating_sub(MAX_BUNDLES))
+        {
+            let _ = std::fs::remove_file(old);
+        }
+        Ok(())
+    }
+}
+
+/// Saved bundles, oldest first
+fn bundle_paths(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|e| e == "json"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn read_bundle(path: &Path) -> Result<SavedBundle, String> {
+    let json =
+        std::fs::read_to_string(path).map_err(|e| format!("Failed to read undo bundle: {e}"))?;
+    serde_json::from_str(&json).map_err(|e| format!("Failed to parse undo bundle: {e}"))
+}
+
+fn last_operation_in(dir: &Path) -> Result<Option<BulkOperationSummary>, String> {
+    let Some(path) = bundle_paths(dir).pop() else {
+        return Ok(None);
+    };
+    let bundle = read_bundle(&path)?;
+    Ok(Some(BulkOperationSummary {
+        operation: bundle.operation,
+        created_at: bundle.created_at,
+        file_count: bundle.files.len() as u32,
+    }))
+}
+
+fn undo_last_in(dir: &Path) -> Result<BulkUndoResult, String> {
+    let path = bundle_paths(dir)
+        .pop()
+        .ok_or("There is no bulk operation to undo")?;
+    let bundle = read_bundle(&path)?;
+
+    let mut result = BulkUndoResult {
+        operation: bundle.operation,
+        restored: Vec::new(),
+        skipped: Vec::new(),
+    };
+
+    for file in bundle.files {
+        let current = std::fs::read_to_string(&file.path).ok();
+        if current.map(|c| content_hash(&c)).as_deref() != Some(file.written_hash.as_str()) {
+            result.skipped.push(file.path);
+            continue;
+        }
+
+        std::fs::write(&file.path, &file.original)
+            .map_err(|e| format!("Failed to restore {}: {e}", file.path))?;
+        audit_log::record(AuditAction::Write, Path::new(&file.path), None, "bulk-undo");
+        result.restored.push(file.path);
+    }
+
+    std::fs::remove_file(&path).map_err(|e| format!("Failed to remove undo bundle: {e}"))?;
+    Ok(result)
+}
+
+/// Returns the most recent bulk operation that can be undone, if any
+#[tauri::command]
+#[specta::specta]
+pub async fn get_last_bulk_operation() -> Result<Option<BulkOperationSummary>, String> {
+    let dir = UNDO_DIR.get().ok_or("Bulk undo is not available")?;
+    last_operation_in(dir)
+}
+
+/// Restores the files rewritten by the most recent bulk operation
+///
+/// Files edited since the operation are left alone and reported as skipped.
+#[tauri::command]
+#[specta::specta]
+pub async fn undo_last_bulk_operation() -> Result<BulkUndoResult, String> {
+    let dir = UNDO_DIR.get().ok_or("Bulk undo is not available")?;
+    let result = undo_last_in(dir)?;
+
+    log::info!(
+        "Astro Editor [BULK_UNDO] Undid {}: {} restored, {} skipped",
+        result.operation,
+        result.restored.len(),
+        result.skipped.len()
+    );
+    Ok(result)
+}
+
+// Initialize the undo bundle directory when the app starts
+pub fn init_bulk_undo(app: &AppHandle) {
+    match app.path().resolve("undo", BaseDirectory::AppLocalData) {
+        Ok(dir) => {
+            let _ = UNDO_DIR.set(dir);
+        }
+        Err(e) => log::warn!("Astro Editor [BULK_UNDO] Failed to resolve undo directory: {e}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_undo_restores_and_skips_later_edits() {
+        let temp = TempDir::new().unwrap();
+        let undo_dir = temp.path().join("undo");
+        let a = temp.path().join("a.md");
+        let b = temp.path().join("b.md");
+        fs::write(&a, "new a").unwrap();
+        fs::write(&b, "new b").unwrap();
+
+        let mut bundle = UndoBundle::new("find-replace");
+        bundle.record(&a, "old a", "new a");
+        bundle.record(&b, "old b", "new b");
+        bundle.save_to(&undo_dir).unwrap();
+
+        // b is edited again after the bulk operation
+        fs::write(&b, "newer b").unwrap();
+
+        let summary = last_operation_in(&undo_dir).unwrap().unwrap();
+        assert_eq!(summary.operation, "find-replace");
+        assert_eq!(summary.file_count, 2);
+
+        let result = undo_last_in(&undo_dir).unwrap();
+        assert_eq!(result.restored, vec![a.to_string_lossy().to_string()]);
+        assert_eq!(result.skipped, vec![b.to_string_lossy().to_string()]);
+        assert_eq!(fs::read_to_string(&a).unwrap(), "old a");
+        assert_eq!(fs::read_to_string(&b).unwrap(), "newer b");
+
+        assert_eq!(last_operation_in(&undo_dir).unwrap(), None);
+        assert!(undo_last_in(&undo_dir).is_err());
+    }
+
+    #[test]
+    fn test_save_skips_empty_bundles_and_prunes_old_ones() {
+        let temp = TempDir::new().unwrap();
+        UndoBundle::new("empty").save_to(temp.path()).unwrap();
+        assert!(bundle_paths(temp.path()).is_empty());
+
+        for i in 0..MAX_BUNDLES + 2 {
+            // Pre-seed older bundles with distinct, sortable names
+            fs::write(temp.path().join(format!("{i:016}.json")), "{}").unwrap();
+        }
+        let mut bundle = UndoBundle::new("latest");
+        bundle.record(Path::new("/p/a.md"), "a", "b");
+        bundle.save_to(temp.path()).unwrap();
+
+        let paths = bundle_paths(temp.path());
+        assert_eq!(paths.len(), MAX_BUNDLES);
+        assert_eq!(
+            read_bundle(paths.last().unwrap()).unwrap().operation,
+            "latest"
+        );
+    }
+}
@@ -0,0 +1,681 @@
+//! Fenced code block language audit and normalization
+//!
+//! Astro highlights code with Shiki, which silently falls back to plain text when a
+//! fence's language isn't recognised. The audit lists every language used in a
+//! collection and flags ones Shiki doesn't know, suggesting the closest match; the
+//! fixer rewrites languages per a project alias mapping (e.g. `js` → `javascript`).
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::bulk_undo::UndoBundle;
+use crate::commands::dry_run;
+use crate::security::validate_project_path;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Shiki's bundled languages and their aliases
+const SHIKI_LANGUAGES: &[(&str, &[&str])] = &[
+    ("abap", &[]),
+    ("actionscript-3", &[]),
+    ("ada", &[]),
+    ("angular-html", &[]),
+    ("angular-ts", &[]),
+    ("apache", &[]),
+    ("apex", &[]),
+    ("apl", &[]),
+    ("applescript", &[]),
+    ("ara", &[]),
+    ("asciidoc", &["adoc"]),
+    ("asm", &[]),
+    ("astro", &[]),
+    ("awk", &[]),
+    ("ballerina", &[]),
+    ("bat", &["batch"]),
+    ("beancount", &[]),
+    ("berry", &["be"]),
+    ("bibtex", &[]),
+    ("bicep", &[]),
+    ("blade", &[]),
+    ("bsl", &["1c"]),
+    ("c", &[]),
+    ("cadence", &["cdc"]),
+    ("cairo", &[]),
+    ("clarity", &[]),
+    ("clojure", &["clj"]),
+    ("cmake", &[]),
+    ("cobol", &[]),
+    ("codeowners", &[]),
+    ("codeql", &["ql"]),
+    ("coffee", &["coffeescript"]),
+    ("common-lisp", &["lisp"]),
+    ("coq", &[]),
+    ("cpp", &["c++"]),
+    ("crystal", &[]),
+    ("csharp", &["c#", "cs"]),
+    ("css", &[]),
+    ("csv", &[]),
+    ("cue", &[]),
+    ("cypher", &["cql"]),
+    ("d", &[]),
+    ("dart", &[]),
+    ("dax", &[]),
+    ("desktop", &[]),
+    ("diff", &[]),
+    ("docker", &["dockerfile"]),
+    ("dotenv", &[]),
+    ("dream-maker", &[]),
+    ("edge", &[]),
+    ("elixir", &[]),
+    ("elm", &[]),
+    ("emacs-lisp", &["elisp"]),
+    ("erb", &[]),
+    ("erlang", &["erl"]),
+    ("fennel", &[]),
+    ("fish", &[]),
+    ("fluent", &["ftl"]),
+    ("fortran-fixed-form", &["f", "for", "f77"]),
+    ("fortran-free-form", &["f90", "f95", "f03", "f08", "f18"]),
+    ("fsharp", &["f#", "fs"]),
+    ("gdresource", &[]),
+    ("gdscript", &[]),
+    ("gdshader", &[]),
+    ("genie", &[]),
+    ("gherkin", &[]),
+    ("git-commit", &[]),
+    ("git-rebase", &[]),
+    ("gleam", &[]),
+    ("glimmer-js", &["gjs"]),
+    ("glimmer-ts", &["gts"]),
+    ("glsl", &[]),
+    ("gnuplot", &[]),
+    ("go", &[]),
+    ("graphql", &["gql"]),
+    ("groovy", &[]),
+    ("hack", &[]),
+    ("haml", &[]),
+    ("handlebars", &["hbs"]),
+    ("haskell", &["hs"]),
+    ("haxe", &[]),
+    ("hcl", &[]),
+    ("hjson", &[]),
+    ("hlsl", &[]),
+    ("html", &[]),
+    ("html-derivative", &[]),
+    ("http", &[]),
+    ("hxml", &[]),
+    ("hy", &[]),
+    ("imba", &[]),
+    ("ini", &["properties"]),
+    ("java", &[]),
+    ("javascript", &["js"]),
+    ("jinja", &[]),
+    ("jison", &[]),
+    ("json", &[]),
+    ("json5", &[]),
+    ("jsonc", &[]),
+    ("jsonl", &[]),
+    ("jsonnet", &[]),
+    ("jssm", &["fsl"]),
+    ("jsx", &[]),
+    ("julia", &["jl"]),
+    ("kotlin", &["kt", "kts"]),
+    ("kusto", &["kql"]),
+    ("latex", &[]),
+    ("lean", &["lean4"]),
+    ("less", &[]),
+    ("liquid", &[]),
+    ("llvm", &[]),
+    ("log", &[]),
+    ("logo", &[]),
+    ("lua", &[]),
+    ("luau", &[]),
+    ("make", &["makefile"]),
+    ("markdown", &["md"]),
+    ("marko", &[]),
+    ("matlab", &[]),
+    ("mdc", &[]),
+    ("mdx", &[]),
+    ("mermaid", &["mmd"]),
+    ("mipsasm", &["mips"]),
+    ("mojo", &[]),
+    ("move", &[]),
+    ("narrat", &["nar"]),
+    ("nextflow", &["nf"]),
+    ("nginx", &[]),
+    ("nim", &[]),
+    ("nix", &[]),
+    ("nushell", &["nu"]),
+    ("objective-c", &["objc"]),
+    ("objective-cpp", &[]),
+    ("ocaml", &[]),
+    ("pascal", &[]),
+    ("perl", &[]),
+    ("php", &[]),
+    ("plsql", &[]),
+    ("po", &["pot", "potx"]),
+    ("polar", &[]),
+    ("postcss", &[]),
+    ("powerquery", &[]),
+    ("powershell", &["ps", "ps1"]),
+    ("prisma", &[]),
+    ("prolog", &[]),
+    ("proto", &["protobuf"]),
+    ("pug", &["jade"]),
+    ("puppet", &[]),
+    ("purescript", &[]),
+    ("python", &["py"]),
+    ("qml", &[]),
+    ("qmldir", &[]),
+    ("qss", &[]),
+    ("r", &[]),
+    ("racket", &[]),
+    ("raku", &["perl6"]),
+    ("razor", &[]),
+    ("reg", &[]),
+    ("regexp", &["regex"]),
+    ("rel", &[]),
+    ("riscv", &[]),
+    ("rst", &[]),
+    ("ruby", &["rb"]),
+    ("rust", &["rs"]),
+    ("sas", &[]),
+    ("sass", &[]),
+    ("scala", &[]),
+    ("scheme", &[]),
+    ("scss", &[]),
+    ("sdbl", &["1c-query"]),
+    ("shaderlab", &["shader"]),
+    ("shellscript", &["bash", "sh", "shell", "zsh"]),
+    ("shellsession", &["console"]),
+    ("smalltalk", &[]),
+    ("solidity", &[]),
+    ("soy", &["closure-templates"]),
+    ("sparql", &[]),
+    ("splunk", &["spl"]),
+    ("sql", &[]),
+    ("ssh-config", &[]),
+    ("stata", &[]),
+    ("stylus", &["styl"]),
+    ("svelte", &[]),
+    ("swift", &[]),
+    ("system-verilog", &[]),
+    ("systemd", &[]),
+    ("talonscript", &["talon"]),
+    ("tasl", &[]),
+    ("tcl", &[]),
+    ("templ", &[]),
+    ("terraform", &["tf", "tfvars"]),
+    ("tex", &[]),
+    ("toml", &[]),
+    ("ts-tags", &["lit"]),
+    ("tsv", &[]),
+    ("tsx", &[]),
+    ("turtle", &[]),
+    ("twig", &[]),
+    ("typescript", &["ts"]),
+    ("typespec", &["tsp"]),
+    ("typst", &["typ"]),
+    ("v", &[]),
+    ("vala", &[]),
+    ("vb", &["cmd"]),
+    ("verilog", &[]),
+    ("vhdl", &[]),
+    ("viml", &["vim", "vimscript"]),
+    ("vue", &[]),
+    ("vue-html", &[]),
+    ("vyper", &["vy"]),
+    ("wasm", &[]),
+    ("wenyan", &[]),
+    ("wgsl", &[]),
+    ("wikitext", &["mediawiki", "wiki"]),
+    ("wolfram", &["wl"]),
+    ("xml", &[]),
+    ("xsl", &[]),
+    ("yaml", &["yml"]),
+    ("zenscript", &[]),
+    ("zig", &[]),
+];
+
+/// Languages Shiki renders as plain text without complaint
+const PLAIN_TEXT_LANGUAGES: &[&str] = &["text", "txt", "plain", "plaintext", "ansi"];
+
+/// Largest edit distance for a "did you mean" suggestion
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// How often a language appears in a collection
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LanguageUsage {
+    pub language: String,
+    pub count: u32,
+    pub known: bool,
+    /// Shiki's id for a known language, e.g. "javascript" for "js"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canonical: Option<String>,
+    /// Closest known language, for unknown ones
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+/// A code block whose language Shiki won't recognise
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnknownCodeBlock {
+    pub file_path: String,
+    /// 1-based line of the opening fence
+    pub line: u32,
+    pub language: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeBlockAudit {
+    /// Languages used, most common first
+    pub languages: Vec<LanguageUsage>,
+    pub unknown_blocks: Vec<UnknownCodeBlock>,
+    /// Code blocks with no language at all
+    pub unlabelled_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeBlockFixResult {
+    pub files_changed: u32,
+    pub blocks_changed: u32,
+    pub changed_files: Vec<String>,
+}
+
+/// An opening code fence and where its language sits within the line
+#[derive(Debug, PartialEq)]
+struct Fence {
+    /// 0-based line index
+    line: usize,
+    /// Byte range of the language within the line (empty when unlabelled)
+    language_start: usize,
+    language_end: usize,
+}
+
+/// Returns (marker char, run length, indent) if `line` starts a code fence
+fn fence_marker(line: &str) -> Option<(char, usize, usize)> {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    if indent > 3 {
+        return None;
+    }
+    let rest = &line[indent..];
+    let marker = rest.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let run = rest.chars().take_while(|c| *c == marker).count();
+    (run >= 3).then_some((marker, run, indent))
+}
+
+/// Finds opening fences in a Markdown document, skipping frontmatter
+fn find_fences(content: &str) -> Vec<Fence> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut fences = Vec::new();
+    let mut open: Option<(char, usize)> = None;
+
+    // Skip YAML frontmatter
+    let mut start = 0;
+    if lines.first().map(|l| l.trim_end()) == Some("---") {
+        if let Some(end) = lines.iter().skip(1).position(|l| l.trim_end() == "---") {
+            start = end + 2;
+        }
+    }
+
+    for (index, line) in lines.iter().enumerate().skip(start) {
+        let Some((marker, run, indent)) = fence_marker(line) else {
+            continue;
+        };
+
+        match open {
+            Some((open_marker, open_run)) => {
+                let is_close = marker == open_marker
+                    && run >= open_run
+                    && line[indent + run..].trim().is_empty();
+                if is_close {
+                    open = None;
+                }
+            }
+            None => {
+                let info_start = indent + run;
+                let info = &line[info_start..];
+                let leading = info.len() - info.trim_start().len();
+                let language_start = info_start + leading;
+                let language_len = line[language_start..]
+                    .find(|c: char| c.is_whitespace() || c == '{')
+                    .unwrap_or(line.len() - language_start);
+
+                // Backtick fences can't have backticks in their info string
+                if marker == '`' && info.contains('`') {
+                    continue;
+                }
+
+                fences.push(Fence {
+                    line: index,
+                    language_start,
+                    language_end: language_start + language_len,
+                });
+                open = Some((marker, run));
+            }
+        }
+    }
+
+    fences
+}
+
+/// Shiki's id for a language name or alias
+fn canonical_language(language: &str) -> Option<&'static str> {
+    if let Some(plain) = PLAIN_TEXT_LANGUAGES.iter().find(|l| **l == language) {
+        return Some(plain);
+    }
+    SHIKI_LANGUAGES
+        .iter()
+        .find(|(id, aliases)| *id == language || aliases.contains(&language))
+        .map(|(id, _)| *id)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Closest known language for an unknown one
+fn suggest_language(language: &str) -> Option<String> {
+    let lower = language.to_lowercase();
+    if let Some(id) = canonical_language(&lower) {
+        return Some(id.to_string());
+    }
+    if lower.chars().count() < 3 {
+        return None;
+    }
+
+    SHIKI_LANGUAGES
+        .iter()
+        .flat_map(|(id, aliases)| {
+            std::iter::once(*id)
+                .chain(aliases.iter().copied())
+                .map(move |name| (name, *id))
+        })
+        .map(|(name, id)| (edit_distance(&lower, name), id))
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, id)| id.to_string())
+}
+
+fn markdown_files(collection_path: &Path) -> impl Iterator<Item = walkdir::DirEntry> {
+    WalkDir::new(collection_path)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry.file_type().is_file()
+                && matches!(
+                    entry.path().extension().and_then(|e| e.to_str()),
+                    Some("md") | Some("mdx")
+                )
+        })
+}
+
+fn audit_collection(collection_path: &Path) -> CodeBlockAudit {
+    let mut counts: BTreeMap<String, u32> = BTreeMap::new();
+    let mut unknown_blocks = Vec::new();
+    let mut unlabelled_count = 0;
+
+    for entry in markdown_files(collection_path) {
+        let Ok(content) = std::fs::read_to_string(entry.path()) else {
+            continue;
+        };
+        let lines: Vec<&str> = content.lines().collect();
+
+        for fence in find_fences(&content) {
+            let language = &lines[fence.line][fence.language_start..fence.language_end];
+            if language.is_empty() {
+                unlabelled_count += 1;
+                continue;
+            }
+
+            *counts.entry(language.to_string()).or_default() += 1;
+            if canonical_language(language).is_none() {
+                unknown_blocks.push(UnknownCodeBlock {
+                    file_path: entry.path().to_string_lossy().to_string(),
+                    line: fence.line as u32 + 1,
+                    language: language.to_string(),
+                    suggestion: suggest_language(language),
+                });
+            }
+        }
+    }
+
+    let mut languages: Vec<LanguageUsage> = counts
+        .into_iter()
+        .map(|(language, count)| {
+            let canonical = canonical_language(&language);
+            LanguageUsage {
+                suggestion: canonical
+                    .is_none()
+                    .then(|| suggest_language(&language))
+                    .flatten(),
+                known: canonical.is_some(),
+                canonical: canonical.map(String::from),
+                language,
+                count,
+            }
+        })
+        .collect();
+    languages.sort_by(|a, b| b.count.cmp(&a.count).then(a.language.cmp(&b.language)));
+    unknown_blocks.sort_by(|a, b| a.file_path.cmp(&b.file_path).then(a.line.cmp(&b.line)));
+
+    CodeBlockAudit {
+        languages,
+        unknown_blocks,
+        unlabelled_count,
+    }
+}
+
+/// Rewrites fence languages found in `mapping`, returning the new content and count
+fn rewrite_languages(content: &str, mapping: &HashMap<String, String>) -> (String, u32) {
+    let fences = find_fences(content);
+    if fences.is_empty() {
+        return (content.to_string(), 0);
+    }
+
+    let mut changed = 0;
+    let mut fences = fences.into_iter().peekable();
+    let mut output = String::with_capacity(content.len());
+
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        let fence = fences.next_if(|f| f.line == index);
+        let replacement = fence.as_ref().and_then(|f| {
+            mapping
+                .get(&line[f.language_start..f.language_end])
+                .filter(|to| !to.trim().is_empty())
+                .map(|to| (f, to))
+        });
+
+        match replacement {
+            Some((fence, to)) => {
+                output.push_str(&line[..fence.language_start]);
+                output.push_str(to.trim());
+                output.push_str(&line[fence.language_end..]);
+                changed += 1;
+            }
+            None => output.push_str(line),
+        }
+    }
+
+    (output, changed)
+}
+
+/// Lists fenced code block languages used in a collection and flags unknown ones
+///
+/// # Arguments
+/// * `collection_path` - The absolute path to the collection directory
+#[tauri::command]
+#[specta::specta]
+pub async fn audit_code_blocks(collection_path: String) -> Result<CodeBlockAudit, String> {
+    let path = Path::new(&collection_path);
+    if !path.is_dir() {
+        return Err(format!("Collection directory not found: {collection_path}"));
+    }
+    Ok(audit_collection(path))
+}
+
+/// Rewrites code block languages across a collection per an alias mapping
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `collection_path` - The absolute path to the collection directory
+/// * `mapping` - Languages to rewrite, e.g. `{ "js": "javascript" }`
+#[tauri::command]
+#[specta::specta]
+pub async fn normalize_code_block_languages(
+    project_path: String,
+    collection_path: String,
+    mapping: HashMap<String, String>,
+) -> Result<CodeBlockFixResult, String> {
+    let collection = validate_project_path(&collection_path, &project_path)?;
+    let mut result = CodeBlockFixResult {
+        files_changed: 0,
+        blocks_changed: 0,
+        changed_files: Vec::new(),
+    };
+    if mapping.is_empty() {
+        return Ok(result);
+    }
+
+    let mut bundle = UndoBundle::new("code-block-normalize");
+    let outcome = (|| {
+        for entry in markdown_files(&collection) {
+            let content = std::fs::read_to_string(entry.path())
+                .map_err(|e| format!("Failed to read {}: {e}", entry.path().display()))?;
+            let (rewritten, changed) = rewrite_languages(&content, &mapping);
+            if changed == 0 {
+                continue;
+            }
+
+            bundle.record(entry.path(), &content, &rewritten);
+            dry_run::write(entry.path(), rewritten)
+                .map_err(|e| format!("Failed to write {}: {e}", entry.path().display()))?;
+            audit_log::record(
+                AuditAction::Write,
+                entry.path(),
+                None,
+                "code-block-normalize",
+            );
+            result.files_changed += 1;
+            result.blocks_changed += changed;
+            result
+                .changed_files
+                .push(entry.path().to_string_lossy().to_string());
+        }
+        Ok::<(), String>(())
+    })();
+    // Saved even on failure so files already rewritten can be restored
+    bundle.save();
+    outcome?;
+
+    log::info!(
+        "Astro Editor [CODE_BLOCKS] Normalized {} code blocks in {} files",
+        result.blocks_changed,
+        result.files_changed
+    );
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_find_fences_skips_frontmatter_and_nested_fences() {
+        let content = "---\ntitle: x\n---\n\n```js title=\"a.js\"\nconst a = 1\n```\n\n~~~~md\n```py\n~~~~\n\n```\nplain\n```\n";
+        let lines: Vec<&str> = content.lines().collect();
+        let languages: Vec<&str> = find_fences(content)
+            .iter()
+            .map(|f| &lines[f.line][f.language_start..f.language_end])
+            .collect();
+        assert_eq!(languages, vec!["js", "md", ""]);
+    }
+
+    #[test]
+    fn test_canonical_and_suggestions() {
+        assert_eq!(canonical_language("js"), Some("javascript"));
+        assert_eq!(canonical_language("zsh"), Some("shellscript"));
+        assert_eq!(canonical_language("text"), Some("text"));
+        assert_eq!(canonical_language("javscript"), None);
+
+        assert_eq!(suggest_language("javscript").as_deref(), Some("javascript"));
+        assert_eq!(
+            suggest_language("TypeScript").as_deref(),
+            Some("typescript")
+        );
+        assert_eq!(suggest_language("pyhton").as_deref(), Some("python"));
+        assert_eq!(suggest_language("zz"), None);
+    }
+
+    #[test]
+    fn test_audit_collection() {
+        let temp = TempDir::new().unwrap();
+        fs::write(
+            temp.path().join("a.md"),
+            "```js\n1\n```\n\n```javscript\n2\n```\n",
+        )
+        .unwrap();
+        fs::write(temp.path().join("b.mdx"), "```js\n3\n```\n\n```\n4\n```\n").unwrap();
+
+        let audit = audit_collection(temp.path());
+        assert_eq!(audit.unlabelled_count, 1);
+        assert_eq!(audit.languages[0].language, "js");
+        assert_eq!(audit.languages[0].count, 2);
+        assert_eq!(audit.languages[0].canonical.as_deref(), Some("javascript"));
+        assert_eq!(audit.unknown_blocks.len(), 1);
+        assert_eq!(audit.unknown_blocks[0].line, 5);
+        assert_eq!(
+            audit.unknown_blocks[0].suggestion.as_deref(),
+            Some("javascript")
+        );
+    }
+
+    #[test]
+    fn test_rewrite_languages_keeps_meta() {
+        let mapping = HashMap::from([("js".to_string(), "javascript".to_string())]);
+        let content =
+            "Text with ```js inline```\n\n```js {1,3} title=\"x\"\na\n```\n\n```jsx\nb\n```\n";
+        let (rewritten, changed) = rewrite_languages(content, &mapping);
+        assert_eq!(changed, 1);
+        assert_eq!(
+            rewritten,
+            "Text with ```js inline```\n\n```javascript {1,3} title=\"x\"\na\n```\n\n```jsx\nb\n```\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_normalize_rejects_paths_outside_project() {
+        let project = TempDir::new().unwrap();
+        let outside = TempDir::new().unwrap();
+        let result = normalize_code_block_languages(
+            project.path().to_string_lossy().to_string(),
+            outside.path().to_string_lossy().to_string(),
+            HashMap::from([("js".to_string(), "javascript".to_string())]),
+        )
+        .await;
+        assert!(result.is_err());
+    }
+}
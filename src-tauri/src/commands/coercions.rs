@@ -0,0 +1,391 @@
+//! Frontmatter type coercion suggestions
+//!
+//! Imported content is full of stringly-typed values: `draft: "true"`, `order: "3"`,
+//! `pubDate: 2024-1-3`, `tags: "a, b"`. After a save the frontend asks
+//! `check_frontmatter_types` which values don't match their schema type but can be
+//! converted without guessing, and offers to fix them with `apply_coercions`. Values
+//! that can't be converted unambiguously (e.g. `"maybe"` for a boolean) are left for
+//! the schema form to flag.
+
+use chrono::{DateTime, NaiveDate};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::dry_run;
+use crate::commands::files::{
+    parse_frontmatter_internal, rebuild_markdown_with_frontmatter_and_imports_ordered,
+};
+use crate::commands::publish_gates::field_value;
+use crate::schema_merger::{SchemaDefinition, SchemaField};
+use crate::security::validate_project_path;
+
+/// Date formats imported content commonly uses, tried in order
+const DATE_FORMATS: [&str; 7] = [
+    "%Y-%m-%d",
+    "%Y/%m/%d",
+    "%Y.%m.%d",
+    "%B %d, %Y",
+    "%b %d, %Y",
+    "%d %B %Y",
+    "%d %b %Y",
+];
+
+/// A frontmatter value that doesn't match its schema type, and what it should be
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CoercionSuggestion {
+    /// Field path; nested fields use dots (`seo.noindex`)
+    pub field: String,
+    /// The schema type, e.g. `boolean`
+    pub expected_type: String,
+    pub current: Value,
+    pub suggested: Value,
+}
+
+fn parse_bool(text: &str) -> Option<bool> {
+    match text.trim().to_lowercase().as_str() {
+        "true" | "yes" | "on" | "1" => Some(true),
+        "false" | "no" | "off" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_number(text: &str, integer: bool) -> Option<Value> {
+    let text = text.trim();
+    if integer {
+        return text.parse::<i64>().ok().map(Value::from);
+    }
+    if let Ok(whole) = text.parse::<i64>() {
+        return Some(Value::from(whole));
+    }
+    let number = text.parse::<f64>().ok().filter(|n| n.is_finite())?;
+    serde_json::Number::from_f64(number).map(Value::Number)
+}
+
+/// `text` as a `YYYY-MM-DD` date, when it's a date in another (or unpadded) form
+fn normalize_date(text: &str) -> Option<String> {
+    let text = text.trim();
+    // Full timestamps are valid dates as they are
+    if DateTime::parse_from_rfc3339(text).is_ok() {
+        return None;
+    }
+    let date = DATE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(text, format).ok())?;
+    let normalized = date.format("%Y-%m-%d").to_string();
+    (normalized != text).then_some(normalized)
+}
+
+fn scalar_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// The value `current` should be for `field`, if it's a different type that converts cleanly
+fn coerce(field: &SchemaField, current: &Value) -> Option<Value> {
+    match (field.field_type.as_str(), current) {
+        ("boolean", Value::String(s)) => parse_bool(s).map(Value::Bool),
+        ("boolean", Value::Number(n)) => match n.as_i64() {
+            Some(0) => Some(Value::Bool(false)),
+            Some(1) => Some(Value::Bool(true)),
+            _ => None,
+        },
+        ("number", Value::String(s)) => parse_number(s, false),
+        ("integer", Value::String(s)) => parse_number(s, true),
+        ("integer", Value::Number(n)) if n.is_f64() => n
+            .as_f64()
+            .filter(|n| n.fract() == 0.0)
+            .map(|n| Value::from(n as i64)),
+        ("date", Value::String(s)) => normalize_date(s).map(Value::String),
+        ("string" | "email" | "url", value) => scalar_to_string(value).map(Value::String),
+        ("enum", value) => {
+            let text = match value {
+                Value::String(s) => s.trim().to_string(),
+                other => scalar_to_string(other)?,
+            };
+            let values = field.enum_values.as_ref()?;
+            if values.contains(&text) && value.is_string() {
+                return None;
+            }
+            values
+                .iter()
+                .find(|v| v.eq_ignore_ascii_case(&text))
+                .map(|v| Value::String(v.clone()))
+        }
+        ("array", Value::String(s)) => {
+            let items = s.split(',').map(str::trim).filter(|item| !item.is_empty());
+            match field.sub_type.as_deref() {
+                None | Some("string") => Some(Value::Array(
+                    items.map(|item| Value::String(item.to_string())).collect(),
+                )),
+                Some(number @ ("number" | "integer")) => items
+                    .map(|item| parse_number(item, number == "integer"))
+                    .collect::<Option<Vec<_>>>()
+                    .map(Value::Array),
+                Some(_) => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Suggestions for every field in `frontmatter` whose value doesn't match the schema
+fn suggest_coercions(
+    frontmatter: &IndexMap<String, Value>,
+    schema: &SchemaDefinition,
+) -> Vec<CoercionSuggestion> {
+    schema
+        .fields
+        .iter()
+        .filter_map(|field| {
+            let current = field_value(frontmatter, &field.name)?;
+            let suggested = coerce(field, current)?;
+            Some(CoercionSuggestion {
+                field: field.name.clone(),
+                expected_type: field.field_type.clone(),
+                current: current.clone(),
+                suggested,
+            })
+        })
+        .collect()
+}
+
+/// Sets a dotted field path, if the field exists
+fn set_field_value(frontmatter: &mut IndexMap<String, Value>, field: &str, value: Value) -> bool {
+    let mut parts = field.split('.');
+    let Some(slot) = parts.next().and_then(|first| frontmatter.get_mut(first)) else {
+        return false;
+    };
+    let slot = parts.try_fold(slot, |slot, part| slot.get_mut(part));
+    match slot {
+        Some(slot) => {
+            *slot = value;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Applies the suggestions whose field still has the value they were made for
+///
+/// Returns how many were applied.
+fn apply_to_frontmatter(
+    frontmatter: &mut IndexMap<String, Value>,
+    coercions: &[CoercionSuggestion],
+) -> u32 {
+    let mut applied = 0;
+    for coercion in coercions {
+        if field_value(frontmatter, &coercion.field) != Some(&coercion.current) {
+            continue;
+        }
+        if set_field_value(frontmatter, &coercion.field, coercion.suggested.clone()) {
+            applied += 1;
+        }
+    }
+    applied
+}
+
+/// Checks a file's frontmatter against its collection schema, returning values that
+/// have the wrong type but can be converted
+///
+/// # Arguments
+/// * `file_path` - The absolute path to the file
+/// * `project_root` - The absolute path to the project root
+/// * `complete_schema` - The collection's serialized complete schema
+#[tauri::command]
+#[specta::specta]
+pub async fn check_frontmatter_types(
+    file_path: String,
+    project_root: String,
+    complete_schema: String,
+) -> Result<Vec<CoercionSuggestion>, String> {
+    let validated_path = validate_project_path(&file_path, &project_root)?;
+    let schema: SchemaDefinition = serde_json::from_str(&complete_schema)
+        .map_err(|e| format!("Failed to parse schema: {e}"))?;
+    let content = std::fs::read_to_string(&validated_path)
+        .map_err(|e| format!("Failed to read file: {e}"))?;
+    let parsed = parse_frontmatter_internal(&content)?;
+    Ok(suggest_coercions(&parsed.frontmatter, &schema))
+}
+
+/// Rewrites frontmatter values to the types suggested by `check_frontmatter_types`
+///
+/// Suggestions for fields edited since the check are skipped.
+///
+/// # Arguments
+/// * `file_path` - The absolute path to the file
+/// * `project_root` - The absolute path to the project root
+/// * `coercions` - The suggestions to apply
+///
+/// # Returns
+/// The number of values changed
+#[tauri::command]
+#[specta::specta]
+pub async fn apply_coercions(
+    file_path: String,
+    project_root: String,
+    coercions: Vec<CoercionSuggestion>,
+) -> Result<u32, String> {
+    let validated_path = validate_project_path(&file_path, &project_root)?;
+    let content = std::fs::read_to_string(&validated_path)
+        .map_err(|e| format!("Failed to read file: {e}"))?;
+    let mut parsed = parse_frontmatter_internal(&content)?;
+
+    let applied = apply_to_frontmatter(&mut parsed.frontmatter, &coercions);
+    if applied == 0 {
+        return Ok(0);
+    }
+
+    let new_content = rebuild_markdown_with_frontmatter_and_imports_ordered(
+        &parsed.frontmatter,
+        &parsed.imports,
+        &parsed.content,
+        None,
+    )?;
+    dry_run::write(&validated_path, new_content)
+        .map_err(|e| format!("Failed to write file: {e}"))?;
+    audit_log::record(AuditAction::Write, &validated_path, None, "coercions");
+
+    Ok(applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn field(name: &str, field_type: &str) -> SchemaField {
+        serde_json::from_value(json!({
+            "name": name,
+            "label": name,
+            "fieldType": field_type,
+            "required": false,
+        }))
+        .unwrap()
+    }
+
+    fn schema(fields: Vec<SchemaField>) -> SchemaDefinition {
+        SchemaDefinition {
+            collection_name: "posts".to_string(),
+            fields,
+            groups: Vec::new(),
+            inferred: false,
+        }
+    }
+
+    #[test]
+    fn test_coerce_scalars() {
+        assert_eq!(
+            coerce(&field("draft", "boolean"), &json!("TRUE")),
+            Some(json!(true))
+        );
+        assert_eq!(coerce(&field("draft", "boolean"), &json!("maybe")), None);
+        assert_eq!(coerce(&field("draft", "boolean"), &json!(true)), None);
+        assert_eq!(
+            coerce(&field("order", "number"), &json!(" 2.5 ")),
+            Some(json!(2.5))
+        );
+        assert_eq!(
+            coerce(&field("order", "integer"), &json!("3")),
+            Some(json!(3))
+        );
+        assert_eq!(coerce(&field("order", "integer"), &json!("3.5")), None);
+        assert_eq!(
+            coerce(&field("title", "string"), &json!(2024)),
+            Some(json!("2024"))
+        );
+    }
+
+    #[test]
+    fn test_coerce_dates() {
+        let date = field("pubDate", "date");
+        assert_eq!(coerce(&date, &json!("2024-1-3")), Some(json!("2024-01-03")));
+        assert_eq!(
+            coerce(&date, &json!("January 3, 2024")),
+            Some(json!("2024-01-03"))
+        );
+        assert_eq!(coerce(&date, &json!("2024-01-03")), None);
+        assert_eq!(coerce(&date, &json!("2024-01-03T10:00:00Z")), None);
+        assert_eq!(coerce(&date, &json!("soon")), None);
+    }
+
+    #[test]
+    fn test_coerce_enums_and_arrays() {
+        let mut status = field("status", "enum");
+        status.enum_values = Some(vec!["Draft".to_string(), "Published".to_string()]);
+        assert_eq!(coerce(&status, &json!("draft")), Some(json!("Draft")));
+        assert_eq!(coerce(&status, &json!("Draft")), None);
+        assert_eq!(coerce(&status, &json!("archived")), None);
+
+        assert_eq!(
+            coerce(&field("tags", "array"), &json!("rust, tauri,")),
+            Some(json!(["rust", "tauri"]))
+        );
+        let mut scores = field("scores", "array");
+        scores.sub_type = Some("integer".to_string());
+        assert_eq!(coerce(&scores, &json!("1, 2")), Some(json!([1, 2])));
+        assert_eq!(coerce(&scores, &json!("1, two")), None);
+    }
+
+    #[test]
+    fn test_suggest_and_apply_nested_fields() {
+        let mut frontmatter: IndexMap<String, Value> =
+            serde_json::from_value(json!({ "title": "Post", "seo": { "noindex": "yes" } }))
+                .unwrap();
+        let suggestions = suggest_coercions(
+            &frontmatter,
+            &schema(vec![
+                field("title", "string"),
+                field("seo.noindex", "boolean"),
+            ]),
+        );
+
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].field, "seo.noindex");
+        assert_eq!(apply_to_frontmatter(&mut frontmatter, &suggestions), 1);
+        assert_eq!(frontmatter["seo"]["noindex"], json!(true));
+        // Already applied, so the value no longer matches
+        assert_eq!(apply_to_frontmatter(&mut frontmatter, &suggestions), 0);
+    }
+
+    #[tokio::test]
+    async fn test_check_and_apply_coercions() {
+        let temp = TempDir::new().unwrap();
+        let project = temp.path().to_string_lossy().to_string();
+        let file = temp.path().join("post.md");
+        fs::write(
+            &file,
+            "---\ntitle: Post\ndraft: \"true\"\npubDate: 2024-1-3\n---\n\nBody\n",
+        )
+        .unwrap();
+        let file_path = file.to_string_lossy().to_string();
+        let complete_schema = serde_json::to_string(&schema(vec![
+            field("draft", "boolean"),
+            field("pubDate", "date"),
+        ]))
+        .unwrap();
+
+        let suggestions =
+            check_frontmatter_types(file_path.clone(), project.clone(), complete_schema)
+                .await
+                .unwrap();
+        assert_eq!(suggestions.len(), 2);
+
+        let applied = apply_coercions(file_path, project, suggestions)
+            .await
+            .unwrap();
+        assert_eq!(applied, 2);
+        let parsed = parse_frontmatter_internal(&fs::read_to_string(&file).unwrap()).unwrap();
+        assert_eq!(parsed.frontmatter["draft"], json!(true));
+        assert_eq!(parsed.frontmatter["pubDate"], json!("2024-01-03"));
+        assert_eq!(parsed.content.trim(), "Body");
+    }
+}
@@ -0,0 +1,405 @@
+//! Content collection scaffolding
+//!
+//! Creates new collections without leaving the app: the content directory, a
+//! `defineCollection` block with a starter schema in `content.config.ts`, and
+//! optionally a first entry. Also supports simple schema tweaks such as adding a field.
+//! The config edits themselves live in `config_editor`.
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::dry_run;
+use crate::commands::files::to_kebab_case;
+use crate::commands::ide::get_augmented_path;
+use crate::commands::processes::{spawn_managed, ProcessLimits};
+use crate::commands::project::send_toast_notification;
+use crate::config_editor::{
+    apply_edit, find_config_path, unified_diff, ConfigEdit, NewSchemaField, DEFAULT_CONTENT_DIR,
+    NEW_CONFIG_PATH,
+};
+use crate::security::validate_project_path;
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::{Component, Path, PathBuf};
+use std::process::Stdio;
+use tauri::Emitter;
+use tokio::io::AsyncReadExt;
+
+/// Result of a dry-run config edit
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigEditPreview {
+    /// Config path relative to the project root
+    pub config_path: String,
+    /// Whether the edit would create the config file
+    pub is_new_file: bool,
+    /// Unified diff of the change
+    pub diff: String,
+}
+
+/// Returns the config path (existing or to-be-created) and its current content
+fn read_config(project_root: &Path) -> Result<(PathBuf, Option<String>), String> {
+    match find_config_path(project_root) {
+        Some(config_path) => {
+            let content = std::fs::read_to_string(&config_path)
+                .map_err(|e| format!("Failed to read config file: {e}"))?;
+            Ok((config_path, Some(content)))
+        }
+        None => Ok((project_root.join(NEW_CONFIG_PATH), None)),
+    }
+}
+
+/// Applies an edit to the project's content config and writes it to disk
+fn write_config_edit(project_root: &Path, edit: &ConfigEdit) -> Result<(), String> {
+    let (config_path, content) = read_config(project_root)?;
+    let updated = apply_edit(content.as_deref(), edit)?;
+    dry_run::write_atomic(&config_path, updated)
+        .map_err(|e| format!("Failed to write config file: {e}"))?;
+    audit_log::record(AuditAction::Write, &config_path, None, "collections");
+    Ok(())
+}
+
+/// Builds a first entry whose frontmatter satisfies the starter schema
+fn build_first_entry(title: &str) -> Result<String, String> {
+    let mut frontmatter = indexmap::IndexMap::new();
+    frontmatter.insert("title", serde_json::Value::String(title.to_string()));
+    frontmatter.insert(
+        "pubDate",
+        serde_json::Value::String(Local::now().format("%Y-%m-%d").to_string()),
+    );
+    frontmatter.insert("draft", serde_json::Value::Bool(true));
+
+    let yaml = serde_norway::to_string(&frontmatter)
+        .map_err(|e| format!("Failed to serialize frontmatter: {e}"))?;
+
+    Ok(format!("---\n{yaml}---\n\n"))
+}
+
+/// Checks that a collection directory would be created inside the project
+///
+/// The directory and some of its parents may not exist yet, so the nearest one that
+/// does is validated: a symlink there could otherwise lead out of the project.
+fn check_collection_dir(collection_dir: &Path, project_path: &str) -> Result<(), String> {
+    let existing = collection_dir
+        .ancestors()
+        .find(|ancestor| ancestor.exists())
+        .ok_or("Invalid collection directory")?;
+    validate_project_path(&existing.to_string_lossy(), project_path)?;
+    Ok(())
+}
+
+/// Longest `astro sync` may run before it is terminated
+const SYNC_TIMEOUT_MINUTES: u64 = 2;
+
+/// Runs `astro sync` so the generated JSON schemas reflect config changes
+pub(crate) async fn run_astro_sync(
+    app: &tauri::AppHandle,
+    project_root: &Path,
+) -> Result<(), String> {
+    let npx = if cfg!(target_os = "windows") {
+        "npx.cmd"
+    } else {
+        "npx"
+    };
+
+    let mut command = tokio::process::Command::new(npx);
+    command
+        .args(["astro", "sync"])
+        .current_dir(project_root)
+        .env("PATH", get_augmented_path())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped());
+    let mut sync = spawn_managed(
+        app,
+        "astro sync",
+        command,
+        ProcessLimits::minutes(SYNC_TIMEOUT_MINUTES),
+    )?;
+
+    // Read stderr while waiting so a verbose failure can't fill the pipe
+    let stderr = sync.child.stderr.take();
+    let read_stderr = async {
+        let mut output = String::new();
+        if let Some(mut stderr) = stderr {
+            let _ = stderr.read_to_string(&mut output).await;
+        }
+        output
+    };
+    let (stderr, status) = tokio::join!(read_stderr, sync.wait());
+
+    if status?.success() {
+        Ok(())
+    } else {
+        Err(format!("astro sync failed: {}", stderr.trim()))
+    }
+}
+
+/// Runs `astro sync` and emits `schema-changed` so the frontend re-merges schemas
+///
+/// A failed sync is reported as a warning toast since the config edit itself has
+/// already succeeded.
+async fn sync_schemas(app: &tauri::AppHandle, project_root: PathBuf) -> Result<(), String> {
+    if let Err(e) = run_astro_sync(app, &project_root).await {
+        log::warn!("Astro Editor [COLLECTIONS] {e}");
+        let _ = send_toast_notification(
+            app,
+            "warning",
+            "Config updated, but astro sync failed",
+            Some(&e),
+        );
+    }
+
+    app.emit("schema-changed", ())
+        .map_err(|e| format!("Failed to emit schema change event: {e}"))
+}
+
+/// Previews a content config edit without writing it
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `edit` - The edit to preview
+///
+/// # Returns
+/// A unified diff of the change for the user to confirm
+#[tauri::command]
+#[specta::specta]
+pub async fn preview_config_edit(
+    project_path: String,
+    edit: ConfigEdit,
+) -> Result<ConfigEditPreview, String> {
+    let project_root = Path::new(&project_path);
+    let (config_path, content) = read_config(project_root)?;
+    let original = content.as_deref().unwrap_or("");
+    let updated = apply_edit(content.as_deref(), &edit)?;
+
+    let relative_path = config_path
+        .strip_prefix(project_root)
+        .unwrap_or(&config_path)
+        .to_string_lossy()
+        .replace('\\', "/");
+
+    Ok(ConfigEditPreview {
+        diff: unified_diff(original, &updated, &relative_path),
+        config_path: relative_path,
+        is_new_file: content.is_none(),
+    })
+}
+
+/// Creates a new content collection
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `collection_name` - Collection identifier (must be a valid JavaScript identifier)
+/// * `content_directory` - Optional content directory override (defaults to "src/content")
+/// * `first_entry_title` - If provided, creates a first entry with this title
+///
+/// # Returns
+/// The absolute path to the new collection directory
+#[tauri::command]
+#[specta::specta]
+pub async fn create_collection(
+    app: tauri::AppHandle,
+    project_path: String,
+    collection_name: String,
+    content_directory: Option<String>,
+    first_entry_title: Option<String>,
+) -> Result<String, String> {
+    let project_root = Path::new(&project_path);
+    let content_dir = content_directory.unwrap_or_else(|| DEFAULT_CONTENT_DIR.to_string());
+    if Path::new(&content_dir)
+        .components()
+        .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        return Err("Content directory must be relative to the project root".to_string());
+    }
+    let collection_dir = project_root.join(&content_dir).join(&collection_name);
+    check_collection_dir(&collection_dir, &project_path)?;
+
+    // Update (or create) the content config first, so invalid names never touch disk
+    let edit = ConfigEdit::InsertCollection {
+        name: collection_name.clone(),
+        content_directory: Some(content_dir.clone()),
+    };
+    write_config_edit(project_root, &edit)?;
+
+    dry_run::create_dir_all(&collection_dir)
+        .map_err(|e| format!("Failed to create collection directory: {e}"))?;
+    let collection_dir = validate_project_path(&collection_dir.to_string_lossy(), &project_path)?;
+
+    if let Some(title) = first_entry_title.filter(|t| !t.trim().is_empty()) {
+        let filename = format!("{}.md", to_kebab_case(&title.replace('.', " ")));
+        let entry_path = collection_dir.join(filename);
+        if !dry_run::exists(&entry_path) {
+            dry_run::write_atomic(&entry_path, build_first_entry(&title)?)
+                .map_err(|e| format!("Failed to create first entry: {e}"))?;
+            audit_log::record(AuditAction::Create, &entry_path, None, "collections");
+        }
+    }
+
+    log::info!("Astro Editor [COLLECTIONS] Created collection '{collection_name}'");
+
+    sync_schemas(&app, project_root.to_path_buf()).await?;
+
+    Ok(collection_dir.to_string_lossy().to_string())
+}
+
+/// Adds a field to a collection's schema in the content config
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `collection_name` - The collection whose schema should be updated
+/// * `field` - The field to add
+#[tauri::command]
+#[specta::specta]
+pub async fn add_schema_field(
+    app: tauri::AppHandle,
+    project_path: String,
+    collection_name: String,
+    field: NewSchemaField,
+) -> Result<(), String> {
+    let project_root = PathBuf::from(&project_path);
+    let field_name = field.name.clone();
+    let edit = ConfigEdit::InsertField {
+        collection: collection_name.clone(),
+        field,
+    };
+
+    if find_config_path(&project_root).is_none() {
+        return Err("No content config file found".to_string());
+    }
+    write_config_edit(&project_root, &edit)?;
+
+    log::info!("Astro Editor [COLLECTIONS] Added field '{field_name}' to '{collection_name}'");
+
+    sync_schemas(&app, project_root).await
+}
+
+/// Renames a collection's key in the content config
+///
+/// Only the config is changed; the collection's directory and loader are left as-is.
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `from` - The current collection name
+/// * `to` - The new collection name
+#[tauri::command]
+#[specta::specta]
+pub async fn rename_collection(
+    app: tauri::AppHandle,
+    project_path: String,
+    from: String,
+    to: String,
+) -> Result<(), String> {
+    let project_root = PathBuf::from(&project_path);
+    let edit = ConfigEdit::RenameCollection {
+        from: from.clone(),
+        to: to.clone(),
+    };
+    write_config_edit(&project_root, &edit)?;
+
+    log::info!("Astro Editor [COLLECTIONS] Renamed collection '{from}' to '{to}'");
+
+    sync_schemas(&app, project_root).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const CONFIG: &str = r#"import { defineCollection } from 'astro:content';
+import { glob } from 'astro/loaders';
+import { z } from 'astro/zod';
+
+const articles = defineCollection({
+  loader: glob({ pattern: '**/[^_]*.{md,mdx}', base: './src/content/articles' }),
+  schema: z.object({
+    title: z.string(),
+  }),
+});
+
+export const collections = { articles };
+"#;
+
+    #[test]
+    fn test_write_config_edit_creates_config() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("src")).unwrap();
+
+        let edit = ConfigEdit::InsertCollection {
+            name: "notes".to_string(),
+            content_directory: None,
+        };
+        write_config_edit(temp.path(), &edit).unwrap();
+
+        let config = fs::read_to_string(temp.path().join(NEW_CONFIG_PATH)).unwrap();
+        assert!(config.contains("import { defineCollection } from 'astro:content';"));
+        assert!(config.contains("export const collections = { notes };"));
+    }
+
+    #[test]
+    fn test_write_config_edit_updates_existing_config() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("src")).unwrap();
+        fs::write(temp.path().join("src/content.config.ts"), CONFIG).unwrap();
+
+        let edit = ConfigEdit::InsertCollection {
+            name: "notes".to_string(),
+            content_directory: None,
+        };
+        write_config_edit(temp.path(), &edit).unwrap();
+
+        let config = fs::read_to_string(temp.path().join("src/content.config.ts")).unwrap();
+        assert!(config.contains("export const collections = { articles, notes };"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_check_collection_dir_refuses_symlinks_out_of_the_project() {
+        let temp = TempDir::new().unwrap();
+        let project = temp.path().join("project");
+        let outside = temp.path().join("outside");
+        fs::create_dir_all(project.join("src")).unwrap();
+        fs::create_dir_all(&outside).unwrap();
+        std::os::unix::fs::symlink(&outside, project.join("src/content")).unwrap();
+        let root = project.to_string_lossy().to_string();
+
+        assert!(check_collection_dir(&project.join("src/content/notes"), &root).is_err());
+        assert!(check_collection_dir(&project.join("src/docs/notes"), &root).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_preview_config_edit_does_not_write() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("src")).unwrap();
+        fs::write(temp.path().join("src/content.config.ts"), CONFIG).unwrap();
+
+        let preview = preview_config_edit(
+            temp.path().to_string_lossy().to_string(),
+            ConfigEdit::RenameCollection {
+                from: "articles".to_string(),
+                to: "posts".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(preview.config_path, "src/content.config.ts");
+        assert!(!preview.is_new_file);
+        assert!(preview
+            .diff
+            .contains("+export const collections = { posts: articles };"));
+        assert_eq!(
+            fs::read_to_string(temp.path().join("src/content.config.ts")).unwrap(),
+            CONFIG
+        );
+    }
+
+    #[test]
+    fn test_build_first_entry() {
+        let entry = build_first_entry("Hello World").unwrap();
+        assert!(entry.starts_with("---\ntitle: Hello World\n"));
+        assert!(entry.contains("draft: true"));
+        assert!(entry.ends_with("---\n\n"));
+    }
+}
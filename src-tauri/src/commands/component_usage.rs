@@ -0,0 +1,286 @@
+//! Where MDX components are used in content
+//!
+//! `find_component_usages` lists every `<Name ...>` tag for a component across the
+//! project's `.mdx` entries, with the props each one passes, so a component can be
+//! changed or deleted knowing what depends on it. `component_usage_report` does the
+//! same for every component in the MDX directory and lists the ones nothing uses.
+//!
+//! Tags inside code fences and inline code are examples, not usages, and are skipped.
+
+use crate::commands::content_graph::{read_entries, Entry};
+use crate::commands::link_graph::{body_lines, strip_inline_code};
+use crate::commands::mdx_components::scan_mdx_components;
+use crate::commands::project::scan_project_with_content_dir;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::{BTreeSet, HashMap};
+
+/// A component tag in an entry
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentUsage {
+    pub component: String,
+    pub file_path: String,
+    /// 1-based line the tag starts on
+    pub line: u32,
+    /// Attributes on the tag, as written (spreads aren't included)
+    pub props: Vec<String>,
+}
+
+/// How much one component is used
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentUsageSummary {
+    pub name: String,
+    /// The component file, relative to the project root
+    pub component_path: String,
+    pub usage_count: u32,
+    /// Entries using the component at least once
+    pub file_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentUsageReport {
+    /// Every component in the MDX directory, most used first
+    pub components: Vec<ComponentUsageSummary>,
+    /// Components no entry uses
+    pub unused: Vec<String>,
+}
+
+/// Component tags in every MDX entry
+fn entry_usages(entries: &[Entry]) -> Vec<ComponentUsage> {
+    let tag_re = Regex::new(r"<([A-Z][A-Za-z0-9_]*)[\s/>]").unwrap();
+    let mut usages = Vec::new();
+
+    for entry in entries {
+        let is_mdx = entry
+            .path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("mdx"));
+        if !is_mdx {
+            continue;
+        }
+
+        // Blank out code so line numbers still match the body
+        let mut lines = vec![String::new(); entry.body.lines().count()];
+        for (index, line) in body_lines(&entry.body) {
+            lines[index] = strip_inline_code(line);
+        }
+        let text = lines.join("\n");
+
+        for captures in tag_re.captures_iter(&text) {
+            let name = captures.get(1).unwrap();
+            let line = text[..name.start()].matches('\n').count();
+            usages.push(ComponentUsage {
+                component: name.as_str().to_string(),
+                file_path: entry.path.to_string_lossy().to_string(),
+                line: (entry.body_offset + line + 1) as u32,
+                props: tag_props(&text[name.end()..]),
+            });
+        }
+    }
+
+    usages
+}
+
+/// Attribute names of a tag, given the text after its name
+///
+/// Stops at the `>` ending the tag, ignoring any inside quoted values or `{...}`
+/// expressions, so tags split across lines are read whole.
+fn tag_props(rest: &str) -> Vec<String> {
+    let mut props = Vec::new();
+    let mut name = String::new();
+    let mut quote = None;
+    let mut depth = 0usize;
+
+    for c in rest.chars() {
+        if let Some(open) = quote {
+            if c == open {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' | '`' => quote = Some(c),
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            '>' if depth == 0 => break,
+            c if depth == 0 && (c.is_alphanumeric() || matches!(c, '_' | '-' | ':')) => {
+                name.push(c);
+                continue;
+            }
+            _ => {}
+        }
+        if !name.is_empty() {
+            props.push(std::mem::take(&mut name));
+        }
+    }
+    if !name.is_empty() {
+        props.push(name);
+    }
+
+    props
+}
+
+/// Finds every use of an MDX component in the project's content
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+/// * `component_name` - The component's tag name, e.g. `Callout`
+#[tauri::command]
+#[specta::specta]
+pub async fn find_component_usages(
+    project_path: String,
+    content_directory: Option<String>,
+    component_name: String,
+) -> Result<Vec<ComponentUsage>, String> {
+    let collections = scan_project_with_content_dir(project_path, content_directory, None).await?;
+
+    tokio::task::spawn_blocking(move || {
+        let entries = read_entries(&collections);
+        entry_usages(&entries)
+            .into_iter()
+            .filter(|usage| usage.component == component_name)
+            .collect()
+    })
+    .await
+    .map_err(|e| format!("Failed to find component usages: {e}"))
+}
+
+/// Counts how often each MDX component is used and lists unused ones
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+/// * `mdx_directory` - Optional MDX components directory override
+#[tauri::command]
+#[specta::specta]
+pub async fn component_usage_report(
+    project_path: String,
+    content_directory: Option<String>,
+    mdx_directory: Option<String>,
+) -> Result<ComponentUsageReport, String> {
+    let components = scan_mdx_components(project_path.clone(), mdx_directory).await?;
+    let collections = scan_project_with_content_dir(project_path, content_directory, None).await?;
+
+    let usages = tokio::task::spawn_blocking(move || entry_usages(&read_entries(&collections)))
+        .await
+        .map_err(|e| format!("Failed to find component usages: {e}"))?;
+
+    Ok(build_report(
+        components
+            .iter()
+            .map(|c| (c.name.as_str(), c.file_path.as_str())),
+        &usages,
+    ))
+}
+
+/// Summarises usages of the given (name, file path) components
+fn build_report<'a>(
+    components: impl Iterator<Item = (&'a str, &'a str)>,
+    usages: &[ComponentUsage],
+) -> ComponentUsageReport {
+    let mut by_component: HashMap<&str, Vec<&ComponentUsage>> = HashMap::new();
+    for usage in usages {
+        by_component
+            .entry(usage.component.as_str())
+            .or_default()
+            .push(usage);
+    }
+
+    let mut summaries: Vec<ComponentUsageSummary> = components
+        .map(|(name, component_path)| {
+            let found = by_component
+                .get(name)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let files: BTreeSet<&str> = found.iter().map(|u| u.file_path.as_str()).collect();
+            ComponentUsageSummary {
+                name: name.to_string(),
+                component_path: component_path.to_string(),
+                usage_count: found.len() as u32,
+                file_count: files.len() as u32,
+            }
+        })
+        .collect();
+    summaries.sort_by(|a, b| b.usage_count.cmp(&a.usage_count).then(a.name.cmp(&b.name)));
+
+    let unused = summaries
+        .iter()
+        .filter(|summary| summary.usage_count == 0)
+        .map(|summary| summary.name.clone())
+        .collect();
+
+    ComponentUsageReport {
+        components: summaries,
+        unused,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Collection;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_tag_props() {
+        assert_eq!(
+            tag_props(r#" type="warning" title={`a > b`} open client:load />rest"#),
+            vec!["type", "title", "open", "client:load"]
+        );
+        assert_eq!(
+            tag_props(" {...rest} data={{ a: '}' }}\n  icon='x>y'>"),
+            vec!["data", "icon"]
+        );
+    }
+
+    #[test]
+    fn test_entry_usages() {
+        let temp = TempDir::new().unwrap();
+        let blog = temp.path().join("blog");
+        fs::create_dir_all(&blog).unwrap();
+        fs::write(
+            blog.join("post.mdx"),
+            "---\ntitle: Post\n---\n\n<Callout type=\"info\">Hi</Callout>\n\n\
+             `<Callout />` in code\n\n```mdx\n<Callout type=\"fenced\" />\n```\n\n\
+             <Callout\n  type=\"warning\"\n  dismissible\n/>\n<YouTube id=\"abc\" />\n",
+        )
+        .unwrap();
+        fs::write(
+            blog.join("plain.md"),
+            "---\ntitle: Plain\n---\n\n<Callout />\n",
+        )
+        .unwrap();
+
+        let collections = vec![Collection::new("blog".to_string(), blog)];
+        let usages = entry_usages(&read_entries(&collections));
+
+        let callouts: Vec<_> = usages.iter().filter(|u| u.component == "Callout").collect();
+        assert_eq!(callouts.len(), 2);
+        assert_eq!(callouts[0].line, 5);
+        assert_eq!(callouts[0].props, vec!["type"]);
+        assert_eq!(callouts[1].line, 13);
+        assert_eq!(callouts[1].props, vec!["type", "dismissible"]);
+        assert!(callouts[0].file_path.ends_with("post.mdx"));
+
+        let report = build_report(
+            [
+                ("Callout", "src/components/mdx/Callout.astro"),
+                ("Figure", "src/components/mdx/Figure.astro"),
+                ("YouTube", "src/components/mdx/YouTube.astro"),
+            ]
+            .into_iter(),
+            &usages,
+        );
+        assert_eq!(report.components[0].name, "Callout");
+        assert_eq!(report.components[0].usage_count, 2);
+        assert_eq!(report.components[0].file_count, 1);
+        assert_eq!(report.components[1].name, "YouTube");
+        assert_eq!(report.unused, vec!["Figure"]);
+    }
+}
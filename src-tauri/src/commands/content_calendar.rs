@@ -0,0 +1,538 @@
+//! Content calendar
+//!
+//! `get_content_calendar` reads every entry's dates, draft status and title in one
+//! pass and groups them by month, for a calendar or planner view. The dates are the
+//! collection schema's date fields (e.g. `pubDate`, `updatedDate`), or without a schema,
+//! fields named like dates. An entry shows up in the month of each date it has, and
+//! drafts without a publish date are listed separately. The project's frontmatter
+//! mappings for the title, publish date and draft fields are honored.
+
+use crate::commands::files::parse_frontmatter_internal;
+use crate::commands::project::scan_project_with_content_dir;
+use crate::commands::projects;
+use crate::commands::publishing::{detect_draft_field, DATE_FIELDS};
+use crate::models::Collection;
+use crate::schema_merger::SchemaDefinition;
+use chrono::{Local, NaiveDate};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// What a calendar date records
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum CalendarDateKind {
+    /// A publish date field, e.g. `pubDate`
+    Published,
+    /// A field named for updates, e.g. `updatedDate` or `lastModified`
+    Updated,
+    Other,
+}
+
+/// One date of one entry
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarItem {
+    pub file_path: String,
+    pub collection: String,
+    pub title: String,
+    pub is_draft: bool,
+    /// The frontmatter field the date is from
+    pub field: String,
+    pub kind: CalendarDateKind,
+    /// `YYYY-MM-DD`
+    pub date: String,
+    /// A publish date after today
+    pub scheduled: bool,
+}
+
+/// The dates that fall in one month
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarMonth {
+    /// `YYYY-MM`
+    pub month: String,
+    /// In date order
+    pub items: Vec<CalendarItem>,
+}
+
+/// A draft with no publish date yet
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UndatedDraft {
+    pub file_path: String,
+    pub collection: String,
+    pub title: String,
+}
+
+/// The project's frontmatter mappings the calendar reads
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct CalendarFields {
+    /// The title field; `title` when unset
+    pub title: Option<String>,
+    /// Publish date fields, counted alongside the usual names (`pubDate`, `date`, ...)
+    pub published_date: Vec<String>,
+    /// The draft field, checked before the usual names
+    pub draft: Option<String>,
+}
+
+/// Every entry's dates across the project
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentCalendar {
+    /// Oldest first
+    pub months: Vec<CalendarMonth>,
+    pub undated_drafts: Vec<UndatedDraft>,
+}
+
+fn date_kind(field: &str, fields: &CalendarFields) -> CalendarDateKind {
+    let lower = field.to_ascii_lowercase();
+    if DATE_FIELDS.contains(&field) || fields.published_date.iter().any(|name| name == field) {
+        CalendarDateKind::Published
+    } else if lower.contains("updated") || lower.contains("modified") {
+        CalendarDateKind::Updated
+    } else {
+        CalendarDateKind::Other
+    }
+}
+
+/// Reads `YYYY-MM-DD` from a date or datetime frontmatter value
+fn frontmatter_date(value: &Value) -> Option<NaiveDate> {
+    let text = value.as_str()?;
+    NaiveDate::parse_from_str(text.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// The entry's top-level date fields: those the schema declares as dates, or without
+/// one, those with "date" in their name and the mapped publish date fields
+fn date_fields(
+    schema: Option<&SchemaDefinition>,
+    frontmatter: &IndexMap<String, Value>,
+    fields: &CalendarFields,
+) -> Vec<(String, NaiveDate)> {
+    let names: Vec<&String> = match schema {
+        Some(schema) => schema
+            .fields
+            .iter()
+            .filter(|field| field.field_type == "date" && field.is_nested != Some(true))
+            .map(|field| &field.name)
+            .collect(),
+        None => frontmatter
+            .keys()
+            .filter(|name| {
+                name.to_ascii_lowercase().contains("date") || fields.published_date.contains(name)
+            })
+            .collect(),
+    };
+    names
+        .into_iter()
+        .filter_map(|name| Some((name.clone(), frontmatter_date(frontmatter.get(name)?)?)))
+        .collect()
+}
+
+fn is_draft(
+    schema: Option<&SchemaDefinition>,
+    frontmatter: &IndexMap<String, Value>,
+    fields: &CalendarFields,
+) -> bool {
+    detect_draft_field(fields.draft.as_deref(), schema, frontmatter)
+        .is_some_and(|draft| draft.is_draft(frontmatter))
+}
+
+fn entry_title(
+    path: &Path,
+    frontmatter: &IndexMap<String, Value>,
+    fields: &CalendarFields,
+) -> String {
+    frontmatter
+        .get(fields.title.as_deref().unwrap_or("title"))
+        .and_then(Value::as_str)
+        .map(String::from)
+        .unwrap_or_else(|| {
+            path.file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string()
+        })
+}
+
+fn build_calendar(
+    collections: &[Collection],
+    fields: &CalendarFields,
+    today: NaiveDate,
+) -> ContentCalendar {
+    let mut seen = HashSet::new();
+    let mut months: BTreeMap<String, Vec<CalendarItem>> = BTreeMap::new();
+    let mut undated_drafts = Vec::new();
+
+    for collection in collections.iter().filter(|collection| !collection.remote) {
+        let schema: Option<SchemaDefinition> = collection
+            .complete_schema
+            .as_deref()
+            .and_then(|schema| serde_json::from_str(schema).ok());
+
+        let files = WalkDir::new(&collection.path)
+            .follow_links(false)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                entry.file_type().is_file()
+                    && matches!(
+                        entry.path().extension().and_then(|e| e.to_str()),
+                        Some("md") | Some("mdx")
+                    )
+            });
+
+        for file in files {
+            // Nested collections are walked by their parent too
+            if !seen.insert(file.path().to_path_buf()) {
+                continue;
+            }
+            let Some(frontmatter) = std::fs::read_to_string(file.path())
+                .ok()
+                .and_then(|content| parse_frontmatter_internal(&content).ok())
+                .map(|parsed| parsed.frontmatter)
+            else {
+                continue;
+            };
+
+            let file_path = file.path().to_string_lossy().to_string();
+            let title = entry_title(file.path(), &frontmatter, fields);
+            let is_draft = is_draft(schema.as_ref(), &frontmatter, fields);
+            let dates = date_fields(schema.as_ref(), &frontmatter, fields);
+
+            let has_publish_date = dates
+                .iter()
+                .any(|(field, _)| date_kind(field, fields) == CalendarDateKind::Published);
+            if is_draft && !has_publish_date {
+                undated_drafts.push(UndatedDraft {
+                    file_path: file_path.clone(),
+                    collection: collection.name.clone(),
+                    title: title.clone(),
+                });
+            }
+
+            for (field, date) in dates {
+                let kind = date_kind(&field, fields);
+                months
+                    .entry(date.format("%Y-%m").to_string())
+                    .or_default()
+                    .push(CalendarItem {
+                        file_path: file_path.clone(),
+                        collection: collection.name.clone(),
+                        title: title.clone(),
+                        is_draft,
+                        field,
+                        kind,
+                        date: date.format("%Y-%m-%d").to_string(),
+                        scheduled: kind == CalendarDateKind::Published && date > today,
+                    });
+            }
+        }
+    }
+
+    let months = months
+        .into_iter()
+        .map(|(month, mut items)| {
+            items.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.title.cmp(&b.title)));
+            CalendarMonth { month, items }
+        })
+        .collect();
+    undated_drafts.sort_by(|a, b| a.title.cmp(&b.title));
+    ContentCalendar {
+        months,
+        undated_drafts,
+    }
+}
+
+/// Gathers every entry's dates, draft status and title, grouped by month
+///
+/// The content directory and field mappings are the project's, from the project
+/// registry.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_content_calendar(project_path: String) -> Result<ContentCalendar, String> {
+    let content_directory = projects::path_settings(&project_path).content_directory;
+    let fields = projects::calendar_fields(&project_path);
+    let collections = scan_project_with_content_dir(project_path, content_directory, None).await?;
+
+    tokio::task::spawn_blocking(move || {
+        build_calendar(&collections, &fields, Local::now().date_naive())
+    })
+    .await
+    .map_err(|e| format!("Failed to build content calendar: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_build_calendar() {
+        let temp = TempDir::new().unwrap();
+        let blog = temp.path().join("blog");
+        fs::create_dir_all(&blog).unwrap();
+        fs::write(
+            blog.join("old.md"),
+            "---\ntitle: Old\npubDate: 2024-01-20\nupdatedDate: 2024-03-02T10:00:00Z\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            blog.join("soon.md"),
+            "---\ntitle: Soon\npubDate: 2024-03-28\ndraft: true\n---\n",
+        )
+        .unwrap();
+        fs::write(blog.join("idea.md"), "---\ntitle: Idea\ndraft: true\n---\n").unwrap();
+
+        let schema = serde_json::json!({
+            "collectionName": "blog",
+            "fields": [
+                { "name": "title", "label": "Title", "fieldType": "string", "required": true },
+                { "name": "pubDate", "label": "Pub Date", "fieldType": "date", "required": false },
+                { "name": "updatedDate", "label": "Updated", "fieldType": "date", "required": false },
+                { "name": "draft", "label": "Draft", "fieldType": "boolean", "required": false },
+            ],
+        });
+        let mut collection = Collection::new("blog".to_string(), blog.clone());
+        collection.complete_schema = Some(schema.to_string());
+
+        let today = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let calendar = build_calendar(&[collection], &CalendarFields::default(), today);
+
+        let months: Vec<&str> = calendar.months.iter().map(|m| m.month.as_str()).collect();
+        assert_eq!(months, vec!["2024-01", "2024-03"]);
+
+        let march = &calendar.months[1].items;
+        assert_eq!(march.len(), 2);
+        assert_eq!(march[0].title, "Old");
+        assert_eq!(march[0].kind, CalendarDateKind::Updated);
+        assert_eq!(march[0].date, "2024-03-02");
+        assert_eq!(march[1].title, "Soon");
+        assert!(march[1].is_draft);
+        assert!(march[1].scheduled);
+
+        assert_eq!(
+            calendar.undated_drafts,
+            vec![UndatedDraft {
+                file_path: blog.join("idea.md").to_string_lossy().to_string(),
+                collection: "blog".to_string(),
+                title: "Idea".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_months_across_year_boundary() {
+        let temp = TempDir::new().unwrap();
+        let notes = temp.path().join("notes");
+        fs::create_dir_all(&notes).unwrap();
+        fs::write(
+            notes.join("eve.md"),
+            "---\ntitle: Eve\ndate: 2023-12-31\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            notes.join("new-year.md"),
+            "---\ntitle: New Year\ndate: 2024-01-01\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            notes.join("early.md"),
+            "---\ntitle: Early\ndate: 2023-12-05\n---\n",
+        )
+        .unwrap();
+
+        let today = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let collection = Collection::new("notes".to_string(), notes);
+        let calendar = build_calendar(&[collection], &CalendarFields::default(), today);
+
+        let months: Vec<(&str, Vec<&str>)> = calendar
+            .months
+            .iter()
+            .map(|month| {
+                let titles = month.items.iter().map(|item| item.title.as_str()).collect();
+                (month.month.as_str(), titles)
+            })
+            .collect();
+        assert_eq!(
+            months,
+            vec![
+                ("2023-12", vec!["Early", "Eve"]),
+                ("2024-01", vec!["New Year"]),
+            ]
+        );
+        assert_eq!(
+            calendar.months[1].items[0].kind,
+            CalendarDateKind::Published
+        );
+        assert!(!calendar.months[1].items[0].scheduled);
+    }
+
+    #[test]
+    fn test_missing_and_invalid_dates() {
+        let temp = TempDir::new().unwrap();
+        let blog = temp.path().join("blog");
+        fs::create_dir_all(&blog).unwrap();
+        fs::write(
+            blog.join("garbled.md"),
+            "---\ntitle: Garbled\npubDate: soon\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            blog.join("impossible.md"),
+            "---\ntitle: Impossible\npubDate: 2024-13-45\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            blog.join("number.md"),
+            "---\ntitle: Number\ndate: 20240101\n---\n",
+        )
+        .unwrap();
+        fs::write(blog.join("undated.md"), "---\ntitle: Undated\n---\n").unwrap();
+        fs::write(
+            blog.join("draft.md"),
+            "---\ntitle: Draft\npubDate: not yet\ndraft: true\n---\n",
+        )
+        .unwrap();
+        fs::write(blog.join("broken.md"), "---\ntitle: [unclosed\n---\n").unwrap();
+
+        let today = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let collection = Collection::new("blog".to_string(), blog);
+        let calendar = build_calendar(&[collection], &CalendarFields::default(), today);
+
+        assert!(calendar.months.is_empty());
+        // An unreadable publish date leaves a draft undated
+        let undated: Vec<&str> = calendar
+            .undated_drafts
+            .iter()
+            .map(|draft| draft.title.as_str())
+            .collect();
+        assert_eq!(undated, vec!["Draft"]);
+    }
+
+    #[test]
+    fn test_draft_status() {
+        let temp = TempDir::new().unwrap();
+        let blog = temp.path().join("blog");
+        fs::create_dir_all(&blog).unwrap();
+        fs::write(
+            blog.join("live.md"),
+            "---\ntitle: Live\npubDate: 2024-02-01\ndraft: false\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            blog.join("queued.md"),
+            "---\ntitle: Queued\npubDate: 2024-02-10\ndraft: true\n---\n",
+        )
+        .unwrap();
+        fs::write(blog.join("loose.md"), "---\ntitle: Loose\n---\n").unwrap();
+        fs::write(
+            blog.join("b-idea.md"),
+            "---\ntitle: B Idea\ndraft: true\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            blog.join("a-idea.md"),
+            "---\ntitle: A Idea\ndraft: true\n---\n",
+        )
+        .unwrap();
+
+        let today = NaiveDate::from_ymd_opt(2024, 2, 5).unwrap();
+        let collection = Collection::new("blog".to_string(), blog);
+        let calendar = build_calendar(&[collection], &CalendarFields::default(), today);
+
+        let february: Vec<(&str, bool, bool)> = calendar.months[0]
+            .items
+            .iter()
+            .map(|item| (item.title.as_str(), item.is_draft, item.scheduled))
+            .collect();
+        assert_eq!(
+            february,
+            vec![("Live", false, false), ("Queued", true, true)]
+        );
+
+        // Only drafts without a publish date, by title; non-drafts without dates are left out
+        let undated: Vec<&str> = calendar
+            .undated_drafts
+            .iter()
+            .map(|draft| draft.title.as_str())
+            .collect();
+        assert_eq!(undated, vec!["A Idea", "B Idea"]);
+    }
+
+    #[test]
+    fn test_configured_field_mappings() {
+        let temp = TempDir::new().unwrap();
+        let posts = temp.path().join("posts");
+        fs::create_dir_all(&posts).unwrap();
+        fs::write(
+            posts.join("launch.md"),
+            "---\nheading: Launch\nreleased: 2024-05-01\nhidden: true\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            posts.join("someday.md"),
+            "---\nheading: Someday\nhidden: true\n---\n",
+        )
+        .unwrap();
+
+        let fields = CalendarFields {
+            title: Some("heading".to_string()),
+            published_date: vec!["released".to_string()],
+            draft: Some("hidden".to_string()),
+        };
+        let today = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+        let collection = Collection::new("posts".to_string(), posts.clone());
+        let calendar = build_calendar(&[collection], &fields, today);
+
+        assert_eq!(calendar.months.len(), 1);
+        let launch = &calendar.months[0].items[0];
+        assert_eq!(launch.title, "Launch");
+        assert_eq!(launch.field, "released");
+        assert_eq!(launch.kind, CalendarDateKind::Published);
+        assert!(launch.is_draft);
+        assert!(launch.scheduled);
+        assert_eq!(
+            calendar.undated_drafts,
+            vec![UndatedDraft {
+                file_path: posts.join("someday.md").to_string_lossy().to_string(),
+                collection: "posts".to_string(),
+                title: "Someday".to_string(),
+            }]
+        );
+
+        // With a schema, the mapped field is a publish date when it's declared as a date
+        let schema = serde_json::json!({
+            "collectionName": "posts",
+            "fields": [
+                { "name": "heading", "label": "Heading", "fieldType": "string", "required": true },
+                { "name": "released", "label": "Released", "fieldType": "date", "required": false },
+                { "name": "hidden", "label": "Hidden", "fieldType": "boolean", "required": false },
+            ],
+        });
+        let mut collection = Collection::new("posts".to_string(), posts);
+        collection.complete_schema = Some(schema.to_string());
+        let calendar = build_calendar(&[collection], &fields, today);
+        assert_eq!(
+            calendar.months[0].items[0].kind,
+            CalendarDateKind::Published
+        );
+        assert_eq!(calendar.undated_drafts.len(), 1);
+
+        // Unmapped and without a schema, `released` isn't read as a date
+        let calendar = build_calendar(
+            &[Collection::new(
+                "posts".to_string(),
+                temp.path().join("posts"),
+            )],
+            &CalendarFields::default(),
+            today,
+        );
+        assert!(calendar.months.is_empty());
+    }
+}
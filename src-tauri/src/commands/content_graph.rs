@@ -0,0 +1,584 @@
+//! Content dependency graph export
+//!
+//! Builds a graph of every entry in a project, connected by schema `reference()`
+//! fields, internal body links and shared series, and renders it as Graphviz DOT or
+//! JSON. Clusters show how content hangs together; isolated entries are listed so
+//! they can be linked up.
+
+use crate::commands::files::parse_frontmatter_internal;
+use crate::commands::project::scan_project_with_content_dir;
+use crate::commands::publish_gates::{entry_slug, link_targets, slug_from_url};
+use crate::models::{Collection, FileEntry};
+use crate::schema_merger::SchemaDefinition;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Output format for the exported graph
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum GraphFormat {
+    Dot,
+    Json,
+}
+
+/// Why two entries are connected
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "camelCase")]
+pub enum GraphEdgeKind {
+    /// A schema `reference()` field points at the target
+    Reference,
+    /// The body links to the target
+    Link,
+    /// Consecutive entries in the same series
+    Series,
+}
+
+/// An entry in the graph
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphNode {
+    /// Entry ID, e.g. `blog/first-post`
+    pub id: String,
+    pub label: String,
+    pub collection: String,
+    /// Index of the connected cluster the entry belongs to; 0 is the largest
+    pub cluster: u32,
+}
+
+/// A connection between two entries
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphEdge {
+    pub from: String,
+    pub to: String,
+    pub kind: GraphEdgeKind,
+    /// The reference field or series name, if any
+    pub detail: Option<String>,
+}
+
+/// The project's content graph, with the rendered export
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+    pub cluster_count: u32,
+    /// Entries with no connections at all
+    pub isolated: Vec<String>,
+    /// The graph as DOT or JSON
+    pub output: String,
+}
+
+pub(crate) struct Entry {
+    pub(crate) id: String,
+    pub(crate) collection: String,
+    pub(crate) path: PathBuf,
+    pub(crate) frontmatter: IndexMap<String, Value>,
+    pub(crate) body: String,
+    /// Lines in the file before the body (frontmatter and MDX imports)
+    pub(crate) body_offset: usize,
+}
+
+/// Reads every entry once, assigning files in nested collections to the innermost one
+pub(crate) fn read_entries(collections: &[Collection]) -> Vec<Entry> {
+    let mut by_depth: Vec<&Collection> = collections.iter().collect();
+    by_depth.sort_by_key(|c| std::cmp::Reverse(c.path.as_os_str().len()));
+
+    let mut seen = BTreeSet::new();
+    let mut entries = Vec::new();
+    for collection in by_depth {
+        let files = WalkDir::new(&collection.path)
+            .follow_links(false)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                entry.file_type().is_file()
+                    && matches!(
+                        entry.path().extension().and_then(|e| e.to_str()),
+                        Some("md") | Some("mdx")
+                    )
+            });
+        for file in files {
+            if !seen.insert(file.path().to_path_buf()) {
+                continue;
+            }
+            let Ok(content) = std::fs::read_to_string(file.path()) else {
+                continue;
+            };
+            let Ok(parsed) = parse_frontmatter_internal(&content) else {
+                continue;
+            };
+            let id = FileEntry::new(
+                file.path().to_path_buf(),
+                collection.name.clone(),
+                collection.path.clone(),
+            )
+            .id;
+            // The body is the end of the file, so the lines before it are the difference
+            let body_offset = content
+                .lines()
+                .count()
+                .saturating_sub(parsed.content.lines().count());
+            entries.push(Entry {
+                id,
+                collection: collection.name.clone(),
+                path: file.path().to_path_buf(),
+                frontmatter: parsed.frontmatter,
+                body: parsed.content,
+                body_offset,
+            });
+        }
+    }
+    entries.sort_by(|a, b| a.id.cmp(&b.id));
+    entries
+}
+
+/// Top-level reference fields per collection, as `(field, target collection)`
+pub(crate) fn reference_fields(
+    collections: &[Collection],
+) -> HashMap<String, Vec<(String, String)>> {
+    collections
+        .iter()
+        .filter_map(|collection| {
+            let schema: SchemaDefinition =
+                serde_json::from_str(collection.complete_schema.as_deref()?).ok()?;
+            let fields = schema
+                .fields
+                .into_iter()
+                .filter(|field| !field.is_nested.unwrap_or(false))
+                .filter_map(|field| {
+                    let target = field
+                        .reference_collection
+                        .or(field.array_reference_collection)?;
+                    Some((field.name, target))
+                })
+                .collect();
+            Some((collection.name.clone(), fields))
+        })
+        .collect()
+}
+
+/// Entry IDs a reference value points at: `"id"`, `{ collection, id }` or a list of them
+pub(crate) fn referenced_ids(value: &Value, target_collection: &str) -> Vec<(String, String)> {
+    match value {
+        Value::String(id) => vec![(target_collection.to_string(), id.clone())],
+        Value::Object(map) => {
+            let collection = map
+                .get("collection")
+                .and_then(Value::as_str)
+                .unwrap_or(target_collection);
+            map.get("id")
+                .or_else(|| map.get("slug"))
+                .and_then(Value::as_str)
+                .map(|id| vec![(collection.to_string(), id.to_string())])
+                .unwrap_or_default()
+        }
+        Value::Array(items) => items
+            .iter()
+            .flat_map(|item| referenced_ids(item, target_collection))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Finds connected clusters, numbered largest first, and returns each node's cluster
+fn clusters(ids: &[String], edges: &[GraphEdge]) -> HashMap<String, u32> {
+    let index: HashMap<&str, usize> = ids
+        .iter()
+        .enumerate()
+        .map(|(i, id)| (id.as_str(), i))
+        .collect();
+    let mut parent: Vec<usize> = (0..ids.len()).collect();
+    fn root(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+    for edge in edges {
+        let a = root(&mut parent, index[edge.from.as_str()]);
+        let b = root(&mut parent, index[edge.to.as_str()]);
+        parent[a] = b;
+    }
+
+    let mut members: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for i in 0..ids.len() {
+        members.entry(root(&mut parent, i)).or_default().push(i);
+    }
+    let mut groups: Vec<Vec<usize>> = members.into_values().collect();
+    // Largest first
+    groups.sort_by_key(|group| std::cmp::Reverse(group.len()));
+
+    groups
+        .into_iter()
+        .enumerate()
+        .flat_map(|(cluster, group)| {
+            group
+                .into_iter()
+                .map(move |i| (ids[i].clone(), cluster as u32))
+        })
+        .collect()
+}
+
+/// Looks entries up by `(collection, id or slug)` and by canonical path
+pub(crate) struct EntryIndex {
+    by_key: HashMap<(String, String), String>,
+    by_path: HashMap<PathBuf, String>,
+}
+
+impl EntryIndex {
+    pub(crate) fn new(entries: &[Entry], collections: &[Collection]) -> Self {
+        let collection_paths: HashMap<&str, &Path> = collections
+            .iter()
+            .map(|c| (c.name.as_str(), c.path.as_path()))
+            .collect();
+
+        let mut by_key = HashMap::new();
+        let mut by_path = HashMap::new();
+        for entry in entries {
+            let collection_path = collection_paths[entry.collection.as_str()];
+            let slug = entry_slug(&entry.path, collection_path, &entry.frontmatter);
+            by_key.insert((entry.collection.clone(), slug), entry.id.clone());
+            by_key.insert(
+                (entry.collection.clone(), local_id(entry).to_string()),
+                entry.id.clone(),
+            );
+            if let Ok(canonical) = std::fs::canonicalize(&entry.path) {
+                by_path.insert(canonical, entry.id.clone());
+            }
+        }
+        Self { by_key, by_path }
+    }
+
+    /// The entry with an ID or slug in a collection
+    pub(crate) fn get(&self, collection: &str, id_or_slug: &str) -> Option<&String> {
+        self.by_key
+            .get(&(collection.to_string(), id_or_slug.to_string()))
+    }
+
+    /// The entry at a path, if it's one of the indexed entries
+    pub(crate) fn at_path(&self, path: &Path) -> Option<&String> {
+        self.by_path.get(&std::fs::canonicalize(path).ok()?)
+    }
+
+    /// The entry a Markdown link target points at: a relative path to the entry's file,
+    /// or a site-absolute URL matching a collection's URL pattern
+    pub(crate) fn resolve_link(
+        &self,
+        from: &Path,
+        target: &str,
+        url_patterns: &HashMap<String, String>,
+    ) -> Option<&String> {
+        let path = target.split(['#', '?']).next().unwrap_or_default();
+        if path.starts_with('/') {
+            url_patterns.iter().find_map(|(collection, pattern)| {
+                self.get(collection, slug_from_url(path, pattern)?)
+            })
+        } else if path.is_empty() || target.contains(':') {
+            None
+        } else {
+            let base = from.parent().unwrap_or(Path::new(""));
+            self.at_path(&base.join(path.replace("%20", " ")))
+        }
+    }
+}
+
+/// An entry's ID within its collection (`first-post` for `blog/first-post`)
+pub(crate) fn local_id(entry: &Entry) -> &str {
+    entry
+        .id
+        .strip_prefix(&format!("{}/", entry.collection))
+        .unwrap_or(&entry.id)
+}
+
+fn build_graph(
+    collections: &[Collection],
+    title_field: &str,
+    series_field: &str,
+    url_patterns: &HashMap<String, String>,
+) -> (Vec<GraphNode>, Vec<GraphEdge>) {
+    let entries = read_entries(collections);
+    let references = reference_fields(collections);
+    let index = EntryIndex::new(&entries, collections);
+
+    let mut edges: BTreeSet<(String, String, GraphEdgeKind, Option<String>)> = BTreeSet::new();
+    let mut series: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for entry in &entries {
+        for (field, target_collection) in references.get(&entry.collection).into_iter().flatten() {
+            let Some(value) = entry.frontmatter.get(field) else {
+                continue;
+            };
+            for (collection, id) in referenced_ids(value, target_collection) {
+                if let Some(target) = index.get(&collection, &id) {
+                    edges.insert((
+                        entry.id.clone(),
+                        target.clone(),
+                        GraphEdgeKind::Reference,
+                        Some(field.clone()),
+                    ));
+                }
+            }
+        }
+
+        for target in link_targets(&entry.body) {
+            if let Some(linked) = index.resolve_link(&entry.path, &target, url_patterns) {
+                edges.insert((entry.id.clone(), linked.clone(), GraphEdgeKind::Link, None));
+            }
+        }
+
+        if let Some(name) = entry.frontmatter.get(series_field).and_then(Value::as_str) {
+            series
+                .entry(name.to_string())
+                .or_default()
+                .push(entry.id.clone());
+        }
+    }
+
+    // Series members are chained in ID order
+    for (name, members) in &series {
+        for pair in members.windows(2) {
+            edges.insert((
+                pair[0].clone(),
+                pair[1].clone(),
+                GraphEdgeKind::Series,
+                Some(name.clone()),
+            ));
+        }
+    }
+
+    let edges: Vec<GraphEdge> = edges
+        .into_iter()
+        .filter(|(from, to, ..)| from != to)
+        .map(|(from, to, kind, detail)| GraphEdge {
+            from,
+            to,
+            kind,
+            detail,
+        })
+        .collect();
+
+    let ids: Vec<String> = entries.iter().map(|e| e.id.clone()).collect();
+    let cluster_of = clusters(&ids, &edges);
+    let nodes = entries
+        .into_iter()
+        .map(|entry| GraphNode {
+            label: entry
+                .frontmatter
+                .get(title_field)
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| entry.id.clone()),
+            cluster: cluster_of[&entry.id],
+            id: entry.id,
+            collection: entry.collection,
+        })
+        .collect();
+
+    (nodes, edges)
+}
+
+fn dot_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Renders the graph as Graphviz DOT, one subgraph per collection
+fn render_dot(nodes: &[GraphNode], edges: &[GraphEdge]) -> String {
+    let mut dot =
+        String::from("digraph content {\n  rankdir=LR;\n  node [shape=box, style=rounded];\n");
+
+    let mut by_collection: BTreeMap<&str, Vec<&GraphNode>> = BTreeMap::new();
+    for node in nodes {
+        by_collection
+            .entry(&node.collection)
+            .or_default()
+            .push(node);
+    }
+    for (collection, members) in by_collection {
+        dot.push_str(&format!(
+            "  subgraph {} {{\n    label={};\n",
+            dot_string(&format!("cluster_{collection}")),
+            dot_string(collection)
+        ));
+        for node in members {
+            dot.push_str(&format!(
+                "    {} [label={}];\n",
+                dot_string(&node.id),
+                dot_string(&node.label)
+            ));
+        }
+        dot.push_str("  }\n");
+    }
+
+    for edge in edges {
+        let style = match edge.kind {
+            GraphEdgeKind::Reference => "solid",
+            GraphEdgeKind::Link => "dashed",
+            GraphEdgeKind::Series => "bold",
+        };
+        let label = edge
+            .detail
+            .as_deref()
+            .map(|detail| format!(", label={}", dot_string(detail)))
+            .unwrap_or_default();
+        dot.push_str(&format!(
+            "  {} -> {} [style={style}{label}];\n",
+            dot_string(&edge.from),
+            dot_string(&edge.to)
+        ));
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Exports the project's content graph for visualization
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+/// * `format` - Whether `output` is DOT or JSON
+/// * `title_field` - Frontmatter field used for node labels
+/// * `series_field` - Frontmatter field naming an entry's series
+/// * `url_patterns` - Collection URL patterns (e.g. `{ "blog": "/writing/{slug}" }`) for
+///   resolving site-absolute links
+#[tauri::command]
+#[specta::specta]
+pub async fn export_content_graph(
+    project_path: String,
+    content_directory: Option<String>,
+    format: GraphFormat,
+    title_field: String,
+    series_field: String,
+    url_patterns: HashMap<String, String>,
+) -> Result<ContentGraph, String> {
+    let collections = scan_project_with_content_dir(project_path, content_directory, None).await?;
+
+    tokio::task::spawn_blocking(move || {
+        let (nodes, edges) = build_graph(&collections, &title_field, &series_field, &url_patterns);
+
+        let connected: BTreeSet<&str> = edges
+            .iter()
+            .flat_map(|e| [e.from.as_str(), e.to.as_str()])
+            .collect();
+        let isolated = nodes
+            .iter()
+            .filter(|n| !connected.contains(n.id.as_str()))
+            .map(|n| n.id.clone())
+            .collect();
+        let cluster_count = nodes.iter().map(|n| n.cluster + 1).max().unwrap_or(0);
+
+        let output = match format {
+            GraphFormat::Dot => render_dot(&nodes, &edges),
+            GraphFormat::Json => {
+                serde_json::to_string_pretty(&serde_json::json!({ "nodes": nodes, "edges": edges }))
+                    .map_err(|e| format!("Failed to serialize graph: {e}"))?
+            }
+        };
+
+        log::info!(
+            "Astro Editor [CONTENT_GRAPH] Exported {} entries, {} connections, {cluster_count} clusters",
+            nodes.len(),
+            edges.len()
+        );
+
+        Ok(ContentGraph {
+            nodes,
+            edges,
+            cluster_count,
+            isolated,
+            output,
+        })
+    })
+    .await
+    .map_err(|e| format!("Failed to build content graph: {e}"))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn project() -> (TempDir, Vec<Collection>) {
+        let temp = TempDir::new().unwrap();
+        let blog = temp.path().join("blog");
+        let authors = temp.path().join("authors");
+        fs::create_dir_all(&blog).unwrap();
+        fs::create_dir_all(&authors).unwrap();
+
+        fs::write(authors.join("ada.md"), "---\nname: Ada\n---\n").unwrap();
+        fs::write(
+            blog.join("one.md"),
+            "---\ntitle: One\nauthor: ada\nseries: intro\n---\n\nSee [two](/writing/two/).\n",
+        )
+        .unwrap();
+        fs::write(
+            blog.join("two.md"),
+            "---\ntitle: Two\nseries: intro\n---\n\nBack to [one](./one.md).\n",
+        )
+        .unwrap();
+        fs::write(blog.join("lonely.md"), "---\ntitle: Lonely\n---\n").unwrap();
+
+        let schema = r#"{"collectionName":"blog","fields":[{"name":"author","label":"Author","fieldType":"reference","required":false,"referenceCollection":"authors"}]}"#;
+        let collections = vec![
+            Collection::new("blog".to_string(), blog).with_complete_schema(schema.to_string()),
+            Collection::new("authors".to_string(), authors),
+        ];
+        (temp, collections)
+    }
+
+    #[test]
+    fn test_build_graph() {
+        let (_temp, collections) = project();
+        let patterns = HashMap::from([("blog".to_string(), "/writing/{slug}".to_string())]);
+        let (nodes, edges) = build_graph(&collections, "title", "series", &patterns);
+
+        let summary: Vec<(&str, &str, GraphEdgeKind)> = edges
+            .iter()
+            .map(|e| (e.from.as_str(), e.to.as_str(), e.kind))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("blog/one", "authors/ada", GraphEdgeKind::Reference),
+                ("blog/one", "blog/two", GraphEdgeKind::Link),
+                ("blog/one", "blog/two", GraphEdgeKind::Series),
+                ("blog/two", "blog/one", GraphEdgeKind::Link),
+            ]
+        );
+
+        let lonely = nodes.iter().find(|n| n.id == "blog/lonely").unwrap();
+        let one = nodes.iter().find(|n| n.id == "blog/one").unwrap();
+        assert_eq!(one.label, "One");
+        assert_eq!(one.cluster, 0);
+        assert_eq!(lonely.cluster, 1);
+    }
+
+    #[test]
+    fn test_render_dot() {
+        let nodes = vec![GraphNode {
+            id: "blog/a".to_string(),
+            label: "Say \"hi\"".to_string(),
+            collection: "blog".to_string(),
+            cluster: 0,
+        }];
+        let edges = vec![GraphEdge {
+            from: "blog/a".to_string(),
+            to: "blog/a".to_string(),
+            kind: GraphEdgeKind::Reference,
+            detail: Some("related".to_string()),
+        }];
+
+        let dot = render_dot(&nodes, &edges);
+        assert!(dot.contains("subgraph \"cluster_blog\" {"));
+        assert!(dot.contains("\"blog/a\" [label=\"Say \\\"hi\\\"\"];"));
+        assert!(dot.contains("\"blog/a\" -> \"blog/a\" [style=solid, label=\"related\"];"));
+    }
+}
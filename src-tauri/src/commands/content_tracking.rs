@@ -0,0 +1,245 @@
+//! Unsaved-change tracking for the autosave indicator
+//!
+//! The frontend reports editor content once typing pauses; reports are coalesced per
+//! file and diffed word-by-word against the last saved body, then emitted as
+//! `content-modified` events. The baseline is whatever is on disk, re-read whenever the
+//! file's modification time changes, so saves from the editor and external edits both
+//! reset the counts.
+
+use crate::commands::files::parse_frontmatter_internal;
+use crate::security::validate_project_path;
+use serde::Serialize;
+use similar::{ChangeTag, TextDiff};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter, Manager};
+
+// Tracked files keyed by absolute path
+pub type ContentTrackerMap = Arc<Mutex<HashMap<String, TrackedFile>>>;
+
+/// Reports arriving within this window are diffed once, against the latest content
+const COALESCE_WINDOW: Duration = Duration::from_millis(750);
+
+/// Payload of the `content-modified` event
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentModifiedEvent {
+    pub file_path: String,
+    pub words_added: u32,
+    pub words_removed: u32,
+    /// When the file was last written, in milliseconds since the Unix epoch
+    pub last_saved_at: f64,
+    pub seconds_since_save: f64,
+}
+
+/// Last saved body of a file plus any content waiting to be diffed
+#[derive(Debug, Default)]
+pub struct TrackedFile {
+    /// The file's path once validated against its project
+    path: PathBuf,
+    baseline: Option<Baseline>,
+    pending: Option<String>,
+    flush_scheduled: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Baseline {
+    body: String,
+    modified: SystemTime,
+}
+
+/// Counts words inserted into and deleted from `old` to produce `new`
+fn word_changes(old: &str, new: &str) -> (u32, u32) {
+    let diff = TextDiff::from_words(old, new);
+    let mut added = 0u32;
+    let mut removed = 0u32;
+
+    for change in diff.iter_all_changes() {
+        if change.value().trim().is_empty() {
+            continue;
+        }
+        match change.tag() {
+            ChangeTag::Insert => added += 1,
+            ChangeTag::Delete => removed += 1,
+            ChangeTag::Equal => {}
+        }
+    }
+
+    (added, removed)
+}
+
+/// Reads the on-disk body (without frontmatter and imports) and its modification time
+fn read_baseline(file_path: &Path) -> Result<Baseline, String> {
+    let modified = std::fs::metadata(file_path)
+        .and_then(|m| m.modified())
+        .map_err(|e| format!("Failed to read file metadata: {e}"))?;
+    let content =
+        std::fs::read_to_string(file_path).map_err(|e| format!("Failed to read file: {e}"))?;
+    let body = parse_frontmatter_internal(&content)?.content;
+
+    Ok(Baseline { body, modified })
+}
+
+fn build_event(
+    file_path: &str,
+    baseline: &Baseline,
+    content: &str,
+    now: SystemTime,
+) -> ContentModifiedEvent {
+    let (words_added, words_removed) = word_changes(&baseline.body, content);
+    let last_saved_at = baseline
+        .modified
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as f64)
+        .unwrap_or(0.0);
+    let seconds_since_save = now
+        .duration_since(baseline.modified)
+        .map(|d| d.as_secs_f64())
+        .unwrap_or(0.0);
+
+    ContentModifiedEvent {
+        file_path: file_path.to_string(),
+        words_added,
+        words_removed,
+        last_saved_at,
+        seconds_since_save,
+    }
+}
+
+/// Diffs the latest pending content for a file and emits `content-modified`
+fn flush(app: &AppHandle, file_path: &str) {
+    let tracker = app.state::<ContentTrackerMap>();
+
+    let (path, content, cached) = {
+        let mut files = tracker.lock().unwrap();
+        let Some(tracked) = files.get_mut(file_path) else {
+            return;
+        };
+        tracked.flush_scheduled = false;
+        let Some(content) = tracked.pending.take() else {
+            return;
+        };
+        (tracked.path.clone(), content, tracked.baseline.clone())
+    };
+
+    // Refresh the baseline if the file was written since we last read it
+    let current_modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+    let baseline = match cached {
+        Some(baseline) if Some(baseline.modified) == current_modified => baseline,
+        _ => match read_baseline(&path) {
+            Ok(baseline) => {
+                if let Some(tracked) = tracker.lock().unwrap().get_mut(file_path) {
+                    tracked.baseline = Some(baseline.clone());
+                }
+                baseline
+            }
+            Err(e) => {
+                log::debug!("Astro Editor [CONTENT_TRACKING] {e}");
+                return;
+            }
+        },
+    };
+
+    let event = build_event(file_path, &baseline, &content, SystemTime::now());
+    if let Err(e) = app.emit("content-modified", event) {
+        log::warn!("Astro Editor [CONTENT_TRACKING] Failed to emit content-modified: {e}");
+    }
+}
+
+/// Reports the editor's current body for a file
+///
+/// Called when typing pauses rather than on every change, since each report sends the
+/// whole body; reports are also coalesced and only the latest content in each window is
+/// diffed. Call again after a save to emit a reset (zero-change) event.
+///
+/// # Arguments
+/// * `file_path` - The absolute path to the file being edited
+/// * `content` - The editor body (without frontmatter and imports)
+/// * `project_root` - The project the file must be in
+#[tauri::command]
+#[specta::specta]
+pub async fn report_content_changed(
+    app: AppHandle,
+    file_path: String,
+    content: String,
+    project_root: String,
+) -> Result<(), String> {
+    let validated_path = validate_project_path(&file_path, &project_root)?;
+    let tracker = app.state::<ContentTrackerMap>();
+    let mut files = tracker.lock().unwrap();
+
+    // Only the open file matters; drop idle state for files that were closed
+    files.retain(|path, tracked| path == &file_path || tracked.flush_scheduled);
+
+    let tracked = files.entry(file_path.clone()).or_default();
+    tracked.path = validated_path;
+    tracked.pending = Some(content);
+
+    if !tracked.flush_scheduled {
+        tracked.flush_scheduled = true;
+        let app = app.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(COALESCE_WINDOW).await;
+            let _ = tokio::task::spawn_blocking(move || flush(&app, &file_path)).await;
+        });
+    }
+
+    Ok(())
+}
+
+// Initialize the content tracker when the app starts
+pub fn init_content_tracker() -> ContentTrackerMap {
+    Arc::new(Mutex::new(HashMap::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_word_changes() {
+        assert_eq!(word_changes("one two three", "one two three"), (0, 0));
+        assert_eq!(
+            word_changes("one two three", "one two three four five"),
+            (2, 0)
+        );
+        assert_eq!(word_changes("one two three", "one three"), (0, 1));
+        assert_eq!(word_changes("the quick fox", "the slow fox"), (1, 1));
+        // Whitespace-only changes don't count
+        assert_eq!(word_changes("one two", "one\n\ntwo"), (0, 0));
+    }
+
+    #[test]
+    fn test_read_baseline_strips_frontmatter() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("post.md");
+        fs::write(&path, "---\ntitle: Hello\n---\n\nBody words here\n").unwrap();
+
+        let baseline = read_baseline(&path).unwrap();
+        assert_eq!(baseline.body.trim(), "Body words here");
+    }
+
+    #[test]
+    fn test_build_event_reports_time_since_save() {
+        let saved = UNIX_EPOCH + Duration::from_secs(1_000);
+        let baseline = Baseline {
+            body: "alpha beta".to_string(),
+            modified: saved,
+        };
+
+        let event = build_event(
+            "/p/post.md",
+            &baseline,
+            "alpha beta gamma",
+            saved + Duration::from_secs(12),
+        );
+        assert_eq!(event.words_added, 1);
+        assert_eq!(event.words_removed, 0);
+        assert_eq!(event.last_saved_at, 1_000_000.0);
+        assert_eq!(event.seconds_since_save, 12.0);
+    }
+}
@@ -0,0 +1,374 @@
+//! Crash reports saved when a critical operation fails
+//!
+//! The frontend sends what it knows about the failure (error, stack, the action being
+//! performed); `save_crash_report` adds a snapshot of backend state under `backend` so
+//! a report can be diagnosed without the user's logs. Paths are hashed rather than
+//! included, in the snapshot and in the log lines alike, so reports can be shared
+//! without revealing project or file names.
+
+use chrono::Local;
+use regex::{Captures, Regex};
+use serde::Serialize;
+use serde_json::Value;
+use specta::Type;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Manager};
+
+use crate::commands::bulk_undo::content_hash;
+use crate::commands::intents::IntentQueue;
+use crate::commands::processes::ProcessRegistryState;
+use crate::commands::watcher::WatcherStatsMap;
+
+/// Log lines included in each report
+const LOG_TAIL_LINES: usize = 50;
+
+/// Watcher counters for one open project
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct WatcherSnapshot {
+    project_id: String,
+    processed_events: u32,
+    suppressed_events: u32,
+}
+
+/// Backend state at the time of a crash report
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BackendSnapshot {
+    app_version: String,
+    platform: String,
+    /// Hashes of the open projects' paths
+    project_ids: Vec<String>,
+    /// Hash of the open file's path
+    open_file_id: Option<String>,
+    watchers: Vec<WatcherSnapshot>,
+    pending_intents: usize,
+    running_processes: usize,
+    /// Recent log lines, with paths replaced by their hashes
+    log_tail: Vec<String>,
+}
+
+/// A saved crash report, for listing on a debug screen
+#[derive(Debug, Clone, Serialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CrashReportSummary {
+    pub file_name: String,
+    /// When the report was written, as `YYYY-MM-DD HH:MM:SS` local time
+    pub timestamp: String,
+    /// The error message, if the report has one
+    pub error: Option<String>,
+    /// The action that failed, e.g. "save"
+    pub action: Option<String>,
+    /// The full report, including the backend snapshot
+    pub report: Value,
+}
+
+/// A non-reversible identifier for a path, the same across runs and builds
+fn path_id(path: &str) -> String {
+    content_hash(path)
+}
+
+/// Replaces each path in a log line with `<path ID>`, using the ID of `path_id` so
+/// paths in the log can still be matched against the snapshot's
+fn redact_paths(line: &str) -> String {
+    static PATH: OnceLock<Regex> = OnceLock::new();
+    PATH.get_or_init(|| {
+        // A token containing a separator, starting a word; URLs start after `:`
+        Regex::new(r#"(^|[\s"'(=\[])((?:[A-Za-z]:)?[~.\w-]*[/\\][^\s"'()\[\],;:]*)"#).unwrap()
+    })
+    .replace_all(line, |caps: &Captures| {
+        format!("{}<path {}>", &caps[1], path_id(&caps[2]))
+    })
+    .into_owned()
+}
+
+fn crash_reports_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .resolve("crash-reports", BaseDirectory::AppLocalData)
+        .map_err(|e| format!("Failed to resolve crash reports directory: {e}"))
+}
+
+/// The last `count` lines of the most recently written log file in `log_dir`, with
+/// paths redacted
+fn read_log_tail(log_dir: &Path, count: usize) -> Vec<String> {
+    let newest_log = std::fs::read_dir(log_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "log"))
+        .filter_map(|entry| {
+            let modified = entry.metadata().ok()?.modified().ok()?;
+            Some((modified, entry.path()))
+        })
+        .max_by_key(|(modified, _)| *modified);
+
+    let Some((_, path)) = newest_log else {
+        return Vec::new();
+    };
+    let Ok(bytes) = std::fs::read(&path) else {
+        return Vec::new();
+    };
+
+    let content = String::from_utf8_lossy(&bytes);
+    let lines: Vec<&str> = content.lines().collect();
+    lines[lines.len().saturating_sub(count)..]
+        .iter()
+        .map(|line| redact_paths(line))
+        .collect()
+}
+
+fn backend_snapshot(app: &AppHandle) -> BackendSnapshot {
+    let mut watchers: Vec<WatcherSnapshot> = app
+        .state::<WatcherStatsMap>()
+        .lock()
+        .map(|stats| {
+            stats
+                .iter()
+                .map(|(project_path, stats)| WatcherSnapshot {
+                    project_id: path_id(project_path),
+                    processed_events: stats.processed_events,
+                    suppressed_events: stats.suppressed_events,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    watchers.sort_by(|a, b| a.project_id.cmp(&b.project_id));
+
+    let mut project_ids: Vec<String> = crate::commands::watcher::watched_project_paths(app)
+        .iter()
+        .map(|path| path_id(&path.to_string_lossy()))
+        .collect();
+    project_ids.sort();
+
+    let pending_intents = app
+        .state::<IntentQueue>()
+        .lock()
+        .map(|intents| intents.len())
+        .unwrap_or_default();
+    let running_processes = app
+        .state::<ProcessRegistryState>()
+        .lock()
+        .map(|registry| registry.running_count())
+        .unwrap_or_default();
+
+    let log_tail = app
+        .path()
+        .app_log_dir()
+        .map(|dir| read_log_tail(&dir, LOG_TAIL_LINES))
+        .unwrap_or_default();
+
+    BackendSnapshot {
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        platform: std::env::consts::OS.to_string(),
+        project_ids,
+        open_file_id: crate::commands::window::current_document().map(|path| path_id(&path)),
+        watchers,
+        pending_intents,
+        running_processes,
+        log_tail,
+    }
+}
+
+/// Adds the backend snapshot to a frontend report under `backend`
+fn enrich_report(report: Value, snapshot: &BackendSnapshot) -> Value {
+    let backend = serde_json::to_value(snapshot).unwrap_or(Value::Null);
+    match report {
+        Value::Object(mut fields) => {
+            fields.insert("backend".to_string(), backend);
+            Value::Object(fields)
+        }
+        other => serde_json::json!({ "report": other, "backend": backend }),
+    }
+}
+
+/// Reads a saved report into a summary
+fn summarize_report(path: &Path) -> Option<CrashReportSummary> {
+    let file_name = path.file_name()?.to_string_lossy().to_string();
+    let stamp = file_name.strip_suffix("-crash.json")?;
+    let timestamp = chrono::NaiveDateTime::parse_from_str(stamp, "%Y%m%d-%H%M%S")
+        .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|_| stamp.to_string());
+
+    let content = std::fs::read_to_string(path).ok()?;
+    let report: Value = serde_json::from_str(&content).ok()?;
+    let text = |pointer: &str| {
+        report
+            .pointer(pointer)
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+
+    Some(CrashReportSummary {
+        error: text("/error"),
+        action: text("/context/action"),
+        file_name,
+        timestamp,
+        report,
+    })
+}
+
+/// Saved crash reports in `dir`, newest first
+fn list_reports_in(dir: &Path, limit: usize) -> Vec<CrashReportSummary> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.to_string_lossy().ends_with("-crash.json"))
+        .collect();
+    // Names start with a sortable timestamp
+    paths.sort();
+    paths.reverse();
+
+    paths
+        .iter()
+        .filter_map(|path| summarize_report(path))
+        .take(limit)
+        .collect()
+}
+
+/// Saves a crash report with a snapshot of backend state appended
+///
+/// # Arguments
+/// * `report` - The frontend's report (`error`, `stack`, `context`, ...)
+#[tauri::command]
+#[specta::specta]
+pub async fn save_crash_report(app: AppHandle, report: Value) -> Result<(), String> {
+    let timestamp = Local::now().format("%Y%m%d-%H%M%S").to_string();
+
+    // Create crash-reports directory
+    let crash_dir = crash_reports_dir(&app)?;
+    std::fs::create_dir_all(&crash_dir)
+        .map_err(|e| format!("Failed to create crash reports directory: {e}"))?;
+
+    // Save crash report
+    let report = enrich_report(report, &backend_snapshot(&app));
+    let file_path = crash_dir.join(format!("{timestamp}-crash.json"));
+    let content = serde_json::to_string_pretty(&report)
+        .map_err(|e| format!("Failed to serialize crash report: {e}"))?;
+
+    std::fs::write(&file_path, content)
+        .map_err(|e| format!("Failed to write crash report: {e}"))?;
+
+    Ok(())
+}
+
+/// Lists saved crash reports, newest first
+///
+/// # Arguments
+/// * `limit` - Maximum number of reports (defaults to 50)
+#[tauri::command]
+#[specta::specta]
+pub async fn list_crash_reports(
+    app: AppHandle,
+    limit: Option<u32>,
+) -> Result<Vec<CrashReportSummary>, String> {
+    let crash_dir = crash_reports_dir(&app)?;
+    Ok(list_reports_in(&crash_dir, limit.unwrap_or(50) as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn snapshot() -> BackendSnapshot {
+        BackendSnapshot {
+            app_version: "1.0.0".to_string(),
+            platform: "macos".to_string(),
+            project_ids: vec![path_id("/Users/me/blog")],
+            open_file_id: None,
+            watchers: Vec::new(),
+            pending_intents: 2,
+            running_processes: 0,
+            log_tail: vec!["last line".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_enrich_report_adds_backend_without_paths() {
+        let report = serde_json::json!({ "error": "Save failed", "context": { "action": "save" } });
+        let enriched = enrich_report(report, &snapshot());
+
+        assert_eq!(enriched["error"], "Save failed");
+        assert_eq!(enriched["backend"]["pendingIntents"], 2);
+        assert_eq!(enriched["backend"]["logTail"][0], "last line");
+        assert!(!enriched.to_string().contains("/Users/me/blog"));
+
+        let wrapped = enrich_report(Value::String("oops".to_string()), &snapshot());
+        assert_eq!(wrapped["report"], "oops");
+        assert!(wrapped["backend"].is_object());
+    }
+
+    #[test]
+    fn test_read_log_tail_uses_newest_log() {
+        let temp = TempDir::new().unwrap();
+        let lines: Vec<String> = (1..=80).map(|n| format!("line {n}")).collect();
+        std::fs::write(temp.path().join("Astro Editor.log"), lines.join("\n")).unwrap();
+        std::fs::write(temp.path().join("notes.txt"), "not a log").unwrap();
+
+        let tail = read_log_tail(temp.path(), 50);
+
+        assert_eq!(tail.len(), 50);
+        assert_eq!(tail[0], "line 31");
+        assert_eq!(tail[49], "line 80");
+        assert!(read_log_tail(&temp.path().join("missing"), 50).is_empty());
+    }
+
+    #[test]
+    fn test_read_log_tail_redacts_paths() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("Astro Editor.log"),
+            "[files] Failed to read /Users/me/blog/src/content/post.md: not found\n\
+             [files] Saved \"C:\\Users\\me\\blog\\post.md\"\n\
+             [files] Moved src/content/draft.md to ~/Desktop\n\
+             [updater] Checking https://example.com/latest.json",
+        )
+        .unwrap();
+
+        let tail = read_log_tail(temp.path(), 50).join("\n");
+
+        assert!(!tail.contains("blog"));
+        assert!(!tail.contains("draft.md"));
+        assert!(!tail.contains("Desktop"));
+        assert!(tail.contains(&format!(
+            "Failed to read <path {}>: not found",
+            path_id("/Users/me/blog/src/content/post.md")
+        )));
+        assert!(tail.contains("Checking https://example.com/latest.json"));
+    }
+
+    #[test]
+    fn test_path_id_is_stable() {
+        assert_eq!(path_id("/Users/me/blog"), path_id("/Users/me/blog"));
+        assert_ne!(path_id("/Users/me/blog"), path_id("/Users/me/notes"));
+        assert_eq!(path_id("/Users/me/blog").len(), 16);
+    }
+
+    #[test]
+    fn test_list_reports_newest_first() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("20240101-090000-crash.json"),
+            r#"{"error":"First","context":{"action":"save"}}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            temp.path().join("20240102-090000-crash.json"),
+            r#"{"error":"Second"}"#,
+        )
+        .unwrap();
+        std::fs::write(temp.path().join("20240103-090000-crash.json"), "not json").unwrap();
+
+        let reports = list_reports_in(temp.path(), 10);
+
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].error.as_deref(), Some("Second"));
+        assert_eq!(reports[0].timestamp, "2024-01-02 09:00:00");
+        assert_eq!(reports[1].action.as_deref(), Some("save"));
+        assert_eq!(list_reports_in(temp.path(), 1).len(), 1);
+    }
+}
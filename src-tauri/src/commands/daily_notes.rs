@@ -0,0 +1,419 @@
+//! Daily notes / journal mode
+//!
+//! A daily note's location comes from a filename pattern relative to the content
+//! directory, e.g. `journal/{YYYY}-{MM}-{DD}.md` or `journal/{YYYY}/{MM}/{DD}.md`.
+//! Supported tokens are `{YYYY}`, `{MM}`, `{DD}` and `{date}` (`YYYY-MM-DD`); the same
+//! tokens are substituted into note templates.
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::files::parse_frontmatter_internal;
+use crate::security::validate_project_path;
+use chrono::{Datelike, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::{Component, Path, PathBuf};
+
+/// Pattern used when the project doesn't configure one
+const DEFAULT_PATTERN: &str = "journal/{YYYY}-{MM}-{DD}.md";
+
+/// Frontmatter field the default template writes the date to
+const DEFAULT_DATE_FIELD: &str = "date";
+
+/// A daily note opened (and possibly created) by `open_daily_note`
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyNote {
+    pub file_path: String,
+    /// The note's date as `YYYY-MM-DD`
+    pub date: String,
+    /// True if the note didn't exist and was created from the template
+    pub created: bool,
+}
+
+/// A day in a month that has a daily note
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyNoteDay {
+    /// `YYYY-MM-DD`
+    pub date: String,
+    pub file_path: String,
+    /// Words in the note body, excluding frontmatter
+    pub word_count: u32,
+}
+
+/// Substitutes date tokens into a pattern or template
+fn render_date_tokens(text: &str, date: NaiveDate) -> String {
+    text.replace("{date}", &date.format("%Y-%m-%d").to_string())
+        .replace("{YYYY}", &date.format("%Y").to_string())
+        .replace("{MM}", &date.format("%m").to_string())
+        .replace("{DD}", &date.format("%d").to_string())
+}
+
+/// Renders the pattern for `date` into a path relative to the content directory
+///
+/// Rejects patterns that would escape the content directory or don't name a
+/// Markdown file.
+fn note_relative_path(pattern: &str, date: NaiveDate) -> Result<PathBuf, String> {
+    let rendered = PathBuf::from(render_date_tokens(pattern.trim(), date));
+
+    if rendered
+        .components()
+        .any(|c| !matches!(c, Component::Normal(_)))
+    {
+        return Err(format!(
+            "Daily note pattern must be a relative path without '..': {pattern}"
+        ));
+    }
+
+    match rendered.extension().and_then(|e| e.to_str()) {
+        Some("md") | Some("mdx") => Ok(rendered),
+        _ => Err(format!(
+            "Daily note pattern must end in .md or .mdx: {pattern}"
+        )),
+    }
+}
+
+fn default_template(date_field: &str) -> String {
+    format!("---\ntitle: \"{{date}}\"\n{date_field}: {{date}}\n---\n\n")
+}
+
+fn content_root(project_path: &str, content_directory: Option<&str>) -> PathBuf {
+    Path::new(project_path).join(content_directory.unwrap_or("src/content"))
+}
+
+fn parse_date(date: Option<&str>) -> Result<NaiveDate, String> {
+    match date {
+        Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|e| format!("Invalid date '{date}': {e}")),
+        None => Ok(Local::now().date_naive()),
+    }
+}
+
+fn word_count(content: &str) -> u32 {
+    let body = parse_frontmatter_internal(content)
+        .map(|parsed| parsed.content)
+        .unwrap_or_else(|_| content.to_string());
+    body.split_whitespace().count() as u32
+}
+
+/// Days in `year`/`month` that have a note, in date order
+fn calendar_for_month(
+    root: &Path,
+    pattern: &str,
+    year: i32,
+    month: u32,
+) -> Result<Vec<DailyNoteDay>, String> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1)
+        .ok_or_else(|| format!("Invalid month: {year}-{month}"))?;
+
+    let mut days = Vec::new();
+    for date in first.iter_days().take_while(|d| d.month() == month) {
+        let path = root.join(note_relative_path(pattern, date)?);
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+
+        days.push(DailyNoteDay {
+            date: date.format("%Y-%m-%d").to_string(),
+            file_path: path.to_string_lossy().to_string(),
+            word_count: word_count(&content),
+        });
+    }
+
+    Ok(days)
+}
+
+/// Where a project's daily notes go and what new ones start with
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct DailyNoteSettings {
+    /// Filename pattern relative to the content directory
+    pub pattern: Option<String>,
+    /// Template file, relative to the project root
+    pub template_path: Option<String>,
+    /// Frontmatter field for the default template's date
+    pub date_field: Option<String>,
+}
+
+/// Finds the daily note for `date`, creating it from the template if it doesn't exist
+fn daily_note(
+    project_path: &str,
+    content_directory: Option<&str>,
+    settings: &DailyNoteSettings,
+    date: NaiveDate,
+) -> Result<DailyNote, String> {
+    let pattern = settings.pattern.as_deref().unwrap_or(DEFAULT_PATTERN);
+    let note_path =
+        content_root(project_path, content_directory).join(note_relative_path(pattern, date)?);
+    let date_string = date.format("%Y-%m-%d").to_string();
+
+    if note_path.exists() {
+        let validated = validate_project_path(&note_path.to_string_lossy(), project_path)?;
+        return Ok(DailyNote {
+            file_path: validated.to_string_lossy().to_string(),
+            date: date_string,
+            created: false,
+        });
+    }
+
+    let template = match settings
+        .template_path
+        .as_deref()
+        .filter(|p| !p.trim().is_empty())
+    {
+        Some(template_path) => {
+            let validated = validate_project_path(
+                &Path::new(project_path)
+                    .join(template_path)
+                    .to_string_lossy(),
+                project_path,
+            )?;
+            std::fs::read_to_string(&validated)
+                .map_err(|e| format!("Failed to read daily note template: {e}"))?
+        }
+        None => default_template(settings.date_field.as_deref().unwrap_or(DEFAULT_DATE_FIELD)),
+    };
+
+    if let Some(parent) = note_path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create daily note directory: {e}"))?;
+    }
+    let validated = validate_project_path(&note_path.to_string_lossy(), project_path)?;
+
+    std::fs::write(&validated, render_date_tokens(&template, date))
+        .map_err(|e| format!("Failed to create daily note: {e}"))?;
+    audit_log::record(AuditAction::Create, &validated, None, "daily-notes");
+
+    log::info!(
+        "Astro Editor [DAILY_NOTES] Created daily note: {}",
+        validated.display()
+    );
+
+    Ok(DailyNote {
+        file_path: validated.to_string_lossy().to_string(),
+        date: date_string,
+        created: true,
+    })
+}
+
+/// The daily note for `date` when the pattern puts it inside `directory`, created from
+/// the template if it doesn't exist; `None` when daily notes are kept elsewhere
+pub(crate) fn daily_note_in(
+    project_path: &str,
+    content_directory: Option<&str>,
+    settings: &DailyNoteSettings,
+    directory: &Path,
+    date: NaiveDate,
+) -> Result<Option<DailyNote>, String> {
+    let pattern = settings.pattern.as_deref().unwrap_or(DEFAULT_PATTERN);
+    let relative_path = note_relative_path(pattern, date)?;
+    // The relative path has no `..`, so joining it to the canonical root is enough
+    let inside = match (
+        content_root(project_path, content_directory).canonicalize(),
+        directory.canonicalize(),
+    ) {
+        (Ok(root), Ok(directory)) => root.join(relative_path).starts_with(directory),
+        _ => false,
+    };
+    if !inside {
+        return Ok(None);
+    }
+    daily_note(project_path, content_directory, settings, date).map(Some)
+}
+
+/// Opens the daily note for a date, creating it from the template if it doesn't exist
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+/// * `pattern` - Filename pattern relative to the content directory (defaults to
+///   `journal/{YYYY}-{MM}-{DD}.md`)
+/// * `template_path` - Optional template file, relative to the project root
+/// * `date_field` - Frontmatter field for the default template's date (defaults to `date`)
+/// * `date` - `YYYY-MM-DD` to open; defaults to today
+#[tauri::command]
+#[specta::specta]
+pub async fn open_daily_note(
+    project_path: String,
+    content_directory: Option<String>,
+    pattern: Option<String>,
+    template_path: Option<String>,
+    date_field: Option<String>,
+    date: Option<String>,
+) -> Result<DailyNote, String> {
+    let date = parse_date(date.as_deref())?;
+    let settings = DailyNoteSettings {
+        pattern,
+        template_path,
+        date_field,
+    };
+    daily_note(&project_path, content_directory.as_deref(), &settings, date)
+}
+
+/// Lists the days in a month that have a daily note
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+/// * `pattern` - Filename pattern relative to the content directory
+/// * `year` - Calendar year
+/// * `month` - Month, 1-12
+#[tauri::command]
+#[specta::specta]
+pub async fn get_daily_note_calendar(
+    project_path: String,
+    content_directory: Option<String>,
+    pattern: Option<String>,
+    year: i32,
+    month: u32,
+) -> Result<Vec<DailyNoteDay>, String> {
+    calendar_for_month(
+        &content_root(&project_path, content_directory.as_deref()),
+        pattern.as_deref().unwrap_or(DEFAULT_PATTERN),
+        year,
+        month,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_note_relative_path() {
+        assert_eq!(
+            note_relative_path(DEFAULT_PATTERN, date(2025, 1, 5)).unwrap(),
+            PathBuf::from("journal/2025-01-05.md")
+        );
+        assert_eq!(
+            note_relative_path("log/{YYYY}/{MM}/{DD}.mdx", date(2025, 11, 30)).unwrap(),
+            PathBuf::from("log/2025/11/30.mdx")
+        );
+        assert!(note_relative_path("../outside/{date}.md", date(2025, 1, 5)).is_err());
+        assert!(note_relative_path("/abs/{date}.md", date(2025, 1, 5)).is_err());
+        assert!(note_relative_path("journal/{date}.txt", date(2025, 1, 5)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_open_daily_note_creates_once() {
+        let temp = TempDir::new().unwrap();
+        let project = temp.path().to_string_lossy().to_string();
+
+        let note = open_daily_note(
+            project.clone(),
+            None,
+            None,
+            None,
+            Some("pubDate".to_string()),
+            Some("2025-01-15".to_string()),
+        )
+        .await
+        .unwrap();
+        assert!(note.created);
+        assert!(note
+            .file_path
+            .ends_with("src/content/journal/2025-01-15.md"));
+        assert_eq!(
+            fs::read_to_string(&note.file_path).unwrap(),
+            "---\ntitle: \"2025-01-15\"\npubDate: 2025-01-15\n---\n\n"
+        );
+
+        fs::write(&note.file_path, "---\ntitle: kept\n---\n").unwrap();
+        let again = open_daily_note(project, None, None, None, None, Some(note.date))
+            .await
+            .unwrap();
+        assert!(!again.created);
+        assert_eq!(
+            fs::read_to_string(&again.file_path).unwrap(),
+            "---\ntitle: kept\n---\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_open_daily_note_uses_template() {
+        let temp = TempDir::new().unwrap();
+        let project = temp.path().to_string_lossy().to_string();
+        fs::write(
+            temp.path().join("daily.md"),
+            "---\ntitle: Journal {date}\n---\n\n# {DD}/{MM}/{YYYY}\n",
+        )
+        .unwrap();
+
+        let note = open_daily_note(
+            project,
+            Some("content".to_string()),
+            Some("days/{YYYY}/{MM}-{DD}.md".to_string()),
+            Some("daily.md".to_string()),
+            None,
+            Some("2025-03-09".to_string()),
+        )
+        .await
+        .unwrap();
+        assert!(note.file_path.ends_with("content/days/2025/03-09.md"));
+        assert_eq!(
+            fs::read_to_string(&note.file_path).unwrap(),
+            "---\ntitle: Journal 2025-03-09\n---\n\n# 09/03/2025\n"
+        );
+    }
+
+    #[test]
+    fn test_daily_note_in() {
+        let temp = TempDir::new().unwrap();
+        let project = temp.path().to_string_lossy().to_string();
+        let content = temp.path().join("src/content");
+        fs::create_dir_all(content.join("journal")).unwrap();
+        fs::create_dir_all(content.join("notes")).unwrap();
+        let settings = DailyNoteSettings::default();
+
+        let note = daily_note_in(
+            &project,
+            None,
+            &settings,
+            &content.join("journal"),
+            date(2025, 2, 1),
+        )
+        .unwrap()
+        .unwrap();
+        assert!(note.created);
+        assert!(note.file_path.ends_with("journal/2025-02-01.md"));
+
+        let elsewhere = daily_note_in(
+            &project,
+            None,
+            &settings,
+            &content.join("notes"),
+            date(2025, 2, 1),
+        )
+        .unwrap();
+        assert_eq!(elsewhere, None);
+        assert!(!content.join("notes/2025-02-01.md").exists());
+    }
+
+    #[test]
+    fn test_calendar_for_month() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("journal");
+        fs::create_dir_all(&journal).unwrap();
+        fs::write(
+            journal.join("2025-02-03.md"),
+            "---\ntitle: x\n---\n\nThree words here\n",
+        )
+        .unwrap();
+        fs::write(journal.join("2025-02-28.md"), "one\n").unwrap();
+        fs::write(journal.join("2025-03-01.md"), "next month\n").unwrap();
+
+        let days = calendar_for_month(temp.path(), DEFAULT_PATTERN, 2025, 2).unwrap();
+        let summary: Vec<(&str, u32)> = days
+            .iter()
+            .map(|d| (d.date.as_str(), d.word_count))
+            .collect();
+        assert_eq!(summary, vec![("2025-02-03", 3), ("2025-02-28", 1)]);
+
+        assert!(calendar_for_month(temp.path(), DEFAULT_PATTERN, 2025, 13).is_err());
+    }
+}
@@ -0,0 +1,290 @@
+//! Locale-aware date formatting
+//!
+//! Dates are formatted in Rust so the file list and frontmatter panel agree without
+//! bundling an i18n library in the frontend. Month and weekday names come from
+//! chrono's locale data; the locale is the project's `locale` setting when set,
+//! otherwise the OS locale. Relative phrases ("3 days ago") are translated for
+//! English, German, French and Spanish and fall back to English, the UI language.
+
+use chrono::{Local, Locale, NaiveDate};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// How a date is written
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DateStyle {
+    /// Numeric, in the locale's order (`05/01/2025`)
+    Short,
+    /// Abbreviated month (`5 Jan 2025`, `Jan 5, 2025`)
+    Medium,
+    /// Full month name (`5 January 2025`)
+    Long,
+    /// Weekday and full month name (`Sunday, 5 January 2025`)
+    Full,
+    /// Relative to today (`yesterday`, `in 3 weeks`)
+    Relative,
+}
+
+/// Words for relative dates in one language
+struct RelativeWords {
+    today: &'static str,
+    yesterday: &'static str,
+    tomorrow: &'static str,
+    /// `{}` is replaced by the amount and unit
+    past: &'static str,
+    future: &'static str,
+    /// Singular and plural for days, weeks, months and years
+    units: [(&'static str, &'static str); 4],
+}
+
+const ENGLISH: RelativeWords = RelativeWords {
+    today: "today",
+    yesterday: "yesterday",
+    tomorrow: "tomorrow",
+    past: "{} ago",
+    future: "in {}",
+    units: [
+        ("day", "days"),
+        ("week", "weeks"),
+        ("month", "months"),
+        ("year", "years"),
+    ],
+};
+
+const RELATIVE_WORDS: [(&str, RelativeWords); 3] = [
+    (
+        "de",
+        RelativeWords {
+            today: "heute",
+            yesterday: "gestern",
+            tomorrow: "morgen",
+            past: "vor {}",
+            future: "in {}",
+            units: [
+                ("Tag", "Tagen"),
+                ("Woche", "Wochen"),
+                ("Monat", "Monaten"),
+                ("Jahr", "Jahren"),
+            ],
+        },
+    ),
+    (
+        "es",
+        RelativeWords {
+            today: "hoy",
+            yesterday: "ayer",
+            tomorrow: "mañana",
+            past: "hace {}",
+            future: "dentro de {}",
+            units: [
+                ("día", "días"),
+                ("semana", "semanas"),
+                ("mes", "meses"),
+                ("año", "años"),
+            ],
+        },
+    ),
+    (
+        "fr",
+        RelativeWords {
+            today: "aujourd’hui",
+            yesterday: "hier",
+            tomorrow: "demain",
+            past: "il y a {}",
+            future: "dans {}",
+            units: [
+                ("jour", "jours"),
+                ("semaine", "semaines"),
+                ("mois", "mois"),
+                ("an", "ans"),
+            ],
+        },
+    ),
+];
+
+/// Resolves a BCP 47 (`en-GB`) or POSIX (`de_DE.UTF-8`) locale name, trying the
+/// language's main region when only a language is given
+fn parse_locale(name: &str) -> Option<Locale> {
+    let name = name.split(['.', '@']).next()?.replace('-', "_");
+    let mut parts = name.split('_');
+    let language = parts.next()?.to_lowercase();
+    let region = parts.last().map(str::to_uppercase);
+
+    let candidates = match region {
+        Some(region) => vec![format!("{language}_{region}")],
+        None => vec![
+            language.clone(),
+            format!("{language}_{}", language.to_uppercase()),
+        ],
+    };
+    candidates
+        .iter()
+        .find_map(|candidate| Locale::try_from(candidate.as_str()).ok())
+}
+
+/// The project's locale override, otherwise the OS locale, otherwise US English
+fn resolve_locale(locale: Option<&str>) -> Locale {
+    locale
+        .filter(|name| !name.trim().is_empty())
+        .and_then(parse_locale)
+        .or_else(|| tauri_plugin_os::locale().as_deref().and_then(parse_locale))
+        .unwrap_or(Locale::en_US)
+}
+
+fn language(locale: Locale) -> String {
+    format!("{locale:?}")
+        .split('_')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Parses `YYYY-MM-DD`, ignoring any time part as frontmatter dates often have one
+fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    let date_part = value.trim().split(['T', ' ']).next().unwrap_or_default();
+    NaiveDate::parse_from_str(date_part, "%Y-%m-%d").map_err(|_| format!("Invalid date: {value}"))
+}
+
+/// strftime pattern for the absolute styles
+fn pattern(style: DateStyle, locale: Locale) -> &'static str {
+    let language = language(locale);
+    let month_first = locale == Locale::en_US;
+    match (style, language.as_str()) {
+        (DateStyle::Short, _) => "%x",
+        (DateStyle::Medium, _) if month_first => "%b %-d, %Y",
+        (DateStyle::Long, _) if month_first => "%B %-d, %Y",
+        (DateStyle::Full, _) if month_first => "%A, %B %-d, %Y",
+        (DateStyle::Medium, "ja" | "zh" | "ko") => "%Y-%m-%d",
+        (DateStyle::Long | DateStyle::Full, "ja" | "zh") => "%Y年%-m月%-d日",
+        (DateStyle::Long | DateStyle::Full, "ko") => "%Y년 %-m월 %-d일",
+        (DateStyle::Medium, "de") => "%-d. %b %Y",
+        (DateStyle::Long, "de") => "%-d. %B %Y",
+        (DateStyle::Full, "de") => "%A, %-d. %B %Y",
+        (DateStyle::Medium, "es" | "pt") => "%-d %b %Y",
+        (DateStyle::Long, "es" | "pt") => "%-d de %B de %Y",
+        (DateStyle::Full, "es" | "pt") => "%A, %-d de %B de %Y",
+        (DateStyle::Medium, _) => "%-d %b %Y",
+        (DateStyle::Long, _) => "%-d %B %Y",
+        (DateStyle::Full | DateStyle::Relative, _) => "%A %-d %B %Y",
+    }
+}
+
+/// Describes `date` relative to `today` in the locale's language
+fn format_relative(date: NaiveDate, today: NaiveDate, locale: Locale) -> String {
+    let language = language(locale);
+    let words = RELATIVE_WORDS
+        .iter()
+        .find(|(code, _)| *code == language)
+        .map_or(&ENGLISH, |(_, words)| words);
+
+    let days = (date - today).num_days();
+    let (amount, unit) = match days.abs() {
+        0 => return words.today.to_string(),
+        1 if days < 0 => return words.yesterday.to_string(),
+        1 => return words.tomorrow.to_string(),
+        n @ 2..=6 => (n, 0),
+        n @ 7..=29 => (n / 7, 1),
+        n @ 30..=364 => (n / 30, 2),
+        n => (n / 365, 3),
+    };
+
+    let (singular, plural) = words.units[unit];
+    let phrase = format!("{amount} {}", if amount == 1 { singular } else { plural });
+    let template = if days < 0 { words.past } else { words.future };
+    template.replace("{}", &phrase)
+}
+
+fn format_with(date: NaiveDate, style: DateStyle, locale: Locale, today: NaiveDate) -> String {
+    match style {
+        DateStyle::Relative => format_relative(date, today, locale),
+        _ => date
+            .format_localized(pattern(style, locale), locale)
+            .to_string(),
+    }
+}
+
+/// Formats a date for display
+///
+/// # Arguments
+/// * `date` - A `YYYY-MM-DD` date; a time part is ignored
+/// * `style` - How to write it
+/// * `locale` - The project's `locale` setting (e.g. `en-GB`); the OS locale when `None`
+/// * `today` - Reference day for relative dates (`YYYY-MM-DD`, today in the project's
+///   timezone); the system date when `None`
+#[tauri::command]
+#[specta::specta]
+pub async fn format_date(
+    date: String,
+    style: DateStyle,
+    locale: Option<String>,
+    today: Option<String>,
+) -> Result<String, String> {
+    let date = parse_date(&date)?;
+    let today = match today {
+        Some(today) => parse_date(&today)?,
+        None => Local::now().date_naive(),
+    };
+    Ok(format_with(
+        date,
+        style,
+        resolve_locale(locale.as_deref()),
+        today,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(value: &str) -> NaiveDate {
+        parse_date(value).unwrap()
+    }
+
+    #[test]
+    fn test_parse_locale_accepts_common_forms() {
+        assert_eq!(parse_locale("en-GB"), Some(Locale::en_GB));
+        assert_eq!(parse_locale("de_DE.UTF-8"), Some(Locale::de_DE));
+        assert_eq!(parse_locale("fr"), Some(Locale::fr_FR));
+        assert_eq!(parse_locale("xx-YY"), None);
+    }
+
+    #[test]
+    fn test_absolute_styles_use_locale_names_and_order() {
+        let date = day("2025-01-05T10:30:00Z");
+        let today = day("2025-01-05");
+
+        assert_eq!(
+            format_with(date, DateStyle::Medium, Locale::en_US, today),
+            "Jan 5, 2025"
+        );
+        assert_eq!(
+            format_with(date, DateStyle::Long, Locale::en_GB, today),
+            "5 January 2025"
+        );
+        assert_eq!(
+            format_with(date, DateStyle::Long, Locale::de_DE, today),
+            "5. Januar 2025"
+        );
+        assert_eq!(
+            format_with(date, DateStyle::Full, Locale::fr_FR, today),
+            "dimanche 5 janvier 2025"
+        );
+    }
+
+    #[test]
+    fn test_relative_dates() {
+        let today = day("2025-03-15");
+        let relative = |date: &str, locale| format_relative(day(date), today, locale);
+
+        assert_eq!(relative("2025-03-15", Locale::en_US), "today");
+        assert_eq!(relative("2025-03-14", Locale::en_US), "yesterday");
+        assert_eq!(relative("2025-03-12", Locale::en_US), "3 days ago");
+        assert_eq!(relative("2025-03-29", Locale::en_US), "in 2 weeks");
+        assert_eq!(relative("2024-03-15", Locale::en_US), "1 year ago");
+        assert_eq!(relative("2025-03-12", Locale::de_DE), "vor 3 Tagen");
+        assert_eq!(relative("2025-05-15", Locale::fr_FR), "dans 2 mois");
+        // Languages without translations use English
+        assert_eq!(relative("2025-03-16", Locale::nl_NL), "tomorrow");
+    }
+}
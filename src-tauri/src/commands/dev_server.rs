@@ -0,0 +1,433 @@
+//! Astro dev server for previewing entries
+//!
+//! `start_dev_server` runs `npm run dev` in the project under the process registry,
+//! keeping its recent output and the local URL Astro prints. A dev server started
+//! outside the editor is found by checking the port from the Astro config (4321 by
+//! default) instead. `get_preview_url` maps a content file to the route it renders
+//! at, using the collection's URL pattern or Astro's `/{collection}/{slug}/`
+//! convention.
+
+use crate::commands::files::parse_frontmatter_internal;
+use crate::commands::ide::get_augmented_path;
+use crate::commands::markdown_flavor::ASTRO_CONFIG_FILES;
+use crate::commands::processes::{spawn_managed, ProcessLimits};
+use crate::commands::project::scan_project_with_content_dir;
+use crate::commands::publish_gates::entry_slug;
+use crate::security::validate_project_path;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::{HashMap, VecDeque};
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, TcpStream};
+use std::path::Path;
+use std::process::Stdio;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::sync::Notify;
+
+/// Astro's dev server port when the config doesn't set one
+const DEFAULT_ASTRO_PORT: u16 = 4321;
+
+/// Output lines kept per dev server
+const MAX_OUTPUT_LINES: usize = 500;
+
+/// How long `start_dev_server` waits for Astro to print its URL
+const START_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Longest a dev server the editor started may run before it is stopped
+const DEV_SERVER_MAX_MINUTES: u64 = 12 * 60;
+
+/// A project's dev server, as far as the editor knows
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DevServerStatus {
+    /// Whether a dev server is reachable for the project
+    pub running: bool,
+    /// Whether the editor started it, and so can stop it
+    pub managed: bool,
+    /// The server's origin, e.g. `http://localhost:4321`
+    pub url: Option<String>,
+    /// Recent stdout and stderr of a server the editor started, oldest first
+    pub output: Vec<String>,
+}
+
+/// Where an entry renders on the dev server
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewUrl {
+    /// Site-absolute route, including the config's `base`, e.g. `/writing/first-post/`
+    pub route: String,
+    /// The route on the running dev server, if there is one
+    pub url: Option<String>,
+}
+
+/// A dev server the editor started
+#[derive(Default)]
+struct DevServer {
+    running: bool,
+    url: Option<String>,
+    output: VecDeque<String>,
+    stop: Option<Arc<Notify>>,
+}
+
+static DEV_SERVERS: OnceLock<Mutex<HashMap<String, DevServer>>> = OnceLock::new();
+
+fn dev_servers() -> &'static Mutex<HashMap<String, DevServer>> {
+    DEV_SERVERS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn read_astro_config(project_root: &Path) -> String {
+    ASTRO_CONFIG_FILES
+        .iter()
+        .find_map(|name| std::fs::read_to_string(project_root.join(name)).ok())
+        .unwrap_or_default()
+}
+
+/// The `server.port` set in the Astro config, or Astro's default
+fn configured_port(config: &str) -> u16 {
+    Regex::new(r"\bport\s*:\s*(\d{2,5})\b")
+        .unwrap()
+        .captures(config)
+        .and_then(|caps| caps[1].parse().ok())
+        .unwrap_or(DEFAULT_ASTRO_PORT)
+}
+
+/// The `base` path set in the Astro config, without a trailing slash
+fn configured_base(config: &str) -> String {
+    Regex::new(r#"\bbase\s*:\s*["'`]([^"'`]*)["'`]"#)
+        .unwrap()
+        .captures(config)
+        .map(|caps| caps[1].trim_end_matches('/').to_string())
+        .filter(|base| base.starts_with('/'))
+        .unwrap_or_default()
+}
+
+/// The origin of a dev server already listening on `port`, on IPv4 or IPv6 localhost
+fn detect_running(port: u16) -> Option<String> {
+    let addresses = [
+        SocketAddr::from((Ipv4Addr::LOCALHOST, port)),
+        SocketAddr::from((Ipv6Addr::LOCALHOST, port)),
+    ];
+    addresses
+        .iter()
+        .any(|address| TcpStream::connect_timeout(address, Duration::from_millis(300)).is_ok())
+        .then(|| format!("http://localhost:{port}"))
+}
+
+/// The origin of the first local URL in a line of dev server output
+fn parse_server_url(line: &str) -> Option<String> {
+    let ansi_re = Regex::new(r"\x1b\[[0-9;]*m").unwrap();
+    let url_re = Regex::new(r"https?://(?:localhost|127\.0\.0\.1|\[::1\]):\d+").unwrap();
+    let line = ansi_re.replace_all(line, "");
+    url_re.find(&line).map(|m| m.as_str().to_string())
+}
+
+/// The route an entry renders at: its URL pattern with `{slug}` filled in, or
+/// `/{collection}/{slug}/`, under the site's base path
+fn preview_route(slug: &str, collection: &str, url_pattern: Option<&str>, base: &str) -> String {
+    // `index` entries render at their collection's root
+    let slug = if slug == "index" { "" } else { slug };
+    let route = match url_pattern {
+        Some(pattern) => pattern.replace("{slug}", slug),
+        None => format!("/{collection}/{slug}/"),
+    };
+    let route = Regex::new("/{2,}")
+        .unwrap()
+        .replace_all(&format!("{base}/{route}"), "/")
+        .to_string();
+    if route.is_empty() {
+        "/".to_string()
+    } else {
+        route
+    }
+}
+
+/// Keeps a line of output and picks up the URL Astro prints once it's listening
+fn record_output(project_path: &str, line: String) {
+    let mut servers = dev_servers().lock().unwrap();
+    let Some(server) = servers.get_mut(project_path) else {
+        return;
+    };
+    if server.url.is_none() {
+        server.url = parse_server_url(&line);
+    }
+    server.output.push_back(line);
+    while server.output.len() > MAX_OUTPUT_LINES {
+        server.output.pop_front();
+    }
+}
+
+async fn forward_output(project_path: &str, stream: Option<impl AsyncRead + Unpin>) {
+    let Some(stream) = stream else {
+        return;
+    };
+    let mut lines = BufReader::new(stream).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        if !line.trim().is_empty() {
+            record_output(project_path, line);
+        }
+    }
+}
+
+/// The status of the dev server the editor started, or of one found on the port
+fn status(project_path: &str) -> DevServerStatus {
+    if let Some(server) = dev_servers().lock().unwrap().get(project_path) {
+        if server.running {
+            return DevServerStatus {
+                running: true,
+                managed: true,
+                url: server.url.clone(),
+                output: server.output.iter().cloned().collect(),
+            };
+        }
+    }
+    let config = read_astro_config(Path::new(project_path));
+    let url = detect_running(configured_port(&config));
+    let output = dev_servers()
+        .lock()
+        .unwrap()
+        .get(project_path)
+        .map(|server| server.output.iter().cloned().collect())
+        .unwrap_or_default();
+    DevServerStatus {
+        running: url.is_some(),
+        managed: false,
+        url,
+        output,
+    }
+}
+
+/// `status`, off the async runtime since checking the port blocks
+async fn current_status(project_path: String) -> Result<DevServerStatus, String> {
+    tokio::task::spawn_blocking(move || status(&project_path))
+        .await
+        .map_err(|e| format!("Failed to check dev server: {e}"))
+}
+
+/// Starts the project's dev server with `npm run dev`, unless one is already running
+///
+/// Resolves once Astro reports the URL it's listening on. A dev server already on the
+/// configured port, started outside the editor, is used as is.
+#[tauri::command]
+#[specta::specta]
+pub async fn start_dev_server(
+    app: tauri::AppHandle,
+    project_path: String,
+) -> Result<DevServerStatus, String> {
+    let current = current_status(project_path.clone()).await?;
+    if current.running {
+        return Ok(current);
+    }
+
+    let npm = if cfg!(target_os = "windows") {
+        "npm.cmd"
+    } else {
+        "npm"
+    };
+    let mut command = tokio::process::Command::new(npm);
+    command
+        .args(["run", "dev"])
+        .current_dir(&project_path)
+        .env("PATH", get_augmented_path())
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let mut managed = spawn_managed(
+        &app,
+        "astro dev",
+        command,
+        ProcessLimits::minutes(DEV_SERVER_MAX_MINUTES),
+    )?;
+
+    dev_servers().lock().unwrap().insert(
+        project_path.clone(),
+        DevServer {
+            running: true,
+            stop: Some(managed.stop_handle()),
+            ..DevServer::default()
+        },
+    );
+
+    let stdout = managed.child.stdout.take();
+    let stderr = managed.child.stderr.take();
+    let path = project_path.clone();
+    tokio::spawn(async move {
+        let (_, _, result) = tokio::join!(
+            forward_output(&path, stdout),
+            forward_output(&path, stderr),
+            managed.wait()
+        );
+        let ended = match result {
+            Ok(status) => format!("Dev server exited ({status})"),
+            Err(e) => e,
+        };
+        log::info!("Astro Editor [DEV_SERVER] {ended}");
+        record_output(&path, ended);
+        if let Some(server) = dev_servers().lock().unwrap().get_mut(&path) {
+            server.running = false;
+            server.url = None;
+            server.stop = None;
+        }
+    });
+
+    let started = Instant::now();
+    loop {
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        let (running, url, output) = {
+            let servers = dev_servers().lock().unwrap();
+            let server = &servers[&project_path];
+            (server.running, server.url.clone(), server.output.clone())
+        };
+        if url.is_some() {
+            return Ok(status(&project_path));
+        }
+        if !running {
+            let recent: Vec<&str> = output
+                .iter()
+                .rev()
+                .take(5)
+                .rev()
+                .map(String::as_str)
+                .collect();
+            return Err(format!("Dev server failed to start: {}", recent.join("\n")));
+        }
+        if started.elapsed() >= START_TIMEOUT {
+            return Err(format!(
+                "Dev server didn't report a URL within {} seconds",
+                START_TIMEOUT.as_secs()
+            ));
+        }
+    }
+}
+
+/// Stops the dev server the editor started for a project
+#[tauri::command]
+#[specta::specta]
+pub async fn stop_dev_server(project_path: String) -> Result<(), String> {
+    let servers = dev_servers().lock().unwrap();
+    let stop = servers
+        .get(&project_path)
+        .and_then(|server| server.stop.as_ref())
+        .ok_or("The editor didn't start a dev server for this project")?;
+    // The task awaiting the server terminates it and records why
+    stop.notify_one();
+    Ok(())
+}
+
+/// Reports whether the project's dev server is running, with its URL and recent output
+#[tauri::command]
+#[specta::specta]
+pub async fn get_dev_server_status(project_path: String) -> Result<DevServerStatus, String> {
+    current_status(project_path).await
+}
+
+/// Works out where a content file renders on the dev server
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `file_path` - The entry to preview
+/// * `content_directory` - Optional content directory override
+/// * `url_pattern` - The collection's URL pattern (e.g. `/writing/{slug}`), if any
+///
+/// # Returns
+/// The entry's route, and its full URL when a dev server is running
+#[tauri::command]
+#[specta::specta]
+pub async fn get_preview_url(
+    project_path: String,
+    file_path: String,
+    content_directory: Option<String>,
+    url_pattern: Option<String>,
+) -> Result<PreviewUrl, String> {
+    let path = validate_project_path(&file_path, &project_path)?;
+    let collections =
+        scan_project_with_content_dir(project_path.clone(), content_directory, None).await?;
+    let (collection, root) = collections
+        .into_iter()
+        .filter_map(|c| {
+            let root = c.path.canonicalize().ok()?;
+            Some((c.name, root))
+        })
+        .filter(|(_, root)| path.starts_with(root))
+        .max_by_key(|(_, root)| root.components().count())
+        .ok_or("This file isn't in a collection, so it has no page to preview")?;
+
+    let frontmatter = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|content| parse_frontmatter_internal(&content).ok())
+        .map(|parsed| parsed.frontmatter)
+        .unwrap_or_default();
+    let slug = entry_slug(&path, &root, &frontmatter);
+
+    tokio::task::spawn_blocking(move || {
+        let config = read_astro_config(Path::new(&project_path));
+        let route = preview_route(
+            &slug,
+            &collection,
+            url_pattern.as_deref(),
+            &configured_base(&config),
+        );
+        let url = status(&project_path)
+            .url
+            .map(|origin| format!("{origin}{route}"));
+        PreviewUrl { route, url }
+    })
+    .await
+    .map_err(|e| format!("Failed to check dev server: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    #[test]
+    fn test_config_port_and_base() {
+        let config =
+            "export default defineConfig({\n  base: '/docs/',\n  server: { port: 3000 },\n});";
+        assert_eq!(configured_port(config), 3000);
+        assert_eq!(configured_base(config), "/docs");
+        assert_eq!(configured_port(""), DEFAULT_ASTRO_PORT);
+        assert_eq!(configured_base(""), "");
+    }
+
+    #[test]
+    fn test_parse_server_url() {
+        assert_eq!(
+            parse_server_url(
+                "  \x1b[32m┃\x1b[39m Local    \x1b[1mhttp://localhost:4321/docs/\x1b[22m"
+            ),
+            Some("http://localhost:4321".to_string())
+        );
+        assert_eq!(parse_server_url("watching for file changes..."), None);
+        assert_eq!(parse_server_url("See https://docs.astro.build/"), None);
+    }
+
+    #[test]
+    fn test_preview_route() {
+        assert_eq!(
+            preview_route("first-post", "blog", None, ""),
+            "/blog/first-post/"
+        );
+        assert_eq!(
+            preview_route("first-post", "blog", Some("/writing/{slug}"), "/docs"),
+            "/docs/writing/first-post"
+        );
+        assert_eq!(preview_route("index", "blog", None, ""), "/blog/");
+        assert_eq!(preview_route("index", "pages", Some("/{slug}"), ""), "/");
+    }
+
+    #[test]
+    fn test_detect_running() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        assert_eq!(
+            detect_running(port),
+            Some(format!("http://localhost:{port}"))
+        );
+
+        drop(listener);
+        assert_eq!(detect_running(port), None);
+    }
+}
@@ -0,0 +1,661 @@
+//! Dry-run previews of mutating commands
+//!
+//! `preview_changes` runs a mutating command with its filesystem writes recorded rather
+//! than performed, and returns the exact changes it would have made (with a diff for
+//! each rewritten text file). Commands take part by writing through the helpers here
+//! ([`write`], [`rename`], [`remove_file`], [`copy`], [`create_dir_all`]) in place of
+//! `std::fs`; outside a preview they behave exactly like `std::fs`. Side effects that
+//! aren't file changes (the audit log, bulk undo bundles, toasts) are skipped while a
+//! preview is running.
+//!
+//! The plan follows the command's task. Blocking work must be started with
+//! [`spawn_blocking`] so it records into the same plan.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::commands::coercions::CoercionSuggestion;
+use crate::commands::image_gallery::ImageFolderOptions;
+use crate::config_editor::unified_diff;
+
+/// A filesystem change a command would make
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum PlannedChange {
+    /// A file written with new contents
+    Write {
+        path: String,
+        /// Whether the file doesn't exist yet
+        created: bool,
+        /// Unified diff against the current contents; omitted for binary files
+        diff: Option<String>,
+    },
+    Delete {
+        path: String,
+    },
+    Rename {
+        from: String,
+        to: String,
+    },
+    Copy {
+        from: String,
+        to: String,
+    },
+    CreateDirectory {
+        path: String,
+    },
+}
+
+impl PlannedChange {
+    /// The path this change creates or writes, if any
+    fn target(&self) -> Option<&str> {
+        match self {
+            PlannedChange::Write { path, .. } | PlannedChange::CreateDirectory { path } => {
+                Some(path)
+            }
+            PlannedChange::Rename { to, .. } | PlannedChange::Copy { to, .. } => Some(to),
+            PlannedChange::Delete { .. } => None,
+        }
+    }
+}
+
+/// A mutating command to preview, with the arguments it would be called with
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum MutationRequest {
+    #[serde(rename_all = "camelCase")]
+    WriteFile {
+        file_path: String,
+        content: String,
+        project_root: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    CreateFile {
+        directory: String,
+        filename: String,
+        content: String,
+        project_root: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    DeleteFile {
+        file_path: String,
+        project_root: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    RenameFile {
+        old_path: String,
+        new_path: String,
+        project_root: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    UpdateFrontmatter {
+        file_path: String,
+        frontmatter: IndexMap<String, Value>,
+        project_root: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    ApplyCoercions {
+        file_path: String,
+        project_root: String,
+        coercions: Vec<CoercionSuggestion>,
+    },
+    #[serde(rename_all = "camelCase")]
+    CopyFileToAssets {
+        source_path: String,
+        project_path: String,
+        collection: String,
+        assets_directory: Option<String>,
+        current_file_path: String,
+        use_relative_paths: bool,
+        use_path_aliases: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    ImportImageFolder {
+        folder_path: String,
+        project_path: String,
+        collection: String,
+        current_file_path: String,
+        options: ImageFolderOptions,
+    },
+    #[serde(rename_all = "camelCase")]
+    NormalizeCodeBlockLanguages {
+        project_path: String,
+        collection_path: String,
+        mapping: HashMap<String, String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    RepairAssetReferences {
+        project_path: String,
+        content_directory: Option<String>,
+        assets_directory: Option<String>,
+    },
+}
+
+/// The changes a previewed command would make
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangePreview {
+    /// Changes in the order the command would make them
+    pub changes: Vec<PlannedChange>,
+    /// Why the command would fail; `changes` holds what it would do before failing
+    pub error: Option<String>,
+}
+
+type Plan = Arc<Mutex<Vec<PlannedChange>>>;
+
+tokio::task_local! {
+    static PLAN: Plan;
+}
+
+thread_local! {
+    /// The plan of a dry run that started blocking work on this thread
+    static BLOCKING_PLAN: RefCell<Option<Plan>> = const { RefCell::new(None) };
+}
+
+fn current_plan() -> Option<Plan> {
+    PLAN.try_with(Arc::clone)
+        .ok()
+        .or_else(|| BLOCKING_PLAN.with(|plan| plan.borrow().clone()))
+}
+
+/// Whether filesystem changes are currently being recorded instead of made
+pub fn is_active() -> bool {
+    current_plan().is_some()
+}
+
+/// Runs `future` as a dry run, returning its output and the changes it would have made
+pub async fn run<F: Future>(future: F) -> (F::Output, Vec<PlannedChange>) {
+    let plan = Plan::default();
+    let output = PLAN.scope(plan.clone(), future).await;
+    let changes = std::mem::take(&mut *plan.lock().unwrap());
+    (output, changes)
+}
+
+/// `tokio::task::spawn_blocking`, carrying the current dry run (if any) into the task
+pub async fn spawn_blocking<F, R>(f: F) -> Result<R, tokio::task::JoinError>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let plan = current_plan();
+    tokio::task::spawn_blocking(move || {
+        let previous = BLOCKING_PLAN.with(|cell| cell.replace(plan));
+        let output = f();
+        BLOCKING_PLAN.with(|cell| cell.replace(previous));
+        output
+    })
+    .await
+}
+
+fn record(plan: &Plan, change: PlannedChange) {
+    let mut changes = plan.lock().unwrap();
+    // A file written twice appears once, with its final contents
+    if let PlannedChange::Write { path, .. } = &change {
+        changes.retain(|c| !matches!(c, PlannedChange::Write { path: p, .. } if p == path));
+    }
+    changes.push(change);
+}
+
+fn display(path: &Path) -> String {
+    path.to_string_lossy().to_string()
+}
+
+fn not_found(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("{} does not exist", path.display()),
+    )
+}
+
+/// Whether `path` exists, or would exist by this point in the current dry run
+pub fn exists(path: &Path) -> bool {
+    if path.exists() {
+        return true;
+    }
+    let Some(plan) = current_plan() else {
+        return false;
+    };
+    let path = display(path);
+    let changes = plan.lock().unwrap();
+    changes.iter().any(|c| c.target() == Some(path.as_str()))
+}
+
+/// A write planned for a file: whether it's new, and a diff of text contents
+fn planned_write(path: &Path, contents: &[u8]) -> PlannedChange {
+    let label = display(path);
+    let original = std::fs::read(path).ok();
+    let diff = match (&original, std::str::from_utf8(contents)) {
+        (None, Ok(updated)) => Some(unified_diff("", updated, &label)),
+        (Some(original), Ok(updated)) => std::str::from_utf8(original)
+            .ok()
+            .map(|original| unified_diff(original, updated, &label)),
+        (_, Err(_)) => None,
+    };
+    PlannedChange::Write {
+        created: original.is_none(),
+        path: label,
+        diff,
+    }
+}
+
+/// `std::fs::write`, recorded instead of performed during a dry run
+pub fn write<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> io::Result<()> {
+    let path = path.as_ref();
+    match current_plan() {
+        Some(plan) => {
+            record(&plan, planned_write(path, contents.as_ref()));
+            Ok(())
+        }
+        None => std::fs::write(path, contents),
+    }
+}
+
+/// Numbers temporary files, so concurrent writes to one file don't share one
+static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Like [`write`], but through a temporary file renamed into place, so a crash or full
+/// disk can't leave the file half written
+pub fn write_atomic<P: AsRef<Path>, C: AsRef<[u8]>>(path: P, contents: C) -> io::Result<()> {
+    let path = path.as_ref();
+    if let Some(plan) = current_plan() {
+        record(&plan, planned_write(path, contents.as_ref()));
+        return Ok(());
+    }
+
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Path has no file name"))?;
+    let temp_path = path.with_file_name(format!(
+        ".{}.{}.{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id(),
+        TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let written = std::fs::write(&temp_path, contents).and_then(|()| {
+        // Keep the original file's permissions
+        if let Ok(metadata) = std::fs::metadata(path) {
+            std::fs::set_permissions(&temp_path, metadata.permissions())?;
+        }
+        std::fs::rename(&temp_path, path)
+    });
+    if written.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+    }
+    written
+}
+
+/// `std::fs::remove_file`, recorded instead of performed during a dry run
+pub fn remove_file<P: AsRef<Path>>(path: P) -> io::Result<()> {
+    let path = path.as_ref();
+    match current_plan() {
+        Some(_) if !path.is_file() => Err(not_found(path)),
+        Some(plan) => {
+            record(
+                &plan,
+                PlannedChange::Delete {
+                    path: display(path),
+                },
+            );
+            Ok(())
+        }
+        None => std::fs::remove_file(path),
+    }
+}
+
+/// `std::fs::rename`, recorded instead of performed during a dry run
+pub fn rename<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) -> io::Result<()> {
+    let (from, to) = (from.as_ref(), to.as_ref());
+    match current_plan() {
+        Some(_) if !from.exists() => Err(not_found(from)),
+        Some(plan) => {
+            let change = PlannedChange::Rename {
+                from: display(from),
+                to: display(to),
+            };
+            record(&plan, change);
+            Ok(())
+        }
+        None => std::fs::rename(from, to),
+    }
+}
+
+/// `std::fs::copy`, recorded instead of performed during a dry run
+pub fn copy<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) -> io::Result<u64> {
+    let (from, to) = (from.as_ref(), to.as_ref());
+    match current_plan() {
+        Some(plan) => {
+            let size = std::fs::metadata(from)?.len();
+            let change = PlannedChange::Copy {
+                from: display(from),
+                to: display(to),
+            };
+            record(&plan, change);
+            Ok(size)
+        }
+        None => std::fs::copy(from, to),
+    }
+}
+
+/// `std::fs::create_dir_all`, recorded instead of performed during a dry run
+pub fn create_dir_all<P: AsRef<Path>>(path: P) -> io::Result<()> {
+    let path = path.as_ref();
+    match current_plan() {
+        Some(_) if exists(path) => Ok(()),
+        Some(plan) => {
+            record(
+                &plan,
+                PlannedChange::CreateDirectory {
+                    path: display(path),
+                },
+            );
+            Ok(())
+        }
+        None => std::fs::create_dir_all(path),
+    }
+}
+
+async fn run_request(request: MutationRequest) -> Result<(), String> {
+    use crate::commands::{asset_repair, code_blocks, coercions, files, image_gallery};
+
+    match request {
+        MutationRequest::WriteFile {
+            file_path,
+            content,
+            project_root,
+        } => files::write_file(file_path, content, project_root, None, None)
+            .await
+            .map(|_| ())
+            .map_err(String::from),
+        MutationRequest::CreateFile {
+            directory,
+            filename,
+            content,
+            project_root,
+        } => files::create_file(directory, filename, content, project_root, None)
+            .await
+            .map(|_| ()),
+        MutationRequest::DeleteFile {
+            file_path,
+            project_root,
+        } => files::delete_file(file_path, project_root, None)
+            .await
+            .map(|_| ()),
+        MutationRequest::RenameFile {
+            old_path,
+            new_path,
+            project_root,
+        } => files::rename_file(old_path, new_path, project_root, None)
+            .await
+            .map(|_| ()),
+        MutationRequest::UpdateFrontmatter {
+            file_path,
+            frontmatter,
+            project_root,
+        } => files::update_frontmatter(file_path, frontmatter, project_root, None).await,
+        MutationRequest::ApplyCoercions {
+            file_path,
+            project_root,
+            coercions,
+        } => coercions::apply_coercions(file_path, project_root, coercions)
+            .await
+            .map(|_| ()),
+        MutationRequest::CopyFileToAssets {
+            source_path,
+            project_path,
+            collection,
+            assets_directory,
+            current_file_path,
+            use_relative_paths,
+            use_path_aliases,
+        } => files::copy_file_to_assets_with_override(
+            source_path,
+            project_path,
+            collection,
+            assets_directory,
+            current_file_path,
+            use_relative_paths,
+            use_path_aliases,
+        )
+        .await
+        .map(|_| ()),
+        MutationRequest::ImportImageFolder {
+            folder_path,
+            project_path,
+            collection,
+            current_file_path,
+            options,
+        } => image_gallery::import_image_folder(
+            folder_path,
+            project_path,
+            collection,
+            current_file_path,
+            options,
+        )
+        .await
+        .map(|_| ()),
+        MutationRequest::NormalizeCodeBlockLanguages {
+            project_path,
+            collection_path,
+            mapping,
+        } => code_blocks::normalize_code_block_languages(project_path, collection_path, mapping)
+            .await
+            .map(|_| ()),
+        MutationRequest::RepairAssetReferences {
+            project_path,
+            content_directory,
+            assets_directory,
+        } => asset_repair::repair_asset_references(
+            project_path,
+            content_directory,
+            assets_directory,
+            true,
+        )
+        .await
+        .map(|_| ()),
+    }
+}
+
+/// Runs a mutating command without touching disk, returning the changes it would make
+///
+/// The command is validated exactly as it would be for real, so a preview fails for
+/// the same reasons the command would.
+///
+/// # Arguments
+/// * `request` - The command and its arguments
+#[tauri::command]
+#[specta::specta]
+pub async fn preview_changes(request: MutationRequest) -> Result<ChangePreview, String> {
+    let (outcome, changes) = run(run_request(request)).await;
+    Ok(ChangePreview {
+        changes,
+        error: outcome.err(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_dry_run_records_without_touching_disk() {
+        let temp = TempDir::new().unwrap();
+        let existing = temp.path().join("post.md");
+        fs::write(&existing, "title\nold line\n").unwrap();
+        let created = temp.path().join("drafts/new.md");
+
+        let (_, changes) = run(async {
+            create_dir_all(temp.path().join("drafts")).unwrap();
+            write(&existing, "title\nnew line\n").unwrap();
+            write(&created, "first").unwrap();
+            write(&created, "second").unwrap();
+            rename(&existing, temp.path().join("renamed.md")).unwrap();
+            assert!(remove_file(temp.path().join("missing.md")).is_err());
+            assert!(exists(&created));
+        })
+        .await;
+
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "title\nold line\n");
+        assert!(!temp.path().join("drafts").exists());
+        assert_eq!(changes.len(), 4);
+        assert!(
+            matches!(&changes[0], PlannedChange::CreateDirectory { path } if path.ends_with("drafts"))
+        );
+        match &changes[1] {
+            PlannedChange::Write {
+                created: false,
+                diff: Some(diff),
+                ..
+            } => {
+                assert!(diff.contains("-old line"));
+                assert!(diff.contains("+new line"));
+            }
+            other => panic!("unexpected change: {other:?}"),
+        }
+        match &changes[2] {
+            PlannedChange::Write {
+                created: true,
+                diff: Some(diff),
+                ..
+            } => assert!(diff.contains("+second")),
+            other => panic!("unexpected change: {other:?}"),
+        }
+        assert!(matches!(&changes[3], PlannedChange::Rename { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_helpers_write_outside_dry_run() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("post.md");
+
+        write(&path, "content").unwrap();
+        spawn_blocking({
+            let path = path.clone();
+            move || assert!(!is_active() && path.exists())
+        })
+        .await
+        .unwrap();
+
+        assert!(!is_active());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "content");
+    }
+
+    #[test]
+    fn test_concurrent_atomic_writes() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("post.md");
+
+        let writers: Vec<_> = (0..8)
+            .map(|i| {
+                let path = path.clone();
+                std::thread::spawn(move || write_atomic(&path, format!("version {i}")))
+            })
+            .collect();
+        for writer in writers {
+            writer.join().unwrap().unwrap();
+        }
+
+        assert!(fs::read_to_string(&path).unwrap().starts_with("version "));
+        assert_eq!(fs::read_dir(temp.path()).unwrap().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_spawn_blocking_carries_dry_run() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("image.png");
+
+        let (_, changes) = run(async {
+            let path = path.clone();
+            spawn_blocking(move || write(&path, [0xff, 0xfe]).unwrap())
+                .await
+                .unwrap();
+        })
+        .await;
+
+        assert!(!path.exists());
+        assert_eq!(
+            changes,
+            vec![PlannedChange::Write {
+                path: display(&path),
+                created: true,
+                diff: None,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_preview_changes_reports_command_changes() {
+        let temp = TempDir::new().unwrap();
+        let project = temp.path().to_string_lossy().to_string();
+        let file = temp.path().join("post.md");
+        fs::write(&file, "---\ntitle: Old\n---\n\nBody\n").unwrap();
+
+        let mut frontmatter = IndexMap::new();
+        frontmatter.insert("title".to_string(), Value::String("New".to_string()));
+        let preview = preview_changes(MutationRequest::UpdateFrontmatter {
+            file_path: file.to_string_lossy().to_string(),
+            frontmatter,
+            project_root: project.clone(),
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(preview.error, None);
+        assert_eq!(preview.changes.len(), 1);
+        assert!(fs::read_to_string(&file).unwrap().contains("title: Old"));
+
+        let preview = preview_changes(MutationRequest::DeleteFile {
+            file_path: "/etc/passwd".to_string(),
+            project_root: project,
+        })
+        .await
+        .unwrap();
+        assert!(preview.changes.is_empty());
+        assert!(preview.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_preview_copy_into_missing_assets_directory() {
+        let temp = TempDir::new().unwrap();
+        let project = temp.path().canonicalize().unwrap();
+        let source = temp.path().join("photo.png");
+        fs::write(&source, b"image").unwrap();
+
+        let preview = preview_changes(MutationRequest::CopyFileToAssets {
+            source_path: source.to_string_lossy().to_string(),
+            project_path: project.to_string_lossy().to_string(),
+            collection: "posts".to_string(),
+            assets_directory: None,
+            current_file_path: project
+                .join("src/content/posts/a.md")
+                .to_string_lossy()
+                .to_string(),
+            use_relative_paths: false,
+            use_path_aliases: false,
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(preview.error, None);
+        assert_eq!(preview.changes.len(), 2);
+        assert!(matches!(
+            &preview.changes[0],
+            PlannedChange::CreateDirectory { .. }
+        ));
+        assert!(
+            matches!(&preview.changes[1], PlannedChange::Copy { to, .. } if to.contains("src/assets/posts"))
+        );
+        assert!(!project.join("src").exists());
+    }
+}
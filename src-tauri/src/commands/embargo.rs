@@ -0,0 +1,358 @@
+//! Content embargoes
+//!
+//! An entry can be held back until a given time with an `embargoUntil` frontmatter
+//! field or, to keep the time out of the published frontmatter, a sidecar file next
+//! to it (`post.md.embargo`) containing only the time. Times are RFC 3339, or a local
+//! `YYYY-MM-DD` / `YYYY-MM-DDTHH:MM` in the machine's timezone.
+//!
+//! The publish workflow refuses embargoed entries (see `check_publish_readiness`),
+//! and while the frontend has a project registered a background task checks every
+//! minute and emits `embargo-lifted` with the entries whose embargo has just passed.
+
+use crate::commands::files::parse_frontmatter_internal;
+use crate::commands::project::scan_project_with_content_dir;
+use crate::models::Collection;
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, SecondsFormat, TimeZone, Utc};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use walkdir::WalkDir;
+
+/// Frontmatter field holding the embargo time
+pub const EMBARGO_FIELD: &str = "embargoUntil";
+
+/// Extension appended to an entry's file name for its sidecar
+const SIDECAR_EXTENSION: &str = "embargo";
+
+/// How often the background task looks for lifted embargoes
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Where an entry's embargo time comes from
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum EmbargoSource {
+    Frontmatter,
+    Sidecar,
+}
+
+/// An entry with an embargo
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbargoedEntry {
+    pub file_path: String,
+    pub collection: String,
+    /// The embargo time, RFC 3339 in UTC
+    pub embargo_until: String,
+    pub source: EmbargoSource,
+}
+
+/// The project the background task watches
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbargoWatchTarget {
+    pub project_path: String,
+    pub content_directory: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct EmbargoWatch {
+    target: Option<EmbargoWatchTarget>,
+    /// Entries under embargo at the last check; `None` until the first check
+    pending: Option<HashSet<String>>,
+}
+
+// The project watched for lifted embargoes, if any
+pub type EmbargoWatchState = Arc<Mutex<EmbargoWatch>>;
+
+/// Parses an embargo time; naive dates and times are in the local timezone
+pub(crate) fn parse_embargo_time(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Some(time.with_timezone(&Utc));
+    }
+    let naive = ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()?
+                .and_hms_opt(0, 0, 0)
+        })?;
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|time| time.with_timezone(&Utc))
+}
+
+fn sidecar_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{SIDECAR_EXTENSION}"));
+    path.with_file_name(name)
+}
+
+/// The raw embargo value for an entry; the frontmatter field wins over a sidecar
+pub(crate) fn embargo_value(
+    path: &Path,
+    frontmatter: &IndexMap<String, Value>,
+) -> Option<(String, EmbargoSource)> {
+    match frontmatter.get(EMBARGO_FIELD) {
+        Some(Value::String(value)) if !value.trim().is_empty() => {
+            Some((value.clone(), EmbargoSource::Frontmatter))
+        }
+        _ => std::fs::read_to_string(sidecar_path(path))
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+            .map(|value| (value, EmbargoSource::Sidecar)),
+    }
+}
+
+/// Every entry with a readable embargo, past or future, soonest first
+fn find_embargoes(collections: &[Collection]) -> Vec<(DateTime<Utc>, EmbargoedEntry)> {
+    let mut seen = BTreeSet::new();
+    let mut embargoes = Vec::new();
+
+    for collection in collections {
+        let files = WalkDir::new(&collection.path)
+            .follow_links(false)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                entry.file_type().is_file()
+                    && matches!(
+                        entry.path().extension().and_then(|e| e.to_str()),
+                        Some("md") | Some("mdx")
+                    )
+            });
+
+        for file in files {
+            // Nested collections are walked by their parent too
+            if !seen.insert(file.path().to_path_buf()) {
+                continue;
+            }
+            let Ok(content) = std::fs::read_to_string(file.path()) else {
+                continue;
+            };
+            let Ok(parsed) = parse_frontmatter_internal(&content) else {
+                continue;
+            };
+            let Some((value, source)) = embargo_value(file.path(), &parsed.frontmatter) else {
+                continue;
+            };
+            let Some(until) = parse_embargo_time(&value) else {
+                log::warn!(
+                    "Astro Editor [EMBARGO] Ignoring unreadable embargo time '{}' for {}",
+                    value.trim(),
+                    file.path().display()
+                );
+                continue;
+            };
+
+            embargoes.push((
+                until,
+                EmbargoedEntry {
+                    file_path: file.path().to_string_lossy().to_string(),
+                    collection: collection.name.clone(),
+                    embargo_until: until.to_rfc3339_opts(SecondsFormat::Secs, true),
+                    source,
+                },
+            ));
+        }
+    }
+
+    embargoes.sort_by(|a, b| {
+        a.0.cmp(&b.0)
+            .then_with(|| a.1.file_path.cmp(&b.1.file_path))
+    });
+    embargoes
+}
+
+/// Splits embargoes at `now`, returning entries that were pending last time and have
+/// since become publishable, and the entries still pending
+fn lifted_since(
+    embargoes: Vec<(DateTime<Utc>, EmbargoedEntry)>,
+    previously_pending: Option<&HashSet<String>>,
+    now: DateTime<Utc>,
+) -> (Vec<EmbargoedEntry>, HashSet<String>) {
+    let mut lifted = Vec::new();
+    let mut pending = HashSet::new();
+    for (until, entry) in embargoes {
+        if until > now {
+            pending.insert(entry.file_path);
+        } else if previously_pending.is_some_and(|p| p.contains(&entry.file_path)) {
+            lifted.push(entry);
+        }
+    }
+    (lifted, pending)
+}
+
+/// Emits `embargo-lifted` for entries whose embargo passed since the last check
+async fn check_embargoes(app: &AppHandle) -> Result<(), String> {
+    let state = app.state::<EmbargoWatchState>();
+    let Some(target) = state.lock().unwrap().target.clone() else {
+        return Ok(());
+    };
+
+    let collections = scan_project_with_content_dir(
+        target.project_path.clone(),
+        target.content_directory.clone(),
+        None,
+    )
+    .await?;
+    let embargoes = tokio::task::spawn_blocking(move || find_embargoes(&collections))
+        .await
+        .map_err(|e| format!("Failed to check embargoes: {e}"))?;
+
+    let lifted = {
+        let mut watch = state.lock().unwrap();
+        // The project may have changed while scanning
+        if watch.target.as_ref() != Some(&target) {
+            return Ok(());
+        }
+        let (lifted, pending) = lifted_since(embargoes, watch.pending.as_ref(), Utc::now());
+        watch.pending = Some(pending);
+        lifted
+    };
+    if lifted.is_empty() {
+        return Ok(());
+    }
+
+    log::info!(
+        "Astro Editor [EMBARGO] {} entries are now publishable",
+        lifted.len()
+    );
+    app.emit("embargo-lifted", &lifted)
+        .map_err(|e| format!("Failed to emit embargo-lifted: {e}"))
+}
+
+/// Lists entries still under embargo, soonest to lift first
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+#[tauri::command]
+#[specta::specta]
+pub async fn list_embargoed_entries(
+    project_path: String,
+    content_directory: Option<String>,
+) -> Result<Vec<EmbargoedEntry>, String> {
+    let collections = scan_project_with_content_dir(project_path, content_directory, None).await?;
+
+    tokio::task::spawn_blocking(move || {
+        let now = Utc::now();
+        find_embargoes(&collections)
+            .into_iter()
+            .filter(|(until, _)| *until > now)
+            .map(|(_, entry)| entry)
+            .collect()
+    })
+    .await
+    .map_err(|e| format!("Failed to list embargoed entries: {e}"))
+}
+
+/// Sets the project watched for lifted embargoes
+///
+/// # Arguments
+/// * `target` - The open project; `None` stops watching
+#[tauri::command]
+#[specta::specta]
+pub async fn set_embargo_watch(
+    watch: tauri::State<'_, EmbargoWatchState>,
+    target: Option<EmbargoWatchTarget>,
+) -> Result<(), String> {
+    let mut watch = watch
+        .lock()
+        .map_err(|e| format!("Failed to update embargo watch: {e}"))?;
+    if watch.target != target {
+        *watch = EmbargoWatch {
+            target,
+            pending: None,
+        };
+    }
+    Ok(())
+}
+
+/// Starts the background task that reports lifted embargoes
+pub fn start_embargo_task(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = check_embargoes(&app).await {
+                log::warn!("Astro Editor [EMBARGO] {e}");
+            }
+        }
+    });
+}
+
+// Initialize the embargo watch when the app starts
+pub fn init_embargo_watch() -> EmbargoWatchState {
+    Arc::new(Mutex::new(EmbargoWatch::default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_embargo_time() {
+        let time = parse_embargo_time("2026-11-01T09:30:00+01:00").unwrap();
+        assert_eq!(
+            time.to_rfc3339_opts(SecondsFormat::Secs, true),
+            "2026-11-01T08:30:00Z"
+        );
+        assert!(parse_embargo_time(" 2026-11-01 ").is_some());
+        assert!(parse_embargo_time("2026-11-01T09:30").is_some());
+        assert!(parse_embargo_time("next week").is_none());
+    }
+
+    #[test]
+    fn test_find_embargoes_and_lifted() {
+        let temp = TempDir::new().unwrap();
+        let blog = temp.path().join("blog");
+        fs::create_dir_all(&blog).unwrap();
+        fs::write(
+            blog.join("launch.md"),
+            "---\ntitle: Launch\nembargoUntil: 2026-11-01T09:00:00Z\n---\n",
+        )
+        .unwrap();
+        fs::write(blog.join("quiet.md"), "---\ntitle: Quiet\n---\n").unwrap();
+        fs::write(blog.join("quiet.md.embargo"), "2026-10-01T00:00:00Z\n").unwrap();
+        fs::write(blog.join("open.md"), "---\ntitle: Open\n---\n").unwrap();
+        let collections = vec![Collection::new("blog".to_string(), blog.clone())];
+
+        let embargoes = find_embargoes(&collections);
+        let summary: Vec<(&str, EmbargoSource)> = embargoes
+            .iter()
+            .map(|(_, e)| (e.embargo_until.as_str(), e.source))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("2026-10-01T00:00:00Z", EmbargoSource::Sidecar),
+                ("2026-11-01T09:00:00Z", EmbargoSource::Frontmatter),
+            ]
+        );
+
+        let launch = blog.join("launch.md").to_string_lossy().to_string();
+        let before = parse_embargo_time("2026-10-15T00:00:00Z").unwrap();
+        let after = parse_embargo_time("2026-11-01T09:01:00Z").unwrap();
+
+        // The first check only records what is pending
+        let (lifted, pending) = lifted_since(find_embargoes(&collections), None, before);
+        assert!(lifted.is_empty());
+        assert_eq!(pending, HashSet::from([launch.clone()]));
+
+        let (lifted, pending) = lifted_since(embargoes, Some(&pending), after);
+        assert_eq!(lifted.len(), 1);
+        assert_eq!(lifted[0].file_path, launch);
+        assert!(pending.is_empty());
+    }
+}
@@ -0,0 +1,427 @@
+//! Embed suggestions for pasted URLs
+//!
+//! Recognises URLs from known embed providers (YouTube, Twitter/X, Vimeo) and, when the
+//! project has a matching MDX component such as `<YouTube id="..." />`, builds the
+//! component snippet and the import it needs. Anything else falls back to a plain link.
+
+use crate::commands::files::calculate_relative_path;
+use crate::commands::mdx_components::scan_mdx_components;
+use crate::models::MdxComponent;
+use crate::utils::tsconfig::{load_path_aliases, to_aliased_path};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::Path;
+use tauri::Url;
+
+/// A provider whose URLs can be turned into an MDX component
+struct EmbedProvider {
+    name: &'static str,
+    /// Component names to look for, in order of preference (matched case-insensitively)
+    components: &'static [&'static str],
+    extract: fn(&Url) -> Option<EmbedParams>,
+}
+
+/// Values extracted from a provider URL
+#[derive(Debug, PartialEq)]
+struct EmbedParams {
+    id: String,
+    /// Start offset in seconds, for video providers
+    start: Option<u32>,
+}
+
+const PROVIDERS: [EmbedProvider; 3] = [
+    EmbedProvider {
+        name: "youtube",
+        components: &["YouTube", "YouTubeEmbed", "LiteYouTube"],
+        extract: extract_youtube,
+    },
+    EmbedProvider {
+        name: "twitter",
+        components: &["Tweet", "TweetEmbed", "Twitter"],
+        extract: extract_tweet,
+    },
+    EmbedProvider {
+        name: "vimeo",
+        components: &["Vimeo", "VimeoEmbed"],
+        extract: extract_vimeo,
+    },
+];
+
+/// Props that receive the provider id, in order of preference
+const ID_PROPS: [&str; 4] = ["id", "videoId", "tweetId", "videoid"];
+
+/// Props that receive the full URL when a component has no id prop
+const URL_PROPS: [&str; 3] = ["url", "href", "src"];
+
+/// What to insert for a pasted URL
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbedSuggestion {
+    /// The recognised provider, e.g. "youtube"; `None` for unrecognised URLs
+    pub provider: Option<String>,
+    /// The MDX component used; `None` when falling back to a link
+    pub component: Option<String>,
+    /// Text to insert: the component tag, or a Markdown link
+    pub snippet: String,
+    /// Import statement the component needs, e.g. `import YouTube from '../../components/mdx/YouTube.astro'`
+    pub import: Option<String>,
+}
+
+fn host(url: &Url) -> Option<String> {
+    url.host_str().map(|h| {
+        h.trim_start_matches("www.")
+            .trim_start_matches("m.")
+            .to_lowercase()
+    })
+}
+
+fn path_segments(url: &Url) -> Vec<&str> {
+    url.path_segments()
+        .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default()
+}
+
+fn query_param(url: &Url, name: &str) -> Option<String> {
+    url.query_pairs()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Parses YouTube start times: `90`, `90s`, `1m30s`, `1h2m3s`
+fn parse_timestamp(value: &str) -> Option<u32> {
+    if let Ok(seconds) = value.parse() {
+        return Some(seconds);
+    }
+
+    let mut total = 0u32;
+    let mut number = String::new();
+    for c in value.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'h' | 'm' | 's' => {
+                let n: u32 = number.parse().ok()?;
+                number.clear();
+                total += n * match c {
+                    'h' => 3600,
+                    'm' => 60,
+                    _ => 1,
+                };
+            }
+            _ => return None,
+        }
+    }
+    number.is_empty().then_some(total)
+}
+
+fn is_youtube_id(id: &str) -> bool {
+    id.len() == 11
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn extract_youtube(url: &Url) -> Option<EmbedParams> {
+    let segments = path_segments(url);
+    let id = match host(url)?.as_str() {
+        "youtu.be" => segments.first()?.to_string(),
+        "youtube.com" | "music.youtube.com" | "youtube-nocookie.com" => match segments.as_slice() {
+            ["watch"] => query_param(url, "v")?,
+            ["embed" | "shorts" | "live" | "v", id, ..] => id.to_string(),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    if !is_youtube_id(&id) {
+        return None;
+    }
+
+    let start = query_param(url, "t")
+        .or_else(|| query_param(url, "start"))
+        .and_then(|t| parse_timestamp(&t));
+
+    Some(EmbedParams { id, start })
+}
+
+fn extract_tweet(url: &Url) -> Option<EmbedParams> {
+    if !matches!(host(url)?.as_str(), "twitter.com" | "x.com") {
+        return None;
+    }
+
+    match path_segments(url).as_slice() {
+        [_, "status", id, ..] if id.chars().all(|c| c.is_ascii_digit()) => Some(EmbedParams {
+            id: id.to_string(),
+            start: None,
+        }),
+        _ => None,
+    }
+}
+
+fn extract_vimeo(url: &Url) -> Option<EmbedParams> {
+    let segments = path_segments(url);
+    let id = match host(url)?.as_str() {
+        "vimeo.com" => segments
+            .iter()
+            .find(|s| s.chars().all(|c| c.is_ascii_digit()))?,
+        "player.vimeo.com" => match segments.as_slice() {
+            ["video", id, ..] => id,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(EmbedParams {
+        id: id.to_string(),
+        start: None,
+    })
+}
+
+fn escape_attribute(value: &str) -> String {
+    value.replace('&', "&amp;").replace('"', "&quot;")
+}
+
+/// Builds the component tag, using whichever id/url/start props the component declares
+///
+/// Components whose props couldn't be parsed get a conventional `id` prop.
+fn build_component_tag(component: &MdxComponent, url: &str, params: &EmbedParams) -> String {
+    let has_prop = |name: &str| component.props.iter().any(|p| p.name == name);
+
+    let mut attributes = Vec::new();
+    if let Some(prop) = ID_PROPS.iter().find(|p| has_prop(p)) {
+        attributes.push(format!("{prop}=\"{}\"", escape_attribute(&params.id)));
+    } else if let Some(prop) = URL_PROPS.iter().find(|p| has_prop(p)) {
+        attributes.push(format!("{prop}=\"{}\"", escape_attribute(url)));
+    } else {
+        attributes.push(format!("id=\"{}\"", escape_attribute(&params.id)));
+    }
+
+    if let Some(start) = params.start {
+        if has_prop("start") {
+            attributes.push(format!("start=\"{start}\""));
+        } else if has_prop("params") {
+            attributes.push(format!("params=\"start={start}\""));
+        }
+    }
+
+    format!("<{} {} />", component.name, attributes.join(" "))
+}
+
+/// Markdown link fallback; MDX doesn't support `<https://...>` autolinks
+fn plain_link(url: &str) -> String {
+    format!("[{url}]({url})")
+}
+
+/// Matches a URL against the providers and available components
+///
+/// `import_path` maps a component's project-relative file path to the path to import
+/// it from.
+fn suggest(
+    url: &str,
+    components: &[MdxComponent],
+    import_path: impl Fn(&str) -> Option<String>,
+) -> EmbedSuggestion {
+    let fallback = |provider: Option<&str>| EmbedSuggestion {
+        provider: provider.map(str::to_string),
+        component: None,
+        snippet: plain_link(url),
+        import: None,
+    };
+
+    let Ok(parsed) = Url::parse(url) else {
+        return fallback(None);
+    };
+    let Some((provider, params)) = PROVIDERS
+        .iter()
+        .find_map(|p| (p.extract)(&parsed).map(|params| (p, params)))
+    else {
+        return fallback(None);
+    };
+
+    let component = provider.components.iter().find_map(|name| {
+        components
+            .iter()
+            .find(|c| c.name.eq_ignore_ascii_case(name))
+    });
+    let Some(component) = component else {
+        return fallback(Some(provider.name));
+    };
+
+    EmbedSuggestion {
+        provider: Some(provider.name.to_string()),
+        component: Some(component.name.clone()),
+        snippet: build_component_tag(component, url, &params),
+        import: import_path(&component.file_path)
+            .map(|path| format!("import {} from '{path}'", component.name)),
+    }
+}
+
+/// Suggests an MDX component embed for a pasted URL
+///
+/// Only `.mdx` files get component suggestions; other files, unrecognised URLs and
+/// providers without a matching component fall back to a Markdown link.
+///
+/// # Arguments
+/// * `url` - The pasted URL
+/// * `project_path` - The absolute path to the project root
+/// * `file_path` - The absolute path of the file being edited
+/// * `mdx_directory` - Optional MDX components directory override
+/// * `use_path_aliases` - Import via a `tsconfig.json` alias when one covers the component
+#[tauri::command]
+#[specta::specta]
+pub async fn suggest_embed(
+    url: String,
+    project_path: String,
+    file_path: String,
+    mdx_directory: Option<String>,
+    use_path_aliases: bool,
+) -> Result<EmbedSuggestion, String> {
+    let url = url.trim();
+    let is_mdx = Path::new(&file_path)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("mdx"));
+
+    let components = if is_mdx {
+        scan_mdx_components(project_path.clone(), mdx_directory).await?
+    } else {
+        Vec::new()
+    };
+
+    let project_root = Path::new(&project_path);
+    let aliases = if use_path_aliases {
+        load_path_aliases(project_root)
+    } else {
+        Vec::new()
+    };
+
+    Ok(suggest(url, &components, |component_path| {
+        to_aliased_path(project_root, component_path, &aliases)
+            .or_else(|| calculate_relative_path(&file_path, &project_path, component_path).ok())
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ComponentFramework, PropInfo};
+
+    fn params(url: &str) -> Option<EmbedParams> {
+        let url = Url::parse(url).unwrap();
+        PROVIDERS.iter().find_map(|p| (p.extract)(&url))
+    }
+
+    fn component(name: &str, props: &[&str]) -> MdxComponent {
+        MdxComponent {
+            name: name.to_string(),
+            file_path: format!("src/components/mdx/{name}.astro"),
+            props: props
+                .iter()
+                .map(|p| PropInfo {
+                    name: p.to_string(),
+                    prop_type: "string".to_string(),
+                    is_optional: false,
+                    default_value: None,
+                    description: None,
+                })
+                .collect(),
+            has_slot: false,
+            description: None,
+            framework: ComponentFramework::Astro,
+        }
+    }
+
+    fn import_path(path: &str) -> Option<String> {
+        Some(format!("../../{path}"))
+    }
+
+    #[test]
+    fn test_extract_provider_ids() {
+        let expected = |id: &str, start| {
+            Some(EmbedParams {
+                id: id.to_string(),
+                start,
+            })
+        };
+        assert_eq!(
+            params("https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=1m30s"),
+            expected("dQw4w9WgXcQ", Some(90))
+        );
+        assert_eq!(
+            params("https://youtu.be/dQw4w9WgXcQ?t=42"),
+            expected("dQw4w9WgXcQ", Some(42))
+        );
+        assert_eq!(
+            params("https://youtube.com/shorts/dQw4w9WgXcQ"),
+            expected("dQw4w9WgXcQ", None)
+        );
+        assert_eq!(
+            params("https://x.com/astrodotbuild/status/1511750228428435457?s=20"),
+            expected("1511750228428435457", None)
+        );
+        assert_eq!(
+            params("https://vimeo.com/channels/staffpicks/76979871"),
+            expected("76979871", None)
+        );
+
+        assert_eq!(params("https://www.youtube.com/watch?v=short"), None);
+        assert_eq!(params("https://twitter.com/astrodotbuild"), None);
+        assert_eq!(params("https://example.com/watch?v=dQw4w9WgXcQ"), None);
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(parse_timestamp("75"), Some(75));
+        assert_eq!(parse_timestamp("1h2m3s"), Some(3723));
+        assert_eq!(parse_timestamp("2m"), Some(120));
+        assert_eq!(parse_timestamp("1m30"), None);
+        assert_eq!(parse_timestamp("abc"), None);
+    }
+
+    #[test]
+    fn test_suggest_uses_matching_component() {
+        let components = vec![
+            component("Callout", &["type"]),
+            component("Youtube", &["id", "params"]),
+        ];
+        let suggestion = suggest(
+            "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=10",
+            &components,
+            import_path,
+        );
+        assert_eq!(suggestion.provider.as_deref(), Some("youtube"));
+        assert_eq!(suggestion.component.as_deref(), Some("Youtube"));
+        assert_eq!(
+            suggestion.snippet,
+            "<Youtube id=\"dQw4w9WgXcQ\" params=\"start=10\" />"
+        );
+        assert_eq!(
+            suggestion.import.as_deref(),
+            Some("import Youtube from '../../src/components/mdx/Youtube.astro'")
+        );
+
+        let tweet_url = "https://twitter.com/user/status/123";
+        let suggestion = suggest(tweet_url, &[component("Tweet", &["url"])], import_path);
+        assert_eq!(suggestion.snippet, format!("<Tweet url=\"{tweet_url}\" />"));
+    }
+
+    #[test]
+    fn test_suggest_falls_back_to_link() {
+        let url = "https://vimeo.com/76979871";
+        let suggestion = suggest(url, &[component("YouTube", &["id"])], import_path);
+        assert_eq!(suggestion.provider.as_deref(), Some("vimeo"));
+        assert_eq!(suggestion.component, None);
+        assert_eq!(suggestion.snippet, format!("[{url}]({url})"));
+        assert_eq!(suggestion.import, None);
+
+        let suggestion = suggest("https://example.com", &[], import_path);
+        assert_eq!(suggestion.provider, None);
+        assert_eq!(
+            suggestion.snippet,
+            "[https://example.com](https://example.com)"
+        );
+    }
+}
@@ -0,0 +1,386 @@
+//! Editing `file()` loader collections
+//!
+//! A `file()` collection keeps all its entries in one JSON or YAML array, usually small
+//! lookups like authors or tags. Entries are changed one at a time and the array is
+//! written back with the file's own key order (nested objects included) and trailing
+//! newline. JSON keeps its indentation too, so a diff only shows the entry that
+//! changed. YAML is rewritten in a standard style: comments are dropped and strings
+//! are only quoted where YAML requires it.
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::dry_run;
+use crate::commands::project::file_collection_path;
+use crate::commands::watcher;
+use crate::security::validate_project_path;
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+/// One entry of a `file()` collection, in the order its keys appear in the file
+pub(crate) type DataEntry = IndexMap<String, Value>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DataFormat {
+    Json,
+    Yaml,
+}
+
+impl DataFormat {
+    fn for_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml" | "yml") => Self::Yaml,
+            _ => Self::Json,
+        }
+    }
+}
+
+/// The identifier Astro uses for an entry: `id`, falling back to `slug`
+pub(crate) fn entry_id(entry: &DataEntry) -> Option<&str> {
+    entry
+        .get("id")
+        .and_then(Value::as_str)
+        .or_else(|| entry.get("slug").and_then(Value::as_str))
+}
+
+/// Reads the entries of a collection's JSON or YAML file
+pub(crate) fn read_entries(path: &Path) -> Result<Vec<DataEntry>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read collection file: {e}"))?;
+    parse_entries(&content, DataFormat::for_path(path))
+}
+
+fn parse_entries(content: &str, format: DataFormat) -> Result<Vec<DataEntry>, String> {
+    let value: Value = match format {
+        DataFormat::Json => {
+            serde_json::from_str(content).map_err(|e| format!("Failed to parse JSON: {e}"))?
+        }
+        DataFormat::Yaml => {
+            serde_norway::from_str(content).map_err(|e| format!("Failed to parse YAML: {e}"))?
+        }
+    };
+    if !value.is_array() {
+        return Err("Collection file must contain an array".to_string());
+    }
+    serde_json::from_value(value).map_err(|e| format!("Collection entries must be objects: {e}"))
+}
+
+/// The indentation of the first indented line, so rewritten JSON keeps the file's style
+fn detect_indent(content: &str) -> &str {
+    content
+        .lines()
+        .map(|line| &line[..line.len() - line.trim_start().len()])
+        .find(|indent| !indent.is_empty())
+        .unwrap_or("  ")
+}
+
+fn serialize_entries(
+    entries: &[DataEntry],
+    format: DataFormat,
+    original: &str,
+) -> Result<String, String> {
+    let mut serialized = match format {
+        DataFormat::Json => {
+            let formatter =
+                serde_json::ser::PrettyFormatter::with_indent(detect_indent(original).as_bytes());
+            let mut buffer = Vec::new();
+            let mut serializer = serde_json::Serializer::with_formatter(&mut buffer, formatter);
+            entries
+                .serialize(&mut serializer)
+                .map_err(|e| format!("Failed to serialize JSON: {e}"))?;
+            String::from_utf8(buffer).map_err(|e| format!("Failed to serialize JSON: {e}"))?
+        }
+        DataFormat::Yaml => {
+            let yaml = serde_norway::to_string(entries)
+                .map_err(|e| format!("Failed to serialize YAML: {e}"))?;
+            if original.starts_with("---") {
+                format!("---\n{yaml}")
+            } else {
+                yaml
+            }
+        }
+    };
+
+    if original.ends_with('\n') && !serialized.ends_with('\n') {
+        serialized.push('\n');
+    } else if !original.ends_with('\n') {
+        serialized.truncate(serialized.trim_end_matches('\n').len());
+    }
+    Ok(serialized)
+}
+
+/// Applies `update` to a collection's entries and writes them back
+fn update_entries(
+    project_path: &str,
+    collection_name: &str,
+    origin: &str,
+    update: impl FnOnce(&mut Vec<DataEntry>) -> Result<(), String>,
+) -> Result<(), String> {
+    let file_path = file_collection_path(Path::new(project_path), collection_name)?;
+    let validated_path = validate_project_path(&file_path.to_string_lossy(), project_path)?;
+    let format = DataFormat::for_path(&validated_path);
+
+    let original = std::fs::read_to_string(&validated_path)
+        .map_err(|e| format!("Failed to read collection file: {e}"))?;
+    let mut entries = parse_entries(&original, format)?;
+    update(&mut entries)?;
+    let updated = serialize_entries(&entries, format, &original)?;
+
+    watcher::ignore_write(&validated_path);
+    dry_run::write(&validated_path, updated)
+        .map_err(|e| format!("Failed to write collection file: {e}"))?;
+    audit_log::record(AuditAction::Write, &validated_path, None, origin);
+    Ok(())
+}
+
+fn position_of(entries: &[DataEntry], id: &str) -> Option<usize> {
+    entries.iter().position(|entry| entry_id(entry) == Some(id))
+}
+
+/// `data` in place of `existing`, keeping the keys that remain in their original order
+/// and appending new ones
+fn merge_entry(existing: &DataEntry, mut data: DataEntry) -> DataEntry {
+    let mut merged: DataEntry = existing
+        .keys()
+        .filter_map(|key| data.shift_remove(key).map(|value| (key.clone(), value)))
+        .collect();
+    merged.extend(data);
+    merged
+}
+
+/// Replaces an entry of a `file()` collection with `data`
+///
+/// `entry_id` is the entry's current `id` (or `slug`). Keys keep their place in the file;
+/// keys missing from `data` are removed and new ones are added at the end.
+#[tauri::command]
+#[specta::specta]
+pub async fn save_file_based_collection_entry(
+    project_path: String,
+    collection_name: String,
+    entry_id: String,
+    data: IndexMap<String, Value>,
+    origin: Option<String>,
+) -> Result<(), String> {
+    let origin = origin
+        .as_deref()
+        .unwrap_or("save_file_based_collection_entry");
+    update_entries(&project_path, &collection_name, origin, |entries| {
+        let index = position_of(entries, &entry_id)
+            .ok_or_else(|| format!("Entry '{entry_id}' not found in {collection_name}"))?;
+        let merged = merge_entry(&entries[index], data);
+
+        let new_id = self::entry_id(&merged).ok_or_else(|| {
+            "Collection entries must have either an 'id' or 'slug' field".to_string()
+        })?;
+        if new_id != entry_id && position_of(entries, new_id).is_some() {
+            return Err(format!(
+                "An entry with id '{new_id}' already exists in {collection_name}"
+            ));
+        }
+
+        entries[index] = merged;
+        Ok(())
+    })
+}
+
+/// Adds an entry to the end of a `file()` collection
+#[tauri::command]
+#[specta::specta]
+pub async fn create_file_based_entry(
+    project_path: String,
+    collection_name: String,
+    data: IndexMap<String, Value>,
+    origin: Option<String>,
+) -> Result<(), String> {
+    let origin = origin.as_deref().unwrap_or("create_file_based_entry");
+    update_entries(&project_path, &collection_name, origin, |entries| {
+        let id = entry_id(&data).ok_or_else(|| {
+            "Collection entries must have either an 'id' or 'slug' field".to_string()
+        })?;
+        if position_of(entries, id).is_some() {
+            return Err(format!(
+                "An entry with id '{id}' already exists in {collection_name}"
+            ));
+        }
+        entries.push(data);
+        Ok(())
+    })
+}
+
+/// Removes an entry from a `file()` collection
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_file_based_entry(
+    project_path: String,
+    collection_name: String,
+    entry_id: String,
+    origin: Option<String>,
+) -> Result<(), String> {
+    let origin = origin.as_deref().unwrap_or("delete_file_based_entry");
+    update_entries(&project_path, &collection_name, origin, |entries| {
+        let index = position_of(entries, &entry_id)
+            .ok_or_else(|| format!("Entry '{entry_id}' not found in {collection_name}"))?;
+        entries.remove(index);
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn entry(value: Value) -> DataEntry {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn project_with(file: &str, content: &str) -> (TempDir, String) {
+        let temp = TempDir::new().unwrap();
+        let loader = format!(
+            "import {{ file }} from 'astro/loaders';\n\
+             const authors = defineCollection({{ loader: file('{file}') }});\n\
+             export const collections = {{ authors }};\n"
+        );
+        fs::create_dir_all(temp.path().join("src/data")).unwrap();
+        fs::write(temp.path().join("src/content.config.ts"), loader).unwrap();
+        fs::write(temp.path().join(file), content).unwrap();
+        let project = temp.path().to_string_lossy().to_string();
+        (temp, project)
+    }
+
+    #[test]
+    fn test_parse_keeps_key_order() {
+        let entries =
+            parse_entries(r#"[{"name": "Jane", "id": "jane"}]"#, DataFormat::Json).unwrap();
+        let keys: Vec<&str> = entries[0].keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["name", "id"]);
+        assert_eq!(entry_id(&entries[0]), Some("jane"));
+
+        assert!(parse_entries(r#"{"jane": {}}"#, DataFormat::Json).is_err());
+    }
+
+    #[test]
+    fn test_nested_objects_keep_key_order() {
+        let json = "[\n  {\n    \"id\": \"jane\",\n    \"social\": {\n      \
+                    \"twitter\": \"@jane\",\n      \"github\": \"jane\"\n    }\n  }\n]\n";
+        let entries = parse_entries(json, DataFormat::Json).unwrap();
+        assert_eq!(
+            serialize_entries(&entries, DataFormat::Json, json).unwrap(),
+            json
+        );
+
+        let yaml = "- id: jane\n  social:\n    twitter: '@jane'\n    github: jane\n";
+        let entries = parse_entries(yaml, DataFormat::Yaml).unwrap();
+        assert_eq!(
+            serialize_entries(&entries, DataFormat::Yaml, yaml).unwrap(),
+            yaml
+        );
+    }
+
+    #[test]
+    fn test_yaml_comments_and_quoting_are_not_kept() {
+        let yaml = "# Site authors\n- id: \"jane\" # lead\n  name: Jane\n";
+        let entries = parse_entries(yaml, DataFormat::Yaml).unwrap();
+        assert_eq!(entry_id(&entries[0]), Some("jane"));
+        assert_eq!(
+            serialize_entries(&entries, DataFormat::Yaml, yaml).unwrap(),
+            "- id: jane\n  name: Jane\n"
+        );
+    }
+
+    #[test]
+    fn test_serialize_matches_original_formatting() {
+        let entries = vec![entry(json!({"id": "jane"}))];
+        let tabs = serialize_entries(&entries, DataFormat::Json, "[\n\t{}\n]\n").unwrap();
+        assert_eq!(tabs, "[\n\t{\n\t\t\"id\": \"jane\"\n\t}\n]\n");
+
+        let no_newline = serialize_entries(&entries, DataFormat::Json, "[]").unwrap();
+        assert_eq!(no_newline, "[\n  {\n    \"id\": \"jane\"\n  }\n]");
+
+        let yaml = serialize_entries(&entries, DataFormat::Yaml, "- id: old\n").unwrap();
+        assert_eq!(yaml, "- id: jane\n");
+    }
+
+    #[test]
+    fn test_merge_entry_keeps_existing_key_order() {
+        let existing = entry(json!({"id": "jane", "name": "Jane", "bio": "Writes"}));
+        let mut data = DataEntry::new();
+        data.insert("twitter".to_string(), json!("@jane"));
+        data.insert("name".to_string(), json!("Jane Doe"));
+        data.insert("id".to_string(), json!("jane"));
+
+        let merged = merge_entry(&existing, data);
+        let keys: Vec<&str> = merged.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["id", "name", "twitter"]);
+        assert_eq!(merged["name"], "Jane Doe");
+    }
+
+    #[tokio::test]
+    async fn test_create_save_and_delete_entries() {
+        let (temp, project) = project_with(
+            "src/data/authors.json",
+            "[\n    {\n        \"id\": \"jane\",\n        \"name\": \"Jane\"\n    }\n]\n",
+        );
+        let path = temp.path().join("src/data/authors.json");
+
+        create_file_based_entry(
+            project.clone(),
+            "authors".to_string(),
+            entry(json!({"id": "sam", "name": "Sam"})),
+            None,
+        )
+        .await
+        .unwrap();
+        let duplicate = create_file_based_entry(
+            project.clone(),
+            "authors".to_string(),
+            entry(json!({"id": "sam"})),
+            None,
+        )
+        .await;
+        assert!(duplicate.is_err());
+
+        let mut renamed = DataEntry::new();
+        renamed.insert("name".to_string(), json!("Jane Doe"));
+        renamed.insert("id".to_string(), json!("jane"));
+        save_file_based_collection_entry(
+            project.clone(),
+            "authors".to_string(),
+            "jane".to_string(),
+            renamed,
+            None,
+        )
+        .await
+        .unwrap();
+
+        delete_file_based_entry(project, "authors".to_string(), "sam".to_string(), None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(path).unwrap(),
+            "[\n    {\n        \"id\": \"jane\",\n        \"name\": \"Jane Doe\"\n    }\n]\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_yaml_collections() {
+        let (temp, project) = project_with("src/data/authors.yaml", "- id: jane\n  name: Jane\n");
+
+        create_file_based_entry(
+            project,
+            "authors".to_string(),
+            entry(json!({"id": "sam", "name": "Sam"})),
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(temp.path().join("src/data/authors.yaml")).unwrap(),
+            "- id: jane\n  name: Jane\n- id: sam\n  name: Sam\n"
+        );
+    }
+}
@@ -1,46 +1,36 @@
 use chrono::Local;
 use indexmap::IndexMap;
 use pathdiff::diff_paths;
+use regex::Regex;
 use serde_json::Value;
 use serde_norway;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use tauri::{path::BaseDirectory, Manager};
-
-/// Validates that a file path is within the project boundaries
-///
-/// This function prevents path traversal attacks by ensuring all file operations
-/// stay within the current project root directory.
-fn validate_project_path(file_path: &str, project_root: &str) -> Result<PathBuf, String> {
-    let file_path = Path::new(file_path);
-    let project_root = Path::new(project_root);
-
-    // Resolve canonical paths to handle symlinks and .. traversal
-    let canonical_file = file_path
-        .canonicalize()
-        .or_else(|_| {
-            // If file doesn't exist, try to canonicalize parent and append filename
-            if let (Some(parent), Some(filename)) = (file_path.parent(), file_path.file_name()) {
-                parent.canonicalize().map(|p| p.join(filename))
-            } else {
-                Err(std::io::Error::new(
-                    std::io::ErrorKind::NotFound,
-                    "Invalid file path",
-                ))
-            }
-        })
-        .map_err(|_| "Invalid file path".to_string())?;
-
-    let canonical_root = project_root
-        .canonicalize()
-        .map_err(|_| "Invalid project root".to_string())?;
-
-    // Ensure file is within project bounds
-    canonical_file
-        .strip_prefix(&canonical_root)
-        .map_err(|_| "File outside project directory".to_string())?;
-
-    Ok(canonical_file)
-}
+use std::sync::{Mutex, OnceLock};
+use tauri::{path::BaseDirectory, Emitter, Manager};
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::bulk_undo::content_hash;
+use crate::commands::dry_run;
+use crate::commands::frontmatter_errors::{explain_yaml_error, FrontmatterError};
+use crate::commands::link_refactor::{self, LinkRewriteReport, PlannedRewrite, RewrittenLink};
+use crate::commands::mdx_escaping::{self, MdxEscapingSettings};
+use crate::commands::project::{scan_project_with_content_dir, send_toast_notification};
+use crate::commands::reading_time::{self, ReadingTimeSettings};
+use crate::commands::trash::{self, TrashedFile};
+use crate::commands::unsaved_drafts;
+use crate::commands::versions;
+use crate::commands::watcher;
+use crate::schema_merger::{find_violations, FieldViolation, SchemaDefinition};
+use crate::security::{
+    is_in_project, validate_app_data_path, validate_assets_path, validate_project_path,
+    validate_public_path,
+};
+use crate::utils::svg::sanitize_svg;
+use crate::utils::tsconfig::{load_path_aliases, resolve_aliased_path, to_aliased_path};
+
+/// Lets asset imports report sanitized SVGs to the user; set at startup
+static ASSET_NOTIFIER: OnceLock<tauri::AppHandle> = OnceLock::new();
 
 /// Calculates the relative path from the current file to an asset
 ///
@@ -51,7 +41,7 @@ fn validate_project_path(file_path: &str, project_root: &str) -> Result<PathBuf,
 ///
 /// # Returns
 /// Relative path from the file's directory to the asset (e.g., "../../assets/image.png")
-fn calculate_relative_path(
+pub(crate) fn calculate_relative_path(
     current_file_path: &str,
     project_path: &str,
     project_relative_asset_path: &str,
@@ -80,6 +70,38 @@ fn calculate_relative_path(
     Ok(final_path)
 }
 
+/// Formats a project-relative asset path in the style configured for the project
+///
+/// When `use_path_aliases` is set and a `tsconfig.json` alias covers the asset, the aliased
+/// form (e.g. "@assets/blog/image.png") is returned. Otherwise falls back to a path relative
+/// to the current file, or an absolute path from project root.
+pub(crate) fn format_asset_path(
+    current_file_path: &str,
+    project_path: &str,
+    project_relative_asset_path: &str,
+    use_relative_paths: bool,
+    use_path_aliases: bool,
+) -> Result<String, String> {
+    if use_path_aliases {
+        let project_root = Path::new(project_path);
+        let aliases = load_path_aliases(project_root);
+        if let Some(aliased) = to_aliased_path(project_root, project_relative_asset_path, &aliases)
+        {
+            return Ok(aliased);
+        }
+    }
+
+    if use_relative_paths {
+        calculate_relative_path(current_file_path, project_path, project_relative_asset_path)
+    } else {
+        // Absolute path from project root (legacy behavior)
+        Ok(format!(
+            "/{}",
+            project_relative_asset_path.replace('\\', "/")
+        ))
+    }
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn read_file(file_path: String, project_root: String) -> Result<String, String> {
@@ -87,15 +109,125 @@ pub async fn read_file(file_path: String, project_root: String) -> Result<String
     std::fs::read_to_string(&validated_path).map_err(|e| format!("Failed to read file: {e}"))
 }
 
+/// Error returned when a file can't be saved
+#[derive(Debug, serde::Serialize, serde::Deserialize, specta::Type, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum FileSaveError {
+    /// The file changed on disk since it was loaded (e.g. by `git pull` or another editor),
+    /// so nothing was written
+    #[serde(rename_all = "camelCase")]
+    Conflict {
+        /// The file as it is now; `None` if it was deleted
+        disk_content: Option<String>,
+        disk_hash: Option<String>,
+        /// The file as it would have been written
+        attempted_content: String,
+        /// The file as it was loaded, to merge against; `None` if it's no longer known
+        base_content: Option<String>,
+    },
+    /// Any other failure (path outside project, write error)
+    Failed { message: String },
+}
+
+impl From<String> for FileSaveError {
+    fn from(message: String) -> Self {
+        FileSaveError::Failed { message }
+    }
+}
+
+impl From<FileSaveError> for String {
+    fn from(error: FileSaveError) -> Self {
+        match error {
+            FileSaveError::Conflict { .. } => "The file changed on disk since it was loaded".into(),
+            FileSaveError::Failed { message } => message,
+        }
+    }
+}
+
+/// Most files whose loaded content is kept for merging
+const MAX_KNOWN_CONTENT: usize = 32;
+
+/// What each recently opened file held when it was last loaded or saved, so a save that
+/// conflicts with changes on disk can be merged against the version it started from
+static KNOWN_CONTENT: OnceLock<Mutex<IndexMap<PathBuf, String>>> = OnceLock::new();
+
+fn known_content() -> &'static Mutex<IndexMap<PathBuf, String>> {
+    KNOWN_CONTENT.get_or_init(|| Mutex::new(IndexMap::new()))
+}
+
+fn remember_content(path: &Path, content: &str) {
+    let mut known = known_content().lock().unwrap();
+    known.shift_remove(path);
+    known.insert(path.to_path_buf(), content.to_string());
+    if known.len() > MAX_KNOWN_CONTENT {
+        known.shift_remove_index(0);
+    }
+}
+
+/// Fails with `FileSaveError::Conflict` if the file no longer has the content hash it was
+/// loaded with; any file passes when no hash is expected
+fn check_unchanged_on_disk(
+    path: &Path,
+    expected_hash: Option<&str>,
+    attempted_content: &str,
+) -> Result<(), FileSaveError> {
+    let Some(expected_hash) = expected_hash else {
+        return Ok(());
+    };
+    let disk_content = match std::fs::read_to_string(path) {
+        Ok(content) => Some(content),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => return Err(format!("Failed to read file: {e}").into()),
+    };
+    let disk_hash = disk_content.as_deref().map(content_hash);
+    if disk_hash.as_deref() == Some(expected_hash) {
+        return Ok(());
+    }
+    let base_content = known_content()
+        .lock()
+        .unwrap()
+        .get(path)
+        .filter(|content| content_hash(content) == expected_hash)
+        .cloned();
+    Err(FileSaveError::Conflict {
+        disk_content,
+        disk_hash,
+        attempted_content: attempted_content.to_string(),
+        base_content,
+    })
+}
+
+/// Writes a file in the project
+///
+/// With `expected_hash` (the `content_hash` the file was loaded with), the write is
+/// refused with `FileSaveError::Conflict` if the file has changed on disk since.
+///
+/// # Returns
+/// The content hash of the written file, to expect on the next write
 #[tauri::command]
 #[specta::specta]
 pub async fn write_file(
     file_path: String,
     content: String,
     project_root: String,
-) -> Result<(), String> {
+    origin: Option<String>,
+    expected_hash: Option<String>,
+) -> Result<String, FileSaveError> {
     let validated_path = validate_project_path(&file_path, &project_root)?;
-    std::fs::write(&validated_path, content).map_err(|e| format!("Failed to write file: {e}"))
+    check_unchanged_on_disk(&validated_path, expected_hash.as_deref(), &content)?;
+    dry_run::write_atomic(&validated_path, &content)
+        .map_err(|e| format!("Failed to write file: {e}"))?;
+    audit_log::record(
+        AuditAction::Write,
+        &validated_path,
+        None,
+        origin.as_deref().unwrap_or("write_file"),
+    );
+    // A dry run leaves the file as it was, so it stays the merge base
+    if !dry_run::is_active() {
+        remember_content(&validated_path, &content);
+    }
+    Ok(content_hash(&content))
 }
 
 #[tauri::command]
@@ -105,6 +237,7 @@ pub async fn create_file(
     filename: String,
     content: String,
     project_root: String,
+    origin: Option<String>,
 ) -> Result<String, String> {
     // Validate directory is within project
     let validated_dir = validate_project_path(&directory, &project_root)?;
@@ -114,38 +247,256 @@ pub async fn create_file(
     let final_path_str = path.to_string_lossy().to_string();
     let validated_final_path = validate_project_path(&final_path_str, &project_root)?;
 
-    if validated_final_path.exists() {
+    if dry_run::exists(&validated_final_path) {
         return Err("File already exists".to_string());
     }
 
-    std::fs::write(&validated_final_path, content)
+    dry_run::write(&validated_final_path, content)
         .map_err(|e| format!("Failed to create file: {e}"))?;
+    audit_log::record(
+        AuditAction::Create,
+        &validated_final_path,
+        None,
+        origin.as_deref().unwrap_or("create_file"),
+    );
 
     Ok(validated_final_path.to_string_lossy().to_string())
 }
 
+/// Deletes a file by moving it to the trash, where it can be restored from
+///
+/// # Returns
+/// The trash entry, or `None` during a dry run, when the delete is only recorded
 #[tauri::command]
 #[specta::specta]
-pub async fn delete_file(file_path: String, project_root: String) -> Result<(), String> {
+pub async fn delete_file(
+    file_path: String,
+    project_root: String,
+    origin: Option<String>,
+) -> Result<Option<TrashedFile>, String> {
     let validated_path = validate_project_path(&file_path, &project_root)?;
-    std::fs::remove_file(&validated_path).map_err(|e| format!("Failed to delete file: {e}"))
+    let trashed = trash::move_to_trash(&validated_path, &project_root)?;
+    audit_log::record(
+        AuditAction::Delete,
+        &validated_path,
+        None,
+        origin.as_deref().unwrap_or("delete_file"),
+    );
+    Ok(trashed)
+}
+
+/// Frontmatter changes made to the copy by `duplicate_file`
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, specta::Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateAdjustments {
+    /// Fields to set, e.g. `draft: true`
+    pub set: IndexMap<String, Value>,
+    /// Fields to remove, e.g. `pubDate`
+    pub clear: Vec<String>,
+    /// A string field to append " Copy" to, usually the title
+    pub append_copy_to: Option<String>,
+}
+
+impl DuplicateAdjustments {
+    fn is_empty(&self) -> bool {
+        self.set.is_empty() && self.clear.is_empty() && self.append_copy_to.is_none()
+    }
+}
+
+/// `{name}-copy.{ext}` in kebab case, numbered past any file already in `dir`
+fn duplicate_filename(dir: &Path, original: &Path) -> String {
+    let stem = original
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().replace('.', " "))
+        .unwrap_or_default();
+    let extension = original
+        .extension()
+        .map(|ext| format!(".{}", ext.to_string_lossy().to_lowercase()))
+        .unwrap_or_default();
+    let base = to_kebab_case(&format!("{stem} copy"));
+
+    (1..)
+        .map(|n| match n {
+            1 => format!("{base}{extension}"),
+            n => format!("{base}-{n}{extension}"),
+        })
+        .find(|filename| !dry_run::exists(&dir.join(filename)))
+        .unwrap_or_default()
 }
 
+fn adjust_duplicate(content: &str, adjustments: &DuplicateAdjustments) -> Result<String, String> {
+    if adjustments.is_empty() {
+        return Ok(content.to_string());
+    }
+
+    let mut parsed = parse_frontmatter(content)?;
+    for field in &adjustments.clear {
+        parsed.frontmatter.shift_remove(field);
+    }
+    if let Some(field) = &adjustments.append_copy_to {
+        if let Some(Value::String(value)) = parsed.frontmatter.get_mut(field) {
+            value.push_str(" Copy");
+        }
+    }
+    for (field, value) in &adjustments.set {
+        parsed.frontmatter.insert(field.clone(), value.clone());
+    }
+
+    rebuild_markdown_with_frontmatter_and_imports(
+        &parsed.frontmatter,
+        &parsed.imports,
+        &parsed.content,
+    )
+}
+
+/// Copies an entry into a new file beside it, adjusting the copy's frontmatter
+///
+/// The copy is named `{name}-copy.md`, numbered (`-copy-2`, ...) past any existing
+/// file. Without adjustments the content is copied unchanged.
+///
+/// # Returns
+/// The new file's path
 #[tauri::command]
 #[specta::specta]
-pub async fn rename_file(
+pub async fn duplicate_file(
+    file_path: String,
+    project_root: String,
+    adjustments: Option<DuplicateAdjustments>,
+    origin: Option<String>,
+) -> Result<String, String> {
+    let validated_path = validate_project_path(&file_path, &project_root)?;
+    let directory = validated_path
+        .parent()
+        .ok_or("File has no parent directory")?;
+    let content = std::fs::read_to_string(&validated_path)
+        .map_err(|e| format!("Failed to read file: {e}"))?;
+    let content = adjust_duplicate(&content, &adjustments.unwrap_or_default())?;
+
+    let target = directory.join(duplicate_filename(directory, &validated_path));
+    dry_run::write(&target, content).map_err(|e| format!("Failed to create file: {e}"))?;
+    audit_log::record(
+        AuditAction::Create,
+        &target,
+        None,
+        origin.as_deref().unwrap_or("duplicate_file"),
+    );
+
+    Ok(target.to_string_lossy().to_string())
+}
+
+/// Renames a file and rewrites the references to it `plan` finds
+///
+/// The plan runs before the rename, while references still resolve from the old
+/// location, and gets the canonical old path, new path and project root.
+async fn rename_with_rewrites<F>(
     old_path: String,
     new_path: String,
     project_root: String,
-) -> Result<(), String> {
+    origin: &str,
+    plan: F,
+) -> Result<Vec<RewrittenLink>, String>
+where
+    F: FnOnce(&Path, &Path, &Path) -> Result<Vec<PlannedRewrite>, String> + Send + 'static,
+{
     let validated_old_path = validate_project_path(&old_path, &project_root)?;
     let validated_new_path = validate_project_path(&new_path, &project_root)?;
-    std::fs::rename(&validated_old_path, &validated_new_path)
-        .map_err(|e| format!("Failed to rename file: {e}"))
+    let canonical_root = Path::new(&project_root)
+        .canonicalize()
+        .map_err(|e| format!("Invalid project root: {e}"))?;
+
+    let plan = {
+        let (old_path, new_path) = (validated_old_path.clone(), validated_new_path.clone());
+        tokio::task::spawn_blocking(move || plan(&old_path, &new_path, &canonical_root))
+            .await
+            .map_err(|e| format!("Failed to check links: {e}"))??
+    };
+
+    dry_run::rename(&validated_old_path, &validated_new_path)
+        .map_err(|e| format!("Failed to rename file: {e}"))?;
+    audit_log::record(
+        AuditAction::Rename,
+        &validated_old_path,
+        Some(&validated_new_path),
+        origin,
+    );
+
+    link_refactor::apply_link_rewrites(plan, origin)
+        .map_err(|e| format!("File renamed, but updating links failed: {e}"))
+}
+
+/// Renames or moves a file, rewriting relative references it breaks
+///
+/// Relative links and images in the file itself are re-based on its new directory,
+/// and references to it from other content files follow it. Returns every reference
+/// rewritten.
+#[tauri::command]
+#[specta::specta]
+pub async fn rename_file(
+    old_path: String,
+    new_path: String,
+    project_root: String,
+    origin: Option<String>,
+) -> Result<Vec<RewrittenLink>, String> {
+    let origin = origin.as_deref().unwrap_or("rename_file");
+    rename_with_rewrites(
+        old_path,
+        new_path,
+        project_root,
+        origin,
+        link_refactor::plan_link_rewrites,
+    )
+    .await
+}
+
+/// Renames or moves an entry, rewriting relative references and links to its ID or slug
+///
+/// Does everything `rename_file` does, and also updates `reference()` values, links to
+/// its URL and wiki links that name the entry by its old ID or slug.
+///
+/// # Arguments
+/// * `content_directory` - Optional content directory override
+/// * `url_patterns` - Collection URL patterns (e.g. `{ "blog": "/writing/{slug}" }`) for
+///   finding site-absolute links to the entry
+///
+/// # Returns
+/// The files touched and every reference rewritten in them
+#[tauri::command]
+#[specta::specta]
+pub async fn rename_file_with_link_rewrite(
+    old_path: String,
+    new_path: String,
+    project_root: String,
+    content_directory: Option<String>,
+    url_patterns: HashMap<String, String>,
+    origin: Option<String>,
+) -> Result<LinkRewriteReport, String> {
+    let collections =
+        scan_project_with_content_dir(project_root.clone(), content_directory, None).await?;
+    let origin = origin.as_deref().unwrap_or("rename_file_with_link_rewrite");
+
+    rename_with_rewrites(
+        old_path,
+        new_path,
+        project_root,
+        origin,
+        move |old_path, new_path, project_root| {
+            let plan = link_refactor::plan_link_rewrites(old_path, new_path, project_root)?;
+            Ok(link_refactor::plan_entry_rewrites(
+                plan,
+                old_path,
+                new_path,
+                project_root,
+                &collections,
+                &url_patterns,
+            ))
+        },
+    )
+    .await
+    .map(LinkRewriteReport::from)
 }
 
 /// Convert a string to kebab case
-fn to_kebab_case(s: &str) -> String {
+pub(crate) fn to_kebab_case(s: &str) -> String {
     let parts: Vec<&str> = s.split('.').collect();
     let extension = if parts.len() > 1 { parts.last() } else { None };
 
@@ -175,6 +526,112 @@ fn to_kebab_case(s: &str) -> String {
     }
 }
 
+// Initialize asset import notifications when the app starts
+pub fn init_asset_notifications(app: &tauri::AppHandle) {
+    let _ = ASSET_NOTIFIER.set(app.clone());
+}
+
+/// Logs what was stripped from an imported SVG and warns the user about it
+fn report_sanitized_svg(file_name: &str, removed: &[String]) {
+    if removed.is_empty() || dry_run::is_active() {
+        return;
+    }
+    log::warn!(
+        "Astro Editor [SVG] Removed from {file_name}: {}",
+        removed.join("; ")
+    );
+    if let Some(app) = ASSET_NOTIFIER.get() {
+        let _ = send_toast_notification(
+            app,
+            "warning",
+            &format!("Removed active content from {file_name}"),
+            Some(&removed.join("\n")),
+        );
+    }
+}
+
+/// Creates an empty, uniquely named file in `assets_dir` for an asset called `file_name`
+///
+/// The name is the date-prefixed kebab-case form of `file_name` (e.g.
+/// "2026-10-16-my-photo.png"), with a counter appended when it's taken.
+pub(crate) fn create_asset_file(
+    assets_dir: &Path,
+    file_name: &str,
+    project_path: &str,
+) -> Result<PathBuf, String> {
+    use std::fs;
+
+    let extension = Path::new(file_name)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("");
+
+    // Create the base filename with date prefix
+    let date_prefix = Local::now().format("%Y-%m-%d").to_string();
+    let name_without_ext = file_name.trim_end_matches(&format!(".{extension}"));
+    let kebab_name = to_kebab_case(name_without_ext);
+
+    // Build the new filename
+    let mut base_name = format!("{date_prefix}-{kebab_name}");
+    if !extension.is_empty() {
+        base_name.push('.');
+        base_name.push_str(extension);
+    }
+
+    // Atomically find an available filename
+    // This prevents TOCTOU race conditions where multiple simultaneous calls
+    // could all check existence and decide to use the same filename
+    let mut final_path = assets_dir.join(&base_name);
+    let mut counter = 1;
+    const MAX_ATTEMPTS: u32 = 100;
+
+    loop {
+        // Validate the candidate path is within project bounds
+        let final_path_str = final_path.to_string_lossy().to_string();
+        let validated_path = validate_project_path(&final_path_str, project_path)?;
+
+        // Try to create the destination file atomically using create_new()
+        // This fails if the file already exists, preventing race conditions.
+        // A dry run only reserves the name, which the caller's write then records.
+        let created = if dry_run::is_active() {
+            if dry_run::exists(&validated_path) {
+                Err(std::io::Error::from(std::io::ErrorKind::AlreadyExists))
+            } else {
+                Ok(())
+            }
+        } else {
+            fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&validated_path)
+                .map(|_| ())
+        };
+        match created {
+            Ok(()) => return Ok(validated_path),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                // File exists, try with counter suffix
+                if counter > MAX_ATTEMPTS {
+                    return Err(format!(
+                        "Could not find available filename after {MAX_ATTEMPTS} attempts"
+                    ));
+                }
+
+                let name_with_counter = if extension.is_empty() {
+                    format!("{date_prefix}-{kebab_name}-{counter}")
+                } else {
+                    format!("{date_prefix}-{kebab_name}-{counter}.{extension}")
+                };
+                final_path = assets_dir.join(name_with_counter);
+                counter += 1; // Increment for next iteration
+            }
+            Err(e) => {
+                // Other error (permissions, disk full, etc.)
+                return Err(format!("Failed to create file: {e}"));
+            }
+        }
+    }
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn copy_file_to_assets(
@@ -183,6 +640,7 @@ pub async fn copy_file_to_assets(
     collection: String,
     current_file_path: String,
     use_relative_paths: bool,
+    use_path_aliases: bool,
 ) -> Result<String, String> {
     copy_file_to_assets_with_override(
         source_path,
@@ -191,6 +649,7 @@ pub async fn copy_file_to_assets(
         None,
         current_file_path,
         use_relative_paths,
+        use_path_aliases,
     )
     .await
 }
@@ -204,6 +663,7 @@ pub async fn copy_file_to_assets_with_override(
     assets_directory: Option<String>,
     current_file_path: String,
     use_relative_paths: bool,
+    use_path_aliases: bool,
 ) -> Result<String, String> {
     use std::fs;
 
@@ -221,8 +681,10 @@ pub async fn copy_file_to_assets_with_override(
 
     let assets_dir = assets_base.join(&collection);
 
-    fs::create_dir_all(&assets_dir)
+    dry_run::create_dir_all(&assets_dir)
         .map_err(|e| format!("Failed to create assets directory: {e}"))?;
+    // The override and collection name must not lead outside the project's assets
+    let assets_dir = validate_assets_path(&assets_dir, &validated_project_root, &assets_base)?;
 
     // Get the source file info
     let source = PathBuf::from(&source_path);
@@ -231,89 +693,235 @@ pub async fn copy_file_to_assets_with_override(
         .ok_or("Invalid source file path")?
         .to_string_lossy();
 
-    // Extract extension
-    let extension = source
+    // SVGs can carry scripts into the site, so they're sanitized rather than copied
+    let is_svg = source
         .extension()
-        .and_then(|ext| ext.to_str())
-        .unwrap_or("");
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("svg"));
+    let sanitized = if is_svg {
+        let svg = fs::read_to_string(&source).map_err(|e| format!("Failed to read SVG: {e}"))?;
+        Some(sanitize_svg(&svg)?)
+    } else {
+        None
+    };
 
-    // Create the base filename with date prefix
-    let date_prefix = Local::now().format("%Y-%m-%d").to_string();
-    let name_without_ext = file_name.trim_end_matches(&format!(".{extension}"));
-    let kebab_name = to_kebab_case(name_without_ext);
+    let validated_final_path = create_asset_file(&assets_dir, &file_name, &project_path)?;
+    // Note: create_asset_file created an empty file, so we copy over it
+    match &sanitized {
+        Some(svg) => dry_run::write(&validated_final_path, &svg.content)
+            .map_err(|e| format!("Failed to write file content: {e}"))?,
+        None => {
+            dry_run::copy(&source_path, &validated_final_path)
+                .map_err(|e| format!("Failed to copy file content: {e}"))?;
+        }
+    }
+    audit_log::record(
+        AuditAction::Copy,
+        &source,
+        Some(&validated_final_path),
+        "copy_file_to_assets",
+    );
+    if let Some(svg) = &sanitized {
+        report_sanitized_svg(&file_name, &svg.removed);
+    }
 
-    // Build the new filename
-    let mut base_name = format!("{date_prefix}-{kebab_name}");
-    if !extension.is_empty() {
-        base_name.push('.');
-        base_name.push_str(extension);
+    // Get the path relative to project root
+    let project_relative_path = validated_final_path
+        .strip_prefix(&validated_project_root)
+        .map_err(|_| "Failed to create relative path")?
+        .to_string_lossy()
+        .to_string();
+
+    format_asset_path(
+        &current_file_path,
+        &project_path,
+        &project_relative_path,
+        use_relative_paths,
+        use_path_aliases,
+    )
+}
+
+/// Largest remote image `download_image_to_assets` will fetch (20 MB)
+const MAX_REMOTE_IMAGE_BYTES: u64 = 20 * 1024 * 1024;
+
+/// How long a remote image download may take
+const REMOTE_IMAGE_TIMEOUT_SECS: u64 = 30;
+
+/// The extension for downloaded image data, detected from the bytes themselves
+///
+/// Servers often send the wrong content type (or none), so it isn't trusted.
+fn remote_image_extension(bytes: &[u8]) -> Option<&'static str> {
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        let text = text.trim_start_matches('\u{feff}').trim_start();
+        if text.starts_with('<') && text.contains("<svg") {
+            return Some("svg");
+        }
     }
+    match image::guess_format(bytes).ok()? {
+        image::ImageFormat::Png => Some("png"),
+        image::ImageFormat::Jpeg => Some("jpg"),
+        image::ImageFormat::Gif => Some("gif"),
+        image::ImageFormat::WebP => Some("webp"),
+        image::ImageFormat::Bmp => Some("bmp"),
+        image::ImageFormat::Ico => Some("ico"),
+        _ => None,
+    }
+}
 
-    // Atomically find available filename and copy file
-    // This prevents TOCTOU race conditions where multiple simultaneous calls
-    // could all check existence and decide to use the same filename
-    let mut final_path = assets_dir.join(&base_name);
-    let mut counter = 1;
-    const MAX_ATTEMPTS: u32 = 100;
+/// A file name for an image downloaded from `url`, using the detected `extension`
+///
+/// Takes the stem of the URL's last path segment, e.g. "photo" from
+/// `https://example.com/images/photo.jpeg?w=800`, or "image" when there isn't one.
+fn remote_image_file_name(url: &tauri::Url, extension: &str) -> String {
+    let segment = url
+        .path_segments()
+        .and_then(|mut segments| segments.rfind(|segment| !segment.is_empty()))
+        .unwrap_or("")
+        .replace("%20", " ");
+    let stem = Path::new(&segment)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .filter(|stem| stem.chars().any(|c| c.is_alphanumeric()))
+        .unwrap_or_else(|| "image".to_string());
+    format!("{stem}.{extension}")
+}
 
-    let validated_final_path = loop {
-        // Validate the candidate path is within project bounds
-        let final_path_str = final_path.to_string_lossy().to_string();
-        let validated_path = validate_project_path(&final_path_str, &project_path)?;
+/// Fetches an image, enforcing the size limit while it streams in
+async fn fetch_remote_image(url: &tauri::Url) -> Result<Vec<u8>, String> {
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(REMOTE_IMAGE_TIMEOUT_SECS))
+        .user_agent("astro-editor")
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+
+    let mut response = client
+        .get(url.as_str())
+        .send()
+        .await
+        .map_err(|e| format!("Failed to download image: {e}"))?;
+    if !response.status().is_success() {
+        return Err(format!(
+            "Failed to download image: HTTP {}",
+            response.status().as_u16()
+        ));
+    }
+
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("")
+        .to_lowercase();
+    let maybe_image = content_type.is_empty()
+        || content_type.starts_with("image/")
+        || content_type.contains("octet-stream");
+    if !maybe_image {
+        return Err(format!("URL is not an image ({content_type})"));
+    }
+
+    let too_large = || {
+        format!(
+            "Image is larger than {} MB",
+            MAX_REMOTE_IMAGE_BYTES / (1024 * 1024)
+        )
+    };
+    if response
+        .content_length()
+        .is_some_and(|length| length > MAX_REMOTE_IMAGE_BYTES)
+    {
+        return Err(too_large());
+    }
+
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| format!("Failed to download image: {e}"))?
+    {
+        bytes.extend_from_slice(&chunk);
+        if bytes.len() as u64 > MAX_REMOTE_IMAGE_BYTES {
+            return Err(too_large());
+        }
+    }
+    Ok(bytes)
+}
+
+/// Downloads a remote image into the collection's assets directory
+///
+/// The image is named like a copied asset, and SVGs are sanitized. Downloads over
+/// 20 MB, taking longer than 30 seconds, or that aren't a supported image are rejected.
+///
+/// # Returns
+/// The path to reference the image by from `current_file_path`
+#[tauri::command]
+#[specta::specta]
+pub async fn download_image_to_assets(
+    url: String,
+    project_path: String,
+    collection: String,
+    assets_directory: Option<String>,
+    current_file_path: String,
+    use_relative_paths: bool,
+    use_path_aliases: bool,
+) -> Result<String, String> {
+    let url = tauri::Url::parse(url.trim()).map_err(|e| format!("Invalid URL: {e}"))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(format!("Unsupported URL scheme: {}", url.scheme()));
+    }
+
+    let validated_project_root = Path::new(&project_path)
+        .canonicalize()
+        .map_err(|_| "Invalid project root".to_string())?;
+    let assets_base = if let Some(assets_override) = assets_directory {
+        validated_project_root.join(assets_override)
+    } else {
+        validated_project_root.join("src").join("assets")
+    };
+    let assets_dir = assets_base.join(&collection);
 
-        // Try to create the destination file atomically using create_new()
-        // This fails if the file already exists, preventing race conditions
-        match fs::OpenOptions::new()
-            .write(true)
-            .create_new(true)
-            .open(&validated_path)
-        {
-            Ok(_) => {
-                // File created successfully, now copy the content
-                // Note: We created an empty file, so we need to copy over it
-                fs::copy(&source_path, &validated_path)
-                    .map_err(|e| format!("Failed to copy file content: {e}"))?;
-                break validated_path;
-            }
-            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
-                // File exists, try with counter suffix
-                if counter > MAX_ATTEMPTS {
-                    return Err(format!(
-                        "Could not find available filename after {MAX_ATTEMPTS} attempts"
-                    ));
-                }
+    let bytes = fetch_remote_image(&url).await?;
+    let extension = remote_image_extension(&bytes).ok_or("URL is not a supported image type")?;
+    let file_name = remote_image_file_name(&url, extension);
 
-                let name_with_counter = if extension.is_empty() {
-                    format!("{date_prefix}-{kebab_name}-{counter}")
-                } else {
-                    format!("{date_prefix}-{kebab_name}-{counter}.{extension}")
-                };
-                final_path = assets_dir.join(name_with_counter);
-                counter += 1; // Increment for next iteration
-            }
-            Err(e) => {
-                // Other error (permissions, disk full, etc.)
-                return Err(format!("Failed to create file: {e}"));
-            }
-        }
+    let sanitized = if extension == "svg" {
+        let svg = String::from_utf8(bytes.clone()).map_err(|_| "Invalid SVG encoding")?;
+        Some(sanitize_svg(&svg)?)
+    } else {
+        None
     };
 
-    // Get the path relative to project root
+    dry_run::create_dir_all(&assets_dir)
+        .map_err(|e| format!("Failed to create assets directory: {e}"))?;
+    let assets_dir = validate_assets_path(&assets_dir, &validated_project_root, &assets_base)?;
+
+    let validated_final_path = create_asset_file(&assets_dir, &file_name, &project_path)?;
+    match &sanitized {
+        Some(svg) => dry_run::write(&validated_final_path, &svg.content),
+        None => dry_run::write(&validated_final_path, &bytes),
+    }
+    .map_err(|e| format!("Failed to write file content: {e}"))?;
+    audit_log::record(
+        AuditAction::Create,
+        &validated_final_path,
+        None,
+        "download_image_to_assets",
+    );
+    if let Some(svg) = &sanitized {
+        report_sanitized_svg(&file_name, &svg.removed);
+    }
+
     let project_relative_path = validated_final_path
         .strip_prefix(&validated_project_root)
         .map_err(|_| "Failed to create relative path")?
         .to_string_lossy()
         .to_string();
 
-    // Convert to appropriate path style based on setting
-    let final_path = if use_relative_paths {
-        calculate_relative_path(&current_file_path, &project_path, &project_relative_path)?
-    } else {
-        // Absolute path from project root (legacy behavior)
-        format!("/{}", project_relative_path.replace('\\', "/"))
-    };
-
-    Ok(final_path)
+    format_asset_path(
+        &current_file_path,
+        &project_path,
+        &project_relative_path,
+        use_relative_paths,
+        use_path_aliases,
+    )
 }
 
 #[derive(serde::Serialize, serde::Deserialize, specta::Type)]
@@ -321,20 +929,148 @@ pub struct MarkdownContent {
     pub frontmatter: IndexMap<String, Value>,
     pub content: String,
     pub raw_frontmatter: String,
-    pub imports: String, // MDX imports to hide from editor
+    pub imports: String,      // MDX imports to hide from editor
+    pub content_hash: String, // Hash of the whole file, passed back on save to detect edits on disk
+}
+
+/// Default limit for loading a file into the editor (5 MB)
+const DEFAULT_MAX_EDITOR_FILE_SIZE_MB: u32 = 5;
+
+/// Error returned when a file can't be loaded into the editor
+#[derive(Debug, serde::Serialize, serde::Deserialize, specta::Type, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum FileLoadError {
+    /// The file exceeds the size limit; retry with `allow_large_file` or edit frontmatter only
+    #[serde(rename_all = "camelCase")]
+    TooLarge {
+        #[specta(type = f64)]
+        size_bytes: u64,
+        #[specta(type = f64)]
+        limit_bytes: u64,
+    },
+    /// The frontmatter isn't valid YAML or isn't closed; `raw_frontmatter` holds the lines
+    /// after the opening `---` so they can be shown with the broken line highlighted
+    #[serde(rename_all = "camelCase")]
+    InvalidFrontmatter {
+        error: FrontmatterError,
+        raw_frontmatter: String,
+    },
+    /// Any other failure (missing file, path outside project)
+    Failed { message: String },
+}
+
+impl From<String> for FileLoadError {
+    fn from(message: String) -> Self {
+        FileLoadError::Failed { message }
+    }
+}
+
+/// Frontmatter of a file that's too large to open in the editor
+#[derive(serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontmatterOnlyContent {
+    pub frontmatter: IndexMap<String, Value>,
+    pub raw_frontmatter: String,
+    #[specta(type = f64)]
+    pub size_bytes: u64,
 }
 
+/// Loads a markdown file for the editor
+///
+/// Files above `max_file_size_mb` (default 5 MB) are refused with `FileLoadError::TooLarge`
+/// unless `allow_large_file` is set, so a huge generated file can't freeze the webview.
+/// The frontend can offer `parse_frontmatter_only` instead.
 #[tauri::command]
 #[specta::specta]
 pub async fn parse_markdown_content(
     file_path: String,
     project_root: String,
-) -> Result<MarkdownContent, String> {
+    max_file_size_mb: Option<u32>,
+    allow_large_file: bool,
+) -> Result<MarkdownContent, FileLoadError> {
     let validated_path = validate_project_path(&file_path, &project_root)?;
+
+    if !allow_large_file {
+        let limit_bytes =
+            u64::from(max_file_size_mb.unwrap_or(DEFAULT_MAX_EDITOR_FILE_SIZE_MB)) * 1024 * 1024;
+        let size_bytes = std::fs::metadata(&validated_path)
+            .map_err(|e| format!("Failed to read file: {e}"))?
+            .len();
+
+        if size_bytes > limit_bytes {
+            return Err(FileLoadError::TooLarge {
+                size_bytes,
+                limit_bytes,
+            });
+        }
+    }
+
     let content = std::fs::read_to_string(&validated_path)
         .map_err(|e| format!("Failed to read file: {e}"))?;
+    remember_content(&validated_path, &content);
+
+    parse_frontmatter(&content).map_err(|error| FileLoadError::InvalidFrontmatter {
+        error,
+        raw_frontmatter: content
+            .lines()
+            .skip(1)
+            .take_while(|line| *line != "---")
+            .collect::<Vec<_>>()
+            .join("\n"),
+    })
+}
+
+/// Reads only the frontmatter block of a file, without loading the body
+///
+/// Used for frontmatter-only editing of files too large for the editor. Changes are
+/// saved with `update_frontmatter`, which preserves the body.
+#[tauri::command]
+#[specta::specta]
+pub async fn parse_frontmatter_only(
+    file_path: String,
+    project_root: String,
+) -> Result<FrontmatterOnlyContent, String> {
+    use std::io::{BufRead, BufReader};
+
+    let validated_path = validate_project_path(&file_path, &project_root)?;
+    let file =
+        std::fs::File::open(&validated_path).map_err(|e| format!("Failed to read file: {e}"))?;
+    let size_bytes = file
+        .metadata()
+        .map_err(|e| format!("Failed to read file: {e}"))?
+        .len();
+
+    let mut lines = BufReader::new(file).lines();
+    let mut block = String::new();
+
+    let first_line = lines
+        .next()
+        .transpose()
+        .map_err(|e| format!("Failed to read file: {e}"))?;
+    if first_line.as_deref() == Some("---") {
+        block.push_str("---\n");
+        let mut closed = false;
+        for line in lines {
+            let line = line.map_err(|e| format!("Failed to read file: {e}"))?;
+            block.push_str(&line);
+            block.push('\n');
+            if line == "---" {
+                closed = true;
+                break;
+            }
+        }
+        if !closed {
+            return Err("Frontmatter not properly closed with '---'".to_string());
+        }
+    }
+
+    let parsed = parse_frontmatter(&block)?;
 
-    parse_frontmatter(&content)
+    Ok(FrontmatterOnlyContent {
+        frontmatter: parsed.frontmatter,
+        raw_frontmatter: parsed.raw_frontmatter,
+        size_bytes,
+    })
 }
 
 #[tauri::command]
@@ -343,6 +1079,7 @@ pub async fn update_frontmatter(
     file_path: String,
     frontmatter: IndexMap<String, Value>,
     project_root: String,
+    origin: Option<String>,
 ) -> Result<(), String> {
     let validated_path = validate_project_path(&file_path, &project_root)?;
     let content = std::fs::read_to_string(&validated_path)
@@ -355,11 +1092,271 @@ pub async fn update_frontmatter(
         &parsed.content,
     )?;
 
-    std::fs::write(&validated_path, new_content).map_err(|e| format!("Failed to write file: {e}"))
+    dry_run::write(&validated_path, new_content)
+        .map_err(|e| format!("Failed to write file: {e}"))?;
+    audit_log::record(
+        AuditAction::Write,
+        &validated_path,
+        None,
+        origin.as_deref().unwrap_or("update_frontmatter"),
+    );
+    Ok(())
+}
+
+/// Why `apply_raw_frontmatter` didn't write the file
+#[derive(Debug, serde::Serialize, serde::Deserialize, specta::Type, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum RawFrontmatterError {
+    /// The YAML doesn't parse as a mapping of fields; located as in the file
+    InvalidYaml { error: FrontmatterError },
+    /// The YAML parses but breaks the collection's schema. Applying again without a
+    /// schema writes it anyway.
+    SchemaViolations { violations: Vec<FieldViolation> },
+    /// Any other failure (missing file, path outside project)
+    Failed { message: String },
+}
+
+impl From<String> for RawFrontmatterError {
+    fn from(message: String) -> Self {
+        RawFrontmatterError::Failed { message }
+    }
+}
+
+/// Parses frontmatter YAML typed by hand, before it's written between `---` lines
+fn parse_raw_frontmatter(
+    raw_frontmatter: &str,
+) -> Result<IndexMap<String, Value>, FrontmatterError> {
+    // A bare --- would close the frontmatter early and push the rest into the body
+    if let Some(index) = raw_frontmatter.lines().position(|line| line == "---") {
+        return Err(FrontmatterError {
+            message: "A line containing only `---` would end the frontmatter early".to_string(),
+            line: Some(index as u32 + 2),
+            column: None,
+            snippet: Some("---".to_string()),
+            cause: None,
+            suggestion: Some("Remove the line, or indent it if it's part of a value".to_string()),
+        });
+    }
+    if raw_frontmatter.trim().is_empty() {
+        return Ok(IndexMap::new());
+    }
+    serde_norway::from_str(raw_frontmatter).map_err(|e| explain_yaml_error(raw_frontmatter, &e))
+}
+
+/// Replaces the frontmatter block of `content` with `raw_frontmatter`, keeping everything
+/// after the closing `---` byte for byte
+fn replace_raw_frontmatter(content: &str, raw_frontmatter: &str) -> Result<String, String> {
+    let is_delimiter = |line: &str| line.trim_end_matches(['\n', '\r']) == "---";
+    let mut lines = content.split_inclusive('\n');
+    let body = if let Some(first) = lines.next().filter(|line| is_delimiter(line)) {
+        let mut offset = first.len();
+        let mut closed = false;
+        for line in lines {
+            offset += line.len();
+            if is_delimiter(line) {
+                closed = true;
+                break;
+            }
+        }
+        if !closed {
+            return Err("Frontmatter not properly closed with '---'".to_string());
+        }
+        content[offset..].to_string()
+    } else if content.is_empty() {
+        String::new()
+    } else {
+        format!("\n{content}")
+    };
+
+    let raw_frontmatter = raw_frontmatter.trim_end();
+    if raw_frontmatter.is_empty() {
+        return Ok(body.trim_start_matches('\n').to_string());
+    }
+    Ok(format!("---\n{raw_frontmatter}\n---\n{body}"))
+}
+
+/// Replaces a file's frontmatter with YAML edited by hand, for fields the form can't edit
+///
+/// Nothing is written unless the YAML parses and, when `complete_schema` is given, passes
+/// the same checks as `validate_frontmatter`. The rebuilt file is parsed again before it's
+/// written, so an edit can't leave the file unreadable.
+///
+/// # Arguments
+/// * `file_path` - The absolute path to the file
+/// * `project_root` - The absolute path to the project root
+/// * `raw_frontmatter` - The YAML to write between the `---` lines
+/// * `complete_schema` - The collection's serialized complete schema; omit to skip schema checks
+///
+/// # Returns
+/// The file as it now parses, for the editor to reload
+#[tauri::command]
+#[specta::specta]
+pub async fn apply_raw_frontmatter(
+    file_path: String,
+    project_root: String,
+    raw_frontmatter: String,
+    complete_schema: Option<String>,
+    origin: Option<String>,
+) -> Result<MarkdownContent, RawFrontmatterError> {
+    let validated_path = validate_project_path(&file_path, &project_root)?;
+    let content = std::fs::read_to_string(&validated_path)
+        .map_err(|e| format!("Failed to read file: {e}"))?;
+
+    let frontmatter = parse_raw_frontmatter(&raw_frontmatter)
+        .map_err(|error| RawFrontmatterError::InvalidYaml { error })?;
+
+    if let Some(complete_schema) = complete_schema {
+        let schema: SchemaDefinition = serde_json::from_str(&complete_schema)
+            .map_err(|e| format!("Failed to parse schema: {e}"))?;
+        let violations = find_violations(&schema, &frontmatter);
+        if !violations.is_empty() {
+            return Err(RawFrontmatterError::SchemaViolations { violations });
+        }
+    }
+
+    let new_content = replace_raw_frontmatter(&content, &raw_frontmatter)?;
+    let parsed =
+        parse_frontmatter(&new_content).map_err(|e| format!("Rebuilt file doesn't parse: {e}"))?;
+    if parsed.frontmatter != frontmatter {
+        return Err("Rebuilt file doesn't parse to the same frontmatter"
+            .to_string()
+            .into());
+    }
+
+    dry_run::write(&validated_path, new_content)
+        .map_err(|e| format!("Failed to write file: {e}"))?;
+    audit_log::record(
+        AuditAction::Write,
+        &validated_path,
+        None,
+        origin.as_deref().unwrap_or("apply_raw_frontmatter"),
+    );
+    Ok(parsed)
+}
+
+/// A frontmatter flag changed by `toggle_frontmatter_flag`, also emitted as
+/// `frontmatter-flag-toggled`
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontmatterFlagToggle {
+    pub file_path: String,
+    pub field: String,
+    /// The field's new value
+    pub value: Value,
+}
+
+/// Returns the value a flag toggles to
+///
+/// Booleans flip, with a missing field counting as `false`. Enum fields (when `values`
+/// is given) advance to the next value, wrapping round; a missing or unknown value
+/// becomes the first.
+fn next_flag_value(current: Option<&Value>, values: Option<&[String]>) -> Result<Value, String> {
+    match values {
+        Some([]) => Err("No enum values to cycle through".to_string()),
+        Some(values) => {
+            let position = current
+                .and_then(Value::as_str)
+                .and_then(|current| values.iter().position(|v| v == current));
+            let next = position.map_or(0, |i| (i + 1) % values.len());
+            Ok(Value::String(values[next].clone()))
+        }
+        None => match current {
+            None | Some(Value::Null) => Ok(Value::Bool(true)),
+            Some(Value::Bool(flag)) => Ok(Value::Bool(!flag)),
+            Some(other) => Err(format!("Field is not a boolean: {other}")),
+        },
+    }
+}
+
+/// Writes a Markdown file the way an editor save does
+///
+/// The write is atomic and kept as a version. The app already knows the new content,
+/// so the watcher needn't report it back, and it becomes the merge base for the next
+/// save; neither applies during a dry run, when nothing is written.
+fn write_markdown_file(path: &Path, content: &str, origin: &str) -> Result<(), String> {
+    let writing = !dry_run::is_active();
+    if writing {
+        watcher::ignore_write(path);
+    }
+    dry_run::write_atomic(path, content).map_err(|e| format!("Failed to write file: {e}"))?;
+    audit_log::record(AuditAction::Write, path, None, origin);
+    if origin == "autosave" {
+        versions::snapshot_autosave(path, content);
+    } else {
+        versions::snapshot(path, content);
+    }
+    if writing {
+        remember_content(path, content);
+    }
+    Ok(())
+}
+
+/// Toggles a top-level frontmatter field in a file, returning its new value
+fn toggle_flag_in_file(
+    path: &Path,
+    field: &str,
+    values: Option<&[String]>,
+) -> Result<Value, String> {
+    let content = std::fs::read_to_string(path).map_err(|e| format!("Failed to read file: {e}"))?;
+    let mut parsed = parse_frontmatter(&content)?;
+
+    let value = next_flag_value(parsed.frontmatter.get(field), values)?;
+    parsed.frontmatter.insert(field.to_string(), value.clone());
+
+    let new_content = rebuild_markdown_with_frontmatter_and_imports(
+        &parsed.frontmatter,
+        &parsed.imports,
+        &parsed.content,
+    )?;
+    write_markdown_file(path, &new_content, "menu-flag-toggle")?;
+
+    Ok(value)
+}
+
+/// Toggles a boolean frontmatter field, or cycles an enum field, directly on disk
+///
+/// Used by the Edit > Frontmatter menu shortcuts. Emits `frontmatter-flag-toggled` so
+/// the editor and file list can reload.
+///
+/// # Arguments
+/// * `file_path` - The absolute path to the file
+/// * `project_root` - The absolute path to the project root
+/// * `field` - Top-level frontmatter field, e.g. `draft`
+/// * `values` - Enum values to cycle through; omit for boolean fields
+#[tauri::command]
+#[specta::specta]
+pub async fn toggle_frontmatter_flag(
+    app: tauri::AppHandle,
+    file_path: String,
+    project_root: String,
+    field: String,
+    values: Option<Vec<String>>,
+) -> Result<FrontmatterFlagToggle, String> {
+    let validated_path = validate_project_path(&file_path, &project_root)?;
+    let value = toggle_flag_in_file(&validated_path, &field, values.as_deref())?;
+
+    let toggle = FrontmatterFlagToggle {
+        file_path: validated_path.to_string_lossy().to_string(),
+        field,
+        value,
+    };
+    if let Err(e) = app.emit("frontmatter-flag-toggled", &toggle) {
+        log::warn!("Astro Editor [FRONTMATTER] Failed to emit flag toggle: {e}");
+    }
+
+    Ok(toggle)
 }
 
+/// Saves a file from the editor
+///
+/// With `expected_hash` (the `content_hash` the file was loaded with), the save is
+/// refused with `FileSaveError::Conflict` if the file has changed on disk since.
+///
+/// # Returns
+/// The content hash of the saved file, to expect on the next save
 #[tauri::command]
 #[specta::specta]
+#[allow(clippy::too_many_arguments)]
 pub async fn save_markdown_content(
     file_path: String,
     frontmatter: Option<IndexMap<String, Value>>,
@@ -368,16 +1365,42 @@ pub async fn save_markdown_content(
     imports: String,
     schema_field_order: Option<Vec<String>>,
     project_root: String,
-) -> Result<(), String> {
+    origin: Option<String>,
+    reading_time: Option<ReadingTimeSettings>,
+    mdx_escaping: Option<MdxEscapingSettings>,
+    date_formats: Option<DateFormatSettings>,
+    expected_hash: Option<String>,
+) -> Result<String, FileSaveError> {
     let validated_path = validate_project_path(&file_path, &project_root)?;
 
+    // Escape prose characters MDX would parse as code, when the project opts in
+    let content = match mdx_escaping {
+        Some(settings) => {
+            mdx_escaping::apply_on_save(&validated_path, content, &imports, &settings)
+        }
+        None => content,
+    };
+
+    // Keep the schema's reading time field current when the project opts in
+    let (frontmatter, raw_frontmatter) = match reading_time {
+        Some(settings) => reading_time::apply_on_save(
+            frontmatter,
+            raw_frontmatter,
+            &content,
+            schema_field_order.as_deref(),
+            &settings,
+        )?,
+        None => (frontmatter, raw_frontmatter),
+    };
+
     let new_content = match (frontmatter, raw_frontmatter) {
         // Frontmatter was edited - reorder and normalize
-        (Some(fm), _) => rebuild_markdown_with_frontmatter_and_imports_ordered(
+        (Some(fm), _) => rebuild_markdown_with_date_formats(
             &fm,
             &imports,
             &content,
             schema_field_order,
+            &date_formats.unwrap_or_default(),
         )?,
         // Frontmatter unchanged - preserve original (non-empty)
         (None, Some(ref raw)) if !raw.trim().is_empty() => {
@@ -387,14 +1410,22 @@ pub async fn save_markdown_content(
         _ => rebuild_markdown_content_only(&imports, &content)?,
     };
 
-    std::fs::write(&validated_path, new_content).map_err(|e| format!("Failed to write file: {e}"))
+    check_unchanged_on_disk(&validated_path, expected_hash.as_deref(), &new_content)?;
+
+    write_markdown_file(
+        &validated_path,
+        &new_content,
+        origin.as_deref().unwrap_or("save_markdown_content"),
+    )?;
+    unsaved_drafts::discard(&validated_path, &project_root);
+    Ok(content_hash(&new_content))
 }
 
 pub fn parse_frontmatter_internal(content: &str) -> Result<MarkdownContent, String> {
-    parse_frontmatter(content)
+    Ok(parse_frontmatter(content)?)
 }
 
-fn parse_frontmatter(content: &str) -> Result<MarkdownContent, String> {
+fn parse_frontmatter(content: &str) -> Result<MarkdownContent, FrontmatterError> {
     // Track if original content ends with newline - lines() drops this info
     let original_ends_with_newline = content.ends_with('\n');
     let lines: Vec<&str> = content.lines().collect();
@@ -413,6 +1444,7 @@ fn parse_frontmatter(content: &str) -> Result<MarkdownContent, String> {
             content: body_content,
             raw_frontmatter: String::new(),
             imports,
+            content_hash: content_hash(content),
         });
     }
 
@@ -426,7 +1458,7 @@ fn parse_frontmatter(content: &str) -> Result<MarkdownContent, String> {
     }
 
     let Some(end_index) = frontmatter_end else {
-        return Err("Frontmatter not properly closed with '---'".to_string());
+        return Err(FrontmatterError::unclosed());
     };
 
     // Extract frontmatter lines (between the --- markers)
@@ -437,7 +1469,8 @@ fn parse_frontmatter(content: &str) -> Result<MarkdownContent, String> {
     let frontmatter: IndexMap<String, Value> = if raw_frontmatter.trim().is_empty() {
         IndexMap::new()
     } else {
-        parse_yaml_to_json(&raw_frontmatter)?
+        serde_norway::from_str(&raw_frontmatter)
+            .map_err(|e| explain_yaml_error(&raw_frontmatter, &e))?
     };
 
     // Extract content after frontmatter and process imports
@@ -461,6 +1494,7 @@ fn parse_frontmatter(content: &str) -> Result<MarkdownContent, String> {
         content: body_content,
         raw_frontmatter,
         imports,
+        content_hash: content_hash(content),
     })
 }
 
@@ -599,41 +1633,127 @@ fn extract_imports_from_content(lines: &[&str]) -> (String, String) {
     (imports_string, content_string)
 }
 
-/// Normalizes ISO datetime strings to date-only format recursively
-/// Converts "2024-01-15T00:00:00Z" -> "2024-01-15"
-fn normalize_dates(frontmatter: &mut IndexMap<String, Value>) {
-    for (_, value) in frontmatter.iter_mut() {
-        normalize_value(value);
+/// How a frontmatter date is written on save
+#[derive(
+    Debug, Clone, Copy, Default, serde::Serialize, serde::Deserialize, specta::Type, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum DateFormat {
+    /// Datetimes are cut to the date, e.g. `2024-01-15`
+    #[default]
+    DateOnly,
+    /// Dates are kept as written, as quoted strings, e.g. `'2024-01-15T18:30:00Z'`
+    DateTime,
+    /// Dates are kept as written and unquoted, so YAML reads them as timestamps
+    Timestamp,
+}
+
+/// The `dateFormats` setting
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, specta::Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DateFormatSettings {
+    /// Format for dates in fields not listed in `fields` (defaults to `dateOnly`)
+    #[serde(default)]
+    #[specta(optional)]
+    pub default: Option<DateFormat>,
+    /// Formats by field name, with nested fields as dotted paths (e.g. `event.start`)
+    #[serde(default)]
+    #[specta(optional)]
+    pub fields: Option<HashMap<String, DateFormat>>,
+}
+
+impl DateFormatSettings {
+    pub(crate) fn format_for(&self, field_path: &str) -> DateFormat {
+        self.fields
+            .as_ref()
+            .and_then(|fields| fields.get(field_path))
+            .copied()
+            .or(self.default)
+            .unwrap_or_default()
+    }
+}
+
+/// Placeholder for a quoted date while frontmatter is serialized, as `serde_norway`
+/// only quotes strings that would otherwise read as another type
+fn quoted_date_placeholder(index: usize) -> String {
+    format!("__astro_editor_quoted_date_{index}__")
+}
+
+/// Whether a string is an ISO date or datetime, e.g. `2024-01-15` or `2024-01-15T18:30:00+02:00`
+fn is_iso_date(s: &str) -> bool {
+    static ISO_DATE: OnceLock<Regex> = OnceLock::new();
+    ISO_DATE
+        .get_or_init(|| {
+            Regex::new(
+                r"^\d{4}-\d{2}-\d{2}([Tt ]\d{2}:\d{2}(:\d{2}(\.\d+)?)?(Z|[+-]\d{2}:?\d{2})?)?$",
+            )
+            .unwrap()
+        })
+        .is_match(s)
+}
+
+/// Writes dates in frontmatter recursively in their configured formats
+///
+/// Date-only fields have ISO datetime strings cut to the date
+/// ("2024-01-15T00:00:00Z" -> "2024-01-15"). Dates to be quoted are swapped for
+/// placeholders and returned, in placeholder order, for `quote_dates` to restore.
+fn normalize_dates(
+    frontmatter: &mut IndexMap<String, Value>,
+    date_formats: &DateFormatSettings,
+) -> Vec<String> {
+    let mut quoted = Vec::new();
+    for (key, value) in frontmatter.iter_mut() {
+        normalize_value(value, key, date_formats, &mut quoted);
     }
+    quoted
 }
 
-/// Recursively normalizes dates in a Value
-fn normalize_value(value: &mut Value) {
+/// Recursively normalizes dates in a Value; array items share their field's path
+fn normalize_value(
+    value: &mut Value,
+    path: &str,
+    date_formats: &DateFormatSettings,
+    quoted: &mut Vec<String>,
+) {
     match value {
-        Value::String(s)
-            if s.len() > 10 && s.contains('T') && (s.ends_with('Z') || s.contains('+')) =>
-        {
-            // If string looks like ISO datetime, extract date part
-            if let Some(date_part) = s.split('T').next() {
-                if date_part.len() == 10 && date_part.matches('-').count() == 2 {
-                    *s = date_part.to_string();
+        Value::String(s) => match date_formats.format_for(path) {
+            DateFormat::DateOnly
+                if s.len() > 10 && s.contains('T') && (s.ends_with('Z') || s.contains('+')) =>
+            {
+                // If string looks like ISO datetime, extract date part
+                if let Some(date_part) = s.split('T').next() {
+                    if date_part.len() == 10 && date_part.matches('-').count() == 2 {
+                        *s = date_part.to_string();
+                    }
                 }
             }
-        }
+            DateFormat::DateTime if is_iso_date(s) => {
+                let placeholder = quoted_date_placeholder(quoted.len());
+                quoted.push(std::mem::replace(s, placeholder));
+            }
+            _ => {}
+        },
         Value::Object(obj) => {
-            for (_, v) in obj.iter_mut() {
-                normalize_value(v);
+            for (key, v) in obj.iter_mut() {
+                normalize_value(v, &format!("{path}.{key}"), date_formats, quoted);
             }
         }
         Value::Array(arr) => {
             for v in arr.iter_mut() {
-                normalize_value(v);
+                normalize_value(v, path, date_formats, quoted);
             }
         }
         _ => {}
     }
 }
 
+/// Puts the dates `normalize_dates` set aside back into serialized YAML, quoted
+fn quote_dates(yaml: String, quoted: &[String]) -> String {
+    quoted.iter().enumerate().fold(yaml, |yaml, (index, date)| {
+        yaml.replacen(&quoted_date_placeholder(index), &format!("'{date}'"), 1)
+    })
+}
+
 /// Builds an ordered IndexMap with schema fields first, then remaining fields alphabetically
 fn build_ordered_frontmatter(
     frontmatter: IndexMap<String, Value>,
@@ -665,7 +1785,7 @@ fn build_ordered_frontmatter(
 }
 
 /// Parse YAML string to IndexMap using serde_norway
-fn parse_yaml_to_json(yaml_str: &str) -> Result<IndexMap<String, Value>, String> {
+pub(crate) fn parse_yaml_to_json(yaml_str: &str) -> Result<IndexMap<String, Value>, String> {
     serde_norway::from_str(yaml_str).map_err(|e| format!("Failed to parse YAML: {e}"))
 }
 
@@ -678,11 +1798,28 @@ fn rebuild_markdown_with_frontmatter_and_imports(
 }
 
 /// Serialize a value to YAML format with proper indentation
-fn rebuild_markdown_with_frontmatter_and_imports_ordered(
+pub(crate) fn rebuild_markdown_with_frontmatter_and_imports_ordered(
+    frontmatter: &IndexMap<String, Value>,
+    imports: &str,
+    content: &str,
+    schema_field_order: Option<Vec<String>>,
+) -> Result<String, String> {
+    rebuild_markdown_with_date_formats(
+        frontmatter,
+        imports,
+        content,
+        schema_field_order,
+        &DateFormatSettings::default(),
+    )
+}
+
+/// Rebuilds a markdown file with its dates written in the configured formats
+pub(crate) fn rebuild_markdown_with_date_formats(
     frontmatter: &IndexMap<String, Value>,
     imports: &str,
     content: &str,
     schema_field_order: Option<Vec<String>>,
+    date_formats: &DateFormatSettings,
 ) -> Result<String, String> {
     let mut result = String::new();
 
@@ -691,15 +1828,15 @@ fn rebuild_markdown_with_frontmatter_and_imports_ordered(
         // Build ordered frontmatter (schema fields first, then alphabetical)
         let ordered = build_ordered_frontmatter(frontmatter.clone(), schema_field_order);
 
-        // Normalize dates (ISO datetime -> date-only)
+        // Normalize dates (ISO datetime -> date-only, unless configured otherwise)
         let mut normalized = ordered;
-        normalize_dates(&mut normalized);
+        let quoted = normalize_dates(&mut normalized, date_formats);
 
         // Serialize to YAML using serde_norway
         result.push_str("---\n");
         let yaml = serde_norway::to_string(&normalized)
             .map_err(|e| format!("Failed to serialize YAML: {e}"))?;
-        result.push_str(&yaml);
+        result.push_str(&quote_dates(yaml, &quoted));
         result.push_str("---\n");
     }
 
@@ -726,7 +1863,7 @@ fn rebuild_markdown_with_frontmatter_and_imports_ordered(
 }
 
 /// Rebuild markdown file preserving original raw frontmatter (no normalization)
-fn rebuild_markdown_with_raw_frontmatter(
+pub(crate) fn rebuild_markdown_with_raw_frontmatter(
     raw_frontmatter: &str,
     imports: &str,
     content: &str,
@@ -760,7 +1897,10 @@ fn rebuild_markdown_with_raw_frontmatter(
 }
 
 /// Rebuild markdown file with no frontmatter (content only)
-fn rebuild_markdown_content_only(imports: &str, content: &str) -> Result<String, String> {
+pub(crate) fn rebuild_markdown_content_only(
+    imports: &str,
+    content: &str,
+) -> Result<String, String> {
     let mut result = String::new();
 
     // Add imports if present
@@ -782,73 +1922,6 @@ fn rebuild_markdown_content_only(imports: &str, content: &str) -> Result<String,
     Ok(result)
 }
 
-#[tauri::command]
-#[specta::specta]
-pub async fn save_recovery_data(app: tauri::AppHandle, data: Value) -> Result<(), String> {
-    let timestamp = Local::now().format("%Y%m%d-%H%M%S").to_string();
-    let filename = data
-        .get("fileName")
-        .and_then(|v| v.as_str())
-        .unwrap_or("untitled");
-
-    // Create recovery directory
-    let recovery_dir = app
-        .path()
-        .resolve("recovery", BaseDirectory::AppLocalData)
-        .map_err(|e| format!("Failed to resolve recovery directory: {e}"))?;
-
-    std::fs::create_dir_all(&recovery_dir)
-        .map_err(|e| format!("Failed to create recovery directory: {e}"))?;
-
-    // Save JSON file with complete state
-    let json_filename = format!("{timestamp}-{filename}.recovery.json");
-    let json_path = recovery_dir.join(&json_filename);
-    let json_content = serde_json::to_string_pretty(&data)
-        .map_err(|e| format!("Failed to serialize recovery data: {e}"))?;
-
-    std::fs::write(&json_path, json_content)
-        .map_err(|e| format!("Failed to write recovery JSON: {e}"))?;
-
-    // Save Markdown file with just the content
-    let md_filename = format!("{timestamp}-{filename}.recovery.md");
-    let md_path = recovery_dir.join(&md_filename);
-    let md_content = data
-        .get("editorContent")
-        .and_then(|v| v.as_str())
-        .unwrap_or("");
-
-    std::fs::write(&md_path, md_content)
-        .map_err(|e| format!("Failed to write recovery Markdown: {e}"))?;
-
-    Ok(())
-}
-
-#[tauri::command]
-#[specta::specta]
-pub async fn save_crash_report(app: tauri::AppHandle, report: Value) -> Result<(), String> {
-    let timestamp = Local::now().format("%Y%m%d-%H%M%S").to_string();
-
-    // Create crash-reports directory
-    let crash_dir = app
-        .path()
-        .resolve("crash-reports", BaseDirectory::AppLocalData)
-        .map_err(|e| format!("Failed to resolve crash reports directory: {e}"))?;
-
-    std::fs::create_dir_all(&crash_dir)
-        .map_err(|e| format!("Failed to create crash reports directory: {e}"))?;
-
-    // Save crash report
-    let filename = format!("{timestamp}-crash.json");
-    let file_path = crash_dir.join(&filename);
-    let content = serde_json::to_string_pretty(&report)
-        .map_err(|e| format!("Failed to serialize crash report: {e}"))?;
-
-    std::fs::write(&file_path, content)
-        .map_err(|e| format!("Failed to write crash report: {e}"))?;
-
-    Ok(())
-}
-
 #[tauri::command]
 #[specta::specta]
 pub async fn get_app_data_dir(app: tauri::AppHandle) -> Result<String, String> {
@@ -860,75 +1933,6 @@ pub async fn get_app_data_dir(app: tauri::AppHandle) -> Result<String, String> {
     Ok(app_data_dir.to_string_lossy().to_string())
 }
 
-/// Validates that a file path is within the app data directory
-///
-/// This function prevents path traversal attacks for app data operations
-/// by ensuring all file operations stay within the app's data directory.
-/// Creates the app data directory if it doesn't exist.
-fn validate_app_data_path(file_path: &str, app_data_dir: &str) -> Result<PathBuf, String> {
-    use log::info;
-
-    let app_data_dir = Path::new(app_data_dir);
-
-    // Create app data directory if it doesn't exist
-    if !app_data_dir.exists() {
-        info!(
-            "Astro Editor [PROJECT_REGISTRY] Creating app data directory: {}",
-            app_data_dir.display()
-        );
-        std::fs::create_dir_all(app_data_dir)
-            .map_err(|e| format!("Failed to create app data directory: {e}"))?;
-        info!("Astro Editor [PROJECT_REGISTRY] App data directory created successfully");
-    }
-
-    // If file_path is relative (just a filename), join it with app_data_dir
-    let file_path = if Path::new(file_path).is_absolute() {
-        Path::new(file_path).to_path_buf()
-    } else {
-        app_data_dir.join(file_path)
-    };
-
-    // Resolve canonical paths to handle symlinks and .. traversal
-    let canonical_file = file_path
-        .canonicalize()
-        .or_else(|_| {
-            // If file doesn't exist, try to canonicalize parent and append filename
-            if let (Some(parent), Some(filename)) = (file_path.parent(), file_path.file_name()) {
-                // Ensure parent directory exists
-                if !parent.as_os_str().is_empty() && !parent.exists() {
-                    info!(
-                        "Astro Editor [PROJECT_REGISTRY] Creating parent directory: {}",
-                        parent.display()
-                    );
-                    if let Err(e) = std::fs::create_dir_all(parent) {
-                        return Err(std::io::Error::new(
-                            std::io::ErrorKind::Other,
-                            format!("Failed to create parent directory: {e}"),
-                        ));
-                    }
-                }
-                parent.canonicalize().map(|p| p.join(filename))
-            } else {
-                Err(std::io::Error::new(
-                    std::io::ErrorKind::NotFound,
-                    "Invalid file path",
-                ))
-            }
-        })
-        .map_err(|e| format!("Invalid file path: {e}"))?;
-
-    let canonical_app_data = app_data_dir
-        .canonicalize()
-        .map_err(|e| format!("Invalid app data directory: {e}"))?;
-
-    // Ensure file is within app data bounds
-    canonical_file
-        .strip_prefix(&canonical_app_data)
-        .map_err(|_| "File outside app data directory".to_string())?;
-
-    Ok(canonical_file)
-}
-
 #[tauri::command]
 #[specta::specta]
 pub async fn write_app_data_file(
@@ -987,6 +1991,7 @@ pub async fn write_file_content(
     file_path: String,
     content: String,
     project_root: String,
+    origin: Option<String>,
 ) -> Result<(), String> {
     let validated_path = validate_project_path(&file_path, &project_root)?;
 
@@ -996,7 +2001,14 @@ pub async fn write_file_content(
             .map_err(|e| format!("Failed to create directories: {e}"))?;
     }
 
-    std::fs::write(&validated_path, content).map_err(|e| format!("Failed to write file: {e}"))
+    std::fs::write(&validated_path, content).map_err(|e| format!("Failed to write file: {e}"))?;
+    audit_log::record(
+        AuditAction::Write,
+        &validated_path,
+        None,
+        origin.as_deref().unwrap_or("write_file_content"),
+    );
+    Ok(())
 }
 
 #[tauri::command]
@@ -1017,13 +2029,7 @@ pub async fn create_directory(path: String, project_root: String) -> Result<(),
 #[tauri::command]
 #[specta::specta]
 pub async fn is_path_in_project(file_path: String, project_path: String) -> bool {
-    let file = Path::new(&file_path);
-    let project = Path::new(&project_path);
-
-    file.canonicalize()
-        .ok()
-        .and_then(|f| project.canonicalize().ok().map(|p| f.starts_with(p)))
-        .unwrap_or(false)
+    is_in_project(Path::new(&file_path), Path::new(&project_path))
 }
 
 /// Gets the relative path of a file from the project root
@@ -1041,6 +2047,7 @@ pub async fn get_relative_path(
     project_path: String,
     current_file_path: String,
     use_relative_paths: bool,
+    use_path_aliases: bool,
 ) -> Result<String, String> {
     let file = Path::new(&file_path)
         .canonicalize()
@@ -1050,19 +2057,17 @@ pub async fn get_relative_path(
         .map_err(|e| format!("Invalid project path: {e}"))?;
 
     let project_relative_path = file
-        .strip_prefix(&project)
-        .map(|p| p.to_string_lossy().to_string())
-        .map_err(|_| "Path not in project".to_string())?;
-
-    // Convert to appropriate path style based on setting
-    let final_path = if use_relative_paths {
-        calculate_relative_path(&current_file_path, &project_path, &project_relative_path)?
-    } else {
-        // Absolute path from project root (legacy behavior)
-        format!("/{}", project_relative_path.replace('\\', "/"))
-    };
+        .strip_prefix(&project)
+        .map(|p| p.to_string_lossy().to_string())
+        .map_err(|_| "Path not in project".to_string())?;
 
-    Ok(final_path)
+    format_asset_path(
+        &current_file_path,
+        &project_path,
+        &project_relative_path,
+        use_relative_paths,
+        use_path_aliases,
+    )
 }
 
 /// Resolves an image path from markdown to an absolute filesystem path
@@ -1070,6 +2075,7 @@ pub async fn get_relative_path(
 /// Handles both absolute paths (starting with /) and relative paths (starting with ./ or ../)
 /// For absolute paths: treats them as relative to project root
 /// For relative paths: resolves relative to the current file's directory
+/// For aliased paths (e.g. "@assets/image.png"): resolves using tsconfig.json path aliases
 ///
 /// # Arguments
 /// * `image_path` - The image path from markdown (e.g., "/src/assets/image.png" or "./image.png")
@@ -1085,7 +2091,17 @@ pub async fn resolve_image_path(
     project_root: String,
     current_file_path: Option<String>,
 ) -> Result<String, String> {
-    let project_root_path = Path::new(&project_root);
+    resolve_asset_path(&image_path, &project_root, current_file_path.as_deref())
+        .map(|path| path.to_string_lossy().to_string())
+}
+
+/// Resolves an asset reference to an existing file, as `resolve_image_path` does
+pub(crate) fn resolve_asset_path(
+    image_path: &str,
+    project_root: &str,
+    current_file_path: Option<&str>,
+) -> Result<PathBuf, String> {
+    let project_root_path = Path::new(project_root);
 
     // Determine the absolute path based on the image path format
     let absolute_path = if image_path.starts_with('/') {
@@ -1096,21 +2112,39 @@ pub async fn resolve_image_path(
         // Relative path - need current file path to resolve
         let current_file = current_file_path
             .ok_or_else(|| "Cannot resolve relative path without current file path".to_string())?;
-        let current_file_path = Path::new(&current_file);
-        let current_dir = current_file_path
+        let current_dir = Path::new(current_file)
             .parent()
             .ok_or_else(|| "Invalid current file path".to_string())?;
-        current_dir.join(&image_path)
+        current_dir.join(image_path)
+    } else if let Some(aliased) =
+        resolve_aliased_path(image_path, &load_path_aliases(project_root_path))
+    {
+        // tsconfig path alias (e.g. "@assets/image.png")
+        aliased
     } else {
         // Ambiguous path (no leading / or ./) - try as absolute from project root first
-        project_root_path.join(&image_path)
+        project_root_path.join(image_path)
     };
 
-    // Validate the path is within project bounds and exists
+    // Validate the path is within project bounds
     let validated_path =
-        validate_project_path(absolute_path.to_string_lossy().as_ref(), &project_root)?;
+        validate_project_path(absolute_path.to_string_lossy().as_ref(), project_root);
+
+    // Site-absolute paths missing from the project root are served from public/, even
+    // when their folder doesn't exist at the root at all
+    if image_path.starts_with('/') && !validated_path.as_ref().is_ok_and(|path| path.exists()) {
+        let public_path = project_root_path
+            .join("public")
+            .join(image_path.trim_start_matches('/'));
+        if let Ok(validated_public) = validate_public_path(&public_path, project_root_path) {
+            if validated_public.exists() {
+                return Ok(validated_public);
+            }
+        }
+    }
 
     // Check if file exists
+    let validated_path = validated_path?;
     if !validated_path.exists() {
         return Err(format!(
             "Image file not found: {}",
@@ -1118,8 +2152,7 @@ pub async fn resolve_image_path(
         ));
     }
 
-    // Return the absolute path as a string
-    Ok(validated_path.to_string_lossy().to_string())
+    Ok(validated_path)
 }
 
 #[cfg(test)]
@@ -1295,6 +2328,8 @@ mod tests {
             test_file.to_string_lossy().to_string(),
             test_content.to_string(),
             project_root.to_string_lossy().to_string(),
+            None,
+            None,
         )
         .await;
 
@@ -1308,6 +2343,95 @@ mod tests {
         let _ = fs::remove_dir_all(&project_root);
     }
 
+    #[tokio::test]
+    async fn test_write_file_detects_changes_on_disk() {
+        use tempfile::TempDir;
+
+        let project = TempDir::new().unwrap();
+        let file = project.path().join("post.md");
+        fs::write(&file, "---\ntitle: Loaded\n---\n\nBody\n").unwrap();
+        let loaded = parse_frontmatter(&fs::read_to_string(&file).unwrap()).unwrap();
+
+        let file_path = file.to_string_lossy().to_string();
+        let root = project.path().to_string_lossy().to_string();
+        let hash = write_file(
+            file_path.clone(),
+            "first save".to_string(),
+            root.clone(),
+            None,
+            Some(loaded.content_hash.clone()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(hash, content_hash("first save"));
+
+        // Changed by something else since it was loaded
+        fs::write(&file, "pulled from git").unwrap();
+        let result = write_file(
+            file_path.clone(),
+            "second save".to_string(),
+            root.clone(),
+            None,
+            Some(hash),
+        )
+        .await;
+        assert_eq!(
+            result,
+            Err(FileSaveError::Conflict {
+                disk_content: Some("pulled from git".to_string()),
+                disk_hash: Some(content_hash("pulled from git")),
+                attempted_content: "second save".to_string(),
+                base_content: Some("first save".to_string()),
+            })
+        );
+        assert_eq!(fs::read_to_string(&file).unwrap(), "pulled from git");
+
+        // No temporary files are left beside the file
+        let entries: Vec<_> = fs::read_dir(project.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_write_keeps_merge_base() {
+        use tempfile::TempDir;
+
+        let project = TempDir::new().unwrap();
+        let file = project.path().join("post.md");
+        fs::write(&file, "loaded").unwrap();
+        let file_path = file.to_string_lossy().to_string();
+        let root = project.path().to_string_lossy().to_string();
+
+        write_file(
+            file_path.clone(),
+            "saved".to_string(),
+            root.clone(),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+        let (result, changes) = dry_run::run(write_file(
+            file_path,
+            "previewed".to_string(),
+            root,
+            None,
+            None,
+        ))
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(changes.len(), 1);
+
+        assert_eq!(fs::read_to_string(&file).unwrap(), "saved");
+        assert_eq!(
+            known_content()
+                .lock()
+                .unwrap()
+                .get(&file.canonicalize().unwrap())
+                .map(String::as_str),
+            Some("saved")
+        );
+    }
+
     #[tokio::test]
     async fn test_create_file_success() {
         let temp_dir = std::env::temp_dir();
@@ -1330,6 +2454,7 @@ mod tests {
             "test_create.md".to_string(),
             test_content.to_string(),
             project_root.to_string_lossy().to_string(),
+            None,
         )
         .await;
 
@@ -1366,6 +2491,7 @@ mod tests {
             "malicious.md".to_string(),
             "malicious content".to_string(),
             project_root.to_string_lossy().to_string(),
+            None,
         )
         .await;
 
@@ -1399,6 +2525,7 @@ mod tests {
         let result = delete_file(
             test_file.to_string_lossy().to_string(),
             project_root.to_string_lossy().to_string(),
+            None,
         )
         .await;
 
@@ -1427,6 +2554,7 @@ mod tests {
         let result = delete_file(
             malicious_file.to_string_lossy().to_string(),
             project_root.to_string_lossy().to_string(),
+            None,
         )
         .await;
 
@@ -1573,6 +2701,11 @@ This is a test post with arrays."#;
             String::new(), // No imports for this test
             None,          // No schema field order for this test
             project_root.to_string_lossy().to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
         )
         .await;
 
@@ -1984,6 +3117,7 @@ Regular markdown content here."#;
                 .unwrap()
                 .to_string(),
             false, // Use absolute paths for test assertions
+            false,
         )
         .await;
 
@@ -2039,6 +3173,7 @@ Regular markdown content here."#;
                 .unwrap()
                 .to_string(),
             false, // Use absolute paths for test assertions
+            false,
         )
         .await;
 
@@ -2085,6 +3220,7 @@ Regular markdown content here."#;
                 .unwrap()
                 .to_string(),
             false, // Use absolute paths for test assertions
+            false,
         )
         .await;
 
@@ -2101,6 +3237,325 @@ Regular markdown content here."#;
         assert!(dest_path.exists());
     }
 
+    #[tokio::test]
+    async fn test_copy_file_to_assets_sanitizes_svg() {
+        use tempfile::TempDir;
+
+        let source_dir = TempDir::new().unwrap();
+        let project_dir = TempDir::new().unwrap();
+        let svg = source_dir.path().join("logo.svg");
+        fs::write(
+            &svg,
+            "<svg onload=\"alert(1)\"><script>alert(2)</script><rect/></svg>",
+        )
+        .unwrap();
+
+        let relative_path = copy_file_to_assets(
+            svg.to_string_lossy().to_string(),
+            project_dir.path().to_string_lossy().to_string(),
+            "blog".to_string(),
+            project_dir
+                .path()
+                .join("src/content/blog/post.md")
+                .to_string_lossy()
+                .to_string(),
+            false,
+            false,
+        )
+        .await
+        .unwrap();
+
+        let copied = project_dir
+            .path()
+            .join(relative_path.trim_start_matches('/'));
+        assert_eq!(fs::read_to_string(copied).unwrap(), "<svg><rect/></svg>");
+
+        // Malformed SVGs are refused rather than copied unsanitized
+        fs::write(&svg, "<svg><script>").unwrap();
+        let result = copy_file_to_assets(
+            svg.to_string_lossy().to_string(),
+            project_dir.path().to_string_lossy().to_string(),
+            "blog".to_string(),
+            project_dir
+                .path()
+                .join("src/content/blog/post.md")
+                .to_string_lossy()
+                .to_string(),
+            false,
+            false,
+        )
+        .await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_remote_image_extension_and_file_name() {
+        let mut png = Vec::new();
+        image::RgbImage::new(2, 2)
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .unwrap();
+        assert_eq!(remote_image_extension(&png), Some("png"));
+        assert_eq!(
+            remote_image_extension(b"<?xml version=\"1.0\"?>\n<svg></svg>"),
+            Some("svg")
+        );
+        assert_eq!(remote_image_extension(b"<html><body></body></html>"), None);
+
+        let url = tauri::Url::parse("https://example.com/img/My%20Photo.jpeg?w=800").unwrap();
+        assert_eq!(remote_image_file_name(&url, "jpg"), "My Photo.jpg");
+        let url = tauri::Url::parse("https://example.com/").unwrap();
+        assert_eq!(remote_image_file_name(&url, "png"), "image.png");
+    }
+
+    /// Serves a single HTTP response on a local port, returning its URL
+    fn serve_once(content_type: &str, body: Vec<u8>) -> String {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let head = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            body.len()
+        );
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 1024];
+            let _ = stream.read(&mut request);
+            stream.write_all(head.as_bytes()).unwrap();
+            stream.write_all(&body).unwrap();
+        });
+        format!("http://{address}/images/Hero%20Shot.png")
+    }
+
+    #[tokio::test]
+    async fn test_download_image_to_assets() {
+        use tempfile::TempDir;
+
+        let project_dir = TempDir::new().unwrap();
+        let project_path = project_dir.path().to_string_lossy().to_string();
+        let current_file = project_dir
+            .path()
+            .join("src/content/blog/post.md")
+            .to_string_lossy()
+            .to_string();
+
+        let mut png = Vec::new();
+        image::RgbImage::new(2, 2)
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .unwrap();
+        let url = serve_once("image/png", png.clone());
+
+        let path = download_image_to_assets(
+            url,
+            project_path.clone(),
+            "blog".to_string(),
+            None,
+            current_file.clone(),
+            true,
+            false,
+        )
+        .await
+        .unwrap();
+        assert!(path.starts_with("../../assets/blog/"));
+        assert!(path.ends_with("-hero-shot.png"));
+        let saved = project_dir
+            .path()
+            .join("src/assets/blog")
+            .join(path.rsplit('/').next().unwrap());
+        assert_eq!(fs::read(saved).unwrap(), png);
+
+        // Pages and other non-images are refused
+        let url = serve_once("text/html", b"<html></html>".to_vec());
+        let result = download_image_to_assets(
+            url,
+            project_path.clone(),
+            "blog".to_string(),
+            None,
+            current_file.clone(),
+            true,
+            false,
+        )
+        .await;
+        assert!(result.is_err());
+
+        let result = download_image_to_assets(
+            "file:///etc/passwd".to_string(),
+            project_path,
+            "blog".to_string(),
+            None,
+            current_file,
+            true,
+            false,
+        )
+        .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_parse_markdown_content_refuses_large_files() {
+        use tempfile::TempDir;
+
+        let project = TempDir::new().unwrap();
+        let file_path = project.path().join("big.md");
+        let body = "x".repeat(2 * 1024 * 1024);
+        fs::write(&file_path, format!("---\ntitle: Big\n---\n\n{body}\n")).unwrap();
+
+        let file = file_path.to_string_lossy().to_string();
+        let root = project.path().to_string_lossy().to_string();
+
+        let refused = parse_markdown_content(file.clone(), root.clone(), Some(1), false).await;
+        match refused {
+            Err(FileLoadError::TooLarge {
+                size_bytes,
+                limit_bytes,
+            }) => {
+                assert_eq!(limit_bytes, 1024 * 1024);
+                assert!(size_bytes > limit_bytes);
+            }
+            _ => panic!("Expected TooLarge error"),
+        }
+
+        // Override flag loads it anyway
+        let loaded = parse_markdown_content(file.clone(), root.clone(), Some(1), true)
+            .await
+            .unwrap();
+        assert_eq!(loaded.frontmatter["title"], "Big");
+
+        // Frontmatter-only read doesn't need the body
+        let frontmatter = parse_frontmatter_only(file, root).await.unwrap();
+        assert_eq!(frontmatter.frontmatter["title"], "Big");
+        assert_eq!(frontmatter.raw_frontmatter, "title: Big");
+        assert!(frontmatter.size_bytes > 2 * 1024 * 1024);
+    }
+
+    #[tokio::test]
+    async fn test_parse_markdown_content_explains_invalid_frontmatter() {
+        use crate::commands::frontmatter_errors::FrontmatterErrorCause;
+        use tempfile::TempDir;
+
+        let project = TempDir::new().unwrap();
+        let file_path = project.path().join("broken.md");
+        fs::write(
+            &file_path,
+            "---\ntitle: Post\ndescription: Note: read this\n---\n\nBody\n",
+        )
+        .unwrap();
+
+        let result = parse_markdown_content(
+            file_path.to_string_lossy().to_string(),
+            project.path().to_string_lossy().to_string(),
+            None,
+            false,
+        )
+        .await;
+        match result {
+            Err(FileLoadError::InvalidFrontmatter {
+                error,
+                raw_frontmatter,
+            }) => {
+                assert_eq!(raw_frontmatter, "title: Post\ndescription: Note: read this");
+                assert_eq!(error.line, Some(3));
+                assert_eq!(error.cause, Some(FrontmatterErrorCause::UnquotedColon));
+            }
+            _ => panic!("Expected InvalidFrontmatter error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_parse_frontmatter_only_without_frontmatter() {
+        use tempfile::TempDir;
+
+        let project = TempDir::new().unwrap();
+        let file_path = project.path().join("plain.md");
+        fs::write(&file_path, "# Just a heading\n").unwrap();
+
+        let result = parse_frontmatter_only(
+            file_path.to_string_lossy().to_string(),
+            project.path().to_string_lossy().to_string(),
+        )
+        .await
+        .unwrap();
+
+        assert!(result.frontmatter.is_empty());
+        assert!(result.raw_frontmatter.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_copy_file_to_assets_with_path_aliases() {
+        use std::fs;
+        use tempfile::TempDir;
+
+        let source_dir = TempDir::new().unwrap();
+        let project_dir = TempDir::new().unwrap();
+        fs::write(
+            project_dir.path().join("tsconfig.json"),
+            r#"{ "compilerOptions": { "paths": { "@assets/*": ["src/assets/*"] } } }"#,
+        )
+        .unwrap();
+
+        let test_file_path = source_dir.path().join("Hero.png");
+        fs::write(&test_file_path, b"fake image data").unwrap();
+
+        let result = copy_file_to_assets(
+            test_file_path.to_str().unwrap().to_string(),
+            project_dir.path().to_str().unwrap().to_string(),
+            "blog".to_string(),
+            project_dir
+                .path()
+                .join("src/content/blog/post.md")
+                .to_str()
+                .unwrap()
+                .to_string(),
+            true,
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert!(result.starts_with("@assets/blog/"), "Got: {result}");
+        assert!(result.ends_with("-hero.png"));
+
+        // Aliased paths resolve back to the copied file for previews
+        let resolved = resolve_image_path(
+            result,
+            project_dir.path().to_str().unwrap().to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(Path::new(&resolved).exists());
+    }
+
+    #[tokio::test]
+    async fn test_copy_file_to_assets_alias_falls_back_to_relative() {
+        use std::fs;
+        use tempfile::TempDir;
+
+        // No tsconfig.json - aliases requested but unavailable
+        let source_dir = TempDir::new().unwrap();
+        let project_dir = TempDir::new().unwrap();
+        let test_file_path = source_dir.path().join("hero.png");
+        fs::write(&test_file_path, b"fake image data").unwrap();
+
+        let result = copy_file_to_assets(
+            test_file_path.to_str().unwrap().to_string(),
+            project_dir.path().to_str().unwrap().to_string(),
+            "blog".to_string(),
+            project_dir
+                .path()
+                .join("src/content/blog/post.md")
+                .to_str()
+                .unwrap()
+                .to_string(),
+            true,
+            true,
+        )
+        .await
+        .unwrap();
+
+        assert!(result.starts_with("../../assets/blog/"), "Got: {result}");
+    }
+
     #[test]
     fn test_serialize_nested_object_to_yaml() {
         use serde_json::json;
@@ -2343,7 +3798,7 @@ folded: >
         );
 
         // Apply normalization
-        normalize_dates(&mut frontmatter);
+        normalize_dates(&mut frontmatter, &DateFormatSettings::default());
 
         // Verify nested object dates are normalized
         let metadata = frontmatter.get("metadata").unwrap().as_object().unwrap();
@@ -2399,12 +3854,56 @@ folded: >
         ); // Already date-only
 
         let original = frontmatter.clone();
-        normalize_dates(&mut frontmatter);
+        normalize_dates(&mut frontmatter, &DateFormatSettings::default());
 
         // All non-datetime strings should be unchanged
         assert_eq!(frontmatter, original);
     }
 
+    #[test]
+    fn test_date_formats_per_field() {
+        let mut frontmatter = IndexMap::new();
+        frontmatter.insert(
+            "pubDate".to_string(),
+            Value::String("2024-01-15T09:00:00Z".to_string()),
+        );
+        frontmatter.insert(
+            "startsAt".to_string(),
+            Value::String("2024-01-15T18:30:00Z".to_string()),
+        );
+        let mut event = serde_json::Map::new();
+        event.insert(
+            "end".to_string(),
+            Value::String("2024-01-15T21:00:00+00:00".to_string()),
+        );
+        frontmatter.insert("event".to_string(), Value::Object(event));
+        frontmatter.insert(
+            "title".to_string(),
+            Value::String("2024-01-15T09:00:00Z recap".to_string()),
+        );
+
+        let date_formats = DateFormatSettings {
+            default: Some(DateFormat::Timestamp),
+            fields: Some(HashMap::from([
+                ("pubDate".to_string(), DateFormat::DateOnly),
+                ("startsAt".to_string(), DateFormat::DateTime),
+                ("title".to_string(), DateFormat::DateTime),
+            ])),
+        };
+        let result =
+            rebuild_markdown_with_date_formats(&frontmatter, "", "", None, &date_formats).unwrap();
+
+        assert!(result.contains("pubDate: 2024-01-15\n"));
+        assert!(result.contains("startsAt: '2024-01-15T18:30:00Z'\n"));
+        assert!(result.contains("  end: 2024-01-15T21:00:00+00:00\n"));
+        assert!(result.contains("title: 2024-01-15T09:00:00Z recap\n"));
+
+        // Quoted dates are read back as the same strings
+        let parsed = parse_frontmatter(&result).unwrap();
+        assert_eq!(parsed.frontmatter["startsAt"], "2024-01-15T18:30:00Z");
+        assert_eq!(parsed.frontmatter["title"], "2024-01-15T09:00:00Z recap");
+    }
+
     #[test]
     fn test_field_ordering_preserved() {
         // Test that build_ordered_frontmatter respects schema order then alphabetical
@@ -2519,6 +4018,7 @@ This is the main content."#;
             test_file.to_string_lossy().to_string(),
             new_frontmatter,
             project_root.to_string_lossy().to_string(),
+            None,
         )
         .await;
 
@@ -2888,4 +4388,206 @@ X"#;
         let parsed = parse_frontmatter(saved).unwrap();
         assert_eq!(parsed.content, saved);
     }
+
+    #[test]
+    fn test_next_flag_value() {
+        let statuses = ["idea".to_string(), "draft".to_string(), "done".to_string()];
+        let string = |v: &str| Value::String(v.to_string());
+
+        assert_eq!(next_flag_value(None, None), Ok(Value::Bool(true)));
+        assert_eq!(
+            next_flag_value(Some(&Value::Bool(true)), None),
+            Ok(Value::Bool(false))
+        );
+        assert!(next_flag_value(Some(&string("yes")), None).is_err());
+
+        assert_eq!(
+            next_flag_value(Some(&string("draft")), Some(&statuses)),
+            Ok(string("done"))
+        );
+        assert_eq!(
+            next_flag_value(Some(&string("done")), Some(&statuses)),
+            Ok(string("idea"))
+        );
+        assert_eq!(next_flag_value(None, Some(&statuses)), Ok(string("idea")));
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_file_adjusts_frontmatter() {
+        use tempfile::TempDir;
+
+        let temp = TempDir::new().unwrap();
+        let post = temp.path().join("My_Post.md");
+        fs::write(
+            &post,
+            "---\ntitle: Hello\npubDate: 2024-01-01\ndraft: false\n---\n\n# Body\n",
+        )
+        .unwrap();
+        let duplicate = |adjustments| {
+            duplicate_file(
+                post.to_string_lossy().to_string(),
+                temp.path().to_string_lossy().to_string(),
+                adjustments,
+                None,
+            )
+        };
+
+        let adjustments = DuplicateAdjustments {
+            set: IndexMap::from([("draft".to_string(), Value::Bool(true))]),
+            clear: vec!["pubDate".to_string()],
+            append_copy_to: Some("title".to_string()),
+        };
+        let copy = duplicate(Some(adjustments)).await.unwrap();
+        assert!(copy.ends_with("my-post-copy.md"));
+
+        let parsed = parse_frontmatter(&fs::read_to_string(&copy).unwrap()).unwrap();
+        assert_eq!(
+            parsed.frontmatter.get("title"),
+            Some(&Value::from("Hello Copy"))
+        );
+        assert_eq!(parsed.frontmatter.get("draft"), Some(&Value::Bool(true)));
+        assert!(!parsed.frontmatter.contains_key("pubDate"));
+        assert!(parsed.content.contains("# Body"));
+
+        // A second copy gets the next free name and, unadjusted, the same content
+        let second = duplicate(None).await.unwrap();
+        assert!(second.ends_with("my-post-copy-2.md"));
+        assert_eq!(
+            fs::read_to_string(&second).unwrap(),
+            fs::read_to_string(&post).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_toggle_flag_in_file_preserves_imports_and_body() {
+        use tempfile::TempDir;
+
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("post.mdx");
+        fs::write(
+            &path,
+            "---\ntitle: Hello\ndraft: true\n---\n\nimport Card from './Card.astro'\n\n# Body\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            toggle_flag_in_file(&path, "draft", None),
+            Ok(Value::Bool(false))
+        );
+        assert_eq!(
+            toggle_flag_in_file(&path, "featured", None),
+            Ok(Value::Bool(true))
+        );
+
+        let parsed = parse_frontmatter(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(parsed.frontmatter.get("draft"), Some(&Value::Bool(false)));
+        assert_eq!(parsed.frontmatter.get("featured"), Some(&Value::Bool(true)));
+        assert_eq!(parsed.imports.trim(), "import Card from './Card.astro'");
+        assert!(parsed.content.contains("# Body"));
+    }
+
+    #[test]
+    fn test_replace_raw_frontmatter_keeps_body_verbatim() {
+        let content = "---\ntitle: Old\n---\n\nimport A from './A.astro'\n\n# Body  \n";
+        assert_eq!(
+            replace_raw_frontmatter(content, "title: New\ntags: [a]\n"),
+            Ok(
+                "---\ntitle: New\ntags: [a]\n---\n\nimport A from './A.astro'\n\n# Body  \n"
+                    .to_string()
+            )
+        );
+
+        // Files without frontmatter gain a block; an empty block is removed
+        assert_eq!(
+            replace_raw_frontmatter("# Body\n", "title: New"),
+            Ok("---\ntitle: New\n---\n\n# Body\n".to_string())
+        );
+        assert_eq!(
+            replace_raw_frontmatter(content, "  \n").unwrap(),
+            "import A from './A.astro'\n\n# Body  \n"
+        );
+
+        assert!(replace_raw_frontmatter("---\ntitle: Old\n", "title: New").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_apply_raw_frontmatter_guards_the_file() {
+        use tempfile::TempDir;
+
+        let project = TempDir::new().unwrap();
+        let file_path = project.path().join("post.md");
+        let original = "---\ntitle: Post\n---\n\nBody\n";
+        fs::write(&file_path, original).unwrap();
+
+        let file = file_path.to_string_lossy().to_string();
+        let root = project.path().to_string_lossy().to_string();
+        let schema = serde_json::json!({
+            "collectionName": "posts",
+            "fields": [{
+                "name": "title",
+                "label": "Title",
+                "fieldType": "string",
+                "required": true,
+            }],
+        })
+        .to_string();
+
+        let invalid = apply_raw_frontmatter(
+            file.clone(),
+            root.clone(),
+            "title: Part 1: The Beginning".to_string(),
+            None,
+            None,
+        )
+        .await;
+        assert!(matches!(
+            invalid,
+            Err(RawFrontmatterError::InvalidYaml { error }) if error.line == Some(2)
+        ));
+
+        let early_close = apply_raw_frontmatter(
+            file.clone(),
+            root.clone(),
+            "a: 1\n---\nb: 2".to_string(),
+            None,
+            None,
+        )
+        .await;
+        assert!(matches!(
+            early_close,
+            Err(RawFrontmatterError::InvalidYaml { .. })
+        ));
+
+        let violating = apply_raw_frontmatter(
+            file.clone(),
+            root.clone(),
+            "draft: true".to_string(),
+            Some(schema.clone()),
+            None,
+        )
+        .await;
+        match violating {
+            Err(RawFrontmatterError::SchemaViolations { violations }) => {
+                assert_eq!(violations.len(), 1);
+                assert_eq!(violations[0].field, "title");
+            }
+            _ => panic!("Expected SchemaViolations error"),
+        }
+        assert_eq!(fs::read_to_string(&file_path).unwrap(), original);
+
+        let applied = apply_raw_frontmatter(
+            file.clone(),
+            root.clone(),
+            "title: Post\nseo:\n  noindex: true".to_string(),
+            Some(schema),
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(applied.frontmatter["seo"]["noindex"], true);
+        assert_eq!(
+            fs::read_to_string(&file_path).unwrap(),
+            "---\ntitle: Post\nseo:\n  noindex: true\n---\n\nBody\n"
+        );
+    }
 }
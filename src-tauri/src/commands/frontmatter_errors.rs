@@ -0,0 +1,248 @@
+//! Explanations for frontmatter that fails to parse
+//!
+//! serde_norway reports errors against the YAML rather than the file ("mapping values are
+//! not allowed in this context at line 3 column 12") and doesn't say what to change. The
+//! error is located in the file and the lines around it checked for the usual mistakes,
+//! so the editor can highlight the broken line in its raw frontmatter view and suggest a
+//! fix.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::fmt;
+
+/// File line of the first frontmatter line, after the opening `---`
+const FIRST_LINE: usize = 2;
+
+/// A likely reason frontmatter didn't parse
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum FrontmatterErrorCause {
+    /// No closing `---`
+    Unclosed,
+    /// An unquoted value containing `: `
+    UnquotedColon,
+    /// A line indented with a tab, which YAML doesn't allow
+    TabIndentation,
+    /// A quoted value without its closing quote
+    UnclosedQuote,
+    /// An unquoted value starting with a character YAML reserves (`@`, `` ` ``, `%`)
+    ReservedCharacter,
+}
+
+/// Why a file's frontmatter couldn't be parsed, located in the file
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontmatterError {
+    /// The parser's message, without its YAML-relative location
+    pub message: String,
+    /// 1-based line in the file
+    pub line: Option<u32>,
+    /// 1-based column
+    pub column: Option<u32>,
+    /// The offending line as written
+    pub snippet: Option<String>,
+    pub cause: Option<FrontmatterErrorCause>,
+    /// What to change, in plain words
+    pub suggestion: Option<String>,
+}
+
+impl FrontmatterError {
+    /// Frontmatter opened with `---` but never closed
+    pub fn unclosed() -> Self {
+        Self {
+            message: "Frontmatter not properly closed with '---'".to_string(),
+            line: Some(1),
+            column: None,
+            snippet: Some("---".to_string()),
+            cause: Some(FrontmatterErrorCause::Unclosed),
+            suggestion: Some(suggestion(FrontmatterErrorCause::Unclosed, "---")),
+        }
+    }
+}
+
+impl fmt::Display for FrontmatterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.cause == Some(FrontmatterErrorCause::Unclosed) {
+            return write!(f, "{}", self.message);
+        }
+        write!(f, "Failed to parse YAML: {}", self.message)?;
+        if let Some(line) = self.line {
+            write!(f, " (line {line})")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<FrontmatterError> for String {
+    fn from(error: FrontmatterError) -> Self {
+        error.to_string()
+    }
+}
+
+/// Splits `key: value` (or `- key: value`), returning the trimmed key and value
+fn key_value(line: &str) -> Option<(&str, &str)> {
+    let entry = line.trim_start();
+    let entry = entry.strip_prefix("- ").unwrap_or(entry);
+    let (key, value) = entry.split_once(':')?;
+    Some((key.trim().trim_matches(['"', '\'']), value.trim()))
+}
+
+/// The mistake on a single line, if it has a recognisable one
+fn line_cause(line: &str) -> Option<FrontmatterErrorCause> {
+    let (_, value) = key_value(line)?;
+    match value.chars().next()? {
+        quote @ ('"' | '\'') if value.len() == 1 || !value.ends_with(quote) => {
+            Some(FrontmatterErrorCause::UnclosedQuote)
+        }
+        '"' | '\'' | '[' | '{' | '|' | '>' | '&' | '*' | '!' | '#' => None,
+        '@' | '`' | '%' => Some(FrontmatterErrorCause::ReservedCharacter),
+        _ if value.contains(": ") || value.ends_with(':') => {
+            Some(FrontmatterErrorCause::UnquotedColon)
+        }
+        _ => None,
+    }
+}
+
+/// Finds the broken line (0-based) and why it's broken, looking from the reported line
+/// back to the start since unclosed quotes are reported further down
+fn diagnose(lines: &[&str], reported: Option<usize>) -> Option<(usize, FrontmatterErrorCause)> {
+    if let Some(index) = lines
+        .iter()
+        .position(|line| line.trim_start_matches(' ').starts_with('\t'))
+    {
+        return Some((index, FrontmatterErrorCause::TabIndentation));
+    }
+
+    let last = reported
+        .unwrap_or(usize::MAX)
+        .min(lines.len().checked_sub(1)?);
+    (0..=last)
+        .rev()
+        .find_map(|index| line_cause(lines[index]).map(|cause| (index, cause)))
+}
+
+/// Rewrites `key: value` with the value double-quoted
+fn quoted(line: &str) -> Option<String> {
+    let (key, value) = key_value(line)?;
+    let indent = &line[..line.len() - line.trim_start().len()];
+    let dash = if line.trim_start().starts_with("- ") {
+        "- "
+    } else {
+        ""
+    };
+    let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+    Some(format!("{indent}{dash}{key}: \"{value}\""))
+}
+
+fn suggestion(cause: FrontmatterErrorCause, line: &str) -> String {
+    match cause {
+        FrontmatterErrorCause::Unclosed => {
+            "Add a line containing only `---` after the frontmatter".to_string()
+        }
+        FrontmatterErrorCause::UnquotedColon | FrontmatterErrorCause::ReservedCharacter => {
+            match quoted(line) {
+                Some(fixed) => format!("Quote the value: `{}`", fixed.trim()),
+                None => "Wrap the value in double quotes".to_string(),
+            }
+        }
+        FrontmatterErrorCause::TabIndentation => "Indent with spaces instead of tabs".to_string(),
+        FrontmatterErrorCause::UnclosedQuote => {
+            "Add the closing quote at the end of the value".to_string()
+        }
+    }
+}
+
+/// Explains a YAML error in the frontmatter block `raw_frontmatter`
+pub fn explain_yaml_error(raw_frontmatter: &str, error: &serde_norway::Error) -> FrontmatterError {
+    let full_message = error.to_string();
+    let message = full_message
+        .split(" at line ")
+        .next()
+        .unwrap_or(&full_message)
+        .to_string();
+    let lines: Vec<&str> = raw_frontmatter.lines().collect();
+    let reported = error
+        .location()
+        .map(|location| (location.line().saturating_sub(1), location.column()));
+
+    let diagnosis = diagnose(&lines, reported.map(|(line, _)| line));
+    let index = diagnosis
+        .map(|(index, _)| index)
+        .or(reported.map(|(line, _)| line));
+    let column = match (reported, index) {
+        (Some((line, column)), Some(index)) if line == index => Some(column as u32),
+        _ => None,
+    };
+    let snippet = index.and_then(|index| lines.get(index)).copied();
+
+    FrontmatterError {
+        message,
+        line: index.map(|index| (index + FIRST_LINE) as u32),
+        column,
+        snippet: snippet.map(str::to_string),
+        cause: diagnosis.map(|(_, cause)| cause),
+        suggestion: diagnosis.map(|(_, cause)| suggestion(cause, snippet.unwrap_or_default())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indexmap::IndexMap;
+    use serde_json::Value;
+
+    fn explain(raw_frontmatter: &str) -> FrontmatterError {
+        let error = serde_norway::from_str::<IndexMap<String, Value>>(raw_frontmatter)
+            .expect_err("frontmatter should not parse");
+        explain_yaml_error(raw_frontmatter, &error)
+    }
+
+    #[test]
+    fn test_unquoted_colon_is_located_and_quoted() {
+        let error = explain("title: Part 1: The Beginning\ndraft: false");
+
+        assert_eq!(error.line, Some(2));
+        assert_eq!(
+            error.snippet.as_deref(),
+            Some("title: Part 1: The Beginning")
+        );
+        assert_eq!(error.cause, Some(FrontmatterErrorCause::UnquotedColon));
+        assert_eq!(
+            error.suggestion.as_deref(),
+            Some("Quote the value: `title: \"Part 1: The Beginning\"`")
+        );
+        assert!(!error.message.contains(" at line "));
+    }
+
+    #[test]
+    fn test_common_causes() {
+        let tab = explain("title: Post\nseo:\n\tdescription: Hello");
+        assert_eq!(tab.cause, Some(FrontmatterErrorCause::TabIndentation));
+        assert_eq!(tab.line, Some(4));
+
+        let quote = explain("title: \"Unfinished\ndraft: false\ntags: []");
+        assert_eq!(quote.cause, Some(FrontmatterErrorCause::UnclosedQuote));
+        assert_eq!(quote.line, Some(2));
+
+        let reserved = explain("title: Post\nauthor: @someone");
+        assert_eq!(
+            reserved.cause,
+            Some(FrontmatterErrorCause::ReservedCharacter)
+        );
+        assert_eq!(reserved.line, Some(3));
+    }
+
+    #[test]
+    fn test_display_keeps_string_errors_readable() {
+        assert_eq!(
+            FrontmatterError::unclosed().to_string(),
+            "Frontmatter not properly closed with '---'"
+        );
+
+        let error = explain("title: Part 1: The Beginning");
+        assert!(error
+            .to_string()
+            .starts_with("Failed to parse YAML: mapping values are not allowed"));
+        assert!(error.to_string().ends_with("(line 2)"));
+    }
+}
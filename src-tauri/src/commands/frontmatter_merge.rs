@@ -0,0 +1,497 @@
+//! Resolving git merge conflicts in frontmatter
+//!
+//! Git merges line by line, so two branches editing nearby fields leave conflict
+//! markers in the frontmatter even when the edits don't overlap. `resolve_frontmatter_conflict`
+//! splits a conflicted file into both sides (and the merge base, when the conflict was
+//! written with `merge.conflictStyle = diff3`), merges their frontmatter field by field,
+//! and writes the file once every remaining conflict has a choice.
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::files::{
+    parse_frontmatter_internal, rebuild_markdown_with_frontmatter_and_imports_ordered,
+};
+use crate::security::validate_project_path;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::HashMap;
+
+/// How to resolve a conflicting field or body
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ConflictChoice {
+    /// Keep the version from the current branch
+    Ours,
+    /// Keep the version being merged in
+    Theirs,
+    /// Use a different value; for the body this must be a string
+    Value { value: Value },
+}
+
+/// A frontmatter field both sides changed differently
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldConflict {
+    pub field: String,
+    /// `None` when the field was removed on that side
+    pub ours: Option<Value>,
+    pub theirs: Option<Value>,
+    /// The value before either change, when the conflict includes the merge base
+    pub base: Option<Value>,
+}
+
+/// A merge proposal for a conflicted file
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontmatterMerge {
+    /// Fields merged without conflict, in file order
+    pub merged: IndexMap<String, Value>,
+    /// Fields still needing a choice
+    pub conflicts: Vec<FieldConflict>,
+    /// The body differs between the sides and needs a choice too
+    pub body_conflict: bool,
+    /// Whether the resolved file was written
+    pub written: bool,
+}
+
+/// A conflicted file split into the versions being merged
+#[derive(Debug, PartialEq)]
+struct ConflictSides {
+    ours: String,
+    theirs: String,
+    /// Only when every conflict hunk includes the base (diff3 style)
+    base: Option<String>,
+}
+
+fn is_marker(line: &str, marker: &str) -> bool {
+    line.strip_prefix(marker)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+}
+
+/// Rebuilds both sides of a file containing conflict markers; `None` if there are none
+fn split_conflict_sides(content: &str) -> Result<Option<ConflictSides>, String> {
+    enum Section {
+        Shared,
+        Ours,
+        Base,
+        Theirs,
+    }
+
+    let mut section = Section::Shared;
+    let (mut ours, mut theirs, mut base) = (String::new(), String::new(), String::new());
+    let mut hunks = 0;
+    let mut hunks_with_base = 0;
+
+    for line in content.split_inclusive('\n') {
+        let bare = line.trim_end_matches(['\n', '\r']);
+        section = match section {
+            Section::Shared if is_marker(bare, "<<<<<<<") => {
+                hunks += 1;
+                Section::Ours
+            }
+            Section::Ours if is_marker(bare, "|||||||") => {
+                hunks_with_base += 1;
+                Section::Base
+            }
+            Section::Ours | Section::Base if bare == "=======" => Section::Theirs,
+            Section::Theirs if is_marker(bare, ">>>>>>>") => Section::Shared,
+            Section::Shared => {
+                ours.push_str(line);
+                theirs.push_str(line);
+                base.push_str(line);
+                Section::Shared
+            }
+            Section::Ours => {
+                ours.push_str(line);
+                Section::Ours
+            }
+            Section::Base => {
+                base.push_str(line);
+                Section::Base
+            }
+            Section::Theirs => {
+                theirs.push_str(line);
+                Section::Theirs
+            }
+        };
+    }
+
+    if !matches!(section, Section::Shared) {
+        return Err("Unterminated conflict marker".to_string());
+    }
+    if hunks == 0 {
+        return Ok(None);
+    }
+    Ok(Some(ConflictSides {
+        ours,
+        theirs,
+        base: (hunks_with_base == hunks).then_some(base),
+    }))
+}
+
+/// Merges two frontmatter maps field by field
+///
+/// Returns the output field order, the merged fields and the conflicts. With a base,
+/// a field changed on only one side takes that change (including removal); without
+/// one, fields present on only one side are kept.
+pub(crate) fn merge_frontmatter(
+    ours: &IndexMap<String, Value>,
+    theirs: &IndexMap<String, Value>,
+    base: Option<&IndexMap<String, Value>>,
+) -> (Vec<String>, IndexMap<String, Value>, Vec<FieldConflict>) {
+    let mut order: Vec<String> = ours.keys().cloned().collect();
+    order.extend(theirs.keys().filter(|k| !ours.contains_key(*k)).cloned());
+
+    let mut merged = IndexMap::new();
+    let mut conflicts = Vec::new();
+    for field in &order {
+        let (o, t) = (ours.get(field), theirs.get(field));
+        let b = base.and_then(|base| base.get(field));
+
+        let resolved = if o == t {
+            Some(o)
+        } else if base.is_some() {
+            if o == b {
+                Some(t)
+            } else if t == b {
+                Some(o)
+            } else {
+                None
+            }
+        } else if o.is_none() {
+            Some(t)
+        } else if t.is_none() {
+            Some(o)
+        } else {
+            None
+        };
+
+        match resolved {
+            Some(Some(value)) => {
+                merged.insert(field.clone(), value.clone());
+            }
+            Some(None) => {}
+            None => conflicts.push(FieldConflict {
+                field: field.clone(),
+                ours: o.cloned(),
+                theirs: t.cloned(),
+                base: b.cloned(),
+            }),
+        }
+    }
+    (order, merged, conflicts)
+}
+
+/// Merges the frontmatter of a file with git conflict markers, writing the resolved
+/// file once every conflict has a choice
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `file_path` - The absolute path to the conflicted file
+/// * `resolutions` - Choices for conflicting fields, by field name; omit to only get
+///   the merge proposal
+/// * `body` - Choice for the body, when it differs between the sides
+#[tauri::command]
+#[specta::specta]
+pub async fn resolve_frontmatter_conflict(
+    project_path: String,
+    file_path: String,
+    resolutions: Option<HashMap<String, ConflictChoice>>,
+    body: Option<ConflictChoice>,
+) -> Result<FrontmatterMerge, String> {
+    let path = validate_project_path(&file_path, &project_path)?;
+    let content =
+        std::fs::read_to_string(&path).map_err(|e| format!("Failed to read file: {e}"))?;
+    let sides = split_conflict_sides(&content)?
+        .ok_or_else(|| "File has no conflict markers".to_string())?;
+
+    let ours = parse_frontmatter_internal(&sides.ours)
+        .map_err(|e| format!("Failed to parse our version: {e}"))?;
+    let theirs = parse_frontmatter_internal(&sides.theirs)
+        .map_err(|e| format!("Failed to parse their version: {e}"))?;
+    let base = match &sides.base {
+        Some(base) => Some(
+            parse_frontmatter_internal(base)
+                .map_err(|e| format!("Failed to parse the merge base: {e}"))?,
+        ),
+        None => None,
+    };
+
+    let (order, mut merged, conflicts) = merge_frontmatter(
+        &ours.frontmatter,
+        &theirs.frontmatter,
+        base.as_ref().map(|b| &b.frontmatter),
+    );
+    let body_conflict = (&ours.imports, &ours.content) != (&theirs.imports, &theirs.content);
+
+    let apply = resolutions.is_some();
+    let mut resolutions = resolutions.unwrap_or_default();
+    let mut unresolved = Vec::new();
+    let mut chosen = IndexMap::new();
+    for conflict in conflicts {
+        match resolutions.remove(&conflict.field) {
+            Some(ConflictChoice::Ours) => {
+                chosen.insert(conflict.field.clone(), conflict.ours.clone());
+            }
+            Some(ConflictChoice::Theirs) => {
+                chosen.insert(conflict.field.clone(), conflict.theirs.clone());
+            }
+            Some(ConflictChoice::Value { value }) => {
+                chosen.insert(conflict.field.clone(), Some(value));
+            }
+            None => unresolved.push(conflict),
+        }
+    }
+    if let Some(field) = resolutions.keys().next() {
+        return Err(format!("No conflict in field: {field}"));
+    }
+
+    let (imports, body_content) = match (&body, body_conflict) {
+        (_, false) | (Some(ConflictChoice::Ours), true) => (ours.imports, ours.content),
+        (Some(ConflictChoice::Theirs), true) => (theirs.imports, theirs.content),
+        (Some(ConflictChoice::Value { value }), true) => (
+            String::new(),
+            value
+                .as_str()
+                .ok_or("The body value must be a string")?
+                .to_string(),
+        ),
+        (None, true) => (String::new(), String::new()),
+    };
+
+    let written = apply && unresolved.is_empty() && (!body_conflict || body.is_some());
+    if written {
+        let mut frontmatter = IndexMap::new();
+        for field in &order {
+            let value = match chosen.get(field) {
+                Some(choice) => choice.clone(),
+                None => merged.get(field).cloned(),
+            };
+            if let Some(value) = value {
+                frontmatter.insert(field.clone(), value);
+            }
+        }
+        let resolved = rebuild_markdown_with_frontmatter_and_imports_ordered(
+            &frontmatter,
+            &imports,
+            &body_content,
+            Some(order),
+        )?;
+        std::fs::write(&path, resolved).map_err(|e| format!("Failed to write file: {e}"))?;
+        audit_log::record(AuditAction::Write, &path, None, "conflict-merge");
+        log::info!(
+            "Astro Editor [CONFLICTS] Resolved frontmatter conflict in {}",
+            path.display()
+        );
+        merged = frontmatter;
+    }
+
+    Ok(FrontmatterMerge {
+        merged,
+        conflicts: unresolved,
+        body_conflict: body_conflict && body.is_none(),
+        written,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const DIFF3: &str = "---\n\
+<<<<<<< HEAD\n\
+title: Our title\n\
+tags: [a]\n\
+draft: true\n\
+||||||| base\n\
+title: Title\n\
+tags: [a]\n\
+draft: true\n\
+=======\n\
+title: Title\n\
+tags: [a, b]\n\
+draft: false\n\
+>>>>>>> feature\n\
+description: Shared\n\
+---\n\
+\n\
+Body text\n";
+
+    fn map(fields: &[(&str, Value)]) -> IndexMap<String, Value> {
+        fields
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_split_conflict_sides() {
+        let sides = split_conflict_sides(DIFF3).unwrap().unwrap();
+        assert!(sides
+            .ours
+            .contains("title: Our title\ntags: [a]\ndraft: true\ndescription"));
+        assert!(sides
+            .theirs
+            .contains("title: Title\ntags: [a, b]\ndraft: false\ndescription"));
+        assert!(sides.base.unwrap().contains("title: Title\ntags: [a]\n"));
+
+        let two_way = "a\n<<<<<<< HEAD\nb\n=======\nc\n>>>>>>> x\nd\n";
+        let sides = split_conflict_sides(two_way).unwrap().unwrap();
+        assert_eq!(sides.ours, "a\nb\nd\n");
+        assert_eq!(sides.theirs, "a\nc\nd\n");
+        assert_eq!(sides.base, None);
+
+        assert_eq!(split_conflict_sides("no markers\n=======\n").unwrap(), None);
+        assert!(split_conflict_sides("<<<<<<< HEAD\nb\n=======\n").is_err());
+    }
+
+    #[test]
+    fn test_merge_frontmatter() {
+        let base = map(&[
+            ("title", json!("T")),
+            ("tags", json!(["a"])),
+            ("old", json!(1)),
+            ("draft", json!(true)),
+        ]);
+        let ours = map(&[
+            ("title", json!("Ours")),
+            ("tags", json!(["a"])),
+            ("draft", json!(true)),
+            ("new", json!("x")),
+        ]);
+        let theirs = map(&[
+            ("title", json!("Theirs")),
+            ("tags", json!(["a", "b"])),
+            ("old", json!(1)),
+            ("draft", json!(true)),
+        ]);
+
+        let (order, merged, conflicts) = merge_frontmatter(&ours, &theirs, Some(&base));
+        assert_eq!(order, vec!["title", "tags", "draft", "new", "old"]);
+        assert_eq!(
+            merged,
+            map(&[
+                ("tags", json!(["a", "b"])),
+                ("draft", json!(true)),
+                ("new", json!("x"))
+            ])
+        );
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].field, "title");
+        assert_eq!(conflicts[0].base, Some(json!("T")));
+
+        // Without a base, fields on one side only are kept
+        let (_, merged, conflicts) = merge_frontmatter(&ours, &theirs, None);
+        assert_eq!(merged.get("old"), Some(&json!(1)));
+        assert_eq!(
+            conflicts
+                .iter()
+                .map(|c| c.field.as_str())
+                .collect::<Vec<_>>(),
+            vec!["title", "tags"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_frontmatter_conflict() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("post.md");
+        fs::write(&file, DIFF3).unwrap();
+        let project = temp.path().to_string_lossy().to_string();
+        let file_path = file.to_string_lossy().to_string();
+
+        let proposal = resolve_frontmatter_conflict(project.clone(), file_path.clone(), None, None)
+            .await
+            .unwrap();
+        assert!(!proposal.written && !proposal.body_conflict);
+        assert!(proposal.conflicts.is_empty());
+        assert_eq!(
+            proposal.merged,
+            map(&[
+                ("title", json!("Our title")),
+                ("tags", json!(["a", "b"])),
+                ("draft", json!(false)),
+                ("description", json!("Shared")),
+            ])
+        );
+
+        let result = resolve_frontmatter_conflict(
+            project.clone(),
+            file_path.clone(),
+            Some(HashMap::new()),
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(result.written);
+        let written = fs::read_to_string(&file).unwrap();
+        assert!(!written.contains("<<<<<<<"));
+        let parsed = parse_frontmatter_internal(&written).unwrap();
+        assert_eq!(
+            parsed.frontmatter.keys().collect::<Vec<_>>(),
+            vec!["title", "tags", "draft", "description"]
+        );
+        assert_eq!(parsed.content.trim(), "Body text");
+
+        let err = resolve_frontmatter_conflict(project, file_path, None, None).await;
+        assert_eq!(err.unwrap_err(), "File has no conflict markers");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_frontmatter_conflict_with_choices() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("post.md");
+        fs::write(
+            &file,
+            "---\n<<<<<<< HEAD\ntitle: Mine\n=======\ntitle: Yours\n>>>>>>> other\n---\n\n<<<<<<< HEAD\nMy body\n=======\nYour body\n>>>>>>> other\n",
+        )
+        .unwrap();
+        let project = temp.path().to_string_lossy().to_string();
+        let file_path = file.to_string_lossy().to_string();
+
+        let proposal = resolve_frontmatter_conflict(project.clone(), file_path.clone(), None, None)
+            .await
+            .unwrap();
+        assert_eq!(proposal.conflicts[0].ours, Some(json!("Mine")));
+        assert_eq!(proposal.conflicts[0].theirs, Some(json!("Yours")));
+        assert!(proposal.body_conflict);
+
+        let choices = HashMap::from([("title".to_string(), ConflictChoice::Theirs)]);
+        let partial = resolve_frontmatter_conflict(
+            project.clone(),
+            file_path.clone(),
+            Some(choices.clone()),
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(!partial.written && partial.body_conflict);
+
+        let unknown = HashMap::from([("date".to_string(), ConflictChoice::Ours)]);
+        assert!(resolve_frontmatter_conflict(
+            project.clone(),
+            file_path.clone(),
+            Some(unknown),
+            None
+        )
+        .await
+        .is_err());
+
+        let result = resolve_frontmatter_conflict(
+            project,
+            file_path,
+            Some(choices),
+            Some(ConflictChoice::Ours),
+        )
+        .await
+        .unwrap();
+        assert!(result.written);
+        let written = fs::read_to_string(&file).unwrap();
+        assert!(written.starts_with("---\ntitle: Yours\n---\n"));
+        assert!(written.contains("My body") && !written.contains("Your body"));
+    }
+}
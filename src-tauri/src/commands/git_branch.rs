@@ -0,0 +1,346 @@
+//! Content branch workflow for git-backed projects
+//!
+//! A project can be set up so content is edited on its own branch (the `contentBranch`
+//! setting) and reaches `main` through a merge or pull request. `get_git_branch_state`
+//! reports where the project stands, including whether it's a linked worktree, and
+//! `switch_content_branch` creates or checks out the branch.
+
+use crate::utils::git;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::{Path, PathBuf};
+
+/// Branches tried, in order, when the repository has no `origin/HEAD`
+const DEFAULT_BRANCH_CANDIDATES: [&str; 2] = ["main", "master"];
+
+/// The project's `contentBranch` setting
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentBranchSettings {
+    /// Branch content is edited on, e.g. `content`
+    #[serde(default)]
+    #[specta(optional)]
+    pub branch: Option<String>,
+    /// Warn when editing on the default branch instead
+    #[serde(default)]
+    #[specta(optional)]
+    pub require: Option<bool>,
+}
+
+/// Where a project stands in git, for the title bar
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GitBranchState {
+    pub is_repository: bool,
+    /// The checked-out branch; `None` when HEAD is detached
+    pub branch: Option<String>,
+    /// `origin`'s HEAD, otherwise `main` or `master`
+    pub default_branch: Option<String>,
+    /// The project is a linked worktree rather than the main checkout
+    pub is_worktree: bool,
+    /// The main checkout, when the project is a linked worktree
+    pub main_worktree: Option<String>,
+    pub content_branch: Option<String>,
+    pub content_branch_exists: bool,
+    /// Another worktree with the content branch checked out. Git won't check a
+    /// branch out twice, so edit it there instead.
+    pub content_branch_worktree: Option<String>,
+    /// A content branch is required but the project is on the default branch
+    pub editing_default_branch: bool,
+}
+
+/// A `git worktree list --porcelain` entry
+#[derive(Debug, PartialEq)]
+struct Worktree {
+    path: PathBuf,
+    /// `None` when detached
+    branch: Option<String>,
+}
+
+fn parse_worktrees(porcelain: &str) -> Vec<Worktree> {
+    porcelain
+        .split("\n\n")
+        .filter_map(|block| {
+            let mut lines = block.lines();
+            let path = lines.next()?.strip_prefix("worktree ")?;
+            let branch = lines
+                .find_map(|line| line.strip_prefix("branch "))
+                .map(|branch| branch.trim_start_matches("refs/heads/").to_string());
+            Some(Worktree {
+                path: PathBuf::from(path),
+                branch,
+            })
+        })
+        .collect()
+}
+
+fn same_path(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+fn branch_exists(root: &Path, branch: &str) -> bool {
+    let reference = format!("refs/heads/{branch}");
+    git::query(root, &["rev-parse", "--verify", "--quiet", &reference]).is_some()
+}
+
+fn default_branch(root: &Path) -> Option<String> {
+    let remote_head = git::query(
+        root,
+        &[
+            "symbolic-ref",
+            "--quiet",
+            "--short",
+            "refs/remotes/origin/HEAD",
+        ],
+    );
+    match remote_head {
+        Some(head) => Some(head.trim().trim_start_matches("origin/").to_string()),
+        None => DEFAULT_BRANCH_CANDIDATES
+            .into_iter()
+            .find(|branch| branch_exists(root, branch))
+            .map(String::from),
+    }
+}
+
+fn configured_branch(settings: Option<&ContentBranchSettings>) -> Option<&str> {
+    settings
+        .and_then(|settings| settings.branch.as_deref())
+        .map(str::trim)
+        .filter(|branch| !branch.is_empty())
+}
+
+fn branch_state(root: &Path, settings: Option<&ContentBranchSettings>) -> GitBranchState {
+    if !git::is_repository(root) {
+        return GitBranchState::default();
+    }
+
+    let branch = git::query(root, &["symbolic-ref", "--quiet", "--short", "HEAD"])
+        .map(|branch| branch.trim().to_string());
+    let default_branch = default_branch(root);
+    let worktrees = git::query(root, &["worktree", "list", "--porcelain"])
+        .map(|porcelain| parse_worktrees(&porcelain))
+        .unwrap_or_default();
+    let toplevel = git::query(root, &["rev-parse", "--show-toplevel"])
+        .map(|toplevel| PathBuf::from(toplevel.trim()))
+        .unwrap_or_else(|| root.to_path_buf());
+
+    // The main checkout is always listed first
+    let main_worktree = worktrees
+        .first()
+        .filter(|main| !same_path(&main.path, &toplevel))
+        .map(|main| main.path.to_string_lossy().to_string());
+
+    let content_branch = configured_branch(settings);
+    let content_branch_worktree = content_branch.and_then(|content_branch| {
+        worktrees
+            .iter()
+            .find(|worktree| {
+                worktree.branch.as_deref() == Some(content_branch)
+                    && !same_path(&worktree.path, &toplevel)
+            })
+            .map(|worktree| worktree.path.to_string_lossy().to_string())
+    });
+    let require = settings.and_then(|settings| settings.require) == Some(true);
+
+    GitBranchState {
+        is_repository: true,
+        editing_default_branch: require
+            && content_branch.is_some()
+            && branch.is_some()
+            && branch == default_branch,
+        branch,
+        default_branch,
+        is_worktree: main_worktree.is_some(),
+        main_worktree,
+        content_branch: content_branch.map(String::from),
+        content_branch_exists: content_branch.is_some_and(|b| branch_exists(root, b)),
+        content_branch_worktree,
+    }
+}
+
+/// Checks out `branch`, creating it from the current commit if it doesn't exist
+fn switch_branch(root: &Path, branch: &str) -> Result<(), String> {
+    git::run(root, &["check-ref-format", "--branch", branch])
+        .map_err(|_| format!("'{branch}' isn't a valid branch name"))?;
+
+    let args: &[&str] = if branch_exists(root, branch) {
+        &["switch", branch]
+    } else {
+        &["switch", "--create", branch]
+    };
+    git::run(root, args).map_err(|e| format!("Failed to switch to {branch}: {e}"))?;
+    Ok(())
+}
+
+/// Reports the project's branch, worktree and content branch state
+///
+/// Not a repository (or no git installed) comes back as `isRepository: false`.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_git_branch_state(
+    project_path: String,
+    settings: Option<ContentBranchSettings>,
+) -> Result<GitBranchState, String> {
+    Ok(branch_state(Path::new(&project_path), settings.as_ref()))
+}
+
+/// Checks out the configured content branch, creating it from the current commit
+/// when it doesn't exist yet
+///
+/// Uncommitted changes come along, unless they conflict with the branch, in which
+/// case git's error is returned and nothing changes.
+///
+/// # Returns
+/// The project's state after switching
+#[tauri::command]
+#[specta::specta]
+pub async fn switch_content_branch(
+    project_path: String,
+    settings: ContentBranchSettings,
+) -> Result<GitBranchState, String> {
+    let root = Path::new(&project_path);
+    let branch = configured_branch(Some(&settings)).ok_or("No content branch is configured")?;
+
+    let state = branch_state(root, Some(&settings));
+    if !state.is_repository {
+        return Err("The project isn't a git repository".to_string());
+    }
+    if let Some(worktree) = &state.content_branch_worktree {
+        return Err(format!(
+            "{branch} is checked out in another worktree at {worktree}"
+        ));
+    }
+
+    switch_branch(root, branch)?;
+    Ok(branch_state(root, Some(&settings)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn init_repo(dir: &Path) {
+        git::run(dir, &["init", "--initial-branch", "main"]).unwrap();
+        git::run(
+            dir,
+            &[
+                "-c",
+                "user.name=Test",
+                "-c",
+                "user.email=test@example.com",
+                "commit",
+                "--allow-empty",
+                "--message",
+                "Initial commit",
+            ],
+        )
+        .unwrap();
+    }
+
+    fn settings(require: bool) -> ContentBranchSettings {
+        ContentBranchSettings {
+            branch: Some("content".to_string()),
+            require: Some(require),
+        }
+    }
+
+    #[test]
+    fn test_parse_worktrees() {
+        let porcelain = "worktree /repo\nHEAD 1234\nbranch refs/heads/main\n\n\
+                         worktree /repo-content\nHEAD 5678\nbranch refs/heads/content\n\n\
+                         worktree /repo-review\nHEAD 9abc\ndetached\n";
+
+        assert_eq!(
+            parse_worktrees(porcelain),
+            vec![
+                Worktree {
+                    path: PathBuf::from("/repo"),
+                    branch: Some("main".to_string()),
+                },
+                Worktree {
+                    path: PathBuf::from("/repo-content"),
+                    branch: Some("content".to_string()),
+                },
+                Worktree {
+                    path: PathBuf::from("/repo-review"),
+                    branch: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_branch_state_outside_a_repository() {
+        let temp = TempDir::new().unwrap();
+        assert_eq!(
+            branch_state(temp.path(), Some(&settings(true))),
+            GitBranchState::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_switch_content_branch() {
+        let temp = TempDir::new().unwrap();
+        init_repo(temp.path());
+        let project_path = temp.path().to_string_lossy().to_string();
+
+        let state = branch_state(temp.path(), Some(&settings(true)));
+        assert_eq!(state.branch.as_deref(), Some("main"));
+        assert_eq!(state.default_branch.as_deref(), Some("main"));
+        assert!(state.editing_default_branch);
+        assert!(!state.content_branch_exists);
+        assert!(!state.is_worktree);
+
+        // Not required: no warning on main
+        assert!(!branch_state(temp.path(), Some(&settings(false))).editing_default_branch);
+
+        let state = switch_content_branch(project_path, settings(true))
+            .await
+            .unwrap();
+        assert_eq!(state.branch.as_deref(), Some("content"));
+        assert!(state.content_branch_exists);
+        assert!(!state.editing_default_branch);
+    }
+
+    #[test]
+    fn test_branch_state_in_a_worktree() {
+        let temp = TempDir::new().unwrap();
+        let repo = temp.path().join("repo");
+        let worktree = temp.path().join("repo-content");
+        std::fs::create_dir(&repo).unwrap();
+        init_repo(&repo);
+        git::run(
+            &repo,
+            &[
+                "worktree",
+                "add",
+                "-b",
+                "content",
+                &worktree.to_string_lossy(),
+            ],
+        )
+        .unwrap();
+
+        let state = branch_state(&worktree, Some(&settings(true)));
+        assert!(state.is_worktree);
+        assert!(same_path(
+            Path::new(state.main_worktree.as_deref().unwrap()),
+            &repo
+        ));
+        assert_eq!(state.branch.as_deref(), Some("content"));
+        assert_eq!(state.content_branch_worktree, None);
+
+        // From the main checkout, the content branch is taken by the worktree
+        let state = branch_state(&repo, Some(&settings(true)));
+        assert!(!state.is_worktree);
+        assert!(state.editing_default_branch);
+        assert!(same_path(
+            Path::new(state.content_branch_worktree.as_deref().unwrap()),
+            &worktree
+        ));
+    }
+}
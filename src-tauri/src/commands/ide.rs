@@ -1,11 +1,58 @@
+use crate::commands::projects;
 use log::{debug, error, info, warn};
+use serde::{Deserialize, Serialize};
+use specta::Type;
 use std::env;
+use std::path::Path;
 use std::process::Command;
 
+/// Editors `detect_ides` looks for: the command, the editor's name, and the arguments
+/// that open a file at a line and column
+const KNOWN_IDES: &[(&str, &str, &str)] = &[
+    (
+        "code",
+        "Visual Studio Code",
+        "--goto {path}:{line}:{column}",
+    ),
+    ("cursor", "Cursor", "--goto {path}:{line}:{column}"),
+    ("windsurf", "Windsurf", "--goto {path}:{line}:{column}"),
+    ("codium", "VSCodium", "--goto {path}:{line}:{column}"),
+    ("zed", "Zed", "{path}:{line}:{column}"),
+    ("subl", "Sublime Text", "{path}:{line}:{column}"),
+    ("mate", "TextMate", "-l {line}:{column} {path}"),
+    (
+        "webstorm",
+        "WebStorm",
+        "--line {line} --column {column} {path}",
+    ),
+    (
+        "idea",
+        "IntelliJ IDEA",
+        "--line {line} --column {column} {path}",
+    ),
+];
+
+/// An editor whose command is on the PATH
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DetectedIde {
+    pub name: String,
+    pub command: String,
+    /// An IDE command that opens a file at a line, e.g. `code --goto {path}:{line}:{column}`
+    pub template: String,
+}
+
+/// Where to put the cursor in an opened file, 1-based
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    line: u32,
+    column: u32,
+}
+
 /// Compute an augmented PATH with common IDE locations for production builds.
 /// Returns the augmented PATH string to be passed to Command::new().env("PATH", ...).
 /// This is thread-safe unlike env::set_var which is deprecated since Rust 1.80.
-fn get_augmented_path() -> String {
+pub(crate) fn get_augmented_path() -> String {
     #[cfg(target_os = "macos")]
     {
         let current_path = env::var("PATH").unwrap_or_default();
@@ -164,37 +211,130 @@ fn validate_file_path(path: &str) -> Result<(), String> {
     Ok(())
 }
 
-#[tauri::command]
-#[specta::specta]
-pub async fn open_path_in_ide(ide_command: String, file_path: String) -> Result<String, String> {
-    info!("Attempting to open path in IDE: {ide_command} -> {file_path}");
+/// Splits an IDE command template into words, keeping quoted words together
+fn split_template(template: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut quote = None;
+
+    for c in template.chars() {
+        match quote {
+            Some(open) if c == open => quote = None,
+            Some(_) => word.push(c),
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                in_word = true;
+            }
+            None if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            None => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
 
-    // Expand tilde in the IDE command if present
-    let expanded_command = expand_tilde(&ide_command);
+    if quote.is_some() {
+        return Err("IDE command has an unclosed quote".to_string());
+    }
+    if in_word {
+        words.push(word);
+    }
+    Ok(words)
+}
 
-    // Validate file path
-    if let Err(validation_error) = validate_file_path(&file_path) {
-        let error_msg = format!("Invalid file path: {validation_error}");
-        error!("{error_msg}");
-        return Err(error_msg);
+fn fill_placeholders(word: &str, file_path: &str, position: Option<Position>) -> String {
+    let Position { line, column } = position.unwrap_or(Position { line: 1, column: 1 });
+    // The path goes in last, so placeholders in it are left alone
+    word.replace("{line}", &line.to_string())
+        .replace("{column}", &column.to_string())
+        .replace("{path}", file_path)
+}
+
+/// The arguments a known editor takes to open a file at a position
+fn position_arguments(program: &str) -> Option<&'static str> {
+    let stem = Path::new(program).file_stem()?.to_str()?;
+    KNOWN_IDES
+        .iter()
+        .find(|(command, _, _)| command.eq_ignore_ascii_case(stem))
+        .map(|(_, _, arguments)| *arguments)
+}
+
+/// The program and arguments that open `file_path` with the IDE command
+///
+/// A command containing `{path}` is a template: it's split into words (quote a program
+/// path with spaces), and `{path}`, `{line}` and `{column}` are filled in. Any other
+/// command is the program itself and is given the file, at the position when it's a
+/// known editor.
+fn ide_invocation(
+    ide_command: &str,
+    file_path: &str,
+    position: Option<Position>,
+) -> Result<(String, Vec<String>), String> {
+    if ide_command.contains("{path}") {
+        let mut words = split_template(ide_command)?.into_iter();
+        let program = words.next().ok_or("IDE command is empty")?;
+        let args = words
+            .map(|word| fill_placeholders(&word, file_path, position))
+            .collect();
+        return Ok((expand_tilde(&program), args));
     }
 
+    let program = expand_tilde(ide_command.trim());
+    let args = match position.and(position_arguments(&program)) {
+        Some(arguments) => arguments
+            .split_whitespace()
+            .map(|word| fill_placeholders(word, file_path, position))
+            .collect(),
+        None => vec![file_path.to_string()],
+    };
+    Ok((program, args))
+}
+
+/// Whether `command` is an executable file in one of the directories of `search_path`
+fn is_on_path(command: &str, search_path: &str) -> bool {
+    let names = if cfg!(target_os = "windows") {
+        vec![format!("{command}.cmd"), format!("{command}.exe")]
+    } else {
+        vec![command.to_string()]
+    };
+    env::split_paths(search_path).any(|dir| names.iter().any(|name| dir.join(name).is_file()))
+}
+
+fn detect_ides_on(search_path: &str) -> Vec<DetectedIde> {
+    KNOWN_IDES
+        .iter()
+        .filter(|(command, _, _)| is_on_path(command, search_path))
+        .map(|(command, name, arguments)| DetectedIde {
+            name: name.to_string(),
+            command: command.to_string(),
+            template: format!("{command} {arguments}"),
+        })
+        .collect()
+}
+
+/// Runs the IDE, reporting a failure with a suggestion for fixing it
+fn run_ide(program: &str, args: &[String], file_path: &str) -> Result<String, String> {
     // Get augmented PATH for production builds (thread-safe, doesn't mutate global env)
     let augmented_path = get_augmented_path();
 
-    info!("Executing IDE command: {expanded_command} \"{file_path}\"");
+    info!("Executing IDE command: {program} {args:?}");
 
-    // Execute the command with augmented PATH (Command::new().arg() handles path escaping safely)
-    let result = Command::new(&expanded_command)
+    // Execute the command with augmented PATH (Command::new().args() handles path escaping safely)
+    let result = Command::new(program)
         .env("PATH", &augmented_path)
-        .arg(&file_path)
+        .args(args)
         .output();
 
     match result {
         Ok(output) => {
             if output.status.success() {
-                let success_msg =
-                    format!("Successfully opened '{file_path}' in {expanded_command}");
+                let success_msg = format!("Successfully opened '{file_path}' in {program}");
                 info!("{success_msg}");
                 Ok(success_msg)
             } else {
@@ -209,7 +349,7 @@ pub async fn open_path_in_ide(ide_command: String, file_path: String) -> Result<
             }
         }
         Err(e) => {
-            let error_msg = format!("Failed to execute IDE command '{expanded_command}': {e}");
+            let error_msg = format!("Failed to execute IDE command '{program}': {e}");
             error!("{error_msg}");
 
             // Provide helpful suggestions based on the error
@@ -230,6 +370,58 @@ pub async fn open_path_in_ide(ide_command: String, file_path: String) -> Result<
     }
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn open_path_in_ide(ide_command: String, file_path: String) -> Result<String, String> {
+    info!("Attempting to open path in IDE: {ide_command} -> {file_path}");
+
+    // Validate file path
+    if let Err(validation_error) = validate_file_path(&file_path) {
+        let error_msg = format!("Invalid file path: {validation_error}");
+        error!("{error_msg}");
+        return Err(error_msg);
+    }
+
+    let (program, args) = ide_invocation(&ide_command, &file_path, None)?;
+    run_ide(&program, &args, &file_path)
+}
+
+/// Opens a file at a line (and column) in the IDE set in preferences
+///
+/// For jumping to a parse error. An IDE command that isn't a template, for an editor
+/// that isn't known, opens the file without moving to the line.
+#[tauri::command]
+#[specta::specta]
+pub async fn open_in_ide(
+    file_path: String,
+    line: u32,
+    column: Option<u32>,
+) -> Result<String, String> {
+    let ide_command =
+        projects::ide_command().ok_or("No IDE configured. Set an IDE command in Preferences.")?;
+    info!("Attempting to open path in IDE at line {line}: {ide_command} -> {file_path}");
+
+    if let Err(validation_error) = validate_file_path(&file_path) {
+        let error_msg = format!("Invalid file path: {validation_error}");
+        error!("{error_msg}");
+        return Err(error_msg);
+    }
+
+    let position = Position {
+        line: line.max(1),
+        column: column.unwrap_or(1).max(1),
+    };
+    let (program, args) = ide_invocation(&ide_command, &file_path, Some(position))?;
+    run_ide(&program, &args, &file_path)
+}
+
+/// Lists the known editors installed on the PATH, with an IDE command template for each
+#[tauri::command]
+#[specta::specta]
+pub async fn detect_ides() -> Result<Vec<DetectedIde>, String> {
+    Ok(detect_ides_on(&get_augmented_path()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -340,4 +532,81 @@ mod tests {
         assert!(validate_file_path("/home/user/projects/post.md").is_ok());
         assert!(validate_file_path("~/Documents/file.md").is_ok());
     }
+
+    #[test]
+    fn test_split_template() {
+        assert_eq!(
+            split_template("nvim +{line}  {path}").unwrap(),
+            vec!["nvim", "+{line}", "{path}"]
+        );
+        // Quoted words keep their spaces
+        assert_eq!(
+            split_template(r#""/Applications/Sublime Text.app/subl" '{path}:{line}'"#).unwrap(),
+            vec!["/Applications/Sublime Text.app/subl", "{path}:{line}"]
+        );
+        assert!(split_template("subl \"{path}").is_err());
+    }
+
+    #[test]
+    fn test_ide_invocation() {
+        let at = |line, column| Some(Position { line, column });
+        let path = "/home/user/My Site/src/content.config.ts";
+
+        // Templates fill in their placeholders, keeping the path as one argument
+        assert_eq!(
+            ide_invocation("nvim +{line} {path}", path, at(12, 3)).unwrap(),
+            (
+                "nvim".to_string(),
+                vec!["+12".to_string(), path.to_string()]
+            )
+        );
+        assert_eq!(
+            ide_invocation("subl {path}:{line}:{column}", path, at(12, 3)).unwrap(),
+            ("subl".to_string(), vec![format!("{path}:12:3")])
+        );
+        // Without a position the cursor goes to the start
+        assert_eq!(
+            ide_invocation("subl {path}:{line}", path, None).unwrap().1,
+            vec![format!("{path}:1")]
+        );
+
+        // Plain commands for known editors are given the position
+        assert_eq!(
+            ide_invocation("/usr/local/bin/code", path, at(12, 3)).unwrap(),
+            (
+                "/usr/local/bin/code".to_string(),
+                vec!["--goto".to_string(), format!("{path}:12:3")]
+            )
+        );
+        assert_eq!(
+            ide_invocation("code", path, None).unwrap().1,
+            vec![path.to_string()]
+        );
+        // Other commands just get the file, and may contain spaces
+        assert_eq!(
+            ide_invocation("/Applications/My Editor.app/bin/edit", path, at(12, 3)).unwrap(),
+            (
+                "/Applications/My Editor.app/bin/edit".to_string(),
+                vec![path.to_string()]
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn test_detect_ides_on() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp.path().join("zed"), "").unwrap();
+        std::fs::create_dir(temp.path().join("code")).unwrap();
+
+        let detected = detect_ides_on(&temp.path().to_string_lossy());
+        assert_eq!(
+            detected,
+            vec![DetectedIde {
+                name: "Zed".to_string(),
+                command: "zed".to_string(),
+                template: "zed {path}:{line}:{column}".to_string(),
+            }]
+        );
+    }
 }
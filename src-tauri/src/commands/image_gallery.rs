@@ -0,0 +1,387 @@
+//! Batch image import for galleries
+//!
+//! `import_image_folder` runs every image in a folder through the same asset pipeline
+//! as a single dropped image: renamed into the collection's assets directory, scaled
+//! down to a maximum width if configured, SVGs sanitized, and skipped in favour of an
+//! existing asset with identical contents. Images are taken in natural filename order (`2.jpg` before
+//! `10.jpg`) and returned as a gallery block plus the individual paths, which the
+//! frontend can put in a schema gallery field instead.
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::dry_run;
+use crate::commands::files::{create_asset_file, format_asset_path};
+use crate::utils::collation::natural_cmp;
+use crate::utils::svg::sanitize_svg;
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+/// Extensions treated as images, matching the frontend's `IMAGE_EXTENSIONS`
+pub(crate) const IMAGE_EXTENSIONS: [&str; 8] =
+    ["png", "jpg", "jpeg", "gif", "webp", "svg", "bmp", "ico"];
+
+const JPEG_QUALITY: u8 = 85;
+
+/// How imported images are stored and referenced
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageFolderOptions {
+    /// Assets directory override, relative to the project root
+    pub assets_directory: Option<String>,
+    pub use_relative_paths: bool,
+    pub use_path_aliases: bool,
+    /// PNG, JPEG and WebP images wider than this are scaled down
+    pub max_width: Option<u32>,
+    /// MDX component wrapping the gallery block (e.g. `Gallery`); plain Markdown
+    /// images when omitted
+    pub component: Option<String>,
+}
+
+/// An image in the generated gallery
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GalleryImage {
+    pub source_path: String,
+    /// Path as referenced from the current file
+    pub path: String,
+    pub alt: String,
+    pub resized: bool,
+    /// An identical asset already existed and was used instead of a new copy
+    pub reused: bool,
+    /// Active content and external references removed from an SVG
+    pub sanitized: Vec<String>,
+}
+
+/// Result of importing a folder of images
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageGallery {
+    pub images: Vec<GalleryImage>,
+    /// Markdown images, wrapped in the MDX component if one was given
+    pub block: String,
+    /// Files in the folder that aren't images, or SVGs that couldn't be sanitized
+    pub skipped: Vec<String>,
+}
+
+/// Alt text from a file name, e.g. "beach_sunset-2.jpg" → "beach sunset 2"
+fn alt_from_file_name(path: &Path) -> String {
+    path.file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .split(['-', '_', ' '])
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Scales an image down to `max_width`, keeping its format and EXIF orientation
+///
+/// Returns `None` when the image is already narrow enough or its format isn't resized.
+fn resize_image(bytes: &[u8], extension: &str, max_width: u32) -> Result<Option<Vec<u8>>, String> {
+    let format = match extension {
+        "png" => ImageFormat::Png,
+        "jpg" | "jpeg" => ImageFormat::Jpeg,
+        "webp" => ImageFormat::WebP,
+        _ => return Ok(None),
+    };
+
+    let mut decoder = ImageReader::with_format(Cursor::new(bytes), format)
+        .into_decoder()
+        .map_err(|e| format!("Failed to read image: {e}"))?;
+    let orientation = decoder
+        .orientation()
+        .map_err(|e| format!("Failed to read image orientation: {e}"))?;
+    let mut image =
+        DynamicImage::from_decoder(decoder).map_err(|e| format!("Failed to decode image: {e}"))?;
+    image.apply_orientation(orientation);
+    if image.width() <= max_width {
+        return Ok(None);
+    }
+
+    let resized = image.resize(max_width, u32::MAX, FilterType::Lanczos3);
+    let mut output = Cursor::new(Vec::new());
+    let written = match format {
+        ImageFormat::Jpeg => {
+            resized.write_with_encoder(JpegEncoder::new_with_quality(&mut output, JPEG_QUALITY))
+        }
+        _ => resized.write_to(&mut output, format),
+    };
+    written.map_err(|e| format!("Failed to encode resized image: {e}"))?;
+    Ok(Some(output.into_inner()))
+}
+
+/// An existing file in `candidates` with exactly `bytes` as its contents
+fn find_identical<'a>(candidates: &'a [PathBuf], bytes: &[u8]) -> Option<&'a PathBuf> {
+    candidates.iter().find(|path| {
+        std::fs::metadata(path).is_ok_and(|m| m.len() == bytes.len() as u64)
+            && std::fs::read(path).is_ok_and(|existing| existing == bytes)
+    })
+}
+
+fn gallery_block(images: &[GalleryImage], component: Option<&str>) -> String {
+    let markdown = images
+        .iter()
+        .map(|image| format!("![{}]({})", image.alt, image.path))
+        .collect::<Vec<_>>()
+        .join("\n");
+    match component {
+        // Blank lines let MDX parse the images inside the component as Markdown
+        Some(component) => format!("<{component}>\n\n{markdown}\n\n</{component}>"),
+        None => markdown,
+    }
+}
+
+/// Imports every image in a folder as assets and builds a gallery from them
+///
+/// # Arguments
+/// * `folder_path` - The folder of images (not searched recursively)
+/// * `project_path` - The absolute path to the project root
+/// * `collection` - The collection whose assets directory receives the images
+/// * `current_file_path` - The file the gallery is inserted into
+/// * `options` - Asset location, path style, maximum width and gallery component
+#[tauri::command]
+#[specta::specta]
+pub async fn import_image_folder(
+    folder_path: String,
+    project_path: String,
+    collection: String,
+    current_file_path: String,
+    options: ImageFolderOptions,
+) -> Result<ImageGallery, String> {
+    let project_root = Path::new(&project_path)
+        .canonicalize()
+        .map_err(|_| "Invalid project root".to_string())?;
+    let folder = PathBuf::from(&folder_path);
+    if !folder.is_dir() {
+        return Err(format!("Not a folder: {folder_path}"));
+    }
+
+    dry_run::spawn_blocking(move || {
+        let assets_dir = project_root
+            .join(options.assets_directory.as_deref().unwrap_or("src/assets"))
+            .join(&collection);
+        dry_run::create_dir_all(&assets_dir)
+            .map_err(|e| format!("Failed to create assets directory: {e}"))?;
+
+        let mut files: Vec<PathBuf> = std::fs::read_dir(&folder)
+            .map_err(|e| format!("Failed to read folder: {e}"))?
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .collect();
+        files.sort_by(|a, b| {
+            natural_cmp(
+                &a.file_name().unwrap_or_default().to_string_lossy(),
+                &b.file_name().unwrap_or_default().to_string_lossy(),
+            )
+        });
+
+        // Existing assets, plus those written by this import, for deduplication. The
+        // directory is missing only in a dry run, before it would have been created.
+        let mut assets: Vec<PathBuf> = std::fs::read_dir(&assets_dir)
+            .into_iter()
+            .flatten()
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .collect();
+
+        let mut images = Vec::new();
+        let mut skipped = Vec::new();
+        for source in files {
+            let file_name = source
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string();
+            let extension = source
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(str::to_lowercase)
+                .unwrap_or_default();
+            if !IMAGE_EXTENSIONS.contains(&extension.as_str()) {
+                skipped.push(file_name);
+                continue;
+            }
+
+            let mut original =
+                std::fs::read(&source).map_err(|e| format!("Failed to read {file_name}: {e}"))?;
+            let mut sanitized = Vec::new();
+            if extension == "svg" {
+                let svg = String::from_utf8(original)
+                    .map_err(|e| e.to_string())
+                    .and_then(|svg| sanitize_svg(&svg));
+                match svg {
+                    Ok(svg) => {
+                        original = svg.content.into_bytes();
+                        sanitized = svg.removed;
+                    }
+                    Err(e) => {
+                        log::warn!("Astro Editor [IMAGE_GALLERY] Skipping {file_name}: {e}");
+                        skipped.push(file_name);
+                        continue;
+                    }
+                }
+            }
+            let resized = match options.max_width {
+                Some(max_width) => {
+                    resize_image(&original, &extension, max_width).unwrap_or_else(|e| {
+                        log::warn!(
+                            "Astro Editor [IMAGE_GALLERY] Copying {file_name} unchanged: {e}"
+                        );
+                        None
+                    })
+                }
+                None => None,
+            };
+            let bytes = resized.as_deref().unwrap_or(&original);
+
+            let (asset, reused) = match find_identical(&assets, bytes) {
+                Some(existing) => (existing.clone(), true),
+                None => {
+                    let asset = create_asset_file(&assets_dir, &file_name, &project_path)?;
+                    dry_run::write(&asset, bytes)
+                        .map_err(|e| format!("Failed to write {file_name}: {e}"))?;
+                    audit_log::record(AuditAction::Create, &asset, None, "import_image_folder");
+                    assets.push(asset.clone());
+                    (asset, false)
+                }
+            };
+
+            let project_relative = asset
+                .strip_prefix(&project_root)
+                .map_err(|_| "Failed to create relative path")?
+                .to_string_lossy()
+                .to_string();
+            images.push(GalleryImage {
+                source_path: source.to_string_lossy().to_string(),
+                path: format_asset_path(
+                    &current_file_path,
+                    &project_path,
+                    &project_relative,
+                    options.use_relative_paths,
+                    options.use_path_aliases,
+                )?,
+                alt: alt_from_file_name(&source),
+                resized: resized.is_some(),
+                reused,
+                sanitized,
+            });
+        }
+
+        log::info!(
+            "Astro Editor [IMAGE_GALLERY] Imported {} images from {}",
+            images.len(),
+            folder.display()
+        );
+
+        Ok(ImageGallery {
+            block: gallery_block(&images, options.component.as_deref()),
+            images,
+            skipped,
+        })
+    })
+    .await
+    .map_err(|e| format!("Failed to import images: {e}"))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GenericImageView, RgbImage};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = Cursor::new(Vec::new());
+        DynamicImage::ImageRgb8(RgbImage::new(width, height))
+            .write_to(&mut bytes, ImageFormat::Png)
+            .unwrap();
+        bytes.into_inner()
+    }
+
+    #[test]
+    fn test_natural_cmp() {
+        let mut names = vec!["IMG-10.jpg", "img-2.jpg", "img-02b.jpg", "cover.png"];
+        names.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(
+            names,
+            vec!["cover.png", "img-2.jpg", "img-02b.jpg", "IMG-10.jpg"]
+        );
+        assert_eq!(
+            alt_from_file_name(Path::new("beach_sunset-2.jpg")),
+            "beach sunset 2"
+        );
+    }
+
+    #[test]
+    fn test_resize_image() {
+        let resized = resize_image(&png(400, 200), "png", 100).unwrap().unwrap();
+        let image = image::load_from_memory(&resized).unwrap();
+        assert_eq!(image.dimensions(), (100, 50));
+
+        assert!(resize_image(&png(80, 40), "png", 100).unwrap().is_none());
+        assert!(resize_image(b"<svg/>", "svg", 100).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_import_image_folder() {
+        let temp = TempDir::new().unwrap();
+        let project = temp.path().join("project");
+        let post = project.join("src/content/blog/post.mdx");
+        fs::create_dir_all(post.parent().unwrap()).unwrap();
+        fs::write(&post, "").unwrap();
+
+        let folder = temp.path().join("photos");
+        fs::create_dir_all(&folder).unwrap();
+        fs::write(folder.join("10.png"), png(300, 300)).unwrap();
+        fs::write(folder.join("2.png"), png(50, 50)).unwrap();
+        // Same contents as 2.png, so it reuses that asset
+        fs::write(folder.join("3-copy.png"), png(50, 50)).unwrap();
+        fs::write(folder.join("notes.txt"), "captions").unwrap();
+        fs::write(
+            folder.join("logo.svg"),
+            "<svg><script>alert(1)</script><rect/></svg>",
+        )
+        .unwrap();
+
+        let gallery = import_image_folder(
+            folder.to_string_lossy().to_string(),
+            project.to_string_lossy().to_string(),
+            "blog".to_string(),
+            post.to_string_lossy().to_string(),
+            ImageFolderOptions {
+                assets_directory: None,
+                use_relative_paths: true,
+                use_path_aliases: false,
+                max_width: Some(100),
+                component: Some("Gallery".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+
+        let alts: Vec<&str> = gallery.images.iter().map(|i| i.alt.as_str()).collect();
+        assert_eq!(alts, vec!["2", "3 copy", "10", "logo"]);
+        assert_eq!(gallery.images[3].sanitized, vec!["<script>"]);
+        assert!(gallery.images[1].reused);
+        assert_eq!(gallery.images[1].path, gallery.images[0].path);
+        assert!(gallery.images[2].resized);
+        assert_eq!(gallery.skipped, vec!["notes.txt"]);
+        assert_eq!(
+            fs::read_dir(project.join("src/assets/blog"))
+                .unwrap()
+                .count(),
+            3
+        );
+
+        assert!(gallery
+            .block
+            .starts_with("<Gallery>\n\n![2](../../assets/blog/"));
+        assert!(gallery.block.ends_with(")\n\n</Gallery>"));
+    }
+}
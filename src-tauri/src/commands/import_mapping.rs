@@ -0,0 +1,554 @@
+//! CSV column mapping suggestions for bulk imports
+//!
+//! Before a spreadsheet is imported as collection entries, each column has to be
+//! mapped to a frontmatter field. `suggest_import_mapping` compares the CSV headers
+//! with the collection's schema fields by name (including common synonyms such as
+//! `summary` for `description`) and checks that the sampled values fit each field's
+//! type, so a `Published` column of dates maps to `pubDate` and a column of words
+//! doesn't map to a number field. Each column gets at most one field and each field
+//! at most one column, best matches first.
+
+use crate::commands::project::scan_project_with_content_dir;
+use crate::commands::tables::{delimiter_option, infer_delimiter, is_numeric, parse_delimited};
+use crate::schema_merger::{SchemaDefinition, SchemaField};
+use chrono::{DateTime, NaiveDate};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashSet;
+
+/// Data rows inspected when detecting column types
+const SAMPLE_ROWS: usize = 100;
+
+/// Example values returned per column
+const SAMPLE_VALUES: usize = 3;
+
+/// Matches below this confidence aren't suggested
+const MIN_CONFIDENCE: f32 = 0.45;
+
+/// Alternatives returned per column, besides the suggestion
+const MAX_ALTERNATIVES: usize = 3;
+
+/// Names that usually mean the same field in spreadsheets and Astro schemas
+const SYNONYMS: [&[&str]; 9] = [
+    &["title", "name", "headline", "heading", "subject"],
+    &[
+        "description",
+        "summary",
+        "excerpt",
+        "abstract",
+        "blurb",
+        "subtitle",
+    ],
+    &[
+        "pubdate",
+        "date",
+        "published",
+        "publishdate",
+        "publishedat",
+        "publishedon",
+        "posted",
+    ],
+    &[
+        "updateddate",
+        "updated",
+        "modified",
+        "lastmodified",
+        "updatedat",
+        "edited",
+    ],
+    &["tags", "keywords", "categories", "labels", "topics"],
+    &[
+        "heroimage",
+        "image",
+        "cover",
+        "coverimage",
+        "thumbnail",
+        "featuredimage",
+        "photo",
+    ],
+    &["author", "authors", "by", "writer", "byline"],
+    &["draft", "unpublished", "hidden"],
+    &["slug", "permalink", "path", "handle"],
+];
+
+/// Date formats accepted besides ISO 8601 and RFC 3339
+const DATE_FORMATS: [&str; 6] = [
+    "%Y/%m/%d",
+    "%m/%d/%Y",
+    "%d.%m.%Y",
+    "%B %d, %Y",
+    "%b %d, %Y",
+    "%d %B %Y",
+];
+
+/// What a CSV column's values look like
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ColumnType {
+    /// No values in the sampled rows
+    Empty,
+    Boolean,
+    Integer,
+    Number,
+    Date,
+    Url,
+    Email,
+    /// Several values separated by `;`, `|` or `, `
+    List,
+    Text,
+}
+
+/// A schema field a column could map to
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldMatch {
+    pub field: String,
+    /// 0 to 1; name similarity weighted by how well the values fit the field's type
+    pub confidence: f32,
+}
+
+/// The suggested field for one CSV column
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnMapping {
+    /// The column header
+    pub column: String,
+    /// 0-based column position
+    pub index: u32,
+    pub column_type: ColumnType,
+    pub sample_values: Vec<String>,
+    /// The suggested field, when one matches well enough
+    pub suggestion: Option<FieldMatch>,
+    /// Other plausible fields, best first, including ones taken by other columns
+    pub alternatives: Vec<FieldMatch>,
+}
+
+/// A proposed mapping of CSV columns to a collection's fields
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportMappingSuggestion {
+    pub columns: Vec<ColumnMapping>,
+    /// Required fields no column maps to
+    pub unmapped_required_fields: Vec<String>,
+    /// The delimiter used, e.g. "," or "\t"
+    pub delimiter: String,
+    /// Data rows, excluding the header
+    pub row_count: u32,
+}
+
+fn is_date(value: &str) -> bool {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok()
+        || DateTime::parse_from_rfc3339(value).is_ok()
+        || DATE_FORMATS
+            .iter()
+            .any(|format| NaiveDate::parse_from_str(value, format).is_ok())
+}
+
+fn value_type(value: &str) -> ColumnType {
+    let lower = value.to_lowercase();
+    if matches!(lower.as_str(), "true" | "false" | "yes" | "no") {
+        ColumnType::Boolean
+    } else if value.parse::<i64>().is_ok() {
+        ColumnType::Integer
+    } else if is_numeric(value) {
+        ColumnType::Number
+    } else if is_date(value) {
+        ColumnType::Date
+    } else if lower.starts_with("http://") || lower.starts_with("https://") {
+        ColumnType::Url
+    } else if !value.contains(' ') && value.split_once('@').is_some_and(|(_, d)| d.contains('.')) {
+        ColumnType::Email
+    } else if value.contains(';') || value.contains('|') || value.contains(", ") {
+        ColumnType::List
+    } else {
+        ColumnType::Text
+    }
+}
+
+/// The type shared by every sampled value, widening integers to numbers and
+/// otherwise falling back to text (or a list when any value is one)
+fn column_type(values: &[&str]) -> ColumnType {
+    let mut types = values.iter().map(|v| value_type(v));
+    let Some(first) = types.next() else {
+        return ColumnType::Empty;
+    };
+    types.fold(first, |acc, t| match (acc, t) {
+        (a, b) if a == b => a,
+        (ColumnType::Integer | ColumnType::Number, ColumnType::Integer | ColumnType::Number) => {
+            ColumnType::Number
+        }
+        (ColumnType::List, _) | (_, ColumnType::List) => ColumnType::List,
+        _ => ColumnType::Text,
+    })
+}
+
+/// Splits a header or field name into lowercase words: `pubDate`, `pub_date` and
+/// `Pub Date` all give `["pub", "date"]`
+fn name_tokens(name: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut prev_lower = false;
+    for c in name.chars() {
+        if !c.is_alphanumeric() {
+            tokens.push(std::mem::take(&mut current));
+            prev_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && prev_lower {
+            tokens.push(std::mem::take(&mut current));
+        }
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        current.extend(c.to_lowercase());
+    }
+    tokens.push(current);
+    tokens.retain(|t| !t.is_empty());
+    tokens
+}
+
+fn bigrams(text: &str) -> Vec<(char, char)> {
+    let chars: Vec<char> = text.chars().collect();
+    chars.windows(2).map(|w| (w[0], w[1])).collect()
+}
+
+/// Sørensen–Dice similarity of character bigrams
+fn dice(a: &str, b: &str) -> f32 {
+    let (a, b) = (bigrams(a), bigrams(b));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let mut remaining = b.clone();
+    let shared = a
+        .iter()
+        .filter(|pair| {
+            remaining
+                .iter()
+                .position(|other| other == *pair)
+                .map(|i| remaining.swap_remove(i))
+                .is_some()
+        })
+        .count();
+    2.0 * shared as f32 / (a.len() + b.len()) as f32
+}
+
+/// How alike two names are, from 0 to 1
+fn name_similarity(a: &str, b: &str) -> f32 {
+    let (a_tokens, b_tokens) = (name_tokens(a), name_tokens(b));
+    let (a_joined, b_joined) = (a_tokens.concat(), b_tokens.concat());
+    if a_joined.is_empty() || b_joined.is_empty() {
+        return 0.0;
+    }
+    if a_joined == b_joined {
+        return 1.0;
+    }
+    if SYNONYMS
+        .iter()
+        .any(|group| group.contains(&a_joined.as_str()) && group.contains(&b_joined.as_str()))
+    {
+        return 0.85;
+    }
+
+    // Words match when equal or one abbreviates the other, e.g. `pub` and `publish`
+    let words_match = |x: &String, y: &String| {
+        x == y
+            || (x.len().min(y.len()) >= 3
+                && (x.starts_with(y.as_str()) || y.starts_with(x.as_str())))
+    };
+    let shared = a_tokens
+        .iter()
+        .filter(|x| b_tokens.iter().any(|y| words_match(x, y)))
+        .count();
+    let overlap = shared as f32 / a_tokens.len().max(b_tokens.len()) as f32;
+
+    0.8 * overlap.max(dice(&a_joined, &b_joined))
+}
+
+/// How well values of `column_type` fit a schema field, from 0 to 1
+fn type_compatibility(field: &SchemaField, column_type: ColumnType, values: &[&str]) -> f32 {
+    use ColumnType::*;
+    if column_type == Empty {
+        return 0.5;
+    }
+    match (field.field_type.as_str(), column_type) {
+        ("enum", _) => {
+            let options = field.enum_values.as_deref().unwrap_or_default();
+            let allowed = values
+                .iter()
+                .filter(|v| options.iter().any(|o| o.eq_ignore_ascii_case(v)))
+                .count();
+            allowed as f32 / values.len() as f32
+        }
+        ("string", Text) => 1.0,
+        ("string", Boolean | Integer | Number | List) => 0.6,
+        ("string", _) => 0.8,
+        ("number", Integer | Number) | ("integer", Integer) => 1.0,
+        ("integer", Number) => 0.5,
+        ("boolean", Boolean) => 1.0,
+        // 0/1 columns
+        ("boolean", Integer) if values.iter().all(|v| *v == "0" || *v == "1") => 0.8,
+        ("date", Date) => 1.0,
+        ("url", Url) | ("email", Email) => 1.0,
+        // Relative paths are common for images
+        ("image" | "url", Text) => 0.6,
+        ("image", Url) => 0.9,
+        ("array", List) => 1.0,
+        // A single item per row
+        ("array", Text | Url | Email) => 0.7,
+        ("array", _) => 0.4,
+        ("reference", Text) => 0.9,
+        ("reference", List | Integer) => 0.5,
+        ("unknown", _) => 0.5,
+        _ => 0.0,
+    }
+}
+
+fn field_match(
+    field: &SchemaField,
+    column: &str,
+    column_type: ColumnType,
+    values: &[&str],
+) -> FieldMatch {
+    let name_score =
+        name_similarity(column, &field.name).max(name_similarity(column, &field.label));
+    let type_score = type_compatibility(field, column_type, values);
+    FieldMatch {
+        field: field.name.clone(),
+        confidence: name_score * (0.4 + 0.6 * type_score),
+    }
+}
+
+/// Proposes a field for each column of `rows`, where the first row is the header
+fn suggest_mapping(
+    rows: &[Vec<String>],
+    schema: &SchemaDefinition,
+) -> (Vec<ColumnMapping>, Vec<String>) {
+    let Some((header, data)) = rows.split_first() else {
+        return (Vec::new(), Vec::new());
+    };
+    let sample = &data[..data.len().min(SAMPLE_ROWS)];
+
+    let mut columns: Vec<ColumnMapping> = Vec::new();
+    let mut candidates: Vec<Vec<FieldMatch>> = Vec::new();
+    for (index, column) in header.iter().enumerate() {
+        let column = column.trim();
+        let values: Vec<&str> = sample
+            .iter()
+            .filter_map(|row| row.get(index))
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+            .collect();
+        let column_type = column_type(&values);
+
+        let mut matches: Vec<FieldMatch> = schema
+            .fields
+            .iter()
+            .map(|field| field_match(field, column, column_type, &values))
+            .filter(|m| m.confidence >= MIN_CONFIDENCE)
+            .collect();
+        matches.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+
+        columns.push(ColumnMapping {
+            column: column.to_string(),
+            index: index as u32,
+            column_type,
+            sample_values: values
+                .iter()
+                .take(SAMPLE_VALUES)
+                .map(|v| v.to_string())
+                .collect(),
+            suggestion: None,
+            alternatives: Vec::new(),
+        });
+        candidates.push(matches);
+    }
+
+    // Best pairs first, so a strong match isn't taken by a weaker column
+    let mut pairs: Vec<(usize, &FieldMatch)> = candidates
+        .iter()
+        .enumerate()
+        .flat_map(|(column, matches)| matches.iter().map(move |m| (column, m)))
+        .collect();
+    pairs.sort_by(|a, b| b.1.confidence.total_cmp(&a.1.confidence));
+    let mut taken: HashSet<&str> = HashSet::new();
+    for (column, field_match) in pairs {
+        if columns[column].suggestion.is_none() && taken.insert(&field_match.field) {
+            columns[column].suggestion = Some(field_match.clone());
+        }
+    }
+
+    for (column, matches) in columns.iter_mut().zip(&candidates) {
+        let suggested = column.suggestion.as_ref().map(|s| s.field.as_str());
+        column.alternatives = matches
+            .iter()
+            .filter(|m| Some(m.field.as_str()) != suggested)
+            .take(MAX_ALTERNATIVES)
+            .cloned()
+            .collect();
+    }
+
+    let unmapped_required = schema
+        .fields
+        .iter()
+        .filter(|field| field.required && !taken.contains(field.name.as_str()))
+        .map(|field| field.name.clone())
+        .collect();
+    (columns, unmapped_required)
+}
+
+/// Suggests which schema field each column of a CSV file maps to, for importing the
+/// rows as entries of a collection
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+/// * `collection_name` - The collection the rows will be imported into
+/// * `file_path` - The CSV/TSV file; its first row must be the header
+/// * `delimiter` - Optional delimiter; inferred from the data when omitted
+#[tauri::command]
+#[specta::specta]
+pub async fn suggest_import_mapping(
+    project_path: String,
+    content_directory: Option<String>,
+    collection_name: String,
+    file_path: String,
+    delimiter: Option<String>,
+) -> Result<ImportMappingSuggestion, String> {
+    let text =
+        std::fs::read_to_string(&file_path).map_err(|e| format!("Failed to read file: {e}"))?;
+    let delimiter = delimiter_option(delimiter.as_deref())?
+        .or_else(|| infer_delimiter(&text))
+        .ok_or("Could not detect a delimiter; the file doesn't look like CSV or TSV")?;
+
+    let collections = scan_project_with_content_dir(project_path, content_directory, None).await?;
+    let schema: SchemaDefinition = collections
+        .into_iter()
+        .find(|collection| collection.name == collection_name)
+        .ok_or_else(|| format!("Collection not found: {collection_name}"))?
+        .complete_schema
+        .and_then(|schema| serde_json::from_str(&schema).ok())
+        .ok_or_else(|| format!("No schema available for collection '{collection_name}'"))?;
+
+    tokio::task::spawn_blocking(move || {
+        let rows = parse_delimited(&text, delimiter);
+        if rows.is_empty() {
+            return Err("The file has no rows".to_string());
+        }
+        let (columns, unmapped_required_fields) = suggest_mapping(&rows, &schema);
+        Ok(ImportMappingSuggestion {
+            columns,
+            unmapped_required_fields,
+            delimiter: delimiter.to_string(),
+            row_count: (rows.len() - 1) as u32,
+        })
+    })
+    .await
+    .map_err(|e| format!("Mapping task failed: {e}"))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str, field_type: &str, required: bool) -> SchemaField {
+        SchemaField {
+            name: name.to_string(),
+            label: name.to_string(),
+            field_type: field_type.to_string(),
+            sub_type: None,
+            required,
+            constraints: None,
+            description: None,
+            markdown_description: None,
+            default: None,
+            enum_values: None,
+            reference_collection: None,
+            array_reference_collection: None,
+            is_nested: None,
+            parent_path: None,
+            group: None,
+        }
+    }
+
+    #[test]
+    fn test_name_similarity() {
+        assert_eq!(name_tokens("pubDate"), vec!["pub", "date"]);
+        assert_eq!(name_tokens("Hero_Image URL"), vec!["hero", "image", "url"]);
+        assert_eq!(name_similarity("Pub Date", "pubDate"), 1.0);
+        assert_eq!(name_similarity("Summary", "description"), 0.85);
+        assert!(name_similarity("Publish Date", "pubDate") > 0.7);
+        assert!(name_similarity("Price", "title") < MIN_CONFIDENCE);
+    }
+
+    #[test]
+    fn test_column_type() {
+        assert_eq!(column_type(&["1", "2"]), ColumnType::Integer);
+        assert_eq!(column_type(&["1", "2.5"]), ColumnType::Number);
+        assert_eq!(
+            column_type(&["2024-01-02", "March 3, 2024"]),
+            ColumnType::Date
+        );
+        assert_eq!(column_type(&["yes", "No"]), ColumnType::Boolean);
+        assert_eq!(column_type(&["astro; rust", "web"]), ColumnType::List);
+        assert_eq!(column_type(&["2024-01-02", "soon"]), ColumnType::Text);
+        assert_eq!(column_type(&[]), ColumnType::Empty);
+    }
+
+    #[test]
+    fn test_suggest_mapping() {
+        let mut category = field("category", "enum", false);
+        category.enum_values = Some(vec!["news".to_string(), "guide".to_string()]);
+        let schema = SchemaDefinition {
+            collection_name: "blog".to_string(),
+            fields: vec![
+                field("title", "string", true),
+                field("description", "string", false),
+                field("pubDate", "date", true),
+                field("updatedDate", "date", false),
+                field("tags", "array", false),
+                field("heroImage", "image", false),
+                category,
+                field("draft", "boolean", false),
+            ],
+            groups: Vec::new(),
+            inferred: false,
+        };
+        let rows = parse_delimited(
+            "Name,Summary,Published,Tags,Cover,Type,Views\n\
+             First,Intro,2024-01-02,astro; web,/img/a.png,news,10\n\
+             Second,More,2024-02-03,rust,/img/b.png,Guide,12\n",
+            ',',
+        );
+
+        let (columns, unmapped) = suggest_mapping(&rows, &schema);
+        let suggested: Vec<(&str, Option<&str>)> = columns
+            .iter()
+            .map(|c| {
+                (
+                    c.column.as_str(),
+                    c.suggestion.as_ref().map(|s| s.field.as_str()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            suggested,
+            vec![
+                ("Name", Some("title")),
+                ("Summary", Some("description")),
+                ("Published", Some("pubDate")),
+                ("Tags", Some("tags")),
+                ("Cover", Some("heroImage")),
+                ("Type", None),
+                ("Views", None),
+            ]
+        );
+        assert!(unmapped.is_empty());
+        assert_eq!(columns[2].column_type, ColumnType::Date);
+        assert_eq!(columns[0].sample_values, vec!["First", "Second"]);
+
+        // Values that don't fit the field's type lower the confidence
+        let (columns, unmapped) =
+            suggest_mapping(&parse_delimited("Date\nnext week\nsoon", ','), &schema);
+        let confidence = columns[0].suggestion.as_ref().map_or(0.0, |s| s.confidence);
+        assert!(confidence < 0.5);
+        assert!(unmapped.contains(&"title".to_string()));
+    }
+}
@@ -0,0 +1,1297 @@
+//! Automation intents for Shortcuts, AppleScript and other URL-driven tools
+//!
+//! Intents arrive as `astro-editor://` URLs using the x-callback-url conventions
+//! Shortcuts understands:
+//!
+//! - `astro-editor://new-file?collection=posts`
+//! - `astro-editor://append-daily-note?collection=notes&text=...`
+//! - `astro-editor://open-project?path=/Users/me/blog`
+//!
+//! Rust parses and queues each intent, then emits `intent-received`. The frontend
+//! drains the queue with `take_pending_intents`, asks the user to confirm each action,
+//! performs it, and reports the outcome with `complete_intent`, which follows the
+//! intent's `x-success` / `x-error` callback so the calling shortcut can continue.
+//! Daily notes follow the project's daily note pattern when it points into the
+//! collection.
+//!
+//! For companion apps on a phone, an opt-in HTTP API serves the same quick capture
+//! over the network: `GET /collections` lists the open project's collections and
+//! `POST /capture` appends a note or photo to today's note in one. It listens on
+//! localhost unless LAN access is enabled, refuses clients outside the local network,
+//! requires a bearer token and rate-limits each client.
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::daily_notes::daily_note_in;
+use crate::commands::files::{calculate_relative_path, create_asset_file};
+use crate::commands::project::{scan_project_with_content_dir, send_toast_notification};
+use crate::commands::projects;
+use crate::models::Collection;
+use crate::security::{check_external_url, validate_project_path};
+use chrono::Local;
+use image::ImageFormat;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, Url};
+use tauri_plugin_deep_link::DeepLinkExt;
+use tauri_plugin_opener::OpenerExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Semaphore;
+
+// Intents received but not yet completed, oldest first
+pub type IntentQueue = Arc<Mutex<Vec<PendingIntent>>>;
+
+/// An action requested from outside the app
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum IntentAction {
+    /// Create a new entry in a collection of the open project
+    #[serde(rename_all = "camelCase")]
+    NewFile { collection: String },
+    /// Append text to today's note in a collection of the open project
+    #[serde(rename_all = "camelCase")]
+    AppendToDailyNote { collection: String, text: String },
+    /// Open a project folder
+    #[serde(rename_all = "camelCase")]
+    OpenProject { path: String },
+}
+
+/// An intent waiting to be handled by the frontend
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IntentRequest {
+    pub id: String,
+    pub action: IntentAction,
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingIntent {
+    request: IntentRequest,
+    success_url: Option<String>,
+    error_url: Option<String>,
+    delivered: bool,
+}
+
+/// Parses an intent URL, returning the action and its `x-success` / `x-error` callbacks
+///
+/// Returns `None` for URLs that aren't intents (e.g. `astro-editor://open?path=...`,
+/// which is handled by the frontend deep-link hook).
+fn parse_intent_url(url: &Url) -> Option<PendingIntent> {
+    if url.scheme() != "astro-editor" {
+        return None;
+    }
+
+    let param = |name: &str| {
+        url.query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+
+    let action = match url.host_str()? {
+        "new-file" => IntentAction::NewFile {
+            collection: param("collection")?,
+        },
+        "append-daily-note" => IntentAction::AppendToDailyNote {
+            collection: param("collection")?,
+            text: param("text")?,
+        },
+        "open-project" => IntentAction::OpenProject {
+            path: param("path")?,
+        },
+        _ => return None,
+    };
+
+    Some(PendingIntent {
+        request: IntentRequest {
+            id: uuid::Uuid::new_v4().to_string(),
+            action,
+        },
+        success_url: param("x-success"),
+        error_url: param("x-error"),
+        delivered: false,
+    })
+}
+
+/// Queues any intent URLs and notifies the frontend
+fn enqueue_intent_urls(app: &AppHandle, urls: &[Url]) {
+    let intents: Vec<PendingIntent> = urls.iter().filter_map(parse_intent_url).collect();
+    if intents.is_empty() {
+        return;
+    }
+
+    for intent in &intents {
+        log::info!(
+            "Astro Editor [INTENTS] Received intent {:?}",
+            intent.request.action
+        );
+    }
+    app.state::<IntentQueue>().lock().unwrap().extend(intents);
+
+    if let Err(e) = app.emit("intent-received", ()) {
+        log::warn!("Astro Editor [INTENTS] Failed to emit intent-received: {e}");
+    }
+}
+
+/// Registers intent handling for URLs received at launch and while running
+pub fn register_intent_handlers(app: &AppHandle) {
+    if let Ok(Some(urls)) = app.deep_link().get_current() {
+        enqueue_intent_urls(app, &urls);
+    }
+
+    let app_handle = app.clone();
+    app.deep_link().on_open_url(move |event| {
+        enqueue_intent_urls(&app_handle, &event.urls());
+    });
+}
+
+/// Adds a `key=value` query parameter to a callback URL
+fn callback_url(base: &str, key: &str, value: Option<&str>) -> Option<String> {
+    let mut url = Url::parse(base).ok()?;
+    if let Some(value) = value {
+        url.query_pairs_mut().append_pair(key, value);
+    }
+    Some(url.to_string())
+}
+
+/// Returns intents that haven't been handed to the frontend yet
+///
+/// Each intent is returned once; call `complete_intent` with its id when done.
+#[tauri::command]
+#[specta::specta]
+pub async fn take_pending_intents(app: AppHandle) -> Result<Vec<IntentRequest>, String> {
+    let queue = app.state::<IntentQueue>();
+    let mut intents = queue.lock().unwrap();
+
+    Ok(intents
+        .iter_mut()
+        .filter(|intent| !intent.delivered)
+        .map(|intent| {
+            intent.delivered = true;
+            intent.request.clone()
+        })
+        .collect())
+}
+
+/// Reports the outcome of an intent and follows its x-callback-url, if any
+///
+/// # Arguments
+/// * `id` - The intent id from `take_pending_intents`
+/// * `result` - Passed to the `x-success` callback as `result` (e.g. a created file path)
+/// * `error` - Set when the intent failed; passed to `x-error` as `errorMessage`
+#[tauri::command]
+#[specta::specta]
+pub async fn complete_intent(
+    app: AppHandle,
+    id: String,
+    result: Option<String>,
+    error: Option<String>,
+) -> Result<(), String> {
+    let intent = {
+        let queue = app.state::<IntentQueue>();
+        let mut intents = queue.lock().unwrap();
+        let index = intents
+            .iter()
+            .position(|intent| intent.request.id == id)
+            .ok_or_else(|| format!("Unknown intent: {id}"))?;
+        intents.remove(index)
+    };
+
+    let callback = match &error {
+        Some(message) => {
+            log::warn!("Astro Editor [INTENTS] Intent failed: {message}");
+            if intent.error_url.is_none() {
+                let _ = send_toast_notification(&app, "error", "Shortcut failed", Some(message));
+            }
+            intent
+                .error_url
+                .and_then(|url| callback_url(&url, "errorMessage", Some(message)))
+        }
+        None => intent
+            .success_url
+            .and_then(|url| callback_url(&url, "result", result.as_deref())),
+    };
+
+    if let Some(url) = callback {
+        check_external_url(&url)?;
+        app.opener()
+            .open_url(url, None::<&str>)
+            .map_err(|e| format!("Failed to open callback URL: {e}"))?;
+    }
+
+    Ok(())
+}
+
+/// Appends `text` as a new paragraph to a note, creating it with `date` as its title
+/// if it doesn't exist
+fn append_to_note_file(note_path: &Path, date: &str, text: &str) -> Result<String, String> {
+    let mut content = if note_path.exists() {
+        std::fs::read_to_string(note_path).map_err(|e| format!("Failed to read daily note: {e}"))?
+    } else {
+        if let Some(directory) = note_path.parent() {
+            std::fs::create_dir_all(directory)
+                .map_err(|e| format!("Failed to create directory: {e}"))?;
+        }
+        format!("---\ntitle: \"{date}\"\n---\n")
+    };
+
+    let trimmed_len = content.trim_end().len();
+    content.truncate(trimmed_len);
+    content.push_str("\n\n");
+    content.push_str(text.trim());
+    content.push('\n');
+
+    std::fs::write(note_path, content).map_err(|e| format!("Failed to write daily note: {e}"))?;
+    audit_log::record(AuditAction::Write, note_path, None, "shortcuts");
+
+    Ok(note_path.to_string_lossy().to_string())
+}
+
+/// Today's note in a collection of the project, with today's date
+///
+/// When the project's daily note pattern puts notes in this collection, that's the note,
+/// created from the daily note template as `open_daily_note` does. Otherwise it's
+/// `YYYY-MM-DD.md` at the top of the collection.
+fn today_note(
+    project_path: &str,
+    content_directory: Option<&str>,
+    collection_path: &Path,
+) -> Result<(PathBuf, String), String> {
+    let collection_path = validate_project_path(&collection_path.to_string_lossy(), project_path)?;
+    let today = Local::now().date_naive();
+    let date = today.format("%Y-%m-%d").to_string();
+
+    let note_path = match daily_note_in(
+        project_path,
+        content_directory,
+        &projects::daily_note_settings(project_path),
+        &collection_path,
+        today,
+    )? {
+        Some(note) => PathBuf::from(note.file_path),
+        None => collection_path.join(format!("{date}.md")),
+    };
+    Ok((note_path, date))
+}
+
+/// Appends text to today's note in a collection of the open project
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the open project's root
+/// * `collection_path` - The absolute path to the collection directory, inside the project
+/// * `text` - Markdown to append as a new paragraph
+///
+/// # Returns
+/// The absolute path of the daily note
+#[tauri::command]
+#[specta::specta]
+pub async fn append_to_daily_note(
+    project_path: String,
+    collection_path: String,
+    text: String,
+) -> Result<String, String> {
+    let content_directory = projects::path_settings(&project_path).content_directory;
+    let (note_path, date) = today_note(
+        &project_path,
+        content_directory.as_deref(),
+        Path::new(&collection_path),
+    )?;
+    append_to_note_file(&note_path, &date, &text)
+}
+
+/// Requests allowed from one client per rate limit window
+const CAPTURE_RATE_LIMIT: u32 = 30;
+const CAPTURE_RATE_WINDOW: Duration = Duration::from_secs(60);
+const MAX_CAPTURE_HEADERS: usize = 16 * 1024;
+/// Largest request body accepted, enough for a full-size phone photo
+const MAX_CAPTURE_BODY: usize = 20 * 1024 * 1024;
+const MAX_CAPTURE_TEXT: usize = 64 * 1024;
+const MIN_CAPTURE_TOKEN_LENGTH: usize = 16;
+/// Clients that don't send a complete request in this time are dropped
+const CAPTURE_READ_TIMEOUT: Duration = Duration::from_secs(15);
+/// Connections served at once; further clients wait to be accepted
+const MAX_CAPTURE_CONNECTIONS: usize = 16;
+/// Photo content types accepted by `POST /capture`, with their format and extension
+const CAPTURE_IMAGE_TYPES: [(&str, ImageFormat, &str); 4] = [
+    ("image/jpeg", ImageFormat::Jpeg, "jpg"),
+    ("image/png", ImageFormat::Png, "png"),
+    ("image/webp", ImageFormat::WebP, "webp"),
+    ("image/gif", ImageFormat::Gif, "gif"),
+];
+
+/// How the capture API is served
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureServerOptions {
+    pub project_path: String,
+    pub content_directory: Option<String>,
+    /// Where captured photos are stored, relative to the project root
+    pub assets_directory: Option<String>,
+    pub port: u16,
+    /// Token clients send as `Authorization: Bearer <token>`; generated when omitted
+    pub token: Option<String>,
+    /// Listen on the local network as well as this machine; other networks are refused
+    pub allow_lan: bool,
+}
+
+/// Where a running capture API can be reached
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureServerInfo {
+    /// Base URL for clients, e.g. `http://192.168.1.20:4870`
+    pub url: String,
+    pub token: String,
+}
+
+pub struct CaptureServer {
+    options: CaptureServerOptions,
+    info: CaptureServerInfo,
+    task: tauri::async_runtime::JoinHandle<()>,
+}
+
+// The running capture API, if enabled
+pub type CaptureServerState = Arc<Mutex<Option<CaptureServer>>>;
+
+/// Fixed-window request counts per client address
+#[derive(Default)]
+struct RateLimiter {
+    windows: HashMap<IpAddr, (Instant, u32)>,
+}
+
+impl RateLimiter {
+    /// Counts a request from `ip`, returning false once it's over the limit
+    fn allow(&mut self, ip: IpAddr, now: Instant) -> bool {
+        self.windows
+            .retain(|_, (start, _)| now.duration_since(*start) < CAPTURE_RATE_WINDOW);
+        let (_, count) = self.windows.entry(ip).or_insert((now, 0));
+        *count += 1;
+        *count <= CAPTURE_RATE_LIMIT
+    }
+}
+
+/// Settings shared by every connection to the capture API
+struct CaptureContext {
+    /// Canonical project root
+    project_path: String,
+    content_directory: Option<String>,
+    assets_directory: Option<String>,
+    token: String,
+    limiter: Mutex<RateLimiter>,
+}
+
+struct CaptureRequest {
+    method: String,
+    path: String,
+    query: Vec<(String, String)>,
+    authorization: Option<String>,
+    content_type: Option<String>,
+    /// Declared body length, checked against the limit before the body is read
+    content_length: usize,
+    body: Vec<u8>,
+}
+
+struct CaptureResponse {
+    status: u16,
+    body: serde_json::Value,
+    /// Shown as a toast once the response is sent
+    notice: Option<String>,
+}
+
+impl CaptureResponse {
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: serde_json::json!({ "error": message }),
+            notice: None,
+        }
+    }
+}
+
+/// A note sent to `POST /capture` as JSON
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CaptureNote {
+    collection: String,
+    text: String,
+}
+
+/// Loopback and private network addresses: the only clients the API accepts
+fn is_local_network(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => ip.is_loopback() || ip.is_private() || ip.is_link_local(),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_local_network(IpAddr::V4(ip)),
+            None => {
+                let first = ip.segments()[0];
+                // Unique local (fc00::/7) and link-local (fe80::/10) addresses
+                ip.is_loopback() || first & 0xfe00 == 0xfc00 || first & 0xffc0 == 0xfe80
+            }
+        },
+    }
+}
+
+/// Compares tokens without stopping at the first difference
+fn tokens_match(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// This machine's address on the local network, from the route to a public address
+///
+/// Connecting a UDP socket sends nothing; it only picks the outgoing interface.
+fn lan_address() -> Option<IpAddr> {
+    let socket = std::net::UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect("192.0.2.1:80").ok()?;
+    socket.local_addr().ok().map(|addr| addr.ip())
+}
+
+/// Makes a caption safe to use as image alt text
+fn alt_text(caption: &str) -> String {
+    caption
+        .chars()
+        .filter(|c| !matches!(c, '[' | ']'))
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .take(200)
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/// Reads the head of one HTTP request, enforcing the header limit
+///
+/// Any body bytes that arrived with the headers are kept in `body`; the rest is
+/// left unread for `read_capture_body`.
+async fn read_capture_head(stream: &mut TcpStream) -> Result<CaptureRequest, CaptureResponse> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 8192];
+
+    loop {
+        let read = stream
+            .read(&mut chunk)
+            .await
+            .map_err(|_| CaptureResponse::error(400, "Failed to read request"))?;
+        if read == 0 {
+            return Err(CaptureResponse::error(400, "Incomplete request"));
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+
+        let mut headers = [httparse::EMPTY_HEADER; 32];
+        let mut parsed = httparse::Request::new(&mut headers);
+        let header_len = match parsed.parse(&buffer) {
+            Ok(httparse::Status::Complete(len)) => len,
+            Ok(httparse::Status::Partial) if buffer.len() < MAX_CAPTURE_HEADERS => continue,
+            Ok(httparse::Status::Partial) => {
+                return Err(CaptureResponse::error(431, "Request headers too large"))
+            }
+            Err(_) => return Err(CaptureResponse::error(400, "Malformed request")),
+        };
+
+        let header = |name: &str| {
+            parsed
+                .headers
+                .iter()
+                .find(|h| h.name.eq_ignore_ascii_case(name))
+                .and_then(|h| std::str::from_utf8(h.value).ok())
+                .map(|v| v.trim().to_string())
+        };
+        if header("transfer-encoding").is_some() {
+            return Err(CaptureResponse::error(411, "Content-Length is required"));
+        }
+        let content_length = match header("content-length") {
+            Some(length) => length
+                .parse::<usize>()
+                .map_err(|_| CaptureResponse::error(400, "Invalid Content-Length"))?,
+            None => 0,
+        };
+
+        let target = parsed.path.unwrap_or("/");
+        let url = Url::parse(&format!("http://capture{target}"))
+            .map_err(|_| CaptureResponse::error(400, "Invalid request path"))?;
+        return Ok(CaptureRequest {
+            method: parsed.method.unwrap_or_default().to_string(),
+            path: url.path().to_string(),
+            query: url.query_pairs().into_owned().collect(),
+            authorization: header("authorization"),
+            content_type: header("content-type"),
+            content_length,
+            body: buffer.split_off(header_len),
+        });
+    }
+}
+
+/// Reads the rest of a request's body, enforcing the body limit
+async fn read_capture_body(
+    stream: &mut TcpStream,
+    request: &mut CaptureRequest,
+) -> Result<(), CaptureResponse> {
+    if request.content_length > MAX_CAPTURE_BODY {
+        return Err(CaptureResponse::error(413, "Request body too large"));
+    }
+    request
+        .body
+        .reserve(request.content_length.saturating_sub(request.body.len()));
+
+    let mut chunk = [0u8; 8192];
+    while request.body.len() < request.content_length {
+        let read = stream
+            .read(&mut chunk)
+            .await
+            .map_err(|_| CaptureResponse::error(400, "Failed to read request"))?;
+        if read == 0 {
+            return Err(CaptureResponse::error(400, "Incomplete request body"));
+        }
+        request.body.extend_from_slice(&chunk[..read]);
+    }
+    request.body.truncate(request.content_length);
+    Ok(())
+}
+
+/// Saves a captured photo to the collection's assets and adds it to today's note
+fn capture_photo(
+    context: &CaptureContext,
+    collection: &Collection,
+    extension: &str,
+    bytes: &[u8],
+    caption: &str,
+) -> Result<String, String> {
+    let project_root = Path::new(&context.project_path);
+    let assets_dir = project_root
+        .join(context.assets_directory.as_deref().unwrap_or("src/assets"))
+        .join(&collection.name);
+    std::fs::create_dir_all(&assets_dir)
+        .map_err(|e| format!("Failed to create assets directory: {e}"))?;
+
+    let asset = create_asset_file(
+        &assets_dir,
+        &format!("capture.{extension}"),
+        &context.project_path,
+    )?;
+    std::fs::write(&asset, bytes).map_err(|e| format!("Failed to save photo: {e}"))?;
+    audit_log::record(AuditAction::Create, &asset, None, "capture");
+
+    let (note_path, date) = today_note(
+        &context.project_path,
+        context.content_directory.as_deref(),
+        &collection.path,
+    )?;
+    let relative_asset = asset
+        .strip_prefix(project_root)
+        .map_err(|_| "Photo was saved outside the project".to_string())?
+        .to_string_lossy()
+        .replace('\\', "/");
+    let reference = calculate_relative_path(
+        &note_path.to_string_lossy(),
+        &context.project_path,
+        &relative_asset,
+    )?;
+
+    append_to_note_file(
+        &note_path,
+        &date,
+        &format!("![{}]({reference})", alt_text(caption)),
+    )
+}
+
+/// Handles `POST /capture`: a JSON note, or a photo with `?collection=` and an
+/// optional `&caption=`
+async fn handle_capture(context: &CaptureContext, request: &CaptureRequest) -> CaptureResponse {
+    let content_type = request
+        .content_type
+        .as_deref()
+        .and_then(|t| t.split(';').next())
+        .map(|t| t.trim().to_lowercase())
+        .unwrap_or_default();
+    let param = |name: &str| {
+        request
+            .query
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.trim().to_string())
+    };
+
+    let (collection_name, note) = if content_type == "application/json" {
+        let note: CaptureNote = match serde_json::from_slice(&request.body) {
+            Ok(note) => note,
+            Err(e) => return CaptureResponse::error(400, &format!("Invalid note: {e}")),
+        };
+        let text = note.text.trim();
+        if text.is_empty() {
+            return CaptureResponse::error(400, "text is empty");
+        }
+        if text.len() > MAX_CAPTURE_TEXT {
+            return CaptureResponse::error(413, "text is too long");
+        }
+        (note.collection.trim().to_string(), Some(text.to_string()))
+    } else if CAPTURE_IMAGE_TYPES
+        .iter()
+        .any(|(mime, _, _)| *mime == content_type)
+    {
+        match param("collection").filter(|c| !c.is_empty()) {
+            Some(collection) => (collection, None),
+            None => return CaptureResponse::error(400, "collection is required"),
+        }
+    } else {
+        return CaptureResponse::error(
+            415,
+            "Send a note as application/json or a photo as JPEG, PNG, WebP or GIF",
+        );
+    };
+
+    let collections = match scan_project_with_content_dir(
+        context.project_path.clone(),
+        context.content_directory.clone(),
+        None,
+    )
+    .await
+    {
+        Ok(collections) => collections,
+        Err(e) => return CaptureResponse::error(500, &e),
+    };
+    let Some(collection) = collections.iter().find(|c| c.name == collection_name) else {
+        return CaptureResponse::error(404, &format!("Unknown collection: {collection_name}"));
+    };
+
+    let (result, notice) = match note {
+        Some(text) => (
+            today_note(
+                &context.project_path,
+                context.content_directory.as_deref(),
+                &collection.path,
+            )
+            .and_then(|(note_path, date)| append_to_note_file(&note_path, &date, &text)),
+            format!("Captured a note in {collection_name}"),
+        ),
+        None => {
+            let Some((_, format, extension)) = CAPTURE_IMAGE_TYPES
+                .iter()
+                .find(|(mime, _, _)| *mime == content_type)
+            else {
+                return CaptureResponse::error(415, "Unsupported photo type");
+            };
+            if image::guess_format(&request.body).ok() != Some(*format) {
+                return CaptureResponse::error(400, "Photo doesn't match its content type");
+            }
+            (
+                capture_photo(
+                    context,
+                    collection,
+                    extension,
+                    &request.body,
+                    &param("caption").unwrap_or_default(),
+                ),
+                format!("Captured a photo in {collection_name}"),
+            )
+        }
+    };
+
+    match result {
+        Ok(file) => CaptureResponse {
+            status: 201,
+            body: serde_json::json!({
+                "file": Path::new(&file)
+                    .strip_prefix(&context.project_path)
+                    .map(|p| p.to_string_lossy().replace('\\', "/"))
+                    .unwrap_or(file),
+            }),
+            notice: Some(notice),
+        },
+        Err(e) => CaptureResponse::error(500, &e),
+    }
+}
+
+/// Checks the rate limit and token from a request's headers
+fn authorize_capture_request(
+    context: &CaptureContext,
+    client: IpAddr,
+    request: &CaptureRequest,
+) -> Result<(), CaptureResponse> {
+    if !context
+        .limiter
+        .lock()
+        .unwrap()
+        .allow(client, Instant::now())
+    {
+        return Err(CaptureResponse::error(429, "Too many requests"));
+    }
+    let authorized = request
+        .authorization
+        .as_deref()
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|token| tokens_match(token.trim(), &context.token));
+    if !authorized {
+        return Err(CaptureResponse::error(401, "Missing or invalid token"));
+    }
+    Ok(())
+}
+
+/// Routes an authorized request to the capture API
+async fn handle_capture_request(
+    context: &CaptureContext,
+    request: CaptureRequest,
+) -> CaptureResponse {
+    match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/collections") => match scan_project_with_content_dir(
+            context.project_path.clone(),
+            context.content_directory.clone(),
+            None,
+        )
+        .await
+        {
+            Ok(collections) => CaptureResponse {
+                status: 200,
+                body: serde_json::json!({
+                    "collections": collections
+                        .iter()
+                        .map(|c| serde_json::json!({ "name": c.name }))
+                        .collect::<Vec<_>>(),
+                }),
+                notice: None,
+            },
+            Err(e) => CaptureResponse::error(500, &e),
+        },
+        ("POST", "/capture") => handle_capture(context, &request).await,
+        (_, "/collections" | "/capture") => CaptureResponse::error(405, "Method not allowed"),
+        _ => CaptureResponse::error(404, "Not found"),
+    }
+}
+
+fn status_text(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        415 => "Unsupported Media Type",
+        429 => "Too Many Requests",
+        431 => "Request Header Fields Too Large",
+        _ => "Internal Server Error",
+    }
+}
+
+/// Reads and answers one request
+///
+/// The client is authorized from the headers alone, so the body of a refused
+/// request is never read.
+async fn respond_to_capture(
+    context: &CaptureContext,
+    stream: &mut TcpStream,
+    client: IpAddr,
+) -> CaptureResponse {
+    if !is_local_network(client) {
+        return CaptureResponse::error(403, "Only clients on the local network are accepted");
+    }
+    let read = async {
+        let mut request = read_capture_head(stream).await?;
+        authorize_capture_request(context, client, &request)?;
+        read_capture_body(stream, &mut request).await?;
+        Ok(request)
+    };
+    match tokio::time::timeout(CAPTURE_READ_TIMEOUT, read).await {
+        Ok(Ok(request)) => handle_capture_request(context, request).await,
+        Ok(Err(response)) => response,
+        Err(_) => CaptureResponse::error(408, "Request timed out"),
+    }
+}
+
+/// Serves one connection: a single request, then the connection is closed
+async fn serve_capture_connection(
+    app: &AppHandle,
+    context: &CaptureContext,
+    mut stream: TcpStream,
+    client: IpAddr,
+) {
+    let response = respond_to_capture(context, &mut stream, client).await;
+
+    if response.status >= 400 {
+        log::warn!(
+            "Astro Editor [CAPTURE] Refused request from {client}: {} {}",
+            response.status,
+            response.body
+        );
+    }
+    let body = response.body.to_string();
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        status_text(response.status),
+        body.len()
+    );
+    let _ = stream.write_all(head.as_bytes()).await;
+    let _ = stream.write_all(body.as_bytes()).await;
+    let _ = stream.shutdown().await;
+
+    if let Some(notice) = response.notice {
+        log::info!("Astro Editor [CAPTURE] {notice}");
+        let _ = send_toast_notification(app, "success", &notice, Some("From the capture API"));
+    }
+}
+
+/// Starts the capture API for the open project, replacing any running instance
+///
+/// Clients on this machine (or the local network, with `allow_lan`) can then
+/// `GET /collections` and `POST /capture` notes and photos into today's note of a
+/// collection, authenticating with the returned token.
+///
+/// # Arguments
+/// * `options` - The project to capture into and how to serve the API
+#[tauri::command]
+#[specta::specta]
+pub async fn start_capture_server(
+    app: AppHandle,
+    state: tauri::State<'_, CaptureServerState>,
+    options: CaptureServerOptions,
+) -> Result<CaptureServerInfo, String> {
+    let previous = {
+        let mut server = state.lock().unwrap();
+        match server.as_ref() {
+            Some(running) if running.options == options => return Ok(running.info.clone()),
+            _ => server.take(),
+        }
+    };
+    if let Some(running) = previous {
+        // Wait for the old listener to close so its port can be reused
+        running.task.abort();
+        let _ = running.task.await;
+    }
+
+    if options.port < 1024 {
+        return Err("Capture API port must be 1024 or higher".to_string());
+    }
+    let token = match options.token.as_deref().map(str::trim) {
+        Some(token) if token.len() < MIN_CAPTURE_TOKEN_LENGTH => {
+            return Err(format!(
+                "Capture API token must be at least {MIN_CAPTURE_TOKEN_LENGTH} characters"
+            ))
+        }
+        Some(token) => token.to_string(),
+        None => uuid::Uuid::new_v4().simple().to_string(),
+    };
+    let project_path = Path::new(&options.project_path)
+        .canonicalize()
+        .map_err(|_| "Invalid project root".to_string())?
+        .to_string_lossy()
+        .to_string();
+
+    let bind_ip = if options.allow_lan {
+        IpAddr::from([0, 0, 0, 0])
+    } else {
+        IpAddr::from([127, 0, 0, 1])
+    };
+    let listener = TcpListener::bind((bind_ip, options.port))
+        .await
+        .map_err(|e| format!("Failed to listen on port {}: {e}", options.port))?;
+
+    let host = if options.allow_lan {
+        lan_address().unwrap_or(IpAddr::from([127, 0, 0, 1]))
+    } else {
+        bind_ip
+    };
+    let info = CaptureServerInfo {
+        url: format!("http://{host}:{}", options.port),
+        token: token.clone(),
+    };
+    let context = Arc::new(CaptureContext {
+        project_path,
+        content_directory: options.content_directory.clone(),
+        assets_directory: options.assets_directory.clone(),
+        token,
+        limiter: Mutex::new(RateLimiter::default()),
+    });
+
+    let app_handle = app.clone();
+    let connections = Arc::new(Semaphore::new(MAX_CAPTURE_CONNECTIONS));
+    let task = tauri::async_runtime::spawn(async move {
+        loop {
+            // Wait for a free slot before accepting, so clients queue in the backlog
+            let Ok(permit) = connections.clone().acquire_owned().await else {
+                break;
+            };
+            let (stream, client) = match listener.accept().await {
+                Ok(connection) => connection,
+                Err(e) => {
+                    log::warn!("Astro Editor [CAPTURE] Failed to accept connection: {e}");
+                    continue;
+                }
+            };
+            let app = app_handle.clone();
+            let context = context.clone();
+            tauri::async_runtime::spawn(async move {
+                serve_capture_connection(&app, &context, stream, client.ip()).await;
+                drop(permit);
+            });
+        }
+    });
+
+    log::info!("Astro Editor [CAPTURE] Listening on {}", info.url);
+    // Stored with the token filled in, so restarting with the generated token is a no-op
+    *state.lock().unwrap() = Some(CaptureServer {
+        options: CaptureServerOptions {
+            token: Some(info.token.clone()),
+            ..options
+        },
+        info: info.clone(),
+        task,
+    });
+    Ok(info)
+}
+
+/// Stops the capture API, if it's running
+#[tauri::command]
+#[specta::specta]
+pub async fn stop_capture_server(
+    state: tauri::State<'_, CaptureServerState>,
+) -> Result<(), String> {
+    if let Some(running) = state.lock().unwrap().take() {
+        running.task.abort();
+        log::info!("Astro Editor [CAPTURE] Stopped");
+    }
+    Ok(())
+}
+
+// Initialize the intent queue when the app starts
+pub fn init_intent_queue() -> IntentQueue {
+    Arc::new(Mutex::new(Vec::new()))
+}
+
+// Initialize the capture API state when the app starts
+pub fn init_capture_server() -> CaptureServerState {
+    Arc::new(Mutex::new(None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn parse(url: &str) -> Option<PendingIntent> {
+        parse_intent_url(&Url::parse(url).unwrap())
+    }
+
+    #[test]
+    fn test_parse_intent_urls() {
+        let intent = parse("astro-editor://new-file?collection=posts").unwrap();
+        assert_eq!(
+            intent.request.action,
+            IntentAction::NewFile {
+                collection: "posts".to_string()
+            }
+        );
+
+        let intent = parse(
+            "astro-editor://append-daily-note?collection=notes&text=Buy%20milk\
+             &x-success=shortcuts%3A%2F%2Fx-callback-url%2Fdone",
+        )
+        .unwrap();
+        assert_eq!(
+            intent.request.action,
+            IntentAction::AppendToDailyNote {
+                collection: "notes".to_string(),
+                text: "Buy milk".to_string()
+            }
+        );
+        assert_eq!(
+            intent.success_url.as_deref(),
+            Some("shortcuts://x-callback-url/done")
+        );
+        assert_eq!(intent.error_url, None);
+
+        let intent = parse("astro-editor://open-project?path=%2FUsers%2Fme%2Fblog").unwrap();
+        assert_eq!(
+            intent.request.action,
+            IntentAction::OpenProject {
+                path: "/Users/me/blog".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_ignores_non_intents() {
+        assert!(parse("astro-editor://open?path=%2Fa.md").is_none());
+        assert!(parse("astro-editor://new-file").is_none());
+        assert!(parse("astro-editor://append-daily-note?collection=notes&text=").is_none());
+        assert!(parse("https://new-file?collection=posts").is_none());
+    }
+
+    #[test]
+    fn test_callback_url_appends_result() {
+        assert_eq!(
+            callback_url("shortcuts://x-callback-url/done", "result", Some("/a b.md")).as_deref(),
+            Some("shortcuts://x-callback-url/done?result=%2Fa+b.md")
+        );
+        assert_eq!(
+            callback_url("shortcuts://done", "result", None).as_deref(),
+            Some("shortcuts://done")
+        );
+    }
+
+    #[test]
+    fn test_append_to_note_file() {
+        let temp = TempDir::new().unwrap();
+        let notes = temp.path().join("notes");
+
+        let note = notes.join("2026-10-16.md");
+        let path = append_to_note_file(&note, "2026-10-16", "First thought").unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "---\ntitle: \"2026-10-16\"\n---\n\nFirst thought\n"
+        );
+
+        append_to_note_file(&note, "2026-10-16", "  Second thought\n").unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "---\ntitle: \"2026-10-16\"\n---\n\nFirst thought\n\nSecond thought\n"
+        );
+    }
+
+    fn capture_context(project: &Path) -> CaptureContext {
+        CaptureContext {
+            project_path: project
+                .canonicalize()
+                .unwrap()
+                .to_string_lossy()
+                .to_string(),
+            content_directory: None,
+            assets_directory: None,
+            token: "0123456789abcdef".to_string(),
+            limiter: Mutex::new(RateLimiter::default()),
+        }
+    }
+
+    fn capture_request(
+        method: &str,
+        target: &str,
+        content_type: Option<&str>,
+        body: &[u8],
+    ) -> CaptureRequest {
+        let url = Url::parse(&format!("http://capture{target}")).unwrap();
+        CaptureRequest {
+            method: method.to_string(),
+            path: url.path().to_string(),
+            query: url.query_pairs().into_owned().collect(),
+            authorization: Some("Bearer 0123456789abcdef".to_string()),
+            content_type: content_type.map(str::to_string),
+            content_length: body.len(),
+            body: body.to_vec(),
+        }
+    }
+
+    /// Authorizes and routes a request, as a connection would once its body is read
+    async fn respond(
+        context: &CaptureContext,
+        client: IpAddr,
+        request: CaptureRequest,
+    ) -> CaptureResponse {
+        match authorize_capture_request(context, client, &request) {
+            Ok(()) => handle_capture_request(context, request).await,
+            Err(response) => response,
+        }
+    }
+
+    #[test]
+    fn test_is_local_network() {
+        for ip in [
+            "127.0.0.1",
+            "192.168.1.20",
+            "10.0.0.5",
+            "172.16.4.1",
+            "::1",
+            "fd12::1",
+            "fe80::1",
+            "::ffff:192.168.1.2",
+        ] {
+            assert!(is_local_network(ip.parse().unwrap()), "{ip}");
+        }
+        for ip in ["8.8.8.8", "172.32.0.1", "2001:db8::1", "::ffff:8.8.8.8"] {
+            assert!(!is_local_network(ip.parse().unwrap()), "{ip}");
+        }
+    }
+
+    #[test]
+    fn test_rate_limiter() {
+        let mut limiter = RateLimiter::default();
+        let client: IpAddr = "192.168.1.20".parse().unwrap();
+        let other: IpAddr = "192.168.1.21".parse().unwrap();
+        let now = Instant::now();
+
+        assert!((0..CAPTURE_RATE_LIMIT).all(|_| limiter.allow(client, now)));
+        assert!(!limiter.allow(client, now));
+        assert!(limiter.allow(other, now));
+        assert!(limiter.allow(client, now + CAPTURE_RATE_WINDOW));
+    }
+
+    #[tokio::test]
+    async fn test_handle_capture_request() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("src/content/notes")).unwrap();
+        let context = capture_context(temp.path());
+        let client: IpAddr = "192.168.1.20".parse().unwrap();
+
+        let response = respond(
+            &context,
+            client,
+            capture_request("GET", "/collections", None, b""),
+        )
+        .await;
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body["collections"][0]["name"], "notes");
+
+        let mut unauthorized = capture_request("GET", "/collections", None, b"");
+        unauthorized.authorization = Some("Bearer 0123456789abcdeX".to_string());
+        let response = respond(&context, client, unauthorized).await;
+        assert_eq!(response.status, 401);
+
+        let note = br#"{"collection": "notes", "text": "Idea from the train"}"#;
+        let response = respond(
+            &context,
+            client,
+            capture_request(
+                "POST",
+                "/capture",
+                Some("application/json; charset=utf-8"),
+                note,
+            ),
+        )
+        .await;
+        assert_eq!(response.status, 201);
+        let date = Local::now().format("%Y-%m-%d").to_string();
+        assert_eq!(
+            response.body["file"],
+            format!("src/content/notes/{date}.md")
+        );
+
+        let mut png = Vec::new();
+        image::DynamicImage::new_rgb8(2, 2)
+            .write_to(&mut std::io::Cursor::new(&mut png), ImageFormat::Png)
+            .unwrap();
+        let response = respond(
+            &context,
+            client,
+            capture_request(
+                "POST",
+                "/capture?collection=notes&caption=Whiteboard%20%5Bdraft%5D",
+                Some("image/png"),
+                &png,
+            ),
+        )
+        .await;
+        assert_eq!(response.status, 201);
+        let content =
+            fs::read_to_string(temp.path().join(format!("src/content/notes/{date}.md"))).unwrap();
+        assert!(content.contains("Idea from the train"));
+        assert!(content.contains(&format!(
+            "![Whiteboard draft](../../assets/notes/{date}-capture.png)"
+        )));
+
+        let rejected = [
+            capture_request(
+                "POST",
+                "/capture?collection=notes",
+                Some("image/jpeg"),
+                &png,
+            ),
+            capture_request("POST", "/capture", Some("image/png"), &png),
+            capture_request("POST", "/capture", Some("text/plain"), b"hi"),
+            capture_request(
+                "POST",
+                "/capture",
+                Some("application/json"),
+                br#"{"collection": "posts", "text": "x"}"#,
+            ),
+            capture_request(
+                "POST",
+                "/capture",
+                Some("application/json"),
+                br#"{"collection": "notes", "text": "x", "path": "/etc"}"#,
+            ),
+            capture_request("DELETE", "/capture", None, b""),
+        ];
+        let statuses: Vec<u16> = response_statuses(&context, client, rejected).await;
+        assert_eq!(statuses, vec![400, 400, 415, 404, 400, 405]);
+    }
+
+    async fn response_statuses(
+        context: &CaptureContext,
+        client: IpAddr,
+        requests: impl IntoIterator<Item = CaptureRequest>,
+    ) -> Vec<u16> {
+        let mut statuses = Vec::new();
+        for request in requests {
+            statuses.push(respond(context, client, request).await.status);
+        }
+        statuses
+    }
+
+    #[tokio::test]
+    async fn test_read_capture_request() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let client = tokio::spawn(async move {
+            let mut stream = TcpStream::connect(address).await.unwrap();
+            stream
+                .write_all(b"POST /capture?collection=notes HTTP/1.1\r\nHost: x\r\nAuthorization: Bearer abc\r\nContent-Type: application/json\r\nContent-Length: 10\r\n\r\n")
+                .await
+                .unwrap();
+            stream.write_all(b"{\"text\":1}").await.unwrap();
+        });
+
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = read_capture_head(&mut stream).await.ok().unwrap();
+        read_capture_body(&mut stream, &mut request)
+            .await
+            .ok()
+            .unwrap();
+        client.await.unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/capture");
+        assert_eq!(
+            request.query,
+            vec![("collection".to_string(), "notes".to_string())]
+        );
+        assert_eq!(request.authorization.as_deref(), Some("Bearer abc"));
+        assert_eq!(request.body, b"{\"text\":1}");
+    }
+
+    #[tokio::test]
+    async fn test_unauthorized_request_body_is_not_read() {
+        let temp = TempDir::new().unwrap();
+        let context = capture_context(temp.path());
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let client = tokio::spawn(async move {
+            let mut stream = TcpStream::connect(address).await.unwrap();
+            let head = format!(
+                "POST /capture?collection=notes HTTP/1.1\r\nHost: x\r\nContent-Type: image/png\r\nContent-Length: {MAX_CAPTURE_BODY}\r\n\r\n"
+            );
+            stream.write_all(head.as_bytes()).await.unwrap();
+            // Holds the connection open without sending the body
+            stream
+        });
+
+        let (mut stream, peer) = listener.accept().await.unwrap();
+        // Waiting for the body would block until the read timeout
+        let response = tokio::time::timeout(
+            Duration::from_secs(1),
+            respond_to_capture(&context, &mut stream, peer.ip()),
+        )
+        .await
+        .expect("refused without reading the body");
+        assert_eq!(response.status, 401);
+        drop(client.await.unwrap());
+    }
+}
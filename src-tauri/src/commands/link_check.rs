@@ -0,0 +1,482 @@
+//! Broken link checking
+//!
+//! `check_links` finds the links in an entry, a collection or the whole project and
+//! reports whether each one works:
+//!
+//! - links to other files (`./part-two.md`) must exist on disk;
+//! - images and other assets must resolve the way image previews do
+//!   (`resolve_image_path`): relative, site-absolute, from `public/` or a tsconfig alias;
+//! - site-absolute links matching a collection's URL pattern must name an entry. Other
+//!   routes are looked for in `src/pages/` and `public/`, and skipped if they aren't
+//!   there, since dynamic routes can't be resolved without building the site;
+//! - external URLs are only checked when asked, with a HEAD request (GET where HEAD
+//!   isn't allowed), `MAX_CONCURRENT_REQUESTS` at a time.
+//!
+//! Anchors within the page and non-HTTP schemes (`mailto:`) aren't reported.
+
+use crate::commands::content_graph::{read_entries, Entry, EntryIndex};
+use crate::commands::files::resolve_asset_path;
+use crate::commands::link_graph::{body_lines, strip_inline_code};
+use crate::commands::project::scan_project_with_content_dir;
+use crate::commands::publish_gates::slug_from_url;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// External URLs requested at once
+const MAX_CONCURRENT_REQUESTS: usize = 8;
+
+/// How long to wait for each external URL
+const REQUEST_TIMEOUT_SECS: u64 = 10;
+
+/// Files a route can be served from, relative to `src/pages/{route}`
+const PAGE_SUFFIXES: [&str; 7] = [
+    ".astro",
+    ".md",
+    ".mdx",
+    ".html",
+    "/index.astro",
+    "/index.md",
+    "/index.mdx",
+];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum CheckedLinkKind {
+    /// Another file or a page on the site
+    Internal,
+    /// An image or other file the page embeds or links to
+    Asset,
+    External,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum LinkStatus {
+    Ok,
+    /// The target doesn't exist, or the server says it's not found
+    Broken,
+    /// The server couldn't be reached, timed out or gave an unexpected response
+    Unverified,
+    /// Not checked: external links when external checking is off, and routes that
+    /// can't be resolved without building the site
+    Skipped,
+}
+
+/// A link and whether it works
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckedLink {
+    pub file_path: String,
+    /// 1-based line of the link
+    pub line: u32,
+    pub target: String,
+    pub kind: CheckedLinkKind,
+    pub status: LinkStatus,
+    /// The response status, for external links that got one
+    pub http_status: Option<u16>,
+    /// Why the link is broken or wasn't checked
+    pub message: Option<String>,
+}
+
+impl CheckedLink {
+    fn with_kind(mut self, kind: CheckedLinkKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    fn with_status(mut self, status: LinkStatus, message: Option<String>) -> Self {
+        self.status = status;
+        self.message = message;
+        self
+    }
+}
+
+/// Link targets in a body by line index: Markdown links and images, reference
+/// definitions, HTML `href`/`src` attributes and autolinks, outside code
+fn link_targets(body: &str) -> Vec<(usize, String)> {
+    let patterns = [
+        r"\]\(\s*<?([^)\s>]+)>?(?:\s+[^)]*)?\)",
+        r"^\s{0,3}\[[^\]^][^\]]*\]:\s*<?([^\s>]+)",
+        r#"\b(?:href|src)=["']([^"'{}]+)["']"#,
+        r"<(https?://[^>\s]+)>",
+    ];
+    let regexes: Vec<Regex> = patterns.iter().map(|p| Regex::new(p).unwrap()).collect();
+
+    let mut targets = Vec::new();
+    for (index, line) in body_lines(body) {
+        let line = strip_inline_code(line);
+        for regex in &regexes {
+            for captures in regex.captures_iter(&line) {
+                let target = (index, captures[1].to_string());
+                if !targets.contains(&target) {
+                    targets.push(target);
+                }
+            }
+        }
+    }
+    targets
+}
+
+fn is_external(target: &str) -> bool {
+    target.starts_with("http://") || target.starts_with("https://") || target.starts_with("//")
+}
+
+/// Whether a site-absolute route is a page in `src/pages/` or a file in `public/`
+fn route_exists(project_root: &Path, route: &str) -> bool {
+    let route = route.trim_matches('/');
+    let pages = project_root.join("src/pages");
+    let page = if route.is_empty() { "index" } else { route };
+    PAGE_SUFFIXES
+        .iter()
+        .any(|suffix| pages.join(format!("{page}{suffix}")).is_file())
+        || (!route.is_empty() && project_root.join("public").join(route).is_file())
+        || project_root
+            .join("public")
+            .join(route)
+            .join("index.html")
+            .is_file()
+}
+
+/// Checks a link that doesn't leave the site
+fn check_local(
+    link: CheckedLink,
+    entry: &Entry,
+    project_root: &str,
+    index: &EntryIndex,
+    url_patterns: &HashMap<String, String>,
+) -> CheckedLink {
+    let path = link.target.split(['#', '?']).next().unwrap_or_default();
+    let path = path.replace("%20", " ");
+    let extension = Path::new(&path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase);
+    let is_page = matches!(
+        extension.as_deref(),
+        None | Some("md" | "mdx" | "html" | "htm")
+    );
+
+    if !is_page || path.starts_with('@') {
+        let file = entry.path.to_string_lossy();
+        return match resolve_asset_path(&path, project_root, Some(&file)) {
+            Ok(_) => link
+                .with_kind(CheckedLinkKind::Asset)
+                .with_status(LinkStatus::Ok, None),
+            Err(_) => link
+                .with_kind(CheckedLinkKind::Asset)
+                .with_status(LinkStatus::Broken, Some("Asset not found".into())),
+        };
+    }
+
+    if path.starts_with('/') {
+        if index
+            .resolve_link(&entry.path, &link.target, url_patterns)
+            .is_some()
+            || route_exists(Path::new(project_root), &path)
+        {
+            return link.with_status(LinkStatus::Ok, None);
+        }
+        let collection = url_patterns
+            .iter()
+            .find(|(_, pattern)| slug_from_url(&path, pattern).is_some());
+        return match collection {
+            Some((collection, _)) => link.with_status(
+                LinkStatus::Broken,
+                Some(format!("No {collection} entry at this URL")),
+            ),
+            None => link.with_status(
+                LinkStatus::Skipped,
+                Some("Not a page in src/pages or public; dynamic routes aren't checked".into()),
+            ),
+        };
+    }
+
+    let base = entry.path.parent().unwrap_or(Path::new(""));
+    match (base.join(&path).exists(), extension) {
+        (true, _) => link.with_status(LinkStatus::Ok, None),
+        // Route-style relative links like `../other-post/`
+        (false, None) => link.with_status(
+            LinkStatus::Skipped,
+            Some("Relative routes aren't checked".into()),
+        ),
+        (false, Some(_)) => link.with_status(LinkStatus::Broken, Some("File not found".into())),
+    }
+}
+
+/// Requests an external URL, returning its status, response code and problem
+async fn check_url(
+    client: &reqwest::Client,
+    url: &str,
+) -> (LinkStatus, Option<u16>, Option<String>) {
+    let response = match client.head(url).send().await {
+        // Some servers don't support HEAD, or refuse it
+        Ok(response) if matches!(response.status().as_u16(), 403 | 405 | 501) => {
+            client.get(url).send().await
+        }
+        other => other,
+    };
+    match response {
+        Ok(response) => {
+            let code = response.status().as_u16();
+            match code {
+                200..=399 => (LinkStatus::Ok, Some(code), None),
+                404 | 410 => (
+                    LinkStatus::Broken,
+                    Some(code),
+                    Some(format!("Server responded {code}")),
+                ),
+                _ => (
+                    LinkStatus::Unverified,
+                    Some(code),
+                    Some(format!("Server responded {code}")),
+                ),
+            }
+        }
+        Err(e) if e.is_timeout() => (
+            LinkStatus::Unverified,
+            None,
+            Some(format!("No response within {REQUEST_TIMEOUT_SECS}s")),
+        ),
+        Err(e) => (LinkStatus::Unverified, None, Some(e.to_string())),
+    }
+}
+
+/// Checks external links in place, each distinct URL once
+async fn check_external(links: &mut [CheckedLink]) -> Result<(), String> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .user_agent("astro-editor")
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+    let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_REQUESTS));
+
+    let mut urls: Vec<String> = links
+        .iter()
+        .filter(|link| link.kind == CheckedLinkKind::External)
+        .map(|link| link.target.clone())
+        .collect();
+    urls.sort();
+    urls.dedup();
+
+    let mut requests = JoinSet::new();
+    for url in urls {
+        let (client, semaphore) = (client.clone(), semaphore.clone());
+        requests.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            let request_url = match url.strip_prefix("//") {
+                Some(rest) => format!("https://{rest}"),
+                None => url.clone(),
+            };
+            let result = check_url(&client, &request_url).await;
+            (url, result)
+        });
+    }
+
+    let mut results = HashMap::new();
+    while let Some(joined) = requests.join_next().await {
+        if let Ok((url, result)) = joined {
+            results.insert(url, result);
+        }
+    }
+    for link in links
+        .iter_mut()
+        .filter(|link| link.kind == CheckedLinkKind::External)
+    {
+        if let Some((status, code, message)) = results.get(&link.target) {
+            link.status = *status;
+            link.http_status = *code;
+            link.message = message.clone();
+        }
+    }
+    Ok(())
+}
+
+/// Finds and checks the local links in entries, marking external ones skipped
+fn check_entries(
+    entries: &[&Entry],
+    index: &EntryIndex,
+    project_root: &str,
+    url_patterns: &HashMap<String, String>,
+) -> Vec<CheckedLink> {
+    let mut links = Vec::new();
+    for entry in entries {
+        for (line, target) in link_targets(&entry.body) {
+            if target.starts_with('#') || (target.contains(':') && !is_external(&target)) {
+                continue;
+            }
+            let link = CheckedLink {
+                file_path: entry.path.to_string_lossy().to_string(),
+                line: (entry.body_offset + line + 1) as u32,
+                target,
+                kind: CheckedLinkKind::Internal,
+                status: LinkStatus::Skipped,
+                http_status: None,
+                message: None,
+            };
+            links.push(if is_external(&link.target) {
+                link.with_kind(CheckedLinkKind::External)
+            } else {
+                check_local(link, entry, project_root, index, url_patterns)
+            });
+        }
+    }
+    links
+}
+
+/// Checks the links in an entry, a collection or the whole project
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+/// * `file_path` - Check only this entry
+/// * `collection` - Check only this collection's entries (ignored with `file_path`)
+/// * `url_patterns` - Collection URL patterns (e.g. `{ "blog": "/writing/{slug}" }`) for
+///   checking site-absolute links to entries
+/// * `check_external` - Whether to request external URLs; otherwise they're skipped
+///
+/// # Returns
+/// Every link found, by file and line, with whether it works
+#[tauri::command]
+#[specta::specta]
+pub async fn check_links(
+    project_path: String,
+    content_directory: Option<String>,
+    file_path: Option<String>,
+    collection: Option<String>,
+    url_patterns: HashMap<String, String>,
+    check_external: bool,
+) -> Result<Vec<CheckedLink>, String> {
+    let collections =
+        scan_project_with_content_dir(project_path.clone(), content_directory, None).await?;
+
+    let mut links = tokio::task::spawn_blocking(move || {
+        let entries = read_entries(&collections);
+        let index = EntryIndex::new(&entries, &collections);
+        let file_path = file_path.map(|path| {
+            let path = Path::new(&path);
+            path.canonicalize().unwrap_or(path.to_path_buf())
+        });
+        let selected: Vec<&Entry> = entries
+            .iter()
+            .filter(|entry| match (&file_path, &collection) {
+                (Some(path), _) => entry.path.canonicalize().ok().as_ref() == Some(path),
+                (None, Some(collection)) => &entry.collection == collection,
+                (None, None) => true,
+            })
+            .collect();
+        if let (Some(path), true) = (&file_path, selected.is_empty()) {
+            return Err(format!("{} isn't an entry in a collection", path.display()));
+        }
+        Ok(check_entries(
+            &selected,
+            &index,
+            &project_path,
+            &url_patterns,
+        ))
+    })
+    .await
+    .map_err(|e| format!("Failed to check links: {e}"))??;
+
+    if check_external {
+        check_external(&mut links).await?;
+    }
+    Ok(links)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Collection;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn status_of<'a>(links: &'a [CheckedLink], target: &str) -> &'a CheckedLink {
+        links.iter().find(|link| link.target == target).unwrap()
+    }
+
+    #[test]
+    fn test_link_targets() {
+        let body = "See [a](./a.md) and ![img](../img.png \"Title\").\n\n\
+                    `[not](./code.md)` <a href=\"/about/\">About</a> <https://astro.build>\n\n\
+                    ```\n[fenced](./fenced.md)\n```\n\n[ref]: https://example.com/ref\n";
+        let targets: Vec<String> = link_targets(body).into_iter().map(|(_, t)| t).collect();
+        assert_eq!(
+            targets,
+            vec![
+                "./a.md",
+                "../img.png",
+                "/about/",
+                "https://astro.build",
+                "https://example.com/ref"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_entries() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        let blog = root.join("src/content/blog");
+        fs::create_dir_all(&blog).unwrap();
+        fs::create_dir_all(root.join("src/pages")).unwrap();
+        fs::create_dir_all(root.join("public/images")).unwrap();
+        fs::write(root.join("src/pages/about.astro"), "").unwrap();
+        fs::write(root.join("public/images/cat.png"), "").unwrap();
+        fs::write(blog.join("second.md"), "---\ntitle: Second\n---\n").unwrap();
+        fs::write(
+            blog.join("first.md"),
+            "---\ntitle: First\n---\n\n[ok](./second.md) [gone](./third.md)\n\
+             ![cat](/images/cat.png) ![dog](/images/dog.png)\n\
+             [post](/writing/second/) [missing](/writing/nope/) [about](/about/) [tag](/tags/astro/)\n\
+             [ext](https://example.com) [mail](mailto:me@example.com) [top](#top)\n",
+        )
+        .unwrap();
+
+        let collections = vec![Collection::new("blog".to_string(), blog.clone())];
+        let entries = read_entries(&collections);
+        let index = EntryIndex::new(&entries, &collections);
+        let first: Vec<&Entry> = entries.iter().filter(|e| e.id == "blog/first").collect();
+        let links = check_entries(
+            &first,
+            &index,
+            &root.to_string_lossy(),
+            &HashMap::from([("blog".to_string(), "/writing/{slug}".to_string())]),
+        );
+
+        assert_eq!(links.len(), 9);
+        assert_eq!(status_of(&links, "./second.md").status, LinkStatus::Ok);
+        assert_eq!(status_of(&links, "./third.md").status, LinkStatus::Broken);
+        assert_eq!(status_of(&links, "./third.md").line, 5);
+        let cat = status_of(&links, "/images/cat.png");
+        assert_eq!(
+            (cat.kind, cat.status),
+            (CheckedLinkKind::Asset, LinkStatus::Ok)
+        );
+        assert_eq!(
+            status_of(&links, "/images/dog.png").status,
+            LinkStatus::Broken
+        );
+        assert_eq!(status_of(&links, "/writing/second/").status, LinkStatus::Ok);
+        assert_eq!(
+            status_of(&links, "/writing/nope/").status,
+            LinkStatus::Broken
+        );
+        assert_eq!(status_of(&links, "/about/").status, LinkStatus::Ok);
+        assert_eq!(
+            status_of(&links, "/tags/astro/").status,
+            LinkStatus::Skipped
+        );
+        let external = status_of(&links, "https://example.com");
+        assert_eq!(
+            (external.kind, external.status),
+            (CheckedLinkKind::External, LinkStatus::Skipped)
+        );
+    }
+}
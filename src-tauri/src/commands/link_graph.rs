@@ -0,0 +1,458 @@
+//! Internal links and backlinks
+//!
+//! Finds the links between entries in a project: Markdown links to another entry's
+//! file (`[next](./part-two.md)`) or to its URL (`/writing/part-two/`, using the
+//! collection's URL pattern), schema `reference()` fields and wiki-style links
+//! (`[[part-two]]`, `[[blog/part-two|Part two]]`). Wiki links name an entry by ID,
+//! by its ID or slug within any collection, or by title.
+//!
+//! `get_backlinks` lists the entries that link to one file, for the sidebar.
+
+use crate::commands::content_graph::{
+    local_id, read_entries, reference_fields, referenced_ids, Entry, EntryIndex,
+};
+use crate::commands::project::scan_project_with_content_dir;
+use crate::commands::publish_gates::entry_slug;
+use crate::models::{Collection, FileEntry};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+/// Longest line excerpt shown with a backlink, in characters
+const MAX_CONTEXT_CHARS: usize = 160;
+
+/// How one entry links to another
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "camelCase")]
+pub enum LinkKind {
+    /// A Markdown link to the entry's file or URL
+    Markdown,
+    /// A schema `reference()` field
+    Reference,
+    /// A `[[wiki link]]`
+    Wiki,
+}
+
+/// A link from one entry to another
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InternalLink {
+    /// Entry ID of the linking entry, e.g. `blog/first-post`
+    pub from: String,
+    pub to: String,
+    pub kind: LinkKind,
+    /// 1-based line of the link in the linking file; `None` for reference fields
+    pub line: Option<u32>,
+    /// The line the link is on, or the reference field's name
+    pub context: String,
+}
+
+/// Every internal link in a project
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkGraph {
+    /// Entry IDs, including entries without links
+    pub entries: Vec<String>,
+    pub links: Vec<InternalLink>,
+}
+
+/// An entry linking to the file whose backlinks were requested
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct Backlink {
+    /// The linking entry, ready to open
+    pub source: FileEntry,
+    pub kind: LinkKind,
+    pub line: Option<u32>,
+    pub context: String,
+}
+
+/// Wiki link targets by line, outside code fences and inline code, as
+/// `(line index, target)` with any `|label` or `#heading` removed
+fn wiki_links(body: &str) -> Vec<(usize, String)> {
+    let wiki_re = Regex::new(r"\[\[([^\[\]|#]+)(?:#[^\[\]|]*)?(?:\|[^\[\]]*)?\]\]").unwrap();
+    body_lines(body)
+        .flat_map(|(index, line)| {
+            let without_code = strip_inline_code(line);
+            wiki_re
+                .captures_iter(&without_code)
+                .map(|c| (index, c[1].trim().to_string()))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Markdown link targets by line, outside code fences, as `(line index, target)`
+fn markdown_links(body: &str) -> Vec<(usize, String)> {
+    let link_re = Regex::new(r"\]\(\s*<?([^)\s>]+)>?(?:\s+[^)]*)?\)").unwrap();
+    body_lines(body)
+        .flat_map(|(index, line)| {
+            link_re
+                .captures_iter(line)
+                .map(|c| (index, c[1].to_string()))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Lines of `body` outside code fences, with their index
+pub(crate) fn body_lines(body: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut fence: Option<&str> = None;
+    body.lines().enumerate().filter(move |(_, line)| {
+        let trimmed = line.trim_start();
+        let marker = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m));
+        match (fence, marker) {
+            (None, Some(m)) => fence = Some(m),
+            (Some(open), Some(m)) if open == m => fence = None,
+            _ => {}
+        }
+        fence.is_none() && marker.is_none()
+    })
+}
+
+pub(crate) fn strip_inline_code(line: &str) -> String {
+    line.split('`')
+        .enumerate()
+        .filter(|(i, _)| i % 2 == 0)
+        .map(|(_, part)| part)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A line trimmed and shortened for display
+fn excerpt(line: &str) -> String {
+    let line = line.trim();
+    if line.chars().count() <= MAX_CONTEXT_CHARS {
+        return line.to_string();
+    }
+    let mut short: String = line.chars().take(MAX_CONTEXT_CHARS - 1).collect();
+    short.push('…');
+    short
+}
+
+/// Entry IDs by the names a wiki link can use: full ID, ID or slug within the
+/// collection, and lowercased title. Earlier entries (by ID) win clashes.
+fn wiki_names(
+    entries: &[Entry],
+    collections: &[Collection],
+    title_field: &str,
+) -> HashMap<String, String> {
+    let collection_paths: HashMap<&str, &Path> = collections
+        .iter()
+        .map(|c| (c.name.as_str(), c.path.as_path()))
+        .collect();
+
+    let mut names = HashMap::new();
+    for entry in entries {
+        let slug = entry_slug(
+            &entry.path,
+            collection_paths[entry.collection.as_str()],
+            &entry.frontmatter,
+        );
+        let title = entry
+            .frontmatter
+            .get(title_field)
+            .and_then(Value::as_str)
+            .map(str::to_lowercase);
+        let keys = [
+            Some(entry.id.clone()),
+            Some(local_id(entry).to_string()),
+            Some(slug),
+            title,
+        ];
+        for key in keys.into_iter().flatten() {
+            names.entry(key).or_insert_with(|| entry.id.clone());
+        }
+    }
+    names
+}
+
+fn build_links(
+    entries: &[Entry],
+    collections: &[Collection],
+    title_field: &str,
+    url_patterns: &HashMap<String, String>,
+) -> Vec<InternalLink> {
+    let index = EntryIndex::new(entries, collections);
+    let references = reference_fields(collections);
+    let names = wiki_names(entries, collections, title_field);
+
+    let mut links: BTreeSet<(String, String, LinkKind, Option<u32>, String)> = BTreeSet::new();
+    for entry in entries {
+        for (field, target_collection) in references.get(&entry.collection).into_iter().flatten() {
+            let Some(value) = entry.frontmatter.get(field) else {
+                continue;
+            };
+            for (collection, id) in referenced_ids(value, target_collection) {
+                if let Some(target) = index.get(&collection, &id) {
+                    links.insert((
+                        entry.id.clone(),
+                        target.clone(),
+                        LinkKind::Reference,
+                        None,
+                        field.clone(),
+                    ));
+                }
+            }
+        }
+
+        let lines: Vec<&str> = entry.body.lines().collect();
+        let found = markdown_links(&entry.body)
+            .into_iter()
+            .filter_map(|(line, target)| {
+                let linked = index.resolve_link(&entry.path, &target, url_patterns)?;
+                Some((line, linked.clone(), LinkKind::Markdown))
+            })
+            .chain(
+                wiki_links(&entry.body)
+                    .into_iter()
+                    .filter_map(|(line, name)| {
+                        let linked = names
+                            .get(&name)
+                            .or_else(|| names.get(&name.to_lowercase()))?;
+                        Some((line, linked.clone(), LinkKind::Wiki))
+                    }),
+            );
+        for (line, target, kind) in found {
+            links.insert((
+                entry.id.clone(),
+                target,
+                kind,
+                Some((entry.body_offset + line + 1) as u32),
+                excerpt(lines.get(line).copied().unwrap_or_default()),
+            ));
+        }
+    }
+
+    links
+        .into_iter()
+        .filter(|(from, to, ..)| from != to)
+        .map(|(from, to, kind, line, context)| InternalLink {
+            from,
+            to,
+            kind,
+            line,
+            context,
+        })
+        .collect()
+}
+
+/// Lists the links pointing at the entry at `file_path`, with the linking entries
+fn backlinks_to(
+    collections: &[Collection],
+    file_path: &Path,
+    title_field: &str,
+    url_patterns: &HashMap<String, String>,
+) -> Vec<Backlink> {
+    let entries = read_entries(collections);
+    let index = EntryIndex::new(&entries, collections);
+    let Some(target) = index.at_path(file_path).cloned() else {
+        return Vec::new();
+    };
+
+    let collection_paths: HashMap<&str, &PathBuf> = collections
+        .iter()
+        .map(|c| (c.name.as_str(), &c.path))
+        .collect();
+    let by_id: HashMap<&str, &Entry> = entries.iter().map(|e| (e.id.as_str(), e)).collect();
+
+    build_links(&entries, collections, title_field, url_patterns)
+        .into_iter()
+        .filter(|link| link.to == target)
+        .filter_map(|link| {
+            let entry = by_id.get(link.from.as_str())?;
+            let source = FileEntry::new(
+                entry.path.clone(),
+                entry.collection.clone(),
+                collection_paths[entry.collection.as_str()].clone(),
+            )
+            .with_frontmatter(entry.frontmatter.clone());
+            Some(Backlink {
+                source,
+                kind: link.kind,
+                line: link.line,
+                context: link.context,
+            })
+        })
+        .collect()
+}
+
+/// Lists every internal link between entries in the project
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+/// * `title_field` - Frontmatter field wiki links can name entries by
+/// * `url_patterns` - Collection URL patterns (e.g. `{ "blog": "/writing/{slug}" }`) for
+///   resolving site-absolute links
+#[tauri::command]
+#[specta::specta]
+pub async fn get_link_graph(
+    project_path: String,
+    content_directory: Option<String>,
+    title_field: String,
+    url_patterns: HashMap<String, String>,
+) -> Result<LinkGraph, String> {
+    let collections = scan_project_with_content_dir(project_path, content_directory, None).await?;
+
+    tokio::task::spawn_blocking(move || {
+        let entries = read_entries(&collections);
+        let links = build_links(&entries, &collections, &title_field, &url_patterns);
+        LinkGraph {
+            entries: entries.into_iter().map(|e| e.id).collect(),
+            links,
+        }
+    })
+    .await
+    .map_err(|e| format!("Failed to build link graph: {e}"))
+}
+
+/// Lists the entries that link to a file
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+/// * `file_path` - The file to find links to
+/// * `title_field` - Frontmatter field wiki links can name entries by
+/// * `url_patterns` - Collection URL patterns for resolving site-absolute links
+///
+/// # Returns
+/// One backlink per link, ordered by linking entry; empty if the file isn't an entry
+#[tauri::command]
+#[specta::specta]
+pub async fn get_backlinks(
+    project_path: String,
+    content_directory: Option<String>,
+    file_path: String,
+    title_field: String,
+    url_patterns: HashMap<String, String>,
+) -> Result<Vec<Backlink>, String> {
+    let collections = scan_project_with_content_dir(project_path, content_directory, None).await?;
+
+    tokio::task::spawn_blocking(move || {
+        backlinks_to(
+            &collections,
+            Path::new(&file_path),
+            &title_field,
+            &url_patterns,
+        )
+    })
+    .await
+    .map_err(|e| format!("Failed to find backlinks: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn project() -> (TempDir, Vec<Collection>) {
+        let temp = TempDir::new().unwrap();
+        let blog = temp.path().join("blog");
+        let authors = temp.path().join("authors");
+        fs::create_dir_all(&blog).unwrap();
+        fs::create_dir_all(&authors).unwrap();
+
+        fs::write(authors.join("ada.md"), "---\nname: Ada\n---\n").unwrap();
+        fs::write(
+            blog.join("one.md"),
+            "---\ntitle: One\nauthor: ada\n---\n\nSee [two](/writing/two/).\n\n```\n[[two]]\n```\n",
+        )
+        .unwrap();
+        fs::write(
+            blog.join("two.md"),
+            "---\ntitle: Two\n---\n\nBack to [one](./one.md) and [[One|the first]].\n",
+        )
+        .unwrap();
+        fs::write(
+            blog.join("three.md"),
+            "---\ntitle: Three\n---\n\nAlso see [[blog/one#intro]] and `[[two]]`.\n",
+        )
+        .unwrap();
+
+        let schema = r#"{"collectionName":"blog","fields":[{"name":"author","label":"Author","fieldType":"reference","required":false,"referenceCollection":"authors"}]}"#;
+        let collections = vec![
+            Collection::new("blog".to_string(), blog).with_complete_schema(schema.to_string()),
+            Collection::new("authors".to_string(), authors),
+        ];
+        (temp, collections)
+    }
+
+    #[test]
+    fn test_wiki_links_skip_code() {
+        let body = "[[a]] and [[b|B]]\n`[[c]]` [[d#part]]\n```\n[[e]]\n```\n";
+        assert_eq!(
+            wiki_links(body),
+            vec![
+                (0, "a".to_string()),
+                (0, "b".to_string()),
+                (1, "d".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_links() {
+        let (_temp, collections) = project();
+        let patterns = HashMap::from([("blog".to_string(), "/writing/{slug}".to_string())]);
+        let entries = read_entries(&collections);
+        let links = build_links(&entries, &collections, "title", &patterns);
+
+        let summary: Vec<(&str, &str, LinkKind, Option<u32>)> = links
+            .iter()
+            .map(|l| (l.from.as_str(), l.to.as_str(), l.kind, l.line))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("blog/one", "authors/ada", LinkKind::Reference, None),
+                ("blog/one", "blog/two", LinkKind::Markdown, Some(6)),
+                ("blog/three", "blog/one", LinkKind::Wiki, Some(5)),
+                ("blog/two", "blog/one", LinkKind::Markdown, Some(5)),
+                ("blog/two", "blog/one", LinkKind::Wiki, Some(5)),
+            ]
+        );
+        assert_eq!(links[0].context, "author");
+        assert_eq!(links[1].context, "See [two](/writing/two/).");
+    }
+
+    #[test]
+    fn test_backlinks_to() {
+        let (temp, collections) = project();
+        let backlinks = backlinks_to(
+            &collections,
+            &temp.path().join("blog/one.md"),
+            "title",
+            &HashMap::new(),
+        );
+
+        let sources: Vec<(&str, LinkKind)> = backlinks
+            .iter()
+            .map(|b| (b.source.id.as_str(), b.kind))
+            .collect();
+        assert_eq!(
+            sources,
+            vec![
+                ("blog/three", LinkKind::Wiki),
+                ("blog/two", LinkKind::Markdown),
+                ("blog/two", LinkKind::Wiki),
+            ]
+        );
+        assert_eq!(
+            backlinks[0].source.frontmatter.as_ref().unwrap()["title"],
+            "Three"
+        );
+
+        let none = backlinks_to(
+            &collections,
+            &temp.path().join("missing.md"),
+            "title",
+            &HashMap::new(),
+        );
+        assert!(none.is_empty());
+    }
+}
@@ -0,0 +1,788 @@
+//! Keeping relative links working when a file is renamed or moved
+//!
+//! A rename breaks relative references in both directions: the file's own links,
+//! images and MDX imports were written relative to its old directory, and other files
+//! point at its old name. `plan_link_rewrites` finds both kinds before the rename and
+//! `apply_link_rewrites` writes them afterwards. Only references that resolve to a file
+//! on disk are rewritten, so route-style links like `../other-post/` are left alone.
+//!
+//! Renaming an entry also changes its ID and, unless frontmatter sets one, its slug.
+//! `plan_entry_rewrites` follows those too: `reference()` values, site-absolute links
+//! matching the collection's URL pattern and `[[wiki links]]` naming the entry.
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::bulk_undo::UndoBundle;
+use crate::commands::content_graph::reference_fields;
+use crate::commands::dry_run;
+use crate::commands::files::parse_frontmatter_internal;
+use crate::commands::publish_gates::{entry_slug, slug_from_url};
+use crate::commands::watcher::DEFAULT_EXCLUDED_DIRECTORIES;
+use crate::models::{Collection, FileEntry};
+use pathdiff::diff_paths;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use walkdir::WalkDir;
+
+/// A reference rewritten to follow a renamed file
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RewrittenLink {
+    /// The file containing the reference, at its path after the rename
+    pub file_path: String,
+    /// 1-based line of the reference
+    pub line: u32,
+    pub old_target: String,
+    pub new_target: String,
+}
+
+/// What a rename rewrote to keep references to the file working
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkRewriteReport {
+    /// Files changed, at their paths after the rename
+    pub files: Vec<String>,
+    pub links: Vec<RewrittenLink>,
+}
+
+impl From<Vec<RewrittenLink>> for LinkRewriteReport {
+    fn from(links: Vec<RewrittenLink>) -> Self {
+        let mut files: Vec<String> = Vec::new();
+        for link in &links {
+            if !files.contains(&link.file_path) {
+                files.push(link.file_path.clone());
+            }
+        }
+        Self { files, links }
+    }
+}
+
+/// A file whose references change, with its contents before and after
+pub(crate) struct PlannedRewrite {
+    path: PathBuf,
+    original: String,
+    updated: String,
+    links: Vec<RewrittenLink>,
+}
+
+fn is_content_file(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|e| e.to_str()),
+        Some("md") | Some("mdx")
+    )
+}
+
+/// Resolves `.` and `..` without touching the filesystem
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// The path part of a relative reference, or `None` for URLs, anchors, site-absolute
+/// paths and aliases
+fn relative_path(target: &str) -> Option<&str> {
+    let path = target.split(['?', '#']).next().unwrap_or_default();
+    let is_relative =
+        !path.is_empty() && !path.contains(':') && !path.starts_with(['/', '@', '~', '{']);
+    is_relative.then_some(path)
+}
+
+/// `target` as a reference from `dir`, in the style of the `original` reference
+fn reference_from(dir: &Path, target: &Path, original: &str) -> Option<String> {
+    let relative = diff_paths(target, dir)?
+        .to_string_lossy()
+        .replace('\\', "/");
+    // Keep bare names bare; anything written with `./` or `../` keeps a prefix
+    if relative.starts_with("../") || !original.starts_with('.') {
+        Some(relative)
+    } else {
+        Some(format!("./{relative}"))
+    }
+}
+
+/// Byte ranges of reference targets: Markdown links, images and reference definitions,
+/// `src`/`href` attributes and MDX imports in the body (outside code fences), and
+/// `./` or `../` paths in frontmatter values
+fn reference_ranges(content: &str) -> Vec<(usize, usize)> {
+    let body_res = [
+        Regex::new(r"\]\(\s*<?([^)\s>]+)").unwrap(),
+        Regex::new(r"^ {0,3}\[[^\]]+\]:\s*<?([^\s>]+)").unwrap(),
+        Regex::new(r#"\b(?:src|href)\s*=\s*\{?\s*["']([^"']+)["']"#).unwrap(),
+        Regex::new(r#"^\s*import\s+[\w{}\s,*]+\s+from\s+["']([^"']+)["']"#).unwrap(),
+    ];
+    let frontmatter_re =
+        Regex::new(r#"^\s*(?:-\s+)?(?:[\w-]+:\s*)?["']?(\.\.?/[^"'\s]+)"#).unwrap();
+
+    let mut ranges = Vec::new();
+    let mut in_frontmatter = content.starts_with("---\n") || content.starts_with("---\r\n");
+    let mut fence: Option<&str> = None;
+    let mut offset = 0;
+
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        let start = offset;
+        offset += line.len();
+        let text = line.trim_end_matches(['\n', '\r']);
+
+        if in_frontmatter {
+            if index > 0 && text == "---" {
+                in_frontmatter = false;
+            } else if let Some(caps) = frontmatter_re.captures(text) {
+                let m = caps.get(1).unwrap();
+                ranges.push((start + m.start(), start + m.end()));
+            }
+            continue;
+        }
+
+        let trimmed = text.trim_start();
+        let marker = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m));
+        match (fence, marker) {
+            (None, Some(m)) => fence = Some(m),
+            (Some(open), Some(m)) if open == m => fence = None,
+            _ => {}
+        }
+        if fence.is_some() || marker.is_some() {
+            continue;
+        }
+
+        for re in &body_res {
+            for caps in re.captures_iter(text) {
+                let m = caps.get(1).unwrap();
+                ranges.push((start + m.start(), start + m.end()));
+            }
+        }
+    }
+
+    ranges.sort_unstable();
+    ranges.dedup();
+    ranges
+}
+
+/// Rewrites each reference `rewrite` returns a new path for, keeping query strings
+/// and fragments; returns the new contents and `(line, old, new)` for each change
+fn rewrite_references(
+    content: &str,
+    mut rewrite: impl FnMut(&str) -> Option<String>,
+) -> (String, Vec<(u32, String, String)>) {
+    let mut updated = String::with_capacity(content.len());
+    let mut changes = Vec::new();
+    let mut last = 0;
+
+    for (start, end) in reference_ranges(content) {
+        if start < last {
+            continue;
+        }
+        let target = &content[start..end];
+        let Some(path) = relative_path(target) else {
+            continue;
+        };
+        let Some(new_path) = rewrite(path).filter(|new_path| new_path != path) else {
+            continue;
+        };
+        let new_target = format!("{new_path}{}", &target[path.len()..]);
+
+        let line = content[..start].matches('\n').count() as u32 + 1;
+        updated.push_str(&content[last..start]);
+        updated.push_str(&new_target);
+        last = end;
+        changes.push((line, target.to_string(), new_target));
+    }
+
+    updated.push_str(&content[last..]);
+    (updated, changes)
+}
+
+/// Content files under `project_root`, skipping excluded directories
+fn content_files(project_root: &Path) -> Vec<PathBuf> {
+    WalkDir::new(project_root)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|entry| {
+            !entry.file_type().is_dir()
+                || !entry
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|name| DEFAULT_EXCLUDED_DIRECTORIES.contains(&name))
+        })
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file() && is_content_file(entry.path()))
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
+fn planned(
+    path: &Path,
+    original: String,
+    updated: String,
+    changes: Vec<(u32, String, String)>,
+) -> PlannedRewrite {
+    let file_path = path.to_string_lossy().to_string();
+    PlannedRewrite {
+        path: path.to_path_buf(),
+        original,
+        updated,
+        links: changes
+            .into_iter()
+            .map(|(line, old_target, new_target)| RewrittenLink {
+                file_path: file_path.clone(),
+                line,
+                old_target,
+                new_target,
+            })
+            .collect(),
+    }
+}
+
+/// Finds the reference changes renaming `old_path` to `new_path` needs
+///
+/// Must run before the rename: the renamed file is read from its old path, and its
+/// rewrite is planned against the new one. All paths must be canonical.
+pub(crate) fn plan_link_rewrites(
+    old_path: &Path,
+    new_path: &Path,
+    project_root: &Path,
+) -> Result<Vec<PlannedRewrite>, String> {
+    let (Some(old_dir), Some(new_dir)) = (old_path.parent(), new_path.parent()) else {
+        return Ok(Vec::new());
+    };
+    let mut plan = Vec::new();
+
+    // The renamed file's own references, re-based on its new directory
+    if is_content_file(old_path) {
+        let original = std::fs::read_to_string(old_path)
+            .map_err(|e| format!("Failed to read {}: {e}", old_path.display()))?;
+        let (updated, changes) = rewrite_references(&original, |path| {
+            let target = normalize(&old_dir.join(path));
+            if target == old_path {
+                return reference_from(new_dir, new_path, path);
+            }
+            if !target.exists() {
+                return None;
+            }
+            reference_from(new_dir, &target, path)
+        });
+        if !changes.is_empty() {
+            plan.push(planned(new_path, original, updated, changes));
+        }
+    }
+
+    // References to the renamed file from everywhere else
+    for file in content_files(project_root) {
+        if file == old_path {
+            continue;
+        }
+        let Some(dir) = file.parent() else {
+            continue;
+        };
+        let Ok(original) = std::fs::read_to_string(&file) else {
+            continue;
+        };
+        let (updated, changes) = rewrite_references(&original, |path| {
+            (normalize(&dir.join(path)) == old_path)
+                .then(|| reference_from(dir, new_path, path))
+                .flatten()
+        });
+        if !changes.is_empty() {
+            plan.push(planned(&file, original, updated, changes));
+        }
+    }
+
+    Ok(plan)
+}
+
+/// A renamed entry's ID and slug before and after the rename
+struct EntryRename {
+    collection: String,
+    /// IDs within the collection, e.g. `first-post`
+    old_id: String,
+    new_id: String,
+    old_slug: String,
+    new_slug: String,
+    url_pattern: Option<String>,
+}
+
+impl EntryRename {
+    /// Works out how renaming `old_path` to `new_path` changes the entry, or `None` if
+    /// it isn't in a collection or moves to a different one
+    fn new(
+        old_path: &Path,
+        new_path: &Path,
+        collections: &[(String, PathBuf)],
+        url_patterns: &HashMap<String, String>,
+    ) -> Option<Self> {
+        let (collection, collection_path) = collection_of(old_path, collections)?;
+        if collection_of(new_path, collections)?.0 != collection {
+            return None;
+        }
+        let frontmatter = std::fs::read_to_string(old_path)
+            .ok()
+            .and_then(|content| parse_frontmatter_internal(&content).ok())
+            .map(|parsed| parsed.frontmatter)
+            .unwrap_or_default();
+        let id = |path: &Path| {
+            let id = FileEntry::new(
+                path.to_path_buf(),
+                collection.to_string(),
+                collection_path.to_path_buf(),
+            )
+            .id;
+            id.strip_prefix(&format!("{collection}/"))
+                .unwrap_or(&id)
+                .to_string()
+        };
+
+        Some(Self {
+            collection: collection.to_string(),
+            old_id: id(old_path),
+            new_id: id(new_path),
+            old_slug: entry_slug(old_path, collection_path, &frontmatter),
+            new_slug: entry_slug(new_path, collection_path, &frontmatter),
+            url_pattern: url_patterns.get(collection).cloned(),
+        })
+    }
+
+    /// The new name for a reference to the entry by ID, slug or `collection/id`
+    fn renamed(&self, name: &str) -> Option<String> {
+        let prefixed = |id: &str| format!("{}/{id}", self.collection);
+        let renamed = if name == self.old_id {
+            self.new_id.clone()
+        } else if name == self.old_slug {
+            self.new_slug.clone()
+        } else if name == prefixed(&self.old_id) {
+            prefixed(&self.new_id)
+        } else {
+            return None;
+        };
+        (renamed != name).then_some(renamed)
+    }
+
+    /// `target` with the entry's slug replaced, if it's a site-absolute link to it
+    fn renamed_url(&self, target: &str) -> Option<String> {
+        let path = target.split(['?', '#']).next().unwrap_or_default();
+        let slug = slug_from_url(path, self.url_pattern.as_deref()?)
+            .filter(|slug| *slug == self.old_slug && self.new_slug != self.old_slug)?;
+        // `slug` borrows from `target`, so its offset is where to splice
+        let start = slug.as_ptr() as usize - target.as_ptr() as usize;
+        Some(format!(
+            "{}{}{}",
+            &target[..start],
+            self.new_slug,
+            &target[start + slug.len()..]
+        ))
+    }
+}
+
+/// The innermost collection containing `path`, by name and canonical directory
+fn collection_of<'a>(
+    path: &Path,
+    collections: &'a [(String, PathBuf)],
+) -> Option<(&'a str, &'a Path)> {
+    collections
+        .iter()
+        .filter(|(_, dir)| path.starts_with(dir))
+        .max_by_key(|(_, dir)| dir.as_os_str().len())
+        .map(|(name, dir)| (name.as_str(), dir.as_path()))
+}
+
+/// Byte ranges of names that can refer to an entry: values of `fields` in frontmatter
+/// (plain, listed or as an `id:`) and `[[wiki link]]` targets outside code
+fn entry_name_ranges(content: &str, fields: &[&str]) -> Vec<(usize, usize)> {
+    let key_re = Regex::new(r"^([\w-]+):").unwrap();
+    let item_key_re = Regex::new(r"^\s*(?:-\s+)?([\w-]+):").unwrap();
+    let token_re = Regex::new(r"[\w./-]+").unwrap();
+    let wiki_re = Regex::new(r"\[\[([^\[\]|#]+)(?:#[^\[\]|]*)?(?:\|[^\[\]]*)?\]\]").unwrap();
+
+    let mut ranges = Vec::new();
+    let mut in_frontmatter = content.starts_with("---\n") || content.starts_with("---\r\n");
+    let mut in_field = false;
+    let mut fence: Option<&str> = None;
+    let mut offset = 0;
+
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        let start = offset;
+        offset += line.len();
+        let text = line.trim_end_matches(['\n', '\r']);
+
+        if in_frontmatter {
+            if index == 0 {
+                continue;
+            }
+            if text == "---" {
+                in_frontmatter = false;
+                continue;
+            }
+            // Where this line's value starts, if it belongs to a reference field
+            let value_start = if let Some(caps) = key_re.captures(text) {
+                in_field = fields.contains(&&caps[1]);
+                in_field.then(|| caps.get(0).unwrap().end())
+            } else if !in_field {
+                None
+            } else {
+                match item_key_re.captures(text) {
+                    Some(caps) if &caps[1] == "id" => Some(caps.get(0).unwrap().end()),
+                    Some(_) => None,
+                    None => Some(0),
+                }
+            };
+            if let Some(value_start) = value_start {
+                for m in token_re.find_iter(&text[value_start..]) {
+                    let m_start = start + value_start + m.start();
+                    ranges.push((m_start, m_start + m.len()));
+                }
+            }
+            continue;
+        }
+
+        let trimmed = text.trim_start();
+        let marker = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m));
+        match (fence, marker) {
+            (None, Some(m)) => fence = Some(m),
+            (Some(open), Some(m)) if open == m => fence = None,
+            _ => {}
+        }
+        if fence.is_some() || marker.is_some() {
+            continue;
+        }
+
+        for caps in wiki_re.captures_iter(text) {
+            let m = caps.get(1).unwrap();
+            // Inside inline code when an odd number of backticks come before it
+            if text[..m.start()].matches('`').count() % 2 == 1 {
+                continue;
+            }
+            let name = m.as_str();
+            let m_start = start + m.start() + (name.len() - name.trim_start().len());
+            ranges.push((m_start, m_start + name.trim().len()));
+        }
+    }
+    ranges
+}
+
+/// Rewrites references to a renamed entry by ID or slug; returns the new contents and
+/// `(line, old, new)` for each change
+fn rewrite_entry_references(
+    content: &str,
+    rename: &EntryRename,
+    fields: &[&str],
+) -> (String, Vec<(u32, String, String)>) {
+    let mut edits: Vec<(usize, usize, String)> = reference_ranges(content)
+        .into_iter()
+        .filter_map(|(start, end)| {
+            let renamed = rename.renamed_url(&content[start..end])?;
+            Some((start, end, renamed))
+        })
+        .chain(
+            entry_name_ranges(content, fields)
+                .into_iter()
+                .filter_map(|(start, end)| {
+                    let renamed = rename.renamed(&content[start..end])?;
+                    Some((start, end, renamed))
+                }),
+        )
+        .collect();
+    edits.sort_by_key(|(start, ..)| *start);
+
+    let mut updated = String::with_capacity(content.len());
+    let mut changes = Vec::new();
+    let mut last = 0;
+    for (start, end, new_target) in edits {
+        if start < last {
+            continue;
+        }
+        let line = content[..start].matches('\n').count() as u32 + 1;
+        updated.push_str(&content[last..start]);
+        updated.push_str(&new_target);
+        last = end;
+        changes.push((line, content[start..end].to_string(), new_target));
+    }
+    updated.push_str(&content[last..]);
+    (updated, changes)
+}
+
+/// Adds the changes renaming an entry needs beyond relative paths to `plan`: its new
+/// ID in `reference()` fields, its new slug in links matching the collection's URL
+/// pattern, and both in wiki links
+///
+/// Like `plan_link_rewrites`, must run before the rename, with canonical paths.
+pub(crate) fn plan_entry_rewrites(
+    mut plan: Vec<PlannedRewrite>,
+    old_path: &Path,
+    new_path: &Path,
+    project_root: &Path,
+    collections: &[Collection],
+    url_patterns: &HashMap<String, String>,
+) -> Vec<PlannedRewrite> {
+    let canonical: Vec<(String, PathBuf)> = collections
+        .iter()
+        .filter_map(|c| Some((c.name.clone(), c.path.canonicalize().ok()?)))
+        .collect();
+    let Some(rename) = EntryRename::new(old_path, new_path, &canonical, url_patterns) else {
+        return plan;
+    };
+
+    // Reference fields pointing at the renamed entry's collection, by collection
+    let fields: HashMap<String, Vec<String>> = reference_fields(collections)
+        .into_iter()
+        .map(|(collection, fields)| {
+            let fields = fields
+                .into_iter()
+                .filter(|(_, target)| *target == rename.collection)
+                .map(|(field, _)| field)
+                .collect();
+            (collection, fields)
+        })
+        .collect();
+
+    for file in content_files(project_root) {
+        // The renamed file is read from its old path but written to its new one
+        let target = if file == old_path {
+            new_path
+        } else {
+            file.as_path()
+        };
+        let file_fields: Vec<&str> = collection_of(&file, &canonical)
+            .and_then(|(collection, _)| fields.get(collection))
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+
+        let existing = plan.iter().position(|p| p.path == target);
+        let current = match existing {
+            Some(i) => plan[i].updated.clone(),
+            None => match std::fs::read_to_string(&file) {
+                Ok(content) => content,
+                Err(_) => continue,
+            },
+        };
+        let (updated, changes) = rewrite_entry_references(&current, &rename, &file_fields);
+        if changes.is_empty() {
+            continue;
+        }
+        match existing {
+            Some(i) => {
+                let rewrite = planned(target, String::new(), updated, changes);
+                plan[i].updated = rewrite.updated;
+                plan[i].links.extend(rewrite.links);
+                plan[i].links.sort_by_key(|link| link.line);
+            }
+            None => plan.push(planned(target, current, updated, changes)),
+        }
+    }
+    plan
+}
+
+/// Writes planned rewrites as one undoable operation, returning what changed
+pub(crate) fn apply_link_rewrites(
+    plan: Vec<PlannedRewrite>,
+    origin: &str,
+) -> Result<Vec<RewrittenLink>, String> {
+    let mut bundle = UndoBundle::new("rename-links");
+    let mut rewritten = Vec::new();
+    let outcome = (|| {
+        for rewrite in plan {
+            bundle.record(&rewrite.path, &rewrite.original, &rewrite.updated);
+            dry_run::write(&rewrite.path, &rewrite.updated)
+                .map_err(|e| format!("Failed to write {}: {e}", rewrite.path.display()))?;
+            audit_log::record(AuditAction::Write, &rewrite.path, None, origin);
+            rewritten.extend(rewrite.links);
+        }
+        Ok::<(), String>(())
+    })();
+    // Saved even on failure so files already rewritten can be restored
+    bundle.save();
+    outcome?;
+
+    if !rewritten.is_empty() {
+        log::info!(
+            "Astro Editor [LINK_REFACTOR] Rewrote {} references after rename",
+            rewritten.len()
+        );
+    }
+    Ok(rewritten)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_reference_ranges_skip_code_fences_and_cover_frontmatter() {
+        let content = "---\ntitle: Post\ncover: ./cover.jpg\n---\nimport hero from '../hero.png'\n\n[Other](./other.md#intro) ![Img](img/a.png \"t\")\n<img src=\"./b.png\" />\n\n```md\n[Code](./code.md)\n```\n[ref]: ../ref.md\n";
+        let targets: Vec<&str> = reference_ranges(content)
+            .into_iter()
+            .map(|(start, end)| &content[start..end])
+            .collect();
+
+        assert_eq!(
+            targets,
+            vec![
+                "./cover.jpg",
+                "../hero.png",
+                "./other.md#intro",
+                "img/a.png",
+                "./b.png",
+                "../ref.md"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_relative_path_skips_urls_and_absolute_paths() {
+        assert_eq!(relative_path("./a.md?x=1#top"), Some("./a.md"));
+        assert_eq!(relative_path("a.png"), Some("a.png"));
+        for target in [
+            "https://example.com/a.md",
+            "/images/a.png",
+            "#top",
+            "@assets/a.png",
+        ] {
+            assert_eq!(relative_path(target), None);
+        }
+    }
+
+    #[test]
+    fn test_plan_rewrites_both_directions() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        let blog = root.join("src/content/blog");
+        fs::create_dir_all(blog.join("2024")).unwrap();
+        fs::create_dir_all(root.join("src/assets")).unwrap();
+        fs::write(root.join("src/assets/hero.png"), "png").unwrap();
+        fs::write(blog.join("photo.jpg"), "jpg").unwrap();
+
+        let old_path = blog.join("post.md");
+        fs::write(
+            &old_path,
+            "---\ncover: ./photo.jpg\n---\n![Hero](../../assets/hero.png)\n[Route](../other-post/)\n",
+        )
+        .unwrap();
+        fs::write(
+            blog.join("linker.md"),
+            "See [post](./post.md#intro) and [again](post.md).\n",
+        )
+        .unwrap();
+        fs::write(blog.join("2024/nested.md"), "[Up](../post.md)\n").unwrap();
+
+        let new_path = blog.join("2024/renamed.md");
+        let plan = plan_link_rewrites(&old_path, &new_path, &root).unwrap();
+
+        let moved = plan.iter().find(|p| p.path == new_path).unwrap();
+        assert_eq!(
+            moved.updated,
+            "---\ncover: ../photo.jpg\n---\n![Hero](../../../assets/hero.png)\n[Route](../other-post/)\n"
+        );
+        assert_eq!(moved.links[0].line, 2);
+
+        let linker = plan
+            .iter()
+            .find(|p| p.path == blog.join("linker.md"))
+            .unwrap();
+        assert_eq!(
+            linker.updated,
+            "See [post](./2024/renamed.md#intro) and [again](2024/renamed.md).\n"
+        );
+
+        let nested = plan
+            .iter()
+            .find(|p| p.path == blog.join("2024/nested.md"))
+            .unwrap();
+        assert_eq!(nested.updated, "[Up](./renamed.md)\n");
+        assert_eq!(plan.len(), 3);
+    }
+
+    #[test]
+    fn test_apply_writes_rewrites() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        let old_path = root.join("a.md");
+        let new_path = root.join("b.md");
+        fs::write(&old_path, "Self").unwrap();
+        fs::write(root.join("c.md"), "[A](./a.md)").unwrap();
+
+        let plan = plan_link_rewrites(&old_path, &new_path, &root).unwrap();
+        let links = apply_link_rewrites(plan, "test").unwrap();
+
+        assert_eq!(
+            fs::read_to_string(root.join("c.md")).unwrap(),
+            "[A](./b.md)"
+        );
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].old_target, "./a.md");
+        assert_eq!(links[0].new_target, "./b.md");
+    }
+
+    #[test]
+    fn test_plan_entry_rewrites_follow_id_and_slug() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        let blog = root.join("blog");
+        let notes = root.join("notes");
+        fs::create_dir_all(&blog).unwrap();
+        fs::create_dir_all(&notes).unwrap();
+
+        let old_path = blog.join("old-post.md");
+        fs::write(
+            &old_path,
+            "---\ntitle: Old\n---\n\nSee [other](./other.md).\n",
+        )
+        .unwrap();
+        fs::write(
+            blog.join("other.md"),
+            "[Old](/writing/old-post/#intro) [[old-post|Old]] `[[old-post]]` [[blog/old-post#h]]\n\n```\n[[old-post]]\n```\n",
+        )
+        .unwrap();
+        fs::write(
+            notes.join("note.md"),
+            "---\ntitle: old-post\nrelated: old-post\nposts:\n  - old-post\n  - other\n---\n",
+        )
+        .unwrap();
+
+        let schema = r#"{"collectionName":"notes","fields":[{"name":"related","label":"Related","fieldType":"reference","required":false,"referenceCollection":"blog"},{"name":"posts","label":"Posts","fieldType":"array","required":false,"arrayReferenceCollection":"blog"}]}"#;
+        let collections = vec![
+            Collection::new("blog".to_string(), blog.clone()),
+            Collection::new("notes".to_string(), notes.clone())
+                .with_complete_schema(schema.to_string()),
+        ];
+        let patterns = HashMap::from([("blog".to_string(), "/writing/{slug}".to_string())]);
+
+        let new_path = blog.join("new-post.md");
+        let plan = plan_link_rewrites(&old_path, &new_path, &root).unwrap();
+        let plan = plan_entry_rewrites(plan, &old_path, &new_path, &root, &collections, &patterns);
+
+        let other = plan
+            .iter()
+            .find(|p| p.path == blog.join("other.md"))
+            .unwrap();
+        assert_eq!(
+            other.updated,
+            "[Old](/writing/new-post/#intro) [[new-post|Old]] `[[old-post]]` [[blog/new-post#h]]\n\n```\n[[old-post]]\n```\n"
+        );
+        let note = plan
+            .iter()
+            .find(|p| p.path == notes.join("note.md"))
+            .unwrap();
+        assert_eq!(
+            note.updated,
+            "---\ntitle: old-post\nrelated: new-post\nposts:\n  - new-post\n  - other\n---\n"
+        );
+        assert_eq!(note.links.len(), 2);
+        assert_eq!(plan.len(), 2);
+
+        let report = LinkRewriteReport::from(apply_link_rewrites(plan, "test").unwrap());
+        assert_eq!(report.files.len(), 2);
+        assert_eq!(report.links.len(), 5);
+    }
+}
@@ -0,0 +1,411 @@
+//! Streamed collection listings
+//!
+//! A large collection's listing can run to tens of megabytes as a single response.
+//! `stream_collection_files` instead emits it as `listing-batch` events of at most
+//! `batch_size` entries, then a `listing-complete` event with the total.
+//!
+//! The frontend acknowledges each batch with `ack_listing_batch`; no more than
+//! `MAX_BATCHES_IN_FLIGHT` go unacknowledged, so a slow webview isn't flooded.
+//! `cancel_listing_stream` stops a stream between files or batches, e.g. when the
+//! user switches to another collection.
+
+use crate::commands::project::{sort_file_entries, with_listing_fields, ListingFields};
+use crate::data_store::StoredEntries;
+use crate::models::FileEntry;
+use crate::utils::collation::Collation;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::Notify;
+
+const BATCH_EVENT: &str = "listing-batch";
+const COMPLETE_EVENT: &str = "listing-complete";
+
+const DEFAULT_BATCH_SIZE: u32 = 250;
+
+/// Batches sent ahead of the frontend's acknowledgements
+const MAX_BATCHES_IN_FLIGHT: u32 = 4;
+
+/// A stream waiting this long for an acknowledgement is abandoned (the webview
+/// reloaded, or the listener went away without cancelling)
+const ACK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Payload of `listing-batch`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ListingBatch {
+    pub stream_id: String,
+    /// Position of this batch in the stream, from 0
+    pub index: u32,
+    pub files: Vec<FileEntry>,
+}
+
+/// Payload of `listing-complete`, also returned by `stream_collection_files`
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ListingComplete {
+    pub stream_id: String,
+    /// Entries sent across all batches
+    pub total: u32,
+    /// The stream was cancelled, or abandoned, before every entry was sent
+    pub cancelled: bool,
+}
+
+#[derive(Default)]
+struct StreamControl {
+    cancelled: AtomicBool,
+    acked: AtomicU32,
+    changed: Notify,
+}
+
+impl StreamControl {
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Waits until fewer than `MAX_BATCHES_IN_FLIGHT` of the `sent` batches are
+    /// unacknowledged. False if the stream was cancelled or abandoned meanwhile.
+    async fn wait_for_credit(&self, sent: u32) -> bool {
+        loop {
+            // Created before checking so an ack in between isn't missed
+            let changed = self.changed.notified();
+            if self.is_cancelled() {
+                return false;
+            }
+            let unacknowledged = sent.saturating_sub(self.acked.load(Ordering::SeqCst));
+            if unacknowledged < MAX_BATCHES_IN_FLIGHT {
+                return true;
+            }
+            if tokio::time::timeout(ACK_TIMEOUT, changed).await.is_err() {
+                return false;
+            }
+        }
+    }
+}
+
+type Streams = HashMap<String, Arc<StreamControl>>;
+
+static STREAMS: OnceLock<Mutex<Streams>> = OnceLock::new();
+
+fn streams() -> &'static Mutex<Streams> {
+    STREAMS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn control_for(stream_id: &str) -> Option<Arc<StreamControl>> {
+    streams().lock().unwrap().get(stream_id).cloned()
+}
+
+/// Markdown files in a collection, skipping hidden and `_` entries and symlinks
+/// when recursing, as `scan_collection_files_recursive` does
+fn markdown_paths(dir: &Path, recursive: bool, paths: &mut Vec<PathBuf>) -> Result<(), String> {
+    for entry in std::fs::read_dir(dir).map_err(|e| format!("Failed to read directory: {e}"))? {
+        let entry = entry.map_err(|e| format!("Failed to read directory entry: {e}"))?;
+        let path = entry.path();
+
+        if recursive {
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy();
+            if file_name.starts_with('.') || file_name.starts_with('_') {
+                continue;
+            }
+            if entry.file_type().is_ok_and(|t| t.is_symlink()) {
+                continue;
+            }
+            if path.is_dir() {
+                markdown_paths(&path, recursive, paths)?;
+                continue;
+            }
+        }
+
+        let is_markdown = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| matches!(ext, "md" | "mdx"));
+        if is_markdown && path.is_file() {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Reads a collection's entries, in listing order. `None` if cancelled part way.
+fn collect_entries(
+    collection_root: &Path,
+    collection_name: &str,
+    recursive: bool,
+    collation: Collation,
+    fields: ListingFields,
+    title_field: Option<&str>,
+    control: &StreamControl,
+) -> Result<Option<Vec<FileEntry>>, String> {
+    let mut paths = Vec::new();
+    if collection_root.is_dir() {
+        markdown_paths(collection_root, recursive, &mut paths)?;
+    }
+
+    let stored = StoredEntries::load(collection_root, collection_name);
+    let mut files = Vec::with_capacity(paths.len());
+    for path in paths {
+        if control.is_cancelled() {
+            return Ok(None);
+        }
+        let file_entry = FileEntry::new(
+            path,
+            collection_name.to_string(),
+            collection_root.to_path_buf(),
+        );
+        files.push(with_listing_fields(file_entry, stored.as_ref(), fields));
+    }
+
+    sort_file_entries(&mut files, title_field, collation);
+    Ok(Some(files))
+}
+
+/// Sends `files` in batches, waiting for acknowledgements as needed
+async fn send_batches(
+    stream_id: &str,
+    files: Vec<FileEntry>,
+    batch_size: usize,
+    control: &StreamControl,
+    mut send: impl FnMut(ListingBatch) -> Result<(), String>,
+) -> Result<ListingComplete, String> {
+    let mut total = 0;
+    let mut cancelled = false;
+
+    for (index, batch) in (0..).zip(files.chunks(batch_size.max(1))) {
+        if !control.wait_for_credit(index).await {
+            cancelled = true;
+            break;
+        }
+        send(ListingBatch {
+            stream_id: stream_id.to_string(),
+            index,
+            files: batch.to_vec(),
+        })?;
+        total += batch.len() as u32;
+    }
+
+    Ok(ListingComplete {
+        stream_id: stream_id.to_string(),
+        total,
+        cancelled,
+    })
+}
+
+/// Lists a collection's markdown files as a stream of `listing-batch` events
+///
+/// Entries arrive in the same order, and with the same fields, as from
+/// `scan_collection_files` (or `scan_collection_files_recursive` when `recursive`).
+/// Each batch must be acknowledged with `ack_listing_batch`. Ends with a
+/// `listing-complete` event carrying what's also returned here.
+///
+/// # Arguments
+/// * `stream_id` - Chosen by the caller, to tell its events apart and to cancel
+/// * `batch_size` - Entries per batch, 250 when omitted
+#[tauri::command]
+#[specta::specta]
+#[allow(clippy::too_many_arguments)]
+pub async fn stream_collection_files(
+    app: AppHandle,
+    stream_id: String,
+    collection_path: String,
+    collection_name: String,
+    recursive: bool,
+    collation: Option<Collation>,
+    title_field: Option<String>,
+    draft_field: Option<String>,
+    batch_size: Option<u32>,
+) -> Result<ListingComplete, String> {
+    let control = Arc::new(StreamControl::default());
+    streams()
+        .lock()
+        .unwrap()
+        .insert(stream_id.clone(), control.clone());
+
+    let result: Result<ListingComplete, String> = async {
+        let Some(files) = collect_entries(
+            Path::new(&collection_path),
+            &collection_name,
+            recursive,
+            collation.unwrap_or_default(),
+            ListingFields::new(title_field.as_deref(), draft_field.as_deref()),
+            title_field.as_deref(),
+            &control,
+        )?
+        else {
+            return Ok(ListingComplete {
+                stream_id: stream_id.clone(),
+                total: 0,
+                cancelled: true,
+            });
+        };
+
+        let batch_size = batch_size.unwrap_or(DEFAULT_BATCH_SIZE) as usize;
+        send_batches(&stream_id, files, batch_size, &control, |batch| {
+            app.emit(BATCH_EVENT, batch)
+                .map_err(|e| format!("Failed to send files: {e}"))
+        })
+        .await
+    }
+    .await;
+
+    streams().lock().unwrap().remove(&stream_id);
+
+    if let Ok(complete) = &result {
+        if let Err(e) = app.emit(COMPLETE_EVENT, complete) {
+            log::warn!("Astro Editor [LISTING] Failed to emit listing completion: {e}");
+        }
+    }
+    result
+}
+
+/// Acknowledges a `listing-batch`, letting the stream send another
+#[tauri::command]
+#[specta::specta]
+pub async fn ack_listing_batch(stream_id: String) -> Result<(), String> {
+    if let Some(control) = control_for(&stream_id) {
+        control.acked.fetch_add(1, Ordering::SeqCst);
+        control.changed.notify_waiters();
+    }
+    Ok(())
+}
+
+/// Stops a listing stream; a stream that's already finished is left alone
+#[tauri::command]
+#[specta::specta]
+pub async fn cancel_listing_stream(stream_id: String) -> Result<(), String> {
+    if let Some(control) = control_for(&stream_id) {
+        control.cancelled.store(true, Ordering::SeqCst);
+        control.changed.notify_waiters();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn entries(count: usize) -> Vec<FileEntry> {
+        (0..count)
+            .map(|i| {
+                FileEntry::new(
+                    PathBuf::from(format!("/blog/post-{i}.md")),
+                    "blog".to_string(),
+                    PathBuf::from("/blog"),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_collect_entries_recursive_and_flat() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("2024")).unwrap();
+        fs::create_dir_all(temp.path().join("_drafts")).unwrap();
+        fs::write(temp.path().join("b.md"), "---\ntitle: Beta\n---\n").unwrap();
+        fs::write(temp.path().join("2024/a.mdx"), "---\ntitle: Alpha\n---\n").unwrap();
+        fs::write(temp.path().join("_drafts/c.md"), "---\ntitle: Gamma\n---\n").unwrap();
+        fs::write(temp.path().join("notes.txt"), "not markdown").unwrap();
+
+        let control = StreamControl::default();
+        let collect = |recursive| {
+            collect_entries(
+                temp.path(),
+                "blog",
+                recursive,
+                Collation::default(),
+                ListingFields::new(None, None),
+                None,
+                &control,
+            )
+            .unwrap()
+            .unwrap()
+        };
+
+        let titles = |files: Vec<FileEntry>| -> Vec<String> {
+            files.into_iter().filter_map(|file| file.title).collect()
+        };
+        assert_eq!(titles(collect(true)), vec!["Alpha", "Beta"]);
+        assert_eq!(titles(collect(false)), vec!["Beta"]);
+
+        control.cancelled.store(true, Ordering::SeqCst);
+        assert!(matches!(
+            collect_entries(
+                temp.path(),
+                "blog",
+                true,
+                Collation::default(),
+                ListingFields::new(None, None),
+                None,
+                &control,
+            ),
+            Ok(None)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_batches_waits_for_acks() {
+        let control = Arc::new(StreamControl::default());
+        let sent = Arc::new(Mutex::new(Vec::new()));
+
+        let stream = {
+            let (control, sent) = (control.clone(), sent.clone());
+            tokio::spawn(async move {
+                send_batches("s", entries(25), 2, &control, |batch| {
+                    sent.lock().unwrap().push(batch.files.len());
+                    Ok(())
+                })
+                .await
+            })
+        };
+
+        // Stalls once MAX_BATCHES_IN_FLIGHT batches are unacknowledged
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(sent.lock().unwrap().len(), MAX_BATCHES_IN_FLIGHT as usize);
+
+        // Each acknowledgement lets one more through
+        control.acked.fetch_add(1, Ordering::SeqCst);
+        control.changed.notify_waiters();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(
+            sent.lock().unwrap().len(),
+            MAX_BATCHES_IN_FLIGHT as usize + 1
+        );
+
+        control.cancelled.store(true, Ordering::SeqCst);
+        control.changed.notify_waiters();
+        let complete = stream.await.unwrap().unwrap();
+        assert!(complete.cancelled);
+        assert_eq!(complete.total, 10);
+    }
+
+    #[tokio::test]
+    async fn test_send_batches_completes_with_total() {
+        let control = StreamControl::default();
+        let mut sizes = Vec::new();
+
+        let complete = send_batches("s", entries(5), 2, &control, |batch| {
+            sizes.push(batch.files.len());
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(sizes, vec![2, 2, 1]);
+        assert_eq!(
+            complete,
+            ListingComplete {
+                stream_id: "s".to_string(),
+                total: 5,
+                cancelled: false,
+            }
+        );
+    }
+}
@@ -0,0 +1,299 @@
+//! Markdown extensions a project's pipeline accepts
+//!
+//! Astro enables GFM (tables, footnotes, task lists) and SmartyPants by default, and
+//! MDX only with the `@astrojs/mdx` integration; `markdown.gfm` and
+//! `markdown.smartypants` in `astro.config` turn the defaults off. The flavor detected
+//! from the project, adjusted by the project's `markdownFlavor` setting, decides what
+//! table conversion and print rendering produce and what `check_markdown_flavor`
+//! reports, so the editor agrees with what the site will actually render.
+
+use crate::security::validate_project_path;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::Path;
+
+/// Astro config files, in the order Astro looks for them
+pub(crate) const ASTRO_CONFIG_FILES: [&str; 5] = [
+    "astro.config.mjs",
+    "astro.config.js",
+    "astro.config.ts",
+    "astro.config.mts",
+    "astro.config.cjs",
+];
+
+/// Markdown extensions enabled for a project
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkdownFlavor {
+    pub gfm_tables: bool,
+    pub footnotes: bool,
+    pub task_lists: bool,
+    /// Curly quotes, dashes and ellipses from straight punctuation
+    pub smart_punctuation: bool,
+    pub mdx: bool,
+}
+
+impl Default for MarkdownFlavor {
+    fn default() -> Self {
+        Self {
+            gfm_tables: true,
+            footnotes: true,
+            task_lists: true,
+            smart_punctuation: true,
+            mdx: true,
+        }
+    }
+}
+
+/// The project's `markdownFlavor` setting; unset extensions follow the Astro config
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkdownFlavorSettings {
+    #[serde(default)]
+    #[specta(optional)]
+    pub gfm_tables: Option<bool>,
+    #[serde(default)]
+    #[specta(optional)]
+    pub footnotes: Option<bool>,
+    #[serde(default)]
+    #[specta(optional)]
+    pub task_lists: Option<bool>,
+    #[serde(default)]
+    #[specta(optional)]
+    pub smart_punctuation: Option<bool>,
+    #[serde(default)]
+    #[specta(optional)]
+    pub mdx: Option<bool>,
+}
+
+impl MarkdownFlavor {
+    fn with_settings(self, settings: &MarkdownFlavorSettings) -> Self {
+        Self {
+            gfm_tables: settings.gfm_tables.unwrap_or(self.gfm_tables),
+            footnotes: settings.footnotes.unwrap_or(self.footnotes),
+            task_lists: settings.task_lists.unwrap_or(self.task_lists),
+            smart_punctuation: settings.smart_punctuation.unwrap_or(self.smart_punctuation),
+            mdx: settings.mdx.unwrap_or(self.mdx),
+        }
+    }
+}
+
+/// Syntax in a document that the project's flavor won't render
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FlavorIssue {
+    /// The disabled extension, e.g. `gfmTables`
+    pub feature: String,
+    /// 1-based line in the file
+    pub line: u32,
+    pub message: String,
+}
+
+/// The flavor Astro will use for a project, from its config and dependencies
+fn detect_markdown_flavor(project_root: &Path) -> MarkdownFlavor {
+    let config = ASTRO_CONFIG_FILES
+        .iter()
+        .find_map(|name| std::fs::read_to_string(project_root.join(name)).ok())
+        .unwrap_or_default();
+    let package = std::fs::read_to_string(project_root.join("package.json")).unwrap_or_default();
+
+    let disabled = |option: &str| {
+        Regex::new(&format!(r"\b{option}\s*:\s*false\b"))
+            .map(|re| re.is_match(&config))
+            .unwrap_or(false)
+    };
+    let gfm = !disabled("gfm");
+
+    MarkdownFlavor {
+        gfm_tables: gfm,
+        footnotes: gfm,
+        task_lists: gfm,
+        smart_punctuation: !disabled("smartypants"),
+        mdx: config.contains("@astrojs/mdx") || package.contains("\"@astrojs/mdx\""),
+    }
+}
+
+fn issue(feature: &str, line: usize, message: &str) -> FlavorIssue {
+    FlavorIssue {
+        feature: feature.to_string(),
+        line: line as u32 + 1,
+        message: message.to_string(),
+    }
+}
+
+/// Tables, footnotes and task lists in `content` that `flavor` doesn't enable, and MDX
+/// files when MDX isn't set up. Frontmatter and fenced code are skipped.
+fn unsupported_syntax(
+    content: &str,
+    is_mdx_file: bool,
+    flavor: &MarkdownFlavor,
+) -> Vec<FlavorIssue> {
+    let table_delimiter_re = Regex::new(r"^\s*\|?\s*:?-+:?\s*(\|\s*:?-+:?\s*)+\|?\s*$").unwrap();
+    let footnote_re = Regex::new(r"\[\^[^\]\s]+\]").unwrap();
+    let task_re = Regex::new(r"^\s*(?:[-*+]|\d+[.)])\s+\[[ xX]\]\s").unwrap();
+
+    let mut issues = Vec::new();
+    if is_mdx_file && !flavor.mdx {
+        issues.push(issue(
+            "mdx",
+            0,
+            "MDX isn't enabled for this project; add the @astrojs/mdx integration",
+        ));
+    }
+
+    let lines: Vec<&str> = content.lines().collect();
+    let mut start = 0;
+    if lines.first().map(|l| l.trim_end()) == Some("---") {
+        if let Some(end) = lines.iter().skip(1).position(|l| l.trim_end() == "---") {
+            start = end + 2;
+        }
+    }
+
+    let mut fence: Option<&str> = None;
+    for (index, line) in lines.iter().enumerate().skip(start) {
+        let trimmed = line.trim_start();
+        let marker = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m));
+        match (fence, marker) {
+            (None, Some(m)) => fence = Some(m),
+            (Some(open), Some(m)) if open == m => fence = None,
+            _ => {}
+        }
+        if fence.is_some() || marker.is_some() {
+            continue;
+        }
+
+        if !flavor.gfm_tables && line.contains('|') && table_delimiter_re.is_match(line) {
+            // Reported on the header row, where the table starts
+            issues.push(issue(
+                "gfmTables",
+                index.saturating_sub(1),
+                "Tables are disabled for this project and will render as plain text",
+            ));
+        }
+        if !flavor.footnotes && footnote_re.is_match(line) {
+            issues.push(issue(
+                "footnotes",
+                index,
+                "Footnotes are disabled for this project and will render as plain text",
+            ));
+        }
+        if !flavor.task_lists && task_re.is_match(line) {
+            issues.push(issue(
+                "taskLists",
+                index,
+                "Task lists are disabled for this project; checkboxes will render as text",
+            ));
+        }
+    }
+    issues
+}
+
+/// Returns the Markdown extensions enabled for a project
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `settings` - The project's `markdownFlavor` setting, overriding the Astro config
+#[tauri::command]
+#[specta::specta]
+pub async fn get_markdown_flavor(
+    project_path: String,
+    settings: Option<MarkdownFlavorSettings>,
+) -> Result<MarkdownFlavor, String> {
+    Ok(detect_markdown_flavor(Path::new(&project_path))
+        .with_settings(&settings.unwrap_or_default()))
+}
+
+/// Reports syntax in a document that the project's Markdown flavor won't render
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `file_path` - The absolute path to the document
+/// * `content` - The document's current content; frontmatter, if included, is skipped
+/// * `settings` - The project's `markdownFlavor` setting, overriding the Astro config
+#[tauri::command]
+#[specta::specta]
+pub async fn check_markdown_flavor(
+    project_path: String,
+    file_path: String,
+    content: String,
+    settings: Option<MarkdownFlavorSettings>,
+) -> Result<Vec<FlavorIssue>, String> {
+    let path = validate_project_path(&file_path, &project_path)?;
+    let flavor = detect_markdown_flavor(Path::new(&project_path))
+        .with_settings(&settings.unwrap_or_default());
+    let is_mdx_file = path.extension().and_then(|e| e.to_str()) == Some("mdx");
+    Ok(unsupported_syntax(&content, is_mdx_file, &flavor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_detect_markdown_flavor() {
+        let temp = TempDir::new().unwrap();
+        assert_eq!(
+            detect_markdown_flavor(temp.path()),
+            MarkdownFlavor {
+                mdx: false,
+                ..MarkdownFlavor::default()
+            }
+        );
+
+        fs::write(
+            temp.path().join("astro.config.mjs"),
+            "import mdx from '@astrojs/mdx';\nexport default defineConfig({\n  integrations: [mdx()],\n  markdown: { gfm: false, smartypants: false },\n});\n",
+        )
+        .unwrap();
+        assert_eq!(
+            detect_markdown_flavor(temp.path()),
+            MarkdownFlavor {
+                gfm_tables: false,
+                footnotes: false,
+                task_lists: false,
+                smart_punctuation: false,
+                mdx: true,
+            }
+        );
+
+        let settings = MarkdownFlavorSettings {
+            footnotes: Some(true),
+            ..Default::default()
+        };
+        let flavor = detect_markdown_flavor(temp.path()).with_settings(&settings);
+        assert!(flavor.footnotes && !flavor.gfm_tables);
+    }
+
+    #[test]
+    fn test_unsupported_syntax() {
+        let content = "---\ntitle: Post\n---\n\n| a | b |\n| --- | ---: |\n| 1 | 2 |\n\nText[^1]\n\n- [ ] todo\n- [x] done\n\n```md\n| x | y |\n|---|---|\n- [ ] sample\n```\n\n[^1]: Note\n";
+        let flavor = MarkdownFlavor {
+            gfm_tables: false,
+            footnotes: false,
+            task_lists: false,
+            smart_punctuation: true,
+            mdx: false,
+        };
+        let issues = unsupported_syntax(content, true, &flavor);
+        let found: Vec<(&str, u32)> = issues
+            .iter()
+            .map(|i| (i.feature.as_str(), i.line))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("mdx", 1),
+                ("gfmTables", 5),
+                ("footnotes", 9),
+                ("taskLists", 11),
+                ("taskLists", 12),
+                ("footnotes", 20),
+            ]
+        );
+
+        assert!(unsupported_syntax(content, true, &MarkdownFlavor::default()).is_empty());
+    }
+}
@@ -0,0 +1,457 @@
+//! Math/LaTeX validation
+//!
+//! Finds `$...$` and `$$...$$` regions in Markdown (skipping code) and reports the
+//! problems that make KaTeX fail at build time: unclosed display blocks, unbalanced
+//! braces, `\left`/`\right` mismatches and unknown or mismatched environments. This is
+//! a structural check, not a full LaTeX parser, so unknown commands aren't reported.
+//!
+//! Offsets are UTF-16 code units into the content, matching editor positions.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Environments KaTeX supports
+const KATEX_ENVIRONMENTS: &[&str] = &[
+    "matrix",
+    "matrix*",
+    "pmatrix",
+    "pmatrix*",
+    "bmatrix",
+    "bmatrix*",
+    "Bmatrix",
+    "Bmatrix*",
+    "vmatrix",
+    "vmatrix*",
+    "Vmatrix",
+    "Vmatrix*",
+    "smallmatrix",
+    "subarray",
+    "array",
+    "darray",
+    "cases",
+    "dcases",
+    "rcases",
+    "drcases",
+    "aligned",
+    "alignedat",
+    "gathered",
+    "split",
+    "align",
+    "align*",
+    "alignat",
+    "alignat*",
+    "gather",
+    "gather*",
+    "equation",
+    "equation*",
+    "multline",
+    "multline*",
+    "CD",
+];
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum MathDiagnosticSeverity {
+    Error,
+    Warning,
+}
+
+/// A problem found in a math region
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MathDiagnostic {
+    /// Start offset in UTF-16 code units
+    pub start: u32,
+    /// End offset (exclusive) in UTF-16 code units
+    pub end: u32,
+    /// 1-based line of `start`
+    pub line: u32,
+    pub severity: MathDiagnosticSeverity,
+    pub message: String,
+}
+
+/// A diagnostic with byte offsets, converted to editor offsets at the end
+struct Finding {
+    start: usize,
+    end: usize,
+    severity: MathDiagnosticSeverity,
+    message: String,
+}
+
+impl Finding {
+    fn error(start: usize, end: usize, message: impl Into<String>) -> Self {
+        Self {
+            start,
+            end,
+            severity: MathDiagnosticSeverity::Error,
+            message: message.into(),
+        }
+    }
+}
+
+/// A math region; `body` is the byte range between the delimiters
+#[derive(Debug, PartialEq)]
+struct MathRegion {
+    start: usize,
+    end: usize,
+    body_start: usize,
+    body_end: usize,
+}
+
+fn is_escaped(bytes: &[u8], index: usize) -> bool {
+    bytes[..index]
+        .iter()
+        .rev()
+        .take_while(|&&b| b == b'\\')
+        .count()
+        % 2
+        == 1
+}
+
+/// Finds the closing `$` of inline math opened at `open` on the same line
+///
+/// Follows the pandoc rules so currency isn't mistaken for math: the opener must be
+/// followed by non-whitespace, the closer preceded by non-whitespace and not followed
+/// by a digit.
+pub(crate) fn find_inline_close(bytes: &[u8], open: usize, line_end: usize) -> Option<usize> {
+    let first = *bytes.get(open + 1)?;
+    if open + 1 >= line_end || first.is_ascii_whitespace() || first == b'$' {
+        return None;
+    }
+
+    (open + 2..line_end).find(|&i| {
+        bytes[i] == b'$'
+            && !is_escaped(bytes, i)
+            && !bytes[i - 1].is_ascii_whitespace()
+            && !bytes
+                .get(i + 1)
+                .is_some_and(|b| b.is_ascii_digit() || *b == b'$')
+    })
+}
+
+/// Locates math regions, reporting display blocks that are never closed
+fn find_math_regions(content: &str) -> (Vec<MathRegion>, Vec<Finding>) {
+    let bytes = content.as_bytes();
+    let mut regions = Vec::new();
+    let mut findings = Vec::new();
+    let mut fence: Option<&str> = None;
+    let mut open_display: Option<usize> = None;
+    let mut line_start = 0;
+
+    for line in content.split_inclusive('\n') {
+        let line_end = line_start + line.trim_end_matches(['\n', '\r']).len();
+        let trimmed = line.trim_start();
+
+        // Fenced code blocks are never math
+        if open_display.is_none() {
+            if let Some(marker) = fence {
+                if trimmed.starts_with(marker) {
+                    fence = None;
+                }
+                line_start += line.len();
+                continue;
+            }
+            if let Some(marker) = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m)) {
+                fence = Some(marker);
+                line_start += line.len();
+                continue;
+            }
+        }
+
+        let mut i = line_start;
+        while i < line_end {
+            match bytes[i] {
+                b'\\' => i += 2,
+                b'$' if bytes.get(i + 1) == Some(&b'$') => {
+                    match open_display.take() {
+                        Some(start) => regions.push(MathRegion {
+                            start,
+                            end: i + 2,
+                            body_start: start + 2,
+                            body_end: i,
+                        }),
+                        None => open_display = Some(i),
+                    }
+                    i += 2;
+                }
+                _ if open_display.is_some() => i += 1,
+                b'`' => {
+                    // Skip inline code spans with a matching backtick run
+                    let run = bytes[i..line_end]
+                        .iter()
+                        .take_while(|&&b| b == b'`')
+                        .count();
+                    let marker = &content[i..i + run];
+                    i = content[i + run..line_end]
+                        .find(marker)
+                        .map(|offset| i + run + offset + run)
+                        .unwrap_or(i + run);
+                }
+                b'$' => match find_inline_close(bytes, i, line_end) {
+                    Some(close) => {
+                        regions.push(MathRegion {
+                            start: i,
+                            end: close + 1,
+                            body_start: i + 1,
+                            body_end: close,
+                        });
+                        i = close + 1;
+                    }
+                    None => i += 1,
+                },
+                _ => i += 1,
+            }
+        }
+
+        line_start += line.len();
+    }
+
+    if let Some(start) = open_display {
+        findings.push(Finding::error(
+            start,
+            start + 2,
+            "Display math opened with $$ is never closed",
+        ));
+    }
+
+    (regions, findings)
+}
+
+/// Reads a `{name}` argument starting at `index` (after optional spaces)
+fn read_group_argument(body: &str, index: usize) -> Option<(&str, usize)> {
+    let rest = &body[index..];
+    let skipped = rest.len() - rest.trim_start().len();
+    let rest = rest.trim_start().strip_prefix('{')?;
+    let close = rest.find('}')?;
+    Some((&rest[..close], index + skipped + close + 2))
+}
+
+/// Checks braces, environments and `\left`/`\right` pairs within one region
+fn check_latex(content: &str, region: &MathRegion) -> Vec<Finding> {
+    let body = &content[region.body_start..region.body_end];
+    let base = region.body_start;
+    let bytes = body.as_bytes();
+    let mut findings = Vec::new();
+    let mut braces: Vec<usize> = Vec::new();
+    let mut environments: Vec<(&str, usize, usize)> = Vec::new();
+    let mut lefts: Vec<usize> = Vec::new();
+
+    if body.trim().is_empty() {
+        findings.push(Finding {
+            start: region.start,
+            end: region.end,
+            severity: MathDiagnosticSeverity::Warning,
+            message: "Empty math block".to_string(),
+        });
+        return findings;
+    }
+
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => {
+                let name_len = bytes[i + 1..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_alphabetic())
+                    .count();
+                if name_len == 0 {
+                    // Escaped symbol such as \{ or \\
+                    i += 2;
+                    continue;
+                }
+
+                let name = &body[i + 1..i + 1 + name_len];
+                let command_end = i + 1 + name_len;
+                match name {
+                    "begin" | "end" => {
+                        let Some((env, after)) = read_group_argument(body, command_end) else {
+                            findings.push(Finding::error(
+                                base + i,
+                                base + command_end,
+                                format!("\\{name} is missing an environment name"),
+                            ));
+                            i = command_end;
+                            continue;
+                        };
+                        let (start, end) = (base + i, base + after);
+
+                        if name == "begin" {
+                            if !KATEX_ENVIRONMENTS.contains(&env) {
+                                findings.push(Finding::error(
+                                    start,
+                                    end,
+                                    format!("Unknown environment '{env}'"),
+                                ));
+                            }
+                            environments.push((env, start, end));
+                        } else {
+                            match environments.pop() {
+                                Some((open, _, _)) if open == env => {}
+                                Some((open, _, _)) => findings.push(Finding::error(
+                                    start,
+                                    end,
+                                    format!("\\end{{{env}}} does not match \\begin{{{open}}}"),
+                                )),
+                                None => findings.push(Finding::error(
+                                    start,
+                                    end,
+                                    format!("\\end{{{env}}} without a matching \\begin"),
+                                )),
+                            }
+                        }
+                        i = after;
+                        continue;
+                    }
+                    "left" => lefts.push(base + i),
+                    // Popping in the guard pairs each \right with the latest \left
+                    "right" if lefts.pop().is_none() => {
+                        findings.push(Finding::error(
+                            base + i,
+                            base + command_end,
+                            "\\right without a matching \\left",
+                        ));
+                    }
+                    _ => {}
+                }
+                i = command_end;
+            }
+            b'{' => {
+                braces.push(base + i);
+                i += 1;
+            }
+            b'}' => {
+                if braces.pop().is_none() {
+                    findings.push(Finding::error(base + i, base + i + 1, "Unexpected '}'"));
+                }
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+
+    for start in braces {
+        findings.push(Finding::error(start, start + 1, "Unclosed '{'"));
+    }
+    for (env, start, end) in environments {
+        findings.push(Finding::error(
+            start,
+            end,
+            format!("\\begin{{{env}}} is never closed with \\end{{{env}}}"),
+        ));
+    }
+    for start in lefts {
+        findings.push(Finding::error(
+            start,
+            start + "\\left".len(),
+            "\\left without a matching \\right",
+        ));
+    }
+
+    findings
+}
+
+fn to_diagnostic(content: &str, finding: Finding) -> MathDiagnostic {
+    let utf16_offset = |byte: usize| content[..byte].encode_utf16().count() as u32;
+    MathDiagnostic {
+        start: utf16_offset(finding.start),
+        end: utf16_offset(finding.end),
+        line: content[..finding.start].matches('\n').count() as u32 + 1,
+        severity: finding.severity,
+        message: finding.message,
+    }
+}
+
+fn validate(content: &str) -> Vec<MathDiagnostic> {
+    let (regions, mut findings) = find_math_regions(content);
+    for region in &regions {
+        findings.extend(check_latex(content, region));
+    }
+
+    findings.sort_by_key(|f| f.start);
+    findings
+        .into_iter()
+        .map(|finding| to_diagnostic(content, finding))
+        .collect()
+}
+
+/// Validates `$...$` and `$$...$$` math in Markdown content
+///
+/// # Arguments
+/// * `content` - The editor content (offsets in the result are relative to it)
+///
+/// # Returns
+/// Diagnostics ordered by position; empty when all math looks well-formed
+#[tauri::command]
+#[specta::specta]
+pub async fn validate_math_blocks(content: String) -> Result<Vec<MathDiagnostic>, String> {
+    Ok(validate(&content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(content: &str) -> Vec<String> {
+        validate(content).into_iter().map(|d| d.message).collect()
+    }
+
+    #[test]
+    fn test_find_regions_skips_code_and_currency() {
+        let content = "Costs $5 and $10.\n\n`$x$` and $a^2$\n\n```\n$$\n```\n\n$$\nE = mc^2\n$$\n";
+        let (regions, findings) = find_math_regions(content);
+        let bodies: Vec<&str> = regions
+            .iter()
+            .map(|r| content[r.body_start..r.body_end].trim())
+            .collect();
+        assert_eq!(bodies, vec!["a^2", "E = mc^2"]);
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn test_valid_math_has_no_diagnostics() {
+        let content = "Inline $\\frac{a}{b}$ and \\$5.\n\n$$\n\\begin{aligned}\n\\left( x \\right) &= \\{1\\}\n\\end{aligned}\n$$\n";
+        assert!(validate(content).is_empty());
+    }
+
+    #[test]
+    fn test_unclosed_display_math() {
+        let diagnostics = validate("Intro\n\n$$\nx = 1\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line, 3);
+        assert_eq!((diagnostics[0].start, diagnostics[0].end), (7, 9));
+    }
+
+    #[test]
+    fn test_brace_and_delimiter_errors() {
+        assert_eq!(messages("$\\frac{a}{b$"), vec!["Unclosed '{'"]);
+        assert_eq!(messages("$a}$"), vec!["Unexpected '}'"]);
+        assert_eq!(
+            messages("$$\\left( x$$"),
+            vec!["\\left without a matching \\right"]
+        );
+    }
+
+    #[test]
+    fn test_environment_errors() {
+        assert_eq!(
+            messages("$$\\begin{eqnarray}x\\end{eqnarray}$$"),
+            vec!["Unknown environment 'eqnarray'"]
+        );
+        assert_eq!(
+            messages("$$\\begin{matrix}x\\end{pmatrix}$$"),
+            vec!["\\end{pmatrix} does not match \\begin{matrix}"]
+        );
+        assert_eq!(
+            messages("$$\\begin{cases}x$$"),
+            vec!["\\begin{cases} is never closed with \\end{cases}"]
+        );
+    }
+
+    #[test]
+    fn test_offsets_are_utf16() {
+        // "é" is 2 bytes in UTF-8 but one UTF-16 unit; "😀" is 4 bytes, two units
+        let diagnostics = validate("é😀 $a}$");
+        assert_eq!(diagnostics[0].start, 6);
+        assert_eq!(diagnostics[0].severity, MathDiagnosticSeverity::Error);
+    }
+}
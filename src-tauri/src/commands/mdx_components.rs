@@ -1,36 +1,18 @@
 use crate::models::{ComponentFramework, MdxComponent, PropInfo};
+use crate::security::{check_path, Scope};
+use std::collections::HashMap;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 use std::rc::Rc;
+use swc_common::comments::{CommentKind, Comments, SingleThreadedComments};
 use swc_common::sync::Lrc;
-use swc_common::{FileName, SourceMap};
+use swc_common::{BytePos, FileName, SourceMap, Spanned};
 use swc_ecma_ast::EsVersion;
 use swc_ecma_ast::*;
 use swc_ecma_parser::{parse_file_as_module, Syntax, TsSyntax};
 use swc_ecma_visit::{Visit, VisitWith};
 use walkdir::WalkDir;
 
-/// Validates that a file path is within the project boundaries
-///
-/// This function prevents path traversal attacks by ensuring all file operations
-/// stay within the current project root directory.
-fn validate_project_path(file_path: &Path, project_root: &Path) -> Result<PathBuf, String> {
-    // Resolve canonical paths to handle symlinks and .. traversal
-    let canonical_file = file_path
-        .canonicalize()
-        .map_err(|_| "Invalid file path".to_string())?;
-    let canonical_root = project_root
-        .canonicalize()
-        .map_err(|_| "Invalid project root".to_string())?;
-
-    // Ensure file is within project bounds
-    canonical_file
-        .strip_prefix(&canonical_root)
-        .map_err(|_| "File outside project directory".to_string())?;
-
-    Ok(canonical_file)
-}
-
 /// Detects the framework based on file extension
 fn detect_framework(path: &Path) -> ComponentFramework {
     match path.extension().and_then(|s| s.to_str()) {
@@ -57,7 +39,7 @@ pub async fn scan_mdx_components(
         eprintln!("[MDX] Directory not found: {}", mdx_dir.display());
         return Ok(vec![]);
     }
-    if let Err(e) = validate_project_path(&mdx_dir, project_root) {
+    if let Err(e) = check_path(&mdx_dir, project_root, Scope::Project) {
         eprintln!(
             "[MDX] Directory outside project bounds: {}: {e}",
             mdx_dir.display()
@@ -86,7 +68,7 @@ pub async fn scan_mdx_components(
         }
 
         // Validate each component file is within project bounds
-        match validate_project_path(path, project_root) {
+        match check_path(path, project_root, Scope::Project) {
             Ok(_) => {
                 let framework = detect_framework(path);
                 let result = match framework {
@@ -123,7 +105,7 @@ pub async fn scan_mdx_components(
 fn parse_astro_component(path: &Path, project_root: &str) -> Result<MdxComponent, String> {
     // Validate the component file path is within project bounds
     let project_root_path = Path::new(project_root);
-    let _validated_path = validate_project_path(path, project_root_path)?;
+    let _validated_path = check_path(path, project_root_path, Scope::Project)?;
 
     let content = fs::read_to_string(path).map_err(|e| format!("Failed to read file: {e}"))?;
 
@@ -138,7 +120,7 @@ fn parse_astro_component(path: &Path, project_root: &str) -> Result<MdxComponent
     let frontmatter = extract_frontmatter(&content)?;
 
     // Parse TypeScript AST
-    let props = parse_props_from_typescript(&frontmatter)?;
+    let (props, description) = parse_props_from_typescript(&frontmatter)?;
 
     // Check for <slot /> in the template part
     let has_slot = content.contains("<slot") || content.contains("<slot/>");
@@ -155,7 +137,7 @@ fn parse_astro_component(path: &Path, project_root: &str) -> Result<MdxComponent
         file_path: relative_path,
         props,
         has_slot,
-        description: None, // TODO: Extract from JSDoc comments
+        description,
         framework: ComponentFramework::Astro,
     })
 }
@@ -163,7 +145,7 @@ fn parse_astro_component(path: &Path, project_root: &str) -> Result<MdxComponent
 fn parse_react_component(path: &Path, project_root: &str) -> Result<MdxComponent, String> {
     // Validate the component file path is within project bounds
     let project_root_path = Path::new(project_root);
-    let _validated_path = validate_project_path(path, project_root_path)?;
+    let _validated_path = check_path(path, project_root_path, Scope::Project)?;
 
     let content = fs::read_to_string(path).map_err(|e| format!("Failed to read file: {e}"))?;
 
@@ -182,14 +164,15 @@ fn parse_react_component(path: &Path, project_root: &str) -> Result<MdxComponent
         .to_string();
 
     // Parse React component props (with graceful degradation)
-    let (props, has_slot) = parse_react_props(&content).unwrap_or_else(|_| (Vec::new(), false));
+    let (props, has_slot, description) =
+        parse_react_props(&content).unwrap_or_else(|_| (Vec::new(), false, None));
 
     Ok(MdxComponent {
         name: component_name,
         file_path: relative_path,
         props,
         has_slot,
-        description: None,
+        description,
         framework: ComponentFramework::React,
     })
 }
@@ -197,7 +180,7 @@ fn parse_react_component(path: &Path, project_root: &str) -> Result<MdxComponent
 fn parse_vue_component(path: &Path, project_root: &str) -> Result<MdxComponent, String> {
     // Validate the component file path is within project bounds
     let project_root_path = Path::new(project_root);
-    let _validated_path = validate_project_path(path, project_root_path)?;
+    let _validated_path = check_path(path, project_root_path, Scope::Project)?;
 
     let content = fs::read_to_string(path).map_err(|e| format!("Failed to read file: {e}"))?;
 
@@ -216,14 +199,15 @@ fn parse_vue_component(path: &Path, project_root: &str) -> Result<MdxComponent,
         .to_string();
 
     // Parse Vue component props (with graceful degradation)
-    let (props, has_slot) = parse_vue_props(&content).unwrap_or_else(|_| (Vec::new(), false));
+    let (props, has_slot, description) =
+        parse_vue_props(&content).unwrap_or_else(|_| (Vec::new(), false, None));
 
     Ok(MdxComponent {
         name: component_name,
         file_path: relative_path,
         props,
         has_slot,
-        description: None,
+        description,
         framework: ComponentFramework::Vue,
     })
 }
@@ -231,7 +215,7 @@ fn parse_vue_component(path: &Path, project_root: &str) -> Result<MdxComponent,
 fn parse_svelte_component(path: &Path, project_root: &str) -> Result<MdxComponent, String> {
     // Validate the component file path is within project bounds
     let project_root_path = Path::new(project_root);
-    let _validated_path = validate_project_path(path, project_root_path)?;
+    let _validated_path = check_path(path, project_root_path, Scope::Project)?;
 
     let content = fs::read_to_string(path).map_err(|e| format!("Failed to read file: {e}"))?;
 
@@ -250,14 +234,15 @@ fn parse_svelte_component(path: &Path, project_root: &str) -> Result<MdxComponen
         .to_string();
 
     // Parse Svelte component props (with graceful degradation)
-    let (props, has_slot) = parse_svelte_props(&content).unwrap_or_else(|_| (Vec::new(), false));
+    let (props, has_slot, description) =
+        parse_svelte_props(&content).unwrap_or_else(|_| (Vec::new(), false, None));
 
     Ok(MdxComponent {
         name: component_name,
         file_path: relative_path,
         props,
         has_slot,
-        description: None,
+        description,
         framework: ComponentFramework::Svelte,
     })
 }
@@ -274,8 +259,8 @@ fn extract_frontmatter(content: &str) -> Result<String, String> {
 
 /// Parse React component props from TypeScript/TSX file
 /// Supports inline types and interface references
-/// Returns (props, has_children)
-fn parse_react_props(content: &str) -> Result<(Vec<PropInfo>, bool), String> {
+/// Returns (props, has_children, description)
+fn parse_react_props(content: &str) -> Result<(Vec<PropInfo>, bool, Option<String>), String> {
     // Create a source map (required by swc)
     let cm = Lrc::new(SourceMap::default());
 
@@ -292,13 +277,17 @@ fn parse_react_props(content: &str) -> Result<(Vec<PropInfo>, bool), String> {
         ..Default::default()
     });
 
-    let module = parse_file_as_module(&fm, syntax, EsVersion::Es2022, None, &mut vec![])
+    let comments = SingleThreadedComments::default();
+    let module = parse_file_as_module(&fm, syntax, EsVersion::Es2022, Some(&comments), &mut vec![])
         .map_err(|e| format!("Failed to parse TypeScript: {e:?}"))?;
 
     // Find function component and extract props
     let mut visitor = ReactPropsVisitor {
         props: Vec::new(),
         interfaces: std::collections::HashMap::new(),
+        comments: &comments,
+        item_pos: BytePos::DUMMY,
+        description: None,
     };
 
     module.visit_with(&mut visitor);
@@ -306,207 +295,585 @@ fn parse_react_props(content: &str) -> Result<(Vec<PropInfo>, bool), String> {
     // Check if 'children' prop exists
     let has_children = visitor.props.iter().any(|p| p.name == "children");
 
-    Ok((visitor.props, has_children))
+    // JSDoc on the component itself, else one heading the file or on its props type
+    let description = visitor
+        .description
+        .or_else(|| component_description(&module, &comments));
+
+    Ok((visitor.props, has_children, description))
 }
 
 /// Parse Vue component props from .vue file
-/// Supports Composition API defineProps<{...}>() pattern
-/// Returns (props, has_slot)
-fn parse_vue_props(content: &str) -> Result<(Vec<PropInfo>, bool), String> {
-    // Extract script section
-    let script_content = extract_vue_script(content)?;
-
-    // Find defineProps<{...}>() pattern
-    let props_type = extract_define_props_type(&script_content)?;
+/// Supports `defineProps<{...}>()` / `defineProps<Props>()` (with `withDefaults`),
+/// runtime `defineProps({...})` declarations and the Options API `props` option
+/// Returns (props, has_slot, description)
+fn parse_vue_props(content: &str) -> Result<(Vec<PropInfo>, bool, Option<String>), String> {
+    // Props are declared in <script setup> when the component has one
+    let blocks = script_blocks(content);
+    let (_, script) = blocks
+        .iter()
+        .find(|(attrs, _)| attrs.split_whitespace().any(|attr| attr == "setup"))
+        .or_else(|| blocks.first())
+        .ok_or("No <script> tag found")?;
+
+    let (module, comments) = parse_script(script)?;
+    let types = collect_prop_types(&module, &comments);
+    let mut visitor = VuePropsVisitor {
+        types: &types,
+        comments: &comments,
+        props: None,
+        defaults: Vec::new(),
+    };
+    module.visit_with(&mut visitor);
 
-    // Parse the type definition as TypeScript
-    let props = parse_vue_type_definition(&props_type)?;
+    let mut props = visitor.props.ok_or("No defineProps found")?;
+    apply_defaults(&mut props, visitor.defaults);
 
     // Check for slot in template
-    let has_slot = content.contains("<slot") || content.contains("<slot/>");
+    let has_slot = content.contains("<slot");
 
-    Ok((props, has_slot))
+    Ok((props, has_slot, component_description(&module, &comments)))
 }
 
-/// Extract <script> section from Vue file
-fn extract_vue_script(content: &str) -> Result<String, String> {
-    // Find <script> tag (handle both <script> and <script setup lang="ts">)
-    let script_start = content.find("<script").ok_or("No <script> tag found")?;
+/// Parse Svelte component props from .svelte file
+/// Supports `export let propName: Type` (Svelte 4) and `let { ... }: Props = $props()`
+/// (Svelte 5 runes)
+/// Returns (props, has_slot, description)
+fn parse_svelte_props(content: &str) -> Result<(Vec<PropInfo>, bool, Option<String>), String> {
+    // The instance script, not `<script context="module">` / `<script module>`
+    let (_, script) = script_blocks(content)
+        .into_iter()
+        .find(|(attrs, _)| {
+            !attrs.contains("context=\"module\"")
+                && !attrs.split_whitespace().any(|attr| attr == "module")
+        })
+        .ok_or("No <script> tag found")?;
+
+    let (module, comments) = parse_script(script)?;
+    let types = collect_prop_types(&module, &comments);
+    let mut visitor = SveltePropsVisitor {
+        types: &types,
+        comments: &comments,
+        props: Vec::new(),
+    };
+    module.visit_with(&mut visitor);
+
+    if visitor.props.is_empty() {
+        return Err("No export let or $props() declarations found".to_string());
+    }
 
-    let script_content_start = content[script_start..]
-        .find('>')
-        .ok_or("Malformed <script> tag")?
-        + script_start
-        + 1;
+    // Slots in Svelte 4; `children` snippets rendered with {@render} in Svelte 5
+    let has_slot = content.contains("<slot")
+        || content.contains("{@render children")
+        || visitor.props.iter().any(|p| p.name == "children");
 
-    let script_end = content[script_content_start..]
-        .find("</script>")
-        .ok_or("No closing </script> tag")?
-        + script_content_start;
+    // Svelte's own `<!-- @component -->` documentation comes first
+    let description =
+        svelte_component_doc(content).or_else(|| component_description(&module, &comments));
 
-    Ok(content[script_content_start..script_end].to_string())
+    Ok((visitor.props, has_slot, description))
 }
 
-/// Extract the type definition from defineProps<{...}>()
-fn extract_define_props_type(script: &str) -> Result<String, String> {
-    // Find defineProps<
-    let define_props_start = script.find("defineProps<").ok_or("No defineProps found")?;
-
-    let type_start = define_props_start + "defineProps<".len();
-
-    // Find matching closing >
-    let mut depth = 1;
-    let mut type_end = type_start;
-    let chars: Vec<char> = script.chars().collect();
-
-    for (i, ch) in chars.iter().enumerate().skip(type_start) {
-        match ch {
-            '<' => depth += 1,
-            '>' => {
-                depth -= 1;
-                if depth == 0 {
-                    type_end = i;
-                    break;
-                }
-            }
-            _ => {}
-        }
-    }
+/// The `<script>` blocks of a Vue or Svelte file, as (attributes, content)
+fn script_blocks(content: &str) -> Vec<(&str, &str)> {
+    let mut blocks = Vec::new();
+    let mut rest = content;
 
-    if depth != 0 {
-        return Err("Unmatched angle brackets in defineProps".to_string());
+    while let Some(start) = rest.find("<script") {
+        let after_tag = &rest[start + "<script".len()..];
+        let Some(attrs_end) = after_tag.find('>') else {
+            break;
+        };
+        let body = &after_tag[attrs_end + 1..];
+        let Some(body_end) = body.find("</script>") else {
+            break;
+        };
+        blocks.push((&after_tag[..attrs_end], &body[..body_end]));
+        rest = &body[body_end + "</script>".len()..];
     }
 
-    Ok(script[type_start..type_end].to_string())
+    blocks
 }
 
-/// Parse Vue props type definition (TypeScript object type)
-fn parse_vue_type_definition(type_def: &str) -> Result<Vec<PropInfo>, String> {
-    // Strip outer braces if present
-    let trimmed = type_def.trim();
-    let inner_content = if trimmed.starts_with('{') && trimmed.ends_with('}') {
-        &trimmed[1..trimmed.len() - 1]
-    } else {
-        trimmed
-    };
+/// Parse a Vue or Svelte `<script>` block as TypeScript (plain JS parses too),
+/// keeping its comments for JSDoc
+fn parse_script(script: &str) -> Result<(Module, SingleThreadedComments), String> {
+    let cm = Lrc::new(SourceMap::default());
+    let fm = cm.new_source_file(
+        Rc::new(FileName::Custom("script.ts".into())),
+        script.to_string(),
+    );
 
-    // Add semicolons to each line for proper TypeScript parsing
-    let lines: Vec<String> = inner_content
-        .lines()
-        .map(|line| {
-            let trimmed_line = line.trim();
-            // Skip empty lines or lines that already have semicolons
-            if trimmed_line.is_empty() || trimmed_line.ends_with(';') {
-                line.to_string()
-            } else {
-                // Add semicolon to property definitions
-                format!("{line};")
-            }
-        })
-        .collect();
+    let syntax = Syntax::Typescript(TsSyntax {
+        tsx: false,
+        decorators: true,
+        ..Default::default()
+    });
 
-    let normalized_type = lines.join("\n");
+    let comments = SingleThreadedComments::default();
+    let module = parse_file_as_module(&fm, syntax, EsVersion::Es2022, Some(&comments), &mut vec![])
+        .map_err(|e| format!("Failed to parse script: {e:?}"))?;
 
-    // Wrap in interface to reuse TypeScript parser
-    let wrapped = format!("interface Props {{\n{normalized_type}\n}}");
+    Ok((module, comments))
+}
 
-    // Parse as TypeScript
-    parse_props_from_typescript(&wrapped)
+/// Props declared by the script's interfaces and object type aliases, by type name
+fn collect_prop_types(
+    module: &Module,
+    comments: &SingleThreadedComments,
+) -> HashMap<String, Vec<PropInfo>> {
+    let mut visitor = PropTypesVisitor {
+        types: HashMap::new(),
+        comments,
+    };
+    module.visit_with(&mut visitor);
+    visitor.types
 }
 
-/// Parse Svelte component props from .svelte file
-/// Supports export let propName: Type pattern
-/// Returns (props, has_slot)
-fn parse_svelte_props(content: &str) -> Result<(Vec<PropInfo>, bool), String> {
-    // Extract script section
-    let script_content = extract_svelte_script(content)?;
+struct PropTypesVisitor<'a> {
+    types: HashMap<String, Vec<PropInfo>>,
+    comments: &'a SingleThreadedComments,
+}
 
-    // Find all export let statements
-    let props = parse_svelte_export_lets(&script_content)?;
+impl Visit for PropTypesVisitor<'_> {
+    fn visit_ts_interface_decl(&mut self, node: &TsInterfaceDecl) {
+        let props = node
+            .body
+            .body
+            .iter()
+            .filter_map(|member| match member {
+                TsTypeElement::TsPropertySignature(prop) => extract_prop_info(prop, self.comments),
+                _ => None,
+            })
+            .collect();
+        self.types.insert(node.id.sym.to_string(), props);
+    }
 
-    // Check for slot in markup
-    let has_slot = content.contains("<slot") || content.contains("<slot/>");
+    // type Props = { ... }
+    fn visit_ts_type_alias_decl(&mut self, node: &TsTypeAliasDecl) {
+        if let Some(props) =
+            extract_props_from_type_annotation(&node.type_ann, &self.types, self.comments)
+        {
+            self.types.insert(node.id.sym.to_string(), props);
+        }
+    }
+}
 
-    Ok((props, has_slot))
+struct VuePropsVisitor<'a> {
+    types: &'a HashMap<String, Vec<PropInfo>>,
+    comments: &'a SingleThreadedComments,
+    props: Option<Vec<PropInfo>>,
+    /// Defaults from `withDefaults(defineProps<...>(), { ... })`
+    defaults: Vec<(String, Option<String>)>,
 }
 
-/// Extract <script> section from Svelte file
-fn extract_svelte_script(content: &str) -> Result<String, String> {
-    // Find <script> tag
-    let script_start = content.find("<script").ok_or("No <script> tag found")?;
+impl Visit for VuePropsVisitor<'_> {
+    fn visit_call_expr(&mut self, node: &CallExpr) {
+        match callee_name(node) {
+            Some("defineProps") => self.props = Some(define_props(node, self.types, self.comments)),
+            Some("withDefaults") => {
+                if let Some(Expr::Object(defaults)) = node.args.get(1).map(|arg| &*arg.expr) {
+                    self.defaults = object_defaults(defaults);
+                }
+            }
+            // defineComponent({ props: { ... } })
+            Some("defineComponent") => {
+                if let Some(Expr::Object(options)) = node.args.first().map(|arg| &*arg.expr) {
+                    if let Some(props) = options_api_props(options, self.comments) {
+                        self.props = Some(props);
+                    }
+                }
+            }
+            _ => {}
+        }
+        node.visit_children_with(self);
+    }
 
-    let script_content_start = content[script_start..]
-        .find('>')
-        .ok_or("Malformed <script> tag")?
-        + script_start
-        + 1;
+    // export default { props: { ... } }
+    fn visit_export_default_expr(&mut self, node: &ExportDefaultExpr) {
+        if let Expr::Object(options) = &*node.expr {
+            if let Some(props) = options_api_props(options, self.comments) {
+                self.props = Some(props);
+            }
+        }
+        node.visit_children_with(self);
+    }
+}
+
+struct SveltePropsVisitor<'a> {
+    types: &'a HashMap<String, Vec<PropInfo>>,
+    comments: &'a SingleThreadedComments,
+    props: Vec<PropInfo>,
+}
+
+impl Visit for SveltePropsVisitor<'_> {
+    // export let propName: Type = defaultValue
+    fn visit_export_decl(&mut self, node: &ExportDecl) {
+        if let Decl::Var(var) = &node.decl {
+            if var.kind == VarDeclKind::Let {
+                let description = jsdoc(self.comments, node.span.lo);
+                self.props
+                    .extend(
+                        var.decls
+                            .iter()
+                            .filter_map(export_let_prop)
+                            .map(|prop| PropInfo {
+                                description: description.clone(),
+                                ..prop
+                            }),
+                    );
+            }
+        }
+    }
 
-    let script_end = content[script_content_start..]
-        .find("</script>")
-        .ok_or("No closing </script> tag")?
-        + script_content_start;
+    // let { title, size = 'md' }: Props = $props()
+    fn visit_var_declarator(&mut self, node: &VarDeclarator) {
+        let is_props_rune = node.init.as_deref().is_some_and(
+            |init| matches!(init, Expr::Call(call) if callee_name(call) == Some("$props")),
+        );
+        if is_props_rune {
+            self.props
+                .extend(rune_props(&node.name, self.types, self.comments));
+        }
+        node.visit_children_with(self);
+    }
+}
 
-    Ok(content[script_content_start..script_end].to_string())
+/// The name of a called function, e.g. `defineProps`
+fn callee_name(call: &CallExpr) -> Option<&str> {
+    match &call.callee {
+        Callee::Expr(expr) => match &**expr {
+            Expr::Ident(ident) => Some(ident.sym.as_str()),
+            _ => None,
+        },
+        _ => None,
+    }
 }
 
-/// Parse export let statements from Svelte script
-fn parse_svelte_export_lets(script: &str) -> Result<Vec<PropInfo>, String> {
-    let mut props = Vec::new();
+/// Props from `defineProps<Type>()`, `defineProps({ ... })` or `defineProps([...])`
+fn define_props(
+    call: &CallExpr,
+    types: &HashMap<String, Vec<PropInfo>>,
+    comments: &SingleThreadedComments,
+) -> Vec<PropInfo> {
+    if let Some(type_args) = &call.type_args {
+        return type_args
+            .params
+            .first()
+            .and_then(|props_type| extract_props_from_type_annotation(props_type, types, comments))
+            .unwrap_or_default();
+    }
 
-    // Process each line looking for export let statements
-    for line in script.lines() {
-        let trimmed = line.trim();
+    match call.args.first().map(|arg| &*arg.expr) {
+        Some(Expr::Object(declaration)) => runtime_props(declaration, comments),
+        Some(Expr::Array(names)) => array_props(names),
+        _ => Vec::new(),
+    }
+}
 
-        // Skip lines that don't start with export let
-        if !trimmed.starts_with("export let ") {
-            continue;
+/// The `props` option of an Options API component
+fn options_api_props(
+    options: &ObjectLit,
+    comments: &SingleThreadedComments,
+) -> Option<Vec<PropInfo>> {
+    options.props.iter().find_map(|prop| {
+        let PropOrSpread::Prop(prop) = prop else {
+            return None;
+        };
+        let Prop::KeyValue(kv) = &**prop else {
+            return None;
+        };
+        if prop_name(&kv.key).as_deref() != Some("props") {
+            return None;
+        }
+        match &*kv.value {
+            Expr::Object(declaration) => Some(runtime_props(declaration, comments)),
+            Expr::Array(names) => Some(array_props(names)),
+            _ => None,
         }
+    })
+}
 
-        // Parse: export let propName: Type
-        // or: export let propName: Type = defaultValue
-        let after_export = trimmed.strip_prefix("export let ").unwrap();
+/// Props from a runtime declaration: `{ title: String, size: { type: String, default: 'md' } }`
+///
+/// Runtime props are optional unless declared `required: true`.
+fn runtime_props(declaration: &ObjectLit, comments: &SingleThreadedComments) -> Vec<PropInfo> {
+    declaration
+        .props
+        .iter()
+        .filter_map(|prop| {
+            let PropOrSpread::Prop(prop) = prop else {
+                return None;
+            };
+            let Prop::KeyValue(kv) = &**prop else {
+                return None;
+            };
+            let name = prop_name(&kv.key)?;
+            let description = jsdoc(comments, kv.key.span_lo());
+
+            let Expr::Object(options) = &*kv.value else {
+                return Some(PropInfo {
+                    name,
+                    prop_type: runtime_type(&kv.value),
+                    is_optional: true,
+                    default_value: None,
+                    description,
+                });
+            };
 
-        // Find the colon that separates name from type
-        let colon_pos = match after_export.find(':') {
-            Some(pos) => pos,
-            None => continue, // No type annotation, skip
-        };
+            let mut prop_type = "unknown".to_string();
+            let mut required = false;
+            let mut default_value = None;
+            for option in &options.props {
+                let PropOrSpread::Prop(option) = option else {
+                    continue;
+                };
+                let Prop::KeyValue(option) = &**option else {
+                    continue;
+                };
+                match prop_name(&option.key).as_deref() {
+                    Some("type") => prop_type = runtime_type(&option.value),
+                    Some("required") => {
+                        required = matches!(&*option.value, Expr::Lit(Lit::Bool(b)) if b.value)
+                    }
+                    Some("default") => default_value = literal_value(&option.value),
+                    _ => {}
+                }
+            }
 
-        let prop_name = after_export[..colon_pos].trim().to_string();
-        let after_colon = &after_export[colon_pos + 1..];
+            Some(PropInfo {
+                name,
+                prop_type,
+                is_optional: !required,
+                default_value,
+                description,
+            })
+        })
+        .collect()
+}
 
-        // Find where the type ends (either at = or newline/semicolon)
-        let type_end = after_colon.find('=').unwrap_or(after_colon.len());
+/// Props from `['title', 'size']`, which have no type information
+fn array_props(names: &ArrayLit) -> Vec<PropInfo> {
+    names
+        .elems
+        .iter()
+        .flatten()
+        .filter_map(|elem| match &*elem.expr {
+            Expr::Lit(Lit::Str(name)) => Some(PropInfo {
+                name: name.value.to_string_lossy().to_string(),
+                prop_type: "unknown".to_string(),
+                is_optional: true,
+                default_value: None,
+                description: None,
+            }),
+            _ => None,
+        })
+        .collect()
+}
 
-        let type_str = after_colon[..type_end].trim();
+/// The TypeScript type of a runtime prop type: `String`, `[String, Number]` or
+/// `String as PropType<'a' | 'b'>`
+fn runtime_type(expr: &Expr) -> String {
+    match expr {
+        Expr::Ident(ident) => match ident.sym.as_str() {
+            "String" => "string".to_string(),
+            "Number" => "number".to_string(),
+            "Boolean" => "boolean".to_string(),
+            "Array" => "unknown[]".to_string(),
+            "Object" => "object".to_string(),
+            other => other.to_string(),
+        },
+        Expr::Array(types) => types
+            .elems
+            .iter()
+            .flatten()
+            .map(|elem| runtime_type(&elem.expr))
+            .collect::<Vec<_>>()
+            .join(" | "),
+        Expr::TsAs(as_expr) => match &*as_expr.type_ann {
+            TsType::TsTypeRef(type_ref) => type_ref
+                .type_params
+                .as_ref()
+                .and_then(|params| params.params.first())
+                .map(|prop_type| type_to_string(prop_type))
+                .unwrap_or_else(|| runtime_type(&as_expr.expr)),
+            _ => runtime_type(&as_expr.expr),
+        },
+        Expr::Paren(paren) => runtime_type(&paren.expr),
+        _ => "unknown".to_string(),
+    }
+}
+
+/// `{ size: 'md', count: 3 }` as (name, default) pairs; non-literal defaults have no value
+fn object_defaults(defaults: &ObjectLit) -> Vec<(String, Option<String>)> {
+    defaults
+        .props
+        .iter()
+        .filter_map(|prop| {
+            let PropOrSpread::Prop(prop) = prop else {
+                return None;
+            };
+            match &**prop {
+                Prop::KeyValue(kv) => Some((prop_name(&kv.key)?, literal_value(&kv.value))),
+                Prop::Method(method) => Some((prop_name(&method.key)?, None)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Marks props with defaults as optional and records literal defaults
+fn apply_defaults(props: &mut [PropInfo], defaults: Vec<(String, Option<String>)>) {
+    for (name, default_value) in defaults {
+        if let Some(prop) = props.iter_mut().find(|prop| prop.name == name) {
+            prop.is_optional = true;
+            prop.default_value = default_value;
+        }
+    }
+}
 
-        // Check if optional (has default value or includes undefined)
-        let is_optional = after_colon.contains('=') || type_str.contains("undefined");
+/// A prop from `export let name: Type = default`
+///
+/// Untyped props are only included when their default shows the type.
+fn export_let_prop(decl: &VarDeclarator) -> Option<PropInfo> {
+    let Pat::Ident(binding) = &decl.name else {
+        return None;
+    };
+    let default_value = decl.init.as_deref().and_then(literal_value);
+    let prop_type = match &binding.type_ann {
+        Some(type_ann) => type_to_string(&type_ann.type_ann),
+        None => decl.init.as_deref().and_then(literal_type)?,
+    };
 
-        // Clean up the type string
-        let clean_type = type_str.trim_end_matches(';').trim().to_string();
+    Some(PropInfo {
+        name: binding.id.sym.to_string(),
+        is_optional: decl.init.is_some() || prop_type.split(" | ").any(|t| t == "undefined"),
+        prop_type,
+        default_value,
+        description: None,
+    })
+}
 
-        props.push(PropInfo {
-            name: prop_name,
-            prop_type: clean_type,
-            is_optional,
-            default_value: None,
-        });
+/// Props from the pattern `$props()` is assigned to, typed by its annotation when it has one
+fn rune_props(
+    pattern: &Pat,
+    types: &HashMap<String, Vec<PropInfo>>,
+    comments: &SingleThreadedComments,
+) -> Vec<PropInfo> {
+    match pattern {
+        Pat::Ident(binding) => binding
+            .type_ann
+            .as_ref()
+            .and_then(|type_ann| {
+                extract_props_from_type_annotation(&type_ann.type_ann, types, comments)
+            })
+            .unwrap_or_default(),
+        Pat::Object(object) => {
+            // (name, default) for each destructured prop
+            let destructured: Vec<(String, Option<&Expr>)> = object
+                .props
+                .iter()
+                .filter_map(|prop| match prop {
+                    ObjectPatProp::Assign(assign) => {
+                        Some((assign.key.id.sym.to_string(), assign.value.as_deref()))
+                    }
+                    ObjectPatProp::KeyValue(kv) => {
+                        let default = match &*kv.value {
+                            Pat::Assign(assign) => Some(&*assign.right),
+                            _ => None,
+                        };
+                        Some((prop_name(&kv.key)?, default))
+                    }
+                    ObjectPatProp::Rest(_) => None,
+                })
+                .collect();
+
+            let annotated = object.type_ann.as_ref().and_then(|type_ann| {
+                extract_props_from_type_annotation(&type_ann.type_ann, types, comments)
+            });
+            match annotated {
+                Some(mut props) => {
+                    let defaults = destructured
+                        .iter()
+                        .filter_map(|(name, default)| {
+                            default.map(|expr| (name.clone(), literal_value(expr)))
+                        })
+                        .collect();
+                    apply_defaults(&mut props, defaults);
+                    props
+                }
+                None => destructured
+                    .into_iter()
+                    .map(|(name, default)| PropInfo {
+                        name,
+                        prop_type: default
+                            .and_then(literal_type)
+                            .unwrap_or_else(|| "unknown".to_string()),
+                        is_optional: default.is_some(),
+                        default_value: default.and_then(literal_value),
+                        description: None,
+                    })
+                    .collect(),
+            }
+        }
+        _ => Vec::new(),
     }
+}
 
-    if props.is_empty() {
-        return Err("No export let statements found".to_string());
+/// An identifier or string property key
+fn prop_name(key: &PropName) -> Option<String> {
+    match key {
+        PropName::Ident(ident) => Some(ident.sym.to_string()),
+        PropName::Str(s) => Some(s.value.to_string_lossy().to_string()),
+        _ => None,
     }
+}
 
-    Ok(props)
+/// A literal default as it's written in TypeScript, e.g. `'md'` or `3`
+fn literal_value(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(Lit::Str(s)) => Some(format!("'{}'", s.value.to_string_lossy())),
+        Expr::Lit(Lit::Num(n)) => Some(n.value.to_string()),
+        Expr::Lit(Lit::Bool(b)) => Some(b.value.to_string()),
+        Expr::Lit(Lit::Null(_)) => Some("null".to_string()),
+        _ => None,
+    }
+}
+
+/// The type of a literal default, for props without a type annotation
+fn literal_type(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(Lit::Str(_)) | Expr::Tpl(_) => Some("string".to_string()),
+        Expr::Lit(Lit::Num(_)) => Some("number".to_string()),
+        Expr::Lit(Lit::Bool(_)) => Some("boolean".to_string()),
+        Expr::Array(_) => Some("unknown[]".to_string()),
+        _ => None,
+    }
 }
 
-struct ReactPropsVisitor {
+struct ReactPropsVisitor<'a> {
     props: Vec<PropInfo>,
     interfaces: std::collections::HashMap<String, Vec<PropInfo>>,
+    comments: &'a SingleThreadedComments,
+    /// Start of the top-level statement being visited, where its JSDoc attaches
+    item_pos: BytePos,
+    /// JSDoc on the component the props were found on
+    description: Option<String>,
+}
+
+impl ReactPropsVisitor<'_> {
+    fn found_component(&mut self, props: Vec<PropInfo>) {
+        self.props = props;
+        self.description = jsdoc(self.comments, self.item_pos);
+    }
 }
 
-impl Visit for ReactPropsVisitor {
+impl Visit for ReactPropsVisitor<'_> {
+    fn visit_module_item(&mut self, node: &ModuleItem) {
+        self.item_pos = node.span_lo();
+        node.visit_children_with(self);
+    }
+
     // Collect interface definitions
     fn visit_ts_interface_decl(&mut self, node: &TsInterfaceDecl) {
         let interface_name = node.id.sym.to_string();
@@ -514,7 +881,7 @@ impl Visit for ReactPropsVisitor {
 
         for member in &node.body.body {
             if let TsTypeElement::TsPropertySignature(prop) = member {
-                if let Some(prop_info) = extract_prop_info(prop) {
+                if let Some(prop_info) = extract_prop_info(prop, self.comments) {
                     interface_props.push(prop_info);
                 }
             }
@@ -525,8 +892,10 @@ impl Visit for ReactPropsVisitor {
 
     // Find function declarations
     fn visit_fn_decl(&mut self, node: &FnDecl) {
-        if let Some(props) = extract_props_from_function(&node.function, &self.interfaces) {
-            self.props = props;
+        if let Some(props) =
+            extract_props_from_function(&node.function, &self.interfaces, self.comments)
+        {
+            self.found_component(props);
         }
     }
 
@@ -534,8 +903,10 @@ impl Visit for ReactPropsVisitor {
     fn visit_var_declarator(&mut self, node: &VarDeclarator) {
         if let Some(init) = &node.init {
             if let Expr::Arrow(arrow) = init.as_ref() {
-                if let Some(props) = extract_props_from_arrow(arrow, &self.interfaces) {
-                    self.props = props;
+                if let Some(props) =
+                    extract_props_from_arrow(arrow, &self.interfaces, self.comments)
+                {
+                    self.found_component(props);
                 }
             }
         }
@@ -544,8 +915,10 @@ impl Visit for ReactPropsVisitor {
     // Handle export default function
     fn visit_export_default_decl(&mut self, node: &ExportDefaultDecl) {
         if let DefaultDecl::Fn(fn_expr) = &node.decl {
-            if let Some(props) = extract_props_from_function(&fn_expr.function, &self.interfaces) {
-                self.props = props;
+            if let Some(props) =
+                extract_props_from_function(&fn_expr.function, &self.interfaces, self.comments)
+            {
+                self.found_component(props);
             }
         }
     }
@@ -555,42 +928,45 @@ impl Visit for ReactPropsVisitor {
 fn extract_props_from_function(
     func: &Function,
     interfaces: &std::collections::HashMap<String, Vec<PropInfo>>,
+    comments: &SingleThreadedComments,
 ) -> Option<Vec<PropInfo>> {
     // Get first parameter
     let first_param = func.params.first()?;
 
     // Extract type annotation from the pattern
-    extract_type_ann_from_pat(&first_param.pat, interfaces)
+    extract_type_ann_from_pat(&first_param.pat, interfaces, comments)
 }
 
 /// Extract props from an arrow function
 fn extract_props_from_arrow(
     arrow: &ArrowExpr,
     interfaces: &std::collections::HashMap<String, Vec<PropInfo>>,
+    comments: &SingleThreadedComments,
 ) -> Option<Vec<PropInfo>> {
     // Get first parameter
     let first_param = arrow.params.first()?;
 
     // Extract type annotation from the pattern
-    extract_type_ann_from_pat(first_param, interfaces)
+    extract_type_ann_from_pat(first_param, interfaces, comments)
 }
 
 /// Extract type annotation from a Pat (pattern)
 fn extract_type_ann_from_pat(
     pat: &Pat,
     interfaces: &std::collections::HashMap<String, Vec<PropInfo>>,
+    comments: &SingleThreadedComments,
 ) -> Option<Vec<PropInfo>> {
     match pat {
         Pat::Object(obj_pat) => {
             if let Some(type_ann) = &obj_pat.type_ann {
-                extract_props_from_type_annotation(&type_ann.type_ann, interfaces)
+                extract_props_from_type_annotation(&type_ann.type_ann, interfaces, comments)
             } else {
                 None
             }
         }
         Pat::Ident(ident) => {
             if let Some(type_ann) = &ident.type_ann {
-                extract_props_from_type_annotation(&type_ann.type_ann, interfaces)
+                extract_props_from_type_annotation(&type_ann.type_ann, interfaces, comments)
             } else {
                 None
             }
@@ -604,6 +980,7 @@ fn extract_type_ann_from_pat(
 fn extract_props_from_type_annotation(
     ts_type: &TsType,
     interfaces: &std::collections::HashMap<String, Vec<PropInfo>>,
+    comments: &SingleThreadedComments,
 ) -> Option<Vec<PropInfo>> {
     match ts_type {
         // Inline object type: { prop: Type }
@@ -611,7 +988,7 @@ fn extract_props_from_type_annotation(
             let mut props = Vec::new();
             for member in &type_lit.members {
                 if let TsTypeElement::TsPropertySignature(prop) = member {
-                    if let Some(prop_info) = extract_prop_info(prop) {
+                    if let Some(prop_info) = extract_prop_info(prop, comments) {
                         props.push(prop_info);
                     }
                 }
@@ -631,7 +1008,11 @@ fn extract_props_from_type_annotation(
     }
 }
 
-fn parse_props_from_typescript(typescript_code: &str) -> Result<Vec<PropInfo>, String> {
+/// Parse an Astro component's frontmatter for its `Props` interface
+/// Returns (props, description)
+fn parse_props_from_typescript(
+    typescript_code: &str,
+) -> Result<(Vec<PropInfo>, Option<String>), String> {
     // Create a source map (required by swc)
     let cm = Lrc::new(SourceMap::default());
 
@@ -648,28 +1029,35 @@ fn parse_props_from_typescript(typescript_code: &str) -> Result<Vec<PropInfo>, S
         ..Default::default()
     });
 
-    let module = parse_file_as_module(&fm, syntax, EsVersion::Es2022, None, &mut vec![])
+    let comments = SingleThreadedComments::default();
+    let module = parse_file_as_module(&fm, syntax, EsVersion::Es2022, Some(&comments), &mut vec![])
         .map_err(|e| format!("Failed to parse TypeScript: {e:?}"))?;
 
     // Find Props interface
-    let mut visitor = PropsVisitor { props: Vec::new() };
+    let mut visitor = PropsVisitor {
+        props: Vec::new(),
+        comments: &comments,
+    };
 
     module.visit_with(&mut visitor);
 
-    Ok(visitor.props)
+    let description = component_description(&module, &comments);
+
+    Ok((visitor.props, description))
 }
 
-struct PropsVisitor {
+struct PropsVisitor<'a> {
     props: Vec<PropInfo>,
+    comments: &'a SingleThreadedComments,
 }
 
-impl Visit for PropsVisitor {
+impl Visit for PropsVisitor<'_> {
     fn visit_ts_interface_decl(&mut self, node: &TsInterfaceDecl) {
         // Look for interface named "Props"
         if node.id.sym.as_str() == "Props" {
             for member in &node.body.body {
                 if let TsTypeElement::TsPropertySignature(prop) = member {
-                    if let Some(prop_info) = extract_prop_info(prop) {
+                    if let Some(prop_info) = extract_prop_info(prop, self.comments) {
                         self.props.push(prop_info);
                     }
                 }
@@ -678,7 +1066,10 @@ impl Visit for PropsVisitor {
     }
 }
 
-fn extract_prop_info(prop: &TsPropertySignature) -> Option<PropInfo> {
+fn extract_prop_info(
+    prop: &TsPropertySignature,
+    comments: &SingleThreadedComments,
+) -> Option<PropInfo> {
     // Extract property name
     let name = match prop.key.as_ref() {
         Expr::Ident(ident) => ident.sym.to_string(),
@@ -700,9 +1091,77 @@ fn extract_prop_info(prop: &TsPropertySignature) -> Option<PropInfo> {
         prop_type,
         is_optional,
         default_value: None, // TODO: Extract default values from AST
+        description: jsdoc(comments, prop.span.lo),
+    })
+}
+
+/// The component's description: a JSDoc block heading the script, or the one on
+/// its props interface or type
+fn component_description(module: &Module, comments: &SingleThreadedComments) -> Option<String> {
+    let header = module
+        .body
+        .first()
+        .and_then(|item| jsdoc(comments, item.span_lo()));
+
+    header.or_else(|| {
+        module.body.iter().find_map(|item| {
+            let decl = match item {
+                ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => &export.decl,
+                _ => return None,
+            };
+            let type_name = match decl {
+                Decl::TsInterface(interface) => &interface.id.sym,
+                Decl::TsTypeAlias(alias) => &alias.id.sym,
+                _ => return None,
+            };
+            if type_name.ends_with("Props") {
+                jsdoc(comments, item.span_lo())
+            } else {
+                None
+            }
+        })
     })
 }
 
+/// The text of the JSDoc block (`/** ... */`) directly above the node starting at
+/// `pos`, without its `*` gutter or `@tags`
+fn jsdoc(comments: &SingleThreadedComments, pos: BytePos) -> Option<String> {
+    let comment = comments
+        .get_leading(pos)?
+        .into_iter()
+        .rev()
+        .find(|comment| comment.kind == CommentKind::Block && comment.text.starts_with('*'))?;
+
+    let text = comment.text[1..]
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            line.strip_prefix('*').map(str::trim_start).unwrap_or(line)
+        })
+        .take_while(|line| !line.starts_with('@'))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// The `<!-- @component ... -->` comment documenting a Svelte component
+fn svelte_component_doc(content: &str) -> Option<String> {
+    let start = content.find("<!-- @component")? + "<!-- @component".len();
+    let end = content[start..].find("-->")? + start;
+
+    let text = content[start..end]
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
 fn type_to_string(ts_type: &TsType) -> String {
     match ts_type {
         TsType::TsKeywordType(keyword) => match keyword.kind {
@@ -710,6 +1169,9 @@ fn type_to_string(ts_type: &TsType) -> String {
             TsKeywordTypeKind::TsNumberKeyword => "number".to_string(),
             TsKeywordTypeKind::TsBooleanKeyword => "boolean".to_string(),
             TsKeywordTypeKind::TsAnyKeyword => "any".to_string(),
+            TsKeywordTypeKind::TsUndefinedKeyword => "undefined".to_string(),
+            TsKeywordTypeKind::TsNullKeyword => "null".to_string(),
+            TsKeywordTypeKind::TsObjectKeyword => "object".to_string(),
             _ => "unknown".to_string(),
         },
         TsType::TsLitType(lit) => match &lit.lit {
@@ -765,7 +1227,7 @@ mod tests {
         fs::create_dir_all(test_file.parent().unwrap()).unwrap();
         fs::write(&test_file, "test content").unwrap();
 
-        let result = validate_project_path(&test_file, &project_root);
+        let result = check_path(&test_file, &project_root, Scope::Project);
 
         assert!(result.is_ok());
 
@@ -788,11 +1250,11 @@ mod tests {
         // Create project directory
         fs::create_dir_all(&project_root).unwrap();
 
-        let result = validate_project_path(&malicious_path, &project_root);
+        let result = check_path(&malicious_path, &project_root, Scope::Project);
 
         // Should fail due to path traversal
         assert!(result.is_err());
-        let error = result.unwrap_err();
+        let error = result.unwrap_err().to_string();
         assert!(
             error.contains("File outside project directory") || error.contains("Invalid file path")
         );
@@ -812,7 +1274,7 @@ mod tests {
             }
         "#;
 
-        let props = parse_props_from_typescript(typescript_code).unwrap();
+        let (props, _) = parse_props_from_typescript(typescript_code).unwrap();
 
         assert_eq!(props.len(), 4);
 
@@ -927,7 +1389,7 @@ interface Props {
             }
         "#;
 
-        let (props, has_children) = parse_react_props(code).unwrap();
+        let (props, has_children, _) = parse_react_props(code).unwrap();
 
         assert_eq!(props.len(), 2);
         assert_eq!(props[0].name, "variant");
@@ -954,7 +1416,7 @@ interface Props {
             }
         "#;
 
-        let (props, has_children) = parse_react_props(code).unwrap();
+        let (props, has_children, _) = parse_react_props(code).unwrap();
 
         assert_eq!(props.len(), 2);
         assert_eq!(props[0].name, "variant");
@@ -981,7 +1443,7 @@ interface Props {
             }
         "#;
 
-        let (props, has_children) = parse_react_props(code).unwrap();
+        let (props, has_children, _) = parse_react_props(code).unwrap();
 
         assert_eq!(props.len(), 2);
         assert_eq!(props[0].name, "title");
@@ -999,7 +1461,7 @@ interface Props {
             };
         "#;
 
-        let (props, has_children) = parse_react_props(code).unwrap();
+        let (props, has_children, _) = parse_react_props(code).unwrap();
 
         assert_eq!(props.len(), 2);
         assert_eq!(props[0].name, "message");
@@ -1027,7 +1489,7 @@ interface Props {
             }
         "#;
 
-        let (props, _) = parse_react_props(code).unwrap();
+        let (props, _, _) = parse_react_props(code).unwrap();
 
         assert_eq!(props.len(), 3);
         assert!(!props[0].is_optional); // required
@@ -1133,7 +1595,7 @@ const props = defineProps<{
 </template>
 "#;
 
-        let (props, has_slot) = parse_vue_props(code).unwrap();
+        let (props, has_slot, _) = parse_vue_props(code).unwrap();
 
         assert_eq!(props.len(), 3);
         assert_eq!(props[0].name, "variant");
@@ -1168,7 +1630,7 @@ const props = defineProps<{
 </template>
 "#;
 
-        let (props, has_slot) = parse_vue_props(code).unwrap();
+        let (props, has_slot, _) = parse_vue_props(code).unwrap();
 
         assert_eq!(props.len(), 1);
         assert_eq!(props[0].name, "title");
@@ -1191,7 +1653,7 @@ const props = defineProps<{
 </template>
 "#;
 
-        let (props, _) = parse_vue_props(code).unwrap();
+        let (props, _, _) = parse_vue_props(code).unwrap();
 
         assert_eq!(props.len(), 3);
         assert!(!props[0].is_optional); // required
@@ -1214,7 +1676,7 @@ const props = defineProps<{
 </template>
 "#;
 
-        let (props, _) = parse_vue_props(code).unwrap();
+        let (props, _, _) = parse_vue_props(code).unwrap();
 
         assert_eq!(props.len(), 2);
         assert_eq!(props[0].name, "size");
@@ -1278,6 +1740,70 @@ const props = defineProps<{
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_vue_with_defaults_and_type_reference() {
+        let code = r#"
+<script lang="ts">
+export default { name: 'Callout' }
+</script>
+
+<script setup lang="ts">
+interface Props {
+  title: string
+  size?: 'sm' | 'md'
+  dismissible?: boolean
+}
+const props = withDefaults(defineProps<Props>(), {
+  size: 'md',
+  dismissible: false,
+})
+</script>
+"#;
+
+        let (props, _, _) = parse_vue_props(code).unwrap();
+
+        assert_eq!(props.len(), 3);
+        assert!(!props[0].is_optional);
+        assert_eq!(props[1].prop_type, "'sm' | 'md'");
+        assert_eq!(props[1].default_value.as_deref(), Some("'md'"));
+        assert_eq!(props[2].default_value.as_deref(), Some("false"));
+    }
+
+    #[test]
+    fn test_parse_vue_runtime_declarations() {
+        let code = r#"
+<script setup>
+const props = defineProps({
+  title: { type: String, required: true },
+  count: { type: Number, default: 3 },
+  tags: [String, Array],
+  tone: { type: String as PropType<'calm' | 'loud'> },
+})
+</script>
+"#;
+
+        let (props, _, _) = parse_vue_props(code).unwrap();
+
+        assert_eq!(props.len(), 4);
+        assert_eq!(props[0].prop_type, "string");
+        assert!(!props[0].is_optional);
+        assert_eq!(props[1].default_value.as_deref(), Some("3"));
+        assert!(props[1].is_optional);
+        assert_eq!(props[2].prop_type, "string | unknown[]");
+        assert_eq!(props[3].prop_type, "'calm' | 'loud'");
+
+        let options_api = r#"
+<script>
+export default {
+  props: ['label', 'href'],
+}
+</script>
+"#;
+        let (props, _, _) = parse_vue_props(options_api).unwrap();
+        let names: Vec<&str> = props.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["label", "href"]);
+    }
+
     // Svelte Parser Tests
 
     #[test]
@@ -1292,7 +1818,7 @@ const props = defineProps<{
 <div>{message}</div>
 "#;
 
-        let (props, has_slot) = parse_svelte_props(code).unwrap();
+        let (props, has_slot, _) = parse_svelte_props(code).unwrap();
 
         assert_eq!(props.len(), 3);
         assert_eq!(props[0].name, "variant");
@@ -1322,7 +1848,7 @@ const props = defineProps<{
 <div />
 "#;
 
-        let (props, _) = parse_svelte_props(code).unwrap();
+        let (props, _, _) = parse_svelte_props(code).unwrap();
 
         assert_eq!(props.len(), 3);
         assert!(!props[0].is_optional); // required
@@ -1343,7 +1869,7 @@ const props = defineProps<{
 </div>
 "#;
 
-        let (props, has_slot) = parse_svelte_props(code).unwrap();
+        let (props, has_slot, _) = parse_svelte_props(code).unwrap();
 
         assert_eq!(props.len(), 1);
         assert_eq!(props[0].name, "title");
@@ -1361,7 +1887,7 @@ const props = defineProps<{
 <div />
 "#;
 
-        let (props, _) = parse_svelte_props(code).unwrap();
+        let (props, _, _) = parse_svelte_props(code).unwrap();
 
         assert_eq!(props.len(), 2);
         assert_eq!(props[0].name, "size");
@@ -1419,6 +1945,173 @@ const props = defineProps<{
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_svelte_untyped_export_let_with_default() {
+        let code = r#"
+<script>
+  export let label = 'Read more'
+  export let count = 0
+</script>
+"#;
+
+        let (props, _, _) = parse_svelte_props(code).unwrap();
+
+        assert_eq!(props[0].prop_type, "string");
+        assert_eq!(props[0].default_value.as_deref(), Some("'Read more'"));
+        assert_eq!(props[1].prop_type, "number");
+        assert!(props[1].is_optional);
+    }
+
+    #[test]
+    fn test_parse_svelte_props_rune() {
+        let code = r#"
+<script lang="ts" module>
+  export const variants = ['info', 'warning']
+</script>
+
+<script lang="ts">
+  import type { Snippet } from 'svelte'
+
+  interface Props {
+    title: string
+    variant?: 'info' | 'warning'
+    children?: Snippet
+  }
+
+  let { title, variant = 'info', children }: Props = $props()
+</script>
+
+<div class={variant}>{title} {@render children?.()}</div>
+"#;
+
+        let (props, has_slot, _) = parse_svelte_props(code).unwrap();
+
+        assert_eq!(props.len(), 3);
+        assert_eq!(props[0].name, "title");
+        assert!(!props[0].is_optional);
+        assert_eq!(props[1].prop_type, "'info' | 'warning'");
+        assert_eq!(props[1].default_value.as_deref(), Some("'info'"));
+        assert!(has_slot);
+    }
+
+    #[test]
+    fn test_parse_svelte_untyped_props_rune() {
+        let code = r#"
+<script>
+  let { href, external = false, ...rest } = $props()
+</script>
+"#;
+
+        let (props, has_slot, _) = parse_svelte_props(code).unwrap();
+
+        assert_eq!(props.len(), 2);
+        assert_eq!(props[0].prop_type, "unknown");
+        assert!(!props[0].is_optional);
+        assert_eq!(props[1].prop_type, "boolean");
+        assert!(props[1].is_optional);
+        assert!(!has_slot);
+    }
+
+    #[test]
+    fn test_astro_jsdoc_descriptions() {
+        let frontmatter = r#"
+/**
+ * A highlighted box for asides.
+ *
+ * @example <Callout type="info">Note</Callout>
+ */
+import Icon from './Icon.astro';
+
+interface Props {
+  /** Visual style of the box */
+  type?: 'info' | 'warning';
+  // Not JSDoc, so ignored
+  title: string;
+}
+"#;
+
+        let (props, description) = parse_props_from_typescript(frontmatter).unwrap();
+
+        assert_eq!(
+            description.as_deref(),
+            Some("A highlighted box for asides.")
+        );
+        assert_eq!(
+            props[0].description.as_deref(),
+            Some("Visual style of the box")
+        );
+        assert_eq!(props[1].description, None);
+    }
+
+    #[test]
+    fn test_react_jsdoc_descriptions() {
+        let code = r#"
+/** Props for the button */
+interface ButtonProps {
+  /**
+   * Text shown on the button
+   * across two lines
+   */
+  label: string;
+}
+
+/** A button that submits the surrounding form */
+export default function Button({ label }: ButtonProps) {
+  return <button>{label}</button>;
+}
+"#;
+
+        let (props, _, description) = parse_react_props(code).unwrap();
+
+        assert_eq!(
+            description.as_deref(),
+            Some("A button that submits the surrounding form")
+        );
+        assert_eq!(
+            props[0].description.as_deref(),
+            Some("Text shown on the button\nacross two lines")
+        );
+
+        // Without JSDoc on the component, the props interface describes it
+        let (_, _, description) = parse_react_props(
+            &code.replace("/** A button that submits the surrounding form */", ""),
+        )
+        .unwrap();
+        assert_eq!(description.as_deref(), Some("Props for the button"));
+    }
+
+    #[test]
+    fn test_vue_and_svelte_jsdoc_descriptions() {
+        let vue = r#"
+<script setup lang="ts">
+/** A card with a title */
+defineProps({
+  /** Heading text */
+  title: { type: String, required: true },
+})
+</script>
+"#;
+        let (props, _, description) = parse_vue_props(vue).unwrap();
+        assert_eq!(description.as_deref(), Some("A card with a title"));
+        assert_eq!(props[0].description.as_deref(), Some("Heading text"));
+
+        let svelte = r#"
+<!-- @component
+A counter that starts at `count`.
+-->
+<script lang="ts">
+  /** The starting value */
+  export let count: number = 0;
+</script>
+"#;
+        let (props, _, description) = parse_svelte_props(svelte).unwrap();
+        assert_eq!(
+            description.as_deref(),
+            Some("A counter that starts at `count`.")
+        );
+        assert_eq!(props[0].description.as_deref(), Some("The starting value"));
+    }
+
     #[tokio::test]
     async fn test_scan_all_frameworks() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,443 @@
+//! Escaping prose characters MDX would parse as code
+//!
+//! In MDX, `{` starts a JavaScript expression and `<` starts a JSX tag, so prose like
+//! "use {slug} in the URL" or "a < b" fails the build with "Unexpected token" or
+//! "Unexpected character before name". `escape_mdx` escapes those characters outside
+//! code, JSX tags and import/export blocks. Braces around JavaScript are kept: anything
+//! that parses as an expression (`{new Date().getFullYear()}`, `{Astro.props.x}`),
+//! `{/* comments */}`, and expressions starting with a name the file imports or exports
+//! (or `props`/`frontmatter`). A bare unknown name or a comma list (`{slug}`,
+//! `{red, green}`) reads as prose and is escaped.
+//!
+//! Projects opt in with `mdxEscaping.escapeOnSave`; `check_mdx_escaping` reports the
+//! same characters without changing anything.
+
+use crate::security::validate_project_path;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashSet;
+use std::path::Path;
+use swc_common::sync::Lrc;
+use swc_common::{FileName, SourceMap, Spanned};
+use swc_ecma_ast::{EsVersion, Expr};
+use swc_ecma_parser::{parse_file_as_expr, EsSyntax, Syntax};
+
+/// How escaped characters are written
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum EscapeStyle {
+    /// `\{`, `\}` and `\<`
+    #[default]
+    Backslash,
+    /// `&#123;`, `&#125;` and `&lt;`
+    Entity,
+}
+
+/// The project's `mdxEscaping` setting
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MdxEscapingSettings {
+    /// Escape problem characters in MDX files on save
+    #[serde(default)]
+    #[specta(optional)]
+    pub escape_on_save: Option<bool>,
+    /// Defaults to backslash escapes
+    #[serde(default)]
+    #[specta(optional)]
+    pub style: Option<EscapeStyle>,
+}
+
+/// A character in prose that MDX would parse as code
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnescapedCharacter {
+    /// 1-based line in the file
+    pub line: u32,
+    /// 1-based column, in characters
+    pub column: u32,
+    pub character: String,
+    pub message: String,
+}
+
+fn is_mdx_file(path: &Path) -> bool {
+    path.extension().and_then(|e| e.to_str()) == Some("mdx")
+}
+
+/// Names an expression may use: imports, exported declarations, `props` and
+/// `frontmatter`
+fn known_bindings(source: &str) -> HashSet<String> {
+    let import_re = Regex::new(r"(?m)^\s*import\s+(.+?)\s+from\s").unwrap();
+    let export_re =
+        Regex::new(r"(?m)^\s*export\s+(?:const|let|var|function|class)\s+([A-Za-z_$][\w$]*)")
+            .unwrap();
+    let identifier_re = Regex::new(r"[A-Za-z_$][\w$]*").unwrap();
+
+    let mut names: HashSet<String> = HashSet::from(["props".into(), "frontmatter".into()]);
+    for caps in import_re.captures_iter(source) {
+        // `a, { b as c }, * as d`: the local name is the last word of each specifier
+        for specifier in caps[1].split(',') {
+            if let Some(name) = identifier_re.find_iter(specifier).last() {
+                names.insert(name.as_str().to_string());
+            }
+        }
+    }
+    names.extend(export_re.captures_iter(source).map(|c| c[1].to_string()));
+    names
+}
+
+/// Parses `source` as a single JavaScript expression, failing if anything follows it
+fn parse_expression(source: &str) -> Option<Box<Expr>> {
+    let cm = SourceMap::default();
+    let fm = cm.new_source_file(Lrc::new(FileName::Anon), source.to_string());
+    let syntax = Syntax::Es(EsSyntax {
+        jsx: true,
+        ..Default::default()
+    });
+    let mut errors = vec![];
+    let expr = parse_file_as_expr(&fm, syntax, EsVersion::latest(), None, &mut errors).ok()?;
+    (errors.is_empty() && expr.span().hi == fm.end_pos).then_some(expr)
+}
+
+/// True for `{...}` contents that are meant as an expression
+fn is_intended_expression(expression: &str, bindings: &HashSet<String>) -> bool {
+    let expression = expression.trim();
+    if expression.starts_with("/*") {
+        return true;
+    }
+    let name_end = expression
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .unwrap_or(expression.len());
+    if name_end > 0 && bindings.contains(&expression[..name_end]) {
+        return true;
+    }
+    // A lone name or a comma list is more likely prose than code
+    parse_expression(expression).is_some_and(|expr| !matches!(*expr, Expr::Ident(_) | Expr::Seq(_)))
+}
+
+/// The end of the paragraph starting at or before `from`
+fn paragraph_end(text: &str, from: usize) -> usize {
+    text[from..].find("\n\n").map_or(text.len(), |i| from + i)
+}
+
+/// The byte after the `}` closing the `{` at `open`, within its paragraph
+fn closing_brace(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in text[open..paragraph_end(text, open)].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The byte after the `>` closing the tag at `open`, skipping quoted and braced
+/// attribute values
+fn closing_angle(text: &str, open: usize) -> Option<usize> {
+    let (mut depth, mut quote) = (0, None);
+    for (i, c) in text[open..paragraph_end(text, open)].char_indices().skip(1) {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') if depth == 0 => quote = Some(c),
+            (None, '{') => depth += 1,
+            (None, '}') => depth -= 1,
+            (None, '>') if depth <= 0 => return Some(open + i + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Byte offsets of `{`, `}` and `<` in `text` that MDX would parse as code
+fn find_unescaped(text: &str, bindings: &HashSet<String>) -> Vec<usize> {
+    let autolink_re = Regex::new(r"^<[A-Za-z][A-Za-z0-9+.-]*:[^\s<>]*>").unwrap();
+    let mut found = Vec::new();
+    let mut fence: Option<&str> = None;
+    let mut in_esm = false;
+    let mut pos = 0;
+
+    while pos < text.len() {
+        if pos == 0 || text.as_bytes()[pos - 1] == b'\n' {
+            let line_end = text[pos..].find('\n').map_or(text.len(), |i| pos + i + 1);
+            let trimmed = text[pos..line_end].trim();
+            let marker = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m));
+            match (fence, marker) {
+                (None, Some(m)) => fence = Some(m),
+                (Some(open), Some(m)) if open == m => fence = None,
+                _ => {}
+            }
+            // Import/export blocks run to the next blank line
+            if trimmed.starts_with("import ") || trimmed.starts_with("export ") {
+                in_esm = true;
+            } else if trimmed.is_empty() {
+                in_esm = false;
+            }
+            if fence.is_some() || marker.is_some() || in_esm {
+                pos = line_end;
+                continue;
+            }
+        }
+
+        let rest = &text[pos..];
+        let c = rest.chars().next().unwrap();
+        match c {
+            '\\' => {
+                // Already escaped; skip the escaped character too
+                pos += 1 + rest[1..].chars().next().map_or(0, char::len_utf8);
+            }
+            '`' => {
+                // An inline code span closes at the next run of the same length
+                let ticks = rest.len() - rest.trim_start_matches('`').len();
+                let run = &rest[..ticks];
+                let search_end = paragraph_end(text, pos) - pos;
+                let closing = rest[ticks..search_end]
+                    .match_indices(run)
+                    .find(|(i, _)| !rest[ticks + i + ticks..].starts_with('`'))
+                    .map(|(i, _)| ticks + i + ticks);
+                pos += closing.unwrap_or(ticks);
+            }
+            '<' => {
+                let starts_tag = rest[1..]
+                    .chars()
+                    .next()
+                    .is_some_and(|next| next.is_alphabetic() || matches!(next, '/' | '>'));
+                match closing_angle(text, pos) {
+                    Some(end) if starts_tag && !autolink_re.is_match(rest) => pos = end,
+                    _ => {
+                        found.push(pos);
+                        pos += 1;
+                    }
+                }
+            }
+            '{' => match closing_brace(text, pos) {
+                Some(end) if is_intended_expression(&text[pos + 1..end - 1], bindings) => {
+                    pos = end;
+                }
+                _ => {
+                    found.push(pos);
+                    pos += 1;
+                }
+            },
+            '}' => {
+                found.push(pos);
+                pos += 1;
+            }
+            _ => pos += c.len_utf8(),
+        }
+    }
+    found
+}
+
+fn escaped(character: u8, style: EscapeStyle) -> &'static str {
+    match (character, style) {
+        (b'{', EscapeStyle::Backslash) => "\\{",
+        (b'}', EscapeStyle::Backslash) => "\\}",
+        (_, EscapeStyle::Backslash) => "\\<",
+        (b'{', EscapeStyle::Entity) => "&#123;",
+        (b'}', EscapeStyle::Entity) => "&#125;",
+        (_, EscapeStyle::Entity) => "&lt;",
+    }
+}
+
+/// Escapes the characters in an MDX body that would otherwise be parsed as code
+///
+/// `imports` is the file's import block, for the names expressions may use.
+pub(crate) fn escape_mdx(body: &str, imports: &str, style: EscapeStyle) -> String {
+    let bindings = known_bindings(&format!("{imports}\n{body}"));
+    let mut escaped_body = String::with_capacity(body.len());
+    let mut last = 0;
+    for offset in find_unescaped(body, &bindings) {
+        escaped_body.push_str(&body[last..offset]);
+        escaped_body.push_str(escaped(body.as_bytes()[offset], style));
+        last = offset + 1;
+    }
+    escaped_body.push_str(&body[last..]);
+    escaped_body
+}
+
+/// Escapes the body before a save, if the project opts in and the file is MDX
+pub(crate) fn apply_on_save(
+    path: &Path,
+    body: String,
+    imports: &str,
+    settings: &MdxEscapingSettings,
+) -> String {
+    if settings.escape_on_save != Some(true) || !is_mdx_file(path) {
+        return body;
+    }
+    escape_mdx(&body, imports, settings.style.unwrap_or_default())
+}
+
+/// Characters in `content` that MDX would parse as code; frontmatter is skipped
+fn unescaped_characters(content: &str, imports: &str) -> Vec<UnescapedCharacter> {
+    // Skip frontmatter, counting its lines so reported lines match the file
+    let mut body_start = 0;
+    let mut line_offset = 0;
+    if content.starts_with("---\n") || content.starts_with("---\r\n") {
+        let mut offset = 0;
+        for (index, line) in content.split_inclusive('\n').enumerate() {
+            offset += line.len();
+            if index > 0 && line.trim_end() == "---" {
+                body_start = offset;
+                line_offset = index + 1;
+                break;
+            }
+        }
+    }
+    let body = &content[body_start..];
+    let bindings = known_bindings(&format!("{imports}\n{body}"));
+
+    find_unescaped(body, &bindings)
+        .into_iter()
+        .map(|offset| {
+            let before = &body[..offset];
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            let character = body.as_bytes()[offset] as char;
+            let message = match character {
+                '{' => "`{` starts a JavaScript expression in MDX; escape it as `\\{`",
+                '}' => "`}` has no matching expression in MDX; escape it as `\\}`",
+                _ => "`<` starts a JSX tag in MDX; escape it as `\\<`",
+            };
+            UnescapedCharacter {
+                line: (line_offset + before.matches('\n').count() + 1) as u32,
+                column: before[line_start..].chars().count() as u32 + 1,
+                character: character.to_string(),
+                message: message.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Reports prose characters in an MDX document that the MDX compiler would parse as
+/// code, without changing them. Plain Markdown files have none.
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `file_path` - The absolute path to the document
+/// * `content` - The document's current content; frontmatter, if included, is skipped
+/// * `imports` - The document's MDX imports, for the names expressions may use
+#[tauri::command]
+#[specta::specta]
+pub async fn check_mdx_escaping(
+    project_path: String,
+    file_path: String,
+    content: String,
+    imports: Option<String>,
+) -> Result<Vec<UnescapedCharacter>, String> {
+    let path = validate_project_path(&file_path, &project_path)?;
+    if !is_mdx_file(&path) {
+        return Ok(Vec::new());
+    }
+    Ok(unescaped_characters(
+        &content,
+        imports.as_deref().unwrap_or_default(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_mdx_leaves_code_tags_and_expressions() {
+        let body = concat!(
+            "Use {slug} in the URL when a < b.\n\n",
+            "Already \\{escaped\\} and `{code}` and ``a`{b}`` here.\n\n",
+            "<Callout type=\"tip\" data={{ a: 1 }}>Title is {frontmatter.title}</Callout>\n\n",
+            "{/* a comment */} <Chart data={points} /> <https://example.com>\n\n",
+            "```js\nconst x = { y: 1 } < 2;\n```\n\n",
+            "export const points = [1, 2]\n",
+        );
+
+        assert_eq!(
+            escape_mdx(
+                body,
+                "import Callout from './Callout.astro'",
+                EscapeStyle::Backslash
+            ),
+            concat!(
+                "Use \\{slug\\} in the URL when a \\< b.\n\n",
+                "Already \\{escaped\\} and `{code}` and ``a`{b}`` here.\n\n",
+                "<Callout type=\"tip\" data={{ a: 1 }}>Title is {frontmatter.title}</Callout>\n\n",
+                "{/* a comment */} <Chart data={points} /> \\<https://example.com>\n\n",
+                "```js\nconst x = { y: 1 } < 2;\n```\n\n",
+                "export const points = [1, 2]\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_escape_styles_and_opt_in() {
+        assert_eq!(
+            escape_mdx("a {b} <3", "", EscapeStyle::Entity),
+            "a &#123;b&#125; &lt;3"
+        );
+
+        let path = Path::new("/project/post.mdx");
+        let opted_in = MdxEscapingSettings {
+            escape_on_save: Some(true),
+            style: None,
+        };
+        assert_eq!(
+            apply_on_save(path, "a {b}".into(), "", &MdxEscapingSettings::default()),
+            "a {b}"
+        );
+        assert_eq!(
+            apply_on_save(Path::new("/project/post.md"), "a {b}".into(), "", &opted_in),
+            "a {b}"
+        );
+        assert_eq!(
+            apply_on_save(path, "a {b}".into(), "", &opted_in),
+            "a \\{b\\}"
+        );
+    }
+
+    #[test]
+    fn test_known_bindings_from_imports_and_exports() {
+        let bindings = known_bindings(
+            "import Hero, { Card as Tile } from './x'\nimport * as icons from './icons'\nexport function helper() {}\n",
+        );
+        for name in ["Hero", "Tile", "icons", "helper", "props", "frontmatter"] {
+            assert!(bindings.contains(name), "missing {name}");
+        }
+        assert!(!bindings.contains("Card"));
+    }
+
+    #[test]
+    fn test_unescaped_characters_report_file_positions() {
+        let content = "---\ntitle: {braces}\n---\n\nCosts {approx} <5\n";
+        let found = unescaped_characters(content, "");
+
+        let positions: Vec<(u32, u32, &str)> = found
+            .iter()
+            .map(|c| (c.line, c.column, c.character.as_str()))
+            .collect();
+        assert_eq!(positions, vec![(5, 7, "{"), (5, 14, "}"), (5, 16, "<")]);
+    }
+
+    #[test]
+    fn test_expressions_that_parse_are_kept() {
+        let body = concat!(
+            "© {new Date().getFullYear()} by {Astro.props.author}.\n\n",
+            "Total: {items.length * 2} {`${a}-${b}`} {cond ? 'yes' : 'no'}\n\n",
+            "Prose: {slug}, {red, green}, {not code at all}, {don't} and {unclosed\n",
+        );
+
+        assert_eq!(
+            escape_mdx(body, "", EscapeStyle::Backslash),
+            concat!(
+                "© {new Date().getFullYear()} by {Astro.props.author}.\n\n",
+                "Total: {items.length * 2} {`${a}-${b}`} {cond ? 'yes' : 'no'}\n\n",
+                "Prose: \\{slug\\}, \\{red, green\\}, \\{not code at all\\}, \\{don't\\} and \\{unclosed\n",
+            )
+        );
+    }
+}
@@ -1,19 +1,69 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
 use std::sync::Mutex;
+use tauri::menu::MenuItem;
 use tauri::Manager;
 
 use crate::MenuState;
 
+/// Prefix for Edit > Frontmatter item ids; the rest of the id is the field name
+pub const FLAG_MENU_ID_PREFIX: &str = "toggle_flag:";
+
+/// Fixed shortcuts for common flag fields
+const FLAG_SHORTCUTS: [(&str, &str); 1] = [("draft", "CmdOrCtrl+Shift+D")];
+
+/// A boolean or enum frontmatter field to offer in the Edit > Frontmatter menu
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FrontmatterFlagField {
+    /// Top-level frontmatter field name, e.g. "draft"
+    pub name: String,
+    /// Human-readable label, e.g. "Draft"
+    pub label: String,
+    /// True for enum fields, which cycle through their values instead of toggling
+    pub is_enum: bool,
+}
+
+/// Editor state reported by the frontend, used to enable or disable menu items
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MenuContext {
+    pub has_file_open: bool,
+    pub editor_focused: bool,
+    /// True if the editor has a non-empty selection
+    pub has_selection: bool,
+    /// True if a collection is selected in the sidebar
+    pub has_collection: bool,
+}
+
+/// Whether the menu item with `id` should be enabled in `context`
+///
+/// Items without a rule are always enabled.
+pub(crate) fn is_item_enabled(id: &str, context: &MenuContext) -> bool {
+    match id {
+        "format_link" => context.has_file_open && context.editor_focused && context.has_selection,
+        id if id.starts_with("format_") => context.has_file_open && context.editor_focused,
+        "save" | "print" => context.has_file_open,
+        "new_file" => context.has_collection,
+        _ => true,
+    }
+}
+
+/// Enables or disables context-dependent menu items (Format, Save, Print, New File)
+///
+/// # Arguments
+/// * `context` - Current editor state as seen by the frontend
 #[tauri::command]
 #[specta::specta]
-pub async fn update_format_menu_state(
+pub async fn update_menu_context(
     app_handle: tauri::AppHandle,
-    enabled: bool,
+    context: MenuContext,
 ) -> Result<(), String> {
     // Try to enable/disable menu items using stored references
     if let Some(menu_state) = app_handle.try_state::<Mutex<MenuState>>() {
         if let Ok(state) = menu_state.lock() {
-            for item in state.format_items.values() {
-                let _ = item.set_enabled(enabled);
+            for (id, item) in state.format_items.iter().chain(&state.context_items) {
+                let _ = item.set_enabled(is_item_enabled(id, &context));
             }
         } else {
             log::debug!("Failed to lock menu state");
@@ -24,3 +74,105 @@ pub async fn update_format_menu_state(
 
     Ok(())
 }
+
+/// Replaces the Edit > Frontmatter items with toggles for the current collection's fields
+///
+/// Selecting an item emits `menu-toggle-frontmatter-flag` with the field name.
+///
+/// # Arguments
+/// * `fields` - Boolean and enum fields of the current file's collection; empty disables
+///   the submenu
+#[tauri::command]
+#[specta::specta]
+pub async fn update_frontmatter_flag_menu(
+    app_handle: tauri::AppHandle,
+    fields: Vec<FrontmatterFlagField>,
+) -> Result<(), String> {
+    let Some(menu_state) = app_handle.try_state::<Mutex<MenuState>>() else {
+        log::debug!("Menu state not available");
+        return Ok(());
+    };
+    let state = menu_state
+        .lock()
+        .map_err(|_| "Failed to lock menu state".to_string())?;
+    let Some(submenu) = &state.frontmatter_menu else {
+        return Ok(());
+    };
+
+    let existing = submenu
+        .items()
+        .map_err(|e| format!("Failed to read frontmatter menu: {e}"))?;
+    for item in existing {
+        submenu
+            .remove(&item)
+            .map_err(|e| format!("Failed to update frontmatter menu: {e}"))?;
+    }
+
+    for field in &fields {
+        let text = if field.is_enum {
+            format!("Cycle {}", field.label)
+        } else {
+            format!("Toggle {}", field.label)
+        };
+        let shortcut = FLAG_SHORTCUTS
+            .iter()
+            .find(|(name, _)| *name == field.name)
+            .map(|(_, shortcut)| *shortcut);
+
+        let item = MenuItem::with_id(
+            &app_handle,
+            format!("{FLAG_MENU_ID_PREFIX}{}", field.name),
+            text,
+            true,
+            shortcut,
+        )
+        .map_err(|e| format!("Failed to create menu item: {e}"))?;
+        submenu
+            .append(&item)
+            .map_err(|e| format!("Failed to update frontmatter menu: {e}"))?;
+    }
+
+    submenu
+        .set_enabled(!fields.is_empty())
+        .map_err(|e| format!("Failed to update frontmatter menu: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_item_enabled() {
+        let idle = MenuContext::default();
+        assert!(!is_item_enabled("format_bold", &idle));
+        assert!(!is_item_enabled("save", &idle));
+        assert!(!is_item_enabled("new_file", &idle));
+        assert!(is_item_enabled("open_project", &idle));
+
+        let editing = MenuContext {
+            has_file_open: true,
+            editor_focused: true,
+            has_selection: false,
+            has_collection: true,
+        };
+        assert!(is_item_enabled("format_bold", &editing));
+        assert!(is_item_enabled("format_h2", &editing));
+        assert!(!is_item_enabled("format_link", &editing));
+        assert!(is_item_enabled("save", &editing));
+        assert!(is_item_enabled("print", &editing));
+        assert!(is_item_enabled("new_file", &editing));
+
+        let selecting = MenuContext {
+            has_selection: true,
+            ..editing.clone()
+        };
+        assert!(is_item_enabled("format_link", &selecting));
+
+        let sidebar_focused = MenuContext {
+            editor_focused: false,
+            ..selecting
+        };
+        assert!(!is_item_enabled("format_link", &sidebar_focused));
+        assert!(is_item_enabled("save", &sidebar_focused));
+    }
+}
@@ -0,0 +1,276 @@
+//! Merging the editor's version of a file with changes made on disk
+//!
+//! When a save is refused because the file changed since it was loaded (e.g. after a
+//! `git pull`), `merge_file_versions` combines the version the editor loaded (the
+//! base), the editor's version and the one on disk. Frontmatter is merged field by
+//! field, as for git conflicts; the body is merged line by line, so edits to different
+//! paragraphs combine cleanly and only overlapping edits are returned as conflicts.
+
+use crate::commands::files::{
+    parse_frontmatter_internal, rebuild_markdown_content_only,
+    rebuild_markdown_with_frontmatter_and_imports_ordered, rebuild_markdown_with_raw_frontmatter,
+    MarkdownContent,
+};
+use crate::commands::frontmatter_merge::{merge_frontmatter, FieldConflict};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use similar::{Algorithm, DiffOp};
+use specta::Type;
+
+/// A run of body lines in a merge
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum BodyHunk {
+    /// Lines merged without conflict
+    Merged { text: String },
+    /// Lines both versions changed differently
+    Conflict {
+        base: String,
+        mine: String,
+        theirs: String,
+    },
+}
+
+/// The result of merging two versions of a file
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FileMerge {
+    /// The merged file; `None` while anything conflicts
+    pub merged: Option<String>,
+    /// Frontmatter fields merged without conflict
+    pub frontmatter: IndexMap<String, Value>,
+    /// Frontmatter fields both versions changed differently (`ours` is the editor's
+    /// version, `theirs` the one on disk)
+    pub field_conflicts: Vec<FieldConflict>,
+    /// The body in order, as merged runs and conflicts
+    pub body_hunks: Vec<BodyHunk>,
+}
+
+/// The text after the frontmatter, imports included
+fn body_text(parsed: &MarkdownContent) -> String {
+    if parsed.imports.trim().is_empty() {
+        parsed.content.clone()
+    } else if parsed.content.is_empty() {
+        format!("{}\n", parsed.imports.trim_end_matches('\n'))
+    } else {
+        format!(
+            "{}\n\n{}",
+            parsed.imports.trim_end_matches('\n'),
+            parsed.content
+        )
+    }
+}
+
+/// For each line of `base`, the line of `other` it was kept as, if any
+fn kept_lines(base: &[&str], other: &[&str]) -> Vec<Option<usize>> {
+    let mut kept = vec![None; base.len()];
+    for op in similar::capture_diff_slices(Algorithm::Myers, base, other) {
+        if let DiffOp::Equal {
+            old_index,
+            new_index,
+            len,
+        } = op
+        {
+            for offset in 0..len {
+                kept[old_index + offset] = Some(new_index + offset);
+            }
+        }
+    }
+    kept
+}
+
+/// Three-way merges text line by line (diff3)
+///
+/// Runs of lines between those all three versions share are taken from whichever side
+/// changed them; when both sides changed a run differently, it's a conflict.
+fn merge_lines(base: &str, mine: &str, theirs: &str) -> Vec<BodyHunk> {
+    let base: Vec<&str> = base.split_inclusive('\n').collect();
+    let mine: Vec<&str> = mine.split_inclusive('\n').collect();
+    let theirs: Vec<&str> = theirs.split_inclusive('\n').collect();
+    let kept_in_mine = kept_lines(&base, &mine);
+    let kept_in_theirs = kept_lines(&base, &theirs);
+
+    fn push_merged(hunks: &mut Vec<BodyHunk>, lines: &[&str]) {
+        if lines.is_empty() {
+            return;
+        }
+        match hunks.last_mut() {
+            Some(BodyHunk::Merged { text }) => text.push_str(&lines.concat()),
+            _ => hunks.push(BodyHunk::Merged {
+                text: lines.concat(),
+            }),
+        }
+    }
+
+    let mut hunks: Vec<BodyHunk> = Vec::new();
+
+    let (mut b, mut m, mut t) = (0, 0, 0);
+    loop {
+        // The next base line both sides kept, or the end of all three
+        let stable = (b..base.len()).find_map(|i| Some((i, kept_in_mine[i]?, kept_in_theirs[i]?)));
+        let (next_b, next_m, next_t) = stable.unwrap_or((base.len(), mine.len(), theirs.len()));
+
+        let (base_run, mine_run, theirs_run) =
+            (&base[b..next_b], &mine[m..next_m], &theirs[t..next_t]);
+        if mine_run == base_run || mine_run == theirs_run {
+            push_merged(&mut hunks, theirs_run);
+        } else if theirs_run == base_run {
+            push_merged(&mut hunks, mine_run);
+        } else {
+            hunks.push(BodyHunk::Conflict {
+                base: base_run.concat(),
+                mine: mine_run.concat(),
+                theirs: theirs_run.concat(),
+            });
+        }
+
+        let Some((i, j, k)) = stable else {
+            break;
+        };
+        push_merged(&mut hunks, &base[i..=i]);
+        (b, m, t) = (i + 1, j + 1, k + 1);
+    }
+
+    hunks
+}
+
+/// Merges the editor's version of a file with the version now on disk
+///
+/// # Arguments
+/// * `base` - The file as the editor loaded it
+/// * `mine` - The file as the editor would save it (`attemptedContent` of a save conflict)
+/// * `theirs` - The file as it is on disk now (`diskContent` of a save conflict)
+#[tauri::command]
+#[specta::specta]
+pub async fn merge_file_versions(
+    base: String,
+    mine: String,
+    theirs: String,
+) -> Result<FileMerge, String> {
+    let parse = |content: &str, version: &str| {
+        parse_frontmatter_internal(content)
+            .map_err(|e| format!("Failed to parse the {version} version: {e}"))
+    };
+    let base = parse(&base, "original")?;
+    let mine = parse(&mine, "edited")?;
+    let theirs = parse(&theirs, "on-disk")?;
+
+    let (order, frontmatter, field_conflicts) = merge_frontmatter(
+        &mine.frontmatter,
+        &theirs.frontmatter,
+        Some(&base.frontmatter),
+    );
+    let body_hunks = merge_lines(&body_text(&base), &body_text(&mine), &body_text(&theirs));
+
+    let body_conflict = body_hunks
+        .iter()
+        .any(|hunk| matches!(hunk, BodyHunk::Conflict { .. }));
+    let merged = if field_conflicts.is_empty() && !body_conflict {
+        let body: String = body_hunks
+            .iter()
+            .map(|hunk| match hunk {
+                BodyHunk::Merged { text } => text.as_str(),
+                BodyHunk::Conflict { .. } => "",
+            })
+            .collect();
+
+        // Keep a side's frontmatter as written when the merge didn't change it
+        let raw = if frontmatter == mine.frontmatter {
+            Some(&mine.raw_frontmatter)
+        } else if frontmatter == theirs.frontmatter {
+            Some(&theirs.raw_frontmatter)
+        } else {
+            None
+        };
+        Some(match raw {
+            Some(raw) if raw.trim().is_empty() => rebuild_markdown_content_only("", &body)?,
+            Some(raw) => rebuild_markdown_with_raw_frontmatter(raw, "", &body)?,
+            None => rebuild_markdown_with_frontmatter_and_imports_ordered(
+                &frontmatter,
+                "",
+                &body,
+                Some(order),
+            )?,
+        })
+    } else {
+        None
+    };
+
+    Ok(FileMerge {
+        merged,
+        frontmatter,
+        field_conflicts,
+        body_hunks,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_lines() {
+        let base = "One\n\nTwo\n\nThree\n";
+        let mine = "One, edited\n\nTwo\n\nThree\n";
+        let theirs = "One\n\nTwo\n\nThree, edited\n";
+        assert_eq!(
+            merge_lines(base, mine, theirs),
+            vec![BodyHunk::Merged {
+                text: "One, edited\n\nTwo\n\nThree, edited\n".to_string()
+            }]
+        );
+
+        let theirs = "One, changed\n\nTwo\n\nThree\n";
+        assert_eq!(
+            merge_lines(base, mine, theirs),
+            vec![
+                BodyHunk::Conflict {
+                    base: "One\n".to_string(),
+                    mine: "One, edited\n".to_string(),
+                    theirs: "One, changed\n".to_string(),
+                },
+                BodyHunk::Merged {
+                    text: "\nTwo\n\nThree\n".to_string()
+                },
+            ]
+        );
+
+        // The same change on both sides isn't a conflict
+        assert_eq!(
+            merge_lines(base, mine, mine),
+            vec![BodyHunk::Merged {
+                text: mine.to_string()
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_merge_file_versions() {
+        let base = "---\ntitle: Post\ndraft: true\n---\n\nFirst.\n\nSecond.\n";
+        let mine = "---\ntitle: Better post\ndraft: true\n---\n\nFirst, expanded.\n\nSecond.\n";
+        let theirs = "---\ntitle: Post\ndraft: false\n---\n\nFirst.\n\nSecond.\n\nThird.\n";
+
+        let merge = merge_file_versions(base.into(), mine.into(), theirs.into())
+            .await
+            .unwrap();
+        assert!(merge.field_conflicts.is_empty());
+        assert_eq!(
+            merge.merged.as_deref(),
+            Some(
+                "---\ntitle: Better post\ndraft: false\n---\n\n\
+                 First, expanded.\n\nSecond.\n\nThird.\n"
+            )
+        );
+
+        // Both changed the title: the field conflicts and nothing is merged
+        let theirs = "---\ntitle: Another post\ndraft: true\n---\n\nFirst.\n\nSecond.\n";
+        let merge = merge_file_versions(base.into(), mine.into(), theirs.into())
+            .await
+            .unwrap();
+        assert_eq!(merge.merged, None);
+        assert_eq!(merge.field_conflicts.len(), 1);
+        assert_eq!(merge.field_conflicts[0].field, "title");
+        assert_eq!(merge.frontmatter.get("draft"), Some(&Value::Bool(true)));
+    }
+}
@@ -1,11 +1,79 @@
+pub mod accessibility;
+pub mod activity_report;
+pub mod asset_browser;
+pub mod asset_repair;
+pub mod asset_urls;
+pub mod audit_log;
+pub mod backup;
+pub mod bulk_undo;
 pub mod clipboard;
+pub mod code_blocks;
+pub mod coercions;
+pub mod collections;
+pub mod component_usage;
+pub mod content_calendar;
+pub mod content_graph;
+pub mod content_tracking;
+pub mod crash_reports;
+pub mod daily_notes;
+pub mod date_format;
+pub mod dev_server;
 pub mod diagnostics;
+pub mod dry_run;
+pub mod embargo;
+pub mod embeds;
+pub mod file_collections;
 pub mod files;
 pub mod fonts;
+pub mod frontmatter_errors;
+pub mod frontmatter_merge;
+pub mod git_branch;
 pub mod ide;
+pub mod image_gallery;
+pub mod import_mapping;
+pub mod intents;
+pub mod link_check;
+pub mod link_graph;
+pub mod link_refactor;
+pub mod listing_stream;
+pub mod markdown_flavor;
+pub mod math;
 pub mod mdx_components;
+pub mod mdx_escaping;
 pub mod menu;
+pub mod merge;
+pub mod natural_dates;
+pub mod orphaned_assets;
 pub mod preferences;
+pub mod preview;
+pub mod print;
+pub mod processes;
 pub mod project;
+pub mod projects;
+pub mod publish_gates;
+pub mod publishing;
+pub mod reading_time;
+pub mod recovery;
+pub mod reference_labels;
+pub mod remote_collections;
+pub mod saved_filters;
+pub mod schema_fixtures;
+pub mod scratchpads;
+pub mod search;
+pub mod slugs;
+pub mod spellcheck;
+pub mod stale_drafts;
+pub mod starter;
+pub mod tables;
+pub mod todos;
+pub mod toolbar;
+pub mod transclusion;
+pub mod transcripts;
+pub mod trash;
+pub mod unsaved_drafts;
 pub mod updater;
+pub mod usage;
+pub mod versions;
 pub mod watcher;
+pub mod window;
+pub mod zoom;
@@ -0,0 +1,279 @@
+//! Natural-language date entry
+//!
+//! Lets date fields accept phrases like "tomorrow", "next friday", "in 2 weeks",
+//! "3 days ago" or "march 5 2027" as well as ISO dates. Phrases are resolved against
+//! a reference day supplied by the frontend (today in the project's timezone), so
+//! results don't depend on the machine's clock or timezone.
+//!
+//! Weekdays: "friday" and "this friday" mean the next Friday on or after the
+//! reference day, "next friday" the first one after it and "last friday" the most
+//! recent one before it.
+
+use chrono::{Datelike, Days, Local, Months, NaiveDate, Weekday};
+
+const WEEKDAYS: [(&str, Weekday); 7] = [
+    ("monday", Weekday::Mon),
+    ("tuesday", Weekday::Tue),
+    ("wednesday", Weekday::Wed),
+    ("thursday", Weekday::Thu),
+    ("friday", Weekday::Fri),
+    ("saturday", Weekday::Sat),
+    ("sunday", Weekday::Sun),
+];
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+const NUMBER_WORDS: [&str; 13] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    "eleven", "twelve",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Unit {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// Full names and abbreviations of at least three letters ("wed", "weds", "wednesday")
+fn weekday(word: &str) -> Option<Weekday> {
+    let word = if word == "weds" { "wed" } else { word };
+    WEEKDAYS
+        .iter()
+        .find(|(name, _)| word.len() >= 3 && name.starts_with(word))
+        .map(|(_, day)| *day)
+}
+
+/// 1-based month for "march", "mar" or "sept"
+fn month(word: &str) -> Option<u32> {
+    MONTHS
+        .iter()
+        .position(|name| word.len() >= 3 && name.starts_with(word))
+        .map(|i| i as u32 + 1)
+}
+
+fn unit(word: &str) -> Option<Unit> {
+    match word.trim_end_matches('s') {
+        "day" => Some(Unit::Day),
+        "week" | "wk" => Some(Unit::Week),
+        "month" | "mo" => Some(Unit::Month),
+        "year" | "yr" => Some(Unit::Year),
+        _ => None,
+    }
+}
+
+/// "3", "three", "a" or "an"
+fn count(word: &str) -> Option<i64> {
+    match word {
+        "a" | "an" => Some(1),
+        _ => word.parse().ok().or_else(|| {
+            NUMBER_WORDS
+                .iter()
+                .position(|w| *w == word)
+                .map(|i| i as i64)
+        }),
+    }
+}
+
+/// "5", "5th", "21st"
+fn day_of_month(word: &str) -> Option<u32> {
+    word.trim_end_matches(|c: char| c.is_ascii_alphabetic())
+        .parse()
+        .ok()
+        .filter(|day| (1..=31).contains(day))
+}
+
+fn shift(date: NaiveDate, amount: i64, unit: Unit) -> Option<NaiveDate> {
+    let magnitude = amount.unsigned_abs();
+    match unit {
+        Unit::Day | Unit::Week => {
+            let days = Days::new(if unit == Unit::Week {
+                magnitude * 7
+            } else {
+                magnitude
+            });
+            if amount < 0 {
+                date.checked_sub_days(days)
+            } else {
+                date.checked_add_days(days)
+            }
+        }
+        Unit::Month | Unit::Year => {
+            let months = Months::new(
+                u32::try_from(magnitude).ok()? * if unit == Unit::Year { 12 } else { 1 },
+            );
+            if amount < 0 {
+                date.checked_sub_months(months)
+            } else {
+                date.checked_add_months(months)
+            }
+        }
+    }
+}
+
+/// Days from `from` forward to the next `target`, 0 if `from` is already `target`
+fn days_until(from: Weekday, target: Weekday) -> u64 {
+    (7 + target.num_days_from_monday() as i64 - from.num_days_from_monday() as i64) as u64 % 7
+}
+
+/// "march 5", "5 march", "5th of march", each optionally followed by a year
+fn month_day(words: &[&str], today: NaiveDate) -> Option<NaiveDate> {
+    let words: Vec<&str> = words.iter().copied().filter(|w| *w != "of").collect();
+    let (month, day, rest) = match words.as_slice() {
+        [m, d, rest @ ..] if month(m).is_some() && day_of_month(d).is_some() => {
+            (month(m)?, day_of_month(d)?, rest)
+        }
+        [d, m, rest @ ..] if month(m).is_some() && day_of_month(d).is_some() => {
+            (month(m)?, day_of_month(d)?, rest)
+        }
+        _ => return None,
+    };
+    let year = match rest {
+        [] => today.year(),
+        [year] => year.parse().ok()?,
+        _ => return None,
+    };
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+/// Resolves a date phrase against `today`
+fn parse_phrase(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let normalized = input.trim().to_lowercase().replace(',', " ");
+    if let Ok(date) = NaiveDate::parse_from_str(&normalized, "%Y-%m-%d") {
+        return Some(date);
+    }
+    let words: Vec<&str> = normalized.split_whitespace().collect();
+
+    match words.as_slice() {
+        ["today"] | ["now"] => Some(today),
+        ["tomorrow"] => today.succ_opt(),
+        ["yesterday"] => today.pred_opt(),
+        ["day", "after", "tomorrow"] => shift(today, 2, Unit::Day),
+        ["day", "before", "yesterday"] => shift(today, -2, Unit::Day),
+        ["next", w] | ["last", w] if unit(w).is_some() => {
+            let amount = if words[0] == "next" { 1 } else { -1 };
+            shift(today, amount, unit(w)?)
+        }
+        ["next", w] if weekday(w).is_some() => {
+            let ahead = days_until(today.weekday(), weekday(w)?);
+            shift(today, if ahead == 0 { 7 } else { ahead as i64 }, Unit::Day)
+        }
+        ["last", w] if weekday(w).is_some() => {
+            let behind = days_until(weekday(w)?, today.weekday());
+            shift(
+                today,
+                -(if behind == 0 { 7 } else { behind as i64 }),
+                Unit::Day,
+            )
+        }
+        [w] | ["this", w] if weekday(w).is_some() => shift(
+            today,
+            days_until(today.weekday(), weekday(w)?) as i64,
+            Unit::Day,
+        ),
+        ["in", n, u] => shift(today, count(n)?, unit(u)?),
+        [n, u, "from", "now"] | [n, u, "from", "today"] => shift(today, count(n)?, unit(u)?),
+        [n, u, "ago"] => shift(today, -count(n)?, unit(u)?),
+        ["end", "of", "week"] => shift(
+            today,
+            days_until(today.weekday(), Weekday::Sun) as i64,
+            Unit::Day,
+        ),
+        ["end", "of", "month"] => {
+            let first = NaiveDate::from_ymd_opt(today.year(), today.month(), 1)?;
+            shift(first, 1, Unit::Month)?.pred_opt()
+        }
+        ["end", "of", "year"] => NaiveDate::from_ymd_opt(today.year(), 12, 31),
+        _ => month_day(&words, today),
+    }
+}
+
+/// Parses a natural-language date into an ISO date (`YYYY-MM-DD`)
+///
+/// # Arguments
+/// * `input` - e.g. "tomorrow", "next tuesday", "in 2 weeks", "march 5"
+/// * `today` - The reference day as `YYYY-MM-DD`, i.e. today in the project's
+///   timezone; the system's local date when omitted
+#[tauri::command]
+#[specta::specta]
+pub async fn parse_natural_date(input: String, today: Option<String>) -> Result<String, String> {
+    let today = match today {
+        Some(today) => NaiveDate::parse_from_str(&today, "%Y-%m-%d")
+            .map_err(|e| format!("Invalid reference date '{today}': {e}"))?,
+        None => Local::now().date_naive(),
+    };
+
+    parse_phrase(&input, today)
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .ok_or_else(|| format!("Couldn't understand \"{}\" as a date", input.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Option<String> {
+        // A Wednesday
+        let today = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+        parse_phrase(input, today).map(|d| d.format("%Y-%m-%d").to_string())
+    }
+
+    #[test]
+    fn test_relative_days_and_units() {
+        assert_eq!(parse("Today").as_deref(), Some("2026-10-14"));
+        assert_eq!(parse("tomorrow").as_deref(), Some("2026-10-15"));
+        assert_eq!(parse("yesterday").as_deref(), Some("2026-10-13"));
+        assert_eq!(parse("day after tomorrow").as_deref(), Some("2026-10-16"));
+        assert_eq!(parse("in 2 weeks").as_deref(), Some("2026-10-28"));
+        assert_eq!(parse("in three days").as_deref(), Some("2026-10-17"));
+        assert_eq!(parse("a month ago").as_deref(), Some("2026-09-14"));
+        assert_eq!(parse("2 years from now").as_deref(), Some("2028-10-14"));
+        assert_eq!(parse("next month").as_deref(), Some("2026-11-14"));
+        assert_eq!(parse("last week").as_deref(), Some("2026-10-07"));
+        assert_eq!(parse("end of month").as_deref(), Some("2026-10-31"));
+        assert_eq!(parse("end of week").as_deref(), Some("2026-10-18"));
+    }
+
+    #[test]
+    fn test_weekdays() {
+        assert_eq!(parse("wednesday").as_deref(), Some("2026-10-14"));
+        assert_eq!(parse("this friday").as_deref(), Some("2026-10-16"));
+        assert_eq!(parse("next friday").as_deref(), Some("2026-10-16"));
+        assert_eq!(parse("next wed").as_deref(), Some("2026-10-21"));
+        assert_eq!(parse("last monday").as_deref(), Some("2026-10-12"));
+        assert_eq!(parse("last wednesday").as_deref(), Some("2026-10-07"));
+    }
+
+    #[test]
+    fn test_absolute_dates() {
+        assert_eq!(parse("2027-01-02").as_deref(), Some("2027-01-02"));
+        assert_eq!(parse("March 5").as_deref(), Some("2026-03-05"));
+        assert_eq!(parse("5th of march 2027").as_deref(), Some("2027-03-05"));
+        assert_eq!(parse("Dec 25, 2026").as_deref(), Some("2026-12-25"));
+        assert_eq!(parse("feb 30"), None);
+        assert_eq!(parse("someday"), None);
+    }
+
+    #[tokio::test]
+    async fn test_parse_natural_date_uses_reference_day() {
+        let date = parse_natural_date("tomorrow".to_string(), Some("2026-12-31".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(date, "2027-01-01");
+        assert!(parse_natural_date("soon".to_string(), None).await.is_err());
+    }
+}
@@ -0,0 +1,328 @@
+//! Finding and deleting assets nothing uses
+//!
+//! `find_orphaned_assets` resolves every local reference in the content's Markdown and
+//! MDX files (frontmatter values, links, images, `src` attributes and imports) the way
+//! `repair_asset_references` does, and reports files in the assets directory none of
+//! them point at. Templates reference assets in too many ways to resolve, so an asset
+//! whose file name appears in any other file under `src/` (components, layouts, data
+//! files) counts as used as well.
+//!
+//! `delete_orphaned_assets` checks again before moving each file to the trash, so
+//! anything referenced since the report was made is left alone.
+
+use crate::commands::asset_repair::{local_references, possible_locations};
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::trash;
+use crate::security::{check_path, validate_project_path, Scope};
+use crate::utils::path::normalize_path_for_serialization;
+use crate::utils::tsconfig::load_path_aliases;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Files under `src/` whose text is searched for asset file names
+const SOURCE_EXTENSIONS: [&str; 17] = [
+    "astro", "ts", "tsx", "js", "jsx", "mjs", "cjs", "vue", "svelte", "css", "scss", "sass",
+    "less", "json", "yaml", "yml", "toml",
+];
+
+/// An asset nothing references
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OrphanedAsset {
+    /// Path relative to the project root, e.g. `src/assets/blog/old-hero.png`
+    pub path: String,
+    #[specta(type = f64)]
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OrphanedAssetReport {
+    /// Unreferenced assets, largest first
+    pub assets: Vec<OrphanedAsset>,
+    #[specta(type = f64)]
+    pub total_bytes: u64,
+    /// Files checked in the assets directory
+    pub scanned: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OrphanedAssetDeletion {
+    /// Project-relative paths moved to the trash
+    pub deleted: Vec<String>,
+    /// Requested paths left alone because they're referenced now, or not an asset
+    pub skipped: Vec<String>,
+    #[specta(type = f64)]
+    pub freed_bytes: u64,
+}
+
+/// Visible files under `root`, skipping dot-directories like `.git`
+fn visible_files(root: &Path) -> impl Iterator<Item = PathBuf> {
+    WalkDir::new(root)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() == 0 || !entry.file_name().to_string_lossy().starts_with('.')
+        })
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.into_path())
+}
+
+/// Assets in `assets_dir` not referenced from content or mentioned in other source files
+///
+/// `project_root` and `assets_dir` must be canonical, so paths can be compared.
+fn find_orphans(
+    project_root: &Path,
+    content_root: &Path,
+    assets_dir: &Path,
+) -> OrphanedAssetReport {
+    let aliases = load_path_aliases(project_root);
+
+    let mut referenced: HashSet<PathBuf> = HashSet::new();
+    for file in visible_files(content_root) {
+        let is_markdown = matches!(
+            file.extension().and_then(|e| e.to_str()),
+            Some("md") | Some("mdx")
+        );
+        if !is_markdown {
+            continue;
+        }
+        let Ok(content) = std::fs::read_to_string(&file) else {
+            continue;
+        };
+        for reference in local_references(&content) {
+            referenced.extend(
+                possible_locations(&reference, &file, project_root, &aliases)
+                    .iter()
+                    .filter_map(|location| location.canonicalize().ok()),
+            );
+        }
+    }
+
+    // Everything else under src/ (outside the assets) is only searched for file names
+    let mut sources = String::new();
+    for file in visible_files(&project_root.join("src")) {
+        let is_source = file
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| SOURCE_EXTENSIONS.contains(&e.to_lowercase().as_str()));
+        if is_source && !file.starts_with(assets_dir) {
+            if let Ok(text) = std::fs::read_to_string(&file) {
+                sources.push_str(&text);
+                sources.push('\n');
+            }
+        }
+    }
+
+    let mut scanned = 0;
+    let mut assets = Vec::new();
+    for asset in visible_files(assets_dir) {
+        scanned += 1;
+        let name = asset.file_name().unwrap_or_default().to_string_lossy();
+        if referenced.contains(&asset) || sources.contains(name.as_ref()) {
+            continue;
+        }
+        assets.push(OrphanedAsset {
+            path: normalize_path_for_serialization(
+                asset.strip_prefix(project_root).unwrap_or(&asset),
+            ),
+            size_bytes: std::fs::metadata(&asset).map(|m| m.len()).unwrap_or(0),
+        });
+    }
+    assets.sort_by(|a, b| b.size_bytes.cmp(&a.size_bytes).then(a.path.cmp(&b.path)));
+
+    OrphanedAssetReport {
+        total_bytes: assets.iter().map(|asset| asset.size_bytes).sum(),
+        assets,
+        scanned,
+    }
+}
+
+/// The canonical project, content and assets directories
+fn project_directories(
+    project_path: &str,
+    content_directory: Option<&str>,
+    assets_directory: Option<&str>,
+) -> Result<(PathBuf, PathBuf, PathBuf), String> {
+    let project_root = Path::new(project_path)
+        .canonicalize()
+        .map_err(|_| "Invalid project root".to_string())?;
+    let content_root = project_root.join(content_directory.unwrap_or("src/content"));
+    if !content_root.is_dir() {
+        return Err(format!(
+            "Content directory not found: {}",
+            content_root.display()
+        ));
+    }
+    // A missing assets directory has nothing to report
+    let assets_dir = project_root.join(assets_directory.unwrap_or("src/assets"));
+    let assets_dir = if assets_dir.exists() {
+        check_path(&assets_dir, &project_root, Scope::Project)?
+    } else {
+        assets_dir
+    };
+    Ok((project_root, content_root, assets_dir))
+}
+
+/// Finds files in the assets directory that no content references
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+/// * `assets_directory` - Optional assets directory override
+#[tauri::command]
+#[specta::specta]
+pub async fn find_orphaned_assets(
+    project_path: String,
+    content_directory: Option<String>,
+    assets_directory: Option<String>,
+) -> Result<OrphanedAssetReport, String> {
+    let (project_root, content_root, assets_dir) = project_directories(
+        &project_path,
+        content_directory.as_deref(),
+        assets_directory.as_deref(),
+    )?;
+
+    tokio::task::spawn_blocking(move || find_orphans(&project_root, &content_root, &assets_dir))
+        .await
+        .map_err(|e| format!("Failed to find orphaned assets: {e}"))
+}
+
+/// Moves orphaned assets to the trash, skipping any that are referenced now
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+/// * `assets_directory` - Optional assets directory override
+/// * `paths` - Project-relative paths from `find_orphaned_assets` to delete
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_orphaned_assets(
+    project_path: String,
+    content_directory: Option<String>,
+    assets_directory: Option<String>,
+    paths: Vec<String>,
+    origin: Option<String>,
+) -> Result<OrphanedAssetDeletion, String> {
+    let (project_root, content_root, assets_dir) = project_directories(
+        &project_path,
+        content_directory.as_deref(),
+        assets_directory.as_deref(),
+    )?;
+
+    let report = tokio::task::spawn_blocking(move || {
+        find_orphans(&project_root, &content_root, &assets_dir)
+    })
+    .await
+    .map_err(|e| format!("Failed to find orphaned assets: {e}"))?;
+
+    let mut deletion = OrphanedAssetDeletion {
+        deleted: Vec::new(),
+        skipped: Vec::new(),
+        freed_bytes: 0,
+    };
+    for path in paths {
+        let Some(orphan) = report.assets.iter().find(|asset| asset.path == path) else {
+            deletion.skipped.push(path);
+            continue;
+        };
+        let full_path = Path::new(&project_path).join(&orphan.path);
+        let validated = validate_project_path(&full_path.to_string_lossy(), &project_path)?;
+        trash::move_to_trash(&validated, &project_path)?;
+        audit_log::record(
+            AuditAction::Delete,
+            &validated,
+            None,
+            origin.as_deref().unwrap_or("delete_orphaned_assets"),
+        );
+        deletion.freed_bytes += orphan.size_bytes;
+        deletion.deleted.push(path);
+    }
+
+    Ok(deletion)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn project() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        let blog = root.join("src/content/blog");
+        let assets = root.join("src/assets");
+        fs::create_dir_all(&blog).unwrap();
+        fs::create_dir_all(assets.join("blog")).unwrap();
+        fs::create_dir_all(assets.join("docs")).unwrap();
+        fs::create_dir_all(root.join("src/components")).unwrap();
+
+        fs::write(
+            blog.join("post.md"),
+            "---\ntitle: Post\ncover: ../../assets/blog/cover.png\n---\n\n\
+             ![Used](../../assets/blog/used.png)\n[Guide](/src/assets/docs/guide.pdf)\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/components/Header.astro"),
+            "---\nimport logo from '../assets/logo.svg';\n---\n",
+        )
+        .unwrap();
+
+        fs::write(assets.join("blog/cover.png"), "cover").unwrap();
+        fs::write(assets.join("blog/used.png"), "used").unwrap();
+        fs::write(assets.join("blog/old-hero.png"), "old hero image").unwrap();
+        fs::write(assets.join("docs/guide.pdf"), "guide").unwrap();
+        fs::write(assets.join("docs/draft.pdf"), "draft").unwrap();
+        fs::write(assets.join("logo.svg"), "<svg/>").unwrap();
+        fs::write(assets.join(".DS_Store"), "").unwrap();
+        temp
+    }
+
+    #[tokio::test]
+    async fn test_find_orphaned_assets() {
+        let temp = project();
+        let report = find_orphaned_assets(temp.path().to_string_lossy().to_string(), None, None)
+            .await
+            .unwrap();
+
+        let paths: Vec<&str> = report.assets.iter().map(|a| a.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["src/assets/blog/old-hero.png", "src/assets/docs/draft.pdf"]
+        );
+        assert_eq!(report.total_bytes, 19);
+        assert_eq!(report.scanned, 6);
+    }
+
+    #[tokio::test]
+    async fn test_delete_orphaned_assets_skips_referenced() {
+        let temp = project();
+        let project_path = temp.path().to_string_lossy().to_string();
+
+        let deletion = delete_orphaned_assets(
+            project_path,
+            None,
+            None,
+            vec![
+                "src/assets/blog/old-hero.png".to_string(),
+                "src/assets/blog/used.png".to_string(),
+            ],
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(deletion.deleted, vec!["src/assets/blog/old-hero.png"]);
+        assert_eq!(deletion.skipped, vec!["src/assets/blog/used.png"]);
+        assert_eq!(deletion.freed_bytes, 14);
+        assert!(!temp.path().join("src/assets/blog/old-hero.png").exists());
+        assert!(temp.path().join("src/assets/blog/used.png").exists());
+    }
+}
@@ -0,0 +1,158 @@
+//! Built-in Markdown preview
+//!
+//! `render_markdown_preview` renders the editor buffer to HTML with comrak, so the
+//! preview pane works without the project's dev server. Raw HTML and MDX components
+//! are omitted and `javascript:` style links are dropped, so the result is safe to
+//! inject into the main window. Code blocks are highlighted with syntect's bundled
+//! syntaxes, using inline styles for a light or dark theme.
+
+use crate::commands::files::parse_frontmatter_internal;
+use comrak::plugins::syntect::SyntectAdapter;
+use comrak::{markdown_to_html_with_plugins, Options, Plugins};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::sync::OnceLock;
+
+const LIGHT_THEME: &str = "InspiredGitHub";
+const DARK_THEME: &str = "base16-ocean.dark";
+
+static LIGHT_HIGHLIGHTER: OnceLock<SyntectAdapter> = OnceLock::new();
+static DARK_HIGHLIGHTER: OnceLock<SyntectAdapter> = OnceLock::new();
+
+/// A rendered preview of a document
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkdownPreview {
+    /// The frontmatter title, if the document has one
+    pub title: Option<String>,
+    /// Sanitized HTML for the body, headed by the title unless the body opens with
+    /// its own `#` heading
+    pub html: String,
+}
+
+fn highlighter(dark: bool) -> &'static SyntectAdapter {
+    // Loading syntect's syntaxes and themes takes a while, so do it once per theme
+    if dark {
+        DARK_HIGHLIGHTER.get_or_init(|| SyntectAdapter::new(Some(DARK_THEME)))
+    } else {
+        LIGHT_HIGHLIGHTER.get_or_init(|| SyntectAdapter::new(Some(LIGHT_THEME)))
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn preview_options(smart_punctuation: bool) -> Options<'static> {
+    let mut options = Options::default();
+    options.extension.table = true;
+    options.extension.footnotes = true;
+    options.extension.strikethrough = true;
+    options.extension.tasklist = true;
+    options.extension.autolink = true;
+    options.extension.tagfilter = true;
+    options.parse.smart = smart_punctuation;
+    options
+}
+
+/// Whether the body's first content is a level 1 ATX heading
+fn opens_with_heading(body: &str) -> bool {
+    body.lines()
+        .find(|line| !line.trim().is_empty())
+        .is_some_and(|line| line.trim_start().starts_with("# "))
+}
+
+fn render(body: &str, title: Option<&str>, smart_punctuation: bool, dark: bool) -> String {
+    let options = preview_options(smart_punctuation);
+    let mut plugins = Plugins::default();
+    plugins.render.codefence_syntax_highlighter = Some(highlighter(dark));
+
+    let html = markdown_to_html_with_plugins(body, &options, &plugins);
+    match title.filter(|_| !opens_with_heading(body)) {
+        Some(title) => format!("<h1>{}</h1>\n{html}", escape_html(title)),
+        None => html,
+    }
+}
+
+/// Renders a document to HTML for the preview pane
+///
+/// # Arguments
+/// * `content` - The document body, or the whole file when `frontmatter` is omitted
+/// * `frontmatter` - The editor's current frontmatter; parsed from `content` if omitted
+/// * `title_field` - Frontmatter field holding the title (defaults to `title`)
+/// * `smart_punctuation` - Whether to render curly quotes, dashes and ellipses
+/// * `dark` - Whether to highlight code for a dark background
+#[tauri::command]
+#[specta::specta]
+pub async fn render_markdown_preview(
+    content: String,
+    frontmatter: Option<IndexMap<String, Value>>,
+    title_field: Option<String>,
+    smart_punctuation: bool,
+    dark: bool,
+) -> Result<MarkdownPreview, String> {
+    let (frontmatter, body) = match frontmatter {
+        Some(frontmatter) => (frontmatter, content),
+        None => {
+            let parsed = parse_frontmatter_internal(&content)?;
+            (parsed.frontmatter, parsed.content)
+        }
+    };
+    let title = frontmatter
+        .get(title_field.as_deref().unwrap_or("title"))
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|title| !title.is_empty())
+        .map(str::to_string);
+
+    tokio::task::spawn_blocking(move || {
+        let html = render(&body, title.as_deref(), smart_punctuation, dark);
+        MarkdownPreview { title, html }
+    })
+    .await
+    .map_err(|e| format!("Failed to render preview: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_render_markdown_preview_from_file() {
+        let content = "---\ntitle: Tips & Tricks\n---\n\nSee the table[^1].\n\n\
+                       | A | B |\n|---|---|\n| 1 | 2 |\n\n[^1]: Below.\n";
+        let preview = render_markdown_preview(content.to_string(), None, None, false, false)
+            .await
+            .unwrap();
+
+        assert_eq!(preview.title.as_deref(), Some("Tips & Tricks"));
+        assert!(preview.html.starts_with("<h1>Tips &amp; Tricks</h1>"));
+        assert!(preview.html.contains("<table>"));
+        assert!(preview.html.contains("footnote"));
+        assert!(!preview.html.contains("title:"));
+    }
+
+    #[test]
+    fn test_render_is_sanitized_and_highlighted() {
+        let body = "# Heading\n\n<script>alert(1)</script>\n\n\
+                    [link](javascript:alert(1))\n\n```rust\nfn main() {}\n```\n";
+        let html = render(body, Some("Title"), false, false);
+
+        // The body's own heading stands in for the title
+        assert!(!html.contains("<h1>Title</h1>"));
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("javascript:"));
+        assert!(html.contains("style=\""));
+    }
+
+    #[test]
+    fn test_smart_punctuation() {
+        assert!(render("\"Quoted\" -- text...", None, true, false).contains('\u{201c}'));
+        assert!(render("\"Quoted\"", None, false, false).contains("&quot;Quoted&quot;"));
+    }
+}
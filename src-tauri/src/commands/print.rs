@@ -0,0 +1,209 @@
+//! Printing the current document
+//!
+//! The frontend renders the document body to HTML; this module wraps it in a print
+//! stylesheet with the configured page header and footer, serves it from the
+//! `print-document://` protocol to a hidden window, and opens the native print dialog
+//! once the page has loaded. Printing the main window would print the editor UI.
+
+use chrono::Local;
+use std::sync::{Arc, Mutex};
+use tauri::http::{header, Request, Response, StatusCode};
+use tauri::webview::PageLoadEvent;
+use tauri::{Manager, Url, WebviewUrl, WebviewWindowBuilder};
+
+/// URI scheme the print window loads its page from
+pub const SCHEME: &str = "print-document";
+
+/// Label of the hidden window used for printing
+const PRINT_WINDOW_LABEL: &str = "print";
+
+/// The page most recently prepared for printing
+pub type PrintPage = Arc<Mutex<Option<String>>>;
+
+const PRINT_STYLES: &str = r#"
+@page { margin: 2cm 1.8cm; }
+body { font: 11pt/1.6 -apple-system, "Segoe UI", Helvetica, Arial, sans-serif; color: #111; margin: 0; }
+.page-header, .page-footer { position: fixed; left: 0; right: 0; font-size: 8pt; color: #666; }
+.page-header { top: -1.2cm; }
+.page-footer { bottom: -1.2cm; text-align: right; }
+h1, h2, h3, h4 { line-height: 1.25; page-break-after: avoid; }
+img { max-width: 100%; }
+pre { white-space: pre-wrap; font-size: 9pt; background: #f5f5f5; padding: 0.6em; }
+code { font-family: ui-monospace, Menlo, Consolas, monospace; }
+blockquote { margin-left: 0; padding-left: 1em; border-left: 3px solid #ccc; color: #444; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #ccc; padding: 0.3em 0.6em; }
+pre, blockquote, table, img { page-break-inside: avoid; }
+"#;
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Substitutes `{title}` and `{date}` in a header/footer template
+fn expand_template(template: &str, title: &str, date: &str) -> String {
+    template.replace("{title}", title).replace("{date}", date)
+}
+
+/// Builds the printable page
+///
+/// # Arguments
+/// * `title` - Document title, used for `<title>` and `{title}`
+/// * `body_html` - Rendered document body
+/// * `header` / `footer` - Templates for the running header/footer; empty omits them
+/// * `date` - Value for `{date}`
+fn build_print_html(
+    title: &str,
+    body_html: &str,
+    header: &str,
+    footer: &str,
+    date: &str,
+) -> String {
+    let running = |class: &str, template: &str| {
+        let text = expand_template(template, title, date);
+        if text.trim().is_empty() {
+            String::new()
+        } else {
+            format!("<div class=\"{class}\">{}</div>\n", escape_html(&text))
+        }
+    };
+
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+         <style>{PRINT_STYLES}</style>\n</head>\n<body>\n{}{}<main>\n{body_html}\n</main>\n\
+         </body>\n</html>\n",
+        escape_html(title),
+        running("page-header", header),
+        running("page-footer", footer),
+    )
+}
+
+/// URL of the print page; custom schemes are served from `http://<scheme>.localhost`
+/// on Windows
+fn print_page_url() -> Url {
+    let url = if cfg!(windows) {
+        format!("http://{SCHEME}.localhost/")
+    } else {
+        format!("{SCHEME}://localhost/")
+    };
+    Url::parse(&url).expect("print page URL is valid")
+}
+
+/// Serves the prepared print page
+pub fn handle_request(_request: &Request<Vec<u8>>, page: &PrintPage) -> Response<Vec<u8>> {
+    let body = page.lock().ok().and_then(|page| page.clone());
+    match body {
+        Some(html) => Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "text/html; charset=utf-8")
+            .body(html.into_bytes())
+            .unwrap(),
+        None => Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(b"Nothing to print".to_vec())
+            .unwrap(),
+    }
+}
+
+/// Prints a rendered document through the native print dialog
+///
+/// # Arguments
+/// * `title` - Document title
+/// * `body_html` - The document body rendered to HTML
+/// * `header` - Running header template (`{title}`, `{date}`); empty for none
+/// * `footer` - Running footer template (`{title}`, `{date}`); empty for none
+#[tauri::command]
+#[specta::specta]
+pub async fn print_current_document(
+    app: tauri::AppHandle,
+    page: tauri::State<'_, PrintPage>,
+    title: String,
+    body_html: String,
+    header: String,
+    footer: String,
+) -> Result<(), String> {
+    let date = Local::now().format("%-d %B %Y").to_string();
+    let html = build_print_html(&title, &body_html, &header, &footer, &date);
+    *page
+        .lock()
+        .map_err(|e| format!("Failed to prepare print page: {e}"))? = Some(html);
+
+    // Each print gets a fresh window so it loads the new page
+    if let Some(existing) = app.get_webview_window(PRINT_WINDOW_LABEL) {
+        existing
+            .destroy()
+            .map_err(|e| format!("Failed to close print window: {e}"))?;
+    }
+
+    WebviewWindowBuilder::new(
+        &app,
+        PRINT_WINDOW_LABEL,
+        WebviewUrl::CustomProtocol(print_page_url()),
+    )
+    .title(&title)
+    .visible(false)
+    .on_page_load(|window, payload| {
+        if payload.event() == PageLoadEvent::Finished {
+            if let Err(e) = window.print() {
+                log::warn!("Astro Editor [PRINT] Failed to open print dialog: {e}");
+            }
+        }
+    })
+    .build()
+    .map_err(|e| format!("Failed to open print window: {e}"))?;
+
+    log::info!("Astro Editor [PRINT] Printing \"{title}\"");
+    Ok(())
+}
+
+// Initialize the print page state when the app starts
+pub fn init_print_page() -> PrintPage {
+    Arc::new(Mutex::new(None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_print_html() {
+        let html = build_print_html(
+            "Fish & Chips",
+            "<p>Body</p>",
+            "{title}",
+            "Printed {date}",
+            "16 October 2026",
+        );
+
+        assert!(html.contains("<title>Fish &amp; Chips</title>"));
+        assert!(html.contains("<div class=\"page-header\">Fish &amp; Chips</div>"));
+        assert!(html.contains("<div class=\"page-footer\">Printed 16 October 2026</div>"));
+        assert!(html.contains("<main>\n<p>Body</p>\n</main>"));
+
+        let bare = build_print_html("Title", "<p>Body</p>", "", "  ", "today");
+        assert!(!bare.contains("page-header\">"));
+        assert!(!bare.contains("page-footer\">"));
+    }
+
+    #[test]
+    fn test_handle_request_serves_prepared_page() {
+        let page = init_print_page();
+        let request = Request::builder()
+            .uri("print-document://localhost/")
+            .body(Vec::new())
+            .unwrap();
+
+        assert_eq!(
+            handle_request(&request, &page).status(),
+            StatusCode::NOT_FOUND
+        );
+
+        *page.lock().unwrap() = Some("<p>Hi</p>".to_string());
+        let response = handle_request(&request, &page);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.body(), b"<p>Hi</p>");
+    }
+}
@@ -0,0 +1,451 @@
+//! Lifecycle management for spawned processes
+//!
+//! Long-running children such as `npm install` and `astro sync` are started with
+//! [`spawn_managed`], which gives each its own process group and registers it so the
+//! frontend can list and stop it. While a child is awaited it is held to a maximum
+//! runtime and, on macOS and Linux, a memory ceiling; a child over either limit is
+//! terminated along with anything it started. Whatever is still running when the app
+//! exits is terminated too, so nothing keeps ports or CPU busy after the editor quits.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::BTreeMap;
+use std::process::ExitStatus;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+use tokio::process::{Child, Command};
+use tokio::sync::Notify;
+
+/// How often a running child's memory use is checked
+const MEMORY_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long a terminated child gets to exit before it is killed outright
+const TERMINATE_GRACE: Duration = Duration::from_secs(5);
+
+/// Memory ceiling for managed processes, in megabytes
+pub(crate) const DEFAULT_MAX_MEMORY_MB: u32 = 4096;
+
+/// Limits a managed process is held to while it is awaited
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ProcessLimits {
+    pub max_runtime: Duration,
+    pub max_memory_mb: u32,
+}
+
+impl ProcessLimits {
+    pub(crate) fn minutes(minutes: u64) -> Self {
+        Self {
+            max_runtime: Duration::from_secs(minutes * 60),
+            max_memory_mb: DEFAULT_MAX_MEMORY_MB,
+        }
+    }
+}
+
+/// A running process started by the editor
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedProcessInfo {
+    /// Registry ID, for `kill_managed_process`
+    pub id: u32,
+    pub pid: u32,
+    /// What the process is for, e.g. "astro sync"
+    pub label: String,
+    /// The program and its arguments
+    pub command: String,
+    pub working_directory: Option<String>,
+    /// RFC 3339 UTC start time
+    pub started_at: String,
+    pub runtime_seconds: u32,
+    pub max_runtime_seconds: u32,
+    /// Resident memory of the process and its children; unavailable on Windows
+    pub memory_mb: Option<u32>,
+    pub max_memory_mb: u32,
+}
+
+struct ManagedEntry {
+    pid: u32,
+    label: String,
+    command: String,
+    working_directory: Option<String>,
+    started_at: DateTime<Utc>,
+    started: Instant,
+    limits: ProcessLimits,
+    stop: Arc<Notify>,
+}
+
+/// Processes started with `spawn_managed` that haven't exited yet
+#[derive(Default)]
+pub struct ProcessRegistry {
+    next_id: u32,
+    processes: BTreeMap<u32, ManagedEntry>,
+}
+
+impl ProcessRegistry {
+    /// Number of managed processes still running
+    pub(crate) fn running_count(&self) -> usize {
+        self.processes.len()
+    }
+}
+
+// Registry of running child processes
+pub type ProcessRegistryState = Arc<Mutex<ProcessRegistry>>;
+
+/// A child registered with the process registry; it is unregistered when dropped,
+/// and killed if still running
+pub(crate) struct ManagedChild {
+    id: u32,
+    pid: u32,
+    label: String,
+    limits: ProcessLimits,
+    started: Instant,
+    stop: Arc<Notify>,
+    registry: ProcessRegistryState,
+    pub child: Child,
+}
+
+impl ManagedChild {
+    /// A handle that stops the child, for stopping it while another task awaits it
+    pub(crate) fn stop_handle(&self) -> Arc<Notify> {
+        self.stop.clone()
+    }
+
+    /// Waits for the child to exit, terminating it when it is stopped from the
+    /// frontend or goes over its limits
+    pub(crate) async fn wait(&mut self) -> Result<ExitStatus, String> {
+        let deadline = tokio::time::Instant::from_std(self.started + self.limits.max_runtime);
+        let mut memory_check = tokio::time::interval(MEMORY_CHECK_INTERVAL);
+
+        let reason = loop {
+            tokio::select! {
+                status = self.child.wait() => {
+                    return status.map_err(|e| format!("Failed to wait for {}: {e}", self.label));
+                }
+                _ = self.stop.notified() => break "it was stopped".to_string(),
+                _ = tokio::time::sleep_until(deadline) => {
+                    break format!(
+                        "it ran for more than {}",
+                        describe_duration(self.limits.max_runtime)
+                    );
+                }
+                _ = memory_check.tick() => {
+                    let pid = self.pid;
+                    let memory = tokio::task::spawn_blocking(move || resident_memory_mb(pid))
+                        .await
+                        .ok()
+                        .flatten();
+                    if let Some(memory) = memory.filter(|mb| *mb > self.limits.max_memory_mb) {
+                        break format!("it used {memory} MB of memory");
+                    }
+                }
+            }
+        };
+
+        log::warn!(
+            "Astro Editor [PROCESSES] Terminating {} (pid {}): {reason}",
+            self.label,
+            self.pid
+        );
+        terminate_tree(self.pid);
+        if tokio::time::timeout(TERMINATE_GRACE, self.child.wait())
+            .await
+            .is_err()
+        {
+            let _ = self.child.kill().await;
+        }
+        Err(format!("{} was terminated because {reason}", self.label))
+    }
+}
+
+impl Drop for ManagedChild {
+    fn drop(&mut self) {
+        if let Ok(mut registry) = self.registry.lock() {
+            registry.processes.remove(&self.id);
+        }
+    }
+}
+
+fn describe_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let (amount, unit) = if seconds >= 60 && seconds % 60 == 0 {
+        (seconds / 60, "minute")
+    } else {
+        (seconds, "second")
+    };
+    format!("{amount} {unit}{}", if amount == 1 { "" } else { "s" })
+}
+
+/// Spawns `command` under the app's process registry
+pub(crate) fn spawn_managed(
+    app: &AppHandle,
+    label: &str,
+    command: Command,
+    limits: ProcessLimits,
+) -> Result<ManagedChild, String> {
+    let registry = app.state::<ProcessRegistryState>().inner().clone();
+    spawn_in_registry(registry, label, command, limits)
+}
+
+fn spawn_in_registry(
+    registry: ProcessRegistryState,
+    label: &str,
+    mut command: Command,
+    limits: ProcessLimits,
+) -> Result<ManagedChild, String> {
+    // Own process group, so terminating it also ends whatever the child started
+    #[cfg(unix)]
+    command.process_group(0);
+    command.kill_on_drop(true);
+
+    let std_command = command.as_std();
+    let description = std::iter::once(std_command.get_program())
+        .chain(std_command.get_args())
+        .map(|part| part.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let working_directory = std_command
+        .get_current_dir()
+        .map(|dir| dir.to_string_lossy().to_string());
+
+    let child = command
+        .spawn()
+        .map_err(|e| format!("Failed to run {label}: {e}"))?;
+    let pid = child
+        .id()
+        .ok_or_else(|| format!("{label} exited before it could be tracked"))?;
+    let stop = Arc::new(Notify::new());
+    let started = Instant::now();
+
+    let id = {
+        let mut registry = registry
+            .lock()
+            .map_err(|e| format!("Failed to lock process registry: {e}"))?;
+        registry.next_id += 1;
+        let id = registry.next_id;
+        registry.processes.insert(
+            id,
+            ManagedEntry {
+                pid,
+                label: label.to_string(),
+                command: description,
+                working_directory,
+                started_at: Utc::now(),
+                started,
+                limits,
+                stop: stop.clone(),
+            },
+        );
+        id
+    };
+    log::info!("Astro Editor [PROCESSES] Started {label} (pid {pid})");
+
+    Ok(ManagedChild {
+        id,
+        pid,
+        label: label.to_string(),
+        limits,
+        started,
+        stop,
+        registry,
+        child,
+    })
+}
+
+/// Asks a process and everything in its process group to exit
+fn terminate_tree(pid: u32) {
+    #[cfg(unix)]
+    let result = std::process::Command::new("kill")
+        .args(["-TERM", "--", &format!("-{pid}")])
+        .status();
+    #[cfg(windows)]
+    let result = std::process::Command::new("taskkill")
+        .args(["/PID", &pid.to_string(), "/T", "/F"])
+        .status();
+    #[cfg(not(any(unix, windows)))]
+    let result: std::io::Result<std::process::ExitStatus> =
+        Err(std::io::ErrorKind::Unsupported.into());
+
+    if let Err(e) = result {
+        log::warn!("Astro Editor [PROCESSES] Failed to terminate pid {pid}: {e}");
+    }
+}
+
+/// Resident memory of a process group, in megabytes
+#[cfg(unix)]
+fn resident_memory_mb(pgid: u32) -> Option<u32> {
+    let output = std::process::Command::new("ps")
+        .args(["-A", "-o", "pgid=,rss="])
+        .output()
+        .ok()?;
+    let kilobytes: u64 = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let mut columns = line.split_whitespace();
+            let group: u32 = columns.next()?.parse().ok()?;
+            let rss: u64 = columns.next()?.parse().ok()?;
+            (group == pgid).then_some(rss)
+        })
+        .sum();
+    (kilobytes > 0).then_some((kilobytes / 1024) as u32)
+}
+
+#[cfg(not(unix))]
+fn resident_memory_mb(_pgid: u32) -> Option<u32> {
+    None
+}
+
+/// Terminates every managed process; called when the app exits
+pub fn terminate_all(registry: &ProcessRegistryState) {
+    let Ok(registry) = registry.lock() else {
+        return;
+    };
+    for entry in registry.processes.values() {
+        log::info!(
+            "Astro Editor [PROCESSES] Terminating {} (pid {}) on exit",
+            entry.label,
+            entry.pid
+        );
+        terminate_tree(entry.pid);
+    }
+}
+
+/// Lists processes the editor started that are still running
+#[tauri::command]
+#[specta::specta]
+pub async fn list_managed_processes(
+    registry: tauri::State<'_, ProcessRegistryState>,
+) -> Result<Vec<ManagedProcessInfo>, String> {
+    let processes: Vec<ManagedProcessInfo> = {
+        let registry = registry
+            .lock()
+            .map_err(|e| format!("Failed to lock process registry: {e}"))?;
+        registry
+            .processes
+            .iter()
+            .map(|(id, entry)| ManagedProcessInfo {
+                id: *id,
+                pid: entry.pid,
+                label: entry.label.clone(),
+                command: entry.command.clone(),
+                working_directory: entry.working_directory.clone(),
+                started_at: entry.started_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+                runtime_seconds: entry.started.elapsed().as_secs() as u32,
+                max_runtime_seconds: entry.limits.max_runtime.as_secs() as u32,
+                memory_mb: None,
+                max_memory_mb: entry.limits.max_memory_mb,
+            })
+            .collect()
+    };
+
+    tokio::task::spawn_blocking(move || {
+        processes
+            .into_iter()
+            .map(|process| ManagedProcessInfo {
+                memory_mb: resident_memory_mb(process.pid),
+                ..process
+            })
+            .collect()
+    })
+    .await
+    .map_err(|e| format!("Failed to read process memory: {e}"))
+}
+
+/// Terminates a process the editor started, along with any processes it started
+///
+/// # Arguments
+/// * `id` - The registry ID from `list_managed_processes`
+#[tauri::command]
+#[specta::specta]
+pub async fn kill_managed_process(
+    registry: tauri::State<'_, ProcessRegistryState>,
+    id: u32,
+) -> Result<(), String> {
+    let registry = registry
+        .lock()
+        .map_err(|e| format!("Failed to lock process registry: {e}"))?;
+    let entry = registry
+        .processes
+        .get(&id)
+        .ok_or_else(|| format!("No running process with ID {id}"))?;
+    // The task awaiting the child terminates it and reports why
+    entry.stop.notify_one();
+    Ok(())
+}
+
+// Initialize the process registry when the app starts
+pub fn init_process_registry() -> ProcessRegistryState {
+    Arc::new(Mutex::new(ProcessRegistry::default()))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn sleep_command(seconds: &str) -> Command {
+        let mut command = Command::new("sleep");
+        command.arg(seconds);
+        command
+    }
+
+    #[tokio::test]
+    async fn test_managed_child_registers_and_exits() {
+        let registry = init_process_registry();
+        let mut child = spawn_in_registry(
+            registry.clone(),
+            "sleep",
+            sleep_command("0"),
+            ProcessLimits::minutes(1),
+        )
+        .unwrap();
+
+        {
+            let registry = registry.lock().unwrap();
+            let entry = registry.processes.get(&1).unwrap();
+            assert_eq!(entry.command, "sleep 0");
+            assert_eq!(entry.pid, child.pid);
+        }
+        assert!(child.wait().await.unwrap().success());
+
+        drop(child);
+        assert!(registry.lock().unwrap().processes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_managed_child_is_terminated() {
+        let registry = init_process_registry();
+
+        let limits = ProcessLimits {
+            max_runtime: Duration::from_secs(1),
+            max_memory_mb: DEFAULT_MAX_MEMORY_MB,
+        };
+        let mut child =
+            spawn_in_registry(registry.clone(), "sleep", sleep_command("30"), limits).unwrap();
+        let err = child.wait().await.unwrap_err();
+        assert_eq!(
+            err,
+            "sleep was terminated because it ran for more than 1 second"
+        );
+
+        let mut child = spawn_in_registry(
+            registry.clone(),
+            "sleep",
+            sleep_command("30"),
+            ProcessLimits::minutes(1),
+        )
+        .unwrap();
+        // Stopping before the child is awaited is remembered
+        registry
+            .lock()
+            .unwrap()
+            .processes
+            .get(&child.id)
+            .unwrap()
+            .stop
+            .notify_one();
+        let started = Instant::now();
+        let err = child.wait().await.unwrap_err();
+        assert_eq!(err, "sleep was terminated because it was stopped");
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+}
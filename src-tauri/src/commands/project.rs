@@ -1,9 +1,18 @@
+use crate::commands::file_collections;
+use crate::commands::projects;
+use crate::commands::reading_time;
+use crate::data_store::StoredEntries;
+use crate::field_docs::{find_field_docs, FieldDocs};
 use crate::models::{Collection, DirectoryInfo, FileEntry};
 use crate::parser::parse_astro_config;
-use crate::schema_merger;
+use crate::schema_merger::{self, FieldGroupSettings};
+use crate::security::{check_path, Scope};
+use crate::utils::collation::{self, Collation};
+use crate::utils::glob::EntryPatterns;
 use log::{debug, error, info, warn};
 use serde::{Deserialize, Serialize};
 use specta::Type;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use tauri::Emitter;
 
@@ -13,6 +22,18 @@ pub struct DirectoryScanResult {
     pub files: Vec<FileEntry>,
 }
 
+/// A directory in a collection with everything under it, from `scan_collection_tree`
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct DirectoryTree {
+    /// `None` for the collection root
+    pub directory: Option<DirectoryInfo>,
+    pub files: Vec<FileEntry>,
+    pub subdirectories: Vec<DirectoryTree>,
+    /// Past the requested depth, so `files` and `subdirectories` weren't scanned
+    pub truncated: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct RustToastEvent {
     r#type: String,
@@ -22,7 +43,7 @@ struct RustToastEvent {
 }
 
 /// Send a toast notification to the frontend
-fn send_toast_notification(
+pub(crate) fn send_toast_notification(
     app: &tauri::AppHandle,
     toast_type: &str,
     message: &str,
@@ -40,7 +61,7 @@ fn send_toast_notification(
 }
 
 /// Check if a directory path is in the blocked/dangerous list
-fn is_blocked_directory(path: &Path) -> bool {
+pub(crate) fn is_blocked_directory(path: &Path) -> bool {
     let path_str = path.to_string_lossy();
     // Normalize path for consistent comparison (handle both / and \ separators)
     let mut normalized_path = path_str.replace('\\', "/");
@@ -172,14 +193,19 @@ pub async fn select_project_folder(app: tauri::AppHandle) -> Result<Option<Strin
 #[specta::specta]
 pub async fn scan_project(project_path: String) -> Result<Vec<Collection>, String> {
     info!("Astro Editor [PROJECT_SCAN] Scanning project at path: {project_path}");
-    scan_project_with_content_dir(project_path, None).await
+    // Use the content directory saved for the project, if any
+    let content_directory = projects::path_settings(&project_path).content_directory;
+    scan_project_with_content_dir(project_path, content_directory, None).await
 }
 
+/// Scans a project's collections, with `field_groups` (the project's `fieldGroups`
+/// setting) deciding the order and collapsed state of schema field groups
 #[tauri::command]
 #[specta::specta]
 pub async fn scan_project_with_content_dir(
     project_path: String,
     content_directory: Option<String>,
+    field_groups: Option<FieldGroupSettings>,
 ) -> Result<Vec<Collection>, String> {
     info!("Astro Editor [PROJECT_SCAN] Scanning project at path: {project_path}");
     info!(
@@ -213,7 +239,7 @@ pub async fn scan_project_with_content_dir(
 
             // Generate complete schema for each collection
             for collection in &mut collections {
-                generate_complete_schema(collection);
+                generate_complete_schema(collection, field_groups.as_ref());
             }
 
             Ok(collections)
@@ -238,7 +264,7 @@ pub async fn scan_project_with_content_dir(
 
             // Generate complete schema for each collection
             for collection in &mut collections {
-                generate_complete_schema(collection);
+                generate_complete_schema(collection, field_groups.as_ref());
             }
 
             Ok(collections)
@@ -263,7 +289,7 @@ pub async fn scan_project_with_content_dir(
 
             // Generate complete schema for each collection
             for collection in &mut collections {
-                generate_complete_schema(collection);
+                generate_complete_schema(collection, field_groups.as_ref());
             }
 
             Ok(collections)
@@ -287,13 +313,23 @@ fn load_json_schema_for_collection(
     std::fs::read_to_string(&schema_path).map_err(|e| format!("Failed to read JSON schema: {e}"))
 }
 
-/// Generate complete schema by merging JSON schema and Zod schema
-fn generate_complete_schema(collection: &mut Collection) {
-    match schema_merger::create_complete_schema(
-        &collection.name,
-        collection.json_schema.as_deref(),
-        collection.schema.as_deref(),
-    ) {
+/// Generate complete schema by merging JSON schema and Zod schema, or inferring one
+/// from the collection's entries when neither exists
+fn generate_complete_schema(
+    collection: &mut Collection,
+    field_groups: Option<&FieldGroupSettings>,
+) {
+    let schema = if collection.json_schema.is_none() && collection.schema.is_none() {
+        schema_merger::infer_schema_from_content(&collection.name, &collection.path, field_groups)
+    } else {
+        schema_merger::create_complete_schema(
+            &collection.name,
+            collection.json_schema.as_deref(),
+            collection.schema.as_deref(),
+            field_groups,
+        )
+    };
+    match schema {
         Ok(complete_schema) => match serde_json::to_string(&complete_schema) {
             Ok(serialized) => {
                 debug!(
@@ -374,9 +410,111 @@ fn scan_content_directories_with_override(
     Ok(collections)
 }
 
+/// Orders files by title (the `title_field` frontmatter value, or the file name when
+/// there isn't one), then by file name and id so the order is the same on every scan
+pub(crate) fn sort_file_entries(
+    files: &mut [FileEntry],
+    title_field: Option<&str>,
+    collation: Collation,
+) {
+    let title_field = title_field.unwrap_or("title");
+    let title = |file: &FileEntry| -> String {
+        file.frontmatter
+            .as_ref()
+            .and_then(|frontmatter| frontmatter.get(title_field))
+            .and_then(|value| value.as_str())
+            .map(str::trim)
+            .filter(|title| !title.is_empty())
+            .unwrap_or(&file.name)
+            .to_string()
+    };
+    files.sort_by(|a, b| {
+        collation::compare(&title(a), &title(b), collation)
+            .then_with(|| collation::compare(&a.name, &b.name, collation))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+}
+
+/// Frontmatter fields listings read an entry's title and draft status from
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ListingFields<'a> {
+    title: &'a str,
+    draft: &'a str,
+}
+
+impl<'a> ListingFields<'a> {
+    pub(crate) fn new(title_field: Option<&'a str>, draft_field: Option<&'a str>) -> Self {
+        Self {
+            title: title_field.unwrap_or("title"),
+            draft: draft_field.unwrap_or("draft"),
+        }
+    }
+}
+
+/// Adds listing frontmatter and stats to a file entry
+///
+/// Frontmatter is the data Astro resolved for the entry (with schema defaults applied)
+/// when the data store is current for the file, otherwise the file's own. The body
+/// comes from the store too when Astro kept it, so those files aren't read at all.
+pub(crate) fn with_listing_fields(
+    file_entry: FileEntry,
+    stored: Option<&StoredEntries>,
+    fields: ListingFields,
+) -> FileEntry {
+    let read_file = |path: &Path| {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| crate::commands::files::parse_frontmatter_internal(&content).ok())
+    };
+
+    let (file_entry, body) = match stored.and_then(|stored| stored.current(&file_entry.path)) {
+        Some(entry) => {
+            let body = entry
+                .body
+                .clone()
+                .or_else(|| read_file(&file_entry.path).map(|parsed| parsed.content));
+            let mut file_entry = file_entry.with_frontmatter(entry.data.clone());
+            file_entry.digest = entry.digest.clone();
+            (file_entry, body)
+        }
+        None => match read_file(&file_entry.path) {
+            Some(parsed) => (
+                file_entry.with_frontmatter(parsed.frontmatter),
+                Some(parsed.content),
+            ),
+            None => (file_entry, None),
+        },
+    };
+    with_stats(file_entry, body.as_deref(), fields)
+}
+
+/// Fills in a listed entry's title, word count and draft status
+fn with_stats(mut file_entry: FileEntry, body: Option<&str>, fields: ListingFields) -> FileEntry {
+    let frontmatter = file_entry.frontmatter.as_ref();
+    let title = frontmatter
+        .and_then(|frontmatter| frontmatter.get(fields.title))
+        .and_then(|value| value.as_str())
+        .or_else(|| body?.lines().find_map(|line| line.strip_prefix("# ")))
+        .map(str::trim)
+        .filter(|title| !title.is_empty())
+        .map(String::from);
+    let is_draft = frontmatter
+        .map(|frontmatter| frontmatter.get(fields.draft) == Some(&serde_json::Value::Bool(true)));
+
+    file_entry.title = title;
+    file_entry.is_draft = is_draft;
+    file_entry.word_count = body.map(reading_time::word_count);
+    file_entry
+}
+
 #[tauri::command]
 #[specta::specta]
-pub async fn scan_collection_files(collection_path: String) -> Result<Vec<FileEntry>, String> {
+pub async fn scan_collection_files(
+    collection_path: String,
+    collation: Option<Collation>,
+    title_field: Option<String>,
+    draft_field: Option<String>,
+) -> Result<Vec<FileEntry>, String> {
     let path = PathBuf::from(&collection_path);
     let mut files = Vec::new();
 
@@ -389,6 +527,8 @@ pub async fn scan_collection_files(collection_path: String) -> Result<Vec<FileEn
 
     // Use path as collection root (flat scan, no subdirectories)
     let collection_root = path.clone();
+    let stored = StoredEntries::load(&collection_root, &collection_name);
+    let fields = ListingFields::new(title_field.as_deref(), draft_field.as_deref());
 
     // Scan for markdown and MDX files
     for entry in
@@ -400,45 +540,36 @@ pub async fn scan_collection_files(collection_path: String) -> Result<Vec<FileEn
         if path.is_file() {
             if let Some(extension) = path.extension().and_then(|ext| ext.to_str()) {
                 if matches!(extension, "md" | "mdx") {
-                    let mut file_entry = FileEntry::new(
+                    let file_entry = FileEntry::new(
                         path.clone(),
                         collection_name.clone(),
                         collection_root.clone(),
                     );
-
-                    // Parse frontmatter for basic metadata
-                    if let Ok(content) = std::fs::read_to_string(&path) {
-                        if let Ok(parsed) =
-                            crate::commands::files::parse_frontmatter_internal(&content)
-                        {
-                            file_entry = file_entry.with_frontmatter(parsed.frontmatter);
-                        }
-                    }
-
-                    files.push(file_entry);
+                    files.push(with_listing_fields(file_entry, stored.as_ref(), fields));
                 }
             }
         }
     }
 
+    sort_file_entries(
+        &mut files,
+        title_field.as_deref(),
+        collation.unwrap_or_default(),
+    );
     Ok(files)
 }
 
-#[tauri::command]
-#[specta::specta]
-pub async fn load_file_based_collection(
-    project_path: String,
-    collection_name: String,
-) -> Result<Vec<FileEntry>, String> {
+/// Finds the JSON or YAML file a `file()` loader collection reads from, per content.config
+pub(crate) fn file_collection_path(
+    project_path: &Path,
+    collection_name: &str,
+) -> Result<PathBuf, String> {
     use regex::Regex;
 
-    debug!("Astro Editor [FILE_COLLECTION] Loading file-based collection: {collection_name}");
-
     // Read content.config.ts to find the file path
-    let project = PathBuf::from(&project_path);
     let config_paths = [
-        project.join("src").join("content.config.ts"),
-        project.join("src").join("content").join("config.ts"),
+        project_path.join("src").join("content.config.ts"),
+        project_path.join("src").join("content").join("config.ts"),
     ];
 
     let mut file_path: Option<PathBuf> = None;
@@ -465,7 +596,7 @@ pub async fn load_file_based_collection(
                 if let Some(cap) = re.captures(&content) {
                     let path_str = cap.get(1).unwrap().as_str();
                     let cleaned_path = path_str.trim_start_matches("./");
-                    file_path = Some(project.join(cleaned_path));
+                    file_path = Some(project_path.join(cleaned_path));
                     debug!("Astro Editor [FILE_COLLECTION] Matched! File path: {cleaned_path}");
                     break;
                 } else {
@@ -477,59 +608,55 @@ pub async fn load_file_based_collection(
         }
     }
 
-    let file_path = file_path.ok_or_else(|| {
+    file_path.ok_or_else(|| {
         format!("File-based collection '{collection_name}' not found in content.config")
-    })?;
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn load_file_based_collection(
+    project_path: String,
+    collection_name: String,
+) -> Result<Vec<FileEntry>, String> {
+    debug!("Astro Editor [FILE_COLLECTION] Loading file-based collection: {collection_name}");
+
+    let file_path = file_collection_path(Path::new(&project_path), &collection_name)?;
 
     debug!(
         "Astro Editor [FILE_COLLECTION] Found file path: {}",
         file_path.display()
     );
 
-    // Read and parse the JSON file
-    let json_content = std::fs::read_to_string(&file_path)
-        .map_err(|e| format!("Failed to read collection file: {e}"))?;
-
-    let json_data: serde_json::Value =
-        serde_json::from_str(&json_content).map_err(|e| format!("Failed to parse JSON: {e}"))?;
+    // Read and parse the JSON or YAML array, keeping each entry's key order
+    let entries = file_collections::read_entries(&file_path)?;
 
-    // Convert JSON array to FileEntry objects
+    // Use the file's parent directory as collection root for file-based collections
+    let collection_root = file_path.parent().unwrap_or(&file_path).to_path_buf();
     let mut files = Vec::new();
 
-    if let Some(array) = json_data.as_array() {
-        for item in array {
-            if let Some(obj) = item.as_object() {
-                // Extract unique identifier - try 'id' first, then 'slug'
-                let item_id = obj
-                    .get("id")
-                    .and_then(|v| v.as_str())
-                    .or_else(|| obj.get("slug").and_then(|v| v.as_str()))
-                    .ok_or_else(|| {
-                        "Missing unique identifier: collection items must have either 'id' or 'slug' field".to_string()
-                    })?
-                    .to_string();
-
-                // Convert JSON object to IndexMap for FileEntry frontmatter
-                let frontmatter: indexmap::IndexMap<String, serde_json::Value> =
-                    obj.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
-
-                // Create FileEntry with the JSON data as frontmatter
-                // For file-based collections, we need to manually set the id to use the item's id
-                // instead of deriving it from the file path
-                // Use the file's parent directory as collection root for file-based collections
-                let collection_root = file_path.parent().unwrap_or(&file_path).to_path_buf();
-                let mut file_entry =
-                    FileEntry::new(file_path.clone(), collection_name.clone(), collection_root)
-                        .with_frontmatter(frontmatter);
-
-                // Override the auto-generated id with the item's unique identifier from JSON
-                file_entry.id = format!("{collection_name}/{item_id}");
-
-                files.push(file_entry);
-            }
-        }
-    } else {
-        return Err("Collection file must contain a JSON array".to_string());
+    for entry in entries {
+        // Extract unique identifier - try 'id' first, then 'slug'
+        let item_id = file_collections::entry_id(&entry)
+            .ok_or_else(|| {
+                "Missing unique identifier: collection items must have either 'id' or 'slug' field"
+                    .to_string()
+            })?
+            .to_string();
+
+        // Create FileEntry with the entry's data as frontmatter
+        let mut file_entry = FileEntry::new(
+            file_path.clone(),
+            collection_name.clone(),
+            collection_root.clone(),
+        )
+        .with_frontmatter(entry);
+
+        // Override the auto-generated id with the item's unique identifier, since
+        // every entry shares the same file path
+        file_entry.id = format!("{collection_name}/{item_id}");
+
+        files.push(file_entry);
     }
 
     debug!(
@@ -571,12 +698,20 @@ pub async fn read_json_schema(
 }
 
 /// Scan a single directory (non-recursive) for subdirectories and markdown/mdx files
+///
+/// Subdirectories are ordered by name and files by title, using `collation`
+/// (natural order by default). With `pattern` (a `glob()` loader's globs, see
+/// `Collection::pattern`) only files it matches are listed.
 #[tauri::command]
 #[specta::specta]
 pub async fn scan_directory(
     directory_path: String,
     collection_name: String,
     collection_root: String,
+    collation: Option<Collation>,
+    title_field: Option<String>,
+    draft_field: Option<String>,
+    pattern: Option<Vec<String>>,
 ) -> Result<DirectoryScanResult, String> {
     let dir_path = PathBuf::from(&directory_path);
     let collection_root_path = PathBuf::from(&collection_root);
@@ -589,12 +724,36 @@ pub async fn scan_directory(
         return Err(format!("Path is not a directory: {}", dir_path.display()));
     }
 
+    let stored = StoredEntries::load(&collection_root_path, &collection_name);
+    let patterns = pattern.as_deref().map(EntryPatterns::new);
+    read_directory(
+        &dir_path,
+        &collection_name,
+        &collection_root_path,
+        stored.as_ref(),
+        patterns.as_ref(),
+        collation.unwrap_or_default(),
+        ListingFields::new(title_field.as_deref(), draft_field.as_deref()),
+    )
+}
+
+/// Lists a directory's subdirectories and markdown/mdx files, skipping hidden
+/// (`.`/`_`) entries, symlinks and files outside `patterns`, in listing order
+fn read_directory(
+    dir_path: &Path,
+    collection_name: &str,
+    collection_root_path: &PathBuf,
+    stored: Option<&StoredEntries>,
+    patterns: Option<&EntryPatterns>,
+    collation: Collation,
+    fields: ListingFields,
+) -> Result<DirectoryScanResult, String> {
     let mut subdirectories = Vec::new();
     let mut files = Vec::new();
 
     // Read directory entries
     for entry in
-        std::fs::read_dir(&dir_path).map_err(|e| format!("Failed to read directory: {e}"))?
+        std::fs::read_dir(dir_path).map_err(|e| format!("Failed to read directory: {e}"))?
     {
         let entry = entry.map_err(|e| format!("Failed to read directory entry: {e}"))?;
         let path = entry.path();
@@ -618,44 +777,130 @@ pub async fn scan_directory(
 
         if path.is_dir() {
             // Add subdirectory
-            if let Ok(dir_info) = DirectoryInfo::new(path, &collection_root_path) {
+            if let Ok(dir_info) = DirectoryInfo::new(path, collection_root_path) {
                 subdirectories.push(dir_info);
             }
         } else if path.is_file() {
             // Check if it's a markdown or MDX file
             if let Some(extension) = path.extension().and_then(|ext| ext.to_str()) {
-                if matches!(extension, "md" | "mdx") {
-                    let mut file_entry = FileEntry::new(
+                if matches!(extension, "md" | "mdx")
+                    && is_collection_entry(&path, collection_root_path, patterns)
+                {
+                    let file_entry = FileEntry::new(
                         path.clone(),
-                        collection_name.clone(),
+                        collection_name.to_string(),
                         collection_root_path.clone(),
                     );
-
-                    // Parse frontmatter for basic metadata
-                    if let Ok(content) = std::fs::read_to_string(&path) {
-                        if let Ok(parsed) =
-                            crate::commands::files::parse_frontmatter_internal(&content)
-                        {
-                            file_entry = file_entry.with_frontmatter(parsed.frontmatter);
-                        }
-                    }
-
-                    files.push(file_entry);
+                    files.push(with_listing_fields(file_entry, stored, fields));
                 }
             }
         }
     }
 
+    subdirectories.sort_by(|a, b| collation::compare(&a.name, &b.name, collation));
+    sort_file_entries(&mut files, Some(fields.title), collation);
+
     Ok(DirectoryScanResult {
         subdirectories,
         files,
     })
 }
 
-/// Count all markdown/mdx files recursively in a collection
+/// Whether a file under `collection_root` matches the collection's `glob()` patterns
+fn is_collection_entry(
+    path: &Path,
+    collection_root: &Path,
+    patterns: Option<&EntryPatterns>,
+) -> bool {
+    patterns
+        .is_none_or(|patterns| patterns.matches(path.strip_prefix(collection_root).unwrap_or(path)))
+}
+
+/// Scan a collection's whole directory tree in one call
+///
+/// Directories nested deeper than `max_depth` below the collection root are listed
+/// but not scanned (`truncated`); load them with `scan_directory`. Without
+/// `max_depth` the whole tree is scanned. Ordering and `pattern` filtering match
+/// `scan_directory`.
+#[tauri::command]
+#[specta::specta]
+pub async fn scan_collection_tree(
+    collection_path: String,
+    collection_name: String,
+    max_depth: Option<u32>,
+    collation: Option<Collation>,
+    title_field: Option<String>,
+    draft_field: Option<String>,
+    pattern: Option<Vec<String>>,
+) -> Result<DirectoryTree, String> {
+    let root = PathBuf::from(&collection_path);
+
+    if !root.is_dir() {
+        return Err(format!("Path is not a directory: {}", root.display()));
+    }
+
+    fn scan_tree(
+        directory: Option<DirectoryInfo>,
+        path: &Path,
+        depth: u32,
+        max_depth: Option<u32>,
+        scan: &dyn Fn(&Path) -> Result<DirectoryScanResult, String>,
+    ) -> Result<DirectoryTree, String> {
+        let DirectoryScanResult {
+            subdirectories,
+            files,
+        } = scan(path)?;
+
+        let subdirectories = subdirectories
+            .into_iter()
+            .map(|subdirectory| {
+                if max_depth.is_some_and(|max| depth >= max) {
+                    return Ok(DirectoryTree {
+                        directory: Some(subdirectory),
+                        files: Vec::new(),
+                        subdirectories: Vec::new(),
+                        truncated: true,
+                    });
+                }
+                let path = subdirectory.full_path.clone();
+                scan_tree(Some(subdirectory), &path, depth + 1, max_depth, scan)
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(DirectoryTree {
+            directory,
+            files,
+            subdirectories,
+            truncated: false,
+        })
+    }
+
+    let stored = StoredEntries::load(&root, &collection_name);
+    let collation = collation.unwrap_or_default();
+    let fields = ListingFields::new(title_field.as_deref(), draft_field.as_deref());
+    let patterns = pattern.as_deref().map(EntryPatterns::new);
+    let scan = |path: &Path| {
+        read_directory(
+            path,
+            &collection_name,
+            &root,
+            stored.as_ref(),
+            patterns.as_ref(),
+            collation,
+            fields,
+        )
+    };
+    scan_tree(None, &root, 0, max_depth, &scan)
+}
+
+/// Count all markdown/mdx files recursively in a collection, only those matching
+/// `pattern` when given
 #[tauri::command]
 #[specta::specta]
-pub async fn count_collection_files_recursive(collection_path: String) -> Result<u32, String> {
+pub async fn count_collection_files_recursive(
+    collection_path: String,
+    pattern: Option<Vec<String>>,
+) -> Result<u32, String> {
     let path = PathBuf::from(&collection_path);
 
     if !path.exists() {
@@ -666,7 +911,11 @@ pub async fn count_collection_files_recursive(collection_path: String) -> Result
         return Err(format!("Path is not a directory: {}", path.display()));
     }
 
-    fn count_files_recursive(dir_path: &Path) -> Result<u32, String> {
+    fn count_files_recursive(
+        dir_path: &Path,
+        collection_root: &Path,
+        patterns: Option<&EntryPatterns>,
+    ) -> Result<u32, String> {
         let mut count: u32 = 0;
 
         for entry in
@@ -694,11 +943,13 @@ pub async fn count_collection_files_recursive(collection_path: String) -> Result
 
             if path.is_dir() {
                 // Recursively count files in subdirectory
-                count += count_files_recursive(&path)?;
+                count += count_files_recursive(&path, collection_root, patterns)?;
             } else if path.is_file() {
                 // Check if it's a markdown or MDX file
                 if let Some(extension) = path.extension().and_then(|ext| ext.to_str()) {
-                    if matches!(extension, "md" | "mdx") {
+                    if matches!(extension, "md" | "mdx")
+                        && is_collection_entry(&path, collection_root, patterns)
+                    {
                         count += 1;
                     }
                 }
@@ -708,7 +959,8 @@ pub async fn count_collection_files_recursive(collection_path: String) -> Result
         Ok(count)
     }
 
-    count_files_recursive(&path)
+    let patterns = pattern.as_deref().map(EntryPatterns::new);
+    count_files_recursive(&path, &path, patterns.as_ref())
 }
 
 /// Scan all markdown/mdx files recursively in a collection directory
@@ -717,6 +969,9 @@ pub async fn count_collection_files_recursive(collection_path: String) -> Result
 pub async fn scan_collection_files_recursive(
     collection_path: String,
     collection_name: String,
+    collation: Option<Collation>,
+    title_field: Option<String>,
+    draft_field: Option<String>,
 ) -> Result<Vec<FileEntry>, String> {
     let path = PathBuf::from(&collection_path);
     let collection_root = path.clone();
@@ -733,6 +988,8 @@ pub async fn scan_collection_files_recursive(
         dir_path: &Path,
         collection_name: &str,
         collection_root: &Path,
+        stored: Option<&StoredEntries>,
+        fields: ListingFields,
     ) -> Result<Vec<FileEntry>, String> {
         let mut files = Vec::new();
 
@@ -763,25 +1020,18 @@ pub async fn scan_collection_files_recursive(
                     &path,
                     collection_name,
                     collection_root,
+                    stored,
+                    fields,
                 )?);
             } else if path.is_file() {
                 if let Some(extension) = path.extension().and_then(|ext| ext.to_str()) {
                     if matches!(extension, "md" | "mdx") {
-                        let mut file_entry = FileEntry::new(
+                        let file_entry = FileEntry::new(
                             path.clone(),
                             collection_name.to_string(),
                             collection_root.to_path_buf(),
                         );
-
-                        if let Ok(content) = std::fs::read_to_string(&path) {
-                            if let Ok(parsed) =
-                                crate::commands::files::parse_frontmatter_internal(&content)
-                            {
-                                file_entry = file_entry.with_frontmatter(parsed.frontmatter);
-                            }
-                        }
-
-                        files.push(file_entry);
+                        files.push(with_listing_fields(file_entry, stored, fields));
                     }
                 }
             }
@@ -790,7 +1040,21 @@ pub async fn scan_collection_files_recursive(
         Ok(files)
     }
 
-    collect_files_recursive(&path, &collection_name, &collection_root)
+    let stored = StoredEntries::load(&collection_root, &collection_name);
+    let fields = ListingFields::new(title_field.as_deref(), draft_field.as_deref());
+    let mut files = collect_files_recursive(
+        &path,
+        &collection_name,
+        &collection_root,
+        stored.as_ref(),
+        fields,
+    )?;
+    sort_file_entries(
+        &mut files,
+        title_field.as_deref(),
+        collation.unwrap_or_default(),
+    );
+    Ok(files)
 }
 
 /// Resolves an absolute file path to a `FileEntry` within the given project, if the
@@ -810,21 +1074,18 @@ pub async fn resolve_file_entry(
     project_path: String,
     content_directory: Option<String>,
 ) -> Result<Option<FileEntry>, String> {
-    // Canonicalize the project root (must exist).
-    let project_canon =
-        std::fs::canonicalize(&project_path).map_err(|e| format!("Invalid project path: {e}"))?;
-
-    // Canonicalize the target file. A missing file resolves to None (not an error).
-    let file_canon = match std::fs::canonicalize(&file_path) {
-        Ok(p) => p,
-        Err(_) => return Ok(None),
-    };
-
-    // Security: the file must live inside the project root.
-    if !file_canon.starts_with(&project_canon) {
-        return Err("File is not inside the project".to_string());
+    // A missing file resolves to None (not an error).
+    if std::fs::canonicalize(&file_path).is_err() {
+        return Ok(None);
     }
 
+    // Security: the file must live inside the project (or one of its trusted roots).
+    let file_canon = check_path(
+        Path::new(&file_path),
+        Path::new(&project_path),
+        Scope::Project,
+    )?;
+
     // Only Markdown/MDX files are openable.
     let is_markdown = file_canon
         .extension()
@@ -836,7 +1097,7 @@ pub async fn resolve_file_entry(
     }
 
     // Reuse the existing project scan to discover collections and their roots.
-    let collections = scan_project_with_content_dir(project_path, content_directory).await?;
+    let collections = scan_project_with_content_dir(project_path, content_directory, None).await?;
 
     // Find the collection whose directory is the most specific ancestor of the file.
     let owning = collections
@@ -861,6 +1122,37 @@ pub async fn resolve_file_entry(
     }
 }
 
+/// Returns display-ready documentation for a single frontmatter field
+///
+/// Drives hover-help in the frontmatter panel. Paths that pass through a reference
+/// field (e.g. `author.bio`) are resolved against the referenced collection's schema.
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+/// * `collection_name` - The collection the field belongs to
+/// * `field_path` - Dot-separated field path, e.g. `seo.title`
+#[tauri::command]
+#[specta::specta]
+pub async fn get_field_docs(
+    project_path: String,
+    content_directory: Option<String>,
+    collection_name: String,
+    field_path: String,
+) -> Result<FieldDocs, String> {
+    let collections = scan_project_with_content_dir(project_path, content_directory, None).await?;
+
+    let schemas: HashMap<String, schema_merger::SchemaDefinition> = collections
+        .into_iter()
+        .filter_map(|collection| {
+            let schema = serde_json::from_str(collection.complete_schema.as_deref()?).ok()?;
+            Some((collection.name, schema))
+        })
+        .collect();
+
+    find_field_docs(&schemas, &collection_name, &field_path)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1199,4 +1491,199 @@ mod tests {
             "markdown outside a collection should resolve to None"
         );
     }
+
+    #[tokio::test]
+    async fn test_scan_directory_sorts_naturally_by_title() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path();
+        std::fs::write(dir.join("part-10.md"), "# Ten").unwrap();
+        std::fs::write(dir.join("part-2.md"), "# Two").unwrap();
+        std::fs::write(dir.join("b.md"), "---\ntitle: Étude\n---\n").unwrap();
+        std::fs::write(dir.join("a.md"), "---\ntitle: zebra\n---\n").unwrap();
+        std::fs::create_dir(dir.join("vol-10")).unwrap();
+        std::fs::create_dir(dir.join("vol-9")).unwrap();
+        let dir = dir.to_string_lossy().to_string();
+
+        let result = scan_directory(
+            dir.clone(),
+            "posts".to_string(),
+            dir.clone(),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let names: Vec<&str> = result.files.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["b", "part-2", "part-10", "a"]);
+        let dirs: Vec<&str> = result
+            .subdirectories
+            .iter()
+            .map(|d| d.name.as_str())
+            .collect();
+        assert_eq!(dirs, vec!["vol-9", "vol-10"]);
+
+        let ordinal = scan_collection_files(dir, Some(Collation::Ordinal), None, None)
+            .await
+            .unwrap();
+        let names: Vec<&str> = ordinal.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["part-10", "part-2", "a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn test_scan_directory_uses_current_data_store_entries() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let blog = temp.path().join("src/content/blog");
+        std::fs::create_dir_all(&blog).unwrap();
+        std::fs::create_dir_all(temp.path().join(".astro")).unwrap();
+        std::fs::write(blog.join("stored.md"), "---\ntitle: Stored\n---\n").unwrap();
+        std::fs::write(blog.join("unsynced.md"), "---\ntitle: Unsynced\n---\n").unwrap();
+        std::fs::write(
+            temp.path().join(".astro/data-store.json"),
+            r#"[["Map",1,2],"blog",["Map",3,4],"stored",{"id":3,"data":5,"filePath":8,"digest":9},
+                {"title":6,"draft":7},"Stored",false,"src/content/blog/stored.md","abc123"]"#,
+        )
+        .unwrap();
+        let dir = blog.to_string_lossy().to_string();
+
+        let result = scan_directory(dir.clone(), "blog".to_string(), dir, None, None, None, None)
+            .await
+            .unwrap();
+
+        let stored = &result.files[0];
+        assert_eq!(stored.name, "stored");
+        // Schema defaults from the store, which the file itself doesn't have
+        assert_eq!(stored.frontmatter.as_ref().unwrap()["draft"], false);
+        assert_eq!(stored.digest.as_deref(), Some("abc123"));
+
+        let unsynced = &result.files[1];
+        assert_eq!(unsynced.frontmatter.as_ref().unwrap()["title"], "Unsynced");
+        assert!(unsynced.digest.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_scan_directory_adds_listing_stats() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path();
+        std::fs::write(
+            dir.join("a.md"),
+            "---\nheadline: Launch\nhidden: true\n---\n# Ignored\n\nWe shipped it.\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("b.md"), "# From the heading\n\n```\ncode\n```\n").unwrap();
+        let dir = dir.to_string_lossy().to_string();
+
+        let result = scan_directory(
+            dir.clone(),
+            "posts".to_string(),
+            dir,
+            None,
+            Some("headline".to_string()),
+            Some("hidden".to_string()),
+            None,
+        )
+        .await
+        .unwrap();
+
+        let launch = &result.files[1];
+        assert_eq!(launch.title.as_deref(), Some("Launch"));
+        assert_eq!(launch.word_count, Some(4));
+        assert_eq!(launch.is_draft, Some(true));
+        let heading = &result.files[0];
+        assert_eq!(heading.title.as_deref(), Some("From the heading"));
+        assert_eq!(heading.word_count, Some(3));
+        assert_eq!(heading.is_draft, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_scan_collection_tree_stops_at_max_depth() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path();
+        std::fs::create_dir_all(dir.join("2024/january/week-1")).unwrap();
+        std::fs::create_dir_all(dir.join("_drafts")).unwrap();
+        std::fs::write(dir.join("intro.md"), "---\ntitle: Intro\n---\n").unwrap();
+        std::fs::write(dir.join("2024/recap.md"), "# Recap").unwrap();
+        std::fs::write(dir.join("2024/january/launch.mdx"), "# Launch").unwrap();
+        std::fs::write(dir.join("_drafts/wip.md"), "# WIP").unwrap();
+        let dir = dir.to_string_lossy().to_string();
+
+        let tree = scan_collection_tree(
+            dir.clone(),
+            "posts".to_string(),
+            Some(1),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert!(tree.directory.is_none());
+        assert_eq!(
+            tree.files[0].frontmatter.as_ref().unwrap()["title"],
+            "Intro"
+        );
+        assert_eq!(tree.subdirectories.len(), 1);
+        let year = &tree.subdirectories[0];
+        assert_eq!(year.files[0].id, "posts/2024/recap");
+        assert!(!year.truncated);
+        let month = &year.subdirectories[0];
+        assert_eq!(
+            month.directory.as_ref().unwrap().relative_path,
+            "2024/january"
+        );
+        assert!(month.truncated);
+        assert!(month.files.is_empty());
+
+        let full = scan_collection_tree(dir, "posts".to_string(), None, None, None, None, None)
+            .await
+            .unwrap();
+        let month = &full.subdirectories[0].subdirectories[0];
+        assert_eq!(month.files[0].id, "posts/2024/january/launch");
+        assert_eq!(
+            month.subdirectories[0].directory.as_ref().unwrap().name,
+            "week-1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scans_honor_glob_loader_patterns() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path();
+        std::fs::create_dir_all(dir.join("drafts")).unwrap();
+        std::fs::write(dir.join("idea.mdx"), "# Idea").unwrap();
+        std::fs::write(dir.join("skipped.md"), "# Skipped").unwrap();
+        std::fs::write(dir.join("drafts/wip.mdx"), "# WIP").unwrap();
+        let dir = dir.to_string_lossy().to_string();
+        let pattern = Some(vec!["**/*.mdx".to_string(), "!drafts/**".to_string()]);
+
+        let tree = scan_collection_tree(
+            dir.clone(),
+            "notes".to_string(),
+            None,
+            None,
+            None,
+            None,
+            pattern.clone(),
+        )
+        .await
+        .unwrap();
+        let names: Vec<&str> = tree.files.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["idea"]);
+        assert!(tree.subdirectories[0].files.is_empty());
+
+        assert_eq!(
+            count_collection_files_recursive(dir.clone(), pattern)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            count_collection_files_recursive(dir, None).await.unwrap(),
+            3
+        );
+    }
 }
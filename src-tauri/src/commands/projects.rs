@@ -0,0 +1,603 @@
+//! The registry of projects the editor has opened
+//!
+//! Projects are recorded in `preferences/project-registry.json` in app data, and each
+//! project's settings in `preferences/projects/{id}.json`. These are the files the
+//! frontend's project registry reads and writes, in the same format, so a project
+//! registered here keeps its id and settings there, and vice versa.
+//!
+//! `get_project_settings` and `set_project_settings` cover the directory overrides
+//! (content, assets and MDX components). Other settings in a project's file are left
+//! as they are. Daily note settings are read for notes appended from outside the
+//! frontend, and the IDE command and recovery encryption preference from the global
+//! settings file.
+
+use crate::commands::content_calendar::CalendarFields;
+use crate::commands::daily_notes::DailyNoteSettings;
+use crate::parser::parse_astro_config;
+use chrono::{SecondsFormat, Utc};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use specta::Type;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Manager};
+
+/// Version of the registry and project files, as the frontend writes them
+const REGISTRY_VERSION: u32 = 2;
+
+static PREFERENCES_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Held while the registry is read and written back
+static REGISTRY_LOCK: Mutex<()> = Mutex::new(());
+
+/// A project in the registry
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisteredProject {
+    pub id: String,
+    /// From `package.json`, else the directory name
+    pub name: String,
+    pub path: String,
+    /// RFC 3339 UTC timestamp
+    pub last_opened: String,
+    /// RFC 3339 UTC timestamp
+    pub created: String,
+}
+
+/// A project opened before, most recent first in `list_recent_projects`
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentProject {
+    pub id: String,
+    pub name: String,
+    pub path: String,
+    /// RFC 3339 UTC timestamp
+    pub last_opened: String,
+    /// Collections in the content config; `None` when the project is gone or its
+    /// config can't be read
+    pub collection_count: Option<u32>,
+}
+
+/// A project's directory overrides, relative to the project root
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectPathSettings {
+    /// Defaults to `src/content`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[specta(optional)]
+    pub content_directory: Option<String>,
+    /// Defaults to `src/assets`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[specta(optional)]
+    pub assets_directory: Option<String>,
+    /// Defaults to `src/components/mdx`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[specta(optional)]
+    pub mdx_components_directory: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct Registry {
+    #[serde(default)]
+    projects: IndexMap<String, RegisteredProject>,
+    #[serde(default)]
+    last_opened_project: Option<String>,
+    #[serde(default)]
+    version: u32,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self {
+            projects: IndexMap::new(),
+            last_opened_project: None,
+            version: REGISTRY_VERSION,
+        }
+    }
+}
+
+fn registry_path(dir: &Path) -> PathBuf {
+    dir.join("project-registry.json")
+}
+
+fn project_file(dir: &Path, id: &str) -> PathBuf {
+    dir.join("projects").join(format!("{id}.json"))
+}
+
+fn read_registry(dir: &Path) -> Registry {
+    std::fs::read_to_string(registry_path(dir))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create preferences directory: {e}"))?;
+    }
+    let json = serde_json::to_string_pretty(value)
+        .map_err(|e| format!("Failed to serialize {}: {e}", path.display()))?;
+    std::fs::write(path, json).map_err(|e| format!("Failed to write {}: {e}", path.display()))
+}
+
+/// The frontend's `simpleHash`, so both generate the same project ids
+fn simple_hash(text: &str) -> String {
+    let hash = text.encode_utf16().fold(0_i32, |hash, unit| {
+        (hash << 5).wrapping_sub(hash).wrapping_add(i32::from(unit))
+    });
+    let mut n = i64::from(hash).unsigned_abs();
+    let mut digits = Vec::new();
+    loop {
+        digits.push(std::char::from_digit((n % 36) as u32, 36).unwrap_or('0'));
+        n /= 36;
+        if n == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().take(6).collect()
+}
+
+/// A filesystem-safe id from the project's name, with a hash of its path if taken
+fn generate_project_id(name: &str, path: &str, existing: &HashSet<&String>) -> String {
+    let clean: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    if existing.contains(&clean) {
+        format!("{clean}-{}", simple_hash(path))
+    } else {
+        clean
+    }
+}
+
+/// The project's name from `package.json`, else its directory name
+fn project_name(project_path: &str) -> String {
+    std::fs::read_to_string(Path::new(project_path).join("package.json"))
+        .ok()
+        .and_then(|json| serde_json::from_str::<Value>(&json).ok())
+        .and_then(|package| Some(package.get("name")?.as_str()?.to_string()))
+        .filter(|name| !name.is_empty())
+        .or_else(|| {
+            project_path
+                .rsplit(['/', '\\'])
+                .next()
+                .filter(|name| !name.is_empty())
+                .map(String::from)
+        })
+        .unwrap_or_else(|| "unknown-project".to_string())
+}
+
+fn register_in(dir: &Path, project_path: &str) -> Result<RegisteredProject, String> {
+    if !Path::new(project_path).is_dir() {
+        return Err(format!("Project not found: {project_path}"));
+    }
+    let _lock = REGISTRY_LOCK.lock().unwrap();
+    let mut registry = read_registry(dir);
+    let now = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+    let name = project_name(project_path);
+
+    // A project whose registered path is gone has been moved here if the names match
+    let known = registry
+        .projects
+        .values()
+        .find(|project| project.path == project_path)
+        .or_else(|| {
+            registry
+                .projects
+                .values()
+                .find(|project| project.name == name && !Path::new(&project.path).exists())
+        })
+        .map(|project| project.id.clone());
+
+    let project = match known.and_then(|id| registry.projects.get_mut(&id)) {
+        Some(project) => {
+            project.path = project_path.to_string();
+            project.last_opened = now;
+            project.clone()
+        }
+        None => {
+            let existing: HashSet<&String> = registry.projects.keys().collect();
+            let project = RegisteredProject {
+                id: generate_project_id(&name, project_path, &existing),
+                name,
+                path: project_path.to_string(),
+                last_opened: now.clone(),
+                created: now,
+            };
+            registry
+                .projects
+                .insert(project.id.clone(), project.clone());
+            project
+        }
+    };
+
+    registry.last_opened_project = Some(project.id.clone());
+    write_json(&registry_path(dir), &registry)?;
+    Ok(project)
+}
+
+fn find_project(dir: &Path, project_path: &str) -> Option<RegisteredProject> {
+    read_registry(dir)
+        .projects
+        .into_values()
+        .find(|project| project.path == project_path)
+}
+
+/// Reads a project's settings file, or the frontend's defaults for a new one
+fn read_project_data(dir: &Path, id: &str) -> Value {
+    std::fs::read_to_string(project_file(dir, id))
+        .ok()
+        .and_then(|json| serde_json::from_str::<Value>(&json).ok())
+        .filter(Value::is_object)
+        .unwrap_or_else(|| {
+            json!({
+                "settings": { "pathOverrides": {}, "frontmatterMappings": {} },
+                "collections": [],
+                "version": REGISTRY_VERSION,
+            })
+        })
+}
+
+fn overrides_for(dir: &Path, id: &str) -> ProjectPathSettings {
+    read_project_data(dir, id)
+        .pointer("/settings/pathOverrides")
+        .cloned()
+        .and_then(|overrides| serde_json::from_value(overrides).ok())
+        .unwrap_or_default()
+}
+
+fn path_settings_in(dir: &Path, project_path: &str) -> ProjectPathSettings {
+    find_project(dir, project_path)
+        .map(|project| overrides_for(dir, &project.id))
+        .unwrap_or_default()
+}
+
+fn set_path_settings_in(
+    dir: &Path,
+    project_path: &str,
+    settings: &ProjectPathSettings,
+) -> Result<(), String> {
+    let project = find_project(dir, project_path)
+        .ok_or_else(|| format!("Project is not registered: {project_path}"))?;
+    let mut data = read_project_data(dir, &project.id);
+    let overrides = serde_json::to_value(settings)
+        .map_err(|e| format!("Failed to serialize project settings: {e}"))?;
+
+    match data.get_mut("settings").and_then(Value::as_object_mut) {
+        Some(project_settings) => {
+            project_settings.insert("pathOverrides".to_string(), overrides);
+        }
+        None => {
+            data["settings"] = json!({ "pathOverrides": overrides, "frontmatterMappings": {} });
+        }
+    }
+    write_json(&project_file(dir, &project.id), &data)
+}
+
+fn recent_in(dir: &Path) -> Vec<RecentProject> {
+    let mut projects: Vec<RecentProject> = read_registry(dir)
+        .projects
+        .into_values()
+        .map(|project| {
+            let root = Path::new(&project.path);
+            let settings = overrides_for(dir, &project.id);
+            let collection_count = root
+                .is_dir()
+                .then(|| parse_astro_config(root, settings.content_directory.as_deref()).ok())
+                .flatten()
+                .map(|collections| collections.len() as u32);
+            RecentProject {
+                id: project.id,
+                name: project.name,
+                path: project.path,
+                last_opened: project.last_opened,
+                collection_count,
+            }
+        })
+        .collect();
+    projects.sort_by(|a, b| b.last_opened.cmp(&a.last_opened));
+    projects
+}
+
+/// A project's directory overrides, for commands the frontend doesn't pass them to
+///
+/// Empty when app data couldn't be resolved or the project isn't registered.
+pub(crate) fn path_settings(project_path: &str) -> ProjectPathSettings {
+    PREFERENCES_DIR
+        .get()
+        .map(|dir| path_settings_in(dir, project_path))
+        .unwrap_or_default()
+}
+
+fn daily_note_settings_in(dir: &Path, project_path: &str) -> DailyNoteSettings {
+    let Some(project) = find_project(dir, project_path) else {
+        return DailyNoteSettings::default();
+    };
+    let data = read_project_data(dir, &project.id);
+    let setting = |pointer: &str| {
+        data.pointer(pointer)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(String::from)
+    };
+    DailyNoteSettings {
+        pattern: setting("/settings/dailyNotePattern"),
+        template_path: setting("/settings/dailyNoteTemplate"),
+        date_field: setting("/settings/frontmatterMappings/publishedDate"),
+    }
+}
+
+/// A project's daily note pattern, template and date field, for daily notes written
+/// from outside the frontend (intents and the capture API)
+pub(crate) fn daily_note_settings(project_path: &str) -> DailyNoteSettings {
+    PREFERENCES_DIR
+        .get()
+        .map(|dir| daily_note_settings_in(dir, project_path))
+        .unwrap_or_default()
+}
+
+fn calendar_fields_in(dir: &Path, project_path: &str) -> CalendarFields {
+    let Some(project) = find_project(dir, project_path) else {
+        return CalendarFields::default();
+    };
+    let data = read_project_data(dir, &project.id);
+    let mapping = |field: &str| -> Vec<String> {
+        let names = match data.pointer(&format!("/settings/frontmatterMappings/{field}")) {
+            Some(Value::Array(names)) => names.iter().filter_map(Value::as_str).collect(),
+            Some(Value::String(name)) => vec![name.as_str()],
+            _ => vec![],
+        };
+        names
+            .into_iter()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(String::from)
+            .collect()
+    };
+    CalendarFields {
+        title: mapping("title").into_iter().next(),
+        published_date: mapping("publishedDate"),
+        draft: mapping("draft").into_iter().next(),
+    }
+}
+
+/// A project's title, publish date and draft field mappings, for the content calendar
+pub(crate) fn calendar_fields(project_path: &str) -> CalendarFields {
+    PREFERENCES_DIR
+        .get()
+        .map(|dir| calendar_fields_in(dir, project_path))
+        .unwrap_or_default()
+}
+
+/// The global settings (`preferences/global-settings.json`), if they can be read
+fn global_settings() -> Option<Value> {
+    let dir = PREFERENCES_DIR.get()?;
+    std::fs::read_to_string(dir.join("global-settings.json"))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+}
+
+/// The IDE command from global settings, if one is set
+pub(crate) fn ide_command() -> Option<String> {
+    global_settings()
+        .and_then(|settings| {
+            Some(
+                settings
+                    .pointer("/general/ideCommand")?
+                    .as_str()?
+                    .trim()
+                    .to_string(),
+            )
+        })
+        .filter(|command| !command.is_empty())
+}
+
+/// The `recovery.encrypt` preference from global settings, which also covers saved
+/// versions of files
+pub(crate) fn encrypt_recovery_data() -> bool {
+    global_settings()
+        .and_then(|settings| settings.pointer("/general/recovery/encrypt")?.as_bool())
+        .unwrap_or(false)
+}
+
+/// Records that a project was opened, adding it to the registry if it's new
+///
+/// A registered project whose directory no longer exists is taken to have moved here
+/// when its name matches, so it keeps its settings.
+#[tauri::command]
+#[specta::specta]
+pub async fn register_project(project_path: String) -> Result<RegisteredProject, String> {
+    let dir = PREFERENCES_DIR
+        .get()
+        .ok_or("Project registry is not available")?;
+    register_in(dir, &project_path)
+}
+
+/// Lists the projects opened before, most recently opened first
+#[tauri::command]
+#[specta::specta]
+pub async fn list_recent_projects() -> Result<Vec<RecentProject>, String> {
+    let dir = PREFERENCES_DIR
+        .get()
+        .ok_or("Project registry is not available")?
+        .clone();
+    tokio::task::spawn_blocking(move || recent_in(&dir))
+        .await
+        .map_err(|e| format!("Failed to list recent projects: {e}"))
+}
+
+/// Gets a project's content, assets and MDX components directory overrides
+#[tauri::command]
+#[specta::specta]
+pub async fn get_project_settings(project_path: String) -> Result<ProjectPathSettings, String> {
+    let dir = PREFERENCES_DIR
+        .get()
+        .ok_or("Project registry is not available")?;
+    Ok(path_settings_in(dir, &project_path))
+}
+
+/// Replaces a project's directory overrides; an omitted directory goes back to the default
+#[tauri::command]
+#[specta::specta]
+pub async fn set_project_settings(
+    project_path: String,
+    settings: ProjectPathSettings,
+) -> Result<(), String> {
+    let dir = PREFERENCES_DIR
+        .get()
+        .ok_or("Project registry is not available")?;
+    set_path_settings_in(dir, &project_path, &settings)
+}
+
+// Resolve the preferences directory when the app starts
+pub fn init_projects(app: &AppHandle) {
+    match app
+        .path()
+        .resolve("preferences", BaseDirectory::AppLocalData)
+    {
+        Ok(dir) => {
+            let _ = PREFERENCES_DIR.set(dir);
+        }
+        Err(e) => {
+            log::warn!(
+                "Astro Editor [PROJECT_REGISTRY] Failed to resolve preferences directory: {e}"
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_simple_hash_matches_frontend() {
+        // Values from the frontend's simpleHash
+        assert_eq!(simple_hash(""), "0");
+        assert_eq!(simple_hash("/Users/me/blog"), "xirxa5");
+        assert_eq!(
+            simple_hash("/Users/danny/dev/astro-blog-with-a-long-name-éü"),
+            "p090nx"
+        );
+    }
+
+    #[test]
+    fn test_register_project() {
+        let temp = TempDir::new().unwrap();
+        let prefs = temp.path().join("preferences");
+        let blog = temp.path().join("blog");
+        let other = temp.path().join("other").join("blog");
+        fs::create_dir_all(&blog).unwrap();
+        fs::create_dir_all(&other).unwrap();
+        fs::write(blog.join("package.json"), r#"{ "name": "My Blog" }"#).unwrap();
+        let blog_path = blog.to_string_lossy().to_string();
+        let other_path = other.to_string_lossy().to_string();
+
+        let first = register_in(&prefs, &blog_path).unwrap();
+        assert_eq!(first.id, "my-blog");
+        assert_eq!(first.name, "My Blog");
+        // Opening it again updates the same entry
+        let again = register_in(&prefs, &blog_path).unwrap();
+        assert_eq!(
+            (again.id.as_str(), again.created.as_str()),
+            ("my-blog", first.created.as_str())
+        );
+
+        // A different project with a taken name gets a hashed id
+        fs::write(other.join("package.json"), r#"{ "name": "my-blog" }"#).unwrap();
+        let second = register_in(&prefs, &other_path).unwrap();
+        assert_eq!(second.id, format!("my-blog-{}", simple_hash(&other_path)));
+
+        let registry = read_registry(&prefs);
+        assert_eq!(registry.projects.len(), 2);
+        assert_eq!(
+            registry.last_opened_project.as_deref(),
+            Some(second.id.as_str())
+        );
+
+        // Moving the first project keeps its id
+        let moved = temp.path().join("moved");
+        fs::rename(&blog, &moved).unwrap();
+        let moved = register_in(&prefs, &moved.to_string_lossy()).unwrap();
+        assert_eq!(moved.id, "my-blog");
+        assert_eq!(read_registry(&prefs).projects.len(), 2);
+    }
+
+    #[test]
+    fn test_project_settings_keep_other_settings() {
+        let temp = TempDir::new().unwrap();
+        let prefs = temp.path().join("preferences");
+        let blog = temp.path().join("blog");
+        fs::create_dir_all(blog.join("src")).unwrap();
+        let blog_path = blog.to_string_lossy().to_string();
+
+        let settings = ProjectPathSettings {
+            content_directory: Some("content".to_string()),
+            ..Default::default()
+        };
+        assert!(set_path_settings_in(&prefs, &blog_path, &settings).is_err());
+
+        let project = register_in(&prefs, &blog_path).unwrap();
+        write_json(
+            &project_file(&prefs, &project.id),
+            &json!({
+                "settings": {
+                    "pathOverrides": { "assetsDirectory": "public/images" },
+                    "frontmatterMappings": { "title": "heading", "publishedDate": "pubDate" },
+                    "dailyNotePattern": "log/{date}.md",
+                },
+                "version": 2,
+            }),
+        )
+        .unwrap();
+        set_path_settings_in(&prefs, &blog_path, &settings).unwrap();
+
+        assert_eq!(
+            daily_note_settings_in(&prefs, &blog_path),
+            DailyNoteSettings {
+                pattern: Some("log/{date}.md".to_string()),
+                template_path: None,
+                date_field: Some("pubDate".to_string()),
+            }
+        );
+
+        assert_eq!(
+            calendar_fields_in(&prefs, &blog_path),
+            CalendarFields {
+                title: Some("heading".to_string()),
+                published_date: vec!["pubDate".to_string()],
+                draft: None,
+            }
+        );
+
+        assert_eq!(path_settings_in(&prefs, &blog_path), settings);
+        let data = read_project_data(&prefs, &project.id);
+        assert_eq!(
+            data.pointer("/settings/frontmatterMappings/title"),
+            Some(&json!("heading"))
+        );
+        assert_eq!(
+            data.pointer("/settings/pathOverrides"),
+            Some(&json!({ "contentDirectory": "content" }))
+        );
+
+        // No content config, so no collections; a missing project can't be counted
+        assert_eq!(recent_in(&prefs)[0].collection_count, Some(0));
+        fs::remove_dir_all(&blog).unwrap();
+        assert_eq!(recent_in(&prefs)[0].collection_count, None);
+    }
+}
@@ -0,0 +1,430 @@
+//! Publish readiness gates
+//!
+//! Collections can define a review checklist in project settings (e.g. "description
+//! present", "at least 1 tag"). Before a draft is published the frontend asks
+//! `check_publish_readiness` which gates pass; failed blocking gates stop the publish,
+//! failed non-blocking gates only warn. Entries under embargo always get a failed
+//! blocking gate, whatever the collection's settings.
+
+use crate::commands::embargo::{embargo_value, parse_embargo_time, EMBARGO_FIELD};
+use crate::commands::files::parse_frontmatter_internal;
+use crate::security::validate_project_path;
+use chrono::{DateTime, Local, Utc};
+use indexmap::IndexMap;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::HashSet;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// What a publish gate checks
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum PublishRule {
+    /// A frontmatter field is set and not empty; nested fields use dots (`seo.image`)
+    FieldPresent { field: String },
+    /// A frontmatter list has at least `min` items
+    MinItems { field: String, min: u32 },
+    /// Relative links and links matching the collection's URL pattern resolve
+    NoBrokenLinks,
+}
+
+/// A publish gate from a collection's settings
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishGate {
+    /// Shown in the checklist; a description of the rule is used when omitted
+    #[serde(default)]
+    #[specta(optional)]
+    pub label: Option<String>,
+    pub rule: PublishRule,
+    /// Whether failing this gate blocks publishing rather than just warning
+    #[serde(default)]
+    #[specta(optional)]
+    pub blocking: bool,
+}
+
+/// The outcome of one gate
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GateResult {
+    pub label: String,
+    pub passed: bool,
+    pub blocking: bool,
+    /// Why the gate failed, e.g. the broken links
+    pub detail: Option<String>,
+}
+
+/// Gate results for a file
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishReadiness {
+    /// False when any blocking gate failed
+    pub ready: bool,
+    pub gates: Vec<GateResult>,
+}
+
+fn default_label(rule: &PublishRule) -> String {
+    match rule {
+        PublishRule::FieldPresent { field } => format!("{field} is set"),
+        PublishRule::MinItems { field, min } => format!("At least {min} {field}"),
+        PublishRule::NoBrokenLinks => "No broken internal links".to_string(),
+    }
+}
+
+/// Looks up a dotted field path in frontmatter
+pub(crate) fn field_value<'a>(
+    frontmatter: &'a IndexMap<String, Value>,
+    field: &str,
+) -> Option<&'a Value> {
+    let mut parts = field.split('.');
+    let mut value = frontmatter.get(parts.next()?)?;
+    for part in parts {
+        value = value.get(part)?;
+    }
+    Some(value)
+}
+
+fn is_present(value: Option<&Value>) -> bool {
+    match value {
+        None | Some(Value::Null) => false,
+        Some(Value::String(s)) => !s.trim().is_empty(),
+        Some(Value::Array(items)) => !items.is_empty(),
+        Some(Value::Object(map)) => !map.is_empty(),
+        Some(_) => true,
+    }
+}
+
+/// Link targets in Markdown links and images, outside code fences
+pub(crate) fn link_targets(body: &str) -> Vec<String> {
+    let link_re = Regex::new(r"\]\(\s*<?([^)\s>]+)>?(?:\s+[^)]*)?\)").unwrap();
+    let mut targets = Vec::new();
+    let mut fence: Option<&str> = None;
+
+    for line in body.lines() {
+        let trimmed = line.trim_start();
+        let marker = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m));
+        match (fence, marker) {
+            (None, Some(m)) => fence = Some(m),
+            (Some(open), Some(m)) if open == m => fence = None,
+            _ => {}
+        }
+        if fence.is_some() || marker.is_some() {
+            continue;
+        }
+        targets.extend(link_re.captures_iter(line).map(|c| c[1].to_string()));
+    }
+    targets
+}
+
+/// The slug an entry is published under: its `slug` field if set, otherwise its path
+/// within the collection without extension or a trailing `index`
+pub(crate) fn entry_slug(
+    path: &Path,
+    collection_path: &Path,
+    frontmatter: &IndexMap<String, Value>,
+) -> String {
+    if let Some(slug) = frontmatter.get("slug").and_then(Value::as_str) {
+        return slug.to_string();
+    }
+    let relative = path
+        .strip_prefix(collection_path)
+        .unwrap_or(path)
+        .with_extension("")
+        .to_string_lossy()
+        .replace('\\', "/");
+    relative
+        .strip_suffix("/index")
+        .unwrap_or(&relative)
+        .to_string()
+}
+
+/// Slugs of the entries in a collection
+fn collection_slugs(collection_path: &Path) -> HashSet<String> {
+    WalkDir::new(collection_path)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry.file_type().is_file()
+                && matches!(
+                    entry.path().extension().and_then(|e| e.to_str()),
+                    Some("md") | Some("mdx")
+                )
+        })
+        .map(|entry| {
+            let frontmatter = std::fs::read_to_string(entry.path())
+                .ok()
+                .and_then(|content| parse_frontmatter_internal(&content).ok())
+                .map(|parsed| parsed.frontmatter)
+                .unwrap_or_default();
+            entry_slug(entry.path(), collection_path, &frontmatter)
+        })
+        .collect()
+}
+
+/// The slug in a site-absolute link matching a URL pattern like `/writing/{slug}`
+pub(crate) fn slug_from_url<'a>(path: &'a str, url_pattern: &str) -> Option<&'a str> {
+    let (prefix, suffix) = url_pattern.split_once("{slug}")?;
+    path.strip_prefix(prefix)?
+        .trim_end_matches('/')
+        .strip_suffix(suffix.trim_end_matches('/'))
+        .filter(|slug| !slug.is_empty())
+}
+
+/// Internal links in `body` that don't resolve
+///
+/// # Arguments
+/// * `file_path` - The file the links are in; relative links resolve from its directory
+/// * `url_pattern` - The collection's URL pattern (e.g. `/writing/{slug}`), if any
+/// * `slugs` - Slugs of entries in the collection
+fn broken_links(
+    body: &str,
+    file_path: &Path,
+    url_pattern: Option<&str>,
+    slugs: &HashSet<String>,
+) -> Vec<String> {
+    let base = file_path.parent().unwrap_or(Path::new(""));
+
+    link_targets(body)
+        .into_iter()
+        .filter(|target| {
+            let path = target.split(['#', '?']).next().unwrap_or_default();
+            if path.is_empty() || target.contains(':') || path.starts_with('@') {
+                // Anchors, external URLs, mailto: and path aliases aren't checked
+                return false;
+            }
+            if path.starts_with('/') {
+                return url_pattern
+                    .and_then(|pattern| slug_from_url(path, pattern))
+                    .is_some_and(|slug| !slugs.contains(slug));
+            }
+            !base.join(path.replace("%20", " ")).exists()
+        })
+        .collect()
+}
+
+fn evaluate_gate(
+    gate: &PublishGate,
+    frontmatter: &IndexMap<String, Value>,
+    broken: &[String],
+) -> GateResult {
+    let detail = match &gate.rule {
+        PublishRule::FieldPresent { field } => {
+            (!is_present(field_value(frontmatter, field))).then(|| format!("{field} is empty"))
+        }
+        PublishRule::MinItems { field, min } => {
+            let count = match field_value(frontmatter, field) {
+                Some(Value::Array(items)) => items.len(),
+                _ => 0,
+            };
+            (count < *min as usize).then(|| format!("{field} has {count}"))
+        }
+        PublishRule::NoBrokenLinks => (!broken.is_empty()).then(|| broken.join(", ")),
+    };
+
+    GateResult {
+        label: gate
+            .label
+            .clone()
+            .unwrap_or_else(|| default_label(&gate.rule)),
+        passed: detail.is_none(),
+        blocking: gate.blocking,
+        detail,
+    }
+}
+
+/// A blocking gate for an entry's embargo, if it has one; unreadable times fail
+fn embargo_gate(
+    path: &Path,
+    frontmatter: &IndexMap<String, Value>,
+    now: DateTime<Utc>,
+) -> Option<GateResult> {
+    let (value, _) = embargo_value(path, frontmatter)?;
+    let detail = match parse_embargo_time(&value) {
+        Some(until) if until > now => Some(format!(
+            "Embargoed until {}",
+            until.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+        )),
+        Some(_) => None,
+        None => Some(format!(
+            "{EMBARGO_FIELD} '{}' isn't a valid time",
+            value.trim()
+        )),
+    };
+
+    Some(GateResult {
+        label: "Embargo".to_string(),
+        passed: detail.is_none(),
+        blocking: true,
+        detail,
+    })
+}
+
+/// Checks a file against its collection's publish gates and any embargo
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `file_path` - The absolute path to the file, as saved on disk
+/// * `collection_path` - The absolute path to the file's collection directory
+/// * `gates` - The collection's publish gates
+/// * `url_pattern` - The collection's URL pattern (e.g. `/writing/{slug}`), if any
+#[tauri::command]
+#[specta::specta]
+pub async fn check_publish_readiness(
+    project_path: String,
+    file_path: String,
+    collection_path: String,
+    gates: Vec<PublishGate>,
+    url_pattern: Option<String>,
+) -> Result<PublishReadiness, String> {
+    let path = validate_project_path(&file_path, &project_path)?;
+    let content =
+        std::fs::read_to_string(&path).map_err(|e| format!("Failed to read file: {e}"))?;
+    let parsed = parse_frontmatter_internal(&content)?;
+
+    let broken = if gates
+        .iter()
+        .any(|gate| gate.rule == PublishRule::NoBrokenLinks)
+    {
+        let collection = validate_project_path(&collection_path, &project_path)?;
+        let slugs = collection_slugs(&collection);
+        broken_links(&parsed.content, &path, url_pattern.as_deref(), &slugs)
+    } else {
+        Vec::new()
+    };
+
+    let results: Vec<GateResult> = embargo_gate(&path, &parsed.frontmatter, Utc::now())
+        .into_iter()
+        .chain(
+            gates
+                .iter()
+                .map(|gate| evaluate_gate(gate, &parsed.frontmatter, &broken)),
+        )
+        .collect();
+
+    Ok(PublishReadiness {
+        ready: results.iter().all(|r| r.passed || !r.blocking),
+        gates: results,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn gate(rule: PublishRule, blocking: bool) -> PublishGate {
+        PublishGate {
+            label: None,
+            rule,
+            blocking,
+        }
+    }
+
+    #[test]
+    fn test_evaluate_field_gates() {
+        let mut frontmatter = IndexMap::new();
+        frontmatter.insert("description".to_string(), json!("  "));
+        frontmatter.insert("tags".to_string(), json!(["rust"]));
+        frontmatter.insert("seo".to_string(), json!({ "image": "./hero.png" }));
+
+        let present = |field: &str| PublishRule::FieldPresent {
+            field: field.to_string(),
+        };
+        let result = evaluate_gate(&gate(present("description"), true), &frontmatter, &[]);
+        assert!(!result.passed);
+        assert_eq!(result.label, "description is set");
+        assert!(evaluate_gate(&gate(present("seo.image"), true), &frontmatter, &[]).passed);
+
+        let tags = |min| PublishRule::MinItems {
+            field: "tags".to_string(),
+            min,
+        };
+        assert!(evaluate_gate(&gate(tags(1), false), &frontmatter, &[]).passed);
+        let result = evaluate_gate(&gate(tags(2), false), &frontmatter, &[]);
+        assert_eq!(result.detail.as_deref(), Some("tags has 1"));
+    }
+
+    #[test]
+    fn test_broken_links() {
+        let temp = TempDir::new().unwrap();
+        let collection = temp.path().join("blog");
+        fs::create_dir_all(collection.join("nested")).unwrap();
+        fs::write(collection.join("first.md"), "# First").unwrap();
+        fs::write(collection.join("nested/index.md"), "# Nested").unwrap();
+        fs::write(collection.join("custom.md"), "---\nslug: renamed\n---\n").unwrap();
+        let file = collection.join("post.md");
+
+        let body = "[a](./first.md) [b](./missing.md#top) ![c](../img.png)\n\
+                    [d](/writing/nested/) [e](/writing/renamed) [f](/writing/gone)\n\
+                    [g](https://example.com) [h](#intro) [i](/about)\n\
+                    ```md\n[j](./in-code.md)\n```\n";
+        let slugs = collection_slugs(&collection);
+        let broken = broken_links(body, &file, Some("/writing/{slug}"), &slugs);
+
+        assert_eq!(
+            broken,
+            vec!["./missing.md#top", "../img.png", "/writing/gone"]
+        );
+    }
+
+    #[test]
+    fn test_embargo_gate() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("post.md");
+        let now = parse_embargo_time("2026-10-16T12:00:00Z").unwrap();
+        let mut frontmatter = IndexMap::new();
+        assert!(embargo_gate(&file, &frontmatter, now).is_none());
+
+        fs::write(temp.path().join("post.md.embargo"), "2026-10-17T00:00:00Z").unwrap();
+        let gate = embargo_gate(&file, &frontmatter, now).unwrap();
+        assert!(!gate.passed && gate.blocking);
+
+        frontmatter.insert(EMBARGO_FIELD.to_string(), json!("2026-10-16T11:00:00Z"));
+        assert!(embargo_gate(&file, &frontmatter, now).unwrap().passed);
+
+        frontmatter.insert(EMBARGO_FIELD.to_string(), json!("soon"));
+        let gate = embargo_gate(&file, &frontmatter, now).unwrap();
+        assert_eq!(
+            gate.detail.as_deref(),
+            Some("embargoUntil 'soon' isn't a valid time")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_check_publish_readiness() {
+        let temp = TempDir::new().unwrap();
+        let collection = temp.path().join("src/content/blog");
+        fs::create_dir_all(&collection).unwrap();
+        let file = collection.join("post.md");
+        fs::write(&file, "---\ntitle: Post\n---\n\n[Gone](./gone.md)\n").unwrap();
+
+        let readiness = check_publish_readiness(
+            temp.path().to_string_lossy().to_string(),
+            file.to_string_lossy().to_string(),
+            collection.to_string_lossy().to_string(),
+            vec![
+                gate(
+                    PublishRule::FieldPresent {
+                        field: "title".to_string(),
+                    },
+                    true,
+                ),
+                gate(PublishRule::NoBrokenLinks, false),
+            ],
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert!(readiness.ready);
+        assert!(readiness.gates[0].passed);
+        assert!(!readiness.gates[1].passed);
+        assert_eq!(readiness.gates[1].detail.as_deref(), Some("./gone.md"));
+    }
+}
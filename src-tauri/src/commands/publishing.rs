@@ -0,0 +1,501 @@
+//! Publishing a draft in one step
+//!
+//! `publish_file` turns off an entry's draft field (or moves its status field to a
+//! published value), fills in its publish date if it has none, and optionally renames
+//! it to the `YYYY-MM-DD-` prefix the rest of its collection uses. Publish gates are
+//! checked separately, by `check_publish_readiness`, before this is called.
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::dry_run;
+use crate::commands::files::{
+    parse_frontmatter_internal, rebuild_markdown_with_date_formats, rename_file_with_link_rewrite,
+    DateFormat, DateFormatSettings,
+};
+use crate::commands::link_refactor::LinkRewriteReport;
+use crate::commands::projects;
+use crate::schema_merger::{SchemaDefinition, SchemaField};
+use crate::security::validate_project_path;
+use chrono::{Local, NaiveDate, SecondsFormat, Utc};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Fields tried for the draft status, after the one the frontend maps
+const DRAFT_FIELDS: &[&str] = &["draft", "isDraft", "status", "state"];
+
+/// Fields tried for the publish date, after the ones the frontend maps
+pub(crate) const DATE_FIELDS: &[&str] = &["pubDate", "publishDate", "publishedDate", "date"];
+
+/// Values of a status enum that mean published, in order of preference
+const PUBLISHED_VALUES: &[&str] = &["published", "publish", "live", "public"];
+
+/// A file after `publish_file`
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishedFile {
+    /// The file's path, which changes when it's renamed
+    pub file_path: String,
+    pub frontmatter: IndexMap<String, Value>,
+    /// Hash of the written file, to expect on the next save
+    pub content_hash: String,
+    /// What renaming the file rewrote, when it was renamed
+    pub rename: Option<LinkRewriteReport>,
+}
+
+/// Renaming in `publish_file`: the file gets a `YYYY-MM-DD-` prefix for its publish date
+/// (replacing any date it starts with) when most of its collection is named that way
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishRename {
+    pub collection_path: String,
+    /// Collection URL patterns (e.g. `{ "blog": "/writing/{slug}" }`), for rewriting
+    /// links to the entry's URL
+    pub url_patterns: HashMap<String, String>,
+}
+
+/// How an entry is marked as a draft
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum DraftField {
+    /// A boolean, published when `false`
+    Flag(String),
+    /// An enum, published at the given value
+    Status(String, String),
+}
+
+impl DraftField {
+    /// Whether the entry is still a draft by this field
+    pub(crate) fn is_draft(&self, frontmatter: &IndexMap<String, Value>) -> bool {
+        match self {
+            DraftField::Flag(name) => frontmatter.get(name) == Some(&Value::Bool(true)),
+            DraftField::Status(name, published) => frontmatter
+                .get(name)
+                .and_then(Value::as_str)
+                .is_some_and(|status| status != published),
+        }
+    }
+}
+
+fn published_value(field: &SchemaField) -> Option<String> {
+    let values = field.enum_values.as_ref()?;
+    PUBLISHED_VALUES.iter().find_map(|published| {
+        values
+            .iter()
+            .find(|value| value.eq_ignore_ascii_case(published))
+            .cloned()
+    })
+}
+
+/// Finds the field that marks the entry as a draft
+///
+/// With a schema, this is the first candidate declared as a boolean, or as an enum with
+/// a published value. Without one, it's the first candidate the entry has, falling back
+/// to the mapped field (or `draft`).
+pub(crate) fn detect_draft_field(
+    mapped: Option<&str>,
+    schema: Option<&SchemaDefinition>,
+    frontmatter: &IndexMap<String, Value>,
+) -> Option<DraftField> {
+    let candidates: Vec<&str> = mapped
+        .into_iter()
+        .chain(DRAFT_FIELDS.iter().copied())
+        .collect();
+
+    if let Some(schema) = schema {
+        return candidates.iter().find_map(|name| {
+            let field = schema.fields.iter().find(|field| field.name == *name)?;
+            match field.field_type.as_str() {
+                "boolean" => Some(DraftField::Flag(field.name.clone())),
+                "enum" => Some(DraftField::Status(
+                    field.name.clone(),
+                    published_value(field)?,
+                )),
+                _ => None,
+            }
+        });
+    }
+
+    let name = candidates
+        .iter()
+        .find(|name| frontmatter.contains_key(**name))
+        .copied()
+        .or(mapped)
+        .unwrap_or("draft");
+    match frontmatter.get(name) {
+        Some(Value::String(_)) => None,
+        _ => Some(DraftField::Flag(name.to_string())),
+    }
+}
+
+/// Finds the publish date field: the first candidate the schema declares as a date, or
+/// without a schema, the first the entry has
+fn detect_date_field(
+    mapped: &[String],
+    schema: Option<&SchemaDefinition>,
+    frontmatter: &IndexMap<String, Value>,
+) -> Option<String> {
+    let mut candidates = mapped
+        .iter()
+        .map(String::as_str)
+        .chain(DATE_FIELDS.iter().copied());
+    match schema {
+        Some(schema) => candidates
+            .find(|name| {
+                schema
+                    .fields
+                    .iter()
+                    .any(|field| field.name == *name && field.field_type == "date")
+            })
+            .map(String::from),
+        None => candidates
+            .find(|name| frontmatter.contains_key(*name))
+            .map(String::from),
+    }
+}
+
+fn is_empty(value: Option<&Value>) -> bool {
+    match value {
+        None | Some(Value::Null) => true,
+        Some(Value::String(s)) => s.trim().is_empty(),
+        Some(_) => false,
+    }
+}
+
+/// Today's date in the format the field is saved in
+fn publish_date_value(field: &str, date_formats: &DateFormatSettings) -> Value {
+    let date = match date_formats.format_for(field) {
+        DateFormat::DateOnly => Local::now().format("%Y-%m-%d").to_string(),
+        DateFormat::DateTime | DateFormat::Timestamp => {
+            Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true)
+        }
+    };
+    Value::String(date)
+}
+
+/// Splits a `YYYY-MM-DD-` prefix off a file name
+fn split_date_prefix(file_name: &str) -> Option<(NaiveDate, &str)> {
+    let date = NaiveDate::parse_from_str(file_name.get(..10)?, "%Y-%m-%d").ok()?;
+    let rest = file_name.get(10..)?.strip_prefix('-')?;
+    Some((date, rest))
+}
+
+/// Whether most other entries in the collection are named with a date prefix
+fn collection_uses_date_prefix(collection_path: &Path, file_path: &Path) -> bool {
+    let (mut dated, mut total) = (0, 0);
+    for entry in walkdir::WalkDir::new(collection_path)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file() && entry.path() != file_path)
+    {
+        let name = entry.file_name().to_string_lossy();
+        if !(name.ends_with(".md") || name.ends_with(".mdx")) {
+            continue;
+        }
+        total += 1;
+        if split_date_prefix(&name).is_some() {
+            dated += 1;
+        }
+    }
+    total > 0 && dated * 2 > total
+}
+
+/// The file's path with a date prefix for `date`, replacing any it has; `None` when the
+/// name is already right
+fn dated_path(file_path: &Path, date: NaiveDate) -> Option<PathBuf> {
+    let file_name = file_path.file_name()?.to_str()?;
+    let rest = split_date_prefix(file_name).map_or(file_name, |(_, rest)| rest);
+    let dated = format!("{}-{rest}", date.format("%Y-%m-%d"));
+    (dated != file_name).then(|| file_path.with_file_name(dated))
+}
+
+/// The date a value starts with, e.g. `2024-01-15` from `2024-01-15T09:00:00Z`
+fn value_date(value: Option<&Value>) -> Option<NaiveDate> {
+    let date = value?.as_str()?.get(..10)?;
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+/// Applies the publish to the entry's frontmatter, returning the date it's published on
+fn publish_frontmatter(
+    frontmatter: &mut IndexMap<String, Value>,
+    draft_field: &DraftField,
+    date_field: Option<&str>,
+    date_formats: &DateFormatSettings,
+) -> NaiveDate {
+    match draft_field {
+        DraftField::Flag(name) => frontmatter.insert(name.clone(), Value::Bool(false)),
+        DraftField::Status(name, value) => {
+            frontmatter.insert(name.clone(), Value::String(value.clone()))
+        }
+    };
+
+    if let Some(field) = date_field {
+        if is_empty(frontmatter.get(field)) {
+            frontmatter.insert(field.to_string(), publish_date_value(field, date_formats));
+        }
+    }
+
+    value_date(date_field.and_then(|field| frontmatter.get(field)))
+        .unwrap_or_else(|| Local::now().date_naive())
+}
+
+/// Publishes a draft: clears its draft status and sets its publish date if empty
+///
+/// Entries that aren't drafts are refused and left as they are.
+///
+/// The draft field is the mapped one when the schema declares it (as a boolean or an
+/// enum with a `published`-like value), else `draft`, `isDraft`, `status` or `state`.
+/// The date field is found the same way from the mapped fields and `pubDate`,
+/// `publishDate`, `publishedDate` and `date`. Fields keep their schema order.
+///
+/// # Arguments
+/// * `complete_schema` - The collection's serialized complete schema
+/// * `draft_field` - The collection's mapped draft field
+/// * `date_fields` - The collection's mapped publish date fields, first match used
+/// * `date_formats` - How dates are saved in the collection
+/// * `rename` - Rename the file for its publish date if its collection is named by date;
+///   links to it are rewritten as by `rename_file_with_link_rewrite`
+#[tauri::command]
+#[specta::specta]
+#[allow(clippy::too_many_arguments)]
+pub async fn publish_file(
+    file_path: String,
+    project_root: String,
+    complete_schema: Option<String>,
+    draft_field: Option<String>,
+    date_fields: Option<Vec<String>>,
+    date_formats: Option<DateFormatSettings>,
+    rename: Option<PublishRename>,
+    origin: Option<String>,
+) -> Result<PublishedFile, String> {
+    let origin = origin.as_deref().unwrap_or("publish_file");
+    let validated_path = validate_project_path(&file_path, &project_root)?;
+    let schema: Option<SchemaDefinition> = complete_schema
+        .map(|schema| {
+            serde_json::from_str(&schema).map_err(|e| format!("Failed to parse schema: {e}"))
+        })
+        .transpose()?;
+    let date_formats = date_formats.unwrap_or_default();
+
+    let content = std::fs::read_to_string(&validated_path)
+        .map_err(|e| format!("Failed to read file: {e}"))?;
+    let mut parsed = parse_frontmatter_internal(&content)?;
+
+    let draft = detect_draft_field(draft_field.as_deref(), schema.as_ref(), &parsed.frontmatter)
+        .ok_or("No draft or published status field found for this collection")?;
+    if !draft.is_draft(&parsed.frontmatter) {
+        return Err("This entry is already published".to_string());
+    }
+    let date_field = detect_date_field(
+        date_fields.as_deref().unwrap_or_default(),
+        schema.as_ref(),
+        &parsed.frontmatter,
+    );
+    let published_on = publish_frontmatter(
+        &mut parsed.frontmatter,
+        &draft,
+        date_field.as_deref(),
+        &date_formats,
+    );
+
+    // Check the new name is free before anything is written
+    let new_path = rename.as_ref().and_then(|rename| {
+        collection_uses_date_prefix(Path::new(&rename.collection_path), &validated_path)
+            .then(|| dated_path(&validated_path, published_on))
+            .flatten()
+    });
+    if let Some(new_path) = &new_path {
+        if dry_run::exists(new_path) {
+            return Err(format!(
+                "Can't rename to {}: a file with that name already exists",
+                new_path.display()
+            ));
+        }
+    }
+
+    let field_order = match &schema {
+        Some(schema) => schema
+            .fields
+            .iter()
+            .map(|field| field.name.clone())
+            .collect(),
+        None => parsed.frontmatter.keys().cloned().collect(),
+    };
+    let new_content = rebuild_markdown_with_date_formats(
+        &parsed.frontmatter,
+        &parsed.imports,
+        &parsed.content,
+        Some(field_order),
+        &date_formats,
+    )?;
+    dry_run::write_atomic(&validated_path, &new_content)
+        .map_err(|e| format!("Failed to write file: {e}"))?;
+    audit_log::record(AuditAction::Write, &validated_path, None, origin);
+
+    let (file_path, rename) = match (new_path, rename) {
+        (Some(new_path), Some(rename)) => {
+            let new_path = new_path.to_string_lossy().to_string();
+            let report = rename_file_with_link_rewrite(
+                validated_path.to_string_lossy().to_string(),
+                new_path.clone(),
+                project_root.clone(),
+                projects::path_settings(&project_root).content_directory,
+                rename.url_patterns,
+                Some(origin.to_string()),
+            )
+            .await
+            .map_err(|e| format!("Published, but renaming the file failed: {e}"))?;
+            (new_path, Some(report))
+        }
+        _ => (validated_path.to_string_lossy().to_string(), None),
+    };
+
+    let written = parse_frontmatter_internal(&new_content)?;
+    Ok(PublishedFile {
+        file_path,
+        frontmatter: written.frontmatter,
+        content_hash: written.content_hash,
+        rename,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn schema(fields: Value) -> String {
+        serde_json::json!({ "collectionName": "blog", "fields": fields }).to_string()
+    }
+
+    fn field(name: &str, field_type: &str, enum_values: Option<&[&str]>) -> Value {
+        serde_json::json!({
+            "name": name,
+            "label": name,
+            "fieldType": field_type,
+            "required": false,
+            "enumValues": enum_values,
+        })
+    }
+
+    #[test]
+    fn test_detect_draft_field() {
+        let schema: SchemaDefinition = serde_json::from_str(&schema(Value::Array(vec![
+            field("title", "string", None),
+            field("status", "enum", Some(&["Draft", "Review", "Published"])),
+        ])))
+        .unwrap();
+        let frontmatter = IndexMap::new();
+
+        // The mapped `draft` isn't in the schema, so the status enum is used
+        assert_eq!(
+            detect_draft_field(Some("draft"), Some(&schema), &frontmatter),
+            Some(DraftField::Status(
+                "status".to_string(),
+                "Published".to_string()
+            ))
+        );
+        // Without a schema, a missing mapped field is treated as a flag
+        assert_eq!(
+            detect_draft_field(Some("hidden"), None, &frontmatter),
+            Some(DraftField::Flag("hidden".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_dated_path() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        assert_eq!(
+            dated_path(Path::new("/blog/my-post.md"), date),
+            Some(PathBuf::from("/blog/2024-03-05-my-post.md"))
+        );
+        assert_eq!(
+            dated_path(Path::new("/blog/2023-12-31-my-post.md"), date),
+            Some(PathBuf::from("/blog/2024-03-05-my-post.md"))
+        );
+        assert_eq!(
+            dated_path(Path::new("/blog/2024-03-05-my-post.md"), date),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_publish_file() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        let blog = root.join("src/content/blog");
+        fs::create_dir_all(&blog).unwrap();
+        fs::write(blog.join("2024-01-01-first.md"), "---\ntitle: First\n---\n").unwrap();
+        fs::write(
+            blog.join("2024-02-01-second.md"),
+            "---\ntitle: Second\n---\n",
+        )
+        .unwrap();
+        let post = blog.join("new-post.md");
+        fs::write(
+            &post,
+            "---\ntitle: New\ndraft: true\npubDate:\n---\n\nBody\n",
+        )
+        .unwrap();
+
+        let complete_schema = schema(Value::Array(vec![
+            field("title", "string", None),
+            field("pubDate", "date", None),
+            field("draft", "boolean", None),
+        ]));
+        let date_formats = DateFormatSettings {
+            default: None,
+            fields: Some(HashMap::from([(
+                "pubDate".to_string(),
+                DateFormat::DateTime,
+            )])),
+        };
+
+        let published = publish_file(
+            post.to_string_lossy().to_string(),
+            root.to_string_lossy().to_string(),
+            Some(complete_schema),
+            Some("draft".to_string()),
+            None,
+            Some(date_formats),
+            Some(PublishRename {
+                collection_path: blog.to_string_lossy().to_string(),
+                url_patterns: HashMap::new(),
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+
+        let pub_date = published.frontmatter["pubDate"].as_str().unwrap();
+        assert!(pub_date.ends_with('Z'));
+        assert_eq!(published.frontmatter["draft"], false);
+
+        let expected = blog.join(format!("{}-new-post.md", &pub_date[..10]));
+        assert_eq!(published.file_path, expected.to_string_lossy());
+        assert!(!post.exists());
+        // Schema order, with the date quoted as configured
+        let written = fs::read_to_string(&expected).unwrap();
+        assert!(written.starts_with(&format!(
+            "---\ntitle: New\npubDate: '{pub_date}'\ndraft: false\n---\n"
+        )));
+        assert!(written.ends_with("Body\n"));
+
+        // Publishing again leaves the entry alone
+        let again = publish_file(
+            published.file_path.clone(),
+            root.to_string_lossy().to_string(),
+            None,
+            Some("draft".to_string()),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await;
+        assert_eq!(again, Err("This entry is already published".to_string()));
+        assert_eq!(fs::read_to_string(&expected).unwrap(), written);
+    }
+}
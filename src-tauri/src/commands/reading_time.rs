@@ -0,0 +1,292 @@
+//! Reading time estimates
+//!
+//! Prose is read at the project's words-per-minute (`readingTime.wordsPerMinute`,
+//! default 200). Words inside fenced code blocks are weighted (default 1.5, since code
+//! is read more slowly) and each image adds a fixed number of seconds (default 12).
+//! An entry can replace the estimate with its own value in `readingTimeOverride`.
+//!
+//! When the project opts in with `readingTime.writeOnSave`, `save_markdown_content`
+//! writes the estimate into the `readingTime` field of entries whose collection schema
+//! declares it.
+
+use indexmap::IndexMap;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+
+use crate::commands::files::parse_yaml_to_json;
+
+const DEFAULT_WORDS_PER_MINUTE: u32 = 200;
+const DEFAULT_SECONDS_PER_IMAGE: u32 = 12;
+const DEFAULT_CODE_WEIGHT: f64 = 1.5;
+const DEFAULT_FIELD: &str = "readingTime";
+const DEFAULT_OVERRIDE_FIELD: &str = "readingTimeOverride";
+
+/// The project's `readingTime` setting
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadingTimeSettings {
+    /// Reading speed for prose (defaults to 200)
+    #[serde(default)]
+    #[specta(optional)]
+    pub words_per_minute: Option<u32>,
+    /// Time added for each image (defaults to 12)
+    #[serde(default)]
+    #[specta(optional)]
+    pub seconds_per_image: Option<u32>,
+    /// Each word in a code block counts as this many words (defaults to 1.5)
+    #[serde(default)]
+    #[specta(optional)]
+    pub code_weight: Option<f64>,
+    /// Frontmatter field the estimate is written to (defaults to `readingTime`)
+    #[serde(default)]
+    #[specta(optional)]
+    pub field: Option<String>,
+    /// Frontmatter field with an entry's own reading time in minutes (defaults to
+    /// `readingTimeOverride`)
+    #[serde(default)]
+    #[specta(optional)]
+    pub override_field: Option<String>,
+    /// Write the estimate into the schema's reading time field on save
+    #[serde(default)]
+    #[specta(optional)]
+    pub write_on_save: Option<bool>,
+}
+
+impl ReadingTimeSettings {
+    fn field(&self) -> &str {
+        self.field.as_deref().unwrap_or(DEFAULT_FIELD)
+    }
+
+    fn override_field(&self) -> &str {
+        self.override_field
+            .as_deref()
+            .unwrap_or(DEFAULT_OVERRIDE_FIELD)
+    }
+}
+
+/// A reading time estimate for one entry
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadingTime {
+    /// Words outside code blocks
+    pub words: u32,
+    /// Words inside fenced code blocks
+    pub code_words: u32,
+    pub images: u32,
+    /// Estimated reading time in seconds
+    pub seconds: u32,
+    /// Whole minutes, rounded up; the entry's override when it has one
+    pub minutes: u32,
+    /// True if `minutes` came from the entry's override field
+    pub overridden: bool,
+}
+
+/// Counts prose words, code words and images in a Markdown/MDX body
+fn count_content(body: &str) -> (u32, u32, u32) {
+    let image_re = Regex::new(r"!\[[^\]]*\]\([^)]*\)|<(?:img|Image|Picture)\b[^>]*>").unwrap();
+    let link_target_re = Regex::new(r"\]\([^)]*\)").unwrap();
+    let count_words = |text: &str| {
+        text.split_whitespace()
+            .filter(|word| word.chars().any(char::is_alphanumeric))
+            .count() as u32
+    };
+
+    let (mut words, mut code_words, mut images) = (0, 0, 0);
+    let mut fence: Option<&str> = None;
+    for line in body.lines() {
+        let trimmed = line.trim_start();
+        let marker = ["```", "~~~"]
+            .into_iter()
+            .find(|marker| trimmed.starts_with(marker));
+
+        match (fence, marker) {
+            (Some(open), Some(marker)) if open == marker => fence = None,
+            (Some(_), _) => code_words += count_words(line),
+            (None, Some(marker)) => fence = Some(marker),
+            (None, None) => {
+                images += image_re.find_iter(line).count() as u32;
+                let prose = image_re.replace_all(line, " ");
+                words += count_words(&link_target_re.replace_all(&prose, "]"));
+            }
+        }
+    }
+
+    (words, code_words, images)
+}
+
+/// Prose words in a body: the word count reading time estimates start from
+pub(crate) fn word_count(body: &str) -> u32 {
+    count_content(body).0
+}
+
+/// Estimates reading time for a body, honouring the entry's override field
+pub(crate) fn estimate(
+    body: &str,
+    frontmatter: Option<&IndexMap<String, Value>>,
+    settings: &ReadingTimeSettings,
+) -> ReadingTime {
+    let (words, code_words, images) = count_content(body);
+
+    let words_per_minute = settings
+        .words_per_minute
+        .filter(|wpm| *wpm > 0)
+        .unwrap_or(DEFAULT_WORDS_PER_MINUTE);
+    let weighted_words =
+        words as f64 + code_words as f64 * settings.code_weight.unwrap_or(DEFAULT_CODE_WEIGHT);
+    let seconds = (weighted_words * 60.0 / words_per_minute as f64).round() as u32
+        + images
+            * settings
+                .seconds_per_image
+                .unwrap_or(DEFAULT_SECONDS_PER_IMAGE);
+
+    let override_minutes = frontmatter
+        .and_then(|frontmatter| frontmatter.get(settings.override_field()))
+        .and_then(Value::as_f64)
+        .filter(|minutes| *minutes >= 0.0)
+        .map(|minutes| minutes.round() as u32);
+
+    ReadingTime {
+        words,
+        code_words,
+        images,
+        seconds,
+        minutes: override_minutes.unwrap_or(seconds.div_ceil(60)),
+        overridden: override_minutes.is_some(),
+    }
+}
+
+/// Writes the estimate into the reading time field before a save
+///
+/// Only applies when the project opts in and `schema_fields` (the collection schema's
+/// field order) includes the field. Unedited raw frontmatter is returned untouched
+/// unless the stored value changes, so saves don't reformat YAML needlessly.
+pub(crate) fn apply_on_save(
+    frontmatter: Option<IndexMap<String, Value>>,
+    raw_frontmatter: Option<String>,
+    body: &str,
+    schema_fields: Option<&[String]>,
+    settings: &ReadingTimeSettings,
+) -> Result<(Option<IndexMap<String, Value>>, Option<String>), String> {
+    let field = settings.field();
+    let in_schema = schema_fields.is_some_and(|fields| fields.iter().any(|f| f == field));
+    if settings.write_on_save != Some(true) || !in_schema {
+        return Ok((frontmatter, raw_frontmatter));
+    }
+
+    let mut fields = match (&frontmatter, raw_frontmatter.as_deref()) {
+        (Some(fields), _) => fields.clone(),
+        (None, Some(raw)) if !raw.trim().is_empty() => parse_yaml_to_json(raw)?,
+        _ => IndexMap::new(),
+    };
+
+    let minutes = estimate(body, Some(&fields), settings).minutes;
+    if fields.get(field).and_then(Value::as_u64) == Some(minutes as u64) {
+        return Ok((frontmatter, raw_frontmatter));
+    }
+
+    fields.insert(field.to_string(), Value::from(minutes));
+    Ok((Some(fields), None))
+}
+
+/// Estimates how long an entry takes to read
+///
+/// # Arguments
+/// * `content` - The body (without frontmatter and imports)
+/// * `frontmatter` - The entry's frontmatter, for its override field
+/// * `settings` - The project's `readingTime` setting
+#[tauri::command]
+#[specta::specta]
+pub async fn get_reading_time(
+    content: String,
+    frontmatter: Option<IndexMap<String, Value>>,
+    settings: Option<ReadingTimeSettings>,
+) -> Result<ReadingTime, String> {
+    Ok(estimate(
+        &content,
+        frontmatter.as_ref(),
+        &settings.unwrap_or_default(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn opted_in() -> ReadingTimeSettings {
+        ReadingTimeSettings {
+            write_on_save: Some(true),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_count_content_separates_code_and_images() {
+        let body = "Intro with a [link](https://example.com/long/path) here.\n\n\
+            ![Alt text](./photo.jpg)\n\n\
+            ```js\nconst answer = 42;\n```\n\n\
+            <Image src={hero} alt=\"Hero\" />\nThe end.";
+
+        assert_eq!(count_content(body), (7, 3, 2));
+    }
+
+    #[test]
+    fn test_estimate_uses_settings_and_override() {
+        let body = "word ".repeat(400);
+        let settings = ReadingTimeSettings {
+            words_per_minute: Some(100),
+            ..Default::default()
+        };
+
+        let estimate_default = estimate(&body, None, &ReadingTimeSettings::default());
+        assert_eq!(estimate_default.seconds, 120);
+        assert_eq!(estimate_default.minutes, 2);
+
+        let slow = estimate(&body, None, &settings);
+        assert_eq!(slow.minutes, 4);
+        assert!(!slow.overridden);
+
+        let mut frontmatter = IndexMap::new();
+        frontmatter.insert("readingTimeOverride".to_string(), Value::from(9));
+        let overridden = estimate(&body, Some(&frontmatter), &settings);
+        assert_eq!(overridden.minutes, 9);
+        assert!(overridden.overridden);
+
+        assert_eq!(estimate("", None, &settings).minutes, 0);
+    }
+
+    #[test]
+    fn test_apply_on_save_requires_opt_in_and_schema_field() {
+        let body = "word ".repeat(450);
+        let raw = Some("title: Hello".to_string());
+        let schema = vec!["title".to_string(), "readingTime".to_string()];
+
+        let untouched = apply_on_save(
+            None,
+            raw.clone(),
+            &body,
+            Some(&schema[..]),
+            &ReadingTimeSettings::default(),
+        )
+        .unwrap();
+        assert_eq!(untouched, (None, raw.clone()));
+
+        let not_in_schema =
+            apply_on_save(None, raw.clone(), &body, Some(&schema[..1]), &opted_in()).unwrap();
+        assert_eq!(not_in_schema, (None, raw.clone()));
+
+        let (fields, raw_after) =
+            apply_on_save(None, raw.clone(), &body, Some(&schema[..]), &opted_in()).unwrap();
+        let fields = fields.unwrap();
+        assert_eq!(fields["title"], "Hello");
+        assert_eq!(fields["readingTime"], 3);
+        assert!(raw_after.is_none());
+
+        // An up-to-date value leaves raw frontmatter alone
+        let current = Some("title: Hello\nreadingTime: 3".to_string());
+        let unchanged =
+            apply_on_save(None, current.clone(), &body, Some(&schema[..]), &opted_in()).unwrap();
+        assert_eq!(unchanged, (None, current));
+    }
+}
@@ -0,0 +1,439 @@
+//! Recovery data written when a save fails
+//!
+//! Each failed save leaves an entry in `recovery/` in app data: a
+//! `{timestamp}-{file}.recovery.json` with the full editor state plus a readable
+//! `.recovery.md` copy of the content. With encryption enabled the entry is a single
+//! `.recovery.enc` file instead, sealed with ChaCha20-Poly1305 using a key kept in the
+//! OS keychain, so document contents never sit on disk in plaintext.
+//!
+//! Every save prunes old entries by count, age and total size. The entry just written
+//! is always kept.
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use chrono::{Local, NaiveDateTime, TimeZone};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Manager};
+
+/// Entries kept when the settings don't say otherwise
+const DEFAULT_MAX_FILES: u32 = 50;
+const DEFAULT_MAX_AGE_DAYS: u32 = 30;
+const DEFAULT_MAX_TOTAL_SIZE_MB: u32 = 100;
+
+/// Keychain entry holding the recovery encryption key
+const KEYCHAIN_SERVICE: &str = "is.danny.astroeditor";
+const KEYCHAIN_ACCOUNT: &str = "recovery-data-key";
+
+const NONCE_LEN: usize = 12;
+
+/// Suffixes of the files making up one recovery entry
+const ENTRY_SUFFIXES: [&str; 3] = [".recovery.json", ".recovery.md", ".recovery.enc"];
+
+/// The `recovery` preference
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoverySettings {
+    /// Most recovery entries to keep (defaults to 50)
+    pub max_files: Option<u32>,
+    /// Entries older than this are removed (defaults to 30)
+    pub max_age_days: Option<u32>,
+    /// Oldest entries are removed once the folder exceeds this (defaults to 100)
+    pub max_total_size_mb: Option<u32>,
+    /// Encrypt entries with a key stored in the system keychain
+    pub encrypt: Option<bool>,
+}
+
+/// Limits applied to the recovery directory after each save
+#[derive(Debug, Clone, PartialEq)]
+struct RetentionPolicy {
+    max_files: usize,
+    max_age: Duration,
+    max_total_bytes: u64,
+}
+
+impl From<&RecoverySettings> for RetentionPolicy {
+    fn from(settings: &RecoverySettings) -> Self {
+        let days = settings.max_age_days.unwrap_or(DEFAULT_MAX_AGE_DAYS);
+        let size_mb = settings
+            .max_total_size_mb
+            .unwrap_or(DEFAULT_MAX_TOTAL_SIZE_MB);
+        Self {
+            max_files: settings.max_files.unwrap_or(DEFAULT_MAX_FILES).max(1) as usize,
+            max_age: Duration::from_secs(u64::from(days) * 24 * 60 * 60),
+            max_total_bytes: u64::from(size_mb) * 1024 * 1024,
+        }
+    }
+}
+
+/// The files belonging to one failed save
+#[derive(Debug)]
+struct RecoveryEntry {
+    files: Vec<PathBuf>,
+    created: SystemTime,
+    size: u64,
+}
+
+fn recovery_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .resolve("recovery", BaseDirectory::AppLocalData)
+        .map_err(|e| format!("Failed to resolve recovery directory: {e}"))
+}
+
+/// The entry name a recovery file belongs to, e.g. `20240115-103000-post.md`
+fn entry_stem(file_name: &str) -> Option<&str> {
+    ENTRY_SUFFIXES
+        .iter()
+        .find_map(|suffix| file_name.strip_suffix(*suffix))
+}
+
+/// When an entry was written, from its `%Y%m%d-%H%M%S` prefix
+fn stem_timestamp(stem: &str) -> Option<SystemTime> {
+    let prefix = stem.get(..15)?;
+    let naive = NaiveDateTime::parse_from_str(prefix, "%Y%m%d-%H%M%S").ok()?;
+    let local = Local.from_local_datetime(&naive).earliest()?;
+    Some(SystemTime::from(local))
+}
+
+/// Recovery entries in `dir`, newest first
+fn list_entries(dir: &Path) -> Vec<RecoveryEntry> {
+    let mut grouped: BTreeMap<String, RecoveryEntry> = BTreeMap::new();
+
+    for file in std::fs::read_dir(dir).into_iter().flatten().flatten() {
+        let file_name = file.file_name().to_string_lossy().to_string();
+        let Some(stem) = entry_stem(&file_name) else {
+            continue;
+        };
+        let Ok(metadata) = file.metadata() else {
+            continue;
+        };
+        let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+
+        let entry = grouped
+            .entry(stem.to_string())
+            .or_insert_with(|| RecoveryEntry {
+                files: Vec::new(),
+                created: stem_timestamp(stem).unwrap_or(modified),
+                size: 0,
+            });
+        entry.files.push(file.path());
+        entry.size += metadata.len();
+    }
+
+    let mut entries: Vec<RecoveryEntry> = grouped.into_values().collect();
+    entries.sort_by(|a, b| b.created.cmp(&a.created));
+    entries
+}
+
+/// Removes entries beyond the policy's count, age and size limits
+///
+/// The newest entry is always kept. Returns the number of entries removed.
+fn apply_retention(dir: &Path, policy: &RetentionPolicy, now: SystemTime) -> usize {
+    let mut removed = 0;
+    let mut total_bytes = 0;
+
+    for (index, entry) in list_entries(dir).into_iter().enumerate() {
+        total_bytes += entry.size;
+        let too_old = now
+            .duration_since(entry.created)
+            .is_ok_and(|age| age > policy.max_age);
+        let keep = index == 0
+            || (index < policy.max_files && !too_old && total_bytes <= policy.max_total_bytes);
+        if keep {
+            continue;
+        }
+
+        for file in &entry.files {
+            if let Err(e) = std::fs::remove_file(file) {
+                log::warn!(
+                    "Astro Editor [RECOVERY] Failed to remove {}: {e}",
+                    file.display()
+                );
+            }
+        }
+        removed += 1;
+    }
+
+    if removed > 0 {
+        log::info!("Astro Editor [RECOVERY] Removed {removed} old recovery entries");
+    }
+    removed
+}
+
+/// The recovery key from the keychain, created on first use
+pub(crate) fn recovery_key() -> Result<Vec<u8>, String> {
+    let entry = keyring::Entry::new(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT)
+        .map_err(|e| format!("Failed to access keychain: {e}"))?;
+
+    match entry.get_secret() {
+        Ok(key) => Ok(key),
+        Err(keyring::Error::NoEntry) => {
+            let key = ChaCha20Poly1305::generate_key(&mut OsRng).to_vec();
+            entry
+                .set_secret(&key)
+                .map_err(|e| format!("Failed to store recovery key in keychain: {e}"))?;
+            Ok(key)
+        }
+        Err(e) => Err(format!("Failed to read recovery key from keychain: {e}")),
+    }
+}
+
+/// Seals `plaintext` as nonce followed by ciphertext
+pub(crate) fn encrypt(key: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    let cipher = ChaCha20Poly1305::new_from_slice(key).map_err(|_| "Invalid recovery key")?;
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .map_err(|_| "Failed to encrypt recovery data")?;
+
+    let mut sealed = nonce.to_vec();
+    sealed.extend_from_slice(&ciphertext);
+    Ok(sealed)
+}
+
+pub(crate) fn decrypt(key: &[u8], sealed: &[u8]) -> Result<Vec<u8>, String> {
+    if sealed.len() < NONCE_LEN {
+        return Err("Recovery file is truncated".to_string());
+    }
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+    let cipher = ChaCha20Poly1305::new_from_slice(key).map_err(|_| "Invalid recovery key")?;
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "Failed to decrypt recovery data (was the keychain key removed?)".to_string())
+}
+
+/// Writes one recovery entry for `data` into `dir`
+fn write_entry(dir: &Path, stem: &str, data: &Value, key: Option<&[u8]>) -> Result<(), String> {
+    let json_content = serde_json::to_string_pretty(data)
+        .map_err(|e| format!("Failed to serialize recovery data: {e}"))?;
+
+    if let Some(key) = key {
+        let sealed = encrypt(key, json_content.as_bytes())?;
+        return std::fs::write(dir.join(format!("{stem}.recovery.enc")), sealed)
+            .map_err(|e| format!("Failed to write encrypted recovery data: {e}"));
+    }
+
+    // Save JSON file with complete state
+    std::fs::write(dir.join(format!("{stem}.recovery.json")), json_content)
+        .map_err(|e| format!("Failed to write recovery JSON: {e}"))?;
+
+    // Save Markdown file with just the content
+    let md_content = data
+        .get("editorContent")
+        .and_then(|v| v.as_str())
+        .unwrap_or("");
+    std::fs::write(dir.join(format!("{stem}.recovery.md")), md_content)
+        .map_err(|e| format!("Failed to write recovery Markdown: {e}"))
+}
+
+/// Saves the editor state after a failed save, then prunes old recovery entries
+///
+/// # Arguments
+/// * `data` - The editor state (`fileName`, `editorContent`, `frontmatter`, ...)
+/// * `settings` - The `recovery` preference; retention defaults apply when unset
+#[tauri::command]
+#[specta::specta]
+pub async fn save_recovery_data(
+    app: AppHandle,
+    data: Value,
+    settings: Option<RecoverySettings>,
+) -> Result<(), String> {
+    let settings = settings.unwrap_or_default();
+    let timestamp = Local::now().format("%Y%m%d-%H%M%S").to_string();
+    let filename = data
+        .get("fileName")
+        .and_then(|v| v.as_str())
+        .unwrap_or("untitled");
+
+    let recovery_dir = recovery_dir(&app)?;
+    std::fs::create_dir_all(&recovery_dir)
+        .map_err(|e| format!("Failed to create recovery directory: {e}"))?;
+
+    let key = if settings.encrypt.unwrap_or(false) {
+        Some(recovery_key()?)
+    } else {
+        None
+    };
+    write_entry(
+        &recovery_dir,
+        &format!("{timestamp}-{filename}"),
+        &data,
+        key.as_deref(),
+    )?;
+
+    apply_retention(
+        &recovery_dir,
+        &RetentionPolicy::from(&settings),
+        SystemTime::now(),
+    );
+    Ok(())
+}
+
+/// Reads a recovery entry's editor state, decrypting it if needed
+///
+/// # Arguments
+/// * `file_name` - A `.recovery.json` or `.recovery.enc` file in the recovery directory
+#[tauri::command]
+#[specta::specta]
+pub async fn read_recovery_data(app: AppHandle, file_name: String) -> Result<Value, String> {
+    let recovery_dir = recovery_dir(&app)?;
+    let path =
+        crate::security::validate_app_data_path(&file_name, &recovery_dir.to_string_lossy())?;
+
+    let bytes = std::fs::read(&path).map_err(|e| format!("Failed to read recovery file: {e}"))?;
+    let json = if file_name.ends_with(".recovery.enc") {
+        decrypt(&recovery_key()?, &bytes)?
+    } else {
+        bytes
+    };
+
+    serde_json::from_slice(&json).map_err(|e| format!("Failed to parse recovery data: {e}"))
+}
+
+/// Deletes recovery entries, returning how many were removed
+///
+/// # Arguments
+/// * `older_than_days` - Only remove entries older than this; removes everything when unset
+#[tauri::command]
+#[specta::specta]
+pub async fn purge_recovery_data(
+    app: AppHandle,
+    older_than_days: Option<u32>,
+) -> Result<u32, String> {
+    let recovery_dir = recovery_dir(&app)?;
+    let cutoff = older_than_days
+        .map(|days| SystemTime::now() - Duration::from_secs(u64::from(days) * 24 * 60 * 60));
+
+    let mut removed = 0;
+    for entry in list_entries(&recovery_dir) {
+        if cutoff.is_some_and(|cutoff| entry.created >= cutoff) {
+            continue;
+        }
+        for file in &entry.files {
+            std::fs::remove_file(file)
+                .map_err(|e| format!("Failed to remove {}: {e}", file.display()))?;
+        }
+        removed += 1;
+    }
+
+    log::info!("Astro Editor [RECOVERY] Purged {removed} recovery entries");
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_plain_entry(dir: &Path, stem: &str, content: &str) {
+        let data = serde_json::json!({ "fileName": "post.md", "editorContent": content });
+        write_entry(dir, stem, &data, None).unwrap();
+    }
+
+    fn stems(dir: &Path) -> Vec<String> {
+        let mut stems: Vec<String> = std::fs::read_dir(dir)
+            .unwrap()
+            .flatten()
+            .filter_map(|f| entry_stem(&f.file_name().to_string_lossy()).map(String::from))
+            .collect();
+        stems.sort();
+        stems.dedup();
+        stems
+    }
+
+    fn policy(max_files: u32, max_age_days: u32, max_total_size_mb: u32) -> RetentionPolicy {
+        RetentionPolicy::from(&RecoverySettings {
+            max_files: Some(max_files),
+            max_age_days: Some(max_age_days),
+            max_total_size_mb: Some(max_total_size_mb),
+            encrypt: None,
+        })
+    }
+
+    fn at(timestamp: &str) -> SystemTime {
+        stem_timestamp(timestamp).unwrap()
+    }
+
+    #[test]
+    fn test_retention_keeps_newest_entries_up_to_max_files() {
+        let temp = TempDir::new().unwrap();
+        for stem in [
+            "20240101-090000-a.md",
+            "20240102-090000-b.md",
+            "20240103-090000-c.md",
+        ] {
+            write_plain_entry(temp.path(), stem, "content");
+        }
+
+        let removed = apply_retention(temp.path(), &policy(2, 30, 100), at("20240104-090000"));
+
+        assert_eq!(removed, 1);
+        assert_eq!(
+            stems(temp.path()),
+            vec!["20240102-090000-b.md", "20240103-090000-c.md"]
+        );
+        // Both files of a kept entry survive
+        assert!(temp
+            .path()
+            .join("20240103-090000-c.md.recovery.md")
+            .exists());
+    }
+
+    #[test]
+    fn test_retention_removes_old_entries_but_never_the_newest() {
+        let temp = TempDir::new().unwrap();
+        write_plain_entry(temp.path(), "20240101-090000-a.md", "old");
+        write_plain_entry(temp.path(), "20240201-090000-b.md", "newer");
+
+        apply_retention(temp.path(), &policy(50, 7, 100), at("20240601-090000"));
+
+        assert_eq!(stems(temp.path()), vec!["20240201-090000-b.md"]);
+    }
+
+    #[test]
+    fn test_retention_caps_total_size() {
+        let temp = TempDir::new().unwrap();
+        let big = "x".repeat(700 * 1024);
+        write_plain_entry(temp.path(), "20240101-090000-a.md", &big);
+        write_plain_entry(temp.path(), "20240102-090000-b.md", &big);
+
+        apply_retention(temp.path(), &policy(50, 30, 2), at("20240103-090000"));
+
+        assert_eq!(stems(temp.path()), vec!["20240102-090000-b.md"]);
+    }
+
+    #[test]
+    fn test_encrypted_entry_round_trips_without_plaintext() {
+        let temp = TempDir::new().unwrap();
+        let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+        let data = serde_json::json!({ "fileName": "post.md", "editorContent": "secret draft" });
+
+        write_entry(
+            temp.path(),
+            "20240101-090000-post.md",
+            &data,
+            Some(key.as_slice()),
+        )
+        .unwrap();
+
+        let sealed_path = temp.path().join("20240101-090000-post.md.recovery.enc");
+        let sealed = std::fs::read(&sealed_path).unwrap();
+        assert!(!String::from_utf8_lossy(&sealed).contains("secret draft"));
+        assert!(!temp
+            .path()
+            .join("20240101-090000-post.md.recovery.md")
+            .exists());
+
+        let plaintext = decrypt(&key, &sealed).unwrap();
+        let restored: Value = serde_json::from_slice(&plaintext).unwrap();
+        assert_eq!(restored, data);
+
+        let other_key = ChaCha20Poly1305::generate_key(&mut OsRng);
+        assert!(decrypt(&other_key, &sealed).is_err());
+    }
+}
@@ -0,0 +1,251 @@
+//! Display labels for reference fields
+//!
+//! Frontmatter stores a `reference()` as the referenced entry's id (`jane-doe`, or
+//! `authors/jane-doe` as written by the reference dropdown). `resolve_reference_labels`
+//! looks a batch of ids up in the referenced collection, glob or `file()` loader, and
+//! returns the title (or name) to show for each and whether the entry exists.
+//!
+//! A collection's labels are cached until one of its files changes, so fields that
+//! reference the same collection don't rescan it on every render.
+
+use crate::commands::project::{
+    file_collection_path, load_file_based_collection, scan_collection_files_recursive,
+};
+use crate::models::FileEntry;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::SystemTime;
+use walkdir::WalkDir;
+
+/// Frontmatter fields tried, after the collection's title field, for a label
+const LABEL_FALLBACK_FIELDS: [&str; 2] = ["name", "slug"];
+
+/// A reference id and what to show for it
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceLabel {
+    pub id: String,
+    /// The entry's title or name; the id itself when it has neither or doesn't exist
+    pub label: String,
+    pub exists: bool,
+}
+
+/// File count, total size and newest modification time under a collection's source
+type Fingerprint = (usize, u64, Option<SystemTime>);
+
+struct CachedLabels {
+    fingerprint: Fingerprint,
+    /// Entry id → label
+    labels: HashMap<String, String>,
+}
+
+/// Keyed by the collection's directory (or JSON file) and title field
+type LabelCache = HashMap<(PathBuf, String), CachedLabels>;
+
+static LABEL_CACHE: OnceLock<Mutex<LabelCache>> = OnceLock::new();
+
+fn label_cache() -> &'static Mutex<LabelCache> {
+    LABEL_CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn fingerprint(source: &Path) -> Fingerprint {
+    let mut fingerprint: Fingerprint = (0, 0, None);
+    for entry in WalkDir::new(source).into_iter().filter_map(Result::ok) {
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        fingerprint.0 += 1;
+        fingerprint.1 += metadata.len();
+        fingerprint.2 = fingerprint.2.max(metadata.modified().ok());
+    }
+    fingerprint
+}
+
+fn label_of(file: &FileEntry, collection_name: &str, title_field: &str) -> String {
+    std::iter::once(title_field)
+        .chain(LABEL_FALLBACK_FIELDS)
+        .filter_map(|field| file.frontmatter.as_ref()?.get(field)?.as_str())
+        .map(str::trim)
+        .find(|label| !label.is_empty())
+        .unwrap_or_else(|| short_id(&file.id, collection_name))
+        .to_string()
+}
+
+fn short_id<'a>(id: &'a str, collection_name: &str) -> &'a str {
+    id.strip_prefix(collection_name)
+        .and_then(|rest| rest.strip_prefix('/'))
+        .unwrap_or(id)
+}
+
+fn labels_for(
+    files: &[FileEntry],
+    collection_name: &str,
+    title_field: &str,
+) -> HashMap<String, String> {
+    files
+        .iter()
+        .map(|file| {
+            (
+                file.id.clone(),
+                label_of(file, collection_name, title_field),
+            )
+        })
+        .collect()
+}
+
+fn resolve(
+    labels: &HashMap<String, String>,
+    collection_name: &str,
+    ids: Vec<String>,
+) -> Vec<ReferenceLabel> {
+    ids.into_iter()
+        .map(|id| {
+            let label = labels
+                .get(&id)
+                .or_else(|| labels.get(&format!("{collection_name}/{id}")));
+            ReferenceLabel {
+                label: label.cloned().unwrap_or_else(|| id.clone()),
+                exists: label.is_some(),
+                id,
+            }
+        })
+        .collect()
+}
+
+/// Resolves reference ids to display labels from the referenced collection
+///
+/// `collection_path` is the directory of a glob collection; without it the collection
+/// is looked up as a `file()` loader in content.config. Ids may be bare (`jane-doe`) or
+/// prefixed with the collection name. Ids that match no entry come back with
+/// `exists: false` and the id as their label.
+#[tauri::command]
+#[specta::specta]
+pub async fn resolve_reference_labels(
+    project_path: String,
+    collection_name: String,
+    collection_path: Option<String>,
+    ids: Vec<String>,
+    title_field: Option<String>,
+) -> Result<Vec<ReferenceLabel>, String> {
+    let title_field = title_field.unwrap_or_else(|| "title".to_string());
+    let source = match &collection_path {
+        Some(path) => PathBuf::from(path),
+        None => file_collection_path(Path::new(&project_path), &collection_name)?,
+    };
+    let key = (source.clone(), title_field.clone());
+    let current = fingerprint(&source);
+
+    if let Some(cached) = label_cache().lock().unwrap().get(&key) {
+        if cached.fingerprint == current {
+            return Ok(resolve(&cached.labels, &collection_name, ids));
+        }
+    }
+
+    let files = match collection_path {
+        Some(path) => {
+            scan_collection_files_recursive(
+                path,
+                collection_name.clone(),
+                None,
+                Some(title_field.clone()),
+                None,
+            )
+            .await?
+        }
+        None => load_file_based_collection(project_path, collection_name.clone()).await?,
+    };
+    let labels = labels_for(&files, &collection_name, &title_field);
+    let resolved = resolve(&labels, &collection_name, ids);
+
+    label_cache().lock().unwrap().insert(
+        key,
+        CachedLabels {
+            fingerprint: current,
+            labels,
+        },
+    );
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_resolve_reference_labels_from_glob_collection() {
+        let temp = TempDir::new().unwrap();
+        let authors = temp.path().join("authors");
+        fs::create_dir_all(&authors).unwrap();
+        fs::write(authors.join("jane.md"), "---\ntitle: Jane Doe\n---\n").unwrap();
+        fs::write(authors.join("bob.md"), "---\nname: Bob\n---\n").unwrap();
+
+        let resolve = || {
+            resolve_reference_labels(
+                temp.path().to_string_lossy().to_string(),
+                "authors".to_string(),
+                Some(authors.to_string_lossy().to_string()),
+                vec![
+                    "jane".to_string(),
+                    "authors/bob".to_string(),
+                    "ghost".to_string(),
+                ],
+                None,
+            )
+        };
+
+        let labels = resolve().await.unwrap();
+        assert_eq!(labels[0].label, "Jane Doe");
+        assert!(labels[0].exists);
+        assert_eq!(labels[1].label, "Bob");
+        assert_eq!(labels[1].id, "authors/bob");
+        assert_eq!(
+            labels[2],
+            ReferenceLabel {
+                id: "ghost".to_string(),
+                label: "ghost".to_string(),
+                exists: false,
+            }
+        );
+
+        // Editing an entry invalidates the cached labels
+        fs::write(authors.join("jane.md"), "---\ntitle: Jane Smith-Doe\n---\n").unwrap();
+        let labels = resolve().await.unwrap();
+        assert_eq!(labels[0].label, "Jane Smith-Doe");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_reference_labels_from_file_collection() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("src/data")).unwrap();
+        fs::write(
+            temp.path().join("src/content.config.ts"),
+            "const team = defineCollection({ loader: file('./src/data/team.json') });",
+        )
+        .unwrap();
+        fs::write(
+            temp.path().join("src/data/team.json"),
+            r#"[{"id": "ann", "name": "Ann"}, {"id": "lee"}]"#,
+        )
+        .unwrap();
+
+        let labels = resolve_reference_labels(
+            temp.path().to_string_lossy().to_string(),
+            "team".to_string(),
+            None,
+            vec!["ann".to_string(), "lee".to_string(), "max".to_string()],
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(labels[0].label, "Ann");
+        assert_eq!(labels[1].label, "lee");
+        assert!(labels[1].exists);
+        assert!(!labels[2].exists);
+    }
+}
@@ -0,0 +1,116 @@
+//! Collections with custom loaders
+//!
+//! A collection whose `loader` fetches from a CMS or API (anything other than `glob()`
+//! or `file()`) has no files to edit, so the parser lists it as `remote`. Its entries
+//! are read from the data store Astro writes when loaders run, so they can be browsed
+//! read-only; refreshing runs `astro sync` first to fetch them again.
+
+use crate::commands::collections::run_astro_sync;
+use crate::data_store::{self, DataStoreEntry};
+use crate::models::FileEntry;
+use std::path::{Path, PathBuf};
+
+/// Lists data store entries as file entries pointing at the store itself
+fn to_file_entries(
+    store_path: &Path,
+    collection_name: &str,
+    entries: Vec<DataStoreEntry>,
+) -> Vec<FileEntry> {
+    let store_dir = store_path.parent().unwrap_or(store_path).to_path_buf();
+    entries
+        .into_iter()
+        .map(|entry| {
+            let mut file_entry = FileEntry::new(
+                store_path.to_path_buf(),
+                collection_name.to_string(),
+                store_dir.clone(),
+            )
+            .with_frontmatter(entry.data);
+            file_entry.id = format!("{collection_name}/{}", entry.id);
+            file_entry.name = entry.id;
+            file_entry.digest = entry.digest;
+            file_entry
+        })
+        .collect()
+}
+
+fn read_remote_entries(
+    project_root: &Path,
+    collection_name: &str,
+) -> Result<Vec<FileEntry>, String> {
+    let Some(store_path) = data_store::find_data_store(project_root) else {
+        return Ok(Vec::new());
+    };
+    let entries = data_store::read_collection(project_root, collection_name)?.unwrap_or_default();
+    Ok(to_file_entries(&store_path, collection_name, entries))
+}
+
+/// Lists a remote collection's entries from Astro's data store
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `collection_name` - The collection's name in the content config
+///
+/// # Returns
+/// The entries with their loaded data as frontmatter; empty until Astro has run the
+/// collection's loader
+#[tauri::command]
+#[specta::specta]
+pub async fn load_remote_collection(
+    project_path: String,
+    collection_name: String,
+) -> Result<Vec<FileEntry>, String> {
+    let project_root = PathBuf::from(&project_path);
+    tokio::task::spawn_blocking(move || read_remote_entries(&project_root, &collection_name))
+        .await
+        .map_err(|e| format!("Failed to read data store: {e}"))?
+}
+
+/// Runs `astro sync` so custom loaders fetch their entries again, then lists the
+/// collection's entries
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `collection_name` - The collection's name in the content config
+#[tauri::command]
+#[specta::specta]
+pub async fn refresh_remote_collection(
+    app: tauri::AppHandle,
+    project_path: String,
+    collection_name: String,
+) -> Result<Vec<FileEntry>, String> {
+    let project_root = PathBuf::from(&project_path);
+    run_astro_sync(&app, &project_root).await?;
+    tokio::task::spawn_blocking(move || read_remote_entries(&project_root, &collection_name))
+        .await
+        .map_err(|e| format!("Failed to read data store: {e}"))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_read_remote_entries_from_store() {
+        let temp = TempDir::new().unwrap();
+        assert!(read_remote_entries(temp.path(), "products")
+            .unwrap()
+            .is_empty());
+
+        let store_dir = temp.path().join("node_modules/.astro");
+        std::fs::create_dir_all(&store_dir).unwrap();
+        std::fs::write(
+            store_dir.join("data-store.json"),
+            r#"[["Map",1,2],"products",["Map",3,4],"kettle",{"id":3,"data":5},{"title":6},"Kettle"]"#,
+        )
+        .unwrap();
+
+        let entries = read_remote_entries(temp.path(), "products").unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].id, "products/kettle");
+        assert_eq!(entries[0].name, "kettle");
+        assert_eq!(entries[0].extension, "json");
+        assert_eq!(entries[0].frontmatter.as_ref().unwrap()["title"], "Kettle");
+    }
+}
@@ -0,0 +1,432 @@
+//! Saved filters for collection views
+//!
+//! Projects can save filters (`savedFilters` in project settings) such as "Drafts
+//! older than 30 days" and show them as smart views in the sidebar. The frontend
+//! sends a filter's condition to `query_collection`, which evaluates it against the
+//! search index in `search.rs`, so only the matching entries cross to the frontend.
+
+use chrono::{Local, NaiveDate};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::cmp::Ordering;
+use std::path::Path;
+use tauri::AppHandle;
+
+use crate::commands::publish_gates::field_value;
+use crate::commands::search::{project_index, IndexedFile};
+
+/// Number of entries returned when the caller doesn't specify a limit
+const DEFAULT_RESULT_LIMIT: u32 = 500;
+
+/// How a frontmatter field is compared
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum FieldOperator {
+    Equals,
+    NotEquals,
+    GreaterThan,
+    LessThan,
+    /// The field is set and not empty; the value is ignored
+    Exists,
+    /// The field is missing or empty; the value is ignored
+    Missing,
+}
+
+/// A condition entries must meet; nested fields use dots (`seo.image`)
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum FilterCondition {
+    /// Compares a frontmatter field with a value
+    Field {
+        field: String,
+        operator: FieldOperator,
+        #[serde(default)]
+        #[specta(optional)]
+        value: Option<Value>,
+    },
+    /// A list field (or single value) includes the tag, ignoring case
+    HasTag { field: String, tag: String },
+    /// A date field falls in a range; bounds are `YYYY-MM-DD` dates, or days before
+    /// today for the `*_days_ago` bounds
+    #[serde(rename_all = "camelCase")]
+    DateRange {
+        field: String,
+        #[serde(default)]
+        #[specta(optional)]
+        from: Option<String>,
+        #[serde(default)]
+        #[specta(optional)]
+        to: Option<String>,
+        /// The date is at least this many days ago
+        #[serde(default)]
+        #[specta(optional)]
+        min_days_ago: Option<u32>,
+        /// The date is at most this many days ago
+        #[serde(default)]
+        #[specta(optional)]
+        max_days_ago: Option<u32>,
+    },
+    /// The title or body contains the text, ignoring case
+    TextContains { text: String },
+    /// Every condition matches
+    All { conditions: Vec<FilterCondition> },
+    /// At least one condition matches
+    Any { conditions: Vec<FilterCondition> },
+}
+
+/// A named filter saved in project settings
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedFilter {
+    pub id: String,
+    pub name: String,
+    /// The collection the filter applies to; all collections when omitted
+    #[serde(default)]
+    #[specta(optional)]
+    pub collection: Option<String>,
+    pub condition: FilterCondition,
+}
+
+/// An entry matching a filter
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FilterMatch {
+    pub file_path: String,
+    pub collection_name: String,
+    /// The entry's title, or its filename when it has none
+    pub title: String,
+}
+
+/// Whether a value counts as set, matching the publish gates' notion of present
+fn is_set(value: Option<&Value>) -> bool {
+    match value {
+        None | Some(Value::Null) => false,
+        Some(Value::String(s)) => !s.trim().is_empty(),
+        Some(Value::Array(items)) => !items.is_empty(),
+        Some(Value::Object(map)) => !map.is_empty(),
+        Some(_) => true,
+    }
+}
+
+/// Orders numbers numerically and everything else as text (so ISO dates sort)
+fn compare_values(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a.as_f64(), b.as_f64()) {
+        (Some(a), Some(b)) => a.partial_cmp(&b),
+        _ => Some(value_text(a)?.cmp(&value_text(b)?)),
+    }
+}
+
+fn value_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// The date part of a `YYYY-MM-DD` date or timestamp
+fn parse_date(text: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text.get(..10)?, "%Y-%m-%d").ok()
+}
+
+fn field_matches(
+    frontmatter: &IndexMap<String, Value>,
+    field: &str,
+    operator: FieldOperator,
+    value: Option<&Value>,
+) -> bool {
+    let current = field_value(frontmatter, field);
+    match operator {
+        FieldOperator::Exists => is_set(current),
+        FieldOperator::Missing => !is_set(current),
+        // A missing boolean counts as false, so "draft equals false" finds undrafted entries
+        FieldOperator::Equals => {
+            current.unwrap_or(&Value::Bool(false)) == value.unwrap_or(&Value::Null)
+        }
+        FieldOperator::NotEquals => {
+            current.unwrap_or(&Value::Bool(false)) != value.unwrap_or(&Value::Null)
+        }
+        FieldOperator::GreaterThan | FieldOperator::LessThan => {
+            let (Some(current), Some(value)) = (current, value) else {
+                return false;
+            };
+            let wanted = match operator {
+                FieldOperator::GreaterThan => Ordering::Greater,
+                _ => Ordering::Less,
+            };
+            compare_values(current, value) == Some(wanted)
+        }
+    }
+}
+
+fn has_tag(value: Option<&Value>, tag: &str) -> bool {
+    match value {
+        Some(Value::Array(items)) => items
+            .iter()
+            .any(|item| item.as_str().is_some_and(|t| t.eq_ignore_ascii_case(tag))),
+        Some(Value::String(s)) => s.eq_ignore_ascii_case(tag),
+        _ => false,
+    }
+}
+
+/// Evaluates a condition against an indexed entry, with relative dates taken from `today`
+fn matches(condition: &FilterCondition, file: &IndexedFile, today: NaiveDate) -> bool {
+    match condition {
+        FilterCondition::Field {
+            field,
+            operator,
+            value,
+        } => field_matches(&file.frontmatter, field, *operator, value.as_ref()),
+        FilterCondition::HasTag { field, tag } => {
+            has_tag(field_value(&file.frontmatter, field), tag)
+        }
+        FilterCondition::DateRange {
+            field,
+            from,
+            to,
+            min_days_ago,
+            max_days_ago,
+        } => {
+            let Some(date) = field_value(&file.frontmatter, field)
+                .and_then(Value::as_str)
+                .and_then(parse_date)
+            else {
+                return false;
+            };
+            let days_ago = (today - date).num_days();
+            from.as_deref()
+                .and_then(parse_date)
+                .is_none_or(|from| date >= from)
+                && to
+                    .as_deref()
+                    .and_then(parse_date)
+                    .is_none_or(|to| date <= to)
+                && min_days_ago.is_none_or(|min| days_ago >= i64::from(min))
+                && max_days_ago.is_none_or(|max| days_ago <= i64::from(max))
+        }
+        FilterCondition::TextContains { text } => {
+            let needle = text.to_lowercase();
+            file.title
+                .as_deref()
+                .is_some_and(|title| title.to_lowercase().contains(&needle))
+                || file.body.to_lowercase().contains(&needle)
+        }
+        FilterCondition::All { conditions } => conditions.iter().all(|c| matches(c, file, today)),
+        FilterCondition::Any { conditions } => conditions.iter().any(|c| matches(c, file, today)),
+    }
+}
+
+/// Entries matching `condition`, sorted by title
+fn filter_files<'a>(
+    files: impl Iterator<Item = (&'a Path, &'a IndexedFile)>,
+    collection: Option<&str>,
+    condition: &FilterCondition,
+    today: NaiveDate,
+    limit: usize,
+) -> Vec<FilterMatch> {
+    let mut found: Vec<FilterMatch> = files
+        .filter(|(_, file)| collection.is_none_or(|name| file.collection_name == name))
+        .filter(|(_, file)| matches(condition, file, today))
+        .map(|(path, file)| FilterMatch {
+            file_path: path.to_string_lossy().to_string(),
+            collection_name: file.collection_name.clone(),
+            title: file.title.clone().unwrap_or_else(|| file.filename.clone()),
+        })
+        .collect();
+    found.sort_by(|a, b| {
+        a.title
+            .to_lowercase()
+            .cmp(&b.title.to_lowercase())
+            .then_with(|| a.file_path.cmp(&b.file_path))
+    });
+    found.truncate(limit);
+    found
+}
+
+/// Returns the entries matching a filter condition
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+/// * `collection` - Only match entries in this collection
+/// * `condition` - The filter to evaluate
+/// * `limit` - Maximum number of entries (defaults to 500)
+#[tauri::command]
+#[specta::specta]
+pub async fn query_collection(
+    app: AppHandle,
+    project_path: String,
+    content_directory: Option<String>,
+    collection: Option<String>,
+    condition: FilterCondition,
+    limit: Option<u32>,
+) -> Result<Vec<FilterMatch>, String> {
+    let index_map = project_index(&app, &project_path, content_directory).await?;
+    let indexes = index_map.lock().unwrap();
+    let Some(index) = indexes.get(&project_path) else {
+        return Ok(Vec::new());
+    };
+
+    Ok(filter_files(
+        index.files().map(|(path, file)| (path.as_path(), file)),
+        collection.as_deref(),
+        &condition,
+        Local::now().date_naive(),
+        limit.unwrap_or(DEFAULT_RESULT_LIMIT) as usize,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::path::PathBuf;
+
+    fn entry(collection: &str, title: &str, frontmatter: Value) -> IndexedFile {
+        IndexedFile::for_test(
+            collection,
+            title,
+            serde_json::from_value(frontmatter).unwrap(),
+            "Some body text about Rust.",
+        )
+    }
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 6, 30).unwrap()
+    }
+
+    fn run(files: &[(PathBuf, IndexedFile)], condition: FilterCondition) -> Vec<String> {
+        filter_files(
+            files.iter().map(|(path, file)| (path.as_path(), file)),
+            None,
+            &condition,
+            today(),
+            100,
+        )
+        .into_iter()
+        .map(|m| m.title)
+        .collect()
+    }
+
+    fn files() -> Vec<(PathBuf, IndexedFile)> {
+        vec![
+            (
+                PathBuf::from("/p/posts/old.md"),
+                entry(
+                    "posts",
+                    "Old Draft",
+                    json!({ "draft": true, "pubDate": "2024-05-01", "tags": ["Rust"] }),
+                ),
+            ),
+            (
+                PathBuf::from("/p/posts/new.md"),
+                entry(
+                    "posts",
+                    "New Draft",
+                    json!({ "draft": true, "pubDate": "2024-06-25T09:00:00Z", "order": 2 }),
+                ),
+            ),
+            (
+                PathBuf::from("/p/notes/live.md"),
+                entry(
+                    "notes",
+                    "Published",
+                    json!({ "pubDate": "2024-01-01", "order": 10, "tags": "astro" }),
+                ),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_drafts_older_than_30_days() {
+        let condition = FilterCondition::All {
+            conditions: vec![
+                FilterCondition::Field {
+                    field: "draft".to_string(),
+                    operator: FieldOperator::Equals,
+                    value: Some(json!(true)),
+                },
+                FilterCondition::DateRange {
+                    field: "pubDate".to_string(),
+                    from: None,
+                    to: None,
+                    min_days_ago: Some(30),
+                    max_days_ago: None,
+                },
+            ],
+        };
+        assert_eq!(run(&files(), condition), vec!["Old Draft"]);
+    }
+
+    #[test]
+    fn test_field_operators_and_any() {
+        let not_draft = FilterCondition::Field {
+            field: "draft".to_string(),
+            operator: FieldOperator::Equals,
+            value: Some(json!(false)),
+        };
+        assert_eq!(run(&files(), not_draft), vec!["Published"]);
+
+        let ordered = FilterCondition::Field {
+            field: "order".to_string(),
+            operator: FieldOperator::GreaterThan,
+            value: Some(json!(5)),
+        };
+        assert_eq!(run(&files(), ordered), vec!["Published"]);
+
+        let any = FilterCondition::Any {
+            conditions: vec![
+                FilterCondition::HasTag {
+                    field: "tags".to_string(),
+                    tag: "rust".to_string(),
+                },
+                FilterCondition::HasTag {
+                    field: "tags".to_string(),
+                    tag: "Astro".to_string(),
+                },
+            ],
+        };
+        assert_eq!(run(&files(), any), vec!["Old Draft", "Published"]);
+    }
+
+    #[test]
+    fn test_date_range_and_text() {
+        let june = FilterCondition::DateRange {
+            field: "pubDate".to_string(),
+            from: Some("2024-06-01".to_string()),
+            to: Some("2024-06-30".to_string()),
+            min_days_ago: None,
+            max_days_ago: None,
+        };
+        assert_eq!(run(&files(), june), vec!["New Draft"]);
+
+        let text = FilterCondition::TextContains {
+            text: "PUBLISHED".to_string(),
+        };
+        assert_eq!(run(&files(), text), vec!["Published"]);
+    }
+
+    #[test]
+    fn test_collection_scope_and_condition_json() {
+        let condition: FilterCondition = serde_json::from_value(json!({
+            "kind": "field",
+            "field": "pubDate",
+            "operator": "exists"
+        }))
+        .unwrap();
+        let files = files();
+        let found = filter_files(
+            files.iter().map(|(path, file)| (path.as_path(), file)),
+            Some("notes"),
+            &condition,
+            today(),
+            100,
+        );
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].collection_name, "notes");
+        assert_eq!(found[0].file_path, "/p/notes/live.md");
+    }
+}
@@ -0,0 +1,477 @@
+//! Schema fixture tests
+//!
+//! A fixture is a directory holding a `content.config.ts`, optional Astro JSON schemas in
+//! `collections/<name>.schema.json` (copied from `.astro/collections`) and an
+//! `expected.json` mapping collection names to the merged schema the editor should build.
+//! Each fixture is parsed in a scratch project through the same pipeline as a real one,
+//! so a schema that parses incorrectly can be reported as a fixture rather than a
+//! description.
+//!
+//! Expectations are partial: only the keys present in `expected.json` are compared, and
+//! fields are matched by `name`, so a fixture only needs to list what it's about.
+
+use crate::parser::parse_astro_config;
+use crate::schema_merger::create_complete_schema;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use specta::Type;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Fixtures shipped with the app, from `src-tauri/schema-fixtures`
+const BUILT_IN_FIXTURES: [BuiltInFixture; 2] = [
+    BuiltInFixture {
+        name: "json-schema-with-zod",
+        config: include_str!("../../schema-fixtures/json-schema-with-zod/content.config.ts"),
+        json_schemas: &[(
+            "posts",
+            include_str!(
+                "../../schema-fixtures/json-schema-with-zod/collections/posts.schema.json"
+            ),
+        )],
+        expected: include_str!("../../schema-fixtures/json-schema-with-zod/expected.json"),
+    },
+    BuiltInFixture {
+        name: "zod-helpers",
+        config: include_str!("../../schema-fixtures/zod-helpers/content.config.ts"),
+        json_schemas: &[],
+        expected: include_str!("../../schema-fixtures/zod-helpers/expected.json"),
+    },
+];
+
+/// Distinguishes scratch projects created at the same time
+static SCRATCH_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+struct BuiltInFixture {
+    name: &'static str,
+    config: &'static str,
+    json_schemas: &'static [(&'static str, &'static str)],
+    expected: &'static str,
+}
+
+struct Fixture {
+    name: String,
+    config: String,
+    /// `(collection, JSON schema)` pairs
+    json_schemas: Vec<(String, String)>,
+    expected: String,
+}
+
+impl From<&BuiltInFixture> for Fixture {
+    fn from(fixture: &BuiltInFixture) -> Self {
+        Self {
+            name: fixture.name.to_string(),
+            config: fixture.config.to_string(),
+            json_schemas: fixture
+                .json_schemas
+                .iter()
+                .map(|(name, schema)| (name.to_string(), schema.to_string()))
+                .collect(),
+            expected: fixture.expected.to_string(),
+        }
+    }
+}
+
+/// A value in the merged schema that differs from the fixture's expectation
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaMismatch {
+    pub collection: String,
+    /// Where the value differs, e.g. `fields[name=cover].fieldType`; empty when the whole
+    /// collection is missing
+    pub path: String,
+    /// The expected value as JSON
+    pub expected: String,
+    /// The actual value as JSON (or the error building the schema), `None` when missing
+    pub actual: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaFixtureResult {
+    pub name: String,
+    /// Shipped with the app rather than loaded from the fixtures directory
+    pub built_in: bool,
+    /// Set when the fixture itself couldn't be read or run
+    pub error: Option<String>,
+    pub mismatches: Vec<SchemaMismatch>,
+}
+
+impl SchemaFixtureResult {
+    pub fn passed(&self) -> bool {
+        self.error.is_none() && self.mismatches.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaFixtureReport {
+    pub results: Vec<SchemaFixtureResult>,
+    pub passed: u32,
+    pub failed: u32,
+}
+
+/// A temporary Astro project laid out for one fixture, removed when dropped
+struct ScratchProject {
+    path: PathBuf,
+}
+
+impl ScratchProject {
+    /// Writes the config and JSON schemas, and creates a content directory for each
+    /// expected collection (the parser only lists collections with a directory)
+    fn create(fixture: &Fixture, collections: &[&String]) -> Result<Self, String> {
+        let path = std::env::temp_dir().join(format!(
+            "astro-editor-schema-fixture-{}-{}",
+            std::process::id(),
+            SCRATCH_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let project = Self { path };
+
+        let src = project.path.join("src");
+        let schemas = project.path.join(".astro").join("collections");
+        for collection in collections {
+            std::fs::create_dir_all(src.join("content").join(collection))
+                .map_err(|e| format!("Failed to create scratch project: {e}"))?;
+        }
+        std::fs::create_dir_all(&schemas)
+            .map_err(|e| format!("Failed to create scratch project: {e}"))?;
+
+        std::fs::write(src.join("content.config.ts"), &fixture.config)
+            .map_err(|e| format!("Failed to write content config: {e}"))?;
+        for (collection, schema) in &fixture.json_schemas {
+            std::fs::write(schemas.join(format!("{collection}.schema.json")), schema)
+                .map_err(|e| format!("Failed to write JSON schema: {e}"))?;
+        }
+
+        Ok(project)
+    }
+}
+
+impl Drop for ScratchProject {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// Parses a project's config and builds each collection's merged schema
+fn merged_schemas(project_path: &Path) -> Result<HashMap<String, Result<Value, String>>, String> {
+    let schemas_dir = project_path.join(".astro").join("collections");
+
+    Ok(parse_astro_config(project_path, None)?
+        .into_iter()
+        .map(|collection| {
+            let json_schema = std::fs::read_to_string(
+                schemas_dir.join(format!("{}.schema.json", collection.name)),
+            )
+            .ok();
+            let schema = create_complete_schema(
+                &collection.name,
+                json_schema.as_deref(),
+                collection.schema.as_deref(),
+                None,
+            )
+            .and_then(|schema| {
+                serde_json::to_value(schema).map_err(|e| format!("Failed to serialize schema: {e}"))
+            });
+            (collection.name, schema)
+        })
+        .collect())
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+fn item_name(item: &Value) -> Option<&str> {
+    item.get("name").and_then(Value::as_str)
+}
+
+/// Compares only what `expected` specifies, pushing `(path, expected, actual)` for each
+/// difference. Arrays of named objects are matched by name; other arrays by position.
+fn diff(
+    expected: &Value,
+    actual: Option<&Value>,
+    path: &str,
+    out: &mut Vec<(String, String, Option<String>)>,
+) {
+    match (expected, actual) {
+        (Value::Object(expected), Some(Value::Object(actual))) => {
+            for (key, value) in expected {
+                diff(value, actual.get(key), &join_path(path, key), out);
+            }
+        }
+        (Value::Array(expected), Some(Value::Array(actual)))
+            if !expected.is_empty() && expected.iter().all(|item| item_name(item).is_some()) =>
+        {
+            for item in expected {
+                let name = item_name(item).unwrap_or_default();
+                let found = actual.iter().find(|other| item_name(other) == Some(name));
+                diff(item, found, &format!("{path}[name={name}]"), out);
+            }
+        }
+        (Value::Array(expected), Some(Value::Array(actual))) if expected.len() == actual.len() => {
+            for (index, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+                diff(expected, Some(actual), &format!("{path}[{index}]"), out);
+            }
+        }
+        _ if actual == Some(expected) => {}
+        _ => out.push((
+            path.to_string(),
+            expected.to_string(),
+            actual.map(Value::to_string),
+        )),
+    }
+}
+
+/// Runs one fixture, returning its mismatches
+fn check_fixture(fixture: &Fixture) -> Result<Vec<SchemaMismatch>, String> {
+    let expected: Map<String, Value> = serde_json::from_str(&fixture.expected)
+        .map_err(|e| format!("Invalid expected.json: {e}"))?;
+
+    let collections: Vec<&String> = expected.keys().collect();
+    let project = ScratchProject::create(fixture, &collections)?;
+    let actual = merged_schemas(&project.path)?;
+
+    let mut mismatches = Vec::new();
+    for (collection, expected_schema) in &expected {
+        let mut differences = Vec::new();
+        match actual.get(collection) {
+            Some(Ok(schema)) => diff(expected_schema, Some(schema), "", &mut differences),
+            Some(Err(error)) => differences.push((
+                String::new(),
+                expected_schema.to_string(),
+                Some(error.clone()),
+            )),
+            None => differences.push((String::new(), expected_schema.to_string(), None)),
+        }
+        mismatches.extend(
+            differences
+                .into_iter()
+                .map(|(path, expected, actual)| SchemaMismatch {
+                    collection: collection.clone(),
+                    path,
+                    expected,
+                    actual,
+                }),
+        );
+    }
+    Ok(mismatches)
+}
+
+fn run_fixture(fixture: &Fixture, built_in: bool) -> SchemaFixtureResult {
+    let (error, mismatches) = match check_fixture(fixture) {
+        Ok(mismatches) => (None, mismatches),
+        Err(error) => (Some(error), Vec::new()),
+    };
+    SchemaFixtureResult {
+        name: fixture.name.clone(),
+        built_in,
+        error,
+        mismatches,
+    }
+}
+
+/// Reads a fixture from its directory
+fn load_fixture(dir: &Path) -> Result<Fixture, String> {
+    let read = |name: &str| {
+        std::fs::read_to_string(dir.join(name)).map_err(|e| format!("Failed to read {name}: {e}"))
+    };
+
+    let mut json_schemas = Vec::new();
+    if let Ok(entries) = std::fs::read_dir(dir.join("collections")) {
+        for entry in entries.flatten() {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if let Some(collection) = file_name.strip_suffix(".schema.json") {
+                let schema = std::fs::read_to_string(entry.path())
+                    .map_err(|e| format!("Failed to read {file_name}: {e}"))?;
+                json_schemas.push((collection.to_string(), schema));
+            }
+        }
+    }
+
+    Ok(Fixture {
+        name: dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        config: read("content.config.ts")?,
+        json_schemas,
+        expected: read("expected.json")?,
+    })
+}
+
+/// Runs every fixture in a directory, one per subdirectory, in name order
+fn run_fixture_directory(dir: &Path) -> Result<Vec<SchemaFixtureResult>, String> {
+    let mut fixture_dirs: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read fixtures directory: {e}"))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    fixture_dirs.sort();
+
+    Ok(fixture_dirs
+        .iter()
+        .map(|fixture_dir| match load_fixture(fixture_dir) {
+            Ok(fixture) => run_fixture(&fixture, false),
+            Err(error) => SchemaFixtureResult {
+                name: fixture_dir
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                built_in: false,
+                error: Some(error),
+                mismatches: Vec::new(),
+            },
+        })
+        .collect())
+}
+
+fn report(results: Vec<SchemaFixtureResult>) -> SchemaFixtureReport {
+    let passed = results.iter().filter(|result| result.passed()).count() as u32;
+    SchemaFixtureReport {
+        failed: results.len() as u32 - passed,
+        passed,
+        results,
+    }
+}
+
+/// Runs the schema fixture tests
+///
+/// # Arguments
+/// * `fixtures_directory` - A directory of extra fixtures, one per subdirectory, run after
+///   the built-in ones
+///
+/// # Returns
+/// Each fixture's mismatches between the expected and merged schemas
+#[tauri::command]
+#[specta::specta]
+pub async fn run_schema_fixture_tests(
+    fixtures_directory: Option<String>,
+) -> Result<SchemaFixtureReport, String> {
+    let mut results: Vec<SchemaFixtureResult> = BUILT_IN_FIXTURES
+        .iter()
+        .map(|fixture| run_fixture(&fixture.into(), true))
+        .collect();
+
+    if let Some(dir) = fixtures_directory {
+        results.extend(run_fixture_directory(Path::new(&dir))?);
+    }
+
+    Ok(report(results))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_built_in_fixtures_pass() {
+        for fixture in &BUILT_IN_FIXTURES {
+            let result = run_fixture(&fixture.into(), true);
+            assert!(result.passed(), "{}: {result:#?}", fixture.name);
+        }
+    }
+
+    #[test]
+    fn test_diff_compares_only_expected_keys() {
+        let actual = json!({
+            "collectionName": "blog",
+            "fields": [
+                { "name": "title", "fieldType": "string", "required": true },
+                { "name": "cover", "fieldType": "image", "required": false },
+            ],
+            "groups": [],
+        });
+        let expected = json!({
+            "fields": [
+                { "name": "cover", "fieldType": "string" },
+                { "name": "author" },
+            ],
+        });
+
+        let mut differences = Vec::new();
+        diff(&expected, Some(&actual), "", &mut differences);
+        assert_eq!(
+            differences,
+            vec![
+                (
+                    "fields[name=cover].fieldType".to_string(),
+                    "\"string\"".to_string(),
+                    Some("\"image\"".to_string()),
+                ),
+                (
+                    "fields[name=author]".to_string(),
+                    r#"{"name":"author"}"#.to_string(),
+                    None,
+                ),
+            ]
+        );
+
+        differences.clear();
+        diff(
+            &json!({ "groups": [] }),
+            Some(&actual),
+            "",
+            &mut differences,
+        );
+        assert!(differences.is_empty());
+    }
+
+    #[test]
+    fn test_fixture_directory_reports_mismatches() {
+        let temp = TempDir::new().unwrap();
+        let fixture_dir = temp.path().join("wrong-expectation");
+        std::fs::create_dir_all(&fixture_dir).unwrap();
+        std::fs::write(
+            fixture_dir.join("content.config.ts"),
+            BUILT_IN_FIXTURES[1].config,
+        )
+        .unwrap();
+        std::fs::write(
+            fixture_dir.join("expected.json"),
+            r#"{
+                "blog": { "fields": [{ "name": "cover", "fieldType": "string" }] },
+                "missing": {}
+            }"#,
+        )
+        .unwrap();
+        std::fs::create_dir_all(temp.path().join("incomplete")).unwrap();
+
+        let results = run_fixture_directory(temp.path()).unwrap();
+        assert_eq!(results.len(), 2);
+
+        assert_eq!(results[0].name, "incomplete");
+        assert!(results[0].error.is_some());
+
+        let result = &results[1];
+        assert_eq!(result.name, "wrong-expectation");
+        assert_eq!(
+            result.mismatches,
+            vec![
+                SchemaMismatch {
+                    collection: "blog".to_string(),
+                    path: "fields[name=cover].fieldType".to_string(),
+                    expected: "\"string\"".to_string(),
+                    actual: Some("\"image\"".to_string()),
+                },
+                SchemaMismatch {
+                    collection: "missing".to_string(),
+                    path: String::new(),
+                    expected: "{}".to_string(),
+                    actual: None,
+                },
+            ]
+        );
+
+        let report = report(results);
+        assert_eq!((report.passed, report.failed), (0, 2));
+    }
+}
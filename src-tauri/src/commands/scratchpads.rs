@@ -0,0 +1,368 @@
+//! Scratchpads: notes kept outside the project
+//!
+//! For pasting and thinking before anything belongs in a collection. Each scratchpad is
+//! a Markdown file in `scratchpads/` in app data, so the project's working tree stays
+//! clean. Promoting one writes it into a collection with frontmatter and removes the
+//! scratchpad. Scratchpads outlive a restart so nothing pasted is lost; ones left empty
+//! are cleared when the app starts.
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::files::rebuild_markdown_with_frontmatter_and_imports_ordered;
+use crate::security::validate_project_path;
+use chrono::Local;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use specta::Type;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Manager};
+
+/// Longest title taken from a scratchpad's first line
+const MAX_TITLE_CHARS: usize = 80;
+
+/// A scratchpad in app data
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Scratchpad {
+    /// `YYYYMMDD-HHMMSS-mmm` when it was created; also its file name
+    pub id: String,
+    /// The first line of the content, without heading marks; empty for a blank scratchpad
+    pub title: String,
+    pub content: String,
+    /// Unix milliseconds of the last save
+    #[specta(type = f64)]
+    pub updated_at: u64,
+}
+
+/// Frontmatter fields a promoted entry is written with
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScratchpadEntryFields {
+    pub title: String,
+    /// Set to today's date when present
+    pub date: Option<String>,
+    /// Set to true when present, so the entry starts as a draft
+    pub draft: Option<String>,
+}
+
+fn scratchpad_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .resolve("scratchpads", BaseDirectory::AppLocalData)
+        .map_err(|e| format!("Failed to resolve scratchpad directory: {e}"))?;
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create scratchpad directory: {e}"))?;
+    Ok(dir)
+}
+
+/// The file for `id`, refusing ids that aren't ones `create_scratchpad` makes
+fn scratchpad_path(dir: &Path, id: &str) -> Result<PathBuf, String> {
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_digit() || c == '-') {
+        return Err(format!("Invalid scratchpad id: {id}"));
+    }
+    Ok(dir.join(format!("{id}.md")))
+}
+
+/// First non-blank line, without leading `#`s
+fn title_of(content: &str) -> String {
+    let line = content
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or_default();
+    line.trim_start_matches('#')
+        .trim()
+        .chars()
+        .take(MAX_TITLE_CHARS)
+        .collect()
+}
+
+fn read_scratchpad(dir: &Path, id: &str) -> Result<Scratchpad, String> {
+    let path = scratchpad_path(dir, id)?;
+    let content =
+        std::fs::read_to_string(&path).map_err(|e| format!("Failed to read scratchpad: {e}"))?;
+    let updated_at = std::fs::metadata(&path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |since| since.as_millis() as u64);
+
+    Ok(Scratchpad {
+        id: id.to_string(),
+        title: title_of(&content),
+        content,
+        updated_at,
+    })
+}
+
+fn write_scratchpad(dir: &Path, id: &str, content: &str) -> Result<Scratchpad, String> {
+    let path = scratchpad_path(dir, id)?;
+    std::fs::write(&path, content).map_err(|e| format!("Failed to save scratchpad: {e}"))?;
+    read_scratchpad(dir, id)
+}
+
+fn create_in(dir: &Path, content: &str) -> Result<Scratchpad, String> {
+    let stamp = Local::now().format("%Y%m%d-%H%M%S-%3f").to_string();
+    let mut id = stamp.clone();
+    let mut n = 1;
+    while dir.join(format!("{id}.md")).exists() {
+        id = format!("{stamp}-{n}");
+        n += 1;
+    }
+    write_scratchpad(dir, &id, content)
+}
+
+/// Scratchpads in `dir`, most recently saved first
+fn list_in(dir: &Path) -> Vec<Scratchpad> {
+    let mut scratchpads: Vec<Scratchpad> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            read_scratchpad(dir, name.strip_suffix(".md")?).ok()
+        })
+        .collect();
+    scratchpads.sort_by(|a, b| b.updated_at.cmp(&a.updated_at).then(b.id.cmp(&a.id)));
+    scratchpads
+}
+
+/// The scratchpad's content as an entry body: a leading `# title` heading is dropped
+/// since the title moves into frontmatter
+fn entry_body(content: &str, title: &str) -> String {
+    let trimmed = content.trim_start();
+    let body = match trimmed.split_once('\n') {
+        Some((first, rest)) if first.trim() == format!("# {}", title.trim()) => rest,
+        None if trimmed.trim() == format!("# {}", title.trim()) => "",
+        _ => trimmed,
+    };
+    let body = body.trim();
+    if body.is_empty() {
+        String::new()
+    } else {
+        format!("{body}\n")
+    }
+}
+
+/// Removes scratchpads with no content; called once at startup
+pub fn clear_empty_scratchpads(app: &AppHandle) {
+    let Ok(dir) = scratchpad_dir(app) else {
+        return;
+    };
+    for scratchpad in list_in(&dir) {
+        if scratchpad.content.trim().is_empty() {
+            if let Ok(path) = scratchpad_path(&dir, &scratchpad.id) {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+}
+
+/// Creates a scratchpad in app data
+///
+/// # Arguments
+/// * `content` - Initial content, e.g. pasted text; omit for an empty scratchpad
+#[tauri::command]
+#[specta::specta]
+pub async fn create_scratchpad(
+    app: AppHandle,
+    content: Option<String>,
+) -> Result<Scratchpad, String> {
+    create_in(
+        &scratchpad_dir(&app)?,
+        content.as_deref().unwrap_or_default(),
+    )
+}
+
+/// Replaces a scratchpad's content
+#[tauri::command]
+#[specta::specta]
+pub async fn save_scratchpad(
+    app: AppHandle,
+    id: String,
+    content: String,
+) -> Result<Scratchpad, String> {
+    let dir = scratchpad_dir(&app)?;
+    if !scratchpad_path(&dir, &id)?.exists() {
+        return Err(format!("Scratchpad not found: {id}"));
+    }
+    write_scratchpad(&dir, &id, &content)
+}
+
+/// Lists scratchpads, most recently saved first
+#[tauri::command]
+#[specta::specta]
+pub async fn list_scratchpads(app: AppHandle) -> Result<Vec<Scratchpad>, String> {
+    Ok(list_in(&scratchpad_dir(&app)?))
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_scratchpad(app: AppHandle, id: String) -> Result<(), String> {
+    let path = scratchpad_path(&scratchpad_dir(&app)?, &id)?;
+    std::fs::remove_file(path).map_err(|e| format!("Failed to delete scratchpad: {e}"))
+}
+
+/// Writes a scratchpad into a collection as a new entry, then removes the scratchpad
+///
+/// # Arguments
+/// * `id` - The scratchpad to promote
+/// * `project_path` - The absolute path to the project root
+/// * `collection_path` - The absolute path to the collection directory
+/// * `filename` - Name for the new entry, e.g. `my-idea.md`
+/// * `title` - The entry's title; a matching `# title` heading at the top is removed
+/// * `fields` - Frontmatter field names for the title, date and draft flag
+///
+/// # Returns
+/// The new entry's path
+#[tauri::command]
+#[specta::specta]
+pub async fn promote_scratchpad_to_entry(
+    app: AppHandle,
+    id: String,
+    project_path: String,
+    collection_path: String,
+    filename: String,
+    title: String,
+    fields: ScratchpadEntryFields,
+) -> Result<String, String> {
+    let dir = scratchpad_dir(&app)?;
+    let target = promote(
+        &dir,
+        &id,
+        &project_path,
+        &collection_path,
+        &filename,
+        &title,
+        &fields,
+    )?;
+
+    log::info!(
+        "Astro Editor [SCRATCHPAD] Promoted scratchpad {id} to {}",
+        target.display()
+    );
+    Ok(target.to_string_lossy().to_string())
+}
+
+fn promote(
+    dir: &Path,
+    id: &str,
+    project_path: &str,
+    collection_path: &str,
+    filename: &str,
+    title: &str,
+    fields: &ScratchpadEntryFields,
+) -> Result<PathBuf, String> {
+    let collection = validate_project_path(collection_path, project_path)?;
+    let target = validate_project_path(&collection.join(filename).to_string_lossy(), project_path)?;
+    if target.exists() {
+        return Err(format!("File already exists: {filename}"));
+    }
+
+    let scratchpad = read_scratchpad(dir, id)?;
+
+    let mut frontmatter: IndexMap<String, Value> = IndexMap::new();
+    frontmatter.insert(fields.title.clone(), json!(title));
+    if let Some(date) = &fields.date {
+        frontmatter.insert(
+            date.clone(),
+            json!(Local::now().format("%Y-%m-%d").to_string()),
+        );
+    }
+    if let Some(draft) = &fields.draft {
+        frontmatter.insert(draft.clone(), json!(true));
+    }
+
+    let order: Vec<String> = frontmatter.keys().cloned().collect();
+    let content = rebuild_markdown_with_frontmatter_and_imports_ordered(
+        &frontmatter,
+        "",
+        &entry_body(&scratchpad.content, title),
+        Some(order),
+    )?;
+    std::fs::write(&target, content).map_err(|e| format!("Failed to create file: {e}"))?;
+    audit_log::record(AuditAction::Create, &target, None, "scratchpad-promote");
+
+    // The entry is written, so a failure here only leaves a stray scratchpad
+    if let Err(e) = std::fs::remove_file(scratchpad_path(dir, id)?) {
+        log::warn!("Astro Editor [SCRATCHPAD] Failed to remove promoted scratchpad: {e}");
+    }
+
+    Ok(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_create_save_and_list() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+
+        let first = create_in(dir, "").unwrap();
+        let second = create_in(dir, "## Pasted notes\n\nSome text").unwrap();
+        assert_ne!(first.id, second.id);
+        assert_eq!(second.title, "Pasted notes");
+
+        let saved = write_scratchpad(dir, &first.id, "An idea").unwrap();
+        assert_eq!(saved.title, "An idea");
+
+        let ids: Vec<String> = list_in(dir).into_iter().map(|s| s.id).collect();
+        assert_eq!(ids.len(), 2);
+        assert!(ids.contains(&first.id) && ids.contains(&second.id));
+
+        assert!(scratchpad_path(dir, "../notes").is_err());
+    }
+
+    #[test]
+    fn test_promote_writes_entry_and_removes_scratchpad() {
+        let app_data = TempDir::new().unwrap();
+        let project = TempDir::new().unwrap();
+        let collection = project.path().join("src/content/notes");
+        fs::create_dir_all(&collection).unwrap();
+
+        let scratchpad =
+            create_in(app_data.path(), "# My idea\n\nIt starts here.\n\n- point\n").unwrap();
+        let fields = ScratchpadEntryFields {
+            title: "title".to_string(),
+            date: None,
+            draft: Some("draft".to_string()),
+        };
+
+        let target = promote(
+            app_data.path(),
+            &scratchpad.id,
+            &project.path().to_string_lossy(),
+            &collection.to_string_lossy(),
+            "my-idea.md",
+            "My idea",
+            &fields,
+        )
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(target).unwrap(),
+            "---\ntitle: My idea\ndraft: true\n---\n\nIt starts here.\n\n- point\n"
+        );
+        assert!(list_in(app_data.path()).is_empty());
+
+        // An existing entry is never overwritten
+        let again = create_in(app_data.path(), "More").unwrap();
+        let result = promote(
+            app_data.path(),
+            &again.id,
+            &project.path().to_string_lossy(),
+            &collection.to_string_lossy(),
+            "my-idea.md",
+            "More",
+            &fields,
+        );
+        assert!(result.is_err());
+        assert_eq!(list_in(app_data.path()).len(), 1);
+    }
+}
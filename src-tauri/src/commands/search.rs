@@ -0,0 +1,498 @@
+//! Quick-switcher search index
+//!
+//! Keeps an in-memory index of entry titles, filenames and headings for each open
+//! project so Cmd+P style lookups stay fast on sites with thousands of entries. The
+//! index is built lazily on the first query and kept current by the file watcher.
+//! It also holds each entry's frontmatter and body for saved filters (see
+//! `saved_filters.rs`).
+
+use crate::commands::files::parse_frontmatter_internal;
+use crate::commands::project::scan_project_with_content_dir;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Manager};
+use walkdir::WalkDir;
+
+// Per-project search indexes, keyed by project path
+pub type SearchIndexMap = Arc<Mutex<HashMap<String, ProjectSearchIndex>>>;
+
+/// Files larger than this are indexed by filename only
+const MAX_INDEXED_FILE_SIZE: u64 = 5 * 1024 * 1024;
+
+/// Number of matches returned when the caller doesn't specify a limit
+const DEFAULT_RESULT_LIMIT: u32 = 50;
+
+/// What part of an entry a match was found in
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum FuzzyMatchKind {
+    Title,
+    Filename,
+    Heading,
+}
+
+/// A ranked quick-switcher result
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FuzzyMatch {
+    pub file_path: String,
+    pub collection_name: String,
+    pub kind: FuzzyMatchKind,
+    /// The matched title, filename or heading text
+    pub text: String,
+    /// 1-based line number, for heading matches
+    pub line: Option<u32>,
+    pub score: i32,
+    /// Character indices in `text` that matched the query, for highlighting
+    pub positions: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Heading {
+    text: String,
+    line: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct IndexedFile {
+    pub(crate) collection_name: String,
+    pub(crate) filename: String,
+    pub(crate) title: Option<String>,
+    headings: Vec<Heading>,
+    pub(crate) frontmatter: IndexMap<String, Value>,
+    /// Content after the frontmatter
+    pub(crate) body: String,
+}
+
+#[cfg(test)]
+impl IndexedFile {
+    pub(crate) fn for_test(
+        collection_name: &str,
+        title: &str,
+        frontmatter: IndexMap<String, Value>,
+        body: &str,
+    ) -> Self {
+        Self {
+            collection_name: collection_name.to_string(),
+            filename: title.to_lowercase().replace(' ', "-"),
+            title: Some(title.to_string()),
+            headings: Vec::new(),
+            frontmatter,
+            body: body.to_string(),
+        }
+    }
+}
+
+/// Index of every Markdown entry in a project's collections
+#[derive(Debug, Default)]
+pub struct ProjectSearchIndex {
+    /// Collection names and their root directories
+    collections: Vec<(String, PathBuf)>,
+    files: HashMap<PathBuf, IndexedFile>,
+}
+
+impl ProjectSearchIndex {
+    fn build(collections: Vec<(String, PathBuf)>) -> Self {
+        let mut files = HashMap::new();
+
+        for (name, root) in &collections {
+            let entries = WalkDir::new(root)
+                .follow_links(false)
+                .into_iter()
+                .filter_map(Result::ok)
+                .filter(|entry| entry.file_type().is_file() && is_markdown(entry.path()));
+
+            for entry in entries {
+                if let Some(indexed) = index_file(entry.path(), name) {
+                    files.insert(entry.path().to_path_buf(), indexed);
+                }
+            }
+        }
+
+        Self { collections, files }
+    }
+
+    /// Re-indexes a single file, or drops it if it no longer exists
+    fn refresh(&mut self, path: &Path) {
+        if !path.is_file() {
+            self.files.remove(path);
+            return;
+        }
+
+        let Some(collection_name) = self.owning_collection(path) else {
+            return;
+        };
+        match index_file(path, &collection_name) {
+            Some(indexed) => {
+                self.files.insert(path.to_path_buf(), indexed);
+            }
+            None => {
+                self.files.remove(path);
+            }
+        }
+    }
+
+    /// Every indexed entry, by path
+    pub(crate) fn files(&self) -> impl Iterator<Item = (&PathBuf, &IndexedFile)> {
+        self.files.iter()
+    }
+
+    /// Returns the collection whose root is the most specific ancestor of `path`
+    fn owning_collection(&self, path: &Path) -> Option<String> {
+        self.collections
+            .iter()
+            .filter(|(_, root)| path.starts_with(root))
+            .max_by_key(|(_, root)| root.components().count())
+            .map(|(name, _)| name.clone())
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Vec<FuzzyMatch> {
+        let matcher = SkimMatcherV2::default().smart_case();
+        let mut matches = Vec::new();
+
+        for (path, file) in &self.files {
+            let file_path = path.to_string_lossy().to_string();
+            let mut push = |kind: FuzzyMatchKind, text: &str, line: Option<u32>| {
+                if let Some((score, indices)) = matcher.fuzzy_indices(text, query) {
+                    matches.push(FuzzyMatch {
+                        file_path: file_path.clone(),
+                        collection_name: file.collection_name.clone(),
+                        kind,
+                        text: text.to_string(),
+                        line,
+                        score: score.clamp(0, i32::MAX as i64) as i32,
+                        positions: indices.into_iter().map(|i| i as u32).collect(),
+                    });
+                }
+            };
+
+            if let Some(title) = &file.title {
+                push(FuzzyMatchKind::Title, title, None);
+            }
+            push(FuzzyMatchKind::Filename, &file.filename, None);
+            for heading in &file.headings {
+                push(FuzzyMatchKind::Heading, &heading.text, Some(heading.line));
+            }
+        }
+
+        // Best score first; on ties prefer titles over filenames over headings,
+        // then shorter (tighter) matches
+        matches.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then(a.kind.cmp(&b.kind))
+                .then(a.text.len().cmp(&b.text.len()))
+                .then(a.file_path.cmp(&b.file_path))
+        });
+        matches.truncate(limit);
+        matches
+    }
+}
+
+fn is_markdown(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|e| e.to_str()),
+        Some("md") | Some("mdx")
+    )
+}
+
+fn index_file(path: &Path, collection_name: &str) -> Option<IndexedFile> {
+    let filename = path.file_stem()?.to_string_lossy().to_string();
+    let mut indexed = IndexedFile {
+        collection_name: collection_name.to_string(),
+        filename,
+        title: None,
+        headings: Vec::new(),
+        frontmatter: IndexMap::new(),
+        body: String::new(),
+    };
+
+    let size = std::fs::metadata(path).ok()?.len();
+    if size > MAX_INDEXED_FILE_SIZE {
+        return Some(indexed);
+    }
+
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            log::debug!(
+                "Astro Editor [SEARCH] Skipping content of {}: {e}",
+                path.display()
+            );
+            return Some(indexed);
+        }
+    };
+
+    if let Ok(parsed) = parse_frontmatter_internal(&content) {
+        indexed.title = parsed
+            .frontmatter
+            .get("title")
+            .and_then(|v| v.as_str())
+            .filter(|title| !title.trim().is_empty())
+            .map(String::from);
+        indexed.frontmatter = parsed.frontmatter;
+        indexed.body = parsed.content;
+    }
+    indexed.headings = extract_headings(&content);
+
+    Some(indexed)
+}
+
+/// Extracts ATX headings, skipping frontmatter and fenced code blocks
+fn extract_headings(content: &str) -> Vec<Heading> {
+    let mut headings = Vec::new();
+    let mut lines = content.lines().enumerate().peekable();
+
+    // Skip the frontmatter block
+    if lines.peek().map(|(_, line)| *line) == Some("---") {
+        lines.next();
+        for (_, line) in lines.by_ref() {
+            if line == "---" {
+                break;
+            }
+        }
+    }
+
+    let mut fence: Option<&str> = None;
+    for (index, line) in lines {
+        let trimmed = line.trim_start();
+
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            continue;
+        }
+        if trimmed.starts_with("```") {
+            fence = Some("```");
+            continue;
+        }
+        if trimmed.starts_with("~~~") {
+            fence = Some("~~~");
+            continue;
+        }
+
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        if !(1..=6).contains(&level) {
+            continue;
+        }
+        let rest = &trimmed[level..];
+        if !rest.starts_with([' ', '\t']) {
+            continue;
+        }
+
+        let text = rest.trim().trim_end_matches('#').trim_end();
+        if !text.is_empty() {
+            headings.push(Heading {
+                text: text.to_string(),
+                line: index as u32 + 1,
+            });
+        }
+    }
+
+    headings
+}
+
+/// Updates the project's index after a watcher event for a Markdown file
+///
+/// Does nothing if the project hasn't been indexed yet; the index is built on first query.
+pub(crate) fn refresh_indexed_file(app: &AppHandle, project_path: &str, path: &Path) {
+    let index_map = app.state::<SearchIndexMap>();
+    let mut indexes = index_map.lock().unwrap();
+    if let Some(index) = indexes.get_mut(project_path) {
+        index.refresh(path);
+    }
+}
+
+/// Drops a project's index so it's rebuilt on the next query
+///
+/// Used when changes may have been missed or collections changed (rescans, schema edits).
+pub(crate) fn invalidate_project_index(app: &AppHandle, project_path: &str) {
+    let index_map = app.state::<SearchIndexMap>();
+    index_map.lock().unwrap().remove(project_path);
+}
+
+/// Fuzzy-matches a query against entry titles, filenames and headings across collections
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+/// * `query` - The text typed into the quick switcher
+/// * `limit` - Maximum number of matches (defaults to 50)
+///
+/// # Returns
+/// Matches ranked best-first, with character positions for highlighting
+#[tauri::command]
+#[specta::specta]
+pub async fn fuzzy_find(
+    app: AppHandle,
+    project_path: String,
+    content_directory: Option<String>,
+    query: String,
+    limit: Option<u32>,
+) -> Result<Vec<FuzzyMatch>, String> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+    let limit = limit.unwrap_or(DEFAULT_RESULT_LIMIT) as usize;
+
+    let index_map = project_index(&app, &project_path, content_directory).await?;
+    let indexes = index_map.lock().unwrap();
+    Ok(indexes
+        .get(&project_path)
+        .map(|index| index.search(query, limit))
+        .unwrap_or_default())
+}
+
+/// Returns the index map with the project's index built, building it if needed
+pub(crate) async fn project_index(
+    app: &AppHandle,
+    project_path: &str,
+    content_directory: Option<String>,
+) -> Result<SearchIndexMap, String> {
+    let index_map = app.state::<SearchIndexMap>().inner().clone();
+    let is_indexed = index_map.lock().unwrap().contains_key(project_path);
+
+    if !is_indexed {
+        let collections =
+            scan_project_with_content_dir(project_path.to_string(), content_directory, None)
+                .await?
+                .into_iter()
+                .map(|collection| (collection.name, collection.path))
+                .collect();
+
+        let index = tokio::task::spawn_blocking(move || ProjectSearchIndex::build(collections))
+            .await
+            .map_err(|e| format!("Failed to build search index: {e}"))?;
+        log::info!(
+            "Astro Editor [SEARCH] Indexed {} files for {project_path}",
+            index.files.len()
+        );
+
+        index_map
+            .lock()
+            .unwrap()
+            .entry(project_path.to_string())
+            .or_insert(index);
+    }
+
+    Ok(index_map)
+}
+
+// Initialize the search index map when the app starts
+pub fn init_search_index() -> SearchIndexMap {
+    Arc::new(Mutex::new(HashMap::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write_entry(dir: &Path, name: &str, content: &str) -> PathBuf {
+        let path = dir.join(name);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_extract_headings_skips_frontmatter_and_code() {
+        let content = "---\ntitle: Post\n# not a heading\n---\n\n# Intro\n\n```md\n## Inside code\n```\n\n### Setup Steps ###\n#hashtag\n";
+        let headings = extract_headings(content);
+
+        assert_eq!(
+            headings,
+            vec![
+                Heading {
+                    text: "Intro".to_string(),
+                    line: 6
+                },
+                Heading {
+                    text: "Setup Steps".to_string(),
+                    line: 12
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_ranks_title_matches_with_positions() {
+        let temp = TempDir::new().unwrap();
+        let posts = temp.path().join("posts");
+        write_entry(
+            &posts,
+            "first.md",
+            "---\ntitle: Getting Started\n---\n\n## Installation\n",
+        );
+        write_entry(&posts, "other.md", "---\ntitle: Another Post\n---\n");
+
+        let index = ProjectSearchIndex::build(vec![("posts".to_string(), posts)]);
+        let matches = index.search("getst", 10);
+
+        assert_eq!(matches[0].kind, FuzzyMatchKind::Title);
+        assert_eq!(matches[0].text, "Getting Started");
+        assert_eq!(matches[0].collection_name, "posts");
+        assert_eq!(matches[0].positions, vec![0, 1, 2, 8, 9]);
+
+        let heading = index.search("install", 10);
+        assert_eq!(heading[0].kind, FuzzyMatchKind::Heading);
+        assert_eq!(heading[0].line, Some(5));
+    }
+
+    #[test]
+    fn test_refresh_updates_and_removes_files() {
+        let temp = TempDir::new().unwrap();
+        let notes = temp.path().join("notes");
+        let path = write_entry(&notes, "draft.md", "---\ntitle: Old Title\n---\n");
+
+        let mut index = ProjectSearchIndex::build(vec![("notes".to_string(), notes.clone())]);
+        assert!(!index.search("old", 10).is_empty());
+
+        fs::write(&path, "---\ntitle: New Title\n---\n").unwrap();
+        index.refresh(&path);
+        assert!(index.search("old title", 10).is_empty());
+        assert!(!index.search("new title", 10).is_empty());
+
+        let added = write_entry(&notes, "nested/extra.mdx", "# Extra Heading\n");
+        index.refresh(&added);
+        assert_eq!(index.files[&added].collection_name, "notes");
+
+        fs::remove_file(&path).unwrap();
+        index.refresh(&path);
+        assert!(!index.files.contains_key(&path));
+    }
+
+    #[test]
+    fn test_owning_collection_prefers_most_specific_root() {
+        let index = ProjectSearchIndex {
+            collections: vec![
+                ("docs".to_string(), PathBuf::from("/p/src/content/docs")),
+                (
+                    "guides".to_string(),
+                    PathBuf::from("/p/src/content/docs/guides"),
+                ),
+            ],
+            files: HashMap::new(),
+        };
+
+        assert_eq!(
+            index.owning_collection(Path::new("/p/src/content/docs/guides/a.md")),
+            Some("guides".to_string())
+        );
+        assert_eq!(
+            index.owning_collection(Path::new("/p/src/content/docs/a.md")),
+            Some("docs".to_string())
+        );
+        assert_eq!(index.owning_collection(Path::new("/elsewhere/a.md")), None);
+    }
+}
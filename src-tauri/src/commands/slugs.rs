@@ -0,0 +1,396 @@
+//! Slugs for new entries and collisions between existing ones
+//!
+//! `generate_slug` turns a title into a slug following the project's `slugs` setting and
+//! numbers it past any slug already used in the collection. `check_slug_conflicts` finds
+//! entries Astro would give the same slug: either from their file paths (`My Post.md`
+//! and `my-post.md`) or from an explicit `slug` in frontmatter.
+
+use crate::commands::files::parse_frontmatter_internal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// The project's `slugs` setting
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SlugSettings {
+    /// Replace accented Latin and Cyrillic letters with ASCII, e.g. `café` → `cafe`
+    /// (off by default, matching Astro)
+    #[serde(default)]
+    #[specta(optional)]
+    pub transliterate: Option<bool>,
+    /// Longest slug in characters, cut at a word boundary (no limit by default)
+    #[serde(default)]
+    #[specta(optional)]
+    pub max_length: Option<u32>,
+    /// Words left out of slugs, e.g. `["a", "the", "of"]`
+    #[serde(default)]
+    #[specta(optional)]
+    pub stop_words: Option<Vec<String>>,
+}
+
+/// Entries sharing a slug
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SlugConflict {
+    pub slug: String,
+    pub entries: Vec<SlugSource>,
+}
+
+/// Where an entry's slug comes from
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SlugSource {
+    pub file_path: String,
+    /// Set by a `slug` frontmatter field rather than the file path
+    pub explicit: bool,
+}
+
+/// ASCII for a letter with diacritics or a Cyrillic letter, if it has one
+fn transliterate_char(c: char) -> Option<&'static str> {
+    Some(match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'ĥ' | 'ħ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'ĵ' => "j",
+        'ķ' => "k",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'œ' => "oe",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' => "s",
+        'ß' => "ss",
+        'ţ' | 'ť' | 'ŧ' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'ŵ' => "w",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' => "g",
+        'д' => "d",
+        'е' | 'э' => "e",
+        'ё' => "yo",
+        'ж' => "zh",
+        'з' => "z",
+        'и' => "i",
+        'й' => "y",
+        'к' => "k",
+        'л' => "l",
+        'м' => "m",
+        'н' => "n",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'у' => "u",
+        'ф' => "f",
+        'х' => "kh",
+        'ц' => "ts",
+        'ч' => "ch",
+        'ш' => "sh",
+        'щ' => "shch",
+        'ы' => "y",
+        'ю' => "yu",
+        'я' => "ya",
+        'ъ' | 'ь' => "",
+        _ => return None,
+    })
+}
+
+/// A title as a slug: lowercase words joined by hyphens, without stop words and cut
+/// to `max_length`
+fn slugify_title(title: &str, settings: &SlugSettings) -> String {
+    let lowercase = title.to_lowercase();
+    let text: String = if settings.transliterate.unwrap_or(false) {
+        lowercase
+            .chars()
+            .map(|c| transliterate_char(c).map_or_else(|| c.to_string(), str::to_string))
+            .collect()
+    } else {
+        lowercase
+    };
+
+    // Apostrophes join rather than split words: "don't" → "dont"
+    let text = text.replace(['\'', '’'], "");
+    let words: Vec<&str> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+
+    let stop_words: HashSet<String> = settings
+        .stop_words
+        .iter()
+        .flatten()
+        .map(|word| word.to_lowercase())
+        .collect();
+    let kept: Vec<&str> = words
+        .iter()
+        .copied()
+        .filter(|word| !stop_words.contains(*word))
+        .collect();
+    // A title made only of stop words keeps them
+    let words = if kept.is_empty() { words } else { kept };
+
+    truncate_slug(&words.join("-"), settings.max_length)
+}
+
+/// Cuts a slug to `max_length` characters at the last hyphen that fits, or mid-word
+/// when even the first word is too long
+fn truncate_slug(slug: &str, max_length: Option<u32>) -> String {
+    let Some(max) = max_length.map(|max| max as usize).filter(|max| *max > 0) else {
+        return slug.to_string();
+    };
+    if slug.chars().count() <= max {
+        return slug.to_string();
+    }
+
+    let cut: String = slug.chars().take(max).collect();
+    if slug.chars().nth(max) == Some('-') {
+        return cut;
+    }
+    match cut.rfind('-') {
+        Some(boundary) if boundary > 0 => cut[..boundary].to_string(),
+        _ => cut.trim_end_matches('-').to_string(),
+    }
+}
+
+/// One path segment slugged the way Astro does (github-slugger): lowercased, spaces
+/// to hyphens, punctuation dropped
+fn slugify_segment(segment: &str) -> String {
+    segment
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The slug Astro derives from an entry's path relative to the collection root
+fn path_slug(relative: &Path) -> String {
+    let without_extension = relative.with_extension("");
+    let mut segments: Vec<String> = without_extension
+        .components()
+        .map(|component| slugify_segment(&component.as_os_str().to_string_lossy()))
+        .collect();
+    // `guides/index.md` is `guides`
+    if segments.len() > 1 && segments.last().is_some_and(|last| last == "index") {
+        segments.pop();
+    }
+    segments.join("/")
+}
+
+/// Every entry in a collection with its slug and whether frontmatter set it
+fn collection_slugs(collection_root: &Path) -> Vec<(String, PathBuf, bool)> {
+    WalkDir::new(collection_root)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() == 0 || {
+                let name = entry.file_name().to_string_lossy();
+                !name.starts_with('.') && !name.starts_with('_')
+            }
+        })
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry.file_type().is_file()
+                && matches!(
+                    entry.path().extension().and_then(|e| e.to_str()),
+                    Some("md" | "mdx")
+                )
+        })
+        .map(|entry| {
+            let explicit = std::fs::read_to_string(entry.path())
+                .ok()
+                .and_then(|content| parse_frontmatter_internal(&content).ok())
+                .and_then(|parsed| {
+                    parsed
+                        .frontmatter
+                        .get("slug")
+                        .and_then(Value::as_str)
+                        .map(str::to_string)
+                });
+            let relative = entry
+                .path()
+                .strip_prefix(collection_root)
+                .unwrap_or(entry.path());
+            match explicit {
+                Some(slug) => (slug, entry.path().to_path_buf(), true),
+                None => (path_slug(relative), entry.path().to_path_buf(), false),
+            }
+        })
+        .collect()
+}
+
+/// `slug`, or `slug-2`, `slug-3`... for the first not in `taken`, kept within `max_length`
+fn unique_slug(slug: String, taken: &HashSet<String>, max_length: Option<u32>) -> String {
+    if !taken.contains(&slug) {
+        return slug;
+    }
+    (2..)
+        .map(|n| {
+            let suffix = format!("-{n}");
+            let room = max_length.map(|max| (max as usize).saturating_sub(suffix.len()) as u32);
+            format!("{}{suffix}", truncate_slug(&slug, room))
+        })
+        .find(|candidate| !taken.contains(candidate))
+        .unwrap()
+}
+
+fn find_conflicts(collection_root: &Path) -> Vec<SlugConflict> {
+    let mut by_slug: BTreeMap<String, Vec<SlugSource>> = BTreeMap::new();
+    for (slug, path, explicit) in collection_slugs(collection_root) {
+        by_slug.entry(slug).or_default().push(SlugSource {
+            file_path: path.to_string_lossy().to_string(),
+            explicit,
+        });
+    }
+
+    by_slug
+        .into_iter()
+        .filter(|(_, entries)| entries.len() > 1)
+        .map(|(slug, mut entries)| {
+            entries.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+            SlugConflict { slug, entries }
+        })
+        .collect()
+}
+
+/// A slug for a new entry titled `title`, following the project's slug rules and
+/// numbered (`-2`, `-3`...) past any slug already in the collection
+#[tauri::command]
+#[specta::specta]
+pub async fn generate_slug(
+    title: String,
+    collection_path: String,
+    settings: Option<SlugSettings>,
+) -> Result<String, String> {
+    let settings = settings.unwrap_or_default();
+    let slug = slugify_title(&title, &settings);
+    if slug.is_empty() {
+        return Err("The title has no letters or numbers to make a slug from".to_string());
+    }
+
+    let taken: HashSet<String> = collection_slugs(Path::new(&collection_path))
+        .into_iter()
+        .map(|(slug, _, _)| slug)
+        .collect();
+    Ok(unique_slug(slug, &taken, settings.max_length))
+}
+
+/// Entries in a collection that share a slug, by slug
+#[tauri::command]
+#[specta::specta]
+pub async fn check_slug_conflicts(collection_path: String) -> Result<Vec<SlugConflict>, String> {
+    let root = PathBuf::from(&collection_path);
+    if !root.is_dir() {
+        return Err(format!("Path is not a directory: {}", root.display()));
+    }
+    Ok(find_conflicts(&root))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_slugify_title_applies_settings() {
+        let defaults = SlugSettings::default();
+        assert_eq!(
+            slugify_title("Don't Panic: A Guide!", &defaults),
+            "dont-panic-a-guide"
+        );
+        assert_eq!(slugify_title("Café Crème", &defaults), "café-crème");
+
+        let settings = SlugSettings {
+            transliterate: Some(true),
+            max_length: Some(20),
+            stop_words: Some(vec!["a".to_string(), "The".to_string()]),
+        };
+        assert_eq!(slugify_title("Café Crème", &settings), "cafe-creme");
+        assert_eq!(slugify_title("Привет мир", &settings), "privet-mir");
+        assert_eq!(
+            slugify_title("The Art of a Slow Morning Routine", &settings),
+            "art-of-slow-morning"
+        );
+        assert_eq!(slugify_title("The", &settings), "the");
+    }
+
+    #[test]
+    fn test_truncate_slug() {
+        assert_eq!(truncate_slug("slow-morning", Some(12)), "slow-morning");
+        assert_eq!(truncate_slug("slow-morning", Some(4)), "slow");
+        assert_eq!(truncate_slug("slow-morning", Some(8)), "slow");
+        assert_eq!(truncate_slug("extraordinary", Some(5)), "extra");
+        assert_eq!(truncate_slug("slow-morning", None), "slow-morning");
+    }
+
+    #[test]
+    fn test_path_slug_matches_astro() {
+        assert_eq!(path_slug(Path::new("My Post.md")), "my-post");
+        assert_eq!(
+            path_slug(Path::new("2024/Hello, World!.mdx")),
+            "2024/hello-world"
+        );
+        assert_eq!(path_slug(Path::new("guides/index.md")), "guides");
+        assert_eq!(path_slug(Path::new("index.md")), "index");
+    }
+
+    #[test]
+    fn test_unique_slug_stays_within_max_length() {
+        let taken: HashSet<String> = ["post", "post-2"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(unique_slug("post".to_string(), &taken, None), "post-3");
+        assert_eq!(unique_slug("fresh".to_string(), &taken, None), "fresh");
+
+        let taken: HashSet<String> = ["slow-morning".to_string()].into();
+        assert_eq!(
+            unique_slug("slow-morning".to_string(), &taken, Some(12)),
+            "slow-2"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_conflicts_and_generated_slugs() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join("_drafts")).unwrap();
+        fs::write(root.join("My Post.md"), "# One").unwrap();
+        fs::write(root.join("my-post.md"), "# Two").unwrap();
+        fs::write(root.join("renamed.md"), "---\nslug: launch\n---\n").unwrap();
+        fs::write(root.join("launch.mdx"), "# Launch").unwrap();
+        fs::write(root.join("unique.md"), "# Unique").unwrap();
+        fs::write(root.join("_drafts/unique.md"), "# Hidden").unwrap();
+        let collection = root.to_string_lossy().to_string();
+
+        let conflicts = check_slug_conflicts(collection.clone()).await.unwrap();
+        let slugs: Vec<&str> = conflicts.iter().map(|c| c.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["launch", "my-post"]);
+        let explicit: Vec<bool> = conflicts[0].entries.iter().map(|e| e.explicit).collect();
+        assert_eq!(explicit, vec![false, true]);
+
+        let slug = generate_slug("Launch".to_string(), collection.clone(), None)
+            .await
+            .unwrap();
+        assert_eq!(slug, "launch-2");
+        assert!(generate_slug("?!".to_string(), collection, None)
+            .await
+            .is_err());
+    }
+}
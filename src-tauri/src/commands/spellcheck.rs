@@ -0,0 +1,473 @@
+//! Spell checking
+//!
+//! `check_text` checks the prose in a Markdown document against a Hunspell dictionary.
+//! What isn't prose is skipped: fenced code and code spans, URLs and email addresses,
+//! HTML/JSX tags, MDX imports, exports and expressions, math, link destinations and
+//! frontmatter keys (frontmatter values are checked). Words with digits, underscores
+//! or capitals after the first letter (`h1`, `snake_case`, `camelCase`, `HTML`) are
+//! treated as code or acronyms.
+//!
+//! Dictionaries are `{lang}.aff`/`{lang}.dic` pairs, looked up in `dictionaries/` in
+//! app data and then the system's Hunspell directories. Words added with
+//! `add_to_project_dictionary` are kept in the project's `.astro-editor/dictionary.txt`
+//! so they can be committed with the site.
+//!
+//! Offsets are UTF-16 code units into the content, matching editor positions.
+
+use crate::commands::math::find_inline_close;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use spellbook::Dictionary;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Manager};
+
+/// Words the project accepts, relative to the project root
+const PROJECT_DICTIONARY: &str = ".astro-editor/dictionary.txt";
+
+/// Suggestions offered for each misspelling
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Where Linux distributions and macOS users install Hunspell dictionaries
+const SYSTEM_DICTIONARY_DIRS: [&str; 4] = [
+    "/usr/share/hunspell",
+    "/usr/share/myspell",
+    "/usr/share/myspell/dicts",
+    "/Library/Spelling",
+];
+
+static USER_DICTIONARY_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Loaded dictionaries by language; parsing one takes a noticeable moment
+static DICTIONARIES: OnceLock<Mutex<HashMap<String, Arc<Dictionary>>>> = OnceLock::new();
+
+/// A word the dictionary doesn't know
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Misspelling {
+    /// Start offset in UTF-16 code units
+    pub start: u32,
+    /// End offset (exclusive) in UTF-16 code units
+    pub end: u32,
+    /// 1-based line of `start`
+    pub line: u32,
+    pub word: String,
+    /// Likely corrections, best first
+    pub suggestions: Vec<String>,
+}
+
+/// `en-US` and `en_US` name the same dictionary
+fn normalize_lang(lang: &str) -> String {
+    lang.trim().replace('-', "_")
+}
+
+fn dictionary_dirs() -> Vec<PathBuf> {
+    let mut candidates: Vec<PathBuf> = USER_DICTIONARY_DIR.get().cloned().into_iter().collect();
+    candidates.extend(dirs::home_dir().map(|home| home.join("Library/Spelling")));
+    candidates.extend(SYSTEM_DICTIONARY_DIRS.iter().map(PathBuf::from));
+    candidates
+}
+
+fn load_dictionary(dirs: &[PathBuf], lang: &str) -> Result<Dictionary, String> {
+    let (aff_name, dic_name) = (format!("{lang}.aff"), format!("{lang}.dic"));
+    let Some(dir) = dirs
+        .iter()
+        .find(|dir| dir.join(&aff_name).is_file() && dir.join(&dic_name).is_file())
+    else {
+        let hint = USER_DICTIONARY_DIR
+            .get()
+            .map(|dir| format!("; add {aff_name} and {dic_name} to {}", dir.display()))
+            .unwrap_or_default();
+        return Err(format!("No spelling dictionary for {lang}{hint}"));
+    };
+
+    let read = |name: &str| {
+        std::fs::read_to_string(dir.join(name))
+            .map_err(|e| format!("Failed to read {}: {e}", dir.join(name).display()))
+    };
+    Dictionary::new(&read(&aff_name)?, &read(&dic_name)?)
+        .map_err(|e| format!("Failed to load the {lang} dictionary: {e}"))
+}
+
+fn dictionary(lang: &str) -> Result<Arc<Dictionary>, String> {
+    let cache = DICTIONARIES.get_or_init(Default::default);
+    if let Some(dictionary) = cache.lock().unwrap().get(lang) {
+        return Ok(dictionary.clone());
+    }
+    let dictionary = Arc::new(load_dictionary(&dictionary_dirs(), lang)?);
+    cache
+        .lock()
+        .unwrap()
+        .insert(lang.to_string(), dictionary.clone());
+    Ok(dictionary)
+}
+
+fn read_project_dictionary(project_path: &Path) -> Vec<String> {
+    std::fs::read_to_string(project_path.join(PROJECT_DICTIONARY))
+        .map(|text| {
+            text.lines()
+                .map(str::trim)
+                .filter(|word| !word.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Where a frontmatter line's value starts; keys aren't prose
+///
+/// Lines without a `key:` are list items or continue a block scalar, so are all value.
+fn frontmatter_value_start(line: &str) -> usize {
+    let indent = line.len() - line.trim_start().len();
+    let rest = line[indent..].trim_start_matches("- ");
+    let offset = line.len() - rest.len();
+    rest.find(':')
+        .filter(|&colon| {
+            let is_key = rest[..colon]
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '"' | '\''));
+            is_key
+                && rest[colon + 1..]
+                    .chars()
+                    .next()
+                    .is_none_or(char::is_whitespace)
+        })
+        .map_or(0, |colon| offset + colon + 1)
+}
+
+/// Blanks the parts of one line of body text that aren't prose
+fn mask_inline(line: &str, start: usize, prose: &mut [bool]) {
+    let bytes = line.as_bytes();
+    let mut blank = |range: Range<usize>| prose[start + range.start..start + range.end].fill(false);
+    let closing = |from: usize, close: u8| bytes[from..].iter().position(|&b| b == close);
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let skip_to = match bytes[i] {
+            b'`' => {
+                let run = bytes[i..].iter().take_while(|&&b| b == b'`').count();
+                line[i + run..]
+                    .find(&line[i..i + run])
+                    .map(|offset| i + run + offset + run)
+            }
+            b'<' if bytes
+                .get(i + 1)
+                .is_some_and(|b| b.is_ascii_alphabetic() || matches!(b, b'/' | b'!')) =>
+            {
+                closing(i, b'>').map(|offset| i + offset + 1)
+            }
+            b'(' if i > 0 && bytes[i - 1] == b']' => closing(i, b')').map(|offset| i + offset + 1),
+            b'{' => closing(i, b'}').map(|offset| i + offset + 1),
+            b'$' => find_inline_close(bytes, i, bytes.len()).map(|close| close + 1),
+            _ => None,
+        };
+        match skip_to {
+            Some(end) => {
+                blank(i..end);
+                i = end;
+            }
+            None => i += 1,
+        }
+    }
+
+    // URLs, domains and email addresses
+    let mut offset = 0;
+    for chunk in line.split(|c: char| c.is_ascii_whitespace()) {
+        if chunk.contains("://") || chunk.contains("www.") || chunk.contains('@') {
+            blank(offset..offset + chunk.len());
+        }
+        offset += chunk.len() + 1;
+    }
+}
+
+/// Marks which bytes of `content` are prose to be checked
+fn prose_bytes(content: &str) -> Vec<bool> {
+    let mut prose = vec![true; content.len()];
+    let mut in_frontmatter = content.starts_with("---\n") || content.starts_with("---\r\n");
+    let mut fence: Option<&str> = None;
+    let mut display_math = false;
+    let mut line_start = 0;
+
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        let range = line_start..line_start + line.len();
+        line_start += line.len();
+        let text = line.trim_end_matches(['\n', '\r']);
+        let trimmed = text.trim_start();
+
+        if in_frontmatter {
+            if index > 0 && text == "---" {
+                in_frontmatter = false;
+            } else {
+                prose[range.start..range.start + frontmatter_value_start(text)].fill(false);
+                mask_inline(text, range.start, &mut prose);
+            }
+            continue;
+        }
+
+        let skip_line = if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            true
+        } else if let Some(marker) = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m)) {
+            fence = Some(marker);
+            true
+        } else if display_math {
+            display_math = !trimmed.contains("$$");
+            true
+        } else if trimmed.starts_with("$$") && !trimmed[2..].contains("$$") {
+            display_math = true;
+            true
+        } else {
+            // MDX imports and exports, and link reference definitions (not footnotes)
+            trimmed.starts_with("import ")
+                || trimmed.starts_with("export ")
+                || (trimmed.starts_with('[')
+                    && !trimmed.starts_with("[^")
+                    && trimmed.contains("]: "))
+        };
+
+        if skip_line {
+            prose[range].fill(false);
+        } else {
+            mask_inline(text, range.start, &mut prose);
+        }
+    }
+
+    prose
+}
+
+/// Byte ranges of the words in the prose parts of `content`
+///
+/// Apostrophes join letters (`don't`); words touching digits or underscores are code.
+fn words(content: &str, prose: &[bool]) -> Vec<Range<usize>> {
+    let chars: Vec<(usize, char)> = content.char_indices().collect();
+    let is_letter = |&(at, c): &(usize, char)| prose[at] && c.is_alphabetic();
+    let is_code =
+        |c: Option<&(usize, char)>| c.is_some_and(|&(_, c)| c.is_ascii_digit() || c == '_');
+
+    let mut words = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if !is_letter(&chars[i]) {
+            i += 1;
+            continue;
+        }
+        let mut j = i + 1;
+        while j < chars.len() {
+            let joins = matches!(chars[j].1, '\'' | '\u{2019}')
+                && prose[chars[j].0]
+                && chars.get(j + 1).is_some_and(is_letter);
+            if !is_letter(&chars[j]) && !joins {
+                break;
+            }
+            j += 1;
+        }
+
+        let end = chars.get(j).map_or(content.len(), |&(at, _)| at);
+        if !is_code(i.checked_sub(1).map(|p| &chars[p])) && !is_code(chars.get(j)) {
+            words.push(chars[i].0..end);
+        }
+        i = j;
+    }
+    words
+}
+
+/// Single letters, `camelCase` and acronyms aren't worth flagging
+fn worth_checking(word: &str) -> bool {
+    let mut chars = word.chars();
+    chars.next().is_some() && word.chars().nth(1).is_some() && !chars.any(char::is_uppercase)
+}
+
+fn is_known(dictionary: &Dictionary, accepted: &HashSet<String>, word: &str) -> bool {
+    accepted.contains(word)
+        || accepted.contains(&word.to_lowercase())
+        || dictionary.check(&word.replace('\u{2019}', "'"))
+}
+
+fn suggest(dictionary: &Dictionary, word: &str) -> Vec<String> {
+    let mut suggestions = Vec::new();
+    dictionary.suggest(&word.replace('\u{2019}', "'"), &mut suggestions);
+    suggestions.truncate(MAX_SUGGESTIONS);
+    suggestions
+}
+
+fn check(content: &str, dictionary: &Dictionary, accepted: &HashSet<String>) -> Vec<Misspelling> {
+    let prose = prose_bytes(content);
+    let mut known: HashMap<&str, bool> = HashMap::new();
+    let mut suggestions: HashMap<&str, Vec<String>> = HashMap::new();
+    let mut misspellings = Vec::new();
+    // Words come in order, so offsets and lines are counted on from the previous one
+    let (mut byte, mut utf16, mut line) = (0, 0u32, 1u32);
+
+    for range in words(content, &prose) {
+        let word = &content[range.clone()];
+        if !worth_checking(word)
+            || *known
+                .entry(word)
+                .or_insert_with(|| is_known(dictionary, accepted, word))
+        {
+            continue;
+        }
+
+        let before = &content[byte..range.start];
+        utf16 += before.encode_utf16().count() as u32;
+        line += before.matches('\n').count() as u32;
+        byte = range.start;
+        misspellings.push(Misspelling {
+            start: utf16,
+            end: utf16 + word.encode_utf16().count() as u32,
+            line,
+            word: word.to_string(),
+            suggestions: suggestions
+                .entry(word)
+                .or_insert_with(|| suggest(dictionary, word))
+                .clone(),
+        });
+    }
+    misspellings
+}
+
+fn add_word(project_path: &Path, word: &str) -> Result<(), String> {
+    let word = word.trim();
+    if word.is_empty() || word.contains(char::is_whitespace) {
+        return Err(format!(
+            "Can't add '{word}' to the dictionary: not a single word"
+        ));
+    }
+    let mut words = read_project_dictionary(project_path);
+    if words.iter().any(|existing| existing == word) {
+        return Ok(());
+    }
+    words.push(word.to_string());
+    // Sorted so changes to the file diff cleanly
+    words.sort_by(|a, b| a.to_lowercase().cmp(&b.to_lowercase()).then(a.cmp(b)));
+
+    let path = project_path.join(PROJECT_DICTIONARY);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {e}", parent.display()))?;
+    }
+    std::fs::write(&path, words.join("\n") + "\n")
+        .map_err(|e| format!("Failed to write project dictionary: {e}"))
+}
+
+/// Checks the spelling of the prose in a Markdown document
+///
+/// # Arguments
+/// * `content` - The document (offsets in the result are relative to it)
+/// * `lang` - Dictionary to check against, e.g. `en_US` or `en-GB`
+/// * `project_path` - Project whose dictionary of accepted words to use, if any
+///
+/// # Returns
+/// Misspelled words in document order, with suggested corrections
+#[tauri::command]
+#[specta::specta]
+pub async fn check_text(
+    content: String,
+    lang: String,
+    project_path: Option<String>,
+) -> Result<Vec<Misspelling>, String> {
+    tokio::task::spawn_blocking(move || {
+        let dictionary = dictionary(&normalize_lang(&lang))?;
+        let accepted: HashSet<String> = project_path
+            .map(|path| read_project_dictionary(Path::new(&path)))
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
+        Ok(check(&content, &dictionary, &accepted))
+    })
+    .await
+    .map_err(|e| format!("Spell check failed: {e}"))?
+}
+
+/// Adds a word to the project's dictionary so it's no longer reported as misspelled
+#[tauri::command]
+#[specta::specta]
+pub async fn add_to_project_dictionary(project_path: String, word: String) -> Result<(), String> {
+    let project_path = Path::new(&project_path);
+    if !project_path.is_dir() {
+        return Err(format!("Project not found: {}", project_path.display()));
+    }
+    add_word(project_path, &word)
+}
+
+// Resolve the directory users can add Hunspell dictionaries to
+pub fn init_spellcheck(app: &AppHandle) {
+    match app
+        .path()
+        .resolve("dictionaries", BaseDirectory::AppLocalData)
+    {
+        Ok(dir) => {
+            let _ = USER_DICTIONARY_DIR.set(dir);
+        }
+        Err(e) => {
+            log::warn!("Astro Editor [SPELLCHECK] Failed to resolve dictionary directory: {e}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    const AFF: &str = "SET UTF-8\nTRY esiarntolcdugmphbyfvkwzESIARNTOLCDUGMPHBYFVKWZ'\n";
+    const DIC: &str =
+        "15\nthe\nquick\nbrown\nfox\njumps\nover\nlazy\ndog\ndon't\nsee\nlink\ntrue\nuses\nand\ncafe\n";
+
+    fn test_dictionary() -> Dictionary {
+        Dictionary::new(AFF, DIC).unwrap()
+    }
+
+    fn misspelled(content: &str) -> Vec<String> {
+        check(content, &test_dictionary(), &HashSet::new())
+            .into_iter()
+            .map(|m| m.word)
+            .collect()
+    }
+
+    #[test]
+    fn test_skips_markup_and_code() {
+        let content = "---\ntitle: The quikc fox\ndraft: true\n---\n\nimport Widget from './Widget.astro'\n\n\
+                       The brwn fox don't `jumpz` over <Widget propz={valuez} /> the dog.\n\n\
+                       See [the link](https://exampel.com/lazzy) and $x^2 + yy$.\n\n\
+                       ```js\nconst qwerty = camelCase\n```\n\n\
+                       The fox uses HTML, h1 and snake_case.\n";
+        assert_eq!(misspelled(content), vec!["quikc", "brwn"]);
+    }
+
+    #[test]
+    fn test_misspelling_offsets_and_suggestions() {
+        // "é" is 2 bytes in UTF-8 but one UTF-16 unit
+        let misspellings = check("café the\nquikc fox", &test_dictionary(), &HashSet::new());
+        assert_eq!(misspellings.len(), 2);
+        assert_eq!((misspellings[0].start, misspellings[0].end), (0, 4));
+        let quikc = &misspellings[1];
+        assert_eq!((quikc.start, quikc.end, quikc.line), (9, 14, 2));
+        assert!(quikc.suggestions.contains(&"quick".to_string()));
+    }
+
+    #[test]
+    fn test_project_dictionary() {
+        let temp = TempDir::new().unwrap();
+        add_word(temp.path(), "Astro").unwrap();
+        add_word(temp.path(), "frontmatter").unwrap();
+        add_word(temp.path(), "Astro").unwrap();
+        assert!(add_word(temp.path(), "two words").is_err());
+
+        let saved = fs::read_to_string(temp.path().join(PROJECT_DICTIONARY)).unwrap();
+        assert_eq!(saved, "Astro\nfrontmatter\n");
+
+        let accepted: HashSet<String> = read_project_dictionary(temp.path()).into_iter().collect();
+        let misspellings = check("Frontmatter in Astro", &test_dictionary(), &accepted);
+        assert_eq!(
+            misspellings.into_iter().map(|m| m.word).collect::<Vec<_>>(),
+            vec!["in"]
+        );
+    }
+}
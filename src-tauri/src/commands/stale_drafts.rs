@@ -0,0 +1,373 @@
+//! Stale draft reminders
+//!
+//! `get_stale_drafts` lists drafts nobody has edited for a while. When the frontend
+//! enables the weekly digest for the open project, a background task checks hourly
+//! and, at most once a week, posts a native notification and emits
+//! `stale-drafts-digest` with the list. Notifications go through `osascript` on macOS
+//! and `notify-send` on Linux; elsewhere only the event is emitted.
+
+use crate::commands::files::parse_frontmatter_internal;
+use crate::commands::project::scan_project_with_content_dir;
+use crate::models::Collection;
+use chrono::{DateTime, Local, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Emitter, Manager};
+use walkdir::WalkDir;
+
+// The digest configured for the open project, if enabled
+pub type DigestSchedule = Arc<Mutex<Option<StaleDraftDigest>>>;
+
+/// How often the background task checks whether a digest is due
+const CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Minimum time between digests
+const DIGEST_INTERVAL_DAYS: i64 = 7;
+
+/// Drafts named in the notification body before it says "and N more"
+const NOTIFICATION_TITLES: usize = 3;
+
+/// Frontmatter fields used to find drafts and name them
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StaleDraftFields {
+    pub title: String,
+    pub draft: String,
+}
+
+/// A draft that hasn't been edited recently
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StaleDraft {
+    pub file_path: String,
+    pub collection: String,
+    pub title: String,
+    pub word_count: u32,
+    /// Last modification date, `YYYY-MM-DD`
+    pub last_edited: String,
+    pub days_since_edit: u32,
+}
+
+/// Weekly digest settings for a project
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StaleDraftDigest {
+    pub project_path: String,
+    pub content_directory: Option<String>,
+    /// Drafts untouched for at least this many days are stale
+    pub days: u32,
+    pub fields: StaleDraftFields,
+}
+
+/// Drafts across `collections` not modified within `days` of `now`, oldest first
+fn find_stale_drafts(
+    collections: &[Collection],
+    days: u32,
+    fields: &StaleDraftFields,
+    now: SystemTime,
+) -> Vec<StaleDraft> {
+    let mut seen = BTreeSet::new();
+    let mut drafts = Vec::new();
+
+    for collection in collections {
+        let files = WalkDir::new(&collection.path)
+            .follow_links(false)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                entry.file_type().is_file()
+                    && matches!(
+                        entry.path().extension().and_then(|e| e.to_str()),
+                        Some("md") | Some("mdx")
+                    )
+            });
+
+        for file in files {
+            // Nested collections are walked by their parent too
+            if !seen.insert(file.path().to_path_buf()) {
+                continue;
+            }
+            let Some(modified) = file.metadata().ok().and_then(|m| m.modified().ok()) else {
+                continue;
+            };
+            let age_days = now
+                .duration_since(modified)
+                .map(|age| age.as_secs() / 86_400)
+                .unwrap_or(0);
+            if age_days < days as u64 {
+                continue;
+            }
+
+            let Ok(content) = std::fs::read_to_string(file.path()) else {
+                continue;
+            };
+            let Ok(parsed) = parse_frontmatter_internal(&content) else {
+                continue;
+            };
+            if parsed.frontmatter.get(&fields.draft) != Some(&Value::Bool(true)) {
+                continue;
+            }
+
+            drafts.push(StaleDraft {
+                file_path: file.path().to_string_lossy().to_string(),
+                collection: collection.name.clone(),
+                title: parsed
+                    .frontmatter
+                    .get(&fields.title)
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| file.file_name().to_string_lossy().to_string()),
+                word_count: parsed.content.split_whitespace().count() as u32,
+                last_edited: DateTime::<Local>::from(modified)
+                    .format("%Y-%m-%d")
+                    .to_string(),
+                days_since_edit: age_days as u32,
+            });
+        }
+    }
+
+    drafts.sort_by(|a, b| {
+        b.days_since_edit
+            .cmp(&a.days_since_edit)
+            .then_with(|| a.file_path.cmp(&b.file_path))
+    });
+    drafts
+}
+
+/// Whether a digest is due, given when the last one was sent
+fn digest_due(last_sent: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+    last_sent.map_or(true, |last| {
+        now - last >= chrono::Duration::days(DIGEST_INTERVAL_DAYS)
+    })
+}
+
+fn notification_body(drafts: &[StaleDraft], days: u32) -> String {
+    let mut titles: Vec<&str> = drafts
+        .iter()
+        .take(NOTIFICATION_TITLES)
+        .map(|d| d.title.as_str())
+        .collect();
+    let more = drafts.len().saturating_sub(NOTIFICATION_TITLES);
+    let more_text = format!("and {more} more");
+    if more > 0 {
+        titles.push(&more_text);
+    }
+    format!(
+        "{} drafts untouched for {days}+ days: {}",
+        drafts.len(),
+        titles.join(", ")
+    )
+}
+
+/// Posts a native notification; does nothing where notifications aren't supported
+fn send_notification(title: &str, body: &str) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        let quote = |text: &str| format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""));
+        let script = format!(
+            "display notification {} with title {}",
+            quote(body),
+            quote(title)
+        );
+        std::process::Command::new("osascript")
+            .args(["-e", &script])
+            .status()
+            .map_err(|e| format!("Failed to post notification: {e}"))?;
+        Ok(())
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        std::process::Command::new("notify-send")
+            .args(["--app-name=Astro Editor", title, body])
+            .status()
+            .map_err(|e| format!("Failed to post notification: {e}"))?;
+        Ok(())
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    {
+        let _ = (title, body);
+        Ok(())
+    }
+}
+
+fn last_sent_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .resolve("stale-drafts-digest.json", BaseDirectory::AppLocalData)
+        .map_err(|e| format!("Failed to resolve digest state path: {e}"))
+}
+
+fn load_last_sent(app: &AppHandle) -> Option<DateTime<Utc>> {
+    let json = std::fs::read_to_string(last_sent_path(app).ok()?).ok()?;
+    let value: Value = serde_json::from_str(&json).ok()?;
+    DateTime::parse_from_rfc3339(value.get("lastSent")?.as_str()?)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+fn save_last_sent(app: &AppHandle, sent: DateTime<Utc>) -> Result<(), String> {
+    let path = last_sent_path(app)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create app data directory: {e}"))?;
+    }
+    let json = serde_json::json!({ "lastSent": sent.to_rfc3339_opts(SecondsFormat::Secs, true) });
+    std::fs::write(&path, json.to_string()).map_err(|e| format!("Failed to save digest state: {e}"))
+}
+
+/// Sends the digest if one is configured and due
+async fn run_digest(app: &AppHandle) -> Result<(), String> {
+    let Some(digest) = app.state::<DigestSchedule>().lock().unwrap().clone() else {
+        return Ok(());
+    };
+    let now = Utc::now();
+    if !digest_due(load_last_sent(app), now) {
+        return Ok(());
+    }
+
+    let drafts = get_stale_drafts(
+        digest.project_path,
+        digest.content_directory,
+        digest.days,
+        digest.fields,
+    )
+    .await?;
+    save_last_sent(app, now)?;
+    if drafts.is_empty() {
+        return Ok(());
+    }
+
+    log::info!(
+        "Astro Editor [STALE_DRAFTS] Digest: {} stale drafts",
+        drafts.len()
+    );
+    send_notification("Stale drafts", &notification_body(&drafts, digest.days))?;
+    app.emit("stale-drafts-digest", &drafts)
+        .map_err(|e| format!("Failed to emit digest: {e}"))
+}
+
+/// Lists drafts not modified in the last `days` days, oldest first
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+/// * `days` - Drafts untouched for at least this many days are returned
+/// * `fields` - Frontmatter fields for the title and draft flag
+#[tauri::command]
+#[specta::specta]
+pub async fn get_stale_drafts(
+    project_path: String,
+    content_directory: Option<String>,
+    days: u32,
+    fields: StaleDraftFields,
+) -> Result<Vec<StaleDraft>, String> {
+    let collections = scan_project_with_content_dir(project_path, content_directory, None).await?;
+
+    tokio::task::spawn_blocking(move || {
+        find_stale_drafts(&collections, days, &fields, SystemTime::now())
+    })
+    .await
+    .map_err(|e| format!("Failed to find stale drafts: {e}"))
+}
+
+/// Enables or disables the weekly stale draft digest
+///
+/// # Arguments
+/// * `digest` - Digest settings for the open project; `None` turns the digest off
+#[tauri::command]
+#[specta::specta]
+pub async fn set_stale_draft_digest(
+    schedule: tauri::State<'_, DigestSchedule>,
+    digest: Option<StaleDraftDigest>,
+) -> Result<(), String> {
+    *schedule
+        .lock()
+        .map_err(|e| format!("Failed to update digest: {e}"))? = digest;
+    Ok(())
+}
+
+/// Starts the background task that sends digests when due
+pub fn start_digest_task(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = run_digest(&app).await {
+                log::warn!("Astro Editor [STALE_DRAFTS] {e}");
+            }
+        }
+    });
+}
+
+// Initialize the digest schedule when the app starts
+pub fn init_digest_schedule() -> DigestSchedule {
+    Arc::new(Mutex::new(None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_find_stale_drafts() {
+        let temp = TempDir::new().unwrap();
+        let blog = temp.path().join("blog");
+        fs::create_dir_all(&blog).unwrap();
+        fs::write(
+            blog.join("draft.md"),
+            "---\ntitle: Half done\ndraft: true\n---\n\nThree words here\n",
+        )
+        .unwrap();
+        fs::write(
+            blog.join("live.md"),
+            "---\ntitle: Live\ndraft: false\n---\n",
+        )
+        .unwrap();
+        let collections = vec![Collection::new("blog".to_string(), blog)];
+        let fields = StaleDraftFields {
+            title: "title".to_string(),
+            draft: "draft".to_string(),
+        };
+
+        let now = SystemTime::now();
+        assert!(find_stale_drafts(&collections, 30, &fields, now).is_empty());
+
+        let later = now + Duration::from_secs(45 * 86_400);
+        let drafts = find_stale_drafts(&collections, 30, &fields, later);
+        assert_eq!(drafts.len(), 1);
+        assert_eq!(drafts[0].title, "Half done");
+        assert_eq!(drafts[0].word_count, 3);
+        assert_eq!(drafts[0].days_since_edit, 45);
+    }
+
+    #[test]
+    fn test_digest_due_and_body() {
+        let now = Utc::now();
+        assert!(digest_due(None, now));
+        assert!(!digest_due(Some(now - chrono::Duration::days(6)), now));
+        assert!(digest_due(Some(now - chrono::Duration::days(7)), now));
+
+        let draft = |title: &str| StaleDraft {
+            file_path: String::new(),
+            collection: "blog".to_string(),
+            title: title.to_string(),
+            word_count: 0,
+            last_edited: "2026-01-01".to_string(),
+            days_since_edit: 40,
+        };
+        let drafts: Vec<StaleDraft> = ["A", "B", "C", "D", "E"].map(draft).to_vec();
+        assert_eq!(
+            notification_body(&drafts, 30),
+            "5 drafts untouched for 30+ days: A, B, C, and 2 more"
+        );
+    }
+}
@@ -0,0 +1,497 @@
+//! New project scaffolding
+//!
+//! Writes a minimal Astro content site from a bundled starter so first-time users can
+//! start writing without an existing project. Dependency installation streams its
+//! output to the frontend as progress events.
+//!
+//! The sample project (`sample-project/` in the crate) is a fuller site for trying the
+//! editor: two collections with a reference between them, a draft and cover images.
+//! It is written without installing anything, so it works offline.
+
+use crate::commands::ide::get_augmented_path;
+use crate::commands::processes::{spawn_managed, ProcessLimits};
+use crate::commands::project::is_blocked_directory;
+use crate::config_editor::{build_new_config, DEFAULT_CONTENT_DIR};
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use tauri::Emitter;
+use tokio::io::{AsyncBufReadExt, BufReader};
+
+/// Collection created in every new project
+const STARTER_COLLECTION: &str = "blog";
+
+/// Directory name for the sample project; `-2`, `-3`... are appended if it's taken
+const SAMPLE_PROJECT_NAME: &str = "astro-editor-sample";
+
+/// Sample project files that replace or add to the starter's
+const SAMPLE_FILES: [(&str, &str); 9] = [
+    (
+        "src/content.config.ts",
+        include_str!("../../sample-project/src/content.config.ts"),
+    ),
+    (
+        "src/content/authors/alex-rivera.md",
+        include_str!("../../sample-project/src/content/authors/alex-rivera.md"),
+    ),
+    (
+        "src/content/authors/sam-okafor.md",
+        include_str!("../../sample-project/src/content/authors/sam-okafor.md"),
+    ),
+    (
+        "src/content/blog/welcome.md",
+        include_str!("../../sample-project/src/content/blog/welcome.md"),
+    ),
+    (
+        "src/content/blog/markdown-basics.md",
+        include_str!("../../sample-project/src/content/blog/markdown-basics.md"),
+    ),
+    (
+        "src/content/blog/working-with-frontmatter.md",
+        include_str!("../../sample-project/src/content/blog/working-with-frontmatter.md"),
+    ),
+    (
+        "src/content/blog/ideas-for-next-month.md",
+        include_str!("../../sample-project/src/content/blog/ideas-for-next-month.md"),
+    ),
+    (
+        "src/assets/blog/welcome.svg",
+        include_str!("../../sample-project/src/assets/blog/welcome.svg"),
+    ),
+    (
+        "src/assets/blog/frontmatter.svg",
+        include_str!("../../sample-project/src/assets/blog/frontmatter.svg"),
+    ),
+];
+
+/// Longest an install or sync step may run before it is terminated
+const STEP_TIMEOUT_MINUTES: u64 = 15;
+
+/// Progress update emitted as `new-project-progress` while a project is created
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct NewProjectProgress {
+    pub stage: NewProjectStage,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum NewProjectStage {
+    Files,
+    Install,
+    Sync,
+    Done,
+}
+
+fn emit_progress(app: &tauri::AppHandle, stage: NewProjectStage, message: impl Into<String>) {
+    let progress = NewProjectProgress {
+        stage,
+        message: message.into(),
+    };
+    if let Err(e) = app.emit("new-project-progress", progress) {
+        log::warn!("Astro Editor [STARTER] Failed to emit progress event: {e}");
+    }
+}
+
+/// Project names become directory and package names, so keep them npm-safe
+fn is_valid_project_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(['.', '-', '_'])
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+}
+
+/// Returns the bundled starter files as (relative path, content) pairs
+fn starter_files(project_name: &str) -> Vec<(String, String)> {
+    let today = Local::now().format("%Y-%m-%d");
+    let collection = STARTER_COLLECTION;
+
+    vec![
+        (
+            "package.json".to_string(),
+            format!(
+                r#"{{
+  "name": "{project_name}",
+  "type": "module",
+  "version": "0.0.1",
+  "private": true,
+  "scripts": {{
+    "dev": "astro dev",
+    "build": "astro build",
+    "preview": "astro preview",
+    "astro": "astro"
+  }},
+  "dependencies": {{
+    "astro": "^5.0.0"
+  }}
+}}
+"#
+            ),
+        ),
+        (
+            "astro.config.mjs".to_string(),
+            "import { defineConfig } from 'astro/config';\n\nexport default defineConfig({});\n"
+                .to_string(),
+        ),
+        (
+            "tsconfig.json".to_string(),
+            r#"{
+  "extends": "astro/tsconfigs/strict",
+  "include": [".astro/types.d.ts", "**/*"],
+  "exclude": ["dist"]
+}
+"#
+            .to_string(),
+        ),
+        (
+            ".gitignore".to_string(),
+            "dist/\n.astro/\nnode_modules/\n.env\n.DS_Store\n".to_string(),
+        ),
+        (
+            "src/content.config.ts".to_string(),
+            build_new_config(collection, DEFAULT_CONTENT_DIR),
+        ),
+        (
+            format!("{DEFAULT_CONTENT_DIR}/{collection}/welcome.md"),
+            format!(
+                "---\ntitle: Welcome\ndescription: Your first post\npubDate: {today}\ndraft: false\n---\n\nStart writing here.\n"
+            ),
+        ),
+        (
+            "src/pages/index.astro".to_string(),
+            format!(
+                r#"---
+import {{ getCollection }} from 'astro:content';
+
+const posts = (await getCollection('{collection}', ({{ data }}) => !data.draft)).sort(
+  (a, b) => b.data.pubDate.valueOf() - a.data.pubDate.valueOf()
+);
+---
+
+<html lang="en">
+  <head>
+    <meta charset="utf-8" />
+    <title>{project_name}</title>
+  </head>
+  <body>
+    <ul>
+      {{posts.map((post) => <li>{{post.data.title}}</li>)}}
+    </ul>
+  </body>
+</html>
+"#
+            ),
+        ),
+    ]
+}
+
+/// The starter's package, config and page files with the sample content in place of
+/// its single post
+fn sample_files() -> Vec<(String, String)> {
+    let mut files: Vec<(String, String)> = starter_files(SAMPLE_PROJECT_NAME)
+        .into_iter()
+        .filter(|(path, _)| !path.starts_with("src/content"))
+        .collect();
+    files.extend(
+        SAMPLE_FILES
+            .iter()
+            .map(|(path, content)| (path.to_string(), content.to_string())),
+    );
+    files
+}
+
+/// Writes the bundled starter into an empty (or new) directory
+fn write_starter(project_dir: &Path, project_name: &str) -> Result<(), String> {
+    write_project_files(project_dir, starter_files(project_name))
+}
+
+/// First sample project directory in `parent` that doesn't exist yet
+fn available_sample_dir(parent: &Path) -> Result<PathBuf, String> {
+    (1..100)
+        .map(|n| match n {
+            1 => parent.join(SAMPLE_PROJECT_NAME),
+            n => parent.join(format!("{SAMPLE_PROJECT_NAME}-{n}")),
+        })
+        .find(|dir| !dir.exists())
+        .ok_or_else(|| format!("Too many sample projects in {}", parent.display()))
+}
+
+/// Writes files into an empty (or new) directory
+fn write_project_files(project_dir: &Path, files: Vec<(String, String)>) -> Result<(), String> {
+    if project_dir.exists() {
+        let mut entries = std::fs::read_dir(project_dir)
+            .map_err(|e| format!("Failed to read project directory: {e}"))?;
+        if entries.next().is_some() {
+            return Err(format!("Directory is not empty: {}", project_dir.display()));
+        }
+    }
+
+    for (relative_path, content) in files {
+        let path = project_dir.join(&relative_path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create directory: {e}"))?;
+        }
+        std::fs::write(&path, content)
+            .map_err(|e| format!("Failed to write {relative_path}: {e}"))?;
+    }
+
+    Ok(())
+}
+
+/// Resolves `npm`/`npx` to the right executable name for the platform
+fn node_program(name: &str) -> String {
+    if cfg!(target_os = "windows") {
+        format!("{name}.cmd")
+    } else {
+        name.to_string()
+    }
+}
+
+/// Runs an npm/npx command in the project, forwarding each output line as a progress event
+async fn run_with_progress(
+    app: &tauri::AppHandle,
+    project_dir: &Path,
+    program: &str,
+    args: &[&str],
+    stage: NewProjectStage,
+) -> Result<(), String> {
+    let program = node_program(program);
+
+    let mut command = tokio::process::Command::new(&program);
+    command
+        .args(args)
+        .current_dir(project_dir)
+        .env("PATH", get_augmented_path())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let mut managed = spawn_managed(
+        app,
+        &format!("{program} {}", args.join(" ")),
+        command,
+        ProcessLimits::minutes(STEP_TIMEOUT_MINUTES),
+    )?;
+    let child = &mut managed.child;
+
+    // Drain stderr concurrently so a chatty install can't fill the pipe and stall
+    let stderr_task = child.stderr.take().map(|stderr| {
+        tokio::spawn(async move {
+            let mut collected = Vec::new();
+            let mut lines = BufReader::new(stderr).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                collected.push(line);
+            }
+            collected.join("\n")
+        })
+    });
+
+    let stdout = child.stdout.take();
+    let forward_output = async {
+        if let Some(stdout) = stdout {
+            let mut lines = BufReader::new(stdout).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                if !line.trim().is_empty() {
+                    emit_progress(app, stage, line);
+                }
+            }
+        }
+    };
+
+    // Waiting alongside the output lets the watchdog stop a hung step
+    let (_, status) = tokio::join!(forward_output, managed.wait());
+    let status = status?;
+    let stderr = match stderr_task {
+        Some(task) => task.await.unwrap_or_default(),
+        None => String::new(),
+    };
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{program} {} failed: {}",
+            args.join(" "),
+            stderr.trim()
+        ))
+    }
+}
+
+/// Creates a new Astro content site from the bundled starter
+///
+/// Emits `new-project-progress` events as files are written and dependencies install.
+/// The frontend opens the returned path as the current project once this resolves.
+///
+/// # Arguments
+/// * `parent_directory` - Directory to create the project in
+/// * `project_name` - Name of the new project directory (lowercase, npm-safe)
+/// * `install_dependencies` - Whether to run `npm install` and `astro sync`
+///
+/// # Returns
+/// The absolute path to the new project
+#[tauri::command]
+#[specta::specta]
+pub async fn create_new_astro_project(
+    app: tauri::AppHandle,
+    parent_directory: String,
+    project_name: String,
+    install_dependencies: bool,
+) -> Result<String, String> {
+    if !is_valid_project_name(&project_name) {
+        return Err(format!(
+            "Invalid project name '{project_name}': use lowercase letters, numbers, - and _"
+        ));
+    }
+
+    let parent = PathBuf::from(&parent_directory)
+        .canonicalize()
+        .map_err(|e| format!("Invalid parent directory: {e}"))?;
+    if is_blocked_directory(&parent) {
+        return Err(format!(
+            "Cannot create project in restricted directory: {}",
+            parent.display()
+        ));
+    }
+
+    let project_dir = parent.join(&project_name);
+
+    emit_progress(&app, NewProjectStage::Files, "Creating project files");
+    write_starter(&project_dir, &project_name)?;
+    log::info!(
+        "Astro Editor [STARTER] Created project at {}",
+        project_dir.display()
+    );
+
+    if install_dependencies {
+        emit_progress(&app, NewProjectStage::Install, "Installing dependencies");
+        run_with_progress(
+            &app,
+            &project_dir,
+            "npm",
+            &["install"],
+            NewProjectStage::Install,
+        )
+        .await?;
+
+        emit_progress(&app, NewProjectStage::Sync, "Generating content schemas");
+        run_with_progress(
+            &app,
+            &project_dir,
+            "npx",
+            &["astro", "sync"],
+            NewProjectStage::Sync,
+        )
+        .await?;
+    }
+
+    emit_progress(&app, NewProjectStage::Done, "Project ready");
+
+    Ok(project_dir.to_string_lossy().to_string())
+}
+
+/// Writes the bundled sample project so the editor can be tried without an existing site
+///
+/// Nothing is installed, so this works offline. The frontend opens the returned path as
+/// the current project.
+///
+/// # Arguments
+/// * `parent_directory` - Directory to create the `astro-editor-sample` folder in
+///
+/// # Returns
+/// The absolute path to the sample project
+#[tauri::command]
+#[specta::specta]
+pub async fn create_demo_project(parent_directory: String) -> Result<String, String> {
+    let parent = PathBuf::from(&parent_directory)
+        .canonicalize()
+        .map_err(|e| format!("Invalid parent directory: {e}"))?;
+    if is_blocked_directory(&parent) {
+        return Err(format!(
+            "Cannot create project in restricted directory: {}",
+            parent.display()
+        ));
+    }
+
+    let project_dir = available_sample_dir(&parent)?;
+    write_project_files(&project_dir, sample_files())?;
+    log::info!(
+        "Astro Editor [STARTER] Created sample project at {}",
+        project_dir.display()
+    );
+
+    Ok(project_dir.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_astro_config;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_is_valid_project_name() {
+        assert!(is_valid_project_name("my-blog"));
+        assert!(is_valid_project_name("site_2"));
+        assert!(!is_valid_project_name("My Blog"));
+        assert!(!is_valid_project_name("../escape"));
+        assert!(!is_valid_project_name(".hidden"));
+        assert!(!is_valid_project_name(""));
+    }
+
+    #[test]
+    fn test_write_starter_creates_parseable_project() {
+        let temp = TempDir::new().unwrap();
+        let project_dir = temp.path().join("my-blog");
+
+        write_starter(&project_dir, "my-blog").unwrap();
+
+        let package: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(project_dir.join("package.json")).unwrap())
+                .unwrap();
+        assert_eq!(package["name"], "my-blog");
+
+        let collections = parse_astro_config(&project_dir, None).unwrap();
+        assert_eq!(collections.len(), 1);
+        assert_eq!(collections[0].name, STARTER_COLLECTION);
+        assert!(project_dir.join("src/content/blog/welcome.md").exists());
+    }
+
+    #[test]
+    fn test_sample_project_is_parseable() {
+        let temp = TempDir::new().unwrap();
+        let project_dir = available_sample_dir(temp.path()).unwrap();
+        assert!(project_dir.ends_with(SAMPLE_PROJECT_NAME));
+
+        write_project_files(&project_dir, sample_files()).unwrap();
+
+        let mut names: Vec<String> = parse_astro_config(&project_dir, None)
+            .unwrap()
+            .into_iter()
+            .map(|collection| collection.name)
+            .collect();
+        names.sort();
+        assert_eq!(names, ["authors", "blog"]);
+        assert!(project_dir.join("package.json").exists());
+        assert!(project_dir.join("src/assets/blog/welcome.svg").exists());
+        let welcome = fs::read_to_string(project_dir.join("src/content/blog/welcome.md")).unwrap();
+        assert!(welcome.contains("author: alex-rivera"));
+
+        // A second sample goes next to the first
+        assert!(available_sample_dir(temp.path())
+            .unwrap()
+            .ends_with(format!("{SAMPLE_PROJECT_NAME}-2")));
+    }
+
+    #[test]
+    fn test_write_starter_refuses_non_empty_directory() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join("existing.txt"), "data").unwrap();
+
+        let result = write_starter(temp.path(), "my-blog");
+        assert!(result.unwrap_err().contains("not empty"));
+    }
+}
@@ -0,0 +1,449 @@
+//! CSV/TSV to Markdown table conversion
+//!
+//! Used when pasting spreadsheet data or importing a `.csv` file. The delimiter and
+//! header row are inferred unless given, quoted fields (including embedded delimiters,
+//! doubled quotes and newlines) are honoured, and the output is a padded GFM table with
+//! numeric columns right-aligned, or an HTML table for projects with GFM tables turned
+//! off.
+
+use crate::commands::markdown_flavor::MarkdownFlavor;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Delimiters tried when inferring, in tie-break order (spreadsheets paste tabs)
+const CANDIDATE_DELIMITERS: [char; 4] = ['\t', ',', ';', '|'];
+
+/// Lines sampled when inferring the delimiter
+const SNIFF_LINES: usize = 20;
+
+/// A Markdown table converted from delimited text
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkdownTable {
+    pub markdown: String,
+    /// The delimiter used, e.g. "," or "\t"
+    pub delimiter: String,
+    /// Whether the first row was used as the header
+    pub has_header: bool,
+    /// Data rows, excluding the header
+    pub row_count: u32,
+    pub column_count: u32,
+}
+
+/// Splits delimited text into rows of fields, honouring double-quoted fields
+pub(crate) fn parse_delimited(text: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                in_quotes = true;
+            }
+            c if c == delimiter => row.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    // Blank lines aren't rows
+    rows.retain(|row| row.iter().any(|f| !f.trim().is_empty()));
+    rows
+}
+
+/// Picks the delimiter that splits the sample into the most consistent multi-column rows
+pub(crate) fn infer_delimiter(text: &str) -> Option<char> {
+    let sample: String = text
+        .lines()
+        .take(SNIFF_LINES)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    CANDIDATE_DELIMITERS
+        .iter()
+        .filter_map(|&delimiter| {
+            let rows = parse_delimited(&sample, delimiter);
+            let first_width = rows.first()?.len();
+            if first_width < 2 {
+                return None;
+            }
+            let consistent = rows.iter().filter(|r| r.len() == first_width).count();
+            Some((delimiter, consistent, first_width))
+        })
+        // Most consistent rows, then most columns; `max_by` keeps the last maximum,
+        // so iterate in reverse to prefer earlier candidates on ties
+        .rev()
+        .max_by(|a, b| a.1.cmp(&b.1).then(a.2.cmp(&b.2)))
+        .map(|(delimiter, _, _)| delimiter)
+}
+
+pub(crate) fn is_numeric(value: &str) -> bool {
+    let cleaned: String = value
+        .trim()
+        .trim_start_matches(['$', '€', '£'])
+        .trim_end_matches('%')
+        .chars()
+        .filter(|c| *c != ',')
+        .collect();
+    !cleaned.is_empty() && cleaned.parse::<f64>().is_ok()
+}
+
+/// Guesses whether the first row is a header
+///
+/// Votes per column: a numeric column under a non-numeric first cell suggests a header,
+/// a numeric first cell suggests data. Without evidence, a first row of unique,
+/// non-empty cells is treated as a header, as spreadsheet exports usually have one.
+fn infer_header(rows: &[Vec<String>]) -> bool {
+    let Some((first, rest)) = rows.split_first() else {
+        return false;
+    };
+    if rest.is_empty() {
+        return false;
+    }
+
+    let mut votes = 0i32;
+    for (column, cell) in first.iter().enumerate() {
+        let values: Vec<&str> = rest
+            .iter()
+            .filter_map(|row| row.get(column))
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+            .collect();
+        if values.is_empty() || !values.iter().all(|v| is_numeric(v)) {
+            continue;
+        }
+        votes += if is_numeric(cell) { -1 } else { 1 };
+    }
+
+    if votes != 0 {
+        return votes > 0;
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    first
+        .iter()
+        .all(|cell| !cell.trim().is_empty() && seen.insert(cell.trim()))
+}
+
+fn escape_cell(value: &str) -> String {
+    value
+        .trim()
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace(['\n', '\r'], "<br>")
+}
+
+/// Columns whose non-empty values are all numbers, which are right-aligned
+fn numeric_columns(body: &[Vec<String>], column_count: usize) -> Vec<bool> {
+    (0..column_count)
+        .map(|i| {
+            let values: Vec<&String> = body
+                .iter()
+                .filter_map(|r| r.get(i))
+                .filter(|v| !v.is_empty())
+                .collect();
+            !values.is_empty() && values.iter().all(|v| is_numeric(v))
+        })
+        .collect()
+}
+
+/// Renders rows as a padded GFM table
+fn render_table(rows: &[Vec<String>], has_header: bool) -> String {
+    let column_count = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            (0..column_count)
+                .map(|i| row.get(i).map(|v| escape_cell(v)).unwrap_or_default())
+                .collect()
+        })
+        .collect();
+
+    let (header, body) = if has_header {
+        (cells[0].clone(), &cells[1..])
+    } else {
+        (vec![String::new(); column_count], &cells[..])
+    };
+
+    let right_aligned = numeric_columns(body, column_count);
+
+    // GFM needs at least three dashes in the delimiter row
+    let widths: Vec<usize> = (0..column_count)
+        .map(|i| {
+            std::iter::once(&header[i])
+                .chain(body.iter().map(|r| &r[i]))
+                .map(|v| v.chars().count())
+                .max()
+                .unwrap_or(0)
+                .max(3)
+        })
+        .collect();
+
+    let render_row = |row: &[String]| {
+        let padded: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let width = widths[i];
+                if right_aligned[i] {
+                    format!("{value:>width$}")
+                } else {
+                    format!("{value:<width$}")
+                }
+            })
+            .collect();
+        format!("| {} |", padded.join(" | "))
+    };
+
+    let separator: Vec<String> = widths
+        .iter()
+        .zip(&right_aligned)
+        .map(|(&width, &right)| {
+            if right {
+                format!("{}:", "-".repeat(width - 1))
+            } else {
+                "-".repeat(width)
+            }
+        })
+        .collect();
+
+    let mut lines = vec![
+        render_row(&header),
+        format!("| {} |", separator.join(" | ")),
+    ];
+    lines.extend(body.iter().map(|row| render_row(row)));
+    lines.join("\n")
+}
+
+fn escape_html_cell(value: &str) -> String {
+    value
+        .trim()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace("\r\n", "<br>")
+        .replace(['\n', '\r'], "<br>")
+}
+
+/// Renders rows as an HTML table, for projects without GFM tables
+fn render_html_table(rows: &[Vec<String>], has_header: bool) -> String {
+    let column_count = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let cells: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            (0..column_count)
+                .map(|i| row.get(i).map(|v| escape_html_cell(v)).unwrap_or_default())
+                .collect()
+        })
+        .collect();
+    let (header, body) = if has_header {
+        (Some(&cells[0]), &cells[1..])
+    } else {
+        (None, &cells[..])
+    };
+    let right_aligned = numeric_columns(body, column_count);
+
+    let render_row = |row: &[String], tag: &str| {
+        let cells: String = row
+            .iter()
+            .zip(&right_aligned)
+            .map(|(value, &right)| {
+                let style = if right && tag == "td" {
+                    r#" style="text-align: right""#
+                } else {
+                    ""
+                };
+                format!("<{tag}{style}>{value}</{tag}>")
+            })
+            .collect();
+        format!("    <tr>{cells}</tr>")
+    };
+
+    let mut lines = vec!["<table>".to_string()];
+    if let Some(header) = header {
+        lines.push("  <thead>".to_string());
+        lines.push(render_row(header, "th"));
+        lines.push("  </thead>".to_string());
+    }
+    lines.push("  <tbody>".to_string());
+    lines.extend(body.iter().map(|row| render_row(row, "td")));
+    lines.push("  </tbody>".to_string());
+    lines.push("</table>".to_string());
+    lines.join("\n")
+}
+
+/// Reads a delimiter argument; empty means infer, and `\t` may be written escaped
+pub(crate) fn delimiter_option(delimiter: Option<&str>) -> Result<Option<char>, String> {
+    match delimiter {
+        None | Some("") => Ok(None),
+        Some("\\t") => Ok(Some('\t')),
+        Some(d) if d.chars().count() == 1 => Ok(d.chars().next()),
+        Some(d) => Err(format!("Delimiter must be a single character: {d}")),
+    }
+}
+
+fn convert(
+    text: &str,
+    delimiter: Option<char>,
+    has_header: Option<bool>,
+    gfm_tables: bool,
+) -> Result<MarkdownTable, String> {
+    let delimiter = delimiter
+        .or_else(|| infer_delimiter(text))
+        .ok_or("Could not detect a delimiter; the text doesn't look like CSV or TSV")?;
+    let rows = parse_delimited(text, delimiter);
+    if rows.is_empty() {
+        return Err("No rows to convert".to_string());
+    }
+
+    let has_header = has_header.unwrap_or_else(|| infer_header(&rows));
+    let column_count = rows.iter().map(Vec::len).max().unwrap_or(0);
+
+    Ok(MarkdownTable {
+        markdown: if gfm_tables {
+            render_table(&rows, has_header)
+        } else {
+            render_html_table(&rows, has_header)
+        },
+        delimiter: delimiter.to_string(),
+        has_header,
+        row_count: (rows.len() - usize::from(has_header)) as u32,
+        column_count: column_count as u32,
+    })
+}
+
+/// Converts CSV/TSV text or a `.csv`/`.tsv` file into an aligned GFM table
+///
+/// # Arguments
+/// * `text` - Delimited text, e.g. from the clipboard (ignored when `file_path` is set)
+/// * `file_path` - Optional path to a CSV/TSV file to read instead
+/// * `delimiter` - Optional delimiter; inferred from the data when omitted
+/// * `has_header` - Whether the first row is a header; inferred when omitted
+/// * `flavor` - The project's Markdown flavor; an HTML table is produced when GFM
+///   tables are disabled
+#[tauri::command]
+#[specta::specta]
+pub async fn convert_csv_to_table(
+    text: Option<String>,
+    file_path: Option<String>,
+    delimiter: Option<String>,
+    has_header: Option<bool>,
+    flavor: Option<MarkdownFlavor>,
+) -> Result<MarkdownTable, String> {
+    let text = match (file_path, text) {
+        (Some(path), _) => {
+            std::fs::read_to_string(&path).map_err(|e| format!("Failed to read file: {e}"))?
+        }
+        (None, Some(text)) => text,
+        (None, None) => return Err("No text or file provided".to_string()),
+    };
+
+    convert(
+        &text,
+        delimiter_option(delimiter.as_deref())?,
+        has_header,
+        flavor.unwrap_or_default().gfm_tables,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_delimited_handles_quotes() {
+        let rows = parse_delimited(
+            "name,quote\r\n\"Smith, J\",\"He said \"\"hi\"\"\"\nA,\"multi\nline\"\n\n",
+            ',',
+        );
+        assert_eq!(
+            rows,
+            vec![
+                vec!["name", "quote"],
+                vec!["Smith, J", "He said \"hi\""],
+                vec!["A", "multi\nline"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_infer_delimiter() {
+        assert_eq!(infer_delimiter("a,b,c\n1,2,3"), Some(','));
+        assert_eq!(infer_delimiter("a\tb, c\n1\t2, 3"), Some('\t'));
+        assert_eq!(infer_delimiter("a;b\n1,5;2,5"), Some(';'));
+        assert_eq!(infer_delimiter("just a sentence"), None);
+    }
+
+    #[test]
+    fn test_infer_header() {
+        let rows = |text: &str| parse_delimited(text, ',');
+        assert!(infer_header(&rows("item,price\napple,1.20\npear,0.90")));
+        assert!(!infer_header(&rows("2024,10\n2025,12")));
+        // No numeric evidence: unique labels read as a header
+        assert!(infer_header(&rows("first,last\nAda,Lovelace")));
+        assert!(!infer_header(&rows("a,a\nb,c")));
+    }
+
+    #[test]
+    fn test_convert_renders_aligned_table() {
+        let table = convert("Item,Price\nApple,1.2\nWatermelon|XL,10", None, None, true).unwrap();
+        assert!(table.has_header);
+        assert_eq!(table.delimiter, ",");
+        assert_eq!(table.row_count, 2);
+        assert_eq!(table.column_count, 2);
+        assert_eq!(
+            table.markdown,
+            "| Item           | Price |\n\
+             | -------------- | ----: |\n\
+             | Apple          |   1.2 |\n\
+             | Watermelon\\|XL |    10 |"
+        );
+    }
+
+    #[test]
+    fn test_convert_without_header_and_ragged_rows() {
+        let table = convert("a\tb\tc\nd\te", None, Some(false), true).unwrap();
+        assert!(!table.has_header);
+        assert_eq!(
+            table.markdown,
+            "|     |     |     |\n\
+             | --- | --- | --- |\n\
+             | a   | b   | c   |\n\
+             | d   | e   |     |"
+        );
+    }
+
+    #[test]
+    fn test_convert_renders_html_without_gfm_tables() {
+        let table = convert("Item,Price\nFish & <b>chips</b>,4.5", None, None, false).unwrap();
+        assert_eq!(
+            table.markdown,
+            "<table>\n  <thead>\n    <tr><th>Item</th><th>Price</th></tr>\n  </thead>\n  <tbody>\n    <tr><td>Fish &amp; &lt;b&gt;chips&lt;/b&gt;</td><td style=\"text-align: right\">4.5</td></tr>\n  </tbody>\n</table>"
+        );
+    }
+}
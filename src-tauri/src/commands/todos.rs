@@ -0,0 +1,250 @@
+//! Project-wide TODO/FIXME/HACK collection
+//!
+//! Writers leave editorial notes in drafts as bare markers (`TODO: check quote`),
+//! HTML comments (`<!-- FIXME: link -->`), MDX comments (`{/* HACK: ... */}`) or YAML
+//! comments in frontmatter. Markers inside fenced code blocks are sample code, not
+//! notes, and are skipped.
+
+use crate::commands::project::scan_project_with_content_dir;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Files larger than this are skipped
+const MAX_SCANNED_FILE_SIZE: u64 = 2 * 1024 * 1024;
+
+/// The marker that introduced a note
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TodoMarker {
+    Todo,
+    Fixme,
+    Hack,
+}
+
+/// An editorial note found in a content file
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TodoItem {
+    pub file_path: String,
+    pub collection: String,
+    /// 1-based line number
+    pub line: u32,
+    pub marker: TodoMarker,
+    /// The note text after the marker, without comment delimiters
+    pub text: String,
+    /// True if the note is in the frontmatter rather than the body
+    pub in_frontmatter: bool,
+}
+
+fn marker_regex() -> Regex {
+    // Uppercase only, so prose like "a hack" or "todo list" doesn't match
+    Regex::new(r"\b(TODO|FIXME|HACK)\b(?:\([^)]*\))?:?\s*(.*)").unwrap()
+}
+
+/// Strips trailing comment closers (`-->`, `*/}`, `*/`) from a note
+fn clean_text(text: &str) -> String {
+    let mut text = text.trim();
+    for closer in ["-->", "*/}", "*/"] {
+        if let Some(stripped) = text.strip_suffix(closer) {
+            text = stripped.trim_end();
+        }
+    }
+    text.to_string()
+}
+
+/// Finds markers in a document, returning `(line, marker, text, in_frontmatter)`
+fn find_todos(content: &str, marker_re: &Regex) -> Vec<(u32, TodoMarker, String, bool)> {
+    let mut todos = Vec::new();
+    let mut in_frontmatter = false;
+    let mut fence: Option<String> = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+
+        if index == 0 && trimmed == "---" {
+            in_frontmatter = true;
+            continue;
+        }
+        if in_frontmatter && trimmed == "---" {
+            in_frontmatter = false;
+            continue;
+        }
+
+        if !in_frontmatter {
+            let fence_marker: String = trimmed
+                .chars()
+                .take_while(|c| *c == '`' || *c == '~')
+                .collect();
+            match &fence {
+                Some(open) if trimmed.starts_with(open.as_str()) && trimmed == fence_marker => {
+                    fence = None;
+                    continue;
+                }
+                Some(_) => continue,
+                None if fence_marker.len() >= 3 => {
+                    fence = Some(fence_marker);
+                    continue;
+                }
+                None => {}
+            }
+        }
+
+        let Some(captures) = marker_re.captures(line) else {
+            continue;
+        };
+        let marker = match &captures[1] {
+            "TODO" => TodoMarker::Todo,
+            "FIXME" => TodoMarker::Fixme,
+            _ => TodoMarker::Hack,
+        };
+
+        todos.push((
+            index as u32 + 1,
+            marker,
+            clean_text(&captures[2]),
+            in_frontmatter,
+        ));
+    }
+
+    todos
+}
+
+fn collect_from_directory(root: &Path, collection: &str, marker_re: &Regex) -> Vec<TodoItem> {
+    let entries = WalkDir::new(root)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry.file_type().is_file()
+                && matches!(
+                    entry.path().extension().and_then(|e| e.to_str()),
+                    Some("md") | Some("mdx")
+                )
+        });
+
+    let mut items = Vec::new();
+    for entry in entries {
+        let path = entry.path();
+        if entry
+            .metadata()
+            .is_ok_and(|m| m.len() > MAX_SCANNED_FILE_SIZE)
+        {
+            continue;
+        }
+        let Ok(content) = std::fs::read_to_string(path) else {
+            continue;
+        };
+
+        items.extend(find_todos(&content, marker_re).into_iter().map(
+            |(line, marker, text, in_frontmatter)| TodoItem {
+                file_path: path.to_string_lossy().to_string(),
+                collection: collection.to_string(),
+                line,
+                marker,
+                text,
+                in_frontmatter,
+            },
+        ));
+    }
+
+    items
+}
+
+/// Collects TODO/FIXME/HACK notes from every collection in a project
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `content_directory` - Optional content directory override
+///
+/// # Returns
+/// Notes ordered by file path, then line
+#[tauri::command]
+#[specta::specta]
+pub async fn collect_todos(
+    project_path: String,
+    content_directory: Option<String>,
+) -> Result<Vec<TodoItem>, String> {
+    let collections = scan_project_with_content_dir(project_path, content_directory, None).await?;
+
+    tokio::task::spawn_blocking(move || {
+        let marker_re = marker_regex();
+        let mut items: Vec<TodoItem> = collections
+            .iter()
+            .flat_map(|c| collect_from_directory(&c.path, &c.name, &marker_re))
+            .collect();
+
+        // Nested collections are scanned by both parents; keep one copy
+        items.sort_by(|a, b| a.file_path.cmp(&b.file_path).then(a.line.cmp(&b.line)));
+        items.dedup_by(|a, b| a.file_path == b.file_path && a.line == b.line);
+        items
+    })
+    .await
+    .map_err(|e| format!("Failed to collect TODOs: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_find_todos_in_body_and_frontmatter() {
+        let content = "---\n\
+                       title: Draft # TODO: better title\n\
+                       ---\n\
+                       \n\
+                       Intro. TODO: add a source\n\
+                       <!-- FIXME: broken link -->\n\
+                       {/* HACK(dan): remove after launch */}\n\
+                       A todo list and a hack are fine.\n";
+        let todos = find_todos(content, &marker_regex());
+        assert_eq!(
+            todos,
+            vec![
+                (2, TodoMarker::Todo, "better title".to_string(), true),
+                (5, TodoMarker::Todo, "add a source".to_string(), false),
+                (6, TodoMarker::Fixme, "broken link".to_string(), false),
+                (
+                    7,
+                    TodoMarker::Hack,
+                    "remove after launch".to_string(),
+                    false
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_todos_skips_code_fences() {
+        let content = "```js\n// TODO: sample code\n```\n\n~~~\nFIXME\n~~~\nTODO: real\n";
+        let todos = find_todos(content, &marker_regex());
+        assert_eq!(
+            todos,
+            vec![(8, TodoMarker::Todo, "real".to_string(), false)]
+        );
+    }
+
+    #[test]
+    fn test_collect_from_directory() {
+        let temp = TempDir::new().unwrap();
+        let nested = temp.path().join("2025");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(temp.path().join("a.md"), "TODO: first\n").unwrap();
+        fs::write(nested.join("b.mdx"), "text\n<!-- TODO: second -->\n").unwrap();
+        fs::write(temp.path().join("notes.txt"), "TODO: ignored\n").unwrap();
+
+        let mut items = collect_from_directory(temp.path(), "posts", &marker_regex());
+        items.sort_by(|a, b| a.text.cmp(&b.text));
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].text, "first");
+        assert_eq!(items[0].collection, "posts");
+        assert_eq!(items[1].text, "second");
+        assert_eq!(items[1].line, 2);
+        assert!(items[1].file_path.ends_with("b.mdx"));
+    }
+}
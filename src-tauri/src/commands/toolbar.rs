@@ -0,0 +1,226 @@
+//! Optional native window toolbar (macOS)
+//!
+//! Some users prefer native chrome to the webview title bar. When enabled, the main
+//! window gets a standard title bar with an `NSToolbar` whose buttons emit the same
+//! events as the equivalent menu items, so the frontend handles both the same way.
+//! On other platforms the command is a no-op.
+
+use tauri::Manager;
+
+/// Toolbar buttons as `(identifier, label, SF Symbol, event)`, in display order
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+const TOOLBAR_ITEMS: [(&str, &str, &str, &str); 4] = [
+    ("save", "Save", "square.and.arrow.down", "menu-save"),
+    (
+        "toggle_frontmatter",
+        "Frontmatter",
+        "sidebar.right",
+        "menu-toggle-frontmatter",
+    ),
+    ("insert_image", "Insert Image", "photo", "menu-insert-image"),
+    ("publish", "Publish", "paperplane", "menu-publish"),
+];
+
+/// The event a toolbar button emits
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn toolbar_event(identifier: &str) -> Option<&'static str> {
+    TOOLBAR_ITEMS
+        .iter()
+        .find(|(id, ..)| *id == identifier)
+        .map(|(.., event)| *event)
+}
+
+#[cfg(target_os = "macos")]
+mod native {
+    use super::{toolbar_event, TOOLBAR_ITEMS};
+    use objc2::rc::Retained;
+    use objc2::runtime::{AnyObject, NSObject, NSObjectProtocol, ProtocolObject};
+    use objc2::{define_class, msg_send, sel, DefinedClass, MainThreadMarker, MainThreadOnly};
+    use objc2_app_kit::{
+        NSImage, NSToolbar, NSToolbarDelegate, NSToolbarDisplayMode, NSToolbarItem, NSWindow,
+        NSWindowStyleMask,
+    };
+    use objc2_foundation::{NSArray, NSString};
+    use std::cell::RefCell;
+    use tauri::{AppHandle, Emitter};
+
+    pub struct DelegateIvars {
+        app: AppHandle,
+    }
+
+    define_class!(
+        #[unsafe(super(NSObject))]
+        #[thread_kind = MainThreadOnly]
+        #[name = "AstroEditorToolbarDelegate"]
+        #[ivars = DelegateIvars]
+        pub struct ToolbarDelegate;
+
+        impl ToolbarDelegate {
+            #[unsafe(method(toolbarItemClicked:))]
+            fn item_clicked(&self, sender: &NSToolbarItem) {
+                let identifier = sender.itemIdentifier().to_string();
+                if let Some(event) = toolbar_event(&identifier) {
+                    let _ = self.ivars().app.emit(event, ());
+                }
+            }
+        }
+
+        unsafe impl NSObjectProtocol for ToolbarDelegate {}
+
+        unsafe impl NSToolbarDelegate for ToolbarDelegate {
+            #[unsafe(method(toolbar:itemForItemIdentifier:willBeInsertedIntoToolbar:))]
+            fn item_for_identifier(
+                &self,
+                _toolbar: &NSToolbar,
+                identifier: &NSString,
+                _will_insert: bool,
+            ) -> Option<Retained<NSToolbarItem>> {
+                let identifier_str = identifier.to_string();
+                let (_, label, symbol, _) = TOOLBAR_ITEMS
+                    .iter()
+                    .find(|(id, ..)| *id == identifier_str)?;
+
+                let mtm = self.mtm();
+                let label = NSString::from_str(label);
+                let target: &AnyObject = self;
+                unsafe {
+                    let item = NSToolbarItem::initWithItemIdentifier(
+                        NSToolbarItem::alloc(mtm),
+                        identifier,
+                    );
+                    item.setLabel(&label);
+                    item.setToolTip(Some(&label));
+                    let image = NSImage::imageWithSystemSymbolName_accessibilityDescription(
+                        &NSString::from_str(symbol),
+                        Some(&label),
+                    );
+                    item.setImage(image.as_deref());
+                    item.setTarget(Some(target));
+                    item.setAction(Some(sel!(toolbarItemClicked:)));
+                    Some(item)
+                }
+            }
+
+            #[unsafe(method(toolbarDefaultItemIdentifiers:))]
+            fn default_item_identifiers(
+                &self,
+                _toolbar: &NSToolbar,
+            ) -> Retained<NSArray<NSString>> {
+                item_identifiers()
+            }
+
+            #[unsafe(method(toolbarAllowedItemIdentifiers:))]
+            fn allowed_item_identifiers(
+                &self,
+                _toolbar: &NSToolbar,
+            ) -> Retained<NSArray<NSString>> {
+                item_identifiers()
+            }
+        }
+    );
+
+    /// File actions on the left, a flexible space, then Insert Image and Publish
+    fn item_identifiers() -> Retained<NSArray<NSString>> {
+        let mut identifiers: Vec<Retained<NSString>> = TOOLBAR_ITEMS
+            .iter()
+            .map(|(id, ..)| NSString::from_str(id))
+            .collect();
+        // Value of NSToolbarFlexibleSpaceItemIdentifier
+        identifiers.insert(2, NSString::from_str("NSToolbarFlexibleSpaceItem"));
+        NSArray::from_retained_slice(&identifiers)
+    }
+
+    impl ToolbarDelegate {
+        fn new(mtm: MainThreadMarker, app: AppHandle) -> Retained<Self> {
+            let this = Self::alloc(mtm).set_ivars(DelegateIvars { app });
+            unsafe { msg_send![super(this), init] }
+        }
+    }
+
+    thread_local! {
+        // NSToolbar holds its delegate weakly, so keep it alive here
+        static DELEGATE: RefCell<Option<Retained<ToolbarDelegate>>> = const { RefCell::new(None) };
+    }
+
+    /// Shows or hides the toolbar. Must be called on the main thread.
+    pub fn set_toolbar(app: AppHandle, ns_window: usize, visible: bool) {
+        let Some(mtm) = MainThreadMarker::new() else {
+            log::warn!("Astro Editor [TOOLBAR] Not on the main thread");
+            return;
+        };
+        let ns_window = unsafe { &*(ns_window as *const NSWindow) };
+
+        // The window is borderless by default; a toolbar needs a title bar
+        let mut style = ns_window.styleMask();
+        if !visible {
+            style.remove(NSWindowStyleMask::Titled);
+            ns_window.setToolbar(None);
+            ns_window.setStyleMask(style);
+            DELEGATE.with(|slot| slot.borrow_mut().take());
+            return;
+        }
+
+        style.insert(NSWindowStyleMask::Titled);
+        ns_window.setStyleMask(style);
+        let delegate = ToolbarDelegate::new(mtm, app);
+        let toolbar = NSToolbar::initWithIdentifier(
+            NSToolbar::alloc(mtm),
+            &NSString::from_str("AstroEditorToolbar"),
+        );
+        unsafe {
+            toolbar.setDelegate(Some(ProtocolObject::from_ref(&*delegate)));
+        }
+        toolbar.setDisplayMode(NSToolbarDisplayMode::IconOnly);
+        ns_window.setToolbar(Some(&toolbar));
+        DELEGATE.with(|slot| *slot.borrow_mut() = Some(delegate));
+    }
+}
+
+/// Shows or hides the native toolbar on the main window (macOS only)
+///
+/// Toolbar buttons emit `menu-save`, `menu-toggle-frontmatter`, `menu-insert-image`
+/// and `menu-publish`.
+///
+/// # Arguments
+/// * `visible` - Whether the toolbar should be shown
+#[tauri::command]
+#[specta::specta]
+pub async fn set_native_toolbar(app_handle: tauri::AppHandle, visible: bool) -> Result<(), String> {
+    let Some(window) = app_handle.get_webview_window("main") else {
+        log::debug!("Main window not available");
+        return Ok(());
+    };
+
+    #[cfg(target_os = "macos")]
+    {
+        let ns_window = window
+            .ns_window()
+            .map_err(|e| format!("Failed to get native window: {e}"))?
+            as usize;
+        let app = app_handle.clone();
+        window
+            .run_on_main_thread(move || native::set_toolbar(app, ns_window, visible))
+            .map_err(|e| format!("Failed to update toolbar: {e}"))?;
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    let _ = (window, visible);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toolbar_event() {
+        assert_eq!(toolbar_event("save"), Some("menu-save"));
+        assert_eq!(
+            toolbar_event("toggle_frontmatter"),
+            Some("menu-toggle-frontmatter")
+        );
+        assert_eq!(toolbar_event("publish"), Some("menu-publish"));
+        assert_eq!(toolbar_event("unknown"), None);
+    }
+}
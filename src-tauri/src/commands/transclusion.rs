@@ -0,0 +1,402 @@
+//! Includes (transclusion) of shared Markdown
+//!
+//! Boilerplate such as a disclaimer can live in one file and be pulled into entries
+//! with `{{include: ../snippets/disclaimer.md}}`, or with the MDX component named in
+//! the `transclusion` setting (`<Include src="../snippets/disclaimer.md" />`).
+//! `resolve_transclusions` expands them for preview and export; `lint_transclusions`
+//! reports the ones that can't be.
+//!
+//! Paths are relative to the including file, or to the project root when they start
+//! with `/`. Included files are expanded in turn, without their frontmatter or MDX
+//! imports. Includes inside code fences are left alone.
+
+use crate::commands::files::parse_frontmatter_internal;
+use crate::security::validate_project_path;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::{Path, PathBuf};
+
+/// How many includes deep expansion goes by default
+const DEFAULT_MAX_DEPTH: u32 = 8;
+
+/// The project's `transclusion` setting
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransclusionSettings {
+    /// MDX component that includes the file in its `src` attribute, e.g. `Include`
+    #[serde(default)]
+    #[specta(optional)]
+    pub component: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum TransclusionProblemKind {
+    /// The file doesn't exist
+    Missing,
+    OutsideProject,
+    /// The file includes itself, directly or through others
+    Cycle,
+    /// Nested deeper than the depth limit
+    TooDeep,
+}
+
+/// An include that couldn't be expanded, left as written
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransclusionProblem {
+    /// 1-based line in the document; for nested includes, the line of the outermost
+    pub line: u32,
+    /// The path as written
+    pub target: String,
+    pub kind: TransclusionProblemKind,
+    pub message: String,
+}
+
+/// A document with its includes expanded
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedTransclusions {
+    pub content: String,
+    /// Every file included, at any depth
+    pub included: Vec<String>,
+    pub problems: Vec<TransclusionProblem>,
+}
+
+fn include_pattern(settings: Option<&TransclusionSettings>) -> Regex {
+    let mut pattern = r"\{\{\s*include:\s*([^}\n]+?)\s*\}\}".to_string();
+    if let Some(component) = settings
+        .and_then(|settings| settings.component.as_deref())
+        .map(str::trim)
+        .filter(|component| !component.is_empty())
+    {
+        pattern.push_str(&format!(
+            r#"|<{}\b[^>]*?\bsrc=["']([^"']+)["'][^>]*?/>"#,
+            regex::escape(component)
+        ));
+    }
+    Regex::new(&pattern).expect("include pattern is valid")
+}
+
+/// The run of backticks or tildes opening or closing a code fence, if the line has one
+fn fence_marker(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    let fence_char = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let length = trimmed.len() - trimmed.trim_start_matches(fence_char).len();
+    (length >= 3).then(|| &trimmed[..length])
+}
+
+struct Resolver {
+    project_root: PathBuf,
+    pattern: Regex,
+    max_depth: u32,
+    included: Vec<PathBuf>,
+    problems: Vec<TransclusionProblem>,
+}
+
+impl Resolver {
+    /// Expands the includes in `content`, which was read from `file`
+    ///
+    /// `stack` holds the files being expanded, outermost first. `outer_line` is the
+    /// top-level line problems are reported against once nested.
+    fn expand(
+        &mut self,
+        content: &str,
+        file: &Path,
+        stack: &mut Vec<PathBuf>,
+        outer_line: Option<u32>,
+    ) -> String {
+        let pattern = self.pattern.clone();
+        let mut output = String::with_capacity(content.len());
+        let mut fence: Option<String> = None;
+
+        for (index, line) in content.split_inclusive('\n').enumerate() {
+            if let Some(marker) = fence_marker(line) {
+                match &fence {
+                    None => fence = Some(marker.to_string()),
+                    Some(open) if marker.starts_with(open.as_str()) => fence = None,
+                    Some(_) => {}
+                }
+                output.push_str(line);
+                continue;
+            }
+            if fence.is_some() {
+                output.push_str(line);
+                continue;
+            }
+
+            let line_number = outer_line.unwrap_or(index as u32 + 1);
+            let mut last = 0;
+            for captures in pattern.captures_iter(line) {
+                let (Some(whole), Some(target)) =
+                    (captures.get(0), captures.get(1).or_else(|| captures.get(2)))
+                else {
+                    continue;
+                };
+                output.push_str(&line[last..whole.start()]);
+                match self.include(target.as_str().trim(), file, stack, line_number) {
+                    Some(expanded) => output.push_str(expanded.trim_end_matches('\n')),
+                    None => output.push_str(whole.as_str()),
+                }
+                last = whole.end();
+            }
+            output.push_str(&line[last..]);
+        }
+        output
+    }
+
+    /// Reads and expands one included file; `None` (with a problem recorded) if it
+    /// can't be included
+    fn include(
+        &mut self,
+        target: &str,
+        from: &Path,
+        stack: &mut Vec<PathBuf>,
+        line: u32,
+    ) -> Option<String> {
+        let mut problem = |kind, message: String| {
+            self.problems.push(TransclusionProblem {
+                line,
+                target: target.to_string(),
+                kind,
+                message,
+            });
+            None
+        };
+
+        if stack.len() as u32 > self.max_depth {
+            return problem(
+                TransclusionProblemKind::TooDeep,
+                format!("Includes are nested more than {} deep", self.max_depth),
+            );
+        }
+
+        let path = match target.strip_prefix('/') {
+            Some(relative) => self.project_root.join(relative),
+            None => from.parent().unwrap_or(Path::new("")).join(target),
+        };
+        let Ok(path) = path.canonicalize() else {
+            return problem(
+                TransclusionProblemKind::Missing,
+                format!("{target} doesn't exist"),
+            );
+        };
+        if !path.starts_with(&self.project_root) {
+            return problem(
+                TransclusionProblemKind::OutsideProject,
+                format!("{target} is outside the project"),
+            );
+        }
+        if stack.contains(&path) {
+            return problem(
+                TransclusionProblemKind::Cycle,
+                format!("{target} includes itself"),
+            );
+        }
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            return problem(
+                TransclusionProblemKind::Missing,
+                format!("{target} couldn't be read"),
+            );
+        };
+
+        let body = parse_frontmatter_internal(&content)
+            .map(|parsed| parsed.content)
+            .unwrap_or(content);
+        if !self.included.contains(&path) {
+            self.included.push(path.clone());
+        }
+
+        stack.push(path.clone());
+        let expanded = self.expand(&body, &path, stack, Some(line));
+        stack.pop();
+        Some(expanded)
+    }
+}
+
+fn resolve(
+    project_root: &Path,
+    file: &Path,
+    content: &str,
+    settings: Option<&TransclusionSettings>,
+    max_depth: u32,
+) -> ResolvedTransclusions {
+    let mut resolver = Resolver {
+        project_root: project_root.to_path_buf(),
+        pattern: include_pattern(settings),
+        max_depth,
+        included: Vec::new(),
+        problems: Vec::new(),
+    };
+    let content = resolver.expand(content, file, &mut vec![file.to_path_buf()], None);
+
+    ResolvedTransclusions {
+        content,
+        included: resolver
+            .included
+            .iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect(),
+        problems: resolver.problems,
+    }
+}
+
+fn canonical_paths(project_path: &str, file_path: &str) -> Result<(PathBuf, PathBuf), String> {
+    let project_root = Path::new(project_path)
+        .canonicalize()
+        .map_err(|e| format!("Invalid project root: {e}"))?;
+    let file = validate_project_path(file_path, project_path)?;
+    Ok((project_root, file))
+}
+
+/// Expands includes in a document body for preview and export
+///
+/// Includes that can't be expanded are left as written and reported in `problems`.
+///
+/// # Arguments
+/// * `file_path` - The document's file, which relative include paths start from
+/// * `content` - The document body, without frontmatter
+/// * `max_depth` - How many includes deep to expand (defaults to 8)
+#[tauri::command]
+#[specta::specta]
+pub async fn resolve_transclusions(
+    project_path: String,
+    file_path: String,
+    content: String,
+    settings: Option<TransclusionSettings>,
+    max_depth: Option<u32>,
+) -> Result<ResolvedTransclusions, String> {
+    let (project_root, file) = canonical_paths(&project_path, &file_path)?;
+    Ok(resolve(
+        &project_root,
+        &file,
+        &content,
+        settings.as_ref(),
+        max_depth.unwrap_or(DEFAULT_MAX_DEPTH),
+    ))
+}
+
+/// Reports includes in a document that point at missing files, leave the project,
+/// form a cycle or nest too deep
+#[tauri::command]
+#[specta::specta]
+pub async fn lint_transclusions(
+    project_path: String,
+    file_path: String,
+    content: String,
+    settings: Option<TransclusionSettings>,
+) -> Result<Vec<TransclusionProblem>, String> {
+    let (project_root, file) = canonical_paths(&project_path, &file_path)?;
+    Ok(resolve(
+        &project_root,
+        &file,
+        &content,
+        settings.as_ref(),
+        DEFAULT_MAX_DEPTH,
+    )
+    .problems)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// A project with `src/content/blog/post.md` and snippets beside the blog, and a
+    /// file outside it
+    fn project() -> (TempDir, PathBuf, PathBuf) {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().canonicalize().unwrap().join("project");
+        fs::write(temp.path().join("outside.md"), "Outside").unwrap();
+        let blog = root.join("src/content/blog");
+        let snippets = root.join("src/content/snippets");
+        fs::create_dir_all(&blog).unwrap();
+        fs::create_dir_all(&snippets).unwrap();
+        fs::write(
+            snippets.join("disclaimer.md"),
+            "---\ntitle: Disclaimer\n---\n\n**Not advice.** {{include: sign-off.md}}\n",
+        )
+        .unwrap();
+        fs::write(snippets.join("sign-off.md"), "Thanks for reading.\n").unwrap();
+        let post = blog.join("post.md");
+        (temp, root, post)
+    }
+
+    #[test]
+    fn test_resolve_expands_nested_includes() {
+        let (_temp, root, post) = project();
+        let content = "# Post\n\n{{ include: ../snippets/disclaimer.md }}\n\nThe end\n";
+
+        let resolved = resolve(&root, &post, content, None, DEFAULT_MAX_DEPTH);
+
+        assert_eq!(
+            resolved.content,
+            "# Post\n\n**Not advice.** Thanks for reading.\n\nThe end\n"
+        );
+        assert_eq!(resolved.included.len(), 2);
+        assert!(resolved.problems.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_component_syntax_and_root_paths() {
+        let (_temp, root, post) = project();
+        let settings = TransclusionSettings {
+            component: Some("Include".to_string()),
+        };
+        let content = "<Include src=\"/src/content/snippets/sign-off.md\" />\n";
+
+        let resolved = resolve(&root, &post, content, Some(&settings), DEFAULT_MAX_DEPTH);
+        assert_eq!(resolved.content, "Thanks for reading.\n");
+
+        // Without the setting the component is just MDX
+        let resolved = resolve(&root, &post, content, None, DEFAULT_MAX_DEPTH);
+        assert_eq!(resolved.content, content);
+    }
+
+    #[test]
+    fn test_resolve_reports_problems_and_leaves_includes() {
+        let (_temp, root, post) = project();
+        let snippets = root.join("src/content/snippets");
+        fs::write(snippets.join("loop.md"), "{{include: loop.md}}\n").unwrap();
+        let content = "{{include: ../snippets/gone.md}}\n\
+                       ```\n{{include: ../snippets/gone.md}}\n```\n\
+                       {{include: ../snippets/loop.md}}\n\
+                       {{include: ../../../../outside.md}}\n";
+
+        let resolved = resolve(&root, &post, content, None, DEFAULT_MAX_DEPTH);
+
+        let kinds: Vec<_> = resolved
+            .problems
+            .iter()
+            .map(|problem| (problem.line, problem.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (1, TransclusionProblemKind::Missing),
+                (5, TransclusionProblemKind::Cycle),
+                (6, TransclusionProblemKind::OutsideProject),
+            ]
+        );
+        assert!(resolved
+            .content
+            .starts_with("{{include: ../snippets/gone.md}}\n```\n"));
+    }
+
+    #[test]
+    fn test_resolve_stops_at_depth_limit() {
+        let (_temp, root, post) = project();
+        let content = "{{include: ../snippets/disclaimer.md}}\n";
+
+        let resolved = resolve(&root, &post, content, None, 1);
+
+        assert_eq!(
+            resolved.content,
+            "**Not advice.** {{include: sign-off.md}}\n"
+        );
+        assert_eq!(resolved.problems[0].kind, TransclusionProblemKind::TooDeep);
+        assert_eq!(resolved.problems[0].line, 1);
+    }
+}
@@ -0,0 +1,430 @@
+//! Dictation / transcript ingestion
+//!
+//! Turns a raw speech-to-text transcript into a draft entry. Plain text transcripts
+//! are split into paragraphs on blank lines, pause markers (`[pause]`, `(long pause)`)
+//! and speaker changes (`>>`); SRT/VTT subtitles from tools like Whisper are split
+//! wherever the gap between cues is a pause. Filler words are removed, other
+//! bracketed annotations (`[Music]`, `[BLANK_AUDIO]`) are dropped and sentences are
+//! capitalized.
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::files::rebuild_markdown_with_frontmatter_and_imports_ordered;
+use crate::security::validate_project_path;
+use chrono::Local;
+use indexmap::IndexMap;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use specta::Type;
+use std::path::Path;
+
+/// Removed when the caller doesn't supply its own list
+const DEFAULT_FILLER_WORDS: &[&str] = &[
+    "um", "umm", "uh", "uhh", "er", "erm", "ah", "hmm", "mm-hmm", "you know", "I mean",
+];
+
+/// A gap between subtitle cues at least this long starts a new paragraph
+const PAUSE_SECONDS: f64 = 2.0;
+
+/// Frontmatter fields the new entry is written with
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptFields {
+    pub title: String,
+    /// Set to today's date when present
+    pub date: Option<String>,
+    /// Set to true so the entry starts as a draft
+    pub draft: String,
+}
+
+/// The entry created by `ingest_transcript`
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IngestedTranscript {
+    pub file_path: String,
+    pub paragraph_count: u32,
+    pub word_count: u32,
+    pub fillers_removed: u32,
+}
+
+/// A stretch of speech; `breaks` is true when a new paragraph starts before it
+struct Segment {
+    text: String,
+    breaks: bool,
+}
+
+/// Parses `HH:MM:SS,mmm`, `HH:MM:SS.mmm` or `MM:SS.mmm` into seconds
+fn parse_timestamp(text: &str) -> Option<f64> {
+    let mut seconds = 0.0;
+    for part in text.trim().replace(',', ".").split(':') {
+        seconds = seconds * 60.0 + part.parse::<f64>().ok()?;
+    }
+    Some(seconds)
+}
+
+fn format_duration(seconds: f64) -> String {
+    let total = seconds.round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}",
+        total / 3600,
+        total / 60 % 60,
+        total % 60
+    )
+}
+
+/// Splits SRT/VTT cues into segments, breaking paragraphs on pauses. Also returns
+/// the end time of the last cue.
+fn parse_subtitles(raw: &str) -> (Vec<Segment>, f64) {
+    let tag_re = Regex::new(r"<[^>]+>").unwrap();
+    let mut segments = Vec::new();
+    let mut previous_end: Option<f64> = None;
+
+    for block in raw.replace("\r\n", "\n").split("\n\n") {
+        let mut lines = block.lines().skip_while(|line| !line.contains("-->"));
+        let Some(timing) = lines.next() else {
+            continue;
+        };
+        let mut times = timing.split("-->").map(|t| {
+            // VTT cue settings follow the end time
+            parse_timestamp(t.split_whitespace().next().unwrap_or_default())
+        });
+        let (Some(Some(start)), Some(Some(end))) = (times.next(), times.next()) else {
+            continue;
+        };
+
+        let text = lines
+            .map(|line| tag_re.replace_all(line, "").trim().to_string())
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let breaks = previous_end.is_some_and(|prev| start - prev >= PAUSE_SECONDS);
+        previous_end = Some(end);
+        if !text.is_empty() {
+            segments.push(Segment { text, breaks });
+        }
+    }
+
+    (segments, previous_end.unwrap_or(0.0))
+}
+
+/// Splits plain text into segments, breaking paragraphs on blank lines
+fn parse_plain_text(raw: &str) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut breaks = false;
+    for line in raw.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            breaks = true;
+            continue;
+        }
+        segments.push(Segment {
+            text: line.to_string(),
+            breaks,
+        });
+        breaks = false;
+    }
+    segments
+}
+
+/// Joins segments into paragraphs, also breaking on pause markers and `>>` speaker
+/// changes and dropping other bracketed annotations
+fn paragraphs(segments: &[Segment]) -> Vec<String> {
+    let marker_re = Regex::new(r"\[[^\]]*\]|\([^)]*\)|>>").unwrap();
+    let mut paragraphs: Vec<String> = vec![String::new()];
+
+    for segment in segments {
+        if segment.breaks {
+            paragraphs.push(String::new());
+        }
+        let mut last = 0;
+        for marker in marker_re.find_iter(&segment.text) {
+            let current = paragraphs.last_mut().unwrap();
+            current.push(' ');
+            current.push_str(&segment.text[last..marker.start()]);
+            last = marker.end();
+
+            let is_break = marker.as_str() == ">>" || {
+                let inner = marker.as_str().trim_matches(['[', ']', '(', ')']);
+                inner.to_lowercase().contains("pause")
+            };
+            if is_break {
+                paragraphs.push(String::new());
+            } else if !marker.as_str().starts_with('[') {
+                // Parenthesised text may be speech, so only brackets are dropped
+                paragraphs.last_mut().unwrap().push_str(marker.as_str());
+            }
+        }
+        let current = paragraphs.last_mut().unwrap();
+        current.push(' ');
+        current.push_str(&segment.text[last..]);
+    }
+
+    paragraphs
+        .into_iter()
+        .map(|p| p.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|p| p.chars().any(char::is_alphanumeric))
+        .collect()
+}
+
+/// Removes filler words, returning the cleaned text and how many were removed
+fn remove_fillers(text: &str, filler_re: Option<&Regex>) -> (String, u32) {
+    let Some(filler_re) = filler_re else {
+        return (text.to_string(), 0);
+    };
+    let count = filler_re.find_iter(text).count() as u32;
+    let cleaned = filler_re.replace_all(text, " ");
+    (cleaned.into_owned(), count)
+}
+
+/// Tidies spacing and punctuation left by removals, then capitalizes sentences and "I"
+fn sentence_case(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let text = Regex::new(r"\s+([,.!?;:])")
+        .unwrap()
+        .replace_all(&text, "$1");
+    let text = Regex::new(r",+([,.!?])").unwrap().replace_all(&text, "$1");
+    let text = Regex::new(r"\bi\b('[a-z]+)?")
+        .unwrap()
+        .replace_all(&text, "I$1");
+    let text = text.trim_start_matches([',', ';', ':', ' ']);
+
+    let mut result = String::with_capacity(text.len() + 1);
+    let mut capitalize = true;
+    for c in text.chars() {
+        if capitalize && c.is_alphabetic() {
+            result.extend(c.to_uppercase());
+            capitalize = false;
+        } else {
+            result.push(c);
+            if matches!(c, '.' | '!' | '?') {
+                capitalize = true;
+            } else if c.is_alphanumeric() {
+                capitalize = false;
+            }
+        }
+    }
+
+    let result = result.trim_end_matches([',', ';', ':']).to_string();
+    if result.ends_with(|c: char| c.is_alphanumeric()) {
+        result + "."
+    } else {
+        result
+    }
+}
+
+fn filler_regex(filler_words: &[String]) -> Option<Regex> {
+    let mut words: Vec<&String> = filler_words
+        .iter()
+        .filter(|w| !w.trim().is_empty())
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+    // Longest first so "umm" wins over "um"
+    words.sort_by_key(|w| std::cmp::Reverse(w.len()));
+    let alternatives: Vec<String> = words
+        .iter()
+        .map(|w| regex::escape(w.trim()).replace(' ', r"\s+"))
+        .collect();
+    Regex::new(&format!(r"(?i)\b(?:{})\b[,.]?", alternatives.join("|"))).ok()
+}
+
+/// Cleans a transcript into Markdown paragraphs
+///
+/// # Returns
+/// The body, the number of filler words removed and, for subtitles, the duration
+fn clean_transcript(
+    raw: &str,
+    is_subtitles: bool,
+    filler_words: &[String],
+) -> (String, u32, Option<f64>) {
+    let (segments, duration) = if is_subtitles {
+        let (segments, end) = parse_subtitles(raw);
+        (segments, Some(end))
+    } else {
+        (parse_plain_text(raw), None)
+    };
+
+    let filler_re = filler_regex(filler_words);
+    let mut fillers_removed = 0;
+    let body: Vec<String> = paragraphs(&segments)
+        .into_iter()
+        .map(|paragraph| {
+            let (text, removed) = remove_fillers(&paragraph, filler_re.as_ref());
+            fillers_removed += removed;
+            sentence_case(&text)
+        })
+        .filter(|paragraph| paragraph.chars().any(char::is_alphanumeric))
+        .collect();
+
+    (body.join("\n\n") + "\n", fillers_removed, duration)
+}
+
+/// Creates a draft entry from a speech-to-text transcript
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `transcript_path` - The transcript file: plain text, `.srt` or `.vtt`
+/// * `collection_path` - The absolute path to the collection directory
+/// * `filename` - Name for the new entry, e.g. `episode-12.md`
+/// * `title` - The entry's title
+/// * `fields` - Frontmatter field names for the title, date and draft flag
+/// * `filler_words` - Words and phrases to remove; omit for the default list
+#[tauri::command]
+#[specta::specta]
+#[allow(clippy::too_many_arguments)]
+pub async fn ingest_transcript(
+    project_path: String,
+    transcript_path: String,
+    collection_path: String,
+    filename: String,
+    title: String,
+    fields: TranscriptFields,
+    filler_words: Option<Vec<String>>,
+) -> Result<IngestedTranscript, String> {
+    let collection = validate_project_path(&collection_path, &project_path)?;
+    let target =
+        validate_project_path(&collection.join(&filename).to_string_lossy(), &project_path)?;
+    if target.exists() {
+        return Err(format!("File already exists: {filename}"));
+    }
+
+    let source = Path::new(&transcript_path);
+    let raw =
+        std::fs::read_to_string(source).map_err(|e| format!("Failed to read transcript: {e}"))?;
+    let is_subtitles = matches!(
+        source
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_lowercase)
+            .as_deref(),
+        Some("srt") | Some("vtt")
+    );
+    let filler_words = filler_words
+        .unwrap_or_else(|| DEFAULT_FILLER_WORDS.iter().map(|w| w.to_string()).collect());
+    let (body, fillers_removed, duration) = clean_transcript(&raw, is_subtitles, &filler_words);
+
+    let mut source_info = json!({
+        "type": "transcript",
+        "file": source.file_name().map(|n| n.to_string_lossy().to_string()),
+    });
+    if let Some(duration) = duration.filter(|d| *d > 0.0) {
+        source_info["duration"] = json!(format_duration(duration));
+    }
+
+    let mut frontmatter: IndexMap<String, Value> = IndexMap::new();
+    frontmatter.insert(fields.title.clone(), json!(title));
+    if let Some(date) = &fields.date {
+        frontmatter.insert(
+            date.clone(),
+            json!(Local::now().format("%Y-%m-%d").to_string()),
+        );
+    }
+    frontmatter.insert(fields.draft.clone(), json!(true));
+    frontmatter.insert("source".to_string(), source_info);
+
+    let order: Vec<String> = frontmatter.keys().cloned().collect();
+    let content = rebuild_markdown_with_frontmatter_and_imports_ordered(
+        &frontmatter,
+        "",
+        &body,
+        Some(order),
+    )?;
+    std::fs::write(&target, content).map_err(|e| format!("Failed to create file: {e}"))?;
+    audit_log::record(AuditAction::Create, &target, None, "transcript-ingest");
+
+    log::info!(
+        "Astro Editor [TRANSCRIPT] Created {} from {transcript_path}",
+        target.display()
+    );
+
+    Ok(IngestedTranscript {
+        file_path: target.to_string_lossy().to_string(),
+        paragraph_count: body.split("\n\n").filter(|p| !p.trim().is_empty()).count() as u32,
+        word_count: body.split_whitespace().count() as u32,
+        fillers_removed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn defaults() -> Vec<String> {
+        DEFAULT_FILLER_WORDS.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn test_clean_plain_transcript() {
+        let raw = "so um welcome to the show. i think, uh, today we're\n\
+                   talking about rust [pause] you know it's great\n\n\
+                   [Music]\n\
+                   >> thanks for having me (laughs) i'm glad to be here";
+
+        let (body, removed, duration) = clean_transcript(raw, false, &defaults());
+
+        assert_eq!(
+            body,
+            "So welcome to the show. I think, today we're talking about rust.\n\n\
+             It's great.\n\n\
+             Thanks for having me (laughs) I'm glad to be here.\n"
+        );
+        assert_eq!(removed, 3);
+        assert_eq!(duration, None);
+    }
+
+    #[test]
+    fn test_clean_subtitles_breaks_on_pauses() {
+        let raw = "WEBVTT\n\n\
+                   00:00:00.000 --> 00:00:02.000\nhello and welcome\n\n\
+                   00:00:02.100 --> 00:00:04.000 align:start\n<v Host>to episode twelve.\n\n\
+                   00:00:09.000 --> 00:01:05.400\nlet's begin\n";
+
+        let (body, _, duration) = clean_transcript(raw, true, &[]);
+
+        assert_eq!(
+            body,
+            "Hello and welcome to episode twelve.\n\nLet's begin.\n"
+        );
+        assert_eq!(duration.map(format_duration).as_deref(), Some("00:01:05"));
+    }
+
+    #[tokio::test]
+    async fn test_ingest_transcript_creates_draft() {
+        let temp = TempDir::new().unwrap();
+        let collection = temp.path().join("src/content/episodes");
+        fs::create_dir_all(&collection).unwrap();
+        let transcript = temp.path().join("episode-12.txt");
+        fs::write(&transcript, "um hello there").unwrap();
+
+        let fields = TranscriptFields {
+            title: "title".to_string(),
+            date: None,
+            draft: "draft".to_string(),
+        };
+        let ingest = || {
+            ingest_transcript(
+                temp.path().to_string_lossy().to_string(),
+                transcript.to_string_lossy().to_string(),
+                collection.to_string_lossy().to_string(),
+                "episode-12.md".to_string(),
+                "Episode 12".to_string(),
+                fields.clone(),
+                None,
+            )
+        };
+
+        let result = ingest().await.unwrap();
+        assert_eq!(result.fillers_removed, 1);
+        assert_eq!(result.paragraph_count, 1);
+
+        let content = fs::read_to_string(collection.join("episode-12.md")).unwrap();
+        assert!(content.starts_with("---\ntitle: Episode 12\ndraft: true\nsource:\n"));
+        assert!(content.contains("  file: episode-12.txt\n"));
+        assert!(content.ends_with("---\n\nHello there.\n"));
+
+        assert!(ingest().await.is_err());
+    }
+}
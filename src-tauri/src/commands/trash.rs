@@ -0,0 +1,305 @@
+//! Trash for deleted files
+//!
+//! `delete_file` moves files to `trash/` in app data instead of removing them, so an
+//! accidental delete can be undone with `restore_trashed_file`. Each trashed file is
+//! kept as `{id}.data`, with where it came from in `{id}.json`. Files trashed more
+//! than `RETENTION_DAYS` ago are purged when the app starts.
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::dry_run;
+use crate::security::validate_project_path;
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::{Component, Path, PathBuf};
+use std::sync::OnceLock;
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Manager};
+
+/// Days a trashed file is kept before it's deleted for good
+const RETENTION_DAYS: i64 = 30;
+
+static TRASH_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// A deleted file that can still be restored
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashedFile {
+    pub id: String,
+    /// Where the file was deleted from
+    pub original_path: String,
+    pub project_root: String,
+    /// RFC 3339 UTC timestamp
+    pub trashed_at: String,
+}
+
+fn canonical_root(project_root: &str) -> Result<String, String> {
+    Path::new(project_root)
+        .canonicalize()
+        .map(|root| root.to_string_lossy().to_string())
+        .map_err(|e| format!("Invalid project root: {e}"))
+}
+
+/// `std::fs::rename`, falling back to copy and delete across volumes
+fn move_file(from: &Path, to: &Path) -> std::io::Result<()> {
+    if std::fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    std::fs::copy(from, to)?;
+    std::fs::remove_file(from)
+}
+
+fn metadata_paths(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|e| e == "json"))
+        .collect()
+}
+
+fn read_entry(path: &Path) -> Option<TrashedFile> {
+    let json = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+fn trash_in(dir: &Path, path: &Path, project_root: &str) -> Result<TrashedFile, String> {
+    std::fs::create_dir_all(dir).map_err(|e| format!("Failed to create trash: {e}"))?;
+
+    let now = Utc::now();
+    // Zero-padded so ids sort chronologically
+    let base = format!("{:016}", now.timestamp_millis());
+    let id = (0..)
+        .map(|n| match n {
+            0 => base.clone(),
+            n => format!("{base}-{n}"),
+        })
+        .find(|id| !dir.join(format!("{id}.json")).exists())
+        .unwrap_or(base);
+
+    let entry = TrashedFile {
+        id: id.clone(),
+        original_path: path.to_string_lossy().to_string(),
+        project_root: canonical_root(project_root)?,
+        trashed_at: now.to_rfc3339_opts(SecondsFormat::Millis, true),
+    };
+    let json = serde_json::to_string(&entry)
+        .map_err(|e| format!("Failed to serialize trash entry: {e}"))?;
+
+    move_file(path, &dir.join(format!("{id}.data")))
+        .map_err(|e| format!("Failed to move file to trash: {e}"))?;
+    std::fs::write(dir.join(format!("{id}.json")), json)
+        .map_err(|e| format!("Failed to write trash entry: {e}"))?;
+    Ok(entry)
+}
+
+fn list_in(dir: &Path, project_root: &str) -> Result<Vec<TrashedFile>, String> {
+    let project_root = canonical_root(project_root)?;
+    let mut entries: Vec<TrashedFile> = metadata_paths(dir)
+        .iter()
+        .filter_map(|path| read_entry(path))
+        .filter(|entry| entry.project_root == project_root)
+        .filter(|entry| dir.join(format!("{}.data", entry.id)).exists())
+        .collect();
+    // Most recently trashed first
+    entries.sort_by(|a, b| b.id.cmp(&a.id));
+    Ok(entries)
+}
+
+fn restore_in(dir: &Path, id: &str, project_root: &str) -> Result<PathBuf, String> {
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_digit() || c == '-') {
+        return Err(format!("Invalid trash id: {id}"));
+    }
+    let metadata_path = dir.join(format!("{id}.json"));
+    let entry = read_entry(&metadata_path).ok_or("That file is no longer in the trash")?;
+    if entry.project_root != canonical_root(project_root)? {
+        return Err("That file was deleted from a different project".to_string());
+    }
+
+    // Recreate the file's folder if it's gone too, once the part of it that still
+    // exists is known to be in the project
+    let original = Path::new(&entry.original_path);
+    if original.components().any(|c| c == Component::ParentDir) {
+        return Err(format!("Invalid file path: {}", entry.original_path));
+    }
+    if let Some(parent) = original.parent() {
+        let existing = parent
+            .ancestors()
+            .find(|ancestor| ancestor.exists())
+            .ok_or_else(|| format!("Invalid file path: {}", entry.original_path))?;
+        validate_project_path(&existing.to_string_lossy(), project_root)?;
+        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create folder: {e}"))?;
+    }
+    let target = validate_project_path(&entry.original_path, project_root)?;
+    if target.exists() {
+        return Err(format!(
+            "A file already exists at {}; move it before restoring",
+            target.display()
+        ));
+    }
+
+    move_file(&dir.join(format!("{id}.data")), &target)
+        .map_err(|e| format!("Failed to restore file: {e}"))?;
+    let _ = std::fs::remove_file(&metadata_path);
+    Ok(target)
+}
+
+fn purge_in(dir: &Path, cutoff: DateTime<Utc>) {
+    for path in metadata_paths(dir) {
+        let expired = read_entry(&path)
+            .and_then(|entry| DateTime::parse_from_rfc3339(&entry.trashed_at).ok())
+            .is_none_or(|trashed_at| trashed_at < cutoff);
+        if expired {
+            let _ = std::fs::remove_file(path.with_extension("data"));
+            let _ = std::fs::remove_file(&path);
+        }
+    }
+}
+
+/// Moves a project file to the trash
+///
+/// During a dry run the delete is only recorded. Without a trash directory (app data
+/// couldn't be resolved) the file is left in place, rather than deleted for good.
+pub(crate) fn move_to_trash(
+    path: &Path,
+    project_root: &str,
+) -> Result<Option<TrashedFile>, String> {
+    if dry_run::is_active() {
+        dry_run::remove_file(path).map_err(|e| format!("Failed to delete file: {e}"))?;
+        return Ok(None);
+    }
+    let dir = TRASH_DIR.get().ok_or("Trash is not available")?;
+    trash_in(dir, path, project_root).map(Some)
+}
+
+/// Lists files deleted from a project that can still be restored, newest first
+#[tauri::command]
+#[specta::specta]
+pub async fn list_trashed_files(project_root: String) -> Result<Vec<TrashedFile>, String> {
+    let dir = TRASH_DIR.get().ok_or("Trash is not available")?;
+    list_in(dir, &project_root)
+}
+
+/// Restores a trashed file to where it was deleted from
+///
+/// Fails rather than overwrite a file that has since been created at that path.
+///
+/// # Returns
+/// The restored file's path
+#[tauri::command]
+#[specta::specta]
+pub async fn restore_trashed_file(
+    id: String,
+    project_root: String,
+    origin: Option<String>,
+) -> Result<String, String> {
+    let dir = TRASH_DIR.get().ok_or("Trash is not available")?;
+    let restored = restore_in(dir, &id, &project_root)?;
+    audit_log::record(
+        AuditAction::Create,
+        &restored,
+        None,
+        origin.as_deref().unwrap_or("restore_trashed_file"),
+    );
+    Ok(restored.to_string_lossy().to_string())
+}
+
+// Resolve the trash directory and purge expired files when the app starts
+pub fn init_trash(app: &AppHandle) {
+    match app.path().resolve("trash", BaseDirectory::AppLocalData) {
+        Ok(dir) => {
+            purge_in(&dir, Utc::now() - Duration::days(RETENTION_DAYS));
+            let _ = TRASH_DIR.set(dir);
+        }
+        Err(e) => log::warn!("Astro Editor [TRASH] Failed to resolve trash directory: {e}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_trash_and_restore() {
+        let temp = TempDir::new().unwrap();
+        let trash = temp.path().join("trash");
+        let project = temp.path().join("project");
+        let post = project.join("src/content/blog/post.md");
+        fs::create_dir_all(post.parent().unwrap()).unwrap();
+        fs::write(&post, "# Post").unwrap();
+        let root = project.to_string_lossy().to_string();
+
+        let entry = trash_in(&trash, &post, &root).unwrap();
+        assert!(!post.exists());
+        assert_eq!(list_in(&trash, &root).unwrap(), vec![entry.clone()]);
+
+        // The folder went with the file; restoring recreates it
+        fs::remove_dir_all(project.join("src/content/blog")).unwrap();
+        let restored = restore_in(&trash, &entry.id, &root).unwrap();
+        assert_eq!(fs::read_to_string(&restored).unwrap(), "# Post");
+        assert!(list_in(&trash, &root).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_restore_refuses_to_overwrite() {
+        let temp = TempDir::new().unwrap();
+        let trash = temp.path().join("trash");
+        let post = temp.path().join("post.md");
+        fs::write(&post, "old").unwrap();
+        let root = temp.path().to_string_lossy().to_string();
+
+        let entry = trash_in(&trash, &post, &root).unwrap();
+        fs::write(&post, "new").unwrap();
+
+        assert!(restore_in(&trash, &entry.id, &root)
+            .unwrap_err()
+            .contains("already exists"));
+        assert_eq!(fs::read_to_string(&post).unwrap(), "new");
+        assert!(restore_in(&trash, "../post", &root).is_err());
+    }
+
+    #[test]
+    fn test_purge_removes_expired_files() {
+        let temp = TempDir::new().unwrap();
+        let trash = temp.path().join("trash");
+        let post = temp.path().join("post.md");
+        fs::write(&post, "old").unwrap();
+        let root = temp.path().to_string_lossy().to_string();
+        trash_in(&trash, &post, &root).unwrap();
+
+        purge_in(&trash, Utc::now() - Duration::days(RETENTION_DAYS));
+        assert_eq!(list_in(&trash, &root).unwrap().len(), 1);
+
+        purge_in(&trash, Utc::now() + Duration::days(1));
+        assert!(list_in(&trash, &root).unwrap().is_empty());
+        assert_eq!(fs::read_dir(&trash).unwrap().count(), 0);
+    }
+    #[test]
+    fn test_restore_refuses_folders_outside_the_project() {
+        let temp = TempDir::new().unwrap();
+        let trash = temp.path().join("trash");
+        let project = temp.path().join("project");
+        let post = project.join("post.md");
+        fs::create_dir_all(&project).unwrap();
+        fs::write(&post, "# Post").unwrap();
+        let root = project.to_string_lossy().to_string();
+
+        // An entry edited to point outside the project, into a folder that's gone
+        let mut entry = trash_in(&trash, &post, &root).unwrap();
+        let outside = temp.path().join("outside/nested/post.md");
+        entry.original_path = outside.to_string_lossy().to_string();
+        fs::write(
+            trash.join(format!("{}.json", entry.id)),
+            serde_json::to_string(&entry).unwrap(),
+        )
+        .unwrap();
+
+        assert!(restore_in(&trash, &entry.id, &root).is_err());
+        assert!(!temp.path().join("outside").exists());
+        assert_eq!(list_in(&trash, &root).unwrap().len(), 1);
+    }
+}
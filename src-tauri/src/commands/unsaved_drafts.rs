@@ -0,0 +1,350 @@
+//! Unsaved editor changes kept outside the frontend
+//!
+//! While a file has edits that aren't saved yet, the editor keeps a draft of them in
+//! `drafts/` in app data with `save_draft`, so they survive a crash, a failed save or a
+//! save refused because the file changed on disk. Drafts are grouped by project, one
+//! per file, and saving the file discards its draft. When a project is opened, drafts
+//! left over from a previous session are announced with an `unsaved-drafts-found` event.
+//!
+//! Drafts are encrypted like recovery data when the `recovery.encrypt` preference is on.
+//! (These are not draft entries; see `stale_drafts` for those.)
+
+use crate::commands::bulk_undo::content_hash;
+use crate::commands::dry_run;
+use crate::commands::files::parse_frontmatter_internal;
+use crate::commands::recovery;
+use crate::security::validate_project_path;
+use chrono::{SecondsFormat, Utc};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Emitter, Manager};
+
+static DRAFTS_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Projects whose drafts were announced this session
+static ANNOUNCED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Unsaved edits to a file
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsavedDraft {
+    pub file_path: String,
+    /// The editor content, without frontmatter or imports
+    pub content: String,
+    /// The edited frontmatter; `None` when only the content was edited
+    pub frontmatter: Option<IndexMap<String, Value>>,
+    /// `content_hash` of the file the edits were made to
+    pub base_hash: Option<String>,
+    /// RFC 3339 UTC timestamp
+    pub saved_at: String,
+}
+
+/// A file with unsaved edits from an earlier session
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsavedDraftSummary {
+    pub file_path: String,
+    /// RFC 3339 UTC timestamp
+    pub saved_at: String,
+    /// The file has changed on disk since the edits were made
+    pub changed_on_disk: bool,
+}
+
+/// The directory holding a project's drafts
+fn project_dir(dir: &Path, project_root: &str) -> Result<PathBuf, String> {
+    let root = Path::new(project_root)
+        .canonicalize()
+        .map_err(|e| format!("Invalid project root: {e}"))?;
+    Ok(dir.join(content_hash(&root.to_string_lossy())))
+}
+
+fn draft_stem(path: &Path) -> String {
+    content_hash(&path.to_string_lossy())
+}
+
+/// Draft files in a project's directory, only those for `stem` if given
+fn draft_files(project_dir: &Path, stem: Option<&str>) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(project_dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|e| e == "json" || e == "enc"))
+        .filter(|path| stem.is_none_or(|stem| path.file_stem().is_some_and(|s| s == stem)))
+        .collect()
+}
+
+fn write_draft(project_dir: &Path, draft: &UnsavedDraft, key: Option<&[u8]>) -> Result<(), String> {
+    std::fs::create_dir_all(project_dir)
+        .map_err(|e| format!("Failed to create drafts directory: {e}"))?;
+    let json = serde_json::to_vec(draft).map_err(|e| format!("Failed to serialize draft: {e}"))?;
+    let (contents, extension, other) = match key {
+        Some(key) => (recovery::encrypt(key, &json)?, "enc", "json"),
+        None => (json, "json", "enc"),
+    };
+
+    let stem = draft_stem(Path::new(&draft.file_path));
+    std::fs::write(project_dir.join(format!("{stem}.{extension}")), contents)
+        .map_err(|e| format!("Failed to write draft: {e}"))?;
+    // Encryption may have been switched since the last draft of this file
+    let _ = std::fs::remove_file(project_dir.join(format!("{stem}.{other}")));
+    Ok(())
+}
+
+fn read_draft(file: &Path) -> Result<UnsavedDraft, String> {
+    let bytes = std::fs::read(file).map_err(|e| format!("Failed to read draft: {e}"))?;
+    let json = if file.extension().is_some_and(|e| e == "enc") {
+        recovery::decrypt(&recovery::recovery_key()?, &bytes)?
+    } else {
+        bytes
+    };
+    serde_json::from_slice(&json).map_err(|e| format!("Failed to parse draft: {e}"))
+}
+
+/// Whether a file's content on disk already has the draft's edits
+fn matches_disk(draft: &UnsavedDraft, disk_content: &str) -> bool {
+    let Ok(parsed) = parse_frontmatter_internal(disk_content) else {
+        return false;
+    };
+    parsed.content == draft.content
+        && draft
+            .frontmatter
+            .as_ref()
+            .is_none_or(|frontmatter| *frontmatter == parsed.frontmatter)
+}
+
+/// Reads a draft, discarding it if its file is gone or already has its edits
+fn read_unsaved(file: &Path) -> Result<Option<(UnsavedDraft, String)>, String> {
+    let draft = read_draft(file)?;
+    match std::fs::read_to_string(&draft.file_path) {
+        Ok(disk_content) if !matches_disk(&draft, &disk_content) => Ok(Some((draft, disk_content))),
+        _ => {
+            let _ = std::fs::remove_file(file);
+            Ok(None)
+        }
+    }
+}
+
+fn get_in(project_dir: &Path, path: &Path) -> Result<Option<UnsavedDraft>, String> {
+    for file in draft_files(project_dir, Some(&draft_stem(path))) {
+        if let Some((draft, _)) = read_unsaved(&file)? {
+            return Ok(Some(draft));
+        }
+    }
+    Ok(None)
+}
+
+fn discard_in(project_dir: &Path, path: &Path) {
+    for file in draft_files(project_dir, Some(&draft_stem(path))) {
+        let _ = std::fs::remove_file(file);
+    }
+}
+
+fn list_in(project_dir: &Path) -> Vec<UnsavedDraftSummary> {
+    let mut summaries: Vec<UnsavedDraftSummary> = draft_files(project_dir, None)
+        .iter()
+        .filter_map(|file| match read_unsaved(file) {
+            Ok(unsaved) => unsaved,
+            Err(e) => {
+                log::warn!("Astro Editor [DRAFTS] Skipping {}: {e}", file.display());
+                None
+            }
+        })
+        .map(|(draft, disk_content)| UnsavedDraftSummary {
+            changed_on_disk: draft
+                .base_hash
+                .is_some_and(|hash| hash != content_hash(&disk_content)),
+            file_path: draft.file_path,
+            saved_at: draft.saved_at,
+        })
+        .collect();
+    // Most recent first
+    summaries.sort_by(|a, b| b.saved_at.cmp(&a.saved_at));
+    summaries
+}
+
+/// Discards a file's draft once the file has been saved
+///
+/// Drafts are kept during a dry run, since nothing was actually written.
+pub(crate) fn discard(path: &Path, project_root: &str) {
+    let Some(dir) = DRAFTS_DIR.get().filter(|_| !dry_run::is_active()) else {
+        return;
+    };
+    if let Ok(project_dir) = project_dir(dir, project_root) {
+        discard_in(&project_dir, path);
+    }
+}
+
+/// Emits `unsaved-drafts-found` the first time a project with drafts is opened
+pub(crate) fn announce(app: &AppHandle, project_root: &str) {
+    let Some(project_dir) = DRAFTS_DIR
+        .get()
+        .and_then(|dir| project_dir(dir, project_root).ok())
+    else {
+        return;
+    };
+    {
+        let mut announced = ANNOUNCED.lock().unwrap();
+        if announced.iter().any(|root| root == project_root) {
+            return;
+        }
+        announced.push(project_root.to_string());
+    }
+
+    let drafts = list_in(&project_dir);
+    if drafts.is_empty() {
+        return;
+    }
+    log::info!(
+        "Astro Editor [DRAFTS] {} files have unsaved changes from a previous session",
+        drafts.len()
+    );
+    if let Err(e) = app.emit("unsaved-drafts-found", &drafts) {
+        log::warn!("Astro Editor [DRAFTS] Failed to emit unsaved-drafts-found: {e}");
+    }
+}
+
+/// Keeps a file's unsaved edits until the file is saved
+///
+/// # Arguments
+/// * `frontmatter` - The edited frontmatter, or `None` when only the content was edited
+/// * `base_hash` - The `content_hash` the file was loaded with
+/// * `encrypt` - Encrypt the draft (the `recovery.encrypt` preference)
+#[tauri::command]
+#[specta::specta]
+pub async fn save_draft(
+    file_path: String,
+    project_root: String,
+    content: String,
+    frontmatter: Option<IndexMap<String, Value>>,
+    base_hash: Option<String>,
+    encrypt: Option<bool>,
+) -> Result<(), String> {
+    let dir = DRAFTS_DIR.get().ok_or("Drafts are not available")?;
+    let validated_path = validate_project_path(&file_path, &project_root)?;
+    let key = if encrypt.unwrap_or(false) {
+        Some(recovery::recovery_key()?)
+    } else {
+        None
+    };
+
+    let draft = UnsavedDraft {
+        file_path: validated_path.to_string_lossy().to_string(),
+        content,
+        frontmatter,
+        base_hash,
+        saved_at: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+    };
+    write_draft(&project_dir(dir, &project_root)?, &draft, key.as_deref())
+}
+
+/// Gets a file's unsaved edits, if it has any
+#[tauri::command]
+#[specta::specta]
+pub async fn get_draft(
+    file_path: String,
+    project_root: String,
+) -> Result<Option<UnsavedDraft>, String> {
+    let dir = DRAFTS_DIR.get().ok_or("Drafts are not available")?;
+    let validated_path = validate_project_path(&file_path, &project_root)?;
+    get_in(&project_dir(dir, &project_root)?, &validated_path)
+}
+
+/// Throws away a file's unsaved edits
+#[tauri::command]
+#[specta::specta]
+pub async fn discard_draft(file_path: String, project_root: String) -> Result<(), String> {
+    let dir = DRAFTS_DIR.get().ok_or("Drafts are not available")?;
+    let validated_path = validate_project_path(&file_path, &project_root)?;
+    discard_in(&project_dir(dir, &project_root)?, &validated_path);
+    Ok(())
+}
+
+/// Lists the project's files with unsaved edits, most recently edited first
+#[tauri::command]
+#[specta::specta]
+pub async fn list_unsaved_drafts(project_root: String) -> Result<Vec<UnsavedDraftSummary>, String> {
+    let dir = DRAFTS_DIR.get().ok_or("Drafts are not available")?;
+    Ok(list_in(&project_dir(dir, &project_root)?))
+}
+
+// Resolve the drafts directory when the app starts
+pub fn init_unsaved_drafts(app: &AppHandle) {
+    match app.path().resolve("drafts", BaseDirectory::AppLocalData) {
+        Ok(dir) => {
+            let _ = DRAFTS_DIR.set(dir);
+        }
+        Err(e) => log::warn!("Astro Editor [DRAFTS] Failed to resolve drafts directory: {e}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn draft(path: &Path, content: &str, base_hash: Option<String>) -> UnsavedDraft {
+        UnsavedDraft {
+            file_path: path.to_string_lossy().to_string(),
+            content: content.to_string(),
+            frontmatter: None,
+            base_hash,
+            saved_at: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+        }
+    }
+
+    #[test]
+    fn test_draft_round_trip() {
+        let temp = TempDir::new().unwrap();
+        let drafts = temp.path().join("drafts");
+        let post = temp.path().join("post.md");
+        fs::write(&post, "---\ntitle: Post\n---\n\nSaved.\n").unwrap();
+
+        let unsaved = draft(&post, "Edited.\n", None);
+        write_draft(&drafts, &unsaved, None).unwrap();
+        assert_eq!(get_in(&drafts, &post).unwrap(), Some(unsaved));
+
+        discard_in(&drafts, &post);
+        assert_eq!(get_in(&drafts, &post).unwrap(), None);
+    }
+
+    #[test]
+    fn test_list_drops_saved_and_missing_files() {
+        let temp = TempDir::new().unwrap();
+        let drafts = temp.path().join("drafts");
+        let disk = "---\ntitle: Post\n---\n\nSaved.\n";
+        let edited = temp.path().join("edited.md");
+        let saved = temp.path().join("saved.md");
+        let deleted = temp.path().join("deleted.md");
+        fs::write(&edited, disk).unwrap();
+        fs::write(&saved, disk).unwrap();
+
+        write_draft(
+            &drafts,
+            &draft(&edited, "Edited.\n", Some(content_hash(disk))),
+            None,
+        )
+        .unwrap();
+        // The file already has these edits
+        let mut retitled = draft(&saved, "Saved.\n", None);
+        retitled.frontmatter = Some(IndexMap::from([("title".to_string(), "Post".into())]));
+        write_draft(&drafts, &retitled, None).unwrap();
+        write_draft(&drafts, &draft(&deleted, "Gone.\n", None), None).unwrap();
+
+        let listed = list_in(&drafts);
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].file_path, edited.to_string_lossy());
+        assert!(!listed[0].changed_on_disk);
+        assert_eq!(draft_files(&drafts, None).len(), 1);
+
+        fs::write(&edited, "Changed elsewhere.\n").unwrap();
+        assert!(list_in(&drafts)[0].changed_on_disk);
+    }
+}
@@ -1,4 +1,91 @@
-use serde::Deserialize;
+//! App updates and release channels
+//!
+//! Updates are checked from Rust so the release feed can follow the chosen channel:
+//! stable uses the latest GitHub release's `latest.json`; beta uses the rolling `beta`
+//! release, whose `latest.json` points at the newest build of either kind. The channel
+//! is saved to `updater.json` in app data.
+//!
+//! An update found by `get_update_info` is kept until it's installed. Deferring it
+//! downloads it straight away and installs it as the app quits, so the next launch
+//! runs the new version.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_updater::{Update, UpdaterExt};
+
+const STABLE_ENDPOINT: &str =
+    "https://github.com/dannysmith/astro-editor/releases/latest/download/latest.json";
+const BETA_ENDPOINT: &str =
+    "https://github.com/dannysmith/astro-editor/releases/download/beta/latest.json";
+
+/// The update last found by `get_update_info`, and one downloaded to install on quit
+#[derive(Default)]
+pub struct PendingUpdates {
+    pending: Option<Update>,
+    deferred: Option<(Update, Vec<u8>)>,
+}
+
+pub type UpdaterState = Arc<Mutex<PendingUpdates>>;
+
+/// Which releases to update to
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateChannel {
+    #[default]
+    Stable,
+    /// Prereleases as well as stable releases
+    Beta,
+}
+
+impl UpdateChannel {
+    fn endpoint(self) -> &'static str {
+        match self {
+            Self::Stable => STABLE_ENDPOINT,
+            Self::Beta => BETA_ENDPOINT,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct UpdaterSettings {
+    #[serde(default)]
+    channel: UpdateChannel,
+}
+
+/// A newer version on the current channel
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AvailableUpdate {
+    pub version: String,
+    /// Publish date from the release feed
+    pub date: Option<String>,
+    /// Release notes for every version since the running one, newest first (Markdown)
+    pub release_notes: String,
+    /// Already downloaded and waiting to install when the app quits
+    pub deferred: bool,
+}
+
+/// Result of checking the current channel for updates
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateInfo {
+    pub channel: UpdateChannel,
+    pub current_version: String,
+    pub update: Option<AvailableUpdate>,
+}
+
+/// Progress emitted as `update-download-progress` while an update downloads
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDownloadProgress {
+    pub downloaded: u64,
+    pub total: Option<u64>,
+}
 
 #[derive(Deserialize)]
 struct GitHubRelease {
@@ -22,19 +109,30 @@ fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
     ))
 }
 
+/// Parse a release version, ignoring any prerelease suffix ("1.1.0-beta.2" is 1.1.0)
+fn parse_release_version(version: &str) -> Option<(u64, u64, u64)> {
+    parse_version(version.split('-').next().unwrap_or(version))
+}
+
 /// Filter and combine release notes from a list of GitHub releases.
 /// Returns bodies for versions between current (exclusive) and new (inclusive),
-/// sorted reverse chronologically and joined with horizontal rules.
+/// sorted reverse chronologically and joined with horizontal rules. Prereleases are
+/// only included for the beta channel.
 fn filter_and_combine_releases(
     releases: Vec<GitHubRelease>,
     current: (u64, u64, u64),
     new: (u64, u64, u64),
+    include_prereleases: bool,
 ) -> String {
     let mut relevant: Vec<_> = releases
         .into_iter()
-        .filter(|r| !r.draft && !r.prerelease)
+        .filter(|r| !r.draft && (include_prereleases || !r.prerelease))
         .filter_map(|r| {
-            let v = parse_version(&r.tag_name)?;
+            let v = if include_prereleases {
+                parse_release_version(&r.tag_name)?
+            } else {
+                parse_version(&r.tag_name)?
+            };
             // Include versions: current < v <= new
             if v > current && v <= new {
                 let body = r.body.unwrap_or_default();
@@ -58,6 +156,35 @@ fn filter_and_combine_releases(
         .join("\n\n---\n\n")
 }
 
+/// Fetches GitHub releases and combines the notes between two versions
+async fn fetch_combined_notes(
+    current: (u64, u64, u64),
+    new: (u64, u64, u64),
+    include_prereleases: bool,
+) -> Result<String, String> {
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(5))
+        .user_agent("astro-editor")
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+
+    let releases: Vec<GitHubRelease> = client
+        .get("https://api.github.com/repos/dannysmith/astro-editor/releases?per_page=100")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch releases: {e}"))?
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse releases: {e}"))?;
+
+    Ok(filter_and_combine_releases(
+        releases,
+        current,
+        new,
+        include_prereleases,
+    ))
+}
+
 /// Fetch release notes from GitHub Releases API for all versions between
 /// current_version (exclusive) and new_version (inclusive).
 /// Returns combined markdown bodies in reverse chronological order.
@@ -72,22 +199,228 @@ pub async fn fetch_release_notes(
     let new =
         parse_version(&new_version).ok_or_else(|| format!("Invalid new version: {new_version}"))?;
 
-    let client = reqwest::Client::builder()
-        .timeout(std::time::Duration::from_secs(5))
-        .user_agent("astro-editor")
-        .build()
-        .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
+    fetch_combined_notes(current, new, false).await
+}
 
-    let releases: Vec<GitHubRelease> = client
-        .get("https://api.github.com/repos/dannysmith/astro-editor/releases?per_page=100")
-        .send()
+fn settings_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .resolve("updater.json", BaseDirectory::AppLocalData)
+        .map_err(|e| format!("Failed to resolve updater settings path: {e}"))
+}
+
+fn load_settings(path: &Path) -> UpdaterSettings {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_settings(path: &Path, settings: &UpdaterSettings) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create updater settings directory: {e}"))?;
+    }
+    let content = serde_json::to_string_pretty(settings)
+        .map_err(|e| format!("Failed to serialize updater settings: {e}"))?;
+    std::fs::write(path, content).map_err(|e| format!("Failed to write updater settings: {e}"))
+}
+
+fn emit_progress(app: &AppHandle, downloaded: u64, total: Option<u64>) {
+    let progress = UpdateDownloadProgress { downloaded, total };
+    if let Err(e) = app.emit("update-download-progress", progress) {
+        log::warn!("Astro Editor [UPDATER] Failed to emit progress event: {e}");
+    }
+}
+
+/// The update found by the last check, if it hasn't been installed yet
+fn pending_update(app: &AppHandle) -> Result<Update, String> {
+    let state = app.state::<UpdaterState>();
+    let state = state
+        .lock()
+        .map_err(|e| format!("Failed to read update state: {e}"))?;
+    state
+        .pending
+        .clone()
+        .ok_or_else(|| "No update to install; check for updates first".to_string())
+}
+
+/// Gets the release channel updates come from
+#[tauri::command]
+#[specta::specta]
+pub async fn get_update_channel(app: AppHandle) -> Result<UpdateChannel, String> {
+    Ok(load_settings(&settings_path(&app)?).channel)
+}
+
+/// Switches between stable and beta releases
+///
+/// Forgets any update found on the previous channel; call `get_update_info` again to
+/// check the new one.
+///
+/// # Arguments
+/// * `channel` - The channel to follow
+#[tauri::command]
+#[specta::specta]
+pub async fn set_update_channel(app: AppHandle, channel: UpdateChannel) -> Result<(), String> {
+    let path = settings_path(&app)?;
+    let mut settings = load_settings(&path);
+    settings.channel = channel;
+    save_settings(&path, &settings)?;
+
+    if let Ok(mut state) = app.state::<UpdaterState>().lock() {
+        state.pending = None;
+    }
+    Ok(())
+}
+
+/// Checks the current channel's release feed for a newer version
+///
+/// # Returns
+/// The channel and running version, with the available update (including release
+/// notes for every version since the running one) if there is one
+#[tauri::command]
+#[specta::specta]
+pub async fn get_update_info(app: AppHandle) -> Result<UpdateInfo, String> {
+    let channel = load_settings(&settings_path(&app)?).channel;
+    let endpoint = tauri::Url::parse(channel.endpoint())
+        .map_err(|e| format!("Invalid update endpoint: {e}"))?;
+    let update = app
+        .updater_builder()
+        .endpoints(vec![endpoint])
+        .and_then(|builder| builder.build())
+        .map_err(|e| format!("Failed to create updater: {e}"))?
+        .check()
         .await
-        .map_err(|e| format!("Failed to fetch releases: {e}"))?
-        .json()
+        .map_err(|e| format!("Update check failed: {e}"))?;
+
+    let current_version = app.package_info().version.to_string();
+    let Some(update) = update else {
+        return Ok(UpdateInfo {
+            channel,
+            current_version,
+            update: None,
+        });
+    };
+
+    let notes = match (
+        parse_release_version(&current_version),
+        parse_release_version(&update.version),
+    ) {
+        (Some(current), Some(new)) => {
+            fetch_combined_notes(current, new, channel == UpdateChannel::Beta)
+                .await
+                .ok()
+        }
+        _ => None,
+    };
+    let release_notes = notes
+        .filter(|notes| !notes.trim().is_empty())
+        .or_else(|| update.body.clone())
+        .unwrap_or_default();
+
+    let state = app.state::<UpdaterState>();
+    let mut state = state
+        .lock()
+        .map_err(|e| format!("Failed to store update state: {e}"))?;
+    let deferred = state
+        .deferred
+        .as_ref()
+        .is_some_and(|(deferred, _)| deferred.version == update.version);
+    let available = AvailableUpdate {
+        version: update.version.clone(),
+        date: update.date.map(|date| date.to_string()),
+        release_notes,
+        deferred,
+    };
+    state.pending = Some(update);
+
+    Ok(UpdateInfo {
+        channel,
+        current_version,
+        update: Some(available),
+    })
+}
+
+/// Downloads and installs the update found by `get_update_info`
+///
+/// Emits `update-download-progress` while downloading. Relaunch the app afterwards to
+/// run the new version.
+#[tauri::command]
+#[specta::specta]
+pub async fn install_update(app: AppHandle) -> Result<(), String> {
+    let update = pending_update(&app)?;
+    let mut downloaded = 0u64;
+    update
+        .download_and_install(
+            |chunk, total| {
+                downloaded += chunk as u64;
+                emit_progress(&app, downloaded, total);
+            },
+            || log::info!("Astro Editor [UPDATER] Download complete"),
+        )
         .await
-        .map_err(|e| format!("Failed to parse releases: {e}"))?;
+        .map_err(|e| format!("Failed to install update: {e}"))?;
+
+    if let Ok(mut state) = app.state::<UpdaterState>().lock() {
+        state.pending = None;
+        state.deferred = None;
+    }
+    Ok(())
+}
+
+/// Downloads the update found by `get_update_info` now and installs it when the app
+/// quits, so it's running from the next launch
+///
+/// Emits `update-download-progress` while downloading.
+#[tauri::command]
+#[specta::specta]
+pub async fn defer_update(app: AppHandle) -> Result<(), String> {
+    let update = pending_update(&app)?;
+    let mut downloaded = 0u64;
+    let bytes = update
+        .download(
+            |chunk, total| {
+                downloaded += chunk as u64;
+                emit_progress(&app, downloaded, total);
+            },
+            || log::info!("Astro Editor [UPDATER] Download complete"),
+        )
+        .await
+        .map_err(|e| format!("Failed to download update: {e}"))?;
+
+    log::info!(
+        "Astro Editor [UPDATER] Version {} will install when the app quits",
+        update.version
+    );
+    let state = app.state::<UpdaterState>();
+    let mut state = state
+        .lock()
+        .map_err(|e| format!("Failed to store update state: {e}"))?;
+    state.deferred = Some((update, bytes));
+    Ok(())
+}
+
+/// Installs a deferred update; called as the app exits
+pub fn install_deferred_update(app: &AppHandle) {
+    let deferred = app
+        .state::<UpdaterState>()
+        .lock()
+        .ok()
+        .and_then(|mut state| state.deferred.take());
+    let Some((update, bytes)) = deferred else {
+        return;
+    };
+
+    log::info!(
+        "Astro Editor [UPDATER] Installing version {}",
+        update.version
+    );
+    if let Err(e) = update.install(bytes) {
+        log::error!("Astro Editor [UPDATER] Failed to install deferred update: {e}");
+    }
+}
 
-    Ok(filter_and_combine_releases(releases, current, new))
+pub fn init_updater_state() -> UpdaterState {
+    Arc::new(Mutex::new(PendingUpdates::default()))
 }
 
 #[cfg(test)]
@@ -155,7 +488,7 @@ mod tests {
     #[test]
     fn filter_single_release_in_range() {
         let releases = vec![make_release("v1.0.8", "Notes for 1.0.8", false, false)];
-        let result = filter_and_combine_releases(releases, (1, 0, 7), (1, 0, 8));
+        let result = filter_and_combine_releases(releases, (1, 0, 7), (1, 0, 8), false);
         assert_eq!(result, "Notes for 1.0.8");
     }
 
@@ -166,7 +499,7 @@ mod tests {
             make_release("v1.0.8", "Notes 1.0.8", false, false),
             make_release("v1.0.7", "Notes 1.0.7", false, false),
         ];
-        let result = filter_and_combine_releases(releases, (1, 0, 5), (1, 0, 8));
+        let result = filter_and_combine_releases(releases, (1, 0, 5), (1, 0, 8), false);
         assert_eq!(
             result,
             "Notes 1.0.8\n\n---\n\nNotes 1.0.7\n\n---\n\nNotes 1.0.6"
@@ -179,14 +512,14 @@ mod tests {
             make_release("v1.0.7", "Notes 1.0.7", false, false),
             make_release("v1.0.8", "Notes 1.0.8", false, false),
         ];
-        let result = filter_and_combine_releases(releases, (1, 0, 7), (1, 0, 8));
+        let result = filter_and_combine_releases(releases, (1, 0, 7), (1, 0, 8), false);
         assert_eq!(result, "Notes 1.0.8");
     }
 
     #[test]
     fn filter_includes_new_version() {
         let releases = vec![make_release("v1.0.8", "Notes 1.0.8", false, false)];
-        let result = filter_and_combine_releases(releases, (1, 0, 7), (1, 0, 8));
+        let result = filter_and_combine_releases(releases, (1, 0, 7), (1, 0, 8), false);
         assert_eq!(result, "Notes 1.0.8");
     }
 
@@ -196,7 +529,7 @@ mod tests {
             make_release("v1.0.8", "Notes 1.0.8", false, false),
             make_release("v1.0.9", "Notes 1.0.9", false, false),
         ];
-        let result = filter_and_combine_releases(releases, (1, 0, 7), (1, 0, 8));
+        let result = filter_and_combine_releases(releases, (1, 0, 7), (1, 0, 8), false);
         assert_eq!(result, "Notes 1.0.8");
     }
 
@@ -206,7 +539,7 @@ mod tests {
             make_release("v1.0.8", "Draft notes", true, false),
             make_release("v1.0.7", "Published notes", false, false),
         ];
-        let result = filter_and_combine_releases(releases, (1, 0, 6), (1, 0, 8));
+        let result = filter_and_combine_releases(releases, (1, 0, 6), (1, 0, 8), false);
         assert_eq!(result, "Published notes");
     }
 
@@ -216,20 +549,20 @@ mod tests {
             make_release("v1.0.8", "Prerelease notes", false, true),
             make_release("v1.0.7", "Stable notes", false, false),
         ];
-        let result = filter_and_combine_releases(releases, (1, 0, 6), (1, 0, 8));
+        let result = filter_and_combine_releases(releases, (1, 0, 6), (1, 0, 8), false);
         assert_eq!(result, "Stable notes");
     }
 
     #[test]
     fn filter_no_matching_releases_returns_empty() {
         let releases = vec![make_release("v1.0.5", "Old notes", false, false)];
-        let result = filter_and_combine_releases(releases, (1, 0, 7), (1, 0, 8));
+        let result = filter_and_combine_releases(releases, (1, 0, 7), (1, 0, 8), false);
         assert_eq!(result, "");
     }
 
     #[test]
     fn filter_empty_releases_returns_empty() {
-        let result = filter_and_combine_releases(vec![], (1, 0, 7), (1, 0, 8));
+        let result = filter_and_combine_releases(vec![], (1, 0, 7), (1, 0, 8), false);
         assert_eq!(result, "");
     }
 
@@ -239,7 +572,7 @@ mod tests {
             make_release("nightly", "Nightly notes", false, false),
             make_release("v1.0.8", "Notes 1.0.8", false, false),
         ];
-        let result = filter_and_combine_releases(releases, (1, 0, 7), (1, 0, 8));
+        let result = filter_and_combine_releases(releases, (1, 0, 7), (1, 0, 8), false);
         assert_eq!(result, "Notes 1.0.8");
     }
 
@@ -251,7 +584,7 @@ mod tests {
             draft: false,
             prerelease: false,
         }];
-        let result = filter_and_combine_releases(releases, (1, 0, 7), (1, 0, 8));
+        let result = filter_and_combine_releases(releases, (1, 0, 7), (1, 0, 8), false);
         assert_eq!(result, "");
     }
 
@@ -263,10 +596,43 @@ mod tests {
             draft: false,
             prerelease: false,
         }];
-        let result = filter_and_combine_releases(releases, (1, 0, 7), (1, 0, 8));
+        let result = filter_and_combine_releases(releases, (1, 0, 7), (1, 0, 8), false);
         assert_eq!(result, "");
     }
 
+    #[test]
+    fn combine_includes_prereleases_for_beta() {
+        let releases = vec![
+            make_release("v1.1.0-beta.1", "Beta notes", false, true),
+            make_release("v1.0.8", "Notes 1.0.8", false, false),
+        ];
+        let beta = filter_and_combine_releases(releases, (1, 0, 7), (1, 1, 0), true);
+        assert_eq!(beta, "Beta notes\n\n---\n\nNotes 1.0.8");
+
+        let releases = vec![make_release("v1.1.0-beta.1", "Beta notes", false, true)];
+        let stable = filter_and_combine_releases(releases, (1, 0, 7), (1, 1, 0), false);
+        assert_eq!(stable, "");
+    }
+
+    #[test]
+    fn parse_release_version_ignores_prerelease_suffix() {
+        assert_eq!(parse_release_version("v1.1.0-beta.2"), Some((1, 1, 0)));
+        assert_eq!(parse_release_version("1.0.8"), Some((1, 0, 8)));
+    }
+
+    #[test]
+    fn updater_settings_round_trip_through_disk() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("app").join("updater.json");
+        assert_eq!(load_settings(&path).channel, UpdateChannel::Stable);
+
+        let settings = UpdaterSettings {
+            channel: UpdateChannel::Beta,
+        };
+        save_settings(&path, &settings).unwrap();
+        assert_eq!(load_settings(&path), settings);
+    }
+
     #[test]
     fn filter_no_separators_between_empty_bodies() {
         let releases = vec![
@@ -279,7 +645,7 @@ mod tests {
             },
             make_release("v1.0.6", "Notes 1.0.6", false, false),
         ];
-        let result = filter_and_combine_releases(releases, (1, 0, 5), (1, 0, 8));
+        let result = filter_and_combine_releases(releases, (1, 0, 5), (1, 0, 8), false);
         assert_eq!(result, "Notes 1.0.8\n\n---\n\nNotes 1.0.6");
     }
 }
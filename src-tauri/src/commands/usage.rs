@@ -0,0 +1,470 @@
+//! Per-project file usage tracking
+//!
+//! Records when entries are opened and edited so the quick switcher and "Jump back in"
+//! lists can be ordered by actual usage rather than file modification time. Usage is
+//! cached in memory and written through to `usage/<project hash>.json` in app data.
+
+use chrono::{DateTime, Local, NaiveDate, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Manager};
+
+// Per-project usage, keyed by project path and loaded from disk on first access
+pub type UsageStore = Arc<Mutex<HashMap<String, ProjectUsage>>>;
+
+/// Oldest entries beyond this are dropped so usage files stay small
+const MAX_TRACKED_FILES: usize = 500;
+
+/// Number of files returned when the caller doesn't specify a limit
+const DEFAULT_RESULT_LIMIT: u32 = 20;
+
+/// Frequency scores halve for every this many days since a file was last used
+const FREQUENCY_HALF_LIFE_DAYS: f64 = 14.0;
+
+/// Edits count for more than opens when ranking frequent files
+const EDIT_WEIGHT: f64 = 2.0;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct FileUsage {
+    open_count: u32,
+    edit_count: u32,
+    /// RFC 3339 UTC timestamps, which sort lexically
+    last_opened: Option<String>,
+    last_edited: Option<String>,
+}
+
+impl FileUsage {
+    fn last_used(&self) -> Option<&str> {
+        self.last_opened.as_deref().max(self.last_edited.as_deref())
+    }
+
+    /// Use count decayed by time since last use
+    fn frequency_score(&self, now: DateTime<Utc>) -> f64 {
+        let uses = self.open_count as f64 + EDIT_WEIGHT * self.edit_count as f64;
+        let age_days = self
+            .last_used()
+            .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+            .map(|t| (now - t.with_timezone(&Utc)).num_seconds().max(0) as f64 / 86_400.0)
+            .unwrap_or(0.0);
+
+        uses * 0.5_f64.powf(age_days / FREQUENCY_HALF_LIFE_DAYS)
+    }
+}
+
+/// Usage for every tracked file in a project, keyed by project-relative path
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUsage {
+    project_path: String,
+    files: HashMap<String, FileUsage>,
+}
+
+/// A tracked file with its usage, as returned to the frontend
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FileUsageEntry {
+    /// Absolute path to the file
+    pub file_path: String,
+    /// Path relative to the project root
+    pub relative_path: String,
+    pub open_count: u32,
+    pub edit_count: u32,
+    pub last_opened: Option<String>,
+    pub last_edited: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum UsageEvent {
+    Opened,
+    Edited,
+}
+
+impl ProjectUsage {
+    fn record(&mut self, relative_path: String, event: UsageEvent, now: DateTime<Utc>) {
+        let timestamp = now.to_rfc3339_opts(SecondsFormat::Millis, true);
+        let usage = self.files.entry(relative_path).or_default();
+
+        match event {
+            UsageEvent::Opened => {
+                usage.open_count = usage.open_count.saturating_add(1);
+                usage.last_opened = Some(timestamp);
+            }
+            UsageEvent::Edited => {
+                usage.edit_count = usage.edit_count.saturating_add(1);
+                usage.last_edited = Some(timestamp);
+            }
+        }
+
+        self.prune();
+    }
+
+    /// Drops the least recently used entries beyond the tracking limit
+    fn prune(&mut self) {
+        if self.files.len() <= MAX_TRACKED_FILES {
+            return;
+        }
+
+        let mut by_recency: Vec<(String, Option<String>)> = self
+            .files
+            .iter()
+            .map(|(path, usage)| (path.clone(), usage.last_used().map(String::from)))
+            .collect();
+        by_recency.sort_by(|a, b| b.1.cmp(&a.1));
+
+        for (path, _) in by_recency.into_iter().skip(MAX_TRACKED_FILES) {
+            self.files.remove(&path);
+        }
+    }
+
+    /// Existing files ordered by `compare`, most relevant first
+    fn ranked_entries(
+        &self,
+        project_root: &Path,
+        limit: usize,
+        mut compare: impl FnMut(&FileUsage, &FileUsage) -> std::cmp::Ordering,
+    ) -> Vec<FileUsageEntry> {
+        let mut files: Vec<(&String, &FileUsage)> = self
+            .files
+            .iter()
+            .filter(|(relative_path, _)| project_root.join(relative_path).is_file())
+            .collect();
+        files.sort_by(|a, b| compare(a.1, b.1).then_with(|| a.0.cmp(b.0)));
+
+        files
+            .into_iter()
+            .take(limit)
+            .map(|(relative_path, usage)| FileUsageEntry {
+                file_path: project_root
+                    .join(relative_path)
+                    .to_string_lossy()
+                    .to_string(),
+                relative_path: relative_path.clone(),
+                open_count: usage.open_count,
+                edit_count: usage.edit_count,
+                last_opened: usage.last_opened.clone(),
+                last_edited: usage.last_edited.clone(),
+            })
+            .collect()
+    }
+
+    /// Project-relative paths last edited between `from` and `to` (inclusive, local time)
+    fn edited_between(&self, from: NaiveDate, to: NaiveDate) -> BTreeSet<String> {
+        self.files
+            .iter()
+            .filter(|(_, usage)| {
+                usage
+                    .last_edited
+                    .as_deref()
+                    .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+                    .map(|t| t.with_timezone(&Local).date_naive())
+                    .is_some_and(|date| date >= from && date <= to)
+            })
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+
+    fn recent(&self, project_root: &Path, limit: usize) -> Vec<FileUsageEntry> {
+        self.ranked_entries(project_root, limit, |a, b| {
+            b.last_used().cmp(&a.last_used())
+        })
+    }
+
+    fn frequent(
+        &self,
+        project_root: &Path,
+        limit: usize,
+        now: DateTime<Utc>,
+    ) -> Vec<FileUsageEntry> {
+        self.ranked_entries(project_root, limit, |a, b| {
+            b.frequency_score(now).total_cmp(&a.frequency_score(now))
+        })
+    }
+}
+
+/// Stable FNV-1a hash of the project path, used to name its usage file
+fn project_key(project_path: &str) -> String {
+    let hash = project_path
+        .bytes()
+        .fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        });
+    format!("{hash:016x}")
+}
+
+fn usage_file_path(app: &AppHandle, project_path: &str) -> Result<PathBuf, String> {
+    app.path()
+        .resolve(
+            format!("usage/{}.json", project_key(project_path)),
+            BaseDirectory::AppLocalData,
+        )
+        .map_err(|e| format!("Failed to resolve usage file path: {e}"))
+}
+
+fn load_usage(path: &Path, project_path: &str) -> ProjectUsage {
+    let loaded = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<ProjectUsage>(&content).ok());
+
+    match loaded {
+        Some(usage) if usage.project_path == project_path => usage,
+        _ => ProjectUsage {
+            project_path: project_path.to_string(),
+            files: HashMap::new(),
+        },
+    }
+}
+
+fn save_usage(path: &Path, usage: &ProjectUsage) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create usage directory: {e}"))?;
+    }
+    let content = serde_json::to_string_pretty(usage)
+        .map_err(|e| format!("Failed to serialize usage data: {e}"))?;
+    std::fs::write(path, content).map_err(|e| format!("Failed to write usage data: {e}"))
+}
+
+/// Runs `f` against the project's cached usage, loading it from disk first if needed
+fn with_project_usage<T>(
+    app: &AppHandle,
+    project_path: &str,
+    f: impl FnOnce(&mut ProjectUsage, &Path) -> Result<T, String>,
+) -> Result<T, String> {
+    let usage_path = usage_file_path(app, project_path)?;
+    let store = app.state::<UsageStore>();
+    let mut projects = store.lock().unwrap();
+    let usage = projects
+        .entry(project_path.to_string())
+        .or_insert_with(|| load_usage(&usage_path, project_path));
+
+    f(usage, &usage_path)
+}
+
+fn record_event(
+    app: &AppHandle,
+    project_path: &str,
+    file_path: &str,
+    event: UsageEvent,
+) -> Result<(), String> {
+    let relative_path = Path::new(file_path)
+        .strip_prefix(project_path)
+        .map_err(|_| "File is not inside the project".to_string())?;
+    let relative_path = crate::utils::path::normalize_path_for_serialization(relative_path);
+
+    with_project_usage(app, project_path, |usage, usage_path| {
+        usage.record(relative_path, event, Utc::now());
+        save_usage(usage_path, usage)
+    })
+}
+
+/// Project-relative paths of files last edited between `from` and `to` (inclusive)
+///
+/// Only the most recent edit of each file is tracked, so files edited in the range
+/// and again after it are not included.
+pub(crate) fn edited_between(
+    app: &AppHandle,
+    project_path: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<BTreeSet<String>, String> {
+    with_project_usage(app, project_path, |usage, _| {
+        Ok(usage.edited_between(from, to))
+    })
+}
+
+/// Records that a file was opened in the editor
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `file_path` - The absolute path to the opened file
+#[tauri::command]
+#[specta::specta]
+pub async fn record_file_opened(
+    app: AppHandle,
+    project_path: String,
+    file_path: String,
+) -> Result<(), String> {
+    record_event(&app, &project_path, &file_path, UsageEvent::Opened)
+}
+
+/// Records that a file was saved with changes
+///
+/// # Arguments
+/// * `project_path` - The absolute path to the project root
+/// * `file_path` - The absolute path to the saved file
+#[tauri::command]
+#[specta::specta]
+pub async fn record_file_edited(
+    app: AppHandle,
+    project_path: String,
+    file_path: String,
+) -> Result<(), String> {
+    record_event(&app, &project_path, &file_path, UsageEvent::Edited)
+}
+
+/// Returns the project's most recently opened or edited files, newest first
+///
+/// Files that no longer exist are skipped.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_recent_files(
+    app: AppHandle,
+    project_path: String,
+    limit: Option<u32>,
+) -> Result<Vec<FileUsageEntry>, String> {
+    let limit = limit.unwrap_or(DEFAULT_RESULT_LIMIT) as usize;
+    with_project_usage(&app, &project_path, |usage, _| {
+        Ok(usage.recent(Path::new(&project_path), limit))
+    })
+}
+
+/// Returns the project's most frequently used files
+///
+/// Files are ranked by open and edit counts (edits weigh double), decayed by time
+/// since last use so files that were busy months ago drift down the list.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_frequent_files(
+    app: AppHandle,
+    project_path: String,
+    limit: Option<u32>,
+) -> Result<Vec<FileUsageEntry>, String> {
+    let limit = limit.unwrap_or(DEFAULT_RESULT_LIMIT) as usize;
+    with_project_usage(&app, &project_path, |usage, _| {
+        Ok(usage.frequent(Path::new(&project_path), limit, Utc::now()))
+    })
+}
+
+// Initialize the usage store when the app starts
+pub fn init_usage_store() -> UsageStore {
+    Arc::new(Mutex::new(HashMap::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn project_with_files(names: &[&str]) -> TempDir {
+        let temp = TempDir::new().unwrap();
+        for name in names {
+            let path = temp.path().join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "# Entry\n").unwrap();
+        }
+        temp
+    }
+
+    fn usage_for(temp: &TempDir) -> ProjectUsage {
+        ProjectUsage {
+            project_path: temp.path().to_string_lossy().to_string(),
+            files: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_recent_orders_by_last_use_and_skips_missing() {
+        let temp = project_with_files(&["posts/a.md", "posts/b.md"]);
+        let mut usage = usage_for(&temp);
+        let now = Utc::now();
+
+        usage.record("posts/a.md".to_string(), UsageEvent::Opened, now);
+        usage.record(
+            "posts/b.md".to_string(),
+            UsageEvent::Opened,
+            now + Duration::seconds(1),
+        );
+        usage.record(
+            "posts/a.md".to_string(),
+            UsageEvent::Edited,
+            now + Duration::seconds(2),
+        );
+        usage.record(
+            "posts/gone.md".to_string(),
+            UsageEvent::Opened,
+            now + Duration::seconds(3),
+        );
+
+        let recent = usage.recent(temp.path(), 10);
+        let paths: Vec<&str> = recent.iter().map(|e| e.relative_path.as_str()).collect();
+        assert_eq!(paths, vec!["posts/a.md", "posts/b.md"]);
+        assert_eq!(recent[0].open_count, 1);
+        assert_eq!(recent[0].edit_count, 1);
+    }
+
+    #[test]
+    fn test_frequent_weights_edits_and_decays_with_age() {
+        let temp = project_with_files(&["old.md", "busy.md", "edited.md"]);
+        let mut usage = usage_for(&temp);
+        let now = Utc::now();
+
+        // Opened often, but two months ago
+        for _ in 0..10 {
+            usage.record(
+                "old.md".to_string(),
+                UsageEvent::Opened,
+                now - Duration::days(60),
+            );
+        }
+        for _ in 0..3 {
+            usage.record("busy.md".to_string(), UsageEvent::Opened, now);
+        }
+        for _ in 0..2 {
+            usage.record("edited.md".to_string(), UsageEvent::Edited, now);
+        }
+
+        let frequent = usage.frequent(temp.path(), 10, now);
+        let paths: Vec<&str> = frequent.iter().map(|e| e.relative_path.as_str()).collect();
+        assert_eq!(paths, vec!["edited.md", "busy.md", "old.md"]);
+    }
+
+    #[test]
+    fn test_prune_keeps_most_recent_files() {
+        let temp = TempDir::new().unwrap();
+        let mut usage = usage_for(&temp);
+        let now = Utc::now();
+
+        for i in 0..(MAX_TRACKED_FILES + 5) {
+            usage.record(
+                format!("entry-{i}.md"),
+                UsageEvent::Opened,
+                now + Duration::seconds(i as i64),
+            );
+        }
+
+        assert_eq!(usage.files.len(), MAX_TRACKED_FILES);
+        assert!(!usage.files.contains_key("entry-0.md"));
+        assert!(usage
+            .files
+            .contains_key(&format!("entry-{}.md", MAX_TRACKED_FILES + 4)));
+    }
+
+    #[test]
+    fn test_usage_round_trips_through_disk() {
+        let temp = TempDir::new().unwrap();
+        let usage_path = temp.path().join("usage").join("project.json");
+        let mut usage = usage_for(&temp);
+        usage.record("a.md".to_string(), UsageEvent::Opened, Utc::now());
+
+        save_usage(&usage_path, &usage).unwrap();
+        let loaded = load_usage(&usage_path, &usage.project_path);
+        assert_eq!(loaded.files, usage.files);
+
+        // A hash collision with another project starts fresh
+        assert!(load_usage(&usage_path, "/other/project").files.is_empty());
+    }
+
+    #[test]
+    fn test_project_key_is_stable() {
+        assert_eq!(project_key("/Users/me/blog"), project_key("/Users/me/blog"));
+        assert_ne!(project_key("/Users/me/blog"), project_key("/Users/me/site"));
+        assert_eq!(project_key("").len(), 16);
+    }
+}
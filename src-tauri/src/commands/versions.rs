@@ -0,0 +1,421 @@
+//! Version history for saved files
+//!
+//! Each save from the editor keeps a copy of the saved file in `versions/` in app data,
+//! so earlier versions can be compared and restored after the app restarts, without
+//! git. Every file gets its own directory, named by a hash of its path, holding one
+//! `{id}.data` per version (`{id}.enc` when encrypted like recovery data, with the
+//! `recovery.encrypt` preference on) and an `index.json` listing them.
+//!
+//! A save that doesn't change the file adds no version, and autosaves add one at most
+//! every `AUTOSAVE_INTERVAL_MINUTES`. Only the newest `MAX_VERSIONS_PER_FILE` of a file
+//! are kept, and versions older than `RETENTION_DAYS` are purged when the app starts.
+
+use crate::commands::audit_log::{self, AuditAction};
+use crate::commands::bulk_undo::content_hash;
+use crate::commands::dry_run;
+use crate::commands::projects;
+use crate::commands::recovery;
+use crate::config_editor::unified_diff;
+use crate::security::validate_project_path;
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Manager};
+
+/// Versions kept per file; older ones are deleted when a new one is saved
+const MAX_VERSIONS_PER_FILE: usize = 50;
+
+/// Days a version is kept before it's deleted
+const RETENTION_DAYS: i64 = 90;
+
+/// Minutes between versions kept from autosaves of a file; explicit saves always add one
+const AUTOSAVE_INTERVAL_MINUTES: i64 = 10;
+
+static VERSIONS_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// A saved copy of a file
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FileVersion {
+    pub id: String,
+    /// RFC 3339 UTC timestamp
+    pub saved_at: String,
+    #[specta(type = f64)]
+    pub size_bytes: u64,
+    pub content_hash: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct VersionIndex {
+    file_path: String,
+    /// Oldest first
+    versions: Vec<FileVersion>,
+}
+
+/// The file's path as versions are keyed, resolving symlinks where it still exists
+fn version_key(path: &Path) -> String {
+    path.canonicalize()
+        .unwrap_or_else(|_| path.to_path_buf())
+        .to_string_lossy()
+        .to_string()
+}
+
+fn file_dir(dir: &Path, path: &Path) -> PathBuf {
+    dir.join(content_hash(&version_key(path)))
+}
+
+/// The copy of a version, encrypted or not
+fn version_file(file_dir: &Path, id: &str) -> Option<PathBuf> {
+    ["data", "enc"]
+        .iter()
+        .map(|extension| file_dir.join(format!("{id}.{extension}")))
+        .find(|file| file.exists())
+}
+
+fn read_index(file_dir: &Path) -> VersionIndex {
+    std::fs::read_to_string(file_dir.join("index.json"))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn write_index(file_dir: &Path, index: &VersionIndex) -> Result<(), String> {
+    let json = serde_json::to_string(index)
+        .map_err(|e| format!("Failed to serialize version index: {e}"))?;
+    std::fs::write(file_dir.join("index.json"), json)
+        .map_err(|e| format!("Failed to write version index: {e}"))
+}
+
+/// Drops versions from the index (and their copies) that are past `cutoff` or beyond
+/// the newest `MAX_VERSIONS_PER_FILE`
+fn prune(file_dir: &Path, index: &mut VersionIndex, cutoff: DateTime<Utc>) {
+    let excess = index.versions.len().saturating_sub(MAX_VERSIONS_PER_FILE);
+    let mut position = 0;
+    index.versions.retain(|version| {
+        position += 1;
+        let expired =
+            DateTime::parse_from_rfc3339(&version.saved_at).is_ok_and(|saved_at| saved_at < cutoff);
+        let keep = position > excess && !expired;
+        if !keep {
+            let _ = std::fs::remove_file(file_dir.join(format!("{}.data", version.id)));
+            let _ = std::fs::remove_file(file_dir.join(format!("{}.enc", version.id)));
+        }
+        keep
+    });
+}
+
+/// Keeps a version of `content`, encrypted with `key` if given, unless it's the same as
+/// the newest version or that's more recent than `min_interval`
+fn snapshot_in(
+    dir: &Path,
+    path: &Path,
+    content: &str,
+    key: Option<&[u8]>,
+    min_interval: Option<Duration>,
+) -> Result<Option<FileVersion>, String> {
+    let file_dir = file_dir(dir, path);
+    let mut index = read_index(&file_dir);
+    let hash = content_hash(content);
+    let now = Utc::now();
+    if let Some(latest) = index.versions.last() {
+        let recent = min_interval.is_some_and(|interval| {
+            DateTime::parse_from_rfc3339(&latest.saved_at)
+                .is_ok_and(|saved_at| saved_at > now - interval)
+        });
+        if latest.content_hash == hash || recent {
+            return Ok(None);
+        }
+    }
+
+    std::fs::create_dir_all(&file_dir)
+        .map_err(|e| format!("Failed to create version history: {e}"))?;
+    // Zero-padded so ids sort chronologically
+    let base = format!("{:016}", now.timestamp_millis());
+    let id = (0..)
+        .map(|n| match n {
+            0 => base.clone(),
+            n => format!("{base}-{n}"),
+        })
+        .find(|id| index.versions.iter().all(|version| &version.id != id))
+        .unwrap_or(base);
+
+    let version = FileVersion {
+        id: id.clone(),
+        saved_at: now.to_rfc3339_opts(SecondsFormat::Millis, true),
+        size_bytes: content.len() as u64,
+        content_hash: hash,
+    };
+    let (contents, extension) = match key {
+        Some(key) => (recovery::encrypt(key, content.as_bytes())?, "enc"),
+        None => (content.as_bytes().to_vec(), "data"),
+    };
+    std::fs::write(file_dir.join(format!("{id}.{extension}")), contents)
+        .map_err(|e| format!("Failed to save version: {e}"))?;
+    index.file_path = version_key(path);
+    index.versions.push(version.clone());
+    prune(&file_dir, &mut index, now - Duration::days(RETENTION_DAYS));
+    write_index(&file_dir, &index)?;
+    Ok(Some(version))
+}
+
+fn list_in(dir: &Path, path: &Path) -> Vec<FileVersion> {
+    let file_dir = file_dir(dir, path);
+    let mut versions: Vec<FileVersion> = read_index(&file_dir)
+        .versions
+        .into_iter()
+        .filter(|version| version_file(&file_dir, &version.id).is_some())
+        .collect();
+    // Most recent first
+    versions.reverse();
+    versions
+}
+
+fn read_version(dir: &Path, path: &Path, id: &str) -> Result<String, String> {
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_digit() || c == '-') {
+        return Err(format!("Invalid version id: {id}"));
+    }
+    let unavailable = || "That version is no longer available".to_string();
+    let file = version_file(&file_dir(dir, path), id).ok_or_else(unavailable)?;
+    let bytes = std::fs::read(&file).map_err(|_| unavailable())?;
+    let bytes = if file.extension().is_some_and(|e| e == "enc") {
+        recovery::decrypt(&recovery::recovery_key()?, &bytes)?
+    } else {
+        bytes
+    };
+    String::from_utf8(bytes).map_err(|_| "That version isn't valid text".to_string())
+}
+
+fn purge_in(dir: &Path, cutoff: DateTime<Utc>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for file_dir in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+        let mut index = read_index(&file_dir);
+        prune(&file_dir, &mut index, cutoff);
+        if index.versions.is_empty() {
+            let _ = std::fs::remove_dir_all(&file_dir);
+        } else {
+            let _ = write_index(&file_dir, &index);
+        }
+    }
+}
+
+fn snapshot_with(path: &Path, content: &str, min_interval: Option<Duration>) {
+    let Some(dir) = VERSIONS_DIR.get().filter(|_| !dry_run::is_active()) else {
+        return;
+    };
+    // Without the key, nothing is kept rather than keeping it unencrypted
+    let key = match projects::encrypt_recovery_data()
+        .then(recovery::recovery_key)
+        .transpose()
+    {
+        Ok(key) => key,
+        Err(e) => {
+            log::warn!("Astro Editor [VERSIONS] Failed to save version of {path:?}: {e}");
+            return;
+        }
+    };
+    if let Err(e) = snapshot_in(dir, path, content, key.as_deref(), min_interval) {
+        log::warn!("Astro Editor [VERSIONS] Failed to save version of {path:?}: {e}");
+    }
+}
+
+/// Keeps a copy of a file's content as it was just saved
+///
+/// Nothing is kept during a dry run, or without a versions directory (app data couldn't
+/// be resolved). Failures are logged rather than failing the save.
+pub(crate) fn snapshot(path: &Path, content: &str) {
+    snapshot_with(path, content, None);
+}
+
+/// Keeps a copy of a file's content as it was just autosaved, unless a version was kept
+/// in the last `AUTOSAVE_INTERVAL_MINUTES`
+pub(crate) fn snapshot_autosave(path: &Path, content: &str) {
+    snapshot_with(
+        path,
+        content,
+        Some(Duration::minutes(AUTOSAVE_INTERVAL_MINUTES)),
+    );
+}
+
+/// Lists the saved versions of a file, newest first
+#[tauri::command]
+#[specta::specta]
+pub async fn list_file_versions(file_path: String) -> Result<Vec<FileVersion>, String> {
+    let dir = VERSIONS_DIR
+        .get()
+        .ok_or("Version history is not available")?;
+    Ok(list_in(dir, Path::new(&file_path)))
+}
+
+/// Renders a unified diff from a saved version of a file to the file as it is on disk
+#[tauri::command]
+#[specta::specta]
+pub async fn diff_file_version(file_path: String, version_id: String) -> Result<String, String> {
+    let dir = VERSIONS_DIR
+        .get()
+        .ok_or("Version history is not available")?;
+    let path = Path::new(&file_path);
+    let version = read_version(dir, path, &version_id)?;
+    let current = std::fs::read_to_string(path).unwrap_or_default();
+    let label = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| file_path.clone());
+    Ok(unified_diff(&version, &current, &label))
+}
+
+/// Restores a file to a saved version
+///
+/// The file's current content is kept as a version first, so the restore can itself be
+/// undone.
+///
+/// # Returns
+/// The content hash of the restored file, to expect on the next save
+#[tauri::command]
+#[specta::specta]
+pub async fn restore_file_version(
+    file_path: String,
+    project_root: String,
+    version_id: String,
+    origin: Option<String>,
+) -> Result<String, String> {
+    let dir = VERSIONS_DIR
+        .get()
+        .ok_or("Version history is not available")?;
+    let validated_path = validate_project_path(&file_path, &project_root)?;
+    let content = read_version(dir, &validated_path, &version_id)?;
+
+    if let Ok(current) = std::fs::read_to_string(&validated_path) {
+        snapshot(&validated_path, &current);
+    }
+    dry_run::write_atomic(&validated_path, &content)
+        .map_err(|e| format!("Failed to restore version: {e}"))?;
+    audit_log::record(
+        AuditAction::Write,
+        &validated_path,
+        None,
+        origin.as_deref().unwrap_or("restore_file_version"),
+    );
+    snapshot(&validated_path, &content);
+    Ok(content_hash(&content))
+}
+
+// Resolve the versions directory and purge expired versions when the app starts
+pub fn init_versions(app: &AppHandle) {
+    match app.path().resolve("versions", BaseDirectory::AppLocalData) {
+        Ok(dir) => {
+            purge_in(&dir, Utc::now() - Duration::days(RETENTION_DAYS));
+            let _ = VERSIONS_DIR.set(dir);
+        }
+        Err(e) => log::warn!("Astro Editor [VERSIONS] Failed to resolve versions directory: {e}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_snapshot_dedupes_and_lists_newest_first() {
+        let temp = TempDir::new().unwrap();
+        let versions = temp.path().join("versions");
+        let post = temp.path().join("post.md");
+        fs::write(&post, "first").unwrap();
+
+        let first = snapshot_in(&versions, &post, "first", None, None)
+            .unwrap()
+            .unwrap();
+        // Saving unchanged content adds nothing
+        assert_eq!(
+            snapshot_in(&versions, &post, "first", None, None).unwrap(),
+            None
+        );
+        let second = snapshot_in(&versions, &post, "second", None, None)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(list_in(&versions, &post), vec![second, first.clone()]);
+        assert_eq!(read_version(&versions, &post, &first.id).unwrap(), "first");
+        assert!(read_version(&versions, &post, "../index").is_err());
+
+        // Other files have their own history
+        assert!(list_in(&versions, &temp.path().join("other.md")).is_empty());
+    }
+
+    #[test]
+    fn test_autosaves_are_throttled() {
+        let temp = TempDir::new().unwrap();
+        let versions = temp.path().join("versions");
+        let post = temp.path().join("post.md");
+        fs::write(&post, "").unwrap();
+        let interval = Some(Duration::minutes(AUTOSAVE_INTERVAL_MINUTES));
+
+        // The first autosave is kept, later ones only once the interval has passed
+        assert!(snapshot_in(&versions, &post, "one", None, interval)
+            .unwrap()
+            .is_some());
+        assert_eq!(
+            snapshot_in(&versions, &post, "two", None, interval).unwrap(),
+            None
+        );
+        // Explicit saves are always kept
+        assert!(snapshot_in(&versions, &post, "three", None, None)
+            .unwrap()
+            .is_some());
+        assert_eq!(list_in(&versions, &post).len(), 2);
+    }
+
+    #[test]
+    fn test_encrypted_versions_keep_no_plaintext() {
+        let temp = TempDir::new().unwrap();
+        let versions = temp.path().join("versions");
+        let post = temp.path().join("post.md");
+        fs::write(&post, "").unwrap();
+        let key = [7u8; 32];
+
+        let version = snapshot_in(&versions, &post, "secret draft", Some(&key), None)
+            .unwrap()
+            .unwrap();
+        let file_dir = file_dir(&versions, &post);
+        let sealed = fs::read(file_dir.join(format!("{}.enc", version.id))).unwrap();
+        assert!(!String::from_utf8_lossy(&sealed).contains("secret"));
+        assert!(!file_dir.join(format!("{}.data", version.id)).exists());
+        assert_eq!(
+            recovery::decrypt(&key, &sealed).unwrap(),
+            b"secret draft".to_vec()
+        );
+        assert_eq!(list_in(&versions, &post), vec![version]);
+    }
+
+    #[test]
+    fn test_retention() {
+        let temp = TempDir::new().unwrap();
+        let versions = temp.path().join("versions");
+        let post = temp.path().join("post.md");
+        fs::write(&post, "").unwrap();
+
+        for n in 0..MAX_VERSIONS_PER_FILE + 5 {
+            snapshot_in(&versions, &post, &format!("version {n}"), None, None).unwrap();
+        }
+        let listed = list_in(&versions, &post);
+        assert_eq!(listed.len(), MAX_VERSIONS_PER_FILE);
+        let newest = read_version(&versions, &post, &listed[0].id).unwrap();
+        assert_eq!(newest, format!("version {}", MAX_VERSIONS_PER_FILE + 4));
+        let file_dir = file_dir(&versions, &post);
+        assert_eq!(
+            fs::read_dir(&file_dir).unwrap().count(),
+            MAX_VERSIONS_PER_FILE + 1
+        );
+
+        purge_in(&versions, Utc::now() + Duration::days(1));
+        assert!(list_in(&versions, &post).is_empty());
+        assert!(!file_dir.exists());
+    }
+}
@@ -1,14 +1,203 @@
+//! File watching for open projects
+//!
+//! Each project gets a watcher on its content directories and schema files. Events are
+//! debounced, then coalesced into one classified change per Markdown file, emitted as
+//! `file-changed` and as `file-created`, `file-modified`, `file-deleted` or
+//! `file-renamed`, with the collection the file is in. Saves the app makes itself are
+//! marked with `ignore_next_write` so they don't look like external edits.
+
+use crate::commands::project::scan_project_with_content_dir;
+use crate::commands::projects;
+use crate::commands::unsaved_drafts;
+use notify::event::{CreateKind, ModifyKind, RenameMode};
 use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+use specta::Type;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::mpsc::{self, Receiver};
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter, Manager, State};
+use walkdir::WalkDir;
 
 // Global watcher storage
 type WatcherMap = Arc<Mutex<HashMap<String, RecommendedWatcher>>>;
 
+// Per-project event counters, exposed for debugging
+pub type WatcherStatsMap = Arc<Mutex<HashMap<String, WatcherStats>>>;
+
+/// Directories never watched or reported, regardless of project settings.
+/// Generated schema files under `.astro/collections` are still handled.
+pub(crate) const DEFAULT_EXCLUDED_DIRECTORIES: [&str; 4] =
+    ["node_modules", ".git", "dist", ".astro"];
+
+/// Counts of watcher events for a project since watching started
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct WatcherStats {
+    /// Events passed on to the frontend (file or schema changes)
+    pub processed_events: u32,
+    /// Events dropped because they came from an excluded directory
+    pub suppressed_events: u32,
+    /// Suppressed event counts keyed by the exclusion that matched
+    pub suppressed_by_exclusion: HashMap<String, u32>,
+}
+
+/// What happened to a file over one debounced batch of events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum FileChangeKind {
+    Created,
+    Modified,
+    Deleted,
+    Renamed,
+}
+
+impl FileChangeKind {
+    /// The event emitted for this kind of change, alongside `file-changed`
+    fn event_name(self) -> &'static str {
+        match self {
+            FileChangeKind::Created => "file-created",
+            FileChangeKind::Modified => "file-modified",
+            FileChangeKind::Deleted => "file-deleted",
+            FileChangeKind::Renamed => "file-renamed",
+        }
+    }
+}
+
+/// A change to a Markdown file in a watched project
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FileChangeEvent {
+    pub kind: FileChangeKind,
+    pub path: String,
+    /// Where a renamed file was
+    #[serde(default)]
+    #[specta(optional)]
+    pub old_path: Option<String>,
+    /// The collection the file is in, if any
+    pub collection: Option<String>,
+}
+
+/// Collection names and roots, for attributing changes
+type CollectionRoots = Vec<(String, PathBuf)>;
+
+/// Everything the event loop needs to filter events and extend watches
+#[derive(Clone)]
+struct WatchContext {
+    project_path: String,
+    content_directory: Option<String>,
+    excluded: Vec<String>,
+    watcher_map: WatcherMap,
+    stats_map: WatcherStatsMap,
+    /// Scanned when first needed, and again after a schema change
+    collections: Arc<Mutex<Option<CollectionRoots>>>,
+}
+
+/// How long `ignore_next_write` waits for the write's events before giving up
+const IGNORE_WRITE_WINDOW: Duration = Duration::from_secs(3);
+
+/// Paths whose next change isn't reported, with when that lapses
+static IGNORED_WRITES: OnceLock<Mutex<HashMap<PathBuf, Instant>>> = OnceLock::new();
+
+fn ignored_writes() -> &'static Mutex<HashMap<PathBuf, Instant>> {
+    IGNORED_WRITES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Keeps the watcher from reporting the next change to `path`, so a save the app
+/// makes itself doesn't look like an external edit
+pub(crate) fn ignore_write(path: &Path) {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    ignored_writes()
+        .lock()
+        .unwrap()
+        .insert(path, Instant::now() + IGNORE_WRITE_WINDOW);
+}
+
+/// Whether a change to `path` was marked by `ignore_write`, clearing the mark
+fn take_ignored_write(path: &Path) -> bool {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let mut ignored = ignored_writes().lock().unwrap();
+    let now = Instant::now();
+    ignored.retain(|_, until| *until > now);
+    ignored.remove(&path).is_some()
+}
+
+/// Merges the default exclusions with any configured in project settings
+fn build_exclusion_list(configured: Option<Vec<String>>) -> Vec<String> {
+    let mut excluded: Vec<String> = DEFAULT_EXCLUDED_DIRECTORIES
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+    for entry in configured.unwrap_or_default() {
+        let entry = entry.trim().trim_matches('/').replace('\\', "/");
+        if !entry.is_empty() && !excluded.contains(&entry) {
+            excluded.push(entry);
+        }
+    }
+
+    excluded
+}
+
+/// Returns the exclusion matching a path, if any
+///
+/// Plain names (e.g. `node_modules`) match any path component; entries containing a
+/// slash (e.g. `src/content/archive`) match as a prefix relative to the project root.
+fn matching_exclusion<'a>(
+    path: &Path,
+    project_root: &Path,
+    excluded: &'a [String],
+) -> Option<&'a str> {
+    let relative = path.strip_prefix(project_root).unwrap_or(path);
+    let relative_str = crate::utils::path::normalize_path_for_serialization(relative);
+
+    excluded
+        .iter()
+        .find(|exclusion| {
+            if exclusion.contains('/') {
+                relative_str == **exclusion || relative_str.starts_with(&format!("{exclusion}/"))
+            } else {
+                relative
+                    .components()
+                    .any(|c| c.as_os_str().to_string_lossy() == exclusion.as_str())
+            }
+        })
+        .map(|s| s.as_str())
+}
+
+/// Watches a directory and all its non-excluded subdirectories non-recursively
+///
+/// Walking the tree ourselves (rather than using `RecursiveMode::Recursive`) means the OS
+/// never registers watches inside excluded directories like `node_modules`.
+fn watch_directory_tree(
+    watcher: &mut RecommendedWatcher,
+    root: &Path,
+    project_root: &Path,
+    excluded: &[String],
+) -> Result<usize, String> {
+    let mut watched = 0;
+
+    let directories = WalkDir::new(root)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.file_type().is_dir()
+                && matching_exclusion(entry.path(), project_root, excluded).is_none()
+        })
+        .filter_map(Result::ok);
+
+    for entry in directories {
+        watcher
+            .watch(entry.path(), RecursiveMode::NonRecursive)
+            .map_err(|e| format!("Failed to watch {}: {e}", entry.path().display()))?;
+        watched += 1;
+    }
+
+    Ok(watched)
+}
+
 /// Debounce window: process buffered events after 500ms of no new events
 const DEBOUNCE_DURATION: Duration = Duration::from_millis(500);
 
@@ -20,6 +209,7 @@ const RESCAN_INTERVAL: Duration = Duration::from_secs(300);
 fn create_project_watcher(
     project_path: &str,
     content_directory: Option<&str>,
+    excluded: &[String],
 ) -> Result<(RecommendedWatcher, Receiver<Event>), String> {
     let (tx, rx) = mpsc::channel();
     let project_path_log = project_path.to_string();
@@ -47,9 +237,9 @@ fn create_project_watcher(
     };
 
     if content_path.exists() {
-        watcher
-            .watch(&content_path, RecursiveMode::Recursive)
+        let watched = watch_directory_tree(&mut watcher, &content_path, &project_root, excluded)
             .map_err(|e| format!("Failed to watch content directory: {e}"))?;
+        log::debug!("Watching {watched} content directories for {project_path}");
     }
 
     // Watch for schema changes: src/content/config.ts or src/content.config.ts
@@ -78,7 +268,9 @@ fn create_project_watcher(
 #[tauri::command]
 #[specta::specta]
 pub async fn start_watching_project(app: AppHandle, project_path: String) -> Result<(), String> {
-    start_watching_project_with_content_dir(app, project_path, None).await
+    // Use the content directory saved for the project, if any
+    let content_directory = projects::path_settings(&project_path).content_directory;
+    start_watching_project_with_content_dir(app, project_path, content_directory, None).await
 }
 
 #[tauri::command]
@@ -87,8 +279,11 @@ pub async fn start_watching_project_with_content_dir(
     app: AppHandle,
     project_path: String,
     content_directory: Option<String>,
+    excluded_directories: Option<Vec<String>>,
 ) -> Result<(), String> {
-    let (watcher, rx) = create_project_watcher(&project_path, content_directory.as_deref())?;
+    let excluded = build_exclusion_list(excluded_directories);
+    let (watcher, rx) =
+        create_project_watcher(&project_path, content_directory.as_deref(), &excluded)?;
 
     // Store the watcher so it doesn't get dropped
     let watcher_map: State<WatcherMap> = app.state();
@@ -97,19 +292,29 @@ pub async fn start_watching_project_with_content_dir(
         .unwrap()
         .insert(project_path.clone(), watcher);
 
-    // Clone the Arc for the spawned task
-    let watcher_map_arc = app.state::<WatcherMap>().inner().clone();
+    // Reset debugging counters for this project
+    let stats_map = app.state::<WatcherStatsMap>().inner().clone();
+    stats_map
+        .lock()
+        .unwrap()
+        .insert(project_path.clone(), WatcherStats::default());
+
+    // Opening a project is when to mention edits left unsaved last session
+    unsaved_drafts::announce(&app, &project_path);
+
+    // Clone the Arcs for the spawned task
+    let context = WatchContext {
+        project_path,
+        content_directory,
+        excluded,
+        watcher_map: app.state::<WatcherMap>().inner().clone(),
+        stats_map,
+        collections: Arc::new(Mutex::new(None)),
+    };
     let app_handle = app.clone();
 
     tokio::spawn(async move {
-        run_event_loop(
-            app_handle,
-            watcher_map_arc,
-            rx,
-            project_path,
-            content_directory,
-        )
-        .await;
+        run_event_loop(app_handle, rx, context).await;
     });
 
     Ok(())
@@ -121,13 +326,8 @@ pub async fn start_watching_project_with_content_dir(
 /// - Events are buffered and processed after 500ms of quiet
 /// - Every 5 minutes, a rescan event is emitted as a safety net for missed changes
 /// - If the watcher dies (channel disconnects), it's automatically rebuilt
-async fn run_event_loop(
-    app: AppHandle,
-    watcher_map: WatcherMap,
-    mut rx: Receiver<Event>,
-    project_path: String,
-    content_directory: Option<String>,
-) {
+async fn run_event_loop(app: AppHandle, mut rx: Receiver<Event>, context: WatchContext) {
+    let project_path = &context.project_path;
     let mut event_buffer: Vec<Event> = Vec::new();
     let mut last_rescan = Instant::now();
 
@@ -139,40 +339,48 @@ async fn run_event_loop(
             Err(mpsc::RecvTimeoutError::Timeout) => {
                 // Debounce timeout — process any buffered events
                 if !event_buffer.is_empty() {
-                    process_events(&app, &mut event_buffer).await;
+                    process_events(&app, &context, &mut event_buffer).await;
                     event_buffer.clear();
                 }
 
                 // Periodic rescan as safety net for missed changes
                 if last_rescan.elapsed() >= RESCAN_INTERVAL {
                     log::debug!("Periodic rescan for {project_path}");
-                    let _ = app.emit("watcher-rescan", &project_path);
+                    crate::commands::search::invalidate_project_index(&app, project_path);
+                    let _ = app.emit("watcher-rescan", project_path);
                     last_rescan = Instant::now();
                 }
             }
             Err(mpsc::RecvTimeoutError::Disconnected) => {
                 // Watcher died — process remaining events then rebuild
                 if !event_buffer.is_empty() {
-                    process_events(&app, &mut event_buffer).await;
+                    process_events(&app, &context, &mut event_buffer).await;
                     event_buffer.clear();
                 }
 
                 log::warn!("File watcher disconnected for {project_path}, attempting rebuild");
 
-                match create_project_watcher(&project_path, content_directory.as_deref()) {
+                match create_project_watcher(
+                    project_path,
+                    context.content_directory.as_deref(),
+                    &context.excluded,
+                ) {
                     Ok((new_watcher, new_rx)) => {
-                        watcher_map
+                        context
+                            .watcher_map
                             .lock()
                             .unwrap()
                             .insert(project_path.clone(), new_watcher);
                         rx = new_rx;
                         last_rescan = Instant::now();
                         log::info!("File watcher rebuilt for {project_path}");
-                        let _ = app.emit("watcher-rebuilt", &project_path);
+                        // Events may have been missed while the watcher was down
+                        crate::commands::search::invalidate_project_index(&app, project_path);
+                        let _ = app.emit("watcher-rebuilt", project_path);
                     }
                     Err(e) => {
                         log::error!("Failed to rebuild file watcher for {project_path}: {e}");
-                        let _ = app.emit("watcher-error", &project_path);
+                        let _ = app.emit("watcher-error", project_path);
                         break;
                     }
                 }
@@ -188,41 +396,81 @@ pub async fn stop_watching_project(app: AppHandle, project_path: String) -> Resu
     let mut watchers = watcher_map.lock().unwrap();
 
     if watchers.remove(&project_path).is_some() {
+        crate::commands::search::invalidate_project_index(&app, &project_path);
         Ok(())
     } else {
         Err("No watcher found for this project".to_string())
     }
 }
 
-async fn process_events(app: &AppHandle, events: &mut [Event]) {
+/// Keeps the watcher from reporting the next change to a file
+///
+/// For writes the app makes itself outside `save_markdown_content`, which marks its
+/// own. The mark lapses after a few seconds if no change arrives.
+#[tauri::command]
+#[specta::specta]
+pub async fn ignore_next_write(path: String) -> Result<(), String> {
+    ignore_write(Path::new(&path));
+    Ok(())
+}
+
+/// Returns watcher event counts for a project, including events suppressed by exclusions
+#[tauri::command]
+#[specta::specta]
+pub async fn get_watcher_stats(
+    app: AppHandle,
+    project_path: String,
+) -> Result<WatcherStats, String> {
+    let stats_map: State<WatcherStatsMap> = app.state();
+    let stats = stats_map.lock().unwrap();
+
+    stats
+        .get(&project_path)
+        .cloned()
+        .ok_or_else(|| "No watcher found for this project".to_string())
+}
+
+async fn process_events(app: &AppHandle, context: &WatchContext, events: &mut [Event]) {
+    let project_root = PathBuf::from(&context.project_path);
     let mut schema_changed = false;
+    let mut new_directories = Vec::new();
+    let mut processed = 0u32;
+    let mut suppressed: HashMap<String, u32> = HashMap::new();
 
     for event in events.iter() {
         match &event.kind {
             EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_) => {
                 for path in &event.paths {
-                    // Check if it's a schema-related file
+                    // Check if it's a schema-related file (allowed even inside .astro)
                     if is_schema_file(path) {
                         schema_changed = true;
+                        processed += 1;
                         continue;
                     }
 
-                    // Check if it's a markdown file
-                    if let Some(extension) = path.extension() {
-                        if matches!(extension.to_str(), Some("md") | Some("mdx")) {
-                            // Emit event to frontend with normalized path
-                            let normalized_path =
-                                crate::utils::path::normalize_path_for_serialization(path);
-                            if let Err(e) = app.emit(
-                                "file-changed",
-                                FileChangeEvent {
-                                    path: normalized_path,
-                                    kind: format!("{:?}", event.kind),
-                                },
-                            ) {
-                                eprintln!("Failed to emit file change event: {e}");
-                            }
-                        }
+                    if let Some(exclusion) =
+                        matching_exclusion(path, &project_root, &context.excluded)
+                    {
+                        *suppressed.entry(exclusion.to_string()).or_default() += 1;
+                        continue;
+                    }
+
+                    // New subdirectories need their own non-recursive watches
+                    if matches!(
+                        event.kind,
+                        EventKind::Create(CreateKind::Folder | CreateKind::Any)
+                    ) && path.is_dir()
+                    {
+                        new_directories.push(path.clone());
+                    }
+
+                    if is_markdown(path) {
+                        processed += 1;
+                        crate::commands::search::refresh_indexed_file(
+                            app,
+                            &context.project_path,
+                            path,
+                        );
                     }
                 }
             }
@@ -231,14 +479,84 @@ async fn process_events(app: &AppHandle, events: &mut [Event]) {
         }
     }
 
+    if !new_directories.is_empty() {
+        if let Some(watcher) = context
+            .watcher_map
+            .lock()
+            .unwrap()
+            .get_mut(&context.project_path)
+        {
+            for directory in &new_directories {
+                if let Err(e) =
+                    watch_directory_tree(watcher, directory, &project_root, &context.excluded)
+                {
+                    log::warn!("{e}");
+                }
+            }
+        }
+    }
+
+    record_stats(context, processed, suppressed);
+
+    if schema_changed {
+        // Collections may have been added or moved
+        *context.collections.lock().unwrap() = None;
+    }
+
+    let changes = classify_changes(events, |path| {
+        is_markdown(path) && matching_exclusion(path, &project_root, &context.excluded).is_none()
+    });
+    if !changes.is_empty() {
+        let collections = collection_roots(context).await;
+        for change in changes {
+            let own_write = matches!(
+                change.kind,
+                FileChangeKind::Created | FileChangeKind::Modified
+            ) && take_ignored_write(&change.path);
+            if own_write {
+                log::debug!("Ignoring own write to {}", change.path.display());
+                continue;
+            }
+
+            let event = FileChangeEvent {
+                kind: change.kind,
+                path: crate::utils::path::normalize_path_for_serialization(&change.path),
+                old_path: change
+                    .old_path
+                    .as_deref()
+                    .map(crate::utils::path::normalize_path_for_serialization),
+                collection: owning_collection(&collections, &change.path),
+            };
+            for name in ["file-changed", change.kind.event_name()] {
+                if let Err(e) = app.emit(name, &event) {
+                    eprintln!("Failed to emit file change event: {e}");
+                }
+            }
+        }
+    }
+
     // Emit schema-changed event once if any schema files changed
     if schema_changed {
+        // Collections may have been added or moved
+        crate::commands::search::invalidate_project_index(app, &context.project_path);
         if let Err(e) = app.emit("schema-changed", ()) {
             eprintln!("Failed to emit schema change event: {e}");
         }
     }
 }
 
+fn record_stats(context: &WatchContext, processed: u32, suppressed: HashMap<String, u32>) {
+    let mut stats_map = context.stats_map.lock().unwrap();
+    let stats = stats_map.entry(context.project_path.clone()).or_default();
+
+    stats.processed_events = stats.processed_events.saturating_add(processed);
+    for (exclusion, count) in suppressed {
+        stats.suppressed_events = stats.suppressed_events.saturating_add(count);
+        let entry = stats.suppressed_by_exclusion.entry(exclusion).or_default();
+        *entry = entry.saturating_add(count);
+    }
+}
+
 /// Check if a file path is a schema-related file
 fn is_schema_file(path: &std::path::Path) -> bool {
     // Normalize path to forward slashes for consistent cross-platform matching
@@ -257,13 +575,321 @@ fn is_schema_file(path: &std::path::Path) -> bool {
     false
 }
 
-#[derive(serde::Serialize, Clone)]
-struct FileChangeEvent {
-    path: String,
-    kind: String,
+fn is_markdown(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|e| e.to_str()),
+        Some("md") | Some("mdx")
+    )
+}
+
+/// A classified change, before collection attribution
+#[derive(Debug, PartialEq)]
+struct Change {
+    kind: FileChangeKind,
+    path: PathBuf,
+    old_path: Option<PathBuf>,
+}
+
+/// Coalesces a batch of events into one change per file `relevant` accepts
+///
+/// Each file's change is judged from its first event and whether it exists once the
+/// batch is over: a create followed by writes is one `Created`, a save that replaces
+/// the file (remove then create, as atomic writes do) is one `Modified`, and a file
+/// created and removed within the batch isn't reported. Renames are paired when the
+/// platform reports both paths; otherwise they show up as a delete and a create.
+fn classify_changes(events: &[Event], relevant: impl Fn(&Path) -> bool) -> Vec<Change> {
+    // Paths in the order first seen, and whether that first event created them
+    let mut order: Vec<PathBuf> = Vec::new();
+    let mut created_first: HashMap<PathBuf, bool> = HashMap::new();
+    let mut renames: Vec<(PathBuf, PathBuf)> = Vec::new();
+    let mut rename_sources: HashMap<usize, PathBuf> = HashMap::new();
+
+    let mut see = |path: &Path, created: bool| {
+        if relevant(path) && !created_first.contains_key(path) {
+            created_first.insert(path.to_path_buf(), created);
+            order.push(path.to_path_buf());
+        }
+    };
+
+    for event in events {
+        match (&event.kind, event.paths.as_slice()) {
+            (EventKind::Modify(ModifyKind::Name(RenameMode::Both)), [from, to]) => {
+                see(from, false);
+                see(to, true);
+                renames.push((from.clone(), to.clone()));
+            }
+            (EventKind::Modify(ModifyKind::Name(RenameMode::From)), [from]) => {
+                see(from, false);
+                if let Some(tracker) = event.tracker() {
+                    rename_sources.insert(tracker, from.clone());
+                }
+            }
+            (EventKind::Modify(ModifyKind::Name(RenameMode::To)), [to]) => {
+                see(to, true);
+                if let Some(from) = event.tracker().and_then(|t| rename_sources.remove(&t)) {
+                    renames.push((from, to.clone()));
+                }
+            }
+            (EventKind::Create(_), paths) => paths.iter().for_each(|path| see(path, true)),
+            (EventKind::Modify(_) | EventKind::Remove(_), paths) => {
+                paths.iter().for_each(|path| see(path, false))
+            }
+            _ => {}
+        }
+    }
+
+    // A rename counts when both sides are relevant and it's where the file ended up
+    let renamed_to: HashMap<PathBuf, PathBuf> = renames
+        .into_iter()
+        .filter(|(from, to)| {
+            created_first.contains_key(from)
+                && created_first.contains_key(to)
+                && !from.exists()
+                && to.exists()
+        })
+        .map(|(from, to)| (to, from))
+        .collect();
+    let renamed_from: Vec<&PathBuf> = renamed_to.values().collect();
+
+    order
+        .iter()
+        .filter(|path| !renamed_from.contains(path))
+        .filter_map(|path| {
+            if let Some(from) = renamed_to.get(path) {
+                return Some(Change {
+                    kind: FileChangeKind::Renamed,
+                    path: path.clone(),
+                    old_path: Some(from.clone()),
+                });
+            }
+            let kind = match (created_first[path], path.exists()) {
+                (false, true) => FileChangeKind::Modified,
+                (true, true) => FileChangeKind::Created,
+                (false, false) => FileChangeKind::Deleted,
+                (true, false) => return None,
+            };
+            Some(Change {
+                kind,
+                path: path.clone(),
+                old_path: None,
+            })
+        })
+        .collect()
+}
+
+/// The collection whose root is the most specific ancestor of `path`
+fn owning_collection(collections: &[(String, PathBuf)], path: &Path) -> Option<String> {
+    collections
+        .iter()
+        .filter(|(_, root)| path.starts_with(root))
+        .max_by_key(|(_, root)| root.components().count())
+        .map(|(name, _)| name.clone())
+}
+
+/// The project's collection roots, scanning them if they aren't known yet
+async fn collection_roots(context: &WatchContext) -> CollectionRoots {
+    let cached = context.collections.lock().unwrap().clone();
+    if let Some(roots) = cached {
+        return roots;
+    }
+    let roots = match scan_project_with_content_dir(
+        context.project_path.clone(),
+        context.content_directory.clone(),
+        None,
+    )
+    .await
+    {
+        Ok(collections) => collections.into_iter().map(|c| (c.name, c.path)).collect(),
+        Err(e) => {
+            log::warn!("Failed to scan collections for file changes: {e}");
+            Vec::new()
+        }
+    };
+    *context.collections.lock().unwrap() = Some(roots.clone());
+    roots
+}
+
+/// Returns the roots of all projects currently being watched (i.e. open)
+pub(crate) fn watched_project_paths(app: &AppHandle) -> Vec<PathBuf> {
+    let watcher_map: State<WatcherMap> = app.state();
+    let watchers = watcher_map.lock().unwrap();
+    watchers.keys().map(PathBuf::from).collect()
 }
 
 // Initialize the watcher map when the app starts
 pub fn init_watcher_state() -> WatcherMap {
     Arc::new(Mutex::new(HashMap::new()))
 }
+
+// Initialize the watcher stats map when the app starts
+pub fn init_watcher_stats() -> WatcherStatsMap {
+    Arc::new(Mutex::new(HashMap::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_build_exclusion_list_merges_and_normalizes() {
+        let excluded = build_exclusion_list(Some(vec![
+            "/src/content/archive/".to_string(),
+            "node_modules".to_string(),
+            "  ".to_string(),
+        ]));
+
+        assert_eq!(
+            excluded,
+            vec![
+                "node_modules",
+                ".git",
+                "dist",
+                ".astro",
+                "src/content/archive"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_matching_exclusion() {
+        let root = Path::new("/project");
+        let excluded = build_exclusion_list(Some(vec!["src/content/archive".to_string()]));
+
+        assert_eq!(
+            matching_exclusion(
+                Path::new("/project/src/content/node_modules/pkg/readme.md"),
+                root,
+                &excluded
+            ),
+            Some("node_modules")
+        );
+        assert_eq!(
+            matching_exclusion(
+                Path::new("/project/src/content/archive/old.md"),
+                root,
+                &excluded
+            ),
+            Some("src/content/archive")
+        );
+        assert_eq!(
+            matching_exclusion(
+                Path::new("/project/src/content/archived/post.md"),
+                root,
+                &excluded
+            ),
+            None
+        );
+        assert_eq!(
+            matching_exclusion(
+                Path::new("/project/src/content/blog/post.md"),
+                root,
+                &excluded
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_watch_directory_tree_skips_excluded_directories() {
+        let temp = TempDir::new().unwrap();
+        let content = temp.path().join("content");
+        std::fs::create_dir_all(content.join("blog/2024")).unwrap();
+        std::fs::create_dir_all(content.join("node_modules/pkg/deep")).unwrap();
+        std::fs::create_dir_all(content.join(".git/objects")).unwrap();
+
+        let (tx, _rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |result| {
+            let _ = tx.send(result);
+        })
+        .unwrap();
+
+        let excluded = build_exclusion_list(None);
+        let watched = watch_directory_tree(&mut watcher, &content, temp.path(), &excluded).unwrap();
+
+        // content, content/blog, content/blog/2024
+        assert_eq!(watched, 3);
+    }
+
+    #[test]
+    fn test_classify_changes_coalesces_each_file() {
+        let temp = TempDir::new().unwrap();
+        let path = |name: &str| temp.path().join(name);
+        for name in ["new.md", "edited.md", "replaced.md", "notes.txt"] {
+            std::fs::write(path(name), "").unwrap();
+        }
+        let event = |kind: EventKind, name: &str| Event::new(kind).add_path(path(name));
+        let modify = EventKind::Modify(ModifyKind::Any);
+        let create = EventKind::Create(CreateKind::File);
+        let remove = EventKind::Remove(notify::event::RemoveKind::File);
+
+        let events = vec![
+            event(create, "new.md"),
+            event(modify, "new.md"),
+            event(modify, "edited.md"),
+            event(modify, "edited.md"),
+            event(remove, "replaced.md"),
+            event(create, "replaced.md"),
+            event(create, "scratch.md"),
+            event(remove, "scratch.md"),
+            event(remove, "gone.md"),
+            event(modify, "notes.txt"),
+        ];
+        let changes = classify_changes(&events, is_markdown);
+
+        let kinds: Vec<(FileChangeKind, PathBuf)> =
+            changes.into_iter().map(|c| (c.kind, c.path)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (FileChangeKind::Created, path("new.md")),
+                (FileChangeKind::Modified, path("edited.md")),
+                (FileChangeKind::Modified, path("replaced.md")),
+                (FileChangeKind::Deleted, path("gone.md")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_classify_changes_pairs_renames() {
+        let temp = TempDir::new().unwrap();
+        let (old, new) = (temp.path().join("old.md"), temp.path().join("new.md"));
+        std::fs::write(&new, "").unwrap();
+
+        let rename = |mode| EventKind::Modify(ModifyKind::Name(mode));
+        let tracked = vec![
+            Event::new(rename(RenameMode::From))
+                .add_path(old.clone())
+                .set_tracker(7),
+            Event::new(rename(RenameMode::To))
+                .add_path(new.clone())
+                .set_tracker(7),
+        ];
+        let both = vec![Event::new(rename(RenameMode::Both))
+            .add_path(old.clone())
+            .add_path(new.clone())];
+
+        for events in [tracked, both] {
+            assert_eq!(
+                classify_changes(&events, is_markdown),
+                vec![Change {
+                    kind: FileChangeKind::Renamed,
+                    path: new.clone(),
+                    old_path: Some(old.clone()),
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn test_ignore_write_skips_only_the_next_change() {
+        let temp = TempDir::new().unwrap();
+        let post = temp.path().join("post.md");
+        std::fs::write(&post, "").unwrap();
+
+        assert!(!take_ignored_write(&post));
+        ignore_write(&post);
+        assert!(take_ignored_write(&post));
+        assert!(!take_ignored_write(&post));
+    }
+}
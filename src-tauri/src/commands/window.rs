@@ -0,0 +1,118 @@
+//! Native window title and represented document
+//!
+//! On macOS the window's represented filename gives the title bar a proxy icon
+//! (Cmd-clicking the title shows the file's path) and `documentEdited` draws the
+//! unsaved-changes dot in the close button. Other platforms only get the title, with
+//! a leading bullet for unsaved changes.
+
+use std::sync::Mutex;
+use tauri::{Manager, WebviewWindow};
+
+const APP_NAME: &str = "Astro Editor";
+
+/// Path of the file the window currently represents, for crash reports
+static CURRENT_DOCUMENT: Mutex<Option<String>> = Mutex::new(None);
+
+/// Absolute path of the file open in the editor, if any
+pub(crate) fn current_document() -> Option<String> {
+    CURRENT_DOCUMENT.lock().ok()?.clone()
+}
+
+/// Builds the window title for an entry
+fn window_title(title: Option<&str>, is_dirty: bool) -> String {
+    let title = title
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .unwrap_or(APP_NAME);
+
+    if is_dirty && !cfg!(target_os = "macos") {
+        format!("\u{2022} {title}")
+    } else {
+        title.to_string()
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn set_represented_document(
+    window: &WebviewWindow,
+    file_path: Option<String>,
+    is_dirty: bool,
+) -> Result<(), String> {
+    use objc2_app_kit::NSWindow;
+    use objc2_foundation::NSString;
+
+    let ns_window = window
+        .ns_window()
+        .map_err(|e| format!("Failed to get native window: {e}"))? as usize;
+
+    // AppKit must be touched on the main thread
+    window
+        .run_on_main_thread(move || unsafe {
+            let ns_window = &*(ns_window as *const NSWindow);
+            // An empty filename removes the proxy icon
+            ns_window.setRepresentedFilename(&NSString::from_str(
+                file_path.as_deref().unwrap_or_default(),
+            ));
+            ns_window.setDocumentEdited(is_dirty);
+        })
+        .map_err(|e| format!("Failed to update window document: {e}"))
+}
+
+#[cfg(not(target_os = "macos"))]
+fn set_represented_document(
+    _window: &WebviewWindow,
+    _file_path: Option<String>,
+    _is_dirty: bool,
+) -> Result<(), String> {
+    Ok(())
+}
+
+/// Sets the main window's title and represented document for the open entry
+///
+/// # Arguments
+/// * `title` - The entry's title; falls back to the app name when empty or `None`
+/// * `file_path` - Absolute path of the open file, or `None` when no file is open
+/// * `is_dirty` - Whether the file has unsaved changes
+#[tauri::command]
+#[specta::specta]
+pub async fn set_window_document(
+    app_handle: tauri::AppHandle,
+    title: Option<String>,
+    file_path: Option<String>,
+    is_dirty: bool,
+) -> Result<(), String> {
+    if let Ok(mut current) = CURRENT_DOCUMENT.lock() {
+        current.clone_from(&file_path);
+    }
+
+    let Some(window) = app_handle.get_webview_window("main") else {
+        log::debug!("Main window not available");
+        return Ok(());
+    };
+
+    let is_dirty = is_dirty && file_path.is_some();
+    window
+        .set_title(&window_title(title.as_deref(), is_dirty))
+        .map_err(|e| format!("Failed to set window title: {e}"))?;
+
+    set_represented_document(&window, file_path, is_dirty)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_title() {
+        assert_eq!(window_title(None, false), "Astro Editor");
+        assert_eq!(window_title(Some("  "), false), "Astro Editor");
+        assert_eq!(window_title(Some("Hello World"), false), "Hello World");
+
+        let dirty = window_title(Some("Hello World"), true);
+        if cfg!(target_os = "macos") {
+            assert_eq!(dirty, "Hello World");
+        } else {
+            assert_eq!(dirty, "\u{2022} Hello World");
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! Webview zoom
+//!
+//! Zoom is applied natively with `WebviewWindow::set_zoom` rather than CSS, so the
+//! editor, sidebars and native scrollbars all scale together. Each project keeps its
+//! own level in `zoom.json` in app data; projects at 100% have no entry.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Manager, WebviewWindow};
+
+const MIN_ZOOM: f64 = 0.5;
+const MAX_ZOOM: f64 = 3.0;
+const DEFAULT_ZOOM: f64 = 1.0;
+
+/// Saved zoom levels, keyed by project path
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct ZoomLevels {
+    #[serde(default)]
+    projects: BTreeMap<String, f64>,
+}
+
+/// Keeps a level within the supported range, rounded to whole percentages
+fn clamp_zoom(level: f64) -> f64 {
+    if !level.is_finite() {
+        return DEFAULT_ZOOM;
+    }
+    (level.clamp(MIN_ZOOM, MAX_ZOOM) * 100.0).round() / 100.0
+}
+
+fn zoom_file_path(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .resolve("zoom.json", BaseDirectory::AppLocalData)
+        .map_err(|e| format!("Failed to resolve zoom file path: {e}"))
+}
+
+fn load_zoom_levels(path: &Path) -> ZoomLevels {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_zoom_levels(path: &Path, levels: &ZoomLevels) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create zoom directory: {e}"))?;
+    }
+    let content = serde_json::to_string_pretty(levels)
+        .map_err(|e| format!("Failed to serialize zoom levels: {e}"))?;
+    std::fs::write(path, content).map_err(|e| format!("Failed to write zoom levels: {e}"))
+}
+
+/// Records a project's level, dropping the entry when it's back at 100%
+fn remember_zoom(levels: &mut ZoomLevels, project_path: &str, level: f64) {
+    if level == DEFAULT_ZOOM {
+        levels.projects.remove(project_path);
+    } else {
+        levels.projects.insert(project_path.to_string(), level);
+    }
+}
+
+/// Gets the saved zoom level for a project
+///
+/// # Arguments
+/// * `project_path` - The project to look up, or `None` when no project is open
+///
+/// # Returns
+/// The saved level, or 1.0 if the project has none
+#[tauri::command]
+#[specta::specta]
+pub async fn get_zoom_level(app: AppHandle, project_path: Option<String>) -> Result<f64, String> {
+    let Some(project_path) = project_path else {
+        return Ok(DEFAULT_ZOOM);
+    };
+    let levels = load_zoom_levels(&zoom_file_path(&app)?);
+    Ok(levels
+        .projects
+        .get(&project_path)
+        .copied()
+        .map(clamp_zoom)
+        .unwrap_or(DEFAULT_ZOOM))
+}
+
+/// Zooms the window's webview and saves the level for the project
+///
+/// # Arguments
+/// * `project_path` - The open project, or `None` to zoom without saving
+/// * `level` - The zoom factor, where 1.0 is 100% (clamped to 0.5–3.0)
+///
+/// # Returns
+/// The level that was applied
+#[tauri::command]
+#[specta::specta]
+pub async fn set_zoom_level(
+    app: AppHandle,
+    window: WebviewWindow,
+    project_path: Option<String>,
+    level: f64,
+) -> Result<f64, String> {
+    let level = clamp_zoom(level);
+    window
+        .set_zoom(level)
+        .map_err(|e| format!("Failed to set zoom level: {e}"))?;
+
+    if let Some(project_path) = project_path {
+        let path = zoom_file_path(&app)?;
+        let mut levels = load_zoom_levels(&path);
+        remember_zoom(&mut levels, &project_path, level);
+        save_zoom_levels(&path, &levels)?;
+    }
+
+    Ok(level)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_clamp_zoom() {
+        assert_eq!(clamp_zoom(1.0), 1.0);
+        assert_eq!(clamp_zoom(0.1), MIN_ZOOM);
+        assert_eq!(clamp_zoom(10.0), MAX_ZOOM);
+        assert_eq!(clamp_zoom(1.104), 1.1);
+        assert_eq!(clamp_zoom(f64::NAN), DEFAULT_ZOOM);
+        assert_eq!(clamp_zoom(f64::INFINITY), DEFAULT_ZOOM);
+    }
+
+    #[test]
+    fn test_zoom_levels_round_trip_through_disk() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("app").join("zoom.json");
+        assert_eq!(load_zoom_levels(&path), ZoomLevels::default());
+
+        let mut levels = ZoomLevels::default();
+        remember_zoom(&mut levels, "/Users/me/blog", 1.25);
+        remember_zoom(&mut levels, "/Users/me/site", 0.9);
+        save_zoom_levels(&path, &levels).unwrap();
+        assert_eq!(load_zoom_levels(&path), levels);
+
+        // Resetting to 100% removes the project's entry
+        remember_zoom(&mut levels, "/Users/me/site", DEFAULT_ZOOM);
+        assert_eq!(levels.projects.len(), 1);
+        assert!(!levels.projects.contains_key("/Users/me/site"));
+    }
+}
@@ -0,0 +1,742 @@
+//! Formatting-preserving edits to `content.config.ts`
+//!
+//! Rather than regenerating the config from an AST (which would lose comments and the
+//! user's formatting), each edit locates its target with the parser's brace-matching and
+//! splices text into that span only. Edits can be previewed as a unified diff before
+//! they're written.
+
+use crate::parser::find_matching_closing_brace;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::path::{Path, PathBuf};
+
+/// Config file created when a project doesn't have one yet
+pub const NEW_CONFIG_PATH: &str = "src/content.config.ts";
+
+/// Content directory used when no override is configured
+pub const DEFAULT_CONTENT_DIR: &str = "src/content";
+
+/// Quote and semicolon conventions detected from an existing config file
+struct CodeStyle {
+    quote: char,
+    semicolons: bool,
+}
+
+impl CodeStyle {
+    /// Infers style from the first import statement, defaulting to Astro's own templates
+    fn detect(content: &str) -> Self {
+        let import_re = Regex::new(r#"(?m)^import\s.*?from\s+(['"])[^'"]+['"](;?)"#).unwrap();
+
+        match import_re.captures(content) {
+            Some(cap) => Self {
+                quote: cap[1].chars().next().unwrap_or('\''),
+                semicolons: !cap[2].is_empty(),
+            },
+            None => Self {
+                quote: '\'',
+                semicolons: true,
+            },
+        }
+    }
+
+    fn end(&self) -> &'static str {
+        if self.semicolons {
+            ";"
+        } else {
+            ""
+        }
+    }
+}
+
+/// Returns the existing content config path, if any (new format first, then old format)
+pub fn find_config_path(project_root: &Path) -> Option<PathBuf> {
+    [
+        project_root.join("src").join("content.config.ts"),
+        project_root.join("src").join("content").join("config.ts"),
+    ]
+    .into_iter()
+    .find(|p| p.exists())
+}
+
+/// Checks that a name is a plain JavaScript identifier (safe to use as a key or variable)
+pub fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Checks whether a collection with this name is already defined in the config
+fn collection_exists(content: &str, name: &str) -> bool {
+    let pattern = format!(r"\b{name}\s*[=:]\s*defineCollection\s*\(");
+    Regex::new(&pattern)
+        .map(|re| re.is_match(content))
+        .unwrap_or(false)
+}
+
+/// Builds the starter `defineCollection` block for a new collection
+fn build_collection_definition(name: &str, content_dir: &str, style: &CodeStyle) -> String {
+    let q = style.quote;
+    let end = style.end();
+    let base = format!("./{}/{name}", content_dir.trim_matches('/'));
+
+    format!(
+        "const {name} = defineCollection({{\n  \
+           loader: glob({{ pattern: {q}**/[^_]*.{{md,mdx}}{q}, base: {q}{base}{q} }}),\n  \
+           schema: z.object({{\n    \
+             title: z.string(),\n    \
+             description: z.string().optional(),\n    \
+             pubDate: z.coerce.date(),\n    \
+             draft: z.boolean().default(false),\n  \
+           }}),\n\
+         }}){end}\n"
+    )
+}
+
+/// Adds any imports the starter definition relies on that the config is missing
+fn ensure_imports(content: &str, style: &CodeStyle) -> String {
+    let q = style.quote;
+    let end = style.end();
+    let mut missing = Vec::new();
+
+    let imports_name = |name: &str| {
+        Regex::new(&format!(
+            r"import\s*(?:type\s*)?\{{[^}}]*\b{name}\b[^}}]*\}}"
+        ))
+        .map(|re| re.is_match(content))
+        .unwrap_or(false)
+    };
+
+    if !imports_name("defineCollection") {
+        missing.push(format!(
+            "import {{ defineCollection }} from {q}astro:content{q}{end}"
+        ));
+    }
+    if !imports_name("glob") {
+        missing.push(format!("import {{ glob }} from {q}astro/loaders{q}{end}"));
+    }
+    if !imports_name("z") {
+        missing.push(format!("import {{ z }} from {q}astro/zod{q}{end}"));
+    }
+
+    if missing.is_empty() {
+        return content.to_string();
+    }
+
+    // Insert after the last top-level import statement (or at the top of the file)
+    let import_end_re =
+        Regex::new(r#"(?m)^import[\s\S]*?from\s+['"][^'"]+['"];?[ \t]*\n"#).unwrap();
+    let insert_at = import_end_re
+        .find_iter(content)
+        .last()
+        .map(|m| m.end())
+        .unwrap_or(0);
+
+    let mut imports = missing.join("\n");
+    imports.push('\n');
+    if insert_at == 0 {
+        imports.push('\n');
+    }
+
+    format!(
+        "{}{imports}{}",
+        &content[..insert_at],
+        &content[insert_at..]
+    )
+}
+
+/// Inserts a new collection into existing config content
+///
+/// The definition is placed directly above `export const collections = { ... }` and the
+/// name is appended to that object, matching its single-line or multi-line layout.
+/// Everything outside the edited spans is left untouched.
+pub fn insert_collection(content: &str, name: &str, content_dir: &str) -> Result<String, String> {
+    if collection_exists(content, name) {
+        return Err(format!("Collection '{name}' already exists"));
+    }
+
+    let style = CodeStyle::detect(content);
+    let content = ensure_imports(content, &style);
+
+    let export_re = Regex::new(r"(?m)^export\s+const\s+collections\s*=\s*\{").unwrap();
+    let export_match = export_re
+        .find(&content)
+        .ok_or("Could not find `export const collections` in content config")?;
+
+    let open_brace = export_match.end() - 1;
+    let close_brace = find_matching_closing_brace(&content, open_brace, '{', '}')? - 1;
+
+    // Add the name to the exported collections object
+    let inner = &content[open_brace + 1..close_brace];
+    let last_entry_end = open_brace + 1 + inner.trim_end().len();
+    let has_entries = !inner.trim().is_empty();
+    let has_trailing_comma = inner.trim_end().ends_with(',');
+
+    let entry_insert = if inner.contains('\n') && has_entries {
+        let last_line_start = content[..last_entry_end].rfind('\n').map_or(0, |i| i + 1);
+        let indent: String = content[last_line_start..]
+            .chars()
+            .take_while(|c| *c == ' ' || *c == '\t')
+            .collect();
+        if has_trailing_comma {
+            format!("\n{indent}{name},")
+        } else {
+            format!(",\n{indent}{name}")
+        }
+    } else if has_entries {
+        if has_trailing_comma {
+            format!(" {name}")
+        } else {
+            format!(", {name}")
+        }
+    } else {
+        format!(" {name} ")
+    };
+
+    let insert_pos = if has_entries {
+        last_entry_end
+    } else {
+        open_brace + 1
+    };
+    let mut updated = String::with_capacity(content.len() + 512);
+    updated.push_str(&content[..export_match.start()]);
+    updated.push_str(&build_collection_definition(name, content_dir, &style));
+    updated.push('\n');
+    updated.push_str(&content[export_match.start()..insert_pos]);
+    updated.push_str(&entry_insert);
+    updated.push_str(&content[insert_pos..]);
+
+    Ok(updated)
+}
+
+/// Builds a complete config file for projects that don't have one yet
+pub fn build_new_config(name: &str, content_dir: &str) -> String {
+    let style = CodeStyle::detect("");
+    format!(
+        "{}\n\n{}\nexport const collections = {{ {name} }};\n",
+        ensure_imports("", &style).trim_end(),
+        build_collection_definition(name, content_dir, &style)
+    )
+}
+
+/// Zod types that can be added to a schema from the UI
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum SchemaFieldType {
+    String,
+    Number,
+    Boolean,
+    Date,
+    Image,
+    StringArray,
+}
+
+impl SchemaFieldType {
+    fn zod_expression(self) -> &'static str {
+        match self {
+            Self::String => "z.string()",
+            Self::Number => "z.number()",
+            Self::Boolean => "z.boolean()",
+            Self::Date => "z.coerce.date()",
+            Self::Image => "image()",
+            Self::StringArray => "z.array(z.string())",
+        }
+    }
+}
+
+/// A new field to add to a collection's `z.object({...})` schema
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct NewSchemaField {
+    pub name: String,
+    pub field_type: SchemaFieldType,
+    pub optional: bool,
+    pub default_value: Option<Value>,
+}
+
+/// Renders a JSON default value as a JavaScript literal
+fn js_literal(value: &Value, quote: char) -> Result<String, String> {
+    match value {
+        Value::String(s) => {
+            let escaped = s
+                .replace('\\', "\\\\")
+                .replace(quote, &format!("\\{quote}"));
+            Ok(format!("{quote}{escaped}{quote}"))
+        }
+        Value::Number(n) => Ok(n.to_string()),
+        Value::Bool(b) => Ok(b.to_string()),
+        Value::Array(items) => {
+            let items = items
+                .iter()
+                .map(|v| js_literal(v, quote))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(format!("[{}]", items.join(", ")))
+        }
+        _ => Err("Default values must be strings, numbers, booleans or arrays".to_string()),
+    }
+}
+
+/// Builds the field definition line (without indentation), e.g. `rating: z.number().optional(),`
+fn build_field_definition(field: &NewSchemaField, quote: char) -> Result<String, String> {
+    let mut expression = field.field_type.zod_expression().to_string();
+
+    // A default already makes the field optional on input, so it takes precedence
+    if let Some(default) = &field.default_value {
+        expression.push_str(&format!(".default({})", js_literal(default, quote)?));
+    } else if field.optional {
+        expression.push_str(".optional()");
+    }
+
+    Ok(format!("{}: {expression},", field.name))
+}
+
+/// Inserts a field into a collection's top-level `z.object({...})` schema
+///
+/// The new field is added after the last existing field using its indentation. Everything
+/// outside the inserted line is left untouched.
+pub fn insert_schema_field(
+    content: &str,
+    collection_name: &str,
+    field: &NewSchemaField,
+) -> Result<String, String> {
+    let definition_re = Regex::new(&format!(
+        r"\b{collection_name}\s*[=:]\s*defineCollection\s*\("
+    ))
+    .map_err(|e| format!("Invalid collection name: {e}"))?;
+    let definition = definition_re
+        .find(content)
+        .ok_or_else(|| format!("Collection '{collection_name}' not found in content config"))?;
+
+    let open_paren = definition.end() - 1;
+    let close_paren = find_matching_closing_brace(content, open_paren, '(', ')')?;
+    let block = &content[open_paren..close_paren];
+
+    let schema_re = Regex::new(r"schema\s*:[\s\S]*?z\.object\s*\(\s*\{").unwrap();
+    let schema_match = schema_re
+        .find(block)
+        .ok_or_else(|| format!("Collection '{collection_name}' has no z.object schema"))?;
+
+    let object_open = open_paren + schema_match.end() - 1;
+    let object_close = find_matching_closing_brace(content, object_open, '{', '}')? - 1;
+    let inner = &content[object_open + 1..object_close];
+
+    // Reject duplicates among top-level keys
+    let key_re = Regex::new(&format!(r"(?m)^\s*{}\s*:", regex::escape(&field.name))).unwrap();
+    if key_re.is_match(inner) {
+        return Err(format!(
+            "Field '{}' already exists in '{collection_name}'",
+            field.name
+        ));
+    }
+
+    if field.field_type == SchemaFieldType::Image
+        && !Regex::new(r"schema\s*:\s*\(\s*\{[^}]*\bimage\b")
+            .unwrap()
+            .is_match(block)
+    {
+        return Err(
+            "Image fields require the schema to be declared as `schema: ({ image }) => z.object(...)`"
+                .to_string(),
+        );
+    }
+
+    let style = CodeStyle::detect(content);
+    let line = build_field_definition(field, style.quote)?;
+
+    let last_entry_end = object_open + 1 + inner.trim_end().len();
+    let object_line_start = content[..object_open].rfind('\n').map_or(0, |i| i + 1);
+    let object_indent: String = content[object_line_start..]
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect();
+
+    let (insert_pos, insertion) = if inner.trim().is_empty() {
+        // Empty object: expand it onto multiple lines
+        (
+            object_open + 1,
+            format!("\n{object_indent}  {line}\n{object_indent}"),
+        )
+    } else {
+        let last_line_start = content[..last_entry_end].rfind('\n').map_or(0, |i| i + 1);
+        let indent = if last_line_start > object_open {
+            content[last_line_start..]
+                .chars()
+                .take_while(|c| *c == ' ' || *c == '\t')
+                .collect()
+        } else {
+            format!("{object_indent}  ")
+        };
+        let separator = if inner.trim_end().ends_with(',') {
+            ""
+        } else {
+            ","
+        };
+        (last_entry_end, format!("{separator}\n{indent}{line}"))
+    };
+
+    Ok(format!(
+        "{}{insertion}{}",
+        &content[..insert_pos],
+        &content[insert_pos..]
+    ))
+}
+
+/// Renames a collection's key in `export const collections = { ... }`
+///
+/// Shorthand entries (`{ blog }`) become `{ posts: blog }` so the variable and its loader
+/// are untouched. `reference('blog')` calls elsewhere in the file are updated to match,
+/// since they refer to the collection key rather than the variable.
+pub fn rename_collection_key(content: &str, from: &str, to: &str) -> Result<String, String> {
+    let export_re = Regex::new(r"(?m)^export\s+const\s+collections\s*=\s*\{").unwrap();
+    let export_match = export_re
+        .find(content)
+        .ok_or("Could not find `export const collections` in content config")?;
+
+    let open_brace = export_match.end() - 1;
+    let close_brace = find_matching_closing_brace(content, open_brace, '{', '}')? - 1;
+    let inner = &content[open_brace + 1..close_brace];
+
+    // Find top-level keys, skipping anything nested inside inline definitions
+    let key_re = Regex::new(r"\b([A-Za-z_][A-Za-z0-9_]*)\b(\s*:)?").unwrap();
+    let top_level_keys: Vec<_> = key_re
+        .captures_iter(inner)
+        .filter(|cap| {
+            let start = cap.get(0).unwrap().start();
+            let depth = inner[..start].chars().fold(0i32, |depth, c| match c {
+                '{' | '(' | '[' => depth + 1,
+                '}' | ')' | ']' => depth - 1,
+                _ => depth,
+            });
+            let preceding = inner[..start].trim_end();
+            depth == 0 && (preceding.is_empty() || preceding.ends_with(','))
+        })
+        .collect();
+
+    if top_level_keys.iter().any(|cap| &cap[1] == to) {
+        return Err(format!("Collection '{to}' already exists"));
+    }
+
+    let key = top_level_keys
+        .iter()
+        .find(|cap| &cap[1] == from)
+        .ok_or_else(|| format!("Collection '{from}' not found in content config"))?;
+
+    let key_match = key.get(1).unwrap();
+    let replacement = if key.get(2).is_some() {
+        to.to_string()
+    } else {
+        format!("{to}: {from}")
+    };
+
+    let key_start = open_brace + 1 + key_match.start();
+    let key_end = open_brace + 1 + key_match.end();
+    let updated = format!(
+        "{}{replacement}{}",
+        &content[..key_start],
+        &content[key_end..]
+    );
+
+    let reference_re = Regex::new(&format!(r#"reference\(\s*(['"]){from}(['"])\s*\)"#)).unwrap();
+    Ok(reference_re
+        .replace_all(&updated, format!("reference(${{1}}{to}${{2}})"))
+        .into_owned())
+}
+
+/// A targeted edit to the content config
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ConfigEdit {
+    /// Add a new collection with a starter schema
+    InsertCollection {
+        name: String,
+        #[serde(rename = "contentDirectory")]
+        content_directory: Option<String>,
+    },
+    /// Add a field to a collection's schema
+    InsertField {
+        collection: String,
+        field: NewSchemaField,
+    },
+    /// Rename a collection's key in the exported collections object
+    RenameCollection { from: String, to: String },
+}
+
+/// Rejects names that aren't plain identifiers before they reach any regex or the config
+fn validate_edit(edit: &ConfigEdit) -> Result<(), String> {
+    let (collections, field) = match edit {
+        ConfigEdit::InsertCollection { name, .. } => (vec![name], None),
+        ConfigEdit::InsertField { collection, field } => (vec![collection], Some(&field.name)),
+        ConfigEdit::RenameCollection { from, to } => (vec![from, to], None),
+    };
+
+    if let Some(name) = collections.into_iter().find(|n| !is_valid_identifier(n)) {
+        return Err(format!(
+            "Invalid collection name '{name}': use letters, numbers and underscores"
+        ));
+    }
+    if let Some(name) = field.filter(|n| !is_valid_identifier(n)) {
+        return Err(format!(
+            "Invalid field name '{name}': use letters, numbers and underscores"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Applies an edit to the current config content
+///
+/// `content` is `None` when the project has no config yet; only inserting a collection
+/// is possible in that case, which creates a complete config file.
+pub fn apply_edit(content: Option<&str>, edit: &ConfigEdit) -> Result<String, String> {
+    validate_edit(edit)?;
+
+    match (content, edit) {
+        (
+            Some(content),
+            ConfigEdit::InsertCollection {
+                name,
+                content_directory,
+            },
+        ) => insert_collection(
+            content,
+            name,
+            content_directory.as_deref().unwrap_or(DEFAULT_CONTENT_DIR),
+        ),
+        (
+            None,
+            ConfigEdit::InsertCollection {
+                name,
+                content_directory,
+            },
+        ) => Ok(build_new_config(
+            name,
+            content_directory.as_deref().unwrap_or(DEFAULT_CONTENT_DIR),
+        )),
+        (Some(content), ConfigEdit::InsertField { collection, field }) => {
+            insert_schema_field(content, collection, field)
+        }
+        (Some(content), ConfigEdit::RenameCollection { from, to }) => {
+            rename_collection_key(content, from, to)
+        }
+        (None, _) => Err("No content config file found".to_string()),
+    }
+}
+
+/// Renders a unified diff between the original and edited config
+pub fn unified_diff(original: &str, updated: &str, file_label: &str) -> String {
+    similar::TextDiff::from_lines(original, updated)
+        .unified_diff()
+        .context_radius(3)
+        .header(file_label, file_label)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"import { defineCollection } from 'astro:content';
+import { glob } from 'astro/loaders';
+import { z } from 'astro/zod';
+
+// Articles
+const articles = defineCollection({
+  loader: glob({ pattern: '**/[^_]*.{md,mdx}', base: './src/content/articles' }),
+  schema: z.object({
+    title: z.string(),
+  }),
+});
+
+export const collections = { articles };
+"#;
+
+    #[test]
+    fn test_insert_collection_single_line_export() {
+        let result = insert_collection(CONFIG, "notes", "src/content").unwrap();
+
+        assert!(result.contains("export const collections = { articles, notes };"));
+        assert!(result.contains("const notes = defineCollection({"));
+        assert!(result.contains("base: './src/content/notes'"));
+        // Existing content is untouched
+        assert!(result.starts_with(&CONFIG[..CONFIG.find("export const").unwrap()]));
+        assert!(result.contains("// Articles"));
+    }
+
+    #[test]
+    fn test_insert_collection_multi_line_export() {
+        let config = CONFIG.replace(
+            "export const collections = { articles };",
+            "export const collections = {\n  articles,\n};",
+        );
+        let result = insert_collection(&config, "notes", "src/content").unwrap();
+
+        assert!(result.contains("export const collections = {\n  articles,\n  notes,\n};"));
+    }
+
+    #[test]
+    fn test_insert_collection_adds_missing_imports_in_file_style() {
+        let config =
+            "import { defineCollection } from \"astro:content\"\n\nexport const collections = {}\n";
+        let result = insert_collection(config, "blog", "src/content").unwrap();
+
+        assert!(result.contains("import { glob } from \"astro/loaders\"\n"));
+        assert!(result.contains("import { z } from \"astro/zod\"\n"));
+        assert!(result.contains("export const collections = { blog }"));
+        assert!(result.contains("})\n"));
+        assert!(!result.contains("});"));
+    }
+
+    #[test]
+    fn test_insert_collection_rejects_duplicates() {
+        let result = insert_collection(CONFIG, "articles", "src/content");
+        assert!(result.unwrap_err().contains("already exists"));
+    }
+
+    #[test]
+    fn test_is_valid_identifier() {
+        assert!(is_valid_identifier("blog"));
+        assert!(is_valid_identifier("blog_posts2"));
+        assert!(!is_valid_identifier("2blog"));
+        assert!(!is_valid_identifier("blog-posts"));
+        assert!(!is_valid_identifier("../etc"));
+        assert!(!is_valid_identifier(""));
+    }
+
+    fn field(name: &str, field_type: SchemaFieldType) -> NewSchemaField {
+        NewSchemaField {
+            name: name.to_string(),
+            field_type,
+            optional: false,
+            default_value: None,
+        }
+    }
+
+    #[test]
+    fn test_insert_schema_field_appends_after_last_field() {
+        let mut new_field = field("subtitle", SchemaFieldType::String);
+        new_field.optional = true;
+        let result = insert_schema_field(CONFIG, "articles", &new_field).unwrap();
+
+        assert!(
+            result.contains("    title: z.string(),\n    subtitle: z.string().optional(),\n  }),")
+        );
+    }
+
+    #[test]
+    fn test_insert_schema_field_with_default_and_missing_comma() {
+        let config = CONFIG.replace("title: z.string(),", "title: z.string()");
+        let mut new_field = field("draft", SchemaFieldType::Boolean);
+        new_field.default_value = Some(Value::Bool(false));
+        let result = insert_schema_field(&config, "articles", &new_field).unwrap();
+
+        assert!(result.contains("title: z.string(),\n    draft: z.boolean().default(false),"));
+    }
+
+    #[test]
+    fn test_insert_schema_field_string_default_is_quoted() {
+        let mut new_field = field("category", SchemaFieldType::String);
+        new_field.default_value = Some(Value::String("it's".to_string()));
+        let result = insert_schema_field(CONFIG, "articles", &new_field).unwrap();
+
+        assert!(result.contains(r"category: z.string().default('it\'s'),"));
+    }
+
+    #[test]
+    fn test_insert_schema_field_rejects_duplicates_and_unknown_collections() {
+        let duplicate =
+            insert_schema_field(CONFIG, "articles", &field("title", SchemaFieldType::String));
+        assert!(duplicate.unwrap_err().contains("already exists"));
+
+        let missing =
+            insert_schema_field(CONFIG, "notes", &field("title", SchemaFieldType::String));
+        assert!(missing.unwrap_err().contains("not found"));
+    }
+
+    #[test]
+    fn test_insert_schema_field_image_requires_helper() {
+        let result =
+            insert_schema_field(CONFIG, "articles", &field("cover", SchemaFieldType::Image));
+        assert!(result.is_err());
+
+        let config = CONFIG.replace(
+            "schema: z.object({",
+            "schema: ({ image }) =>\n    z.object({",
+        );
+        let result =
+            insert_schema_field(&config, "articles", &field("cover", SchemaFieldType::Image))
+                .unwrap();
+        assert!(result.contains("cover: image(),"));
+    }
+
+    #[test]
+    fn test_rename_collection_key_shorthand() {
+        let config = CONFIG.replace(
+            "title: z.string(),",
+            "title: z.string(),\n    related: reference('articles'),",
+        );
+        let result = rename_collection_key(&config, "articles", "posts").unwrap();
+
+        assert!(result.contains("export const collections = { posts: articles };"));
+        assert!(result.contains("related: reference('posts'),"));
+        assert!(result.contains("const articles = defineCollection({"));
+    }
+
+    #[test]
+    fn test_rename_collection_key_explicit() {
+        let config = CONFIG.replace(
+            "export const collections = { articles };",
+            "export const collections = {\n  articles: articles,\n  notes,\n};",
+        );
+        let result = rename_collection_key(&config, "articles", "posts").unwrap();
+        assert!(result.contains("  posts: articles,\n  notes,\n"));
+
+        let conflict = rename_collection_key(&config, "articles", "notes");
+        assert!(conflict.unwrap_err().contains("already exists"));
+    }
+
+    #[test]
+    fn test_apply_edit_without_config_only_allows_new_collections() {
+        let created = apply_edit(
+            None,
+            &ConfigEdit::InsertCollection {
+                name: "blog".to_string(),
+                content_directory: None,
+            },
+        )
+        .unwrap();
+        assert!(created.contains("export const collections = { blog };"));
+
+        let rename = apply_edit(
+            None,
+            &ConfigEdit::RenameCollection {
+                from: "blog".to_string(),
+                to: "posts".to_string(),
+            },
+        );
+        assert!(rename.is_err());
+    }
+
+    #[test]
+    fn test_unified_diff_only_shows_edited_lines() {
+        let updated = insert_schema_field(
+            CONFIG,
+            "articles",
+            &field("subtitle", SchemaFieldType::String),
+        )
+        .unwrap();
+        let diff = unified_diff(CONFIG, &updated, "src/content.config.ts");
+
+        assert!(diff.starts_with("--- src/content.config.ts\n+++ src/content.config.ts\n"));
+        assert!(diff.contains("+    subtitle: z.string(),\n"));
+        assert_eq!(
+            diff.lines()
+                .filter(|l| l.starts_with('+') && !l.starts_with("+++"))
+                .count(),
+            1
+        );
+        assert!(!diff
+            .lines()
+            .any(|l| l.starts_with('-') && !l.starts_with("---")));
+    }
+}
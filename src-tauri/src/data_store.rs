@@ -0,0 +1,464 @@
+//! Reader for Astro's content layer data store
+//!
+//! Astro 5 loaders write every collection's entries to `data-store.json`, serialized
+//! with devalue: a flat JSON array where index 0 is the root and objects, arrays, Maps
+//! and Sets refer to other values by index. The root is a `Map` of collection name to a
+//! `Map` of entry ID to entry (`{ id, data, body, filePath, digest, ... }`).
+//!
+//! Entry data is what Astro will actually build with: the loader's output after the
+//! schema ran, so defaults are filled in. Listings use it for files that haven't
+//! changed since the store was written and parse the files themselves otherwise.
+
+use chrono::NaiveDate;
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Where Astro writes the data store: `.astro/` during `astro dev`, its cache directory
+/// (`node_modules/.astro/` by default) for `astro sync` and builds
+const DATA_STORE_PATHS: [&str; 2] = [
+    ".astro/data-store.json",
+    "node_modules/.astro/data-store.json",
+];
+
+/// Astro prefixes `image()` paths in the store so it can resolve them at build time
+const IMAGE_PREFIX: &str = "__ASTRO_IMAGE_";
+
+// devalue's encodings for values JSON can't hold
+const UNDEFINED: i64 = -1;
+const NEGATIVE_ZERO: i64 = -6;
+
+/// A slot in a devalue array; objects are kept in order so entry data keeps the
+/// field order the loader produced
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Slot {
+    Object(IndexMap<String, i64>),
+    Array(Vec<Value>),
+    Value(Value),
+}
+
+/// A parsed devalue document
+struct Devalue {
+    slots: Vec<Slot>,
+}
+
+impl Devalue {
+    fn parse(text: &str) -> Result<Self, String> {
+        let slots =
+            serde_json::from_str(text).map_err(|e| format!("Failed to parse data store: {e}"))?;
+        Ok(Self { slots })
+    }
+
+    fn slot(&self, index: i64) -> Option<&Slot> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| self.slots.get(index))
+    }
+
+    /// Key and value indices of the `Map` at `index`
+    fn map_entries(&self, index: i64) -> Vec<(i64, i64)> {
+        match self.slot(index) {
+            Some(Slot::Array(items)) if items.first().and_then(Value::as_str) == Some("Map") => {
+                items[1..]
+                    .chunks_exact(2)
+                    .filter_map(|pair| Some((pair[0].as_i64()?, pair[1].as_i64()?)))
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// The fields of the object at `index`, resolved
+    fn object(&self, index: i64) -> Option<IndexMap<String, Value>> {
+        match self.slot(index) {
+            Some(Slot::Object(fields)) => Some(
+                fields
+                    .iter()
+                    .filter(|(_, field)| **field != UNDEFINED)
+                    .map(|(key, field)| (key.clone(), self.value(*field)))
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    fn string(&self, index: i64) -> Option<String> {
+        match self.value(index) {
+            Value::String(text) => Some(text),
+            Value::Null => None,
+            other => Some(other.to_string()),
+        }
+    }
+
+    /// Resolves the value at `index` to JSON: Dates and URLs become strings, Maps
+    /// become objects and Sets become arrays
+    fn value(&self, index: i64) -> Value {
+        self.resolve(index, &mut Vec::new())
+    }
+
+    fn resolve(&self, index: i64, stack: &mut Vec<i64>) -> Value {
+        if index == NEGATIVE_ZERO {
+            return Value::from(0);
+        }
+        // Cycles can't be represented in JSON
+        if stack.contains(&index) {
+            return Value::Null;
+        }
+        // Negative indices are undefined, holes, NaN and the infinities
+        let Some(slot) = self.slot(index) else {
+            return Value::Null;
+        };
+
+        stack.push(index);
+        let value = match slot {
+            Slot::Object(fields) => Value::Object(
+                fields
+                    .iter()
+                    .filter(|(_, field)| **field != UNDEFINED)
+                    .map(|(key, field)| (key.clone(), self.resolve(*field, stack)))
+                    .collect(),
+            ),
+            Slot::Array(items) => match items.first() {
+                Some(Value::String(kind)) => self.resolve_special(kind, &items[1..], stack),
+                _ => Value::Array(
+                    items
+                        .iter()
+                        .map(|item| {
+                            item.as_i64()
+                                .map_or(Value::Null, |item| self.resolve(item, stack))
+                        })
+                        .collect(),
+                ),
+            },
+            Slot::Value(value) => value.clone(),
+        };
+        stack.pop();
+        value
+    }
+
+    /// Resolves a typed value, e.g. `["Date", "2024-01-01T00:00:00.000Z"]`
+    fn resolve_special(&self, kind: &str, args: &[Value], stack: &mut Vec<i64>) -> Value {
+        let key = |key: Value| match key {
+            Value::String(key) => key,
+            other => other.to_string(),
+        };
+        match kind {
+            // Stored inline rather than by index
+            "Date" | "URL" | "BigInt" | "Object" => args.first().cloned().unwrap_or(Value::Null),
+            "RegExp" => Value::String(format!(
+                "/{}/{}",
+                args.first().and_then(Value::as_str).unwrap_or_default(),
+                args.get(1).and_then(Value::as_str).unwrap_or_default()
+            )),
+            "Set" => Value::Array(
+                args.iter()
+                    .filter_map(Value::as_i64)
+                    .map(|item| self.resolve(item, stack))
+                    .collect(),
+            ),
+            "Map" => Value::Object(
+                args.chunks_exact(2)
+                    .filter_map(|pair| Some((pair[0].as_i64()?, pair[1].as_i64()?)))
+                    .map(|(k, v)| (key(self.resolve(k, stack)), self.resolve(v, stack)))
+                    .collect(),
+            ),
+            // Null-prototype object: inline keys, values by index
+            "null" => Value::Object(
+                args.chunks_exact(2)
+                    .filter_map(|pair| Some((key(pair[0].clone()), pair[1].as_i64()?)))
+                    .map(|(k, v)| (k, self.resolve(v, stack)))
+                    .collect(),
+            ),
+            // Typed arrays and anything newer have no useful JSON form
+            _ => Value::Null,
+        }
+    }
+}
+
+/// An entry as the content layer resolved it
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DataStoreEntry {
+    pub id: String,
+    /// The entry's data after the loader (and schema) ran
+    pub data: IndexMap<String, Value>,
+    /// Source file relative to the project root, for entries loaded from files
+    pub file_path: Option<String>,
+    /// Astro's hash of the entry's source, which changes whenever the source does
+    pub digest: Option<String>,
+    /// The entry's Markdown body, for loaders that keep it
+    pub body: Option<String>,
+}
+
+/// Puts stored values back in the shape frontmatter has: `image()` paths without
+/// Astro's prefix, references as their entry ID and dates without a midnight time
+fn normalize(value: Value) -> Value {
+    match value {
+        Value::String(text) => {
+            if let Some(path) = text.strip_prefix(IMAGE_PREFIX) {
+                return Value::String(path.to_string());
+            }
+            match text.strip_suffix("T00:00:00.000Z") {
+                Some(date) if NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok() => {
+                    Value::String(date.to_string())
+                }
+                _ => Value::String(text),
+            }
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(normalize).collect()),
+        Value::Object(fields) => match (fields.len(), fields.get("collection"), fields.get("id")) {
+            (2, Some(Value::String(_)), Some(Value::String(id))) => Value::String(id.clone()),
+            _ => Value::Object(
+                fields
+                    .into_iter()
+                    .map(|(key, value)| (key, normalize(value)))
+                    .collect(),
+            ),
+        },
+        other => other,
+    }
+}
+
+/// The most recently written data store in the project, if Astro has written one
+pub(crate) fn find_data_store(project_root: &Path) -> Option<PathBuf> {
+    DATA_STORE_PATHS
+        .iter()
+        .map(|relative| project_root.join(relative))
+        .filter_map(|path| {
+            let modified = std::fs::metadata(&path).ok()?.modified().ok()?;
+            Some((modified, path))
+        })
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path)
+}
+
+/// Entries for `collection` from a data store's contents; `None` if the store has no
+/// such collection
+fn collection_entries(
+    store: &str,
+    collection: &str,
+) -> Result<Option<Vec<DataStoreEntry>>, String> {
+    let devalue = Devalue::parse(store)?;
+    let Some((_, entries)) = devalue
+        .map_entries(0)
+        .into_iter()
+        .find(|(name, _)| devalue.string(*name).as_deref() == Some(collection))
+    else {
+        return Ok(None);
+    };
+
+    let entries = devalue
+        .map_entries(entries)
+        .into_iter()
+        .filter_map(|(id, entry)| {
+            let fields = match devalue.slot(entry) {
+                Some(Slot::Object(fields)) => fields,
+                _ => return None,
+            };
+            let id = fields
+                .get("id")
+                .and_then(|id| devalue.string(*id))
+                .or_else(|| devalue.string(id))?;
+            let data = fields
+                .get("data")
+                .and_then(|data| devalue.object(*data))
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(key, value)| (key, normalize(value)))
+                .collect();
+            let field = |name: &str| fields.get(name).and_then(|value| devalue.string(*value));
+            Some(DataStoreEntry {
+                id,
+                data,
+                file_path: field("filePath"),
+                digest: field("digest"),
+                body: field("body"),
+            })
+        })
+        .collect();
+    Ok(Some(entries))
+}
+
+/// Reads a collection's entries from the project's data store
+///
+/// # Returns
+/// `None` if there is no data store yet or it has no entries for the collection
+pub(crate) fn read_collection(
+    project_root: &Path,
+    collection: &str,
+) -> Result<Option<Vec<DataStoreEntry>>, String> {
+    let Some(store_path) = find_data_store(project_root) else {
+        return Ok(None);
+    };
+    let store = std::fs::read_to_string(&store_path)
+        .map_err(|e| format!("Failed to read data store: {e}"))?;
+    collection_entries(&store, collection)
+}
+
+/// A collection's file-backed entries from the data store, keyed by source path
+pub(crate) struct StoredEntries {
+    written: SystemTime,
+    entries: HashMap<PathBuf, DataStoreEntry>,
+}
+
+impl StoredEntries {
+    /// Loads the entries stored for `collection` from the data store of the nearest
+    /// project above `collection_root`; `None` if there is no usable store
+    pub(crate) fn load(collection_root: &Path, collection: &str) -> Option<Self> {
+        let (project_root, store_path) = collection_root
+            .ancestors()
+            .find_map(|dir| Some((dir, find_data_store(dir)?)))?;
+        let written = std::fs::metadata(&store_path).ok()?.modified().ok()?;
+        let store = std::fs::read_to_string(&store_path).ok()?;
+        let entries = match collection_entries(&store, collection) {
+            Ok(entries) => entries?,
+            Err(e) => {
+                log::debug!("Astro Editor [DATA_STORE] Ignoring data store: {e}");
+                return None;
+            }
+        };
+
+        let entries = entries
+            .into_iter()
+            .filter_map(|entry| {
+                let path = entry
+                    .file_path
+                    .as_deref()?
+                    .split('/')
+                    .fold(project_root.to_path_buf(), |path, segment| {
+                        path.join(segment)
+                    });
+                Some((path, entry))
+            })
+            .collect();
+        Some(Self { written, entries })
+    }
+
+    /// The stored entry for a file, unless the file changed after the store was written
+    pub(crate) fn current(&self, path: &Path) -> Option<&DataStoreEntry> {
+        let entry = self.entries.get(path)?;
+        let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+        (modified <= self.written).then_some(entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    // Map { products => Map { "kettle" => { id, data: { title, price, releasedAt,
+    // tags: Set, discontinued: undefined }, digest } }, posts => Map {} }
+    const STORE: &str = r#"[
+        ["Map", 1, 2, 12, 13],
+        "products",
+        ["Map", 3, 4],
+        "kettle",
+        {"id": 3, "data": 5, "digest": 11},
+        {"title": 6, "price": 7, "releasedAt": 8, "tags": 9, "discontinued": -1},
+        "Kettle",
+        24.5,
+        ["Date", "2024-03-01T00:00:00.000Z"],
+        ["Set", 10],
+        "kitchen",
+        "d41d8cd9",
+        "posts",
+        ["Map"]
+    ]"#;
+
+    #[test]
+    fn test_collection_entries_decodes_devalue() {
+        let entries = collection_entries(STORE, "products").unwrap().unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].id, "kettle");
+        let data = &entries[0].data;
+        assert_eq!(
+            data.keys().collect::<Vec<_>>(),
+            vec!["title", "price", "releasedAt", "tags"]
+        );
+        assert_eq!(data["title"], "Kettle");
+        assert_eq!(data["price"], 24.5);
+        assert_eq!(data["releasedAt"], "2024-03-01");
+        assert_eq!(data["tags"], json!(["kitchen"]));
+        assert_eq!(entries[0].digest.as_deref(), Some("d41d8cd9"));
+        assert_eq!(entries[0].file_path, None);
+
+        assert_eq!(collection_entries(STORE, "posts").unwrap(), Some(vec![]));
+        assert_eq!(collection_entries(STORE, "missing").unwrap(), None);
+        assert!(collection_entries("not json", "products").is_err());
+    }
+
+    #[test]
+    fn test_resolve_handles_cycles_and_special_numbers() {
+        let devalue = Devalue::parse(r#"[{"self": 0, "zero": -6, "nan": -3}]"#).unwrap();
+        assert_eq!(
+            devalue.value(0),
+            json!({ "self": null, "zero": 0, "nan": null })
+        );
+    }
+
+    #[test]
+    fn test_normalize_restores_frontmatter_shapes() {
+        assert_eq!(
+            normalize(json!({
+                "cover": "__ASTRO_IMAGE_./cover.jpg",
+                "author": { "collection": "authors", "id": "jane" },
+                "updated": "2024-05-06T09:30:00.000Z",
+                "pubDate": "2024-05-06T00:00:00.000Z",
+            })),
+            json!({
+                "cover": "./cover.jpg",
+                "author": "jane",
+                "updated": "2024-05-06T09:30:00.000Z",
+                "pubDate": "2024-05-06",
+            })
+        );
+    }
+
+    #[test]
+    fn test_stored_entries_are_used_until_the_file_changes() {
+        let temp = TempDir::new().unwrap();
+        let collection_root = temp.path().join("src/content/blog");
+        std::fs::create_dir_all(&collection_root).unwrap();
+        std::fs::create_dir_all(temp.path().join(".astro")).unwrap();
+        let post = collection_root.join("hello.md");
+        std::fs::write(&post, "---\ntitle: Hello\n---\n").unwrap();
+        std::fs::write(
+            temp.path().join(".astro/data-store.json"),
+            r#"[["Map",1,2],"blog",["Map",3,4],"hello",{"id":3,"data":5,"filePath":8},
+                {"title":6,"draft":7},"Hello",false,"src/content/blog/hello.md"]"#,
+        )
+        .unwrap();
+
+        assert!(StoredEntries::load(&collection_root, "docs").is_none());
+        let stored = StoredEntries::load(&collection_root, "blog").unwrap();
+        let entry = stored.current(&post).unwrap();
+        assert_eq!(entry.data["draft"], false);
+
+        let later = SystemTime::now() + std::time::Duration::from_secs(60);
+        std::fs::File::options()
+            .write(true)
+            .open(&post)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        assert!(stored.current(&post).is_none());
+    }
+
+    #[test]
+    fn test_read_collection_uses_project_store() {
+        let temp = TempDir::new().unwrap();
+        assert_eq!(read_collection(temp.path(), "products").unwrap(), None);
+
+        std::fs::create_dir_all(temp.path().join(".astro")).unwrap();
+        std::fs::write(temp.path().join(".astro/data-store.json"), STORE).unwrap();
+
+        let entries = read_collection(temp.path(), "products").unwrap().unwrap();
+        assert_eq!(entries[0].id, "kettle");
+    }
+}
@@ -0,0 +1,315 @@
+//! Field-level schema documentation
+//!
+//! Turns merged collection schemas into display-ready docs for a single frontmatter
+//! field: its description, a readable type, and constraints rendered as short sentences.
+//! Paths that traverse a reference field (e.g. `author.bio` where `author` references
+//! the `authors` collection) are resolved against the referenced collection's schema.
+
+use crate::schema_merger::{SchemaDefinition, SchemaField};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+use std::collections::HashMap;
+
+/// Maximum number of reference hops followed, guarding against reference cycles
+const MAX_REFERENCE_DEPTH: usize = 5;
+
+/// Display-ready documentation for a frontmatter field
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldDocs {
+    /// The field path that was requested
+    pub field_path: String,
+    /// Collection whose schema defines the field
+    pub collection_name: String,
+    pub label: String,
+    /// Human-readable type, e.g. "array of string" or "reference to authors"
+    pub type_description: String,
+    pub required: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub markdown_description: Option<String>,
+    /// Constraints as short sentences, e.g. "At most 160 characters"
+    pub constraints: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<String>,
+    /// Collection the field references, for reference fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_collection: Option<String>,
+    /// Set when the docs come from a referenced collection rather than the one requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inherited_from: Option<String>,
+}
+
+/// Looks up docs for `field_path` in `collection_name`
+///
+/// Exact field matches win. Otherwise the longest prefix that is a reference field is
+/// followed into the referenced collection and the remainder of the path resolved there.
+pub fn find_field_docs(
+    schemas: &HashMap<String, SchemaDefinition>,
+    collection_name: &str,
+    field_path: &str,
+) -> Result<FieldDocs, String> {
+    let (field, defined_in) = resolve_field(schemas, collection_name, field_path, 0)?;
+    let mut docs = build_field_docs(field, &defined_in);
+    docs.field_path = field_path.to_string();
+    if defined_in != collection_name {
+        docs.inherited_from = Some(defined_in);
+    }
+    Ok(docs)
+}
+
+fn resolve_field<'a>(
+    schemas: &'a HashMap<String, SchemaDefinition>,
+    collection_name: &str,
+    field_path: &str,
+    depth: usize,
+) -> Result<(&'a SchemaField, String), String> {
+    if depth > MAX_REFERENCE_DEPTH {
+        return Err(format!("Too many reference hops resolving '{field_path}'"));
+    }
+
+    let schema = schemas
+        .get(collection_name)
+        .ok_or_else(|| format!("No schema available for collection '{collection_name}'"))?;
+
+    if let Some(field) = schema.fields.iter().find(|f| f.name == field_path) {
+        return Ok((field, collection_name.to_string()));
+    }
+
+    // Try the longest reference prefix first, e.g. `author` for `author.bio`
+    let segments: Vec<&str> = field_path.split('.').collect();
+    for split in (1..segments.len()).rev() {
+        let prefix = segments[..split].join(".");
+        let Some(target) = schema
+            .fields
+            .iter()
+            .find(|f| f.name == prefix)
+            .and_then(referenced_collection)
+        else {
+            continue;
+        };
+
+        let rest = segments[split..].join(".");
+        return resolve_field(schemas, target, &rest, depth + 1);
+    }
+
+    Err(format!(
+        "Field '{field_path}' not found in collection '{collection_name}'"
+    ))
+}
+
+fn referenced_collection(field: &SchemaField) -> Option<&str> {
+    field
+        .reference_collection
+        .as_deref()
+        .or(field.array_reference_collection.as_deref())
+}
+
+fn build_field_docs(field: &SchemaField, collection_name: &str) -> FieldDocs {
+    FieldDocs {
+        field_path: field.name.clone(),
+        collection_name: collection_name.to_string(),
+        label: field.label.clone(),
+        type_description: describe_type(field),
+        required: field.required,
+        description: field.description.clone(),
+        markdown_description: field.markdown_description.clone(),
+        constraints: describe_constraints(field),
+        default_value: field.default.as_ref().map(|value| match value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        }),
+        reference_collection: referenced_collection(field).map(String::from),
+        inherited_from: None,
+    }
+}
+
+fn describe_type(field: &SchemaField) -> String {
+    if let Some(collection) = &field.array_reference_collection {
+        return format!("array of references to {collection}");
+    }
+    if let Some(collection) = &field.reference_collection {
+        return format!("reference to {collection}");
+    }
+    match (field.field_type.as_str(), &field.sub_type) {
+        ("array", Some(sub_type)) => format!("array of {sub_type}"),
+        (field_type, _) => field_type.to_string(),
+    }
+}
+
+fn describe_constraints(field: &SchemaField) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    if let Some(values) = field.enum_values.as_ref().filter(|v| !v.is_empty()) {
+        lines.push(format!("One of: {}", values.join(", ")));
+    }
+
+    let Some(constraints) = &field.constraints else {
+        return lines;
+    };
+
+    match (constraints.min, constraints.max) {
+        (Some(min), Some(max)) => lines.push(format!("Between {min} and {max}")),
+        (Some(min), None) => lines.push(format!("Minimum {min}")),
+        (None, Some(max)) => lines.push(format!("Maximum {max}")),
+        (None, None) => {}
+    }
+
+    let unit = if field.field_type == "array" {
+        "items"
+    } else {
+        "characters"
+    };
+    match (constraints.min_length, constraints.max_length) {
+        (Some(min), Some(max)) if min == max => lines.push(format!("Exactly {min} {unit}")),
+        (Some(min), Some(max)) => lines.push(format!("Between {min} and {max} {unit}")),
+        (Some(min), None) => lines.push(format!("At least {min} {unit}")),
+        (None, Some(max)) => lines.push(format!("At most {max} {unit}")),
+        (None, None) => {}
+    }
+
+    if let Some(format) = &constraints.format {
+        let name = match format.as_str() {
+            "email" => "email address",
+            "uri" => "URL",
+            "date-time" => "date and time",
+            other => other,
+        };
+        lines.push(format!("Must be a valid {name}"));
+    }
+
+    if let Some(pattern) = &constraints.pattern {
+        lines.push(format!("Must match /{pattern}/"));
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema_merger::FieldConstraints;
+
+    fn field(name: &str, field_type: &str) -> SchemaField {
+        SchemaField {
+            name: name.to_string(),
+            label: name.to_string(),
+            field_type: field_type.to_string(),
+            sub_type: None,
+            required: false,
+            constraints: None,
+            description: None,
+            markdown_description: None,
+            default: None,
+            enum_values: None,
+            reference_collection: None,
+            array_reference_collection: None,
+            is_nested: None,
+            parent_path: None,
+            group: None,
+        }
+    }
+
+    fn schemas() -> HashMap<String, SchemaDefinition> {
+        let mut title = field("title", "string");
+        title.required = true;
+        title.description = Some("The post title".to_string());
+        title.constraints = Some(FieldConstraints {
+            min: None,
+            max: None,
+            min_length: Some(1),
+            max_length: Some(80),
+            pattern: None,
+            format: None,
+        });
+
+        let mut author = field("author", "reference");
+        author.reference_collection = Some("authors".to_string());
+
+        let mut bio = field("bio", "string");
+        bio.markdown_description = Some("Shown on the **author page**".to_string());
+
+        let mut schemas = HashMap::new();
+        schemas.insert(
+            "posts".to_string(),
+            SchemaDefinition {
+                collection_name: "posts".to_string(),
+                fields: vec![title, author],
+                groups: Vec::new(),
+                inferred: false,
+            },
+        );
+        schemas.insert(
+            "authors".to_string(),
+            SchemaDefinition {
+                collection_name: "authors".to_string(),
+                fields: vec![bio],
+                groups: Vec::new(),
+                inferred: false,
+            },
+        );
+        schemas
+    }
+
+    #[test]
+    fn test_find_field_docs_direct_field() {
+        let docs = find_field_docs(&schemas(), "posts", "title").unwrap();
+        assert_eq!(docs.description.as_deref(), Some("The post title"));
+        assert_eq!(docs.type_description, "string");
+        assert!(docs.required);
+        assert_eq!(docs.constraints, vec!["Between 1 and 80 characters"]);
+        assert_eq!(docs.inherited_from, None);
+    }
+
+    #[test]
+    fn test_find_field_docs_through_reference() {
+        let docs = find_field_docs(&schemas(), "posts", "author.bio").unwrap();
+        assert_eq!(docs.field_path, "author.bio");
+        assert_eq!(docs.collection_name, "authors");
+        assert_eq!(docs.inherited_from.as_deref(), Some("authors"));
+        assert_eq!(
+            docs.markdown_description.as_deref(),
+            Some("Shown on the **author page**")
+        );
+    }
+
+    #[test]
+    fn test_find_field_docs_reference_field() {
+        let docs = find_field_docs(&schemas(), "posts", "author").unwrap();
+        assert_eq!(docs.type_description, "reference to authors");
+        assert_eq!(docs.reference_collection.as_deref(), Some("authors"));
+    }
+
+    #[test]
+    fn test_find_field_docs_unknown_field() {
+        let err = find_field_docs(&schemas(), "posts", "missing").unwrap_err();
+        assert!(err.contains("not found"));
+        assert!(find_field_docs(&schemas(), "nope", "title").is_err());
+    }
+
+    #[test]
+    fn test_describe_constraints_numbers_and_formats() {
+        let mut rating = field("rating", "number");
+        rating.constraints = Some(FieldConstraints {
+            min: Some(0.0),
+            max: None,
+            min_length: None,
+            max_length: None,
+            pattern: Some("^\\d+$".to_string()),
+            format: Some("email".to_string()),
+        });
+        rating.enum_values = Some(vec!["a".to_string(), "b".to_string()]);
+
+        assert_eq!(
+            describe_constraints(&rating),
+            vec![
+                "One of: a, b",
+                "Minimum 0",
+                "Must be a valid email address",
+                "Must match /^\\d+$/",
+            ]
+        );
+    }
+}
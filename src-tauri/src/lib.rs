@@ -1,8 +1,13 @@
+mod asset_protocol;
 mod bindings;
 mod commands;
+mod config_editor;
+mod data_store;
+mod field_docs;
 mod models;
 mod parser;
 mod schema_merger;
+mod security;
 mod telemetry;
 mod utils;
 
@@ -27,12 +32,18 @@ use window_vibrancy::{apply_vibrancy, NSVisualEffectMaterial};
 // Store menu item references for later access
 struct MenuState {
     format_items: HashMap<String, MenuItem<tauri::Wry>>,
+    // File menu items that depend on an open file or selected collection
+    context_items: HashMap<String, MenuItem<tauri::Wry>>,
+    // Edit > Frontmatter, rebuilt for the current collection's flag fields
+    frontmatter_menu: Option<Submenu<tauri::Wry>>,
 }
 
 impl MenuState {
     fn new() -> Self {
         Self {
             format_items: HashMap::new(),
+            context_items: HashMap::new(),
+            frontmatter_menu: None,
         }
     }
 }
@@ -91,6 +102,31 @@ pub fn run() {
         .plugin(tauri_plugin_os::init())
         .plugin(tauri_plugin_window_state::Builder::default().build())
         .manage(commands::watcher::init_watcher_state())
+        .manage(commands::watcher::init_watcher_stats())
+        .manage(commands::search::init_search_index())
+        .manage(commands::usage::init_usage_store())
+        .manage(commands::content_tracking::init_content_tracker())
+        .manage(commands::intents::init_intent_queue())
+        .manage(commands::intents::init_capture_server())
+        .manage(commands::print::init_print_page())
+        .manage(commands::stale_drafts::init_digest_schedule())
+        .manage(commands::embargo::init_embargo_watch())
+        .manage(commands::processes::init_process_registry())
+        .manage(commands::updater::init_updater_state())
+        .register_asynchronous_uri_scheme_protocol(
+            asset_protocol::SCHEME,
+            |ctx, request, responder| {
+                // Only serve files from projects that are currently open
+                let project_roots = commands::watcher::watched_project_paths(ctx.app_handle());
+                std::thread::spawn(move || {
+                    responder.respond(asset_protocol::handle_request(&request, &project_roots));
+                });
+            },
+        )
+        .register_uri_scheme_protocol(commands::print::SCHEME, |ctx, request| {
+            let page = ctx.app_handle().state::<commands::print::PrintPage>();
+            commands::print::handle_request(&request, &page)
+        })
         .setup(|app| {
             // Log app startup information
             let package_info = app.package_info();
@@ -109,6 +145,45 @@ pub fn run() {
                 }
             });
 
+            // Record file writes for troubleshooting unexpected changes
+            commands::audit_log::init_audit_log(app.handle());
+
+            // Report SVGs sanitized on import
+            commands::files::init_asset_notifications(app.handle());
+
+            // Keep undo bundles for bulk file operations
+            commands::bulk_undo::init_bulk_undo(app.handle());
+
+            // Deleted files go to the trash; expired ones are purged
+            commands::trash::init_trash(app.handle());
+
+            // Saved files keep a version history; expired versions are purged
+            commands::versions::init_versions(app.handle());
+
+            // Unsaved edits are kept as drafts until the file is saved
+            commands::unsaved_drafts::init_unsaved_drafts(app.handle());
+
+            // The project registry, shared with the frontend's
+            commands::projects::init_projects(app.handle());
+
+            // Content backups on a schedule and before bulk operations, once configured
+            commands::backup::init_backups(app.handle());
+
+            // Users can add Hunspell dictionaries to app data
+            commands::spellcheck::init_spellcheck(app.handle());
+
+            // Scratchpads left empty last session aren't worth keeping
+            commands::scratchpads::clear_empty_scratchpads(app.handle());
+
+            // Weekly stale draft digest, once the frontend enables it
+            commands::stale_drafts::start_digest_task(app.handle().clone());
+
+            // Report entries whose embargo lifts while the project is open
+            commands::embargo::start_embargo_task(app.handle().clone());
+
+            // Queue Shortcuts / x-callback-url intents for the frontend
+            commands::intents::register_intent_handlers(app.handle());
+
             // Fix PATH environment variable for production builds
             // This ensures shell commands can find executables like 'code', 'cursor', etc.
             // if let Err(e) = fix_path_env::fix() {
@@ -118,6 +193,20 @@ pub fn run() {
             // Create menu state
             let mut menu_state = MenuState::new();
 
+            // File items start disabled until the frontend reports an open file/collection
+            let new_file = MenuItem::with_id(app, "new_file", "New File", false, Some("CmdOrCtrl+N"))?;
+            let save = MenuItem::with_id(app, "save", "Save", false, Some("CmdOrCtrl+S"))?;
+            menu_state
+                .context_items
+                .insert("new_file".to_string(), new_file.clone());
+            let print = MenuItem::with_id(app, "print", "Print...", false, None::<&str>)?;
+            menu_state
+                .context_items
+                .insert("save".to_string(), save.clone());
+            menu_state
+                .context_items
+                .insert("print".to_string(), print.clone());
+
             // Create macOS menu bar
             let file_menu = Submenu::with_items(
                 app,
@@ -131,9 +220,11 @@ pub fn run() {
                         true,
                         Some("CmdOrCtrl+Shift+O"),
                     )?,
-                    &MenuItem::with_id(app, "new_file", "New File", true, Some("CmdOrCtrl+N"))?,
+                    &new_file,
                     &PredefinedMenuItem::separator(app)?,
-                    &MenuItem::with_id(app, "save", "Save", true, Some("CmdOrCtrl+S"))?,
+                    &save,
+                    &PredefinedMenuItem::separator(app)?,
+                    &print,
                     &PredefinedMenuItem::separator(app)?,
                     &PredefinedMenuItem::close_window(app, Some("Close"))?,
                 ],
@@ -187,6 +278,10 @@ pub fn run() {
                 .format_items
                 .insert("format_paragraph".to_string(), format_paragraph.clone());
 
+            // Frontmatter flag toggles are filled in per collection by the frontend
+            let frontmatter_menu = Submenu::with_items(app, "Frontmatter", false, &[])?;
+            menu_state.frontmatter_menu = Some(frontmatter_menu.clone());
+
             let edit_menu = Submenu::with_items(
                 app,
                 "Edit",
@@ -211,6 +306,8 @@ pub fn run() {
                     &format_h3,
                     &format_h4,
                     &format_paragraph,
+                    &PredefinedMenuItem::separator(app)?,
+                    &frontmatter_menu,
                 ],
             )?;
 
@@ -234,6 +331,16 @@ pub fn run() {
                         Some("CmdOrCtrl+2"),
                     )?,
                     &PredefinedMenuItem::separator(app)?,
+                    &MenuItem::with_id(app, "zoom_in", "Zoom In", true, Some("CmdOrCtrl+="))?,
+                    &MenuItem::with_id(app, "zoom_out", "Zoom Out", true, Some("CmdOrCtrl+-"))?,
+                    &MenuItem::with_id(
+                        app,
+                        "zoom_reset",
+                        "Actual Size",
+                        true,
+                        Some("CmdOrCtrl+0"),
+                    )?,
+                    &PredefinedMenuItem::separator(app)?,
                     &MenuItem::with_id(
                         app,
                         "enter_fullscreen",
@@ -308,6 +415,9 @@ pub fn run() {
                 }
             }
 
+            // Emit accessibility-prefs-changed when system settings change
+            commands::accessibility::watch_accessibility_prefs(app.handle());
+
             // Handle menu events
             app.on_menu_event(move |app, event| match event.id().as_ref() {
                 "open_project" => {
@@ -319,12 +429,24 @@ pub fn run() {
                 "save" => {
                     let _ = app.emit("menu-save", ());
                 }
+                "print" => {
+                    let _ = app.emit("menu-print", ());
+                }
                 "toggle_sidebar" => {
                     let _ = app.emit("menu-toggle-sidebar", ());
                 }
                 "toggle_frontmatter" => {
                     let _ = app.emit("menu-toggle-frontmatter", ());
                 }
+                "zoom_in" => {
+                    let _ = app.emit("menu-zoom-in", ());
+                }
+                "zoom_out" => {
+                    let _ = app.emit("menu-zoom-out", ());
+                }
+                "zoom_reset" => {
+                    let _ = app.emit("menu-zoom-reset", ());
+                }
                 "enter_fullscreen" => {
                     if let Some(window) = app.get_webview_window("main") {
                         let _ = window.set_fullscreen(true);
@@ -388,6 +510,11 @@ pub fn run() {
                 "format_paragraph" => {
                     let _ = app.emit("menu-format-paragraph", ());
                 }
+                // Frontmatter flag toggles (Edit > Frontmatter)
+                id if id.starts_with(commands::menu::FLAG_MENU_ID_PREFIX) => {
+                    let field = &id[commands::menu::FLAG_MENU_ID_PREFIX.len()..];
+                    let _ = app.emit("menu-toggle-frontmatter-flag", field);
+                }
                 _ => {}
             });
 
@@ -421,8 +548,20 @@ pub fn run() {
                     let _ = window.set_focus();
                 }
             }
+            // Accessibility settings may have changed while the app was in the background
+            tauri::RunEvent::WindowEvent {
+                event: tauri::WindowEvent::ThemeChanged(_) | tauri::WindowEvent::Focused(true),
+                ..
+            } => {
+                commands::accessibility::emit_if_changed(app_handle);
+            }
             tauri::RunEvent::Exit => {
                 log::info!("Application exiting");
+                // Don't leave installs or syncs running after the editor quits
+                commands::processes::terminate_all(
+                    &app_handle.state::<commands::processes::ProcessRegistryState>(),
+                );
+                commands::updater::install_deferred_update(app_handle);
             }
             _ => {}
         });
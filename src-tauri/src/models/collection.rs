@@ -24,6 +24,18 @@ pub struct Collection {
     // Complete merged schema - ONLY this goes to frontend
     #[serde(skip_serializing_if = "Option::is_none")]
     pub complete_schema: Option<String>, // Serialized SchemaDefinition
+
+    /// Defined with a custom loader (a CMS or API) rather than `glob()` or `file()`, so
+    /// entries come from Astro's data store and are read-only
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    #[specta(optional)]
+    pub remote: bool,
+
+    /// Entry globs from a `glob()` loader, relative to `path` (`!`-prefixed ones
+    /// exclude); without them every Markdown file under `path` is an entry
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[specta(optional)]
+    pub pattern: Option<Vec<String>>,
 }
 
 impl Collection {
@@ -34,6 +46,8 @@ impl Collection {
             schema: None,
             json_schema: None,
             complete_schema: None,
+            remote: false,
+            pattern: None,
         }
     }
 
@@ -45,6 +59,16 @@ impl Collection {
             schema: Some(schema),
             json_schema: None,
             complete_schema: None,
+            remote: false,
+            pattern: None,
+        }
+    }
+
+    /// A collection whose entries come from a custom loader
+    pub fn remote(name: String, path: PathBuf) -> Self {
+        Self {
+            remote: true,
+            ..Self::new(name, path)
         }
     }
 
@@ -17,6 +17,22 @@ pub struct FileEntry {
     #[specta(type = Option<f64>)]
     pub last_modified: Option<u64>,
     pub frontmatter: Option<IndexMap<String, Value>>, // Basic frontmatter for display → Record<string, unknown>
+    /// Astro's content digest, when the frontmatter came from its data store
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[specta(optional)]
+    pub digest: Option<String>,
+    /// The title field's value, or the body's first `# ` heading
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[specta(optional)]
+    pub title: Option<String>,
+    /// Prose words in the body, as counted for reading time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[specta(optional)]
+    pub word_count: Option<u32>,
+    /// Whether the draft field is `true`; unset when the frontmatter couldn't be read
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[specta(optional)]
+    pub is_draft: Option<bool>,
 }
 
 impl FileEntry {
@@ -68,6 +84,10 @@ impl FileEntry {
             collection,
             last_modified,
             frontmatter: None, // Will be populated by enhanced scanning
+            digest: None,
+            title: None,
+            word_count: None,
+            is_draft: None,
         }
     }
 
@@ -16,6 +16,7 @@ pub struct PropInfo {
     pub prop_type: String, // e.g., "'warning' | 'info'", "string", "boolean"
     pub is_optional: bool,
     pub default_value: Option<String>, // For optional props with defaults
+    pub description: Option<String>,   // Extracted from the prop's JSDoc comment
 }
 
 #[derive(Serialize, Clone, Debug, Type)]
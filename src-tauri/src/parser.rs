@@ -21,7 +21,7 @@ use std::path::Path;
 /// - Template literals or other JavaScript string features
 ///
 /// The current implementations also don't handle these cases, so we maintain existing behavior.
-fn find_matching_closing_brace(
+pub(crate) fn find_matching_closing_brace(
     content: &str,
     start_pos: usize,
     open_char: char,
@@ -110,6 +110,7 @@ fn parse_collections_from_content(
     if let Some(collections_block) = extract_collections_block(&clean_content) {
         collections.extend(parse_collection_definitions(
             &collections_block,
+            project_path,
             &content_dir,
             &clean_content, // Pass full content for schema extraction
         )?);
@@ -231,8 +232,103 @@ fn is_file_based_collection(full_content: &str, collection_name: &str) -> bool {
     }
 }
 
+/// The arguments of a collection's `defineCollection(...)` call, parentheses included
+fn collection_definition<'a>(full_content: &'a str, collection_name: &str) -> Option<&'a str> {
+    let definition_pattern =
+        format!(r"(?:(?:const|let|var)\s+)?\b{collection_name}\s*[=:]\s*defineCollection\s*\(");
+    let definition = Regex::new(&definition_pattern)
+        .ok()
+        .and_then(|re| re.find(full_content))?;
+    let open_paren = definition.end() - 1;
+    let close_paren = find_matching_closing_brace(full_content, open_paren, '(', ')').ok()?;
+    Some(&full_content[open_paren..close_paren])
+}
+
+/// Detect if a collection uses a custom loader (fetching from a CMS or API) rather than
+/// `glob()` or `file()`; its entries only exist in Astro's data store
+fn is_remote_collection(full_content: &str, collection_name: &str) -> bool {
+    let Some(definition) = collection_definition(full_content, collection_name) else {
+        return false;
+    };
+
+    // `loader: storyblokLoader({...})`, `loader: async () => [...]`, `loader: () => ...`
+    let loader_re = Regex::new(r"\bloader\s*:\s*([A-Za-z_$][\w$]*)?").unwrap();
+    loader_re.captures(definition).is_some_and(|cap| {
+        !matches!(
+            cap.get(1).map(|loader| loader.as_str()),
+            Some("glob" | "file")
+        )
+    })
+}
+
+/// Options of a collection's `glob()` loader
+#[derive(Debug, PartialEq)]
+struct GlobLoader {
+    /// Directory entries are read from, relative to the project root
+    base: Option<String>,
+    /// Entry globs relative to `base`; `!`-prefixed ones exclude
+    patterns: Vec<String>,
+}
+
+/// Finds `loader: glob({ pattern, base })` in a collection's definition
+///
+/// Only string literals are understood; a `base` built with `new URL(...)` is ignored.
+fn glob_loader(full_content: &str, collection_name: &str) -> Option<GlobLoader> {
+    let definition = collection_definition(full_content, collection_name)?;
+    let glob_call = Regex::new(r"\bloader\s*:\s*glob\s*\(")
+        .unwrap()
+        .find(definition)?;
+    let open_paren = glob_call.end() - 1;
+    let close_paren = find_matching_closing_brace(definition, open_paren, '(', ')').ok()?;
+    let options = &definition[open_paren..close_paren];
+
+    let string_re = Regex::new(r#"['"`]([^'"`]*)['"`]"#).unwrap();
+    let base = Regex::new(r#"\bbase\s*:\s*['"`]([^'"`]*)['"`]"#)
+        .unwrap()
+        .captures(options)
+        .map(|cap| cap[1].to_string());
+    // `pattern: '**/*.md'` or `pattern: ['**/*.md', '!drafts/**']`
+    let patterns = Regex::new(r#"\bpattern\s*:\s*(\[[^\]]*\]|['"`][^'"`]*['"`])"#)
+        .unwrap()
+        .captures(options)
+        .map(|cap| {
+            string_re
+                .captures_iter(&cap[1])
+                .map(|s| s[1].to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(GlobLoader { base, patterns })
+}
+
+/// A directory-based collection, at its `glob()` loader's base when it has one and
+/// otherwise at `content_dir/name`; `None` if the directory doesn't exist
+fn directory_collection(
+    full_content: &str,
+    collection_name: &str,
+    project_path: &Path,
+    content_dir: &Path,
+) -> Option<Collection> {
+    let loader = glob_loader(full_content, collection_name);
+    let collection_path = match loader.as_ref().and_then(|l| l.base.as_deref()) {
+        Some(base) => project_path.join(base.trim_start_matches("./")),
+        None => content_dir.join(collection_name),
+    };
+    if !collection_path.is_dir() {
+        return None;
+    }
+
+    let mut collection = Collection::new(collection_name.to_string(), collection_path);
+    collection.pattern = loader
+        .map(|l| l.patterns)
+        .filter(|patterns| !patterns.is_empty());
+    Some(collection)
+}
+
 fn parse_collection_definitions(
     collections_block: &str,
+    project_path: &Path,
     content_dir: &Path,
     full_content: &str,
 ) -> Result<Vec<Collection>, String> {
@@ -257,13 +353,21 @@ fn parse_collection_definitions(
                     continue;
                 }
 
-                // Only include directory-based collections
-                let collection_path = content_dir.join(collection_name);
-
-                if collection_path.exists() && collection_path.is_dir() {
-                    let mut collection =
-                        Collection::new(collection_name.to_string(), collection_path);
+                // Remote collections have no directory; list them read-only
+                if is_remote_collection(full_content, collection_name) {
+                    let mut collection = Collection::remote(
+                        collection_name.to_string(),
+                        content_dir.join(collection_name),
+                    );
+                    collection.schema = extract_basic_schema(full_content, collection_name);
+                    collections.push(collection);
+                    continue;
+                }
 
+                // Only include directory-based collections
+                if let Some(mut collection) =
+                    directory_collection(full_content, collection_name, project_path, content_dir)
+                {
                     if let Some(schema) = extract_basic_schema(full_content, collection_name) {
                         collection.schema = Some(schema);
                     }
@@ -285,12 +389,24 @@ fn parse_collection_definitions(
             continue;
         }
 
-        // Only include directory-based collections
-        let collection_path = content_dir.join(collection_name);
-
-        if collection_path.exists() && collection_path.is_dir() {
-            let mut collection = Collection::new(collection_name.to_string(), collection_path);
+        // Remote collections have no directory; list them read-only
+        if is_remote_collection(full_content, collection_name) {
+            let mut collection = Collection::remote(
+                collection_name.to_string(),
+                content_dir.join(collection_name),
+            );
+            collection.schema = extract_basic_schema(collections_block, collection_name);
+            collections.push(collection);
+            continue;
+        }
 
+        // Only include directory-based collections
+        if let Some(mut collection) = directory_collection(
+            collections_block,
+            collection_name,
+            project_path,
+            content_dir,
+        ) {
             if let Some(schema) = extract_basic_schema(collections_block, collection_name) {
                 collection.schema = Some(schema);
             }
@@ -1548,4 +1664,82 @@ export default defineConfig({
         // Clean up
         fs::remove_dir_all(&temp_dir).ok();
     }
+    #[test]
+    fn test_remote_loader_collections_are_listed_read_only() {
+        let content = r#"
+import { defineCollection, z } from 'astro:content';
+import { glob, file } from 'astro/loaders';
+import { storyblokLoader } from '@storyblok/astro';
+
+const blog = defineCollection({
+  loader: glob({ pattern: '**/*.md', base: './src/content/blog' }),
+});
+const authors = defineCollection({ loader: file('src/data/authors.json') });
+const products = defineCollection({
+  loader: storyblokLoader({ version: 'published' }),
+  schema: z.object({ title: z.string(), cover: image() }),
+});
+const releases = defineCollection({
+  loader: async () => fetch('https://example.com/releases.json').then(r => r.json()),
+});
+
+export const collections = { blog, authors, products, releases };
+"#;
+
+        let temp_dir = std::env::temp_dir().join("test-remote-loaders");
+        let project_path = temp_dir.join("project");
+        fs::create_dir_all(project_path.join("src/content/blog")).unwrap();
+
+        let collections = parse_collections_from_content(content, &project_path, None).unwrap();
+        let summary: Vec<(&str, bool)> = collections
+            .iter()
+            .map(|c| (c.name.as_str(), c.remote))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("blog", false), ("products", true), ("releases", true)]
+        );
+        // Schema helpers are still extracted for remote collections
+        assert!(collections[1].schema.as_deref().unwrap().contains("cover"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_glob_loader_base_and_pattern() {
+        let content = r#"
+import { defineCollection, z } from 'astro:content';
+import { glob } from 'astro/loaders';
+
+const notes = defineCollection({
+  loader: glob({ base: './content/notes', pattern: '**/*.mdx' }),
+});
+const docs = defineCollection({
+  loader: glob({ pattern: ['**/*.md', '!drafts/**'], base: "docs" }),
+});
+const blog = defineCollection({ loader: glob({ pattern: '**/*.md' }) });
+
+export const collections = { notes, docs, blog };
+"#;
+
+        let temp_dir = std::env::temp_dir().join("test-glob-loaders");
+        let project_path = temp_dir.join("project");
+        fs::create_dir_all(project_path.join("content/notes")).unwrap();
+        fs::create_dir_all(project_path.join("docs")).unwrap();
+        fs::create_dir_all(project_path.join("src/content/blog")).unwrap();
+
+        let collections = parse_collections_from_content(content, &project_path, None).unwrap();
+        assert_eq!(collections.len(), 3);
+        assert_eq!(collections[0].path, project_path.join("content/notes"));
+        assert_eq!(collections[0].pattern, Some(vec!["**/*.mdx".to_string()]));
+        assert_eq!(collections[1].path, project_path.join("docs"));
+        assert_eq!(
+            collections[1].pattern,
+            Some(vec!["**/*.md".to_string(), "!drafts/**".to_string()])
+        );
+        // Without a base, the collection lives in the content directory
+        assert_eq!(collections[2].path, project_path.join("src/content/blog"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
 }
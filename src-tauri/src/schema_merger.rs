@@ -1,7 +1,14 @@
+use crate::commands::files::parse_frontmatter_internal;
+use crate::commands::publish_gates::field_value;
+use chrono::{DateTime, NaiveDate};
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashSet;
+use specta::Type;
+use std::collections::{BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use walkdir::WalkDir;
 
 /// Complete schema definition sent to frontend
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -9,6 +16,46 @@ use std::collections::HashSet;
 pub struct SchemaDefinition {
     pub collection_name: String,
     pub fields: Vec<SchemaField>,
+    /// Nested objects, in display order
+    #[serde(default)]
+    pub groups: Vec<SchemaGroup>,
+    /// Guessed from existing entries rather than declared, so not used for validation
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub inferred: bool,
+}
+
+/// A nested object in the schema, shown as a group of fields
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaGroup {
+    /// Dotted path of the object, e.g. `seo.openGraph`
+    pub path: String,
+    pub label: String,
+    /// The enclosing group's path, for objects nested in objects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    /// Position among all groups, from 0
+    pub order: u32,
+    /// Whether the group starts collapsed in the frontmatter panel
+    pub collapsed: bool,
+}
+
+/// The project's `fieldGroups` setting
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldGroupSettings {
+    /// Group paths in display order; groups not listed follow in schema order
+    #[serde(default)]
+    #[specta(optional)]
+    pub order: Option<Vec<String>>,
+    /// Group paths that start collapsed
+    #[serde(default)]
+    #[specta(optional)]
+    pub collapsed: Option<Vec<String>>,
+    /// Start every group collapsed
+    #[serde(default)]
+    #[specta(optional)]
+    pub collapse_all: Option<bool>,
 }
 
 /// Individual field in the schema
@@ -33,6 +80,8 @@ pub struct SchemaField {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub markdown_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub default: Option<Value>,
 
     // Type-specific
@@ -48,6 +97,9 @@ pub struct SchemaField {
     pub is_nested: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parent_path: Option<String>,
+    /// Path of the group the field is shown in (its parent object)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
 }
 
 /// Field constraints
@@ -180,11 +232,22 @@ enum ItemsType {
     Tuple(Vec<JsonSchemaProperty>),
 }
 
-/// Parse and merge schemas from all sources
+/// Parse and merge schemas from all sources, grouping nested fields
 pub fn create_complete_schema(
     collection_name: &str,
     json_schema: Option<&str>,
     zod_schema: Option<&str>,
+    field_groups: Option<&FieldGroupSettings>,
+) -> Result<SchemaDefinition, String> {
+    let mut schema = merge_schema_sources(collection_name, json_schema, zod_schema)?;
+    assign_groups(&mut schema, &field_groups.cloned().unwrap_or_default());
+    Ok(schema)
+}
+
+fn merge_schema_sources(
+    collection_name: &str,
+    json_schema: Option<&str>,
+    zod_schema: Option<&str>,
 ) -> Result<SchemaDefinition, String> {
     log::debug!(
         "[Schema] Creating complete schema for: {} (json: {}, zod: {})",
@@ -328,6 +391,8 @@ fn parse_entry_schema(
     Ok(SchemaDefinition {
         collection_name: collection_name.to_string(),
         fields,
+        groups: Vec::new(),
+        inferred: false,
     })
 }
 
@@ -391,6 +456,7 @@ fn parse_field(
             .description
             .clone()
             .or_else(|| field_schema.markdown_description.clone()),
+        markdown_description: field_schema.markdown_description.clone(),
         default: field_schema.default.clone(),
         enum_values: field_type_info.enum_values,
         reference_collection: field_type_info.reference_collection,
@@ -405,6 +471,7 @@ fn parse_field(
         } else {
             None
         },
+        group: None,
     };
 
     Ok(vec![field])
@@ -1027,12 +1094,14 @@ fn parse_zod_schema(collection_name: &str, zod_schema: &str) -> Result<SchemaDef
                 required: !f.optional,
                 constraints: f.constraints.and_then(|c| parse_zod_constraints(&c)),
                 description: None,
+                markdown_description: None,
                 default: f.default.map(Value::String),
                 enum_values: f.options,
                 reference_collection: f.referenced_collection,
                 array_reference_collection: f.array_reference_collection,
                 is_nested: None,
                 parent_path: None,
+                group: None,
             }
         })
         .collect();
@@ -1040,6 +1109,8 @@ fn parse_zod_schema(collection_name: &str, zod_schema: &str) -> Result<SchemaDef
     Ok(SchemaDefinition {
         collection_name: collection_name.to_string(),
         fields,
+        groups: Vec::new(),
+        inferred: false,
     })
 }
 
@@ -1098,6 +1169,459 @@ fn parse_zod_constraints(constraints: &Value) -> Option<FieldConstraints> {
     }
 }
 
+/// Builds group descriptors for nested objects and sets each field's `group`
+///
+/// Fields group under their parent object (`parent_path`, or the prefix of a dotted
+/// name from Zod-only schemas). Objects nested in objects get a group for every level.
+fn assign_groups(schema: &mut SchemaDefinition, settings: &FieldGroupSettings) {
+    let mut paths: Vec<String> = Vec::new();
+    for field in &mut schema.fields {
+        field.group = field.parent_path.clone().or_else(|| {
+            field
+                .name
+                .rsplit_once('.')
+                .map(|(parent, _)| parent.to_string())
+        });
+        let Some(group) = &field.group else {
+            continue;
+        };
+        // Enclosing groups first, so `seo` comes before `seo.openGraph`
+        for (end, _) in group.match_indices('.').chain([(group.len(), "")]) {
+            let path = &group[..end];
+            if !paths.iter().any(|p| p == path) {
+                paths.push(path.to_string());
+            }
+        }
+    }
+
+    // Groups listed in the setting come first, in its order; the sort is stable
+    let order = settings.order.as_deref().unwrap_or_default();
+    paths.sort_by_key(|path| order.iter().position(|p| p == path).unwrap_or(order.len()));
+
+    let collapsed = settings.collapsed.as_deref().unwrap_or_default();
+    let collapse_all = settings.collapse_all == Some(true);
+    schema.groups = paths
+        .into_iter()
+        .enumerate()
+        .map(|(index, path)| SchemaGroup {
+            label: camel_case_to_title_case(path.rsplit('.').next().unwrap_or(&path)),
+            parent: path.rsplit_once('.').map(|(parent, _)| parent.to_string()),
+            order: index as u32,
+            collapsed: collapse_all || collapsed.contains(&path),
+            path,
+        })
+        .collect();
+}
+
+/// Entries read when inferring a schema, most recently modified first
+const INFERENCE_SAMPLE_SIZE: usize = 50;
+
+/// Fewest values a string field needs before it can be inferred as an enum
+const MIN_ENUM_SAMPLES: usize = 4;
+
+/// Most distinct values an inferred enum can have
+const MAX_INFERRED_ENUM_VALUES: usize = 8;
+
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "webp", "avif", "svg"];
+
+/// Records a frontmatter value, flattening objects into dotted field names
+///
+/// Each field maps to its non-null values, one per entry that has it.
+fn observe(observed: &mut IndexMap<String, Vec<Value>>, path: String, value: &Value) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, nested) in map {
+                observe(observed, format!("{path}.{key}"), nested);
+            }
+        }
+        Value::Null => {
+            observed.entry(path).or_default();
+        }
+        _ => observed.entry(path).or_default().push(value.clone()),
+    }
+}
+
+fn is_date_string(text: &str) -> bool {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d").is_ok()
+        || DateTime::parse_from_rfc3339(text).is_ok()
+}
+
+fn is_image_path(text: &str) -> bool {
+    Path::new(text)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| IMAGE_EXTENSIONS.contains(&e.to_ascii_lowercase().as_str()))
+}
+
+/// Short, recurring values that look like a fixed set of choices
+fn inferred_enum(strings: &[&str]) -> Option<Vec<String>> {
+    let distinct: BTreeSet<&str> = strings.iter().copied().collect();
+    let recurring = distinct.len() * 2 <= strings.len();
+    let short = distinct.iter().all(|v| v.len() <= 40 && !v.contains('\n'));
+    (strings.len() >= MIN_ENUM_SAMPLES
+        && distinct.len() <= MAX_INFERRED_ENUM_VALUES
+        && recurring
+        && short)
+        .then(|| distinct.into_iter().map(str::to_string).collect())
+}
+
+/// The type of a scalar field, or of array items: `None` when values disagree
+fn scalar_type(values: &[&Value]) -> Option<&'static str> {
+    if values.is_empty() {
+        return None;
+    }
+    if values.iter().all(|v| v.is_boolean()) {
+        return Some("boolean");
+    }
+    if values.iter().all(|v| v.is_number()) {
+        return Some("number");
+    }
+    let strings: Vec<&str> = values.iter().filter_map(|v| v.as_str()).collect();
+    if strings.len() != values.len() {
+        return None;
+    }
+    if strings.iter().all(|s| is_date_string(s)) {
+        return Some("date");
+    }
+    if strings.iter().all(|s| is_image_path(s)) {
+        return Some("image");
+    }
+    Some("string")
+}
+
+fn infer_field(
+    name: String,
+    values: Vec<Value>,
+    samples: &[IndexMap<String, Value>],
+) -> SchemaField {
+    let parent_path = name.rsplit_once('.').map(|(parent, _)| parent.to_string());
+    // Required when every entry that has the field's parent object has the field
+    let parent_count = samples
+        .iter()
+        .filter(|fm| {
+            parent_path
+                .as_deref()
+                .is_none_or(|p| field_value(fm, p).is_some())
+        })
+        .count();
+
+    let present = values.len();
+    let values: Vec<&Value> = values.iter().collect();
+    let (field_type, sub_type, enum_values) =
+        if values.iter().all(|v| v.is_array()) && !values.is_empty() {
+            let items: Vec<&Value> = values
+                .iter()
+                .filter_map(|v| v.as_array())
+                .flatten()
+                .collect();
+            ("array", scalar_type(&items).map(str::to_string), None)
+        } else {
+            match scalar_type(&values) {
+                Some("string") => {
+                    let strings: Vec<&str> = values.iter().filter_map(|v| v.as_str()).collect();
+                    match inferred_enum(&strings) {
+                        Some(options) => ("enum", None, Some(options)),
+                        None => ("string", None, None),
+                    }
+                }
+                Some(scalar) => (scalar, None, None),
+                None => ("string", None, None),
+            }
+        };
+
+    SchemaField {
+        label: camel_case_to_title_case(name.rsplit('.').next().unwrap_or(&name)),
+        field_type: field_type.to_string(),
+        sub_type,
+        required: present == parent_count,
+        constraints: None,
+        description: None,
+        markdown_description: None,
+        default: None,
+        enum_values,
+        reference_collection: None,
+        array_reference_collection: None,
+        is_nested: parent_path.as_ref().map(|_| true),
+        parent_path,
+        group: None,
+        name,
+    }
+}
+
+/// Infers fields, types, optionality and enums from entries' frontmatter
+fn infer_schema(collection_name: &str, samples: &[IndexMap<String, Value>]) -> SchemaDefinition {
+    let mut observed = IndexMap::new();
+    for frontmatter in samples {
+        for (key, value) in frontmatter {
+            observe(&mut observed, key.clone(), value);
+        }
+    }
+
+    SchemaDefinition {
+        collection_name: collection_name.to_string(),
+        fields: observed
+            .into_iter()
+            .map(|(name, values)| infer_field(name, values, samples))
+            .collect(),
+        groups: Vec::new(),
+        inferred: true,
+    }
+}
+
+/// Infers a schema from the frontmatter of a collection's existing entries
+///
+/// The fallback for collections with neither a `content.config.ts` schema nor a
+/// generated `.astro/collections/*.schema.json`. Samples the most recently modified
+/// Markdown and MDX files. The schema is marked `inferred`, so it shapes the frontmatter
+/// form but isn't used to validate saves.
+pub fn infer_schema_from_content(
+    collection_name: &str,
+    collection_dir: &Path,
+    field_groups: Option<&FieldGroupSettings>,
+) -> Result<SchemaDefinition, String> {
+    let mut files: Vec<(SystemTime, PathBuf)> = WalkDir::new(collection_dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter(|entry| {
+            matches!(
+                entry.path().extension().and_then(|e| e.to_str()),
+                Some("md" | "mdx")
+            )
+        })
+        .map(|entry| {
+            let modified = entry
+                .metadata()
+                .ok()
+                .and_then(|m| m.modified().ok())
+                .unwrap_or(SystemTime::UNIX_EPOCH);
+            (modified, entry.into_path())
+        })
+        .collect();
+    files.sort_by(|a, b| b.0.cmp(&a.0));
+
+    let samples: Vec<IndexMap<String, Value>> = files
+        .iter()
+        .take(INFERENCE_SAMPLE_SIZE)
+        .filter_map(|(_, path)| std::fs::read_to_string(path).ok())
+        .filter_map(|content| parse_frontmatter_internal(&content).ok())
+        .map(|parsed| parsed.frontmatter)
+        .filter(|frontmatter| !frontmatter.is_empty())
+        .collect();
+    if samples.is_empty() {
+        return Err(format!(
+            "No frontmatter to infer a schema from in {}",
+            collection_dir.display()
+        ));
+    }
+
+    log::debug!(
+        "[Schema] Inferring schema for {collection_name} from {} entries",
+        samples.len()
+    );
+    let mut schema = infer_schema(collection_name, &samples);
+    assign_groups(&mut schema, &field_groups.cloned().unwrap_or_default());
+    Ok(schema)
+}
+
+/// Which rule a frontmatter value breaks
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Type, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ViolationKind {
+    Required,
+    Min,
+    Max,
+    MinLength,
+    MaxLength,
+    Pattern,
+    Format,
+    EnumValue,
+}
+
+/// A frontmatter value that breaks one of its field's constraints
+#[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldViolation {
+    /// Field path; nested fields use dots (`seo.title`)
+    pub field: String,
+    pub kind: ViolationKind,
+    /// Shown under the field, e.g. "Must be at most 60 characters"
+    pub message: String,
+}
+
+fn is_blank(value: Option<&Value>) -> bool {
+    match value {
+        None | Some(Value::Null) => true,
+        Some(Value::String(s)) => s.trim().is_empty(),
+        _ => false,
+    }
+}
+
+fn plural(count: usize, unit: &str) -> String {
+    if count == 1 {
+        format!("{count} {unit}")
+    } else {
+        format!("{count} {unit}s")
+    }
+}
+
+fn is_email(text: &str) -> bool {
+    match text.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !text.chars().any(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
+/// Checks one field's value against its required flag, constraints and enum values
+fn validate_field(field: &SchemaField, value: Option<&Value>) -> Vec<FieldViolation> {
+    let violation = |kind, message: String| FieldViolation {
+        field: field.name.clone(),
+        kind,
+        message,
+    };
+
+    if is_blank(value) {
+        return if field.required {
+            vec![violation(ViolationKind::Required, "Required".to_string())]
+        } else {
+            Vec::new()
+        };
+    }
+    let Some(value) = value else {
+        return Vec::new();
+    };
+
+    let mut violations = Vec::new();
+
+    if let (Some(values), Value::String(text)) = (&field.enum_values, value) {
+        if !values.contains(text) {
+            violations.push(violation(
+                ViolationKind::EnumValue,
+                format!("Must be one of: {}", values.join(", ")),
+            ));
+        }
+    }
+
+    let Some(constraints) = &field.constraints else {
+        return violations;
+    };
+
+    if let Some(number) = value.as_f64() {
+        if let Some(min) = constraints.min.filter(|min| number < *min) {
+            violations.push(violation(
+                ViolationKind::Min,
+                format!("Must be at least {min}"),
+            ));
+        }
+        if let Some(max) = constraints.max.filter(|max| number > *max) {
+            violations.push(violation(
+                ViolationKind::Max,
+                format!("Must be at most {max}"),
+            ));
+        }
+    }
+
+    let length = match value {
+        Value::String(text) => Some((text.chars().count(), "character")),
+        Value::Array(items) => Some((items.len(), "item")),
+        _ => None,
+    };
+    if let Some((length, unit)) = length {
+        if let Some(min) = constraints.min_length.filter(|min| length < *min) {
+            violations.push(violation(
+                ViolationKind::MinLength,
+                format!("Must be at least {}", plural(min, unit)),
+            ));
+        }
+        if let Some(max) = constraints.max_length.filter(|max| length > *max) {
+            violations.push(violation(
+                ViolationKind::MaxLength,
+                format!("Must be at most {}", plural(max, unit)),
+            ));
+        }
+    }
+
+    if let Value::String(text) = value {
+        // Patterns come from JavaScript; ones the regex crate can't compile are skipped
+        if let Some(pattern) = &constraints.pattern {
+            if regex::Regex::new(pattern).is_ok_and(|re| !re.is_match(text)) {
+                violations.push(violation(
+                    ViolationKind::Pattern,
+                    format!("Must match the pattern {pattern}"),
+                ));
+            }
+        }
+        match constraints.format.as_deref() {
+            Some("email") if !is_email(text) => violations.push(violation(
+                ViolationKind::Format,
+                "Must be a valid email address".to_string(),
+            )),
+            Some("uri") if tauri::Url::parse(text).is_err() => violations.push(violation(
+                ViolationKind::Format,
+                "Must be a valid URL".to_string(),
+            )),
+            _ => {}
+        }
+    }
+
+    violations
+}
+
+/// Checks frontmatter against every field in the schema
+///
+/// Fields inside an optional object that's absent aren't required. Inferred schemas
+/// only describe what entries have looked like so far, so nothing violates them.
+pub fn find_violations(
+    schema: &SchemaDefinition,
+    frontmatter: &IndexMap<String, Value>,
+) -> Vec<FieldViolation> {
+    if schema.inferred {
+        return Vec::new();
+    }
+    schema
+        .fields
+        .iter()
+        .filter(|field| match &field.parent_path {
+            Some(parent) => field_value(frontmatter, parent).is_some(),
+            None => true,
+        })
+        .flat_map(|field| validate_field(field, field_value(frontmatter, &field.name)))
+        .collect()
+}
+
+/// Validates frontmatter against its collection's merged schema before it's saved
+///
+/// # Arguments
+/// * `collection_name` - The collection the file belongs to
+/// * `frontmatter` - The frontmatter as it would be saved
+/// * `complete_schema` - The collection's serialized complete schema
+///
+/// # Returns
+/// Every required field, constraint and enum the frontmatter breaks, in schema order
+#[tauri::command]
+#[specta::specta]
+pub async fn validate_frontmatter(
+    collection_name: String,
+    frontmatter: IndexMap<String, Value>,
+    complete_schema: String,
+) -> Result<Vec<FieldViolation>, String> {
+    let schema: SchemaDefinition = serde_json::from_str(&complete_schema)
+        .map_err(|e| format!("Failed to parse schema: {e}"))?;
+    if schema.collection_name != collection_name {
+        return Err(format!(
+            "Schema is for {}, not {collection_name}",
+            schema.collection_name
+        ));
+    }
+    Ok(find_violations(&schema, &frontmatter))
+}
+
 /// Convert camelCase to Title Case
 fn camel_case_to_title_case(s: &str) -> String {
     let mut result = String::new();
@@ -1696,6 +2220,81 @@ mod tests {
         assert!(!email_field.required);
     }
 
+    #[test]
+    fn test_create_complete_schema_groups_nested_fields() {
+        let json_schema = r##"{
+            "type": "object",
+            "properties": {
+                "title": { "type": "string" },
+                "seo": {
+                    "type": "object",
+                    "properties": {
+                        "description": { "type": "string" },
+                        "openGraph": {
+                            "type": "object",
+                            "properties": { "image": { "type": "string" } }
+                        }
+                    }
+                },
+                "author": {
+                    "type": "object",
+                    "properties": { "name": { "type": "string" } }
+                }
+            }
+        }"##;
+
+        let schema = create_complete_schema("posts", Some(json_schema), None, None).unwrap();
+        let groups: Vec<(&str, &str, Option<&str>, u32)> = schema
+            .groups
+            .iter()
+            .map(|g| {
+                (
+                    g.path.as_str(),
+                    g.label.as_str(),
+                    g.parent.as_deref(),
+                    g.order,
+                )
+            })
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                ("seo", "Seo", None, 0),
+                ("seo.openGraph", "Open Graph", Some("seo"), 1),
+                ("author", "Author", None, 2),
+            ]
+        );
+        let group_of = |name: &str| {
+            schema
+                .fields
+                .iter()
+                .find(|f| f.name == name)
+                .and_then(|f| f.group.clone())
+        };
+        assert_eq!(group_of("title"), None);
+        assert_eq!(
+            group_of("seo.openGraph.image").as_deref(),
+            Some("seo.openGraph")
+        );
+
+        let settings = FieldGroupSettings {
+            order: Some(vec!["author".to_string()]),
+            collapsed: Some(vec!["seo.openGraph".to_string()]),
+            collapse_all: None,
+        };
+        let schema =
+            create_complete_schema("posts", Some(json_schema), None, Some(&settings)).unwrap();
+        let groups: Vec<(&str, bool)> = schema
+            .groups
+            .iter()
+            .map(|g| (g.path.as_str(), g.collapsed))
+            .collect();
+        assert_eq!(
+            groups,
+            vec![("author", false), ("seo", false), ("seo.openGraph", true)]
+        );
+    }
+
     #[test]
     fn test_parse_anyof_nullable_array_of_strings() {
         // Test z.array(z.string()).nullish()
@@ -2129,4 +2728,185 @@ mod tests {
     }
 
     // --- END ASTRO 6 FORMAT TESTS ---
+
+    fn validation_schema() -> SchemaDefinition {
+        let json_schema = r##"{
+            "$ref": "#/definitions/posts",
+            "definitions": {
+                "posts": {
+                    "type": "object",
+                    "properties": {
+                        "title": { "type": "string", "minLength": 5, "maxLength": 60 },
+                        "slug": { "type": "string", "pattern": "^[a-z0-9-]+$" },
+                        "rating": { "type": "number", "minimum": 1, "maximum": 5 },
+                        "status": { "type": "string", "enum": ["draft", "published"] },
+                        "contact": { "type": "string", "format": "email" },
+                        "tags": { "type": "array", "items": { "type": "string" }, "maxItems": 2 },
+                        "seo": {
+                            "type": "object",
+                            "properties": { "title": { "type": "string" } },
+                            "required": ["title"]
+                        }
+                    },
+                    "required": ["title", "status"]
+                }
+            }
+        }"##;
+        parse_json_schema("posts", json_schema).unwrap()
+    }
+
+    fn frontmatter(value: Value) -> IndexMap<String, Value> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_valid_frontmatter_has_no_violations() {
+        let schema = validation_schema();
+        let data = frontmatter(serde_json::json!({
+            "title": "A valid title",
+            "slug": "a-valid-title",
+            "rating": 4,
+            "status": "draft",
+            "contact": "me@example.com",
+            "tags": ["one"]
+        }));
+
+        assert!(find_violations(&schema, &data).is_empty());
+    }
+
+    #[test]
+    fn test_violations_are_reported_per_field() {
+        let schema = validation_schema();
+        let data = frontmatter(serde_json::json!({
+            "title": "Hi",
+            "slug": "Not A Slug",
+            "rating": 9,
+            "status": "archived",
+            "contact": "not-an-email",
+            "tags": ["one", "two", "three"],
+            "seo": { "title": "" }
+        }));
+
+        let violations: Vec<_> = find_violations(&schema, &data)
+            .into_iter()
+            .map(|v| (v.field, v.kind))
+            .collect();
+        assert_eq!(
+            violations,
+            vec![
+                ("title".to_string(), ViolationKind::MinLength),
+                ("slug".to_string(), ViolationKind::Pattern),
+                ("rating".to_string(), ViolationKind::Max),
+                ("status".to_string(), ViolationKind::EnumValue),
+                ("contact".to_string(), ViolationKind::Format),
+                ("tags".to_string(), ViolationKind::MaxLength),
+                ("seo.title".to_string(), ViolationKind::Required),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_fields() {
+        let schema = validation_schema();
+        let violations =
+            find_violations(&schema, &frontmatter(serde_json::json!({ "title": "  " })));
+
+        // Blank counts as missing; seo is optional so its required title isn't
+        let fields: Vec<_> = violations.iter().map(|v| v.field.as_str()).collect();
+        assert_eq!(fields, vec!["title", "status"]);
+        assert!(violations.iter().all(|v| v.kind == ViolationKind::Required));
+        assert_eq!(violations[0].message, "Required");
+    }
+
+    #[tokio::test]
+    async fn test_validate_frontmatter_rejects_other_collections() {
+        let schema = serde_json::to_string(&validation_schema()).unwrap();
+        let result = validate_frontmatter(
+            "notes".to_string(),
+            frontmatter(serde_json::json!({ "title": "Hello world" })),
+            schema,
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_infer_schema() {
+        let samples: Vec<_> = [
+            serde_json::json!({
+                "title": "One",
+                "pubDate": "2024-01-05",
+                "status": "draft",
+                "tags": ["a"],
+                "seo": { "image": "/og/one.png" }
+            }),
+            serde_json::json!({
+                "title": "Two",
+                "pubDate": "2024-02-05",
+                "status": "published",
+                "draft": true
+            }),
+            serde_json::json!({
+                "title": "Three",
+                "pubDate": "2024-03-05",
+                "status": "published",
+                "tags": []
+            }),
+            serde_json::json!({
+                "title": "Four",
+                "pubDate": "2024-04-05T10:00:00Z",
+                "status": "draft",
+                "rating": 4
+            }),
+        ]
+        .into_iter()
+        .map(frontmatter)
+        .collect();
+
+        let schema = infer_schema("posts", &samples);
+        assert!(schema.inferred);
+        let field = |name: &str| schema.fields.iter().find(|f| f.name == name).unwrap();
+
+        assert_eq!(field("title").field_type, "string");
+        assert!(field("title").required);
+        assert_eq!(field("pubDate").field_type, "date");
+        assert_eq!(field("status").field_type, "enum");
+        assert_eq!(
+            field("status").enum_values,
+            Some(vec!["draft".to_string(), "published".to_string()])
+        );
+        assert_eq!(field("tags").field_type, "array");
+        assert_eq!(field("tags").sub_type.as_deref(), Some("string"));
+        assert!(!field("tags").required);
+        assert_eq!(field("draft").field_type, "boolean");
+        assert_eq!(field("rating").field_type, "number");
+        // The only entry with seo has seo.image, so it's required within seo
+        assert_eq!(field("seo.image").field_type, "image");
+        assert_eq!(field("seo.image").parent_path.as_deref(), Some("seo"));
+        assert!(field("seo.image").required);
+
+        // Inferred schemas describe entries rather than constrain them
+        assert!(find_violations(&schema, &frontmatter(serde_json::json!({}))).is_empty());
+    }
+
+    #[test]
+    fn test_infer_schema_from_content() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("one.md"),
+            "---\ntitle: One\nseo:\n  description: First\n---\n\nBody",
+        )
+        .unwrap();
+        std::fs::write(temp.path().join("notes.txt"), "---\nignored: true\n---\n").unwrap();
+
+        let schema = infer_schema_from_content("posts", temp.path(), None).unwrap();
+        let names: Vec<_> = schema.fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["title", "seo.description"]);
+        assert_eq!(schema.groups.len(), 1);
+        assert_eq!(schema.groups[0].path, "seo");
+
+        let empty = tempfile::TempDir::new().unwrap();
+        assert!(infer_schema_from_content("posts", empty.path(), None).is_err());
+    }
 }
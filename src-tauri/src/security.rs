@@ -0,0 +1,408 @@
+//! Path and URL access policy shared by every command
+//!
+//! Commands that touch the filesystem resolve untrusted paths through here rather than
+//! checking bounds themselves. A path is canonicalized (so symlinks and `..` can't
+//! escape) and must land inside the root of its scope: the project, the app data
+//! directory, or the project's assets or public directory. Projects can additionally
+//! trust directories outside their root (e.g. a shared media folder) via the
+//! `trustedRoots` project setting, which the frontend registers with
+//! [`set_trusted_roots`]. Denials are logged and carry a [`Denial`] reason that
+//! formats to the error message returned to the frontend.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use tauri::Url;
+
+/// What an untrusted path is being checked against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    Project,
+    AppData,
+    Assets,
+    PublicDir,
+}
+
+impl Scope {
+    fn label(self) -> &'static str {
+        match self {
+            Scope::Project => "project directory",
+            Scope::AppData => "app data directory",
+            Scope::Assets => "assets directory",
+            Scope::PublicDir => "public directory",
+        }
+    }
+}
+
+/// Why an access check failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Denial {
+    /// The path couldn't be resolved (neither it nor its parent exists)
+    InvalidPath,
+    /// The scope's root doesn't exist
+    InvalidRoot(Scope),
+    /// The path resolves outside the scope's root and any trusted roots
+    OutsideScope(Scope),
+    /// The URL's scheme isn't one the app will hand to the system
+    DisallowedScheme(String),
+    /// The URL couldn't be parsed
+    InvalidUrl,
+}
+
+impl fmt::Display for Denial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Denial::InvalidPath => write!(f, "Invalid file path"),
+            Denial::InvalidRoot(Scope::Project) => write!(f, "Invalid project root"),
+            Denial::InvalidRoot(scope) => write!(f, "Invalid {}", scope.label()),
+            Denial::OutsideScope(scope) => write!(f, "File outside {}", scope.label()),
+            Denial::DisallowedScheme(scheme) => write!(f, "URL scheme not allowed: {scheme}"),
+            Denial::InvalidUrl => write!(f, "Invalid URL"),
+        }
+    }
+}
+
+impl From<Denial> for String {
+    fn from(denial: Denial) -> Self {
+        denial.to_string()
+    }
+}
+
+/// URL schemes that may be opened: the browser, mail client and Shortcuts callbacks
+const ALLOWED_URL_SCHEMES: &[&str] = &["http", "https", "mailto", "shortcuts"];
+
+/// Extra roots each project trusts, keyed by canonical project root
+static TRUSTED_ROOTS: OnceLock<Mutex<HashMap<PathBuf, Vec<PathBuf>>>> = OnceLock::new();
+
+fn trusted_roots() -> &'static Mutex<HashMap<PathBuf, Vec<PathBuf>>> {
+    TRUSTED_ROOTS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Canonicalizes `path`; during a dry run, also paths under directories it would create
+fn canonicalize(path: &Path) -> std::io::Result<PathBuf> {
+    path.canonicalize().or_else(|e| {
+        if crate::commands::dry_run::is_active() {
+            resolve_under_existing(path).ok_or(e)
+        } else {
+            Err(e)
+        }
+    })
+}
+
+/// Canonicalizes the nearest existing ancestor of `path` and appends the rest
+///
+/// Gives up on any `..` below the existing ancestor, since it can't be resolved safely.
+fn resolve_under_existing(path: &Path) -> Option<PathBuf> {
+    let mut missing = Vec::new();
+    let mut ancestor = path;
+    loop {
+        if let Ok(base) = ancestor.canonicalize() {
+            return Some(
+                missing
+                    .iter()
+                    .rev()
+                    .fold(base, |base, name| base.join(name)),
+            );
+        }
+        missing.push(ancestor.file_name()?);
+        ancestor = ancestor.parent()?;
+    }
+}
+
+/// Canonicalizes `path`, falling back to its canonical parent for files that don't exist yet
+fn resolve(path: &Path) -> Result<PathBuf, Denial> {
+    canonicalize(path)
+        .or_else(|_| match (path.parent(), path.file_name()) {
+            (Some(parent), Some(filename)) => canonicalize(parent).map(|p| p.join(filename)),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "Invalid file path",
+            )),
+        })
+        .map_err(|_| Denial::InvalidPath)
+}
+
+/// Checks that `path` resolves inside `root`, returning its canonical form
+///
+/// For [`Scope::Project`], directories registered as trusted for the project are
+/// accepted too. Paths that don't exist yet are allowed as long as their parent does.
+pub fn check_path(path: &Path, root: &Path, scope: Scope) -> Result<PathBuf, Denial> {
+    let canonical_path = resolve(path)?;
+    let canonical_root = canonicalize(root).map_err(|_| Denial::InvalidRoot(scope))?;
+
+    if canonical_path.starts_with(&canonical_root) {
+        return Ok(canonical_path);
+    }
+
+    if scope == Scope::Project {
+        let trusted = trusted_roots().lock().unwrap();
+        if trusted
+            .get(&canonical_root)
+            .is_some_and(|roots| roots.iter().any(|r| canonical_path.starts_with(r)))
+        {
+            return Ok(canonical_path);
+        }
+    }
+
+    log::warn!(
+        "Astro Editor [SECURITY] Denied access outside {}: {}",
+        scope.label(),
+        path.display()
+    );
+    Err(Denial::OutsideScope(scope))
+}
+
+/// Validates that a file path is within the project boundaries
+///
+/// This function prevents path traversal attacks by ensuring all file operations
+/// stay within the current project root directory (or one of its trusted roots).
+pub fn validate_project_path(file_path: &str, project_root: &str) -> Result<PathBuf, String> {
+    Ok(check_path(
+        Path::new(file_path),
+        Path::new(project_root),
+        Scope::Project,
+    )?)
+}
+
+/// Validates that a file path is within the app data directory
+///
+/// Relative paths are taken relative to `app_data_dir`. Creates the app data
+/// directory, and the file's parent directory, if they don't exist.
+pub fn validate_app_data_path(file_path: &str, app_data_dir: &str) -> Result<PathBuf, String> {
+    let app_data_dir = Path::new(app_data_dir);
+
+    if !app_data_dir.exists() {
+        log::info!(
+            "Astro Editor [PROJECT_REGISTRY] Creating app data directory: {}",
+            app_data_dir.display()
+        );
+        std::fs::create_dir_all(app_data_dir)
+            .map_err(|e| format!("Failed to create app data directory: {e}"))?;
+        log::info!("Astro Editor [PROJECT_REGISTRY] App data directory created successfully");
+    }
+
+    let file_path = if Path::new(file_path).is_absolute() {
+        Path::new(file_path).to_path_buf()
+    } else {
+        app_data_dir.join(file_path)
+    };
+
+    // Parent directories are only created for paths that can't climb out with `..`
+    let climbs = file_path.components().any(|c| c == Component::ParentDir);
+    if let Some(parent) = file_path.parent().filter(|_| !climbs) {
+        if !parent.as_os_str().is_empty() && !parent.exists() {
+            log::info!(
+                "Astro Editor [PROJECT_REGISTRY] Creating parent directory: {}",
+                parent.display()
+            );
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create parent directory: {e}"))?;
+        }
+    }
+
+    Ok(check_path(&file_path, app_data_dir, Scope::AppData)?)
+}
+
+/// Validates that a path is within the given assets directory of the project
+pub fn validate_assets_path(
+    file_path: &Path,
+    project_root: &Path,
+    assets_dir: &Path,
+) -> Result<PathBuf, String> {
+    check_path(assets_dir, project_root, Scope::Project)?;
+    Ok(check_path(file_path, assets_dir, Scope::Assets)?)
+}
+
+/// Validates that a path is within the project's `public/` directory
+pub fn validate_public_path(file_path: &Path, project_root: &Path) -> Result<PathBuf, String> {
+    Ok(check_path(
+        file_path,
+        &project_root.join("public"),
+        Scope::PublicDir,
+    )?)
+}
+
+/// Whether `file_path` resolves inside `project_root` or one of its trusted roots
+pub fn is_in_project(file_path: &Path, project_root: &Path) -> bool {
+    file_path.exists() && check_path(file_path, project_root, Scope::Project).is_ok()
+}
+
+/// Checks that a URL is safe to hand to the system opener
+pub fn check_external_url(url: &str) -> Result<(), Denial> {
+    let url = Url::parse(url).map_err(|_| Denial::InvalidUrl)?;
+    if !ALLOWED_URL_SCHEMES.contains(&url.scheme()) {
+        log::warn!(
+            "Astro Editor [SECURITY] Denied opening URL with scheme: {}",
+            url.scheme()
+        );
+        return Err(Denial::DisallowedScheme(url.scheme().to_string()));
+    }
+    Ok(())
+}
+
+/// Replaces the directories trusted for a project outside its own root
+///
+/// Roots that don't exist are skipped, so a missing external drive doesn't block
+/// the rest. Returns the canonical roots that were registered.
+pub fn register_trusted_roots(
+    project_root: &Path,
+    roots: &[String],
+) -> Result<Vec<PathBuf>, Denial> {
+    let canonical_root = project_root
+        .canonicalize()
+        .map_err(|_| Denial::InvalidRoot(Scope::Project))?;
+
+    let canonical_roots: Vec<PathBuf> = roots
+        .iter()
+        .filter_map(|root| {
+            let resolved = Path::new(root).canonicalize();
+            if resolved.is_err() {
+                log::warn!("Astro Editor [SECURITY] Skipping missing trusted root: {root}");
+            }
+            resolved.ok()
+        })
+        .collect();
+
+    let mut trusted = trusted_roots().lock().unwrap();
+    if canonical_roots.is_empty() {
+        trusted.remove(&canonical_root);
+    } else {
+        trusted.insert(canonical_root, canonical_roots.clone());
+    }
+    Ok(canonical_roots)
+}
+
+/// Registers the project's `trustedRoots` setting with the access policy
+///
+/// Called whenever the project opens or the setting changes; an empty list
+/// clears any previously trusted roots.
+#[tauri::command]
+#[specta::specta]
+pub async fn set_trusted_roots(project_path: String, roots: Vec<String>) -> Result<(), String> {
+    register_trusted_roots(Path::new(&project_path), &roots)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_check_path_inside_and_outside_project() {
+        let project = TempDir::new().unwrap();
+        let outside = TempDir::new().unwrap();
+        std::fs::write(project.path().join("post.md"), "").unwrap();
+        std::fs::write(outside.path().join("secret.md"), "").unwrap();
+
+        assert!(check_path(
+            &project.path().join("post.md"),
+            project.path(),
+            Scope::Project
+        )
+        .is_ok());
+        assert!(check_path(
+            &project.path().join("new.md"),
+            project.path(),
+            Scope::Project
+        )
+        .is_ok());
+        assert_eq!(
+            check_path(
+                &outside.path().join("secret.md"),
+                project.path(),
+                Scope::Project
+            ),
+            Err(Denial::OutsideScope(Scope::Project))
+        );
+        assert!(check_path(
+            &project.path().join("../../etc/passwd"),
+            project.path(),
+            Scope::Project
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_trusted_roots_extend_project_scope() {
+        let project = TempDir::new().unwrap();
+        let media = TempDir::new().unwrap();
+        let image = media.path().join("photo.jpg");
+        std::fs::write(&image, "").unwrap();
+
+        assert!(
+            validate_project_path(&image.to_string_lossy(), &project.path().to_string_lossy())
+                .is_err()
+        );
+
+        let registered = register_trusted_roots(
+            project.path(),
+            &[
+                media.path().to_string_lossy().to_string(),
+                "/definitely/not/here".to_string(),
+            ],
+        )
+        .unwrap();
+        assert_eq!(registered.len(), 1);
+        assert!(
+            validate_project_path(&image.to_string_lossy(), &project.path().to_string_lossy())
+                .is_ok()
+        );
+
+        // Trusted roots never widen the other scopes
+        assert!(check_path(&image, project.path(), Scope::Assets).is_err());
+
+        register_trusted_roots(project.path(), &[]).unwrap();
+        assert!(!is_in_project(&image, project.path()));
+    }
+
+    #[test]
+    fn test_assets_and_public_scopes() {
+        let project = TempDir::new().unwrap();
+        let assets = project.path().join("src/assets");
+        std::fs::create_dir_all(&assets).unwrap();
+        std::fs::create_dir_all(project.path().join("public")).unwrap();
+
+        assert!(validate_assets_path(&assets.join("a.png"), project.path(), &assets).is_ok());
+        assert_eq!(
+            validate_assets_path(&project.path().join("a.png"), project.path(), &assets),
+            Err("File outside assets directory".to_string())
+        );
+        assert!(
+            validate_public_path(&project.path().join("public/favicon.svg"), project.path())
+                .is_ok()
+        );
+        assert!(validate_public_path(&assets.join("a.png"), project.path()).is_err());
+    }
+
+    #[test]
+    fn test_denial_messages() {
+        assert_eq!(
+            Denial::OutsideScope(Scope::Project).to_string(),
+            "File outside project directory"
+        );
+        assert_eq!(
+            Denial::OutsideScope(Scope::AppData).to_string(),
+            "File outside app data directory"
+        );
+        assert_eq!(
+            Denial::InvalidRoot(Scope::Project).to_string(),
+            "Invalid project root"
+        );
+    }
+
+    #[test]
+    fn test_check_external_url() {
+        assert!(check_external_url("https://example.com/post").is_ok());
+        assert!(check_external_url("mailto:me@example.com").is_ok());
+        assert!(check_external_url("HTTP://example.com").is_ok());
+        assert!(check_external_url("shortcuts://x-callback-url/done").is_ok());
+        assert_eq!(
+            check_external_url("file:///etc/passwd"),
+            Err(Denial::DisallowedScheme("file".to_string()))
+        );
+        assert!(check_external_url("javascript:alert(1)").is_err());
+        assert_eq!(check_external_url("not a url"), Err(Denial::InvalidUrl));
+    }
+}
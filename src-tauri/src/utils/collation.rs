@@ -0,0 +1,157 @@
+//! Ordering for file names and titles in listings.
+//!
+//! Plain string comparison puts `post-10` before `post-2`, `Zebra` before `apple` and
+//! `Émile` after `Zoë`. The collations here compare case- and accent-insensitively
+//! (accents only break ties), and the default also compares embedded numbers by value.
+//! Every collation falls back to code point order, so equal-looking names still sort
+//! the same way on every scan.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::cmp::Ordering;
+
+/// How titles and file names are ordered in listings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum Collation {
+    /// Embedded numbers compare by value (`post-2` before `post-10`), ignoring case
+    /// and accents
+    #[default]
+    Natural,
+    /// Character by character, ignoring case and accents
+    Alphabetical,
+    /// Unicode code point order
+    Ordinal,
+}
+
+/// The base letter of an accented Latin letter, for accent-insensitive comparison
+fn base_letter(c: char) -> char {
+    match c {
+        'à'..='å' | 'ā' | 'ă' | 'ą' => 'a',
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'ď' | 'đ' => 'd',
+        'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+        'ĥ' | 'ħ' => 'h',
+        'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+        'ĵ' => 'j',
+        'ķ' => 'k',
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => 'l',
+        'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+        'ŕ' | 'ŗ' | 'ř' => 'r',
+        'ś' | 'ŝ' | 'ş' | 'š' => 's',
+        'ţ' | 'ť' | 'ŧ' => 't',
+        'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'ŵ' => 'w',
+        'ý' | 'ÿ' | 'ŷ' => 'y',
+        'ź' | 'ż' | 'ž' => 'z',
+        other => other,
+    }
+}
+
+/// Lowercases and strips accents, expanding ligatures (`ß` → `ss`)
+fn fold(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.chars().flat_map(char::to_lowercase) {
+        match c {
+            'ß' => folded.push_str("ss"),
+            'æ' => folded.push_str("ae"),
+            'œ' => folded.push_str("oe"),
+            other => folded.push(base_letter(other)),
+        }
+    }
+    folded
+}
+
+/// Compares names so that embedded numbers sort numerically, ignoring ASCII case
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a.chars().peekable(), b.chars().peekable());
+    loop {
+        match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let take_number = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+                    let mut digits = String::new();
+                    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                        digits.push(c);
+                    }
+                    digits.trim_start_matches('0').to_string()
+                };
+                let (x, y) = (take_number(&mut a), take_number(&mut b));
+                let ordering = x.len().cmp(&y.len()).then_with(|| x.cmp(&y));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(x), Some(y)) => {
+                let ordering = x.to_ascii_lowercase().cmp(&y.to_ascii_lowercase());
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                a.next();
+                b.next();
+            }
+        }
+    }
+}
+
+/// Compares two strings under `collation`; only identical strings are equal
+pub fn compare(a: &str, b: &str, collation: Collation) -> Ordering {
+    let ordering = match collation {
+        Collation::Natural => natural_cmp(&fold(a), &fold(b)),
+        Collation::Alphabetical => fold(a).cmp(&fold(b)),
+        Collation::Ordinal => Ordering::Equal,
+    };
+    ordering.then_with(|| a.cmp(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(names: &[&'static str], collation: Collation) -> Vec<&'static str> {
+        let mut names = names.to_vec();
+        names.sort_by(|a, b| compare(a, b, collation));
+        names
+    }
+
+    #[test]
+    fn test_natural_orders_numbers_by_value() {
+        assert_eq!(
+            sorted(
+                &["post-10", "Post-2", "post-1", "post-02b"],
+                Collation::Natural
+            ),
+            vec!["post-1", "Post-2", "post-02b", "post-10"]
+        );
+        assert_eq!(
+            sorted(&["post-10", "post-2"], Collation::Alphabetical),
+            vec!["post-10", "post-2"]
+        );
+    }
+
+    #[test]
+    fn test_accents_and_case_are_ignored() {
+        let names = ["Zoë", "émile", "Eve", "apple", "Straße"];
+        assert_eq!(
+            sorted(&names, Collation::Alphabetical),
+            vec!["apple", "émile", "Eve", "Straße", "Zoë"]
+        );
+        assert_eq!(
+            sorted(&names, Collation::Ordinal),
+            vec!["Eve", "Straße", "Zoë", "apple", "émile"]
+        );
+        // Accents only break ties
+        assert_eq!(
+            compare("resume", "résumé", Collation::Natural),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare("Résumé", "résumé", Collation::Natural),
+            Ordering::Less
+        );
+    }
+}
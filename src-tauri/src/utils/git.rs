@@ -0,0 +1,32 @@
+//! Running the `git` command line in a project.
+//!
+//! Git integration shells out rather than linking libgit2, so it behaves exactly as the
+//! user's own git does (config, hooks, credential helpers, worktrees).
+
+use std::path::Path;
+use std::process::Command;
+
+/// Runs git in `dir`, returning stdout, or git's error message when it fails
+pub fn run(dir: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run git: {e}"))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+/// Like `run`, for queries where failure just means there's no answer
+pub fn query(dir: &Path, args: &[&str]) -> Option<String> {
+    run(dir, args).ok()
+}
+
+/// True if `dir` is inside a git working tree
+pub fn is_repository(dir: &Path) -> bool {
+    query(dir, &["rev-parse", "--is-inside-work-tree"]).is_some_and(|out| out.trim() == "true")
+}
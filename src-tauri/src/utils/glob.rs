@@ -0,0 +1,148 @@
+//! Matching entry paths against the patterns of an Astro `glob()` loader.
+//!
+//! Supports the subset of glob syntax content configs use in practice: `*`, `?`,
+//! `**` (any number of directories), `{a,b}` alternatives and `[...]` classes.
+//! Patterns starting with `!` exclude.
+
+use regex::Regex;
+use std::path::Path;
+
+/// A collection's compiled `pattern` globs
+#[derive(Debug, Default)]
+pub struct EntryPatterns {
+    include: Vec<Regex>,
+    exclude: Vec<Regex>,
+}
+
+impl EntryPatterns {
+    /// Compiles `patterns`, skipping any that can't be translated
+    pub fn new(patterns: &[String]) -> Self {
+        let mut compiled = Self::default();
+        for pattern in patterns {
+            let (negated, glob) = match pattern.strip_prefix('!') {
+                Some(glob) => (true, glob),
+                None => (false, pattern.as_str()),
+            };
+            let Some(regex) = glob_to_regex(glob) else {
+                continue;
+            };
+            if negated {
+                compiled.exclude.push(regex);
+            } else {
+                compiled.include.push(regex);
+            }
+        }
+        compiled
+    }
+
+    /// Whether a path relative to the collection root is an entry; with only
+    /// exclusions, everything else matches
+    pub fn matches(&self, relative: &Path) -> bool {
+        let relative = super::path::normalize_path_for_serialization(relative);
+        let included =
+            self.include.is_empty() || self.include.iter().any(|re| re.is_match(&relative));
+        included && !self.exclude.iter().any(|re| re.is_match(&relative))
+    }
+}
+
+/// Translates a glob into an anchored regex
+fn glob_to_regex(glob: &str) -> Option<Regex> {
+    let glob = glob.trim().trim_start_matches("./");
+    let chars: Vec<char> = glob.chars().collect();
+    let mut regex = String::from("^");
+    let mut brace_depth = 0;
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                let at_segment_start = i == 0 || chars[i - 1] == '/';
+                if at_segment_start && chars.get(i + 2) == Some(&'/') {
+                    // `**/` matches zero or more whole directories
+                    regex.push_str("(?:[^/]*/)*");
+                    i += 3;
+                    continue;
+                }
+                regex.push_str(".*");
+                i += 2;
+                continue;
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            '{' => {
+                brace_depth += 1;
+                regex.push_str("(?:");
+            }
+            '}' if brace_depth > 0 => {
+                brace_depth -= 1;
+                regex.push(')');
+            }
+            ',' if brace_depth > 0 => regex.push('|'),
+            '[' => {
+                let close = chars[i + 1..].iter().position(|&c| c == ']')? + i + 1;
+                let class: String = chars[i + 1..close].iter().collect();
+                let class = match class.strip_prefix('!') {
+                    Some(rest) => format!("^{rest}"),
+                    None => class,
+                };
+                regex.push('[');
+                regex.push_str(&class.replace('\\', "\\\\"));
+                regex.push(']');
+                i = close + 1;
+                continue;
+            }
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+        i += 1;
+    }
+
+    if brace_depth > 0 {
+        return None;
+    }
+    regex.push('$');
+    Regex::new(&regex).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patterns(globs: &[&str]) -> EntryPatterns {
+        EntryPatterns::new(&globs.iter().map(|g| g.to_string()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_double_star_matches_any_depth() {
+        let patterns = patterns(&["**/*.mdx"]);
+        assert!(patterns.matches(Path::new("post.mdx")));
+        assert!(patterns.matches(Path::new("2024/january/post.mdx")));
+        assert!(!patterns.matches(Path::new("post.md")));
+    }
+
+    #[test]
+    fn test_single_star_stays_in_segment() {
+        let patterns = patterns(&["*.md"]);
+        assert!(patterns.matches(Path::new("post.md")));
+        assert!(!patterns.matches(Path::new("nested/post.md")));
+    }
+
+    #[test]
+    fn test_braces_and_classes() {
+        let patterns = patterns(&["[a-c]*/**/*.{md,mdx}"]);
+        assert!(patterns.matches(Path::new("blog/post.md")));
+        assert!(patterns.matches(Path::new("cats/deep/post.mdx")));
+        assert!(!patterns.matches(Path::new("drafts/post.md")));
+        assert!(!patterns.matches(Path::new("blog/post.txt")));
+    }
+
+    #[test]
+    fn test_negated_patterns_exclude() {
+        let patterns = patterns(&["**/*.md", "!drafts/**"]);
+        assert!(patterns.matches(Path::new("notes/idea.md")));
+        assert!(!patterns.matches(Path::new("drafts/idea.md")));
+
+        let only_exclusions = self::patterns(&["!**/README.md"]);
+        assert!(only_exclusions.matches(Path::new("post.md")));
+        assert!(!only_exclusions.matches(Path::new("docs/README.md")));
+    }
+}
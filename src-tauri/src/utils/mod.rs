@@ -1,3 +1,8 @@
+pub mod collation;
+pub mod git;
+pub mod glob;
 pub mod path;
+pub mod svg;
+pub mod tsconfig;
 
 pub use path::serialize_path;
@@ -0,0 +1,298 @@
+//! SVG sanitization for imported assets.
+//!
+//! SVGs copied from the web can carry scripts, event handlers and references to
+//! external resources, all of which end up served from the site. Imported SVGs are
+//! rewritten without them:
+//!
+//! - `<script>`, `<foreignObject>` and embedded-document elements, with their contents
+//! - `on*` event handler attributes
+//! - `href`/`src` values other than fragment (`#id`) and `data:image/` references
+//! - CSS with `@import`, `javascript:`, `expression()` or external `url()`s, whether in
+//!   `<style>` elements or attributes
+//! - animations that target `href` or event handler attributes
+//! - processing instructions (`<?xml-stylesheet?>`) and `<!DOCTYPE>` declarations
+//!
+//! Everything else passes through byte-for-byte.
+
+use quick_xml::events::attributes::Attribute;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, Writer};
+
+/// Elements removed along with everything inside them
+const BLOCKED_ELEMENTS: [&str; 7] = [
+    "script",
+    "foreignobject",
+    "iframe",
+    "embed",
+    "object",
+    "handler",
+    "listener",
+];
+
+/// Animation elements, which can rewrite other attributes at runtime
+const ANIMATION_ELEMENTS: [&str; 5] = [
+    "animate",
+    "animatecolor",
+    "animatemotion",
+    "animatetransform",
+    "set",
+];
+
+/// A sanitized SVG and what was removed from it
+#[derive(Debug, Clone, PartialEq)]
+pub struct SanitizedSvg {
+    pub content: String,
+    /// Human-readable descriptions of removed content, e.g. "<script>"
+    pub removed: Vec<String>,
+}
+
+fn local_name(element: &BytesStart) -> String {
+    String::from_utf8_lossy(element.local_name().as_ref()).to_lowercase()
+}
+
+fn attribute_value(attr: &Attribute) -> String {
+    attr.unescape_value()
+        .map(|value| value.into_owned())
+        .unwrap_or_else(|_| String::from_utf8_lossy(&attr.value).into_owned())
+}
+
+/// References that stay inside the document or embed an image
+fn is_local_reference(target: &str) -> bool {
+    let target = target.trim().to_lowercase();
+    target.starts_with('#') || target.starts_with("data:image/")
+}
+
+/// CSS that can run script or load something from elsewhere
+fn is_unsafe_css(css: &str) -> bool {
+    let css = css.to_lowercase();
+    if css.contains("@import") || css.contains("javascript:") || css.contains("expression(") {
+        return true;
+    }
+    css.split("url(").skip(1).any(|rest| {
+        let target = rest.split(')').next().unwrap_or_default();
+        !is_local_reference(target.trim().trim_matches(['"', '\'']))
+    })
+}
+
+/// Why an attribute should be removed, if it should
+fn unsafe_attribute(element: &str, attr: &Attribute) -> Option<String> {
+    let key = String::from_utf8_lossy(attr.key.as_ref()).into_owned();
+    let name = String::from_utf8_lossy(attr.key.local_name().as_ref()).to_lowercase();
+    let value = attribute_value(attr);
+
+    if name.starts_with("on") {
+        Some(format!("{key} handler on <{element}>"))
+    } else if (name == "href" || name == "src") && !is_local_reference(&value) {
+        Some(format!("external {key} on <{element}> ({})", value.trim()))
+    } else if is_unsafe_css(&value) {
+        Some(format!("unsafe {key} on <{element}>"))
+    } else {
+        None
+    }
+}
+
+/// An animation that sets `href` or an event handler
+fn is_unsafe_animation(element: &str, start: &BytesStart) -> bool {
+    ANIMATION_ELEMENTS.contains(&element)
+        && start.attributes().with_checks(false).flatten().any(|attr| {
+            attr.key.local_name().as_ref() == b"attributeName" && {
+                let target = attribute_value(&attr).to_lowercase();
+                let target = target.rsplit(':').next().unwrap_or_default();
+                target == "href" || target.starts_with("on")
+            }
+        })
+}
+
+/// `start` without its unsafe attributes, recording them in `removed`
+///
+/// Elements with nothing to remove are returned untouched, keeping their formatting.
+fn clean_element<'a>(
+    element: &str,
+    start: BytesStart<'a>,
+    removed: &mut Vec<String>,
+) -> Result<BytesStart<'a>, String> {
+    let mut kept = Vec::new();
+    let mut changed = false;
+    for attr in start.attributes().with_checks(false) {
+        let attr = attr.map_err(|e| format!("Invalid SVG attribute: {e}"))?;
+        match unsafe_attribute(element, &attr) {
+            Some(reason) => {
+                removed.push(reason);
+                changed = true;
+            }
+            None => kept.push((
+                String::from_utf8_lossy(attr.key.as_ref()).into_owned(),
+                attribute_value(&attr),
+            )),
+        }
+    }
+    if !changed {
+        return Ok(start);
+    }
+
+    let mut cleaned = start.to_owned();
+    cleaned.clear_attributes();
+    for (key, value) in &kept {
+        cleaned.push_attribute((key.as_str(), value.as_str()));
+    }
+    Ok(cleaned)
+}
+
+/// Removes active content and external references from an SVG
+///
+/// Fails if the SVG isn't well-formed XML, since it can't be sanitized reliably.
+pub fn sanitize_svg(svg: &str) -> Result<SanitizedSvg, String> {
+    let mut reader = Reader::from_str(svg);
+    let mut writer = Writer::new(Vec::new());
+    let mut removed: Vec<String> = Vec::new();
+    // Open elements, to catch truncated files
+    let mut depth = 0usize;
+    // Depth inside a removed element; its events are dropped until it closes
+    let mut skip_depth = 0usize;
+    let mut in_style = false;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| format!("Invalid SVG at byte {}: {e}", reader.error_position()))?;
+
+        match event {
+            Event::Start(_) => depth += 1,
+            Event::End(_) => depth = depth.saturating_sub(1),
+            Event::Eof if depth > 0 => return Err("Invalid SVG: unclosed elements".to_string()),
+            _ => {}
+        }
+
+        if skip_depth > 0 {
+            match event {
+                Event::Start(_) => skip_depth += 1,
+                Event::End(_) => skip_depth -= 1,
+                Event::Eof => break,
+                _ => {}
+            }
+            continue;
+        }
+
+        let blocked = match &event {
+            Event::Start(start) | Event::Empty(start) => {
+                let element = local_name(start);
+                BLOCKED_ELEMENTS.contains(&element.as_str()) || is_unsafe_animation(&element, start)
+            }
+            _ => false,
+        };
+        if blocked {
+            if let Event::Start(start) | Event::Empty(start) = &event {
+                removed.push(format!(
+                    "<{}>",
+                    String::from_utf8_lossy(start.name().as_ref())
+                ));
+            }
+            // An opening tag's children go too; a self-closing one has none
+            if matches!(event, Event::Start(_)) {
+                skip_depth = 1;
+            }
+            continue;
+        }
+
+        let kept = match event {
+            Event::Eof => break,
+            Event::Start(start) => {
+                let element = local_name(&start);
+                in_style = element == "style";
+                Some(Event::Start(clean_element(&element, start, &mut removed)?))
+            }
+            Event::Empty(start) => {
+                let element = local_name(&start);
+                Some(Event::Empty(clean_element(&element, start, &mut removed)?))
+            }
+            Event::End(end) => {
+                in_style = false;
+                Some(Event::End(end))
+            }
+            Event::Text(text) if in_style && is_unsafe_css(&String::from_utf8_lossy(&text)) => {
+                removed.push("unsafe CSS in <style>".to_string());
+                None
+            }
+            Event::CData(text) if in_style && is_unsafe_css(&String::from_utf8_lossy(&text)) => {
+                removed.push("unsafe CSS in <style>".to_string());
+                None
+            }
+            Event::PI(_) => {
+                removed.push("processing instruction".to_string());
+                None
+            }
+            Event::DocType(_) => {
+                removed.push("<!DOCTYPE>".to_string());
+                None
+            }
+            other => Some(other),
+        };
+
+        if let Some(event) = kept {
+            writer
+                .write_event(event)
+                .map_err(|e| format!("Failed to write SVG: {e}"))?;
+        }
+    }
+
+    let content = String::from_utf8(writer.into_inner())
+        .map_err(|e| format!("Sanitized SVG isn't valid UTF-8: {e}"))?;
+    Ok(SanitizedSvg { content, removed })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_svg_removes_active_content() {
+        let svg = r##"<?xml version="1.0"?>
+<?xml-stylesheet href="https://evil.example/x.css"?>
+<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" onload="alert(1)" viewBox="0 0 10 10">
+  <script>alert(2)</script>
+  <style>@import url(https://evil.example/a.css);</style>
+  <foreignObject><div><p>hi</p></div></foreignObject>
+  <a xlink:href="javascript:alert(3)"><rect width="5" height="5" fill="url(#grad)"/></a>
+  <use href="https://evil.example/sprite.svg#icon"/>
+  <image href="data:image/png;base64,AAAA" style="filter: url('https://evil.example/f')"/>
+  <set attributeName="xlink:href" to="javascript:alert(4)"/>
+  <circle cx="5" cy="5" r="2"/>
+</svg>"##;
+
+        let result = sanitize_svg(svg).unwrap();
+        assert_eq!(
+            result.removed,
+            vec![
+                "processing instruction",
+                "onload handler on <svg>",
+                "<script>",
+                "unsafe CSS in <style>",
+                "<foreignObject>",
+                "external xlink:href on <a> (javascript:alert(3))",
+                "external href on <use> (https://evil.example/sprite.svg#icon)",
+                "unsafe style on <image>",
+                "<set>",
+            ]
+        );
+        assert!(!result.content.contains("alert"));
+        assert!(!result.content.contains("evil.example"));
+        assert!(result
+            .content
+            .contains(r#"<rect width="5" height="5" fill="url(#grad)"/>"#));
+        assert!(result
+            .content
+            .contains(r#"<image href="data:image/png;base64,AAAA"/>"#));
+        assert!(result.content.contains(r#"<circle cx="5" cy="5" r="2"/>"#));
+    }
+
+    #[test]
+    fn test_sanitize_svg_leaves_clean_files_alone() {
+        let svg = "<svg xmlns=\"http://www.w3.org/2000/svg\">\n  <!-- logo -->\n  <path d=\"M0 0h10v10z\" fill=\"#f00\"/>\n</svg>\n";
+        let result = sanitize_svg(svg).unwrap();
+        assert!(result.removed.is_empty());
+        assert_eq!(result.content, svg);
+
+        assert!(sanitize_svg("<svg><g></svg>").is_err());
+        assert!(sanitize_svg("<svg><script>").is_err());
+    }
+}
@@ -0,0 +1,338 @@
+//! `tsconfig.json` path alias support.
+//!
+//! Astro projects commonly define aliases such as `"@assets/*": ["src/assets/*"]` under
+//! `compilerOptions.paths`. These helpers read those aliases so asset paths can be emitted
+//! in aliased form (`@assets/blog/image.png`) and resolved back to files for previews.
+
+use serde_json::Value;
+use std::path::{Component, Path, PathBuf};
+
+/// Maximum depth of relative `extends` chains we follow before giving up
+const MAX_EXTENDS_DEPTH: usize = 5;
+
+/// A single resolved path alias from `compilerOptions.paths`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathAlias {
+    /// Alias prefix without the trailing `*` (e.g. `@assets/`), or the exact alias
+    pub alias: String,
+    /// Absolute target path without the trailing `*`
+    pub target: PathBuf,
+    /// Whether the alias ends in `*` and matches everything under the target
+    pub wildcard: bool,
+}
+
+/// Loads path aliases from the project's `tsconfig.json`
+///
+/// Follows relative `extends` entries so aliases defined in a shared base config are
+/// picked up. Package-based extends (e.g. `astro/tsconfigs/strict`) are skipped since
+/// they never define project paths. Returns an empty list if no tsconfig exists or it
+/// can't be parsed.
+pub fn load_path_aliases(project_root: &Path) -> Vec<PathAlias> {
+    let mut aliases = Vec::new();
+    collect_aliases(&project_root.join("tsconfig.json"), &mut aliases, 0);
+    aliases
+}
+
+fn collect_aliases(config_path: &Path, aliases: &mut Vec<PathAlias>, depth: usize) {
+    if depth > MAX_EXTENDS_DEPTH {
+        return;
+    }
+
+    let Ok(content) = std::fs::read_to_string(config_path) else {
+        return;
+    };
+    let Ok(config) = serde_json::from_str::<Value>(&strip_jsonc(&content)) else {
+        log::warn!(
+            "Astro Editor [TSCONFIG] Could not parse {}",
+            config_path.display()
+        );
+        return;
+    };
+
+    let config_dir = config_path.parent().unwrap_or(Path::new(""));
+    let compiler_options = config.get("compilerOptions");
+    let paths = compiler_options.and_then(|o| o.get("paths"));
+
+    // Paths in the child config override the entire `paths` map of the parent
+    if let Some(Value::Object(paths)) = paths {
+        let base_dir = match compiler_options
+            .and_then(|o| o.get("baseUrl"))
+            .and_then(Value::as_str)
+        {
+            Some(base_url) => config_dir.join(base_url),
+            None => config_dir.to_path_buf(),
+        };
+
+        for (pattern, targets) in paths {
+            let Some(first_target) = targets
+                .as_array()
+                .and_then(|t| t.first())
+                .and_then(Value::as_str)
+            else {
+                continue;
+            };
+
+            let wildcard = pattern.ends_with('*') && first_target.ends_with('*');
+            let alias = pattern.trim_end_matches('*').to_string();
+            let target = normalize(&base_dir.join(first_target.trim_end_matches('*')));
+
+            aliases.push(PathAlias {
+                alias,
+                target,
+                wildcard,
+            });
+        }
+        return;
+    }
+
+    if let Some(extends) = config.get("extends").and_then(Value::as_str) {
+        if extends.starts_with("./") || extends.starts_with("../") {
+            let parent = if extends.ends_with(".json") {
+                config_dir.join(extends)
+            } else {
+                config_dir.join(format!("{extends}.json"))
+            };
+            collect_aliases(&parent, aliases, depth + 1);
+        }
+    }
+}
+
+/// Converts a project-relative path to its aliased form, if an alias covers it
+///
+/// When several aliases match, the one with the most specific (longest) target wins,
+/// so `@assets/` is preferred over a catch-all like `@/` pointing at `src/`.
+pub fn to_aliased_path(
+    project_root: &Path,
+    project_relative_path: &str,
+    aliases: &[PathAlias],
+) -> Option<String> {
+    let full_path = normalize(&project_root.join(project_relative_path));
+
+    aliases
+        .iter()
+        .filter(|a| a.wildcard)
+        .filter_map(|a| {
+            full_path
+                .strip_prefix(&a.target)
+                .ok()
+                .map(|rest| (a, rest.to_string_lossy().replace('\\', "/")))
+        })
+        .filter(|(_, rest)| !rest.is_empty())
+        .max_by_key(|(a, _)| a.target.components().count())
+        .map(|(a, rest)| format!("{}{rest}", a.alias))
+}
+
+/// Resolves an aliased import path (e.g. `@assets/image.png`) to an absolute path
+pub fn resolve_aliased_path(import_path: &str, aliases: &[PathAlias]) -> Option<PathBuf> {
+    aliases
+        .iter()
+        .filter_map(|a| {
+            if a.wildcard {
+                import_path
+                    .strip_prefix(&a.alias)
+                    .map(|rest| (a, a.target.join(rest)))
+            } else if import_path == a.alias {
+                Some((a, a.target.clone()))
+            } else {
+                None
+            }
+        })
+        .max_by_key(|(a, _)| a.alias.len())
+        .map(|(_, path)| path)
+}
+
+/// Lexically normalizes `.` and `..` components without touching the filesystem
+fn normalize(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                result.pop();
+            }
+            other => result.push(other),
+        }
+    }
+    result
+}
+
+/// Strips comments and trailing commas so JSONC can be parsed as plain JSON
+fn strip_jsonc(content: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+    let mut escape_next = false;
+
+    while let Some(ch) = chars.next() {
+        if in_string {
+            result.push(ch);
+            if escape_next {
+                escape_next = false;
+            } else if ch == '\\' {
+                escape_next = true;
+            } else if ch == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match ch {
+            '"' => {
+                in_string = true;
+                result.push(ch);
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        result.push('\n');
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = '\0';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            '}' | ']' => {
+                // Drop a trailing comma before the closing bracket
+                let trimmed_len = result.trim_end().len();
+                if result[..trimmed_len].ends_with(',') {
+                    result.truncate(trimmed_len - 1);
+                }
+                result.push(ch);
+            }
+            _ => result.push(ch),
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_strip_jsonc_comments_and_trailing_commas() {
+        let input = r#"{
+  // line comment
+  "a": "http://example.com", /* block */
+  "b": [1, 2,],
+}"#;
+        let value: Value = serde_json::from_str(&strip_jsonc(input)).unwrap();
+        assert_eq!(value["a"], "http://example.com");
+        assert_eq!(value["b"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_load_path_aliases_with_base_url() {
+        let temp = TempDir::new().unwrap();
+        fs::write(
+            temp.path().join("tsconfig.json"),
+            r#"{
+  "extends": "astro/tsconfigs/strict",
+  "compilerOptions": {
+    "baseUrl": ".",
+    "paths": {
+      "@assets/*": ["src/assets/*"],
+      "@/*": ["src/*"],
+    }
+  }
+}"#,
+        )
+        .unwrap();
+
+        let aliases = load_path_aliases(temp.path());
+        assert_eq!(aliases.len(), 2);
+        let assets = aliases.iter().find(|a| a.alias == "@assets/").unwrap();
+        assert_eq!(assets.target, temp.path().join("src/assets/"));
+        assert!(assets.wildcard);
+    }
+
+    #[test]
+    fn test_load_path_aliases_follows_relative_extends() {
+        let temp = TempDir::new().unwrap();
+        fs::write(
+            temp.path().join("tsconfig.base.json"),
+            r#"{ "compilerOptions": { "paths": { "~/*": ["./src/*"] } } }"#,
+        )
+        .unwrap();
+        fs::write(
+            temp.path().join("tsconfig.json"),
+            r#"{ "extends": "./tsconfig.base" }"#,
+        )
+        .unwrap();
+
+        let aliases = load_path_aliases(temp.path());
+        assert_eq!(aliases.len(), 1);
+        assert_eq!(aliases[0].target, temp.path().join("src"));
+    }
+
+    #[test]
+    fn test_load_path_aliases_missing_tsconfig() {
+        let temp = TempDir::new().unwrap();
+        assert!(load_path_aliases(temp.path()).is_empty());
+    }
+
+    #[test]
+    fn test_to_aliased_path_prefers_most_specific() {
+        let root = Path::new("/project");
+        let aliases = vec![
+            PathAlias {
+                alias: "@/".to_string(),
+                target: root.join("src"),
+                wildcard: true,
+            },
+            PathAlias {
+                alias: "@assets/".to_string(),
+                target: root.join("src/assets"),
+                wildcard: true,
+            },
+        ];
+
+        assert_eq!(
+            to_aliased_path(root, "src/assets/blog/image.png", &aliases),
+            Some("@assets/blog/image.png".to_string())
+        );
+        assert_eq!(
+            to_aliased_path(root, "src/content/post.md", &aliases),
+            Some("@/content/post.md".to_string())
+        );
+        assert_eq!(to_aliased_path(root, "public/image.png", &aliases), None);
+    }
+
+    #[test]
+    fn test_resolve_aliased_path() {
+        let root = Path::new("/project");
+        let aliases = vec![
+            PathAlias {
+                alias: "@assets/".to_string(),
+                target: root.join("src/assets"),
+                wildcard: true,
+            },
+            PathAlias {
+                alias: "@logo".to_string(),
+                target: root.join("src/assets/logo.png"),
+                wildcard: false,
+            },
+        ];
+
+        assert_eq!(
+            resolve_aliased_path("@assets/blog/image.png", &aliases),
+            Some(root.join("src/assets/blog/image.png"))
+        );
+        assert_eq!(
+            resolve_aliased_path("@logo", &aliases),
+            Some(root.join("src/assets/logo.png"))
+        );
+        assert_eq!(resolve_aliased_path("./image.png", &aliases), None);
+    }
+}
@@ -1,6 +1,5 @@
 import { Layout } from './components/layout'
 import { ThemeProvider } from './lib/theme-provider'
-import { check } from '@tauri-apps/plugin-updater'
 import { info, error } from '@tauri-apps/plugin-log'
 import { listen } from '@tauri-apps/api/event'
 import { commands } from '@/lib/bindings'
@@ -9,66 +8,44 @@ import { UpdateDialog } from '@/components/update-dialog'
 import { useUpdateStore } from '@/store/updateStore'
 import './App.css'
 
-async function fetchAndSetReleaseNotes(
-  currentVersion: string,
-  newVersion: string
-) {
-  const store = useUpdateStore.getState()
-  try {
-    const result = await commands.fetchReleaseNotes(currentVersion, newVersion)
-    if (result.status === 'ok') {
-      store.setReleaseNotes(result.data)
-    } else {
-      store.setReleaseNotesError()
-    }
-  } catch {
-    store.setReleaseNotesError()
-  }
-}
-
 async function checkForUpdates(manual: boolean): Promise<void> {
   if (manual) {
     useUpdateStore.getState().setChecking()
   }
 
-  try {
-    const update = await check()
+  const result = await commands.getUpdateInfo()
+  if (result.status === 'error') {
+    await error(result.error)
 
-    if (update) {
-      await info(`Update available: ${update.version}`)
-
-      // Re-read store after await to get fresh skippedVersion
-      const { skippedVersion } = useUpdateStore.getState()
+    if (manual) {
+      useUpdateStore.getState().setError(result.error)
+    }
+    return
+  }
 
-      // For automatic checks, skip if user has skipped this version
-      if (!manual && skippedVersion === update.version) {
-        await info(`Skipping version ${update.version} (user skipped)`)
-        return
-      }
+  const { channel, currentVersion, update } = result.data
+  if (!update) {
+    await info(`No updates available on the ${channel} channel`)
 
-      const currentVersion = update.currentVersion
-      useUpdateStore
-        .getState()
-        .setAvailable(update, update.version, currentVersion)
+    if (manual) {
+      useUpdateStore.getState().setNoUpdate(currentVersion)
+    }
+    return
+  }
 
-      // Fetch release notes in the background
-      void fetchAndSetReleaseNotes(currentVersion, update.version)
-    } else {
-      await info('No updates available')
+  await info(`Update available on the ${channel} channel: ${update.version}`)
 
-      if (manual) {
-        const result = await commands.getAppVersion()
-        const version = result.status === 'ok' ? result.data : 'unknown'
-        useUpdateStore.getState().setNoUpdate(version)
-      }
-    }
-  } catch (err) {
-    await error(`Update check failed: ${String(err)}`)
+  // Re-read store after await to get fresh skippedVersion
+  const { skippedVersion } = useUpdateStore.getState()
 
-    if (manual) {
-      useUpdateStore.getState().setError(`Update check failed: ${String(err)}`)
-    }
+  // For automatic checks, skip if user has skipped this version or it's
+  // already waiting to install on quit
+  if (!manual && (skippedVersion === update.version || update.deferred)) {
+    await info(`Not prompting for version ${update.version}`)
+    return
   }
+
+  useUpdateStore.getState().setAvailable(update, currentVersion)
 }
 
 function App() {
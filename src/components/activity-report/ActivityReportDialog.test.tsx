@@ -0,0 +1,96 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent, waitFor } from '@testing-library/react'
+import { ActivityReportDialog } from './ActivityReportDialog'
+import { useActivityReportStore } from '../../store/activityReportStore'
+import { useProjectStore } from '../../store/projectStore'
+import { renderWithProviders } from '../../test/test-utils'
+import { formatIsoDate } from '../../lib/dates'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    exportActivityReport: vi.fn(),
+    copyTextToClipboard: vi.fn(),
+  },
+}))
+
+import { commands } from '@/lib/bindings'
+
+const now = new Date()
+const firstOfMonth = (offset: number) =>
+  formatIsoDate(new Date(now.getFullYear(), now.getMonth() + offset, 1))
+const lastOfMonth = (offset: number) =>
+  formatIsoDate(new Date(now.getFullYear(), now.getMonth() + offset + 1, 0))
+
+describe('ActivityReportDialog', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    vi.mocked(commands.exportActivityReport).mockImplementation(
+      async (_project, _content, _assets, from, to) => ({
+        status: 'ok',
+        data: {
+          from,
+          to,
+          gitAvailable: true,
+          published: [
+            {
+              path: 'src/content/blog/post.md',
+              collection: 'blog',
+              title: 'Post',
+              publishedDate: from,
+            },
+          ],
+          created: [],
+          updated: [],
+          wordsWritten: 1200,
+          newAssets: [],
+          markdown: `# Activity ${from} to ${to}`,
+        },
+      })
+    )
+    vi.mocked(commands.copyTextToClipboard).mockResolvedValue({
+      status: 'ok',
+      data: null,
+    })
+    useProjectStore.setState({
+      projectPath: '/project',
+      currentProjectSettings: null,
+    })
+    useActivityReportStore.setState({ isOpen: true })
+  })
+
+  it('reports on the current month', async () => {
+    renderWithProviders(<ActivityReportDialog />)
+
+    expect(
+      await screen.findByText(
+        `# Activity ${firstOfMonth(0)} to ${lastOfMonth(0)}`
+      )
+    ).toBeInTheDocument()
+    expect(screen.getByText('1,200')).toBeInTheDocument()
+  })
+
+  it('steps back a month', async () => {
+    renderWithProviders(<ActivityReportDialog />)
+
+    fireEvent.click(screen.getByRole('button', { name: 'Previous month' }))
+
+    expect(
+      await screen.findByText(
+        `# Activity ${firstOfMonth(-1)} to ${lastOfMonth(-1)}`
+      )
+    ).toBeInTheDocument()
+  })
+
+  it('copies the report as Markdown', async () => {
+    renderWithProviders(<ActivityReportDialog />)
+
+    await screen.findByText('1,200')
+    fireEvent.click(screen.getByRole('button', { name: 'Copy Markdown' }))
+
+    await waitFor(() =>
+      expect(commands.copyTextToClipboard).toHaveBeenCalledWith(
+        `# Activity ${firstOfMonth(0)} to ${lastOfMonth(0)}`
+      )
+    )
+  })
+})
@@ -0,0 +1,140 @@
+import React from 'react'
+import { useShallow } from 'zustand/react/shallow'
+import { ChevronLeft, ChevronRight } from 'lucide-react'
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from '../ui/dialog'
+import { Button } from '../ui/button'
+import { useActivityReportStore } from '../../store/activityReportStore'
+import { useProjectStore } from '../../store/projectStore'
+import { useActivityReportQuery } from '../../hooks/queries/useActivityReportQuery'
+import { commands } from '@/lib/bindings'
+import { formatIsoDate } from '../../lib/dates'
+import { toast } from '../../lib/toast'
+
+/**
+ * What was published, written and added in a month, as Markdown ready to
+ * paste into a "what I shipped" post
+ */
+export function ActivityReportDialog() {
+  const isOpen = useActivityReportStore(state => state.isOpen)
+  const close = useActivityReportStore(state => state.close)
+  const projectPath = useProjectStore(state => state.projectPath)
+  const currentProjectSettings = useProjectStore(
+    useShallow(state => state.currentProjectSettings)
+  )
+  const [month, setMonth] = React.useState(() => {
+    const today = new Date()
+    return new Date(today.getFullYear(), today.getMonth(), 1)
+  })
+
+  const from = formatIsoDate(month)
+  const to = formatIsoDate(
+    new Date(month.getFullYear(), month.getMonth() + 1, 0)
+  )
+  const { data: report, isLoading } = useActivityReportQuery(
+    isOpen ? projectPath : null,
+    currentProjectSettings,
+    from,
+    to
+  )
+
+  const stepMonth = (step: number) =>
+    setMonth(new Date(month.getFullYear(), month.getMonth() + step, 1))
+
+  const copyMarkdown = async () => {
+    if (!report) return
+    const result = await commands.copyTextToClipboard(report.markdown)
+    if (result.status === 'error') {
+      toast.error('Failed to copy report', { description: result.error })
+      return
+    }
+    toast.success('Report copied as Markdown')
+  }
+
+  const stats: [string, number | null][] | undefined = report && [
+    ['Published', report.published.length],
+    ['Created', report.created.length],
+    ['Updated', report.updated.length],
+    ['Words written', report.wordsWritten],
+    ['New assets', report.newAssets.length],
+  ]
+
+  return (
+    <Dialog open={isOpen} onOpenChange={open => !open && close()}>
+      <DialogContent className="sm:max-w-2xl">
+        <DialogHeader>
+          <DialogTitle>Activity Report</DialogTitle>
+          <DialogDescription>
+            Entries published, created and updated in a month, with the words
+            written and assets added.
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="flex items-center justify-between">
+          <Button
+            variant="ghost"
+            size="icon"
+            aria-label="Previous month"
+            onClick={() => stepMonth(-1)}
+          >
+            <ChevronLeft className="size-4" />
+          </Button>
+          <span className="text-sm font-medium">
+            {month.toLocaleDateString(undefined, {
+              month: 'long',
+              year: 'numeric',
+            })}
+          </span>
+          <Button
+            variant="ghost"
+            size="icon"
+            aria-label="Next month"
+            onClick={() => stepMonth(1)}
+          >
+            <ChevronRight className="size-4" />
+          </Button>
+        </div>
+
+        {isLoading || !report || !stats ? (
+          <div className="py-8 text-center text-sm text-muted-foreground">
+            Building report…
+          </div>
+        ) : (
+          <div className="flex flex-col gap-3">
+            <dl className="grid grid-cols-5 gap-2 text-center">
+              {stats.map(([label, value]) => (
+                <div key={label} className="rounded-md border p-2">
+                  <dt className="text-xs text-muted-foreground">{label}</dt>
+                  <dd className="text-lg font-semibold">
+                    {value?.toLocaleString() ?? '—'}
+                  </dd>
+                </div>
+              ))}
+            </dl>
+            {!report.gitAvailable && (
+              <p className="text-xs text-muted-foreground">
+                Without git history, created and updated entries come from
+                file dates and words written can&apos;t be counted.
+              </p>
+            )}
+            <pre className="max-h-64 overflow-auto rounded-md bg-muted p-3 text-xs whitespace-pre-wrap">
+              {report.markdown}
+            </pre>
+          </div>
+        )}
+
+        <DialogFooter>
+          <Button disabled={!report} onClick={() => void copyMarkdown()}>
+            Copy Markdown
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  )
+}
@@ -0,0 +1 @@
+export { ActivityReportDialog } from './ActivityReportDialog'
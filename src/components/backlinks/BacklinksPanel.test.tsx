@@ -0,0 +1,83 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent } from '@testing-library/dom'
+import { BacklinksPanel } from './BacklinksPanel'
+import { useEditorStore } from '../../store/editorStore'
+import type { Backlink, FileEntry } from '@/types'
+import { renderWithProviders } from '../../test/test-utils'
+
+import { useBacklinksQuery } from '../../hooks/queries/useBacklinksQuery'
+vi.mock('../../hooks/queries/useBacklinksQuery')
+
+const createMockFile = (overrides: Partial<FileEntry> = {}): FileEntry => ({
+  id: 'posts/test',
+  path: '/project/posts/test.md',
+  name: 'test',
+  extension: 'md',
+  collection: 'posts',
+  last_modified: null,
+  frontmatter: null,
+  ...overrides,
+})
+
+describe('BacklinksPanel', () => {
+  const mockBacklinksQuery = (backlinks: Backlink[]) => {
+    // eslint-disable-next-line @typescript-eslint/no-unsafe-argument
+    vi.mocked(useBacklinksQuery).mockReturnValue({
+      data: backlinks,
+      isLoading: false,
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    } as any)
+  }
+
+  const openFile = vi.fn()
+
+  beforeEach(() => {
+    openFile.mockReset()
+    mockBacklinksQuery([])
+    useEditorStore.setState({ currentFile: createMockFile(), openFile })
+  })
+
+  it('renders nothing when no file is open', () => {
+    useEditorStore.setState({ currentFile: null })
+    const { container } = renderWithProviders(<BacklinksPanel />)
+    expect(container).toBeEmptyDOMElement()
+  })
+
+  it('says when nothing links to the file', () => {
+    renderWithProviders(<BacklinksPanel />)
+    expect(screen.getByText('No other entries link here.')).toBeInTheDocument()
+  })
+
+  it('lists linking entries by title and opens them', () => {
+    const source = createMockFile({
+      id: 'posts/other',
+      path: '/project/posts/other.md',
+      name: 'other',
+      frontmatter: { title: 'Another Post' },
+    })
+    mockBacklinksQuery([
+      {
+        source,
+        kind: 'wiki',
+        line: 7,
+        context: 'As covered in [[test]].',
+      },
+      {
+        source: createMockFile({ id: 'posts/untitled', name: 'untitled' }),
+        kind: 'reference',
+        line: null,
+        context: 'related',
+      },
+    ])
+
+    renderWithProviders(<BacklinksPanel />)
+
+    expect(screen.getByText('(2)')).toBeInTheDocument()
+    expect(screen.getByText('As covered in [[test]].')).toBeInTheDocument()
+    expect(screen.getByText('untitled')).toBeInTheDocument()
+    expect(screen.getByText('related field')).toBeInTheDocument()
+
+    fireEvent.click(screen.getByText('Another Post'))
+    expect(openFile).toHaveBeenCalledWith(source)
+  })
+})
@@ -0,0 +1,97 @@
+import React from 'react'
+import { useShallow } from 'zustand/react/shallow'
+import { ChevronDown, Link2 } from 'lucide-react'
+import { useEditorStore } from '../../store/editorStore'
+import { useProjectStore } from '../../store/projectStore'
+import { useBacklinksQuery } from '../../hooks/queries/useBacklinksQuery'
+import { getEffectiveFrontmatterMappings } from '../../lib/project-registry/path-resolution'
+import {
+  Collapsible,
+  CollapsibleContent,
+  CollapsibleTrigger,
+} from '@/components/ui/collapsible'
+import type { Backlink, LinkKind } from '@/types'
+
+const KIND_LABELS: Record<LinkKind, string> = {
+  markdown: 'Link',
+  reference: 'Reference',
+  wiki: 'Wiki link',
+}
+
+export const BacklinksPanel: React.FC = () => {
+  const currentFile = useEditorStore(useShallow(state => state.currentFile))
+  const projectPath = useProjectStore(state => state.projectPath)
+  const currentProjectSettings = useProjectStore(
+    useShallow(state => state.currentProjectSettings)
+  )
+
+  const { data: backlinks = [], isLoading } = useBacklinksQuery(
+    projectPath,
+    currentProjectSettings,
+    currentFile?.path ?? null
+  )
+
+  if (!currentFile) return null
+
+  const titleOf = (backlink: Backlink) => {
+    const { title } = getEffectiveFrontmatterMappings(
+      currentProjectSettings,
+      backlink.source.collection
+    )
+    const value = backlink.source.frontmatter?.[title]
+    return typeof value === 'string' && value ? value : backlink.source.name
+  }
+
+  return (
+    <Collapsible
+      defaultOpen
+      className="shrink-0 border-t border-border max-h-[40%] flex flex-col"
+    >
+      <CollapsibleTrigger className="group flex items-center gap-1 px-4 py-2 text-sm font-medium text-foreground">
+        <ChevronDown className="h-4 w-4 text-muted-foreground transition-transform group-data-[state=closed]:-rotate-90" />
+        Backlinks
+        {backlinks.length > 0 && (
+          <span className="text-muted-foreground font-normal">
+            ({backlinks.length})
+          </span>
+        )}
+      </CollapsibleTrigger>
+      <CollapsibleContent className="overflow-y-auto px-2 pb-3">
+        {backlinks.length === 0 ? (
+          <p className="px-2 text-sm text-muted-foreground">
+            {isLoading ? 'Finding links…' : 'No other entries link here.'}
+          </p>
+        ) : (
+          <ul className="space-y-1">
+            {backlinks.map(backlink => (
+              <li
+                key={`${backlink.source.id}:${backlink.kind}:${backlink.line ?? backlink.context}`}
+              >
+                <button
+                  type="button"
+                  className="w-full rounded-md px-2 py-1.5 text-left hover:bg-accent"
+                  onClick={() =>
+                    useEditorStore.getState().openFile(backlink.source)
+                  }
+                >
+                  <span className="flex items-center gap-1.5 text-sm text-foreground">
+                    <Link2 className="h-3.5 w-3.5 shrink-0 text-muted-foreground" />
+                    <span className="truncate">{titleOf(backlink)}</span>
+                    <span className="ml-auto shrink-0 text-xs text-muted-foreground">
+                      {KIND_LABELS[backlink.kind]}
+                    </span>
+                  </span>
+                  <span className="mt-0.5 block truncate text-xs text-muted-foreground">
+                    {backlink.kind === 'reference'
+                      ? `${backlink.context} field`
+                      : backlink.context}
+                  </span>
+                </button>
+              </li>
+            ))}
+          </ul>
+        )}
+      </CollapsibleContent>
+    </Collapsible>
+  )
+}
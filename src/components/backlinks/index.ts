@@ -0,0 +1 @@
+export { BacklinksPanel } from './BacklinksPanel'
@@ -0,0 +1,90 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent, waitFor } from '@testing-library/react'
+import { BackupsDialog } from './BackupsDialog'
+import { useBackupsStore } from '../../store/backupsStore'
+import { useProjectStore } from '../../store/projectStore'
+import { renderWithProviders } from '../../test/test-utils'
+import type { BackupInfo } from '@/types'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    listBackups: vi.fn(),
+    createBackup: vi.fn(),
+    restoreBackup: vi.fn(),
+  },
+}))
+
+import { commands } from '@/lib/bindings'
+
+const backup: BackupInfo = {
+  id: '0001772366400000',
+  projectRoot: '/project',
+  contentDirectory: 'src/content',
+  reason: 'asset-repair',
+  createdAt: '2026-03-01T12:00:00.000Z',
+  fileCount: 12,
+  sizeBytes: 4096,
+}
+
+describe('BackupsDialog', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    vi.mocked(commands.listBackups).mockResolvedValue({
+      status: 'ok',
+      data: [backup],
+    })
+    useProjectStore.setState({
+      projectPath: '/project',
+      currentProjectSettings: null,
+    })
+    useBackupsStore.setState({ isOpen: true })
+  })
+
+  it('lists backups with what took them', async () => {
+    renderWithProviders(<BackupsDialog />)
+
+    expect(
+      await screen.findByText('Before asset-repair · 12 files')
+    ).toBeInTheDocument()
+    expect(commands.listBackups).toHaveBeenCalledWith('/project')
+  })
+
+  it('restores a backup into the current project', async () => {
+    vi.mocked(commands.restoreBackup).mockResolvedValue({
+      status: 'ok',
+      data: {
+        restored: ['/project/src/content/blog/post.md'],
+        safetyBackup: { ...backup, id: '0001772366500000' },
+      },
+    })
+    renderWithProviders(<BackupsDialog />)
+
+    const createdAt = new Date(backup.createdAt).toLocaleString()
+    fireEvent.click(
+      await screen.findByRole('button', {
+        name: `Restore backup from ${createdAt}`,
+      })
+    )
+
+    await waitFor(() =>
+      expect(commands.restoreBackup).toHaveBeenCalledWith(
+        backup.id,
+        '/project'
+      )
+    )
+  })
+
+  it('backs up the default content directory on request', async () => {
+    vi.mocked(commands.createBackup).mockResolvedValue({
+      status: 'ok',
+      data: backup,
+    })
+    renderWithProviders(<BackupsDialog />)
+
+    fireEvent.click(screen.getByRole('button', { name: 'Back Up Now' }))
+
+    await waitFor(() =>
+      expect(commands.createBackup).toHaveBeenCalledWith('/project', null)
+    )
+  })
+})
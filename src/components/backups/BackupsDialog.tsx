@@ -0,0 +1,99 @@
+import React from 'react'
+import { Archive, RotateCcw } from 'lucide-react'
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from '../ui/dialog'
+import { Button } from '../ui/button'
+import { useBackupsStore } from '../../store/backupsStore'
+import { useProjectStore } from '../../store/projectStore'
+import { useBackupsQuery } from '../../hooks/queries/useBackupsQuery'
+import { backupReason, createBackup, restoreBackup } from '../../lib/backups'
+import type { BackupInfo } from '@/types'
+
+/**
+ * Backups of the current project's content directory, any of which can be
+ * restored over the current content
+ */
+export function BackupsDialog() {
+  const isOpen = useBackupsStore(state => state.isOpen)
+  const close = useBackupsStore(state => state.close)
+  const projectPath = useProjectStore(state => state.projectPath)
+  const { data: backups = [], isLoading } = useBackupsQuery(
+    projectPath,
+    isOpen
+  )
+  const [busy, setBusy] = React.useState(false)
+
+  const backUp = async () => {
+    setBusy(true)
+    await createBackup()
+    setBusy(false)
+  }
+
+  const restore = async (backup: BackupInfo) => {
+    setBusy(true)
+    await restoreBackup(backup)
+    setBusy(false)
+  }
+
+  return (
+    <Dialog open={isOpen} onOpenChange={open => !open && close()}>
+      <DialogContent className="sm:max-w-2xl">
+        <DialogHeader>
+          <DialogTitle>Backups</DialogTitle>
+          <DialogDescription>
+            Restoring a backup puts its files back in the content directory.
+            The current content is backed up first, and files added since are
+            kept.
+          </DialogDescription>
+        </DialogHeader>
+
+        {!isLoading && backups.length === 0 ? (
+          <div className="py-8 text-center text-sm text-muted-foreground">
+            This project hasn&apos;t been backed up yet.
+          </div>
+        ) : (
+          <ul className="max-h-96 divide-y overflow-y-auto">
+            {backups.map(backup => {
+              const createdAt = new Date(backup.createdAt).toLocaleString()
+              return (
+                <li key={backup.id} className="flex items-center gap-3 py-2">
+                  <div className="min-w-0 flex-1">
+                    <div className="truncate text-sm font-medium">
+                      {createdAt}
+                    </div>
+                    <div className="truncate text-xs text-muted-foreground">
+                      {backupReason(backup)} · {backup.fileCount} files
+                    </div>
+                  </div>
+                  <Button
+                    size="sm"
+                    variant="outline"
+                    aria-label={`Restore backup from ${createdAt}`}
+                    disabled={busy}
+                    onClick={() => void restore(backup)}
+                  >
+                    <RotateCcw className="size-4" />
+                    Restore
+                  </Button>
+                </li>
+              )
+            })}
+          </ul>
+        )}
+
+        <DialogFooter>
+          <Button disabled={busy} onClick={() => void backUp()}>
+            <Archive className="size-4" />
+            Back Up Now
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  )
+}
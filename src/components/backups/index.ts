@@ -0,0 +1 @@
+export { BackupsDialog } from './BackupsDialog'
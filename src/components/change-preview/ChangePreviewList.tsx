@@ -0,0 +1,76 @@
+import type { ChangePreview, PlannedChange } from '@/types'
+
+interface ChangePreviewListProps {
+  preview: ChangePreview
+  /** Paths under this directory are shown relative to it */
+  rootPath?: string | null
+}
+
+/**
+ * Describes one planned change, e.g. "Rename a.md → b.md"
+ */
+function describeChange(
+  change: PlannedChange,
+  relative: (path: string) => string
+): string {
+  switch (change.kind) {
+    case 'write':
+      return `${change.created ? 'Create' : 'Edit'} ${relative(change.path)}`
+    case 'delete':
+      return `Delete ${relative(change.path)}`
+    case 'rename':
+      return `Rename ${relative(change.from)} → ${relative(change.to)}`
+    case 'copy':
+      return `Copy ${relative(change.from)} → ${relative(change.to)}`
+    case 'createDirectory':
+      return `Create folder ${relative(change.path)}`
+  }
+}
+
+/**
+ * The changes a command would make, from a dry run, with the diff of each
+ * edited file
+ */
+export function ChangePreviewList({
+  preview,
+  rootPath,
+}: ChangePreviewListProps) {
+  const relative = (path: string) =>
+    rootPath && path.startsWith(rootPath)
+      ? path.slice(rootPath.length + 1)
+      : path
+
+  return (
+    <div className="flex flex-col gap-2">
+      {preview.error && (
+        <p className="text-sm text-destructive">
+          This would fail: {preview.error}
+        </p>
+      )}
+      {preview.changes.length === 0 ? (
+        <p className="text-sm text-muted-foreground">No files would change.</p>
+      ) : (
+        <ul className="max-h-64 overflow-y-auto text-sm">
+          {preview.changes.map((change, index) => (
+            <li key={index}>
+              {change.kind === 'write' && change.diff ? (
+                <details>
+                  <summary className="cursor-pointer truncate">
+                    {describeChange(change, relative)}
+                  </summary>
+                  <pre className="overflow-x-auto rounded-md bg-muted p-2 text-xs">
+                    {change.diff}
+                  </pre>
+                </details>
+              ) : (
+                <span className="block truncate">
+                  {describeChange(change, relative)}
+                </span>
+              )}
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  )
+}
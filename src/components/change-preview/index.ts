@@ -0,0 +1 @@
+export { ChangePreviewList } from './ChangePreviewList'
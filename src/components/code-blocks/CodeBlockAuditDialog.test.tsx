@@ -0,0 +1,120 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent, waitFor } from '@testing-library/react'
+import { CodeBlockAuditDialog } from './CodeBlockAuditDialog'
+import { useCodeBlockAuditStore } from '../../store/codeBlockAuditStore'
+import { useProjectStore } from '../../store/projectStore'
+import { renderWithProviders } from '../../test/test-utils'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    auditCodeBlocks: vi.fn(),
+    normalizeCodeBlockLanguages: vi.fn(),
+    previewChanges: vi.fn(),
+  },
+}))
+vi.mock('../../lib/open-file', () => ({
+  openProjectFile: vi.fn(),
+}))
+
+import { commands } from '@/lib/bindings'
+import { openProjectFile } from '../../lib/open-file'
+
+const collection = { name: 'blog', path: '/project/src/content/blog' }
+
+describe('CodeBlockAuditDialog', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    vi.mocked(commands.auditCodeBlocks).mockResolvedValue({
+      status: 'ok',
+      data: {
+        languages: [
+          { language: 'js', count: 4, known: true, canonical: 'javascript' },
+          { language: 'rust', count: 2, known: true, canonical: 'rust' },
+          { language: 'typscript', count: 1, known: false },
+        ],
+        unknownBlocks: [
+          {
+            filePath: '/project/src/content/blog/post.md',
+            line: 12,
+            language: 'typscript',
+            suggestion: 'typescript',
+          },
+        ],
+        unlabelledCount: 0,
+      },
+    })
+    useProjectStore.setState({
+      projectPath: '/project',
+      currentProjectSettings: null,
+    })
+    useCodeBlockAuditStore.setState({ collection })
+  })
+
+  it('lists the blocks that will not be highlighted', async () => {
+    renderWithProviders(<CodeBlockAuditDialog />)
+
+    expect(await screen.findByText('post.md:12')).toBeInTheDocument()
+    expect(screen.getByText('typscript → typescript?')).toBeInTheDocument()
+    expect(commands.auditCodeBlocks).toHaveBeenCalledWith(collection.path)
+  })
+
+  it('opens a block at its fence', async () => {
+    renderWithProviders(<CodeBlockAuditDialog />)
+
+    fireEvent.click(await screen.findByText('post.md:12'))
+
+    expect(openProjectFile).toHaveBeenCalledWith(
+      '/project/src/content/blog/post.md',
+      12
+    )
+  })
+
+  it('rewrites aliases to the languages Shiki knows them by', async () => {
+    vi.mocked(commands.normalizeCodeBlockLanguages).mockResolvedValue({
+      status: 'ok',
+      data: { filesChanged: 2, blocksChanged: 4, changedFiles: [] },
+    })
+    renderWithProviders(<CodeBlockAuditDialog />)
+
+    expect(await screen.findByText('js → javascript')).toBeInTheDocument()
+    fireEvent.click(screen.getByRole('button', { name: 'Rewrite Languages' }))
+
+    await waitFor(() =>
+      expect(commands.normalizeCodeBlockLanguages).toHaveBeenCalledWith(
+        '/project',
+        collection.path,
+        { js: 'javascript' }
+      )
+    )
+  })
+
+  it('previews the files a rewrite would change', async () => {
+    vi.mocked(commands.previewChanges).mockResolvedValue({
+      status: 'ok',
+      data: {
+        changes: [
+          {
+            kind: 'write',
+            path: '/project/src/content/blog/post.md',
+            created: false,
+            diff: '-```js\n+```javascript',
+          },
+        ],
+        error: null,
+      },
+    })
+    renderWithProviders(<CodeBlockAuditDialog />)
+
+    await screen.findByText('js → javascript')
+    fireEvent.click(screen.getByRole('button', { name: 'Preview Changes' }))
+
+    expect(await screen.findByText('Edit post.md')).toBeInTheDocument()
+    expect(commands.previewChanges).toHaveBeenCalledWith({
+      kind: 'normalizeCodeBlockLanguages',
+      projectPath: '/project',
+      collectionPath: collection.path,
+      mapping: { js: 'javascript' },
+    })
+    expect(commands.normalizeCodeBlockLanguages).not.toHaveBeenCalled()
+  })
+})
@@ -0,0 +1,194 @@
+import React from 'react'
+import { useShallow } from 'zustand/react/shallow'
+import { AlertTriangle } from 'lucide-react'
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from '../ui/dialog'
+import { Badge } from '../ui/badge'
+import { Button } from '../ui/button'
+import { useCodeBlockAuditStore } from '../../store/codeBlockAuditStore'
+import { useProjectStore } from '../../store/projectStore'
+import { useCodeBlockAuditQuery } from '../../hooks/queries/useCodeBlockAuditQuery'
+import { useChangePreviewQuery } from '../../hooks/queries/useChangePreviewQuery'
+import { useNormalizeCodeBlocksMutation } from '../../hooks/mutations/useNormalizeCodeBlocksMutation'
+import { ChangePreviewList } from '../change-preview'
+import { openProjectFile } from '../../lib/open-file'
+import type { CodeBlockAudit } from '@/types'
+
+/**
+ * The languages to rewrite: the project's `codeLanguageAliases` setting if it
+ * has one, otherwise each alias Shiki knows by another id (`js` →
+ * `javascript`)
+ */
+function rewriteMapping(
+  audit: CodeBlockAudit | undefined,
+  projectAliases: Record<string, string> | undefined
+): Record<string, string> {
+  if (projectAliases && Object.keys(projectAliases).length > 0) {
+    return projectAliases
+  }
+  return Object.fromEntries(
+    (audit?.languages ?? [])
+      .filter(usage => usage.canonical && usage.canonical !== usage.language)
+      .map(usage => [usage.language, usage.canonical!])
+  )
+}
+
+/**
+ * Code block languages used in a collection, with the blocks Shiki won't
+ * highlight, and a fix that rewrites aliases to one spelling
+ */
+export function CodeBlockAuditDialog() {
+  const collection = useCodeBlockAuditStore(state => state.collection)
+  const close = useCodeBlockAuditStore(state => state.close)
+  const projectPath = useProjectStore(state => state.projectPath)
+  const projectAliases = useProjectStore(
+    useShallow(state => state.currentProjectSettings?.codeLanguageAliases)
+  )
+  const { data: audit, isLoading } = useCodeBlockAuditQuery(
+    projectPath,
+    collection?.name ?? null,
+    collection?.path ?? null
+  )
+  const normalize = useNormalizeCodeBlocksMutation()
+
+  const mapping = rewriteMapping(audit, projectAliases)
+  const rewrites = Object.entries(mapping).filter(([from]) =>
+    audit?.languages.some(usage => usage.language === from)
+  )
+
+  const [showPreview, setShowPreview] = React.useState(false)
+  const { data: preview } = useChangePreviewQuery(
+    projectPath,
+    showPreview && projectPath && collection && rewrites.length > 0
+      ? {
+          kind: 'normalizeCodeBlockLanguages',
+          projectPath,
+          collectionPath: collection.path,
+          mapping: Object.fromEntries(rewrites),
+        }
+      : null
+  )
+
+  const relativePath = (filePath: string) =>
+    collection && filePath.startsWith(collection.path)
+      ? filePath.slice(collection.path.length + 1)
+      : filePath
+
+  return (
+    <Dialog
+      open={collection !== null}
+      onOpenChange={open => {
+        if (open) return
+        setShowPreview(false)
+        close()
+      }}
+    >
+      <DialogContent className="sm:max-w-2xl">
+        <DialogHeader>
+          <DialogTitle>Code Blocks in {collection?.name}</DialogTitle>
+          <DialogDescription>
+            Languages Shiki doesn&apos;t know are shown as plain text, without
+            highlighting.
+          </DialogDescription>
+        </DialogHeader>
+
+        {isLoading || !audit ? (
+          <div className="py-8 text-center text-sm text-muted-foreground">
+            Checking code blocks…
+          </div>
+        ) : audit.languages.length === 0 ? (
+          <div className="py-8 text-center text-sm text-muted-foreground">
+            No code blocks name a language.
+          </div>
+        ) : (
+          <div className="flex max-h-96 flex-col gap-4 overflow-y-auto">
+            <div className="flex flex-wrap gap-1.5">
+              {audit.languages.map(usage => (
+                <Badge
+                  key={usage.language}
+                  variant={usage.known ? 'secondary' : 'destructive'}
+                  className="font-mono"
+                >
+                  {usage.language} · {usage.count}
+                </Badge>
+              ))}
+            </div>
+
+            {audit.unknownBlocks.length > 0 && (
+              <ul className="divide-y">
+                {audit.unknownBlocks.map(block => (
+                  <li key={`${block.filePath}:${block.line}`}>
+                    <button
+                      type="button"
+                      className="flex w-full items-center gap-2 rounded-md px-2 py-1.5 text-left text-sm hover:bg-accent"
+                      onClick={() => {
+                        close()
+                        void openProjectFile(block.filePath, block.line)
+                      }}
+                    >
+                      <AlertTriangle className="size-3.5 shrink-0 text-muted-foreground" />
+                      <span className="truncate">
+                        {relativePath(block.filePath)}:{block.line}
+                      </span>
+                      <span className="ml-auto shrink-0 font-mono text-xs text-muted-foreground">
+                        {block.language}
+                        {block.suggestion && ` → ${block.suggestion}?`}
+                      </span>
+                    </button>
+                  </li>
+                ))}
+              </ul>
+            )}
+
+            {audit.unlabelledCount > 0 && (
+              <p className="text-sm text-muted-foreground">
+                {audit.unlabelledCount} code blocks have no language.
+              </p>
+            )}
+          </div>
+        )}
+
+        {showPreview && preview && (
+          <ChangePreviewList preview={preview} rootPath={collection?.path} />
+        )}
+
+        {rewrites.length > 0 && (
+          <DialogFooter className="items-center sm:justify-between">
+            <span className="font-mono text-xs text-muted-foreground">
+              {rewrites.map(([from, to]) => `${from} → ${to}`).join(', ')}
+            </span>
+            <div className="flex gap-2">
+              <Button
+                variant="outline"
+                onClick={() => setShowPreview(shown => !shown)}
+              >
+                {showPreview ? 'Hide Changes' : 'Preview Changes'}
+              </Button>
+              <Button
+                disabled={normalize.isPending}
+                onClick={() => {
+                  if (!projectPath || !collection) return
+                  setShowPreview(false)
+                  normalize.mutate({
+                    projectPath,
+                    collectionName: collection.name,
+                    collectionPath: collection.path,
+                    mapping: Object.fromEntries(rewrites),
+                  })
+                }}
+              >
+                Rewrite Languages
+              </Button>
+            </div>
+          </DialogFooter>
+        )}
+      </DialogContent>
+    </Dialog>
+  )
+}
@@ -0,0 +1 @@
+export { CodeBlockAuditDialog } from './CodeBlockAuditDialog'
@@ -9,16 +9,28 @@ import {
   CommandSeparator,
 } from '../ui/command'
 import { useCommandPalette } from '../../hooks/useCommandPalette'
+import { FileMatches } from './FileMatches'
+import { RecentFiles } from './RecentFiles'
 
 /**
  * Command palette component with Cmd+P shortcut
- * Provides quick access to application commands
+ * Provides quick access to application commands, recently used files, and
+ * files and headings matching the search
  */
 export function CommandPalette() {
   const { open, setOpen, commandGroups, executeCommand } = useCommandPalette()
+  const [search, setSearch] = React.useState('')
+
+  // Start from an empty search each time the palette opens
+  React.useEffect(() => {
+    if (!open) setSearch('')
+  }, [open])
 
   // Custom filter function that only searches command labels
   const customFilter = React.useCallback((value: string, search: string) => {
+    // File matches are already ranked by the Rust index
+    if (value.startsWith('file:')) return 1
+
     // Extract the label from the value (format: "id:label")
     const label = value.split(':')[1] || value
 
@@ -38,9 +50,18 @@ export function CommandPalette() {
       filter={customFilter}
       loop
     >
-      <CommandInput placeholder="Type a command..." />
+      <CommandInput
+        placeholder="Type a command or file name..."
+        value={search}
+        onValueChange={setSearch}
+      />
       <CommandList>
         <CommandEmpty>No results found.</CommandEmpty>
+        {search.trim() ? (
+          <FileMatches search={search} onOpen={() => setOpen(false)} />
+        ) : (
+          <RecentFiles onOpen={() => setOpen(false)} />
+        )}
 
         {commandGroups.map((group, groupIndex) => (
           <React.Fragment key={group.heading}>
@@ -0,0 +1,97 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent } from '@testing-library/react'
+import { FileMatches } from './FileMatches'
+import { Command, CommandList } from '../ui/command'
+import { useProjectStore } from '../../store/projectStore'
+import { renderWithProviders } from '../../test/test-utils'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    fuzzyFind: vi.fn(),
+  },
+}))
+vi.mock('../../lib/open-file', () => ({
+  openProjectFile: vi.fn(),
+}))
+
+import { commands } from '@/lib/bindings'
+import { openProjectFile } from '../../lib/open-file'
+
+const renderMatches = (search: string, onOpen = vi.fn()) =>
+  renderWithProviders(
+    <Command>
+      <CommandList>
+        <FileMatches search={search} onOpen={onOpen} />
+      </CommandList>
+    </Command>
+  )
+
+// cmdk measures its list and scrolls the selected item into view
+vi.stubGlobal(
+  'ResizeObserver',
+  class {
+    observe() {}
+    unobserve() {}
+    disconnect() {}
+  }
+)
+Element.prototype.scrollIntoView = vi.fn()
+
+describe('FileMatches', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    useProjectStore.setState({
+      projectPath: '/project',
+      currentProjectSettings: null,
+    })
+    vi.mocked(commands.fuzzyFind).mockResolvedValue({
+      status: 'ok',
+      data: [
+        {
+          filePath: '/project/src/content/blog/hello.md',
+          collectionName: 'blog',
+          kind: 'heading',
+          text: 'Getting started',
+          line: 12,
+          score: 90,
+          positions: [0, 1, 2],
+        },
+      ],
+    })
+  })
+
+  it('lists matches with their path and line', async () => {
+    renderMatches('get')
+
+    expect(
+      await screen.findByText('src/content/blog/hello.md:12')
+    ).toBeInTheDocument()
+    expect(screen.getByText('blog')).toBeInTheDocument()
+    expect(commands.fuzzyFind).toHaveBeenCalledWith(
+      '/project',
+      null,
+      'get',
+      20
+    )
+  })
+
+  it('opens the file at the matched heading', async () => {
+    const onOpen = vi.fn()
+    renderMatches('get', onOpen)
+
+    fireEvent.click(await screen.findByText('src/content/blog/hello.md:12'))
+
+    expect(onOpen).toHaveBeenCalled()
+    expect(openProjectFile).toHaveBeenCalledWith(
+      '/project/src/content/blog/hello.md',
+      12
+    )
+  })
+
+  it('shows nothing without a search', () => {
+    renderMatches('')
+
+    expect(commands.fuzzyFind).not.toHaveBeenCalled()
+    expect(screen.queryByText('Go to')).not.toBeInTheDocument()
+  })
+})
@@ -0,0 +1,91 @@
+import React from 'react'
+import { FileText, Hash } from 'lucide-react'
+import { CommandGroup, CommandItem } from '../ui/command'
+import { Badge } from '../ui/badge'
+import { useProjectStore } from '../../store/projectStore'
+import { useFuzzyFindQuery } from '../../hooks/queries/useFuzzyFindQuery'
+import { getEffectiveContentDirectory } from '../../lib/project-registry'
+import { ASTRO_PATHS } from '../../lib/constants'
+import { openProjectFile } from '../../lib/open-file'
+import type { FuzzyMatch } from '@/types'
+
+/**
+ * Match text with the characters that matched the query in bold
+ */
+function HighlightedText({ match }: { match: FuzzyMatch }) {
+  const positions = new Set(match.positions)
+  return (
+    <span className="truncate">
+      {Array.from(match.text).map((char, index) =>
+        positions.has(index) ? (
+          <span key={index} className="font-semibold text-foreground">
+            {char}
+          </span>
+        ) : (
+          char
+        )
+      )}
+    </span>
+  )
+}
+
+interface FileMatchesProps {
+  search: string
+  onOpen: () => void
+}
+
+/**
+ * Entries and headings matching the palette's search, ranked by the Rust
+ * index, for jumping straight to a file
+ */
+export function FileMatches({ search, onOpen }: FileMatchesProps) {
+  const projectPath = useProjectStore(state => state.projectPath)
+  const currentProjectSettings = useProjectStore(
+    state => state.currentProjectSettings
+  )
+  const contentDirectory = getEffectiveContentDirectory(currentProjectSettings)
+  const { data: matches = [] } = useFuzzyFindQuery(
+    projectPath,
+    search,
+    contentDirectory !== ASTRO_PATHS.CONTENT_DIR ? contentDirectory : undefined,
+    20
+  )
+
+  if (!search.trim() || matches.length === 0) return null
+
+  const relativePath = (filePath: string) =>
+    projectPath && filePath.startsWith(projectPath)
+      ? filePath.slice(projectPath.length + 1)
+      : filePath
+
+  return (
+    <CommandGroup heading="Go to">
+      {matches.map(match => {
+        const Icon = match.kind === 'heading' ? Hash : FileText
+        return (
+          <CommandItem
+            key={`${match.filePath}:${match.kind}:${match.line ?? ''}`}
+            value={`file:${match.filePath}:${match.kind}:${match.line ?? ''}`}
+            onSelect={() => {
+              onOpen()
+              void openProjectFile(match.filePath, match.line)
+            }}
+            className="cursor-pointer"
+          >
+            <Icon className="mr-2 h-4 w-4" />
+            <div className="flex min-w-0 flex-1 flex-col">
+              <HighlightedText match={match} />
+              <span className="truncate text-xs text-muted-foreground">
+                {relativePath(match.filePath)}
+                {match.line !== null && `:${match.line}`}
+              </span>
+            </div>
+            <Badge variant="outline" className="shrink-0 text-xs">
+              {match.collectionName}
+            </Badge>
+          </CommandItem>
+        )
+      })}
+    </CommandGroup>
+  )
+}
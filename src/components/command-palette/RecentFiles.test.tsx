@@ -0,0 +1,91 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent } from '@testing-library/react'
+import { RecentFiles } from './RecentFiles'
+import { Command, CommandList } from '../ui/command'
+import { useProjectStore } from '../../store/projectStore'
+import { renderWithProviders } from '../../test/test-utils'
+import type { FileUsageEntry } from '@/types'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    getRecentFiles: vi.fn(),
+    getFrequentFiles: vi.fn(),
+  },
+}))
+vi.mock('../../lib/open-file', () => ({
+  openProjectFile: vi.fn(),
+}))
+
+import { commands } from '@/lib/bindings'
+import { openProjectFile } from '../../lib/open-file'
+
+// cmdk measures its list and scrolls the selected item into view
+vi.stubGlobal(
+  'ResizeObserver',
+  class {
+    observe() {}
+    unobserve() {}
+    disconnect() {}
+  }
+)
+Element.prototype.scrollIntoView = vi.fn()
+
+const usage = (relativePath: string): FileUsageEntry => ({
+  filePath: `/project/${relativePath}`,
+  relativePath,
+  openCount: 1,
+  editCount: 0,
+  lastOpened: null,
+  lastEdited: null,
+})
+
+describe('RecentFiles', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    useProjectStore.setState({ projectPath: '/project' })
+    vi.mocked(commands.getRecentFiles).mockResolvedValue({
+      status: 'ok',
+      data: [usage('src/content/blog/latest.md')],
+    })
+    vi.mocked(commands.getFrequentFiles).mockResolvedValue({
+      status: 'ok',
+      data: [
+        usage('src/content/blog/latest.md'),
+        usage('src/content/notes/ideas.md'),
+      ],
+    })
+  })
+
+  it('lists recent files, then frequent files not already listed', async () => {
+    renderWithProviders(
+      <Command>
+        <CommandList>
+          <RecentFiles onOpen={vi.fn()} />
+        </CommandList>
+      </Command>
+    )
+
+    expect(await screen.findByText('ideas.md')).toBeInTheDocument()
+    expect(screen.getByText('Recent Files')).toBeInTheDocument()
+    expect(screen.getByText('Frequently Used')).toBeInTheDocument()
+    expect(screen.getAllByText('latest.md')).toHaveLength(1)
+  })
+
+  it('opens the chosen file', async () => {
+    const onOpen = vi.fn()
+    renderWithProviders(
+      <Command>
+        <CommandList>
+          <RecentFiles onOpen={onOpen} />
+        </CommandList>
+      </Command>
+    )
+
+    fireEvent.click(await screen.findByText('latest.md'))
+
+    expect(onOpen).toHaveBeenCalled()
+    expect(openProjectFile).toHaveBeenCalledWith(
+      '/project/src/content/blog/latest.md'
+    )
+  })
+})
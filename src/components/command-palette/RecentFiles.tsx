@@ -0,0 +1,87 @@
+import React from 'react'
+import { Clock, Flame } from 'lucide-react'
+import { CommandGroup, CommandItem } from '../ui/command'
+import { useProjectStore } from '../../store/projectStore'
+import {
+  useFrequentFilesQuery,
+  useRecentFilesQuery,
+} from '../../hooks/queries/useFileUsageQuery'
+import { openProjectFile } from '../../lib/open-file'
+import type { FileUsageEntry } from '@/types'
+
+const FILES_PER_GROUP = 5
+
+interface UsageGroupProps {
+  heading: string
+  files: FileUsageEntry[]
+  icon: React.ComponentType<{ className?: string }>
+  onOpen: () => void
+}
+
+function UsageGroup({ heading, files, icon: Icon, onOpen }: UsageGroupProps) {
+  if (files.length === 0) return null
+
+  return (
+    <CommandGroup heading={heading}>
+      {files.map(file => (
+        <CommandItem
+          key={file.filePath}
+          value={`file:${heading}:${file.filePath}`}
+          onSelect={() => {
+            onOpen()
+            void openProjectFile(file.filePath)
+          }}
+          className="cursor-pointer"
+        >
+          <Icon className="mr-2 h-4 w-4" />
+          <div className="flex min-w-0 flex-col">
+            <span className="truncate">
+              {file.relativePath.split(/[\\/]/).pop()}
+            </span>
+            <span className="truncate text-xs text-muted-foreground">
+              {file.relativePath}
+            </span>
+          </div>
+        </CommandItem>
+      ))}
+    </CommandGroup>
+  )
+}
+
+/**
+ * Files to jump back into before anything is typed: the most recently used,
+ * then the most used of the rest
+ */
+export function RecentFiles({ onOpen }: { onOpen: () => void }) {
+  const projectPath = useProjectStore(state => state.projectPath)
+  const { data: recent = [] } = useRecentFilesQuery(
+    projectPath,
+    FILES_PER_GROUP
+  )
+  const { data: frequent = [] } = useFrequentFilesQuery(
+    projectPath,
+    FILES_PER_GROUP * 2
+  )
+
+  const recentPaths = new Set(recent.map(file => file.filePath))
+  const frequentOnly = frequent
+    .filter(file => !recentPaths.has(file.filePath))
+    .slice(0, FILES_PER_GROUP)
+
+  return (
+    <>
+      <UsageGroup
+        heading="Recent Files"
+        files={recent}
+        icon={Clock}
+        onOpen={onOpen}
+      />
+      <UsageGroup
+        heading="Frequently Used"
+        files={frequentOnly}
+        icon={Flame}
+        onOpen={onOpen}
+      />
+    </>
+  )
+}
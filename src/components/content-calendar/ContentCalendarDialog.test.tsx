@@ -0,0 +1,92 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent } from '@testing-library/react'
+import { ContentCalendarDialog } from './ContentCalendarDialog'
+import { useContentCalendarStore } from '../../store/contentCalendarStore'
+import { useProjectStore } from '../../store/projectStore'
+import { renderWithProviders } from '../../test/test-utils'
+import { formatIsoDate } from '../../lib/dates'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    getContentCalendar: vi.fn(),
+  },
+}))
+vi.mock('../../lib/open-file', () => ({
+  openProjectFile: vi.fn(),
+}))
+
+import { commands } from '@/lib/bindings'
+import { openProjectFile } from '../../lib/open-file'
+
+const now = new Date()
+const dayOfMonth = (offset: number, day: number) =>
+  formatIsoDate(new Date(now.getFullYear(), now.getMonth() + offset, day))
+const item = (title: string, date: string, scheduled = false) => ({
+  filePath: `/project/src/content/blog/${title.toLowerCase()}.md`,
+  collection: 'blog',
+  title,
+  isDraft: false,
+  field: 'pubDate',
+  kind: 'published' as const,
+  date,
+  scheduled,
+})
+
+describe('ContentCalendarDialog', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    vi.mocked(commands.getContentCalendar).mockResolvedValue({
+      status: 'ok',
+      data: {
+        months: [
+          {
+            month: dayOfMonth(-1, 1).slice(0, 7),
+            items: [item('Earlier', dayOfMonth(-1, 20))],
+          },
+          {
+            month: dayOfMonth(0, 1).slice(0, 7),
+            items: [item('Launch', dayOfMonth(0, 28), true)],
+          },
+        ],
+        undatedDrafts: [
+          {
+            filePath: '/project/src/content/blog/idea.md',
+            collection: 'blog',
+            title: 'Idea',
+          },
+        ],
+      },
+    })
+    useProjectStore.setState({ projectPath: '/project' })
+    useContentCalendarStore.setState({ isOpen: true })
+  })
+
+  it('shows the current month with undated drafts', async () => {
+    renderWithProviders(<ContentCalendarDialog />)
+
+    expect(await screen.findByText('Launch')).toBeInTheDocument()
+    expect(screen.getByText('scheduled')).toBeInTheDocument()
+    expect(screen.getByText('Idea')).toBeInTheDocument()
+    expect(screen.queryByText('Earlier')).not.toBeInTheDocument()
+  })
+
+  it('steps back a month', async () => {
+    renderWithProviders(<ContentCalendarDialog />)
+
+    await screen.findByText('Launch')
+    fireEvent.click(screen.getByRole('button', { name: 'Previous month' }))
+
+    expect(screen.getByText('Earlier')).toBeInTheDocument()
+  })
+
+  it('opens an entry', async () => {
+    renderWithProviders(<ContentCalendarDialog />)
+
+    fireEvent.click(await screen.findByText('Launch'))
+
+    expect(openProjectFile).toHaveBeenCalledWith(
+      '/project/src/content/blog/launch.md'
+    )
+    expect(useContentCalendarStore.getState().isOpen).toBe(false)
+  })
+})
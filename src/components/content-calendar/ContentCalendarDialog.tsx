@@ -0,0 +1,150 @@
+import React from 'react'
+import { ChevronLeft, ChevronRight } from 'lucide-react'
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+} from '../ui/dialog'
+import { Badge } from '../ui/badge'
+import { Button } from '../ui/button'
+import { useContentCalendarStore } from '../../store/contentCalendarStore'
+import { useProjectStore } from '../../store/projectStore'
+import { useContentCalendarQuery } from '../../hooks/queries/useContentCalendarQuery'
+import { openProjectFile } from '../../lib/open-file'
+import { parseIsoDate } from '../../lib/dates'
+
+/** `YYYY-MM` for a month, as the calendar groups by */
+function monthKey(date: Date): string {
+  return `${date.getFullYear()}-${String(date.getMonth() + 1).padStart(2, '0')}`
+}
+
+/**
+ * A month of entries by date: published, scheduled and updated, with drafts
+ * that have no publish date yet listed below. Choosing an entry opens it.
+ */
+export function ContentCalendarDialog() {
+  const isOpen = useContentCalendarStore(state => state.isOpen)
+  const close = useContentCalendarStore(state => state.close)
+  const projectPath = useProjectStore(state => state.projectPath)
+  const { data: calendar, isLoading } = useContentCalendarQuery(
+    isOpen ? projectPath : null
+  )
+  const [month, setMonth] = React.useState(() => {
+    const today = new Date()
+    return new Date(today.getFullYear(), today.getMonth(), 1)
+  })
+
+  const items =
+    calendar?.months.find(entry => entry.month === monthKey(month))?.items ??
+    []
+  const undatedDrafts = calendar?.undatedDrafts ?? []
+
+  const stepMonth = (step: number) =>
+    setMonth(new Date(month.getFullYear(), month.getMonth() + step, 1))
+
+  const openEntry = (filePath: string) => {
+    close()
+    void openProjectFile(filePath)
+  }
+
+  return (
+    <Dialog open={isOpen} onOpenChange={open => !open && close()}>
+      <DialogContent className="sm:max-w-2xl">
+        <DialogHeader>
+          <DialogTitle>Content Calendar</DialogTitle>
+          <DialogDescription>
+            Entries by their publish and update dates. Future publish dates
+            are marked as scheduled.
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="flex items-center justify-between">
+          <Button
+            variant="ghost"
+            size="icon"
+            aria-label="Previous month"
+            onClick={() => stepMonth(-1)}
+          >
+            <ChevronLeft className="size-4" />
+          </Button>
+          <span className="text-sm font-medium">
+            {month.toLocaleDateString(undefined, {
+              month: 'long',
+              year: 'numeric',
+            })}
+          </span>
+          <Button
+            variant="ghost"
+            size="icon"
+            aria-label="Next month"
+            onClick={() => stepMonth(1)}
+          >
+            <ChevronRight className="size-4" />
+          </Button>
+        </div>
+
+        {isLoading ? (
+          <div className="py-8 text-center text-sm text-muted-foreground">
+            Reading dates…
+          </div>
+        ) : items.length === 0 ? (
+          <div className="py-8 text-center text-sm text-muted-foreground">
+            Nothing dated this month.
+          </div>
+        ) : (
+          <ul className="max-h-80 divide-y overflow-y-auto">
+            {items.map(item => (
+              <li key={`${item.filePath}:${item.field}`}>
+                <button
+                  type="button"
+                  className="flex w-full items-center gap-2 rounded-md px-2 py-1.5 text-left text-sm hover:bg-accent"
+                  onClick={() => openEntry(item.filePath)}
+                >
+                  <span className="w-6 shrink-0 text-right font-mono text-xs text-muted-foreground">
+                    {parseIsoDate(item.date)?.getDate()}
+                  </span>
+                  <span className="truncate">{item.title}</span>
+                  <span className="text-xs text-muted-foreground">
+                    {item.collection}
+                  </span>
+                  <span className="ml-auto flex shrink-0 gap-1">
+                    {item.kind === 'updated' && (
+                      <Badge variant="outline">updated</Badge>
+                    )}
+                    {item.scheduled && (
+                      <Badge variant="secondary">scheduled</Badge>
+                    )}
+                    {item.isDraft && <Badge variant="outline">draft</Badge>}
+                  </span>
+                </button>
+              </li>
+            ))}
+          </ul>
+        )}
+
+        {undatedDrafts.length > 0 && (
+          <div>
+            <h3 className="px-2 text-xs font-medium text-muted-foreground">
+              Drafts without a publish date
+            </h3>
+            <ul className="max-h-32 overflow-y-auto">
+              {undatedDrafts.map(draft => (
+                <li key={draft.filePath}>
+                  <button
+                    type="button"
+                    className="w-full truncate rounded-md px-2 py-1 text-left text-sm hover:bg-accent"
+                    onClick={() => openEntry(draft.filePath)}
+                  >
+                    {draft.title}
+                  </button>
+                </li>
+              ))}
+            </ul>
+          </div>
+        )}
+      </DialogContent>
+    </Dialog>
+  )
+}
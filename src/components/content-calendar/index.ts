@@ -0,0 +1 @@
+export { ContentCalendarDialog } from './ContentCalendarDialog'
@@ -0,0 +1,70 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent, waitFor } from '@testing-library/react'
+import { ContentGraphDialog } from './ContentGraphDialog'
+import { useContentGraphStore } from '../../store/contentGraphStore'
+import { useProjectStore } from '../../store/projectStore'
+import { renderWithProviders } from '../../test/test-utils'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    exportContentGraph: vi.fn(),
+    copyTextToClipboard: vi.fn(),
+  },
+}))
+
+import { commands } from '@/lib/bindings'
+
+describe('ContentGraphDialog', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    vi.mocked(commands.exportContentGraph).mockImplementation(
+      async (_project, _content, format) => ({
+        status: 'ok',
+        data: {
+          nodes: [
+            { id: 'blog/a', label: 'A', collection: 'blog', cluster: 0 },
+            { id: 'blog/b', label: 'B', collection: 'blog', cluster: 0 },
+            { id: 'notes/c', label: 'C', collection: 'notes', cluster: 1 },
+          ],
+          edges: [{ from: 'blog/a', to: 'blog/b', kind: 'link', detail: null }],
+          clusterCount: 2,
+          isolated: ['notes/c'],
+          output: format === 'dot' ? 'digraph content {}' : '{"nodes":[]}',
+        },
+      })
+    )
+    vi.mocked(commands.copyTextToClipboard).mockResolvedValue({
+      status: 'ok',
+      data: null,
+    })
+    useProjectStore.setState({
+      projectPath: '/project',
+      currentProjectSettings: null,
+    })
+    useContentGraphStore.setState({ isOpen: true })
+  })
+
+  it('summarizes the graph and lists isolated entries', async () => {
+    renderWithProviders(<ContentGraphDialog />)
+
+    expect(
+      await screen.findByText('3 entries, 1 connections, 2 clusters')
+    ).toBeInTheDocument()
+    expect(screen.getByText('notes/c')).toBeInTheDocument()
+    expect(screen.getByText('digraph content {}')).toBeInTheDocument()
+  })
+
+  it('copies the graph in the chosen format', async () => {
+    renderWithProviders(<ContentGraphDialog />)
+
+    fireEvent.click(screen.getByRole('radio', { name: 'JSON' }))
+    await screen.findByText('{"nodes":[]}')
+    fireEvent.click(screen.getByRole('button', { name: 'Copy Graph' }))
+
+    await waitFor(() =>
+      expect(commands.copyTextToClipboard).toHaveBeenCalledWith(
+        '{"nodes":[]}'
+      )
+    )
+  })
+})
@@ -0,0 +1,104 @@
+import React from 'react'
+import { useShallow } from 'zustand/react/shallow'
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from '../ui/dialog'
+import { Button } from '../ui/button'
+import { ToggleGroup, ToggleGroupItem } from '../ui/toggle-group'
+import { useContentGraphStore } from '../../store/contentGraphStore'
+import { useProjectStore } from '../../store/projectStore'
+import { useContentGraphQuery } from '../../hooks/queries/useContentGraphQuery'
+import { commands } from '@/lib/bindings'
+import { toast } from '../../lib/toast'
+import type { GraphFormat } from '@/types'
+
+/**
+ * The project's entries connected by references, links and series, with
+ * the isolated ones listed and the graph ready to copy as DOT or JSON
+ */
+export function ContentGraphDialog() {
+  const isOpen = useContentGraphStore(state => state.isOpen)
+  const close = useContentGraphStore(state => state.close)
+  const projectPath = useProjectStore(state => state.projectPath)
+  const currentProjectSettings = useProjectStore(
+    useShallow(state => state.currentProjectSettings)
+  )
+  const [format, setFormat] = React.useState<GraphFormat>('dot')
+  const { data: graph, isLoading } = useContentGraphQuery(
+    isOpen ? projectPath : null,
+    currentProjectSettings,
+    format
+  )
+
+  const copyGraph = async () => {
+    if (!graph) return
+    const result = await commands.copyTextToClipboard(graph.output)
+    if (result.status === 'error') {
+      toast.error('Failed to copy graph', { description: result.error })
+      return
+    }
+    toast.success(`Graph copied as ${format.toUpperCase()}`)
+  }
+
+  return (
+    <Dialog open={isOpen} onOpenChange={open => !open && close()}>
+      <DialogContent className="sm:max-w-2xl">
+        <DialogHeader>
+          <DialogTitle>Content Graph</DialogTitle>
+          <DialogDescription>
+            Entries connected by reference fields, links in their body and
+            shared series.
+          </DialogDescription>
+        </DialogHeader>
+
+        {isLoading || !graph ? (
+          <div className="py-8 text-center text-sm text-muted-foreground">
+            Building graph…
+          </div>
+        ) : (
+          <div className="flex flex-col gap-3">
+            <p className="text-sm">
+              {`${graph.nodes.length} entries, ${graph.edges.length} ` +
+                `connections, ${graph.clusterCount} clusters`}
+            </p>
+            {graph.isolated.length > 0 && (
+              <div>
+                <h3 className="text-xs font-medium text-muted-foreground">
+                  Not connected to anything
+                </h3>
+                <ul className="max-h-32 overflow-y-auto font-mono text-xs">
+                  {graph.isolated.map(id => (
+                    <li key={id}>{id}</li>
+                  ))}
+                </ul>
+              </div>
+            )}
+            <pre className="max-h-64 overflow-auto rounded-md bg-muted p-3 text-xs">
+              {graph.output}
+            </pre>
+          </div>
+        )}
+
+        <DialogFooter className="items-center sm:justify-between">
+          <ToggleGroup
+            type="single"
+            variant="outline"
+            value={format}
+            onValueChange={value => value && setFormat(value as GraphFormat)}
+          >
+            <ToggleGroupItem value="dot">DOT</ToggleGroupItem>
+            <ToggleGroupItem value="json">JSON</ToggleGroupItem>
+          </ToggleGroup>
+          <Button disabled={!graph} onClick={() => void copyGraph()}>
+            Copy Graph
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  )
+}
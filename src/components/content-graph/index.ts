@@ -0,0 +1 @@
+export { ContentGraphDialog } from './ContentGraphDialog'
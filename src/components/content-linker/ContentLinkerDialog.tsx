@@ -15,7 +15,9 @@ import { useEditorStore } from '../../store/editorStore'
 import { useCollectionsQuery } from '../../hooks/queries/useCollectionsQuery'
 import { getCollectionSettings } from '../../lib/project-registry/collection-settings'
 import { resolveTitle } from '../../lib/content-linker'
-import { commands, type FileEntry, type Collection } from '@/types'
+import { getListingOrder } from '../../lib/files/sorting'
+import { streamCollectionFiles } from '../../lib/listing-stream'
+import type { FileEntry, Collection } from '@/types'
 import { usePlatform } from '../../hooks/usePlatform'
 
 const EMPTY_COLLECTIONS: Collection[] = []
@@ -59,21 +61,27 @@ export function ContentLinkerDialog() {
     }
 
     let cancelled = false
+    const controller = new AbortController()
 
     const fetchAll = async () => {
       try {
         const results = await Promise.all(
-          collections.map(collection =>
-            commands.scanCollectionFilesRecursive(
-              collection.path,
+          collections.map(collection => {
+            const { collation, titleField, draftField } = getListingOrder(
+              currentProjectSettings,
               collection.name
             )
-          )
+            return streamCollectionFiles(collection.path, collection.name, {
+              recursive: true,
+              collation,
+              titleField,
+              draftField,
+              signal: controller.signal,
+            }).catch(() => [])
+          })
         )
 
-        const files = results.flatMap(result =>
-          result.status === 'ok' ? result.data : []
-        )
+        const files = results.flat()
 
         if (!cancelled) {
           setAllFiles(files)
@@ -102,6 +110,7 @@ export function ContentLinkerDialog() {
 
     return () => {
       cancelled = true
+      controller.abort()
     }
     // eslint-disable-next-line react-hooks/exhaustive-deps
   }, [isOpen, projectPath, collectionsKey])
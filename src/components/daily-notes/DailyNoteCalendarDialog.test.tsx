@@ -0,0 +1,65 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent } from '@testing-library/react'
+import { DailyNoteCalendarDialog } from './DailyNoteCalendarDialog'
+import { useDailyNotesStore } from '../../store/dailyNotesStore'
+import { useProjectStore } from '../../store/projectStore'
+import { renderWithProviders } from '../../test/test-utils'
+import { formatIsoDate } from '../../lib/dates'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    getDailyNoteCalendar: vi.fn(),
+  },
+}))
+vi.mock('../../lib/daily-notes', () => ({
+  openDailyNote: vi.fn(),
+}))
+
+import { commands } from '@/lib/bindings'
+import { openDailyNote } from '../../lib/daily-notes'
+
+const now = new Date()
+const dayOfThisMonth = (day: number) =>
+  formatIsoDate(new Date(now.getFullYear(), now.getMonth(), day))
+
+describe('DailyNoteCalendarDialog', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    vi.mocked(commands.getDailyNoteCalendar).mockResolvedValue({
+      status: 'ok',
+      data: [
+        { date: dayOfThisMonth(14), filePath: '/a.md', wordCount: 200 },
+        { date: dayOfThisMonth(15), filePath: '/b.md', wordCount: 150 },
+      ],
+    })
+    useProjectStore.setState({
+      projectPath: '/project',
+      currentProjectSettings: null,
+    })
+    useDailyNotesStore.setState({ isOpen: true })
+  })
+
+  it('summarizes the notes of the current month', async () => {
+    renderWithProviders(<DailyNoteCalendarDialog />)
+
+    expect(
+      await screen.findByText('2 notes this month · 350 words')
+    ).toBeInTheDocument()
+    expect(commands.getDailyNoteCalendar).toHaveBeenCalledWith(
+      '/project',
+      null,
+      null,
+      now.getFullYear(),
+      now.getMonth() + 1
+    )
+  })
+
+  it('opens the note for the chosen day', async () => {
+    renderWithProviders(<DailyNoteCalendarDialog />)
+
+    fireEvent.click(await screen.findByText('15'))
+
+    expect(openDailyNote).toHaveBeenCalledWith(dayOfThisMonth(15))
+    expect(useDailyNotesStore.getState().isOpen).toBe(false)
+  })
+})
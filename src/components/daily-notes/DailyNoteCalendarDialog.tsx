@@ -0,0 +1,78 @@
+import React from 'react'
+import { useShallow } from 'zustand/react/shallow'
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+} from '../ui/dialog'
+import { Calendar } from '../ui/calendar'
+import { useDailyNotesStore } from '../../store/dailyNotesStore'
+import { useProjectStore } from '../../store/projectStore'
+import { useDailyNoteCalendarQuery } from '../../hooks/queries/useDailyNoteCalendarQuery'
+import { openDailyNote } from '../../lib/daily-notes'
+import { formatIsoDate, parseIsoDate } from '../../lib/dates'
+
+/**
+ * A month of the journal, with the days that have a daily note marked.
+ * Choosing a day opens its note, creating it if there isn't one yet.
+ */
+export function DailyNoteCalendarDialog() {
+  const isOpen = useDailyNotesStore(state => state.isOpen)
+  const close = useDailyNotesStore(state => state.close)
+  const projectPath = useProjectStore(state => state.projectPath)
+  const currentProjectSettings = useProjectStore(
+    useShallow(state => state.currentProjectSettings)
+  )
+  const [month, setMonth] = React.useState(() => new Date())
+
+  const { data: days = [] } = useDailyNoteCalendarQuery(
+    isOpen ? projectPath : null,
+    currentProjectSettings,
+    month.getFullYear(),
+    month.getMonth() + 1
+  )
+
+  const noteDates = days
+    .map(day => parseIsoDate(day.date))
+    .filter((date): date is Date => date !== undefined)
+  const words = days.reduce((total, day) => total + day.wordCount, 0)
+  const notes = days.length === 1 ? '1 note' : `${days.length} notes`
+
+  const openDay = (date: Date) => {
+    close()
+    void openDailyNote(formatIsoDate(date))
+  }
+
+  return (
+    <Dialog open={isOpen} onOpenChange={open => !open && close()}>
+      <DialogContent className="sm:max-w-sm">
+        <DialogHeader>
+          <DialogTitle>Journal</DialogTitle>
+          <DialogDescription>
+            Days with a daily note are underlined. Choose a day to open its
+            note.
+          </DialogDescription>
+        </DialogHeader>
+
+        <Calendar
+          month={month}
+          onMonthChange={setMonth}
+          onDayClick={openDay}
+          modifiers={{ hasNote: noteDates }}
+          modifiersClassNames={{
+            hasNote: 'font-semibold underline underline-offset-4',
+          }}
+          className="mx-auto"
+        />
+
+        <p className="text-center text-sm text-muted-foreground">
+          {days.length === 0
+            ? 'No notes this month'
+            : `${notes} this month · ${words.toLocaleString()} words`}
+        </p>
+      </DialogContent>
+    </Dialog>
+  )
+}
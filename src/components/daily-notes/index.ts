@@ -0,0 +1 @@
+export { DailyNoteCalendarDialog } from './DailyNoteCalendarDialog'
@@ -22,6 +22,7 @@ import '../../lib/editor/extensions/copyedit-mode.css'
 declare global {
   interface Window {
     isEditorFocused: boolean
+    editorHasSelection: boolean
   }
 }
 
@@ -44,6 +45,7 @@ const EditorViewComponent: React.FC = () => {
   // Initialize global focus flag (menu state managed in Layout)
   useEffect(() => {
     window.isEditorFocused = false
+    window.editorHasSelection = false
   }, [])
 
   const { handleChange, handleFocus, handleBlur, handleSave } =
@@ -170,6 +172,13 @@ const EditorViewComponent: React.FC = () => {
       extensions: [
         ...extensions,
         EditorView.updateListener.of(update => {
+          // Report selection presence for menu state (managed in Layout)
+          const hasSelection = !update.state.selection.main.empty
+          if (hasSelection !== window.editorHasSelection) {
+            window.editorHasSelection = hasSelection
+            window.dispatchEvent(new CustomEvent('editor-selection-changed'))
+          }
+
           if (update.docChanged && !isProgrammaticUpdate.current) {
             const newContent = update.state.doc.toString()
             // Use captured handler to avoid infinite loops
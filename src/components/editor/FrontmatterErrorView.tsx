@@ -0,0 +1,114 @@
+import React from 'react'
+import { AlertTriangle } from 'lucide-react'
+import { Button } from '../ui/button'
+import { RawFrontmatterEditor } from '../frontmatter/RawFrontmatterEditor'
+import { openInIde, openInIdeAt } from '../../lib/ide'
+import { useProjectStore } from '../../store/projectStore'
+import type { FrontmatterError } from '@/types'
+
+interface FrontmatterErrorViewProps {
+  filePath: string
+  error: FrontmatterError
+  rawFrontmatter: string
+}
+
+/**
+ * Shown instead of the editor when a file's frontmatter can't be parsed:
+ * the raw frontmatter with the broken line highlighted, and a suggested fix.
+ * Unless the block is unclosed, it can be fixed here as YAML.
+ */
+export const FrontmatterErrorView: React.FC<FrontmatterErrorViewProps> = ({
+  filePath,
+  error,
+  rawFrontmatter,
+}) => {
+  const hasIde = useProjectStore(
+    state => !!state.globalSettings?.general?.ideCommand
+  )
+  const [isEditing, setIsEditing] = React.useState(false)
+  const canEdit = error.cause !== 'unclosed'
+
+  // Numbered as in the file, starting with the opening ---
+  const lines = ['---', ...rawFrontmatter.split('\n')]
+  if (error.cause !== 'unclosed') {
+    lines.push('---')
+  }
+
+  const column = error.column == null ? '' : `, column ${error.column}`
+  const location = error.line == null ? '' : ` (line ${error.line}${column})`
+
+  return (
+    <div className="mx-auto flex max-w-3xl flex-col gap-4 px-8 py-10">
+      <div className="flex items-start gap-3">
+        <AlertTriangle className="mt-0.5 h-5 w-5 shrink-0 text-destructive" />
+        <div className="flex flex-col gap-1">
+          <h2 className="m-0 text-base font-medium text-foreground">
+            The frontmatter in this file couldn&apos;t be read
+          </h2>
+          <p className="m-0 text-sm text-muted-foreground">
+            {error.message}
+            {location}
+          </p>
+          {error.suggestion && (
+            <p className="m-0 text-sm text-foreground">{error.suggestion}</p>
+          )}
+        </div>
+      </div>
+
+      {isEditing ? (
+        <RawFrontmatterEditor
+          filePath={filePath}
+          initialValue={rawFrontmatter}
+          onApplied={() => setIsEditing(false)}
+          onCancel={() => setIsEditing(false)}
+        />
+      ) : (
+        <pre className="m-0 overflow-x-auto rounded-md border border-border bg-muted/40 py-2 font-mono text-xs">
+          {lines.map((line, index) => {
+            const lineNumber = index + 1
+            const isBroken = lineNumber === error.line
+            return (
+              <div
+                key={lineNumber}
+                data-broken={isBroken || undefined}
+                className={`flex ${isBroken ? 'bg-destructive/15 text-destructive' : ''}`}
+              >
+                <span className="w-10 shrink-0 select-none pr-3 text-right text-muted-foreground">
+                  {lineNumber}
+                </span>
+                <span className="whitespace-pre">{line || ' '}</span>
+              </div>
+            )
+          })}
+        </pre>
+      )}
+
+      {!isEditing && (canEdit || hasIde) && (
+        <div className="flex gap-2">
+          {canEdit && (
+            <Button
+              variant="outline"
+              size="sm"
+              onClick={() => setIsEditing(true)}
+            >
+              Edit as YAML
+            </Button>
+          )}
+          {hasIde && (
+            <Button
+              variant="outline"
+              size="sm"
+              onClick={() =>
+                void (error.line == null
+                  ? openInIde(filePath)
+                  : openInIdeAt(filePath, error.line, error.column))
+              }
+            >
+              Open in IDE
+            </Button>
+          )}
+        </div>
+      )}
+    </div>
+  )
+}
@@ -1,6 +1,6 @@
 import React, { useEffect, useState } from 'react'
-import { convertFileSrc } from '@tauri-apps/api/core'
 import { commands } from '@/lib/bindings'
+import { projectAssetUrl } from '@/lib/files'
 import type { HoveredImage } from '../../hooks/editor/useImageHover'
 
 interface ImagePreviewProps {
@@ -60,8 +60,8 @@ const ImagePreviewComponent: React.FC<ImagePreviewProps> = ({
         }
 
         if (!cancelled) {
-          // Convert to asset protocol URL
-          const assetUrl = convertFileSrc(result.data)
+          // Serve through the project asset protocol
+          const assetUrl = projectAssetUrl(result.data)
           setImageUrl(assetUrl)
           setLoadingState('success')
         }
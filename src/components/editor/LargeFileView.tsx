@@ -0,0 +1,73 @@
+import React from 'react'
+import { FileWarning } from 'lucide-react'
+import { Button } from '../ui/button'
+import { useEditorStore } from '../../store/editorStore'
+import { useUIStore } from '../../store/uiStore'
+
+interface LargeFileViewProps {
+  sizeBytes: number
+  limitBytes: number
+}
+
+const formatMb = (bytes: number) => `${(bytes / 1024 / 1024).toFixed(1)} MB`
+
+const openAnyway = async () => {
+  // Frontmatter edits are written first, so loading the whole file keeps them
+  const { isDirty, saveFile } = useEditorStore.getState()
+  if (isDirty) await saveFile(false)
+  if (useEditorStore.getState().isDirty) return
+  useEditorStore.setState({ allowLargeFile: true })
+}
+
+/**
+ * Shown instead of the editor when a file is over the size limit. Its
+ * frontmatter is still loaded into the frontmatter panel, where it can be
+ * edited without touching the body, and the file can be opened anyway.
+ */
+export const LargeFileView: React.FC<LargeFileViewProps> = ({
+  sizeBytes,
+  limitBytes,
+}) => {
+  const frontmatterPanelVisible = useUIStore(
+    state => state.frontmatterPanelVisible
+  )
+
+  return (
+    <div className="mx-auto flex max-w-3xl flex-col gap-4 px-8 py-10">
+      <div className="flex items-start gap-3">
+        <FileWarning className="mt-0.5 h-5 w-5 shrink-0 text-muted-foreground" />
+        <div className="flex flex-col gap-1">
+          <h2 className="m-0 text-base font-medium text-foreground">
+            This file is too large to edit comfortably
+          </h2>
+          <p className="m-0 text-sm text-muted-foreground">
+            It&apos;s {formatMb(sizeBytes)}, over the {formatMb(limitBytes)}{' '}
+            limit for this project. Its frontmatter can still be edited in the
+            frontmatter panel; saving it leaves the rest of the file as it is.
+          </p>
+        </div>
+      </div>
+
+      <div className="flex gap-2">
+        <Button
+          variant="outline"
+          size="sm"
+          onClick={() => void openAnyway()}
+        >
+          Open anyway
+        </Button>
+        {!frontmatterPanelVisible && (
+          <Button
+            variant="outline"
+            size="sm"
+            onClick={() =>
+              useUIStore.getState().setFrontmatterPanelVisible(true)
+            }
+          >
+            Show frontmatter
+          </Button>
+        )}
+      </div>
+    </div>
+  )
+}
@@ -0,0 +1,95 @@
+import React from 'react'
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from '../ui/dialog'
+import { Button } from '../ui/button'
+import { useEditorStore, type SaveConflict } from '../../store/editorStore'
+import {
+  mergeWithDisk,
+  overwriteDisk,
+  reloadFromDisk,
+} from '../../lib/save-conflict'
+
+/**
+ * Shown when a save is refused because the open file changed on disk since
+ * it was loaded. Auto-save stays paused until one of the versions is chosen;
+ * closing the dialog keeps editing, and saving by hand brings it back.
+ */
+export function SaveConflictDialog() {
+  const saveConflict = useEditorStore(state => state.saveConflict)
+  const fileName = useEditorStore(state => state.currentFile?.name)
+  const [dismissed, setDismissed] = React.useState<SaveConflict | null>(null)
+  const [resolving, setResolving] = React.useState(false)
+
+  const resolve = async (action: () => Promise<void>) => {
+    setResolving(true)
+    await action()
+    setResolving(false)
+  }
+
+  const isOpen = saveConflict !== null && saveConflict !== dismissed
+  const deleted = saveConflict?.diskContent === null
+  // Merging needs the version both sides started from
+  const canMerge = !deleted && saveConflict?.baseContent != null
+
+  return (
+    <Dialog
+      open={isOpen}
+      onOpenChange={open => !open && setDismissed(saveConflict)}
+    >
+      <DialogContent className="sm:max-w-lg">
+        <DialogHeader>
+          <DialogTitle>
+            {fileName ? `${fileName} changed on disk` : 'File changed on disk'}
+          </DialogTitle>
+          <DialogDescription>
+            {deleted
+              ? 'The file was deleted since it was opened,'
+              : 'Another app changed the file since it was opened,'}{' '}
+            so your changes have not been saved. Auto-save is paused until
+            you choose which version to keep.
+          </DialogDescription>
+        </DialogHeader>
+
+        <DialogFooter>
+          <Button
+            variant="ghost"
+            disabled={resolving}
+            onClick={() => setDismissed(saveConflict)}
+          >
+            Keep Editing
+          </Button>
+          {!deleted && (
+            <Button
+              variant="outline"
+              disabled={resolving}
+              onClick={() => void resolve(reloadFromDisk)}
+            >
+              Reload from Disk
+            </Button>
+          )}
+          <Button
+            variant={canMerge ? 'outline' : 'default'}
+            disabled={resolving}
+            onClick={() => void resolve(overwriteDisk)}
+          >
+            {deleted ? 'Save Anyway' : 'Overwrite'}
+          </Button>
+          {canMerge && (
+            <Button
+              disabled={resolving}
+              onClick={() => void resolve(mergeWithDisk)}
+            >
+              Merge
+            </Button>
+          )}
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  )
+}
@@ -0,0 +1,94 @@
+import { describe, it, expect, beforeEach } from 'vitest'
+import { screen, fireEvent } from '@testing-library/dom'
+import { FrontmatterErrorView } from '../FrontmatterErrorView'
+import { useProjectStore } from '../../../store/projectStore'
+import type { FrontmatterError } from '@/types'
+import { renderWithProviders } from '../../../test/test-utils'
+
+const unquotedColon: FrontmatterError = {
+  message: 'mapping values are not allowed in this context',
+  line: 3,
+  column: 18,
+  snippet: 'description: Note: read this',
+  cause: 'unquotedColon',
+  suggestion: 'Quote the value: `description: "Note: read this"`',
+}
+
+describe('FrontmatterErrorView', () => {
+  beforeEach(() => {
+    useProjectStore.setState({ globalSettings: null })
+  })
+
+  it('shows the error, its location and the suggested fix', () => {
+    renderWithProviders(
+      <FrontmatterErrorView
+        filePath="/project/posts/broken.md"
+        error={unquotedColon}
+        rawFrontmatter={'title: Post\ndescription: Note: read this'}
+      />
+    )
+
+    expect(
+      screen.getByText(
+        'mapping values are not allowed in this context (line 3, column 18)'
+      )
+    ).toBeInTheDocument()
+    expect(
+      screen.getByText('Quote the value: `description: "Note: read this"`')
+    ).toBeInTheDocument()
+    expect(screen.queryByText('Open in IDE')).not.toBeInTheDocument()
+  })
+
+  it('highlights the broken line, numbered as in the file', () => {
+    const { container } = renderWithProviders(
+      <FrontmatterErrorView
+        filePath="/project/posts/broken.md"
+        error={unquotedColon}
+        rawFrontmatter={'title: Post\ndescription: Note: read this'}
+      />
+    )
+
+    const broken = container.querySelectorAll('[data-broken]')
+    expect(broken).toHaveLength(1)
+    expect(broken[0]).toHaveTextContent('3description: Note: read this')
+    // Opening and closing delimiters around the raw lines
+    expect(container.querySelector('pre')?.children).toHaveLength(4)
+  })
+
+  it('can switch to editing the frontmatter as YAML', () => {
+    renderWithProviders(
+      <FrontmatterErrorView
+        filePath="/project/posts/broken.md"
+        error={unquotedColon}
+        rawFrontmatter={'title: Post\ndescription: Note: read this'}
+      />
+    )
+
+    fireEvent.click(screen.getByRole('button', { name: 'Edit as YAML' }))
+
+    expect(screen.getByLabelText('Frontmatter YAML')).toHaveValue(
+      'title: Post\ndescription: Note: read this'
+    )
+  })
+
+  it('leaves the closing delimiter out when it is missing', () => {
+    const { container } = renderWithProviders(
+      <FrontmatterErrorView
+        filePath="/project/posts/broken.md"
+        error={{
+          message: "Frontmatter not properly closed with '---'",
+          line: 1,
+          column: null,
+          snippet: '---',
+          cause: 'unclosed',
+          suggestion: 'Add a line containing only `---` after the frontmatter',
+        }}
+        rawFrontmatter={'title: Post\n\nBody'}
+      />
+    )
+
+    expect(container.querySelector('pre')?.children).toHaveLength(4)
+    expect(container.querySelector('[data-broken]')).toHaveTextContent('1---')
+    expect(screen.queryByText('Edit as YAML')).not.toBeInTheDocument()
+  })
+})
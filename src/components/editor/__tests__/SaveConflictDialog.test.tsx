@@ -0,0 +1,188 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent, waitFor } from '@testing-library/react'
+import { SaveConflictDialog } from '../SaveConflictDialog'
+import { useEditorStore } from '../../../store/editorStore'
+import { useProjectStore } from '../../../store/projectStore'
+import { renderWithProviders } from '../../../test/test-utils'
+import type { FileEntry } from '@/types'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    parseMarkdownContent: vi.fn(),
+    discardDraft: vi.fn(),
+    mergeFileVersions: vi.fn(),
+    writeFile: vi.fn(),
+  },
+}))
+
+import { commands } from '@/lib/bindings'
+
+const file = {
+  id: 'blog/first-post',
+  path: '/project/src/content/blog/first-post.md',
+  name: 'first-post',
+  collection: 'blog',
+} as FileEntry
+
+const conflict = {
+  kind: 'conflict' as const,
+  diskContent: '---\ntitle: Theirs\n---\n\nTheir body\n',
+  diskHash: 'disk-hash',
+  attemptedContent: '---\ntitle: Mine\n---\n\nMy body\n',
+  baseContent: '---\ntitle: Base\n---\n\nBase body\n',
+}
+
+describe('SaveConflictDialog', () => {
+  const saveFile = vi.fn()
+
+  beforeEach(() => {
+    vi.clearAllMocks()
+    useProjectStore.setState({ projectPath: '/project' })
+    useEditorStore.setState({
+      currentFile: file,
+      editorContent: 'My body',
+      contentHash: 'loaded-hash',
+      isDirty: true,
+      saveConflict: conflict,
+      saveFile,
+    })
+  })
+
+  it('asks which version to keep', () => {
+    renderWithProviders(<SaveConflictDialog />)
+
+    expect(screen.getByText('first-post changed on disk')).toBeInTheDocument()
+    expect(screen.getByText(/Auto-save is paused/)).toBeInTheDocument()
+  })
+
+  it('overwrites the file without checking it against disk', async () => {
+    renderWithProviders(<SaveConflictDialog />)
+
+    fireEvent.click(screen.getByRole('button', { name: 'Overwrite' }))
+
+    await waitFor(() => expect(saveFile).toHaveBeenCalledWith(true))
+    expect(useEditorStore.getState().contentHash).toBeNull()
+    expect(useEditorStore.getState().saveConflict).toBeNull()
+  })
+
+  it('reloads the file from disk, dropping the edits', async () => {
+    vi.mocked(commands.parseMarkdownContent).mockResolvedValue({
+      status: 'ok',
+      data: {
+        frontmatter: { title: 'Theirs' },
+        content: 'Their body',
+        raw_frontmatter: 'title: Theirs',
+        imports: '',
+        content_hash: 'disk-hash',
+      },
+    })
+    renderWithProviders(<SaveConflictDialog />)
+
+    fireEvent.click(screen.getByRole('button', { name: 'Reload from Disk' }))
+
+    await waitFor(() =>
+      expect(useEditorStore.getState().saveConflict).toBeNull()
+    )
+    const state = useEditorStore.getState()
+    expect(state.editorContent).toBe('Their body')
+    expect(state.contentHash).toBe('disk-hash')
+    expect(state.isDirty).toBe(false)
+    expect(commands.discardDraft).toHaveBeenCalledWith(file.path, '/project')
+  })
+
+  it('saves a clean merge with the changes on disk', async () => {
+    vi.mocked(commands.mergeFileVersions).mockResolvedValue({
+      status: 'ok',
+      data: {
+        merged: '---\ntitle: Mine\n---\n\nTheir body\n',
+        frontmatter: { title: 'Mine' },
+        fieldConflicts: [],
+        bodyHunks: [{ kind: 'merged', text: 'Their body\n' }],
+      },
+    })
+    vi.mocked(commands.writeFile).mockResolvedValue({
+      status: 'ok',
+      data: 'merged-hash',
+    })
+    vi.mocked(commands.parseMarkdownContent).mockResolvedValue({
+      status: 'ok',
+      data: {
+        frontmatter: { title: 'Mine' },
+        content: 'Their body\n',
+        raw_frontmatter: 'title: Mine',
+        imports: '',
+        content_hash: 'merged-hash',
+      },
+    })
+    renderWithProviders(<SaveConflictDialog />)
+
+    fireEvent.click(screen.getByRole('button', { name: 'Merge' }))
+
+    await waitFor(() =>
+      expect(useEditorStore.getState().contentHash).toBe('merged-hash')
+    )
+    expect(commands.mergeFileVersions).toHaveBeenCalledWith(
+      conflict.baseContent,
+      conflict.attemptedContent,
+      conflict.diskContent
+    )
+    expect(commands.writeFile).toHaveBeenCalledWith(
+      file.path,
+      '---\ntitle: Mine\n---\n\nTheir body\n',
+      '/project',
+      'merge',
+      'disk-hash'
+    )
+    expect(useEditorStore.getState().saveConflict).toBeNull()
+  })
+
+  it('loads an overlapping merge with the conflicts marked', async () => {
+    vi.mocked(commands.mergeFileVersions).mockResolvedValue({
+      status: 'ok',
+      data: {
+        merged: null,
+        frontmatter: {},
+        fieldConflicts: [
+          { field: 'title', ours: 'Mine', theirs: 'Theirs', base: 'Base' },
+        ],
+        bodyHunks: [
+          {
+            kind: 'conflict',
+            base: 'Base body\n',
+            mine: 'My body\n',
+            theirs: 'Their body\n',
+          },
+        ],
+      },
+    })
+    renderWithProviders(<SaveConflictDialog />)
+
+    fireEvent.click(screen.getByRole('button', { name: 'Merge' }))
+
+    await waitFor(() =>
+      expect(useEditorStore.getState().saveConflict).toBeNull()
+    )
+    const state = useEditorStore.getState()
+    expect(state.editorContent).toBe(
+      '<<<<<<< Your edits\nMy body\n=======\nTheir body\n>>>>>>> On disk\n'
+    )
+    expect(state.frontmatter).toEqual({ title: 'Mine' })
+    expect(state.contentHash).toBe('disk-hash')
+    expect(state.isDirty).toBe(true)
+    expect(commands.writeFile).not.toHaveBeenCalled()
+  })
+
+  it('keeps the conflict pending when dismissed', async () => {
+    renderWithProviders(<SaveConflictDialog />)
+
+    fireEvent.click(screen.getByRole('button', { name: 'Keep Editing' }))
+
+    await waitFor(() =>
+      expect(
+        screen.queryByText('first-post changed on disk')
+      ).not.toBeInTheDocument()
+    )
+    expect(useEditorStore.getState().saveConflict).toBe(conflict)
+    expect(useEditorStore.getState().isDirty).toBe(true)
+  })
+})
@@ -1 +1,2 @@
 export { EditorViewComponent as Editor } from './Editor'
+export { SaveConflictDialog } from './SaveConflictDialog'
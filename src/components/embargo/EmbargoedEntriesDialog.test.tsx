@@ -0,0 +1,61 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent } from '@testing-library/react'
+import { EmbargoedEntriesDialog } from './EmbargoedEntriesDialog'
+import { useEmbargoStore } from '../../store/embargoStore'
+import { useProjectStore } from '../../store/projectStore'
+import { renderWithProviders } from '../../test/test-utils'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    listEmbargoedEntries: vi.fn(),
+  },
+}))
+vi.mock('../../lib/open-file', () => ({
+  openProjectFile: vi.fn(),
+}))
+
+import { commands } from '@/lib/bindings'
+import { openProjectFile } from '../../lib/open-file'
+
+describe('EmbargoedEntriesDialog', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    vi.mocked(commands.listEmbargoedEntries).mockResolvedValue({
+      status: 'ok',
+      data: [
+        {
+          filePath: '/project/src/content/blog/launch.md',
+          collection: 'blog',
+          embargoUntil: '2030-03-01T09:00:00Z',
+          source: 'sidecar',
+        },
+      ],
+    })
+    useProjectStore.setState({
+      projectPath: '/project',
+      currentProjectSettings: null,
+    })
+    useEmbargoStore.setState({ isOpen: true })
+  })
+
+  it('lists entries with when their embargo lifts', async () => {
+    renderWithProviders(<EmbargoedEntriesDialog />)
+
+    expect(await screen.findByText('blog/launch.md')).toBeInTheDocument()
+    expect(screen.getByText('sidecar')).toBeInTheDocument()
+    expect(
+      screen.getByText(new Date('2030-03-01T09:00:00Z').toLocaleString())
+    ).toBeInTheDocument()
+  })
+
+  it('opens an entry', async () => {
+    renderWithProviders(<EmbargoedEntriesDialog />)
+
+    fireEvent.click(await screen.findByText('blog/launch.md'))
+
+    expect(openProjectFile).toHaveBeenCalledWith(
+      '/project/src/content/blog/launch.md'
+    )
+    expect(useEmbargoStore.getState().isOpen).toBe(false)
+  })
+})
@@ -0,0 +1,84 @@
+import { useShallow } from 'zustand/react/shallow'
+import { Lock } from 'lucide-react'
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+} from '../ui/dialog'
+import { Badge } from '../ui/badge'
+import { useEmbargoStore } from '../../store/embargoStore'
+import { useProjectStore } from '../../store/projectStore'
+import { useEmbargoedEntriesQuery } from '../../hooks/queries/useEmbargoedEntriesQuery'
+import { openProjectFile } from '../../lib/open-file'
+
+/**
+ * Entries that can't be published yet, soonest embargo to lift first.
+ * Choosing one opens it.
+ */
+export function EmbargoedEntriesDialog() {
+  const isOpen = useEmbargoStore(state => state.isOpen)
+  const close = useEmbargoStore(state => state.close)
+  const projectPath = useProjectStore(state => state.projectPath)
+  const currentProjectSettings = useProjectStore(
+    useShallow(state => state.currentProjectSettings)
+  )
+  const { data: entries = [], isLoading } = useEmbargoedEntriesQuery(
+    isOpen ? projectPath : null,
+    currentProjectSettings
+  )
+
+  const fileName = (filePath: string) =>
+    filePath.split(/[\\/]/).pop() ?? filePath
+
+  return (
+    <Dialog open={isOpen} onOpenChange={open => !open && close()}>
+      <DialogContent className="sm:max-w-xl">
+        <DialogHeader>
+          <DialogTitle>Embargoed Entries</DialogTitle>
+          <DialogDescription>
+            Entries with an <code className="text-xs">embargoUntil</code> time
+            can&apos;t be published until it passes.
+          </DialogDescription>
+        </DialogHeader>
+
+        {isLoading ? (
+          <div className="py-8 text-center text-sm text-muted-foreground">
+            Looking for embargoes…
+          </div>
+        ) : entries.length === 0 ? (
+          <div className="py-8 text-center text-sm text-muted-foreground">
+            No entries are under embargo.
+          </div>
+        ) : (
+          <ul className="max-h-96 divide-y overflow-y-auto">
+            {entries.map(entry => (
+              <li key={entry.filePath}>
+                <button
+                  type="button"
+                  className="flex w-full items-center gap-2 rounded-md px-2 py-1.5 text-left text-sm hover:bg-accent"
+                  onClick={() => {
+                    close()
+                    void openProjectFile(entry.filePath)
+                  }}
+                >
+                  <Lock className="size-3.5 shrink-0 text-muted-foreground" />
+                  <span className="truncate">
+                    {entry.collection}/{fileName(entry.filePath)}
+                  </span>
+                  {entry.source === 'sidecar' && (
+                    <Badge variant="outline">sidecar</Badge>
+                  )}
+                  <span className="ml-auto shrink-0 text-xs text-muted-foreground">
+                    {new Date(entry.embargoUntil).toLocaleString()}
+                  </span>
+                </button>
+              </li>
+            ))}
+          </ul>
+        )}
+      </DialogContent>
+    </Dialog>
+  )
+}
@@ -0,0 +1 @@
+export { EmbargoedEntriesDialog } from './EmbargoedEntriesDialog'
@@ -137,6 +137,37 @@ describe('FrontmatterPanel Component', () => {
     expect(screen.getByDisplayValue('5')).toBeInTheDocument()
   })
 
+  it('should say when the schema was inferred from existing entries', () => {
+    mockCollectionsQuery([
+      {
+        name: 'posts',
+        path: '/project/posts',
+        complete_schema: JSON.stringify({
+          collectionName: 'posts',
+          fields: [
+            {
+              name: 'title',
+              label: 'Title',
+              fieldType: 'string',
+              required: true,
+            },
+          ],
+          inferred: true,
+        }),
+      },
+    ])
+    useEditorStore.setState({
+      currentFile: createMockFile(),
+      frontmatter: { title: 'Test Post' },
+    })
+
+    renderWithProviders(<FrontmatterPanel />)
+
+    expect(
+      screen.getByText('Fields inferred from existing entries')
+    ).toBeInTheDocument()
+  })
+
   it('should handle text input changes', () => {
     useEditorStore.setState({
       currentFile: createMockFile(),
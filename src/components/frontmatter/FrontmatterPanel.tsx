@@ -3,11 +3,26 @@ import { useShallow } from 'zustand/react/shallow'
 import { useEditorStore } from '../../store/editorStore'
 import { useProjectStore } from '../../store/projectStore'
 import { useCollectionsQuery } from '../../hooks/queries/useCollectionsQuery'
+import { useFrontmatterValidationQuery } from '../../hooks/queries/useFrontmatterValidationQuery'
 import { deserializeCompleteSchema } from '../../lib/schema'
 import { camelCaseToTitleCase } from '../../lib/utils'
+import {
+  Collapsible,
+  CollapsibleContent,
+  CollapsibleTrigger,
+} from '@/components/ui/collapsible'
+import { ChevronDown } from 'lucide-react'
+import { Button } from '@/components/ui/button'
 import { FrontmatterField } from './fields'
+import { RawFrontmatterEditor } from './RawFrontmatterEditor'
+import { toast } from '@/lib/toast'
 import { getEffectiveSettings } from '../../lib/project-registry/effective-settings'
-import type { Collection } from '@/types'
+import {
+  commands,
+  type Collection,
+  type FieldViolation,
+  type MarkdownContent,
+} from '@/types'
 
 export const FrontmatterPanel: React.FC = () => {
   // Object subscriptions need shallow to prevent re-renders on reference changes
@@ -45,6 +60,60 @@ export const FrontmatterPanel: React.FC = () => {
     return parsed
   }, [currentCollection])
 
+  // YAML edited in place of the form, tied to the file it was read from
+  const [yamlDraft, setYamlDraft] = React.useState<{
+    fileId: string
+    raw: string
+  } | null>(null)
+  const yamlDraftForFile =
+    currentFile && yamlDraft?.fileId === currentFile.id ? yamlDraft : null
+
+  const editAsYaml = async () => {
+    if (!currentFile || !projectPath) return
+    // Unsaved form changes are written first so the YAML includes them
+    const { isDirty, saveFile } = useEditorStore.getState()
+    if (isDirty) await saveFile(false)
+
+    const result = await commands.parseFrontmatterOnly(
+      currentFile.path,
+      projectPath
+    )
+    if (result.status === 'error') {
+      toast.error('Failed to read frontmatter', { description: result.error })
+      return
+    }
+    setYamlDraft({ fileId: currentFile.id, raw: result.data.rawFrontmatter })
+  }
+
+  const handleYamlApplied = (content: MarkdownContent) => {
+    useEditorStore.setState({
+      frontmatter: content.frontmatter,
+      rawFrontmatter: content.raw_frontmatter,
+      contentHash: content.content_hash,
+      isFrontmatterDirty: false,
+    })
+    setYamlDraft(null)
+  }
+
+  // Constraint violations from Rust, shown under each field
+  const { data: violations } = useFrontmatterValidationQuery(
+    projectPath,
+    currentCollection?.name ?? null,
+    currentCollection?.complete_schema,
+    frontmatter
+  )
+
+  const violationsByField = React.useMemo(() => {
+    const byField = new Map<string, FieldViolation[]>()
+    for (const violation of violations ?? []) {
+      byField.set(violation.field, [
+        ...(byField.get(violation.field) ?? []),
+        violation,
+      ])
+    }
+    return byField
+  }, [violations])
+
   // Get all fields to display
   const allFields = React.useMemo(() => {
     if (schema) {
@@ -107,16 +176,16 @@ export const FrontmatterPanel: React.FC = () => {
     }
   }, [frontmatter, schema, currentProjectSettings, currentFile])
 
-  // Group fields by parent path for nested object rendering
+  // Group fields by their schema group for nested object rendering
   const groupedFields = React.useMemo(() => {
     const groups: Map<string | null, typeof allFields> = new Map()
 
     for (const field of allFields) {
-      const parentPath = field.schemaField?.parentPath ?? null
-      if (!groups.has(parentPath)) {
-        groups.set(parentPath, [])
+      const group = field.schemaField?.group ?? null
+      if (!groups.has(group)) {
+        groups.set(group, [])
       }
-      groups.get(parentPath)!.push(field)
+      groups.get(group)!.push(field)
     }
 
     return groups
@@ -125,9 +194,33 @@ export const FrontmatterPanel: React.FC = () => {
   return (
     <div className="h-full flex flex-col">
       <div className="flex-1 p-4 overflow-y-auto">
-        {currentFile ? (
+        {currentFile && yamlDraftForFile ? (
+          <RawFrontmatterEditor
+            key={currentFile.id}
+            filePath={currentFile.path}
+            initialValue={yamlDraftForFile.raw}
+            completeSchema={currentCollection?.complete_schema}
+            onApplied={handleYamlApplied}
+            onCancel={() => setYamlDraft(null)}
+          />
+        ) : currentFile ? (
           allFields.length > 0 ? (
             <div className="space-y-6">
+              <div className="flex items-center justify-end gap-2">
+                {schema?.inferred && (
+                  <span className="mr-auto text-xs text-muted-foreground">
+                    Fields inferred from existing entries
+                  </span>
+                )}
+                <Button
+                  variant="ghost"
+                  size="sm"
+                  onClick={() => void editAsYaml()}
+                >
+                  Edit as YAML
+                </Button>
+              </div>
+
               {/* Render top-level SCHEMA fields only */}
               {groupedFields
                 .get(null)
@@ -139,39 +232,46 @@ export const FrontmatterPanel: React.FC = () => {
                     label={camelCaseToTitleCase(fieldName)}
                     field={schemaField}
                     collectionName={currentFile.collection}
+                    violations={violationsByField.get(fieldName)}
                   />
                 ))}
 
-              {/* Render nested field groups */}
-              {Array.from(groupedFields.entries())
-                .filter(([parentPath]) => parentPath !== null)
-                .map(([parentPath, fields]) => (
-                  <div key={parentPath} className="space-y-4">
-                    {/* Parent section header */}
-                    <h3 className="text-sm font-medium text-foreground pt-2">
-                      {camelCaseToTitleCase(
-                        parentPath!.split('.').pop() || parentPath!
-                      )}
-                    </h3>
+              {/* Render nested field groups, in the order the schema gives */}
+              {schema?.groups
+                .filter(group => groupedFields.has(group.path))
+                .map(group => (
+                  <Collapsible
+                    key={`${currentFile.id}:${group.path}`}
+                    defaultOpen={!group.collapsed}
+                    className="space-y-4"
+                  >
+                    {/* Group section header */}
+                    <CollapsibleTrigger className="group flex items-center gap-1 text-sm font-medium text-foreground pt-2">
+                      <ChevronDown className="h-4 w-4 text-muted-foreground transition-transform group-data-[state=closed]:-rotate-90" />
+                      {group.label}
+                    </CollapsibleTrigger>
 
                     {/* Nested fields with indentation */}
-                    <div className="pl-4 border-l-2 border-border space-y-4">
-                      {fields.map(({ fieldName, schemaField }) => (
-                        <FrontmatterField
-                          key={fieldName}
-                          name={fieldName}
-                          label={
-                            schemaField?.label ||
-                            camelCaseToTitleCase(
-                              fieldName.split('.').pop() || fieldName
-                            )
-                          }
-                          field={schemaField}
-                          collectionName={currentFile.collection}
-                        />
-                      ))}
-                    </div>
-                  </div>
+                    <CollapsibleContent className="pl-4 border-l-2 border-border space-y-4">
+                      {groupedFields
+                        .get(group.path)!
+                        .map(({ fieldName, schemaField }) => (
+                          <FrontmatterField
+                            key={fieldName}
+                            name={fieldName}
+                            label={
+                              schemaField?.label ||
+                              camelCaseToTitleCase(
+                                fieldName.split('.').pop() || fieldName
+                              )
+                            }
+                            field={schemaField}
+                            collectionName={currentFile.collection}
+                            violations={violationsByField.get(fieldName)}
+                          />
+                        ))}
+                    </CollapsibleContent>
+                  </Collapsible>
                 ))}
 
               {/* Render extra fields (not in schema) at the very end, alphabetically */}
@@ -185,6 +285,7 @@ export const FrontmatterPanel: React.FC = () => {
                     label={camelCaseToTitleCase(fieldName)}
                     field={schemaField}
                     collectionName={currentFile.collection}
+                    violations={violationsByField.get(fieldName)}
                   />
                 ))}
             </div>
@@ -0,0 +1,98 @@
+import { describe, it, expect, vi, beforeEach } from 'vitest'
+import { screen, fireEvent, waitFor } from '@testing-library/react'
+import { RawFrontmatterEditor } from './RawFrontmatterEditor'
+import { useProjectStore } from '../../store/projectStore'
+import { renderWithProviders } from '../../test/test-utils'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    applyRawFrontmatter: vi.fn(),
+  },
+}))
+
+import { commands } from '@/lib/bindings'
+
+const applied = {
+  frontmatter: { title: 'Post' },
+  content: 'Body\n',
+  raw_frontmatter: 'title: Post',
+  imports: '',
+  content_hash: '0123456789abcdef',
+}
+
+describe('RawFrontmatterEditor', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    useProjectStore.setState({ projectPath: '/project' })
+  })
+
+  const renderEditor = (onApplied = vi.fn()) =>
+    renderWithProviders(
+      <RawFrontmatterEditor
+        filePath="/project/posts/post.md"
+        initialValue="title: Post"
+        completeSchema='{"collectionName":"posts","fields":[]}'
+        onApplied={onApplied}
+        onCancel={vi.fn()}
+      />
+    )
+
+  it('shows where the YAML is broken and how to fix it', async () => {
+    vi.mocked(commands.applyRawFrontmatter).mockResolvedValue({
+      status: 'error',
+      error: {
+        kind: 'invalidYaml',
+        error: {
+          message: 'mapping values are not allowed in this context',
+          line: 2,
+          column: 13,
+          snippet: 'title: Part 1: The Beginning',
+          cause: 'unquotedColon',
+          suggestion: 'Quote the value: `title: "Part 1: The Beginning"`',
+        },
+      },
+    })
+    const onApplied = vi.fn()
+    renderEditor(onApplied)
+
+    fireEvent.click(screen.getByRole('button', { name: 'Apply' }))
+
+    expect(
+      await screen.findByText(
+        'Quote the value: `title: "Part 1: The Beginning"`'
+      )
+    ).toBeInTheDocument()
+    expect(screen.getByRole('alert')).toHaveTextContent('(line 2)')
+    expect(onApplied).not.toHaveBeenCalled()
+  })
+
+  it('lists schema violations and can apply without the schema', async () => {
+    vi.mocked(commands.applyRawFrontmatter)
+      .mockResolvedValueOnce({
+        status: 'error',
+        error: {
+          kind: 'schemaViolations',
+          violations: [
+            { field: 'description', kind: 'required', message: 'Required' },
+          ],
+        },
+      })
+      .mockResolvedValueOnce({ status: 'ok', data: applied })
+    const onApplied = vi.fn()
+    renderEditor(onApplied)
+
+    fireEvent.click(screen.getByRole('button', { name: 'Apply' }))
+    expect(await screen.findByText('description: Required')).toBeInTheDocument()
+
+    fireEvent.click(screen.getByRole('button', { name: 'Apply anyway' }))
+
+    await waitFor(() => expect(onApplied).toHaveBeenCalled())
+    expect(commands.applyRawFrontmatter).toHaveBeenLastCalledWith(
+      '/project/posts/post.md',
+      '/project',
+      'title: Post',
+      null,
+      'raw-frontmatter'
+    )
+  })
+})
@@ -0,0 +1,111 @@
+import React from 'react'
+import { Button } from '../ui/button'
+import { Textarea } from '../ui/textarea'
+import { FieldError } from '../ui/field'
+import { useProjectStore } from '../../store/projectStore'
+import {
+  RawFrontmatterRejectedError,
+  useApplyRawFrontmatterMutation,
+} from '../../hooks/mutations/useApplyRawFrontmatterMutation'
+import type { MarkdownContent } from '@/types'
+
+interface RawFrontmatterEditorProps {
+  filePath: string
+  initialValue: string
+  // The collection's complete schema; the YAML is checked against it first
+  completeSchema?: string | null
+  onApplied: (content: MarkdownContent) => void
+  onCancel: () => void
+}
+
+/**
+ * Edits a file's frontmatter as YAML, for values the form can't express.
+ * Rust refuses YAML that doesn't parse or breaks the schema, so nothing is
+ * written until it's fixed (or, for schema violations, applied anyway).
+ */
+export const RawFrontmatterEditor: React.FC<RawFrontmatterEditorProps> = ({
+  filePath,
+  initialValue,
+  completeSchema,
+  onApplied,
+  onCancel,
+}) => {
+  const projectPath = useProjectStore(state => state.projectPath)
+  const [value, setValue] = React.useState(initialValue)
+  const applyMutation = useApplyRawFrontmatterMutation()
+
+  const apply = (checkSchema: boolean) => {
+    if (!projectPath) return
+    applyMutation.mutate(
+      {
+        projectPath,
+        filePath,
+        rawFrontmatter: value,
+        completeSchema: checkSchema ? (completeSchema ?? null) : null,
+      },
+      { onSuccess: onApplied }
+    )
+  }
+
+  const error = applyMutation.error
+  const rejection =
+    error instanceof RawFrontmatterRejectedError ? error.rejection : null
+
+  return (
+    <div className="flex flex-col gap-3">
+      <Textarea
+        aria-label="Frontmatter YAML"
+        className="min-h-48 font-mono text-xs"
+        value={value}
+        aria-invalid={!!error || undefined}
+        onChange={event => {
+          setValue(event.target.value)
+          applyMutation.reset()
+        }}
+      />
+
+      {rejection?.kind === 'invalidYaml' && (
+        <FieldError>
+          {rejection.error.message}
+          {rejection.error.line != null && ` (line ${rejection.error.line})`}
+          {rejection.error.suggestion && (
+            <span className="block text-foreground">
+              {rejection.error.suggestion}
+            </span>
+          )}
+        </FieldError>
+      )}
+      {rejection?.kind === 'schemaViolations' && (
+        <FieldError
+          errors={rejection.violations.map(violation => ({
+            message: `${violation.field}: ${violation.message}`,
+          }))}
+        />
+      )}
+      {error && !rejection && <FieldError>{error.message}</FieldError>}
+
+      <div className="flex gap-2">
+        <Button
+          size="sm"
+          disabled={applyMutation.isPending}
+          onClick={() => apply(true)}
+        >
+          Apply
+        </Button>
+        {rejection?.kind === 'schemaViolations' && (
+          <Button
+            size="sm"
+            variant="outline"
+            disabled={applyMutation.isPending}
+            onClick={() => apply(false)}
+          >
+            Apply anyway
+          </Button>
+        )}
+        <Button size="sm" variant="ghost" onClick={onCancel}>
+          Cancel
+        </Button>
+      </div>
+    </div>
+  )
+}
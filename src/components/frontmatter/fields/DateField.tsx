@@ -1,16 +1,36 @@
 import React from 'react'
+import { commands } from '@/lib/bindings'
 import { useEditorStore } from '../../../store/editorStore'
+import { useProjectStore } from '../../../store/projectStore'
 import { getNestedValue } from '../../../lib/object-utils'
 import { DatePicker } from '../../ui/date-picker'
+import { useFormattedDateQuery } from '../../../hooks/queries/useFormattedDateQuery'
 import { FieldWrapper } from './FieldWrapper'
 import type { FieldProps } from '../../../types/common'
 import type { SchemaField } from '../../../lib/schema'
-import { formatIsoDate, parseIsoDate } from '../../../lib/dates'
+import {
+  formatIsoDate,
+  parseIsoDate,
+  todayIsoDate,
+} from '../../../lib/dates'
 
 interface DateFieldProps extends FieldProps {
   field?: SchemaField
 }
 
+/**
+ * Resolves typed dates like "tomorrow" or "in 2 weeks", relative to today in
+ * the project's timezone
+ */
+async function parseNaturalDate(text: string): Promise<Date | undefined> {
+  const { currentProjectSettings } = useProjectStore.getState()
+  const result = await commands.parseNaturalDate(
+    text,
+    todayIsoDate(currentProjectSettings?.timezone)
+  )
+  return result.status === 'ok' ? parseIsoDate(result.data) : undefined
+}
+
 export const DateField: React.FC<DateFieldProps> = ({
   name,
   label,
@@ -21,6 +41,11 @@ export const DateField: React.FC<DateFieldProps> = ({
   const updateFrontmatterField = useEditorStore(
     state => state.updateFrontmatterField
   )
+  const dateValue =
+    value && typeof value === 'string' ? parseIsoDate(value) : undefined
+  const { data: formattedDate } = useFormattedDateQuery(
+    dateValue ? formatIsoDate(dateValue) : null
+  )
 
   return (
     <FieldWrapper
@@ -34,17 +59,17 @@ export const DateField: React.FC<DateFieldProps> = ({
       currentValue={value}
     >
       <DatePicker
-        value={
-          value && typeof value === 'string' ? parseIsoDate(value) : undefined
-        }
+        value={dateValue}
+        displayValue={formattedDate}
         onChange={(date: Date | undefined) => {
-          const dateValue =
+          const isoDate =
             date instanceof Date && !isNaN(date.getTime())
               ? formatIsoDate(date)
               : undefined
-          updateFrontmatterField(name, dateValue)
+          updateFrontmatterField(name, isoDate)
         }}
         placeholder="Select date..."
+        parseText={parseNaturalDate}
       />
     </FieldWrapper>
   )
@@ -0,0 +1,72 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent } from '@testing-library/react'
+import { FieldDocsProvider } from './FieldDocsHint'
+import { FieldWrapper } from './FieldWrapper'
+import { useProjectStore } from '../../../store/projectStore'
+import { renderWithProviders } from '../../../test/test-utils'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    getFieldDocs: vi.fn(),
+  },
+}))
+
+import { commands } from '@/lib/bindings'
+
+describe('FieldDocsHint', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    useProjectStore.setState({
+      projectPath: '/project',
+      currentProjectSettings: null,
+    })
+    vi.mocked(commands.getFieldDocs).mockResolvedValue({
+      status: 'ok',
+      data: {
+        fieldPath: 'author.bio',
+        collectionName: 'authors',
+        label: 'Bio',
+        typeDescription: 'string',
+        required: true,
+        description: 'A short introduction',
+        constraints: ['At most 160 characters'],
+        inheritedFrom: 'authors',
+      },
+    })
+  })
+
+  it('shows the field docs when the hint is focused', async () => {
+    renderWithProviders(
+      <FieldDocsProvider collectionName="blog" fieldPath="author.bio">
+        <FieldWrapper label="Bio">
+          <input />
+        </FieldWrapper>
+      </FieldDocsProvider>
+    )
+
+    expect(commands.getFieldDocs).not.toHaveBeenCalled()
+    fireEvent.focus(screen.getByLabelText('About author.bio'))
+
+    // Radix renders tooltip text twice, once for screen readers
+    expect(await screen.findAllByText('A short introduction')).not.toHaveLength(
+      0
+    )
+    expect(screen.getAllByText('At most 160 characters')).not.toHaveLength(0)
+    expect(commands.getFieldDocs).toHaveBeenCalledWith(
+      '/project',
+      null,
+      'blog',
+      'author.bio'
+    )
+  })
+
+  it('adds no hint outside a provider', () => {
+    renderWithProviders(
+      <FieldWrapper label="Bio">
+        <input />
+      </FieldWrapper>
+    )
+
+    expect(screen.queryByLabelText('About author.bio')).not.toBeInTheDocument()
+  })
+})
@@ -0,0 +1,104 @@
+import React from 'react'
+import { Info } from 'lucide-react'
+import { Tooltip, TooltipContent, TooltipTrigger } from '../../ui/tooltip'
+import { useProjectStore } from '../../../store/projectStore'
+import { useFieldDocsQuery } from '../../../hooks/queries/useFieldDocsQuery'
+import { getEffectiveContentDirectory } from '../../../lib/project-registry'
+import { ASTRO_PATHS } from '../../../lib/constants'
+
+interface FieldDocsTarget {
+  collectionName: string
+  fieldPath: string
+}
+
+const FieldDocsContext = React.createContext<FieldDocsTarget | null>(null)
+
+/**
+ * Tells the `FieldWrapper`s inside which field of which collection they show,
+ * so their labels can offer the field's schema docs
+ */
+export const FieldDocsProvider: React.FC<
+  FieldDocsTarget & { children: React.ReactNode }
+> = ({ collectionName, fieldPath, children }) => {
+  const target = React.useMemo(
+    () => ({ collectionName, fieldPath }),
+    [collectionName, fieldPath]
+  )
+  return (
+    <FieldDocsContext.Provider value={target}>
+      {children}
+    </FieldDocsContext.Provider>
+  )
+}
+
+const FieldDocsTooltip: React.FC<FieldDocsTarget> = ({
+  collectionName,
+  fieldPath,
+}) => {
+  const projectPath = useProjectStore(state => state.projectPath)
+  const currentProjectSettings = useProjectStore(
+    state => state.currentProjectSettings
+  )
+  const [open, setOpen] = React.useState(false)
+
+  const contentDirectory = getEffectiveContentDirectory(currentProjectSettings)
+  const { data: docs, isError } = useFieldDocsQuery(
+    projectPath,
+    collectionName,
+    open ? fieldPath : null,
+    contentDirectory !== ASTRO_PATHS.CONTENT_DIR ? contentDirectory : undefined
+  )
+
+  if (!projectPath) return null
+
+  return (
+    <Tooltip open={open} onOpenChange={setOpen}>
+      <TooltipTrigger asChild>
+        {/* Not a button, which would take clicks on the label it sits in */}
+        <span
+          tabIndex={0}
+          aria-label={`About ${fieldPath}`}
+          className="ml-1 inline-flex align-middle text-muted-foreground hover:text-foreground"
+        >
+          <Info className="size-3.5" />
+        </span>
+      </TooltipTrigger>
+      <TooltipContent side="left" className="max-w-72 text-left">
+        {docs ? (
+          <div className="flex flex-col gap-1">
+            <span className="font-medium">
+              {docs.typeDescription}
+              {docs.required && ', required'}
+            </span>
+            {(docs.description ?? docs.markdownDescription) && (
+              <span>{docs.description ?? docs.markdownDescription}</span>
+            )}
+            {docs.constraints.map(constraint => (
+              <span key={constraint}>{constraint}</span>
+            ))}
+            {docs.defaultValue && <span>Default: {docs.defaultValue}</span>}
+            {docs.inheritedFrom && (
+              <span className="opacity-80">
+                From the {docs.inheritedFrom} collection
+              </span>
+            )}
+          </div>
+        ) : isError ? (
+          'No docs for this field'
+        ) : (
+          'Loading…'
+        )}
+      </TooltipContent>
+    </Tooltip>
+  )
+}
+
+/**
+ * An info icon beside a field's label that shows the field's schema docs on
+ * hover, for fields inside a `FieldDocsProvider`. Docs are only fetched once
+ * the tooltip opens.
+ */
+export const FieldDocsHint: React.FC = () => {
+  const target = React.useContext(FieldDocsContext)
+  return target ? <FieldDocsTooltip {...target} /> : null
+}
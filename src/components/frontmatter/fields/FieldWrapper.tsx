@@ -6,6 +6,7 @@ import {
   FieldContent,
 } from '@/components/ui/field'
 import type { FieldConstraints } from '../../../lib/schema'
+import { FieldDocsHint } from './FieldDocsHint'
 
 interface FieldWrapperProps {
   label: string
@@ -45,6 +46,7 @@ export const FieldWrapper: React.FC<FieldWrapperProps> = ({
       <FieldLabel>
         {label}
         {required && <span className="text-required ml-1">*</span>}
+        <FieldDocsHint />
       </FieldLabel>
       <FieldContent>
         {children}
@@ -407,4 +407,47 @@ describe('FrontmatterField Orchestrator', () => {
       expect(screen.getByRole('textbox')).toBeInTheDocument()
     })
   })
+
+  describe('Schema Violations', () => {
+    const summaryField: SchemaField = {
+      name: 'summary',
+      label: 'Summary',
+      type: FieldType.String,
+      required: false,
+    }
+
+    it('should show violations under the field', () => {
+      renderWithProviders(
+        <FrontmatterField
+          name="summary"
+          label="Summary"
+          field={summaryField}
+          violations={[
+            {
+              field: 'summary',
+              kind: 'maxLength',
+              message: 'Must be at most 60 characters',
+            },
+          ]}
+        />
+      )
+
+      expect(screen.getByRole('alert')).toHaveTextContent(
+        'Must be at most 60 characters'
+      )
+    })
+
+    it('should show nothing when there are no violations', () => {
+      renderWithProviders(
+        <FrontmatterField
+          name="summary"
+          label="Summary"
+          field={summaryField}
+          violations={[]}
+        />
+      )
+
+      expect(screen.queryByRole('alert')).not.toBeInTheDocument()
+    })
+  })
 })
@@ -2,6 +2,8 @@ import React from 'react'
 import { useEditorStore } from '../../../store/editorStore'
 import { useEffectiveSettings } from '../../../hooks/settings/useEffectiveSettings'
 import { FieldType, type SchemaField } from '../../../lib/schema'
+import { FieldError } from '../../ui/field'
+import type { FieldViolation } from '@/types'
 import { StringField } from './StringField'
 import { TextareaField } from './TextareaField'
 import { NumberField } from './NumberField'
@@ -12,15 +14,18 @@ import { ArrayField } from './ArrayField'
 import { ReferenceField } from './ReferenceField'
 import { YamlField } from './YamlField'
 import { ImageField } from './ImageField'
+import { FieldDocsProvider } from './FieldDocsHint'
 
 interface FrontmatterFieldProps {
   name: string
   label: string
   field?: SchemaField
   collectionName?: string
+  /** Schema constraints the current value breaks, shown under the field */
+  violations?: FieldViolation[]
 }
 
-export const FrontmatterField: React.FC<FrontmatterFieldProps> = ({
+const FieldControl: React.FC<Omit<FrontmatterFieldProps, 'violations'>> = ({
   name,
   label,
   field,
@@ -241,3 +246,29 @@ export const FrontmatterField: React.FC<FrontmatterFieldProps> = ({
     <StringField name={name} label={label} required={required} field={field} />
   )
 }
+
+export const FrontmatterField: React.FC<FrontmatterFieldProps> = ({
+  violations,
+  ...props
+}) => {
+  // Always wrapped, so the control isn't remounted (losing focus) as
+  // violations come and go while typing
+  return (
+    <div
+      className="flex flex-col gap-1.5"
+      data-invalid={violations?.length ? true : undefined}
+    >
+      {props.collectionName ? (
+        <FieldDocsProvider
+          collectionName={props.collectionName}
+          fieldPath={props.name}
+        >
+          <FieldControl {...props} />
+        </FieldDocsProvider>
+      ) : (
+        <FieldControl {...props} />
+      )}
+      {violations?.length ? <FieldError errors={violations} /> : null}
+    </div>
+  )
+}
@@ -71,6 +71,7 @@ export const ImageField: React.FC<ImageFieldProps> = ({
         copyStrategy: 'only-if-outside-project',
         currentFilePath: currentFile.path,
         useRelativePaths,
+        usePathAliases: effectiveSettings.usePathAliases,
       })
 
       // CRITICAL: Check if the user switched files during the async operation
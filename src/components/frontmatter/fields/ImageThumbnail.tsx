@@ -1,6 +1,6 @@
 import React, { useEffect, useState } from 'react'
-import { convertFileSrc } from '@tauri-apps/api/core'
 import { commands } from '@/lib/bindings'
+import { projectAssetUrl } from '@/lib/files'
 import { useProjectStore } from '../../../store/projectStore'
 import { useEditorStore } from '../../../store/editorStore'
 
@@ -47,8 +47,8 @@ export const ImageThumbnail: React.FC<ImageThumbnailProps> = ({ path }) => {
         }
 
         if (!cancelled) {
-          // Convert to asset protocol URL
-          const assetUrl = convertFileSrc(result.data)
+          // Serve through the project asset protocol
+          const assetUrl = projectAssetUrl(result.data)
           setImageUrl(assetUrl)
           setLoadingState('success')
         }
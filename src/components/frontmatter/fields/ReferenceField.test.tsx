@@ -0,0 +1,79 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, waitFor } from '@testing-library/react'
+import { ReferenceField } from './ReferenceField'
+import { useEditorStore } from '../../../store/editorStore'
+import { useProjectStore } from '../../../store/projectStore'
+import { renderWithProviders } from '../../../test/test-utils'
+import { FieldType, type SchemaField } from '../../../lib/schema'
+import type { Collection } from '@/types'
+
+import { useCollectionsQuery } from '../../../hooks/queries/useCollectionsQuery'
+vi.mock('../../../hooks/queries/useCollectionsQuery')
+
+vi.mock('@/lib/listing-stream', () => ({
+  streamCollectionFiles: vi.fn().mockResolvedValue([]),
+}))
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    loadFileBasedCollection: vi.fn(),
+    resolveReferenceLabels: vi.fn(),
+  },
+}))
+
+import { commands } from '@/lib/bindings'
+
+const authorsField: SchemaField = {
+  name: 'authors',
+  label: 'Authors',
+  type: FieldType.Array,
+  subReference: 'authors',
+  required: false,
+}
+
+describe('ReferenceField', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    // eslint-disable-next-line @typescript-eslint/no-unsafe-argument
+    vi.mocked(useCollectionsQuery).mockReturnValue({
+      data: [
+        { name: 'authors', path: '/project/src/content/authors' },
+      ] as Collection[],
+      isSuccess: true,
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    } as any)
+    useProjectStore.setState({ projectPath: '/project' })
+    useEditorStore.setState({
+      frontmatter: { authors: ['jane', 'ghost'] },
+      updateFrontmatterField: vi.fn(),
+    })
+  })
+
+  it('labels selected references and marks broken ones', async () => {
+    vi.mocked(commands.resolveReferenceLabels).mockResolvedValue({
+      status: 'ok',
+      data: [
+        { id: 'jane', label: 'Jane Doe', exists: true },
+        { id: 'ghost', label: 'ghost', exists: false },
+      ],
+    })
+
+    renderWithProviders(
+      <ReferenceField name="authors" label="Authors" field={authorsField} />
+    )
+
+    expect(await screen.findByText('Jane Doe')).toBeInTheDocument()
+    await waitFor(() =>
+      expect(
+        screen.getByTitle('"ghost" not found in authors')
+      ).toHaveTextContent('ghost')
+    )
+    expect(commands.resolveReferenceLabels).toHaveBeenCalledWith(
+      '/project',
+      'authors',
+      '/project/src/content/authors',
+      ['jane', 'ghost'],
+      'title'
+    )
+  })
+})
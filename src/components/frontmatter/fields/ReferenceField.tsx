@@ -5,6 +5,8 @@ import { useProjectStore } from '../../../store/projectStore'
 import { useCollectionsQuery } from '../../../hooks/queries/useCollectionsQuery'
 import { useCollectionFilesQuery } from '../../../hooks/queries/useCollectionFilesQuery'
 import { useFileBasedCollectionQuery } from '../../../hooks/queries/useFileBasedCollectionQuery'
+import { useReferenceLabelsQuery } from '../../../hooks/queries/useReferenceLabelsQuery'
+import { getListingOrder } from '../../../lib/files/sorting'
 import { Button } from '../../ui/button'
 import { Popover, PopoverContent, PopoverTrigger } from '../../ui/popover'
 import {
@@ -31,6 +33,7 @@ interface ReferenceFieldProps extends FieldProps {
 interface ReferenceOption {
   value: string // slug/id
   label: string // title from frontmatter or fallback
+  missing?: boolean // no entry in the referenced collection has this id
 }
 
 export const ReferenceField: React.FC<ReferenceFieldProps> = ({
@@ -59,10 +62,8 @@ export const ReferenceField: React.FC<ReferenceFieldProps> = ({
   )
 
   // Get collections to find the collection path
-  const { data: collections = [] } = useCollectionsQuery(
-    projectPath,
-    currentProjectSettings
-  )
+  const { data: collections = [], isSuccess: collectionsLoaded } =
+    useCollectionsQuery(projectPath, currentProjectSettings)
   const currentCollection = collections.find(
     c => c.name === referencedCollection
   )
@@ -72,7 +73,8 @@ export const ReferenceField: React.FC<ReferenceFieldProps> = ({
     useCollectionFilesQuery(
       projectPath,
       referencedCollection || '',
-      currentCollection?.path || null
+      currentCollection?.path || null,
+      currentProjectSettings
     )
 
   // Try file-based collection if not found in regular collections
@@ -124,13 +126,36 @@ export const ReferenceField: React.FC<ReferenceFieldProps> = ({
       : []
     : []
 
+  // Resolve the selected ids to labels in Rust, which also reports broken ones
+  const { data: resolvedLabels } = useReferenceLabelsQuery(
+    projectPath,
+    referencedCollection,
+    currentCollection?.path ?? null,
+    isMultiSelect ? selectedValues : selectedValue ? [selectedValue] : [],
+    getListingOrder(currentProjectSettings, referencedCollection || '')
+      .titleField,
+    collectionsLoaded
+  )
+
+  const toSelectedOption = (val: string): ReferenceOption => {
+    const resolved = resolvedLabels?.find(label => label.id === val)
+    if (resolved) {
+      return {
+        value: val,
+        label: resolved.label,
+        missing: !resolved.exists,
+      }
+    }
+    return options.find(opt => opt.value === val) ?? { value: val, label: val }
+  }
+
   // Get current selection label for single select
-  const selectedOption = options.find(opt => opt.value === selectedValue)
+  const selectedOption = selectedValue
+    ? toSelectedOption(selectedValue)
+    : undefined
 
   // Get selected options for multi-select
-  const selectedOptions = selectedValues
-    .map(val => options.find(opt => opt.value === val))
-    .filter((opt): opt is ReferenceOption => !!opt)
+  const selectedOptions = selectedValues.map(toSelectedOption)
 
   // Handle multi-select toggle
   const handleMultiSelectToggle = (optionValue: string) => {
@@ -179,8 +204,13 @@ export const ReferenceField: React.FC<ReferenceFieldProps> = ({
                   selectedOptions.map(opt => (
                     <Badge
                       key={opt.value}
-                      variant="secondary"
+                      variant={opt.missing ? 'destructive' : 'secondary'}
                       className="gap-1 pr-1"
+                      title={
+                        opt.missing
+                          ? `"${opt.value}" not found in ${referencedCollection}`
+                          : undefined
+                      }
                     >
                       {opt.label}
                       <span
@@ -207,7 +237,16 @@ export const ReferenceField: React.FC<ReferenceFieldProps> = ({
                   </span>
                 )
               ) : selectedOption ? (
-                selectedOption.label
+                <span
+                  className={cn(selectedOption.missing && 'text-destructive')}
+                  title={
+                    selectedOption.missing
+                      ? `"${selectedOption.value}" not found in ${referencedCollection}`
+                      : undefined
+                  }
+                >
+                  {selectedOption.label}
+                </span>
               ) : (
                 <span className="text-muted-foreground">
                   Select {label.toLowerCase()}...
@@ -1,4 +1,5 @@
 export { FrontmatterPanel } from './FrontmatterPanel'
+export { RawFrontmatterEditor } from './RawFrontmatterEditor'
 
 // Export field components for potential reuse
 export * from './fields'
@@ -1,7 +1,8 @@
 import React, { useState, useEffect, useRef } from 'react'
 import { Badge } from '../ui/badge'
 import { cn } from '@/lib/utils'
-import { getPublishedDate, getTitle } from '../../lib/files/sorting'
+import { getPublishedDateValue, getTitle } from '../../lib/files/sorting'
+import { useFormattedDateQuery } from '../../hooks/queries/useFormattedDateQuery'
 import type { FileEntry } from '@/types'
 
 type FrontmatterMappings = {
@@ -11,22 +12,6 @@ type FrontmatterMappings = {
   draft: string
 }
 
-export function formatDate(dateValue: unknown): string {
-  if (!dateValue) return ''
-
-  try {
-    const date = new Date(dateValue as string | number | Date)
-    if (isNaN(date.getTime())) return ''
-    return date.toLocaleDateString('en-US', {
-      month: 'short',
-      day: 'numeric',
-      year: 'numeric',
-    })
-  } catch {
-    return ''
-  }
-}
-
 interface FileItemProps {
   file: FileEntry
   isSelected: boolean
@@ -57,10 +42,12 @@ export const FileItem: React.FC<FileItemProps> = ({
   const isFileDraft = file.frontmatter?.[frontmatterMappings.draft] === true
   const isMdx = file.extension === 'mdx'
   const title = getTitle(file, frontmatterMappings.title)
-  const publishedDate = getPublishedDate(
+  // As written, so the sidebar shows the same day as the frontmatter
+  const publishedDate = getPublishedDateValue(
     file.frontmatter || {},
     frontmatterMappings.publishedDate
   )
+  const { data: formattedDate } = useFormattedDateQuery(publishedDate)
 
   // Compute the full name for rename
   const fullName = file.extension ? `${file.name}.${file.extension}` : file.name
@@ -132,7 +119,7 @@ export const FileItem: React.FC<FileItemProps> = ({
           </div>
           {publishedDate && (
             <div className="text-xs text-muted-foreground mt-1">
-              {formatDate(publishedDate)}
+              {formattedDate}
             </div>
           )}
           <div className="text-xs font-mono text-muted-foreground mt-1">
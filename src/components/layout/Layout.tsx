@@ -8,14 +8,37 @@ import { LeftSidebar } from './LeftSidebar'
 import { MainEditor } from './MainEditor'
 import { RightSidebar } from './RightSidebar'
 import { FrontmatterPanel } from '../frontmatter'
+import { BacklinksPanel } from '../backlinks'
 import { CommandPalette } from '../command-palette'
 import { ComponentBuilderDialog } from '../component-builder'
 import { ContentLinkerDialog } from '../content-linker'
+import { ScratchpadDialog } from '../scratchpad'
+import { TrashDialog } from '../trash'
+import { DailyNoteCalendarDialog } from '../daily-notes'
+import { CodeBlockAuditDialog } from '../code-blocks'
+import { TodosDialog } from '../todos'
+import { ActivityReportDialog } from '../activity-report'
+import { ContentGraphDialog } from '../content-graph'
+import { StaleDraftsDialog } from '../stale-drafts'
+import { EmbargoedEntriesDialog } from '../embargo'
+import { ContentCalendarDialog } from '../content-calendar'
+import { SaveConflictDialog } from '../editor'
+import { BackupsDialog } from '../backups'
 import { Toaster } from '../ui/sonner'
 import { PreferencesDialog } from '../preferences'
 import { useProjectInitialization } from '../../hooks/useProjectInitialization'
 import { useRustToastBridge } from '../../hooks/useRustToastBridge'
 import { useEditorFocusTracking } from '../../hooks/useEditorFocusTracking'
+import { useWindowDocument } from '../../hooks/useWindowDocument'
+import { useNativeToolbar } from '../../hooks/useNativeToolbar'
+import { useStaleDraftDigest } from '../../hooks/useStaleDraftDigest'
+import { useBackupSchedule } from '../../hooks/useBackupSchedule'
+import { useEmbargoWatch } from '../../hooks/useEmbargoWatch'
+import { useUnsavedDrafts } from '../../hooks/useUnsavedDrafts'
+import { useCaptureApi } from '../../hooks/useCaptureApi'
+import { useTrustedRoots } from '../../hooks/useTrustedRoots'
+import { useProjectZoom } from '../../hooks/useProjectZoom'
+import { useSystemAccessibility } from '../../hooks/useSystemAccessibility'
 import { useKeyboardShortcuts } from '../../hooks/useKeyboardShortcuts'
 import { useMenuEvents } from '../../hooks/useMenuEvents'
 import { useDOMEventListeners } from '../../hooks/useDOMEventListeners'
@@ -24,6 +47,8 @@ import { useFileChangeHandler } from '../../hooks/useFileChangeHandler'
 import { useEditorActions } from '../../hooks/editor/useEditorActions'
 import { useCreateFile } from '../../hooks/useCreateFile'
 import { useDeepLink } from '../../hooks/useDeepLink'
+import { useIntents } from '../../hooks/useIntents'
+import { useFrontmatterFlagMenu } from '../../hooks/useFrontmatterFlagMenu'
 import { useSquareCornersEffect } from '../../hooks/useSquareCornersEffect'
 import { useExternalLinkHandler } from '../../hooks/useExternalLinkHandler'
 import { useEditorStore } from '../../store/editorStore'
@@ -130,8 +155,20 @@ export const Layout: React.FC = () => {
   useKeyboardShortcuts(handleSetPreferencesOpen)
   useMenuEvents(createNewFileWithQuery, handleSetPreferencesOpen)
   useSquareCornersEffect()
+  useSystemAccessibility()
   useDOMEventListeners(createNewFileWithQuery, handleSetPreferencesOpen)
   useDeepLink(openFileByPath)
+  useIntents(createNewFileWithQuery)
+  useFrontmatterFlagMenu()
+  useWindowDocument()
+  useNativeToolbar()
+  useStaleDraftDigest()
+  useBackupSchedule()
+  useEmbargoWatch()
+  useUnsavedDrafts()
+  useCaptureApi()
+  useTrustedRoots()
+  useProjectZoom()
   useExternalLinkHandler()
 
   // Enable query-based file loading
@@ -262,7 +299,10 @@ export const Layout: React.FC = () => {
             onResize={handleRightPanelResize}
           >
             <RightSidebar>
-              <FrontmatterPanel />
+              <div className="flex-1 min-h-0">
+                <FrontmatterPanel />
+              </div>
+              <BacklinksPanel />
             </RightSidebar>
           </ResizablePanel>
         </ResizablePanelGroup>
@@ -272,6 +312,18 @@ export const Layout: React.FC = () => {
       <CommandPalette />
       <ComponentBuilderDialog />
       <ContentLinkerDialog />
+      <ScratchpadDialog />
+      <TrashDialog />
+      <SaveConflictDialog />
+      <BackupsDialog />
+      <DailyNoteCalendarDialog />
+      <CodeBlockAuditDialog />
+      <TodosDialog />
+      <ActivityReportDialog />
+      <ContentGraphDialog />
+      <StaleDraftsDialog />
+      <EmbargoedEntriesDialog />
+      <ContentCalendarDialog />
       <PreferencesDialog
         open={preferencesOpen}
         onOpenChange={handleSetPreferencesOpen}
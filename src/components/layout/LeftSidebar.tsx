@@ -6,6 +6,8 @@ import { useProjectStore } from '../../store/projectStore'
 import { useUIStore } from '../../store/uiStore'
 import { useCollectionsQuery } from '../../hooks/queries/useCollectionsQuery'
 import { useDirectoryScanQuery } from '../../hooks/queries/useDirectoryScanQuery'
+import { useCollectionTreeQuery } from '../../hooks/queries/useCollectionTreeQuery'
+import { useRemoteCollectionQuery } from '../../hooks/queries/useRemoteCollectionQuery'
 import type { FileEntry, Collection } from '@/types'
 import { useRenameFileMutation } from '../../hooks/mutations/useRenameFileMutation'
 import { Button } from '../ui/button'
@@ -26,11 +28,14 @@ import { Tooltip, TooltipContent, TooltipTrigger } from '../ui/tooltip'
 import { useEffectiveSettings } from '../../hooks/settings/useEffectiveSettings'
 import { FileItem } from './FileItem'
 import { FilterBar } from './FilterBar'
+import { SavedFilters } from './SavedFilters'
 import { openProjectViaDialog } from '../../lib/projects/actions'
 import { filterFilesByDraft } from '../../lib/files/filtering'
 import { sortFiles, getSortOptionsForCollection } from '../../lib/files/sorting'
 import { filterFilesBySearch } from '../../lib/files/search'
 import { deserializeCompleteSchema } from '../../lib/schema'
+import { refreshRemoteCollection } from '../../lib/remote-collections'
+import { toast } from '../../lib/toast'
 
 export const LeftSidebar: React.FC = () => {
   // Object subscription needs shallow
@@ -117,21 +122,54 @@ export const LeftSidebar: React.FC = () => {
   )
 
   const currentCollection = collections.find(c => c.name === selectedCollection)
+  const isRemote = Boolean(currentCollection?.remote)
 
   const {
     data: dirContents,
-    refetch: refetchFiles,
+    refetch: refetchDirectory,
     isLoading: isLoadingDirectory,
     isError: hasDirectoryError,
     error: directoryError,
   } = useDirectoryScanQuery(
     projectPath,
     selectedCollection,
-    currentCollection?.path || null,
-    currentSubdirectory
+    isRemote ? null : currentCollection?.path || null,
+    currentSubdirectory,
+    currentProjectSettings,
+    currentCollection?.pattern ?? null
   )
+
+  // Load nested directories up front so browsing into them is instant
+  useCollectionTreeQuery(
+    projectPath,
+    selectedCollection,
+    isRemote ? null : currentCollection?.path || null,
+    currentProjectSettings,
+    currentCollection?.pattern ?? null
+  )
+
+  // Remote collections have no directory; entries come from Astro's data store
+  const {
+    data: remoteFiles,
+    refetch: refetchRemoteFiles,
+    isLoading: isLoadingRemoteFiles,
+    isError: hasRemoteFilesError,
+    error: remoteFilesError,
+  } = useRemoteCollectionQuery(
+    projectPath,
+    isRemote ? selectedCollection : null
+  )
+
+  const refetchFiles = isRemote ? refetchRemoteFiles : refetchDirectory
+  const isLoadingFiles = isRemote ? isLoadingRemoteFiles : isLoadingDirectory
+  const hasFilesError = isRemote ? hasRemoteFilesError : hasDirectoryError
+  const filesError = isRemote ? remoteFilesError : directoryError
+
   // Extract files and subdirectories in useMemo to avoid lint warnings
-  const files = React.useMemo(() => dirContents?.files || [], [dirContents])
+  const files = React.useMemo(
+    () => (isRemote ? remoteFiles : dirContents?.files) || [],
+    [isRemote, remoteFiles, dirContents]
+  )
   const subdirectories = React.useMemo(
     () => dirContents?.subdirectories || [],
     [dirContents]
@@ -147,9 +185,11 @@ export const LeftSidebar: React.FC = () => {
       const counts: Record<string, number> = {}
 
       for (const collection of collections) {
+        if (collection.remote) continue
         try {
           const result = await commands.countCollectionFilesRecursive(
-            collection.path
+            collection.path,
+            collection.pattern ?? null
           )
           if (result.status === 'error') {
             counts[collection.name] = 0
@@ -202,6 +242,12 @@ export const LeftSidebar: React.FC = () => {
   }
 
   const handleFileClick = (file: FileEntry) => {
+    if (isRemote) {
+      toast.info('Remote entries are read-only', {
+        description: 'Edit them where the collection loader fetches them from',
+      })
+      return
+    }
     useEditorStore.getState().openFile(file)
   }
 
@@ -211,6 +257,7 @@ export const LeftSidebar: React.FC = () => {
   ) => {
     event.preventDefault()
     event.stopPropagation()
+    if (isRemote) return
 
     await FileContextMenu.show({
       file,
@@ -315,11 +362,13 @@ export const LeftSidebar: React.FC = () => {
         mode: collectionViewState.sortMode,
         direction: collectionViewState.sortDirection,
       },
-      frontmatterMappings
+      frontmatterMappings,
+      currentProjectSettings?.collation
     )
   }, [
     files,
     frontmatterMappings,
+    currentProjectSettings?.collation,
     showDraftsOnly,
     collectionViewState.searchQuery,
     collectionViewState.sortMode,
@@ -539,11 +588,15 @@ export const LeftSidebar: React.FC = () => {
                       )}
                     </div>
                     <Badge variant="secondary" className="text-xs">
-                      {fileCount} item{fileCount !== 1 ? 's' : ''}
+                      {collection.remote
+                        ? 'remote'
+                        : `${fileCount} item${fileCount !== 1 ? 's' : ''}`}
                     </Badge>
                   </div>
                   <div className="text-xs text-muted-foreground mt-1 font-mono">
-                    {collection.path.split('/').pop()}
+                    {collection.remote
+                      ? 'custom loader'
+                      : collection.path.split('/').pop()}
                   </div>
                 </button>
               )
@@ -553,26 +606,27 @@ export const LeftSidebar: React.FC = () => {
                 No collections found. Open an Astro project to get started.
               </div>
             )}
+            <SavedFilters />
           </div>
         ) : (
           // Files List
           <div className="p-2">
             {/* Loading State */}
-            {isLoadingDirectory && (
+            {isLoadingFiles && (
               <div className="p-4 text-center text-muted-foreground text-sm">
                 Loading directory...
               </div>
             )}
 
             {/* Error State */}
-            {hasDirectoryError && (
+            {hasFilesError && (
               <div className="p-4 text-center">
                 <div className="text-sm text-destructive mb-2">
                   Failed to load directory
                 </div>
                 <div className="text-xs text-muted-foreground mb-3">
-                  {directoryError instanceof Error
-                    ? directoryError.message
+                  {filesError instanceof Error
+                    ? filesError.message
                     : 'Unknown error occurred'}
                 </div>
                 <Button
@@ -586,23 +640,21 @@ export const LeftSidebar: React.FC = () => {
             )}
 
             {/* Content (only show if not loading and no error) */}
-            {!isLoadingDirectory && !hasDirectoryError && (
+            {!isLoadingFiles && !hasFilesError && (
               <>
-                {/* Subdirectories (alphabetically sorted) */}
-                {subdirectories
-                  .sort((a, b) => a.name.localeCompare(b.name))
-                  .map(dir => (
-                    <button
-                      key={dir.relative_path}
-                      onClick={() => handleSubdirectoryClick(dir.relative_path)}
-                      className="w-full text-left p-3 rounded-md hover:bg-accent transition-colors flex items-center gap-2 mb-1"
-                    >
-                      <Folder className="size-4 text-muted-foreground flex-shrink-0" />
-                      <span className="font-medium text-foreground truncate">
-                        {dir.name}
-                      </span>
-                    </button>
-                  ))}
+                {/* Subdirectories (already ordered by scan_directory) */}
+                {subdirectories.map(dir => (
+                  <button
+                    key={dir.relative_path}
+                    onClick={() => handleSubdirectoryClick(dir.relative_path)}
+                    className="w-full text-left p-3 rounded-md hover:bg-accent transition-colors flex items-center gap-2 mb-1"
+                  >
+                    <Folder className="size-4 text-muted-foreground flex-shrink-0" />
+                    <span className="font-medium text-foreground truncate">
+                      {dir.name}
+                    </span>
+                  </button>
+                ))}
 
                 {/* Files (sorted by date) */}
                 {filteredAndSortedFiles.map(file => {
@@ -629,7 +681,21 @@ export const LeftSidebar: React.FC = () => {
                         ? 'No files match your search.'
                         : showDraftsOnly
                           ? 'No draft files found in this directory.'
-                          : 'This directory is empty.'}
+                          : isRemote
+                            ? 'No entries loaded yet.'
+                            : 'This directory is empty.'}
+                      {isRemote && selectedCollection && (
+                        <Button
+                          onClick={() =>
+                            void refreshRemoteCollection(selectedCollection)
+                          }
+                          variant="outline"
+                          size="sm"
+                          className="mt-3 block mx-auto"
+                        >
+                          Refresh with astro sync
+                        </Button>
+                      )}
                     </div>
                   )}
               </>
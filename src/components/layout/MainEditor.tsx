@@ -1,8 +1,20 @@
 import React from 'react'
+import { useShallow } from 'zustand/react/shallow'
 import { useEditorStore } from '../../store/editorStore'
 import { useUIStore } from '../../store/uiStore'
 import { Editor } from '../editor'
-import { openProjectViaDialog } from '../../lib/projects/actions'
+import { PreviewPane } from '../preview'
+import { FrontmatterErrorView } from '../editor/FrontmatterErrorView'
+import { LargeFileView } from '../editor/LargeFileView'
+import {
+  FileTooLargeError,
+  InvalidFrontmatterError,
+  useFileContentQuery,
+} from '../../hooks/queries/useFileContentQuery'
+import {
+  openProjectViaDialog,
+  openSampleProjectViaDialog,
+} from '../../lib/projects/actions'
 import { Button } from '../ui/button'
 import { useProjectStore } from '@/store/projectStore'
 
@@ -17,33 +29,89 @@ const WelcomeScreen: React.FC = () => {
           Select a project folder to get started, then choose a file to edit.
         </p>
         {projectPath == null && (
-          <Button
-            onClick={() => void openProjectViaDialog()}
-            className="self-center"
-            variant="outline"
-            size="sm"
-            title="Open Project"
-          >
-            Open Project
-          </Button>
+          <div className="flex flex-col items-center gap-2">
+            <Button
+              onClick={() => void openProjectViaDialog()}
+              variant="outline"
+              size="sm"
+              title="Open Project"
+            >
+              Open Project
+            </Button>
+            <Button
+              onClick={() => void openSampleProjectViaDialog()}
+              variant="link"
+              size="sm"
+              title="Create a sample project to explore the editor"
+            >
+              Try with a sample project
+            </Button>
+          </div>
         )}
       </div>
     </div>
   )
 }
 
+// Editor for the open file, the raw frontmatter when it can't be parsed, or
+// a notice when it's too large for the editor
+const CurrentFileView: React.FC = () => {
+  const currentFile = useEditorStore(useShallow(state => state.currentFile))
+  const allowLargeFile = useEditorStore(state => state.allowLargeFile)
+  const projectPath = useProjectStore(state => state.projectPath)
+  const maxFileSizeMb = useProjectStore(
+    state => state.currentProjectSettings?.maxEditorFileSizeMb ?? null
+  )
+  // Shares the cache entry useEditorFileContent loads
+  const { error } = useFileContentQuery(
+    projectPath,
+    currentFile?.id || null,
+    currentFile?.path || null,
+    maxFileSizeMb,
+    allowLargeFile
+  )
+
+  if (currentFile && error instanceof FileTooLargeError) {
+    return (
+      <LargeFileView
+        sizeBytes={error.sizeBytes}
+        limitBytes={error.limitBytes}
+      />
+    )
+  }
+
+  if (currentFile && error instanceof InvalidFrontmatterError) {
+    return (
+      <FrontmatterErrorView
+        filePath={currentFile.path}
+        error={error.frontmatterError}
+        rawFrontmatter={error.rawFrontmatter}
+      />
+    )
+  }
+  return <Editor />
+}
+
 export const MainEditor: React.FC = () => {
   // PERFORMANCE FIX: Use specific selector instead of currentFile object to avoid cascade
   const hasCurrentFile = useEditorStore(state => !!state.currentFile)
   const typewriterModeEnabled = useUIStore(state => state.typewriterModeEnabled)
+  const previewPaneVisible = useUIStore(state => state.previewPaneVisible)
 
   return (
-    <div className="flex flex-col h-full">
-      <div
-        className={`flex-1 overflow-x-hidden overflow-y-auto bg-[var(--editor-color-background)]${typewriterModeEnabled ? ' no-scrollbar' : ''}`}
-      >
-        {hasCurrentFile ? <Editor /> : <WelcomeScreen />}
+    <div className="flex h-full">
+      <div className="flex flex-col h-full flex-1 min-w-0">
+        <div
+          className={`flex-1 overflow-x-hidden overflow-y-auto bg-[var(--editor-color-background)]${typewriterModeEnabled ? ' no-scrollbar' : ''}`}
+        >
+          {hasCurrentFile ? <CurrentFileView /> : <WelcomeScreen />}
+        </div>
       </div>
+      {hasCurrentFile && previewPaneVisible && (
+        <div className="h-full flex-1 min-w-0">
+          <PreviewPane />
+        </div>
+      )}
     </div>
   )
 }
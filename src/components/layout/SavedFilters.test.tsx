@@ -0,0 +1,87 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent } from '@testing-library/react'
+import { SavedFilters } from './SavedFilters'
+import { useProjectStore } from '../../store/projectStore'
+import { renderWithProviders } from '../../test/test-utils'
+import type { SavedFilter } from '@/types'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    queryCollection: vi.fn(),
+  },
+}))
+vi.mock('../../lib/open-file', () => ({
+  openProjectFile: vi.fn(),
+}))
+
+import { commands } from '@/lib/bindings'
+import { openProjectFile } from '../../lib/open-file'
+
+const oldDrafts: SavedFilter = {
+  id: 'old-drafts',
+  name: 'Drafts older than 30 days',
+  collection: 'blog',
+  condition: {
+    kind: 'dateRange',
+    field: 'pubDate',
+    minDaysAgo: 30,
+  },
+}
+
+describe('SavedFilters', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    vi.mocked(commands.queryCollection).mockResolvedValue({
+      status: 'ok',
+      data: [
+        {
+          filePath: '/project/src/content/blog/old.md',
+          collectionName: 'blog',
+          title: 'An Old Draft',
+        },
+      ],
+    })
+    useProjectStore.setState({
+      projectPath: '/project',
+      currentProjectSettings: {
+        pathOverrides: {},
+        frontmatterMappings: {},
+        savedFilters: [oldDrafts],
+      },
+    })
+  })
+
+  it('renders nothing without saved filters', () => {
+    useProjectStore.setState({ currentProjectSettings: null })
+    const { container } = renderWithProviders(<SavedFilters />)
+
+    expect(container).toBeEmptyDOMElement()
+  })
+
+  it('queries a view only once it is expanded', async () => {
+    renderWithProviders(<SavedFilters />)
+
+    expect(commands.queryCollection).not.toHaveBeenCalled()
+    fireEvent.click(screen.getByText('Drafts older than 30 days'))
+
+    expect(await screen.findByText('An Old Draft')).toBeInTheDocument()
+    expect(commands.queryCollection).toHaveBeenCalledWith(
+      '/project',
+      null,
+      'blog',
+      oldDrafts.condition,
+      50
+    )
+  })
+
+  it('opens a matching entry', async () => {
+    renderWithProviders(<SavedFilters />)
+
+    fireEvent.click(screen.getByText('Drafts older than 30 days'))
+    fireEvent.click(await screen.findByText('An Old Draft'))
+
+    expect(openProjectFile).toHaveBeenCalledWith(
+      '/project/src/content/blog/old.md'
+    )
+  })
+})
@@ -0,0 +1,96 @@
+import React from 'react'
+import { useShallow } from 'zustand/react/shallow'
+import { ChevronDown } from 'lucide-react'
+import {
+  Collapsible,
+  CollapsibleContent,
+  CollapsibleTrigger,
+} from '../ui/collapsible'
+import { useProjectStore } from '../../store/projectStore'
+import { useSavedFilterQuery } from '../../hooks/queries/useSavedFilterQuery'
+import { getEffectiveContentDirectory } from '../../lib/project-registry'
+import { ASTRO_PATHS } from '../../lib/constants'
+import { openProjectFile } from '../../lib/open-file'
+import type { SavedFilter } from '@/types'
+
+/** Most entries listed under one smart view */
+const MATCH_LIMIT = 50
+
+/**
+ * The entries matching one saved filter. Only mounted while its view is
+ * expanded, so collapsed views don't query.
+ */
+const SavedFilterMatches: React.FC<{ filter: SavedFilter }> = ({ filter }) => {
+  const projectPath = useProjectStore(state => state.projectPath)
+  const currentProjectSettings = useProjectStore(
+    useShallow(state => state.currentProjectSettings)
+  )
+  const contentDirectory = getEffectiveContentDirectory(currentProjectSettings)
+  const { data: matches = [], isLoading } = useSavedFilterQuery(
+    projectPath,
+    filter,
+    contentDirectory !== ASTRO_PATHS.CONTENT_DIR ? contentDirectory : undefined,
+    MATCH_LIMIT
+  )
+
+  if (isLoading) {
+    return <div className="px-3 py-1 text-xs text-muted-foreground">…</div>
+  }
+  if (matches.length === 0) {
+    return (
+      <div className="px-3 py-1 text-xs text-muted-foreground">No entries</div>
+    )
+  }
+
+  return (
+    <ul>
+      {matches.map(match => (
+        <li key={match.filePath}>
+          <button
+            type="button"
+            onClick={() => void openProjectFile(match.filePath)}
+            className="w-full truncate rounded-md px-3 py-1 text-left text-sm hover:bg-accent"
+          >
+            {match.title}
+            {!filter.collection && (
+              <span className="ml-1.5 text-xs text-muted-foreground">
+                {match.collectionName}
+              </span>
+            )}
+          </button>
+        </li>
+      ))}
+    </ul>
+  )
+}
+
+/**
+ * Smart views from the project's `savedFilters` setting, shown under the
+ * collections. Each expands to the entries it matches.
+ */
+export const SavedFilters: React.FC = () => {
+  const savedFilters = useProjectStore(
+    useShallow(state => state.currentProjectSettings?.savedFilters)
+  )
+
+  if (!savedFilters?.length) return null
+
+  return (
+    <div className="mt-2 border-t px-1 pt-3">
+      <div className="px-2 pb-1 text-xs font-medium text-muted-foreground">
+        Smart Views
+      </div>
+      {savedFilters.map(filter => (
+        <Collapsible key={filter.id}>
+          <CollapsibleTrigger className="group flex w-full items-center gap-1 rounded-md px-2 py-1.5 text-sm font-medium hover:bg-accent">
+            <ChevronDown className="size-4 text-muted-foreground transition-transform group-data-[state=closed]:-rotate-90" />
+            {filter.name}
+          </CollapsibleTrigger>
+          <CollapsibleContent className="pl-4">
+            <SavedFilterMatches filter={filter} />
+          </CollapsibleContent>
+        </Collapsible>
+      ))}
+    </div>
+  )
+}
@@ -0,0 +1,101 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, act } from '@testing-library/react'
+import { GitBranchIndicator } from './GitBranchIndicator'
+import { useEditorStore } from '../../../store/editorStore'
+import { useProjectStore } from '../../../store/projectStore'
+import { renderWithProviders } from '../../../test/test-utils'
+import { mockToast, resetToastMocks } from '../../../test/mocks/toast'
+import type { GitBranchState } from '@/types'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    getGitBranchState: vi.fn(),
+    switchContentBranch: vi.fn(),
+  },
+}))
+
+import { commands } from '@/lib/bindings'
+
+const onMain: GitBranchState = {
+  isRepository: true,
+  branch: 'main',
+  defaultBranch: 'main',
+  isWorktree: false,
+  mainWorktree: null,
+  contentBranch: 'content',
+  contentBranchExists: true,
+  contentBranchWorktree: null,
+  editingDefaultBranch: true,
+}
+
+describe('GitBranchIndicator', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    resetToastMocks()
+    useProjectStore.setState({
+      projectPath: '/project',
+      currentProjectSettings: {
+        pathOverrides: {},
+        frontmatterMappings: {},
+        contentBranch: { branch: 'content', require: true },
+      },
+    })
+    useEditorStore.setState({ isDirty: false })
+  })
+
+  it('shows the branch and whether the project is a worktree', async () => {
+    vi.mocked(commands.getGitBranchState).mockResolvedValue({
+      status: 'ok',
+      data: {
+        ...onMain,
+        branch: 'content',
+        isWorktree: true,
+        mainWorktree: '/repo',
+        editingDefaultBranch: false,
+      },
+    })
+    renderWithProviders(<GitBranchIndicator />)
+
+    expect(await screen.findByText('content')).toBeInTheDocument()
+    expect(screen.getByText('(worktree)')).toBeInTheDocument()
+    expect(screen.getByRole('button')).toBeDisabled()
+  })
+
+  it('renders nothing outside a git repository', async () => {
+    vi.mocked(commands.getGitBranchState).mockResolvedValue({
+      status: 'ok',
+      data: { ...onMain, isRepository: false, branch: null },
+    })
+    renderWithProviders(<GitBranchIndicator />)
+
+    await vi.waitFor(() =>
+      expect(commands.getGitBranchState).toHaveBeenCalledWith('/project', {
+        branch: 'content',
+        require: true,
+      })
+    )
+    expect(screen.queryByRole('button')).not.toBeInTheDocument()
+  })
+
+  it('warns once when editing on the default branch', async () => {
+    vi.mocked(commands.getGitBranchState).mockResolvedValue({
+      status: 'ok',
+      data: onMain,
+    })
+    renderWithProviders(<GitBranchIndicator />)
+    expect(await screen.findByText('main')).toBeInTheDocument()
+    expect(mockToast.warning).not.toHaveBeenCalled()
+
+    act(() => useEditorStore.setState({ isDirty: true }))
+    act(() => useEditorStore.setState({ isDirty: false }))
+    act(() => useEditorStore.setState({ isDirty: true }))
+
+    expect(mockToast.warning).toHaveBeenCalledTimes(1)
+    expect(mockToast.warning).toHaveBeenCalledWith(
+      'Editing on main',
+      expect.objectContaining({
+        action: expect.objectContaining({ label: 'Switch to content' }),
+      })
+    )
+  })
+})
@@ -0,0 +1,89 @@
+import React from 'react'
+import { GitBranch, TriangleAlert } from 'lucide-react'
+import { useEditorStore } from '../../../store/editorStore'
+import { useProjectStore } from '../../../store/projectStore'
+import { useGitBranchStateQuery } from '../../../hooks/queries/useGitBranchStateQuery'
+import { switchToContentBranch } from '../../../lib/git-branch'
+import { toast } from '../../../lib/toast'
+import { cn } from '../../../lib/utils'
+import type { GitBranchState } from '@/types'
+
+function branchSummary(state: GitBranchState): string {
+  const lines = [
+    state.branch ? `On branch ${state.branch}` : 'HEAD is detached',
+  ]
+  if (state.mainWorktree) {
+    lines.push(`Worktree of ${state.mainWorktree}`)
+  }
+  if (state.editingDefaultBranch && state.contentBranch) {
+    lines.push(
+      state.contentBranchWorktree
+        ? `Content is edited on ${state.contentBranch}, checked out at ${state.contentBranchWorktree}`
+        : `Content is edited on ${state.contentBranch}. Click to switch.`
+    )
+  }
+  return lines.join('\n')
+}
+
+/**
+ * The project's git branch, shown beside its name. Turns amber, and warns on
+ * the first edit, when the project requires a content branch but is on the
+ * default branch.
+ */
+export const GitBranchIndicator: React.FC = () => {
+  const projectPath = useProjectStore(state => state.projectPath)
+  const contentBranch = useProjectStore(
+    state => state.currentProjectSettings?.contentBranch
+  )
+  const isDirty = useEditorStore(state => state.isDirty)
+  const { data: state } = useGitBranchStateQuery(projectPath, contentBranch)
+
+  // Warn once per branch, not on every edit
+  const warnedBranchRef = React.useRef<string | null>(null)
+  React.useEffect(() => {
+    if (!isDirty || !state?.editingDefaultBranch || !state.contentBranch) {
+      return
+    }
+    if (warnedBranchRef.current === state.branch) return
+    warnedBranchRef.current = state.branch
+
+    toast.warning(`Editing on ${state.branch}`, {
+      description: state.contentBranchWorktree
+        ? `This project's content is edited on ${state.contentBranch}, checked out at ${state.contentBranchWorktree}.`
+        : `This project's content is edited on ${state.contentBranch}.`,
+      action: state.contentBranchWorktree
+        ? undefined
+        : {
+            label: `Switch to ${state.contentBranch}`,
+            onClick: () => void switchToContentBranch(),
+          },
+    })
+  }, [isDirty, state])
+
+  if (!state?.isRepository) return null
+
+  const canSwitch = state.editingDefaultBranch && !state.contentBranchWorktree
+
+  return (
+    <button
+      type="button"
+      disabled={!canSwitch}
+      onClick={() => void switchToContentBranch()}
+      title={branchSummary(state)}
+      className={cn(
+        'flex min-w-0 items-center gap-1 rounded px-1 text-xs text-muted-foreground',
+        state.editingDefaultBranch &&
+          'text-amber-600 hover:bg-amber-500/10 dark:text-amber-400',
+        !canSwitch && 'cursor-default'
+      )}
+    >
+      {state.editingDefaultBranch ? (
+        <TriangleAlert className="size-3 shrink-0" />
+      ) : (
+        <GitBranch className="size-3 shrink-0" />
+      )}
+      <span className="truncate">{state.branch ?? 'detached'}</span>
+      {state.isWorktree && <span className="opacity-70">(worktree)</span>}
+    </button>
+  )
+}
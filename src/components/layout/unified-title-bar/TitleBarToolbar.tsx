@@ -4,6 +4,7 @@ import { useEditorStore } from '../../../store/editorStore'
 import { useProjectStore } from '../../../store/projectStore'
 import { useUIStore } from '../../../store/uiStore'
 import { useCreateFile } from '../../../hooks/useCreateFile'
+import { useContentModified } from '../../../hooks/useContentModified'
 import { Button } from '../../ui/button'
 import {
   Save,
@@ -14,8 +15,11 @@ import {
   Plus,
   Eye,
   Pilcrow,
+  Globe,
 } from 'lucide-react'
 import { cn } from '../../../lib/utils'
+import { openPreview } from '../../../lib/dev-server'
+import { GitBranchIndicator } from './GitBranchIndicator'
 
 interface TitleBarToolbarProps {
   /** Window controls for the left side (e.g., macOS traffic lights) */
@@ -35,6 +39,12 @@ export const TitleBarToolbar: React.FC<TitleBarToolbarProps> = ({
   const currentFile = useEditorStore(useShallow(state => state.currentFile))
   const saveFile = useEditorStore(state => state.saveFile)
   const isDirty = useEditorStore(state => state.isDirty)
+  const modified = useContentModified(currentFile?.path ?? null)
+  // Word counts since the last save, once there are any
+  const unsaved =
+    isDirty && modified && modified.wordsAdded + modified.wordsRemoved > 0
+      ? modified
+      : null
 
   const projectPath = useProjectStore(state => state.projectPath)
   const selectedCollection = useProjectStore(state => state.selectedCollection)
@@ -96,9 +106,12 @@ export const TitleBarToolbar: React.FC<TitleBarToolbarProps> = ({
         </Button>
 
         {projectPath ? (
-          <span className="text-xs text-muted-foreground whitespace-nowrap overflow-hidden text-ellipsis">
-            {projectPath.split('/').pop() || projectPath}
-          </span>
+          <>
+            <span className="text-xs text-muted-foreground whitespace-nowrap overflow-hidden text-ellipsis">
+              {projectPath.split('/').pop() || projectPath}
+            </span>
+            <GitBranchIndicator />
+          </>
         ) : (
           <span className="text-sm font-medium text-muted-foreground">
             Astro Editor
@@ -161,6 +174,27 @@ export const TitleBarToolbar: React.FC<TitleBarToolbarProps> = ({
           />
         </Button>
 
+        <Button
+          onClick={() => void openPreview()}
+          variant="ghost"
+          size="sm"
+          disabled={!currentFile}
+          title="Open Preview"
+          aria-label="Open Preview"
+          className="size-7 p-0 [&_svg]:transform-gpu [&_svg]:scale-100 text-gray-700 dark:text-gray-300"
+        >
+          <Globe className="size-4" />
+        </Button>
+
+        {unsaved && (
+          <span
+            className="px-1 text-xs tabular-nums text-muted-foreground"
+            title={`${unsaved.wordsAdded} words added and ${unsaved.wordsRemoved} removed since last saved at ${new Date(unsaved.lastSavedAt).toLocaleTimeString()}`}
+          >
+            +{unsaved.wordsAdded} −{unsaved.wordsRemoved}
+          </span>
+        )}
+
         <Button
           onClick={handleSave}
           variant="ghost"
@@ -1,11 +1,17 @@
 import React from 'react'
+import { useProjectStore } from '../../../store/projectStore'
 import { TrafficLights } from './TrafficLights'
 import { TitleBarToolbar } from './TitleBarToolbar'
 
 /**
  * macOS-specific unified title bar with traffic light window controls.
  * Traffic lights are positioned on the left, before the sidebar toggle.
+ * With the native toolbar enabled the window has real traffic lights, so the
+ * custom ones are omitted.
  */
 export const UnifiedTitleBarMacOS: React.FC = () => {
-  return <TitleBarToolbar leftSlot={<TrafficLights />} />
+  const nativeToolbar = useProjectStore(
+    state => state.globalSettings?.general?.nativeToolbar ?? false
+  )
+  return <TitleBarToolbar leftSlot={nativeToolbar ? null : <TrafficLights />} />
 }
@@ -63,6 +63,10 @@ export const WindowsMenu: React.FC = () => {
     void emit('menu-save')
   }
 
+  const handlePrint = () => {
+    void emit('menu-print')
+  }
+
   const handleToggleSidebar = () => {
     void emit('menu-toggle-sidebar')
   }
@@ -71,6 +75,18 @@ export const WindowsMenu: React.FC = () => {
     void emit('menu-toggle-frontmatter')
   }
 
+  const handleZoomIn = () => {
+    void emit('menu-zoom-in')
+  }
+
+  const handleZoomOut = () => {
+    void emit('menu-zoom-out')
+  }
+
+  const handleZoomReset = () => {
+    void emit('menu-zoom-reset')
+  }
+
   const handleFullScreen = async () => {
     try {
       const window = getCurrentWindow()
@@ -143,6 +159,9 @@ export const WindowsMenu: React.FC = () => {
           Save
           <DropdownMenuShortcut>Ctrl+S</DropdownMenuShortcut>
         </DropdownMenuItem>
+        <DropdownMenuItem onClick={handlePrint} disabled={!currentFile}>
+          Print...
+        </DropdownMenuItem>
 
         <DropdownMenuSeparator />
 
@@ -154,6 +173,18 @@ export const WindowsMenu: React.FC = () => {
           Toggle Frontmatter
           <DropdownMenuShortcut>Ctrl+2</DropdownMenuShortcut>
         </DropdownMenuItem>
+        <DropdownMenuItem onClick={handleZoomIn}>
+          Zoom In
+          <DropdownMenuShortcut>Ctrl+=</DropdownMenuShortcut>
+        </DropdownMenuItem>
+        <DropdownMenuItem onClick={handleZoomOut}>
+          Zoom Out
+          <DropdownMenuShortcut>Ctrl+-</DropdownMenuShortcut>
+        </DropdownMenuItem>
+        <DropdownMenuItem onClick={handleZoomReset}>
+          Actual Size
+          <DropdownMenuShortcut>Ctrl+0</DropdownMenuShortcut>
+        </DropdownMenuItem>
         <DropdownMenuItem onClick={() => void handleFullScreen()}>
           {isFullscreen ? 'Exit Full Screen' : 'Enter Full Screen'}
           <DropdownMenuShortcut>F11</DropdownMenuShortcut>
@@ -8,6 +8,7 @@ import {
 } from '@/components/ui/select'
 import { Button } from '@/components/ui/button'
 import { Input } from '@/components/ui/input'
+import { Switch } from '@/components/ui/switch'
 import {
   Field,
   FieldLabel,
@@ -15,15 +16,27 @@ import {
   FieldContent,
 } from '@/components/ui/field'
 import { usePreferences } from '../../../hooks/usePreferences'
+import { usePlatform } from '../../../hooks/usePlatform'
 import { useTheme } from '../../../lib/theme-provider'
 import { SettingsSection } from '../SettingsSection'
 import { PreferencesTextInput } from '../PreferencesTextInput'
 import { DocsLink } from '../DocsLink'
 import { DOCS_URLS } from '../../../lib/docs-urls'
+import { DEFAULT_PRINT_FOOTER, DEFAULT_PRINT_HEADER } from '../../../lib/print'
+import { DEFAULT_CAPTURE_API_PORT } from '../../../hooks/useCaptureApi'
+import { useUpdateChannelQuery } from '../../../hooks/queries/useUpdateChannelQuery'
+import { useDetectedIdesQuery } from '../../../hooks/queries/useDetectedIdesQuery'
+import { useSetUpdateChannelMutation } from '../../../hooks/mutations/useSetUpdateChannelMutation'
+import { commands, type UpdateChannel } from '@/types'
+import { toast } from '../../../lib/toast'
 
 export const GeneralPane: React.FC = () => {
   const { globalSettings, updateGlobal } = usePreferences()
   const { setTheme } = useTheme()
+  const platform = usePlatform()
+  const { data: updateChannel } = useUpdateChannelQuery()
+  const { data: detectedIdes = [] } = useDetectedIdesQuery()
+  const setUpdateChannel = useSetUpdateChannelMutation()
 
   const handleThemeChange = useCallback(
     (value: 'light' | 'dark' | 'system') => {
@@ -62,6 +75,51 @@ export const GeneralPane: React.FC = () => {
     [updateGlobal]
   )
 
+  const handleNativeToolbarChange = useCallback(
+    (checked: boolean) => {
+      void updateGlobal({ general: { nativeToolbar: checked } })
+    },
+    [updateGlobal]
+  )
+
+  const handleCaptureApiPortChange = useCallback(
+    (value: string) => {
+      const parsed = parseInt(value, 10)
+      if (!Number.isNaN(parsed) && parsed >= 1024 && parsed <= 65535) {
+        void updateGlobal({ general: { captureApi: { port: parsed } } })
+      }
+    },
+    [updateGlobal]
+  )
+
+  const handleNewCaptureToken = useCallback(() => {
+    const token = crypto.randomUUID().replace(/-/g, '')
+    void updateGlobal({ general: { captureApi: { token } } })
+  }, [updateGlobal])
+
+  const handleRecoveryLimitChange = useCallback(
+    (key: 'maxFiles' | 'maxAgeDays', value: string) => {
+      const parsed = parseInt(value, 10)
+      if (!Number.isNaN(parsed) && parsed >= 1) {
+        void updateGlobal({ general: { recovery: { [key]: parsed } } })
+      }
+    },
+    [updateGlobal]
+  )
+
+  const handlePurgeRecoveryData = useCallback(async () => {
+    const result = await commands.purgeRecoveryData(null)
+    if (result.status === 'error') {
+      toast.error('Failed to delete recovery data', {
+        description: result.error,
+      })
+      return
+    }
+    toast.success(
+      `Deleted ${result.data} recovery ${result.data === 1 ? 'entry' : 'entries'}`
+    )
+  }, [])
+
   const DEFAULT_EDITOR_BASE_FONT_SIZE = 18
 
   const handleEditorBaseFontSizeChange = useCallback(
@@ -105,9 +163,34 @@ export const GeneralPane: React.FC = () => {
             <FieldDescription>
               The command to launch your editor. Use <code>code</code> or{' '}
               <code>cursor</code> if installed in a standard location, or
-              provide the full path (e.g., <code>/usr/local/bin/nvim</code>).{' '}
+              provide the full path (e.g., <code>/usr/local/bin/nvim</code>).
+              To open files at a line, include <code>{'{path}'}</code>,{' '}
+              <code>{'{line}'}</code> and <code>{'{column}'}</code> (e.g.,{' '}
+              <code>{'subl {path}:{line}'}</code>).{' '}
               <DocsLink href={DOCS_URLS.ideIntegration}>Learn more</DocsLink>
             </FieldDescription>
+            {detectedIdes.length > 0 && (
+              <div className="flex flex-wrap items-center gap-2">
+                <span className="text-xs text-muted-foreground">
+                  Installed:
+                </span>
+                {detectedIdes.map(ide => (
+                  <Button
+                    key={ide.command}
+                    variant="outline"
+                    size="sm"
+                    title={ide.template}
+                    onClick={() =>
+                      void updateGlobal({
+                        general: { ideCommand: ide.template },
+                      })
+                    }
+                  >
+                    {ide.name}
+                  </Button>
+                ))}
+              </div>
+            )}
           </FieldContent>
         </Field>
 
@@ -153,6 +236,29 @@ export const GeneralPane: React.FC = () => {
             </FieldDescription>
           </FieldContent>
         </Field>
+
+        <Field>
+          <FieldLabel>Update Channel</FieldLabel>
+          <FieldContent>
+            <Select
+              value={updateChannel ?? 'stable'}
+              onValueChange={value =>
+                setUpdateChannel.mutate(value as UpdateChannel)
+              }
+            >
+              <SelectTrigger>
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value="stable">Stable</SelectItem>
+                <SelectItem value="beta">Beta</SelectItem>
+              </SelectContent>
+            </Select>
+            <FieldDescription>
+              Beta updates arrive sooner but may be less polished
+            </FieldDescription>
+          </FieldContent>
+        </Field>
       </SettingsSection>
 
       <SettingsSection title="Appearance">
@@ -241,6 +347,202 @@ export const GeneralPane: React.FC = () => {
         </Field>
       </SettingsSection>
 
+      <SettingsSection title="Printing">
+        <Field>
+          <FieldLabel>Page Header</FieldLabel>
+          <FieldContent>
+            <PreferencesTextInput
+              value={
+                globalSettings?.general?.printHeader ?? DEFAULT_PRINT_HEADER
+              }
+              onCommit={value =>
+                void updateGlobal({ general: { printHeader: value } })
+              }
+              className="max-w-md"
+            />
+            <FieldDescription>
+              Printed at the top of each page. Use <code>{'{title}'}</code> and{' '}
+              <code>{'{date}'}</code>; leave empty for no header.
+            </FieldDescription>
+          </FieldContent>
+        </Field>
+
+        <Field>
+          <FieldLabel>Page Footer</FieldLabel>
+          <FieldContent>
+            <PreferencesTextInput
+              value={
+                globalSettings?.general?.printFooter ?? DEFAULT_PRINT_FOOTER
+              }
+              onCommit={value =>
+                void updateGlobal({ general: { printFooter: value } })
+              }
+              className="max-w-md"
+            />
+            <FieldDescription>
+              Printed at the bottom of each page. Use <code>{'{title}'}</code>{' '}
+              and <code>{'{date}'}</code>; leave empty for no footer.
+            </FieldDescription>
+          </FieldContent>
+        </Field>
+      </SettingsSection>
+
+      <SettingsSection title="Capture API">
+        <Field>
+          <div className="flex items-center justify-between">
+            <div className="flex-1">
+              <FieldLabel>Enable Capture API</FieldLabel>
+              <FieldDescription>
+                Let a companion app send notes and photos into today&apos;s
+                note with <code>POST /capture</code>.
+              </FieldDescription>
+            </div>
+            <Switch
+              checked={globalSettings?.general?.captureApi?.enabled ?? false}
+              onCheckedChange={checked =>
+                void updateGlobal({
+                  general: { captureApi: { enabled: checked } },
+                })
+              }
+            />
+          </div>
+        </Field>
+
+        <Field>
+          <div className="flex items-center justify-between">
+            <div className="flex-1">
+              <FieldLabel>Allow Local Network</FieldLabel>
+              <FieldDescription>
+                Accept requests from devices on your network, not only this
+                computer.
+              </FieldDescription>
+            </div>
+            <Switch
+              checked={globalSettings?.general?.captureApi?.allowLan ?? false}
+              onCheckedChange={checked =>
+                void updateGlobal({
+                  general: { captureApi: { allowLan: checked } },
+                })
+              }
+            />
+          </div>
+        </Field>
+
+        <Field>
+          <FieldLabel>Port</FieldLabel>
+          <FieldContent>
+            <PreferencesTextInput
+              value={String(
+                globalSettings?.general?.captureApi?.port ??
+                  DEFAULT_CAPTURE_API_PORT
+              )}
+              onCommit={handleCaptureApiPortChange}
+              className="max-w-32"
+            />
+          </FieldContent>
+        </Field>
+
+        <Field>
+          <FieldLabel>Token</FieldLabel>
+          <FieldContent>
+            <div className="flex gap-2">
+              <Input
+                readOnly
+                value={globalSettings?.general?.captureApi?.token ?? ''}
+                placeholder="Generated when the API first starts"
+                className="max-w-md font-mono"
+              />
+              <Button variant="outline" onClick={handleNewCaptureToken}>
+                New Token
+              </Button>
+            </div>
+            <FieldDescription>
+              Send as <code>Authorization: Bearer &lt;token&gt;</code>.
+            </FieldDescription>
+          </FieldContent>
+        </Field>
+      </SettingsSection>
+
+      <SettingsSection title="Recovery">
+        <Field>
+          <div className="flex items-center justify-between">
+            <div className="flex-1">
+              <FieldLabel>Encrypt Recovery Data</FieldLabel>
+              <FieldDescription>
+                Encrypt content saved after a failed save, unsaved edits and
+                earlier versions of files with a key kept in the system
+                keychain.
+              </FieldDescription>
+            </div>
+            <Switch
+              checked={globalSettings?.general?.recovery?.encrypt ?? false}
+              onCheckedChange={checked =>
+                void updateGlobal({
+                  general: { recovery: { encrypt: checked } },
+                })
+              }
+            />
+          </div>
+        </Field>
+
+        <Field>
+          <FieldLabel>Keep At Most</FieldLabel>
+          <FieldContent>
+            <PreferencesTextInput
+              value={String(globalSettings?.general?.recovery?.maxFiles ?? 50)}
+              onCommit={value => handleRecoveryLimitChange('maxFiles', value)}
+              className="max-w-32"
+            />
+            <FieldDescription>
+              Recovery entries; the oldest are removed first.
+            </FieldDescription>
+          </FieldContent>
+        </Field>
+
+        <Field>
+          <FieldLabel>Delete After (Days)</FieldLabel>
+          <FieldContent>
+            <div className="flex gap-2">
+              <PreferencesTextInput
+                value={String(
+                  globalSettings?.general?.recovery?.maxAgeDays ?? 30
+                )}
+                onCommit={value =>
+                  handleRecoveryLimitChange('maxAgeDays', value)
+                }
+                className="max-w-32"
+              />
+              <Button
+                variant="outline"
+                onClick={() => void handlePurgeRecoveryData()}
+              >
+                Delete All Now
+              </Button>
+            </div>
+          </FieldContent>
+        </Field>
+      </SettingsSection>
+
+      {platform === 'macos' && (
+        <SettingsSection title="Window">
+          <Field>
+            <div className="flex items-center justify-between">
+              <div className="flex-1">
+                <FieldLabel>Native Toolbar</FieldLabel>
+                <FieldDescription>
+                  Show a native macOS toolbar with Save, Frontmatter, Insert
+                  Image and Publish buttons.
+                </FieldDescription>
+              </div>
+              <Switch
+                checked={globalSettings?.general?.nativeToolbar ?? false}
+                onCheckedChange={handleNativeToolbarChange}
+              />
+            </div>
+          </Field>
+        </SettingsSection>
+      )}
+
       <SettingsSection title="Editor">
         <Field>
           <FieldLabel>Auto Save Delay</FieldLabel>
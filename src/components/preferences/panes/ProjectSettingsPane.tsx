@@ -43,12 +43,36 @@ export const ProjectSettingsPane: React.FC = () => {
     })
   }
 
+  const handleMaxEditorFileSizeChange = (value: string) => {
+    const sizeMb = parseFloat(value)
+    void updateProject({
+      maxEditorFileSizeMb: sizeMb > 0 ? sizeMb : undefined,
+    })
+  }
+
   const handleAbsolutePathsChange = (checked: boolean) => {
     void updateProject({
       useAbsoluteAssetPaths: checked,
     })
   }
 
+  const handlePathAliasesChange = (checked: boolean) => {
+    void updateProject({
+      usePathAliases: checked,
+    })
+  }
+
+  const handleContentBranchChange = (
+    settings: Partial<{ branch: string; require: boolean }>
+  ) => {
+    void updateProject({
+      contentBranch: {
+        ...currentProjectSettings?.contentBranch,
+        ...settings,
+      },
+    })
+  }
+
   return (
     <div className="space-y-6">
       <div className="rounded-lg border bg-muted/50 p-4 mb-6">
@@ -149,6 +173,25 @@ export const ProjectSettingsPane: React.FC = () => {
             />
           </div>
         </Field>
+
+        <Field>
+          <div className="flex items-center justify-between">
+            <div className="flex-1">
+              <FieldLabel>Use Path Aliases for Images</FieldLabel>
+              <FieldDescription>
+                When your <code className="text-xs">tsconfig.json</code> defines
+                a path alias covering the assets directory, insert images using
+                it (e.g.,{' '}
+                <code className="text-xs">@assets/blog/image.png</code>) instead
+                of a relative or absolute path.
+              </FieldDescription>
+            </div>
+            <Switch
+              checked={currentProjectSettings?.usePathAliases ?? false}
+              onCheckedChange={handlePathAliasesChange}
+            />
+          </div>
+        </Field>
       </SettingsSection>
 
       <SettingsSection title="File Defaults">
@@ -180,6 +223,94 @@ export const ProjectSettingsPane: React.FC = () => {
             </FieldDescription>
           </FieldContent>
         </Field>
+
+        <Field>
+          <FieldLabel>Maximum File Size for the Editor</FieldLabel>
+          <FieldContent>
+            <div className="flex items-center gap-2 w-fit">
+              <PreferencesTextInput
+                type="number"
+                min={0.1}
+                step={0.5}
+                value={String(
+                  currentProjectSettings?.maxEditorFileSizeMb ?? ''
+                )}
+                onCommit={handleMaxEditorFileSizeChange}
+                placeholder="5"
+                className="w-20"
+              />
+              <span className="text-sm text-muted-foreground">MB</span>
+            </div>
+            <FieldDescription>
+              Larger files open with only their frontmatter editable, with an
+              option to open them anyway (default: 5 MB)
+            </FieldDescription>
+          </FieldContent>
+        </Field>
+      </SettingsSection>
+
+      <SettingsSection title="Git">
+        <Field>
+          <FieldLabel>Content Branch</FieldLabel>
+          <FieldContent>
+            <PreferencesTextInput
+              value={currentProjectSettings?.contentBranch?.branch || ''}
+              onCommit={value =>
+                handleContentBranchChange({ branch: value.trim() })
+              }
+              placeholder="content"
+            />
+            <FieldDescription>
+              Branch content is edited on before it's merged into the default
+              branch. Created from the current commit the first time you
+              switch to it.
+            </FieldDescription>
+          </FieldContent>
+        </Field>
+
+        <Field>
+          <div className="flex items-center justify-between">
+            <div className="flex-1">
+              <FieldLabel>Require Content Branch</FieldLabel>
+              <FieldDescription>
+                Warn when editing while the project is on its default branch
+                (e.g. <code className="text-xs">main</code>) instead of the
+                content branch.
+              </FieldDescription>
+            </div>
+            <Switch
+              checked={currentProjectSettings?.contentBranch?.require ?? false}
+              disabled={!currentProjectSettings?.contentBranch?.branch}
+              onCheckedChange={checked =>
+                handleContentBranchChange({ require: checked })
+              }
+            />
+          </div>
+        </Field>
+      </SettingsSection>
+
+      <SettingsSection title="Includes">
+        <Field>
+          <FieldLabel>Include Component</FieldLabel>
+          <FieldContent>
+            <PreferencesTextInput
+              value={currentProjectSettings?.transclusion?.component || ''}
+              onCommit={value =>
+                void updateProject({
+                  transclusion: { component: value.trim() || null },
+                })
+              }
+              placeholder="Include"
+            />
+            <FieldDescription>
+              MDX component that includes another file, as in{' '}
+              <code className="text-xs">{'<Include src="..." />'}</code>.
+              Expanded alongside{' '}
+              <code className="text-xs">{'{{include: path}}'}</code> when
+              printing or copying as HTML.
+            </FieldDescription>
+          </FieldContent>
+        </Field>
       </SettingsSection>
     </div>
   )
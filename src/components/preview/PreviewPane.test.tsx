@@ -0,0 +1,81 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, waitFor } from '@testing-library/react'
+import { PreviewPane } from './PreviewPane'
+import { useEditorStore } from '../../store/editorStore'
+import { useProjectStore } from '../../store/projectStore'
+import { renderWithProviders } from '../../test/test-utils'
+import type { FileEntry } from '@/types'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    renderMarkdownPreview: vi.fn(),
+  },
+}))
+vi.mock('../../lib/markdown-flavor', () => ({
+  getMarkdownFlavor: vi.fn().mockResolvedValue({ smartPunctuation: true }),
+}))
+vi.mock('../../lib/transclusion', () => ({
+  expandIncludes: vi.fn((markdown: string) => Promise.resolve(markdown)),
+}))
+vi.mock('../../lib/print', () => ({
+  resolveImages: vi.fn((html: string) => Promise.resolve(html)),
+}))
+
+import { commands } from '@/lib/bindings'
+
+const file: FileEntry = {
+  id: 'posts/hello',
+  path: '/project/src/content/posts/hello.md',
+  name: 'hello',
+  extension: 'md',
+  collection: 'posts',
+  last_modified: null,
+  frontmatter: null,
+}
+
+describe('PreviewPane', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    useProjectStore.setState({
+      projectPath: '/project',
+      currentProjectSettings: null,
+    })
+    useEditorStore.setState({
+      currentFile: file,
+      editorContent: 'Hello **world**',
+      frontmatter: { title: 'Hello' },
+    })
+  })
+
+  it('renders the document once typing pauses', async () => {
+    vi.mocked(commands.renderMarkdownPreview).mockResolvedValue({
+      status: 'ok',
+      data: {
+        title: 'Hello',
+        html: '<h1>Hello</h1>\n<p>Hello <strong>world</strong></p>',
+      },
+    })
+    renderWithProviders(<PreviewPane />)
+
+    expect(await screen.findByText('world')).toBeInTheDocument()
+    expect(commands.renderMarkdownPreview).toHaveBeenCalledWith(
+      'Hello **world**',
+      { title: 'Hello' },
+      'title',
+      true,
+      false
+    )
+  })
+
+  it('shows the error when rendering fails', async () => {
+    vi.mocked(commands.renderMarkdownPreview).mockResolvedValue({
+      status: 'error',
+      error: 'Failed to render preview',
+    })
+    renderWithProviders(<PreviewPane />)
+
+    await waitFor(() =>
+      expect(screen.getByText('Failed to render preview')).toBeInTheDocument()
+    )
+  })
+})
@@ -0,0 +1,91 @@
+import React, { useEffect, useState } from 'react'
+import { useShallow } from 'zustand/react/shallow'
+import { commands, type JsonValue } from '@/lib/bindings'
+import { useEditorStore } from '../../store/editorStore'
+import { useProjectStore } from '../../store/projectStore'
+import { getEffectiveFrontmatterMappings } from '../../lib/project-registry'
+import { getMarkdownFlavor } from '../../lib/markdown-flavor'
+import { resolveImages } from '../../lib/print'
+import { expandIncludes } from '../../lib/transclusion'
+
+/** Pause in typing before the preview re-renders */
+const RENDER_DEBOUNCE_MS = 300
+
+/**
+ * Live HTML preview of the open document, rendered in Rust so it works
+ * without the project's dev server
+ */
+export const PreviewPane: React.FC = () => {
+  const currentFile = useEditorStore(useShallow(state => state.currentFile))
+  const editorContent = useEditorStore(state => state.editorContent)
+  const frontmatter = useEditorStore(useShallow(state => state.frontmatter))
+  const projectPath = useProjectStore(state => state.projectPath)
+  const currentProjectSettings = useProjectStore(
+    useShallow(state => state.currentProjectSettings)
+  )
+  const [html, setHtml] = useState('')
+  const [error, setError] = useState<string | null>(null)
+
+  useEffect(() => {
+    if (!currentFile || !projectPath) return
+    let cancelled = false
+
+    const timeout = setTimeout(() => {
+      void (async () => {
+        const titleField = getEffectiveFrontmatterMappings(
+          currentProjectSettings,
+          currentFile.collection
+        ).title
+        const flavor = await getMarkdownFlavor()
+        const result = await commands.renderMarkdownPreview(
+          await expandIncludes(editorContent),
+          frontmatter as Partial<Record<string, JsonValue>>,
+          titleField,
+          flavor.smartPunctuation,
+          document.documentElement.classList.contains('dark')
+        )
+        if (cancelled) return
+        if (result.status === 'error') {
+          setError(result.error)
+          return
+        }
+        const resolved = await resolveImages(
+          result.data.html,
+          projectPath,
+          currentFile.path
+        )
+        if (cancelled) return
+        setError(null)
+        setHtml(resolved)
+      })()
+    }, RENDER_DEBOUNCE_MS)
+
+    return () => {
+      cancelled = true
+      clearTimeout(timeout)
+    }
+  }, [
+    currentFile,
+    editorContent,
+    frontmatter,
+    projectPath,
+    currentProjectSettings,
+  ])
+
+  if (!currentFile) return null
+
+  return (
+    <div className="h-full overflow-y-auto border-l border-border bg-[var(--editor-color-background)] px-8 py-6">
+      {error ? (
+        <p className="text-sm text-destructive">{error}</p>
+      ) : (
+        <article
+          className="markdown-preview"
+          aria-label="Preview"
+          // Sanitized by render_markdown_preview: raw HTML is omitted
+          dangerouslySetInnerHTML={{ __html: html }}
+        />
+      )}
+    </div>
+  )
+}
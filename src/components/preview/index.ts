@@ -0,0 +1 @@
+export { PreviewPane } from './PreviewPane'
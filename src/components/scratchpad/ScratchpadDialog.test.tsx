@@ -0,0 +1,104 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent, waitFor } from '@testing-library/react'
+import { ScratchpadDialog } from './ScratchpadDialog'
+import { useScratchpadStore } from '../../store/scratchpadStore'
+import { useProjectStore } from '../../store/projectStore'
+import { renderWithProviders } from '../../test/test-utils'
+import type { Collection, Scratchpad } from '@/types'
+
+import { useCollectionsQuery } from '../../hooks/queries/useCollectionsQuery'
+vi.mock('../../hooks/queries/useCollectionsQuery')
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    listScratchpads: vi.fn(),
+    saveScratchpad: vi.fn(),
+    promoteScratchpadToEntry: vi.fn(),
+    resolveFileEntry: vi.fn(),
+  },
+}))
+
+import { commands } from '@/lib/bindings'
+
+const scratchpads: Scratchpad[] = [
+  {
+    id: '20260301-120000-000',
+    title: 'Launch ideas',
+    content: '# Launch ideas\n\nShip it',
+    updatedAt: 2,
+  },
+  {
+    id: '20260228-090000-000',
+    title: 'Old notes',
+    content: 'Old notes',
+    updatedAt: 1,
+  },
+]
+
+const notes = {
+  name: 'notes',
+  path: '/project/src/content/notes',
+} as Collection
+
+describe('ScratchpadDialog', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    // eslint-disable-next-line @typescript-eslint/no-unsafe-argument
+    vi.mocked(useCollectionsQuery).mockReturnValue({
+      data: [notes],
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    } as any)
+    vi.mocked(commands.listScratchpads).mockResolvedValue({
+      status: 'ok',
+      data: scratchpads,
+    })
+    useProjectStore.setState({
+      projectPath: '/project',
+      selectedCollection: 'notes',
+    })
+    useScratchpadStore.setState({ isOpen: true, activeId: null })
+  })
+
+  it('shows the most recent scratchpad and switches between them', async () => {
+    renderWithProviders(<ScratchpadDialog />)
+
+    expect(await screen.findByLabelText('Scratchpad')).toHaveValue(
+      '# Launch ideas\n\nShip it'
+    )
+
+    fireEvent.click(screen.getByRole('button', { name: 'Old notes' }))
+
+    expect(screen.getByLabelText('Scratchpad')).toHaveValue('Old notes')
+    expect(screen.getByLabelText('Entry title')).toHaveValue('Old notes')
+  })
+
+  it('promotes into the selected collection with a slug filename', async () => {
+    vi.mocked(commands.promoteScratchpadToEntry).mockResolvedValue({
+      status: 'ok',
+      data: '/project/src/content/notes/launch-ideas.md',
+    })
+    vi.mocked(commands.resolveFileEntry).mockResolvedValue({
+      status: 'ok',
+      data: null,
+    })
+    renderWithProviders(<ScratchpadDialog />)
+
+    fireEvent.click(
+      await screen.findByRole('button', { name: 'Promote to Entry' })
+    )
+
+    await waitFor(() =>
+      expect(commands.promoteScratchpadToEntry).toHaveBeenCalledWith(
+        '20260301-120000-000',
+        '/project',
+        '/project/src/content/notes',
+        'launch-ideas.md',
+        'Launch ideas',
+        expect.objectContaining({ title: 'title' })
+      )
+    )
+    await waitFor(() =>
+      expect(useScratchpadStore.getState().isOpen).toBe(false)
+    )
+  })
+})
@@ -0,0 +1,272 @@
+import React from 'react'
+import { useQueryClient } from '@tanstack/react-query'
+import { Plus, Trash2 } from 'lucide-react'
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+} from '../ui/dialog'
+import { Button } from '../ui/button'
+import { Input } from '../ui/input'
+import { Textarea } from '../ui/textarea'
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '../ui/select'
+import { useScratchpadStore } from '../../store/scratchpadStore'
+import { useProjectStore } from '../../store/projectStore'
+import { useScratchpadsQuery } from '../../hooks/queries/useScratchpadsQuery'
+import { useCollectionsQuery } from '../../hooks/queries/useCollectionsQuery'
+import { usePromoteScratchpadMutation } from '../../hooks/mutations/usePromoteScratchpadMutation'
+import { getEffectiveFrontmatterMappings } from '../../lib/project-registry/path-resolution'
+import { newScratchpad, openPromotedEntry } from '../../lib/scratchpads'
+import { queryKeys } from '../../lib/query-keys'
+import { slugFromTitle } from '../../lib/slug'
+import { toast } from '../../lib/toast'
+import { commands, type Collection, type Scratchpad } from '@/types'
+
+const EMPTY_COLLECTIONS: Collection[] = []
+
+// Saves are debounced while typing
+const SAVE_DELAY_MS = 500
+
+interface ScratchpadEditorProps {
+  scratchpad: Scratchpad
+  collections: Collection[]
+}
+
+/**
+ * One scratchpad's content, saved as it's typed, with the form to promote it
+ * into a collection
+ */
+const ScratchpadEditor: React.FC<ScratchpadEditorProps> = ({
+  scratchpad,
+  collections,
+}) => {
+  const queryClient = useQueryClient()
+  const projectPath = useProjectStore(state => state.projectPath)
+  const currentProjectSettings = useProjectStore(
+    state => state.currentProjectSettings
+  )
+  const selectedCollection = useProjectStore(state => state.selectedCollection)
+  const close = useScratchpadStore(state => state.close)
+  const promoteMutation = usePromoteScratchpadMutation()
+
+  const [content, setContent] = React.useState(scratchpad.content)
+  const [title, setTitle] = React.useState(scratchpad.title)
+  const [collectionName, setCollectionName] = React.useState(
+    selectedCollection ?? collections[0]?.name ?? ''
+  )
+  const pendingRef = React.useRef<string | null>(null)
+  const timeoutRef = React.useRef<ReturnType<typeof setTimeout> | null>(null)
+
+  const flush = React.useCallback(async () => {
+    if (timeoutRef.current) {
+      clearTimeout(timeoutRef.current)
+      timeoutRef.current = null
+    }
+    const pending = pendingRef.current
+    if (pending === null) return
+    pendingRef.current = null
+
+    const result = await commands.saveScratchpad(scratchpad.id, pending)
+    if (result.status === 'error') {
+      toast.error('Failed to save scratchpad', { description: result.error })
+      return
+    }
+    void queryClient.invalidateQueries({ queryKey: queryKeys.scratchpads() })
+  }, [scratchpad.id, queryClient])
+
+  // Save anything still pending when switching scratchpads or closing
+  React.useEffect(() => () => void flush(), [flush])
+
+  const handleChange = (value: string) => {
+    setContent(value)
+    pendingRef.current = value
+    if (timeoutRef.current) clearTimeout(timeoutRef.current)
+    timeoutRef.current = setTimeout(() => void flush(), SAVE_DELAY_MS)
+  }
+
+  const collection = collections.find(c => c.name === collectionName)
+
+  const promote = async () => {
+    if (!projectPath || !collection || !title.trim()) return
+    await flush()
+
+    const mappings = getEffectiveFrontmatterMappings(
+      currentProjectSettings,
+      collection.name
+    )
+    promoteMutation.mutate(
+      {
+        id: scratchpad.id,
+        projectPath,
+        collectionName: collection.name,
+        collectionPath: collection.path,
+        filename: `${slugFromTitle(title) || 'scratchpad'}.md`,
+        title: title.trim(),
+        fields: {
+          title: mappings.title,
+          date: [mappings.publishedDate].flat()[0] ?? null,
+          draft: mappings.draft,
+        },
+      },
+      {
+        onSuccess: filePath => {
+          close()
+          void openPromotedEntry(filePath)
+          toast.success(`Promoted to ${collection.name}`)
+        },
+      }
+    )
+  }
+
+  const remove = async () => {
+    if (timeoutRef.current) clearTimeout(timeoutRef.current)
+    pendingRef.current = null
+    const result = await commands.deleteScratchpad(scratchpad.id)
+    if (result.status === 'error') {
+      toast.error('Failed to delete scratchpad', { description: result.error })
+      return
+    }
+    useScratchpadStore.getState().select(null)
+    void queryClient.invalidateQueries({ queryKey: queryKeys.scratchpads() })
+  }
+
+  return (
+    <div className="flex min-w-0 flex-1 flex-col gap-3">
+      <Textarea
+        aria-label="Scratchpad"
+        autoFocus
+        className="min-h-72 flex-1 font-mono text-sm"
+        placeholder="Paste or type anything. It stays out of your project until you promote it."
+        value={content}
+        onChange={event => handleChange(event.target.value)}
+      />
+
+      <div className="flex items-center gap-2">
+        <Input
+          aria-label="Entry title"
+          placeholder="Title"
+          value={title}
+          onChange={event => setTitle(event.target.value)}
+        />
+        <Select value={collectionName} onValueChange={setCollectionName}>
+          <SelectTrigger className="w-40" aria-label="Collection">
+            <SelectValue placeholder="Collection" />
+          </SelectTrigger>
+          <SelectContent>
+            {collections.map(c => (
+              <SelectItem key={c.name} value={c.name}>
+                {c.name}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+        <Button
+          size="sm"
+          disabled={
+            !projectPath ||
+            !collection ||
+            !title.trim() ||
+            promoteMutation.isPending
+          }
+          onClick={() => void promote()}
+        >
+          Promote to Entry
+        </Button>
+        <Button
+          size="sm"
+          variant="ghost"
+          title="Delete scratchpad"
+          onClick={() => void remove()}
+        >
+          <Trash2 className="size-4" />
+        </Button>
+      </div>
+    </div>
+  )
+}
+
+/**
+ * Scratchpads: notes kept in app data, out of the project, until they're
+ * promoted into a collection
+ */
+export function ScratchpadDialog() {
+  const isOpen = useScratchpadStore(state => state.isOpen)
+  const activeId = useScratchpadStore(state => state.activeId)
+  const select = useScratchpadStore(state => state.select)
+  const close = useScratchpadStore(state => state.close)
+
+  const projectPath = useProjectStore(state => state.projectPath)
+  const currentProjectSettings = useProjectStore(
+    state => state.currentProjectSettings
+  )
+  const { data: collections = EMPTY_COLLECTIONS } = useCollectionsQuery(
+    projectPath,
+    currentProjectSettings
+  )
+  const { data: scratchpads = [] } = useScratchpadsQuery(isOpen)
+
+  const active =
+    scratchpads.find(scratchpad => scratchpad.id === activeId) ??
+    scratchpads[0]
+
+  return (
+    <Dialog open={isOpen} onOpenChange={open => !open && close()}>
+      <DialogContent className="sm:max-w-4xl">
+        <DialogHeader>
+          <DialogTitle>Scratchpads</DialogTitle>
+          <DialogDescription>
+            Kept outside your project until promoted into a collection.
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="flex min-h-96 gap-4">
+          <div className="flex w-48 shrink-0 flex-col gap-1">
+            <Button
+              size="sm"
+              variant="outline"
+              className="mb-2 justify-start"
+              onClick={() => void newScratchpad()}
+            >
+              <Plus className="size-4" />
+              New Scratchpad
+            </Button>
+            {scratchpads.map(scratchpad => (
+              <button
+                key={scratchpad.id}
+                type="button"
+                onClick={() => select(scratchpad.id)}
+                className={`truncate rounded-md px-2 py-1 text-left text-sm ${
+                  scratchpad.id === active?.id
+                    ? 'bg-accent text-accent-foreground'
+                    : 'text-muted-foreground hover:bg-accent/50'
+                }`}
+              >
+                {scratchpad.title || 'Untitled'}
+              </button>
+            ))}
+          </div>
+
+          {active ? (
+            <ScratchpadEditor
+              key={active.id}
+              scratchpad={active}
+              collections={collections}
+            />
+          ) : (
+            <div className="flex flex-1 items-center justify-center text-sm text-muted-foreground">
+              No scratchpads yet.
+            </div>
+          )}
+        </div>
+      </DialogContent>
+    </Dialog>
+  )
+}
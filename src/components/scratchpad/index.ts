@@ -0,0 +1 @@
+export { ScratchpadDialog } from './ScratchpadDialog'
@@ -0,0 +1,66 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent } from '@testing-library/react'
+import { StaleDraftsDialog } from './StaleDraftsDialog'
+import { useStaleDraftsStore } from '../../store/staleDraftsStore'
+import { useProjectStore } from '../../store/projectStore'
+import { renderWithProviders } from '../../test/test-utils'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    getStaleDrafts: vi.fn(),
+  },
+}))
+vi.mock('../../lib/open-file', () => ({
+  openProjectFile: vi.fn(),
+}))
+
+import { commands } from '@/lib/bindings'
+import { openProjectFile } from '../../lib/open-file'
+
+describe('StaleDraftsDialog', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    vi.mocked(commands.getStaleDrafts).mockResolvedValue({
+      status: 'ok',
+      data: [
+        {
+          filePath: '/project/src/content/blog/half-done.md',
+          collection: 'blog',
+          title: 'Half Done',
+          wordCount: 420,
+          lastEdited: '2026-01-02',
+          daysSinceEdit: 95,
+        },
+      ],
+    })
+    useProjectStore.setState({
+      projectPath: '/project',
+      currentProjectSettings: null,
+    })
+    useStaleDraftsStore.setState({ isOpen: true })
+  })
+
+  it('lists drafts with how long they have been untouched', async () => {
+    renderWithProviders(<StaleDraftsDialog />)
+
+    expect(await screen.findByText('Half Done')).toBeInTheDocument()
+    expect(screen.getByText('95 days ago')).toBeInTheDocument()
+    expect(commands.getStaleDrafts).toHaveBeenCalledWith(
+      '/project',
+      'src/content',
+      30,
+      { title: 'title', draft: 'draft' }
+    )
+  })
+
+  it('opens a draft', async () => {
+    renderWithProviders(<StaleDraftsDialog />)
+
+    fireEvent.click(await screen.findByText('Half Done'))
+
+    expect(openProjectFile).toHaveBeenCalledWith(
+      '/project/src/content/blog/half-done.md'
+    )
+    expect(useStaleDraftsStore.getState().isOpen).toBe(false)
+  })
+})
@@ -0,0 +1,85 @@
+import { useShallow } from 'zustand/react/shallow'
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+} from '../ui/dialog'
+import { useStaleDraftsStore } from '../../store/staleDraftsStore'
+import { useProjectStore } from '../../store/projectStore'
+import { useStaleDraftsQuery } from '../../hooks/queries/useStaleDraftsQuery'
+import { DEFAULT_STALE_DRAFT_DAYS } from '../../hooks/useStaleDraftDigest'
+import { openProjectFile } from '../../lib/open-file'
+
+/**
+ * Drafts nobody has touched for the project's stale draft period, oldest
+ * first. Choosing one opens it.
+ */
+export function StaleDraftsDialog() {
+  const isOpen = useStaleDraftsStore(state => state.isOpen)
+  const close = useStaleDraftsStore(state => state.close)
+  const projectPath = useProjectStore(state => state.projectPath)
+  const currentProjectSettings = useProjectStore(
+    useShallow(state => state.currentProjectSettings)
+  )
+  const days =
+    currentProjectSettings?.staleDrafts?.days ?? DEFAULT_STALE_DRAFT_DAYS
+  const { data: drafts = [], isLoading } = useStaleDraftsQuery(
+    isOpen ? projectPath : null,
+    currentProjectSettings,
+    days
+  )
+
+  return (
+    <Dialog open={isOpen} onOpenChange={open => !open && close()}>
+      <DialogContent className="sm:max-w-2xl">
+        <DialogHeader>
+          <DialogTitle>Stale Drafts</DialogTitle>
+          <DialogDescription>
+            Drafts that haven&apos;t been edited in {days} days or more.
+          </DialogDescription>
+        </DialogHeader>
+
+        {isLoading ? (
+          <div className="py-8 text-center text-sm text-muted-foreground">
+            Looking for drafts…
+          </div>
+        ) : drafts.length === 0 ? (
+          <div className="py-8 text-center text-sm text-muted-foreground">
+            Every draft has been edited recently.
+          </div>
+        ) : (
+          <ul className="max-h-96 divide-y overflow-y-auto">
+            {drafts.map(draft => (
+              <li key={draft.filePath}>
+                <button
+                  type="button"
+                  className="flex w-full items-center gap-3 rounded-md px-2 py-1.5 text-left text-sm hover:bg-accent"
+                  onClick={() => {
+                    close()
+                    void openProjectFile(draft.filePath)
+                  }}
+                >
+                  <span className="min-w-0 flex-1">
+                    <span className="block truncate">{draft.title}</span>
+                    <span className="block text-xs text-muted-foreground">
+                      {`${draft.collection} · ` +
+                        `${draft.wordCount.toLocaleString()} words`}
+                    </span>
+                  </span>
+                  <span
+                    className="shrink-0 text-xs text-muted-foreground"
+                    title={`Last edited ${draft.lastEdited}`}
+                  >
+                    {draft.daysSinceEdit} days ago
+                  </span>
+                </button>
+              </li>
+            ))}
+          </ul>
+        )}
+      </DialogContent>
+    </Dialog>
+  )
+}
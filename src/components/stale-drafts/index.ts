@@ -0,0 +1 @@
+export { StaleDraftsDialog } from './StaleDraftsDialog'
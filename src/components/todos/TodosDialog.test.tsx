@@ -0,0 +1,74 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent } from '@testing-library/react'
+import { TodosDialog } from './TodosDialog'
+import { useTodosStore } from '../../store/todosStore'
+import { useProjectStore } from '../../store/projectStore'
+import { renderWithProviders } from '../../test/test-utils'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    collectTodos: vi.fn(),
+  },
+}))
+vi.mock('../../lib/open-file', () => ({
+  openProjectFile: vi.fn(),
+}))
+
+import { commands } from '@/lib/bindings'
+import { openProjectFile } from '../../lib/open-file'
+
+describe('TodosDialog', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    vi.mocked(commands.collectTodos).mockResolvedValue({
+      status: 'ok',
+      data: [
+        {
+          filePath: '/project/src/content/blog/post.md',
+          collection: 'blog',
+          line: 3,
+          marker: 'todo',
+          text: 'pick a hero image',
+          inFrontmatter: true,
+        },
+        {
+          filePath: '/project/src/content/blog/post.md',
+          collection: 'blog',
+          line: 14,
+          marker: 'fixme',
+          text: 'check these numbers',
+          inFrontmatter: false,
+        },
+      ],
+    })
+    useProjectStore.setState({
+      projectPath: '/project',
+      currentProjectSettings: null,
+    })
+    useTodosStore.setState({ isOpen: true })
+  })
+
+  it('groups notes by the file they are in', async () => {
+    renderWithProviders(<TodosDialog />)
+
+    expect(
+      await screen.findByText('src/content/blog/post.md')
+    ).toBeInTheDocument()
+    expect(screen.getByText('pick a hero image')).toBeInTheDocument()
+    expect(screen.getByText('frontmatter')).toBeInTheDocument()
+    expect(screen.getByText('line 14')).toBeInTheDocument()
+    expect(commands.collectTodos).toHaveBeenCalledWith('/project', null)
+  })
+
+  it('opens an entry at the note', async () => {
+    renderWithProviders(<TodosDialog />)
+
+    fireEvent.click(await screen.findByText('check these numbers'))
+
+    expect(openProjectFile).toHaveBeenCalledWith(
+      '/project/src/content/blog/post.md',
+      14
+    )
+    expect(useTodosStore.getState().isOpen).toBe(false)
+  })
+})
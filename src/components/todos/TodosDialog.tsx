@@ -0,0 +1,115 @@
+import { useShallow } from 'zustand/react/shallow'
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+} from '../ui/dialog'
+import { Badge } from '../ui/badge'
+import { useTodosStore } from '../../store/todosStore'
+import { useProjectStore } from '../../store/projectStore'
+import { useTodosQuery } from '../../hooks/queries/useTodosQuery'
+import { getEffectiveContentDirectory } from '../../lib/project-registry'
+import { ASTRO_PATHS } from '../../lib/constants'
+import { openProjectFile } from '../../lib/open-file'
+import type { TodoItem } from '@/types'
+
+/**
+ * Notes grouped by the file they're in, keeping the files' order
+ */
+function groupByFile(todos: TodoItem[]): [string, TodoItem[]][] {
+  const groups = new Map<string, TodoItem[]>()
+  for (const todo of todos) {
+    groups.set(todo.filePath, [...(groups.get(todo.filePath) ?? []), todo])
+  }
+  return Array.from(groups)
+}
+
+/**
+ * TODO, FIXME and HACK notes left in entries across the project, each of
+ * which opens its entry at the note
+ */
+export function TodosDialog() {
+  const isOpen = useTodosStore(state => state.isOpen)
+  const close = useTodosStore(state => state.close)
+  const projectPath = useProjectStore(state => state.projectPath)
+  const currentProjectSettings = useProjectStore(
+    useShallow(state => state.currentProjectSettings)
+  )
+  const contentDirectory = getEffectiveContentDirectory(currentProjectSettings)
+  const { data: todos = [], isLoading } = useTodosQuery(
+    isOpen ? projectPath : null,
+    contentDirectory !== ASTRO_PATHS.CONTENT_DIR ? contentDirectory : undefined
+  )
+
+  const relativePath = (filePath: string) =>
+    projectPath && filePath.startsWith(projectPath)
+      ? filePath.slice(projectPath.length + 1)
+      : filePath
+
+  return (
+    <Dialog open={isOpen} onOpenChange={open => !open && close()}>
+      <DialogContent className="sm:max-w-2xl">
+        <DialogHeader>
+          <DialogTitle>TODOs</DialogTitle>
+          <DialogDescription>
+            Notes marked TODO, FIXME or HACK in entries, including{' '}
+            <code className="text-xs">{'<!-- TODO: ... -->'}</code> comments.
+          </DialogDescription>
+        </DialogHeader>
+
+        {isLoading ? (
+          <div className="py-8 text-center text-sm text-muted-foreground">
+            Looking for notes…
+          </div>
+        ) : todos.length === 0 ? (
+          <div className="py-8 text-center text-sm text-muted-foreground">
+            No TODOs left in this project.
+          </div>
+        ) : (
+          <div className="flex max-h-96 flex-col gap-3 overflow-y-auto">
+            {groupByFile(todos).map(([filePath, items]) => (
+              <section key={filePath}>
+                <h3 className="truncate px-2 text-xs font-medium text-muted-foreground">
+                  {relativePath(filePath)}
+                </h3>
+                <ul>
+                  {items.map(todo => (
+                    <li key={`${todo.line}:${todo.text}`}>
+                      <button
+                        type="button"
+                        className="flex w-full items-center gap-2 rounded-md px-2 py-1.5 text-left text-sm hover:bg-accent"
+                        onClick={() => {
+                          close()
+                          void openProjectFile(todo.filePath, todo.line)
+                        }}
+                      >
+                        <Badge
+                          variant={
+                            todo.marker === 'fixme' ? 'destructive' : 'outline'
+                          }
+                          className="w-12 font-mono uppercase"
+                        >
+                          {todo.marker}
+                        </Badge>
+                        <span className="truncate">
+                          {todo.text || 'No description'}
+                        </span>
+                        <span className="ml-auto shrink-0 text-xs text-muted-foreground">
+                          {todo.inFrontmatter
+                            ? 'frontmatter'
+                            : `line ${todo.line}`}
+                        </span>
+                      </button>
+                    </li>
+                  ))}
+                </ul>
+              </section>
+            ))}
+          </div>
+        )}
+      </DialogContent>
+    </Dialog>
+  )
+}
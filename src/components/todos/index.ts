@@ -0,0 +1 @@
+export { TodosDialog } from './TodosDialog'
@@ -0,0 +1,75 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { screen, fireEvent, waitFor } from '@testing-library/react'
+import { TrashDialog } from './TrashDialog'
+import { useTrashStore } from '../../store/trashStore'
+import { useProjectStore } from '../../store/projectStore'
+import { renderWithProviders } from '../../test/test-utils'
+import type { TrashedFile } from '@/types'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    listTrashedFiles: vi.fn(),
+    restoreTrashedFile: vi.fn(),
+  },
+}))
+
+import { commands } from '@/lib/bindings'
+
+const trashed: TrashedFile = {
+  id: '0001772366400000',
+  originalPath: '/project/src/content/blog/first-post.md',
+  projectRoot: '/project',
+  trashedAt: '2026-03-01T12:00:00.000Z',
+}
+
+describe('TrashDialog', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    vi.mocked(commands.listTrashedFiles).mockResolvedValue({
+      status: 'ok',
+      data: [trashed],
+    })
+    useProjectStore.setState({ projectPath: '/project' })
+    useTrashStore.setState({ isOpen: true })
+  })
+
+  it('lists deleted files with the folder they came from', async () => {
+    renderWithProviders(<TrashDialog />)
+
+    expect(await screen.findByText('first-post.md')).toBeInTheDocument()
+    expect(screen.getByText(/^src\/content\/blog ·/)).toBeInTheDocument()
+    expect(commands.listTrashedFiles).toHaveBeenCalledWith('/project')
+  })
+
+  it('restores a file into the current project', async () => {
+    vi.mocked(commands.restoreTrashedFile).mockResolvedValue({
+      status: 'ok',
+      data: trashed.originalPath,
+    })
+    renderWithProviders(<TrashDialog />)
+
+    fireEvent.click(
+      await screen.findByRole('button', { name: 'Restore first-post.md' })
+    )
+
+    await waitFor(() =>
+      expect(commands.restoreTrashedFile).toHaveBeenCalledWith(
+        trashed.id,
+        '/project',
+        'restore'
+      )
+    )
+  })
+
+  it('says when nothing has been deleted', async () => {
+    vi.mocked(commands.listTrashedFiles).mockResolvedValue({
+      status: 'ok',
+      data: [],
+    })
+    renderWithProviders(<TrashDialog />)
+
+    expect(
+      await screen.findByText('Nothing has been deleted from this project.')
+    ).toBeInTheDocument()
+  })
+})
@@ -0,0 +1,92 @@
+import React from 'react'
+import { RotateCcw } from 'lucide-react'
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+} from '../ui/dialog'
+import { Button } from '../ui/button'
+import { useTrashStore } from '../../store/trashStore'
+import { useProjectStore } from '../../store/projectStore'
+import { useTrashedFilesQuery } from '../../hooks/queries/useTrashedFilesQuery'
+import { restoreTrashedFile, trashedFileName } from '../../lib/trash'
+import type { TrashedFile } from '@/types'
+
+/**
+ * The folder a trashed file was deleted from, relative to the project
+ */
+function deletedFrom(file: TrashedFile): string {
+  const relative = file.originalPath.startsWith(file.projectRoot)
+    ? file.originalPath.slice(file.projectRoot.length + 1)
+    : file.originalPath
+  return relative.split(/[\\/]/).slice(0, -1).join('/')
+}
+
+/**
+ * Files deleted from the current project in the last 30 days, each of which
+ * can be put back where it was
+ */
+export function TrashDialog() {
+  const isOpen = useTrashStore(state => state.isOpen)
+  const close = useTrashStore(state => state.close)
+  const projectPath = useProjectStore(state => state.projectPath)
+  const { data: files = [], isLoading } = useTrashedFilesQuery(
+    projectPath,
+    isOpen
+  )
+  const [restoring, setRestoring] = React.useState<string | null>(null)
+
+  const restore = async (file: TrashedFile) => {
+    setRestoring(file.id)
+    await restoreTrashedFile(file)
+    setRestoring(null)
+  }
+
+  return (
+    <Dialog open={isOpen} onOpenChange={open => !open && close()}>
+      <DialogContent className="sm:max-w-2xl">
+        <DialogHeader>
+          <DialogTitle>Deleted Files</DialogTitle>
+          <DialogDescription>
+            Deleted files are kept for 30 days and can be restored to where
+            they were.
+          </DialogDescription>
+        </DialogHeader>
+
+        {!isLoading && files.length === 0 ? (
+          <div className="py-8 text-center text-sm text-muted-foreground">
+            Nothing has been deleted from this project.
+          </div>
+        ) : (
+          <ul className="max-h-96 divide-y overflow-y-auto">
+            {files.map(file => (
+              <li key={file.id} className="flex items-center gap-3 py-2">
+                <div className="min-w-0 flex-1">
+                  <div className="truncate text-sm font-medium">
+                    {trashedFileName(file)}
+                  </div>
+                  <div className="truncate text-xs text-muted-foreground">
+                    {deletedFrom(file)} ·{' '}
+                    {new Date(file.trashedAt).toLocaleString()}
+                  </div>
+                </div>
+                <Button
+                  size="sm"
+                  variant="outline"
+                  aria-label={`Restore ${trashedFileName(file)}`}
+                  disabled={restoring !== null}
+                  onClick={() => void restore(file)}
+                >
+                  <RotateCcw className="size-4" />
+                  Restore
+                </Button>
+              </li>
+            ))}
+          </ul>
+        )}
+      </DialogContent>
+    </Dialog>
+  )
+}
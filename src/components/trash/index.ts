@@ -0,0 +1 @@
+export { TrashDialog } from './TrashDialog'
@@ -1,12 +1,12 @@
 import { Menu, MenuItem, PredefinedMenuItem } from '@tauri-apps/api/menu'
 import { LogicalPosition } from '@tauri-apps/api/dpi'
 import { commands } from '@/lib/bindings'
-import { remove } from '@tauri-apps/plugin-fs'
 import { openPath } from '@tauri-apps/plugin-opener'
 import { ask } from '@tauri-apps/plugin-dialog'
 import type { FileEntry } from '@/types'
 import { useProjectStore } from '../../store/projectStore'
 import { openInIde } from '../../lib/ide'
+import { notifyFileDeleted } from '../../lib/trash'
 import { getTitle } from '@/lib/files/sorting'
 import { getPlatform } from '@/hooks/usePlatform'
 import { getPlatformString } from '@/lib/platform-strings'
@@ -38,22 +38,6 @@ export class FileContextMenu {
     }
   }
 
-  private static generateDuplicatePath(originalPath: string): string {
-    const lastSlashIndex = originalPath.lastIndexOf('/')
-    const directory = originalPath.substring(0, lastSlashIndex)
-    const fileName = originalPath.substring(lastSlashIndex + 1)
-
-    const lastDotIndex = fileName.lastIndexOf('.')
-    if (lastDotIndex === -1) {
-      // No extension
-      return `${directory}/${fileName}-1`
-    }
-
-    const nameWithoutExt = fileName.substring(0, lastDotIndex)
-    const extension = fileName.substring(lastDotIndex)
-    return `${directory}/${nameWithoutExt}-1${extension}`
-  }
-
   static async show({
     file,
     position,
@@ -120,30 +104,24 @@ export class FileContextMenu {
         action: () => {
           void (async () => {
             try {
-              const duplicatePath = FileContextMenu.generateDuplicatePath(
-                file.path
+              // The copy starts as a draft, so a post based on another
+              // can't go live by accident
+              const draftField =
+                currentProjectSettings?.frontmatterMappings?.draft || 'draft'
+              const isDraftable = file.frontmatter?.[draftField] !== undefined
+
+              const duplicateResult = await commands.duplicateFile(
+                file.path,
+                projectPath,
+                {
+                  set: isDraftable ? { [draftField]: true } : {},
+                  clear: [],
+                  appendCopyTo: titleField,
+                },
+                'duplicate'
               )
-
-              // Read the original file content
-              const readResult = await commands.readFile(file.path, projectPath)
-              if (readResult.status === 'error') {
-                throw new Error(readResult.error)
-              }
-
-              // Parse the duplicate path into directory and filename
-              const lastSlashIndex = duplicatePath.lastIndexOf('/')
-              const directory = duplicatePath.substring(0, lastSlashIndex)
-              const filename = duplicatePath.substring(lastSlashIndex + 1)
-
-              // Create the duplicate file
-              const createResult = await commands.createFile(
-                directory,
-                filename,
-                readResult.data,
-                projectPath
-              )
-              if (createResult.status === 'error') {
-                throw new Error(createResult.error)
+              if (duplicateResult.status === 'error') {
+                throw new Error(duplicateResult.error)
               }
 
               // Refresh the file list if callback is provided
@@ -199,7 +177,15 @@ export class FileContextMenu {
               const confirmed =
                 await FileContextMenu.showConfirmationDialog(fileName)
               if (confirmed) {
-                await remove(file.path)
+                const deleteResult = await commands.deleteFile(
+                  file.path,
+                  projectPath,
+                  'delete'
+                )
+                if (deleteResult.status === 'error') {
+                  throw new Error(deleteResult.error)
+                }
+                notifyFileDeleted(deleteResult.data)
                 // Refresh the file list if callback is provided
                 if (onRefresh) {
                   onRefresh()
@@ -5,6 +5,7 @@ import { ChevronDownIcon } from 'lucide-react'
 
 import { Button } from '@/components/ui/button'
 import { Calendar } from '@/components/ui/calendar'
+import { Input } from '@/components/ui/input'
 import {
   Popover,
   PopoverContent,
@@ -16,6 +17,10 @@ interface DatePickerProps {
   onChange?: (date: Date | undefined) => void
   placeholder?: string
   className?: string
+  /** Parses typed text such as "next friday"; enables the text entry box */
+  parseText?: (text: string) => Promise<Date | undefined>
+  /** Text shown for the selected date (defaults to `toLocaleDateString`) */
+  displayValue?: string
 }
 
 function DatePicker({
@@ -23,28 +28,64 @@ function DatePicker({
   onChange,
   placeholder = 'Select date',
   className,
+  parseText,
+  displayValue,
 }: DatePickerProps) {
   const [open, setOpen] = React.useState(false)
+  const [text, setText] = React.useState('')
+  const [textError, setTextError] = React.useState(false)
+
+  const handleOpenChange = (next: boolean) => {
+    setOpen(next)
+    setText('')
+    setTextError(false)
+  }
+
+  const handleTextKeyDown = async (e: React.KeyboardEvent) => {
+    if (e.key !== 'Enter' || !parseText || !text.trim()) return
+    e.preventDefault()
+    const date = await parseText(text)
+    if (!date) {
+      setTextError(true)
+      return
+    }
+    onChange?.(date)
+    handleOpenChange(false)
+  }
 
   return (
-    <Popover open={open} onOpenChange={setOpen}>
+    <Popover open={open} onOpenChange={handleOpenChange}>
       <PopoverTrigger asChild>
         <Button
           variant="outline"
           className={`w-full justify-between font-normal ${className || ''}`}
         >
-          {value ? value.toLocaleDateString() : placeholder}
+          {value ? (displayValue ?? value.toLocaleDateString()) : placeholder}
           <ChevronDownIcon className="size-4" />
         </Button>
       </PopoverTrigger>
       <PopoverContent className="w-auto overflow-hidden p-0" align="start">
+        {parseText && (
+          <div className="border-b p-2">
+            <Input
+              value={text}
+              placeholder='e.g. "next friday"'
+              aria-invalid={textError}
+              onChange={e => {
+                setText(e.target.value)
+                setTextError(false)
+              }}
+              onKeyDown={e => void handleTextKeyDown(e)}
+            />
+          </div>
+        )}
         <Calendar
           mode="single"
           selected={value}
           captionLayout="dropdown"
           onSelect={date => {
             onChange?.(date)
-            setOpen(false)
+            handleOpenChange(false)
           }}
         />
         {value && (
@@ -55,7 +96,7 @@ function DatePicker({
               className="w-full"
               onClick={() => {
                 onChange?.(undefined)
-                setOpen(false)
+                handleOpenChange(false)
               }}
             >
               Clear
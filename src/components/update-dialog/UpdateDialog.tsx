@@ -1,7 +1,10 @@
 import { marked } from 'marked'
 import { relaunch } from '@tauri-apps/plugin-process'
-import { error as logError, info } from '@tauri-apps/plugin-log'
+import { listen } from '@tauri-apps/api/event'
+import { error as logError } from '@tauri-apps/plugin-log'
+import { commands, type Result, type UpdateDownloadProgress } from '@/types'
 import { useUpdateStore } from '@/store/updateStore'
+import { toast } from '@/lib/toast'
 import {
   Dialog,
   DialogContent,
@@ -12,7 +15,12 @@ import {
 } from '@/components/ui/dialog'
 import { Button } from '@/components/ui/button'
 import { Spinner } from '@/components/ui/spinner'
-import { CheckCircle2Icon, AlertCircleIcon, DownloadIcon } from 'lucide-react'
+import {
+  CheckCircle2Icon,
+  AlertCircleIcon,
+  DownloadIcon,
+  ClockIcon,
+} from 'lucide-react'
 import './release-notes.css'
 
 function ProgressBar({ value }: { value: number }) {
@@ -26,26 +34,30 @@ function ProgressBar({ value }: { value: number }) {
   )
 }
 
-function ReleaseNotesArea() {
-  const loading = useUpdateStore(s => s.releaseNotesLoading)
-  const hasError = useUpdateStore(s => s.releaseNotesError)
-  const notes = useUpdateStore(s => s.releaseNotes)
-
-  if (loading) {
-    return (
-      <div className="flex items-center justify-center py-8">
-        <Spinner className="size-5" />
-      </div>
-    )
+/**
+ * Runs an updater command, forwarding `update-download-progress` events to
+ * the store while it downloads
+ */
+async function withDownloadProgress(
+  run: () => Promise<Result<null, string>>
+) {
+  useUpdateStore.getState().setDownloading()
+  const unlisten = await listen<UpdateDownloadProgress>(
+    'update-download-progress',
+    event => {
+      const { downloaded, total } = event.payload
+      useUpdateStore.getState().setProgress(downloaded, total ?? 0)
+    }
+  )
+  try {
+    return await run()
+  } finally {
+    unlisten()
   }
+}
 
-  if (hasError) {
-    return (
-      <p className="text-muted-foreground py-4 text-center text-sm">
-        Could not load release notes.
-      </p>
-    )
-  }
+function ReleaseNotesArea() {
+  const notes = useUpdateStore(s => s.releaseNotes)
 
   if (!notes) {
     return (
@@ -84,39 +96,29 @@ function CheckingContent() {
 function AvailableContent() {
   const version = useUpdateStore(s => s.version)
   const currentVersion = useUpdateStore(s => s.currentVersion)
+  const deferred = useUpdateStore(s => s.deferred)
   const skipVersion = useUpdateStore(s => s.skipVersion)
   const closeDialog = useUpdateStore(s => s.closeDialog)
 
   const handleUpdate = async () => {
-    const state = useUpdateStore.getState()
-    if (!state.updateRef) return
-
-    state.setDownloading()
-    try {
-      let totalBytes = 0
-      let downloadedBytes = 0
-
-      await state.updateRef.downloadAndInstall(event => {
-        switch (event.event) {
-          case 'Started':
-            totalBytes = event.data.contentLength ?? 0
-            void info(`Downloading ${totalBytes} bytes`)
-            break
-          case 'Progress':
-            downloadedBytes += event.data.chunkLength
-            useUpdateStore.getState().setProgress(downloadedBytes, totalBytes)
-            break
-          case 'Finished':
-            void info('Download complete')
-            break
-        }
-      })
+    const result = await withDownloadProgress(() => commands.installUpdate())
+    if (result.status === 'error') {
+      void logError(`Update failed: ${result.error}`)
+      useUpdateStore.getState().setError(`Download failed: ${result.error}`)
+      return
+    }
+    useUpdateStore.getState().setReady()
+  }
 
-      useUpdateStore.getState().setReady()
-    } catch (err) {
-      void logError(`Update failed: ${String(err)}`)
-      useUpdateStore.getState().setError(`Download failed: ${String(err)}`)
+  const handleDefer = async () => {
+    const result = await withDownloadProgress(() => commands.deferUpdate())
+    if (result.status === 'error') {
+      void logError(`Update download failed: ${result.error}`)
+      useUpdateStore.getState().setError(`Download failed: ${result.error}`)
+      return
     }
+    useUpdateStore.getState().setDeferred()
+    toast.success(`Version ${version} will be installed when you quit`)
   }
 
   return (
@@ -125,6 +127,7 @@ function AvailableContent() {
         <DialogTitle>Update Available</DialogTitle>
         <DialogDescription>
           {currentVersion && `v${currentVersion}`} → v{version}
+          {deferred && ' (installs when you quit)'}
         </DialogDescription>
       </DialogHeader>
       <ReleaseNotesArea />
@@ -137,6 +140,12 @@ function AvailableContent() {
             Skip This Version
           </Button>
         )}
+        {!deferred && (
+          <Button variant="outline" onClick={() => void handleDefer()}>
+            <ClockIcon />
+            Install on Next Launch
+          </Button>
+        )}
         <Button onClick={() => void handleUpdate()}>
           <DownloadIcon />
           Update Now
@@ -6,6 +6,7 @@ import { commands, type Collection, type JsonValue } from '@/types'
 import { useEditorStore } from '../../store/editorStore'
 import { useProjectStore } from '../../store/projectStore'
 import { saveRecoveryData, saveCrashReport } from '../../lib/recovery'
+import { suggestFrontmatterCoercions } from '../../lib/coercions'
 import { toast } from '../../lib/toast'
 import { queryKeys } from '../../lib/query-keys'
 import { deserializeCompleteSchema } from '../../lib/schema'
@@ -82,20 +83,59 @@ export function useEditorActions() {
         rawFrontmatter,
         isFrontmatterDirty,
         imports,
+        contentHash,
+        frontmatterOnly,
+        saveConflict,
       } = useEditorStore.getState()
       if (!currentFile) return
 
+      // Auto-save is paused while a conflict is unresolved. Saving by hand
+      // tries again, which brings the conflict back up if it's still there.
+      if (saveConflict && !showToast) return
+
       // Get project path using direct store access pattern
-      const { projectPath } = useProjectStore.getState()
+      const { projectPath, currentProjectSettings } = useProjectStore.getState()
 
       if (!projectPath) {
         throw new Error('No project path available')
       }
 
+      // Files too large for the editor only have their frontmatter loaded, so
+      // only the frontmatter is written and the body on disk is kept
+      if (frontmatterOnly) {
+        const result = await commands.updateFrontmatter(
+          currentFile.path,
+          frontmatter as Partial<Record<string, JsonValue>>,
+          projectPath,
+          showToast ? 'save' : 'autosave'
+        )
+        if (result.status === 'error') {
+          toast.error('Save failed', {
+            description: `Could not save frontmatter: ${result.error}`,
+          })
+          return
+        }
+
+        const { autoSaveTimeoutId, frontmatter: latest } =
+          useEditorStore.getState()
+        if (autoSaveTimeoutId) clearTimeout(autoSaveTimeoutId)
+        const frontmatterUnchanged =
+          JSON.stringify(latest) === JSON.stringify(frontmatter)
+        useEditorStore.setState({
+          autoSaveTimeoutId: null,
+          isDirty: !frontmatterUnchanged,
+          isFrontmatterDirty: !frontmatterUnchanged,
+          lastSaveTimestamp: Date.now(),
+        })
+        if (showToast) toast.success('File saved successfully')
+        return
+      }
+
       try {
         // Get schema field order from collections data - NO EVENTS!
         // Direct synchronous access to query cache
         let schemaFieldOrder: string[] | null = null
+        let completeSchema: string | null = null
         if (currentFile) {
           try {
             const collections = queryClient.getQueryData<Collection[]>(
@@ -105,8 +145,9 @@ export function useEditorActions() {
               const collection = collections.find(
                 (c: Collection) => c.name === currentFile.collection
               )
-              const schema = collection?.complete_schema
-                ? deserializeCompleteSchema(collection.complete_schema)
+              completeSchema = collection?.complete_schema ?? null
+              const schema = completeSchema
+                ? deserializeCompleteSchema(completeSchema)
                 : null
               schemaFieldOrder = schema ? schema.fields.map(f => f.name) : null
             }
@@ -116,6 +157,11 @@ export function useEditorActions() {
           }
         }
 
+        // A collection's date formats replace the project's
+        const collectionDateFormats = currentProjectSettings?.collections?.find(
+          c => c.name === currentFile.collection
+        )?.settings.dateFormats
+
         // Only pass frontmatter object if it was edited, otherwise pass raw to preserve formatting
         const result = await commands.saveMarkdownContent(
           currentFile.path,
@@ -126,12 +172,33 @@ export function useEditorActions() {
           editorContent,
           imports,
           schemaFieldOrder,
-          projectPath
+          projectPath,
+          showToast ? 'save' : 'autosave',
+          currentProjectSettings?.readingTime ?? null,
+          currentProjectSettings?.mdxEscaping ?? null,
+          collectionDateFormats ?? currentProjectSettings?.dateFormats ?? null,
+          contentHash
         )
         if (result.status === 'error') {
-          throw new Error(result.error)
+          if (result.error.kind === 'conflict') {
+            // Nothing is lost: the edits stay in the editor (and the unsaved
+            // draft) until the user chooses which version to keep
+            const { autoSaveTimeoutId } = useEditorStore.getState()
+            if (autoSaveTimeoutId) clearTimeout(autoSaveTimeoutId)
+            useEditorStore.setState({
+              saveConflict: result.error,
+              autoSaveTimeoutId: null,
+              isDirty: true,
+            })
+            await info(`Save of ${currentFile.path} refused: changed on disk`)
+            return
+          }
+          throw new Error(result.error.message)
         }
 
+        // Track usage for recent/frequent file lists (fire-and-forget)
+        void commands.recordFileEdited(projectPath, currentFile.path)
+
         // Clear auto-save timeout since we just saved
         const { autoSaveTimeoutId } = useEditorStore.getState()
         if (autoSaveTimeoutId) {
@@ -151,9 +218,18 @@ export function useEditorActions() {
           isDirty: !contentUnchanged || !frontmatterUnchanged,
           isFrontmatterDirty:
             currentState.isFrontmatterDirty || !frontmatterUnchanged,
+          contentHash: result.data,
+          saveConflict: null,
           lastSaveTimestamp: Date.now(),
         })
 
+        // Reset the unsaved word counts against the file we just wrote
+        void commands.reportContentChanged(
+          currentFile.path,
+          currentState.editorContent,
+          projectPath
+        )
+
         // Invalidate queries to update UI
         if (projectPath) {
           // Invalidate file content query to refresh cached content
@@ -177,6 +253,16 @@ export function useEditorActions() {
         // Show success toast only if requested
         if (showToast) {
           toast.success('File saved successfully')
+
+          // Offer to fix stringly-typed frontmatter (manual saves only)
+          if (completeSchema) {
+            void suggestFrontmatterCoercions(
+              currentFile.path,
+              currentFile.id,
+              projectPath,
+              completeSchema
+            )
+          }
         }
       } catch (error) {
         toast.error('Save failed', {
@@ -0,0 +1,61 @@
+// src/hooks/mutations/useApplyRawFrontmatterMutation.ts
+
+import { useMutation, useQueryClient } from '@tanstack/react-query'
+import { commands, type RawFrontmatterError } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+interface ApplyRawFrontmatterPayload {
+  projectPath: string
+  filePath: string
+  rawFrontmatter: string
+  // Omit to write YAML that breaks the schema anyway
+  completeSchema: string | null
+}
+
+/**
+ * Thrown when hand-edited YAML is refused because it doesn't parse or breaks
+ * the schema. Carries the structured reason for inline display.
+ */
+export class RawFrontmatterRejectedError extends Error {
+  constructor(
+    public rejection: Exclude<RawFrontmatterError, { kind: 'failed' }>
+  ) {
+    super(
+      rejection.kind === 'invalidYaml'
+        ? rejection.error.message
+        : 'Frontmatter breaks the collection schema'
+    )
+    this.name = 'RawFrontmatterRejectedError'
+  }
+}
+
+const applyRawFrontmatter = async (payload: ApplyRawFrontmatterPayload) => {
+  const result = await commands.applyRawFrontmatter(
+    payload.filePath,
+    payload.projectPath,
+    payload.rawFrontmatter,
+    payload.completeSchema,
+    'raw-frontmatter'
+  )
+  if (result.status === 'error') {
+    if (result.error.kind === 'failed') {
+      throw new Error(result.error.message)
+    }
+    throw new RawFrontmatterRejectedError(result.error)
+  }
+  return result.data
+}
+
+export const useApplyRawFrontmatterMutation = () => {
+  const queryClient = useQueryClient()
+
+  return useMutation({
+    mutationFn: applyRawFrontmatter,
+    onSuccess: (_, variables) => {
+      // The file may be open, or cached with the error it had before
+      void queryClient.invalidateQueries({
+        queryKey: [...queryKeys.all, variables.projectPath, 'files'],
+      })
+    },
+  })
+}
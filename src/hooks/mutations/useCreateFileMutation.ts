@@ -19,7 +19,8 @@ const createFile = async (payload: CreateFilePayload) => {
     payload.directory,
     payload.filename,
     payload.content,
-    payload.projectPath
+    payload.projectPath,
+    'new-file'
   )
   if (result.status === 'error') {
     throw new Error(result.error)
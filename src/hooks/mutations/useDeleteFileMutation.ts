@@ -1,9 +1,10 @@
 // src/hooks/mutations/useDeleteFileMutation.ts
 
 import { useMutation, useQueryClient } from '@tanstack/react-query'
-import { remove } from '@tauri-apps/plugin-fs'
+import { commands } from '@/lib/bindings'
 import { queryKeys } from '@/lib/query-keys'
 import { toast } from '@/lib/toast'
+import { notifyFileDeleted } from '@/lib/trash'
 import { useProjectStore } from '@/store/projectStore'
 
 interface DeleteFilePayload {
@@ -14,8 +15,16 @@ interface DeleteFilePayload {
 }
 
 const deleteFile = async (payload: DeleteFilePayload) => {
-  // Using the Tauri fs plugin's remove function
-  await remove(payload.filePath)
+  // Goes through Rust so the deletion is validated and audit-logged
+  const result = await commands.deleteFile(
+    payload.filePath,
+    payload.projectPath,
+    'delete'
+  )
+  if (result.status === 'error') {
+    throw new Error(result.error)
+  }
+  return result.data
 }
 
 export const useDeleteFileMutation = () => {
@@ -23,7 +32,7 @@ export const useDeleteFileMutation = () => {
 
   return useMutation({
     mutationFn: deleteFile,
-    onSuccess: (_, variables) => {
+    onSuccess: (trashed, variables) => {
       const { currentSubdirectory } = useProjectStore.getState()
 
       // Invalidate current directory view to remove the deleted file
@@ -48,7 +57,7 @@ export const useDeleteFileMutation = () => {
         ),
       })
 
-      toast.success('File deleted successfully')
+      notifyFileDeleted(trashed)
     },
     onError: error => {
       toast.error('Failed to delete file', {
@@ -0,0 +1,69 @@
+// src/hooks/mutations/useNormalizeCodeBlocksMutation.ts
+
+import { useMutation, useQueryClient } from '@tanstack/react-query'
+import { commands } from '@/lib/bindings'
+import { queryKeys } from '@/lib/query-keys'
+import { toast } from '@/lib/toast'
+import { useUndoBulkOperationMutation } from './useUndoBulkOperationMutation'
+
+interface NormalizeCodeBlocksPayload {
+  projectPath: string
+  collectionName: string
+  collectionPath: string
+  mapping: Record<string, string>
+}
+
+const normalizeCodeBlocks = async (payload: NormalizeCodeBlocksPayload) => {
+  const result = await commands.normalizeCodeBlockLanguages(
+    payload.projectPath,
+    payload.collectionPath,
+    payload.mapping
+  )
+  if (result.status === 'error') {
+    throw new Error(result.error)
+  }
+  return result.data
+}
+
+export const useNormalizeCodeBlocksMutation = () => {
+  const queryClient = useQueryClient()
+  const undo = useUndoBulkOperationMutation()
+
+  return useMutation({
+    mutationFn: normalizeCodeBlocks,
+    onSuccess: (result, variables) => {
+      void queryClient.invalidateQueries({
+        queryKey: queryKeys.codeBlockAudit(
+          variables.projectPath,
+          variables.collectionName
+        ),
+      })
+
+      // Rewritten files may be open or cached
+      void queryClient.invalidateQueries({
+        queryKey: [...queryKeys.all, variables.projectPath, 'files'],
+      })
+
+      if (result.blocksChanged === 0) {
+        toast.success('No code blocks needed changes')
+        return
+      }
+      toast.success(
+        `Updated ${result.blocksChanged} code blocks in ${result.filesChanged} files`,
+        {
+          action: {
+            label: 'Undo',
+            onClick: () =>
+              undo.mutate({ projectPath: variables.projectPath }),
+          },
+        }
+      )
+    },
+    onError: error => {
+      toast.error('Failed to normalize code blocks', {
+        description:
+          error instanceof Error ? error.message : 'Unknown error occurred',
+      })
+    },
+  })
+}
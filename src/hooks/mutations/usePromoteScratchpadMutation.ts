@@ -0,0 +1,61 @@
+// src/hooks/mutations/usePromoteScratchpadMutation.ts
+
+import { useMutation, useQueryClient } from '@tanstack/react-query'
+import { commands, type ScratchpadEntryFields } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+import { toast } from '@/lib/toast'
+
+interface PromoteScratchpadPayload {
+  id: string
+  projectPath: string
+  collectionName: string
+  collectionPath: string
+  filename: string
+  title: string
+  fields: ScratchpadEntryFields
+}
+
+const promoteScratchpad = async (payload: PromoteScratchpadPayload) => {
+  const result = await commands.promoteScratchpadToEntry(
+    payload.id,
+    payload.projectPath,
+    payload.collectionPath,
+    payload.filename,
+    payload.title,
+    payload.fields
+  )
+  if (result.status === 'error') {
+    throw new Error(result.error)
+  }
+  return result.data
+}
+
+export const usePromoteScratchpadMutation = () => {
+  const queryClient = useQueryClient()
+
+  return useMutation({
+    mutationFn: promoteScratchpad,
+    onSuccess: (_, variables) => {
+      void queryClient.invalidateQueries({
+        queryKey: queryKeys.scratchpads(),
+      })
+      // The new entry shows up in its collection's listing
+      void queryClient.invalidateQueries({
+        queryKey: [
+          ...queryKeys.all,
+          variables.projectPath,
+          variables.collectionName,
+        ],
+      })
+      void queryClient.invalidateQueries({
+        queryKey: queryKeys.collections(variables.projectPath),
+      })
+    },
+    onError: error => {
+      toast.error('Failed to promote scratchpad', {
+        description:
+          error instanceof Error ? error.message : 'Unknown error occurred',
+      })
+    },
+  })
+}
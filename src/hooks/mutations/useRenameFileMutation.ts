@@ -2,6 +2,7 @@
 
 import { useMutation, useQueryClient } from '@tanstack/react-query'
 import { commands } from '@/lib/bindings'
+import { getEffectiveContentDirectory } from '@/lib/project-registry'
 import { queryKeys } from '@/lib/query-keys'
 import { toast } from '@/lib/toast'
 import { useProjectStore } from '@/store/projectStore'
@@ -15,10 +16,19 @@ interface RenameFilePayload {
 }
 
 const renameFile = async (payload: RenameFilePayload) => {
-  const result = await commands.renameFile(
+  const { currentProjectSettings } = useProjectStore.getState()
+  // Links to the entry's URL are found through its collection's URL pattern
+  const urlPatterns: Record<string, string> = {}
+  for (const { name, settings } of currentProjectSettings?.collections ?? []) {
+    if (settings.urlPattern) urlPatterns[name] = settings.urlPattern
+  }
+  const result = await commands.renameFileWithLinkRewrite(
     payload.oldPath,
     payload.newPath,
-    payload.projectPath
+    payload.projectPath,
+    getEffectiveContentDirectory(currentProjectSettings),
+    urlPatterns,
+    'rename'
   )
   if (result.status === 'error') {
     throw new Error(result.error)
@@ -31,7 +41,7 @@ export const useRenameFileMutation = () => {
 
   return useMutation({
     mutationFn: renameFile,
-    onSuccess: (_, variables) => {
+    onSuccess: (report, variables) => {
       const { currentSubdirectory } = useProjectStore.getState()
 
       // Invalidate current directory view to show the renamed file
@@ -51,6 +61,19 @@ export const useRenameFileMutation = () => {
         ),
       })
 
+      if (report.links.length > 0) {
+        // Links were rewritten in other files, so reload anything showing them
+        void queryClient.invalidateQueries({
+          queryKey: [...queryKeys.all, variables.projectPath],
+        })
+        const links = report.links.length
+        const files = report.files.length
+        toast.success('File renamed successfully', {
+          description: `Updated ${links} links in ${files} files`,
+        })
+        return
+      }
+
       toast.success('File renamed successfully')
     },
     onError: error => {
@@ -25,10 +25,19 @@ const saveFile = async (payload: SaveFilePayload) => {
     payload.content,
     payload.imports,
     payload.schemaFieldOrder,
-    payload.projectPath
+    payload.projectPath,
+    'save',
+    null,
+    null,
+    null,
+    null
   )
   if (result.status === 'error') {
-    throw new Error(result.error)
+    throw new Error(
+      result.error.kind === 'conflict'
+        ? 'The file was changed on disk since it was opened'
+        : result.error.message
+    )
   }
   return result.data
 }
@@ -61,6 +70,11 @@ export const useSaveFileMutation = () => {
         ],
       })
 
+      // Links in the saved file may have changed other files' backlinks
+      void queryClient.invalidateQueries({
+        queryKey: [...queryKeys.all, variables.projectPath, 'backlinks'],
+      })
+
       toast.success('File saved successfully')
     },
     onError: error => {
@@ -0,0 +1,31 @@
+// src/hooks/mutations/useSetUpdateChannelMutation.ts
+
+import { useMutation, useQueryClient } from '@tanstack/react-query'
+import { commands, type UpdateChannel } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+import { toast } from '@/lib/toast'
+
+const setUpdateChannel = async (channel: UpdateChannel) => {
+  const result = await commands.setUpdateChannel(channel)
+  if (result.status === 'error') {
+    throw new Error(result.error)
+  }
+  return channel
+}
+
+export const useSetUpdateChannelMutation = () => {
+  const queryClient = useQueryClient()
+
+  return useMutation({
+    mutationFn: setUpdateChannel,
+    onSuccess: channel => {
+      queryClient.setQueryData(queryKeys.updateChannel(), channel)
+    },
+    onError: error => {
+      toast.error('Failed to change update channel', {
+        description:
+          error instanceof Error ? error.message : 'Unknown error occurred',
+      })
+    },
+  })
+}
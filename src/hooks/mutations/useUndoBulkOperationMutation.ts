@@ -0,0 +1,47 @@
+// src/hooks/mutations/useUndoBulkOperationMutation.ts
+
+import { useMutation, useQueryClient } from '@tanstack/react-query'
+import { commands } from '@/lib/bindings'
+import { queryKeys } from '@/lib/query-keys'
+import { toast } from '@/lib/toast'
+
+interface UndoBulkOperationPayload {
+  projectPath: string
+}
+
+const undoBulkOperation = async (_payload: UndoBulkOperationPayload) => {
+  const result = await commands.undoLastBulkOperation()
+  if (result.status === 'error') {
+    throw new Error(result.error)
+  }
+  return result.data
+}
+
+export const useUndoBulkOperationMutation = () => {
+  const queryClient = useQueryClient()
+
+  return useMutation({
+    mutationFn: undoBulkOperation,
+    onSuccess: (result, variables) => {
+      // Restored files may be open or cached
+      void queryClient.invalidateQueries({
+        queryKey: [...queryKeys.all, variables.projectPath],
+      })
+
+      const restored = `Restored ${result.restored.length} files`
+      if (result.skipped.length === 0) {
+        toast.success(restored)
+      } else {
+        toast.warning(restored, {
+          description: `Left ${result.skipped.length} files edited since`,
+        })
+      }
+    },
+    onError: error => {
+      toast.error('Failed to undo bulk operation', {
+        description:
+          error instanceof Error ? error.message : 'Unknown error occurred',
+      })
+    },
+  })
+}
@@ -0,0 +1,47 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type ActivityReport } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+import {
+  getEffectiveAssetsDirectory,
+  getEffectiveContentDirectory,
+  getEffectiveFrontmatterMappings,
+  type ProjectSettings,
+} from '@/lib/project-registry'
+
+/**
+ * Activity summary (published, created and updated entries, words written,
+ * new assets) for a date range, e.g. for monthly "what I shipped" posts.
+ *
+ * @param from - First day, `YYYY-MM-DD`
+ * @param to - Last day (inclusive), `YYYY-MM-DD`
+ */
+export function useActivityReportQuery(
+  projectPath: string | null,
+  projectSettings: ProjectSettings | null | undefined,
+  from: string,
+  to: string
+) {
+  return useQuery({
+    queryKey: queryKeys.activityReport(projectPath || '', from, to),
+    queryFn: async (): Promise<ActivityReport> => {
+      const mappings = getEffectiveFrontmatterMappings(projectSettings)
+      const result = await commands.exportActivityReport(
+        projectPath!,
+        getEffectiveContentDirectory(projectSettings),
+        getEffectiveAssetsDirectory(projectSettings),
+        from,
+        to,
+        {
+          title: mappings.title,
+          publishedDate: [mappings.publishedDate].flat(),
+          draft: mappings.draft,
+        }
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath && !!from && !!to,
+  })
+}
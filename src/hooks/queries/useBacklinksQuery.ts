@@ -0,0 +1,41 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type Backlink } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+import {
+  getEffectiveContentDirectory,
+  getEffectiveFrontmatterMappings,
+  type ProjectSettings,
+} from '@/lib/project-registry'
+
+/**
+ * Entries that link to a file through Markdown links, reference fields or
+ * wiki links, for the backlinks panel. Refetched whenever a file is saved.
+ */
+export function useBacklinksQuery(
+  projectPath: string | null,
+  projectSettings: ProjectSettings | null | undefined,
+  filePath: string | null
+) {
+  return useQuery({
+    queryKey: queryKeys.backlinks(projectPath || '', filePath || ''),
+    queryFn: async (): Promise<Backlink[]> => {
+      // Site-absolute links resolve through each collection's URL pattern
+      const urlPatterns: Record<string, string> = {}
+      for (const { name, settings } of projectSettings?.collections ?? []) {
+        if (settings.urlPattern) urlPatterns[name] = settings.urlPattern
+      }
+      const result = await commands.getBacklinks(
+        projectPath!,
+        getEffectiveContentDirectory(projectSettings),
+        filePath!,
+        getEffectiveFrontmatterMappings(projectSettings).title,
+        urlPatterns
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath && !!filePath,
+  })
+}
@@ -0,0 +1,20 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type BackupInfo } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * Backups of the project's content directory, newest first
+ */
+export function useBackupsQuery(projectPath: string | null, enabled = true) {
+  return useQuery({
+    queryKey: queryKeys.backups(projectPath || ''),
+    queryFn: async (): Promise<BackupInfo[]> => {
+      const result = await commands.listBackups(projectPath!)
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: enabled && !!projectPath,
+  })
+}
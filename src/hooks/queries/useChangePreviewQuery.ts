@@ -0,0 +1,24 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type ChangePreview, type MutationRequest } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * The changes a mutating command would make, computed without touching disk.
+ * Pass `null` until there's something to preview (e.g. a confirm dialog opens).
+ */
+export function useChangePreviewQuery(
+  projectPath: string | null,
+  request: MutationRequest | null
+) {
+  return useQuery({
+    queryKey: queryKeys.changePreview(projectPath || '', request ?? {}),
+    queryFn: async (): Promise<ChangePreview> => {
+      const result = await commands.previewChanges(request!)
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath && !!request,
+  })
+}
@@ -0,0 +1,25 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type CodeBlockAudit } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * Fenced code block languages used in a collection, flagging ones Shiki
+ * won't highlight.
+ */
+export function useCodeBlockAuditQuery(
+  projectPath: string | null,
+  collectionName: string | null,
+  collectionPath: string | null
+) {
+  return useQuery({
+    queryKey: queryKeys.codeBlockAudit(projectPath || '', collectionName || ''),
+    queryFn: async (): Promise<CodeBlockAudit> => {
+      const result = await commands.auditCodeBlocks(collectionPath!)
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath && !!collectionName && !!collectionPath,
+  })
+}
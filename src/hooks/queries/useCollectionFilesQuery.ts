@@ -1,33 +1,63 @@
 // src/hooks/queries/useCollectionFilesQuery.ts
 
 import { useQuery } from '@tanstack/react-query'
-import { commands, type FileEntry } from '@/types'
+import type { FileEntry } from '@/types'
 import { queryKeys } from '@/lib/query-keys'
+import { getListingOrder } from '@/lib/files/sorting'
+import { streamCollectionFiles } from '@/lib/listing-stream'
+import { ProjectSettings } from '@/lib/project-registry/types'
 
 const fetchCollectionFiles = async (
-  collectionPath: string
+  collectionPath: string,
+  collectionName: string,
+  projectSettings?: ProjectSettings | null,
+  signal?: AbortSignal
 ): Promise<FileEntry[]> => {
   if (!collectionPath) {
     throw new Error('Collection path is required to fetch files.')
   }
-  const result = await commands.scanCollectionFiles(collectionPath)
-  if (result.status === 'error') {
-    throw new Error(result.error)
-  }
-  return result.data
+  const { collation, titleField, draftField } = getListingOrder(
+    projectSettings,
+    collectionName
+  )
+  // Streamed in batches; switching collections aborts the signal and cancels
+  // the listing part way
+  return streamCollectionFiles(collectionPath, collectionName, {
+    recursive: false,
+    collation,
+    titleField,
+    draftField,
+    signal,
+  })
 }
 
 export const useCollectionFilesQuery = (
   projectPath: string | null,
   collectionName: string | null,
-  collectionPath: string | null
+  collectionPath: string | null,
+  projectSettings?: ProjectSettings | null
 ) => {
+  const { collation, titleField, draftField } = getListingOrder(
+    projectSettings,
+    collectionName || ''
+  )
+
   return useQuery({
-    queryKey: queryKeys.collectionFiles(
-      projectPath || '',
-      collectionName || ''
-    ),
-    queryFn: () => fetchCollectionFiles(collectionPath!),
+    // Files come back in title order with the title and draft fields applied,
+    // so refetch when any of them change
+    queryKey: [
+      ...queryKeys.collectionFiles(projectPath || '', collectionName || ''),
+      collation,
+      titleField,
+      draftField,
+    ],
+    queryFn: ({ signal }) =>
+      fetchCollectionFiles(
+        collectionPath!,
+        collectionName!,
+        projectSettings,
+        signal
+      ),
     enabled: !!projectPath && !!collectionName && !!collectionPath,
   })
 }
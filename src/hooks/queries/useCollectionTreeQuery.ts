@@ -0,0 +1,104 @@
+// src/hooks/queries/useCollectionTreeQuery.ts
+
+import {
+  useQuery,
+  useQueryClient,
+  type QueryClient,
+} from '@tanstack/react-query'
+import {
+  commands,
+  type DirectoryInfo,
+  type DirectoryScanResult,
+  type DirectoryTree,
+} from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+import { getListingOrder } from '@/lib/files/sorting'
+import { ProjectSettings } from '@/lib/project-registry/types'
+
+// Directory levels below the collection root loaded up front
+const DEFAULT_MAX_DEPTH = 4
+
+/**
+ * Seeds the directory scan cache (see useDirectoryScanQuery) from a scanned
+ * tree, so browsing into subdirectories doesn't wait on a scan per level.
+ * Truncated directories are left for useDirectoryScanQuery to load.
+ */
+const seedDirectoryScans = (
+  queryClient: QueryClient,
+  tree: DirectoryTree,
+  keyFor: (subdirectory: string) => readonly unknown[]
+) => {
+  if (tree.truncated) return
+
+  const result: DirectoryScanResult = {
+    subdirectories: tree.subdirectories
+      .map(child => child.directory)
+      .filter((directory): directory is DirectoryInfo => !!directory),
+    files: tree.files,
+  }
+  queryClient.setQueryData(
+    keyFor(tree.directory?.relative_path || 'root'),
+    result
+  )
+  tree.subdirectories.forEach(child =>
+    seedDirectoryScans(queryClient, child, keyFor)
+  )
+}
+
+/**
+ * Loads a collection's directory tree in one call when it's opened and seeds
+ * the per-directory scans with it
+ */
+export const useCollectionTreeQuery = (
+  projectPath: string | null,
+  collectionName: string | null,
+  collectionPath: string | null,
+  projectSettings?: ProjectSettings | null,
+  pattern: string[] | null = null,
+  maxDepth = DEFAULT_MAX_DEPTH
+) => {
+  const queryClient = useQueryClient()
+  const { collation, titleField, draftField } = getListingOrder(
+    projectSettings,
+    collectionName || ''
+  )
+
+  return useQuery({
+    queryKey: [
+      ...queryKeys.collectionTree(projectPath || '', collectionName || ''),
+      maxDepth,
+      collation,
+      titleField,
+      draftField,
+      pattern,
+    ],
+    queryFn: async (): Promise<DirectoryTree> => {
+      const result = await commands.scanCollectionTree(
+        collectionPath!,
+        collectionName!,
+        maxDepth,
+        collation,
+        titleField,
+        draftField,
+        pattern
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      // Same key shape as useDirectoryScanQuery
+      seedDirectoryScans(queryClient, result.data, subdirectory => [
+        ...queryKeys.directoryContents(
+          projectPath!,
+          collectionName!,
+          subdirectory
+        ),
+        collation,
+        titleField,
+        draftField,
+        pattern,
+      ])
+      return result.data
+    },
+    enabled: !!projectPath && !!collectionName && !!collectionPath,
+  })
+}
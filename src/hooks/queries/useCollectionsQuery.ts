@@ -1,7 +1,7 @@
 // src/hooks/queries/useCollectionsQuery.ts
 
 import { useQuery } from '@tanstack/react-query'
-import { commands, type Collection } from '@/types'
+import { commands, type Collection, type FieldGroupSettings } from '@/types'
 import { queryKeys } from '@/lib/query-keys'
 import { getEffectiveContentDirectory } from '@/lib/project-registry'
 import { ASTRO_PATHS } from '@/lib/constants'
@@ -10,7 +10,8 @@ import { ProjectSettings } from '@/lib/project-registry/types'
 // This is our actual data-fetching function using typed Tauri commands.
 const fetchCollections = async (
   projectPath: string,
-  contentDirectory: string
+  contentDirectory: string,
+  fieldGroups: FieldGroupSettings | null
 ): Promise<Collection[]> => {
   if (!projectPath) {
     // TanStack Query handles errors, so we can throw
@@ -18,8 +19,12 @@ const fetchCollections = async (
   }
 
   const result =
-    contentDirectory !== ASTRO_PATHS.CONTENT_DIR
-      ? await commands.scanProjectWithContentDir(projectPath, contentDirectory)
+    contentDirectory !== ASTRO_PATHS.CONTENT_DIR || fieldGroups
+      ? await commands.scanProjectWithContentDir(
+          projectPath,
+          contentDirectory,
+          fieldGroups
+        )
       : await commands.scanProject(projectPath)
 
   if (result.status === 'error') {
@@ -34,6 +39,7 @@ export const useCollectionsQuery = (
 ) => {
   // Get effective content directory using centralized path resolution
   const contentDirectory = getEffectiveContentDirectory(projectSettings)
+  const fieldGroups = projectSettings?.fieldGroups ?? null
 
   return useQuery({
     // The queryKey uniquely identifies this query.
@@ -42,7 +48,8 @@ export const useCollectionsQuery = (
 
     // The queryFn is the function that fetches the data.
     // TanStack Query automatically provides the context, including the queryKey.
-    queryFn: () => fetchCollections(projectPath!, contentDirectory),
+    queryFn: () =>
+      fetchCollections(projectPath!, contentDirectory, fieldGroups),
 
     // We only want to run this query if a projectPath is available.
     enabled: !!projectPath,
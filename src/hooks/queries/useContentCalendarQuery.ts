@@ -0,0 +1,21 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type ContentCalendar } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * Every entry's dates, draft status and title, grouped by month, for a
+ * calendar or planner view.
+ */
+export function useContentCalendarQuery(projectPath: string | null) {
+  return useQuery({
+    queryKey: queryKeys.contentCalendar(projectPath || ''),
+    queryFn: async (): Promise<ContentCalendar> => {
+      const result = await commands.getContentCalendar(projectPath!)
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath,
+  })
+}
@@ -0,0 +1,43 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type ContentGraph, type GraphFormat } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+import {
+  getEffectiveContentDirectory,
+  getEffectiveFrontmatterMappings,
+  type ProjectSettings,
+} from '@/lib/project-registry'
+
+/**
+ * Graph of entries connected by references, body links and series, rendered
+ * as DOT or JSON for visualization. Entries in a series share a `series`
+ * frontmatter value.
+ */
+export function useContentGraphQuery(
+  projectPath: string | null,
+  projectSettings: ProjectSettings | null | undefined,
+  format: GraphFormat
+) {
+  return useQuery({
+    queryKey: queryKeys.contentGraph(projectPath || '', format),
+    queryFn: async (): Promise<ContentGraph> => {
+      // Site-absolute links resolve through each collection's URL pattern
+      const urlPatterns: Record<string, string> = {}
+      for (const { name, settings } of projectSettings?.collections ?? []) {
+        if (settings.urlPattern) urlPatterns[name] = settings.urlPattern
+      }
+      const result = await commands.exportContentGraph(
+        projectPath!,
+        getEffectiveContentDirectory(projectSettings),
+        format,
+        getEffectiveFrontmatterMappings(projectSettings).title,
+        'series',
+        urlPatterns
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath,
+  })
+}
@@ -0,0 +1,38 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type DailyNoteDay } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+import { getEffectiveContentDirectory } from '@/lib/project-registry'
+import { ASTRO_PATHS } from '@/lib/constants'
+import { ProjectSettings } from '@/lib/project-registry/types'
+
+/**
+ * Days in a month that have a daily note, for the journal calendar.
+ *
+ * @param month - Month number, 1-12
+ */
+export function useDailyNoteCalendarQuery(
+  projectPath: string | null,
+  projectSettings: ProjectSettings | null | undefined,
+  year: number,
+  month: number
+) {
+  const contentDirectory = getEffectiveContentDirectory(projectSettings)
+
+  return useQuery({
+    queryKey: queryKeys.dailyNoteCalendar(projectPath || '', year, month),
+    queryFn: async (): Promise<DailyNoteDay[]> => {
+      const result = await commands.getDailyNoteCalendar(
+        projectPath!,
+        contentDirectory !== ASTRO_PATHS.CONTENT_DIR ? contentDirectory : null,
+        projectSettings?.dailyNotePattern ?? null,
+        year,
+        month
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath,
+  })
+}
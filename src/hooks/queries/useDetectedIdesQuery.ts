@@ -0,0 +1,21 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type DetectedIde } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * Known editors installed on the PATH, each with an IDE command template that
+ * opens a file at a line.
+ */
+export function useDetectedIdesQuery() {
+  return useQuery({
+    queryKey: queryKeys.detectedIdes(),
+    queryFn: async (): Promise<DetectedIde[]> => {
+      const result = await commands.detectIdes()
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    staleTime: Infinity, // Only changes when editors are installed
+  })
+}
@@ -3,21 +3,33 @@
 import { useQuery } from '@tanstack/react-query'
 import { commands, type DirectoryScanResult } from '@/types'
 import { queryKeys } from '@/lib/query-keys'
+import { getListingOrder } from '@/lib/files/sorting'
+import { ProjectSettings } from '@/lib/project-registry/types'
 
 const fetchDirectoryContents = async (
   directoryPath: string,
   collectionName: string,
-  collectionRoot: string
+  collectionRoot: string,
+  projectSettings?: ProjectSettings | null,
+  pattern: string[] | null = null
 ): Promise<DirectoryScanResult> => {
   if (!directoryPath || !collectionName || !collectionRoot) {
     throw new Error(
       'Directory path, collection name, and collection root are required.'
     )
   }
+  const { collation, titleField, draftField } = getListingOrder(
+    projectSettings,
+    collectionName
+  )
   const result = await commands.scanDirectory(
     directoryPath,
     collectionName,
-    collectionRoot
+    collectionRoot,
+    collation,
+    titleField,
+    draftField,
+    pattern
   )
   if (result.status === 'error') {
     throw new Error(result.error)
@@ -29,20 +41,41 @@ export const useDirectoryScanQuery = (
   projectPath: string | null,
   collectionName: string | null,
   collectionPath: string | null,
-  subdirectory: string | null // Relative path from collection root
+  subdirectory: string | null, // Relative path from collection root
+  projectSettings?: ProjectSettings | null,
+  pattern: string[] | null = null // The collection's glob() loader patterns
 ) => {
+  const { collation, titleField, draftField } = getListingOrder(
+    projectSettings,
+    collectionName || ''
+  )
+
   return useQuery({
-    queryKey: queryKeys.directoryContents(
-      projectPath || '',
-      collectionName || '',
-      subdirectory || 'root'
-    ),
+    // Files come back in title order with the title and draft fields applied
+    // and filtered by the glob() pattern, so refetch when any of them change
+    queryKey: [
+      ...queryKeys.directoryContents(
+        projectPath || '',
+        collectionName || '',
+        subdirectory || 'root'
+      ),
+      collation,
+      titleField,
+      draftField,
+      pattern,
+    ],
     queryFn: () => {
       const fullPath = subdirectory
         ? `${collectionPath}/${subdirectory}`
         : collectionPath
 
-      return fetchDirectoryContents(fullPath!, collectionName!, collectionPath!)
+      return fetchDirectoryContents(
+        fullPath!,
+        collectionName!,
+        collectionPath!,
+        projectSettings,
+        pattern
+      )
     },
     enabled: !!projectPath && !!collectionName && !!collectionPath,
   })
@@ -0,0 +1,30 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type EmbargoedEntry } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+import {
+  getEffectiveContentDirectory,
+  type ProjectSettings,
+} from '@/lib/project-registry'
+
+/**
+ * Entries still under embargo, soonest to lift first.
+ */
+export function useEmbargoedEntriesQuery(
+  projectPath: string | null,
+  projectSettings: ProjectSettings | null | undefined
+) {
+  return useQuery({
+    queryKey: queryKeys.embargoedEntries(projectPath || ''),
+    queryFn: async (): Promise<EmbargoedEntry[]> => {
+      const result = await commands.listEmbargoedEntries(
+        projectPath!,
+        getEffectiveContentDirectory(projectSettings)
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath,
+  })
+}
@@ -0,0 +1,37 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type FieldDocs } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * Display-ready docs for a frontmatter field, used for hover-help.
+ * Descriptions and constraint text come from the Rust schema merger, including
+ * docs for paths that pass through reference fields (e.g. `author.bio`).
+ */
+export function useFieldDocsQuery(
+  projectPath: string | null,
+  collectionName: string | null,
+  fieldPath: string | null,
+  contentDirectory?: string
+) {
+  return useQuery({
+    queryKey: queryKeys.fieldDocs(
+      projectPath || '',
+      collectionName || '',
+      fieldPath || ''
+    ),
+    queryFn: async (): Promise<FieldDocs> => {
+      const result = await commands.getFieldDocs(
+        projectPath!,
+        contentDirectory ?? null,
+        collectionName!,
+        fieldPath!
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath && !!collectionName && !!fieldPath,
+    staleTime: 5 * 60 * 1000, // Schemas only change on config edits
+  })
+}
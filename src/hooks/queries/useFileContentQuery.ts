@@ -1,12 +1,49 @@
 // src/hooks/queries/useFileContentQuery.ts
 
 import { useQuery } from '@tanstack/react-query'
-import { commands, type MarkdownContent } from '@/types'
+import {
+  commands,
+  type FrontmatterError,
+  type MarkdownContent,
+} from '@/types'
 import { queryKeys } from '@/lib/query-keys'
 
+/**
+ * Thrown when a file exceeds the editor size limit.
+ * Callers can offer frontmatter-only editing via `commands.parseFrontmatterOnly`,
+ * or fetch again with `allowLargeFile`.
+ */
+export class FileTooLargeError extends Error {
+  constructor(
+    public sizeBytes: number,
+    public limitBytes: number
+  ) {
+    super(
+      `File is too large to open in the editor (${(sizeBytes / 1024 / 1024).toFixed(1)} MB)`
+    )
+    this.name = 'FileTooLargeError'
+  }
+}
+
+/**
+ * Thrown when a file's frontmatter can't be parsed.
+ * Carries the located error and raw frontmatter for the fallback view.
+ */
+export class InvalidFrontmatterError extends Error {
+  constructor(
+    public frontmatterError: FrontmatterError,
+    public rawFrontmatter: string
+  ) {
+    super(frontmatterError.message)
+    this.name = 'InvalidFrontmatterError'
+  }
+}
+
 const fetchFileContent = async (
   filePath: string,
-  projectPath: string
+  projectPath: string,
+  maxFileSizeMb: number | null,
+  allowLargeFile: boolean
 ): Promise<MarkdownContent> => {
   if (!filePath) {
     throw new Error('File path is required to fetch content.')
@@ -15,9 +52,26 @@ const fetchFileContent = async (
     throw new Error('Project path is required to fetch content.')
   }
   // Note: filePath must be absolute path for Rust command
-  const result = await commands.parseMarkdownContent(filePath, projectPath)
+  const result = await commands.parseMarkdownContent(
+    filePath,
+    projectPath,
+    maxFileSizeMb,
+    allowLargeFile
+  )
   if (result.status === 'error') {
-    throw new Error(result.error)
+    if (result.error.kind === 'tooLarge') {
+      throw new FileTooLargeError(
+        result.error.sizeBytes,
+        result.error.limitBytes
+      )
+    }
+    if (result.error.kind === 'invalidFrontmatter') {
+      throw new InvalidFrontmatterError(
+        result.error.error,
+        result.error.rawFrontmatter
+      )
+    }
+    throw new Error(result.error.message)
   }
   return result.data
 }
@@ -25,11 +79,19 @@ const fetchFileContent = async (
 export const useFileContentQuery = (
   projectPath: string | null,
   fileId: string | null,
-  filePath: string | null
+  filePath: string | null,
+  maxFileSizeMb: number | null = null,
+  allowLargeFile = false
 ) => {
   return useQuery({
-    queryKey: queryKeys.fileContent(projectPath || '', fileId || ''),
-    queryFn: () => fetchFileContent(filePath!, projectPath!),
+    // The override is part of the key so "Open anyway" fetches again, while
+    // invalidating `fileContent` still covers both entries
+    queryKey: [
+      ...queryKeys.fileContent(projectPath || '', fileId || ''),
+      { allowLargeFile },
+    ],
+    queryFn: () =>
+      fetchFileContent(filePath!, projectPath!, maxFileSizeMb, allowLargeFile),
     enabled: !!projectPath && !!fileId && !!filePath,
     // Stale time: consider data fresh for 30 seconds
     staleTime: 30 * 1000,
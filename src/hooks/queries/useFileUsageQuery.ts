@@ -0,0 +1,46 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type FileUsageEntry } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * Files ordered by when they were last opened or edited, newest first.
+ */
+export function useRecentFilesQuery(
+  projectPath: string | null,
+  limit?: number
+) {
+  return useQuery({
+    queryKey: queryKeys.recentFiles(projectPath || ''),
+    queryFn: async (): Promise<FileUsageEntry[]> => {
+      const result = await commands.getRecentFiles(projectPath!, limit ?? null)
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath,
+  })
+}
+
+/**
+ * Files ordered by how often they're opened and edited, decayed by age.
+ */
+export function useFrequentFilesQuery(
+  projectPath: string | null,
+  limit?: number
+) {
+  return useQuery({
+    queryKey: queryKeys.frequentFiles(projectPath || ''),
+    queryFn: async (): Promise<FileUsageEntry[]> => {
+      const result = await commands.getFrequentFiles(
+        projectPath!,
+        limit ?? null
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath,
+  })
+}
@@ -0,0 +1,52 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type DateStyle } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+import { formatIsoDate, todayIsoDate } from '@/lib/dates'
+import { useProjectStore } from '../../store/projectStore'
+
+/**
+ * A date formatted by the backend in the project's `locale` setting (or the
+ * OS locale), so every view writes dates the same way. Relative dates are
+ * measured from today in the project's timezone.
+ *
+ * Until the formatted text arrives the ISO date (YYYY-MM-DD) is returned.
+ * A `Date` is read in the local timezone; pass frontmatter dates as written.
+ */
+export function useFormattedDateQuery(
+  date: Date | string | null | undefined,
+  style: DateStyle = 'medium'
+) {
+  const locale = useProjectStore(
+    state => state.currentProjectSettings?.locale ?? null
+  )
+  const timezone = useProjectStore(
+    state => state.currentProjectSettings?.timezone
+  )
+
+  const isoDate =
+    date instanceof Date
+      ? isNaN(date.getTime())
+        ? ''
+        : formatIsoDate(date)
+      : (date ?? '')
+  const today = style === 'relative' ? todayIsoDate(timezone) : ''
+
+  return useQuery({
+    queryKey: queryKeys.formattedDate(isoDate, style, locale ?? '', today),
+    queryFn: async (): Promise<string> => {
+      const result = await commands.formatDate(
+        isoDate,
+        style,
+        locale,
+        today || null
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!isoDate,
+    placeholderData: isoDate.slice(0, 10),
+    staleTime: Infinity, // The key covers everything the result depends on
+  })
+}
@@ -0,0 +1,38 @@
+import { keepPreviousData, useQuery } from '@tanstack/react-query'
+import { commands, type FieldViolation, type JsonValue } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * Schema constraint violations in the current frontmatter (required fields,
+ * min/max, lengths, patterns and enum values). Checked in Rust against the
+ * collection's complete schema each time the frontmatter changes.
+ */
+export function useFrontmatterValidationQuery(
+  projectPath: string | null,
+  collectionName: string | null,
+  completeSchema: string | null | undefined,
+  frontmatter: Record<string, unknown>
+) {
+  return useQuery({
+    queryKey: queryKeys.frontmatterValidation(
+      projectPath || '',
+      collectionName || '',
+      frontmatter
+    ),
+    queryFn: async (): Promise<FieldViolation[]> => {
+      const result = await commands.validateFrontmatter(
+        collectionName!,
+        frontmatter as Partial<Record<string, JsonValue>>,
+        completeSchema!
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath && !!collectionName && !!completeSchema,
+    staleTime: 0,
+    // Keep the previous violations on screen while the next check runs
+    placeholderData: keepPreviousData,
+  })
+}
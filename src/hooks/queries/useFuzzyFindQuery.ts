@@ -0,0 +1,35 @@
+import { keepPreviousData, useQuery } from '@tanstack/react-query'
+import { commands, type FuzzyMatch } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * Quick-switcher matches across entry titles, filenames and headings.
+ * The Rust side keeps the index current via the file watcher, so results are
+ * always fetched fresh rather than cached.
+ */
+export function useFuzzyFindQuery(
+  projectPath: string | null,
+  query: string,
+  contentDirectory?: string,
+  limit?: number
+) {
+  return useQuery({
+    queryKey: queryKeys.fuzzyFind(projectPath || '', query),
+    queryFn: async (): Promise<FuzzyMatch[]> => {
+      const result = await commands.fuzzyFind(
+        projectPath!,
+        contentDirectory ?? null,
+        query,
+        limit ?? null
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath && query.trim().length > 0,
+    staleTime: 0,
+    // Keep showing the previous results while the next query runs
+    placeholderData: keepPreviousData,
+  })
+}
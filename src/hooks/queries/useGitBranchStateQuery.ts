@@ -0,0 +1,37 @@
+import { useQuery } from '@tanstack/react-query'
+import {
+  commands,
+  type ContentBranchSettings,
+  type GitBranchState,
+} from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * The project's git branch, worktree and content branch state. Branches are
+ * usually switched outside the editor, and the file watcher ignores `.git`,
+ * so this refetches when the window regains focus.
+ */
+export function useGitBranchStateQuery(
+  projectPath: string | null,
+  settings: ContentBranchSettings | undefined
+) {
+  return useQuery({
+    queryKey: [
+      ...queryKeys.gitBranchState(projectPath || ''),
+      settings?.branch ?? null,
+      settings?.require ?? false,
+    ],
+    queryFn: async (): Promise<GitBranchState> => {
+      const result = await commands.getGitBranchState(
+        projectPath!,
+        settings ?? null
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath,
+    refetchOnWindowFocus: true,
+  })
+}
@@ -0,0 +1,45 @@
+import { keepPreviousData, useQuery } from '@tanstack/react-query'
+import { commands, type ReferenceLabel } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * Display labels for reference ids, resolved in Rust from the referenced
+ * collection (glob or `file()` loader). Ids that match no entry come back with
+ * `exists: false`, so broken references can be marked.
+ *
+ * `collectionPath` is null for `file()` loader collections; pass
+ * `enabled: false` until it's known which kind the collection is.
+ */
+export function useReferenceLabelsQuery(
+  projectPath: string | null,
+  collectionName: string | null | undefined,
+  collectionPath: string | null,
+  ids: string[],
+  titleField: string,
+  enabled = true
+) {
+  return useQuery({
+    queryKey: queryKeys.referenceLabels(
+      projectPath || '',
+      collectionName || '',
+      ids,
+      titleField
+    ),
+    queryFn: async (): Promise<ReferenceLabel[]> => {
+      const result = await commands.resolveReferenceLabels(
+        projectPath!,
+        collectionName!,
+        collectionPath,
+        ids,
+        titleField
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: enabled && !!projectPath && !!collectionName && ids.length > 0,
+    // Keep chips labelled while a changed selection resolves
+    placeholderData: keepPreviousData,
+  })
+}
@@ -0,0 +1,30 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type FileEntry } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * Query hook for remote collections (custom loaders fetching from a CMS or API)
+ * Entries come from Astro's data store and are read-only
+ */
+export function useRemoteCollectionQuery(
+  projectPath: string | null,
+  collectionName: string | null
+) {
+  return useQuery({
+    queryKey: queryKeys.remoteCollection(
+      projectPath || '',
+      collectionName || ''
+    ),
+    queryFn: async (): Promise<FileEntry[]> => {
+      const result = await commands.loadRemoteCollection(
+        projectPath!,
+        collectionName!
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath && !!collectionName,
+  })
+}
@@ -0,0 +1,33 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type FilterMatch, type SavedFilter } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * Entries matching a saved filter. Evaluated in Rust against the search index,
+ * which the file watcher keeps current, so results are always fetched fresh.
+ */
+export function useSavedFilterQuery(
+  projectPath: string | null,
+  filter: SavedFilter | null,
+  contentDirectory?: string,
+  limit?: number
+) {
+  return useQuery({
+    queryKey: queryKeys.savedFilter(projectPath || '', filter ?? {}),
+    queryFn: async (): Promise<FilterMatch[]> => {
+      const result = await commands.queryCollection(
+        projectPath!,
+        contentDirectory ?? null,
+        filter!.collection ?? null,
+        filter!.condition,
+        limit ?? null
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath && !!filter,
+    staleTime: 0,
+  })
+}
@@ -0,0 +1,21 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type Scratchpad } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * Scratchpads in app data, most recently saved first. They aren't part of
+ * any project, so the list is shared across projects.
+ */
+export function useScratchpadsQuery(enabled = true) {
+  return useQuery({
+    queryKey: queryKeys.scratchpads(),
+    queryFn: async (): Promise<Scratchpad[]> => {
+      const result = await commands.listScratchpads()
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled,
+  })
+}
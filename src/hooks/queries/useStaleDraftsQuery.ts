@@ -0,0 +1,36 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type StaleDraft } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+import {
+  getEffectiveContentDirectory,
+  getEffectiveFrontmatterMappings,
+  type ProjectSettings,
+} from '@/lib/project-registry'
+
+/**
+ * Drafts not modified in the last `days` days, oldest first, with word
+ * counts and last-edit dates.
+ */
+export function useStaleDraftsQuery(
+  projectPath: string | null,
+  projectSettings: ProjectSettings | null | undefined,
+  days: number
+) {
+  return useQuery({
+    queryKey: queryKeys.staleDrafts(projectPath || '', days),
+    queryFn: async (): Promise<StaleDraft[]> => {
+      const { title, draft } = getEffectiveFrontmatterMappings(projectSettings)
+      const result = await commands.getStaleDrafts(
+        projectPath!,
+        getEffectiveContentDirectory(projectSettings),
+        days,
+        { title, draft }
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath,
+  })
+}
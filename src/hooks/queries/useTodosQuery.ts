@@ -0,0 +1,27 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type TodoItem } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * TODO/FIXME/HACK notes across every collection in the project, for the
+ * project-wide task list.
+ */
+export function useTodosQuery(
+  projectPath: string | null,
+  contentDirectory?: string
+) {
+  return useQuery({
+    queryKey: queryKeys.todos(projectPath || ''),
+    queryFn: async (): Promise<TodoItem[]> => {
+      const result = await commands.collectTodos(
+        projectPath!,
+        contentDirectory ?? null
+      )
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: !!projectPath,
+  })
+}
@@ -0,0 +1,24 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type TrashedFile } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * Files deleted from the project that can still be restored, most recently
+ * deleted first
+ */
+export function useTrashedFilesQuery(
+  projectPath: string | null,
+  enabled = true
+) {
+  return useQuery({
+    queryKey: queryKeys.trashedFiles(projectPath || ''),
+    queryFn: async (): Promise<TrashedFile[]> => {
+      const result = await commands.listTrashedFiles(projectPath!)
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    enabled: enabled && !!projectPath,
+  })
+}
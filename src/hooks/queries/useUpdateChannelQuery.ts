@@ -0,0 +1,21 @@
+import { useQuery } from '@tanstack/react-query'
+import { commands, type UpdateChannel } from '@/types'
+import { queryKeys } from '@/lib/query-keys'
+
+/**
+ * The release channel (stable or beta) the app checks for updates on. It's
+ * stored by the backend updater rather than in global settings.
+ */
+export function useUpdateChannelQuery() {
+  return useQuery({
+    queryKey: queryKeys.updateChannel(),
+    queryFn: async (): Promise<UpdateChannel> => {
+      const result = await commands.getUpdateChannel()
+      if (result.status === 'error') {
+        throw new Error(result.error)
+      }
+      return result.data
+    },
+    staleTime: Infinity, // Only changes through useSetUpdateChannelMutation
+  })
+}
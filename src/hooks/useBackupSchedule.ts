@@ -0,0 +1,33 @@
+import { useEffect } from 'react'
+import { commands } from '@/types'
+import { getEffectiveContentDirectory } from '../lib/project-registry'
+import { useProjectStore } from '../store/projectStore'
+
+/** Minutes between automatic backups unless the project says otherwise */
+export const DEFAULT_BACKUP_INTERVAL_MINUTES = 60
+
+/**
+ * Keeps the backend's content backups pointed at the open project. Bulk
+ * operations back up first whenever a project is open; scheduled backups run
+ * at the project's interval, or not at all when it's 0.
+ */
+export function useBackupSchedule() {
+  const projectPath = useProjectStore(state => state.projectPath)
+  const settings = useProjectStore(state => state.currentProjectSettings)
+
+  const interval =
+    settings?.backupIntervalMinutes ?? DEFAULT_BACKUP_INTERVAL_MINUTES
+  const contentDirectory = getEffectiveContentDirectory(settings)
+
+  useEffect(() => {
+    void commands.setBackupSettings(
+      projectPath
+        ? {
+            projectPath,
+            contentDirectory,
+            intervalMinutes: interval > 0 ? interval : null,
+          }
+        : null
+    )
+  }, [projectPath, contentDirectory, interval])
+}
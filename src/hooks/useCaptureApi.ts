@@ -0,0 +1,72 @@
+import { useEffect } from 'react'
+import { commands } from '@/types'
+import { ASTRO_PATHS } from '../lib/constants'
+import {
+  getEffectiveAssetsDirectory,
+  getEffectiveContentDirectory,
+} from '../lib/project-registry'
+import { toast } from '../lib/toast'
+import { useProjectStore } from '../store/projectStore'
+
+export const DEFAULT_CAPTURE_API_PORT = 4870
+
+/**
+ * Runs the capture API for the open project while it's enabled in
+ * preferences, so a phone can send notes and photos into today's note. The
+ * token generated on first start is saved to preferences.
+ */
+export function useCaptureApi() {
+  const projectPath = useProjectStore(state => state.projectPath)
+  const settings = useProjectStore(state => state.currentProjectSettings)
+  const captureApi = useProjectStore(
+    state => state.globalSettings?.general?.captureApi
+  )
+  const contentDirectory = getEffectiveContentDirectory(settings)
+  const assetsDirectory = getEffectiveAssetsDirectory(settings)
+  const enabled = captureApi?.enabled ?? false
+  const port = captureApi?.port ?? DEFAULT_CAPTURE_API_PORT
+  const allowLan = captureApi?.allowLan ?? false
+  const token = captureApi?.token
+
+  useEffect(() => {
+    if (!enabled || !projectPath) {
+      void commands.stopCaptureServer()
+      return
+    }
+
+    void commands
+      .startCaptureServer({
+        projectPath,
+        contentDirectory:
+          contentDirectory !== ASTRO_PATHS.CONTENT_DIR
+            ? contentDirectory
+            : null,
+        assetsDirectory:
+          assetsDirectory !== ASTRO_PATHS.ASSETS_DIR ? assetsDirectory : null,
+        port,
+        token: token || null,
+        allowLan,
+      })
+      .then(result => {
+        if (result.status === 'error') {
+          toast.error('Failed to start the capture API', {
+            description: result.error,
+          })
+          return
+        }
+        if (result.data.token !== token) {
+          void useProjectStore.getState().updateGlobalSettings({
+            general: { captureApi: { token: result.data.token } },
+          })
+        }
+      })
+  }, [
+    enabled,
+    projectPath,
+    contentDirectory,
+    assetsDirectory,
+    port,
+    allowLan,
+    token,
+  ])
+}
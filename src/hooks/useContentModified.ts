@@ -0,0 +1,46 @@
+import { useEffect, useState } from 'react'
+import { listen } from '@tauri-apps/api/event'
+
+/**
+ * Payload of the `content-modified` event emitted by the Rust content tracker.
+ */
+export interface ContentModifiedEvent {
+  filePath: string
+  wordsAdded: number
+  wordsRemoved: number
+  /** When the file was last written, in milliseconds since the Unix epoch */
+  lastSavedAt: number
+  secondsSinceSave: number
+}
+
+/**
+ * Latest unsaved-change summary for a file, for status displays such as
+ * "last saved 12s ago · +84 words".
+ *
+ * Word counts are computed in Rust from coalesced editor reports, so this
+ * doesn't diff the document on every keystroke.
+ */
+export function useContentModified(filePath: string | null) {
+  const [modified, setModified] = useState<ContentModifiedEvent | null>(null)
+
+  useEffect(() => {
+    setModified(null)
+    if (!filePath) return
+
+    let cleanup: (() => void) | undefined
+
+    void listen<ContentModifiedEvent>('content-modified', event => {
+      if (event.payload.filePath === filePath) {
+        setModified(event.payload)
+      }
+    }).then(unlisten => {
+      cleanup = unlisten
+    })
+
+    return () => {
+      cleanup?.()
+    }
+  }, [filePath])
+
+  return modified
+}
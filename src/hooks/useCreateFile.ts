@@ -97,6 +97,10 @@ export const useCreateFile = () => {
         toast.error('Collection not found')
         return
       }
+      if (collection.remote) {
+        toast.error('Remote collections are read-only')
+        return
+      }
 
       // Calculate target directory (collection root or subdirectory)
       const targetDirectory = currentSubdirectory
@@ -121,7 +125,11 @@ export const useCreateFile = () => {
       const existingResult = await commands.scanDirectory(
         targetDirectory,
         selectedCollection,
-        collection.path
+        collection.path,
+        null,
+        null,
+        null,
+        null
       )
       if (existingResult.status === 'error') {
         throw new Error(existingResult.error)
@@ -213,7 +221,11 @@ export const useCreateFile = () => {
       const updatedResult = await commands.scanDirectory(
         targetDirectory,
         selectedCollection,
-        collection.path
+        collection.path,
+        null,
+        null,
+        null,
+        null
       )
       if (updatedResult.status === 'error') {
         throw new Error(updatedResult.error)
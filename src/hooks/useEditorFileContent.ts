@@ -1,8 +1,13 @@
-import { useEffect } from 'react'
+import { useEffect, useRef } from 'react'
 import { useShallow } from 'zustand/react/shallow'
+import { commands } from '@/types'
+import { toast } from '../lib/toast'
 import { useEditorStore } from '../store/editorStore'
 import { useProjectStore } from '../store/projectStore'
-import { useFileContentQuery } from './queries/useFileContentQuery'
+import {
+  FileTooLargeError,
+  useFileContentQuery,
+} from './queries/useFileContentQuery'
 
 /**
  * Hook that bridges TanStack Query (server state) with Zustand (local editing state)
@@ -11,20 +16,31 @@ import { useFileContentQuery } from './queries/useFileContentQuery'
  * 1. Fetch file content when currentFile changes
  * 2. Sync query data to store ONLY when appropriate
  * 3. Respect isDirty state (don't overwrite user's edits)
+ * 4. Offer to restore edits left unsaved in a previous session
+ * 5. Load only the frontmatter of files too large for the editor
  */
 export function useEditorFileContent() {
   // Object subscription needs shallow
   const currentFile = useEditorStore(useShallow(state => state.currentFile))
   const projectPath = useProjectStore(state => state.projectPath)
+  const maxFileSizeMb = useProjectStore(
+    state => state.currentProjectSettings?.maxEditorFileSizeMb ?? null
+  )
+  const allowLargeFile = useEditorStore(state => state.allowLargeFile)
 
   // Query fetches content based on current file
   // Pass both id (for cache key) and path (for Rust command)
   const { data, isLoading, isError, error } = useFileContentQuery(
     projectPath,
     currentFile?.id || null,
-    currentFile?.path || null
+    currentFile?.path || null,
+    maxFileSizeMb,
+    allowLargeFile
   )
 
+  // The file whose unsaved draft has been looked for
+  const draftCheckedFor = useRef<string | null>(null)
+
   // Sync query data to local editing state when it arrives
   useEffect(() => {
     if (!data || !currentFile) return
@@ -41,9 +57,69 @@ export function useEditorFileContent() {
       frontmatter: data.frontmatter,
       rawFrontmatter: data.raw_frontmatter,
       imports: data.imports,
+      contentHash: data.content_hash,
+      frontmatterOnly: false,
       isFrontmatterDirty: false, // Reset when loading from disk
     })
-  }, [data, currentFile])
+
+    // Only look for a draft once per opened file, not after every save
+    if (!projectPath || draftCheckedFor.current === currentFile.path) return
+    draftCheckedFor.current = currentFile.path
+    const filePath = currentFile.path
+    void commands.getDraft(filePath, projectPath).then(result => {
+      if (result.status === 'error' || !result.data) return
+      const draft = result.data
+      toast.warning('This file has unsaved changes from a previous session', {
+        description:
+          draft.baseHash && draft.baseHash !== data.content_hash
+            ? 'The file has changed on disk since they were made.'
+            : undefined,
+        duration: 10000,
+        action: {
+          label: 'Restore',
+          onClick: () => {
+            const { currentFile: openFile, scheduleAutoSave } =
+              useEditorStore.getState()
+            if (openFile?.path !== filePath) return
+            useEditorStore.setState({
+              editorContent: draft.content,
+              ...(draft.frontmatter && {
+                frontmatter: draft.frontmatter,
+                isFrontmatterDirty: true,
+              }),
+              isDirty: true,
+            })
+            scheduleAutoSave()
+          },
+        },
+      })
+    })
+  }, [data, currentFile, projectPath])
+
+  // Too large for the editor: load just the frontmatter for the sidebar form
+  useEffect(() => {
+    if (!(error instanceof FileTooLargeError) || !currentFile || !projectPath) {
+      return
+    }
+    const filePath = currentFile.path
+    void commands.parseFrontmatterOnly(filePath, projectPath).then(result => {
+      const { currentFile: openFile, isDirty } = useEditorStore.getState()
+      if (openFile?.path !== filePath || isDirty) return
+      if (result.status === 'error') {
+        toast.error('Failed to read frontmatter', { description: result.error })
+        return
+      }
+      useEditorStore.setState({
+        editorContent: '',
+        frontmatter: result.data.frontmatter,
+        rawFrontmatter: result.data.rawFrontmatter,
+        imports: '',
+        contentHash: null,
+        frontmatterOnly: true,
+        isFrontmatterDirty: false,
+      })
+    })
+  }, [error, currentFile, projectPath])
 
   return { isLoading, isError, error }
 }
@@ -1,54 +1,65 @@
 import { useEffect } from 'react'
-import { commands } from '@/lib/bindings'
+import { updateMenuContext } from '../lib/editor/commands'
 import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
 
 /**
- * Tracks editor focus state and manages format menu enabled/disabled state.
+ * Tracks editor context and keeps native menu items enabled/disabled.
  *
- * The format menu (Bold, Italic, etc.) should only be enabled when:
- * 1. A file is currently open
- * 2. The editor has focus
+ * - Format items (Bold, Italic, etc.) need an open file and editor focus
+ * - Add Link additionally needs a non-empty selection
+ * - Save and Print need an open file
+ * - New File needs a selected collection
  *
- * This hook listens to the 'editor-focus-changed' event and currentFile changes,
- * updating the native menu state accordingly.
+ * This hook listens to the 'editor-focus-changed' and
+ * 'editor-selection-changed' events plus currentFile and selectedCollection
+ * changes, reporting the combined context to the backend.
  */
 export function useEditorFocusTracking() {
-  // Initialize focus state and listen for changes
   useEffect(() => {
     window.isEditorFocused = false
-    void commands.updateFormatMenuState(false)
+    window.editorHasSelection = false
 
-    const updateMenuState = () => {
+    const reportContext = () => {
       const { currentFile } = useEditorStore.getState()
-      const shouldEnableMenu = Boolean(currentFile && window.isEditorFocused)
-      void commands.updateFormatMenuState(shouldEnableMenu)
+      const { selectedCollection } = useProjectStore.getState()
+      void updateMenuContext({
+        hasFileOpen: Boolean(currentFile),
+        editorFocused: window.isEditorFocused,
+        hasSelection: window.editorHasSelection,
+        hasCollection: Boolean(selectedCollection),
+      })
     }
 
-    // Listen to focus changes
-    const handleEditorFocusChange = () => {
-      updateMenuState()
-    }
+    reportContext()
 
-    window.addEventListener('editor-focus-changed', handleEditorFocusChange)
+    window.addEventListener('editor-focus-changed', reportContext)
+    window.addEventListener('editor-selection-changed', reportContext)
 
     // Subscribe to currentFile changes in the store
     let previousFile = useEditorStore.getState().currentFile
-
-    const unsubscribe = useEditorStore.subscribe(state => {
+    const unsubscribeEditor = useEditorStore.subscribe(state => {
       const newFile = state.currentFile
       // Only update if currentFile actually changed
       if (newFile !== previousFile) {
         previousFile = newFile
-        updateMenuState()
+        reportContext()
+      }
+    })
+
+    let previousCollection = useProjectStore.getState().selectedCollection
+    const unsubscribeProject = useProjectStore.subscribe(state => {
+      if (state.selectedCollection !== previousCollection) {
+        previousCollection = state.selectedCollection
+        reportContext()
       }
     })
 
     return () => {
-      window.removeEventListener(
-        'editor-focus-changed',
-        handleEditorFocusChange
-      )
-      unsubscribe()
+      window.removeEventListener('editor-focus-changed', reportContext)
+      window.removeEventListener('editor-selection-changed', reportContext)
+      unsubscribeEditor()
+      unsubscribeProject()
     }
   }, [])
 }
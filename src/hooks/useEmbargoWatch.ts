@@ -0,0 +1,42 @@
+import { useEffect } from 'react'
+import { listen } from '@tauri-apps/api/event'
+import { commands, type EmbargoedEntry } from '@/types'
+import { getEffectiveContentDirectory } from '../lib/project-registry'
+import { queryClient } from '../lib/query-client'
+import { queryKeys } from '../lib/query-keys'
+import { toast } from '../lib/toast'
+import { useProjectStore } from '../store/projectStore'
+
+/**
+ * Registers the open project with the backend's embargo watch and announces
+ * entries whose embargo has just lifted.
+ */
+export function useEmbargoWatch() {
+  const projectPath = useProjectStore(state => state.projectPath)
+  const settings = useProjectStore(state => state.currentProjectSettings)
+  const contentDirectory = getEffectiveContentDirectory(settings)
+
+  useEffect(() => {
+    void commands.setEmbargoWatch(
+      projectPath ? { projectPath, contentDirectory } : null
+    )
+  }, [projectPath, contentDirectory])
+
+  useEffect(() => {
+    const unlisten = listen<EmbargoedEntry[]>('embargo-lifted', event => {
+      const names = event.payload.map(
+        entry => entry.filePath.split(/[\\/]/).pop() ?? entry.filePath
+      )
+      toast.success('Embargo lifted', {
+        description: `${names.join(', ')} can now be published`,
+      })
+      void queryClient.invalidateQueries({
+        queryKey: queryKeys.embargoedEntries(projectPath || ''),
+      })
+    })
+
+    return () => {
+      void unlisten.then(fn => fn())
+    }
+  }, [projectPath])
+}
@@ -4,11 +4,7 @@ import { useProjectStore } from '../store/projectStore'
 import { queryClient } from '../lib/query-client'
 import { queryKeys } from '../lib/query-keys'
 import { debug, info } from '@tauri-apps/plugin-log'
-
-interface FileChangeEvent {
-  path: string
-  kind: string
-}
+import type { FileChangeEvent } from '@/types'
 
 /**
  * Handles file-changed events from the Rust watcher
@@ -18,19 +14,30 @@ interface FileChangeEvent {
  * 2. Invalidate queries to trigger refetch
  * 3. Respect isDirty state (don't reload user's unsaved work)
  * 4. Ignore events that occur immediately after our own save (race condition protection)
+ * 5. Follow the open file when it's renamed outside the app
  */
 export function useFileChangeHandler() {
   useEffect(() => {
     const handleFileChanged = (event: Event) => {
       const customEvent = event as CustomEvent<FileChangeEvent>
-      const { path } = customEvent.detail
+      const { path, kind, oldPath } = customEvent.detail
 
-      const { currentFile, isDirty, lastSaveTimestamp } =
-        useEditorStore.getState()
+      const {
+        currentFile,
+        isDirty,
+        lastSaveTimestamp,
+        updateCurrentFileAfterRename,
+      } = useEditorStore.getState()
       const { projectPath } = useProjectStore.getState()
 
-      // Only care about currently open file
-      if (!currentFile || currentFile.path !== path) {
+      if (kind === 'renamed' && currentFile && currentFile.path === oldPath) {
+        void info(`Open file renamed to ${path} outside the editor`)
+        updateCurrentFileAfterRename(path)
+        return
+      }
+
+      // Only care about changes to the currently open file's contents
+      if (!currentFile || currentFile.path !== path || kind === 'deleted') {
         return
       }
 
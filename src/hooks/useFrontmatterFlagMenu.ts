@@ -0,0 +1,84 @@
+import { useEffect, useMemo } from 'react'
+import { listen } from '@tauri-apps/api/event'
+import { useShallow } from 'zustand/react/shallow'
+import { commands, type FrontmatterFlagToggle } from '@/types'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import { useCollectionsQuery } from './queries/useCollectionsQuery'
+import { deserializeCompleteSchema } from '../lib/schema'
+import {
+  toFlagMenuFields,
+  toggleFrontmatterFlag,
+} from '../lib/frontmatter-flags'
+import { queryClient } from '../lib/query-client'
+import { queryKeys } from '../lib/query-keys'
+
+/**
+ * Keeps the Edit > Frontmatter menu in sync with the current collection's
+ * boolean/enum fields, and handles its toggle shortcuts (e.g. Cmd+Shift+D for
+ * `draft`).
+ */
+export function useFrontmatterFlagMenu() {
+  const currentFile = useEditorStore(useShallow(state => state.currentFile))
+  const projectPath = useProjectStore(state => state.projectPath)
+  const currentProjectSettings = useProjectStore(
+    useShallow(state => state.currentProjectSettings)
+  )
+  const { data: collections = [] } = useCollectionsQuery(
+    projectPath,
+    currentProjectSettings
+  )
+
+  const collectionName = currentFile?.collection ?? null
+  const completeSchema = collections.find(c => c.name === collectionName)
+    ?.complete_schema
+
+  const menuFields = useMemo(() => {
+    if (!completeSchema) return []
+    return toFlagMenuFields(
+      deserializeCompleteSchema(completeSchema)?.fields ?? []
+    )
+  }, [completeSchema])
+
+  useEffect(() => {
+    void commands.updateFrontmatterFlagMenu(menuFields)
+  }, [menuFields])
+
+  useEffect(() => {
+    const unlistenToggle = listen<string>(
+      'menu-toggle-frontmatter-flag',
+      event => {
+        void toggleFrontmatterFlag(event.payload)
+      }
+    )
+
+    // Reload the file and refresh file lists (e.g. draft badges)
+    const unlistenToggled = listen<FrontmatterFlagToggle>(
+      'frontmatter-flag-toggled',
+      event => {
+        const { currentFile } = useEditorStore.getState()
+        const { projectPath } = useProjectStore.getState()
+        if (!projectPath) return
+
+        if (currentFile?.path === event.payload.filePath) {
+          void queryClient.invalidateQueries({
+            queryKey: queryKeys.fileContent(projectPath, currentFile.id),
+          })
+        }
+        void queryClient.invalidateQueries({
+          queryKey: queryKeys.collections(projectPath),
+        })
+        if (currentFile) {
+          void queryClient.invalidateQueries({
+            queryKey: [...queryKeys.all, projectPath, currentFile.collection],
+          })
+        }
+      }
+    )
+
+    return () => {
+      void unlistenToggle.then(unlisten => unlisten())
+      void unlistenToggled.then(unlisten => unlisten())
+    }
+  }, [])
+}
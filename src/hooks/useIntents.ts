@@ -0,0 +1,130 @@
+import { useEffect, useRef } from 'react'
+import { listen } from '@tauri-apps/api/event'
+import { ask } from '@tauri-apps/plugin-dialog'
+import { error as logError } from '@tauri-apps/plugin-log'
+import { commands, type Collection, type IntentRequest } from '@/types'
+import { useProjectStore } from '../store/projectStore'
+import { queryClient } from '../lib/query-client'
+import { queryKeys } from '../lib/query-keys'
+
+const currentProjectPath = (): string => {
+  const { projectPath } = useProjectStore.getState()
+  if (!projectPath) {
+    throw new Error('No project is open')
+  }
+  return projectPath
+}
+
+const findCollection = (name: string): Collection => {
+  const projectPath = currentProjectPath()
+  const collections = queryClient.getQueryData<Collection[]>(
+    queryKeys.collections(projectPath)
+  )
+  const collection = collections?.find(c => c.name === name)
+  if (!collection) {
+    throw new Error(`Collection not found: ${name}`)
+  }
+  return collection
+}
+
+// Any app or web page can send an intent URL, so nothing is written and no
+// project is opened until the user agrees
+const confirmIntent = async (message: string): Promise<void> => {
+  const confirmed = await ask(message, {
+    title: 'Shortcut Request',
+    kind: 'warning',
+  })
+  if (!confirmed) {
+    throw new Error('Cancelled in Astro Editor')
+  }
+}
+
+const preview = (text: string, length = 200): string =>
+  text.length > length ? `${text.slice(0, length)}…` : text
+
+/**
+ * Handles automation intents (Apple Shortcuts, AppleScript `open location`, any
+ * x-callback-url client).
+ *
+ * Rust parses `astro-editor://new-file`, `append-daily-note` and `open-project`
+ * URLs, queues them and emits `intent-received`. This hook drains the queue,
+ * asks the user to confirm each action, performs it, and reports back via
+ * `complete_intent` so Rust can follow the intent's `x-success` / `x-error`
+ * callback.
+ */
+export function useIntents(createNewFile: () => Promise<void>) {
+  // Capture the latest callback in a ref so the listener is set up only once
+  const createNewFileRef = useRef(createNewFile)
+
+  useEffect(() => {
+    createNewFileRef.current = createNewFile
+  }, [createNewFile])
+
+  useEffect(() => {
+    let unlisten: (() => void) | undefined
+
+    const runIntent = async ({ action }: IntentRequest): Promise<string> => {
+      switch (action.type) {
+        case 'openProject':
+          await confirmIntent(`Open the project at ${action.path}?`)
+          useProjectStore.getState().setProject(action.path)
+          return action.path
+        case 'newFile':
+          findCollection(action.collection)
+          await confirmIntent(
+            `Create a new file in the ${action.collection} collection?`
+          )
+          useProjectStore.getState().setSelectedCollection(action.collection)
+          await createNewFileRef.current()
+          return action.collection
+        case 'appendToDailyNote': {
+          const collection = findCollection(action.collection)
+          await confirmIntent(
+            `Add this to today's note in the ${action.collection} collection?\n\n${preview(action.text)}`
+          )
+          const result = await commands.appendToDailyNote(
+            currentProjectPath(),
+            collection.path,
+            action.text
+          )
+          if (result.status === 'error') {
+            throw new Error(result.error)
+          }
+          return result.data
+        }
+      }
+    }
+
+    const drainIntents = async () => {
+      const pending = await commands.takePendingIntents()
+      if (pending.status === 'error') {
+        await logError(`Failed to read pending intents: ${pending.error}`)
+        return
+      }
+
+      for (const intent of pending.data) {
+        try {
+          const result = await runIntent(intent)
+          await commands.completeIntent(intent.id, result, null)
+        } catch (error) {
+          const message = error instanceof Error ? error.message : String(error)
+          await commands.completeIntent(intent.id, null, message)
+        }
+      }
+    }
+
+    const setup = async () => {
+      unlisten = await listen('intent-received', () => {
+        void drainIntents()
+      })
+      // Pick up intents that arrived before the listener (e.g. launch URLs)
+      await drainIntents()
+    }
+
+    void setup()
+
+    return () => {
+      unlisten?.()
+    }
+  }, [])
+}
@@ -7,6 +7,7 @@ import { useUIStore } from '../store/uiStore'
 import { focusEditor } from '../lib/focus-utils'
 import { openProjectViaDialog } from '../lib/projects/actions'
 import { toast } from '../lib/toast'
+import { resetZoom, zoomIn, zoomOut } from '../lib/zoom'
 import { useEditorActions } from './editor/useEditorActions'
 import { usePlatform } from './usePlatform'
 
@@ -104,9 +105,9 @@ export function useKeyboardShortcuts(
     DEFAULT_HOTKEY_OPTS
   )
 
-  // Cmd+0: Focus main editor
+  // Cmd+Shift+0: Focus main editor (Cmd+0 is Actual Size)
   useHotkeys(
-    'mod+0',
+    'mod+shift+0',
     () => {
       focusEditor()
     },
@@ -138,4 +139,29 @@ export function useKeyboardShortcuts(
     },
     { ...DEFAULT_HOTKEY_OPTS, enabled: platform === 'windows' }
   )
+
+  // Ctrl+=, Ctrl+-, Ctrl+0: Zoom (Windows only - macOS uses the View menu)
+  useHotkeys(
+    'mod+equal',
+    () => {
+      void zoomIn()
+    },
+    { ...DEFAULT_HOTKEY_OPTS, enabled: platform === 'windows' }
+  )
+
+  useHotkeys(
+    'mod+minus',
+    () => {
+      void zoomOut()
+    },
+    { ...DEFAULT_HOTKEY_OPTS, enabled: platform === 'windows' }
+  )
+
+  useHotkeys(
+    'mod+0',
+    () => {
+      void resetZoom()
+    },
+    { ...DEFAULT_HOTKEY_OPTS, enabled: platform === 'windows' }
+  )
 }
@@ -6,6 +6,10 @@ import { useProjectStore } from '../store/projectStore'
 import { useUIStore } from '../store/uiStore'
 import { globalCommandRegistry } from '../lib/editor/commands'
 import { openProjectViaDialog } from '../lib/projects/actions'
+import { insertImageFromDialog } from '../lib/editor/insert-image'
+import { publishCurrentFile } from '../lib/frontmatter-flags'
+import { printCurrentDocument } from '../lib/print'
+import { resetZoom, zoomIn, zoomOut } from '../lib/zoom'
 import { DOCS_URLS } from '../lib/docs-urls'
 import type { HeadingLevel } from '../lib/editor/markdown/types'
 
@@ -67,6 +71,9 @@ export function useMenuEvents(
             void saveFile()
           }
         }),
+        listen('menu-print', () => {
+          void printCurrentDocument()
+        }),
         listen('menu-new-file', () => {
           const { selectedCollection } = useProjectStore.getState()
           if (selectedCollection) {
@@ -83,6 +90,25 @@ export function useMenuEvents(
         listen('menu-toggle-frontmatter', () => {
           useUIStore.getState().toggleFrontmatterPanel()
         }),
+        listen('menu-zoom-in', () => {
+          void zoomIn()
+        }),
+        listen('menu-zoom-out', () => {
+          void zoomOut()
+        }),
+        listen('menu-zoom-reset', () => {
+          void resetZoom()
+        }),
+      ])
+
+      // Native toolbar actions (macOS)
+      const toolbarUnlisteners = await Promise.all([
+        listen('menu-insert-image', () => {
+          void insertImageFromDialog()
+        }),
+        listen('menu-publish', () => {
+          void publishCurrentFile()
+        }),
       ])
 
       // Format operations (using map-based approach)
@@ -119,6 +145,7 @@ export function useMenuEvents(
       unlistenFunctions.push(
         ...fileUnlisteners,
         ...viewUnlisteners,
+        ...toolbarUnlisteners,
         ...formatUnlisteners,
         preferencesUnlistener,
         ...helpUnlisteners
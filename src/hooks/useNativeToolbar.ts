@@ -0,0 +1,20 @@
+import { useEffect } from 'react'
+import { commands } from '@/types'
+import { useProjectStore } from '../store/projectStore'
+import { usePlatform } from './usePlatform'
+
+/**
+ * Shows the native macOS window toolbar when enabled in preferences. Its
+ * buttons emit the same events as the menu (see `useMenuEvents`).
+ */
+export function useNativeToolbar() {
+  const platform = usePlatform()
+  const enabled = useProjectStore(
+    state => state.globalSettings?.general?.nativeToolbar ?? false
+  )
+
+  useEffect(() => {
+    if (platform !== 'macos') return
+    void commands.setNativeToolbar(enabled)
+  }, [platform, enabled])
+}
@@ -0,0 +1,14 @@
+import { useEffect } from 'react'
+import { restoreProjectZoom } from '../lib/zoom'
+import { useProjectStore } from '../store/projectStore'
+
+/**
+ * Restores the open project's saved zoom level whenever the project changes.
+ */
+export function useProjectZoom() {
+  const projectPath = useProjectStore(state => state.projectPath)
+
+  useEffect(() => {
+    void restoreProjectZoom(projectPath)
+  }, [projectPath])
+}
@@ -0,0 +1,56 @@
+import { useEffect } from 'react'
+import { listen } from '@tauri-apps/api/event'
+import { commands, type StaleDraft } from '@/types'
+import {
+  getEffectiveContentDirectory,
+  getEffectiveFrontmatterMappings,
+} from '../lib/project-registry'
+import { toast } from '../lib/toast'
+import { useProjectStore } from '../store/projectStore'
+import { useStaleDraftsStore } from '../store/staleDraftsStore'
+
+/** Drafts untouched for this many days are stale unless the project says otherwise */
+export const DEFAULT_STALE_DRAFT_DAYS = 30
+
+/**
+ * Keeps the backend's weekly stale draft digest in sync with the open
+ * project's settings. The backend posts a native notification when a digest
+ * is due; this also shows it as a toast.
+ */
+export function useStaleDraftDigest() {
+  const projectPath = useProjectStore(state => state.projectPath)
+  const settings = useProjectStore(state => state.currentProjectSettings)
+
+  const enabled = settings?.staleDrafts?.weeklyDigest ?? false
+  const days = settings?.staleDrafts?.days ?? DEFAULT_STALE_DRAFT_DAYS
+  const contentDirectory = getEffectiveContentDirectory(settings)
+  const { title, draft } = getEffectiveFrontmatterMappings(settings)
+
+  useEffect(() => {
+    void commands.setStaleDraftDigest(
+      enabled && projectPath
+        ? { projectPath, contentDirectory, days, fields: { title, draft } }
+        : null
+    )
+  }, [enabled, projectPath, contentDirectory, days, title, draft])
+
+  useEffect(() => {
+    const unlisten = listen<StaleDraft[]>('stale-drafts-digest', event => {
+      const drafts = event.payload
+      toast.info(`${drafts.length} drafts haven't been touched in a while`, {
+        description: drafts
+          .slice(0, 3)
+          .map(d => `${d.title} (${d.daysSinceEdit} days)`)
+          .join(', '),
+        action: {
+          label: 'Show',
+          onClick: () => useStaleDraftsStore.getState().open(),
+        },
+      })
+    })
+
+    return () => {
+      void unlisten.then(fn => fn())
+    }
+  }, [])
+}
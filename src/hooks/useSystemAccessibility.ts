@@ -0,0 +1,38 @@
+import { useEffect } from 'react'
+import { listen } from '@tauri-apps/api/event'
+import { commands, type SystemAccessibilityPrefs } from '@/types'
+
+function applyPrefs(prefs: SystemAccessibilityPrefs) {
+  const root = document.documentElement
+  root.classList.toggle('reduce-motion', prefs.reducedMotion)
+  root.classList.toggle('increase-contrast', prefs.increasedContrast)
+}
+
+/**
+ * Mirrors the OS reduced motion and increased contrast settings as
+ * `reduce-motion` and `increase-contrast` classes on the root element.
+ *
+ * The settings are read natively and pushed on change, so this works in
+ * webviews that don't report `prefers-reduced-motion` or `prefers-contrast`.
+ */
+export function useSystemAccessibility() {
+  useEffect(() => {
+    let cancelled = false
+
+    void commands.getSystemAccessibilityPrefs().then(result => {
+      if (!cancelled && result.status === 'ok') {
+        applyPrefs(result.data)
+      }
+    })
+
+    const unlisten = listen<SystemAccessibilityPrefs>(
+      'accessibility-prefs-changed',
+      event => applyPrefs(event.payload)
+    )
+
+    return () => {
+      cancelled = true
+      void unlisten.then(fn => fn())
+    }
+  }, [])
+}
@@ -0,0 +1,29 @@
+import { useEffect } from 'react'
+import { commands } from '@/types'
+import { toast } from '../lib/toast'
+import { useProjectStore } from '../store/projectStore'
+
+/**
+ * Registers the open project's trusted roots with the backend, so commands
+ * may also touch files in those directories.
+ */
+export function useTrustedRoots() {
+  const projectPath = useProjectStore(state => state.projectPath)
+  const trustedRoots = useProjectStore(
+    state => state.currentProjectSettings?.trustedRoots
+  )
+
+  useEffect(() => {
+    if (!projectPath) return
+
+    void commands
+      .setTrustedRoots(projectPath, trustedRoots ?? [])
+      .then(result => {
+        if (result.status === 'error') {
+          toast.error('Failed to register trusted folders', {
+            description: result.error,
+          })
+        }
+      })
+  }, [projectPath, trustedRoots])
+}
@@ -0,0 +1,35 @@
+import { useEffect } from 'react'
+import { listen } from '@tauri-apps/api/event'
+import type { UnsavedDraftSummary } from '@/types'
+import { toast } from '../lib/toast'
+
+/**
+ * Mentions edits left unsaved in a previous session, which the backend
+ * announces when their project is opened. Opening one of the files offers to
+ * restore its edits.
+ */
+export function useUnsavedDrafts() {
+  useEffect(() => {
+    const unlisten = listen<UnsavedDraftSummary[]>(
+      'unsaved-drafts-found',
+      event => {
+        const drafts = event.payload
+        const files = drafts.length === 1 ? '1 file' : `${drafts.length} files`
+        toast.warning(
+          `You have unsaved changes from a previous session for ${files}`,
+          {
+            description: drafts
+              .slice(0, 3)
+              .map(d => d.filePath.split(/[\\/]/).pop() ?? d.filePath)
+              .join(', '),
+            duration: 10000,
+          }
+        )
+      }
+    )
+
+    return () => {
+      void unlisten.then(fn => fn())
+    }
+  }, [])
+}
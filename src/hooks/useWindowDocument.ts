@@ -0,0 +1,37 @@
+import { useEffect } from 'react'
+import { useShallow } from 'zustand/react/shallow'
+import { commands } from '@/types'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import { getEffectiveFrontmatterMappings } from '../lib/project-registry'
+
+/**
+ * Keeps the native window title and represented document in sync with the
+ * open entry: title from frontmatter (falling back to the file name), the
+ * macOS proxy icon for the file, and the edited dot while there are unsaved
+ * changes.
+ */
+export function useWindowDocument() {
+  const currentFile = useEditorStore(useShallow(state => state.currentFile))
+  const isDirty = useEditorStore(state => state.isDirty)
+  const currentProjectSettings = useProjectStore(
+    useShallow(state => state.currentProjectSettings)
+  )
+
+  const titleField = getEffectiveFrontmatterMappings(
+    currentProjectSettings,
+    currentFile?.collection
+  ).title
+  const frontmatterTitle = useEditorStore(state => {
+    const value = state.frontmatter[titleField]
+    return typeof value === 'string' ? value : null
+  })
+
+  const title = currentFile
+    ? frontmatterTitle?.trim() || currentFile.name
+    : null
+
+  useEffect(() => {
+    void commands.setWindowDocument(title, currentFile?.path ?? null, isDirty)
+  }, [title, currentFile?.path, isDirty])
+}
@@ -0,0 +1,101 @@
+import { commands, type AssetRepairReport } from '@/lib/bindings'
+import { useProjectStore } from '../store/projectStore'
+import {
+  getEffectiveAssetsDirectory,
+  getEffectiveContentDirectory,
+} from './project-registry'
+import { ASTRO_PATHS } from './constants'
+import { queryClient } from './query-client'
+import { queryKeys } from './query-keys'
+import { toast } from './toast'
+
+/**
+ * Runs `repair_asset_references` for the open project, either as a dry run or
+ * rewriting the references it can fix
+ */
+async function runRepair(apply: boolean): Promise<AssetRepairReport | null> {
+  const { projectPath, currentProjectSettings } = useProjectStore.getState()
+  if (!projectPath) return null
+
+  const contentDirectory = getEffectiveContentDirectory(currentProjectSettings)
+  const assetsDirectory = getEffectiveAssetsDirectory(currentProjectSettings)
+  const result = await commands.repairAssetReferences(
+    projectPath,
+    contentDirectory !== ASTRO_PATHS.CONTENT_DIR ? contentDirectory : null,
+    assetsDirectory !== ASTRO_PATHS.ASSETS_DIR ? assetsDirectory : null,
+    apply
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to check image paths', { description: result.error })
+    return null
+  }
+  return result.data
+}
+
+async function applyRepairs(): Promise<void> {
+  const projectPath = useProjectStore.getState().projectPath
+  const report = await runRepair(true)
+  if (!report || !projectPath) return
+
+  // Rewritten files may be open or cached
+  void queryClient.invalidateQueries({
+    queryKey: [...queryKeys.all, projectPath, 'files'],
+  })
+  toast.success(
+    `Fixed ${report.referencesChanged} image paths in ${report.filesChanged} files`,
+    {
+      action: {
+        label: 'Undo',
+        onClick: () => {
+          void commands.undoLastBulkOperation().then(() =>
+            queryClient.invalidateQueries({
+              queryKey: [...queryKeys.all, projectPath],
+            })
+          )
+        },
+      },
+    }
+  )
+}
+
+/**
+ * Looks for image references broken by assets moved outside the editor and
+ * offers to point them at files with the same name in the assets or public
+ * directories. References without a single match are listed for fixing by
+ * hand.
+ */
+export async function repairBrokenImagePaths(): Promise<void> {
+  const report = await runRepair(false)
+  if (!report) return
+
+  const { repairs } = report
+  if (repairs.length === 0) {
+    toast.success('No broken image paths found')
+    return
+  }
+
+  const fixable = repairs.filter(r => r.replacement !== null)
+  const unresolved = repairs
+    .filter(r => r.replacement === null)
+    .map(r => r.reference)
+  const description = [
+    ...fixable.slice(0, 3).map(r => `${r.reference} → ${r.replacement}`),
+    ...(unresolved.length > 0
+      ? [`Can't repair: ${unresolved.slice(0, 3).join(', ')}`]
+      : []),
+  ].join('\n')
+
+  if (fixable.length === 0) {
+    toast.warning(`Found ${repairs.length} broken image paths`, {
+      description,
+    })
+    return
+  }
+  toast.info(
+    `Found ${repairs.length} broken image paths, ${fixable.length} repairable`,
+    {
+      description,
+      action: { label: 'Repair', onClick: () => void applyRepairs() },
+    }
+  )
+}
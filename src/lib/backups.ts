@@ -0,0 +1,73 @@
+import { commands } from '@/lib/bindings'
+import type { BackupInfo } from '@/types'
+import { useProjectStore } from '../store/projectStore'
+import { getEffectiveContentDirectory } from './project-registry'
+import { ASTRO_PATHS } from './constants'
+import { queryClient } from './query-client'
+import { queryKeys } from './query-keys'
+import { toast } from './toast'
+
+const REASON_LABELS: Record<string, string> = {
+  scheduled: 'Scheduled',
+  manual: 'Manual',
+  'before-restore': 'Before restore',
+}
+
+/**
+ * What took a backup, for display: the schedule, the user, a restore or the
+ * bulk operation it came before
+ */
+export function backupReason(backup: BackupInfo): string {
+  return REASON_LABELS[backup.reason] ?? `Before ${backup.reason}`
+}
+
+/**
+ * Backs up the project's content directory now
+ */
+export async function createBackup(): Promise<void> {
+  const { projectPath, currentProjectSettings } = useProjectStore.getState()
+  if (!projectPath) return
+
+  const contentDirectory = getEffectiveContentDirectory(currentProjectSettings)
+  const result = await commands.createBackup(
+    projectPath,
+    contentDirectory !== ASTRO_PATHS.CONTENT_DIR ? contentDirectory : null
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to back up content', { description: result.error })
+    return
+  }
+
+  await queryClient.invalidateQueries({
+    queryKey: queryKeys.backups(projectPath),
+  })
+  toast.success(`Backed up ${result.data.fileCount} files`)
+}
+
+/**
+ * Restores a backup's files into the content directory, after backing up the
+ * current content, and refreshes the project's queries
+ */
+export async function restoreBackup(backup: BackupInfo): Promise<boolean> {
+  const { projectPath } = useProjectStore.getState()
+  if (!projectPath) return false
+
+  const result = await commands.restoreBackup(backup.id, projectPath)
+  if (result.status === 'error') {
+    toast.error('Failed to restore backup', { description: result.error })
+    return false
+  }
+
+  await queryClient.invalidateQueries({
+    queryKey: [...queryKeys.all, projectPath],
+  })
+  const { restored, safetyBackup } = result.data
+  toast.success(`Restored ${restored.length} files`, {
+    description: 'Your content from before the restore was backed up too.',
+    action: {
+      label: 'Undo',
+      onClick: () => void restoreBackup(safetyBackup),
+    },
+  })
+  return true
+}
@@ -9,9 +9,165 @@
 
 
 export const commands = {
-async updateFormatMenuState(enabled: boolean) : Promise<Result<null, string>> {
+/**
+ * Enables or disables context-dependent menu items (Format, Save, Print, New File)
+ * 
+ * # Arguments
+ * * `context` - Current editor state as seen by the frontend
+ */
+async updateMenuContext(context: MenuContext) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_menu_context", { context }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Replaces the Edit > Frontmatter items with toggles for the current collection's fields
+ * 
+ * Selecting an item emits `menu-toggle-frontmatter-flag` with the field name.
+ * 
+ * # Arguments
+ * * `fields` - Boolean and enum fields of the current file's collection; empty disables
+ * the submenu
+ */
+async updateFrontmatterFlagMenu(fields: FrontmatterFlagField[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_frontmatter_flag_menu", { fields }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Sets the main window's title and represented document for the open entry
+ * 
+ * # Arguments
+ * * `title` - The entry's title; falls back to the app name when empty or `None`
+ * * `file_path` - Absolute path of the open file, or `None` when no file is open
+ * * `is_dirty` - Whether the file has unsaved changes
+ */
+async setWindowDocument(title: string | null, filePath: string | null, isDirty: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_window_document", { title, filePath, isDirty }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gets the system's reduced motion, increased contrast and color scheme settings
+ * 
+ * Changes are emitted as `accessibility-prefs-changed` with the same payload.
+ */
+async getSystemAccessibilityPrefs() : Promise<Result<SystemAccessibilityPrefs, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_system_accessibility_prefs") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gets the saved zoom level for a project
+ * 
+ * # Arguments
+ * * `project_path` - The project to look up, or `None` when no project is open
+ * 
+ * # Returns
+ * The saved level, or 1.0 if the project has none
+ */
+async getZoomLevel(projectPath: string | null) : Promise<Result<number, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_zoom_level", { projectPath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Zooms the window's webview and saves the level for the project
+ * 
+ * # Arguments
+ * * `project_path` - The open project, or `None` to zoom without saving
+ * * `level` - The zoom factor, where 1.0 is 100% (clamped to 0.5–3.0)
+ * 
+ * # Returns
+ * The level that was applied
+ */
+async setZoomLevel(projectPath: string | null, level: number) : Promise<Result<number, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_zoom_level", { projectPath, level }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Prints a rendered document through the native print dialog
+ * 
+ * # Arguments
+ * * `title` - Document title
+ * * `body_html` - The document body rendered to HTML
+ * * `header` - Running header template (`{title}`, `{date}`); empty for none
+ * * `footer` - Running footer template (`{title}`, `{date}`); empty for none
+ */
+async printCurrentDocument(title: string, bodyHtml: string, header: string, footer: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("print_current_document", { title, bodyHtml, header, footer }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Renders a document to HTML for the preview pane
+ * 
+ * # Arguments
+ * * `content` - The document body, or the whole file when `frontmatter` is omitted
+ * * `frontmatter` - The editor's current frontmatter; parsed from `content` if omitted
+ * * `title_field` - Frontmatter field holding the title (defaults to `title`)
+ * * `smart_punctuation` - Whether to render curly quotes, dashes and ellipses
+ * * `dark` - Whether to highlight code for a dark background
+ */
+async renderMarkdownPreview(content: string, frontmatter: Partial<{ [key in string]: JsonValue }> | null, titleField: string | null, smartPunctuation: boolean, dark: boolean) : Promise<Result<MarkdownPreview, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("render_markdown_preview", { content, frontmatter, titleField, smartPunctuation, dark }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Points local asset references in rendered HTML at their deployed URLs
+ * 
+ * # Arguments
+ * * `html` - The rendered document
+ * * `project_path` - The project root
+ * * `file_path` - The document's path, for resolving relative references
+ * * `mappings` - The project's `assetUrlMappings` setting
+ */
+async rewriteAssetUrls(html: string, projectPath: string, filePath: string | null, mappings: AssetUrlMapping[]) : Promise<Result<AssetUrlRewrite, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rewrite_asset_urls", { html, projectPath, filePath, mappings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Shows or hides the native toolbar on the main window (macOS only)
+ * 
+ * Toolbar buttons emit `menu-save`, `menu-toggle-frontmatter`, `menu-insert-image`
+ * and `menu-publish`.
+ * 
+ * # Arguments
+ * * `visible` - Whether the toolbar should be shown
+ */
+async setNativeToolbar(visible: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_format_menu_state", { enabled }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_native_toolbar", { visible }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -25,89 +181,225 @@ async readFile(filePath: string, projectRoot: string) : Promise<Result<string, s
     else return { status: "error", error: e  as any };
 }
 },
-async writeFile(filePath: string, content: string, projectRoot: string) : Promise<Result<null, string>> {
+/**
+ * Writes a file in the project
+ * 
+ * With `expected_hash` (the `content_hash` the file was loaded with), the write is
+ * refused with `FileSaveError::Conflict` if the file has changed on disk since.
+ * 
+ * # Returns
+ * The content hash of the written file, to expect on the next write
+ */
+async writeFile(filePath: string, content: string, projectRoot: string, origin: string | null, expectedHash: string | null) : Promise<Result<string, FileSaveError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("write_file", { filePath, content, projectRoot, origin, expectedHash }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async createFile(directory: string, filename: string, content: string, projectRoot: string, origin: string | null) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_file", { directory, filename, content, projectRoot, origin }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Deletes a file by moving it to the trash, where it can be restored from
+ * 
+ * # Returns
+ * The trash entry, or `None` during a dry run, when the delete is only recorded
+ */
+async deleteFile(filePath: string, projectRoot: string, origin: string | null) : Promise<Result<TrashedFile | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_file", { filePath, projectRoot, origin }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Copies an entry into a new file beside it, adjusting the copy's frontmatter
+ * 
+ * The copy is named `{name}-copy.md`, numbered (`-copy-2`, ...) past any existing
+ * file. Without adjustments the content is copied unchanged.
+ * 
+ * # Returns
+ * The new file's path
+ */
+async duplicateFile(filePath: string, projectRoot: string, adjustments: DuplicateAdjustments | null, origin: string | null) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("duplicate_file", { filePath, projectRoot, adjustments, origin }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Renames or moves a file, rewriting relative references it breaks
+ * 
+ * Relative links and images in the file itself are re-based on its new directory,
+ * and references to it from other content files follow it. Returns every reference
+ * rewritten.
+ */
+async renameFile(oldPath: string, newPath: string, projectRoot: string, origin: string | null) : Promise<Result<RewrittenLink[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("write_file", { filePath, content, projectRoot }) };
+    return { status: "ok", data: await TAURI_INVOKE("rename_file", { oldPath, newPath, projectRoot, origin }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async createFile(directory: string, filename: string, content: string, projectRoot: string) : Promise<Result<string, string>> {
+/**
+ * Renames or moves an entry, rewriting relative references and links to its ID or slug
+ * 
+ * Does everything `rename_file` does, and also updates `reference()` values, links to
+ * its URL and wiki links that name the entry by its old ID or slug.
+ * 
+ * # Arguments
+ * * `content_directory` - Optional content directory override
+ * * `url_patterns` - Collection URL patterns (e.g. `{ "blog": "/writing/{slug}" }`) for
+ * finding site-absolute links to the entry
+ * 
+ * # Returns
+ * The files touched and every reference rewritten in them
+ */
+async renameFileWithLinkRewrite(oldPath: string, newPath: string, projectRoot: string, contentDirectory: string | null, urlPatterns: Partial<{ [key in string]: string }>, origin: string | null) : Promise<Result<LinkRewriteReport, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("create_file", { directory, filename, content, projectRoot }) };
+    return { status: "ok", data: await TAURI_INVOKE("rename_file_with_link_rewrite", { oldPath, newPath, projectRoot, contentDirectory, urlPatterns, origin }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async deleteFile(filePath: string, projectRoot: string) : Promise<Result<null, string>> {
+async copyFileToAssets(sourcePath: string, projectPath: string, collection: string, currentFilePath: string, useRelativePaths: boolean, usePathAliases: boolean) : Promise<Result<string, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_file", { filePath, projectRoot }) };
+    return { status: "ok", data: await TAURI_INVOKE("copy_file_to_assets", { sourcePath, projectPath, collection, currentFilePath, useRelativePaths, usePathAliases }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async renameFile(oldPath: string, newPath: string, projectRoot: string) : Promise<Result<null, string>> {
+async copyFileToAssetsWithOverride(sourcePath: string, projectPath: string, collection: string, assetsDirectory: string | null, currentFilePath: string, useRelativePaths: boolean, usePathAliases: boolean) : Promise<Result<string, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("rename_file", { oldPath, newPath, projectRoot }) };
+    return { status: "ok", data: await TAURI_INVOKE("copy_file_to_assets_with_override", { sourcePath, projectPath, collection, assetsDirectory, currentFilePath, useRelativePaths, usePathAliases }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async copyFileToAssets(sourcePath: string, projectPath: string, collection: string, currentFilePath: string, useRelativePaths: boolean) : Promise<Result<string, string>> {
+/**
+ * Downloads a remote image into the collection's assets directory
+ * 
+ * The image is named like a copied asset, and SVGs are sanitized. Downloads over
+ * 20 MB, taking longer than 30 seconds, or that aren't a supported image are rejected.
+ * 
+ * # Returns
+ * The path to reference the image by from `current_file_path`
+ */
+async downloadImageToAssets(url: string, projectPath: string, collection: string, assetsDirectory: string | null, currentFilePath: string, useRelativePaths: boolean, usePathAliases: boolean) : Promise<Result<string, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("copy_file_to_assets", { sourcePath, projectPath, collection, currentFilePath, useRelativePaths }) };
+    return { status: "ok", data: await TAURI_INVOKE("download_image_to_assets", { url, projectPath, collection, assetsDirectory, currentFilePath, useRelativePaths, usePathAliases }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async copyFileToAssetsWithOverride(sourcePath: string, projectPath: string, collection: string, assetsDirectory: string | null, currentFilePath: string, useRelativePaths: boolean) : Promise<Result<string, string>> {
+/**
+ * Loads a markdown file for the editor
+ * 
+ * Files above `max_file_size_mb` (default 5 MB) are refused with `FileLoadError::TooLarge`
+ * unless `allow_large_file` is set, so a huge generated file can't freeze the webview.
+ * The frontend can offer `parse_frontmatter_only` instead.
+ */
+async parseMarkdownContent(filePath: string, projectRoot: string, maxFileSizeMb: number | null, allowLargeFile: boolean) : Promise<Result<MarkdownContent, FileLoadError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("copy_file_to_assets_with_override", { sourcePath, projectPath, collection, assetsDirectory, currentFilePath, useRelativePaths }) };
+    return { status: "ok", data: await TAURI_INVOKE("parse_markdown_content", { filePath, projectRoot, maxFileSizeMb, allowLargeFile }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async parseMarkdownContent(filePath: string, projectRoot: string) : Promise<Result<MarkdownContent, string>> {
+/**
+ * Reads only the frontmatter block of a file, without loading the body
+ * 
+ * Used for frontmatter-only editing of files too large for the editor. Changes are
+ * saved with `update_frontmatter`, which preserves the body.
+ */
+async parseFrontmatterOnly(filePath: string, projectRoot: string) : Promise<Result<FrontmatterOnlyContent, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("parse_markdown_content", { filePath, projectRoot }) };
+    return { status: "ok", data: await TAURI_INVOKE("parse_frontmatter_only", { filePath, projectRoot }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async updateFrontmatter(filePath: string, frontmatter: Partial<{ [key in string]: JsonValue }>, projectRoot: string) : Promise<Result<null, string>> {
+async updateFrontmatter(filePath: string, frontmatter: Partial<{ [key in string]: JsonValue }>, projectRoot: string, origin: string | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_frontmatter", { filePath, frontmatter, projectRoot }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_frontmatter", { filePath, frontmatter, projectRoot, origin }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async saveMarkdownContent(filePath: string, frontmatter: Partial<{ [key in string]: JsonValue }> | null, rawFrontmatter: string | null, content: string, imports: string, schemaFieldOrder: string[] | null, projectRoot: string) : Promise<Result<null, string>> {
+/**
+ * Replaces a file's frontmatter with YAML edited by hand, for fields the form can't edit
+ * 
+ * Nothing is written unless the YAML parses and, when `complete_schema` is given, passes
+ * the same checks as `validate_frontmatter`. The rebuilt file is parsed again before it's
+ * written, so an edit can't leave the file unreadable.
+ * 
+ * # Arguments
+ * * `file_path` - The absolute path to the file
+ * * `project_root` - The absolute path to the project root
+ * * `raw_frontmatter` - The YAML to write between the `---` lines
+ * * `complete_schema` - The collection's serialized complete schema; omit to skip schema checks
+ * 
+ * # Returns
+ * The file as it now parses, for the editor to reload
+ */
+async applyRawFrontmatter(filePath: string, projectRoot: string, rawFrontmatter: string, completeSchema: string | null, origin: string | null) : Promise<Result<MarkdownContent, RawFrontmatterError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("save_markdown_content", { filePath, frontmatter, rawFrontmatter, content, imports, schemaFieldOrder, projectRoot }) };
+    return { status: "ok", data: await TAURI_INVOKE("apply_raw_frontmatter", { filePath, projectRoot, rawFrontmatter, completeSchema, origin }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async saveRecoveryData(data: JsonValue) : Promise<Result<null, string>> {
+/**
+ * Saves a file from the editor
+ * 
+ * With `expected_hash` (the `content_hash` the file was loaded with), the save is
+ * refused with `FileSaveError::Conflict` if the file has changed on disk since.
+ * 
+ * # Returns
+ * The content hash of the saved file, to expect on the next save
+ */
+async saveMarkdownContent(filePath: string, frontmatter: Partial<{ [key in string]: JsonValue }> | null, rawFrontmatter: string | null, content: string, imports: string, schemaFieldOrder: string[] | null, projectRoot: string, origin: string | null, readingTime: ReadingTimeSettings | null, mdxEscaping: MdxEscapingSettings | null, dateFormats: DateFormatSettings | null, expectedHash: string | null) : Promise<Result<string, FileSaveError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("save_recovery_data", { data }) };
+    return { status: "ok", data: await TAURI_INVOKE("save_markdown_content", { filePath, frontmatter, rawFrontmatter, content, imports, schemaFieldOrder, projectRoot, origin, readingTime, mdxEscaping, dateFormats, expectedHash }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async saveCrashReport(report: JsonValue) : Promise<Result<null, string>> {
+/**
+ * Toggles a boolean frontmatter field, or cycles an enum field, directly on disk
+ * 
+ * Used by the Edit > Frontmatter menu shortcuts. Emits `frontmatter-flag-toggled` so
+ * the editor and file list can reload.
+ * 
+ * # Arguments
+ * * `file_path` - The absolute path to the file
+ * * `project_root` - The absolute path to the project root
+ * * `field` - Top-level frontmatter field, e.g. `draft`
+ * * `values` - Enum values to cycle through; omit for boolean fields
+ */
+async toggleFrontmatterFlag(filePath: string, projectRoot: string, field: string, values: string[] | null) : Promise<Result<FrontmatterFlagToggle, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("save_crash_report", { report }) };
+    return { status: "ok", data: await TAURI_INVOKE("toggle_frontmatter_flag", { filePath, projectRoot, field, values }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -145,9 +437,9 @@ async readFileContent(filePath: string, projectRoot: string) : Promise<Result<st
     else return { status: "error", error: e  as any };
 }
 },
-async writeFileContent(filePath: string, content: string, projectRoot: string) : Promise<Result<null, string>> {
+async writeFileContent(filePath: string, content: string, projectRoot: string, origin: string | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("write_file_content", { filePath, content, projectRoot }) };
+    return { status: "ok", data: await TAURI_INVOKE("write_file_content", { filePath, content, projectRoot, origin }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -184,9 +476,9 @@ async isPathInProject(filePath: string, projectPath: string) : Promise<boolean>
  * # Returns
  * The relative path from project root, or an error if the file is not in the project
  */
-async getRelativePath(filePath: string, projectPath: string, currentFilePath: string, useRelativePaths: boolean) : Promise<Result<string, string>> {
+async getRelativePath(filePath: string, projectPath: string, currentFilePath: string, useRelativePaths: boolean, usePathAliases: boolean) : Promise<Result<string, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_relative_path", { filePath, projectPath, currentFilePath, useRelativePaths }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_relative_path", { filePath, projectPath, currentFilePath, useRelativePaths, usePathAliases }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -198,6 +490,7 @@ async getRelativePath(filePath: string, projectPath: string, currentFilePath: st
  * Handles both absolute paths (starting with /) and relative paths (starting with ./ or ../)
  * For absolute paths: treats them as relative to project root
  * For relative paths: resolves relative to the current file's directory
+ * For aliased paths (e.g. "@assets/image.png"): resolves using tsconfig.json path aliases
  * 
  * # Arguments
  * * `image_path` - The image path from markdown (e.g., "/src/assets/image.png" or "./image.png")
@@ -215,251 +508,4306 @@ async resolveImagePath(imagePath: string, projectRoot: string, currentFilePath:
     else return { status: "error", error: e  as any };
 }
 },
-async selectProjectFolder() : Promise<Result<string | null, string>> {
+/**
+ * Saves the editor state after a failed save, then prunes old recovery entries
+ * 
+ * # Arguments
+ * * `data` - The editor state (`fileName`, `editorContent`, `frontmatter`, ...)
+ * * `settings` - The `recovery` preference; retention defaults apply when unset
+ */
+async saveRecoveryData(data: JsonValue, settings: RecoverySettings | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("select_project_folder") };
+    return { status: "ok", data: await TAURI_INVOKE("save_recovery_data", { data, settings }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async scanProject(projectPath: string) : Promise<Result<Collection[], string>> {
+/**
+ * Reads a recovery entry's editor state, decrypting it if needed
+ * 
+ * # Arguments
+ * * `file_name` - A `.recovery.json` or `.recovery.enc` file in the recovery directory
+ */
+async readRecoveryData(fileName: string) : Promise<Result<JsonValue, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("scan_project", { projectPath }) };
+    return { status: "ok", data: await TAURI_INVOKE("read_recovery_data", { fileName }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async scanProjectWithContentDir(projectPath: string, contentDirectory: string | null) : Promise<Result<Collection[], string>> {
+/**
+ * Deletes recovery entries, returning how many were removed
+ * 
+ * # Arguments
+ * * `older_than_days` - Only remove entries older than this; removes everything when unset
+ */
+async purgeRecoveryData(olderThanDays: number | null) : Promise<Result<number, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("scan_project_with_content_dir", { projectPath, contentDirectory }) };
+    return { status: "ok", data: await TAURI_INVOKE("purge_recovery_data", { olderThanDays }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Resolves an absolute file path to a `FileEntry` within the given project, if the
- * file is a Markdown/MDX item owned by one of the project's content collections.
- * 
- * Used by the deep-link handler (`astro-editor://open?path=...`). `file_path` is
- * untrusted input, so we canonicalize and verify it lives inside `project_path`
- * before touching collections, guarding against `../` traversal.
+ * Saves a crash report with a snapshot of backend state appended
  * 
- * Returns `Ok(None)` when the path is valid and inside the project but isn't a
- * Markdown item owned by a loadable collection (the caller opens the project and
- * shows a "couldn't find that file" toast).
+ * # Arguments
+ * * `report` - The frontend's report (`error`, `stack`, `context`, ...)
  */
-async resolveFileEntry(filePath: string, projectPath: string, contentDirectory: string | null) : Promise<Result<FileEntry | null, string>> {
+async saveCrashReport(report: JsonValue) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("resolve_file_entry", { filePath, projectPath, contentDirectory }) };
+    return { status: "ok", data: await TAURI_INVOKE("save_crash_report", { report }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async scanCollectionFiles(collectionPath: string) : Promise<Result<FileEntry[], string>> {
+/**
+ * Lists saved crash reports, newest first
+ * 
+ * # Arguments
+ * * `limit` - Maximum number of reports (defaults to 50)
+ */
+async listCrashReports(limit: number | null) : Promise<Result<CrashReportSummary[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("scan_collection_files", { collectionPath }) };
+    return { status: "ok", data: await TAURI_INVOKE("list_crash_reports", { limit }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async loadFileBasedCollection(projectPath: string, collectionName: string) : Promise<Result<FileEntry[], string>> {
+/**
+ * Returns recorded file operations, newest first
+ * 
+ * # Arguments
+ * * `path` - Optional absolute path; only entries touching this file are returned
+ * * `limit` - Maximum number of entries (defaults to 200)
+ */
+async readAuditLog(path: string | null, limit: number | null) : Promise<Result<AuditEntry[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("load_file_based_collection", { projectPath, collectionName }) };
+    return { status: "ok", data: await TAURI_INVOKE("read_audit_log", { path, limit }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async readJsonSchema(projectPath: string, collectionName: string) : Promise<Result<string, string>> {
+/**
+ * Returns the most recent bulk operation that can be undone, if any
+ */
+async getLastBulkOperation() : Promise<Result<BulkOperationSummary | null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("read_json_schema", { projectPath, collectionName }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_last_bulk_operation") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Scan a single directory (non-recursive) for subdirectories and markdown/mdx files
+ * Restores the files rewritten by the most recent bulk operation
+ * 
+ * Files edited since the operation are left alone and reported as skipped.
  */
-async scanDirectory(directoryPath: string, collectionName: string, collectionRoot: string) : Promise<Result<DirectoryScanResult, string>> {
+async undoLastBulkOperation() : Promise<Result<BulkUndoResult, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("scan_directory", { directoryPath, collectionName, collectionRoot }) };
+    return { status: "ok", data: await TAURI_INVOKE("undo_last_bulk_operation") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Count all markdown/mdx files recursively in a collection
+ * Turns backups on for the open project, or off with `None`
+ * 
+ * # Arguments
+ * * `settings` - The project to back up and how often
  */
-async countCollectionFilesRecursive(collectionPath: string) : Promise<Result<number, string>> {
+async setBackupSettings(settings: BackupSettings | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("count_collection_files_recursive", { collectionPath }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_backup_settings", { settings }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Scan all markdown/mdx files recursively in a collection directory
+ * Lists a project's backups, newest first
  */
-async scanCollectionFilesRecursive(collectionPath: string, collectionName: string) : Promise<Result<FileEntry[], string>> {
+async listBackups(projectPath: string) : Promise<Result<BackupInfo[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("scan_collection_files_recursive", { collectionPath, collectionName }) };
+    return { status: "ok", data: await TAURI_INVOKE("list_backups", { projectPath }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async startWatchingProject(projectPath: string) : Promise<Result<null, string>> {
+/**
+ * Backs up a project's content directory now
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ */
+async createBackup(projectPath: string, contentDirectory: string | null) : Promise<Result<BackupInfo, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("start_watching_project", { projectPath }) };
+    return { status: "ok", data: await TAURI_INVOKE("create_backup", { projectPath, contentDirectory }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async startWatchingProjectWithContentDir(projectPath: string, contentDirectory: string | null) : Promise<Result<null, string>> {
+/**
+ * Restores the files in a backup to the project's content directory
+ * 
+ * The current content is backed up first. Files created since the backup are kept.
+ * 
+ * # Arguments
+ * * `id` - The backup to restore
+ * * `project_path` - The absolute path to the project root the backup belongs to
+ */
+async restoreBackup(id: string, projectPath: string) : Promise<Result<BackupRestoreResult, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("start_watching_project_with_content_dir", { projectPath, contentDirectory }) };
+    return { status: "ok", data: await TAURI_INVOKE("restore_backup", { id, projectPath }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async stopWatchingProject(projectPath: string) : Promise<Result<null, string>> {
+/**
+ * Runs a mutating command without touching disk, returning the changes it would make
+ * 
+ * The command is validated exactly as it would be for real, so a preview fails for
+ * the same reasons the command would.
+ * 
+ * # Arguments
+ * * `request` - The command and its arguments
+ */
+async previewChanges(request: MutationRequest) : Promise<Result<ChangePreview, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("stop_watching_project", { projectPath }) };
+    return { status: "ok", data: await TAURI_INVOKE("preview_changes", { request }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Opens the preferences folder in the system's default file manager
+ * Checks a file's frontmatter against its collection schema, returning values that
+ * have the wrong type but can be converted
+ * 
+ * # Arguments
+ * * `file_path` - The absolute path to the file
+ * * `project_root` - The absolute path to the project root
+ * * `complete_schema` - The collection's serialized complete schema
  */
-async openPreferencesFolder() : Promise<Result<null, string>> {
+async checkFrontmatterTypes(filePath: string, projectRoot: string, completeSchema: string) : Promise<Result<CoercionSuggestion[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("open_preferences_folder") };
+    return { status: "ok", data: await TAURI_INVOKE("check_frontmatter_types", { filePath, projectRoot, completeSchema }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Resets all preferences by deleting the app data directory and restarting the app
- */
-async resetAllPreferences() : Promise<Result<null, string>> {
+ * Rewrites frontmatter values to the types suggested by `check_frontmatter_types`
+ * 
+ * Suggestions for fields edited since the check are skipped.
+ * 
+ * # Arguments
+ * * `file_path` - The absolute path to the file
+ * * `project_root` - The absolute path to the project root
+ * * `coercions` - The suggestions to apply
+ * 
+ * # Returns
+ * The number of values changed
+ */
+async applyCoercions(filePath: string, projectRoot: string, coercions: CoercionSuggestion[]) : Promise<Result<number, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("reset_all_preferences") };
+    return { status: "ok", data: await TAURI_INVOKE("apply_coercions", { filePath, projectRoot, coercions }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getAppVersion() : Promise<Result<string, string>> {
+async selectProjectFolder() : Promise<Result<string | null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_app_version") };
+    return { status: "ok", data: await TAURI_INVOKE("select_project_folder") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getPlatformInfo() : Promise<Result<string, string>> {
+async scanProject(projectPath: string) : Promise<Result<Collection[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_platform_info") };
+    return { status: "ok", data: await TAURI_INVOKE("scan_project", { projectPath }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getAppInfo() : Promise<Result<AppInfo, string>> {
+/**
+ * Scans a project's collections, with `field_groups` (the project's `fieldGroups`
+ * setting) deciding the order and collapsed state of schema field groups
+ */
+async scanProjectWithContentDir(projectPath: string, contentDirectory: string | null, fieldGroups: FieldGroupSettings | null) : Promise<Result<Collection[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_app_info") };
+    return { status: "ok", data: await TAURI_INVOKE("scan_project_with_content_dir", { projectPath, contentDirectory, fieldGroups }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async openPathInIde(ideCommand: string, filePath: string) : Promise<Result<string, string>> {
+/**
+ * Resolves an absolute file path to a `FileEntry` within the given project, if the
+ * file is a Markdown/MDX item owned by one of the project's content collections.
+ * 
+ * Used by the deep-link handler (`astro-editor://open?path=...`). `file_path` is
+ * untrusted input, so we canonicalize and verify it lives inside `project_path`
+ * before touching collections, guarding against `../` traversal.
+ * 
+ * Returns `Ok(None)` when the path is valid and inside the project but isn't a
+ * Markdown item owned by a loadable collection (the caller opens the project and
+ * shows a "couldn't find that file" toast).
+ */
+async resolveFileEntry(filePath: string, projectPath: string, contentDirectory: string | null) : Promise<Result<FileEntry | null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("open_path_in_ide", { ideCommand, filePath }) };
+    return { status: "ok", data: await TAURI_INVOKE("resolve_file_entry", { filePath, projectPath, contentDirectory }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async scanMdxComponents(projectPath: string, mdxDirectory: string | null) : Promise<Result<MdxComponent[], string>> {
+/**
+ * Returns display-ready documentation for a single frontmatter field
+ * 
+ * Drives hover-help in the frontmatter panel. Paths that pass through a reference
+ * field (e.g. `author.bio`) are resolved against the referenced collection's schema.
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * * `collection_name` - The collection the field belongs to
+ * * `field_path` - Dot-separated field path, e.g. `seo.title`
+ */
+async getFieldDocs(projectPath: string, contentDirectory: string | null, collectionName: string, fieldPath: string) : Promise<Result<FieldDocs, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("scan_mdx_components", { projectPath, mdxDirectory }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_field_docs", { projectPath, contentDirectory, collectionName, fieldPath }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async copyTextToClipboard(text: string) : Promise<Result<null, string>> {
+async scanCollectionFiles(collectionPath: string, collation: Collation | null, titleField: string | null, draftField: string | null) : Promise<Result<FileEntry[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("copy_text_to_clipboard", { text }) };
+    return { status: "ok", data: await TAURI_INVOKE("scan_collection_files", { collectionPath, collation, titleField, draftField }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async loadFileBasedCollection(projectPath: string, collectionName: string) : Promise<Result<FileEntry[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("load_file_based_collection", { projectPath, collectionName }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async readJsonSchema(projectPath: string, collectionName: string) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("read_json_schema", { projectPath, collectionName }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Fetch release notes from GitHub Releases API for all versions between
- * current_version (exclusive) and new_version (inclusive).
- * Returns combined markdown bodies in reverse chronological order.
+ * Scan a single directory (non-recursive) for subdirectories and markdown/mdx files
+ * 
+ * Subdirectories are ordered by name and files by title, using `collation`
+ * (natural order by default). With `pattern` (a `glob()` loader's globs, see
+ * `Collection::pattern`) only files it matches are listed.
  */
-async fetchReleaseNotes(currentVersion: string, newVersion: string) : Promise<Result<string, string>> {
+async scanDirectory(directoryPath: string, collectionName: string, collectionRoot: string, collation: Collation | null, titleField: string | null, draftField: string | null, pattern: string[] | null) : Promise<Result<DirectoryScanResult, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("fetch_release_notes", { currentVersion, newVersion }) };
+    return { status: "ok", data: await TAURI_INVOKE("scan_directory", { directoryPath, collectionName, collectionRoot, collation, titleField, draftField, pattern }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Detect the user's configured UI font on Linux.
+ * Count all markdown/mdx files recursively in a collection, only those matching
+ * `pattern` when given
+ */
+async countCollectionFilesRecursive(collectionPath: string, pattern: string[] | null) : Promise<Result<number, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("count_collection_files_recursive", { collectionPath, pattern }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Scan all markdown/mdx files recursively in a collection directory
+ */
+async scanCollectionFilesRecursive(collectionPath: string, collectionName: string, collation: Collation | null, titleField: string | null, draftField: string | null) : Promise<Result<FileEntry[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("scan_collection_files_recursive", { collectionPath, collectionName, collation, titleField, draftField }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Scan a collection's whole directory tree in one call
  * 
- * Tries gsettings (GNOME/Cinnamon/Budgie/XFCE) first, then falls back to
- * reading KDE's kdeglobals config file. Returns None if neither works or
- * if not running on Linux.
+ * Directories nested deeper than `max_depth` below the collection root are listed
+ * but not scanned (`truncated`); load them with `scan_directory`. Without
+ * `max_depth` the whole tree is scanned. Ordering and `pattern` filtering match
+ * `scan_directory`.
  */
-async getLinuxUiFont() : Promise<Result<string | null, string>> {
+async scanCollectionTree(collectionPath: string, collectionName: string, maxDepth: number | null, collation: Collation | null, titleField: string | null, draftField: string | null, pattern: string[] | null) : Promise<Result<DirectoryTree, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_linux_ui_font") };
+    return { status: "ok", data: await TAURI_INVOKE("scan_collection_tree", { collectionPath, collectionName, maxDepth, collation, titleField, draftField, pattern }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+/**
+ * Records that a project was opened, adding it to the registry if it's new
+ * 
+ * A registered project whose directory no longer exists is taken to have moved here
+ * when its name matches, so it keeps its settings.
+ */
+async registerProject(projectPath: string) : Promise<Result<RegisteredProject, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("register_project", { projectPath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
 }
+},
+/**
+ * Lists the projects opened before, most recently opened first
+ */
+async listRecentProjects() : Promise<Result<RecentProject[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_recent_projects") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
 }
-
-/** user-defined events **/
-
-
-
-/** user-defined constants **/
-
-
-
-/** user-defined types **/
-
-export type AppInfo = { version: string; platform: string }
-export type Collection = { name: string; path: string; complete_schema?: string | null }
-export type ComponentFramework = "astro" | "react" | "vue" | "svelte"
-export type DirectoryInfo = { name: string; relative_path: string; full_path: string }
-export type DirectoryScanResult = { subdirectories: DirectoryInfo[]; files: FileEntry[] }
-export type FileEntry = { id: string; path: string; name: string; extension: string; collection: string; last_modified: number | null; frontmatter: Partial<{ [key in string]: JsonValue }> | null }
-export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
-export type MarkdownContent = { frontmatter: Partial<{ [key in string]: JsonValue }>; content: string; raw_frontmatter: string; imports: string }
-export type MdxComponent = { name: string; file_path: string; props: PropInfo[]; has_slot: boolean; description: string | null; framework: ComponentFramework }
-export type PropInfo = { name: string; prop_type: string; is_optional: boolean; default_value: string | null }
+},
+/**
+ * Gets a project's content, assets and MDX components directory overrides
+ */
+async getProjectSettings(projectPath: string) : Promise<Result<ProjectPathSettings, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_project_settings", { projectPath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Replaces a project's directory overrides; an omitted directory goes back to the default
+ */
+async setProjectSettings(projectPath: string, settings: ProjectPathSettings) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_project_settings", { projectPath, settings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Replaces an entry of a `file()` collection with `data`
+ * 
+ * `entry_id` is the entry's current `id` (or `slug`). Keys keep their place in the file;
+ * keys missing from `data` are removed and new ones are added at the end.
+ */
+async saveFileBasedCollectionEntry(projectPath: string, collectionName: string, entryId: string, data: Partial<{ [key in string]: JsonValue }>, origin: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_file_based_collection_entry", { projectPath, collectionName, entryId, data, origin }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds an entry to the end of a `file()` collection
+ */
+async createFileBasedEntry(projectPath: string, collectionName: string, data: Partial<{ [key in string]: JsonValue }>, origin: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_file_based_entry", { projectPath, collectionName, data, origin }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Removes an entry from a `file()` collection
+ */
+async deleteFileBasedEntry(projectPath: string, collectionName: string, entryId: string, origin: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_file_based_entry", { projectPath, collectionName, entryId, origin }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists a collection's markdown files as a stream of `listing-batch` events
+ * 
+ * Entries arrive in the same order, and with the same fields, as from
+ * `scan_collection_files` (or `scan_collection_files_recursive` when `recursive`).
+ * Each batch must be acknowledged with `ack_listing_batch`. Ends with a
+ * `listing-complete` event carrying what's also returned here.
+ * 
+ * # Arguments
+ * * `stream_id` - Chosen by the caller, to tell its events apart and to cancel
+ * * `batch_size` - Entries per batch, 250 when omitted
+ */
+async streamCollectionFiles(streamId: string, collectionPath: string, collectionName: string, recursive: boolean, collation: Collation | null, titleField: string | null, draftField: string | null, batchSize: number | null) : Promise<Result<ListingComplete, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("stream_collection_files", { streamId, collectionPath, collectionName, recursive, collation, titleField, draftField, batchSize }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Acknowledges a `listing-batch`, letting the stream send another
+ */
+async ackListingBatch(streamId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("ack_listing_batch", { streamId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Stops a listing stream; a stream that's already finished is left alone
+ */
+async cancelListingStream(streamId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("cancel_listing_stream", { streamId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Registers the project's `trustedRoots` setting with the access policy
+ * 
+ * Called whenever the project opens or the setting changes; an empty list
+ * clears any previously trusted roots.
+ */
+async setTrustedRoots(projectPath: string, roots: string[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_trusted_roots", { projectPath, roots }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Creates a new content collection
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `collection_name` - Collection identifier (must be a valid JavaScript identifier)
+ * * `content_directory` - Optional content directory override (defaults to "src/content")
+ * * `first_entry_title` - If provided, creates a first entry with this title
+ * 
+ * # Returns
+ * The absolute path to the new collection directory
+ */
+async createCollection(projectPath: string, collectionName: string, contentDirectory: string | null, firstEntryTitle: string | null) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_collection", { projectPath, collectionName, contentDirectory, firstEntryTitle }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds a field to a collection's schema in the content config
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `collection_name` - The collection whose schema should be updated
+ * * `field` - The field to add
+ */
+async addSchemaField(projectPath: string, collectionName: string, field: NewSchemaField) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_schema_field", { projectPath, collectionName, field }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Renames a collection's key in the content config
+ * 
+ * Only the config is changed; the collection's directory and loader are left as-is.
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `from` - The current collection name
+ * * `to` - The new collection name
+ */
+async renameCollection(projectPath: string, from: string, to: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rename_collection", { projectPath, from, to }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Previews a content config edit without writing it
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `edit` - The edit to preview
+ * 
+ * # Returns
+ * A unified diff of the change for the user to confirm
+ */
+async previewConfigEdit(projectPath: string, edit: ConfigEdit) : Promise<Result<ConfigEditPreview, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("preview_config_edit", { projectPath, edit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists a remote collection's entries from Astro's data store
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `collection_name` - The collection's name in the content config
+ * 
+ * # Returns
+ * The entries with their loaded data as frontmatter; empty until Astro has run the
+ * collection's loader
+ */
+async loadRemoteCollection(projectPath: string, collectionName: string) : Promise<Result<FileEntry[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("load_remote_collection", { projectPath, collectionName }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Runs `astro sync` so custom loaders fetch their entries again, then lists the
+ * collection's entries
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `collection_name` - The collection's name in the content config
+ */
+async refreshRemoteCollection(projectPath: string, collectionName: string) : Promise<Result<FileEntry[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("refresh_remote_collection", { projectPath, collectionName }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Creates a new Astro content site from the bundled starter
+ * 
+ * Emits `new-project-progress` events as files are written and dependencies install.
+ * The frontend opens the returned path as the current project once this resolves.
+ * 
+ * # Arguments
+ * * `parent_directory` - Directory to create the project in
+ * * `project_name` - Name of the new project directory (lowercase, npm-safe)
+ * * `install_dependencies` - Whether to run `npm install` and `astro sync`
+ * 
+ * # Returns
+ * The absolute path to the new project
+ */
+async createNewAstroProject(parentDirectory: string, projectName: string, installDependencies: boolean) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_new_astro_project", { parentDirectory, projectName, installDependencies }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Writes the bundled sample project so the editor can be tried without an existing site
+ * 
+ * Nothing is installed, so this works offline. The frontend opens the returned path as
+ * the current project.
+ * 
+ * # Arguments
+ * * `parent_directory` - Directory to create the `astro-editor-sample` folder in
+ * 
+ * # Returns
+ * The absolute path to the sample project
+ */
+async createDemoProject(parentDirectory: string) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_demo_project", { parentDirectory }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async startWatchingProject(projectPath: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("start_watching_project", { projectPath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async startWatchingProjectWithContentDir(projectPath: string, contentDirectory: string | null, excludedDirectories: string[] | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("start_watching_project_with_content_dir", { projectPath, contentDirectory, excludedDirectories }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async stopWatchingProject(projectPath: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("stop_watching_project", { projectPath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Returns watcher event counts for a project, including events suppressed by exclusions
+ */
+async getWatcherStats(projectPath: string) : Promise<Result<WatcherStats, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_watcher_stats", { projectPath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Keeps the watcher from reporting the next change to a file
+ * 
+ * For writes the app makes itself outside `save_markdown_content`, which marks its
+ * own. The mark lapses after a few seconds if no change arrives.
+ */
+async ignoreNextWrite(path: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("ignore_next_write", { path }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Exports the project's content graph for visualization
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * * `format` - Whether `output` is DOT or JSON
+ * * `title_field` - Frontmatter field used for node labels
+ * * `series_field` - Frontmatter field naming an entry's series
+ * * `url_patterns` - Collection URL patterns (e.g. `{ "blog": "/writing/{slug}" }`) for
+ * resolving site-absolute links
+ */
+async exportContentGraph(projectPath: string, contentDirectory: string | null, format: GraphFormat, titleField: string, seriesField: string, urlPatterns: Partial<{ [key in string]: string }>) : Promise<Result<ContentGraph, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_content_graph", { projectPath, contentDirectory, format, titleField, seriesField, urlPatterns }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gathers every entry's dates, draft status and title, grouped by month
+ * 
+ * The content directory is the project's override from the project registry.
+ */
+async getContentCalendar(projectPath: string) : Promise<Result<ContentCalendar, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_content_calendar", { projectPath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists every internal link between entries in the project
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * * `title_field` - Frontmatter field wiki links can name entries by
+ * * `url_patterns` - Collection URL patterns (e.g. `{ "blog": "/writing/{slug}" }`) for
+ * resolving site-absolute links
+ */
+async getLinkGraph(projectPath: string, contentDirectory: string | null, titleField: string, urlPatterns: Partial<{ [key in string]: string }>) : Promise<Result<LinkGraph, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_link_graph", { projectPath, contentDirectory, titleField, urlPatterns }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists the entries that link to a file
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * * `file_path` - The file to find links to
+ * * `title_field` - Frontmatter field wiki links can name entries by
+ * * `url_patterns` - Collection URL patterns for resolving site-absolute links
+ * 
+ * # Returns
+ * One backlink per link, ordered by linking entry; empty if the file isn't an entry
+ */
+async getBacklinks(projectPath: string, contentDirectory: string | null, filePath: string, titleField: string, urlPatterns: Partial<{ [key in string]: string }>) : Promise<Result<Backlink[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_backlinks", { projectPath, contentDirectory, filePath, titleField, urlPatterns }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Checks the links in an entry, a collection or the whole project
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * * `file_path` - Check only this entry
+ * * `collection` - Check only this collection's entries (ignored with `file_path`)
+ * * `url_patterns` - Collection URL patterns (e.g. `{ "blog": "/writing/{slug}" }`) for
+ * checking site-absolute links to entries
+ * * `check_external` - Whether to request external URLs; otherwise they're skipped
+ * 
+ * # Returns
+ * Every link found, by file and line, with whether it works
+ */
+async checkLinks(projectPath: string, contentDirectory: string | null, filePath: string | null, collection: string | null, urlPatterns: Partial<{ [key in string]: string }>, checkExternal: boolean) : Promise<Result<CheckedLink[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_links", { projectPath, contentDirectory, filePath, collection, urlPatterns, checkExternal }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * A slug for a new entry titled `title`, following the project's slug rules and
+ * numbered (`-2`, `-3`...) past any slug already in the collection
+ */
+async generateSlug(title: string, collectionPath: string, settings: SlugSettings | null) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("generate_slug", { title, collectionPath, settings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Entries in a collection that share a slug, by slug
+ */
+async checkSlugConflicts(collectionPath: string) : Promise<Result<SlugConflict[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_slug_conflicts", { collectionPath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Creates a draft entry from a speech-to-text transcript
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `transcript_path` - The transcript file: plain text, `.srt` or `.vtt`
+ * * `collection_path` - The absolute path to the collection directory
+ * * `filename` - Name for the new entry, e.g. `episode-12.md`
+ * * `title` - The entry's title
+ * * `fields` - Frontmatter field names for the title, date and draft flag
+ * * `filler_words` - Words and phrases to remove; omit for the default list
+ */
+async ingestTranscript(projectPath: string, transcriptPath: string, collectionPath: string, filename: string, title: string, fields: TranscriptFields, fillerWords: string[] | null) : Promise<Result<IngestedTranscript, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("ingest_transcript", { projectPath, transcriptPath, collectionPath, filename, title, fields, fillerWords }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Parses a natural-language date into an ISO date (`YYYY-MM-DD`)
+ * 
+ * # Arguments
+ * * `input` - e.g. "tomorrow", "next tuesday", "in 2 weeks", "march 5"
+ * * `today` - The reference day as `YYYY-MM-DD`, i.e. today in the project's
+ * timezone; the system's local date when omitted
+ */
+async parseNaturalDate(input: string, today: string | null) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("parse_natural_date", { input, today }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Formats a date for display
+ * 
+ * # Arguments
+ * * `date` - A `YYYY-MM-DD` date; a time part is ignored
+ * * `style` - How to write it
+ * * `locale` - The project's `locale` setting (e.g. `en-GB`); the OS locale when `None`
+ * * `today` - Reference day for relative dates (`YYYY-MM-DD`, today in the project's
+ * timezone); the system date when `None`
+ */
+async formatDate(date: string, style: DateStyle, locale: string | null, today: string | null) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("format_date", { date, style, locale, today }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists drafts not modified in the last `days` days, oldest first
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * * `days` - Drafts untouched for at least this many days are returned
+ * * `fields` - Frontmatter fields for the title and draft flag
+ */
+async getStaleDrafts(projectPath: string, contentDirectory: string | null, days: number, fields: StaleDraftFields) : Promise<Result<StaleDraft[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_stale_drafts", { projectPath, contentDirectory, days, fields }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Enables or disables the weekly stale draft digest
+ * 
+ * # Arguments
+ * * `digest` - Digest settings for the open project; `None` turns the digest off
+ */
+async setStaleDraftDigest(digest: StaleDraftDigest | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_stale_draft_digest", { digest }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists the images in the project's assets directory, grouped by collection
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `assets_directory` - Assets directory override, relative to the project root
+ * * `options` - Collection and extension filters, and the page to return
+ */
+async listProjectAssets(projectPath: string, assetsDirectory: string | null, options: AssetListOptions) : Promise<Result<AssetPage, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_project_assets", { projectPath, assetsDirectory, options }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Finds images referenced in content that are missing on disk and, when `apply` is
+ * set, rewrites the references to files with the same name elsewhere in the project
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * * `assets_directory` - Optional assets directory override; `public` is always searched
+ * * `apply` - Whether to rewrite files; otherwise only reports the proposed repairs
+ */
+async repairAssetReferences(projectPath: string, contentDirectory: string | null, assetsDirectory: string | null, apply: boolean) : Promise<Result<AssetRepairReport, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("repair_asset_references", { projectPath, contentDirectory, assetsDirectory, apply }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Finds files in the assets directory that no content references
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * * `assets_directory` - Optional assets directory override
+ */
+async findOrphanedAssets(projectPath: string, contentDirectory: string | null, assetsDirectory: string | null) : Promise<Result<OrphanedAssetReport, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("find_orphaned_assets", { projectPath, contentDirectory, assetsDirectory }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Moves orphaned assets to the trash, skipping any that are referenced now
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * * `assets_directory` - Optional assets directory override
+ * * `paths` - Project-relative paths from `find_orphaned_assets` to delete
+ */
+async deleteOrphanedAssets(projectPath: string, contentDirectory: string | null, assetsDirectory: string | null, paths: string[], origin: string | null) : Promise<Result<OrphanedAssetDeletion, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_orphaned_assets", { projectPath, contentDirectory, assetsDirectory, paths, origin }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Returns the Markdown extensions enabled for a project
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `settings` - The project's `markdownFlavor` setting, overriding the Astro config
+ */
+async getMarkdownFlavor(projectPath: string, settings: MarkdownFlavorSettings | null) : Promise<Result<MarkdownFlavor, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_markdown_flavor", { projectPath, settings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Reports syntax in a document that the project's Markdown flavor won't render
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `file_path` - The absolute path to the document
+ * * `content` - The document's current content; frontmatter, if included, is skipped
+ * * `settings` - The project's `markdownFlavor` setting, overriding the Astro config
+ */
+async checkMarkdownFlavor(projectPath: string, filePath: string, content: string, settings: MarkdownFlavorSettings | null) : Promise<Result<FlavorIssue[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_markdown_flavor", { projectPath, filePath, content, settings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Reports prose characters in an MDX document that the MDX compiler would parse as
+ * code, without changing them. Plain Markdown files have none.
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `file_path` - The absolute path to the document
+ * * `content` - The document's current content; frontmatter, if included, is skipped
+ * * `imports` - The document's MDX imports, for the names expressions may use
+ */
+async checkMdxEscaping(projectPath: string, filePath: string, content: string, imports: string | null) : Promise<Result<UnescapedCharacter[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_mdx_escaping", { projectPath, filePath, content, imports }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Merges the frontmatter of a file with git conflict markers, writing the resolved
+ * file once every conflict has a choice
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `file_path` - The absolute path to the conflicted file
+ * * `resolutions` - Choices for conflicting fields, by field name; omit to only get
+ * the merge proposal
+ * * `body` - Choice for the body, when it differs between the sides
+ */
+async resolveFrontmatterConflict(projectPath: string, filePath: string, resolutions: Partial<{ [key in string]: ConflictChoice }> | null, body: ConflictChoice | null) : Promise<Result<FrontmatterMerge, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("resolve_frontmatter_conflict", { projectPath, filePath, resolutions, body }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Merges the editor's version of a file with the version now on disk
+ * 
+ * # Arguments
+ * * `base` - The file as the editor loaded it
+ * * `mine` - The file as the editor would save it (`attemptedContent` of a save conflict)
+ * * `theirs` - The file as it is on disk now (`diskContent` of a save conflict)
+ */
+async mergeFileVersions(base: string, mine: string, theirs: string) : Promise<Result<FileMerge, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("merge_file_versions", { base, mine, theirs }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Suggests which schema field each column of a CSV file maps to, for importing the
+ * rows as entries of a collection
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * * `collection_name` - The collection the rows will be imported into
+ * * `file_path` - The CSV/TSV file; its first row must be the header
+ * * `delimiter` - Optional delimiter; inferred from the data when omitted
+ */
+async suggestImportMapping(projectPath: string, contentDirectory: string | null, collectionName: string, filePath: string, delimiter: string | null) : Promise<Result<ImportMappingSuggestion, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("suggest_import_mapping", { projectPath, contentDirectory, collectionName, filePath, delimiter }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists processes the editor started that are still running
+ */
+async listManagedProcesses() : Promise<Result<ManagedProcessInfo[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_managed_processes") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Terminates a process the editor started, along with any processes it started
+ * 
+ * # Arguments
+ * * `id` - The registry ID from `list_managed_processes`
+ */
+async killManagedProcess(id: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("kill_managed_process", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Starts the project's dev server with `npm run dev`, unless one is already running
+ * 
+ * Resolves once Astro reports the URL it's listening on. A dev server already on the
+ * configured port, started outside the editor, is used as is.
+ */
+async startDevServer(projectPath: string) : Promise<Result<DevServerStatus, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("start_dev_server", { projectPath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Stops the dev server the editor started for a project
+ */
+async stopDevServer(projectPath: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("stop_dev_server", { projectPath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Reports whether the project's dev server is running, with its URL and recent output
+ */
+async getDevServerStatus(projectPath: string) : Promise<Result<DevServerStatus, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_dev_server_status", { projectPath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Works out where a content file renders on the dev server
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `file_path` - The entry to preview
+ * * `content_directory` - Optional content directory override
+ * * `url_pattern` - The collection's URL pattern (e.g. `/writing/{slug}`), if any
+ * 
+ * # Returns
+ * The entry's route, and its full URL when a dev server is running
+ */
+async getPreviewUrl(projectPath: string, filePath: string, contentDirectory: string | null, urlPattern: string | null) : Promise<Result<PreviewUrl, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_preview_url", { projectPath, filePath, contentDirectory, urlPattern }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Imports every image in a folder as assets and builds a gallery from them
+ * 
+ * # Arguments
+ * * `folder_path` - The folder of images (not searched recursively)
+ * * `project_path` - The absolute path to the project root
+ * * `collection` - The collection whose assets directory receives the images
+ * * `current_file_path` - The file the gallery is inserted into
+ * * `options` - Asset location, path style, maximum width and gallery component
+ */
+async importImageFolder(folderPath: string, projectPath: string, collection: string, currentFilePath: string, options: ImageFolderOptions) : Promise<Result<ImageGallery, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("import_image_folder", { folderPath, projectPath, collection, currentFilePath, options }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists entries still under embargo, soonest to lift first
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ */
+async listEmbargoedEntries(projectPath: string, contentDirectory: string | null) : Promise<Result<EmbargoedEntry[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_embargoed_entries", { projectPath, contentDirectory }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Sets the project watched for lifted embargoes
+ * 
+ * # Arguments
+ * * `target` - The open project; `None` stops watching
+ */
+async setEmbargoWatch(target: EmbargoWatchTarget | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_embargo_watch", { target }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Checks a file against its collection's publish gates and any embargo
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `file_path` - The absolute path to the file, as saved on disk
+ * * `collection_path` - The absolute path to the file's collection directory
+ * * `gates` - The collection's publish gates
+ * * `url_pattern` - The collection's URL pattern (e.g. `/writing/{slug}`), if any
+ */
+async checkPublishReadiness(projectPath: string, filePath: string, collectionPath: string, gates: PublishGate[], urlPattern: string | null) : Promise<Result<PublishReadiness, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_publish_readiness", { projectPath, filePath, collectionPath, gates, urlPattern }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Publishes a draft: clears its draft status and sets its publish date if empty
+ * 
+ * The draft field is the mapped one when the schema declares it (as a boolean or an
+ * enum with a `published`-like value), else `draft`, `isDraft`, `status` or `state`.
+ * The date field is found the same way from the mapped fields and `pubDate`,
+ * `publishDate`, `publishedDate` and `date`. Fields keep their schema order.
+ * 
+ * # Arguments
+ * * `complete_schema` - The collection's serialized complete schema
+ * * `draft_field` - The collection's mapped draft field
+ * * `date_fields` - The collection's mapped publish date fields, first match used
+ * * `date_formats` - How dates are saved in the collection
+ * * `rename` - Rename the file for its publish date if its collection is named by date;
+ * links to it are rewritten as by `rename_file_with_link_rewrite`
+ */
+async publishFile(filePath: string, projectRoot: string, completeSchema: string | null, draftField: string | null, dateFields: string[] | null, dateFormats: DateFormatSettings | null, rename: PublishRename | null, origin: string | null) : Promise<Result<PublishedFile, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("publish_file", { filePath, projectRoot, completeSchema, draftField, dateFields, dateFormats, rename, origin }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Fuzzy-matches a query against entry titles, filenames and headings across collections
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * * `query` - The text typed into the quick switcher
+ * * `limit` - Maximum number of matches (defaults to 50)
+ * 
+ * # Returns
+ * Matches ranked best-first, with character positions for highlighting
+ */
+async fuzzyFind(projectPath: string, contentDirectory: string | null, query: string, limit: number | null) : Promise<Result<FuzzyMatch[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("fuzzy_find", { projectPath, contentDirectory, query, limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Returns the entries matching a filter condition
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * * `collection` - Only match entries in this collection
+ * * `condition` - The filter to evaluate
+ * * `limit` - Maximum number of entries (defaults to 500)
+ */
+async queryCollection(projectPath: string, contentDirectory: string | null, collection: string | null, condition: FilterCondition, limit: number | null) : Promise<Result<FilterMatch[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("query_collection", { projectPath, contentDirectory, collection, condition, limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Reports the editor's current body for a file
+ * 
+ * Called when typing pauses rather than on every change, since each report sends the
+ * whole body; reports are also coalesced and only the latest content in each window is
+ * diffed. Call again after a save to emit a reset (zero-change) event.
+ * 
+ * # Arguments
+ * * `file_path` - The absolute path to the file being edited
+ * * `content` - The editor body (without frontmatter and imports)
+ * * `project_root` - The project the file must be in
+ */
+async reportContentChanged(filePath: string, content: string, projectRoot: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("report_content_changed", { filePath, content, projectRoot }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Estimates how long an entry takes to read
+ * 
+ * # Arguments
+ * * `content` - The body (without frontmatter and imports)
+ * * `frontmatter` - The entry's frontmatter, for its override field
+ * * `settings` - The project's `readingTime` setting
+ */
+async getReadingTime(content: string, frontmatter: Partial<{ [key in string]: JsonValue }> | null, settings: ReadingTimeSettings | null) : Promise<Result<ReadingTime, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_reading_time", { content, frontmatter, settings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Opens the daily note for a date, creating it from the template if it doesn't exist
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * * `pattern` - Filename pattern relative to the content directory (defaults to
+ * `journal/{YYYY}-{MM}-{DD}.md`)
+ * * `template_path` - Optional template file, relative to the project root
+ * * `date_field` - Frontmatter field for the default template's date (defaults to `date`)
+ * * `date` - `YYYY-MM-DD` to open; defaults to today
+ */
+async openDailyNote(projectPath: string, contentDirectory: string | null, pattern: string | null, templatePath: string | null, dateField: string | null, date: string | null) : Promise<Result<DailyNote, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_daily_note", { projectPath, contentDirectory, pattern, templatePath, dateField, date }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists the days in a month that have a daily note
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * * `pattern` - Filename pattern relative to the content directory
+ * * `year` - Calendar year
+ * * `month` - Month, 1-12
+ */
+async getDailyNoteCalendar(projectPath: string, contentDirectory: string | null, pattern: string | null, year: number, month: number) : Promise<Result<DailyNoteDay[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_daily_note_calendar", { projectPath, contentDirectory, pattern, year, month }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Returns intents that haven't been handed to the frontend yet
+ * 
+ * Each intent is returned once; call `complete_intent` with its id when done.
+ */
+async takePendingIntents() : Promise<Result<IntentRequest[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("take_pending_intents") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Reports the outcome of an intent and follows its x-callback-url, if any
+ * 
+ * # Arguments
+ * * `id` - The intent id from `take_pending_intents`
+ * * `result` - Passed to the `x-success` callback as `result` (e.g. a created file path)
+ * * `error` - Set when the intent failed; passed to `x-error` as `errorMessage`
+ */
+async completeIntent(id: string, result: string | null, error: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("complete_intent", { id, result, error }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Appends text to today's note in a collection of the open project
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the open project's root
+ * * `collection_path` - The absolute path to the collection directory, inside the project
+ * * `text` - Markdown to append as a new paragraph
+ * 
+ * # Returns
+ * The absolute path of the daily note
+ */
+async appendToDailyNote(projectPath: string, collectionPath: string, text: string) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("append_to_daily_note", { projectPath, collectionPath, text }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Starts the capture API for the open project, replacing any running instance
+ * 
+ * Clients on this machine (or the local network, with `allow_lan`) can then
+ * `GET /collections` and `POST /capture` notes and photos into today's note of a
+ * collection, authenticating with the returned token.
+ * 
+ * # Arguments
+ * * `options` - The project to capture into and how to serve the API
+ */
+async startCaptureServer(options: CaptureServerOptions) : Promise<Result<CaptureServerInfo, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("start_capture_server", { options }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Stops the capture API, if it's running
+ */
+async stopCaptureServer() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("stop_capture_server") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Records that a file was opened in the editor
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `file_path` - The absolute path to the opened file
+ */
+async recordFileOpened(projectPath: string, filePath: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("record_file_opened", { projectPath, filePath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Records that a file was saved with changes
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `file_path` - The absolute path to the saved file
+ */
+async recordFileEdited(projectPath: string, filePath: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("record_file_edited", { projectPath, filePath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Returns the project's most recently opened or edited files, newest first
+ * 
+ * Files that no longer exist are skipped.
+ */
+async getRecentFiles(projectPath: string, limit: number | null) : Promise<Result<FileUsageEntry[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_recent_files", { projectPath, limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Returns the project's most frequently used files
+ * 
+ * Files are ranked by open and edit counts (edits weigh double), decayed by time
+ * since last use so files that were busy months ago drift down the list.
+ */
+async getFrequentFiles(projectPath: string, limit: number | null) : Promise<Result<FileUsageEntry[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_frequent_files", { projectPath, limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Opens the preferences folder in the system's default file manager
+ */
+async openPreferencesFolder() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_preferences_folder") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Resets all preferences by deleting the app data directory and restarting the app
+ */
+async resetAllPreferences() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("reset_all_preferences") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getAppVersion() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_app_version") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getPlatformInfo() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_platform_info") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getAppInfo() : Promise<Result<AppInfo, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_app_info") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Runs the schema fixture tests
+ * 
+ * # Arguments
+ * * `fixtures_directory` - A directory of extra fixtures, one per subdirectory, run after
+ * the built-in ones
+ * 
+ * # Returns
+ * Each fixture's mismatches between the expected and merged schemas
+ */
+async runSchemaFixtureTests(fixturesDirectory: string | null) : Promise<Result<SchemaFixtureReport, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("run_schema_fixture_tests", { fixturesDirectory }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Validates frontmatter against its collection's merged schema before it's saved
+ * 
+ * # Arguments
+ * * `collection_name` - The collection the file belongs to
+ * * `frontmatter` - The frontmatter as it would be saved
+ * * `complete_schema` - The collection's serialized complete schema
+ * 
+ * # Returns
+ * Every required field, constraint and enum the frontmatter breaks, in schema order
+ */
+async validateFrontmatter(collectionName: string, frontmatter: Partial<{ [key in string]: JsonValue }>, completeSchema: string) : Promise<Result<FieldViolation[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("validate_frontmatter", { collectionName, frontmatter, completeSchema }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Creates a scratchpad in app data
+ * 
+ * # Arguments
+ * * `content` - Initial content, e.g. pasted text; omit for an empty scratchpad
+ */
+async createScratchpad(content: string | null) : Promise<Result<Scratchpad, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_scratchpad", { content }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Replaces a scratchpad's content
+ */
+async saveScratchpad(id: string, content: string) : Promise<Result<Scratchpad, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_scratchpad", { id, content }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists scratchpads, most recently saved first
+ */
+async listScratchpads() : Promise<Result<Scratchpad[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_scratchpads") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteScratchpad(id: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_scratchpad", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Writes a scratchpad into a collection as a new entry, then removes the scratchpad
+ * 
+ * # Arguments
+ * * `id` - The scratchpad to promote
+ * * `project_path` - The absolute path to the project root
+ * * `collection_path` - The absolute path to the collection directory
+ * * `filename` - Name for the new entry, e.g. `my-idea.md`
+ * * `title` - The entry's title; a matching `# title` heading at the top is removed
+ * * `fields` - Frontmatter field names for the title, date and draft flag
+ * 
+ * # Returns
+ * The new entry's path
+ */
+async promoteScratchpadToEntry(id: string, projectPath: string, collectionPath: string, filename: string, title: string, fields: ScratchpadEntryFields) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("promote_scratchpad_to_entry", { id, projectPath, collectionPath, filename, title, fields }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Resolves reference ids to display labels from the referenced collection
+ * 
+ * `collection_path` is the directory of a glob collection; without it the collection
+ * is looked up as a `file()` loader in content.config. Ids may be bare (`jane-doe`) or
+ * prefixed with the collection name. Ids that match no entry come back with
+ * `exists: false` and the id as their label.
+ */
+async resolveReferenceLabels(projectPath: string, collectionName: string, collectionPath: string | null, ids: string[], titleField: string | null) : Promise<Result<ReferenceLabel[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("resolve_reference_labels", { projectPath, collectionName, collectionPath, ids, titleField }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists files deleted from a project that can still be restored, newest first
+ */
+async listTrashedFiles(projectRoot: string) : Promise<Result<TrashedFile[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_trashed_files", { projectRoot }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Restores a trashed file to where it was deleted from
+ * 
+ * Fails rather than overwrite a file that has since been created at that path.
+ * 
+ * # Returns
+ * The restored file's path
+ */
+async restoreTrashedFile(id: string, projectRoot: string, origin: string | null) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("restore_trashed_file", { id, projectRoot, origin }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists the saved versions of a file, newest first
+ */
+async listFileVersions(filePath: string) : Promise<Result<FileVersion[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_file_versions", { filePath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Renders a unified diff from a saved version of a file to the file as it is on disk
+ */
+async diffFileVersion(filePath: string, versionId: string) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("diff_file_version", { filePath, versionId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Restores a file to a saved version
+ * 
+ * The file's current content is kept as a version first, so the restore can itself be
+ * undone.
+ * 
+ * # Returns
+ * The content hash of the restored file, to expect on the next save
+ */
+async restoreFileVersion(filePath: string, projectRoot: string, versionId: string, origin: string | null) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("restore_file_version", { filePath, projectRoot, versionId, origin }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Keeps a file's unsaved edits until the file is saved
+ * 
+ * # Arguments
+ * * `frontmatter` - The edited frontmatter, or `None` when only the content was edited
+ * * `base_hash` - The `content_hash` the file was loaded with
+ * * `encrypt` - Encrypt the draft (the `recovery.encrypt` preference)
+ */
+async saveDraft(filePath: string, projectRoot: string, content: string, frontmatter: Partial<{ [key in string]: JsonValue }> | null, baseHash: string | null, encrypt: boolean | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("save_draft", { filePath, projectRoot, content, frontmatter, baseHash, encrypt }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gets a file's unsaved edits, if it has any
+ */
+async getDraft(filePath: string, projectRoot: string) : Promise<Result<UnsavedDraft | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_draft", { filePath, projectRoot }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Throws away a file's unsaved edits
+ */
+async discardDraft(filePath: string, projectRoot: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("discard_draft", { filePath, projectRoot }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists the project's files with unsaved edits, most recently edited first
+ */
+async listUnsavedDrafts(projectRoot: string) : Promise<Result<UnsavedDraftSummary[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_unsaved_drafts", { projectRoot }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async openPathInIde(ideCommand: string, filePath: string) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_path_in_ide", { ideCommand, filePath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Opens a file at a line (and column) in the IDE set in preferences
+ * 
+ * For jumping to a parse error. An IDE command that isn't a template, for an editor
+ * that isn't known, opens the file without moving to the line.
+ */
+async openInIde(filePath: string, line: number, column: number | null) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_in_ide", { filePath, line, column }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists the known editors installed on the PATH, with an IDE command template for each
+ */
+async detectIdes() : Promise<Result<DetectedIde[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("detect_ides") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async scanMdxComponents(projectPath: string, mdxDirectory: string | null) : Promise<Result<MdxComponent[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("scan_mdx_components", { projectPath, mdxDirectory }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Finds every use of an MDX component in the project's content
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * * `component_name` - The component's tag name, e.g. `Callout`
+ */
+async findComponentUsages(projectPath: string, contentDirectory: string | null, componentName: string) : Promise<Result<ComponentUsage[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("find_component_usages", { projectPath, contentDirectory, componentName }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Counts how often each MDX component is used and lists unused ones
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * * `mdx_directory` - Optional MDX components directory override
+ */
+async componentUsageReport(projectPath: string, contentDirectory: string | null, mdxDirectory: string | null) : Promise<Result<ComponentUsageReport, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("component_usage_report", { projectPath, contentDirectory, mdxDirectory }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Suggests an MDX component embed for a pasted URL
+ * 
+ * Only `.mdx` files get component suggestions; other files, unrecognised URLs and
+ * providers without a matching component fall back to a Markdown link.
+ * 
+ * # Arguments
+ * * `url` - The pasted URL
+ * * `project_path` - The absolute path to the project root
+ * * `file_path` - The absolute path of the file being edited
+ * * `mdx_directory` - Optional MDX components directory override
+ * * `use_path_aliases` - Import via a `tsconfig.json` alias when one covers the component
+ */
+async suggestEmbed(url: string, projectPath: string, filePath: string, mdxDirectory: string | null, usePathAliases: boolean) : Promise<Result<EmbedSuggestion, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("suggest_embed", { url, projectPath, filePath, mdxDirectory, usePathAliases }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Lists fenced code block languages used in a collection and flags unknown ones
+ * 
+ * # Arguments
+ * * `collection_path` - The absolute path to the collection directory
+ */
+async auditCodeBlocks(collectionPath: string) : Promise<Result<CodeBlockAudit, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("audit_code_blocks", { collectionPath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Rewrites code block languages across a collection per an alias mapping
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `collection_path` - The absolute path to the collection directory
+ * * `mapping` - Languages to rewrite, e.g. `{ "js": "javascript" }`
+ */
+async normalizeCodeBlockLanguages(projectPath: string, collectionPath: string, mapping: Partial<{ [key in string]: string }>) : Promise<Result<CodeBlockFixResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("normalize_code_block_languages", { projectPath, collectionPath, mapping }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Validates `$...$` and `$$...$$` math in Markdown content
+ * 
+ * # Arguments
+ * * `content` - The editor content (offsets in the result are relative to it)
+ * 
+ * # Returns
+ * Diagnostics ordered by position; empty when all math looks well-formed
+ */
+async validateMathBlocks(content: string) : Promise<Result<MathDiagnostic[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("validate_math_blocks", { content }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Checks the spelling of the prose in a Markdown document
+ * 
+ * # Arguments
+ * * `content` - The document (offsets in the result are relative to it)
+ * * `lang` - Dictionary to check against, e.g. `en_US` or `en-GB`
+ * * `project_path` - Project whose dictionary of accepted words to use, if any
+ * 
+ * # Returns
+ * Misspelled words in document order, with suggested corrections
+ */
+async checkText(content: string, lang: string, projectPath: string | null) : Promise<Result<Misspelling[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_text", { content, lang, projectPath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Adds a word to the project's dictionary so it's no longer reported as misspelled
+ */
+async addToProjectDictionary(projectPath: string, word: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_to_project_dictionary", { projectPath, word }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Expands includes in a document body for preview and export
+ * 
+ * Includes that can't be expanded are left as written and reported in `problems`.
+ * 
+ * # Arguments
+ * * `file_path` - The document's file, which relative include paths start from
+ * * `content` - The document body, without frontmatter
+ * * `max_depth` - How many includes deep to expand (defaults to 8)
+ */
+async resolveTransclusions(projectPath: string, filePath: string, content: string, settings: TransclusionSettings | null, maxDepth: number | null) : Promise<Result<ResolvedTransclusions, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("resolve_transclusions", { projectPath, filePath, content, settings, maxDepth }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Reports includes in a document that point at missing files, leave the project,
+ * form a cycle or nest too deep
+ */
+async lintTransclusions(projectPath: string, filePath: string, content: string, settings: TransclusionSettings | null) : Promise<Result<TransclusionProblem[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("lint_transclusions", { projectPath, filePath, content, settings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Summarises project activity over a date range
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override (defaults to `src/content`)
+ * * `assets_directory` - Optional assets directory override (defaults to `src/assets`)
+ * * `from` - First day of the range, `YYYY-MM-DD`
+ * * `to` - Last day of the range (inclusive), `YYYY-MM-DD`
+ * * `mappings` - Title, publish date and draft field names (defaults to `title`,
+ * `pubDate`/`date`/`publishedDate` and `draft`)
+ * 
+ * # Returns
+ * The report as structured data plus a Markdown rendering
+ */
+async exportActivityReport(projectPath: string, contentDirectory: string | null, assetsDirectory: string | null, from: string, to: string, mappings: ReportFieldMappings | null) : Promise<Result<ActivityReport, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_activity_report", { projectPath, contentDirectory, assetsDirectory, from, to, mappings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Reports the project's branch, worktree and content branch state
+ * 
+ * Not a repository (or no git installed) comes back as `isRepository: false`.
+ */
+async getGitBranchState(projectPath: string, settings: ContentBranchSettings | null) : Promise<Result<GitBranchState, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_git_branch_state", { projectPath, settings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Checks out the configured content branch, creating it from the current commit
+ * when it doesn't exist yet
+ * 
+ * Uncommitted changes come along, unless they conflict with the branch, in which
+ * case git's error is returned and nothing changes.
+ * 
+ * # Returns
+ * The project's state after switching
+ */
+async switchContentBranch(projectPath: string, settings: ContentBranchSettings) : Promise<Result<GitBranchState, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("switch_content_branch", { projectPath, settings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Collects TODO/FIXME/HACK notes from every collection in a project
+ * 
+ * # Arguments
+ * * `project_path` - The absolute path to the project root
+ * * `content_directory` - Optional content directory override
+ * 
+ * # Returns
+ * Notes ordered by file path, then line
+ */
+async collectTodos(projectPath: string, contentDirectory: string | null) : Promise<Result<TodoItem[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("collect_todos", { projectPath, contentDirectory }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Converts CSV/TSV text or a `.csv`/`.tsv` file into an aligned GFM table
+ * 
+ * # Arguments
+ * * `text` - Delimited text, e.g. from the clipboard (ignored when `file_path` is set)
+ * * `file_path` - Optional path to a CSV/TSV file to read instead
+ * * `delimiter` - Optional delimiter; inferred from the data when omitted
+ * * `has_header` - Whether the first row is a header; inferred when omitted
+ * * `flavor` - The project's Markdown flavor; an HTML table is produced when GFM
+ * tables are disabled
+ */
+async convertCsvToTable(text: string | null, filePath: string | null, delimiter: string | null, hasHeader: boolean | null, flavor: MarkdownFlavor | null) : Promise<Result<MarkdownTable, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("convert_csv_to_table", { text, filePath, delimiter, hasHeader, flavor }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async copyTextToClipboard(text: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("copy_text_to_clipboard", { text }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Fetch release notes from GitHub Releases API for all versions between
+ * current_version (exclusive) and new_version (inclusive).
+ * Returns combined markdown bodies in reverse chronological order.
+ */
+async fetchReleaseNotes(currentVersion: string, newVersion: string) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("fetch_release_notes", { currentVersion, newVersion }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Gets the release channel updates come from
+ */
+async getUpdateChannel() : Promise<Result<UpdateChannel, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_update_channel") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Switches between stable and beta releases
+ * 
+ * Forgets any update found on the previous channel; call `get_update_info` again to
+ * check the new one.
+ * 
+ * # Arguments
+ * * `channel` - The channel to follow
+ */
+async setUpdateChannel(channel: UpdateChannel) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_update_channel", { channel }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Checks the current channel's release feed for a newer version
+ * 
+ * # Returns
+ * The channel and running version, with the available update (including release
+ * notes for every version since the running one) if there is one
+ */
+async getUpdateInfo() : Promise<Result<UpdateInfo, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_update_info") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Downloads and installs the update found by `get_update_info`
+ * 
+ * Emits `update-download-progress` while downloading. Relaunch the app afterwards to
+ * run the new version.
+ */
+async installUpdate() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("install_update") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Downloads the update found by `get_update_info` now and installs it when the app
+ * quits, so it's running from the next launch
+ * 
+ * Emits `update-download-progress` while downloading.
+ */
+async deferUpdate() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("defer_update") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Detect the user's configured UI font on Linux.
+ * 
+ * Tries gsettings (GNOME/Cinnamon/Budgie/XFCE) first, then falls back to
+ * reading KDE's kdeglobals config file. Returns None if neither works or
+ * if not running on Linux.
+ */
+async getLinuxUiFont() : Promise<Result<string | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_linux_ui_font") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+}
+}
+
+/** user-defined events **/
+
+
+
+/** user-defined constants **/
+
+
+
+/** user-defined types **/
+
+/**
+ * Activity in a project over a date range
+ */
+export type ActivityReport = { 
+/**
+ * First day of the range, `YYYY-MM-DD`
+ */
+from: string; 
+/**
+ * Last day of the range (inclusive), `YYYY-MM-DD`
+ */
+to: string; 
+/**
+ * True if created/updated/words/assets came from git history
+ */
+gitAvailable: boolean; 
+/**
+ * Non-draft entries with a publish date in the range
+ */
+published: ReportEntry[]; created: ReportEntry[]; updated: ReportEntry[]; 
+/**
+ * Words added to content files; `None` without git history
+ */
+wordsWritten: number | null; 
+/**
+ * Project-relative paths of assets added in the range
+ */
+newAssets: string[]; 
+/**
+ * The report rendered as Markdown
+ */
+markdown: string }
+export type AppInfo = { version: string; platform: string }
+/**
+ * The images on a page that belong to one collection
+ */
+export type AssetGroup = { 
+/**
+ * The collection subdirectory; `None` for images directly in the assets directory
+ */
+collection: string | null; assets: ProjectAsset[] }
+/**
+ * Which images to list
+ */
+export type AssetListOptions = { 
+/**
+ * Only images in this collection's subdirectory
+ */
+collection?: string | null; 
+/**
+ * Only these extensions (case-insensitive, without the dot)
+ */
+extensions?: string[] | null; 
+/**
+ * Images to skip, for paging
+ */
+offset?: number | null; 
+/**
+ * Images to return; 100 when omitted
+ */
+limit?: number | null }
+/**
+ * A page of images
+ */
+export type AssetPage = { groups: AssetGroup[]; 
+/**
+ * Images matching the filters, across all pages
+ */
+total: number; hasMore: boolean }
+/**
+ * A broken image reference and how to fix it
+ */
+export type AssetReferenceRepair = { filePath: string; 
+/**
+ * The reference as written in the file
+ */
+reference: string; 
+/**
+ * The rewritten reference, when the image could be found unambiguously
+ */
+replacement: string | null; 
+/**
+ * Project-relative paths of files with the same name
+ */
+candidates: string[] }
+/**
+ * Broken image references found, and what was rewritten when applied
+ */
+export type AssetRepairReport = { repairs: AssetReferenceRepair[]; filesChanged: number; referencesChanged: number }
+/**
+ * A project directory and the URL its files are deployed at
+ */
+export type AssetUrlMapping = { 
+/**
+ * Directory relative to the project root, e.g. `src/assets`
+ */
+localPath: string; 
+/**
+ * URL the directory's files are served from, e.g. `https://cdn.example.com/assets`
+ */
+baseUrl: string }
+/**
+ * HTML with local asset references replaced by deployed URLs
+ */
+export type AssetUrlRewrite = { html: string; 
+/**
+ * References rewritten to a mapped URL
+ */
+rewritten: number; 
+/**
+ * Local references no mapping covers, as written in the HTML
+ */
+unmapped: string[] }
+/**
+ * What a write-class command did
+ */
+export type AuditAction = "write" | "create" | "delete" | "rename" | "copy"
+/**
+ * A recorded file operation
+ */
+export type AuditEntry = { 
+/**
+ * RFC 3339 UTC timestamp
+ */
+timestamp: string; action: AuditAction; path: string; 
+/**
+ * Destination for renames and copies
+ */
+newPath: string | null; 
+/**
+ * The feature that asked for the write, e.g. "autosave" or "daily-notes"
+ */
+feature: string }
+/**
+ * A newer version on the current channel
+ */
+export type AvailableUpdate = { version: string; 
+/**
+ * Publish date from the release feed
+ */
+date: string | null; 
+/**
+ * Release notes for every version since the running one, newest first (Markdown)
+ */
+releaseNotes: string; 
+/**
+ * Already downloaded and waiting to install when the app quits
+ */
+deferred: boolean }
+/**
+ * An entry linking to the file whose backlinks were requested
+ */
+export type Backlink = { 
+/**
+ * The linking entry, ready to open
+ */
+source: FileEntry; kind: LinkKind; line: number | null; context: string }
+/**
+ * A saved backup of a project's content directory
+ */
+export type BackupInfo = { id: string; projectRoot: string; 
+/**
+ * The directory backed up, relative to the project root
+ */
+contentDirectory: string; 
+/**
+ * What took the backup: "scheduled", "manual", "before-restore" or the bulk
+ * operation, e.g. "asset-repair"
+ */
+reason: string; 
+/**
+ * RFC 3339 UTC timestamp
+ */
+createdAt: string; fileCount: number; 
+/**
+ * Size of the archive
+ */
+sizeBytes: number }
+/**
+ * Outcome of restoring a backup
+ */
+export type BackupRestoreResult = { 
+/**
+ * Files written from the backup
+ */
+restored: string[]; 
+/**
+ * Backup of the content as it was just before restoring
+ */
+safetyBackup: BackupInfo }
+/**
+ * Backup settings for a project
+ */
+export type BackupSettings = { projectPath: string; contentDirectory: string | null; 
+/**
+ * Minutes between scheduled backups; `None` only backs up before bulk operations
+ */
+intervalMinutes: number | null }
+/**
+ * A run of body lines in a merge
+ */
+export type BodyHunk = 
+/**
+ * Lines merged without conflict
+ */
+{ kind: "merged"; text: string } | 
+/**
+ * Lines both versions changed differently
+ */
+{ kind: "conflict"; base: string; mine: string; theirs: string }
+/**
+ * The most recent bulk operation that can be undone
+ */
+export type BulkOperationSummary = { 
+/**
+ * The operation, e.g. "code-block-normalize"
+ */
+operation: string; 
+/**
+ * RFC 3339 UTC timestamp
+ */
+createdAt: string; fileCount: number }
+/**
+ * Outcome of undoing a bulk operation
+ */
+export type BulkUndoResult = { operation: string; 
+/**
+ * Files restored to their original content
+ */
+restored: string[]; 
+/**
+ * Files left alone because they changed after the bulk operation
+ */
+skipped: string[] }
+/**
+ * What a calendar date records
+ */
+export type CalendarDateKind = 
+/**
+ * A publish date field, e.g. `pubDate`
+ */
+"published" | 
+/**
+ * A field named for updates, e.g. `updatedDate` or `lastModified`
+ */
+"updated" | "other"
+/**
+ * One date of one entry
+ */
+export type CalendarItem = { filePath: string; collection: string; title: string; isDraft: boolean; 
+/**
+ * The frontmatter field the date is from
+ */
+field: string; kind: CalendarDateKind; 
+/**
+ * `YYYY-MM-DD`
+ */
+date: string; 
+/**
+ * A publish date after today
+ */
+scheduled: boolean }
+/**
+ * The dates that fall in one month
+ */
+export type CalendarMonth = { 
+/**
+ * `YYYY-MM`
+ */
+month: string; 
+/**
+ * In date order
+ */
+items: CalendarItem[] }
+/**
+ * Where a running capture API can be reached
+ */
+export type CaptureServerInfo = { 
+/**
+ * Base URL for clients, e.g. `http://192.168.1.20:4870`
+ */
+url: string; token: string }
+/**
+ * How the capture API is served
+ */
+export type CaptureServerOptions = { projectPath: string; contentDirectory: string | null; 
+/**
+ * Where captured photos are stored, relative to the project root
+ */
+assetsDirectory: string | null; port: number; 
+/**
+ * Token clients send as `Authorization: Bearer <token>`; generated when omitted
+ */
+token: string | null; 
+/**
+ * Listen on the local network as well as this machine; other networks are refused
+ */
+allowLan: boolean }
+/**
+ * The changes a previewed command would make
+ */
+export type ChangePreview = { 
+/**
+ * Changes in the order the command would make them
+ */
+changes: PlannedChange[]; 
+/**
+ * Why the command would fail; `changes` holds what it would do before failing
+ */
+error: string | null }
+/**
+ * A link and whether it works
+ */
+export type CheckedLink = { filePath: string; 
+/**
+ * 1-based line of the link
+ */
+line: number; target: string; kind: CheckedLinkKind; status: LinkStatus; 
+/**
+ * The response status, for external links that got one
+ */
+httpStatus: number | null; 
+/**
+ * Why the link is broken or wasn't checked
+ */
+message: string | null }
+export type CheckedLinkKind = 
+/**
+ * Another file or a page on the site
+ */
+"internal" | 
+/**
+ * An image or other file the page embeds or links to
+ */
+"asset" | "external"
+export type CodeBlockAudit = { 
+/**
+ * Languages used, most common first
+ */
+languages: LanguageUsage[]; unknownBlocks: UnknownCodeBlock[]; 
+/**
+ * Code blocks with no language at all
+ */
+unlabelledCount: number }
+export type CodeBlockFixResult = { filesChanged: number; blocksChanged: number; changedFiles: string[] }
+/**
+ * A frontmatter value that doesn't match its schema type, and what it should be
+ */
+export type CoercionSuggestion = { 
+/**
+ * Field path; nested fields use dots (`seo.noindex`)
+ */
+field: string; 
+/**
+ * The schema type, e.g. `boolean`
+ */
+expectedType: string; current: JsonValue; suggested: JsonValue }
+/**
+ * How titles and file names are ordered in listings
+ */
+export type Collation = 
+/**
+ * Embedded numbers compare by value (`post-2` before `post-10`), ignoring case
+ * and accents
+ */
+"natural" | 
+/**
+ * Character by character, ignoring case and accents
+ */
+"alphabetical" | 
+/**
+ * Unicode code point order
+ */
+"ordinal"
+export type Collection = { name: string; path: string; complete_schema?: string | null; 
+/**
+ * Defined with a custom loader (a CMS or API) rather than `glob()` or `file()`, so
+ * entries come from Astro's data store and are read-only
+ */
+remote?: boolean; 
+/**
+ * Entry globs from a `glob()` loader, relative to `path` (`!`-prefixed ones
+ * exclude); without them every Markdown file under `path` is an entry
+ */
+pattern?: string[] | null }
+export type ColorScheme = "light" | "dark"
+/**
+ * The suggested field for one CSV column
+ */
+export type ColumnMapping = { 
+/**
+ * The column header
+ */
+column: string; 
+/**
+ * 0-based column position
+ */
+index: number; columnType: ColumnType; sampleValues: string[]; 
+/**
+ * The suggested field, when one matches well enough
+ */
+suggestion: FieldMatch | null; 
+/**
+ * Other plausible fields, best first, including ones taken by other columns
+ */
+alternatives: FieldMatch[] }
+/**
+ * What a CSV column's values look like
+ */
+export type ColumnType = 
+/**
+ * No values in the sampled rows
+ */
+"empty" | "boolean" | "integer" | "number" | "date" | "url" | "email" | 
+/**
+ * Several values separated by `;`, `|` or `, `
+ */
+"list" | "text"
+export type ComponentFramework = "astro" | "react" | "vue" | "svelte"
+/**
+ * A component tag in an entry
+ */
+export type ComponentUsage = { component: string; filePath: string; 
+/**
+ * 1-based line the tag starts on
+ */
+line: number; 
+/**
+ * Attributes on the tag, as written (spreads aren't included)
+ */
+props: string[] }
+export type ComponentUsageReport = { 
+/**
+ * Every component in the MDX directory, most used first
+ */
+components: ComponentUsageSummary[]; 
+/**
+ * Components no entry uses
+ */
+unused: string[] }
+/**
+ * How much one component is used
+ */
+export type ComponentUsageSummary = { name: string; 
+/**
+ * The component file, relative to the project root
+ */
+componentPath: string; usageCount: number; 
+/**
+ * Entries using the component at least once
+ */
+fileCount: number }
+/**
+ * A targeted edit to the content config
+ */
+export type ConfigEdit = 
+/**
+ * Add a new collection with a starter schema
+ */
+{ kind: "insertCollection"; name: string; contentDirectory: string | null } | 
+/**
+ * Add a field to a collection's schema
+ */
+{ kind: "insertField"; collection: string; field: NewSchemaField } | 
+/**
+ * Rename a collection's key in the exported collections object
+ */
+{ kind: "renameCollection"; from: string; to: string }
+/**
+ * Result of a dry-run config edit
+ */
+export type ConfigEditPreview = { 
+/**
+ * Config path relative to the project root
+ */
+configPath: string; 
+/**
+ * Whether the edit would create the config file
+ */
+isNewFile: boolean; 
+/**
+ * Unified diff of the change
+ */
+diff: string }
+/**
+ * How to resolve a conflicting field or body
+ */
+export type ConflictChoice = 
+/**
+ * Keep the version from the current branch
+ */
+{ kind: "ours" } | 
+/**
+ * Keep the version being merged in
+ */
+{ kind: "theirs" } | 
+/**
+ * Use a different value; for the body this must be a string
+ */
+{ kind: "value"; value: JsonValue }
+/**
+ * The project's `contentBranch` setting
+ */
+export type ContentBranchSettings = { 
+/**
+ * Branch content is edited on, e.g. `content`
+ */
+branch?: string | null; 
+/**
+ * Warn when editing on the default branch instead
+ */
+require?: boolean | null }
+/**
+ * Every entry's dates across the project
+ */
+export type ContentCalendar = { 
+/**
+ * Oldest first
+ */
+months: CalendarMonth[]; undatedDrafts: UndatedDraft[] }
+/**
+ * The project's content graph, with the rendered export
+ */
+export type ContentGraph = { nodes: GraphNode[]; edges: GraphEdge[]; clusterCount: number; 
+/**
+ * Entries with no connections at all
+ */
+isolated: string[]; 
+/**
+ * The graph as DOT or JSON
+ */
+output: string }
+/**
+ * A saved crash report, for listing on a debug screen
+ */
+export type CrashReportSummary = { fileName: string; 
+/**
+ * When the report was written, as `YYYY-MM-DD HH:MM:SS` local time
+ */
+timestamp: string; 
+/**
+ * The error message, if the report has one
+ */
+error: string | null; 
+/**
+ * The action that failed, e.g. "save"
+ */
+action: string | null; 
+/**
+ * The full report, including the backend snapshot
+ */
+report: JsonValue }
+/**
+ * A daily note opened (and possibly created) by `open_daily_note`
+ */
+export type DailyNote = { filePath: string; 
+/**
+ * The note's date as `YYYY-MM-DD`
+ */
+date: string; 
+/**
+ * True if the note didn't exist and was created from the template
+ */
+created: boolean }
+/**
+ * A day in a month that has a daily note
+ */
+export type DailyNoteDay = { 
+/**
+ * `YYYY-MM-DD`
+ */
+date: string; filePath: string; 
+/**
+ * Words in the note body, excluding frontmatter
+ */
+wordCount: number }
+/**
+ * How a frontmatter date is written on save
+ */
+export type DateFormat = 
+/**
+ * Datetimes are cut to the date, e.g. `2024-01-15`
+ */
+"dateOnly" | 
+/**
+ * Dates are kept as written, as quoted strings, e.g. `'2024-01-15T18:30:00Z'`
+ */
+"dateTime" | 
+/**
+ * Dates are kept as written and unquoted, so YAML reads them as timestamps
+ */
+"timestamp"
+/**
+ * The `dateFormats` setting
+ */
+export type DateFormatSettings = { 
+/**
+ * Format for dates in fields not listed in `fields` (defaults to `dateOnly`)
+ */
+default?: DateFormat | null; 
+/**
+ * Formats by field name, with nested fields as dotted paths (e.g. `event.start`)
+ */
+fields?: Partial<{ [key in string]: DateFormat }> | null }
+/**
+ * How a date is written
+ */
+export type DateStyle = 
+/**
+ * Numeric, in the locale's order (`05/01/2025`)
+ */
+"short" | 
+/**
+ * Abbreviated month (`5 Jan 2025`, `Jan 5, 2025`)
+ */
+"medium" | 
+/**
+ * Full month name (`5 January 2025`)
+ */
+"long" | 
+/**
+ * Weekday and full month name (`Sunday, 5 January 2025`)
+ */
+"full" | 
+/**
+ * Relative to today (`yesterday`, `in 3 weeks`)
+ */
+"relative"
+/**
+ * An editor whose command is on the PATH
+ */
+export type DetectedIde = { name: string; command: string; 
+/**
+ * An IDE command that opens a file at a line, e.g. `code --goto {path}:{line}:{column}`
+ */
+template: string }
+/**
+ * A project's dev server, as far as the editor knows
+ */
+export type DevServerStatus = { 
+/**
+ * Whether a dev server is reachable for the project
+ */
+running: boolean; 
+/**
+ * Whether the editor started it, and so can stop it
+ */
+managed: boolean; 
+/**
+ * The server's origin, e.g. `http://localhost:4321`
+ */
+url: string | null; 
+/**
+ * Recent stdout and stderr of a server the editor started, oldest first
+ */
+output: string[] }
+export type DirectoryInfo = { name: string; relative_path: string; full_path: string }
+export type DirectoryScanResult = { subdirectories: DirectoryInfo[]; files: FileEntry[] }
+/**
+ * A directory in a collection with everything under it, from `scan_collection_tree`
+ */
+export type DirectoryTree = { 
+/**
+ * `None` for the collection root
+ */
+directory: DirectoryInfo | null; files: FileEntry[]; subdirectories: DirectoryTree[]; 
+/**
+ * Past the requested depth, so `files` and `subdirectories` weren't scanned
+ */
+truncated: boolean }
+/**
+ * Frontmatter changes made to the copy by `duplicate_file`
+ */
+export type DuplicateAdjustments = { 
+/**
+ * Fields to set, e.g. `draft: true`
+ */
+set: Partial<{ [key in string]: JsonValue }>; 
+/**
+ * Fields to remove, e.g. `pubDate`
+ */
+clear: string[]; 
+/**
+ * A string field to append " Copy" to, usually the title
+ */
+appendCopyTo: string | null }
+/**
+ * Where an entry's embargo time comes from
+ */
+export type EmbargoSource = "frontmatter" | "sidecar"
+/**
+ * The project the background task watches
+ */
+export type EmbargoWatchTarget = { projectPath: string; contentDirectory: string | null }
+/**
+ * An entry with an embargo
+ */
+export type EmbargoedEntry = { filePath: string; collection: string; 
+/**
+ * The embargo time, RFC 3339 in UTC
+ */
+embargoUntil: string; source: EmbargoSource }
+/**
+ * What to insert for a pasted URL
+ */
+export type EmbedSuggestion = { 
+/**
+ * The recognised provider, e.g. "youtube"; `None` for unrecognised URLs
+ */
+provider: string | null; 
+/**
+ * The MDX component used; `None` when falling back to a link
+ */
+component: string | null; 
+/**
+ * Text to insert: the component tag, or a Markdown link
+ */
+snippet: string; 
+/**
+ * Import statement the component needs, e.g. `import YouTube from '../../components/mdx/YouTube.astro'`
+ */
+import: string | null }
+/**
+ * How escaped characters are written
+ */
+export type EscapeStyle = 
+/**
+ * `\{`, `\}` and `\<`
+ */
+"backslash" | 
+/**
+ * `&#123;`, `&#125;` and `&lt;`
+ */
+"entity"
+/**
+ * A frontmatter field both sides changed differently
+ */
+export type FieldConflict = { field: string; 
+/**
+ * `None` when the field was removed on that side
+ */
+ours: JsonValue | null; theirs: JsonValue | null; 
+/**
+ * The value before either change, when the conflict includes the merge base
+ */
+base: JsonValue | null }
+/**
+ * Display-ready documentation for a frontmatter field
+ */
+export type FieldDocs = { 
+/**
+ * The field path that was requested
+ */
+fieldPath: string; 
+/**
+ * Collection whose schema defines the field
+ */
+collectionName: string; label: string; 
+/**
+ * Human-readable type, e.g. "array of string" or "reference to authors"
+ */
+typeDescription: string; required: boolean; description?: string | null; markdownDescription?: string | null; 
+/**
+ * Constraints as short sentences, e.g. "At most 160 characters"
+ */
+constraints: string[]; defaultValue?: string | null; 
+/**
+ * Collection the field references, for reference fields
+ */
+referenceCollection?: string | null; 
+/**
+ * Set when the docs come from a referenced collection rather than the one requested
+ */
+inheritedFrom?: string | null }
+/**
+ * The project's `fieldGroups` setting
+ */
+export type FieldGroupSettings = { 
+/**
+ * Group paths in display order; groups not listed follow in schema order
+ */
+order?: string[] | null; 
+/**
+ * Group paths that start collapsed
+ */
+collapsed?: string[] | null; 
+/**
+ * Start every group collapsed
+ */
+collapseAll?: boolean | null }
+/**
+ * A schema field a column could map to
+ */
+export type FieldMatch = { field: string; 
+/**
+ * 0 to 1; name similarity weighted by how well the values fit the field's type
+ */
+confidence: number }
+/**
+ * How a frontmatter field is compared
+ */
+export type FieldOperator = "equals" | "notEquals" | "greaterThan" | "lessThan" | 
+/**
+ * The field is set and not empty; the value is ignored
+ */
+"exists" | 
+/**
+ * The field is missing or empty; the value is ignored
+ */
+"missing"
+/**
+ * A frontmatter value that breaks one of its field's constraints
+ */
+export type FieldViolation = { 
+/**
+ * Field path; nested fields use dots (`seo.title`)
+ */
+field: string; kind: ViolationKind; 
+/**
+ * Shown under the field, e.g. "Must be at most 60 characters"
+ */
+message: string }
+/**
+ * A change to a Markdown file in a watched project
+ */
+export type FileChangeEvent = { kind: FileChangeKind; path: string; 
+/**
+ * Where a renamed file was
+ */
+oldPath?: string | null; 
+/**
+ * The collection the file is in, if any
+ */
+collection: string | null }
+/**
+ * What happened to a file over one debounced batch of events
+ */
+export type FileChangeKind = "created" | "modified" | "deleted" | "renamed"
+export type FileEntry = { id: string; path: string; name: string; extension: string; collection: string; last_modified: number | null; frontmatter: Partial<{ [key in string]: JsonValue }> | null; 
+/**
+ * Astro's content digest, when the frontmatter came from its data store
+ */
+digest?: string | null; 
+/**
+ * The title field's value, or the body's first `# ` heading
+ */
+title?: string | null; 
+/**
+ * Prose words in the body, as counted for reading time
+ */
+word_count?: number | null; 
+/**
+ * Whether the draft field is `true`; unset when the frontmatter couldn't be read
+ */
+is_draft?: boolean | null }
+/**
+ * Error returned when a file can't be loaded into the editor
+ */
+export type FileLoadError = 
+/**
+ * The file exceeds the size limit; retry with `allow_large_file` or edit frontmatter only
+ */
+{ kind: "tooLarge"; sizeBytes: number; limitBytes: number } | 
+/**
+ * The frontmatter isn't valid YAML or isn't closed; `raw_frontmatter` holds the lines
+ * after the opening `---` so they can be shown with the broken line highlighted
+ */
+{ kind: "invalidFrontmatter"; error: FrontmatterError; rawFrontmatter: string } | 
+/**
+ * Any other failure (missing file, path outside project)
+ */
+{ kind: "failed"; message: string }
+/**
+ * The result of merging two versions of a file
+ */
+export type FileMerge = { 
+/**
+ * The merged file; `None` while anything conflicts
+ */
+merged: string | null; 
+/**
+ * Frontmatter fields merged without conflict
+ */
+frontmatter: Partial<{ [key in string]: JsonValue }>; 
+/**
+ * Frontmatter fields both versions changed differently (`ours` is the editor's
+ * version, `theirs` the one on disk)
+ */
+fieldConflicts: FieldConflict[]; 
+/**
+ * The body in order, as merged runs and conflicts
+ */
+bodyHunks: BodyHunk[] }
+/**
+ * Error returned when a file can't be saved
+ */
+export type FileSaveError = 
+/**
+ * The file changed on disk since it was loaded (e.g. by `git pull` or another editor),
+ * so nothing was written
+ */
+{ kind: "conflict"; 
+/**
+ * The file as it is now; `None` if it was deleted
+ */
+diskContent: string | null; diskHash: string | null; 
+/**
+ * The file as it would have been written
+ */
+attemptedContent: string; 
+/**
+ * The file as it was loaded, to merge against; `None` if it's no longer known
+ */
+baseContent: string | null } | 
+/**
+ * Any other failure (path outside project, write error)
+ */
+{ kind: "failed"; message: string }
+/**
+ * A tracked file with its usage, as returned to the frontend
+ */
+export type FileUsageEntry = { 
+/**
+ * Absolute path to the file
+ */
+filePath: string; 
+/**
+ * Path relative to the project root
+ */
+relativePath: string; openCount: number; editCount: number; lastOpened: string | null; lastEdited: string | null }
+/**
+ * A saved copy of a file
+ */
+export type FileVersion = { id: string; 
+/**
+ * RFC 3339 UTC timestamp
+ */
+savedAt: string; sizeBytes: number; contentHash: string }
+/**
+ * A condition entries must meet; nested fields use dots (`seo.image`)
+ */
+export type FilterCondition = 
+/**
+ * Compares a frontmatter field with a value
+ */
+{ kind: "field"; field: string; operator: FieldOperator; value?: JsonValue | null } | 
+/**
+ * A list field (or single value) includes the tag, ignoring case
+ */
+{ kind: "hasTag"; field: string; tag: string } | 
+/**
+ * A date field falls in a range; bounds are `YYYY-MM-DD` dates, or days before
+ * today for the `*_days_ago` bounds
+ */
+{ kind: "dateRange"; field: string; from?: string | null; to?: string | null; 
+/**
+ * The date is at least this many days ago
+ */
+minDaysAgo?: number | null; 
+/**
+ * The date is at most this many days ago
+ */
+maxDaysAgo?: number | null } | 
+/**
+ * The title or body contains the text, ignoring case
+ */
+{ kind: "textContains"; text: string } | 
+/**
+ * Every condition matches
+ */
+{ kind: "all"; conditions: FilterCondition[] } | 
+/**
+ * At least one condition matches
+ */
+{ kind: "any"; conditions: FilterCondition[] }
+/**
+ * An entry matching a filter
+ */
+export type FilterMatch = { filePath: string; collectionName: string; 
+/**
+ * The entry's title, or its filename when it has none
+ */
+title: string }
+/**
+ * Syntax in a document that the project's flavor won't render
+ */
+export type FlavorIssue = { 
+/**
+ * The disabled extension, e.g. `gfmTables`
+ */
+feature: string; 
+/**
+ * 1-based line in the file
+ */
+line: number; message: string }
+/**
+ * Why a file's frontmatter couldn't be parsed, located in the file
+ */
+export type FrontmatterError = { 
+/**
+ * The parser's message, without its YAML-relative location
+ */
+message: string; 
+/**
+ * 1-based line in the file
+ */
+line: number | null; 
+/**
+ * 1-based column
+ */
+column: number | null; 
+/**
+ * The offending line as written
+ */
+snippet: string | null; cause: FrontmatterErrorCause | null; 
+/**
+ * What to change, in plain words
+ */
+suggestion: string | null }
+/**
+ * A likely reason frontmatter didn't parse
+ */
+export type FrontmatterErrorCause = 
+/**
+ * No closing `---`
+ */
+"unclosed" | 
+/**
+ * An unquoted value containing `: `
+ */
+"unquotedColon" | 
+/**
+ * A line indented with a tab, which YAML doesn't allow
+ */
+"tabIndentation" | 
+/**
+ * A quoted value without its closing quote
+ */
+"unclosedQuote" | 
+/**
+ * An unquoted value starting with a character YAML reserves (`@`, `` ` ``, `%`)
+ */
+"reservedCharacter"
+/**
+ * A boolean or enum frontmatter field to offer in the Edit > Frontmatter menu
+ */
+export type FrontmatterFlagField = { 
+/**
+ * Top-level frontmatter field name, e.g. "draft"
+ */
+name: string; 
+/**
+ * Human-readable label, e.g. "Draft"
+ */
+label: string; 
+/**
+ * True for enum fields, which cycle through their values instead of toggling
+ */
+isEnum: boolean }
+/**
+ * A frontmatter flag changed by `toggle_frontmatter_flag`, also emitted as
+ * `frontmatter-flag-toggled`
+ */
+export type FrontmatterFlagToggle = { filePath: string; field: string; 
+/**
+ * The field's new value
+ */
+value: JsonValue }
+/**
+ * A merge proposal for a conflicted file
+ */
+export type FrontmatterMerge = { 
+/**
+ * Fields merged without conflict, in file order
+ */
+merged: Partial<{ [key in string]: JsonValue }>; 
+/**
+ * Fields still needing a choice
+ */
+conflicts: FieldConflict[]; 
+/**
+ * The body differs between the sides and needs a choice too
+ */
+bodyConflict: boolean; 
+/**
+ * Whether the resolved file was written
+ */
+written: boolean }
+/**
+ * Frontmatter of a file that's too large to open in the editor
+ */
+export type FrontmatterOnlyContent = { frontmatter: Partial<{ [key in string]: JsonValue }>; rawFrontmatter: string; sizeBytes: number }
+/**
+ * A ranked quick-switcher result
+ */
+export type FuzzyMatch = { filePath: string; collectionName: string; kind: FuzzyMatchKind; 
+/**
+ * The matched title, filename or heading text
+ */
+text: string; 
+/**
+ * 1-based line number, for heading matches
+ */
+line: number | null; score: number; 
+/**
+ * Character indices in `text` that matched the query, for highlighting
+ */
+positions: number[] }
+/**
+ * What part of an entry a match was found in
+ */
+export type FuzzyMatchKind = "title" | "filename" | "heading"
+/**
+ * An image in the generated gallery
+ */
+export type GalleryImage = { sourcePath: string; 
+/**
+ * Path as referenced from the current file
+ */
+path: string; alt: string; resized: boolean; 
+/**
+ * An identical asset already existed and was used instead of a new copy
+ */
+reused: boolean; 
+/**
+ * Active content and external references removed from an SVG
+ */
+sanitized: string[] }
+/**
+ * The outcome of one gate
+ */
+export type GateResult = { label: string; passed: boolean; blocking: boolean; 
+/**
+ * Why the gate failed, e.g. the broken links
+ */
+detail: string | null }
+/**
+ * Where a project stands in git, for the title bar
+ */
+export type GitBranchState = { isRepository: boolean; 
+/**
+ * The checked-out branch; `None` when HEAD is detached
+ */
+branch: string | null; 
+/**
+ * `origin`'s HEAD, otherwise `main` or `master`
+ */
+defaultBranch: string | null; 
+/**
+ * The project is a linked worktree rather than the main checkout
+ */
+isWorktree: boolean; 
+/**
+ * The main checkout, when the project is a linked worktree
+ */
+mainWorktree: string | null; contentBranch: string | null; contentBranchExists: boolean; 
+/**
+ * Another worktree with the content branch checked out. Git won't check a
+ * branch out twice, so edit it there instead.
+ */
+contentBranchWorktree: string | null; 
+/**
+ * A content branch is required but the project is on the default branch
+ */
+editingDefaultBranch: boolean }
+/**
+ * A connection between two entries
+ */
+export type GraphEdge = { from: string; to: string; kind: GraphEdgeKind; 
+/**
+ * The reference field or series name, if any
+ */
+detail: string | null }
+/**
+ * Why two entries are connected
+ */
+export type GraphEdgeKind = 
+/**
+ * A schema `reference()` field points at the target
+ */
+"reference" | 
+/**
+ * The body links to the target
+ */
+"link" | 
+/**
+ * Consecutive entries in the same series
+ */
+"series"
+/**
+ * Output format for the exported graph
+ */
+export type GraphFormat = "dot" | "json"
+/**
+ * An entry in the graph
+ */
+export type GraphNode = { 
+/**
+ * Entry ID, e.g. `blog/first-post`
+ */
+id: string; label: string; collection: string; 
+/**
+ * Index of the connected cluster the entry belongs to; 0 is the largest
+ */
+cluster: number }
+/**
+ * How imported images are stored and referenced
+ */
+export type ImageFolderOptions = { 
+/**
+ * Assets directory override, relative to the project root
+ */
+assetsDirectory: string | null; useRelativePaths: boolean; usePathAliases: boolean; 
+/**
+ * PNG, JPEG and WebP images wider than this are scaled down
+ */
+maxWidth: number | null; 
+/**
+ * MDX component wrapping the gallery block (e.g. `Gallery`); plain Markdown
+ * images when omitted
+ */
+component: string | null }
+/**
+ * Result of importing a folder of images
+ */
+export type ImageGallery = { images: GalleryImage[]; 
+/**
+ * Markdown images, wrapped in the MDX component if one was given
+ */
+block: string; 
+/**
+ * Files in the folder that aren't images, or SVGs that couldn't be sanitized
+ */
+skipped: string[] }
+/**
+ * A proposed mapping of CSV columns to a collection's fields
+ */
+export type ImportMappingSuggestion = { columns: ColumnMapping[]; 
+/**
+ * Required fields no column maps to
+ */
+unmappedRequiredFields: string[]; 
+/**
+ * The delimiter used, e.g. "," or "\t"
+ */
+delimiter: string; 
+/**
+ * Data rows, excluding the header
+ */
+rowCount: number }
+/**
+ * The entry created by `ingest_transcript`
+ */
+export type IngestedTranscript = { filePath: string; paragraphCount: number; wordCount: number; fillersRemoved: number }
+/**
+ * An action requested from outside the app
+ */
+export type IntentAction = 
+/**
+ * Create a new entry in a collection of the open project
+ */
+{ type: "newFile"; collection: string } | 
+/**
+ * Append text to today's note in a collection of the open project
+ */
+{ type: "appendToDailyNote"; collection: string; text: string } | 
+/**
+ * Open a project folder
+ */
+{ type: "openProject"; path: string }
+/**
+ * An intent waiting to be handled by the frontend
+ */
+export type IntentRequest = { id: string; action: IntentAction }
+/**
+ * A link from one entry to another
+ */
+export type InternalLink = { 
+/**
+ * Entry ID of the linking entry, e.g. `blog/first-post`
+ */
+from: string; to: string; kind: LinkKind; 
+/**
+ * 1-based line of the link in the linking file; `None` for reference fields
+ */
+line: number | null; 
+/**
+ * The line the link is on, or the reference field's name
+ */
+context: string }
+export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
+/**
+ * How often a language appears in a collection
+ */
+export type LanguageUsage = { language: string; count: number; known: boolean; 
+/**
+ * Shiki's id for a known language, e.g. "javascript" for "js"
+ */
+canonical?: string | null; 
+/**
+ * Closest known language, for unknown ones
+ */
+suggestion?: string | null }
+/**
+ * Every internal link in a project
+ */
+export type LinkGraph = { 
+/**
+ * Entry IDs, including entries without links
+ */
+entries: string[]; links: InternalLink[] }
+/**
+ * How one entry links to another
+ */
+export type LinkKind = 
+/**
+ * A Markdown link to the entry's file or URL
+ */
+"markdown" | 
+/**
+ * A schema `reference()` field
+ */
+"reference" | 
+/**
+ * A `[[wiki link]]`
+ */
+"wiki"
+/**
+ * What a rename rewrote to keep references to the file working
+ */
+export type LinkRewriteReport = { 
+/**
+ * Files changed, at their paths after the rename
+ */
+files: string[]; links: RewrittenLink[] }
+export type LinkStatus = "ok" | 
+/**
+ * The target doesn't exist, or the server says it's not found
+ */
+"broken" | 
+/**
+ * The server couldn't be reached, timed out or gave an unexpected response
+ */
+"unverified" | 
+/**
+ * Not checked: external links when external checking is off, and routes that
+ * can't be resolved without building the site
+ */
+"skipped"
+/**
+ * Payload of `listing-batch`
+ */
+export type ListingBatch = { streamId: string; 
+/**
+ * Position of this batch in the stream, from 0
+ */
+index: number; files: FileEntry[] }
+/**
+ * Payload of `listing-complete`, also returned by `stream_collection_files`
+ */
+export type ListingComplete = { streamId: string; 
+/**
+ * Entries sent across all batches
+ */
+total: number; 
+/**
+ * The stream was cancelled, or abandoned, before every entry was sent
+ */
+cancelled: boolean }
+/**
+ * A running process started by the editor
+ */
+export type ManagedProcessInfo = { 
+/**
+ * Registry ID, for `kill_managed_process`
+ */
+id: number; pid: number; 
+/**
+ * What the process is for, e.g. "astro sync"
+ */
+label: string; 
+/**
+ * The program and its arguments
+ */
+command: string; workingDirectory: string | null; 
+/**
+ * RFC 3339 UTC start time
+ */
+startedAt: string; runtimeSeconds: number; maxRuntimeSeconds: number; 
+/**
+ * Resident memory of the process and its children; unavailable on Windows
+ */
+memoryMb: number | null; maxMemoryMb: number }
+export type MarkdownContent = { frontmatter: Partial<{ [key in string]: JsonValue }>; content: string; raw_frontmatter: string; imports: string; content_hash: string }
+/**
+ * Markdown extensions enabled for a project
+ */
+export type MarkdownFlavor = { gfmTables: boolean; footnotes: boolean; taskLists: boolean; 
+/**
+ * Curly quotes, dashes and ellipses from straight punctuation
+ */
+smartPunctuation: boolean; mdx: boolean }
+/**
+ * The project's `markdownFlavor` setting; unset extensions follow the Astro config
+ */
+export type MarkdownFlavorSettings = { gfmTables?: boolean | null; footnotes?: boolean | null; taskLists?: boolean | null; smartPunctuation?: boolean | null; mdx?: boolean | null }
+/**
+ * A rendered preview of a document
+ */
+export type MarkdownPreview = { 
+/**
+ * The frontmatter title, if the document has one
+ */
+title: string | null; 
+/**
+ * Sanitized HTML for the body, headed by the title unless the body opens with
+ * its own `#` heading
+ */
+html: string }
+/**
+ * A Markdown table converted from delimited text
+ */
+export type MarkdownTable = { markdown: string; 
+/**
+ * The delimiter used, e.g. "," or "\t"
+ */
+delimiter: string; 
+/**
+ * Whether the first row was used as the header
+ */
+hasHeader: boolean; 
+/**
+ * Data rows, excluding the header
+ */
+rowCount: number; columnCount: number }
+/**
+ * A problem found in a math region
+ */
+export type MathDiagnostic = { 
+/**
+ * Start offset in UTF-16 code units
+ */
+start: number; 
+/**
+ * End offset (exclusive) in UTF-16 code units
+ */
+end: number; 
+/**
+ * 1-based line of `start`
+ */
+line: number; severity: MathDiagnosticSeverity; message: string }
+export type MathDiagnosticSeverity = "error" | "warning"
+export type MdxComponent = { name: string; file_path: string; props: PropInfo[]; has_slot: boolean; description: string | null; framework: ComponentFramework }
+/**
+ * The project's `mdxEscaping` setting
+ */
+export type MdxEscapingSettings = { 
+/**
+ * Escape problem characters in MDX files on save
+ */
+escapeOnSave?: boolean | null; 
+/**
+ * Defaults to backslash escapes
+ */
+style?: EscapeStyle | null }
+/**
+ * Editor state reported by the frontend, used to enable or disable menu items
+ */
+export type MenuContext = { hasFileOpen: boolean; editorFocused: boolean; 
+/**
+ * True if the editor has a non-empty selection
+ */
+hasSelection: boolean; 
+/**
+ * True if a collection is selected in the sidebar
+ */
+hasCollection: boolean }
+/**
+ * A word the dictionary doesn't know
+ */
+export type Misspelling = { 
+/**
+ * Start offset in UTF-16 code units
+ */
+start: number; 
+/**
+ * End offset (exclusive) in UTF-16 code units
+ */
+end: number; 
+/**
+ * 1-based line of `start`
+ */
+line: number; word: string; 
+/**
+ * Likely corrections, best first
+ */
+suggestions: string[] }
+/**
+ * A mutating command to preview, with the arguments it would be called with
+ */
+export type MutationRequest = { kind: "writeFile"; filePath: string; content: string; projectRoot: string } | { kind: "createFile"; directory: string; filename: string; content: string; projectRoot: string } | { kind: "deleteFile"; filePath: string; projectRoot: string } | { kind: "renameFile"; oldPath: string; newPath: string; projectRoot: string } | { kind: "updateFrontmatter"; filePath: string; frontmatter: Partial<{ [key in string]: JsonValue }>; projectRoot: string } | { kind: "applyCoercions"; filePath: string; projectRoot: string; coercions: CoercionSuggestion[] } | { kind: "copyFileToAssets"; sourcePath: string; projectPath: string; collection: string; assetsDirectory: string | null; currentFilePath: string; useRelativePaths: boolean; usePathAliases: boolean } | { kind: "importImageFolder"; folderPath: string; projectPath: string; collection: string; currentFilePath: string; options: ImageFolderOptions } | { kind: "normalizeCodeBlockLanguages"; projectPath: string; collectionPath: string; mapping: Partial<{ [key in string]: string }> } | { kind: "repairAssetReferences"; projectPath: string; contentDirectory: string | null; assetsDirectory: string | null }
+/**
+ * A new field to add to a collection's `z.object({...})` schema
+ */
+export type NewSchemaField = { name: string; fieldType: SchemaFieldType; optional: boolean; defaultValue: JsonValue | null }
+/**
+ * An asset nothing references
+ */
+export type OrphanedAsset = { 
+/**
+ * Path relative to the project root, e.g. `src/assets/blog/old-hero.png`
+ */
+path: string; sizeBytes: number }
+export type OrphanedAssetDeletion = { 
+/**
+ * Project-relative paths moved to the trash
+ */
+deleted: string[]; 
+/**
+ * Requested paths left alone because they're referenced now, or not an asset
+ */
+skipped: string[]; freedBytes: number }
+export type OrphanedAssetReport = { 
+/**
+ * Unreferenced assets, largest first
+ */
+assets: OrphanedAsset[]; totalBytes: number; 
+/**
+ * Files checked in the assets directory
+ */
+scanned: number }
+/**
+ * A filesystem change a command would make
+ */
+export type PlannedChange = 
+/**
+ * A file written with new contents
+ */
+{ kind: "write"; path: string; 
+/**
+ * Whether the file doesn't exist yet
+ */
+created: boolean; 
+/**
+ * Unified diff against the current contents; omitted for binary files
+ */
+diff: string | null } | { kind: "delete"; path: string } | { kind: "rename"; from: string; to: string } | { kind: "copy"; from: string; to: string } | { kind: "createDirectory"; path: string }
+/**
+ * Where an entry renders on the dev server
+ */
+export type PreviewUrl = { 
+/**
+ * Site-absolute route, including the config's `base`, e.g. `/writing/first-post/`
+ */
+route: string; 
+/**
+ * The route on the running dev server, if there is one
+ */
+url: string | null }
+/**
+ * An image in the assets directory
+ */
+export type ProjectAsset = { 
+/**
+ * Path relative to the project root, e.g. `src/assets/blog/hero.png`
+ */
+path: string; name: string; extension: string; 
+/**
+ * Pixel dimensions; `None` when the image can't be read (or an SVG has no size)
+ */
+width: number | null; height: number | null; sizeBytes: number; lastModified: number | null }
+/**
+ * A project's directory overrides, relative to the project root
+ */
+export type ProjectPathSettings = { 
+/**
+ * Defaults to `src/content`
+ */
+contentDirectory?: string | null; 
+/**
+ * Defaults to `src/assets`
+ */
+assetsDirectory?: string | null; 
+/**
+ * Defaults to `src/components/mdx`
+ */
+mdxComponentsDirectory?: string | null }
+export type PropInfo = { name: string; prop_type: string; is_optional: boolean; default_value: string | null; description: string | null }
+/**
+ * A publish gate from a collection's settings
+ */
+export type PublishGate = { 
+/**
+ * Shown in the checklist; a description of the rule is used when omitted
+ */
+label?: string | null; rule: PublishRule; 
+/**
+ * Whether failing this gate blocks publishing rather than just warning
+ */
+blocking?: boolean }
+/**
+ * Gate results for a file
+ */
+export type PublishReadiness = { 
+/**
+ * False when any blocking gate failed
+ */
+ready: boolean; gates: GateResult[] }
+/**
+ * Renaming in `publish_file`: the file gets a `YYYY-MM-DD-` prefix for its publish date
+ * (replacing any date it starts with) when most of its collection is named that way
+ */
+export type PublishRename = { collectionPath: string; 
+/**
+ * Collection URL patterns (e.g. `{ "blog": "/writing/{slug}" }`), for rewriting
+ * links to the entry's URL
+ */
+urlPatterns: Partial<{ [key in string]: string }> }
+/**
+ * What a publish gate checks
+ */
+export type PublishRule = 
+/**
+ * A frontmatter field is set and not empty; nested fields use dots (`seo.image`)
+ */
+{ kind: "fieldPresent"; field: string } | 
+/**
+ * A frontmatter list has at least `min` items
+ */
+{ kind: "minItems"; field: string; min: number } | 
+/**
+ * Relative links and links matching the collection's URL pattern resolve
+ */
+{ kind: "noBrokenLinks" }
+/**
+ * A file after `publish_file`
+ */
+export type PublishedFile = { 
+/**
+ * The file's path, which changes when it's renamed
+ */
+filePath: string; frontmatter: Partial<{ [key in string]: JsonValue }>; 
+/**
+ * Hash of the written file, to expect on the next save
+ */
+contentHash: string; 
+/**
+ * What renaming the file rewrote, when it was renamed
+ */
+rename: LinkRewriteReport | null }
+/**
+ * Why `apply_raw_frontmatter` didn't write the file
+ */
+export type RawFrontmatterError = 
+/**
+ * The YAML doesn't parse as a mapping of fields; located as in the file
+ */
+{ kind: "invalidYaml"; error: FrontmatterError } | 
+/**
+ * The YAML parses but breaks the collection's schema. Applying again without a
+ * schema writes it anyway.
+ */
+{ kind: "schemaViolations"; violations: FieldViolation[] } | 
+/**
+ * Any other failure (missing file, path outside project)
+ */
+{ kind: "failed"; message: string }
+/**
+ * A reading time estimate for one entry
+ */
+export type ReadingTime = { 
+/**
+ * Words outside code blocks
+ */
+words: number; 
+/**
+ * Words inside fenced code blocks
+ */
+codeWords: number; images: number; 
+/**
+ * Estimated reading time in seconds
+ */
+seconds: number; 
+/**
+ * Whole minutes, rounded up; the entry's override when it has one
+ */
+minutes: number; 
+/**
+ * True if `minutes` came from the entry's override field
+ */
+overridden: boolean }
+/**
+ * The project's `readingTime` setting
+ */
+export type ReadingTimeSettings = { 
+/**
+ * Reading speed for prose (defaults to 200)
+ */
+wordsPerMinute?: number | null; 
+/**
+ * Time added for each image (defaults to 12)
+ */
+secondsPerImage?: number | null; 
+/**
+ * Each word in a code block counts as this many words (defaults to 1.5)
+ */
+codeWeight?: number | null; 
+/**
+ * Frontmatter field the estimate is written to (defaults to `readingTime`)
+ */
+field?: string | null; 
+/**
+ * Frontmatter field with an entry's own reading time in minutes (defaults to
+ * `readingTimeOverride`)
+ */
+overrideField?: string | null; 
+/**
+ * Write the estimate into the schema's reading time field on save
+ */
+writeOnSave?: boolean | null }
+/**
+ * A project opened before, most recent first in `list_recent_projects`
+ */
+export type RecentProject = { id: string; name: string; path: string; 
+/**
+ * RFC 3339 UTC timestamp
+ */
+lastOpened: string; 
+/**
+ * Collections in the content config; `None` when the project is gone or its
+ * config can't be read
+ */
+collectionCount: number | null }
+/**
+ * The `recovery` preference
+ */
+export type RecoverySettings = { 
+/**
+ * Most recovery entries to keep (defaults to 50)
+ */
+maxFiles: number | null; 
+/**
+ * Entries older than this are removed (defaults to 30)
+ */
+maxAgeDays: number | null; 
+/**
+ * Oldest entries are removed once the folder exceeds this (defaults to 100)
+ */
+maxTotalSizeMb: number | null; 
+/**
+ * Encrypt entries with a key stored in the system keychain
+ */
+encrypt: boolean | null }
+/**
+ * A reference id and what to show for it
+ */
+export type ReferenceLabel = { id: string; 
+/**
+ * The entry's title or name; the id itself when it has neither or doesn't exist
+ */
+label: string; exists: boolean }
+/**
+ * A project in the registry
+ */
+export type RegisteredProject = { id: string; 
+/**
+ * From `package.json`, else the directory name
+ */
+name: string; path: string; 
+/**
+ * RFC 3339 UTC timestamp
+ */
+lastOpened: string; 
+/**
+ * RFC 3339 UTC timestamp
+ */
+created: string }
+/**
+ * A content entry mentioned in a report
+ */
+export type ReportEntry = { 
+/**
+ * Path relative to the project root
+ */
+path: string; collection: string; title: string; 
+/**
+ * Publish date as `YYYY-MM-DD`, if the entry has one
+ */
+publishedDate: string | null }
+/**
+ * Frontmatter fields the report reads, from the project's field mappings
+ */
+export type ReportFieldMappings = { title: string; 
+/**
+ * Publish date fields, first present wins
+ */
+publishedDate: string[]; draft: string }
+/**
+ * A document with its includes expanded
+ */
+export type ResolvedTransclusions = { content: string; 
+/**
+ * Every file included, at any depth
+ */
+included: string[]; problems: TransclusionProblem[] }
+/**
+ * A reference rewritten to follow a renamed file
+ */
+export type RewrittenLink = { 
+/**
+ * The file containing the reference, at its path after the rename
+ */
+filePath: string; 
+/**
+ * 1-based line of the reference
+ */
+line: number; oldTarget: string; newTarget: string }
+/**
+ * A named filter saved in project settings
+ */
+export type SavedFilter = { id: string; name: string; 
+/**
+ * The collection the filter applies to; all collections when omitted
+ */
+collection?: string | null; condition: FilterCondition }
+/**
+ * Zod types that can be added to a schema from the UI
+ */
+export type SchemaFieldType = "string" | "number" | "boolean" | "date" | "image" | "stringArray"
+export type SchemaFixtureReport = { results: SchemaFixtureResult[]; passed: number; failed: number }
+export type SchemaFixtureResult = { name: string; 
+/**
+ * Shipped with the app rather than loaded from the fixtures directory
+ */
+builtIn: boolean; 
+/**
+ * Set when the fixture itself couldn't be read or run
+ */
+error: string | null; mismatches: SchemaMismatch[] }
+/**
+ * A value in the merged schema that differs from the fixture's expectation
+ */
+export type SchemaMismatch = { collection: string; 
+/**
+ * Where the value differs, e.g. `fields[name=cover].fieldType`; empty when the whole
+ * collection is missing
+ */
+path: string; 
+/**
+ * The expected value as JSON
+ */
+expected: string; 
+/**
+ * The actual value as JSON (or the error building the schema), `None` when missing
+ */
+actual: string | null }
+/**
+ * A scratchpad in app data
+ */
+export type Scratchpad = { 
+/**
+ * `YYYYMMDD-HHMMSS-mmm` when it was created; also its file name
+ */
+id: string; 
+/**
+ * The first line of the content, without heading marks; empty for a blank scratchpad
+ */
+title: string; content: string; 
+/**
+ * Unix milliseconds of the last save
+ */
+updatedAt: number }
+/**
+ * Frontmatter fields a promoted entry is written with
+ */
+export type ScratchpadEntryFields = { title: string; 
+/**
+ * Set to today's date when present
+ */
+date: string | null; 
+/**
+ * Set to true when present, so the entry starts as a draft
+ */
+draft: string | null }
+/**
+ * Entries sharing a slug
+ */
+export type SlugConflict = { slug: string; entries: SlugSource[] }
+/**
+ * The project's `slugs` setting
+ */
+export type SlugSettings = { 
+/**
+ * Replace accented Latin and Cyrillic letters with ASCII, e.g. `café` → `cafe`
+ * (off by default, matching Astro)
+ */
+transliterate?: boolean | null; 
+/**
+ * Longest slug in characters, cut at a word boundary (no limit by default)
+ */
+maxLength?: number | null; 
+/**
+ * Words left out of slugs, e.g. `["a", "the", "of"]`
+ */
+stopWords?: string[] | null }
+/**
+ * Where an entry's slug comes from
+ */
+export type SlugSource = { filePath: string; 
+/**
+ * Set by a `slug` frontmatter field rather than the file path
+ */
+explicit: boolean }
+/**
+ * A draft that hasn't been edited recently
+ */
+export type StaleDraft = { filePath: string; collection: string; title: string; wordCount: number; 
+/**
+ * Last modification date, `YYYY-MM-DD`
+ */
+lastEdited: string; daysSinceEdit: number }
+/**
+ * Weekly digest settings for a project
+ */
+export type StaleDraftDigest = { projectPath: string; contentDirectory: string | null; 
+/**
+ * Drafts untouched for at least this many days are stale
+ */
+days: number; fields: StaleDraftFields }
+/**
+ * Frontmatter fields used to find drafts and name them
+ */
+export type StaleDraftFields = { title: string; draft: string }
+/**
+ * Accessibility settings from the operating system
+ */
+export type SystemAccessibilityPrefs = { 
+/**
+ * "Reduce motion" (macOS) or animations turned off (Windows)
+ */
+reducedMotion: boolean; 
+/**
+ * "Increase contrast" (macOS) or a high contrast theme (Windows)
+ */
+increasedContrast: boolean; colorScheme: ColorScheme }
+/**
+ * An editorial note found in a content file
+ */
+export type TodoItem = { filePath: string; collection: string; 
+/**
+ * 1-based line number
+ */
+line: number; marker: TodoMarker; 
+/**
+ * The note text after the marker, without comment delimiters
+ */
+text: string; 
+/**
+ * True if the note is in the frontmatter rather than the body
+ */
+inFrontmatter: boolean }
+/**
+ * The marker that introduced a note
+ */
+export type TodoMarker = "todo" | "fixme" | "hack"
+/**
+ * An include that couldn't be expanded, left as written
+ */
+export type TransclusionProblem = { 
+/**
+ * 1-based line in the document; for nested includes, the line of the outermost
+ */
+line: number; 
+/**
+ * The path as written
+ */
+target: string; kind: TransclusionProblemKind; message: string }
+export type TransclusionProblemKind = 
+/**
+ * The file doesn't exist
+ */
+"missing" | "outsideProject" | 
+/**
+ * The file includes itself, directly or through others
+ */
+"cycle" | 
+/**
+ * Nested deeper than the depth limit
+ */
+"tooDeep"
+/**
+ * The project's `transclusion` setting
+ */
+export type TransclusionSettings = { 
+/**
+ * MDX component that includes the file in its `src` attribute, e.g. `Include`
+ */
+component?: string | null }
+/**
+ * Frontmatter fields the new entry is written with
+ */
+export type TranscriptFields = { title: string; 
+/**
+ * Set to today's date when present
+ */
+date: string | null; 
+/**
+ * Set to true so the entry starts as a draft
+ */
+draft: string }
+/**
+ * A character in prose that MDX would parse as code
+ */
+/**
+ * A deleted file that can still be restored
+ */
+export type TrashedFile = { id: string; 
+/**
+ * Where the file was deleted from
+ */
+originalPath: string; projectRoot: string; 
+/**
+ * RFC 3339 UTC timestamp
+ */
+trashedAt: string }
+/**
+ * A draft with no publish date yet
+ */
+export type UndatedDraft = { filePath: string; collection: string; title: string }
+export type UnescapedCharacter = { 
+/**
+ * 1-based line in the file
+ */
+line: number; 
+/**
+ * 1-based column, in characters
+ */
+column: number; character: string; message: string }
+/**
+ * A code block whose language Shiki won't recognise
+ */
+export type UnknownCodeBlock = { filePath: string; 
+/**
+ * 1-based line of the opening fence
+ */
+line: number; language: string; suggestion?: string | null }
+/**
+ * Unsaved edits to a file
+ */
+export type UnsavedDraft = { filePath: string; 
+/**
+ * The editor content, without frontmatter or imports
+ */
+content: string; 
+/**
+ * The edited frontmatter; `None` when only the content was edited
+ */
+frontmatter: Partial<{ [key in string]: JsonValue }> | null; 
+/**
+ * `content_hash` of the file the edits were made to
+ */
+baseHash: string | null; 
+/**
+ * RFC 3339 UTC timestamp
+ */
+savedAt: string }
+/**
+ * A file with unsaved edits from an earlier session
+ */
+export type UnsavedDraftSummary = { filePath: string; 
+/**
+ * RFC 3339 UTC timestamp
+ */
+savedAt: string; 
+/**
+ * The file has changed on disk since the edits were made
+ */
+changedOnDisk: boolean }
+/**
+ * Which releases to update to
+ */
+export type UpdateChannel = "stable" | 
+/**
+ * Prereleases as well as stable releases
+ */
+"beta"
+/**
+ * Progress emitted as `update-download-progress` while an update downloads
+ */
+export type UpdateDownloadProgress = { downloaded: number; total: number | null }
+/**
+ * Result of checking the current channel for updates
+ */
+export type UpdateInfo = { channel: UpdateChannel; currentVersion: string; update: AvailableUpdate | null }
+/**
+ * Which rule a frontmatter value breaks
+ */
+export type ViolationKind = "required" | "min" | "max" | "minLength" | "maxLength" | "pattern" | "format" | "enumValue"
+/**
+ * Counts of watcher events for a project since watching started
+ */
+export type WatcherStats = { 
+/**
+ * Events passed on to the frontend (file or schema changes)
+ */
+processedEvents: number; 
+/**
+ * Events dropped because they came from an excluded directory
+ */
+suppressedEvents: number; 
+/**
+ * Suppressed event counts keyed by the exclusion that matched
+ */
+suppressedByExclusion: Partial<{ [key in string]: number }> }
 
 /** tauri-specta globals **/
 
@@ -0,0 +1,73 @@
+import {
+  commands,
+  type CoercionSuggestion,
+  type JsonValue,
+} from '@/lib/bindings'
+import { queryClient } from './query-client'
+import { queryKeys } from './query-keys'
+import { toast } from './toast'
+
+function describeValue(value: JsonValue): string {
+  return typeof value === 'string' ? `"${value}"` : JSON.stringify(value)
+}
+
+async function applyCoercions(
+  filePath: string,
+  fileId: string,
+  projectPath: string,
+  coercions: CoercionSuggestion[]
+): Promise<void> {
+  const result = await commands.applyCoercions(filePath, projectPath, coercions)
+  if (result.status === 'error') {
+    toast.error('Failed to fix frontmatter types', {
+      description: result.error,
+    })
+    return
+  }
+
+  // Reload the open file and any file lists showing its frontmatter
+  void queryClient.invalidateQueries({
+    queryKey: queryKeys.fileContent(projectPath, fileId),
+  })
+  void queryClient.invalidateQueries({
+    queryKey: [...queryKeys.all, projectPath],
+  })
+  toast.success(`Fixed ${result.data} frontmatter values`)
+}
+
+/**
+ * Checks a just-saved file for frontmatter values with the wrong type for the
+ * collection schema (e.g. `"true"` for a boolean, `2024-1-3` for a date) and
+ * offers to convert them. Silent when there's nothing to fix.
+ */
+export async function suggestFrontmatterCoercions(
+  filePath: string,
+  fileId: string,
+  projectPath: string,
+  completeSchema: string
+): Promise<void> {
+  const result = await commands.checkFrontmatterTypes(
+    filePath,
+    projectPath,
+    completeSchema
+  )
+  if (result.status === 'error' || result.data.length === 0) return
+
+  const coercions = result.data
+  const description = coercions
+    .slice(0, 3)
+    .map(c => {
+      const from = describeValue(c.current)
+      return `${c.field}: ${from} → ${describeValue(c.suggested)}`
+    })
+    .join('\n')
+
+  toast.info(`${coercions.length} frontmatter values have the wrong type`, {
+    description,
+    action: {
+      label: 'Fix',
+      onClick: () =>
+        void applyCoercions(filePath, fileId, projectPath, coercions),
+    },
+  })
+}
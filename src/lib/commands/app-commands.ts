@@ -16,6 +16,34 @@ import {
   Link,
   BookOpen,
   Keyboard,
+  CalendarDays,
+  Mic,
+  Images,
+  ImageOff,
+  FileCheck,
+  GitMerge,
+  Activity,
+  ClipboardCopy,
+  Braces,
+  CloudDownload,
+  NotebookPen,
+  ArchiveRestore,
+  GitBranch,
+  FileInput,
+  Globe,
+  Square,
+  Columns2,
+  SpellCheck,
+  BookPlus,
+  Unlink,
+  Archive,
+  History,
+  FileCode,
+  ListTodo,
+  FileChartColumn,
+  Network,
+  Hourglass,
+  Lock,
 } from 'lucide-react'
 import { openPath } from '@tauri-apps/plugin-opener'
 import { AppCommand, CommandContext } from './types'
@@ -23,8 +51,38 @@ import type { Collection } from '@/types'
 import { toast } from '../toast'
 import { openInIde } from '../ide'
 import { openProjectViaDialog } from '../projects/actions'
+import { openDailyNote } from '../daily-notes'
+import { importTranscript } from '../transcripts'
+import { newScratchpad } from '../scratchpads'
+import { insertGalleryFromDialog } from '../editor/insert-gallery'
+import { repairBrokenImagePaths } from '../asset-repair'
+import { checkMarkdownFlavor } from '../markdown-flavor'
+import { checkMdxEscaping } from '../mdx-escaping'
+import { checkIncludes } from '../transclusion'
+import { checkSpelling, addWordToDictionary } from '../spellcheck'
+import { checkLinks } from '../link-check'
+import { createBackup } from '../backups'
+import { refreshRemoteCollection } from '../remote-collections'
+import { copyCurrentDocumentAsHtml } from '../copy-html'
+import { resolveFrontmatterConflict } from '../conflict-merge'
+import { showRunningProcesses } from '../processes'
+import { openPreview, stopDevServer } from '../dev-server'
+import { switchToContentBranch } from '../git-branch'
 import { DOCS_URLS } from '../docs-urls'
 import { useContentLinkerStore } from '@/store/contentLinkerStore'
+import { useScratchpadStore } from '@/store/scratchpadStore'
+import { useTrashStore } from '@/store/trashStore'
+import { useBackupsStore } from '@/store/backupsStore'
+import { useDailyNotesStore } from '@/store/dailyNotesStore'
+import { useCodeBlockAuditStore } from '@/store/codeBlockAuditStore'
+import { useTodosStore } from '@/store/todosStore'
+import { useActivityReportStore } from '@/store/activityReportStore'
+import { useContentGraphStore } from '@/store/contentGraphStore'
+import { useStaleDraftsStore } from '@/store/staleDraftsStore'
+import { useEmbargoStore } from '@/store/embargoStore'
+import { useContentCalendarStore } from '@/store/contentCalendarStore'
+import { useProjectStore } from '@/store/projectStore'
+import { useUIStore } from '@/store/uiStore'
 
 /**
  * File-related commands
@@ -43,6 +101,267 @@ export const fileCommands: AppCommand[] = [
       return Boolean(context.selectedCollection && context.projectPath)
     },
   },
+  {
+    id: 'open-daily-note',
+    label: "Open Today's Note",
+    description: 'Open or create the daily note for today',
+    icon: CalendarDays,
+    group: 'file',
+    execute: async () => {
+      await openDailyNote()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.projectPath)
+    },
+  },
+  {
+    id: 'open-daily-note-calendar',
+    label: 'Open Journal Calendar',
+    description: 'See which days have a daily note and open any of them',
+    icon: CalendarDays,
+    group: 'file',
+    execute: () => {
+      useDailyNotesStore.getState().open()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.projectPath)
+    },
+  },
+  {
+    id: 'import-transcript',
+    label: 'Import Transcript',
+    description: 'Create a draft from a dictation or podcast transcript',
+    icon: Mic,
+    group: 'file',
+    execute: async () => {
+      await importTranscript()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.selectedCollection && context.projectPath)
+    },
+  },
+  {
+    id: 'new-scratchpad',
+    label: 'New Scratchpad',
+    description: 'Jot or paste notes outside the project, promote them later',
+    icon: NotebookPen,
+    group: 'file',
+    execute: async () => {
+      await newScratchpad()
+    },
+    isAvailable: () => true,
+  },
+  {
+    id: 'open-scratchpads',
+    label: 'Open Scratchpads',
+    description: 'Browse scratchpads and promote one into a collection',
+    icon: NotebookPen,
+    group: 'file',
+    execute: () => {
+      useScratchpadStore.getState().open()
+    },
+    isAvailable: () => true,
+  },
+  {
+    id: 'open-trash',
+    label: 'Restore Deleted Files',
+    description: 'Put back files deleted from this project in the last 30 days',
+    icon: ArchiveRestore,
+    group: 'file',
+    execute: () => {
+      useTrashStore.getState().open()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.projectPath)
+    },
+  },
+  {
+    id: 'create-backup',
+    label: 'Back Up Content',
+    description: 'Save a copy of the content directory to restore later',
+    icon: Archive,
+    group: 'file',
+    execute: async () => {
+      await createBackup()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.projectPath)
+    },
+  },
+  {
+    id: 'open-backups',
+    label: 'Restore from Backup',
+    description: 'Put back the content directory as it was in a backup',
+    icon: History,
+    group: 'file',
+    execute: () => {
+      useBackupsStore.getState().open()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.projectPath)
+    },
+  },
+  {
+    id: 'switch-content-branch',
+    label: 'Switch to Content Branch',
+    description: 'Check out the git branch content is edited on',
+    icon: GitBranch,
+    group: 'file',
+    execute: async () => {
+      await switchToContentBranch()
+    },
+    isAvailable: (context: CommandContext) => {
+      const { currentProjectSettings } = useProjectStore.getState()
+      return Boolean(
+        context.projectPath && currentProjectSettings?.contentBranch?.branch
+      )
+    },
+  },
+  {
+    id: 'insert-image-gallery',
+    label: 'Insert Image Gallery',
+    description: 'Import a folder of images into the current file',
+    icon: Images,
+    group: 'file',
+    execute: async () => {
+      await insertGalleryFromDialog()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.currentFile && context.projectPath)
+    },
+  },
+  {
+    id: 'repair-image-paths',
+    label: 'Repair Broken Image Paths',
+    description: 'Find moved images and fix references to them',
+    icon: ImageOff,
+    group: 'file',
+    execute: async () => {
+      await repairBrokenImagePaths()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.projectPath)
+    },
+  },
+  {
+    id: 'check-markdown-syntax',
+    label: 'Check Markdown Syntax',
+    description: "Find syntax this project's Markdown setup won't render",
+    icon: FileCheck,
+    group: 'file',
+    execute: async () => {
+      await checkMarkdownFlavor()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.currentFile && context.projectPath)
+    },
+  },
+  {
+    id: 'check-mdx-escaping',
+    label: 'Check MDX Characters',
+    description: 'Find braces and angle brackets MDX will parse as code',
+    icon: Braces,
+    group: 'file',
+    execute: async () => {
+      await checkMdxEscaping()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(
+        context.currentFile?.extension === 'mdx' && context.projectPath
+      )
+    },
+  },
+  {
+    id: 'check-includes',
+    label: 'Check Includes',
+    description: 'Find includes that point at missing or circular files',
+    icon: FileInput,
+    group: 'file',
+    execute: async () => {
+      await checkIncludes()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.currentFile && context.projectPath)
+    },
+  },
+  {
+    id: 'check-spelling',
+    label: 'Check Spelling',
+    description: 'Find misspelled words in the prose of the current file',
+    icon: SpellCheck,
+    group: 'file',
+    execute: async () => {
+      await checkSpelling()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.currentFile)
+    },
+  },
+  {
+    id: 'add-word-to-dictionary',
+    label: 'Add Word to Dictionary',
+    description: 'Stop flagging the word at the cursor in this project',
+    icon: BookPlus,
+    group: 'file',
+    execute: async () => {
+      await addWordToDictionary()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.currentFile && context.projectPath)
+    },
+  },
+  {
+    id: 'check-links',
+    label: 'Check Links',
+    description: 'Find broken links and images in the current file',
+    icon: Unlink,
+    group: 'file',
+    execute: async () => {
+      await checkLinks('file')
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.currentFile && context.projectPath)
+    },
+  },
+  {
+    id: 'check-collection-links',
+    label: 'Check Collection Links',
+    description: 'Find broken internal links and images across the collection',
+    icon: Unlink,
+    group: 'file',
+    execute: async () => {
+      await checkLinks('collection')
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.selectedCollection && context.projectPath)
+    },
+  },
+  {
+    id: 'copy-as-html',
+    label: 'Copy as HTML',
+    description: 'Copy the document as HTML with images at their deployed URLs',
+    icon: ClipboardCopy,
+    group: 'file',
+    execute: async () => {
+      await copyCurrentDocumentAsHtml()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.currentFile && context.projectPath)
+    },
+  },
+  {
+    id: 'resolve-frontmatter-conflict',
+    label: 'Resolve Frontmatter Conflict',
+    description: 'Merge frontmatter from both sides of a git conflict',
+    icon: GitMerge,
+    group: 'file',
+    execute: async () => {
+      await resolveFrontmatterConflict()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.currentFile && context.projectPath)
+    },
+  },
   {
     id: 'close-file',
     label: 'Close File',
@@ -128,6 +447,156 @@ export const projectCommands: AppCommand[] = [
       return Boolean(context.projectPath)
     },
   },
+  {
+    id: 'refresh-remote-collection',
+    label: 'Refresh Remote Collection',
+    description: 'Run astro sync to fetch entries from the collection loader',
+    icon: CloudDownload,
+    group: 'project',
+    execute: async (context: CommandContext) => {
+      if (context.selectedCollection) {
+        await refreshRemoteCollection(context.selectedCollection)
+      }
+    },
+    isAvailable: (context: CommandContext) => {
+      return context.collections.some(
+        c => c.name === context.selectedCollection && c.remote
+      )
+    },
+  },
+  {
+    id: 'audit-code-blocks',
+    label: 'Audit Code Blocks',
+    description: "Find code block languages that won't be highlighted",
+    icon: FileCode,
+    group: 'project',
+    execute: (context: CommandContext) => {
+      const collection = context.collections.find(
+        c => c.name === context.selectedCollection
+      )
+      if (collection) {
+        useCodeBlockAuditStore.getState().open(collection)
+      }
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.selectedCollection && context.projectPath)
+    },
+  },
+  {
+    id: 'show-todos',
+    label: 'Show TODOs',
+    description: 'List TODO, FIXME and HACK notes across the project',
+    icon: ListTodo,
+    group: 'project',
+    execute: () => {
+      useTodosStore.getState().open()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.projectPath)
+    },
+  },
+  {
+    id: 'show-activity-report',
+    label: 'Show Activity Report',
+    description: 'Summarize what was published and written in a month',
+    icon: FileChartColumn,
+    group: 'project',
+    execute: () => {
+      useActivityReportStore.getState().open()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.projectPath)
+    },
+  },
+  {
+    id: 'show-content-graph',
+    label: 'Show Content Graph',
+    description: 'See how entries connect and export the graph',
+    icon: Network,
+    group: 'project',
+    execute: () => {
+      useContentGraphStore.getState().open()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.projectPath)
+    },
+  },
+  {
+    id: 'show-stale-drafts',
+    label: 'Show Stale Drafts',
+    description: "List drafts that haven't been edited in a while",
+    icon: Hourglass,
+    group: 'project',
+    execute: () => {
+      useStaleDraftsStore.getState().open()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.projectPath)
+    },
+  },
+  {
+    id: 'show-embargoed-entries',
+    label: 'Show Embargoed Entries',
+    description: "List entries that can't be published until a set time",
+    icon: Lock,
+    group: 'project',
+    execute: () => {
+      useEmbargoStore.getState().open()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.projectPath)
+    },
+  },
+  {
+    id: 'show-content-calendar',
+    label: 'Show Content Calendar',
+    description: 'See published, scheduled and updated entries by month',
+    icon: CalendarDays,
+    group: 'project',
+    execute: () => {
+      useContentCalendarStore.getState().open()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.projectPath)
+    },
+  },
+  {
+    id: 'show-background-processes',
+    label: 'Show Background Processes',
+    description: 'List and stop installs and syncs started by the editor',
+    icon: Activity,
+    group: 'project',
+    execute: async () => {
+      await showRunningProcesses()
+    },
+    isAvailable: () => true,
+  },
+  {
+    id: 'open-preview',
+    label: 'Open Preview',
+    description: 'Open the current file on the dev server, starting it first',
+    icon: Globe,
+    group: 'project',
+    execute: async () => {
+      await openPreview()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.currentFile && context.projectPath)
+    },
+  },
+  {
+    id: 'stop-dev-server',
+    label: 'Stop Dev Server',
+    description: 'Stop the dev server started by the editor',
+    icon: Square,
+    group: 'project',
+    execute: async () => {
+      await stopDevServer()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.projectPath)
+    },
+  },
 ]
 
 /**
@@ -173,6 +642,19 @@ export const viewModeCommands: AppCommand[] = [
     },
     isAvailable: () => true,
   },
+  {
+    id: 'toggle-preview-pane',
+    label: 'Toggle Preview Pane',
+    description: 'Show the rendered document beside the editor',
+    icon: Columns2,
+    group: 'settings',
+    execute: () => {
+      useUIStore.getState().togglePreviewPane()
+    },
+    isAvailable: (context: CommandContext) => {
+      return Boolean(context.currentFile)
+    },
+  },
 ]
 
 /**
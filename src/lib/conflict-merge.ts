@@ -0,0 +1,99 @@
+import { commands, type ConflictChoice } from '@/lib/bindings'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import { queryClient } from './query-client'
+import { queryKeys } from './query-keys'
+import { toast } from './toast'
+
+function formatValue(value: unknown): string {
+  return value === null || value === undefined
+    ? '(removed)'
+    : JSON.stringify(value)
+}
+
+async function writeResolution(
+  resolutions: Partial<Record<string, ConflictChoice>>,
+  body: ConflictChoice | null
+): Promise<void> {
+  const { currentFile } = useEditorStore.getState()
+  const { projectPath } = useProjectStore.getState()
+  if (!currentFile || !projectPath) return
+
+  const result = await commands.resolveFrontmatterConflict(
+    projectPath,
+    currentFile.path,
+    resolutions,
+    body
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to resolve the conflict', {
+      description: result.error,
+    })
+    return
+  }
+
+  void queryClient.invalidateQueries({
+    queryKey: queryKeys.fileContent(projectPath, currentFile.id),
+  })
+  toast.success('Conflict resolved', {
+    description: 'Review the file, then commit the merge',
+  })
+}
+
+/**
+ * Merges the frontmatter of the open file after a git conflict. Fields
+ * changed on only one branch are merged automatically; when both branches
+ * changed the same field, the differences are listed with an option to keep
+ * this branch's values.
+ */
+export async function resolveFrontmatterConflict(): Promise<void> {
+  const { currentFile, isDirty } = useEditorStore.getState()
+  const { projectPath } = useProjectStore.getState()
+  if (!currentFile || !projectPath) return
+  if (isDirty) {
+    toast.warning('Save the file before resolving the conflict')
+    return
+  }
+
+  const result = await commands.resolveFrontmatterConflict(
+    projectPath,
+    currentFile.path,
+    null,
+    null
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to read the conflict', { description: result.error })
+    return
+  }
+
+  const { conflicts, bodyConflict } = result.data
+  if (conflicts.length === 0 && !bodyConflict) {
+    await writeResolution({}, null)
+    return
+  }
+
+  const ours: Record<string, ConflictChoice> = Object.fromEntries(
+    conflicts.map(c => [c.field, { kind: 'ours' }])
+  )
+  const description = [
+    ...conflicts
+      .slice(0, 5)
+      .map(
+        c =>
+          `${c.field}: ${formatValue(c.ours)} vs ${formatValue(c.theirs)}`
+      ),
+    ...(bodyConflict ? ['The content below the frontmatter differs'] : []),
+  ].join('\n')
+
+  const changed = bodyConflict
+    ? `${conflicts.length} fields and the content`
+    : `${conflicts.length} fields`
+  toast.warning(`Both branches changed ${changed}`, {
+    description,
+    action: {
+      label: 'Keep Mine',
+      onClick: () =>
+        void writeResolution(ours, bodyConflict ? { kind: 'ours' } : null),
+    },
+  })
+}
@@ -0,0 +1,51 @@
+import { commands } from '@/lib/bindings'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import { renderDocumentBody } from './print'
+import { toast } from './toast'
+import { expandIncludes } from './transclusion'
+
+/**
+ * Copies the open document as HTML for sharing a draft, with local image
+ * paths replaced by their deployed URLs from the `assetUrlMappings` project
+ * setting. Warns about images no mapping covers, since recipients won't be
+ * able to load them.
+ */
+export async function copyCurrentDocumentAsHtml(): Promise<void> {
+  const { currentFile, editorContent } = useEditorStore.getState()
+  const { projectPath, currentProjectSettings } = useProjectStore.getState()
+  if (!currentFile || !projectPath) return
+
+  const rendered = await renderDocumentBody(await expandIncludes(editorContent))
+  if (rendered.status === 'error') {
+    toast.error('Failed to copy as HTML', { description: rendered.error })
+    return
+  }
+
+  const result = await commands.rewriteAssetUrls(
+    rendered.data,
+    projectPath,
+    currentFile.path,
+    currentProjectSettings?.assetUrlMappings ?? []
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to copy as HTML', { description: result.error })
+    return
+  }
+
+  const copied = await commands.copyTextToClipboard(result.data.html)
+  if (copied.status === 'error') {
+    toast.error('Failed to copy as HTML', { description: copied.error })
+    return
+  }
+
+  const { unmapped } = result.data
+  if (unmapped.length > 0) {
+    const examples = unmapped.slice(0, 3).join(', ')
+    toast.warning(`Copied HTML with ${unmapped.length} local paths`, {
+      description: `Add an asset URL mapping for: ${examples}`,
+    })
+  } else {
+    toast.success('Copied as HTML')
+  }
+}
@@ -0,0 +1,52 @@
+import { commands } from '@/lib/bindings'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import { getEffectiveContentDirectory } from './project-registry'
+import { ASTRO_PATHS } from './constants'
+import { toast } from './toast'
+
+/**
+ * Opens the daily note for `date` (YYYY-MM-DD, default today) in the current
+ * project, creating it from the project's daily note template if needed.
+ */
+export async function openDailyNote(date?: string): Promise<void> {
+  const { projectPath, currentProjectSettings } = useProjectStore.getState()
+  if (!projectPath) {
+    toast.error('Open a project to use daily notes')
+    return
+  }
+
+  const contentDirectory = getEffectiveContentDirectory(currentProjectSettings)
+  const contentDirectoryOverride =
+    contentDirectory !== ASTRO_PATHS.CONTENT_DIR ? contentDirectory : null
+
+  const result = await commands.openDailyNote(
+    projectPath,
+    contentDirectoryOverride,
+    currentProjectSettings?.dailyNotePattern ?? null,
+    currentProjectSettings?.dailyNoteTemplate ?? null,
+    currentProjectSettings?.frontmatterMappings.publishedDate ?? null,
+    date ?? null
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to open daily note', { description: result.error })
+    return
+  }
+
+  const entry = await commands.resolveFileEntry(
+    result.data.filePath,
+    projectPath,
+    contentDirectoryOverride
+  )
+  if (entry.status === 'error' || !entry.data) {
+    toast.warning("The daily note isn't part of a content collection", {
+      description: `Check the daily note pattern. Note created at ${result.data.filePath}`,
+    })
+    return
+  }
+
+  useEditorStore.getState().openFile(entry.data)
+  if (result.data.created) {
+    toast.success(`Created daily note for ${result.data.date}`)
+  }
+}
@@ -213,4 +213,18 @@ describe('todayIsoDate', () => {
     expect(parsed!.getMonth()).toBe(now.getMonth())
     expect(parsed!.getDate()).toBe(now.getDate())
   })
+
+  it('returns today in the given timezone', () => {
+    const ahead = todayIsoDate('Pacific/Kiritimati') // UTC+14
+    const behind = todayIsoDate('Etc/GMT+12') // UTC-12
+
+    expect(ahead).toMatch(/^\d{4}-\d{2}-\d{2}$/)
+    expect(behind).toMatch(/^\d{4}-\d{2}-\d{2}$/)
+    // 26 hours apart, so always on different days
+    expect(ahead > behind).toBe(true)
+  })
+
+  it('falls back to the local date for unknown timezones', () => {
+    expect(todayIsoDate('Not/AZone')).toBe(todayIsoDate())
+  })
 })
@@ -48,6 +48,19 @@ export function parseIsoDate(dateString: string): Date | undefined {
   return date
 }
 
-export function todayIsoDate(): string {
+/**
+ * Today's date as YYYY-MM-DD, in `timeZone` (an IANA name such as
+ * "Europe/London") when given, otherwise in the local timezone. Unknown
+ * timezones fall back to local.
+ */
+export function todayIsoDate(timeZone?: string): string {
+  if (timeZone) {
+    try {
+      // en-CA formats dates as YYYY-MM-DD
+      return new Intl.DateTimeFormat('en-CA', { timeZone }).format(new Date())
+    } catch {
+      // Invalid timeZone throws a RangeError
+    }
+  }
   return formatIsoDate(new Date())
 }
@@ -0,0 +1,76 @@
+import { commands } from '@/lib/bindings'
+import { openUrl } from '@tauri-apps/plugin-opener'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import {
+  getCollectionSettings,
+  getEffectiveContentDirectory,
+} from './project-registry'
+import { ASTRO_PATHS } from './constants'
+import { toast } from './toast'
+
+/**
+ * Opens the current file on the project's dev server, starting the server
+ * first if nothing is running
+ */
+export async function openPreview(): Promise<void> {
+  const { currentFile } = useEditorStore.getState()
+  const { projectPath, currentProjectSettings } = useProjectStore.getState()
+  if (!currentFile || !projectPath) return
+
+  const contentDirectory = getEffectiveContentDirectory(currentProjectSettings)
+  const { urlPattern } = getCollectionSettings(
+    currentProjectSettings,
+    currentFile.collection
+  )
+  const resolve = () =>
+    commands.getPreviewUrl(
+      projectPath,
+      currentFile.path,
+      contentDirectory !== ASTRO_PATHS.CONTENT_DIR ? contentDirectory : null,
+      urlPattern || null
+    )
+
+  let result = await resolve()
+  if (result.status === 'ok' && !result.data.url) {
+    const started = commands.startDevServer(projectPath)
+    toast.promise(started, {
+      loading: 'Starting dev server...',
+      success: 'Dev server started',
+      error: 'Failed to start dev server',
+    })
+    const server = await started
+    if (server.status === 'error') {
+      toast.error('Failed to start dev server', { description: server.error })
+      return
+    }
+    result = await resolve()
+  }
+
+  if (result.status === 'error') {
+    toast.error('Failed to open preview', { description: result.error })
+    return
+  }
+  if (!result.data.url) {
+    toast.error('Dev server is not reachable', {
+      description: `Expected ${result.data.route} to be served locally`,
+    })
+    return
+  }
+  await openUrl(result.data.url)
+}
+
+/**
+ * Stops the dev server the editor started for the open project
+ */
+export async function stopDevServer(): Promise<void> {
+  const projectPath = useProjectStore.getState().projectPath
+  if (!projectPath) return
+
+  const result = await commands.stopDevServer(projectPath)
+  if (result.status === 'error') {
+    toast.error('Failed to stop dev server', { description: result.error })
+    return
+  }
+  toast.success('Dev server stopped')
+}
@@ -25,7 +25,7 @@ export { CommandRegistry, globalCommandRegistry } from './CommandRegistry'
 export { createEditorCommandRegistry } from './editorCommands'
 export {
   createMenuCommands,
-  updateFormatMenuState,
+  updateMenuContext,
   exportMenuCommands,
   cleanupMenuCommands,
 } from './menuIntegration'
@@ -1,4 +1,4 @@
-import { commands } from '@/lib/bindings'
+import { commands, type MenuContext } from '@/lib/bindings'
 import { globalCommandRegistry } from './CommandRegistry'
 import { HeadingLevel } from '../markdown/types'
 
@@ -33,16 +33,16 @@ export const createMenuCommands = (): MenuCommands => {
 }
 
 /**
- * Update menu state based on editor focus
+ * Update native menu items based on the current editor context
  */
-export const updateFormatMenuState = async (
-  enabled: boolean
+export const updateMenuContext = async (
+  context: MenuContext
 ): Promise<void> => {
   try {
-    await commands.updateFormatMenuState(enabled)
+    await commands.updateMenuContext(context)
   } catch (error) {
     // eslint-disable-next-line no-console
-    console.error('Failed to update format menu state:', error)
+    console.error('Failed to update menu context:', error)
   }
 }
 
@@ -92,6 +92,7 @@ export const processDroppedFile = async (
       copyStrategy: 'always',
       currentFilePath: currentFile.path,
       useRelativePaths,
+      usePathAliases: effectiveSettings.usePathAliases,
     })
 
     // Format as markdown (editor-specific concern)
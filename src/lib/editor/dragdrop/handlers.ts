@@ -1,7 +1,8 @@
 import { EditorView } from '@codemirror/view'
 import { useEditorStore } from '../../../store/editorStore'
 import { useProjectStore } from '../../../store/projectStore'
-import { processDroppedFiles } from './fileProcessing'
+import { insertImageFolder } from '../insert-gallery'
+import { extractFilename, processDroppedFiles } from './fileProcessing'
 import { validateDropContext, buildFallbackMarkdownForPaths } from './edgeCases'
 import { FileDropPayload, DropResult } from './types'
 
@@ -121,6 +122,15 @@ export const handleTauriFileDrop = async (
     }
   }
 
+  // A single dropped folder becomes an image gallery
+  const folderPath = filePaths.length === 1 ? filePaths[0] : undefined
+  if (folderPath && !extractFilename(folderPath).includes('.')) {
+    const imported = await insertImageFolder(folderPath, editorView)
+    if (imported) {
+      return { success: true, insertText: '' }
+    }
+  }
+
   // Process files normally
   try {
     const processedFiles = await processDroppedFiles(
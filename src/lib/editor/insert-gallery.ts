@@ -0,0 +1,108 @@
+import type { EditorView } from '@codemirror/view'
+import { open } from '@tauri-apps/plugin-dialog'
+import { commands } from '@/types'
+import { useEditorStore } from '../../store/editorStore'
+import { useProjectStore } from '../../store/projectStore'
+import { ASTRO_PATHS } from '../constants'
+import { getCollectionSchemaFields } from '../frontmatter-flags'
+import { getCollectionSettings } from '../project-registry'
+import { FieldType } from '../schema'
+import { toast } from '../toast'
+import { globalCommandRegistry } from './commands'
+
+/**
+ * Imports every image in a folder (renamed, resized and deduplicated like a
+ * dropped image) and adds them as a gallery. Collections whose schema has an
+ * array of images get the paths appended to that field; otherwise a gallery
+ * block is inserted at the cursor, wrapped in the collection's gallery
+ * component in MDX files.
+ *
+ * @returns false when the import failed
+ */
+export async function insertImageFolder(
+  folderPath: string,
+  view: EditorView
+): Promise<boolean> {
+  const { currentFile, frontmatter, updateFrontmatterField } =
+    useEditorStore.getState()
+  const { projectPath, currentProjectSettings } = useProjectStore.getState()
+  if (!currentFile || !projectPath) return false
+
+  const collection = currentFile.collection
+  const settings = getCollectionSettings(currentProjectSettings, collection)
+  const { assetsDirectory } = settings.pathOverrides
+  const galleryComponent = currentProjectSettings?.collections?.find(
+    c => c.name === collection
+  )?.settings.galleryComponent
+
+  const result = await commands.importImageFolder(
+    folderPath,
+    projectPath,
+    collection,
+    currentFile.path,
+    {
+      assetsDirectory:
+        assetsDirectory !== ASTRO_PATHS.ASSETS_DIR ? assetsDirectory : null,
+      useRelativePaths: settings.useRelativeAssetPaths,
+      usePathAliases: settings.usePathAliases,
+      maxWidth: currentProjectSettings?.imageMaxWidth ?? null,
+      component:
+        currentFile.extension === 'mdx' ? (galleryComponent ?? null) : null,
+    }
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to import images', { description: result.error })
+    return false
+  }
+
+  const { images, block, skipped } = result.data
+  if (images.length === 0) {
+    toast.info('No images found in that folder')
+    return true
+  }
+
+  const galleryField = getCollectionSchemaFields(projectPath, collection).find(
+    field => field.type === FieldType.Array && field.subType === FieldType.Image
+  )
+  if (galleryField) {
+    const existing = frontmatter[galleryField.name]
+    const current = Array.isArray(existing) ? existing : []
+    const added = images
+      .map(image => image.path)
+      .filter(path => !current.includes(path))
+    updateFrontmatterField(galleryField.name, [...current, ...added])
+  } else {
+    const { from, to } = view.state.selection.main
+    view.dispatch({
+      changes: { from, to, insert: block },
+      selection: { anchor: from + block.length },
+    })
+    view.focus()
+  }
+
+  const reused = images.filter(image => image.reused).length
+  const sanitized = images.filter(image => image.sanitized.length > 0).length
+  const notes = [
+    reused > 0 ? `${reused} already in assets` : '',
+    sanitized > 0 ? `Removed active content from ${sanitized} SVGs` : '',
+    skipped.length > 0 ? `Skipped ${skipped.join(', ')}` : '',
+  ].filter(Boolean)
+  const target = galleryField ? ` to ${galleryField.label}` : ''
+  toast.success(`Added ${images.length} images${target}`, {
+    description: notes.length > 0 ? notes.join('. ') : undefined,
+  })
+  return true
+}
+
+/**
+ * Picks a folder with the native dialog and adds its images as a gallery.
+ */
+export async function insertGalleryFromDialog(): Promise<void> {
+  const view = globalCommandRegistry.getEditorView()
+  if (!view || !useEditorStore.getState().currentFile) return
+
+  const selected = await open({ directory: true, multiple: false })
+  if (!selected) return
+
+  await insertImageFolder(selected, view)
+}
@@ -0,0 +1,36 @@
+import { open } from '@tauri-apps/plugin-dialog'
+import { useEditorStore } from '../../store/editorStore'
+import { useProjectStore } from '../../store/projectStore'
+import { IMAGE_EXTENSIONS } from '../files'
+import { globalCommandRegistry } from './commands'
+import { processDroppedFile } from './dragdrop'
+
+/**
+ * Picks an image with the native file dialog and inserts it at the cursor,
+ * copying it to the assets directory the same way a dropped image is.
+ */
+export async function insertImageFromDialog(): Promise<void> {
+  const { currentFile } = useEditorStore.getState()
+  const { projectPath } = useProjectStore.getState()
+  const view = globalCommandRegistry.getEditorView()
+  if (!currentFile || !projectPath || !view) return
+
+  const selected = await open({
+    multiple: false,
+    filters: [{ name: 'Images', extensions: [...IMAGE_EXTENSIONS] }],
+  })
+  if (!selected) return
+
+  const { markdownText } = await processDroppedFile(
+    selected,
+    projectPath,
+    currentFile.collection
+  )
+
+  const { from, to } = view.state.selection.main
+  view.dispatch({
+    changes: { from, to, insert: markdownText },
+    selection: { anchor: from + markdownText.length },
+  })
+  view.focus()
+}
@@ -1,7 +1,7 @@
 import { describe, it, expect, vi, beforeEach } from 'vitest'
 import { EditorView } from '@codemirror/view'
 import { EditorState, EditorSelection } from '@codemirror/state'
-import { handlePaste, isClipboardUrl } from './handlers'
+import { handlePaste, isClipboardUrl, isTabularText } from './handlers'
 
 // Mock URL detection
 vi.mock('../urls/detection', () => ({
@@ -77,6 +77,33 @@ describe('Paste Handlers', () => {
     })
   })
 
+  describe('isTabularText', () => {
+    it('should detect rows with a consistent number of tabs', () => {
+      const text = 'Name\tPrice\nApple\t1.20\nPear\t0.90\n'
+      expect(isTabularText(text)).toBe(true)
+    })
+
+    it('should reject a single row or inconsistent tabs', () => {
+      expect(isTabularText('Name\tPrice')).toBe(false)
+      expect(isTabularText('a\tb\tc\nd\te')).toBe(false)
+    })
+
+    it('should not treat tab-indented lines as columns', () => {
+      expect(isTabularText('\tconst a = 1\n\tconst b = 2')).toBe(false)
+      expect(isTabularText('\t\tif (a) {\n\t\t}')).toBe(false)
+      expect(isTabularText('\tName\tPrice\n\tApple\t1.20')).toBe(true)
+    })
+
+    it('should not treat comma-separated prose as a table', () => {
+      expect(isTabularText('First, we write.\nThen, we edit.')).toBe(false)
+    })
+
+    it('should accept delimited text copied with an HTML table', () => {
+      const html = '<table><tr><td>a</td></tr></table>'
+      expect(isTabularText('a,b\n1,2', html)).toBe(true)
+    })
+  })
+
   describe('handlePaste', () => {
     it('should return false when no clipboard data', () => {
       const view = createMockView('Hello world', { from: 0, to: 5 })
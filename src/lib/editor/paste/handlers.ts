@@ -1,5 +1,10 @@
 import { EditorView } from '@codemirror/view'
 import { EditorSelection } from '@codemirror/state'
+import { commands } from '@/lib/bindings'
+import { useEditorStore } from '@/store/editorStore'
+import { useProjectStore } from '@/store/projectStore'
+import { getMarkdownFlavor } from '../../markdown-flavor'
+import { getCollectionSettings } from '../../project-registry'
 import { isValidUrl } from '../urls/detection'
 
 /**
@@ -13,6 +18,14 @@ export const handlePaste = (
   event: ClipboardEvent
 ): boolean => {
   const clipboardText = event.clipboardData?.getData('text/plain')
+  if (
+    clipboardText &&
+    isTabularText(clipboardText, event.clipboardData?.getData('text/html'))
+  ) {
+    void pasteAsTable(view, clipboardText)
+    return true // Inserted asynchronously once Rust has built the table
+  }
+
   if (!clipboardText || !isValidUrl(clipboardText.trim())) {
     return false // Let default paste behavior handle non-URLs
   }
@@ -35,6 +48,12 @@ export const handlePaste = (
     return true // Prevent default paste
   }
 
+  const { currentFile } = useEditorStore.getState()
+  if (currentFile?.extension === 'mdx') {
+    void pasteAsEmbed(view, clipboardText.trim())
+    return true // Inserted asynchronously once Rust has matched a component
+  }
+
   return false // Let default paste behavior handle if no text selected
 }
 
@@ -47,3 +66,127 @@ export const isClipboardUrl = (clipboardText: string | null): boolean => {
   if (clipboardText === null || clipboardText === undefined) return false
   return isValidUrl(clipboardText.trim())
 }
+
+/**
+ * Check if clipboard text is spreadsheet data: at least two rows with the same
+ * number of columns (two or more) separated by tabs, or any delimited text
+ * copied alongside an HTML table. Leading tabs are indentation rather than
+ * separators, so tab-indented code isn't a table, and comma-separated prose is
+ * deliberately not treated as tabular.
+ * @param clipboardText - Plain text from clipboard
+ * @param clipboardHtml - HTML from clipboard, if any
+ * @returns true if the text should be pasted as a markdown table
+ */
+export const isTabularText = (
+  clipboardText: string,
+  clipboardHtml?: string | null
+): boolean => {
+  const lines = clipboardText.split(/\r?\n/).filter(line => line.trim())
+  if (lines.length < 2) return false
+
+  if (clipboardHtml?.includes('<table')) return true
+
+  const columnCounts = lines.map(
+    line => line.replace(/^\s+/, '').split('\t').length
+  )
+  return (
+    columnCounts[0]! >= 2 &&
+    columnCounts.every(count => count === columnCounts[0])
+  )
+}
+
+/**
+ * Replace the selection with a markdown table built from delimited text,
+ * falling back to the raw text if conversion fails
+ */
+const pasteAsTable = async (view: EditorView, text: string): Promise<void> => {
+  const { from, to } = view.state.selection.main
+  const flavor = await getMarkdownFlavor()
+  const result = await commands.convertCsvToTable(
+    text,
+    null,
+    null,
+    null,
+    flavor
+  )
+
+  let insert = text
+  if (result.status === 'ok') {
+    // Tables need blank lines around them to parse as block elements
+    const before = view.state.sliceDoc(Math.max(0, from - 2), from)
+    const prefix = from === 0 || before.endsWith('\n\n') ? '' : '\n\n'
+    insert = `${prefix}${result.data.markdown}\n\n`
+  }
+
+  const docLength = view.state.doc.length
+  const start = Math.min(from, docLength)
+  view.dispatch({
+    changes: { from: start, to: Math.min(to, docLength), insert },
+    selection: EditorSelection.cursor(start + insert.length),
+  })
+}
+
+/**
+ * Insert an `import` line after the frontmatter, unless the document already
+ * contains it
+ */
+const insertImport = (view: EditorView, importLine: string): void => {
+  const doc = view.state.doc.toString()
+  if (doc.includes(importLine)) return
+
+  const frontmatter = /^---\r?\n[\s\S]*?\r?\n---[ \t]*(\r?\n|$)/.exec(doc)
+  const position = frontmatter ? frontmatter[0].length : 0
+  const needsNewline = frontmatter !== null && !frontmatter[1]
+  const rest = doc.slice(position)
+  const separator =
+    rest.startsWith('import ') || rest.startsWith('\n') ? '' : '\n'
+
+  view.dispatch({
+    changes: {
+      from: position,
+      insert: `${needsNewline ? '\n' : ''}${importLine}\n${separator}`,
+    },
+  })
+}
+
+/**
+ * Replace the selection with an MDX embed component when the URL belongs to a
+ * provider the project has a component for (e.g. `<YouTube id="..." />`),
+ * adding the component's import. Other URLs are pasted unchanged.
+ */
+const pasteAsEmbed = async (view: EditorView, url: string): Promise<void> => {
+  const { from, to } = view.state.selection.main
+  const { projectPath, currentProjectSettings } = useProjectStore.getState()
+  const { currentFile } = useEditorStore.getState()
+
+  let insert = url
+  let importLine: string | null = null
+  if (projectPath && currentFile) {
+    const settings = getCollectionSettings(
+      currentProjectSettings,
+      currentFile.collection
+    )
+    const result = await commands.suggestEmbed(
+      url,
+      projectPath,
+      currentFile.path,
+      settings.pathOverrides.mdxComponentsDirectory,
+      settings.usePathAliases
+    )
+    if (result.status === 'ok' && result.data.component) {
+      insert = result.data.snippet
+      importLine = result.data.import
+    }
+  }
+
+  const docLength = view.state.doc.length
+  const start = Math.min(from, docLength)
+  view.dispatch({
+    changes: { from: start, to: Math.min(to, docLength), insert },
+    selection: EditorSelection.cursor(start + insert.length),
+  })
+
+  if (importLine) {
+    insertImport(view, importLine)
+  }
+}
@@ -6,6 +6,8 @@
  * - URL detection in clipboard content
  * - Automatic markdown link creation when pasting URLs over selected text
  * - Integration with existing URL utilities
+ * - Spreadsheet/TSV data pasted as a markdown table (converted in Rust)
+ * - Embed URLs (YouTube, tweets, Vimeo) pasted as MDX components in .mdx files
  *
  * Usage:
  * ```typescript
@@ -19,4 +21,4 @@
  * ```
  */
 
-export { handlePaste, isClipboardUrl } from './handlers'
+export { handlePaste, isClipboardUrl, isTabularText } from './handlers'
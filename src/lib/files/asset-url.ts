@@ -0,0 +1,15 @@
+import { convertFileSrc } from '@tauri-apps/api/core'
+
+/**
+ * Custom protocol registered in `src-tauri/src/lib.rs` that serves media from the
+ * open project (with MIME types, range requests and project-boundary checks).
+ */
+export const PROJECT_ASSET_PROTOCOL = 'project-asset'
+
+/**
+ * Converts an absolute path inside the open project into a URL the webview can load.
+ * Use this instead of calling `convertFileSrc` directly.
+ */
+export function projectAssetUrl(absolutePath: string): string {
+  return convertFileSrc(absolutePath, PROJECT_ASSET_PROTOCOL)
+}
@@ -68,7 +68,8 @@ describe('processFileToAssets', () => {
         '/Users/test/project',
         'blog',
         '/Users/test/project/src/content/blog/post.md',
-        true
+        true,
+        false
       )
       expect(result).toEqual({
         relativePath: 'src/assets/2024-01-15-image.png',
@@ -95,7 +96,8 @@ describe('processFileToAssets', () => {
         'blog',
         'custom/assets',
         '/Users/test/project/src/content/blog/post.md',
-        true
+        true,
+        false
       )
       expect(result).toEqual({
         relativePath: 'custom/assets/2024-01-15-image.png',
@@ -124,7 +126,8 @@ describe('processFileToAssets', () => {
         '/Users/test/project',
         'blog',
         '/Users/test/project/src/content/blog/post.md',
-        true
+        true,
+        false
       )
       expect(commands.isPathInProject).not.toHaveBeenCalled()
       expect(result.wasCopied).toBe(true)
@@ -154,7 +157,8 @@ describe('processFileToAssets', () => {
         '/Users/test/project',
         'blog',
         '/Users/test/project/src/content/blog/post.md',
-        true
+        true,
+        false
       )
       expect(result).toEqual({
         relativePath: 'src/assets/2024-01-15-image.png',
@@ -185,7 +189,8 @@ describe('processFileToAssets', () => {
         '/Users/test/project/images/existing.png',
         '/Users/test/project',
         '/Users/test/project/src/content/blog/post.md',
-        true
+        true,
+        false
       )
       expect(commands.copyFileToAssets).not.toHaveBeenCalled()
       expect(result).toEqual({
@@ -214,7 +219,8 @@ describe('processFileToAssets', () => {
         'blog',
         'custom/assets',
         '/Users/test/project/src/content/blog/post.md',
-        true
+        true,
+        false
       )
       expect(result.wasCopied).toBe(true)
     })
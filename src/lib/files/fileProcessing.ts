@@ -28,6 +28,7 @@ export async function processFileToAssets(
     copyStrategy,
     currentFilePath,
     useRelativePaths,
+    usePathAliases = false,
   } = options
 
   // Extract filename for result
@@ -60,7 +61,8 @@ export async function processFileToAssets(
         collection,
         assetsDirectory,
         currentFilePath,
-        useRelativePaths
+        useRelativePaths,
+        usePathAliases
       )
     } else {
       // Use default assets directory
@@ -69,7 +71,8 @@ export async function processFileToAssets(
         projectPath,
         collection,
         currentFilePath,
-        useRelativePaths
+        useRelativePaths,
+        usePathAliases
       )
     }
 
@@ -85,7 +88,8 @@ export async function processFileToAssets(
       sourcePath,
       projectPath,
       currentFilePath,
-      useRelativePaths
+      useRelativePaths,
+      usePathAliases
     )
     if (result.status === 'error') {
       throw new Error(result.error)
@@ -5,6 +5,9 @@ export type {
   ProcessFileToAssetsResult,
 } from './types'
 
+// Asset URL exports
+export { projectAssetUrl, PROJECT_ASSET_PROTOCOL } from './asset-url'
+
 // Constants exports
 export {
   IMAGE_EXTENSIONS,
@@ -17,8 +20,11 @@ export { filterFilesByDraft } from './filtering'
 export {
   sortFilesByPublishedDate,
   getPublishedDate,
+  getPublishedDateValue,
   getTitle,
   getSortOptionsForCollection,
+  getTextComparator,
+  getListingOrder,
   sortFiles,
 } from './sorting'
 export type { FieldMappings, SortOption, SortConfig } from './sorting'
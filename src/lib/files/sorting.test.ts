@@ -1,5 +1,11 @@
 import { describe, it, expect } from 'vitest'
-import { getPublishedDate, sortFilesByPublishedDate } from './sorting'
+import {
+  getPublishedDate,
+  getPublishedDateValue,
+  getTitle,
+  sortFiles,
+  sortFilesByPublishedDate,
+} from './sorting'
 import type { FileEntry } from '@/types'
 
 const mockMappings = {
@@ -81,6 +87,26 @@ describe('getPublishedDate', () => {
   })
 })
 
+describe('getPublishedDateValue', () => {
+  it('should return the date as written', () => {
+    const frontmatter = { pubDate: '2024-01-15' }
+    expect(getPublishedDateValue(frontmatter, ['date', 'pubDate'])).toBe(
+      '2024-01-15'
+    )
+  })
+
+  it('should skip invalid dates', () => {
+    const frontmatter = { date: 'someday', pubDate: '2024-01-15T10:30:00Z' }
+    expect(getPublishedDateValue(frontmatter, ['date', 'pubDate'])).toBe(
+      '2024-01-15T10:30:00Z'
+    )
+  })
+
+  it('should return null when there is no date', () => {
+    expect(getPublishedDateValue({}, 'publishedDate')).toBeNull()
+  })
+})
+
 describe('sortFilesByPublishedDate', () => {
   it('should sort files by date descending (newest first)', () => {
     const files: FileEntry[] = [
@@ -145,6 +171,20 @@ describe('sortFilesByPublishedDate', () => {
     expect(result.map(f => f.id)).toEqual(['2', '3', '1']) // Apple, Mango, Zebra
   })
 
+  it('should order numbered titles naturally unless the collation is ordinal', () => {
+    const files: FileEntry[] = [
+      createMockFile({ id: '1', frontmatter: { title: 'Part 10' } }),
+      createMockFile({ id: '2', frontmatter: { title: 'part 2' } }),
+      createMockFile({ id: '3', frontmatter: { title: 'Étude' } }),
+    ]
+
+    const natural = sortFilesByPublishedDate(files, mockMappings)
+    expect(natural.map(f => f.id)).toEqual(['3', '2', '1'])
+
+    const ordinal = sortFilesByPublishedDate(files, mockMappings, 'ordinal')
+    expect(ordinal.map(f => f.id)).toEqual(['1', '2', '3'])
+  })
+
   it('should sort undated files by filename when no title', () => {
     const files: FileEntry[] = [
       createMockFile({ id: '1', name: 'zebra', frontmatter: {} }),
@@ -246,3 +286,33 @@ describe('sortFilesByPublishedDate', () => {
     expect(result[1]).toBe(file1)
   })
 })
+
+describe('listing stats', () => {
+  it('should fall back to the heading title before the filename', () => {
+    expect(getTitle(createMockFile({ title: 'From heading' }), 'title')).toBe(
+      'From heading'
+    )
+    expect(
+      getTitle(
+        createMockFile({ frontmatter: { title: 'Set' }, title: 'Set' }),
+        'title'
+      )
+    ).toBe('Set')
+    expect(getTitle(createMockFile({ name: 'plain' }), 'title')).toBe('plain')
+  })
+
+  it('should sort by word count with uncounted files at the bottom', () => {
+    const files: FileEntry[] = [
+      createMockFile({ id: 'short', word_count: 10 }),
+      createMockFile({ id: 'unknown' }),
+      createMockFile({ id: 'long', word_count: 900 }),
+    ]
+
+    const result = sortFiles(
+      files,
+      { mode: 'words', direction: 'desc' },
+      mockMappings
+    )
+    expect(result.map(file => file.id)).toEqual(['long', 'short', 'unknown'])
+  })
+})
@@ -2,8 +2,10 @@
  * File sorting utilities for sidebar file lists
  */
 
-import type { FileEntry } from '@/types'
+import type { Collation, FileEntry } from '@/types'
 import { CompleteSchema, FieldType } from '../schema'
+import { getEffectiveSettings } from '../project-registry/effective-settings'
+import type { ProjectSettings } from '../project-registry/types'
 
 /**
  * Maps semantic field purposes to actual frontmatter field names.
@@ -48,16 +50,56 @@ export interface SortConfig {
 }
 
 /**
- * Get published date from frontmatter
+ * Compares titles and file names the way the Rust listing commands order them
+ * (`utils/collation.rs`). Names differing only in case or accents compare
+ * equal, so the stable sort keeps the listing's order for them.
+ *
+ * @param collation - The project's collation (defaults to natural order)
+ */
+export function getTextComparator(
+  collation: Collation = 'natural'
+): (a: string, b: string) => number {
+  if (collation === 'ordinal') {
+    return (a, b) => (a < b ? -1 : a > b ? 1 : 0)
+  }
+  return new Intl.Collator(undefined, {
+    numeric: collation === 'natural',
+    sensitivity: 'base',
+  }).compare
+}
+
+/**
+ * Arguments for the listing commands so they return a collection's files in
+ * title order: the project's collation and the collection's title field. The
+ * title and draft fields also fill in each entry's `title` and `is_draft`.
+ */
+export function getListingOrder(
+  projectSettings: ProjectSettings | null | undefined,
+  collectionName: string
+): { collation: Collation | null; titleField: string; draftField: string } {
+  const { frontmatterMappings } = getEffectiveSettings(
+    projectSettings,
+    collectionName || undefined
+  )
+  return {
+    collation: projectSettings?.collation ?? null,
+    titleField: frontmatterMappings.title,
+    draftField: frontmatterMappings.draft,
+  }
+}
+
+/**
+ * Get the published date from frontmatter as written, so a date-only value
+ * isn't shifted into another day by timezone conversion
  *
  * @param frontmatter - File frontmatter object
  * @param publishedDateField - Field name(s) to check for published date
- * @returns Date object if found and valid, null otherwise
+ * @returns The first valid date value found, null otherwise
  */
-export function getPublishedDate(
+export function getPublishedDateValue(
   frontmatter: Record<string, unknown>,
   publishedDateField: string | string[]
-): Date | null {
+): string | Date | null {
   const dateFields = Array.isArray(publishedDateField)
     ? publishedDateField
     : [publishedDateField]
@@ -67,7 +109,7 @@ export function getPublishedDate(
     if (value) {
       const date = new Date(value as string)
       if (!isNaN(date.getTime())) {
-        return date
+        return value instanceof Date ? value : String(value)
       }
     }
   }
@@ -75,7 +117,23 @@ export function getPublishedDate(
 }
 
 /**
- * Get display title from file, falling back to filename (without extension)
+ * Get published date from frontmatter
+ *
+ * @param frontmatter - File frontmatter object
+ * @param publishedDateField - Field name(s) to check for published date
+ * @returns Date object if found and valid, null otherwise
+ */
+export function getPublishedDate(
+  frontmatter: Record<string, unknown>,
+  publishedDateField: string | string[]
+): Date | null {
+  const value = getPublishedDateValue(frontmatter, publishedDateField)
+  return value === null ? null : new Date(value)
+}
+
+/**
+ * Get display title from file, falling back to the body's first heading (as
+ * found by the listing commands), then the filename (without extension)
  */
 export function getTitle(file: FileEntry, titleField: string): string {
   if (
@@ -85,6 +143,8 @@ export function getTitle(file: FileEntry, titleField: string): string {
     return file.frontmatter[titleField]
   }
 
+  if (file.title) return file.title
+
   const filename = file.name || file.path.split('/').pop() || 'Untitled'
   return filename.replace(/\.(md|mdx)$/, '')
 }
@@ -99,14 +159,17 @@ export function getTitle(file: FileEntry, titleField: string): string {
  *
  * @param files - Array of file entries to sort
  * @param mappings - Frontmatter field mappings (contains publishedDate and title fields)
+ * @param collation - How titles are compared (defaults to natural order)
  * @returns New sorted array (does not mutate original)
  */
 export function sortFilesByPublishedDate(
   files: FileEntry[],
-  mappings: FieldMappings | null
+  mappings: FieldMappings | null,
+  collation?: Collation
 ): FileEntry[] {
   const titleField = mappings?.title || 'title'
   const publishedDateField = mappings?.publishedDate || 'publishedDate'
+  const compareText = getTextComparator(collation)
 
   return [...files].sort((a, b) => {
     const dateA = getPublishedDate(a.frontmatter || {}, publishedDateField)
@@ -114,7 +177,7 @@ export function sortFilesByPublishedDate(
 
     // Undated files go to top, sorted alphabetically among themselves
     if (!dateA && !dateB) {
-      return getTitle(a, titleField).localeCompare(getTitle(b, titleField))
+      return compareText(getTitle(a, titleField), getTitle(b, titleField))
     }
     if (!dateA) return -1
     if (!dateB) return 1
@@ -122,7 +185,7 @@ export function sortFilesByPublishedDate(
     // Dated files: newest first, alphabetical tiebreaker
     const dateDiff = dateB.getTime() - dateA.getTime()
     if (dateDiff !== 0) return dateDiff
-    return getTitle(a, titleField).localeCompare(getTitle(b, titleField))
+    return compareText(getTitle(a, titleField), getTitle(b, titleField))
   })
 }
 
@@ -178,13 +241,16 @@ export function getSortOptionsForCollection(
     }
   }
 
-  // Always add last modified
-  options.push({
-    id: 'modified',
-    label: 'Last Modified',
-    type: 'date',
-    field: null,
-  })
+  // Always add last modified and word count
+  options.push(
+    {
+      id: 'modified',
+      label: 'Last Modified',
+      type: 'date',
+      field: null,
+    },
+    { id: 'words', label: 'Word Count', type: 'numeric', field: null }
+  )
 
   return options
 }
@@ -195,19 +261,22 @@ export function getSortOptionsForCollection(
  * @param files - Array of file entries to sort
  * @param config - Sort configuration (mode and direction)
  * @param mappings - Frontmatter field mappings
+ * @param collation - How titles and filenames are compared (defaults to natural order)
  * @returns New sorted array (does not mutate original)
  */
 export function sortFiles(
   files: FileEntry[],
   config: SortConfig,
-  mappings: FieldMappings | null
+  mappings: FieldMappings | null,
+  collation?: Collation
 ): FileEntry[] {
   // Default mode uses existing behavior
   if (config.mode === 'default') {
-    return sortFilesByPublishedDate(files, mappings)
+    return sortFilesByPublishedDate(files, mappings, collation)
   }
 
   const titleField = mappings?.title || 'title'
+  const compareText = getTextComparator(collation)
 
   return [...files].sort((a, b) => {
     let valueA: unknown
@@ -220,13 +289,17 @@ export function sortFiles(
         valueB = b.name
         break
       case 'title':
-        valueA = a.frontmatter?.[titleField]
-        valueB = b.frontmatter?.[titleField]
+        valueA = a.frontmatter?.[titleField] ?? a.title
+        valueB = b.frontmatter?.[titleField] ?? b.title
         break
       case 'modified':
         valueA = a.last_modified
         valueB = b.last_modified
         break
+      case 'words':
+        valueA = a.word_count
+        valueB = b.word_count
+        break
       case 'order':
         valueA = a.frontmatter?.order
         valueB = b.frontmatter?.order
@@ -261,7 +334,7 @@ export function sortFiles(
       // For string comparisons, ensure we have strings
       const strA = typeof valueA === 'string' ? valueA : ''
       const strB = typeof valueB === 'string' ? valueB : ''
-      comparison = strA.localeCompare(strB)
+      comparison = compareText(strA, strB)
     }
 
     return config.direction === 'desc' ? -comparison : comparison
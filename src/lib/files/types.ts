@@ -23,6 +23,8 @@ export interface ProcessFileToAssetsOptions {
   currentFilePath: string
   /** Whether to use relative paths (true) or absolute from project root (false) */
   useRelativePaths: boolean
+  /** Whether to prefer tsconfig.json path aliases (e.g., '@assets/image.png') when available */
+  usePathAliases?: boolean
 }
 
 /**
@@ -0,0 +1,220 @@
+import {
+  commands,
+  type Collection,
+  type FrontmatterFlagField,
+} from '@/lib/bindings'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import { queryClient } from './query-client'
+import { queryKeys } from './query-keys'
+import {
+  deserializeCompleteSchema,
+  FieldType,
+  type SchemaField,
+} from './schema'
+import { toast } from './toast'
+import { getEffectiveFrontmatterMappings } from './project-registry'
+
+/**
+ * Top-level boolean and enum fields, which get Edit > Frontmatter menu items
+ */
+export function getFlagFields(fields: SchemaField[]): SchemaField[] {
+  return fields.filter(
+    field =>
+      !field.isNested &&
+      (field.type === FieldType.Boolean ||
+        (field.type === FieldType.Enum && !!field.enumValues?.length))
+  )
+}
+
+/**
+ * Menu entries for a collection's flag fields
+ */
+export function toFlagMenuFields(
+  fields: SchemaField[]
+): FrontmatterFlagField[] {
+  return getFlagFields(fields).map(field => ({
+    name: field.name,
+    label: field.label,
+    isEnum: field.type === FieldType.Enum,
+  }))
+}
+
+/**
+ * Schema fields for a collection, read from the cached collections query
+ */
+export function getCollectionSchemaFields(
+  projectPath: string,
+  collectionName: string
+): SchemaField[] {
+  const collections = queryClient.getQueryData<Collection[]>(
+    queryKeys.collections(projectPath)
+  )
+  const collection = collections?.find(c => c.name === collectionName)
+  if (!collection?.complete_schema) return []
+  return deserializeCompleteSchema(collection.complete_schema)?.fields ?? []
+}
+
+/**
+ * Toggles a boolean field (or cycles an enum field) in the current file's
+ * frontmatter. Unsaved edits are saved first, since Rust updates the file on
+ * disk and the editor reloads it; if they can't be, the file is left alone.
+ */
+export async function toggleFrontmatterFlag(fieldName: string): Promise<void> {
+  const { currentFile, isDirty, saveFile } = useEditorStore.getState()
+  const { projectPath } = useProjectStore.getState()
+  if (!currentFile || !projectPath) return
+
+  const field = getFlagFields(
+    getCollectionSchemaFields(projectPath, currentFile.collection)
+  ).find(f => f.name === fieldName)
+  const values =
+    field?.type === FieldType.Enum ? (field.enumValues ?? null) : null
+
+  if (isDirty) {
+    await saveFile(false)
+  }
+  // Rust would overwrite edits that failed to save, or a conflicting file
+  const { isDirty: stillDirty, saveConflict } = useEditorStore.getState()
+  if (stillDirty || saveConflict) {
+    toast.error(`Failed to update ${field?.label ?? fieldName}`, {
+      description: 'Save the file before toggling its frontmatter',
+    })
+    return
+  }
+
+  const result = await commands.toggleFrontmatterFlag(
+    currentFile.path,
+    projectPath,
+    fieldName,
+    values
+  )
+  if (result.status === 'error') {
+    toast.error(`Failed to update ${field?.label ?? fieldName}`, {
+      description: result.error,
+    })
+  }
+}
+
+/**
+ * Runs the collection's publish gates against the saved file. Failed blocking
+ * gates are reported and stop the publish; other failures are returned as a
+ * warning to show once the file is published. Embargoed entries are always
+ * refused, even without gates.
+ */
+async function checkPublishGates(
+  projectPath: string,
+  filePath: string,
+  collectionName: string
+): Promise<{ ready: boolean; warnings: string | null }> {
+  const { currentProjectSettings } = useProjectStore.getState()
+  const collectionSettings = currentProjectSettings?.collections?.find(
+    c => c.name === collectionName
+  )?.settings
+  const gates = collectionSettings?.publishGates ?? []
+
+  const collection = queryClient
+    .getQueryData<Collection[]>(queryKeys.collections(projectPath))
+    ?.find(c => c.name === collectionName)
+  if (!collection) return { ready: true, warnings: null }
+
+  const result = await commands.checkPublishReadiness(
+    projectPath,
+    filePath,
+    collection.path,
+    gates,
+    collectionSettings?.urlPattern ?? null
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to check publish readiness', {
+      description: result.error,
+    })
+    return { ready: false, warnings: null }
+  }
+
+  const failed = result.data.gates.filter(gate => !gate.passed)
+  const description = failed
+    .map(gate => (gate.detail ? `${gate.label}: ${gate.detail}` : gate.label))
+    .join('\n')
+  if (!result.data.ready) {
+    toast.error('Not ready to publish', { description })
+  }
+  return {
+    ready: result.data.ready,
+    warnings: failed.length > 0 ? description : null,
+  }
+}
+
+/**
+ * Publishes the current file (the toolbar's Publish button): Rust turns off
+ * its draft field, sets an empty publish date, and renames it with a date
+ * prefix if the rest of its collection is named that way. Rust refuses files
+ * that aren't drafts, and the collection's publish gates must pass first.
+ */
+export async function publishCurrentFile(): Promise<void> {
+  const { currentFile, isDirty, saveFile } = useEditorStore.getState()
+  const { currentProjectSettings, projectPath } = useProjectStore.getState()
+  if (!currentFile || !projectPath) return
+
+  const mappings = getEffectiveFrontmatterMappings(
+    currentProjectSettings,
+    currentFile.collection
+  )
+
+  // Gates read the file from disk
+  if (isDirty) {
+    await saveFile(false)
+  }
+  const { ready, warnings } = await checkPublishGates(
+    projectPath,
+    currentFile.path,
+    currentFile.collection
+  )
+  if (!ready) return
+
+  const collection = queryClient
+    .getQueryData<Collection[]>(queryKeys.collections(projectPath))
+    ?.find(c => c.name === currentFile.collection)
+  // A collection's date formats replace the project's
+  const dateFormats =
+    currentProjectSettings?.collections?.find(
+      c => c.name === currentFile.collection
+    )?.settings.dateFormats ?? currentProjectSettings?.dateFormats
+  // Links to the entry's URL are found through its collection's URL pattern
+  const urlPatterns: Record<string, string> = {}
+  for (const { name, settings } of currentProjectSettings?.collections ?? []) {
+    if (settings.urlPattern) urlPatterns[name] = settings.urlPattern
+  }
+
+  const result = await commands.publishFile(
+    currentFile.path,
+    projectPath,
+    collection?.complete_schema ?? null,
+    mappings.draft,
+    [mappings.publishedDate].flat(),
+    dateFormats ?? null,
+    collection ? { collectionPath: collection.path, urlPatterns } : null,
+    'publish'
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to publish', { description: result.error })
+    return
+  }
+  if (warnings) {
+    toast.warning('Published with warnings', { description: warnings })
+  }
+
+  if (result.data.filePath !== currentFile.path) {
+    useEditorStore.getState().updateCurrentFileAfterRename(result.data.filePath)
+  }
+  // Reload the file and refresh file lists (e.g. draft badges)
+  void queryClient.invalidateQueries({
+    queryKey: queryKeys.fileContent(projectPath, currentFile.id),
+  })
+  void queryClient.invalidateQueries({
+    queryKey: queryKeys.collections(projectPath),
+  })
+  void queryClient.invalidateQueries({
+    queryKey: [...queryKeys.all, projectPath, currentFile.collection],
+  })
+}
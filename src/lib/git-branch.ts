@@ -0,0 +1,39 @@
+import { commands } from '@/lib/bindings'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import { queryClient } from './query-client'
+import { queryKeys } from './query-keys'
+import { toast } from './toast'
+
+/**
+ * Checks out the project's content branch, creating it if needed. The open
+ * file is saved first, and everything cached for the project is refetched
+ * since the files on disk may have changed.
+ */
+export async function switchToContentBranch(): Promise<void> {
+  const { projectPath, currentProjectSettings } = useProjectStore.getState()
+  const settings = currentProjectSettings?.contentBranch
+  if (!projectPath) return
+  if (!settings?.branch) {
+    toast.error('No content branch set', {
+      description: 'Choose one in Project Settings.',
+    })
+    return
+  }
+
+  const { isDirty, saveFile } = useEditorStore.getState()
+  if (isDirty) {
+    await saveFile(false)
+  }
+
+  const result = await commands.switchContentBranch(projectPath, settings)
+  if (result.status === 'error') {
+    toast.error('Failed to switch branch', { description: result.error })
+    return
+  }
+
+  await queryClient.invalidateQueries({
+    queryKey: [...queryKeys.all, projectPath],
+  })
+  toast.success(`Switched to ${result.data.branch ?? settings.branch}`)
+}
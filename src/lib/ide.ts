@@ -1,7 +1,19 @@
-import { commands } from '@/lib/bindings'
+import { commands, type Result } from '@/lib/bindings'
 import { useProjectStore } from '../store/projectStore'
 import { toast } from './toast'
 
+function reportIdeResult(result: Result<string, string>): void {
+  if (result.status === 'error') {
+    toast.error('Failed to open in IDE', {
+      description: result.error,
+    })
+    // eslint-disable-next-line no-console
+    console.error('IDE open failed:', result.error)
+    return
+  }
+  toast.success('Opened in IDE')
+}
+
 export async function openInIde(
   filePath: string,
   ideCmd?: string
@@ -16,14 +28,24 @@ export async function openInIde(
     return
   }
 
-  const result = await commands.openPathInIde(ideCommand, filePath)
-  if (result.status === 'error') {
-    toast.error('Failed to open in IDE', {
-      description: result.error,
+  reportIdeResult(await commands.openPathInIde(ideCommand, filePath))
+}
+
+/**
+ * Opens a file in the configured IDE with the cursor at a line (1-based), for
+ * jumping to a parse error.
+ */
+export async function openInIdeAt(
+  filePath: string,
+  line: number,
+  column?: number | null
+): Promise<void> {
+  if (!useProjectStore.getState().globalSettings?.general?.ideCommand) {
+    toast.error('No IDE configured', {
+      description: 'Please configure an IDE in preferences',
     })
-    // eslint-disable-next-line no-console
-    console.error('IDE open failed:', result.error)
     return
   }
-  toast.success('Opened in IDE')
+
+  reportIdeResult(await commands.openInIde(filePath, line, column ?? null))
 }
@@ -0,0 +1,72 @@
+import { commands, type CheckedLink } from '@/lib/bindings'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import { getEffectiveContentDirectory } from './project-registry'
+import { ASTRO_PATHS } from './constants'
+import { toast } from './toast'
+
+function describeLink(link: CheckedLink, withFile: boolean): string {
+  const file = withFile ? `${link.filePath.split('/').pop()} ` : ''
+  const location = `${file}Line ${link.line}: ${link.target}`
+  return link.message ? `${location} — ${link.message}` : location
+}
+
+/**
+ * Checks the links in the current file, including external URLs, or in the
+ * selected collection (local links only, to keep the run quick)
+ */
+export async function checkLinks(scope: 'file' | 'collection'): Promise<void> {
+  const { currentFile } = useEditorStore.getState()
+  const { projectPath, selectedCollection, currentProjectSettings } =
+    useProjectStore.getState()
+  if (!projectPath) return
+  if (scope === 'file' && !currentFile) return
+  if (scope === 'collection' && !selectedCollection) return
+
+  const contentDirectory = getEffectiveContentDirectory(currentProjectSettings)
+  // Site-absolute links to entries are matched through their URL patterns
+  const urlPatterns: Record<string, string> = {}
+  for (const { name, settings } of currentProjectSettings?.collections ?? []) {
+    if (settings.urlPattern) urlPatterns[name] = settings.urlPattern
+  }
+
+  const checking = commands.checkLinks(
+    projectPath,
+    contentDirectory !== ASTRO_PATHS.CONTENT_DIR ? contentDirectory : null,
+    scope === 'file' ? (currentFile?.path ?? null) : null,
+    scope === 'collection' ? selectedCollection : null,
+    urlPatterns,
+    scope === 'file'
+  )
+  toast.promise(checking, {
+    loading: 'Checking links...',
+    success: 'Links checked',
+    error: 'Failed to check links',
+  })
+  const result = await checking
+  if (result.status === 'error') {
+    toast.error('Failed to check links', { description: result.error })
+    return
+  }
+
+  const broken = result.data.filter(link => link.status === 'broken')
+  const unverified = result.data.filter(link => link.status === 'unverified')
+  if (broken.length === 0 && unverified.length === 0) {
+    toast.success(`All ${result.data.length} links work`)
+    return
+  }
+
+  const problems = [...broken, ...unverified]
+  const files = new Set(problems.map(link => link.filePath)).size
+  toast.warning(
+    `Found ${broken.length} broken and ${unverified.length} unverified links`,
+    {
+      description: [
+        ...(scope === 'collection' ? [`In ${files} files`] : []),
+        ...problems
+          .slice(0, 5)
+          .map(link => describeLink(link, scope === 'collection')),
+      ].join('\n'),
+    }
+  )
+}
@@ -0,0 +1,101 @@
+import { describe, it, expect, beforeEach, vi } from 'vitest'
+import { listen } from '@tauri-apps/api/event'
+import { streamCollectionFiles } from './listing-stream'
+import type { FileEntry, ListingBatch, ListingComplete } from '@/types'
+
+vi.mock('@/lib/bindings', () => ({
+  commands: {
+    streamCollectionFiles: vi.fn(),
+    ackListingBatch: vi.fn(),
+    cancelListingStream: vi.fn(),
+  },
+}))
+
+import { commands } from '@/lib/bindings'
+
+type Handler = (event: { payload: unknown }) => void
+
+const handlers = new Map<string, Handler>()
+const emit = (event: string, payload: ListingBatch | ListingComplete) =>
+  handlers.get(event)?.({ payload })
+
+const file = (id: string) => ({ id, path: `/blog/${id}.md` }) as FileEntry
+
+const options = {
+  recursive: true,
+  collation: null,
+  titleField: null,
+  draftField: null,
+}
+
+describe('streamCollectionFiles', () => {
+  beforeEach(() => {
+    vi.clearAllMocks()
+    handlers.clear()
+    vi.mocked(listen).mockImplementation((event, handler) => {
+      handlers.set(event, handler as Handler)
+      return Promise.resolve(() => handlers.delete(event))
+    })
+  })
+
+  it('joins batches in order, acknowledging each one', async () => {
+    vi.mocked(commands.streamCollectionFiles).mockImplementation(
+      (streamId: string) => {
+        emit('listing-batch', { streamId, index: 1, files: [file('c')] })
+        emit('listing-batch', { streamId: 'other', index: 0, files: [] })
+        emit('listing-batch', {
+          streamId,
+          index: 0,
+          files: [file('a'), file('b')],
+        })
+        const data = { streamId, total: 3, cancelled: false }
+        emit('listing-complete', data)
+        return Promise.resolve({ status: 'ok', data })
+      }
+    )
+
+    const files = await streamCollectionFiles('/blog', 'blog', options)
+
+    expect(files.map(f => f.id)).toEqual(['a', 'b', 'c'])
+    expect(commands.ackListingBatch).toHaveBeenCalledTimes(2)
+    expect(handlers.size).toBe(0)
+  })
+
+  it('waits for batches that arrive after the command returns', async () => {
+    let streamId = ''
+    vi.mocked(commands.streamCollectionFiles).mockImplementation(id => {
+      streamId = id
+      return Promise.resolve({
+        status: 'ok',
+        data: { streamId: id, total: 1, cancelled: false },
+      })
+    })
+
+    const pending = streamCollectionFiles('/blog', 'blog', options)
+    await vi.waitFor(() => expect(streamId).not.toBe(''))
+    emit('listing-batch', { streamId, index: 0, files: [file('late')] })
+
+    expect((await pending).map(f => f.id)).toEqual(['late'])
+  })
+
+  it('cancels the stream when the signal aborts', async () => {
+    const controller = new AbortController()
+    vi.mocked(commands.streamCollectionFiles).mockImplementation(
+      (streamId: string) => {
+        controller.abort()
+        return Promise.resolve({
+          status: 'ok',
+          data: { streamId, total: 0, cancelled: true },
+        })
+      }
+    )
+
+    await expect(
+      streamCollectionFiles('/blog', 'blog', {
+        ...options,
+        signal: controller.signal,
+      })
+    ).rejects.toThrow()
+    expect(commands.cancelListingStream).toHaveBeenCalled()
+  })
+})
@@ -0,0 +1,89 @@
+import { listen } from '@tauri-apps/api/event'
+import { commands } from '@/lib/bindings'
+import type {
+  Collation,
+  FileEntry,
+  ListingBatch,
+  ListingComplete,
+} from '@/types'
+
+interface StreamOptions {
+  recursive: boolean
+  collation: Collation | null
+  titleField: string | null
+  draftField: string | null
+  // Aborting cancels the stream; the promise rejects with the abort reason
+  signal?: AbortSignal
+}
+
+/**
+ * Lists a collection's files through `stream_collection_files`: bounded
+ * batches of events rather than one response, which for large collections
+ * can run to tens of megabytes. Each batch is acknowledged as it arrives so
+ * the stream keeps going.
+ */
+export async function streamCollectionFiles(
+  collectionPath: string,
+  collectionName: string,
+  { recursive, collation, titleField, draftField, signal }: StreamOptions
+): Promise<FileEntry[]> {
+  signal?.throwIfAborted()
+
+  const streamId = crypto.randomUUID()
+  const batches: FileEntry[][] = []
+  let received = 0
+  let expected: number | null = null
+
+  // Events can still be arriving when the command returns
+  let settle!: () => void
+  const settled = new Promise<void>(resolve => (settle = resolve))
+  const checkSettled = () => {
+    if (expected !== null && received >= expected) settle()
+  }
+
+  const unlistenBatch = await listen<ListingBatch>('listing-batch', event => {
+    if (event.payload.streamId !== streamId) return
+    batches[event.payload.index] = event.payload.files
+    received += event.payload.files.length
+    void commands.ackListingBatch(streamId)
+    checkSettled()
+  })
+  const unlistenComplete = await listen<ListingComplete>(
+    'listing-complete',
+    event => {
+      if (event.payload.streamId === streamId) settle()
+    }
+  )
+
+  const cancel = () => void commands.cancelListingStream(streamId)
+  signal?.addEventListener('abort', cancel)
+
+  try {
+    const result = await commands.streamCollectionFiles(
+      streamId,
+      collectionPath,
+      collectionName,
+      recursive,
+      collation,
+      titleField,
+      draftField,
+      null
+    )
+    if (result.status === 'error') {
+      throw new Error(result.error)
+    }
+    if (result.data.cancelled) {
+      signal?.throwIfAborted()
+      throw new Error(`Listing ${collectionName} was cancelled`)
+    }
+
+    expected = result.data.total
+    checkSettled()
+    await settled
+    return batches.flat()
+  } finally {
+    signal?.removeEventListener('abort', cancel)
+    unlistenBatch()
+    unlistenComplete()
+  }
+}
@@ -0,0 +1,72 @@
+import { commands, type MarkdownFlavor } from '@/lib/bindings'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import { queryClient } from './query-client'
+import { queryKeys } from './query-keys'
+import { toast } from './toast'
+
+/** Astro's defaults, used when the flavor can't be detected */
+const DEFAULT_MARKDOWN_FLAVOR: MarkdownFlavor = {
+  gfmTables: true,
+  footnotes: true,
+  taskLists: true,
+  smartPunctuation: true,
+  mdx: true,
+}
+
+/**
+ * The Markdown extensions the open project accepts, from its Astro config
+ * and the `markdownFlavor` project setting
+ */
+export async function getMarkdownFlavor(): Promise<MarkdownFlavor> {
+  const { projectPath, currentProjectSettings } = useProjectStore.getState()
+  if (!projectPath) return DEFAULT_MARKDOWN_FLAVOR
+
+  const settings = currentProjectSettings?.markdownFlavor
+  return queryClient.fetchQuery({
+    queryKey: queryKeys.markdownFlavor(projectPath, settings),
+    queryFn: async () => {
+      const result = await commands.getMarkdownFlavor(
+        projectPath,
+        settings ?? null
+      )
+      return result.status === 'ok' ? result.data : DEFAULT_MARKDOWN_FLAVOR
+    },
+    staleTime: 5 * 60 * 1000,
+  })
+}
+
+/**
+ * Lists syntax in the open document that the project's Markdown flavor won't
+ * render, such as tables in a project with GFM turned off
+ */
+export async function checkMarkdownFlavor(): Promise<void> {
+  const { currentFile, editorContent } = useEditorStore.getState()
+  const { projectPath, currentProjectSettings } = useProjectStore.getState()
+  if (!currentFile || !projectPath) return
+
+  const result = await commands.checkMarkdownFlavor(
+    projectPath,
+    currentFile.path,
+    editorContent,
+    currentProjectSettings?.markdownFlavor ?? null
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to check Markdown syntax', {
+      description: result.error,
+    })
+    return
+  }
+
+  const issues = result.data
+  if (issues.length === 0) {
+    toast.success("All syntax is supported by this project's Markdown setup")
+    return
+  }
+  toast.warning(`Found ${issues.length} unsupported Markdown features`, {
+    description: issues
+      .slice(0, 5)
+      .map(issue => `Line ${issue.line}: ${issue.message}`)
+      .join('\n'),
+  })
+}
@@ -0,0 +1,39 @@
+import { commands } from '@/lib/bindings'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import { toast } from './toast'
+
+/**
+ * Lists `{`, `}` and `<` in the open MDX document's prose that the MDX
+ * compiler would parse as code, without changing them
+ */
+export async function checkMdxEscaping(): Promise<void> {
+  const { currentFile, editorContent, imports } = useEditorStore.getState()
+  const { projectPath } = useProjectStore.getState()
+  if (!currentFile || !projectPath) return
+
+  const result = await commands.checkMdxEscaping(
+    projectPath,
+    currentFile.path,
+    editorContent,
+    imports
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to check MDX characters', {
+      description: result.error,
+    })
+    return
+  }
+
+  const found = result.data
+  if (found.length === 0) {
+    toast.success('No characters need escaping for MDX')
+    return
+  }
+  toast.warning(`Found ${found.length} characters MDX will parse as code`, {
+    description: found
+      .slice(0, 5)
+      .map(c => `Line ${c.line}, column ${c.column}: ${c.message}`)
+      .join('\n'),
+  })
+}
@@ -0,0 +1,112 @@
+import { EditorSelection } from '@codemirror/state'
+import { commands } from '@/lib/bindings'
+import { useEditorStore } from '@/store/editorStore'
+import { useProjectStore } from '@/store/projectStore'
+import { ASTRO_PATHS } from './constants'
+import { getCurrentEditorView } from './editor/editor-view-ref'
+import { getEffectiveContentDirectory } from './project-registry'
+import { toast } from './toast'
+
+/**
+ * Waits until `filePath` is the open file and its content has loaded.
+ * Resolves `false` if that doesn't happen within `timeoutMs`.
+ */
+function waitForFileLoaded(
+  filePath: string,
+  timeoutMs = 5000
+): Promise<boolean> {
+  const isLoaded = (state: ReturnType<typeof useEditorStore.getState>) =>
+    state.currentFile?.path === filePath && state.contentHash !== null
+
+  if (isLoaded(useEditorStore.getState())) return Promise.resolve(true)
+
+  return new Promise<boolean>(resolve => {
+    const handles: {
+      unsubscribe?: () => void
+      timer?: ReturnType<typeof setTimeout>
+    } = {}
+
+    handles.timer = setTimeout(() => {
+      handles.unsubscribe?.()
+      resolve(false)
+    }, timeoutMs)
+
+    handles.unsubscribe = useEditorStore.subscribe(state => {
+      if (isLoaded(state)) {
+        clearTimeout(handles.timer)
+        handles.unsubscribe?.()
+        resolve(true)
+      }
+    })
+  })
+}
+
+/**
+ * Moves the cursor to the start of a line of the file on disk. Lines are
+ * counted from the top of the file, so the frontmatter above the editor's
+ * content is skipped.
+ */
+async function revealFileLine(
+  filePath: string,
+  projectPath: string,
+  line: number
+): Promise<void> {
+  const result = await commands.readFile(filePath, projectPath)
+  const view = getCurrentEditorView()
+  if (!view) return
+
+  const body = useEditorStore.getState().editorContent.trimEnd()
+  let bodyStartLine = 0
+  if (result.status === 'ok' && body) {
+    const bodyStart = result.data.lastIndexOf(body)
+    if (bodyStart > 0) {
+      bodyStartLine = result.data.slice(0, bodyStart).split('\n').length - 1
+    }
+  }
+
+  const doc = view.state.doc
+  const target = Math.min(Math.max(line - bodyStartLine, 1), doc.lines)
+  view.dispatch({
+    selection: EditorSelection.cursor(doc.line(target).from),
+    scrollIntoView: true,
+  })
+  view.focus()
+}
+
+/**
+ * Opens a file of the current project given only its path, e.g. from a
+ * search result, optionally at a 1-based line of the file
+ */
+export async function openProjectFile(
+  filePath: string,
+  line?: number | null
+): Promise<void> {
+  const { projectPath, currentProjectSettings } = useProjectStore.getState()
+  if (!projectPath) return
+
+  if (useEditorStore.getState().currentFile?.path !== filePath) {
+    const contentDirectory = getEffectiveContentDirectory(
+      currentProjectSettings
+    )
+    const result = await commands.resolveFileEntry(
+      filePath,
+      projectPath,
+      contentDirectory !== ASTRO_PATHS.CONTENT_DIR ? contentDirectory : null
+    )
+    if (result.status === 'error') {
+      toast.error("Couldn't open that file", { description: result.error })
+      return
+    }
+    if (!result.data) {
+      toast.warning("Couldn't find that file in the project", {
+        description: 'It may not be part of a content collection.',
+      })
+      return
+    }
+    useEditorStore.getState().openFile(result.data)
+  }
+
+  if (line && (await waitForFileLoaded(filePath))) {
+    await revealFileLine(filePath, projectPath, line)
+  }
+}
@@ -0,0 +1,113 @@
+import { commands, type Result } from '@/lib/bindings'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import { getEffectiveFrontmatterMappings } from './project-registry'
+import { projectAssetUrl } from './files'
+import { getMarkdownFlavor } from './markdown-flavor'
+import { toast } from './toast'
+import { expandIncludes } from './transclusion'
+
+export const DEFAULT_PRINT_HEADER = '{title}'
+export const DEFAULT_PRINT_FOOTER = '{date}'
+
+/**
+ * Points local image sources at the project asset protocol so the print
+ * window (or preview pane) can load them.
+ */
+export async function resolveImages(
+  html: string,
+  projectPath: string,
+  currentFilePath: string
+): Promise<string> {
+  const doc = new DOMParser().parseFromString(html, 'text/html')
+  const images = Array.from(doc.querySelectorAll('img'))
+
+  await Promise.all(
+    images.map(async img => {
+      const src = img.getAttribute('src')
+      if (!src || /^(https?:|data:)/.test(src)) return
+
+      const result = await commands.resolveImagePath(
+        decodeURI(src),
+        projectPath,
+        currentFilePath
+      )
+      if (result.status === 'ok') {
+        img.setAttribute('src', projectAssetUrl(result.data))
+      }
+    })
+  )
+
+  return doc.body.innerHTML
+}
+
+/**
+ * Renders a document body to HTML with the same renderer as the preview pane,
+ * so printed and copied documents match it. Image sources are left as written
+ * in the Markdown.
+ */
+export async function renderDocumentBody(
+  markdown: string
+): Promise<Result<string, string>> {
+  const flavor = await getMarkdownFlavor()
+  // Without frontmatter there's no title to head the body with
+  const result = await commands.renderMarkdownPreview(
+    markdown,
+    {},
+    null,
+    flavor.smartPunctuation,
+    false
+  )
+  return result.status === 'ok'
+    ? { status: 'ok', data: result.data.html }
+    : result
+}
+
+/**
+ * Renders the open document and opens the native print dialog for it, with
+ * the header/footer from preferences.
+ */
+export async function printCurrentDocument(): Promise<void> {
+  const { currentFile, editorContent, frontmatter } = useEditorStore.getState()
+  const { projectPath, currentProjectSettings, globalSettings } =
+    useProjectStore.getState()
+  if (!currentFile || !projectPath) return
+
+  const titleField = getEffectiveFrontmatterMappings(
+    currentProjectSettings,
+    currentFile.collection
+  ).title
+  const frontmatterTitle = frontmatter[titleField]
+  const title =
+    typeof frontmatterTitle === 'string' && frontmatterTitle.trim()
+      ? frontmatterTitle
+      : currentFile.name
+
+  const rendered = await renderDocumentBody(await expandIncludes(editorContent))
+  if (rendered.status === 'error') {
+    toast.error('Failed to print', { description: rendered.error })
+    return
+  }
+  const bodyHtml = await resolveImages(
+    rendered.data,
+    projectPath,
+    currentFile.path
+  )
+
+  const result = await commands.printCurrentDocument(
+    title,
+    `<h1>${escapeHtml(title)}</h1>\n${bodyHtml}`,
+    globalSettings?.general?.printHeader ?? DEFAULT_PRINT_HEADER,
+    globalSettings?.general?.printFooter ?? DEFAULT_PRINT_FOOTER
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to print', { description: result.error })
+  }
+}
+
+function escapeHtml(text: string): string {
+  return text
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+}
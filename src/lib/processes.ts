@@ -0,0 +1,51 @@
+import { commands } from '@/lib/bindings'
+import { toast } from './toast'
+
+function formatRuntime(seconds: number): string {
+  return seconds < 60 ? `${seconds}s` : `${Math.floor(seconds / 60)}m`
+}
+
+async function stopAll(ids: number[]): Promise<void> {
+  const results = await Promise.all(
+    ids.map(id => commands.killManagedProcess(id))
+  )
+  const failed = results.filter(result => result.status === 'error')
+  if (failed.length > 0) {
+    toast.error(`Failed to stop ${failed.length} processes`)
+    return
+  }
+  toast.success('Stopped background processes')
+}
+
+/**
+ * Lists installs and syncs the editor started that are still running, with
+ * an option to stop them
+ */
+export async function showRunningProcesses(): Promise<void> {
+  const result = await commands.listManagedProcesses()
+  if (result.status === 'error') {
+    toast.error('Failed to list processes', { description: result.error })
+    return
+  }
+
+  const processes = result.data
+  if (processes.length === 0) {
+    toast.info('No background processes running')
+    return
+  }
+
+  toast.info(`${processes.length} background processes running`, {
+    description: processes
+      .map(process => {
+        const runtime = formatRuntime(process.runtimeSeconds)
+        const memory =
+          process.memoryMb !== null ? `, ${process.memoryMb} MB` : ''
+        return `${process.label} (${runtime}${memory})`
+      })
+      .join('\n'),
+    action: {
+      label: 'Stop All',
+      onClick: () => void stopAll(processes.map(process => process.id)),
+    },
+  })
+}
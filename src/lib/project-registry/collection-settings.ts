@@ -33,6 +33,7 @@ export function getCollectionSettings(
     draft: string
   }
   useRelativeAssetPaths: boolean
+  usePathAliases: boolean
   urlPattern?: string
 } {
   // Handle null/undefined projectSettings
@@ -50,6 +51,7 @@ export function getCollectionSettings(
         draft: 'draft',
       },
       useRelativeAssetPaths: true,
+      usePathAliases: false,
       urlPattern: undefined,
     }
   }
@@ -120,6 +122,7 @@ export function getCollectionSettings(
     pathOverrides: effectivePathOverrides,
     frontmatterMappings: effectiveFrontmatterMappings,
     useRelativeAssetPaths: !useAbsolutePaths, // Invert: absolute=false means relative=true
+    usePathAliases: projectSettings.usePathAliases ?? false,
     urlPattern: collectionSettings?.urlPattern,
   }
 }
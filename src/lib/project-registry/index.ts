@@ -257,6 +257,197 @@ export class ProjectRegistryManager {
       }
     }
 
+    // Update usePathAliases if property is present
+    if ('usePathAliases' in settings) {
+      if (settings.usePathAliases === undefined) {
+        delete projectData.settings.usePathAliases
+      } else {
+        projectData.settings.usePathAliases = settings.usePathAliases
+      }
+    }
+
+    // Update watcherExcludedDirectories if property is present
+    if ('watcherExcludedDirectories' in settings) {
+      if (settings.watcherExcludedDirectories === undefined) {
+        delete projectData.settings.watcherExcludedDirectories
+      } else {
+        projectData.settings.watcherExcludedDirectories =
+          settings.watcherExcludedDirectories
+      }
+    }
+
+    // Update maxEditorFileSizeMb if property is present
+    if ('maxEditorFileSizeMb' in settings) {
+      if (settings.maxEditorFileSizeMb === undefined) {
+        delete projectData.settings.maxEditorFileSizeMb
+      } else {
+        projectData.settings.maxEditorFileSizeMb = settings.maxEditorFileSizeMb
+      }
+    }
+
+    // Update dailyNotePattern if property is present
+    if ('dailyNotePattern' in settings) {
+      if (settings.dailyNotePattern === undefined) {
+        delete projectData.settings.dailyNotePattern
+      } else {
+        projectData.settings.dailyNotePattern = settings.dailyNotePattern
+      }
+    }
+
+    // Update dailyNoteTemplate if property is present
+    if ('dailyNoteTemplate' in settings) {
+      if (settings.dailyNoteTemplate === undefined) {
+        delete projectData.settings.dailyNoteTemplate
+      } else {
+        projectData.settings.dailyNoteTemplate = settings.dailyNoteTemplate
+      }
+    }
+
+    // Update codeLanguageAliases if property is present
+    if ('codeLanguageAliases' in settings) {
+      if (settings.codeLanguageAliases === undefined) {
+        delete projectData.settings.codeLanguageAliases
+      } else {
+        projectData.settings.codeLanguageAliases = settings.codeLanguageAliases
+      }
+    }
+
+    // Update trustedRoots if property is present
+    if ('trustedRoots' in settings) {
+      if (settings.trustedRoots === undefined) {
+        delete projectData.settings.trustedRoots
+      } else {
+        projectData.settings.trustedRoots = settings.trustedRoots
+      }
+    }
+
+    // Update assetUrlMappings if property is present
+    if ('assetUrlMappings' in settings) {
+      if (settings.assetUrlMappings === undefined) {
+        delete projectData.settings.assetUrlMappings
+      } else {
+        projectData.settings.assetUrlMappings = settings.assetUrlMappings
+      }
+    }
+
+    // Update readingTime if property is present
+    if ('readingTime' in settings) {
+      if (settings.readingTime === undefined) {
+        delete projectData.settings.readingTime
+      } else {
+        projectData.settings.readingTime = settings.readingTime
+      }
+    }
+
+    // Update mdxEscaping if property is present
+    if ('mdxEscaping' in settings) {
+      if (settings.mdxEscaping === undefined) {
+        delete projectData.settings.mdxEscaping
+      } else {
+        projectData.settings.mdxEscaping = settings.mdxEscaping
+      }
+    }
+
+    // Update dateFormats if property is present
+    if ('dateFormats' in settings) {
+      if (settings.dateFormats === undefined) {
+        delete projectData.settings.dateFormats
+      } else {
+        projectData.settings.dateFormats = settings.dateFormats
+      }
+    }
+
+    // Update fieldGroups if property is present
+    if ('fieldGroups' in settings) {
+      if (settings.fieldGroups === undefined) {
+        delete projectData.settings.fieldGroups
+      } else {
+        projectData.settings.fieldGroups = settings.fieldGroups
+      }
+    }
+
+    // Update collation if property is present
+    if ('collation' in settings) {
+      if (settings.collation === undefined) {
+        delete projectData.settings.collation
+      } else {
+        projectData.settings.collation = settings.collation
+      }
+    }
+
+    // Update savedFilters if property is present
+    if ('savedFilters' in settings) {
+      if (settings.savedFilters === undefined) {
+        delete projectData.settings.savedFilters
+      } else {
+        projectData.settings.savedFilters = settings.savedFilters
+      }
+    }
+
+    // Update timezone if property is present
+    if ('timezone' in settings) {
+      if (settings.timezone === undefined) {
+        delete projectData.settings.timezone
+      } else {
+        projectData.settings.timezone = settings.timezone
+      }
+    }
+
+    // Update locale if property is present
+    if ('locale' in settings) {
+      if (settings.locale === undefined) {
+        delete projectData.settings.locale
+      } else {
+        projectData.settings.locale = settings.locale
+      }
+    }
+
+    // Update staleDrafts if property is present
+    if ('staleDrafts' in settings) {
+      if (settings.staleDrafts === undefined) {
+        delete projectData.settings.staleDrafts
+      } else {
+        projectData.settings.staleDrafts = settings.staleDrafts
+      }
+    }
+
+    // Update backupIntervalMinutes if property is present
+    if ('backupIntervalMinutes' in settings) {
+      if (settings.backupIntervalMinutes === undefined) {
+        delete projectData.settings.backupIntervalMinutes
+      } else {
+        projectData.settings.backupIntervalMinutes =
+          settings.backupIntervalMinutes
+      }
+    }
+
+    // Update imageMaxWidth if property is present
+    if ('imageMaxWidth' in settings) {
+      if (settings.imageMaxWidth === undefined) {
+        delete projectData.settings.imageMaxWidth
+      } else {
+        projectData.settings.imageMaxWidth = settings.imageMaxWidth
+      }
+    }
+
+    // Update markdownFlavor if property is present
+    if ('markdownFlavor' in settings) {
+      if (settings.markdownFlavor === undefined) {
+        delete projectData.settings.markdownFlavor
+      } else {
+        projectData.settings.markdownFlavor = settings.markdownFlavor
+      }
+    }
+
+    // Update slugs if property is present
+    if ('slugs' in settings) {
+      if (settings.slugs === undefined) {
+        delete projectData.settings.slugs
+      } else {
+        projectData.settings.slugs = settings.slugs
+      }
+    }
+
     // Update collections if property is present
     if ('collections' in settings) {
       if (settings.collections === undefined) {
@@ -279,7 +470,7 @@ export class ProjectRegistryManager {
    *
    * Merging depth:
    * - Level 1: general, appearance (spreads existing + updates)
-   * - Level 2: highlights, headingColor (spreads existing + updates)
+   * - Level 2: highlights, captureApi, recovery, headingColor (spreads existing + updates)
    */
   async updateGlobalSettings(
     settings: DeepPartial<GlobalSettings>
@@ -299,6 +490,16 @@ export class ProjectRegistryManager {
           ...this.globalSettings.general.highlights,
           ...settings.general?.highlights,
         },
+        // Two-level deep merge for captureApi, so saving the generated token
+        // doesn't drop the enabled flag
+        captureApi: {
+          ...this.globalSettings.general.captureApi,
+          ...settings.general?.captureApi,
+        },
+        recovery: {
+          ...this.globalSettings.general.recovery,
+          ...settings.general?.recovery,
+        },
       },
       appearance: {
         ...this.globalSettings.appearance,
@@ -338,6 +539,48 @@ export class ProjectRegistryManager {
       defaultFileType: projectData.settings.defaultFileType,
       // Include useAbsoluteAssetPaths (undefined by default, meaning use relative paths)
       useAbsoluteAssetPaths: projectData.settings.useAbsoluteAssetPaths,
+      // Include usePathAliases (undefined by default, meaning no aliases)
+      usePathAliases: projectData.settings.usePathAliases,
+      // Include watcherExcludedDirectories (undefined means defaults only)
+      watcherExcludedDirectories:
+        projectData.settings.watcherExcludedDirectories,
+      // Include maxEditorFileSizeMb (undefined means the 5 MB default)
+      maxEditorFileSizeMb: projectData.settings.maxEditorFileSizeMb,
+      // Include daily note settings (undefined means the Rust defaults)
+      dailyNotePattern: projectData.settings.dailyNotePattern,
+      dailyNoteTemplate: projectData.settings.dailyNoteTemplate,
+      // Include codeLanguageAliases (undefined means no rewrites)
+      codeLanguageAliases: projectData.settings.codeLanguageAliases,
+      // Include timezone (undefined means the system timezone)
+      timezone: projectData.settings.timezone,
+      // Include locale (undefined means the system locale)
+      locale: projectData.settings.locale,
+      // Include trustedRoots (undefined means only the project directory)
+      trustedRoots: projectData.settings.trustedRoots,
+      // Include assetUrlMappings (undefined means local paths are kept)
+      assetUrlMappings: projectData.settings.assetUrlMappings,
+      // Include readingTime (undefined means 200 wpm, never written on save)
+      readingTime: projectData.settings.readingTime,
+      // Include mdxEscaping (undefined means MDX is saved as written)
+      mdxEscaping: projectData.settings.mdxEscaping,
+      // Include dateFormats (undefined means datetimes are saved as dates)
+      dateFormats: projectData.settings.dateFormats,
+      // Include collation (undefined means natural order)
+      collation: projectData.settings.collation,
+      // Include fieldGroups (undefined means schema order, all expanded)
+      fieldGroups: projectData.settings.fieldGroups,
+      // Include savedFilters (undefined means no smart views)
+      savedFilters: projectData.settings.savedFilters,
+      // Include staleDrafts (undefined means 30 days, no digest)
+      staleDrafts: projectData.settings.staleDrafts,
+      // Include backupIntervalMinutes (undefined means hourly backups)
+      backupIntervalMinutes: projectData.settings.backupIntervalMinutes,
+      // Include imageMaxWidth (undefined means images keep their size)
+      imageMaxWidth: projectData.settings.imageMaxWidth,
+      // Include markdownFlavor (undefined means detected from astro.config)
+      markdownFlavor: projectData.settings.markdownFlavor,
+      // Include slugs (undefined means Astro's own slug rules)
+      slugs: projectData.settings.slugs,
       // Include collections array if present
       collections: projectData.settings.collections || [],
     }
@@ -4,6 +4,22 @@
  * Simple type definitions for project identification and persistence
  */
 
+import type {
+  AssetUrlMapping,
+  Collation,
+  ContentBranchSettings,
+  DateFormatSettings,
+  FieldGroupSettings,
+  MarkdownFlavorSettings,
+  MdxEscapingSettings,
+  PublishGate,
+  ReadingTimeSettings,
+  RecoverySettings,
+  SavedFilter,
+  SlugSettings,
+  TransclusionSettings,
+} from '@/lib/bindings'
+
 /**
  * Utility type for deep partial - makes all nested properties optional
  * Used for settings updates where only changed fields need to be passed
@@ -38,6 +54,55 @@ export interface ProjectSettings {
   defaultFileType?: 'md' | 'mdx'
   // Override to use absolute paths for images (defaults to relative paths, matching Astro conventions)
   useAbsoluteAssetPaths?: boolean
+  // Emit tsconfig.json path aliases (e.g. @assets/image.png) for images when an alias covers them
+  usePathAliases?: boolean
+  // Extra directories the file watcher ignores (node_modules, .git, dist and .astro are always ignored)
+  watcherExcludedDirectories?: string[]
+  // Files larger than this (in MB) open for frontmatter-only editing (defaults to 5)
+  maxEditorFileSizeMb?: number
+  // Daily note location relative to the content directory (defaults to "journal/{YYYY}-{MM}-{DD}.md")
+  dailyNotePattern?: string
+  // Template for new daily notes, relative to the project root ({date}, {YYYY}, {MM}, {DD} are substituted)
+  dailyNoteTemplate?: string
+  // Code block languages to rewrite when normalizing (e.g. { js: "javascript" })
+  codeLanguageAliases?: Record<string, string>
+  // IANA timezone for relative date entry like "tomorrow" (defaults to the system timezone)
+  timezone?: string
+  // Locale for displaying dates, e.g. "en-GB" (defaults to the system locale)
+  locale?: string
+  // Images imported from a folder are scaled down to this width (PNG, JPEG and WebP)
+  imageMaxWidth?: number
+  // Drafts untouched for `days` (default 30) are stale; weeklyDigest sends a weekly notification
+  staleDrafts?: {
+    days?: number
+    weeklyDigest?: boolean
+  }
+  // Minutes between automatic content backups (defaults to 60; 0 only backs up before bulk operations)
+  backupIntervalMinutes?: number
+  // Markdown extensions Astro accepts (detected from astro.config when unset)
+  markdownFlavor?: MarkdownFlavorSettings
+  // Absolute directories outside the project the editor may read and write (e.g. a shared media folder)
+  trustedRoots?: string[]
+  // Deployed URLs for asset directories (e.g. src/assets → https://cdn.example.com/assets), used when copying as HTML
+  assetUrlMappings?: AssetUrlMapping[]
+  // Reading speed and weights; writeOnSave keeps a schema `readingTime` field up to date
+  readingTime?: ReadingTimeSettings
+  // Escape `{`, `}` and `<` in MDX prose on save (escapeOnSave), as backslashes or entities
+  mdxEscaping?: MdxEscapingSettings
+  // How dates are saved: dateOnly (default), dateTime (quoted, with time) or timestamp (unquoted), per field if needed
+  dateFormats?: DateFormatSettings
+  // How file lists are ordered by title: "natural" (default, post-2 before post-10), "alphabetical" or "ordinal"
+  collation?: Collation
+  // Frontmatter panel groups for nested schema objects: display order and which start collapsed
+  fieldGroups?: FieldGroupSettings
+  // Smart views for the sidebar (e.g. "Drafts older than 30 days"), evaluated by query_collection
+  savedFilters?: SavedFilter[]
+  // Git branch content is edited on; with require, editing on the default branch (e.g. main) shows a warning
+  contentBranch?: ContentBranchSettings
+  // Includes of shared Markdown: {{include: path}}, or an MDX component (e.g. <Include src="..." />) named here
+  transclusion?: TransclusionSettings
+  // Rules for generated slugs: transliterate accents (café → cafe), maxLength, and stopWords left out
+  slugs?: SlugSettings
   // Collection-specific settings overrides
   collections?: CollectionSettings[]
 }
@@ -60,6 +125,12 @@ export interface CollectionSpecificSettings {
   useAbsoluteAssetPaths?: boolean
   // URL pattern template for content links (e.g. "/writing/{slug}")
   urlPattern?: string
+  // Checks run before publishing a draft (e.g. description present, at least 1 tag)
+  publishGates?: PublishGate[]
+  // MDX component wrapping image galleries imported from a folder (e.g. "Gallery")
+  galleryComponent?: string
+  // How dates are saved in this collection (replaces the project's dateFormats)
+  dateFormats?: DateFormatSettings
 }
 
 export interface CollectionSettings {
@@ -92,6 +163,18 @@ export interface GlobalSettings {
     }
     autoSaveDelay: number
     defaultFileType: 'md' | 'mdx'
+    nativeToolbar?: boolean // macOS: native window toolbar instead of the webview title bar controls
+    printHeader?: string // Running header when printing ({title}, {date}); defaults to "{title}"
+    printFooter?: string // Running footer when printing ({title}, {date}); defaults to "{date}"
+    // Local HTTP API for capturing notes and photos from a phone
+    captureApi?: {
+      enabled?: boolean
+      port?: number // defaults to 4870
+      allowLan?: boolean // listen on the local network, not just this machine
+      token?: string // bearer token; generated on first start
+    }
+    // Retention and encryption for data saved when a save fails
+    recovery?: Partial<RecoverySettings>
   }
   appearance: {
     headingColor: {
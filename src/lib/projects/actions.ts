@@ -1,3 +1,4 @@
+import { open } from '@tauri-apps/plugin-dialog'
 import { commands } from '@/lib/bindings'
 import { useProjectStore } from '../../store/projectStore'
 import { toast } from '../toast'
@@ -15,3 +16,25 @@ export async function openProjectViaDialog(): Promise<void> {
     toast.success('Project opened successfully')
   }
 }
+
+/**
+ * Writes the bundled sample project into a folder the user picks, then opens it
+ */
+export async function openSampleProjectViaDialog(): Promise<void> {
+  const parentDirectory = await open({
+    directory: true,
+    multiple: false,
+    title: 'Choose where to create the sample project',
+  })
+  if (typeof parentDirectory !== 'string') return
+
+  const result = await commands.createDemoProject(parentDirectory)
+  if (result.status === 'error') {
+    toast.error('Failed to create sample project', {
+      description: result.error,
+    })
+    return
+  }
+  useProjectStore.getState().setProject(result.data)
+  toast.success('Sample project created', { description: result.data })
+}
@@ -20,6 +20,8 @@ export const queryKeys = {
       'directory',
       subdirectory,
     ] as const,
+  collectionTree: (projectPath: string, collectionName: string) =>
+    [...queryKeys.all, projectPath, collectionName, 'collectionTree'] as const,
   fileContent: (projectPath: string, fileId: string) =>
     [...queryKeys.all, projectPath, 'files', fileId] as const,
   mdxComponents: (projectPath: string, mdxDirectory?: string) =>
@@ -36,5 +38,91 @@ export const queryKeys = {
       'fileBasedCollection',
       collectionName,
     ] as const,
+  remoteCollection: (projectPath: string, collectionName: string) =>
+    [
+      ...queryKeys.all,
+      projectPath,
+      'remoteCollection',
+      collectionName,
+    ] as const,
+  fieldDocs: (projectPath: string, collectionName: string, fieldPath: string) =>
+    [
+      ...queryKeys.all,
+      projectPath,
+      'fieldDocs',
+      collectionName,
+      fieldPath,
+    ] as const,
+  fuzzyFind: (projectPath: string, query: string) =>
+    [...queryKeys.all, projectPath, 'fuzzyFind', query] as const,
+  recentFiles: (projectPath: string) =>
+    [...queryKeys.all, projectPath, 'usage', 'recent'] as const,
+  frequentFiles: (projectPath: string) =>
+    [...queryKeys.all, projectPath, 'usage', 'frequent'] as const,
+  dailyNoteCalendar: (projectPath: string, year: number, month: number) =>
+    [...queryKeys.all, projectPath, 'dailyNotes', year, month] as const,
+  codeBlockAudit: (projectPath: string, collectionName: string) =>
+    [...queryKeys.all, projectPath, collectionName, 'codeBlockAudit'] as const,
+  todos: (projectPath: string) =>
+    [...queryKeys.all, projectPath, 'todos'] as const,
+  activityReport: (projectPath: string, from: string, to: string) =>
+    [...queryKeys.all, projectPath, 'activityReport', from, to] as const,
+  contentGraph: (projectPath: string, format: string) =>
+    [...queryKeys.all, projectPath, 'contentGraph', format] as const,
+  backlinks: (projectPath: string, filePath: string) =>
+    [...queryKeys.all, projectPath, 'backlinks', filePath] as const,
+  staleDrafts: (projectPath: string, days: number) =>
+    [...queryKeys.all, projectPath, 'staleDrafts', days] as const,
+  embargoedEntries: (projectPath: string) =>
+    [...queryKeys.all, projectPath, 'embargoedEntries'] as const,
+  contentCalendar: (projectPath: string) =>
+    [...queryKeys.all, projectPath, 'contentCalendar'] as const,
+  markdownFlavor: (projectPath: string, settings?: object) =>
+    [...queryKeys.all, projectPath, 'markdownFlavor', settings ?? {}] as const,
+  changePreview: (projectPath: string, request: object) =>
+    [...queryKeys.all, projectPath, 'changePreview', request] as const,
+  savedFilter: (projectPath: string, filter: object) =>
+    [...queryKeys.all, projectPath, 'savedFilter', filter] as const,
+  formattedDate: (
+    date: string,
+    style: string,
+    locale: string,
+    today: string
+  ) => [...queryKeys.all, 'formattedDate', date, style, locale, today] as const,
+  updateChannel: () => [...queryKeys.all, 'updateChannel'] as const,
+  detectedIdes: () => [...queryKeys.all, 'detectedIdes'] as const,
+  scratchpads: () => [...queryKeys.all, 'scratchpads'] as const,
+  trashedFiles: (projectPath: string) =>
+    [...queryKeys.all, projectPath, 'trashedFiles'] as const,
+  backups: (projectPath: string) =>
+    [...queryKeys.all, projectPath, 'backups'] as const,
+  gitBranchState: (projectPath: string) =>
+    [...queryKeys.all, projectPath, 'gitBranchState'] as const,
+  frontmatterValidation: (
+    projectPath: string,
+    collectionName: string,
+    frontmatter: object
+  ) =>
+    [
+      ...queryKeys.all,
+      projectPath,
+      collectionName,
+      'frontmatterValidation',
+      frontmatter,
+    ] as const,
+  referenceLabels: (
+    projectPath: string,
+    collectionName: string,
+    ids: string[],
+    titleField: string
+  ) =>
+    [
+      ...queryKeys.all,
+      projectPath,
+      collectionName,
+      'referenceLabels',
+      ids,
+      titleField,
+    ] as const,
   // Add more keys here as needed
 }
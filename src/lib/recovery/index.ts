@@ -1,5 +1,6 @@
 import { info, error as logError } from '@tauri-apps/plugin-log'
 import { commands, type JsonValue } from '@/types'
+import { useProjectStore } from '../../store/projectStore'
 import type { RecoveryData, CrashReport } from './types'
 
 export type { RecoveryData, CrashReport }
@@ -35,7 +36,13 @@ export const saveRecoveryData = async (data: {
   }
 
   try {
-    const result = await commands.saveRecoveryData(asJsonValue(recoveryData))
+    const recovery = useProjectStore.getState().globalSettings?.general?.recovery
+    const result = await commands.saveRecoveryData(asJsonValue(recoveryData), {
+      maxFiles: recovery?.maxFiles ?? null,
+      maxAgeDays: recovery?.maxAgeDays ?? null,
+      maxTotalSizeMb: recovery?.maxTotalSizeMb ?? null,
+      encrypt: recovery?.encrypt ?? null,
+    })
     if (result.status === 'error') {
       await logError(`Failed to save recovery data: ${result.error}`)
       return
@@ -0,0 +1,41 @@
+import { commands } from '@/lib/bindings'
+import { useProjectStore } from '../store/projectStore'
+import { queryClient } from './query-client'
+import { queryKeys } from './query-keys'
+import { toast } from './toast'
+
+/**
+ * Runs `astro sync` so a remote collection's loader fetches its entries again,
+ * then shows the refreshed entries
+ */
+export async function refreshRemoteCollection(
+  collectionName: string
+): Promise<void> {
+  const { projectPath } = useProjectStore.getState()
+  if (!projectPath) return
+
+  const id = `refresh-remote-${collectionName}`
+  toast.loading(`Refreshing ${collectionName}…`, {
+    description: 'Running astro sync',
+    id,
+  })
+  const result = await commands.refreshRemoteCollection(
+    projectPath,
+    collectionName
+  )
+  if (result.status === 'error') {
+    toast.error(`Failed to refresh ${collectionName}`, {
+      description: result.error,
+      id,
+    })
+    return
+  }
+
+  queryClient.setQueryData(
+    queryKeys.remoteCollection(projectPath, collectionName),
+    result.data
+  )
+  const count = result.data.length
+  const entries = count === 1 ? 'entry' : 'entries'
+  toast.success(`Loaded ${count} ${entries} from ${collectionName}`, { id })
+}
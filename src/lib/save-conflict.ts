@@ -0,0 +1,157 @@
+import { commands, type BodyHunk } from '@/lib/bindings'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import { queryClient } from './query-client'
+import { queryKeys } from './query-keys'
+import { toast } from './toast'
+
+/**
+ * Resolves a save conflict by discarding the edits and loading the file as
+ * it is now on disk.
+ */
+export async function reloadFromDisk(): Promise<void> {
+  const { currentFile, draftTimeoutId } = useEditorStore.getState()
+  const { projectPath } = useProjectStore.getState()
+  if (!currentFile || !projectPath) return
+
+  // The file is already open, so it isn't held back by the size limit
+  const result = await commands.parseMarkdownContent(
+    currentFile.path,
+    projectPath,
+    null,
+    true
+  )
+  if (result.status === 'error' && result.error.kind === 'failed') {
+    toast.error('Failed to reload the file', {
+      description: result.error.message,
+    })
+    return
+  }
+  if (useEditorStore.getState().currentFile?.path !== currentFile.path) return
+
+  if (draftTimeoutId) clearTimeout(draftTimeoutId)
+  useEditorStore.setState({
+    // Broken frontmatter is shown by the file view once the query refetches
+    ...(result.status === 'ok' && {
+      editorContent: result.data.content,
+      frontmatter: result.data.frontmatter,
+      rawFrontmatter: result.data.raw_frontmatter,
+      imports: result.data.imports,
+      contentHash: result.data.content_hash,
+    }),
+    saveConflict: null,
+    draftTimeoutId: null,
+    isDirty: false,
+    isFrontmatterDirty: false,
+    lastSaveTimestamp: Date.now(),
+  })
+  void commands.discardDraft(currentFile.path, projectPath)
+  void queryClient.invalidateQueries({
+    queryKey: queryKeys.fileContent(projectPath, currentFile.id),
+  })
+}
+
+/**
+ * Resolves a save conflict by saving the edits over the changes on disk.
+ */
+export async function overwriteDisk(): Promise<void> {
+  // Without the hash it was loaded with, the save isn't checked against disk
+  useEditorStore.setState({ saveConflict: null, contentHash: null })
+  await useEditorStore.getState().saveFile(true)
+}
+
+/**
+ * Both sides of lines the edits and the file on disk changed differently,
+ * marked as git marks a conflict
+ */
+function conflictMarkers(hunk: Extract<BodyHunk, { kind: 'conflict' }>) {
+  const lines = (text: string) =>
+    text === '' || text.endsWith('\n') ? text : `${text}\n`
+  return [
+    '<<<<<<< Your edits\n',
+    lines(hunk.mine),
+    '=======\n',
+    lines(hunk.theirs),
+    '>>>>>>> On disk\n',
+  ].join('')
+}
+
+/**
+ * Resolves a save conflict by combining the edits with the changes on disk,
+ * against the version the file was loaded as. A clean merge is saved right
+ * away. Otherwise the merge is loaded into the editor to finish by hand:
+ * lines both sides changed are marked as conflicts, and fields both sides
+ * changed keep the edited values.
+ */
+export async function mergeWithDisk(): Promise<void> {
+  const { currentFile, saveConflict } = useEditorStore.getState()
+  const { projectPath } = useProjectStore.getState()
+  if (!currentFile || !projectPath || !saveConflict) return
+  const { baseContent, attemptedContent, diskContent, diskHash } = saveConflict
+  if (baseContent === null || diskContent === null) return
+
+  const result = await commands.mergeFileVersions(
+    baseContent,
+    attemptedContent,
+    diskContent
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to merge the changes', { description: result.error })
+    return
+  }
+  const { merged, frontmatter, fieldConflicts, bodyHunks } = result.data
+
+  if (merged !== null) {
+    const written = await commands.writeFile(
+      currentFile.path,
+      merged,
+      projectPath,
+      'merge',
+      diskHash
+    )
+    if (written.status === 'error') {
+      if (written.error.kind === 'conflict') {
+        // Changed on disk again in the meantime
+        useEditorStore.setState({ saveConflict: written.error })
+      } else {
+        toast.error('Failed to save the merge', {
+          description: written.error.message,
+        })
+      }
+      return
+    }
+    await reloadFromDisk()
+    toast.success('Your edits were merged with the changes on disk')
+    return
+  }
+
+  const keptFields = fieldConflicts.filter(c => c.ours !== null)
+  useEditorStore.setState({
+    editorContent: bodyHunks
+      .map(hunk =>
+        hunk.kind === 'merged' ? hunk.text : conflictMarkers(hunk)
+      )
+      .join(''),
+    frontmatter: {
+      ...frontmatter,
+      ...Object.fromEntries(keptFields.map(c => [c.field, c.ours])),
+    },
+    imports: '',
+    // Saving replaces the version on disk that was merged in
+    contentHash: diskHash,
+    saveConflict: null,
+    isDirty: true,
+    isFrontmatterDirty: true,
+  })
+  const fields = fieldConflicts.map(c => c.field).join(', ')
+  const overlapping = bodyHunks.some(hunk => hunk.kind === 'conflict')
+  toast.warning('Some of the changes overlap', {
+    description: [
+      overlapping && 'Overlapping lines are marked in the text.',
+      fields && `Kept your values for ${fields}.`,
+    ]
+      .filter(Boolean)
+      .join(' '),
+    duration: 10000,
+  })
+}
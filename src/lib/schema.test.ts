@@ -777,4 +777,65 @@ describe('deserializeCompleteSchema', () => {
       expect(result.collectionName).toBe('blog_posts-2024')
     })
   })
+
+  describe('Field Groups', () => {
+    it('should keep group descriptors and field group keys', () => {
+      const schemaJson = JSON.stringify({
+        collectionName: 'posts',
+        fields: [
+          {
+            name: 'seo.title',
+            label: 'Title',
+            fieldType: 'string',
+            required: false,
+            isNested: true,
+            parentPath: 'seo',
+            group: 'seo',
+          },
+        ],
+        groups: [{ path: 'seo', label: 'SEO', order: 0, collapsed: true }],
+      })
+
+      const result = deserializeCompleteSchema(schemaJson)
+
+      assertResult(result)
+      expect(result.fields[0]!.group).toBe('seo')
+      expect(result.groups).toEqual([
+        { path: 'seo', label: 'SEO', order: 0, collapsed: true },
+      ])
+    })
+
+    it('should default to no groups for schemas without them', () => {
+      const schemaJson = JSON.stringify({ collectionName: 'posts', fields: [] })
+
+      const result = deserializeCompleteSchema(schemaJson)
+
+      assertResult(result)
+      expect(result.groups).toEqual([])
+    })
+  })
+
+  describe('Inferred Schemas', () => {
+    it('should flag schemas inferred from existing entries', () => {
+      const schemaJson = JSON.stringify({
+        collectionName: 'posts',
+        fields: [],
+        inferred: true,
+      })
+
+      const result = deserializeCompleteSchema(schemaJson)
+
+      assertResult(result)
+      expect(result.inferred).toBe(true)
+    })
+
+    it('should treat schemas without the flag as declared', () => {
+      const schemaJson = JSON.stringify({ collectionName: 'posts', fields: [] })
+
+      const result = deserializeCompleteSchema(schemaJson)
+
+      assertResult(result)
+      expect(result.inferred).toBe(false)
+    })
+  })
 })
@@ -2,6 +2,17 @@
 export interface CompleteSchema {
   collectionName: string
   fields: SchemaField[]
+  groups: SchemaGroup[] // Nested objects, in display order
+  inferred: boolean // Guessed from existing entries (no schema in the project)
+}
+
+// A nested object shown as a group of fields in the frontmatter panel
+export interface SchemaGroup {
+  path: string // Dotted path of the object, e.g. "seo.openGraph"
+  label: string
+  parent?: string // Enclosing group's path, for objects nested in objects
+  order: number
+  collapsed: boolean // Starts collapsed (from the fieldGroups project setting)
 }
 
 export interface SchemaField {
@@ -33,6 +44,7 @@ export interface SchemaField {
   nestedFields?: SchemaField[] // Child fields for object types
   isNested?: boolean // Is this field nested under a parent?
   parentPath?: string // Parent path, e.g. "author" for "author.name"
+  group?: string // Path of the SchemaGroup the field is shown in
 }
 
 export interface FieldConstraints {
@@ -77,7 +89,10 @@ interface RawCompleteSchema {
     arrayReferenceCollection?: string
     isNested?: boolean
     parentPath?: string
+    group?: string
   }>
+  groups?: SchemaGroup[]
+  inferred?: boolean
 }
 
 /**
@@ -130,11 +145,14 @@ export function deserializeCompleteSchema(
       referenceCollection: field.referenceCollection, // Backwards compat
       isNested: field.isNested,
       parentPath: field.parentPath,
+      group: field.group,
     }))
 
     return {
       collectionName: parsed.collectionName,
       fields,
+      groups: parsed.groups ?? [],
+      inferred: parsed.inferred ?? false,
     }
   } catch (error) {
     if (import.meta.env.DEV) {
@@ -0,0 +1,42 @@
+import { commands } from '@/lib/bindings'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import { useScratchpadStore } from '../store/scratchpadStore'
+import { getEffectiveContentDirectory } from './project-registry/path-resolution'
+import { ASTRO_PATHS } from './constants'
+import { queryClient } from './query-client'
+import { queryKeys } from './query-keys'
+import { toast } from './toast'
+
+/**
+ * Creates an empty scratchpad in app data and opens it, for pasting and
+ * thinking before anything belongs in a collection.
+ */
+export async function newScratchpad(content?: string): Promise<void> {
+  const result = await commands.createScratchpad(content ?? null)
+  if (result.status === 'error') {
+    toast.error('Failed to create scratchpad', { description: result.error })
+    return
+  }
+
+  await queryClient.invalidateQueries({ queryKey: queryKeys.scratchpads() })
+  useScratchpadStore.getState().open(result.data.id)
+}
+
+/**
+ * Opens an entry just promoted from a scratchpad in the editor
+ */
+export async function openPromotedEntry(filePath: string): Promise<void> {
+  const { projectPath, currentProjectSettings } = useProjectStore.getState()
+  if (!projectPath) return
+
+  const contentDirectory = getEffectiveContentDirectory(currentProjectSettings)
+  const entry = await commands.resolveFileEntry(
+    filePath,
+    projectPath,
+    contentDirectory !== ASTRO_PATHS.CONTENT_DIR ? contentDirectory : null
+  )
+  if (entry.status === 'ok' && entry.data) {
+    useEditorStore.getState().openFile(entry.data)
+  }
+}
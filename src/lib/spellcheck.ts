@@ -0,0 +1,83 @@
+import { EditorSelection } from '@codemirror/state'
+import { commands, type Misspelling } from '@/lib/bindings'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import { getCurrentEditorView } from './editor/editor-view-ref'
+import { toast } from './toast'
+
+function selectMisspelling(misspelling: Misspelling): void {
+  const view = getCurrentEditorView()
+  if (!view) return
+  view.dispatch({
+    selection: EditorSelection.range(misspelling.start, misspelling.end),
+    scrollIntoView: true,
+  })
+  view.focus()
+}
+
+/**
+ * Lists misspelled words in the open document, checked against the
+ * dictionary for the system language and the project's own word list
+ */
+export async function checkSpelling(): Promise<void> {
+  const { currentFile, editorContent } = useEditorStore.getState()
+  const { projectPath } = useProjectStore.getState()
+  if (!currentFile) return
+
+  const result = await commands.checkText(
+    editorContent,
+    navigator.language,
+    projectPath
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to check spelling', { description: result.error })
+    return
+  }
+
+  const misspellings = result.data
+  const first = misspellings[0]
+  if (!first) {
+    toast.success('No spelling mistakes found')
+    return
+  }
+  const words = [...new Set(misspellings.map(m => m.word))]
+  toast.warning(`Found ${words.length} misspelled words`, {
+    description: words
+      .slice(0, 5)
+      .map(word => {
+        const match = misspellings.find(m => m.word === word)
+        const suggestion = match?.suggestions[0]
+        return suggestion ? `${word} → ${suggestion}` : word
+      })
+      .join('\n'),
+    action: {
+      label: 'Go to First',
+      onClick: () => selectMisspelling(first),
+    },
+  })
+}
+
+/**
+ * Adds the selected word, or the word at the cursor, to the project's
+ * dictionary
+ */
+export async function addWordToDictionary(): Promise<void> {
+  const view = getCurrentEditorView()
+  const { projectPath } = useProjectStore.getState()
+  if (!view || !projectPath) return
+
+  const { main } = view.state.selection
+  const range = main.empty ? view.state.wordAt(main.head) : main
+  const word = range ? view.state.sliceDoc(range.from, range.to).trim() : ''
+  if (!word) {
+    toast.info('Select a word to add to the dictionary')
+    return
+  }
+
+  const result = await commands.addToProjectDictionary(projectPath, word)
+  if (result.status === 'error') {
+    toast.error('Failed to add word', { description: result.error })
+    return
+  }
+  toast.success(`Added "${word}" to the project dictionary`)
+}
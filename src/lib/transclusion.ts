@@ -0,0 +1,57 @@
+import { commands } from '@/lib/bindings'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import { toast } from './toast'
+
+/**
+ * Expands includes in the open document's body for preview and export.
+ * Includes that can't be expanded are left as written; if resolving fails
+ * altogether, the body is returned unchanged.
+ */
+export async function expandIncludes(markdown: string): Promise<string> {
+  const { currentFile } = useEditorStore.getState()
+  const { projectPath, currentProjectSettings } = useProjectStore.getState()
+  if (!currentFile || !projectPath) return markdown
+
+  const result = await commands.resolveTransclusions(
+    projectPath,
+    currentFile.path,
+    markdown,
+    currentProjectSettings?.transclusion ?? null,
+    null
+  )
+  return result.status === 'ok' ? result.data.content : markdown
+}
+
+/**
+ * Lists includes in the open document that point at missing files, leave
+ * the project, form a cycle or nest too deep
+ */
+export async function checkIncludes(): Promise<void> {
+  const { currentFile, editorContent } = useEditorStore.getState()
+  const { projectPath, currentProjectSettings } = useProjectStore.getState()
+  if (!currentFile || !projectPath) return
+
+  const result = await commands.lintTransclusions(
+    projectPath,
+    currentFile.path,
+    editorContent,
+    currentProjectSettings?.transclusion ?? null
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to check includes', { description: result.error })
+    return
+  }
+
+  const problems = result.data
+  if (problems.length === 0) {
+    toast.success('All includes resolve')
+    return
+  }
+  toast.warning(`Found ${problems.length} broken includes`, {
+    description: problems
+      .slice(0, 5)
+      .map(problem => `Line ${problem.line}: ${problem.message}`)
+      .join('\n'),
+  })
+}
@@ -0,0 +1,87 @@
+import { open } from '@tauri-apps/plugin-dialog'
+import { commands, type Collection } from '@/lib/bindings'
+import { useEditorStore } from '../store/editorStore'
+import { useProjectStore } from '../store/projectStore'
+import {
+  getEffectiveContentDirectory,
+  getEffectiveFrontmatterMappings,
+} from './project-registry'
+import { ASTRO_PATHS } from './constants'
+import { queryClient } from './query-client'
+import { queryKeys } from './query-keys'
+import { slugFromTitle } from './slug'
+import { toast } from './toast'
+
+/**
+ * Title for a transcript file, e.g. "episode-12_final.srt" → "episode 12 final"
+ */
+function titleFromTranscriptPath(path: string): string {
+  const name = path.split(/[/\\]/).pop() ?? path
+  return name
+    .replace(/\.[^.]+$/, '')
+    .replace(/[-_]+/g, ' ')
+    .trim()
+}
+
+/**
+ * Picks a transcript (plain text, SRT or VTT) and creates a cleaned-up draft
+ * from it in the selected collection, then opens the draft.
+ */
+export async function importTranscript(): Promise<void> {
+  const { projectPath, selectedCollection, currentProjectSettings } =
+    useProjectStore.getState()
+  if (!projectPath || !selectedCollection) {
+    toast.error('Select a collection to import a transcript into')
+    return
+  }
+
+  const collection = queryClient
+    .getQueryData<Collection[]>(queryKeys.collections(projectPath))
+    ?.find(c => c.name === selectedCollection)
+  if (!collection) return
+
+  const selected = await open({
+    multiple: false,
+    filters: [{ name: 'Transcripts', extensions: ['txt', 'srt', 'vtt'] }],
+  })
+  if (!selected) return
+
+  const title = titleFromTranscriptPath(selected) || 'Transcript'
+  const mappings = getEffectiveFrontmatterMappings(
+    currentProjectSettings,
+    selectedCollection
+  )
+  const result = await commands.ingestTranscript(
+    projectPath,
+    selected,
+    collection.path,
+    `${slugFromTitle(title) || 'transcript'}.md`,
+    title,
+    {
+      title: mappings.title,
+      date: [mappings.publishedDate].flat()[0] ?? null,
+      draft: mappings.draft,
+    },
+    null
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to import transcript', { description: result.error })
+    return
+  }
+
+  const contentDirectory = getEffectiveContentDirectory(currentProjectSettings)
+  const entry = await commands.resolveFileEntry(
+    result.data.filePath,
+    projectPath,
+    contentDirectory !== ASTRO_PATHS.CONTENT_DIR ? contentDirectory : null
+  )
+  if (entry.status === 'ok' && entry.data) {
+    useEditorStore.getState().openFile(entry.data)
+  }
+
+  const { paragraphCount, wordCount, fillersRemoved } = result.data
+  toast.success(`Imported ${wordCount} words in ${paragraphCount} paragraphs`, {
+    description:
+      fillersRemoved > 0 ? `Removed ${fillersRemoved} filler words` : undefined,
+  })
+}
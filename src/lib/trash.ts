@@ -0,0 +1,56 @@
+import { commands } from '@/lib/bindings'
+import type { TrashedFile } from '@/types'
+import { useProjectStore } from '../store/projectStore'
+import { queryClient } from './query-client'
+import { queryKeys } from './query-keys'
+import { toast } from './toast'
+
+/**
+ * The file name a trashed file was deleted under
+ */
+export function trashedFileName(file: TrashedFile): string {
+  return file.originalPath.split(/[\\/]/).pop() ?? file.originalPath
+}
+
+/**
+ * Restores a trashed file to where it was deleted from and refreshes the
+ * project's queries so it reappears in the sidebar.
+ */
+export async function restoreTrashedFile(file: TrashedFile): Promise<boolean> {
+  const { projectPath } = useProjectStore.getState()
+  if (!projectPath) return false
+
+  const result = await commands.restoreTrashedFile(
+    file.id,
+    projectPath,
+    'restore'
+  )
+  if (result.status === 'error') {
+    toast.error('Failed to restore file', { description: result.error })
+    return false
+  }
+
+  await queryClient.invalidateQueries({
+    queryKey: [...queryKeys.all, projectPath],
+  })
+  toast.success(`Restored ${trashedFileName(file)}`)
+  return true
+}
+
+/**
+ * Confirms a delete, offering to undo it when the file went to the trash
+ * rather than being deleted outright (dry runs, no app data).
+ */
+export function notifyFileDeleted(trashed: TrashedFile | null): void {
+  if (!trashed) {
+    toast.success('File deleted successfully')
+    return
+  }
+
+  toast.success(`Moved ${trashedFileName(trashed)} to the trash`, {
+    action: {
+      label: 'Undo',
+      onClick: () => void restoreTrashedFile(trashed),
+    },
+  })
+}
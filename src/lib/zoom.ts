@@ -0,0 +1,48 @@
+import { commands } from '@/lib/bindings'
+import { useProjectStore } from '../store/projectStore'
+import { toast } from './toast'
+
+/** Zoom levels stepped through by Zoom In / Zoom Out, matching browsers */
+export const ZOOM_STEPS = [
+  0.5, 0.67, 0.75, 0.8, 0.9, 1, 1.1, 1.25, 1.5, 1.75, 2, 2.5, 3,
+]
+
+let currentZoom = 1
+
+async function applyZoom(level: number): Promise<void> {
+  const { projectPath } = useProjectStore.getState()
+  const result = await commands.setZoomLevel(projectPath, level)
+  if (result.status === 'error') {
+    toast.error('Failed to change zoom', { description: result.error })
+    return
+  }
+  currentZoom = result.data
+}
+
+export function zoomIn(): Promise<void> {
+  const next = ZOOM_STEPS.find(step => step > currentZoom + 0.001)
+  return applyZoom(next ?? ZOOM_STEPS[ZOOM_STEPS.length - 1]!)
+}
+
+export function zoomOut(): Promise<void> {
+  const smaller = ZOOM_STEPS.filter(step => step < currentZoom - 0.001)
+  return applyZoom(smaller[smaller.length - 1] ?? ZOOM_STEPS[0]!)
+}
+
+export function resetZoom(): Promise<void> {
+  return applyZoom(1)
+}
+
+/**
+ * Applies the zoom level saved for a project (100% when it has none)
+ */
+export async function restoreProjectZoom(
+  projectPath: string | null
+): Promise<void> {
+  const saved = await commands.getZoomLevel(projectPath)
+  const level = saved.status === 'ok' ? saved.data : 1
+  const result = await commands.setZoomLevel(null, level)
+  if (result.status === 'ok') {
+    currentZoom = result.data
+  }
+}
@@ -1,10 +1,15 @@
 import { describe, it, expect, beforeEach, vi } from 'vitest'
 import { useUpdateStore } from '../updateStore'
-import type { Update } from '@tauri-apps/plugin-updater'
+import type { AvailableUpdate } from '@/types'
 
 const SKIPPED_VERSION_KEY = 'astro-editor-skipped-update-version'
 
-const mockUpdate = {} as Update
+const mockUpdate: AvailableUpdate = {
+  version: '1.0.9',
+  date: null,
+  releaseNotes: '## v1.0.9\n\nSome notes',
+  deferred: false,
+}
 
 function resetStore() {
   useUpdateStore.setState({
@@ -13,10 +18,8 @@ function resetStore() {
     version: null,
     currentVersion: null,
     errorMessage: null,
-    updateRef: null,
     releaseNotes: null,
-    releaseNotesLoading: false,
-    releaseNotesError: false,
+    deferred: false,
     downloadProgress: 0,
     downloadTotal: null,
     skippedVersion: null,
@@ -46,47 +49,40 @@ describe('updateStore', () => {
 
   describe('setAvailable', () => {
     it('sets update info and opens dialog', () => {
-      useUpdateStore.getState().setAvailable(mockUpdate, '1.0.9', '1.0.8')
+      useUpdateStore.getState().setAvailable(mockUpdate, '1.0.8')
       const state = useUpdateStore.getState()
       expect(state.dialogOpen).toBe(true)
       expect(state.dialogMode).toBe('available')
       expect(state.version).toBe('1.0.9')
       expect(state.currentVersion).toBe('1.0.8')
-      expect(state.updateRef).toBe(mockUpdate)
+      expect(state.deferred).toBe(false)
     })
 
-    it('starts release notes loading', () => {
-      useUpdateStore.getState().setAvailable(mockUpdate, '1.0.9', '1.0.8')
-      const state = useUpdateStore.getState()
-      expect(state.releaseNotesLoading).toBe(true)
-      expect(state.releaseNotes).toBeNull()
-      expect(state.releaseNotesError).toBe(false)
+    it('sets release notes from the update', () => {
+      useUpdateStore.getState().setAvailable(mockUpdate, '1.0.8')
+      expect(useUpdateStore.getState().releaseNotes).toBe(
+        '## v1.0.9\n\nSome notes'
+      )
     })
 
-    it('clears previous error', () => {
-      useUpdateStore.setState({ errorMessage: 'old error' })
-      useUpdateStore.getState().setAvailable(mockUpdate, '1.0.9', '1.0.8')
-      expect(useUpdateStore.getState().errorMessage).toBeNull()
+    it('treats empty release notes as missing', () => {
+      useUpdateStore
+        .getState()
+        .setAvailable({ ...mockUpdate, releaseNotes: '' }, '1.0.8')
+      expect(useUpdateStore.getState().releaseNotes).toBeNull()
     })
-  })
 
-  describe('setReleaseNotes', () => {
-    it('sets notes and stops loading', () => {
-      useUpdateStore.setState({ releaseNotesLoading: true })
-      useUpdateStore.getState().setReleaseNotes('## v1.0.9\n\nSome notes')
-      const state = useUpdateStore.getState()
-      expect(state.releaseNotes).toBe('## v1.0.9\n\nSome notes')
-      expect(state.releaseNotesLoading).toBe(false)
+    it('records an update that is already deferred', () => {
+      useUpdateStore
+        .getState()
+        .setAvailable({ ...mockUpdate, deferred: true }, '1.0.8')
+      expect(useUpdateStore.getState().deferred).toBe(true)
     })
-  })
 
-  describe('setReleaseNotesError', () => {
-    it('sets error flag and stops loading', () => {
-      useUpdateStore.setState({ releaseNotesLoading: true })
-      useUpdateStore.getState().setReleaseNotesError()
-      const state = useUpdateStore.getState()
-      expect(state.releaseNotesLoading).toBe(false)
-      expect(state.releaseNotesError).toBe(true)
+    it('clears previous error', () => {
+      useUpdateStore.setState({ errorMessage: 'old error' })
+      useUpdateStore.getState().setAvailable(mockUpdate, '1.0.8')
+      expect(useUpdateStore.getState().errorMessage).toBeNull()
     })
   })
 
@@ -103,14 +99,9 @@ describe('updateStore', () => {
       useUpdateStore.setState({
         dialogOpen: true,
         releaseNotes: 'some notes',
-        releaseNotesLoading: true,
-        releaseNotesError: true,
       })
       useUpdateStore.getState().closeDialog()
-      const state = useUpdateStore.getState()
-      expect(state.releaseNotes).toBeNull()
-      expect(state.releaseNotesLoading).toBe(false)
-      expect(state.releaseNotesError).toBe(false)
+      expect(useUpdateStore.getState().releaseNotes).toBeNull()
     })
 
     it('resets download progress', () => {
@@ -125,16 +116,16 @@ describe('updateStore', () => {
       expect(state.downloadTotal).toBeNull()
     })
 
-    it('preserves version and updateRef', () => {
+    it('preserves version and deferred state', () => {
       useUpdateStore.setState({
         dialogOpen: true,
         version: '1.0.9',
-        updateRef: mockUpdate,
+        deferred: true,
       })
       useUpdateStore.getState().closeDialog()
       const state = useUpdateStore.getState()
       expect(state.version).toBe('1.0.9')
-      expect(state.updateRef).toBe(mockUpdate)
+      expect(state.deferred).toBe(true)
     })
   })
 
@@ -184,6 +175,16 @@ describe('updateStore', () => {
     })
   })
 
+  describe('setDeferred', () => {
+    it('closes the dialog and marks the update deferred', () => {
+      useUpdateStore.setState({ dialogOpen: true, dialogMode: 'available' })
+      useUpdateStore.getState().setDeferred()
+      const state = useUpdateStore.getState()
+      expect(state.dialogOpen).toBe(false)
+      expect(state.deferred).toBe(true)
+    })
+  })
+
   describe('setNoUpdate', () => {
     it('opens dialog in no-update mode', () => {
       useUpdateStore.getState().setNoUpdate('1.0.8')
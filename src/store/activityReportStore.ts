@@ -0,0 +1,24 @@
+import { create } from 'zustand'
+
+interface ActivityReportState {
+  isOpen: boolean
+}
+
+interface ActivityReportActions {
+  open: () => void
+  close: () => void
+}
+
+export const useActivityReportStore = create<
+  ActivityReportState & ActivityReportActions
+>(set => ({
+  isOpen: false,
+
+  open: () => {
+    set({ isOpen: true })
+  },
+
+  close: () => {
+    set({ isOpen: false })
+  },
+}))
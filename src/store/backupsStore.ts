@@ -0,0 +1,22 @@
+import { create } from 'zustand'
+
+interface BackupsState {
+  isOpen: boolean
+}
+
+interface BackupsActions {
+  open: () => void
+  close: () => void
+}
+
+export const useBackupsStore = create<BackupsState & BackupsActions>(set => ({
+  isOpen: false,
+
+  open: () => {
+    set({ isOpen: true })
+  },
+
+  close: () => {
+    set({ isOpen: false })
+  },
+}))
@@ -0,0 +1,30 @@
+import { create } from 'zustand'
+
+interface AuditedCollection {
+  name: string
+  path: string
+}
+
+interface CodeBlockAuditState {
+  // The collection being audited; the dialog is open while set
+  collection: AuditedCollection | null
+}
+
+interface CodeBlockAuditActions {
+  open: (collection: AuditedCollection) => void
+  close: () => void
+}
+
+export const useCodeBlockAuditStore = create<
+  CodeBlockAuditState & CodeBlockAuditActions
+>(set => ({
+  collection: null,
+
+  open: collection => {
+    set({ collection })
+  },
+
+  close: () => {
+    set({ collection: null })
+  },
+}))
@@ -0,0 +1,24 @@
+import { create } from 'zustand'
+
+interface ContentCalendarState {
+  isOpen: boolean
+}
+
+interface ContentCalendarActions {
+  open: () => void
+  close: () => void
+}
+
+export const useContentCalendarStore = create<
+  ContentCalendarState & ContentCalendarActions
+>(set => ({
+  isOpen: false,
+
+  open: () => {
+    set({ isOpen: true })
+  },
+
+  close: () => {
+    set({ isOpen: false })
+  },
+}))
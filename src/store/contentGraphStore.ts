@@ -0,0 +1,24 @@
+import { create } from 'zustand'
+
+interface ContentGraphState {
+  isOpen: boolean
+}
+
+interface ContentGraphActions {
+  open: () => void
+  close: () => void
+}
+
+export const useContentGraphStore = create<
+  ContentGraphState & ContentGraphActions
+>(set => ({
+  isOpen: false,
+
+  open: () => {
+    set({ isOpen: true })
+  },
+
+  close: () => {
+    set({ isOpen: false })
+  },
+}))
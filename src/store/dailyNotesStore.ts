@@ -0,0 +1,24 @@
+import { create } from 'zustand'
+
+interface DailyNotesState {
+  isOpen: boolean
+}
+
+interface DailyNotesActions {
+  open: () => void
+  close: () => void
+}
+
+export const useDailyNotesStore = create<DailyNotesState & DailyNotesActions>(
+  set => ({
+    isOpen: false,
+
+    open: () => {
+      set({ isOpen: true })
+    },
+
+    close: () => {
+      set({ isOpen: false })
+    },
+  })
+)
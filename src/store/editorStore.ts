@@ -1,9 +1,18 @@
 import { create } from 'zustand'
 import { useProjectStore } from './projectStore'
 import { setNestedValue, deleteNestedValue } from '../lib/object-utils'
-import type { FileEntry } from '@/types'
+import {
+  commands,
+  type FileEntry,
+  type FileSaveError,
+  type JsonValue,
+} from '@/types'
 
 const MAX_AUTO_SAVE_DELAY_MS = 10000 // Maximum time between auto-saves (10 seconds)
+const DRAFT_SAVE_DELAY_MS = 500 // Quiet time before unsaved edits are kept as a draft
+
+// A save refused because the file changed on disk since it was loaded
+export type SaveConflict = Extract<FileSaveError, { kind: 'conflict' }>
 
 interface EditorState {
   // File state
@@ -14,11 +23,16 @@ interface EditorState {
   frontmatter: Record<string, unknown> // Current frontmatter being edited
   rawFrontmatter: string // Original frontmatter string from disk
   imports: string // MDX imports (hidden from editor)
+  contentHash: string | null // Hash of the file on disk when loaded or last saved
+  allowLargeFile: boolean // Open the current file even though it's over the size limit
+  frontmatterOnly: boolean // Only the frontmatter is loaded; saves leave the body alone
+  saveConflict: SaveConflict | null // Unresolved save conflict; pauses auto-save
 
   // Status state
   isDirty: boolean // True if ANY changes need to be saved
   isFrontmatterDirty: boolean // True if frontmatter was modified (vs content-only edits)
   autoSaveTimeoutId: ReturnType<typeof setTimeout> | null // Auto-save timeout ID
+  draftTimeoutId: ReturnType<typeof setTimeout> | null // Pending unsaved-draft write
   lastSaveTimestamp: number | null // Timestamp of last successful save
   autoSaveCallback: ((showToast?: boolean) => Promise<void>) | null // Hook-provided save callback
 
@@ -30,6 +44,7 @@ interface EditorState {
   updateFrontmatter: (frontmatter: Record<string, unknown>) => void
   updateFrontmatterField: (key: string, value: unknown) => void
   scheduleAutoSave: () => void
+  scheduleDraftSave: () => void
   setAutoSaveCallback: (
     callback: ((showToast?: boolean) => Promise<void>) | null
   ) => void
@@ -43,9 +58,14 @@ export const useEditorStore = create<EditorState>((set, get) => ({
   frontmatter: {},
   rawFrontmatter: '',
   imports: '',
+  contentHash: null,
+  allowLargeFile: false,
+  frontmatterOnly: false,
+  saveConflict: null,
   isDirty: false,
   isFrontmatterDirty: false,
   autoSaveTimeoutId: null,
+  draftTimeoutId: null,
   lastSaveTimestamp: null,
   autoSaveCallback: null,
 
@@ -53,10 +73,13 @@ export const useEditorStore = create<EditorState>((set, get) => ({
   openFile: (file: FileEntry) => {
     // Clear auto-save timeout if it exists to prevent race condition
     // where previous file's auto-save could fire after opening new file
-    const { autoSaveTimeoutId } = get()
+    const { autoSaveTimeoutId, draftTimeoutId } = get()
     if (autoSaveTimeoutId) {
       clearTimeout(autoSaveTimeoutId)
     }
+    if (draftTimeoutId) {
+      clearTimeout(draftTimeoutId)
+    }
 
     // CRITICAL: Clear content FIRST, then set currentFile
     // This prevents Editor.tsx from reading stale content via getState()
@@ -65,10 +88,15 @@ export const useEditorStore = create<EditorState>((set, get) => ({
       frontmatter: {},
       rawFrontmatter: '',
       imports: '',
+      contentHash: null,
+      allowLargeFile: false,
+      frontmatterOnly: false,
+      saveConflict: null,
       currentFile: file,
       isDirty: false,
       isFrontmatterDirty: false,
       autoSaveTimeoutId: null,
+      draftTimeoutId: null,
       lastSaveTimestamp: Date.now(),
     })
 
@@ -80,15 +108,24 @@ export const useEditorStore = create<EditorState>((set, get) => ({
       })
     )
 
+    // Track usage for recent/frequent file lists (fire-and-forget)
+    const { projectPath } = useProjectStore.getState()
+    if (projectPath) {
+      void commands.recordFileOpened(projectPath, file.path)
+    }
+
     // Content will be loaded by useFileContentQuery hook
   },
 
   closeCurrentFile: () => {
     // Clear auto-save timeout if it exists
-    const { autoSaveTimeoutId } = get()
+    const { autoSaveTimeoutId, draftTimeoutId } = get()
     if (autoSaveTimeoutId) {
       clearTimeout(autoSaveTimeoutId)
     }
+    if (draftTimeoutId) {
+      clearTimeout(draftTimeoutId)
+    }
 
     // Clear all file-related state
     set({
@@ -97,9 +134,14 @@ export const useEditorStore = create<EditorState>((set, get) => ({
       frontmatter: {},
       rawFrontmatter: '',
       imports: '',
+      contentHash: null,
+      allowLargeFile: false,
+      frontmatterOnly: false,
+      saveConflict: null,
       isDirty: false,
       isFrontmatterDirty: false,
       autoSaveTimeoutId: null,
+      draftTimeoutId: null,
       lastSaveTimestamp: null,
     })
   },
@@ -150,9 +192,15 @@ export const useEditorStore = create<EditorState>((set, get) => ({
   },
 
   scheduleAutoSave: () => {
+    // Keep the edits in the backend in case this save never happens
+    get().scheduleDraftSave()
+
     const store = get()
     const now = Date.now()
 
+    // Saving would only be refused again until the user picks a version
+    if (store.saveConflict) return
+
     // Check if we should force save due to max delay
     if (store.isDirty && store.lastSaveTimestamp) {
       const timeSinceLastSave = now - store.lastSaveTimestamp
@@ -185,6 +233,50 @@ export const useEditorStore = create<EditorState>((set, get) => ({
     set({ autoSaveTimeoutId: timeoutId })
   },
 
+  scheduleDraftSave: () => {
+    const { draftTimeoutId } = get()
+    if (draftTimeoutId) {
+      clearTimeout(draftTimeoutId)
+    }
+
+    const timeoutId = setTimeout(() => {
+      set({ draftTimeoutId: null })
+      const {
+        currentFile,
+        editorContent,
+        frontmatter,
+        isDirty,
+        isFrontmatterDirty,
+        contentHash,
+        frontmatterOnly,
+      } = get()
+      const { projectPath, globalSettings } = useProjectStore.getState()
+      // Saving discards the draft, so there's nothing to keep once saved.
+      // Without the body loaded, a draft would restore as an empty file.
+      if (!currentFile || !projectPath || !isDirty || frontmatterOnly) return
+
+      // Rust emits `content-modified` with word counts since the last save
+      void commands.reportContentChanged(
+        currentFile.path,
+        editorContent,
+        projectPath
+      )
+
+      void commands.saveDraft(
+        currentFile.path,
+        projectPath,
+        editorContent,
+        isFrontmatterDirty
+          ? (frontmatter as Partial<Record<string, JsonValue>>)
+          : null,
+        contentHash,
+        globalSettings?.general?.recovery?.encrypt ?? null
+      )
+    }, DRAFT_SAVE_DELAY_MS)
+
+    set({ draftTimeoutId: timeoutId })
+  },
+
   setAutoSaveCallback: (
     callback: ((showToast?: boolean) => Promise<void>) | null
   ) => {
@@ -0,0 +1,22 @@
+import { create } from 'zustand'
+
+interface EmbargoState {
+  isOpen: boolean
+}
+
+interface EmbargoActions {
+  open: () => void
+  close: () => void
+}
+
+export const useEmbargoStore = create<EmbargoState & EmbargoActions>(set => ({
+  isOpen: false,
+
+  open: () => {
+    set({ isOpen: true })
+  },
+
+  close: () => {
+    set({ isOpen: false })
+  },
+}))
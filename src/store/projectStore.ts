@@ -1,5 +1,5 @@
 import { create } from 'zustand'
-import { commands } from '@/lib/bindings'
+import { commands, type FileChangeEvent } from '@/lib/bindings'
 import { listen, type UnlistenFn } from '@tauri-apps/api/event'
 import { error as logError, info, debug } from '@tauri-apps/plugin-log'
 import { toast } from '../lib/toast'
@@ -161,11 +161,17 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
       // Use path override if configured
       const contentDirectory =
         currentProjectSettings?.pathOverrides?.contentDirectory
+      const excludedDirectories =
+        currentProjectSettings?.watcherExcludedDirectories
 
-      if (contentDirectory && contentDirectory !== ASTRO_PATHS.CONTENT_DIR) {
+      const hasContentOverride =
+        contentDirectory && contentDirectory !== ASTRO_PATHS.CONTENT_DIR
+
+      if (hasContentOverride || excludedDirectories?.length) {
         const result = await commands.startWatchingProjectWithContentDir(
           projectPath,
-          contentDirectory
+          hasContentOverride ? contentDirectory : null,
+          excludedDirectories ?? null
         )
         if (result.status === 'error') {
           throw new Error(result.error)
@@ -178,11 +184,20 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
       }
 
       // Listen for file change events
-      const unlistenFileChanged = await listen(
+      const unlistenFileChanged = await listen<FileChangeEvent>(
         'file-changed',
-        (event: { payload: unknown }) => {
-          // File refresh is now handled by TanStack Query invalidation
-          // This event is kept for future use
+        event => {
+          const { kind, collection } = event.payload
+
+          // Files added, removed or renamed change the collection's listing
+          if (collection && kind !== 'modified') {
+            void queryClient.invalidateQueries({
+              queryKey: [...queryKeys.all, projectPath, collection],
+            })
+            void queryClient.invalidateQueries({
+              queryKey: queryKeys.collectionFiles(projectPath, collection),
+            })
+          }
 
           // Dispatch custom event for editor store to handle recently saved file logic
           window.dispatchEvent(
@@ -473,6 +488,13 @@ export const useProjectStore = create<ProjectState>((set, get) => ({
           await get().startFileWatcher()
         }
 
+        // Field groups are built with the schemas, so rescan collections
+        if ('fieldGroups' in settings) {
+          await queryClient.invalidateQueries({
+            queryKey: queryKeys.collections(projectPath),
+          })
+        }
+
         // If frontmatter mappings changed, invalidate current file to update rendering
         if (settings.frontmatterMappings) {
           const { currentFile } = useEditorStore.getState()
@@ -0,0 +1,32 @@
+import { create } from 'zustand'
+
+interface ScratchpadState {
+  isOpen: boolean
+  // The scratchpad being edited; null shows the most recent one
+  activeId: string | null
+}
+
+interface ScratchpadActions {
+  open: (id?: string | null) => void
+  select: (id: string | null) => void
+  close: () => void
+}
+
+export const useScratchpadStore = create<ScratchpadState & ScratchpadActions>(
+  set => ({
+    isOpen: false,
+    activeId: null,
+
+    open: (id = null) => {
+      set({ isOpen: true, activeId: id })
+    },
+
+    select: id => {
+      set({ activeId: id })
+    },
+
+    close: () => {
+      set({ isOpen: false, activeId: null })
+    },
+  })
+)
@@ -0,0 +1,24 @@
+import { create } from 'zustand'
+
+interface StaleDraftsState {
+  isOpen: boolean
+}
+
+interface StaleDraftsActions {
+  open: () => void
+  close: () => void
+}
+
+export const useStaleDraftsStore = create<
+  StaleDraftsState & StaleDraftsActions
+>(set => ({
+  isOpen: false,
+
+  open: () => {
+    set({ isOpen: true })
+  },
+
+  close: () => {
+    set({ isOpen: false })
+  },
+}))
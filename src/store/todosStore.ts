@@ -0,0 +1,22 @@
+import { create } from 'zustand'
+
+interface TodosState {
+  isOpen: boolean
+}
+
+interface TodosActions {
+  open: () => void
+  close: () => void
+}
+
+export const useTodosStore = create<TodosState & TodosActions>(set => ({
+  isOpen: false,
+
+  open: () => {
+    set({ isOpen: true })
+  },
+
+  close: () => {
+    set({ isOpen: false })
+  },
+}))
@@ -0,0 +1,22 @@
+import { create } from 'zustand'
+
+interface TrashState {
+  isOpen: boolean
+}
+
+interface TrashActions {
+  open: () => void
+  close: () => void
+}
+
+export const useTrashStore = create<TrashState & TrashActions>(set => ({
+  isOpen: false,
+
+  open: () => {
+    set({ isOpen: true })
+  },
+
+  close: () => {
+    set({ isOpen: false })
+  },
+}))
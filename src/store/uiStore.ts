@@ -13,6 +13,7 @@ interface UIState {
   frontmatterPanelVisible: boolean
   focusModeEnabled: boolean
   typewriterModeEnabled: boolean
+  previewPaneVisible: boolean
   distractionFreeBarsHidden: boolean
 
   // View filters (per-collection state, ephemeral)
@@ -26,6 +27,7 @@ interface UIState {
   toggleFrontmatterPanel: () => void
   toggleFocusMode: () => void
   toggleTypewriterMode: () => void
+  togglePreviewPane: () => void
   setDistractionFreeBarsHidden: (hidden: boolean) => void
   handleTypingInEditor: () => void
   showBars: () => void
@@ -56,6 +58,7 @@ export const useUIStore = create<UIState>((set, get) => ({
   frontmatterPanelVisible: true,
   focusModeEnabled: false,
   typewriterModeEnabled: false,
+  previewPaneVisible: false,
   distractionFreeBarsHidden: false,
   draftFilterByCollection: {},
   collectionViewState: {},
@@ -93,6 +96,10 @@ export const useUIStore = create<UIState>((set, get) => ({
     set(state => ({ typewriterModeEnabled: !state.typewriterModeEnabled }))
   },
 
+  togglePreviewPane: () => {
+    set(state => ({ previewPaneVisible: !state.previewPaneVisible }))
+  },
+
   setDistractionFreeBarsHidden: (hidden: boolean) => {
     set({ distractionFreeBarsHidden: hidden })
   },
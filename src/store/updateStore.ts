@@ -1,5 +1,5 @@
 import { create } from 'zustand'
-import type { Update } from '@tauri-apps/plugin-updater'
+import type { AvailableUpdate } from '@/types'
 
 type DialogMode =
   | 'checking'
@@ -21,14 +21,11 @@ interface UpdateState {
   currentVersion: string | null
   errorMessage: string | null
 
-  // The Update object from check() — needed for downloadAndInstall()
-  // Stored as a non-reactive ref (not serializable, has methods)
-  updateRef: Update | null
-
-  // Release notes (fetched via Rust command from GitHub API)
+  // Release notes (combined by the Rust updater from GitHub releases)
   releaseNotes: string | null
-  releaseNotesLoading: boolean
-  releaseNotesError: boolean
+
+  // Downloaded and waiting to install when the app quits
+  deferred: boolean
 
   // Download progress
   downloadProgress: number
@@ -40,16 +37,11 @@ interface UpdateState {
   // Actions
   closeDialog: () => void
   setChecking: () => void
-  setAvailable: (
-    update: Update,
-    version: string,
-    currentVersion: string
-  ) => void
-  setReleaseNotes: (notes: string) => void
-  setReleaseNotesError: () => void
+  setAvailable: (update: AvailableUpdate, currentVersion: string) => void
   setDownloading: () => void
   setProgress: (downloaded: number, total: number) => void
   setReady: () => void
+  setDeferred: () => void
   setNoUpdate: (currentVersion: string) => void
   setError: (message: string) => void
   skipVersion: (version: string) => void
@@ -62,11 +54,9 @@ export const useUpdateStore = create<UpdateState>(set => ({
   version: null,
   currentVersion: null,
   errorMessage: null,
-  updateRef: null,
 
   releaseNotes: null,
-  releaseNotesLoading: false,
-  releaseNotesError: false,
+  deferred: false,
 
   downloadProgress: 0,
   downloadTotal: null,
@@ -78,8 +68,6 @@ export const useUpdateStore = create<UpdateState>(set => ({
       dialogOpen: false,
       dialogMode: 'checking',
       releaseNotes: null,
-      releaseNotesLoading: false,
-      releaseNotesError: false,
       downloadProgress: 0,
       downloadTotal: null,
     }),
@@ -87,25 +75,17 @@ export const useUpdateStore = create<UpdateState>(set => ({
   setChecking: () =>
     set({ dialogOpen: true, dialogMode: 'checking', errorMessage: null }),
 
-  setAvailable: (update, version, currentVersion) =>
+  setAvailable: (update, currentVersion) =>
     set({
       dialogOpen: true,
       dialogMode: 'available',
-      updateRef: update,
-      version,
+      version: update.version,
       currentVersion,
-      releaseNotesLoading: true,
-      releaseNotesError: false,
-      releaseNotes: null,
+      releaseNotes: update.releaseNotes || null,
+      deferred: update.deferred,
       errorMessage: null,
     }),
 
-  setReleaseNotes: notes =>
-    set({ releaseNotes: notes, releaseNotesLoading: false }),
-
-  setReleaseNotesError: () =>
-    set({ releaseNotesLoading: false, releaseNotesError: true }),
-
   setDownloading: () =>
     set({
       dialogMode: 'downloading',
@@ -121,6 +101,8 @@ export const useUpdateStore = create<UpdateState>(set => ({
 
   setReady: () => set({ dialogMode: 'ready', downloadProgress: 100 }),
 
+  setDeferred: () => set({ dialogOpen: false, deferred: true }),
+
   setNoUpdate: currentVersion =>
     set({
       dialogOpen: true,
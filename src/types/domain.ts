@@ -25,9 +25,12 @@ export type {
    * - `collection` - Collection this file belongs to
    * - `last_modified` - Unix timestamp of last modification (null when unavailable)
    * - `frontmatter` - Parsed frontmatter data (null when not loaded)
+   * - `title` - Title field value, or the body's first `# ` heading
+   * - `word_count` - Prose words in the body
+   * - `is_draft` - Whether the configured draft field is `true`
    *
-   * Note: Draft status is determined in the frontend using the user-configured
-   * draft field from settings, accessed via `file.frontmatter[draftField]`.
+   * The listing commands fill in `title`, `word_count` and `is_draft` using
+   * the collection's title and draft fields (see `getListingOrder`).
    */
   FileEntry,
   /**
@@ -41,8 +44,31 @@ export type {
    * - `content` - Markdown content (body, without frontmatter)
    * - `raw_frontmatter` - Raw YAML frontmatter text (between --- delimiters)
    * - `imports` - MDX imports at top of file
+   * - `content_hash` - Hash of the file, passed back on save to detect edits on disk
    */
   MarkdownContent,
+  /**
+   * Why a save was refused: the file changed on disk since it was loaded
+   * (kind `conflict`, with both versions) or the write failed.
+   */
+  FileSaveError,
+  /**
+   * Why frontmatter failed to parse (`FileLoadError` kind
+   * `invalidFrontmatter`): the file line, the likely cause and a fix.
+   */
+  FrontmatterError,
+  FrontmatterErrorCause,
+  /**
+   * A frontmatter value that breaks its field's schema constraints, as
+   * reported by `validateFrontmatter`. `field` is the dotted field path.
+   */
+  FieldViolation,
+  ViolationKind,
+  /**
+   * Why `applyRawFrontmatter` refused hand-edited YAML: it doesn't parse, or
+   * it breaks the collection's schema (apply again without one to override).
+   */
+  RawFrontmatterError,
   /**
    * Represents an Astro content collection.
    *
@@ -76,6 +102,11 @@ export type {
    * - `files` - Files in this directory
    */
   DirectoryScanResult,
+  /**
+   * A collection's directory tree from `scan_collection_tree`, scanned down to
+   * an optional depth; deeper directories are marked `truncated`.
+   */
+  DirectoryTree,
   /**
    * Information about an MDX component discovered in the project.
    *
@@ -96,6 +127,7 @@ export type {
    * - `prop_type` - TypeScript type as string
    * - `is_optional` - Whether prop is optional
    * - `default_value` - Default value if any
+   * - `description` - Prop description (from JSDoc)
    */
   PropInfo,
   /**
@@ -106,6 +138,444 @@ export type {
    * Application info (version, platform).
    */
   AppInfo,
+  /**
+   * Display-ready documentation for a frontmatter field (hover-help).
+   *
+   * Fields:
+   * - `fieldPath` - The requested field path
+   * - `collectionName` - Collection whose schema defines the field
+   * - `typeDescription` - Readable type, e.g. "reference to authors"
+   * - `description` / `markdownDescription` - Schema descriptions
+   * - `constraints` - Constraints as short sentences
+   * - `inheritedFrom` - Set when docs come from a referenced collection
+   */
+  FieldDocs,
+  /**
+   * A ranked quick-switcher result from `fuzzy_find`.
+   *
+   * Fields:
+   * - `filePath` - Absolute path to the entry
+   * - `kind` - Whether the title, filename or a heading matched
+   * - `text` - The matched text
+   * - `line` - 1-based line number for heading matches
+   * - `positions` - Matched character indices in `text`, for highlighting
+   */
+  FuzzyMatch,
+  /**
+   * A tracked file with open/edit counts, from `get_recent_files` and
+   * `get_frequent_files`. Timestamps are RFC 3339 UTC strings.
+   */
+  FileUsageEntry,
+  /**
+   * An automation intent (Shortcuts / x-callback-url) queued by Rust,
+   * from `take_pending_intents`.
+   */
+  IntentRequest,
+  /**
+   * A daily note returned by `open_daily_note`; `created` is true when it was
+   * just created from the template.
+   */
+  DailyNote,
+  /**
+   * A day with a daily note, from `get_daily_note_calendar`.
+   */
+  DailyNoteDay,
+  /**
+   * A GFM table converted from CSV/TSV by `convert_csv_to_table`, with the
+   * delimiter and header detection that was used.
+   */
+  MarkdownTable,
+  /**
+   * A math/LaTeX problem from `validate_math_blocks`. `start`/`end` are
+   * UTF-16 offsets into the validated content, so they map directly to
+   * editor positions.
+   */
+  MathDiagnostic,
+  MathDiagnosticSeverity,
+  /**
+   * Code block languages used in a collection, from `audit_code_blocks`,
+   * with blocks Shiki won't highlight and suggested fixes.
+   */
+  CodeBlockAudit,
+  LanguageUsage,
+  UnknownCodeBlock,
+  /**
+   * Summary of a `normalize_code_block_languages` run.
+   */
+  CodeBlockFixResult,
+  /**
+   * What to insert for a pasted URL: an MDX embed component plus its import,
+   * or a plain link when no component matches.
+   */
+  EmbedSuggestion,
+  /**
+   * A TODO/FIXME/HACK note found in a content file, with its 1-based line.
+   */
+  TodoItem,
+  TodoMarker,
+  /**
+   * Project activity over a date range, with a Markdown rendering.
+   */
+  ActivityReport,
+  ReportEntry,
+  ReportFieldMappings,
+  /**
+   * A recorded file write, create, delete, rename or copy, with the feature
+   * that requested it.
+   */
+  AuditEntry,
+  AuditAction,
+  /**
+   * The latest bulk operation that `undo_last_bulk_operation` would revert,
+   * and the outcome of reverting it. Skipped files changed after the
+   * operation and were left alone.
+   */
+  BulkOperationSummary,
+  BulkUndoResult,
+  /**
+   * A collection's publish checklist and the per-gate results from
+   * `check_publish_readiness`. Failed blocking gates make `ready` false.
+   */
+  PublishGate,
+  PublishRule,
+  PublishReadiness,
+  GateResult,
+  /**
+   * A draft published by `publish_file`, which may have renamed it with a date
+   * prefix like the rest of its collection.
+   */
+  PublishedFile,
+  PublishRename,
+  /**
+   * Every entry's dates grouped by month (`get_content_calendar`), for the
+   * calendar and planner views.
+   */
+  ContentCalendar,
+  CalendarMonth,
+  CalendarItem,
+  CalendarDateKind,
+  UndatedDraft,
+  /**
+   * Entries connected by references, body links and series, from
+   * `export_content_graph`, with the DOT or JSON rendering in `output`.
+   */
+  ContentGraph,
+  GraphNode,
+  GraphEdge,
+  GraphEdgeKind,
+  GraphFormat,
+  /**
+   * Internal links between entries (Markdown, reference and wiki links) from
+   * `get_link_graph`, and the entries linking to one file from `get_backlinks`.
+   */
+  LinkGraph,
+  InternalLink,
+  LinkKind,
+  Backlink,
+  /**
+   * A draft created from a speech-to-text transcript by `ingest_transcript`,
+   * and the frontmatter fields it is written with.
+   */
+  IngestedTranscript,
+  TranscriptFields,
+  /**
+   * A draft not edited for a while, from `get_stale_drafts`, and the weekly
+   * digest settings passed to `set_stale_draft_digest`.
+   */
+  StaleDraft,
+  StaleDraftDigest,
+  StaleDraftFields,
+  /**
+   * An entry held back by an `embargoUntil` field or `.embargo` sidecar, from
+   * `list_embargoed_entries` and the `embargo-lifted` event.
+   */
+  EmbargoedEntry,
+  EmbargoSource,
+  EmbargoWatchTarget,
+  /**
+   * Images imported from a folder by `import_image_folder`, with the gallery
+   * block built from them.
+   */
+  GalleryImage,
+  ImageFolderOptions,
+  ImageGallery,
+  /**
+   * Broken image references found by `repair_asset_references`, with the
+   * rewrite proposed for each.
+   */
+  AssetReferenceRepair,
+  AssetRepairReport,
+  /**
+   * References `rename_file` and `rename_file_with_link_rewrite` rewrote so
+   * links to and from the renamed file keep working.
+   */
+  RewrittenLink,
+  LinkRewriteReport,
+  /**
+   * Markdown extensions a project accepts, detected from its Astro config and
+   * adjusted by the `markdownFlavor` project setting.
+   */
+  MarkdownFlavor,
+  MarkdownFlavorSettings,
+  FlavorIssue,
+  /**
+   * Prose characters MDX would parse as code, reported by `check_mdx_escaping`
+   * and escaped on save when the `mdxEscaping` project setting opts in.
+   */
+  UnescapedCharacter,
+  MdxEscapingSettings,
+  EscapeStyle,
+  /**
+   * A field-level merge of a file with git conflict markers, from
+   * `resolve_frontmatter_conflict`, and the choices that settle the rest.
+   */
+  FrontmatterMerge,
+  FieldConflict,
+  ConflictChoice,
+  /**
+   * A three-way merge of the editor's version of a file with the one on
+   * disk after a save conflict, from `merge_file_versions`.
+   */
+  FileMerge,
+  BodyHunk,
+  /**
+   * A saved copy of a file in its version history (`list_file_versions`),
+   * which `restore_file_version` can bring back.
+   */
+  FileVersion,
+  /**
+   * Unsaved edits kept by the backend until the file is saved (`get_draft`),
+   * and the files that still have them when a project is opened.
+   */
+  UnsavedDraft,
+  UnsavedDraftSummary,
+  /**
+   * Proposed schema fields for the columns of a CSV being imported, from
+   * `suggest_import_mapping`, with confidence scores and sampled values.
+   */
+  ImportMappingSuggestion,
+  ColumnMapping,
+  ColumnType,
+  FieldMatch,
+  /**
+   * A process the editor started (`npm install`, `astro sync`) that is still
+   * running, from `list_managed_processes`.
+   */
+  ManagedProcessInfo,
+  /**
+   * A boolean/enum field offered in the Edit > Frontmatter menu.
+   */
+  FrontmatterFlagField,
+  /**
+   * Payload of `frontmatter-flag-toggled`: the field's new value on disk.
+   */
+  FrontmatterFlagToggle,
+  /**
+   * Editor state reported to the backend to enable/disable menu items.
+   */
+  MenuContext,
+  /**
+   * The action an intent asks for, discriminated by `type`.
+   */
+  IntentAction,
+  /**
+   * How the capture API is served, and where clients can reach it.
+   */
+  CaptureServerOptions,
+  CaptureServerInfo,
+  /**
+   * A saved crash report with its backend state snapshot, from
+   * `list_crash_reports`.
+   */
+  CrashReportSummary,
+  /**
+   * A mutating command to dry-run with `preview_changes`, and the file
+   * writes, deletes, renames, copies and directories it would make.
+   */
+  MutationRequest,
+  ChangePreview,
+  PlannedChange,
+  /**
+   * A frontmatter value with the wrong type for its schema field, and the
+   * value `apply_coercions` would write instead.
+   */
+  CoercionSuggestion,
+  /**
+   * Saved filter definitions (project setting `savedFilters`) and the entries
+   * `query_collection` finds for them.
+   */
+  SavedFilter,
+  FilterCondition,
+  FieldOperator,
+  FilterMatch,
+  /**
+   * How listing commands order titles and file names (project setting
+   * `collation`).
+   */
+  Collation,
+  /**
+   * Order and collapsed state of the frontmatter panel's field groups
+   * (project setting `fieldGroups`), applied when schemas are built.
+   */
+  FieldGroupSettings,
+  /**
+   * Reading time estimates and the project setting (`readingTime`) that
+   * tunes them.
+   */
+  ReadingTime,
+  ReadingTimeSettings,
+  /**
+   * How frontmatter dates are written on save (setting `dateFormats`, per
+   * project or collection, with per-field overrides).
+   */
+  DateFormat,
+  DateFormatSettings,
+  /**
+   * Deployed URLs for asset directories (project setting `assetUrlMappings`)
+   * and the result of rewriting rendered HTML with them.
+   */
+  AssetUrlMapping,
+  AssetUrlRewrite,
+  /**
+   * Display styles for `format_date` (project setting `locale`).
+   */
+  DateStyle,
+  /**
+   * OS reduced motion, increased contrast and color scheme settings, also
+   * pushed as `accessibility-prefs-changed` events.
+   */
+  SystemAccessibilityPrefs,
+  ColorScheme,
+  /**
+   * App updates from the stable or beta channel (`get_update_info`), with
+   * download progress pushed as `update-download-progress` events.
+   */
+  UpdateChannel,
+  UpdateInfo,
+  AvailableUpdate,
+  UpdateDownloadProgress,
+  /**
+   * Results of the schema fixture tests (`run_schema_fixture_tests`): each
+   * fixture's differences between the expected and merged schemas.
+   */
+  SchemaFixtureReport,
+  SchemaFixtureResult,
+  SchemaMismatch,
+  /**
+   * Scratchpads kept in app data until promoted into a collection
+   * (`promote_scratchpad_to_entry`), and the fields the entry is written with.
+   */
+  Scratchpad,
+  ScratchpadEntryFields,
+  /**
+   * Display labels for reference field ids (`resolve_reference_labels`), with
+   * whether each referenced entry exists.
+   */
+  ReferenceLabel,
+  /**
+   * A deleted file kept in the app's trash (`list_trashed_files`) until it's
+   * restored with `restore_trashed_file` or expires.
+   */
+  TrashedFile,
+  /**
+   * Frontmatter changes made to a copy by `duplicate_file`: fields set,
+   * cleared, or given a " Copy" suffix.
+   */
+  DuplicateAdjustments,
+  /**
+   * Payloads of the `listing-batch` and `listing-complete` events sent by
+   * `stream_collection_files`.
+   */
+  ListingBatch,
+  ListingComplete,
+  /**
+   * A project's git branch and worktree (`get_git_branch_state`), and the
+   * `contentBranch` setting that content is edited on.
+   */
+  GitBranchState,
+  ContentBranchSettings,
+  /**
+   * Includes (`{{include: path}}`) expanded by `resolve_transclusions`, and
+   * the ones that couldn't be.
+   */
+  ResolvedTransclusions,
+  TransclusionProblem,
+  TransclusionProblemKind,
+  TransclusionSettings,
+  /**
+   * Payload of the watcher's `file-changed` event, also sent as
+   * `file-created`, `file-modified`, `file-deleted` or `file-renamed`.
+   */
+  FileChangeEvent,
+  FileChangeKind,
+  /**
+   * The project's Astro dev server (`get_dev_server_status`) and where an
+   * entry renders on it (`get_preview_url`).
+   */
+  DevServerStatus,
+  PreviewUrl,
+  /**
+   * The built-in preview of the open document (`render_markdown_preview`).
+   */
+  MarkdownPreview,
+  /**
+   * Spell checking (`check_text`) against Hunspell dictionaries and the
+   * project's own word list.
+   */
+  Misspelling,
+  /**
+   * Broken link checking (`check_links`) for an entry or collection.
+   */
+  CheckedLink,
+  CheckedLinkKind,
+  LinkStatus,
+  /**
+   * Content directory backups: scheduled, manual and before bulk operations.
+   */
+  BackupInfo,
+  BackupRestoreResult,
+  BackupSettings,
+  /**
+   * Slugs for new entries (`generate_slug`, tuned by the project setting
+   * `slugs`) and entries that share one (`check_slug_conflicts`).
+   */
+  SlugConflict,
+  SlugSettings,
+  SlugSource,
+  /**
+   * Where MDX components are used (`find_component_usages`) and which ones
+   * nothing uses (`component_usage_report`).
+   */
+  ComponentUsage,
+  ComponentUsageReport,
+  ComponentUsageSummary,
+  /**
+   * Pages of images in the assets directory for the asset picker
+   * (`list_project_assets`), grouped by collection.
+   */
+  AssetGroup,
+  AssetListOptions,
+  AssetPage,
+  ProjectAsset,
+  /**
+   * Assets no content references (`find_orphaned_assets`), and the result of
+   * moving them to the trash (`delete_orphaned_assets`).
+   */
+  OrphanedAsset,
+  OrphanedAssetDeletion,
+  OrphanedAssetReport,
+  /**
+   * Editors found on the PATH (`detect_ides`), offered as IDE commands in
+   * preferences.
+   */
+  DetectedIde,
+  /**
+   * The registry of opened projects (`list_recent_projects`) and each
+   * project's directory overrides (`get_project_settings`).
+   */
+  RegisteredProject,
+  RecentProject,
+  ProjectPathSettings,
   /**
    * JSON-compatible value type.
    * Used for dynamic frontmatter data.